@@ -0,0 +1,47 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+import type { Credential, CredentialV2, Subject } from "~identity_wasm";
+
+/**
+ * A runtime check asserting that a credential subject conforms to the claim structure `T`,
+ * e.g. a schema validator or a hand written type guard.
+ */
+export type SubjectCheck<T extends Subject> = (subject: Subject) => subject is T;
+
+/** A {@link Credential} whose subjects are known to conform to the claim structure `T`. */
+export type TypedCredential<T extends Subject = Subject> = Credential & {
+    /** Returns a copy of the {@link Credential} subject(s). */
+    credentialSubject(): Array<T>;
+};
+
+/** A {@link CredentialV2} whose subjects are known to conform to the claim structure `T`. */
+export type TypedCredentialV2<T extends Subject = Subject> = CredentialV2 & {
+    /** Returns a copy of the {@link CredentialV2} subject(s). */
+    credentialSubject(): Array<T>;
+};
+
+// Augment credentials with typed access to their subjects.
+declare module "~identity_wasm" {
+    interface Credential {
+        /**
+         * Returns a copy of the {@link Credential} subject(s), typed as `T`.
+         *
+         * If `check` is provided, it is called on every subject and must return `true` for the subject
+         * to be considered a `T`, otherwise a `TypeError` is thrown. Without `check` the subjects are
+         * assumed to be `T`.
+         */
+        getTypedSubject<T extends Subject = Subject>(check?: SubjectCheck<T>): Array<T>;
+    }
+
+    interface CredentialV2 {
+        /**
+         * Returns a copy of the {@link CredentialV2} subject(s), typed as `T`.
+         *
+         * If `check` is provided, it is called on every subject and must return `true` for the subject
+         * to be considered a `T`, otherwise a `TypeError` is thrown. Without `check` the subjects are
+         * assumed to be `T`.
+         */
+        getTypedSubject<T extends Subject = Subject>(check?: SubjectCheck<T>): Array<T>;
+    }
+}
@@ -0,0 +1,222 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/** The prefix of every error code, e.g. `IOTA_ID_1606`. */
+export const ERROR_CODE_PREFIX = "IOTA_ID_";
+
+/**
+ * Returns the number of the stable error code carried by `error`, e.g. `1606` for `IOTA_ID_1606`,
+ * or `undefined` if `error` has no such code.
+ */
+export function errorCodeNumber(error: unknown): number | undefined {
+    if (!(error instanceof Error)) {
+        return undefined;
+    }
+    const code = (error as { code?: unknown }).code;
+    if (typeof code !== "string" || !code.startsWith(ERROR_CODE_PREFIX)) {
+        return undefined;
+    }
+    const number = Number(code.slice(ERROR_CODE_PREFIX.length));
+    return Number.isInteger(number) ? number : undefined;
+}
+
+/**
+ * Base class of the errors thrown by the IOTA Identity bindings that originate from a Rust error with a stable code.
+ *
+ * Errors are thrown as plain `Error` instances; `instanceof` checks against this class and its subclasses
+ * are resolved through the error's `code`, so that
+ * ```ts
+ * try {
+ *     CoreDID.parse("invalid");
+ * } catch (error) {
+ *     if (error instanceof DIDError) {
+ *         console.log(error.code, error.name, error.message, error.cause);
+ *     }
+ * }
+ * ```
+ * works as expected. Codes are stable across releases, names and messages are not.
+ */
+export class IdentityError extends Error {
+    /** The stable code of this error, e.g. `IOTA_ID_1606`. */
+    declare readonly code: string;
+    /** The error that caused this one, if any. */
+    declare readonly cause?: Error;
+
+    /** The range of code numbers identifying errors of this class. */
+    protected static readonly codeRange: readonly [number, number] = [0, Number.MAX_SAFE_INTEGER];
+
+    static [Symbol.hasInstance](value: unknown): boolean {
+        const number = errorCodeNumber(value);
+        return number !== undefined && number >= this.codeRange[0] && number <= this.codeRange[1];
+    }
+}
+
+/** Errors of `identity_core`, e.g. JSON or base decoding failures. Codes 1000–1099. */
+export class CoreError extends IdentityError {
+    protected static readonly codeRange = [1000, 1099] as const;
+}
+
+/** Errors related to parsing or manipulating DIDs and DID URLs. Codes 1100–1199. */
+export class DIDError extends IdentityError {
+    protected static readonly codeRange = [1100, 1199] as const;
+}
+
+/** Errors related to JOSE, e.g. JWK, JWS and JWT handling. Codes 1200–1299. */
+export class JoseError extends IdentityError {
+    protected static readonly codeRange = [1200, 1299] as const;
+}
+
+/** Errors related to verification methods and their key material. Codes 1300–1399. */
+export class VerificationError extends IdentityError {
+    protected static readonly codeRange = [1300, 1399] as const;
+}
+
+/** Errors related to DID documents. Codes 1400–1499. */
+export class DocumentError extends IdentityError {
+    protected static readonly codeRange = [1400, 1499] as const;
+}
+
+/** Errors related to credentials and presentations. Codes 1500–1539. */
+export class CredentialError extends IdentityError {
+    protected static readonly codeRange = [1500, 1539] as const;
+}
+
+/** Errors related to the `RevocationBitmap2022` revocation method. Codes 1540–1559. */
+export class RevocationError extends IdentityError {
+    protected static readonly codeRange = [1540, 1559] as const;
+}
+
+/** Errors related to `StatusList2021` status lists. Codes 1560–1569. */
+export class StatusListError extends IdentityError {
+    protected static readonly codeRange = [1560, 1569] as const;
+}
+
+/** Errors related to `StatusList2021Credential`s. Codes 1570–1599. */
+export class StatusList2021CredentialError extends IdentityError {
+    protected static readonly codeRange = [1570, 1599] as const;
+}
+
+/** Errors raised while validating credentials and presentations. Codes 1600–1699. */
+export class JwtValidationError extends IdentityError {
+    protected static readonly codeRange = [1600, 1699] as const;
+}
+
+/** Errors raised while validating an SD-JWT key binding JWT. Codes 1700–1749. */
+export class KeyBindingJwtError extends IdentityError {
+    protected static readonly codeRange = [1700, 1749] as const;
+}
+
+/** Errors raised while validating a domain linkage. Codes 1750–1799. */
+export class DomainLinkageValidationError extends IdentityError {
+    protected static readonly codeRange = [1750, 1799] as const;
+}
+
+/** Errors raised by the {@link Resolver}. Codes 1800–1899. */
+export class ResolverError extends IdentityError {
+    protected static readonly codeRange = [1800, 1899] as const;
+}
+
+/** Errors raised by a {@link JwkStorage}. Codes 1900–1919. */
+export class KeyStorageError extends IdentityError {
+    protected static readonly codeRange = [1900, 1919] as const;
+}
+
+/** Errors raised by a {@link KeyIdStorage}. Codes 1920–1939. */
+export class KeyIdStorageError extends IdentityError {
+    protected static readonly codeRange = [1920, 1939] as const;
+}
+
+/** Errors raised while creating or using keys stored in a {@link Storage}. Codes 1940–1999. */
+export class JwkStorageDocumentError extends IdentityError {
+    protected static readonly codeRange = [1940, 1999] as const;
+}
+
+/** Errors related to IOTA DIDs and documents. Codes 2000–2099. */
+export class IotaCoreError extends IdentityError {
+    protected static readonly codeRange = [2000, 2099] as const;
+}
+
+/** Errors raised by the identity clients and on-chain identity operations. Codes 2100–2199. */
+export class IdentityClientError extends IdentityError {
+    protected static readonly codeRange = [2100, 2199] as const;
+}
+
+/** Errors raised while issuing or validating delegation credentials. Codes 2300–2349. */
+export class DelegationError extends IdentityError {
+    protected static readonly codeRange = [2300, 2349] as const;
+}
+
+/** Errors raised while validating Wallet Instance Attestations. Codes 2350–2399. */
+export class WalletAttestationError extends IdentityError {
+    protected static readonly codeRange = [2350, 2399] as const;
+}
+
+/** Errors raised while parsing or evaluating DCQL queries. Codes 2400–2449. */
+export class DcqlError extends IdentityError {
+    protected static readonly codeRange = [2400, 2449] as const;
+}
+
+/** Errors raised while validating DID-signed JWTs. Codes 2450–2469. */
+export class DidJwtError extends IdentityError {
+    protected static readonly codeRange = [2450, 2469] as const;
+}
+
+/** Errors raised while comparing a credential with its reissued version. Codes 2470–2479. */
+export class CredentialDiffError extends IdentityError {
+    protected static readonly codeRange = [2470, 2479] as const;
+}
+
+/** Failed constraints across the credentials of a presentation. Codes 2480–2489. */
+export class CrossCredentialError extends IdentityError {
+    protected static readonly codeRange = [2480, 2489] as const;
+}
+
+/** Violations of the key usage policy of a {@link JwkStorage}. Codes 2500–2509. */
+export class KeyUsageViolationError extends IdentityError {
+    protected static readonly codeRange = [2500, 2509] as const;
+}
+
+/** Errors raised while validating a credential of any supported format. Codes 2510–2519. */
+export class AnyCredentialError extends IdentityError {
+    protected static readonly codeRange = [2510, 2519] as const;
+}
+
+/** Errors raised while allocating revocation indices. Codes 2520–2529. */
+export class IndexAllocationError extends IdentityError {
+    protected static readonly codeRange = [2520, 2529] as const;
+}
+
+/** Errors raised while notifying revocations. Codes 2530–2539. */
+export class RevocationNotificationError extends IdentityError {
+    protected static readonly codeRange = [2530, 2539] as const;
+}
+
+/** Errors raised while decoding multibase and multicodec values. Codes 2540–2549. */
+export class CodecError extends IdentityError {
+    protected static readonly codeRange = [2540, 2549] as const;
+}
+
+/** Errors raised while validating credentials and presentations through a verifier context. Codes 2550–2559. */
+export class VerifierContextError extends IdentityError {
+    protected static readonly codeRange = [2550, 2559] as const;
+}
+
+/** Errors raised while exporting, verifying or resolving from offline resolution bundles. Codes 2560–2569. */
+export class OfflineBundleError extends IdentityError {
+    protected static readonly codeRange = [2560, 2569] as const;
+}
+
+/** Errors raised while linking a rotated DID to its predecessor. Codes 2570–2579. */
+export class ContinuityError extends IdentityError {
+    protected static readonly codeRange = [2570, 2579] as const;
+}
+
+/** Errors raised by the batch verification of Ed25519 signatures. Codes 2580–2589. */
+export class BatchVerificationError extends IdentityError {
+    protected static readonly codeRange = [2580, 2589] as const;
+}
+
+/** Errors raised while sending HTTP requests. Codes 2590–2599. */
+export class HttpError extends IdentityError {
+    protected static readonly codeRange = [2590, 2599] as const;
+}
@@ -3,6 +3,7 @@
 
 import "./append_functions.js";
 
+export * from "./errors";
 export * from "./jose";
 export * from "./jwk_storage";
 export * from "./key_id_storage";
@@ -10,7 +11,9 @@ export * from "./key_id_storage";
 export * from "~identity_wasm";
 
 export * from "./controller";
+export * from "./credential";
 export * from "./proposal";
+export * from "./worker";
 
 export * from "@iota/iota-interaction-ts/transaction_internal";
 
@@ -1,5 +1,14 @@
 import * as ed from "@noble/ed25519";
-import { decodeB64, encodeB64, Jwk, JwkGenOutput, JwkStorage, ProofAlgorithm, ProofUpdateCtx } from "~identity_wasm";
+import {
+    decodeB64,
+    EncryptedStorageWrapper,
+    encodeB64,
+    Jwk,
+    JwkGenOutput,
+    JwkStorage,
+    ProofAlgorithm,
+    ProofUpdateCtx,
+} from "~identity_wasm";
 import { EdCurve, JwkType, JwsAlgorithm } from "./jose";
 
 type Ed25519PrivateKey = Uint8Array;
@@ -93,6 +102,104 @@ export class JwkMemStore implements JwkStorage {
     }
 }
 
+/**
+ * A `JwkStorage` persisting Ed25519 keys in an `EncryptedStorageWrapper`, e.g. over IndexedDB in a browser wallet.
+ */
+export class EncryptedJwkStore implements JwkStorage {
+    private _storage: EncryptedStorageWrapper;
+
+    constructor(storage: EncryptedStorageWrapper) {
+        this._storage = storage;
+    }
+
+    public static ed25519KeyType(): string {
+        return JwkMemStore.ed25519KeyType();
+    }
+
+    public async generate(keyType: string, algorithm: JwsAlgorithm): Promise<JwkGenOutput> {
+        if (keyType !== EncryptedJwkStore.ed25519KeyType()) {
+            throw new Error(`unsupported key type ${keyType}`);
+        }
+
+        if (algorithm !== JwsAlgorithm.EdDSA) {
+            throw new Error(`unsupported algorithm`);
+        }
+
+        const keyId = randomKeyId();
+        const privKey: Ed25519PrivateKey = ed.utils.randomPrivateKey();
+        const jwk = await encodeJwk(privKey, algorithm);
+
+        await this._set_key(keyId, jwk);
+
+        const publicJWK = jwk.toPublic();
+        if (!publicJWK) {
+            throw new Error(`JWK is not a public key`);
+        }
+
+        return new JwkGenOutput(keyId, publicJWK);
+    }
+
+    public async sign(keyId: string, data: Uint8Array, publicKey: Jwk): Promise<Uint8Array> {
+        if (publicKey.alg() !== JwsAlgorithm.EdDSA) {
+            throw new Error("unsupported JWS algorithm");
+        } else {
+            if (publicKey.paramsOkp()?.crv !== (EdCurve.Ed25519 as string)) {
+                throw new Error("unsupported Okp parameter");
+            }
+        }
+
+        const jwk = await this._get_key(keyId);
+
+        if (jwk) {
+            const [privateKey, _] = decodeJwk(jwk);
+            return ed.sign(data, privateKey);
+        } else {
+            throw new Error(`key with id ${keyId} not found`);
+        }
+    }
+
+    public async insert(jwk: Jwk): Promise<string> {
+        const keyId = randomKeyId();
+
+        if (!jwk.isPrivate) {
+            throw new Error("expected a JWK with all private key components set");
+        }
+
+        if (!jwk.alg()) {
+            throw new Error("expected a Jwk with an `alg` parameter");
+        }
+
+        await this._set_key(keyId, jwk);
+
+        return keyId;
+    }
+
+    public async delete(keyId: string): Promise<void> {
+        await this._storage.delete(jwkEntry(keyId));
+    }
+
+    public async exists(keyId: string): Promise<boolean> {
+        return (await this._storage.get(jwkEntry(keyId))) !== undefined;
+    }
+
+    private async _set_key(keyId: string, jwk: Jwk): Promise<void> {
+        await this._storage.set(jwkEntry(keyId), new TextEncoder().encode(JSON.stringify(jwk.toJSON())));
+    }
+
+    private async _get_key(keyId: string): Promise<Jwk | undefined> {
+        const value = await this._storage.get(jwkEntry(keyId));
+        if (value === undefined) {
+            return undefined;
+        }
+        return Jwk.fromJSON(JSON.parse(new TextDecoder().decode(value)));
+    }
+}
+
+// Returns the name of the entry holding the key `keyId`.
+function jwkEntry(keyId: string): string {
+    return `jwk:${keyId}`;
+}
+
 // Encodes a Ed25519 keypair into a Jwk.
 async function encodeJwk(privateKey: Ed25519PrivateKey, alg: JwsAlgorithm): Promise<Jwk> {
     const publicKey = await ed.getPublicKey(privateKey);
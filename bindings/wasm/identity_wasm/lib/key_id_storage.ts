@@ -1,5 +1,5 @@
 import { encode as base64Encode } from "base64-arraybuffer";
-import type { KeyIdStorage, MethodDigest } from "~identity_wasm";
+import type { EncryptedStorageWrapper, KeyIdStorage, MethodDigest } from "~identity_wasm";
 
 export class KeyIdMemStore implements KeyIdStorage {
     private _keyIds: Map<string, string>;
@@ -44,6 +44,51 @@ export class KeyIdMemStore implements KeyIdStorage {
     }
 }
 
+/**
+ * A `KeyIdStorage` persisting key ids in an `EncryptedStorageWrapper`, e.g. over IndexedDB in a browser wallet.
+ */
+export class EncryptedKeyIdStore implements KeyIdStorage {
+    private _storage: EncryptedStorageWrapper;
+
+    constructor(storage: EncryptedStorageWrapper) {
+        this._storage = storage;
+    }
+
+    public async insertKeyId(
+        methodDigest: MethodDigest,
+        keyId: string,
+    ): Promise<void> {
+        const entry = keyIdEntry(methodDigest);
+        if ((await this._storage.get(entry)) !== undefined) {
+            throw new Error("KeyId already exists");
+        }
+        await this._storage.set(entry, new TextEncoder().encode(keyId));
+    }
+
+    public async getKeyId(methodDigest: MethodDigest): Promise<string> {
+        const value = await this._storage.get(keyIdEntry(methodDigest));
+        if (value === undefined) {
+            throw new Error("KeyId not found");
+        }
+        return new TextDecoder().decode(value);
+    }
+
+    public async deleteKeyId(methodDigest: MethodDigest): Promise<void> {
+        const entry = keyIdEntry(methodDigest);
+        if ((await this._storage.get(entry)) === undefined) {
+            throw new Error("KeyId not found!");
+        }
+        await this._storage.delete(entry);
+    }
+}
+
+/**
+ * Returns the name of the entry holding the key id of `methodDigest`.
+ */
+function keyIdEntry(methodDigest: MethodDigest): string {
+    return `keyId:${methodDigestToString(methodDigest)}`;
+}
+
 /**
  * Converts a `MethodDigest` to a base64 encoded string.
  */
@@ -0,0 +1,329 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+import {
+    CoreDocument,
+    Credential,
+    CredentialV2,
+    IotaDocument,
+    Jwk,
+    JwkGenOutput,
+    JwkStorage,
+    JwpIssued,
+    Jwt,
+    KeyIdStorage,
+    MethodDigest,
+    Presentation,
+    Storage,
+} from "~identity_wasm";
+
+// ====================================================================================================================
+// Snapshots
+// ====================================================================================================================
+
+/** A structured-clone-friendly snapshot of an `identity_wasm` object, see {@link toSnapshot}. */
+export interface Snapshot {
+    readonly __identityType: string;
+    readonly data: unknown;
+}
+
+interface Codec {
+    readonly type: string;
+    matches(value: unknown): boolean;
+    encode(value: any): unknown;
+    decode(data: any): unknown;
+}
+
+const cborCodec = (type: string, cls: { fromCbor(bytes: Uint8Array): unknown }): Codec => ({
+    type,
+    matches: (value) => value instanceof (cls as any),
+    encode: (value) => value.toCbor(),
+    decode: (data) => cls.fromCbor(data),
+});
+
+const jsonCodec = (type: string, cls: { fromJSON(json: any): unknown }): Codec => ({
+    type,
+    matches: (value) => value instanceof (cls as any),
+    encode: (value) => value.toJSON(),
+    decode: (data) => cls.fromJSON(data),
+});
+
+const CODECS: Codec[] = [
+    cborCodec("CoreDocument", CoreDocument),
+    cborCodec("IotaDocument", IotaDocument),
+    cborCodec("Credential", Credential),
+    cborCodec("CredentialV2", CredentialV2),
+    cborCodec("Presentation", Presentation),
+    cborCodec("JwpIssued", JwpIssued),
+    jsonCodec("Jwk", Jwk),
+    jsonCodec("Jwt", Jwt),
+    jsonCodec("JwkGenOutput", JwkGenOutput),
+    {
+        type: "MethodDigest",
+        matches: (value) => value instanceof MethodDigest,
+        encode: (value: MethodDigest) => value.pack(),
+        decode: (data: Uint8Array) => MethodDigest.unpack(data),
+    },
+];
+
+function isSnapshot(value: unknown): value is Snapshot {
+    return typeof value === "object" && value !== null && "__identityType" in value && "data" in value;
+}
+
+function isPlainObject(value: unknown): value is Record<string, unknown> {
+    return typeof value === "object" && value !== null && Object.getPrototypeOf(value) === Object.prototype;
+}
+
+/**
+ * Converts `value` into a form that can be posted to a worker with `postMessage`.
+ *
+ * `identity_wasm` objects, which live in the memory of the Wasm instance that created them, are replaced by
+ * {@link Snapshot}s of their content; arrays and plain objects are converted recursively. All other values are
+ * returned unchanged. Use {@link fromSnapshot} on the receiving side and {@link transferList} to move, rather than
+ * copy, the underlying buffers.
+ */
+export function toSnapshot(value: unknown): unknown {
+    const codec = CODECS.find((codec) => codec.matches(value));
+    if (codec) {
+        return { __identityType: codec.type, data: codec.encode(value) } satisfies Snapshot;
+    }
+    if (Array.isArray(value)) {
+        return value.map(toSnapshot);
+    }
+    if (isPlainObject(value)) {
+        return Object.fromEntries(Object.entries(value).map(([key, entry]) => [key, toSnapshot(entry)]));
+    }
+    return value;
+}
+
+/** Reconstructs the `identity_wasm` objects contained in a value produced by {@link toSnapshot}. */
+export function fromSnapshot(value: unknown): unknown {
+    if (isSnapshot(value)) {
+        const codec = CODECS.find((codec) => codec.type === value.__identityType);
+        if (!codec) {
+            throw new Error(`unknown snapshot type ${value.__identityType}`);
+        }
+        return codec.decode(value.data);
+    }
+    if (Array.isArray(value)) {
+        return value.map(fromSnapshot);
+    }
+    if (isPlainObject(value)) {
+        return Object.fromEntries(Object.entries(value).map(([key, entry]) => [key, fromSnapshot(entry)]));
+    }
+    return value;
+}
+
+/**
+ * Returns the buffers of the {@link Snapshot}s contained in `value`, to be passed as the transfer list of
+ * `postMessage`.
+ *
+ * Only buffers created by {@link toSnapshot} are returned, transferring them leaves the caller's objects untouched.
+ */
+export function transferList(value: unknown): Transferable[] {
+    if (isSnapshot(value)) {
+        return value.data instanceof Uint8Array ? [value.data.buffer as ArrayBuffer] : [];
+    }
+    if (Array.isArray(value)) {
+        return value.flatMap(transferList);
+    }
+    if (isPlainObject(value)) {
+        return Object.values(value).flatMap(transferList);
+    }
+    return [];
+}
+
+// ====================================================================================================================
+// Remote calls
+// ====================================================================================================================
+
+/** The subset of `MessagePort`, `Worker` and `DedicatedWorkerGlobalScope` used by this module. */
+export interface MessageEndpoint {
+    postMessage(message: any, transfer: Transferable[]): void;
+    addEventListener(type: "message", listener: (event: MessageEvent) => void): void;
+    start?(): void;
+}
+
+type Request = { id: number; method: string; args: unknown[] };
+type Response = { id: number; result?: unknown; error?: string };
+
+/** Sends requests over `endpoint` and matches the responses to them. */
+class RemoteCaller {
+    private _nextId = 0;
+    private readonly _pending = new Map<number, { resolve: (value: unknown) => void; reject: (e: Error) => void }>();
+
+    constructor(private readonly _endpoint: MessageEndpoint) {
+        _endpoint.addEventListener("message", (event) => this.onResponse(event.data as Response));
+        _endpoint.start?.();
+    }
+
+    /** The number of calls awaiting a response. */
+    get pending(): number {
+        return this._pending.size;
+    }
+
+    call(method: string, args: unknown[]): Promise<any> {
+        const id = this._nextId++;
+        const request: Request = { id, method, args: toSnapshot(args) as unknown[] };
+        return new Promise((resolve, reject) => {
+            this._pending.set(id, { resolve, reject });
+            this._endpoint.postMessage(request, transferList(request.args));
+        });
+    }
+
+    private onResponse(response: Response) {
+        const pending = this._pending.get(response.id);
+        if (!pending) {
+            return;
+        }
+        this._pending.delete(response.id);
+        if (response.error !== undefined) {
+            pending.reject(new Error(response.error));
+        } else {
+            pending.resolve(fromSnapshot(response.result));
+        }
+    }
+}
+
+/** Answers the requests received over `endpoint` with the functions in `handlers`. */
+function serve(endpoint: MessageEndpoint, handlers: Record<string, (...args: any[]) => unknown>) {
+    endpoint.addEventListener("message", async (event) => {
+        const { id, method, args } = event.data as Request;
+        let response: Response;
+        try {
+            const handler = handlers[method];
+            if (!handler) {
+                throw new Error(`unknown method ${method}`);
+            }
+            response = { id, result: toSnapshot(await handler(...(fromSnapshot(args) as unknown[]))) };
+        } catch (error) {
+            response = { id, error: error instanceof Error ? error.message : String(error) };
+        }
+        endpoint.postMessage(response, transferList(response.result));
+    });
+    endpoint.start?.();
+}
+
+// ====================================================================================================================
+// Storage
+// ====================================================================================================================
+
+/**
+ * Serves `storage` over `port`, allowing a worker to use it through {@link remoteStorage}.
+ *
+ * Private keys never leave the thread `storage` lives in: the worker only receives public keys, key identifiers and
+ * signatures.
+ */
+export function exposeStorage(storage: Storage, port: MessageEndpoint): void {
+    const keyStorage: JwkStorage = storage.keyStorage();
+    const keyIdStorage: KeyIdStorage = storage.keyIdStorage();
+    serve(port, {
+        generate: (keyType, algorithm) => keyStorage.generate(keyType, algorithm),
+        insert: (jwk) => keyStorage.insert(jwk),
+        sign: (keyId, data, publicKey) => keyStorage.sign(keyId, data, publicKey),
+        delete: (keyId) => keyStorage.delete(keyId),
+        exists: (keyId) => keyStorage.exists(keyId),
+        insertKeyId: (methodDigest, keyId) => keyIdStorage.insertKeyId(methodDigest, keyId),
+        getKeyId: (methodDigest) => keyIdStorage.getKeyId(methodDigest),
+        deleteKeyId: (methodDigest) => keyIdStorage.deleteKeyId(methodDigest),
+    });
+}
+
+/** Returns a {@link Storage} whose operations are forwarded over `port` to a storage served by {@link exposeStorage}. */
+export function remoteStorage(port: MessageEndpoint): Storage {
+    const caller = new RemoteCaller(port);
+    const keyStorage: JwkStorage = {
+        generate: (keyType, algorithm) => caller.call("generate", [keyType, algorithm]),
+        insert: (jwk) => caller.call("insert", [jwk]),
+        sign: (keyId, data, publicKey) => caller.call("sign", [keyId, data, publicKey]),
+        delete: (keyId) => caller.call("delete", [keyId]),
+        exists: (keyId) => caller.call("exists", [keyId]),
+    };
+    const keyIdStorage: KeyIdStorage = {
+        insertKeyId: (methodDigest, keyId) => caller.call("insertKeyId", [methodDigest, keyId]),
+        getKeyId: (methodDigest) => caller.call("getKeyId", [methodDigest]),
+        deleteKeyId: (methodDigest) => caller.call("deleteKeyId", [methodDigest]),
+    };
+    return new Storage(keyStorage, keyIdStorage);
+}
+
+// ====================================================================================================================
+// Worker pool
+// ====================================================================================================================
+
+/** A task that can be run by {@link WorkerPool.run}, registered in the worker with {@link registerWorkerTasks}. */
+export type WorkerTask = (...args: any[]) => unknown;
+
+/**
+ * Registers the `tasks` a worker can run on behalf of a {@link WorkerPool}.
+ *
+ * To be called once in the worker script, after the Wasm module has been initialized. Arguments and results are
+ * converted with {@link toSnapshot} and {@link fromSnapshot}, tasks can therefore receive and return
+ * `identity_wasm` objects.
+ */
+export function registerWorkerTasks(tasks: Record<string, WorkerTask>, scope: MessageEndpoint = self as any): void {
+    serve(scope, tasks);
+}
+
+/** A worker as seen by a {@link WorkerPool}. */
+export interface PoolWorker extends MessageEndpoint {
+    terminate(): void;
+}
+
+/**
+ * A fixed-size pool of workers running heavy operations, e.g. BBS+ proof generation or the validation of large
+ * presentations, off the main thread.
+ *
+ * # Example
+ *
+ * ```ts
+ * // worker.ts
+ * await init();
+ * registerWorkerTasks({
+ *     validate: (jwt: Jwt, issuer: CoreDocument) =>
+ *         new JwtCredentialValidator(new EdDSAJwsVerifier())
+ *             .validate(jwt, issuer, new JwtCredentialValidationOptions(), FailFast.FirstError)
+ *             .credential(),
+ * });
+ *
+ * // main.ts
+ * const pool = new WorkerPool(() => new Worker(new URL("./worker.ts", import.meta.url), { type: "module" }));
+ * const credential: Credential = await pool.run("validate", jwt, issuerDocument);
+ * ```
+ */
+export class WorkerPool {
+    private readonly _workers: { worker: PoolWorker; caller: RemoteCaller }[];
+
+    /**
+     * Creates a pool of `size` workers created by `createWorker`, defaulting to the number of logical processors.
+     */
+    constructor(createWorker: () => PoolWorker, size: number = globalThis.navigator?.hardwareConcurrency ?? 4) {
+        if (size < 1) {
+            throw new Error("a worker pool needs at least one worker");
+        }
+        this._workers = Array.from({ length: size }, () => {
+            const worker = createWorker();
+            return { worker, caller: new RemoteCaller(worker) };
+        });
+    }
+
+    /** Returns the number of workers in this pool. */
+    get size(): number {
+        return this._workers.length;
+    }
+
+    /**
+     * Runs the task registered as `task` with `args` on the least busy worker.
+     *
+     * The promise is rejected with the message of the error thrown by the task, if any.
+     */
+    run<T = unknown>(task: string, ...args: unknown[]): Promise<T> {
+        const { caller } = this._workers.reduce((least, entry) => entry.caller.pending < least.caller.pending ? entry : least);
+        return caller.call(task, args);
+    }
+
+    /** Terminates all the workers of this pool, tasks still running are abandoned. */
+    terminate(): void {
+        this._workers.forEach(({ worker }) => worker.terminate());
+    }
+}
@@ -0,0 +1,29 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::borrow::Cow;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::error::Result;
+use crate::error::WasmError;
+
+/// Encodes `value` as CBOR.
+///
+/// Transferring the resulting bytes across the JS boundary is a single copy, as opposed to materializing the whole
+/// object graph as JS values.
+pub(crate) fn to_cbor<T: Serialize>(value: &T) -> Result<Vec<u8>> {
+  let mut bytes: Vec<u8> = Vec::new();
+  ciborium::into_writer(value, &mut bytes).map_err(|error| cbor_error(error.to_string()))?;
+  Ok(bytes)
+}
+
+/// Decodes a value previously encoded with [`to_cbor`].
+pub(crate) fn from_cbor<T: DeserializeOwned>(bytes: &[u8]) -> Result<T> {
+  ciborium::from_reader(bytes).map_err(|error| cbor_error(error.to_string()))
+}
+
+fn cbor_error(message: String) -> wasm_bindgen::JsValue {
+  WasmError::new(Cow::Borrowed("CborError"), Cow::Owned(message)).into()
+}
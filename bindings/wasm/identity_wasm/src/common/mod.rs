@@ -1,6 +1,7 @@
 // Copyright 2020-2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+pub(crate) use cbor::*;
 pub use timestamp::*;
 pub use types::*;
 pub(crate) use utils::*;
@@ -8,6 +9,7 @@ pub(crate) use utils::*;
 pub(crate) use self::imported_document_lock::ImportedDocumentLock;
 pub(crate) use self::imported_document_lock::ImportedDocumentReadGuard;
 
+mod cbor;
 mod imported_document_lock;
 mod timestamp;
 mod types;
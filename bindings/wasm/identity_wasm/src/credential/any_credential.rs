@@ -0,0 +1,315 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_iota::credential::AnyCredential;
+use identity_iota::credential::AnyCredentialValidationOptions;
+use identity_iota::credential::AnyCredentialValidator;
+use identity_iota::credential::CredentialFormat;
+use identity_iota::credential::DecodedCredential;
+use identity_iota::did::CoreDID;
+use wasm_bindgen::prelude::*;
+
+use crate::common::ImportedDocumentLock;
+use crate::common::RecordStringAny;
+use crate::credential::CredentialAny;
+use crate::credential::WasmCredential;
+use crate::credential::WasmCredentialV2;
+use crate::credential::WasmDecodedJptCredential;
+use crate::credential::WasmDecodedJwtCredential;
+use crate::credential::WasmDecodedJwtCredentialV2;
+use crate::credential::WasmEnvelopedVc;
+use crate::credential::WasmFailFast;
+use crate::credential::WasmJpt;
+use crate::credential::WasmJwt;
+use crate::did::IToCoreDocument;
+use crate::did::WasmCoreDID;
+use crate::error::Result;
+use crate::error::WasmResult;
+use crate::sd_jwt::WasmSdJwt;
+use crate::verification::IJwsVerifier;
+use crate::verification::WasmJwsVerifier;
+
+#[wasm_bindgen(typescript_custom_section)]
+const I_ANY_CREDENTIAL: &str = r#"
+/** The format of an {@link AnyCredential}. */
+type CredentialFormat = "jwt" | "sd-jwt" | "jpt" | "enveloped";
+
+/** Holds options to create a new {@link AnyCredentialValidationOptions}. */
+interface IAnyCredentialValidationOptions {
+  /** The options used to validate JWT and SD-JWT credentials, including enveloped ones. */
+  readonly jwt?: IJwtCredentialValidationOptions;
+  /** The options used to validate JPT credentials. */
+  readonly jpt?: IJptCredentialValidationOptions;
+}
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+  #[wasm_bindgen(typescript_type = "CredentialFormat")]
+  pub type WasmCredentialFormat;
+
+  #[wasm_bindgen(typescript_type = "IAnyCredentialValidationOptions")]
+  pub type IAnyCredentialValidationOptions;
+}
+
+fn format_to_js(format: CredentialFormat) -> WasmCredentialFormat {
+  JsValue::from_str(&format.to_string()).unchecked_into()
+}
+
+/// A verifiable credential in any of the supported formats: JWT, SD-JWT, JPT or VC Data Model 2.0 enveloped
+/// credential.
+#[wasm_bindgen(js_name = AnyCredential)]
+pub struct WasmAnyCredential(pub(crate) AnyCredential);
+
+#[wasm_bindgen(js_class = AnyCredential)]
+impl WasmAnyCredential {
+  /// Parses `input` as a credential, detecting its format.
+  ///
+  /// `input` can be a compact JWT, SD-JWT or JPT, a data URL of an enveloped credential, or the JSON serialization
+  /// of an {@link EnvelopedVc}.
+  #[wasm_bindgen]
+  pub fn parse(input: &str) -> Result<WasmAnyCredential> {
+    AnyCredential::parse(input).map(Self).wasm_result()
+  }
+
+  /// Creates a new {@link AnyCredential} from a {@link Jwt}.
+  #[wasm_bindgen(js_name = fromJwt)]
+  pub fn from_jwt(jwt: &WasmJwt) -> WasmAnyCredential {
+    Self(AnyCredential::from(jwt.0.clone()))
+  }
+
+  /// Creates a new {@link AnyCredential} from an {@link SdJwt}.
+  #[wasm_bindgen(js_name = fromSdJwt)]
+  pub fn from_sd_jwt(sd_jwt: &WasmSdJwt) -> WasmAnyCredential {
+    Self(AnyCredential::from(sd_jwt.0.clone()))
+  }
+
+  /// Creates a new {@link AnyCredential} from a {@link Jpt}.
+  #[wasm_bindgen(js_name = fromJpt)]
+  pub fn from_jpt(jpt: &WasmJpt) -> WasmAnyCredential {
+    Self(AnyCredential::from(jpt.0.clone()))
+  }
+
+  /// Creates a new {@link AnyCredential} from an {@link EnvelopedVc}.
+  #[wasm_bindgen(js_name = fromEnvelopedVc)]
+  pub fn from_enveloped_vc(enveloped_vc: &WasmEnvelopedVc) -> WasmAnyCredential {
+    Self(AnyCredential::from(enveloped_vc.0.clone()))
+  }
+
+  /// Returns the format of this credential.
+  #[wasm_bindgen]
+  pub fn format(&self) -> WasmCredentialFormat {
+    format_to_js(self.0.format())
+  }
+
+  /// Returns the credential wrapped by this enveloped credential, or `undefined` if this credential is not
+  /// enveloped.
+  #[wasm_bindgen(js_name = unwrapEnvelope)]
+  pub fn unwrap_envelope(&self) -> Result<Option<WasmAnyCredential>> {
+    self.0.unwrap_envelope().map(|inner| inner.map(Self)).wasm_result()
+  }
+
+  /// Extracts the DID of the issuer of this credential, without validating it.
+  #[wasm_bindgen(js_name = extractIssuer)]
+  pub fn extract_issuer(&self) -> Result<WasmCoreDID> {
+    self.0.extract_issuer::<CoreDID>().map(WasmCoreDID::from).wasm_result()
+  }
+
+  /// Returns a {@link Jwt} if the credential is of said format, `undefined` otherwise.
+  #[wasm_bindgen(js_name = tryIntoJwt)]
+  pub fn try_into_jwt(&self) -> Option<WasmJwt> {
+    match &self.0 {
+      AnyCredential::Jwt(jwt) => Some(WasmJwt::new(jwt.clone())),
+      _ => None,
+    }
+  }
+
+  /// Returns an {@link SdJwt} if the credential is of said format, `undefined` otherwise.
+  #[wasm_bindgen(js_name = tryIntoSdJwt)]
+  pub fn try_into_sd_jwt(&self) -> Option<WasmSdJwt> {
+    match &self.0 {
+      AnyCredential::SdJwt(sd_jwt) => Some(WasmSdJwt(sd_jwt.clone())),
+      _ => None,
+    }
+  }
+
+  /// Returns a {@link Jpt} if the credential is of said format, `undefined` otherwise.
+  #[wasm_bindgen(js_name = tryIntoJpt)]
+  pub fn try_into_jpt(&self) -> Option<WasmJpt> {
+    match &self.0 {
+      AnyCredential::Jpt(jpt) => Some(WasmJpt(jpt.clone())),
+      _ => None,
+    }
+  }
+
+  /// Returns an {@link EnvelopedVc} if the credential is of said format, `undefined` otherwise.
+  #[wasm_bindgen(js_name = tryIntoEnvelopedVc)]
+  pub fn try_into_enveloped_vc(&self) -> Option<WasmEnvelopedVc> {
+    match &self.0 {
+      AnyCredential::Enveloped(enveloped_vc) => Some(WasmEnvelopedVc(enveloped_vc.clone())),
+      _ => None,
+    }
+  }
+
+  /// Returns the string representation of this credential: the compact serialization of JWTs, SD-JWTs and JPTs, or
+  /// the JSON serialization of enveloped credentials.
+  #[allow(clippy::inherent_to_string)]
+  #[wasm_bindgen(js_name = toString)]
+  pub fn to_string(&self) -> String {
+    self.0.to_string()
+  }
+}
+
+impl_wasm_clone!(WasmAnyCredential, AnyCredential);
+
+impl From<AnyCredential> for WasmAnyCredential {
+  fn from(credential: AnyCredential) -> Self {
+    Self(credential)
+  }
+}
+
+/// Options to declare validation criteria when validating an {@link AnyCredential}.
+#[wasm_bindgen(js_name = AnyCredentialValidationOptions)]
+pub struct WasmAnyCredentialValidationOptions(pub(crate) AnyCredentialValidationOptions);
+
+#[wasm_bindgen(js_class = AnyCredentialValidationOptions)]
+impl WasmAnyCredentialValidationOptions {
+  #[wasm_bindgen(constructor)]
+  pub fn new(options: Option<IAnyCredentialValidationOptions>) -> Result<WasmAnyCredentialValidationOptions> {
+    if let Some(options) = options {
+      options.into_serde().wasm_result().map(Self)
+    } else {
+      Ok(Self(AnyCredentialValidationOptions::default()))
+    }
+  }
+}
+
+impl_wasm_json!(WasmAnyCredentialValidationOptions, AnyCredentialValidationOptions);
+impl_wasm_clone!(WasmAnyCredentialValidationOptions, AnyCredentialValidationOptions);
+
+/// A credential decoded and validated by an {@link AnyCredentialValidator}.
+#[wasm_bindgen(js_name = DecodedCredential)]
+pub struct WasmDecodedCredential(pub(crate) DecodedCredential);
+
+#[wasm_bindgen(js_class = DecodedCredential)]
+impl WasmDecodedCredential {
+  /// Returns the format of the credential this was decoded from.
+  #[wasm_bindgen]
+  pub fn format(&self) -> WasmCredentialFormat {
+    format_to_js(self.0.format())
+  }
+
+  /// Returns a copy of the decoded credential, either a {@link Credential} or a {@link CredentialV2}.
+  #[wasm_bindgen]
+  pub fn credential(&self) -> CredentialAny {
+    let credential: JsValue = match self.innermost() {
+      DecodedCredential::Jwt(decoded) => WasmCredential(decoded.credential.clone()).into(),
+      DecodedCredential::JwtV2(decoded) => WasmCredentialV2(decoded.credential.clone()).into(),
+      DecodedCredential::SdJwt(credential) => WasmCredential(credential.clone()).into(),
+      DecodedCredential::SdJwtV2(credential) => WasmCredentialV2(credential.clone()).into(),
+      DecodedCredential::Jpt(decoded) => WasmCredential(decoded.credential.clone()).into(),
+      _ => unreachable!("enveloped credentials are unwrapped"),
+    };
+    credential.unchecked_into()
+  }
+
+  /// Returns the custom claims of the credential, if its format supports them and any were set.
+  #[wasm_bindgen(js_name = customClaims)]
+  pub fn custom_claims(&self) -> Option<RecordStringAny> {
+    self
+      .0
+      .custom_claims()
+      .and_then(|claims| JsValue::from_serde(claims).ok())
+      .map(|js_val| js_val.unchecked_into::<RecordStringAny>())
+  }
+
+  /// Returns the output of {@link JwtCredentialValidator.validate} if the credential was a VC Data Model 1.1 JWT,
+  /// `undefined` otherwise.
+  #[wasm_bindgen(js_name = tryIntoDecodedJwtCredential)]
+  pub fn try_into_decoded_jwt_credential(&self) -> Option<WasmDecodedJwtCredential> {
+    match self.innermost() {
+      DecodedCredential::Jwt(decoded) => Some(WasmDecodedJwtCredential(decoded.clone())),
+      _ => None,
+    }
+  }
+
+  /// Returns the output of {@link JwtCredentialValidator.validateV2} if the credential was a VC Data Model 2.0 JWT,
+  /// possibly enveloped, `undefined` otherwise.
+  #[wasm_bindgen(js_name = tryIntoDecodedJwtCredentialV2)]
+  pub fn try_into_decoded_jwt_credential_v2(&self) -> Option<WasmDecodedJwtCredentialV2> {
+    match self.innermost() {
+      DecodedCredential::JwtV2(decoded) => Some(WasmDecodedJwtCredentialV2(decoded.clone())),
+      _ => None,
+    }
+  }
+
+  /// Returns the output of {@link JptCredentialValidator.validate} if the credential was a JPT, `undefined`
+  /// otherwise.
+  #[wasm_bindgen(js_name = tryIntoDecodedJptCredential)]
+  pub fn try_into_decoded_jpt_credential(&self) -> Option<WasmDecodedJptCredential> {
+    match self.innermost() {
+      DecodedCredential::Jpt(decoded) => Some(WasmDecodedJptCredential(decoded.clone())),
+      _ => None,
+    }
+  }
+}
+
+impl WasmDecodedCredential {
+  /// Returns the decoded credential wrapped by any envelope.
+  fn innermost(&self) -> &DecodedCredential {
+    let mut decoded = &self.0;
+    while let DecodedCredential::Enveloped(inner) = decoded {
+      decoded = inner;
+    }
+    decoded
+  }
+}
+
+impl From<DecodedCredential> for WasmDecodedCredential {
+  fn from(decoded: DecodedCredential) -> Self {
+    Self(decoded)
+  }
+}
+
+/// A type for validating credentials of any format, dispatching each credential to the validator of its format.
+#[wasm_bindgen(js_name = AnyCredentialValidator)]
+pub struct WasmAnyCredentialValidator(AnyCredentialValidator<WasmJwsVerifier>);
+
+#[wasm_bindgen(js_class = AnyCredentialValidator)]
+impl WasmAnyCredentialValidator {
+  /// Creates a new {@link AnyCredentialValidator}. If a `signatureVerifier` is provided it will be used when
+  /// verifying the signatures of JWT and SD-JWT credentials, otherwise a default verifier capable of handling the
+  /// `EdDSA`, `ES256`, `ES256K` algorithms will be used.
+  #[wasm_bindgen(constructor)]
+  #[allow(non_snake_case)]
+  pub fn new(signatureVerifier: Option<IJwsVerifier>) -> WasmAnyCredentialValidator {
+    let signature_verifier = WasmJwsVerifier::new(signatureVerifier);
+    WasmAnyCredentialValidator(AnyCredentialValidator::with_signature_verifier(signature_verifier))
+  }
+
+  /// Decodes and validates `credential` issued by `issuer`, according to its format. SD-JWT disclosures must be
+  /// hashed with SHA-256 and key binding JWTs are **not** validated.
+  ///
+  /// # Warning
+  /// The caller must ensure that `issuer` represents an up-to-date DID Document.
+  ///
+  /// # Errors
+  /// An error is returned whenever the credential is malformed or a validated condition is not satisfied.
+  #[wasm_bindgen]
+  pub fn validate(
+    &self,
+    credential: &WasmAnyCredential,
+    issuer: &IToCoreDocument,
+    options: &WasmAnyCredentialValidationOptions,
+    fail_fast: WasmFailFast,
+  ) -> Result<WasmDecodedCredential> {
+    let issuer_lock = ImportedDocumentLock::from(issuer);
+    let issuer_guard = issuer_lock.try_read()?;
+
+    self
+      .0
+      .validate(&credential.0, &issuer_guard, &options.0, fail_fast.into())
+      .map(WasmDecodedCredential)
+      .wasm_result()
+  }
+}
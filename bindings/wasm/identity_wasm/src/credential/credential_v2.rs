@@ -19,6 +19,7 @@ use identity_iota::credential::Status;
 use identity_iota::credential::Subject;
 use proc_typescript::typescript;
 use serde_json::Value;
+use std::borrow::Cow;
 use std::collections::BTreeMap;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsCast;
@@ -27,6 +28,7 @@ use crate::common::ArrayString;
 use crate::common::MapStringAny;
 use crate::common::RecordStringAny;
 use crate::common::WasmTimestamp;
+use crate::credential::check_subjects;
 use crate::credential::domain_linkage_credential_builder::IDomainLinkageCredential;
 use crate::credential::ArrayContext;
 use crate::credential::ArrayEvidence;
@@ -38,6 +40,7 @@ use crate::credential::ArraySubject;
 use crate::credential::UrlOrIssuer;
 use crate::credential::WasmProof;
 use crate::error::Result;
+use crate::error::WasmError;
 use crate::error::WasmResult;
 
 /// Represents a set of claims describing an entity.
@@ -52,7 +55,13 @@ impl WasmCredentialV2 {
   pub fn base_context() -> Result<String> {
     match CredentialV2::<Object>::base_context() {
       Context::Url(url) => Ok(url.to_string()),
-      Context::Obj(_) => Err(JsError::new("Credential.BaseContext should be a single URL").into()),
+      Context::Obj(_) => Err(
+        WasmError::new(
+          Cow::Borrowed("InvalidBaseContext"),
+          Cow::Borrowed("Credential.BaseContext should be a single URL"),
+        )
+        .into(),
+      ),
     }
   }
 
@@ -120,6 +129,19 @@ impl WasmCredentialV2 {
       .map(|value| value.unchecked_into::<ArraySubject>())
   }
 
+  /// Returns a copy of the {@link CredentialV2} subject(s), typed as `T`.
+  ///
+  /// If `check` is provided, it is called on every subject and must return `true` for the subject to be
+  /// considered a `T`, otherwise a `TypeError` is thrown.
+  #[wasm_bindgen(js_name = getTypedSubject, skip_typescript)]
+  pub fn get_typed_subject(&self, check: Option<js_sys::Function>) -> Result<ArraySubject> {
+    let subjects = self.credential_subject()?;
+    match check {
+      Some(check) => check_subjects(subjects, &check),
+      None => Ok(subjects),
+    }
+  }
+
   /// Returns a copy of the issuer of the {@link Credential}.
   #[wasm_bindgen]
   pub fn issuer(&self) -> Result<UrlOrIssuer> {
@@ -246,6 +268,7 @@ impl WasmCredentialV2 {
 }
 
 impl_wasm_json!(WasmCredentialV2, CredentialV2);
+impl_wasm_cbor!(WasmCredentialV2, CredentialV2);
 impl_wasm_clone!(WasmCredentialV2, CredentialV2);
 
 impl From<CredentialV2> for WasmCredentialV2 {
@@ -5,7 +5,6 @@ use identity_iota::credential::EnvelopedVc;
 use identity_iota::credential::VcDataUrl;
 use wasm_bindgen::prelude::wasm_bindgen;
 use wasm_bindgen::JsCast as _;
-use wasm_bindgen::JsError;
 use wasm_bindgen::JsValue;
 
 use crate::credential::ArrayContext;
@@ -20,8 +19,8 @@ pub struct WasmEnvelopedVc(pub(crate) EnvelopedVc);
 impl WasmEnvelopedVc {
   /// Creates a new {@link EnvelopedVc} from the given Data URL-encoded VC.
   #[wasm_bindgen(constructor)]
-  pub fn new(vc_data_url: String) -> Result<Self, JsError> {
-    let enveloped_vc = EnvelopedVc::new(VcDataUrl::parse(&vc_data_url)?);
+  pub fn new(vc_data_url: String) -> Result<Self, JsValue> {
+    let enveloped_vc = EnvelopedVc::new(VcDataUrl::parse(&vc_data_url).wasm_result()?);
     Ok(Self(enveloped_vc))
   }
 
@@ -77,4 +77,9 @@ interface IJptCredentialValidationOptions {
      * Options which affect the verification of the proof on the credential.
      */
     readonly verificationOptions?: JwpVerificationOptions;
+
+    /**
+     * Limits on the size and complexity of the credential, enforced before it is decoded.
+     */
+    readonly limits?: ValidationLimits;
 }"#;
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::common::ImportedDocumentLock;
+use crate::common::WasmDuration;
 use crate::common::WasmTimestamp;
 use crate::credential::options::WasmStatusCheck;
 use crate::credential::WasmCredential;
@@ -11,6 +12,7 @@ use crate::did::WasmCoreDID;
 use crate::error::Result;
 use crate::error::WasmResult;
 use identity_iota::core::Object;
+use identity_iota::credential::JptCredentialValidationOptions;
 use identity_iota::credential::JptCredentialValidatorUtils;
 use identity_iota::did::CoreDID;
 use wasm_bindgen::prelude::*;
@@ -55,7 +57,35 @@ impl WasmJptCredentialValidatorUtils {
     JptCredentialValidatorUtils::check_timeframes_with_validity_timeframe_2024(
       &credential.0,
       validity_timeframe.map(|t| t.0),
-      status_check.into(),
+      &JptCredentialValidationOptions::new().status_check(status_check.into()),
+    )
+    .wasm_result()
+  }
+
+  /// Returns the end of the validity timeframe of a credential using `RevocationTimeframe2024`.
+  ///
+  /// `undefined` is returned if the credential has no status, or a status of a different type.
+  #[wasm_bindgen(js_name = "validityTimeframeEnd")]
+  pub fn validity_timeframe_end(credential: &WasmCredential) -> Result<Option<WasmTimestamp>> {
+    JptCredentialValidatorUtils::validity_timeframe_end(&credential.0)
+      .wasm_result()
+      .map(|end| end.map(WasmTimestamp))
+  }
+
+  /// Returns whether the validity timeframe of a credential using `RevocationTimeframe2024` ends within `window` from
+  /// `at`, which defaults to the current time. When that is the case, the holder should ask the issuer to renew the
+  /// credential.
+  #[wasm_bindgen(js_name = "isValidityTimeframeExpiring")]
+  pub fn is_validity_timeframe_expiring(
+    credential: &WasmCredential,
+    window: &WasmDuration,
+    at: Option<WasmTimestamp>,
+  ) -> Result<bool> {
+    JptCredentialValidatorUtils::is_validity_timeframe_expiring(
+      &credential.0,
+      window.0,
+      at.map(|t| t.0),
+      &JptCredentialValidationOptions::default(),
     )
     .wasm_result()
   }
@@ -95,7 +125,7 @@ impl WasmJptCredentialValidatorUtils {
       &credential.0,
       &issuer_guard,
       validity_timeframe.map(|t| t.0),
-      status_check.into(),
+      &JptCredentialValidationOptions::new().status_check(status_check.into()),
     )
     .wasm_result()
   }
@@ -46,6 +46,9 @@ impl From<WasmJptPresentationValidationOptions> for JptPresentationValidationOpt
 extern "C" {
   #[wasm_bindgen(typescript_type = "IJptPresentationValidationOptions")]
   pub type IJptPresentationValidationOptions;
+
+  #[wasm_bindgen(typescript_type = "JptPredicate")]
+  pub type IJptPredicate;
 }
 
 #[wasm_bindgen(typescript_custom_section)]
@@ -61,4 +64,33 @@ interface IJptPresentationValidationOptions {
      * Options which affect the verification of the proof on the credential.
      */
     readonly verificationOptions?: JwpVerificationOptions;
+
+    /**
+     * Limits on the size and complexity of the presented credential, enforced before it is decoded.
+     */
+    readonly limits?: ValidationLimits;
+
+    /**
+     * Predicates the presented credential's subject must satisfy.
+     */
+    readonly predicates?: JptPredicate[];
+}
+
+/**
+ * A predicate over a `credentialSubject` claim of a JPT, e.g. `{ path: "birthDate", operator: "lessThanOrEqual",
+ * value: "2006-10-16" }` to express that the subject is over 18.
+ *
+ * Claims are compared as numbers when both the claim and `value` are numbers, and as dates when both are strings
+ * holding either an RFC 3339 timestamp or a `YYYY-MM-DD` date.
+ *
+ * The BBS+ proof suite used for JPTs does not support range proofs: the claim a predicate refers to must
+ * be disclosed in the presentation, and the predicate is checked by the verifier on the disclosed value.
+ */
+interface JptPredicate {
+    /** The path of the claim within `credentialSubject`, e.g. `birthDate` or `degree.level`. */
+    readonly path: string;
+    /** The comparison applied to the claim. */
+    readonly operator: "lessThan" | "lessThanOrEqual" | "greaterThan" | "greaterThanOrEqual";
+    /** The value the claim is compared with. */
+    readonly value: number | string;
 }"#;
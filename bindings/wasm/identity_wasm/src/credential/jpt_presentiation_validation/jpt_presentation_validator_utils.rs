@@ -8,6 +8,7 @@ use crate::credential::WasmJpt;
 use crate::did::WasmCoreDID;
 use crate::error::Result;
 use crate::error::WasmResult;
+use identity_iota::credential::JptPresentationValidationOptions;
 use identity_iota::credential::JptPresentationValidatorUtils;
 use wasm_bindgen::prelude::*;
 
@@ -38,6 +39,7 @@ impl WasmJptPresentationValidatorUtils {
       &credential.0,
       validity_timeframe.map(|t| t.0),
       status_check.into(),
+      &JptPresentationValidationOptions::default(),
     )
     .wasm_result()
   }
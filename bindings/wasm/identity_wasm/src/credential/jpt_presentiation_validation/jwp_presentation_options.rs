@@ -5,6 +5,8 @@ use identity_iota::core::Url;
 use identity_iota::credential::JwpPresentationOptions;
 use wasm_bindgen::prelude::*;
 
+use crate::error::WasmResult;
+
 /// Options to be set in the JWT claims of a verifiable presentation.
 #[wasm_bindgen(js_name = JwpPresentationOptions, inspectable, getter_with_clone)]
 #[derive(Default, Clone)]
@@ -24,13 +26,10 @@ impl WasmJwpPresentationOptions {
 }
 
 impl TryFrom<WasmJwpPresentationOptions> for JwpPresentationOptions {
-  type Error = JsError;
+  type Error = JsValue;
   fn try_from(value: WasmJwpPresentationOptions) -> Result<Self, Self::Error> {
     let WasmJwpPresentationOptions { audience, nonce } = value;
-    let audience = audience
-      .map(Url::parse)
-      .transpose()
-      .map_err(|e| JsError::new(&e.to_string()))?;
+    let audience = audience.map(Url::parse).transpose().wasm_result()?;
 
     Ok(JwpPresentationOptions { audience, nonce })
   }
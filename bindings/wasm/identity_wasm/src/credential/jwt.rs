@@ -6,6 +6,8 @@ use identity_iota::credential::JwtVcV2;
 use wasm_bindgen::prelude::*;
 
 use crate::credential::WasmEnvelopedVc;
+use crate::error::Result;
+use crate::error::WasmResult;
 
 /// A wrapper around a JSON Web Token (JWK).
 #[wasm_bindgen(js_name = Jwt)]
@@ -62,8 +64,8 @@ impl WasmJwtVcV2 {
   /// This function fails if the media type of the given {@link EnvelopedVc}
   /// is not `application/vc+jwt`.
   #[wasm_bindgen(js_name = fromEnvelopedVc)]
-  pub fn try_from_enveloped_vc(enveloped_vc: WasmEnvelopedVc) -> Result<Self, JsError> {
-    let jwt_vc = JwtVcV2::try_from(enveloped_vc.0)?;
+  pub fn try_from_enveloped_vc(enveloped_vc: WasmEnvelopedVc) -> Result<Self> {
+    let jwt_vc = JwtVcV2::try_from(enveloped_vc.0).wasm_result()?;
     Ok(Self(jwt_vc))
   }
 
@@ -22,6 +22,7 @@ use crate::credential::WasmDecodedJwtCredentialV2;
 use crate::credential::WasmFailFast;
 use crate::credential::WasmJwt;
 use crate::credential::WasmSubjectHolderRelationship;
+use crate::credential::WasmTrustedIssuerSet;
 use crate::did::ArrayIToCoreDocument;
 use crate::did::IToCoreDocument;
 use crate::did::WasmCoreDID;
@@ -91,6 +92,44 @@ impl WasmJwtCredentialValidator {
       .map(WasmDecodedJwtCredential)
   }
 
+  /// Decodes and validates a {@link Credential} issued as a JWS as of `options.validateAt`, e.g. the time it was
+  /// issued.
+  ///
+  /// `issuerAt` must be the issuer's DID Document as it was at that time, while `currentIssuer` must be its
+  /// up-to-date DID Document. The issuer's signature is verified against `issuerAt`. Whether the signing key must
+  /// still be part of `currentIssuer` is determined by `options.keyRevocationPolicy`. The credential's status is
+  /// checked against `currentIssuer`.
+  ///
+  /// # Errors
+  /// An error is returned whenever a validated condition is not satisfied, or if `options.validateAt` is earlier than
+  /// the credential's issuance date.
+  #[wasm_bindgen(js_name = validateWithHistory)]
+  pub fn validate_with_history(
+    &self,
+    credential_jwt: &WasmJwt,
+    issuer_at: &IToCoreDocument,
+    current_issuer: &IToCoreDocument,
+    options: &WasmJwtCredentialValidationOptions,
+    fail_fast: WasmFailFast,
+  ) -> Result<WasmDecodedJwtCredential> {
+    let issuer_at_lock = ImportedDocumentLock::from(issuer_at);
+    let issuer_at_guard = issuer_at_lock.try_read()?;
+    let current_issuer_lock = ImportedDocumentLock::from(current_issuer);
+    let current_issuer_guard = current_issuer_lock.try_read()?;
+
+    self
+      .0
+      .validate_with_history(
+        &credential_jwt.0,
+        &issuer_at_guard,
+        &current_issuer_guard,
+        &options.0,
+        fail_fast.into(),
+      )
+      .wasm_result()
+      .map(WasmDecodedJwtCredential)
+  }
+
   /// Decodes and validates a {@link CredentialV2} issued as a JWS. A {@link DecodedJwtCredentialV2} is returned upon
   /// success.
   ///
@@ -133,6 +172,68 @@ impl WasmJwtCredentialValidator {
       .map(WasmDecodedJwtCredentialV2)
   }
 
+  /// Decodes and validates a {@link Credential} issued as a JWS, using the document of its issuer found in
+  /// `trustedIssuers`. A {@link DecodedJwtCredential} is returned upon success.
+  ///
+  /// Behaves like {@link JwtCredentialValidator.validate}, without converting the issuer's document on every call.
+  ///
+  /// # Errors
+  /// An error is returned if the issuer's document is not part of `trustedIssuers` or whenever a validated condition
+  /// is not satisfied.
+  #[wasm_bindgen(js_name = validateWithTrustedIssuers)]
+  #[allow(non_snake_case)]
+  pub fn validate_with_trusted_issuers(
+    &self,
+    credential_jwt: &WasmJwt,
+    trustedIssuers: &WasmTrustedIssuerSet,
+    options: &WasmJwtCredentialValidationOptions,
+    fail_fast: WasmFailFast,
+  ) -> Result<WasmDecodedJwtCredential> {
+    let issuer: CoreDID = JwtCredentialValidatorUtils::extract_issuer_from_jwt(&credential_jwt.0).wasm_result()?;
+
+    self
+      .0
+      .validate(
+        &credential_jwt.0,
+        trustedIssuers.get(&issuer)?,
+        &options.0,
+        fail_fast.into(),
+      )
+      .wasm_result()
+      .map(WasmDecodedJwtCredential)
+  }
+
+  /// Decodes and validates a {@link CredentialV2} issued as a JWS, using the document of its issuer found in
+  /// `trustedIssuers`. A {@link DecodedJwtCredentialV2} is returned upon success.
+  ///
+  /// Behaves like {@link JwtCredentialValidator.validateV2}, without converting the issuer's document on every call.
+  ///
+  /// # Errors
+  /// An error is returned if the issuer's document is not part of `trustedIssuers` or whenever a validated condition
+  /// is not satisfied.
+  #[wasm_bindgen(js_name = validateV2WithTrustedIssuers)]
+  #[allow(non_snake_case)]
+  pub fn validate_v2_with_trusted_issuers(
+    &self,
+    credential_jwt: &WasmJwtVcV2,
+    trustedIssuers: &WasmTrustedIssuerSet,
+    options: &WasmJwtCredentialValidationOptions,
+    fail_fast: WasmFailFast,
+  ) -> Result<WasmDecodedJwtCredentialV2> {
+    let issuer: CoreDID = JwtCredentialValidatorUtils::extract_issuer_from_jwt(&credential_jwt.0).wasm_result()?;
+
+    self
+      .0
+      .validate_v2(
+        &credential_jwt.0,
+        trustedIssuers.get(&issuer)?,
+        &options.0,
+        fail_fast.into(),
+      )
+      .wasm_result()
+      .map(WasmDecodedJwtCredentialV2)
+  }
+
   /// Decode and verify the JWS signature of a {@link Credential} issued as a JWT using the DID Document of a trusted
   /// issuer.
   ///
@@ -72,4 +72,51 @@ interface IJwtCredentialValidationOptions {
 
     /** Options which affect the verification of the signature on the credential. */
     readonly verifierOptions?: JwsVerificationOptions;
+
+    /** Limits on the size and complexity of the credential, enforced before it is decoded. */
+    readonly limits?: ValidationLimits;
+
+    /** Validates the credential as of this {@link Timestamp} rather than as of the current datetime.
+     * The dates above default to it when not set. */
+    readonly validateAt?: Timestamp;
+
+    /** How the removal of the issuer's signing key after `validateAt` is treated.
+     *
+     * Default: `KeyRevocationPolicy.Strict`. */
+    readonly keyRevocationPolicy?: KeyRevocationPolicy;
+
+    /** How long after its removal a signing key is still accepted under `KeyRevocationPolicy.Grace`.
+     *
+     * Default: 30 days. */
+    readonly keyRevocationGracePeriod?: Duration;
+
+    /** The time at which the signing key was removed from the issuer's DID Document, as found in its history.
+     * The grace period of `KeyRevocationPolicy.Grace` starts at this time, or at the validation time if unset. */
+    readonly keyRemovedAt?: Timestamp;
+}"#;
+
+#[wasm_bindgen(typescript_custom_section)]
+const VALIDATION_LIMITS: &'static str = r#"
+/** Hard limits on the size and complexity of the credentials and presentations accepted by the validators. */
+interface ValidationLimits {
+    /** The maximum size, in bytes, of a JWT, SD-JWT or JPT.
+     *
+     * Default: 1 MiB. */
+    readonly maxJwtSize?: number;
+
+    /** The maximum nesting depth of objects and arrays in the claims of a JWT, the disclosures of an SD-JWT or the
+     * payloads of a JPT.
+     *
+     * Default: 32. */
+    readonly maxClaimDepth?: number;
+
+    /** The maximum number of credentials in a presentation.
+     *
+     * Default: 1000. */
+    readonly maxCredentialsPerPresentation?: number;
+
+    /** The maximum number of disclosures in an SD-JWT, or of payloads in a JPT.
+     *
+     * Default: 1000. */
+    readonly maxDisclosures?: number;
 }"#;
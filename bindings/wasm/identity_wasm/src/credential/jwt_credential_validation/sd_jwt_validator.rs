@@ -11,6 +11,7 @@ use crate::did::ArrayIToCoreDocument;
 use crate::did::IToCoreDocument;
 use crate::did::WasmJwsVerificationOptions;
 use crate::error::Result;
+use crate::error::WasmResult;
 use crate::sd_jwt::WasmHasher;
 use crate::sd_jwt::WasmSdJwt;
 use crate::verification::IJwsVerifier;
@@ -68,18 +69,15 @@ impl WasmSdJwtCredentialValidator {
     sd_jwt: &WasmSdJwt,
     issuer: &IToCoreDocument,
     options: &WasmJwtCredentialValidationOptions,
-  ) -> std::result::Result<WasmCredential, JsError> {
+  ) -> Result<WasmCredential> {
     let issuer_lock = ImportedDocumentLock::from(issuer);
-    let issuer_guard = issuer_lock
-      .try_read()
-      .map_err(|_| JsError::new("failed to lock DidDocument for reading"))?;
+    let issuer_guard = issuer_lock.try_read()?;
 
-    Ok(
-      self
-        .0
-        .validate_credential(&sd_jwt.0, std::slice::from_ref(&issuer_guard), &options.0)
-        .map(WasmCredential)?,
-    )
+    self
+      .0
+      .validate_credential(&sd_jwt.0, std::slice::from_ref(&issuer_guard), &options.0)
+      .map(WasmCredential)
+      .wasm_result()
   }
 
   /// Decodes and validates a {@link CredentialV2} issued as an SD-JWT.
@@ -116,18 +114,15 @@ impl WasmSdJwtCredentialValidator {
     sd_jwt: &WasmSdJwt,
     issuer: &IToCoreDocument,
     options: &WasmJwtCredentialValidationOptions,
-  ) -> std::result::Result<WasmCredentialV2, JsError> {
+  ) -> Result<WasmCredentialV2> {
     let issuer_lock = ImportedDocumentLock::from(issuer);
-    let issuer_guard = issuer_lock
-      .try_read()
-      .map_err(|_| JsError::new("failed to lock DidDocument for reading"))?;
+    let issuer_guard = issuer_lock.try_read()?;
 
-    Ok(
-      self
-        .0
-        .validate_credential_v2(&sd_jwt.0, std::slice::from_ref(&issuer_guard), &options.0)
-        .map(WasmCredentialV2)?,
-    )
+    self
+      .0
+      .validate_credential_v2(&sd_jwt.0, std::slice::from_ref(&issuer_guard), &options.0)
+      .map(WasmCredentialV2)
+      .wasm_result()
   }
 
   /// Decode and verify the JWS signature of an SD-JWT using the DID Document of a trusted issuer.
@@ -146,17 +141,18 @@ impl WasmSdJwtCredentialValidator {
     credential: &WasmSdJwt,
     trustedIssuers: &ArrayIToCoreDocument,
     options: &WasmJwsVerificationOptions,
-  ) -> std::result::Result<(), JsError> {
+  ) -> Result<()> {
     let issuer_locks: Vec<ImportedDocumentLock> = trustedIssuers.into();
     let trusted_issuers: Vec<ImportedDocumentReadGuard<'_>> = issuer_locks
       .iter()
       .map(ImportedDocumentLock::try_read)
-      .collect::<Result<Vec<ImportedDocumentReadGuard<'_>>>>()
-      .map_err(|_| JsError::new("failed to lock DidDocument for reading"))?;
+      .collect::<Result<Vec<ImportedDocumentReadGuard<'_>>>>(
+    )?;
 
-    self.0.verify_signature(&credential.0, &trusted_issuers, &options.0)?;
-
-    Ok(())
+    self
+      .0
+      .verify_signature(&credential.0, &trusted_issuers, &options.0)
+      .wasm_result()
   }
 
   /// Validates a [Key Binding JWT (KB-JWT)](https://www.rfc-editor.org/rfc/rfc9901.html#name-key-binding-jwt)
@@ -175,12 +171,13 @@ impl WasmSdJwtCredentialValidator {
     sd_jwt: &WasmSdJwt,
     holder: &IToCoreDocument,
     options: &WasmKeyBindingJwtValidationOptions,
-  ) -> std::result::Result<(), JsError> {
+  ) -> Result<()> {
     let holder_lock = ImportedDocumentLock::from(holder);
-    let holder_guard = holder_lock
-      .try_read()
-      .map_err(|_| JsError::new("failed to lock DidDocument for reading"))?;
+    let holder_guard = holder_lock.try_read()?;
 
-    Ok(self.0.validate_key_binding_jwt(&sd_jwt.0, &holder_guard, &options.0)?)
+    self
+      .0
+      .validate_key_binding_jwt(&sd_jwt.0, &holder_guard, &options.0)
+      .wasm_result()
   }
 }
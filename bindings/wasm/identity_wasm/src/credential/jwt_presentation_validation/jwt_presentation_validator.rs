@@ -6,6 +6,7 @@ use super::options::WasmJwtPresentationValidationOptions;
 use crate::common::ImportedDocumentLock;
 use crate::credential::WasmJwt;
 use crate::credential::WasmPresentation;
+use crate::credential::WasmTrustedIssuerSet;
 use crate::did::IToCoreDocument;
 use crate::did::WasmCoreDID;
 use crate::error::Result;
@@ -74,6 +75,32 @@ impl WasmJwtPresentationValidator {
       .wasm_result()
   }
 
+  /// Validates a {@link Presentation} encoded as a {@link Jwt}, using the document of its holder found in
+  /// `trustedHolders`.
+  ///
+  /// Behaves like {@link JwtPresentationValidator.validate}, without converting the holder's document on every call.
+  ///
+  /// # Errors
+  ///
+  /// An error is returned if the holder's document is not part of `trustedHolders`, whenever a validated condition is
+  /// not satisfied or when decoding fails.
+  #[wasm_bindgen(js_name = validateWithTrustedHolders)]
+  #[allow(non_snake_case)]
+  pub fn validate_with_trusted_holders(
+    &self,
+    presentationJwt: &WasmJwt,
+    trustedHolders: &WasmTrustedIssuerSet,
+    validation_options: &WasmJwtPresentationValidationOptions,
+  ) -> Result<WasmDecodedJwtPresentation> {
+    let holder: CoreDID = JwtPresentationValidatorUtils::extract_holder(&presentationJwt.0).wasm_result()?;
+
+    self
+      .0
+      .validate(&presentationJwt.0, trustedHolders.get(&holder)?, &validation_options.0)
+      .map(WasmDecodedJwtPresentation::from)
+      .wasm_result()
+  }
+
   /// Validates the semantic structure of the {@link Presentation}.
   #[wasm_bindgen(js_name = checkStructure)]
   pub fn check_structure(presentation: &WasmPresentation) -> Result<()> {
@@ -69,4 +69,9 @@ interface IJwtPresentationValidationOptions {
      * Uses the current datetime during validation if not set. 
      */
     readonly latestIssuanceDate?: Timestamp;
+
+    /**
+     * Limits on the size and complexity of the presentation, enforced before it is decoded.
+     */
+    readonly limits?: ValidationLimits;
 }"#;
@@ -10,6 +10,7 @@ use identity_iota::credential::CredentialV2;
 use wasm_bindgen::prelude::wasm_bindgen;
 use wasm_bindgen::JsValue;
 
+pub use self::any_credential::*;
 pub use self::credential::WasmCredential;
 pub use self::credential_builder::*;
 pub use self::credential_v2::*;
@@ -25,12 +26,15 @@ pub use self::jwt_credential_validation::*;
 pub use self::jwt_presentation_validation::*;
 pub use self::linked_verifiable_presentation_service::*;
 pub use self::options::WasmFailFast;
+pub use self::options::WasmKeyRevocationPolicy;
 pub use self::options::WasmSubjectHolderRelationship;
 pub use self::presentation::*;
 pub use self::proof::WasmProof;
 pub use self::revocation::*;
+pub use self::trusted_issuer_set::*;
 pub use self::types::*;
 
+mod any_credential;
 mod credential;
 mod credential_builder;
 mod credential_v2;
@@ -51,6 +55,7 @@ mod options;
 mod presentation;
 mod proof;
 mod revocation;
+mod trusted_issuer_set;
 mod types;
 
 #[wasm_bindgen]
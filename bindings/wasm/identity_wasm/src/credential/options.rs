@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use identity_iota::credential::FailFast;
+use identity_iota::credential::KeyRevocationPolicy;
 use identity_iota::credential::StatusCheck;
 use identity_iota::credential::SubjectHolderRelationship;
 use serde_repr::Deserialize_repr;
@@ -38,6 +39,30 @@ impl From<WasmStatusCheck> for StatusCheck {
   }
 }
 
+/// Controls how the removal of an issuer's signing key after the validation time is treated when validating a
+/// credential at a point in time.
+#[wasm_bindgen(js_name = KeyRevocationPolicy)]
+#[derive(Debug, Clone, Copy, Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum WasmKeyRevocationPolicy {
+  /// The signing key must still be part of the issuer's current DID Document.
+  ///
+  /// This is the default.
+  Strict = 0,
+  /// The signing key only needs to have been part of the issuer's DID Document at the validation time, until the
+  /// grace period following it has elapsed.
+  Grace = 1,
+}
+
+impl From<WasmKeyRevocationPolicy> for KeyRevocationPolicy {
+  fn from(policy: WasmKeyRevocationPolicy) -> Self {
+    match policy {
+      WasmKeyRevocationPolicy::Strict => Self::Strict,
+      WasmKeyRevocationPolicy::Grace => Self::Grace,
+    }
+  }
+}
+
 /// Declares how credential subjects must relate to the presentation holder.
 ///
 /// See also the [Subject-Holder Relationship](https://www.w3.org/TR/vc-data-model/#subject-holder-relationships) section of the specification.
@@ -5,6 +5,7 @@ use identity_iota::core::Context;
 use identity_iota::core::Object;
 use identity_iota::credential::Presentation;
 use identity_iota::credential::PresentationBuilder;
+use std::borrow::Cow;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsCast;
 
@@ -19,6 +20,7 @@ use crate::credential::UnknownCredential;
 use crate::credential::WasmProof;
 use crate::credential::WasmUnknownCredentialContainer;
 use crate::error::Result;
+use crate::error::WasmError;
 use crate::error::WasmResult;
 
 /// Represents a bundle of one or more {@link Credential}s.
@@ -32,7 +34,13 @@ impl WasmPresentation {
   pub fn base_context() -> Result<String> {
     match Presentation::<Object>::base_context() {
       Context::Url(url) => Ok(url.to_string()),
-      Context::Obj(_) => Err(JsError::new("Presentation.BaseContext should be a single URL").into()),
+      Context::Obj(_) => Err(
+        WasmError::new(
+          Cow::Borrowed("InvalidBaseContext"),
+          Cow::Borrowed("Presentation.BaseContext should be a single URL"),
+        )
+        .into(),
+      ),
     }
   }
 
@@ -104,6 +112,26 @@ impl WasmPresentation {
       .unchecked_into::<ArrayUnknownCredential>()
   }
 
+  /// Returns the number of credentials in this presentation.
+  #[wasm_bindgen(js_name = credentialCount)]
+  pub fn credential_count(&self) -> usize {
+    self.0.verifiable_credential.len()
+  }
+
+  /// Returns the credential at `index`, or `undefined` if `index` is out of bounds.
+  ///
+  /// Unlike {@link Presentation.verifiableCredential}, only the requested credential is copied
+  /// across the JS boundary.
+  #[wasm_bindgen(js_name = credentialAt)]
+  pub fn credential_at(&self, index: usize) -> Option<WasmUnknownCredentialContainer> {
+    self
+      .0
+      .verifiable_credential
+      .get(index)
+      .cloned()
+      .map(WasmUnknownCredentialContainer::new)
+  }
+
   /// Returns a copy of the URI of the entity that generated the presentation.
   #[wasm_bindgen]
   pub fn holder(&self) -> String {
@@ -158,6 +186,7 @@ impl WasmPresentation {
 }
 
 impl_wasm_json!(WasmPresentation, Presentation);
+impl_wasm_cbor!(WasmPresentation, Presentation);
 impl_wasm_clone!(WasmPresentation, Presentation);
 
 impl From<Presentation<UnknownCredential>> for WasmPresentation {
@@ -24,9 +24,9 @@ impl WasmStatusList2021Entry {
     index: usize,
     id: Option<String>,
   ) -> Result<WasmStatusList2021Entry> {
-    let status_list = Url::parse(status_list).map_err(|e| JsError::new(&e.to_string()))?;
+    let status_list = Url::parse(status_list).wasm_result()?;
     let id = if let Some(id) = id {
-      Some(Url::parse(id).map_err(|e| JsError::new(&e.to_string()))?)
+      Some(Url::parse(id).wasm_result()?)
     } else {
       None
     };
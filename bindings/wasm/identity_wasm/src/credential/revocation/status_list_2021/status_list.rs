@@ -19,7 +19,7 @@ impl WasmStatusList2021 {
   #[wasm_bindgen(constructor)]
   pub fn new(size: Option<usize>) -> Result<WasmStatusList2021> {
     Ok(Self(match size {
-      Some(size) => StatusList2021::new(size).map_err(|e| JsError::new(&e.to_string()))?,
+      Some(size) => StatusList2021::new(size).wasm_result()?,
       None => StatusList2021::default(),
     }))
   }
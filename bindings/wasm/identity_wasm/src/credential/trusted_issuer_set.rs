@@ -0,0 +1,130 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+use identity_iota::did::CoreDID;
+use identity_iota::document::CoreDocument;
+use identity_iota::iota::IotaDocument;
+use js_sys::Array;
+use js_sys::Reflect;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+use crate::common::ArrayString;
+use crate::common::ImportedDocumentLock;
+use crate::error::Result;
+use crate::error::WasmError;
+use crate::error::WasmResult;
+
+/// A set of trusted DID documents, indexed by DID.
+///
+/// Documents are converted once, when inserted, and can then be shared by any number of validations, e.g. with
+/// {@link JwtCredentialValidator.validateWithTrustedIssuers} or
+/// {@link JwtPresentationValidator.validateWithTrustedHolders}.
+#[wasm_bindgen(js_name = TrustedIssuerSet)]
+#[derive(Default)]
+pub struct WasmTrustedIssuerSet(BTreeMap<String, CoreDocument>);
+
+#[wasm_bindgen(js_class = TrustedIssuerSet)]
+impl WasmTrustedIssuerSet {
+  /// Creates a new {@link TrustedIssuerSet} containing `documents`.
+  ///
+  /// Documents can be passed as {@link CoreDocument}, {@link IotaDocument} or any other {@link IToCoreDocument}, as
+  /// well as in their plain JSON representation, as returned by `toJSON`.
+  #[wasm_bindgen(constructor)]
+  pub fn new(documents: Option<ArrayAnyDocument>) -> Result<WasmTrustedIssuerSet> {
+    let mut set = Self::default();
+    if let Some(documents) = documents {
+      for document in documents.unchecked_into::<Array>().iter() {
+        set.insert_js_value(&document)?;
+      }
+    }
+    Ok(set)
+  }
+
+  /// Inserts `document` into the set, replacing any document with the same DID.
+  #[wasm_bindgen]
+  pub fn insert(&mut self, document: &AnyDocument) -> Result<()> {
+    self.insert_js_value(document.as_ref())
+  }
+
+  /// Removes the document of `did` from the set, returning whether it was present.
+  #[wasm_bindgen]
+  pub fn remove(&mut self, did: &str) -> bool {
+    self.0.remove(did).is_some()
+  }
+
+  /// Returns whether the set contains a document for `did`.
+  #[wasm_bindgen]
+  pub fn has(&self, did: &str) -> bool {
+    self.0.contains_key(did)
+  }
+
+  /// Returns the DIDs of the documents in the set.
+  #[wasm_bindgen]
+  pub fn dids(&self) -> ArrayString {
+    self
+      .0
+      .keys()
+      .map(|did| JsValue::from_str(did))
+      .collect::<Array>()
+      .unchecked_into::<ArrayString>()
+  }
+
+  /// Returns the number of documents in the set.
+  #[wasm_bindgen(getter)]
+  pub fn size(&self) -> usize {
+    self.0.len()
+  }
+}
+
+impl WasmTrustedIssuerSet {
+  /// Returns the document of `did`, failing if it is not part of the set.
+  pub(crate) fn get(&self, did: &CoreDID) -> Result<&CoreDocument> {
+    self.0.get(did.as_str()).ok_or_else(|| {
+      WasmError::new(
+        Cow::Borrowed("UntrustedDocument"),
+        Cow::Owned(format!("no trusted document for {did}")),
+      )
+      .into()
+    })
+  }
+
+  fn insert_js_value(&mut self, value: &JsValue) -> Result<()> {
+    let document: CoreDocument = document_from_js_value(value)?;
+    self.0.insert(document.id().to_string(), document);
+    Ok(())
+  }
+}
+
+/// Converts an instance implementing `IToCoreDocument`, or the JSON representation of a [`CoreDocument`] or
+/// [`IotaDocument`], into a [`CoreDocument`].
+fn document_from_js_value(value: &JsValue) -> Result<CoreDocument> {
+  let is_function = |name: &str| Reflect::get(value, &JsValue::from_str(name)).is_ok_and(|value| value.is_function());
+  if is_function("toCoreDocument") || is_function("_shallowCloneInternal") {
+    let lock = ImportedDocumentLock::from_js_value_unchecked(value);
+    let guard = lock.try_read()?;
+    return Ok(guard.as_ref().clone());
+  }
+
+  // The JSON representation of an `IotaDocument` wraps the core document along with its metadata.
+  if Reflect::has(value, &JsValue::from_str("doc")).unwrap_or(false) {
+    value
+      .into_serde::<IotaDocument>()
+      .map(|document| document.core_document().clone())
+      .wasm_result()
+  } else {
+    value.into_serde::<CoreDocument>().wasm_result()
+  }
+}
+
+#[wasm_bindgen]
+extern "C" {
+  #[wasm_bindgen(typescript_type = "CoreDocument | IToCoreDocument | Record<string, any>")]
+  pub type AnyDocument;
+
+  #[wasm_bindgen(typescript_type = "Array<CoreDocument | IToCoreDocument | Record<string, any>>")]
+  pub type ArrayAnyDocument;
+}
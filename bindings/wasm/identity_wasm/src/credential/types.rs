@@ -1,7 +1,13 @@
 // Copyright 2020-2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use js_sys::Array;
+use js_sys::Function;
+use js_sys::TypeError;
 use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+use crate::error::Result;
 
 #[wasm_bindgen]
 extern "C" {
@@ -141,3 +147,21 @@ interface Subject {
   /** Additional properties of the credential subject. */
   readonly [properties: string]: unknown;
 }"#;
+
+/// Calls `check` on each of `subjects`, failing with a `TypeError` on the first subject for which it doesn't return
+/// a truthy value.
+pub(crate) fn check_subjects(subjects: ArraySubject, check: &Function) -> Result<ArraySubject> {
+  let array: &Array = subjects.unchecked_ref();
+  for (index, subject) in array.iter().enumerate() {
+    if !check.call1(&JsValue::NULL, &subject)?.is_truthy() {
+      return Err(
+        TypeError::new(&format!(
+          "credential subject at index {index} does not satisfy the given check"
+        ))
+        .into(),
+      );
+    }
+  }
+
+  Ok(subjects)
+}
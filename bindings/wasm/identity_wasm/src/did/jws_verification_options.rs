@@ -43,6 +43,13 @@ impl WasmJwsVerificationOptions {
   pub fn set_method_id(&mut self, value: &WasmDIDUrl) {
     self.0.method_id = Some(value.0.clone());
   }
+
+  /// Set the policy the algorithm of the JWS and the verification key must comply with.
+  #[wasm_bindgen(js_name = setAlgorithmPolicy)]
+  pub fn set_algorithm_policy(&mut self, policy: IAlgorithmPolicy) -> Result<()> {
+    self.0.algorithm_policy = Some(policy.into_serde().wasm_result()?);
+    Ok(())
+  }
 }
 
 impl_wasm_json!(WasmJwsVerificationOptions, JwsVerificationOptions);
@@ -53,6 +60,9 @@ impl_wasm_clone!(WasmJwsVerificationOptions, JwsVerificationOptions);
 extern "C" {
   #[wasm_bindgen(typescript_type = "IJwsVerificationOptions")]
   pub type IJwsVerificationOptions;
+
+  #[wasm_bindgen(typescript_type = "AlgorithmPolicy")]
+  pub type IAlgorithmPolicy;
 }
 
 #[wasm_bindgen(typescript_custom_section)]
@@ -72,4 +82,28 @@ interface IJwsVerificationOptions {
      * If unset, the `kid` of the JWS is used as the DID Url.
      */
     readonly methodId?: DIDUrl;
+
+    /** The policy the algorithm of the JWS and the verification key must comply with. */
+    readonly algorithmPolicy?: AlgorithmPolicy;
+}
+
+/** A policy restricting the algorithms and keys accepted when verifying a JWS.
+ *
+ * By default, every algorithm except `none` is accepted.
+ */
+interface AlgorithmPolicy {
+    /** If set, only these algorithms are accepted. */
+    readonly allowed?: JwsAlgorithm[];
+
+    /** Algorithms that are never accepted, even when listed in `allowed`. */
+    readonly denied?: JwsAlgorithm[];
+
+    /** The minimum size in bits of keys of the given `kty`. */
+    readonly minKeySizes?: Partial<Record<JwkType, number>>;
+
+    /** Whether the `none` algorithm is accepted. Defaults to `false`. */
+    readonly allowNone?: boolean;
+
+    /** Whether post-quantum or hybrid signatures are required. Defaults to `"none"`. */
+    readonly postQuantum?: "none" | "postQuantum" | "hybrid";
 }"#;
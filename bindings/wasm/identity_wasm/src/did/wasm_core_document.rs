@@ -608,6 +608,21 @@ impl WasmCoreDocument {
       .wasm_result()
   }
 
+  /// Serializes to CBOR.
+  ///
+  /// Cheaper than {@link CoreDocument.toJSON} when the result is only meant to be stored or transferred,
+  /// e.g. to a worker.
+  #[wasm_bindgen(js_name = toCbor)]
+  pub fn to_cbor(&self) -> Result<Vec<u8>> {
+    crate::common::to_cbor(self.0.try_read()?.as_ref())
+  }
+
+  /// Deserializes an instance from its CBOR representation.
+  #[wasm_bindgen(js_name = fromCbor)]
+  pub fn from_cbor(bytes: &[u8]) -> Result<WasmCoreDocument> {
+    crate::common::from_cbor(bytes).map(|value| Self(Rc::new(CoreDocumentLock::new(value))))
+  }
+
   // ===========================================================================
   // Storage
   // ===========================================================================
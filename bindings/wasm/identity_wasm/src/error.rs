@@ -1,6 +1,8 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use identity_iota::core::error_code::ErrorCode;
+use identity_iota::core::error_code::HasErrorCode;
 use identity_iota::credential::CompoundJwtPresentationValidationError;
 use identity_iota::resolver;
 use identity_iota::storage::key_id_storage::KeyIdStorageError;
@@ -56,19 +58,55 @@ where
 pub struct WasmError<'a> {
   pub name: Cow<'a, str>,
   pub message: Cow<'a, str>,
+  /// Stable code of the originating Rust error, if it has one.
+  pub code: Option<ErrorCode>,
+  /// Messages of the errors that caused this one, from the most direct cause to the root cause.
+  pub causes: Vec<String>,
 }
 
 impl<'a> WasmError<'a> {
   pub fn new(name: Cow<'a, str>, message: Cow<'a, str>) -> Self {
-    Self { name, message }
+    Self {
+      name,
+      message,
+      code: None,
+      causes: Vec::new(),
+    }
+  }
+
+  /// Attaches the stable [ErrorCode] of the originating Rust error.
+  pub fn with_code(mut self, code: ErrorCode) -> Self {
+    self.code = Some(code);
+    self
   }
 }
 
 /// Convert [WasmError] into [js_sys::Error] for idiomatic error handling.
+///
+/// The error code, if any, is exposed through the `code` property, e.g. `"IOTA_ID_1606"`, and the chain of
+/// underlying errors through the standard `cause` property.
 impl From<WasmError<'_>> for js_sys::Error {
   fn from(error: WasmError<'_>) -> Self {
     let js_error = js_sys::Error::new(&error.message);
     js_error.set_name(&error.name);
+    if let Some(code) = error.code {
+      // Setting a property on a freshly created object cannot fail.
+      let _ = js_sys::Reflect::set(&js_error, &JsValue::from_str("code"), &JsValue::from(code.code()));
+    }
+    if let Some(cause) = error
+      .causes
+      .iter()
+      .rev()
+      .fold(None, |cause: Option<js_sys::Error>, message| {
+        let js_cause = js_sys::Error::new(message);
+        if let Some(cause) = cause {
+          let _ = js_sys::Reflect::set(&js_cause, &JsValue::from_str("cause"), &cause);
+        }
+        Some(js_cause)
+      })
+    {
+      let _ = js_sys::Reflect::set(&js_error, &JsValue::from_str("cause"), &cause);
+    }
     js_error
   }
 }
@@ -90,6 +128,8 @@ macro_rules! impl_wasm_error_from {
     fn from(error: $t) -> Self {
       Self {
         message: Cow::Owned(ErrorMessage(&error).to_string()),
+        code: None,
+        causes: error_causes(&error),
         name: Cow::Borrowed(error.into()),
       }
     }
@@ -97,7 +137,26 @@ macro_rules! impl_wasm_error_from {
   }
 }
 
-impl_wasm_error_from!(
+impl_wasm_error_from!(identity_iota::sd_jwt_payload::Error, ProductCommonError);
+
+// Similar to `impl_wasm_error_from`, but additionally attaches the error's stable `ErrorCode`.
+#[macro_export]
+macro_rules! impl_wasm_error_from_with_code {
+  ( $($t:ty),* ) => {
+  $(impl From<$t> for WasmError<'_> {
+    fn from(error: $t) -> Self {
+      Self {
+        message: Cow::Owned(ErrorMessage(&error).to_string()),
+        code: Some(error.error_code()),
+        causes: error_causes(&error),
+        name: Cow::Borrowed(error.into()),
+      }
+    }
+  })*
+  }
+}
+
+impl_wasm_error_from_with_code!(
   identity_iota::core::Error,
   identity_iota::credential::Error,
   identity_iota::did::Error,
@@ -107,12 +166,11 @@ impl_wasm_error_from!(
   identity_iota::credential::RevocationError,
   identity_iota::verification::Error,
   identity_iota::credential::DomainLinkageValidationError,
-  identity_iota::sd_jwt_payload::Error,
   identity_iota::credential::KeyBindingJwtError,
   identity_iota::credential::status_list_2021::StatusListError,
   identity_iota::credential::status_list_2021::StatusList2021CredentialError,
   identity_iota::iota::rebased::Error,
-  ProductCommonError
+  identity_iota::resolver::LinkedVpError
 );
 
 // Similar to `impl_wasm_error_from`, but uses the types name instead of requiring/calling Into &'static str
@@ -123,6 +181,8 @@ macro_rules! impl_wasm_error_from_with_struct_name {
     fn from(error: $t) -> Self {
       Self {
         message: Cow::Owned(error.to_string()),
+        code: None,
+        causes: Vec::new(),
         name: Cow::Borrowed(stringify!($t)),
       }
     }
@@ -153,6 +213,13 @@ fn error_chain_fmt(e: &impl std::error::Error, f: &mut std::fmt::Formatter<'_>)
   Ok(())
 }
 
+/// Collects the messages of the sources of `error`, from the most direct to the root cause.
+fn error_causes(error: &dyn std::error::Error) -> Vec<String> {
+  std::iter::successors(error.source(), |cause| cause.source())
+    .map(ToString::to_string)
+    .collect()
+}
+
 struct ErrorMessage<'a, E: std::error::Error>(&'a E);
 
 impl<E: std::error::Error> Display for ErrorMessage<'_, E> {
@@ -166,6 +233,8 @@ impl From<resolver::Error> for WasmError<'_> {
     Self {
       name: Cow::Owned(format!("ResolverError::{}", <&'static str>::from(error.error_cause()))),
       message: Cow::Owned(ErrorMessage(&error).to_string()),
+      code: Some(error.error_code()),
+      causes: error_causes(&error),
     }
   }
 }
@@ -175,6 +244,8 @@ impl From<serde_json::Error> for WasmError<'_> {
     Self {
       name: Cow::Borrowed("serde_json::Error"), // the exact error code is embedded in the message
       message: Cow::Owned(error.to_string()),
+      code: None,
+      causes: Vec::new(),
     }
   }
 }
@@ -183,7 +254,9 @@ impl From<anyhow::Error> for WasmError<'_> {
   fn from(value: anyhow::Error) -> Self {
     Self {
       name: Cow::Borrowed("Generic Error"),
-      message: Cow::Owned(value.to_string()),
+      message: Cow::Owned(format!("{value:#}")),
+      code: None,
+      causes: value.chain().skip(1).map(ToString::to_string).collect(),
     }
   }
 }
@@ -193,6 +266,8 @@ impl From<bcs::Error> for WasmError<'_> {
     Self {
       name: Cow::Borrowed("BCS Error"),
       message: Cow::Owned(value.to_string()),
+      code: None,
+      causes: Vec::new(),
     }
   }
 }
@@ -202,6 +277,8 @@ impl From<iota_sdk::types::block::Error> for WasmError<'_> {
     Self {
       name: Cow::Borrowed("iota_sdk::types::block::Error"),
       message: Cow::Owned(error.to_string()),
+      code: None,
+      causes: Vec::new(),
     }
   }
 }
@@ -211,6 +288,8 @@ impl From<iota_interaction::types::sdk_types::AddressParseError> for WasmError<'
     Self {
       name: Cow::Borrowed("AddressParseError"),
       message: Cow::Owned(error.to_string()),
+      code: None,
+      causes: Vec::new(),
     }
   }
 }
@@ -220,6 +299,8 @@ impl From<identity_iota::credential::CompoundCredentialValidationError> for Wasm
     Self {
       name: Cow::Borrowed("CompoundCredentialValidationError"),
       message: Cow::Owned(ErrorMessage(&error).to_string()),
+      code: Some(error.error_code()),
+      causes: error_causes(&error),
     }
   }
 }
@@ -229,6 +310,8 @@ impl From<identity_iota::core::SingleStructError<KeyStorageErrorKind>> for WasmE
     Self {
       name: Cow::Borrowed("KeyStorageError"),
       message: Cow::Owned(ErrorMessage(&error).to_string()),
+      code: Some(error.error_code()),
+      causes: error_causes(&error),
     }
   }
 }
@@ -238,6 +321,8 @@ impl From<identity_iota::core::SingleStructError<KeyIdStorageErrorKind>> for Was
     Self {
       name: Cow::Borrowed("KeyIdStorageError"),
       message: Cow::Owned(ErrorMessage(&error).to_string()),
+      code: Some(error.error_code()),
+      causes: error_causes(&error),
     }
   }
 }
@@ -247,6 +332,8 @@ impl From<identity_iota::storage::key_id_storage::MethodDigestConstructionError>
     Self {
       name: Cow::Borrowed("MethodDigestConstructionError"),
       message: Cow::Owned(ErrorMessage(&error).to_string()),
+      code: None,
+      causes: error_causes(&error),
     }
   }
 }
@@ -256,6 +343,8 @@ impl From<identity_iota::storage::storage::JwkStorageDocumentError> for WasmErro
     Self {
       name: Cow::Borrowed("JwkDocumentExtensionError"),
       message: Cow::Owned(ErrorMessage(&error).to_string()),
+      code: Some(error.error_code()),
+      causes: error_causes(&error),
     }
   }
 }
@@ -265,6 +354,8 @@ impl From<identity_iota::verification::jws::SignatureVerificationError> for Wasm
     Self {
       name: Cow::Borrowed("SignatureVerificationError"),
       message: Cow::Owned(ErrorMessage(&error).to_string()),
+      code: None,
+      causes: error_causes(&error),
     }
   }
 }
@@ -274,6 +365,8 @@ impl From<identity_iota::verification::jose::error::Error> for WasmError<'_> {
     Self {
       name: Cow::Borrowed("JoseError"),
       message: Cow::Owned(ErrorMessage(&error).to_string()),
+      code: Some(error.error_code()),
+      causes: error_causes(&error),
     }
   }
 }
@@ -283,6 +376,8 @@ impl From<CompoundJwtPresentationValidationError> for WasmError<'_> {
     Self {
       name: Cow::Borrowed("CompoundJwtPresentationValidationError"),
       message: Cow::Owned(ErrorMessage(&error).to_string()),
+      code: Some(error.error_code()),
+      causes: error_causes(&error),
     }
   }
 }
@@ -292,6 +387,8 @@ impl From<TryLockError> for WasmError<'_> {
     Self {
       name: Cow::Borrowed("TryLockError"),
       message: Cow::Owned(ErrorMessage(&error).to_string()),
+      code: None,
+      causes: error_causes(&error),
     }
   }
 }
@@ -301,6 +398,8 @@ impl From<serde_wasm_bindgen::Error> for WasmError<'_> {
     Self {
       name: Cow::Borrowed("serde_wasm_bindgen::Error"),
       message: Cow::Owned(ErrorMessage(&error).to_string()),
+      code: None,
+      causes: error_causes(&error),
     }
   }
 }
@@ -310,6 +409,8 @@ impl From<secret_storage::Error> for WasmError<'_> {
     Self {
       name: Cow::Borrowed("secret_storage::Error"),
       message: Cow::Owned(ErrorMessage(&error).to_string()),
+      code: None,
+      causes: error_causes(&error),
     }
   }
 }
@@ -319,6 +420,92 @@ impl From<identity_iota::credential::sd_jwt_vc::Error> for WasmError<'_> {
     Self {
       name: Cow::Borrowed("SdJwtVcError"),
       message: Cow::Owned(ErrorMessage(&error).to_string()),
+      code: None,
+      causes: error_causes(&error),
+    }
+  }
+}
+
+impl From<identity_iota::credential::VcDataUrlParsingError> for WasmError<'_> {
+  fn from(error: identity_iota::credential::VcDataUrlParsingError) -> Self {
+    Self {
+      name: Cow::Borrowed("VcDataUrlParsingError"),
+      message: Cow::Owned(ErrorMessage(&error).to_string()),
+      code: None,
+      causes: error_causes(&error),
+    }
+  }
+}
+
+impl From<identity_iota::credential::JwtVcV2ParsingError> for WasmError<'_> {
+  fn from(error: identity_iota::credential::JwtVcV2ParsingError) -> Self {
+    Self {
+      name: Cow::Borrowed("JwtVcV2ParsingError"),
+      message: Cow::Owned(ErrorMessage(&error).to_string()),
+      code: None,
+      causes: error_causes(&error),
+    }
+  }
+}
+
+impl From<identity_iota::storage::storage::StorageSignerFromVmError> for WasmError<'_> {
+  fn from(error: identity_iota::storage::storage::StorageSignerFromVmError) -> Self {
+    Self {
+      name: Cow::Borrowed("StorageSignerFromVmError"),
+      message: Cow::Owned(ErrorMessage(&error).to_string()),
+      code: None,
+      causes: error_causes(&error),
+    }
+  }
+}
+
+impl From<identity_iota::credential::SdJwtCredentialValidatorError> for WasmError<'_> {
+  fn from(error: identity_iota::credential::SdJwtCredentialValidatorError) -> Self {
+    match error {
+      identity_iota::credential::SdJwtCredentialValidatorError::JwsVerification(error) => error.into(),
+      error => Self {
+        name: Cow::Borrowed("SdJwtCredentialValidatorError"),
+        message: Cow::Owned(ErrorMessage(&error).to_string()),
+        code: None,
+        causes: error_causes(&error),
+      },
+    }
+  }
+}
+
+impl From<identity_iota::credential::AnyCredentialError> for WasmError<'_> {
+  fn from(error: identity_iota::credential::AnyCredentialError) -> Self {
+    match error {
+      identity_iota::credential::AnyCredentialError::InvalidCredential(error) => error.into(),
+      identity_iota::credential::AnyCredentialError::InvalidSdJwt(error) => error.into(),
+      error => Self {
+        message: Cow::Owned(ErrorMessage(&error).to_string()),
+        code: Some(error.error_code()),
+        causes: error_causes(&error),
+        name: Cow::Borrowed(error.into()),
+      },
+    }
+  }
+}
+
+impl From<identity_iota::iota::rebased::proposals::AccessSubIdentityBuilderError> for WasmError<'_> {
+  fn from(error: identity_iota::iota::rebased::proposals::AccessSubIdentityBuilderError) -> Self {
+    Self {
+      name: Cow::Borrowed("AccessSubIdentityBuilderError"),
+      message: Cow::Owned(ErrorMessage(&error).to_string()),
+      code: None,
+      causes: error_causes(&error),
+    }
+  }
+}
+
+impl From<identity_iota::iota::rebased::proposals::AccessSubIdentityError> for WasmError<'_> {
+  fn from(error: identity_iota::iota::rebased::proposals::AccessSubIdentityError) -> Self {
+    Self {
+      name: Cow::Borrowed("AccessSubIdentityError"),
+      message: Cow::Owned(ErrorMessage(&error).to_string()),
+      code: None,
+      causes: error_causes(&error),
     }
   }
 }
@@ -328,6 +515,8 @@ impl From<AdapterError> for WasmError<'_> {
     Self {
       name: Cow::Borrowed("TsSdkError"),
       message: Cow::Owned(ErrorMessage(&error).to_string()),
+      code: None,
+      causes: error_causes(&error),
     }
   }
 }
@@ -60,3 +60,25 @@ macro_rules! impl_wasm_json {
     }
   };
 }
+
+#[macro_export]
+macro_rules! impl_wasm_cbor {
+  ($wasm_class:ident, $js_class:ident) => {
+    #[wasm_bindgen(js_class = $js_class)]
+    impl $wasm_class {
+      /// Serializes this to CBOR.
+      ///
+      /// Cheaper than `toJSON` when the result is only meant to be stored or transferred, e.g. to a worker.
+      #[wasm_bindgen(js_name = toCbor)]
+      pub fn to_cbor(&self) -> $crate::error::Result<Vec<u8>> {
+        $crate::common::to_cbor(&self.0)
+      }
+
+      /// Deserializes an instance from its CBOR representation.
+      #[wasm_bindgen(js_name = fromCbor)]
+      pub fn from_cbor(bytes: &[u8]) -> $crate::error::Result<$wasm_class> {
+        $crate::common::from_cbor(bytes).map(Self)
+      }
+    }
+  };
+}
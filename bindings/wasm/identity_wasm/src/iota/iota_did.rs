@@ -57,7 +57,7 @@ impl WasmIotaDID {
   #[allow(non_snake_case)]
   pub fn from_object_id(objectId: String, network: String) -> Result<WasmIotaDID> {
     let network_name = NetworkName::try_from(network).wasm_result()?;
-    let object_id = ObjectId::from_prefixed_hex(&objectId).map_err(JsError::from)?;
+    let object_id = ObjectId::from_prefixed_hex(&objectId).wasm_result()?;
 
     Ok(Self(IotaDID::from_object_id(object_id, &network_name)))
   }
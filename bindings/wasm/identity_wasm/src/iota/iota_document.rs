@@ -489,6 +489,13 @@ impl WasmIotaDocument {
     Ok(())
   }
 
+  /// Enables recording the updates of the DID document in the provenance log of its metadata.
+  #[wasm_bindgen(js_name = enableMetadataProvenance)]
+  pub fn enable_metadata_provenance(&mut self) -> Result<()> {
+    self.0.try_write()?.metadata.enable_provenance();
+    Ok(())
+  }
+
   /// Sets a custom property in the document metadata.
   /// If the value is set to `null`, the custom property will be removed.
   #[wasm_bindgen(js_name = setMetadataPropertyUnchecked)]
@@ -586,6 +593,23 @@ impl WasmIotaDocument {
       .wasm_result()
   }
 
+  /// Serializes to CBOR.
+  ///
+  /// Cheaper than {@link IotaDocument.toJSON} when the result is only meant to be stored or transferred,
+  /// e.g. to a worker.
+  #[wasm_bindgen(js_name = toCbor)]
+  pub fn to_cbor(&self) -> Result<Vec<u8>> {
+    let read_guard = self.0.try_read()?;
+    let iota_document: &IotaDocument = &read_guard;
+    crate::common::to_cbor(iota_document)
+  }
+
+  /// Deserializes an instance from its CBOR representation.
+  #[wasm_bindgen(js_name = fromCbor)]
+  pub fn from_cbor(bytes: &[u8]) -> Result<WasmIotaDocument> {
+    crate::common::from_cbor(bytes).map(|value| Self(Rc::new(IotaDocumentLock::new(value))))
+  }
+
   // ===========================================================================
   // "AsRef<CoreDocument>"
   // ===========================================================================
@@ -3,11 +3,32 @@
 
 use identity_iota::iota::IotaDocumentMetadata;
 use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast as _;
 
 use crate::common::MapStringAny;
 use crate::common::WasmTimestamp;
 use crate::error::Result;
 
+#[wasm_bindgen(typescript_custom_section)]
+const I_PROVENANCE_ENTRY: &str = r#"
+/** An entry of the provenance log of an IOTA DID Document, describing one of its updates. */
+interface ProvenanceEntry {
+  /** The ID of the controller capability used to propose the update. */
+  controller: string;
+  /** The time at which the update was proposed. */
+  timestamp: string;
+  /** The ID of the proposal that carried out the update, if known. */
+  proposal?: string;
+  /** The digest of the transaction that carried out the update, if known. */
+  txDigest?: string;
+}"#;
+
+#[wasm_bindgen]
+extern "C" {
+  #[wasm_bindgen(typescript_type = "ProvenanceEntry[]")]
+  pub type ArrayProvenanceEntry;
+}
+
 /// Additional attributes related to an IOTA DID Document.
 #[wasm_bindgen(js_name = IotaDocumentMetadata, inspectable)]
 pub struct WasmIotaDocumentMetadata(pub(crate) IotaDocumentMetadata);
@@ -34,6 +55,33 @@ impl WasmIotaDocumentMetadata {
     self.0.deactivated
   }
 
+  /// Returns a copy of the version of the DID document, set when resolving it at a specific version or point in
+  /// time.
+  #[wasm_bindgen(js_name = versionId)]
+  pub fn version_id(&self) -> Option<String> {
+    self.0.version_id.clone()
+  }
+
+  /// Returns a copy of the timestamp of the update that superseded this version of the DID document, if any.
+  #[wasm_bindgen(js_name = nextUpdate)]
+  pub fn next_update(&self) -> Option<WasmTimestamp> {
+    self.0.next_update.map(WasmTimestamp::from)
+  }
+
+  /// Returns whether the updates of the DID document are recorded in its provenance log.
+  #[wasm_bindgen(js_name = isProvenanceEnabled)]
+  pub fn is_provenance_enabled(&self) -> bool {
+    self.0.is_provenance_enabled()
+  }
+
+  /// Returns a copy of the provenance log of the DID document, oldest entry first.
+  #[wasm_bindgen]
+  pub fn history(&self) -> Result<ArrayProvenanceEntry> {
+    serde_wasm_bindgen::to_value(self.0.history())
+      .map(|value| value.unchecked_into())
+      .map_err(JsValue::from)
+  }
+
   /// Returns a copy of the custom metadata properties.
   #[wasm_bindgen]
   pub fn properties(&self) -> Result<MapStringAny> {
@@ -2,9 +2,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::error::Result;
+use crate::error::WasmError;
 use crate::error::WasmResult;
 use crate::jose::WasmJwsHeader;
 use identity_iota::verification::jws::DecodedJws;
+use std::borrow::Cow;
 use wasm_bindgen::prelude::*;
 
 /// A cryptographically verified decoded token from a JWS.
@@ -30,7 +32,8 @@ impl WasmDecodedJws {
   /// This error can only occur if the Token was decoded from a detached payload.  
   #[wasm_bindgen]
   pub fn claims(&self) -> Result<String> {
-    String::from_utf8(self.claims.clone()).map_err(|error| JsError::new(&error.to_string()).into())
+    String::from_utf8(self.claims.clone())
+      .map_err(|error| WasmError::new(Cow::Borrowed("FromUtf8Error"), Cow::Owned(error.to_string())).into())
   }
 
   /// Return a copy of the parsed claims represented as an array of bytes.
@@ -4,9 +4,13 @@
 use identity_iota::verification::jwk::CompositeAlgId;
 use identity_iota::verification::jws::JwsAlgorithm;
 use js_sys::JsString;
+use std::borrow::Cow;
 use std::str::FromStr;
 use wasm_bindgen::prelude::*;
 
+use crate::error::WasmError;
+use crate::error::WasmResult;
+
 #[wasm_bindgen]
 extern "C" {
   #[wasm_bindgen(typescript_type = "IJwkParams")]
@@ -37,10 +41,9 @@ impl TryFrom<WasmJwsAlgorithm> for JwsAlgorithm {
   type Error = JsValue;
   fn try_from(value: WasmJwsAlgorithm) -> Result<Self, Self::Error> {
     if let Ok(js_string) = value.dyn_into::<JsString>() {
-      JwsAlgorithm::from_str(String::from(js_string).as_ref())
-        .map_err(|err| js_sys::Error::new(&err.to_string()).into())
+      JwsAlgorithm::from_str(String::from(js_string).as_ref()).wasm_result()
     } else {
-      Err(js_sys::Error::new("invalid JwsAlgorithm").into())
+      Err(WasmError::new(Cow::Borrowed("TypeError"), Cow::Borrowed("invalid JwsAlgorithm")).into())
     }
   }
 }
@@ -50,9 +53,9 @@ impl TryFrom<WasmCompositeAlgId> for CompositeAlgId {
   fn try_from(value: WasmCompositeAlgId) -> Result<Self, Self::Error> {
     if let Ok(js_string) = value.dyn_into::<JsString>() {
       CompositeAlgId::from_str(String::from(js_string).as_ref())
-        .map_err(|err| js_sys::Error::new(&err.to_string()).into())
+        .map_err(|err| WasmError::new(Cow::Borrowed("CompositeAlgParsingError"), Cow::Owned(err.to_string())).into())
     } else {
-      Err(js_sys::Error::new("invalid CompositeAlgId").into())
+      Err(WasmError::new(Cow::Borrowed("TypeError"), Cow::Borrowed("invalid CompositeAlgId")).into())
     }
   }
 }
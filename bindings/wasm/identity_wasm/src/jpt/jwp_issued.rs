@@ -14,6 +14,7 @@ use crate::jpt::WasmIssuerProtectedHeader;
 pub struct WasmJwpIssued(pub(crate) JwpIssued);
 
 impl_wasm_json!(WasmJwpIssued, JwpIssued);
+impl_wasm_cbor!(WasmJwpIssued, JwpIssued);
 impl_wasm_clone!(WasmJwpIssued, JwpIssued);
 
 #[wasm_bindgen(js_class = JwpIssued)]
@@ -3,6 +3,7 @@
 
 use super::WasmJwpIssued;
 use super::WasmPresentationProtectedHeader;
+use crate::credential::IJptPredicate;
 use crate::error::Result;
 use crate::error::WasmResult;
 use identity_iota::credential::SelectiveDisclosurePresentation;
@@ -75,6 +76,16 @@ impl WasmSelectiveDisclosurePresentation {
     self.0.conceal_in_evidence(&path).wasm_result()
   }
 
+  /// Declares that this presentation is meant to satisfy `predicate`, e.g. that the subject is over 18.
+  ///
+  /// Since the BBS+ proof suite does not support range proofs, the claim the predicate refers to stays disclosed
+  /// and the verifier checks the predicate against it. Fails if the claim has been concealed.
+  #[wasm_bindgen(js_name = addPredicate)]
+  pub fn add_predicate(&mut self, predicate: IJptPredicate) -> Result<()> {
+    let predicate = predicate.into_serde().wasm_result()?;
+    self.0.add_predicate(predicate).wasm_result()
+  }
+
   /// Sets presentation protected header.
   #[wasm_bindgen(js_name = setPresentationHeader)]
   pub fn set_presentation_header(&mut self, header: WasmPresentationProtectedHeader) {
@@ -0,0 +1,81 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_iota::iota::rebased::AuthenticatedAsset;
+use iota_interaction_ts::core_client::WasmCoreClientReadOnly;
+use iota_sdk_types::Address;
+use iota_sdk_types::TypeTag;
+use wasm_bindgen::prelude::*;
+
+use super::client_compat::managed_core_client;
+use super::WasmIotaAddress;
+use crate::error::wasm_error;
+use crate::error::Result;
+use crate::error::WasmResult;
+
+/// An on-chain asset that carries information about its owner and its creator.
+#[derive(Clone)]
+#[wasm_bindgen(js_name = AuthenticatedAsset)]
+pub struct WasmAuthenticatedAsset(pub(crate) AuthenticatedAsset<serde_json::Value>);
+
+#[wasm_bindgen(js_class = AuthenticatedAsset)]
+impl WasmAuthenticatedAsset {
+  /// This asset's ID.
+  #[wasm_bindgen(getter)]
+  pub fn id(&self) -> String {
+    self.0.id().to_string()
+  }
+
+  /// This asset's content.
+  #[wasm_bindgen(getter)]
+  pub fn content(&self) -> Result<JsValue> {
+    serde_wasm_bindgen::to_value(self.0.content()).map_err(wasm_error)
+  }
+
+  /// The address that currently owns this asset.
+  #[wasm_bindgen(getter)]
+  pub fn owner(&self) -> String {
+    self.0.owner().to_string()
+  }
+
+  /// The address that created this asset.
+  #[wasm_bindgen(getter)]
+  pub fn origin(&self) -> String {
+    self.0.origin().to_string()
+  }
+
+  /// Returns all the assets owned by `owner` whose content has Move type `contentType`, e.g. `u64`.
+  #[wasm_bindgen(js_name = findByOwner)]
+  pub async fn find_by_owner(
+    owner: WasmIotaAddress,
+    content_type: String,
+    client: &WasmCoreClientReadOnly,
+  ) -> Result<Vec<WasmAuthenticatedAsset>> {
+    let client = managed_core_client(client)?;
+    let owner = owner.parse::<Address>().wasm_result()?;
+    let content_type = content_type.parse::<TypeTag>().wasm_result()?;
+
+    let assets = AuthenticatedAsset::find_by_owner_with_content_type(owner, content_type, &client)
+      .await
+      .wasm_result()?;
+    Ok(assets.into_iter().map(Self).collect())
+  }
+
+  /// Returns all the existing assets created by `origin` whose content has Move type `contentType`,
+  /// regardless of their current owner.
+  #[wasm_bindgen(js_name = findByOrigin)]
+  pub async fn find_by_origin(
+    origin: WasmIotaAddress,
+    content_type: String,
+    client: &WasmCoreClientReadOnly,
+  ) -> Result<Vec<WasmAuthenticatedAsset>> {
+    let client = managed_core_client(client)?;
+    let origin = origin.parse::<Address>().wasm_result()?;
+    let content_type = content_type.parse::<TypeTag>().wasm_result()?;
+
+    let assets = AuthenticatedAsset::find_by_origin_with_content_type(origin, content_type, &client)
+      .await
+      .wasm_result()?;
+    Ok(assets.into_iter().map(Self).collect())
+  }
+}
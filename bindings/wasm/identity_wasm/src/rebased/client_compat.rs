@@ -0,0 +1,110 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Defensive checks performed on client objects received from JS before handing them over to Rust.
+//!
+//! Passing an object that doesn't implement the expected interface, or a binding object that has already been freed,
+//! would otherwise abort the WASM module with an opaque error such as "null pointer passed to rust".
+
+use iota_interaction_ts::core_client::WasmCoreClientReadOnly;
+use js_sys::Function;
+use js_sys::Reflect;
+use js_sys::TypeError;
+use product_common::bindings::core_client::WasmManagedCoreClientReadOnly;
+use wasm_bindgen::JsCast as _;
+use wasm_bindgen::JsValue;
+
+use crate::error::Result;
+
+/// Version requirement on `@iota/iota-sdk` these bindings are built against.
+/// Must be kept in sync with the `peerDependencies` of `package.json`.
+const IOTA_SDK_VERSION_REQUIREMENT: &str = "^1.14.0";
+
+/// Methods every `CoreClientReadOnly` must expose.
+const CORE_CLIENT_READ_ONLY_METHODS: &[&str] = &["packageId", "packageHistory", "network", "iotaClient"];
+
+/// Methods of `IotaClient` these bindings rely on. Their absence denotes an incompatible version of `@iota/iota-sdk`.
+const IOTA_CLIENT_METHODS: &[&str] = &[
+  "getChainIdentifier",
+  "getObject",
+  "getOwnedObjects",
+  "getDynamicFieldObject",
+];
+
+/// Checks that `client` can be used as a `CoreClientReadOnly` and wraps it into a [WasmManagedCoreClientReadOnly].
+///
+/// # Errors
+/// Throws a `TypeError` describing the incompatibility when `client` is not a valid `CoreClientReadOnly`.
+pub(crate) fn managed_core_client(client: &WasmCoreClientReadOnly) -> Result<WasmManagedCoreClientReadOnly> {
+  check_core_client_read_only(client.as_ref())?;
+  Ok(WasmManagedCoreClientReadOnly::from_wasm(client)?)
+}
+
+/// Checks that `client` exposes every method of `CoreClientReadOnly` and wraps a compatible `IotaClient`.
+pub(crate) fn check_core_client_read_only(client: &JsValue) -> Result<()> {
+  check_object(client, "CoreClientReadOnly")?;
+  check_methods(client, "CoreClientReadOnly", CORE_CLIENT_READ_ONLY_METHODS)?;
+
+  let get_iota_client = Reflect::get(client, &JsValue::from_str("iotaClient"))?.unchecked_into::<Function>();
+  let iota_client = get_iota_client
+    .call0(client)
+    .map_err(|_| type_error("CoreClientReadOnly", "its `iotaClient` method threw an error"))?;
+  check_iota_client(&iota_client)
+}
+
+/// Checks that `client` is an `IotaClient` from a compatible version of `@iota/iota-sdk`.
+pub(crate) fn check_iota_client(client: &JsValue) -> Result<()> {
+  check_object(client, "IotaClient")?;
+  let missing = missing_methods(client, IOTA_CLIENT_METHODS)?;
+  if missing.is_empty() {
+    Ok(())
+  } else {
+    Err(type_error(
+      "IotaClient",
+      &format!(
+        "it is missing the methods {}; make sure a version of `@iota/iota-sdk` matching `{IOTA_SDK_VERSION_REQUIREMENT}` is installed",
+        missing.join(", ")
+      ),
+    ))
+  }
+}
+
+fn check_object(value: &JsValue, expected: &str) -> Result<()> {
+  if !value.is_object() {
+    return Err(type_error(expected, "it is not an object"));
+  }
+  // Binding objects whose ownership was moved to Rust, or that were explicitly freed, keep a null pointer.
+  let ptr = Reflect::get(value, &JsValue::from_str("__wbg_ptr"))?;
+  if ptr.as_f64() == Some(0.0) {
+    return Err(type_error(expected, "it has already been freed or moved"));
+  }
+
+  Ok(())
+}
+
+fn check_methods(value: &JsValue, expected: &str, methods: &[&str]) -> Result<()> {
+  let missing = missing_methods(value, methods)?;
+  if missing.is_empty() {
+    Ok(())
+  } else {
+    Err(type_error(
+      expected,
+      &format!("it is missing the methods {}", missing.join(", ")),
+    ))
+  }
+}
+
+fn missing_methods(value: &JsValue, methods: &[&str]) -> Result<Vec<String>> {
+  let mut missing = Vec::new();
+  for method in methods {
+    if !Reflect::get(value, &JsValue::from_str(method))?.is_function() {
+      missing.push(format!("`{method}`"));
+    }
+  }
+
+  Ok(missing)
+}
+
+fn type_error(expected: &str, reason: &str) -> JsValue {
+  TypeError::new(&format!("the given object cannot be used as a `{expected}`: {reason}")).into()
+}
@@ -0,0 +1,57 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_iota::iota::rebased::migration::CoinBalance;
+use wasm_bindgen::prelude::*;
+
+/// A single coin owned by an {@link OnChainIdentity}.
+#[derive(Clone)]
+#[wasm_bindgen(js_name = IdentityCoin, inspectable, getter_with_clone)]
+pub struct WasmIdentityCoin {
+  pub id: String,
+  pub balance: u64,
+}
+
+/// The funds of a given coin type owned by an {@link OnChainIdentity}.
+#[derive(Clone)]
+#[wasm_bindgen(js_name = CoinBalance)]
+pub struct WasmCoinBalance(pub(crate) CoinBalance);
+
+#[wasm_bindgen(js_class = CoinBalance)]
+impl WasmCoinBalance {
+  /// The type `T` of the `Coin<T>` objects making up this balance.
+  #[wasm_bindgen(js_name = coinType, getter)]
+  pub fn coin_type(&self) -> String {
+    self.0.coin_type().to_string()
+  }
+
+  /// The sum of the balances of all coins of this type.
+  #[wasm_bindgen(js_name = totalBalance, getter)]
+  pub fn total_balance(&self) -> u64 {
+    self.0.total_balance()
+  }
+
+  /// The coins making up this balance, sorted from the largest to the smallest.
+  #[wasm_bindgen(getter)]
+  pub fn coins(&self) -> Vec<WasmIdentityCoin> {
+    self
+      .0
+      .coins()
+      .iter()
+      .map(|coin| WasmIdentityCoin {
+        id: coin.id().to_string(),
+        balance: coin.balance(),
+      })
+      .collect()
+  }
+
+  /// Selects the IDs of the coins needed to cover `amount`, largest coins first.
+  /// Returns `undefined` if this balance is insufficient.
+  #[wasm_bindgen(js_name = selectCoins)]
+  pub fn select_coins(&self, amount: u64) -> Option<Vec<String>> {
+    self
+      .0
+      .select_coins(amount)
+      .map(|coins| coins.iter().map(ToString::to_string).collect())
+  }
+}
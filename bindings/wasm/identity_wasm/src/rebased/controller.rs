@@ -1,6 +1,8 @@
 // Copyright 2020-2025 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::borrow::Cow;
+
 use identity_iota::iota::rebased::migration::ControllerCap;
 use identity_iota::iota::rebased::migration::ControllerToken;
 use identity_iota::iota::rebased::migration::DelegatePermissions;
@@ -12,18 +14,18 @@ use iota_interaction_ts::bindings::WasmIotaTransactionBlockEffects;
 use iota_interaction_ts::core_client::WasmCoreClientReadOnly;
 use iota_sdk_types::ObjectId;
 use js_sys::Object;
-use product_common::bindings::core_client::WasmManagedCoreClientReadOnly;
 use product_common::bindings::transaction::WasmTransactionBuilder;
 use product_common::core_client::CoreClientReadOnly;
 use product_common::transaction::transaction_builder::Transaction as _;
 use product_common::transaction::transaction_builder::TransactionBuilder;
 use wasm_bindgen::prelude::wasm_bindgen;
 use wasm_bindgen::JsCast as _;
-use wasm_bindgen::JsError;
 use wasm_bindgen::JsValue;
 
+use super::client_compat::managed_core_client;
 use crate::error::wasm_error;
 use crate::error::Result;
+use crate::error::WasmError;
 use crate::error::WasmResult;
 
 use super::WasmIdentityClientReadOnly;
@@ -75,8 +77,8 @@ impl WasmControllerToken {
 
   #[wasm_bindgen(js_name = getById)]
   pub async fn get_by_id(id: &str, client: &WasmCoreClientReadOnly) -> Result<Self> {
-    let id = id.parse::<ObjectId>().map_err(|e| JsError::new(&e.to_string()))?;
-    let client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let id = id.parse::<ObjectId>().wasm_result()?;
+    let client = managed_core_client(client)?;
     client.get_object_by_id(id).await.map(WasmControllerToken).wasm_result()
   }
 }
@@ -135,7 +137,12 @@ impl WasmControllerCap {
       .map(TransactionBuilder::into_inner)
       .map(WasmDelegateToken)
       .map(JsValue::from)
-      .ok_or_else(|| JsError::new("this controller cannot delegate its authority"))?;
+      .ok_or_else(|| {
+        WasmError::new(
+          Cow::Borrowed("NonDelegableController"),
+          Cow::Borrowed("this controller cannot delegate its authority"),
+        )
+      })?;
 
     Ok(WasmTransactionBuilder::new(js_tx.unchecked_into()))
   }
@@ -196,7 +203,7 @@ impl WasmDelegateToken {
 
   #[wasm_bindgen(js_name = buildProgrammableTransaction)]
   pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let pt = self
       .0
       .build_programmable_transaction(&managed_client)
@@ -211,7 +218,7 @@ impl WasmDelegateToken {
     wasm_effects: &WasmIotaTransactionBlockEffects,
     client: &WasmCoreClientReadOnly,
   ) -> Result<WasmDelegationToken> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let mut effects = wasm_effects.clone().into();
     let apply_result = self.0.apply(&mut effects, &managed_client).await;
     let rem_wasm_effects = WasmIotaTransactionBlockEffects::from(&effects);
@@ -302,7 +309,7 @@ impl WasmDeleteDelegationToken {
 
   #[wasm_bindgen(js_name = buildProgrammableTransaction)]
   pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let pt = self
       .0
       .build_programmable_transaction(&managed_client)
@@ -317,7 +324,7 @@ impl WasmDeleteDelegationToken {
     wasm_effects: &WasmIotaTransactionBlockEffects,
     client: &WasmCoreClientReadOnly,
   ) -> Result<()> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let mut effects = wasm_effects.clone().into();
     let apply_result = self.0.apply(&mut effects, &managed_client).await;
     let rem_wasm_effects = WasmIotaTransactionBlockEffects::from(&effects);
@@ -0,0 +1,104 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_iota::iota::rebased::gas::GasEstimate;
+use identity_iota::iota::rebased::gas::GasEstimation;
+use iota_interaction_ts::core_client::WasmCoreClientReadOnly;
+use iota_sdk_types::Address;
+use iota_sdk_types::ProgrammableTransaction;
+use js_sys::Function;
+use js_sys::Promise;
+use js_sys::Reflect;
+use js_sys::Uint8Array;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast as _;
+use wasm_bindgen_futures::JsFuture;
+
+use super::client_compat::managed_core_client;
+use super::WasmIotaAddress;
+use crate::error::Result;
+use crate::error::WasmResult;
+
+/// The result of a gas estimation.
+#[wasm_bindgen(js_name = GasEstimate, inspectable)]
+#[derive(Clone, Copy)]
+pub struct WasmGasEstimate(pub(crate) GasEstimate);
+
+#[wasm_bindgen(js_class = GasEstimate)]
+impl WasmGasEstimate {
+  /// The computation cost of the dry-run transaction.
+  #[wasm_bindgen(getter, js_name = computationCost)]
+  pub fn computation_cost(&self) -> u64 {
+    self.0.computation_cost
+  }
+
+  /// The storage cost of the dry-run transaction.
+  #[wasm_bindgen(getter, js_name = storageCost)]
+  pub fn storage_cost(&self) -> u64 {
+    self.0.storage_cost
+  }
+
+  /// The storage rebate of the dry-run transaction.
+  #[wasm_bindgen(getter, js_name = storageRebate)]
+  pub fn storage_rebate(&self) -> u64 {
+    self.0.storage_rebate
+  }
+
+  /// The gas budget the transaction requires, without safety margin.
+  #[wasm_bindgen(getter)]
+  pub fn required(&self) -> u64 {
+    self.0.required
+  }
+
+  /// The suggested gas budget, including the safety margin.
+  /// Pass it to `TransactionBuilder.withGasBudget`.
+  #[wasm_bindgen(getter)]
+  pub fn budget(&self) -> u64 {
+    self.0.budget
+  }
+
+  /// Throws an `InsufficientGas` error if `budget` doesn't cover the gas the transaction requires.
+  #[wasm_bindgen(js_name = checkBudget)]
+  pub fn check_budget(&self, budget: u64) -> Result<()> {
+    self.0.check_budget(budget).wasm_result()
+  }
+}
+
+/// Estimates the gas budget of `tx` when sent by `sender`, by dry-running it.
+///
+/// The estimated gas is multiplied by `safetyMultiplier`, which defaults to `1.2` and must be at least `1`.
+#[wasm_bindgen(js_name = estimateGasBudget)]
+pub async fn estimate_gas_budget(
+  #[wasm_bindgen(unchecked_param_type = "Transaction<unknown>")] tx: JsValue,
+  client: &WasmCoreClientReadOnly,
+  sender: WasmIotaAddress,
+  safety_multiplier: Option<f64>,
+) -> Result<WasmGasEstimate> {
+  let sender = sender.parse::<Address>().wasm_result()?;
+  let estimation = match safety_multiplier {
+    Some(multiplier) => GasEstimation::new().with_safety_multiplier(multiplier).wasm_result()?,
+    None => GasEstimation::new(),
+  };
+  let pt = build_programmable_transaction(&tx, client).await?;
+  let managed_client = managed_core_client(client)?;
+
+  estimation
+    .estimate_programmable_transaction(pt, sender, &managed_client)
+    .await
+    .map(WasmGasEstimate)
+    .wasm_result()
+}
+
+/// Calls `buildProgrammableTransaction` on the JS transaction `tx`.
+async fn build_programmable_transaction(
+  tx: &JsValue,
+  client: &WasmCoreClientReadOnly,
+) -> Result<ProgrammableTransaction> {
+  let build_fn = Reflect::get(tx, &JsValue::from_str("buildProgrammableTransaction"))?
+    .dyn_into::<Function>()
+    .map_err(|_| js_sys::TypeError::new("transaction doesn't implement `buildProgrammableTransaction`"))?;
+  let promise = build_fn.call1(tx, client.as_ref())?.dyn_into::<Promise>()?;
+  let pt_bytes = JsFuture::from(promise).await?.dyn_into::<Uint8Array>()?.to_vec();
+
+  bcs::from_bytes(&pt_bytes).wasm_result()
+}
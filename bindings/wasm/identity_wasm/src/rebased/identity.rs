@@ -15,15 +15,16 @@ use iota_interaction_ts::wasm_error::WasmError;
 use iota_sdk_types::Address;
 use iota_sdk_types::ObjectId;
 use js_sys::Object;
-use product_common::bindings::core_client::WasmManagedCoreClientReadOnly;
 use product_common::bindings::transaction::WasmTransactionBuilder;
 use product_common::transaction::transaction_builder::Transaction;
 use tokio::sync::RwLock;
 use wasm_bindgen::prelude::*;
 
+use super::client_compat::managed_core_client;
 use crate::error::wasm_error;
 use crate::error::Result;
 use crate::error::WasmResult;
+use crate::iota::WasmIotaDID;
 use crate::iota::WasmIotaDocument;
 use crate::rebased::proposals::WasmAccessSubIdentityTx;
 use crate::rebased::proposals::WasmCreateBorrowProposal;
@@ -32,17 +33,21 @@ use crate::rebased::proposals::WasmCreateControllerExecutionProposal;
 use crate::rebased::proposals::WasmCreateUpdateDidProposal;
 use crate::rebased::WasmDeleteDelegationToken;
 
+use super::proposals::BorrowIntent;
 use super::proposals::StringCouple;
 use super::proposals::WasmBorrowFn;
 use super::proposals::WasmConfigChange;
 use super::proposals::WasmControllerExecutionFn;
 use super::proposals::WasmCreateSendProposal;
 use super::proposals::WasmSubAccessFn;
+use super::WasmCoinBalance;
 use super::WasmControllerCap;
 use super::WasmControllerToken;
 use super::WasmDelegationToken;
 use super::WasmDelegationTokenRevocation;
 use super::WasmIdentityClient;
+use super::WasmIdentityClientReadOnly;
+use super::WasmIdentityOwnedObject;
 use super::WasmIotaAddress;
 
 // Helper type for `WasmIdentityBuilder::controllers`.
@@ -67,8 +72,8 @@ pub struct WasmOnChainIdentity(pub(crate) Rc<RwLock<OnChainIdentity>>);
 impl WasmOnChainIdentity {
   #[wasm_bindgen(js_name = getById)]
   pub async fn get_by_id(id: String, client: &WasmCoreClientReadOnly) -> Result<Self> {
-    let client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
-    let id = id.parse::<ObjectId>().map_err(|e| JsError::new(&e.to_string()))?;
+    let client = managed_core_client(client)?;
+    let id = id.parse::<ObjectId>().wasm_result()?;
 
     get_identity(&client, id)
       .await
@@ -107,6 +112,20 @@ impl WasmOnChainIdentity {
       .map(|identity| identity.has_deleted_did())
   }
 
+  /// Returns the version of the `IotaIdentity` package this {@link OnChainIdentity} has been migrated to.
+  #[wasm_bindgen]
+  pub fn version(&self) -> Result<u64> {
+    Ok(self.0.try_read().wasm_result()?.version())
+  }
+
+  /// Returns whether this {@link OnChainIdentity} must be migrated to the latest version of the `IotaIdentity`
+  /// package, e.g. after an on-chain package upgrade.
+  #[wasm_bindgen(js_name = needsMigration)]
+  pub async fn needs_migration(&self, client: &WasmCoreClientReadOnly) -> Result<bool> {
+    let client = managed_core_client(client)?;
+    self.0.read().await.needs_migration(&client).await.wasm_result()
+  }
+
   #[wasm_bindgen(js_name = isShared)]
   pub fn is_shared(&self) -> Result<bool> {
     Ok(self.0.try_read().wasm_result()?.is_shared())
@@ -131,7 +150,7 @@ impl WasmOnChainIdentity {
     address: &str,
     client: &WasmIdentityClient,
   ) -> Result<Option<WasmControllerToken>> {
-    let address = address.parse::<Address>().map_err(|e| JsError::new(&e.to_string()))?;
+    let address = address.parse::<Address>().wasm_result()?;
     let maybe_controller_token = self
       .0
       .read()
@@ -143,6 +162,34 @@ impl WasmOnChainIdentity {
     Ok(maybe_controller_token)
   }
 
+  /// Returns the DIDs of the identities this identity controls.
+  #[wasm_bindgen(js_name = controlledIdentities)]
+  pub async fn controlled_identities(&self, client: &WasmIdentityClientReadOnly) -> Result<Vec<WasmIotaDID>> {
+    let dids = self
+      .0
+      .read()
+      .await
+      .controlled_identities(&client.0)
+      .await
+      .wasm_result()?;
+    Ok(dids.into_iter().map(WasmIotaDID).collect())
+  }
+
+  /// Returns the balances of all the coins owned by this identity, grouped by coin type.
+  pub async fn balances(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<WasmCoinBalance>> {
+    let client = managed_core_client(client)?;
+    let balances = self.0.read().await.balances(&client).await.wasm_result()?;
+    Ok(balances.into_iter().map(WasmCoinBalance).collect())
+  }
+
+  /// Returns all the objects owned by this identity, together with their decoded kind.
+  #[wasm_bindgen(js_name = ownedObjects)]
+  pub async fn owned_objects(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<WasmIdentityOwnedObject>> {
+    let client = managed_core_client(client)?;
+    let objects = self.0.read().await.owned_objects(&client).await.wasm_result()?;
+    Ok(objects.into_iter().map(WasmIdentityOwnedObject::from).collect())
+  }
+
   #[wasm_bindgen(skip_typescript)] // ts type in custom section below
   pub fn proposals(&self) -> Result<JsValue> {
     let lock = self.0.try_read().wasm_result()?;
@@ -173,8 +220,39 @@ impl WasmOnChainIdentity {
     &self,
     controller_token: &WasmControllerToken,
     expiration_epoch: Option<u64>,
+    allow_pending_proposals: Option<bool>,
+  ) -> WasmTransactionBuilder {
+    let create_proposal_tx = WasmCreateUpdateDidProposal::deactivate(
+      self,
+      controller_token.clone(),
+      expiration_epoch,
+      allow_pending_proposals.unwrap_or_default(),
+    );
+    WasmTransactionBuilder::new(JsValue::from(create_proposal_tx).unchecked_into())
+  }
+
+  /// Reactivates this identity's deactivated DID Document, replacing it with `document`.
+  ///
+  /// Creating the proposal fails if the DID Document is not deactivated or if this identity has pending proposals,
+  /// unless `allowPendingProposals` is set to `true`.
+  #[wasm_bindgen(
+    js_name = reactivateDid,
+    unchecked_return_type = "TransactionBuilder<CreateProposal<UpdateDid>>",
+  )]
+  pub fn reactivate_did(
+    &self,
+    document: &WasmIotaDocument,
+    controller_token: &WasmControllerToken,
+    expiration_epoch: Option<u64>,
+    allow_pending_proposals: Option<bool>,
   ) -> WasmTransactionBuilder {
-    let create_proposal_tx = WasmCreateUpdateDidProposal::deactivate(self, controller_token.clone(), expiration_epoch);
+    let create_proposal_tx = WasmCreateUpdateDidProposal::reactivate(
+      self,
+      document.clone(),
+      controller_token.clone(),
+      expiration_epoch,
+      allow_pending_proposals.unwrap_or_default(),
+    );
     WasmTransactionBuilder::new(JsValue::from(create_proposal_tx).unchecked_into())
   }
 
@@ -225,6 +303,96 @@ impl WasmOnChainIdentity {
     Ok(WasmTransactionBuilder::new(JsValue::from(tx).unchecked_into()))
   }
 
+  /// Proposes to send the object with ID `object_id` owned by this identity to `recipient`.
+  #[wasm_bindgen(
+    js_name = sendAsset,
+    unchecked_return_type = "TransactionBuilder<CreateProposal<SendAction>>",
+  )]
+  pub fn send_asset(
+    &self,
+    controller_token: &WasmControllerToken,
+    object_id: String,
+    recipient: WasmIotaAddress,
+    expiration_epoch: Option<u64>,
+  ) -> Result<WasmTransactionBuilder> {
+    self.send_assets(
+      controller_token,
+      vec![StringCouple::from((object_id, recipient))],
+      expiration_epoch,
+    )
+  }
+
+  /// Proposes to send `amount` out of the coins in `balance` to `recipient`.
+  /// The coins needed to cover `amount` are selected automatically, largest first.
+  #[wasm_bindgen(
+    js_name = sendAmount,
+    unchecked_return_type = "TransactionBuilder<CreateProposal<Borrow>>",
+  )]
+  pub fn send_amount(
+    &self,
+    controller_token: &WasmControllerToken,
+    balance: &WasmCoinBalance,
+    amount: u64,
+    recipient: WasmIotaAddress,
+    expiration_epoch: Option<u64>,
+  ) -> Result<WasmTransactionBuilder> {
+    let recipient = recipient.parse::<Address>().wasm_result()?;
+    if amount == 0 {
+      return Err(RebasedError::InvalidArgument(
+        "cannot send an amount of zero".to_owned(),
+      ))
+      .wasm_result();
+    }
+    let objects = balance
+      .0
+      .select_coins(amount)
+      .ok_or_else(|| {
+        RebasedError::InvalidArgument(format!(
+          "insufficient funds: {} of coin type `{}` available, but {amount} was requested",
+          balance.0.total_balance(),
+          balance.0.coin_type(),
+        ))
+      })
+      .wasm_result()?;
+    let tx = JsValue::from(WasmCreateBorrowProposal::new(
+      self,
+      controller_token,
+      objects,
+      Some(BorrowIntent::SendAmount { amount, recipient }),
+      expiration_epoch,
+    ));
+    Ok(WasmTransactionBuilder::new(tx.unchecked_into()))
+  }
+
+  /// Proposes to merge the balances of all coins in `balance` into the largest one.
+  #[wasm_bindgen(
+    js_name = mergeCoins,
+    unchecked_return_type = "TransactionBuilder<CreateProposal<Borrow>>",
+  )]
+  pub fn merge_coins(
+    &self,
+    controller_token: &WasmControllerToken,
+    balance: &WasmCoinBalance,
+    expiration_epoch: Option<u64>,
+  ) -> Result<WasmTransactionBuilder> {
+    if balance.0.coins().len() < 2 {
+      return Err(RebasedError::InvalidArgument(format!(
+        "at least two coins of type `{}` are required for merging",
+        balance.0.coin_type()
+      )))
+      .wasm_result();
+    }
+    let objects = balance.0.coins().iter().map(|coin| coin.id()).collect();
+    let tx = JsValue::from(WasmCreateBorrowProposal::new(
+      self,
+      controller_token,
+      objects,
+      Some(BorrowIntent::MergeCoins),
+      expiration_epoch,
+    ));
+    Ok(WasmTransactionBuilder::new(tx.unchecked_into()))
+  }
+
   #[wasm_bindgen(
     js_name = revokeDelegationToken,
     unchecked_return_type = "TransactionBuilder<DelegationTokenRevocation>",
@@ -270,13 +438,13 @@ impl WasmOnChainIdentity {
   ) -> Result<WasmTransactionBuilder> {
     let objects = objects
       .into_iter()
-      .map(|s| s.parse().map_err(|e| JsError::from(e).into()))
+      .map(|s| s.parse::<ObjectId>().wasm_result())
       .collect::<Result<Vec<ObjectId>>>()?;
     let tx = JsValue::from(WasmCreateBorrowProposal::new(
       self,
       controller_token,
       objects,
-      borrow_fn,
+      borrow_fn.map(Into::into),
       expiration_epoch,
     ));
     Ok(WasmTransactionBuilder::new(tx.unchecked_into()))
@@ -292,8 +460,8 @@ impl WasmOnChainIdentity {
     controller_cap: &str,
     exec_fn: Option<WasmControllerExecutionFn>,
     expiration_epoch: Option<u64>,
-  ) -> std::result::Result<WasmTransactionBuilder, JsError> {
-    let controller_cap = controller_cap.parse()?;
+  ) -> Result<WasmTransactionBuilder> {
+    let controller_cap = controller_cap.parse::<ObjectId>().wasm_result()?;
     let tx = JsValue::from(WasmCreateControllerExecutionProposal::new(
       self,
       controller_token,
@@ -380,7 +548,7 @@ impl WasmCreateIdentity {
 
   #[wasm_bindgen(js_name = buildProgrammableTransaction)]
   pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let pt = self
       .0
       .build_programmable_transaction(&managed_client)
@@ -395,7 +563,7 @@ impl WasmCreateIdentity {
     wasm_effects: &WasmIotaTransactionBlockEffects,
     client: &WasmCoreClientReadOnly,
   ) -> Result<WasmOnChainIdentity> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let mut effects = wasm_effects.clone().into();
     let apply_result = self.0.apply(&mut effects, &managed_client).await;
     let rem_wasm_effects = WasmIotaTransactionBlockEffects::from(&effects);
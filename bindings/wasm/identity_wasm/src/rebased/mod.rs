@@ -1,14 +1,25 @@
 // Copyright 2020-2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+mod asset;
+mod client_compat;
+mod coins;
 mod controller;
+mod gas;
 mod identity;
+mod owned_objects;
 mod proposals;
+mod wallet_signer;
 mod wasm_identity_client;
 mod wasm_identity_client_read_only;
 
+pub use asset::*;
+pub use coins::*;
 pub use controller::*;
+pub use gas::*;
 pub use identity::*;
+pub use owned_objects::*;
+pub use wallet_signer::*;
 pub use wasm_identity_client::*;
 pub use wasm_identity_client_read_only::*;
 
@@ -0,0 +1,42 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_iota::iota::rebased::migration::IdentityOwnedObject;
+use identity_iota::iota::rebased::migration::OwnedObjectKind;
+use wasm_bindgen::prelude::*;
+
+/// An object owned by an {@link OnChainIdentity}.
+#[derive(Clone)]
+#[wasm_bindgen(js_name = IdentityOwnedObject, inspectable, getter_with_clone)]
+pub struct WasmIdentityOwnedObject {
+  /// The object's ID.
+  pub id: String,
+  /// The object's Move type.
+  #[wasm_bindgen(js_name = objectType)]
+  pub object_type: String,
+  /// The decoded kind of the object, one of: "Coin", "AuthenticatedAsset",
+  /// "ControllerCap", "DelegationToken", "Other".
+  pub kind: String,
+  /// For coins, the coin's type. For authenticated assets, the type of the asset's content.
+  #[wasm_bindgen(js_name = innerType)]
+  pub inner_type: Option<String>,
+}
+
+impl From<IdentityOwnedObject> for WasmIdentityOwnedObject {
+  fn from(value: IdentityOwnedObject) -> Self {
+    let (kind, inner_type) = match value.kind() {
+      OwnedObjectKind::Coin { coin_type } => ("Coin", Some(coin_type.to_string())),
+      OwnedObjectKind::AuthenticatedAsset { content_type } => ("AuthenticatedAsset", Some(content_type.to_string())),
+      OwnedObjectKind::ControllerCap => ("ControllerCap", None),
+      OwnedObjectKind::DelegationToken => ("DelegationToken", None),
+      _ => ("Other", None),
+    };
+
+    Self {
+      id: value.id().to_string(),
+      object_type: value.object_type().to_string(),
+      kind: kind.to_owned(),
+      inner_type,
+    }
+  }
+}
@@ -1,14 +1,18 @@
 // Copyright 2020-2025 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::ops::Deref;
 use std::ops::DerefMut;
 use std::rc::Rc;
 use std::result::Result as StdResult;
 
+use identity_iota::iota::rebased::migration::merge_coins_intent;
+use identity_iota::iota::rebased::migration::send_amount_intent;
 use identity_iota::iota::rebased::migration::Proposal;
 use identity_iota::iota::rebased::proposals::BorrowAction;
+use identity_iota::iota::rebased::proposals::BorrowIntentFn;
 use identity_iota::iota::rebased::proposals::BorrowIntentFnT;
 use identity_iota::iota::rebased::proposals::ProposalResult;
 use identity_iota::iota::rebased::proposals::ProposalT as _;
@@ -17,10 +21,10 @@ use iota_interaction::types::programmable_transaction_builder::ProgrammableTrans
 use iota_interaction_ts::bindings::WasmIotaTransactionBlockEffects;
 use iota_interaction_ts::core_client::WasmCoreClientReadOnly;
 use iota_sdk_types::transaction::TransactionKind;
+use iota_sdk_types::Address;
 use iota_sdk_types::Argument;
 use iota_sdk_types::ObjectId;
 use iota_sdk_types::ProgrammableTransaction;
-use product_common::bindings::core_client::WasmManagedCoreClientReadOnly;
 use product_common::bindings::transaction::WasmTransactionBuilder;
 use product_common::core_client::CoreClientReadOnly;
 use product_common::transaction::transaction_builder::Transaction as _;
@@ -29,8 +33,11 @@ use tokio::sync::RwLock;
 use wasm_bindgen::prelude::*;
 
 use crate::error::Result;
+use crate::error::WasmError;
 use crate::error::WasmResult as _;
+use crate::rebased::client_compat::managed_core_client;
 use crate::rebased::WasmControllerToken;
+use crate::rebased::WasmIotaAddress;
 use crate::rebased::WasmOnChainIdentity;
 
 use super::StringSet;
@@ -96,6 +103,33 @@ impl WasmBorrowFn {
   }
 }
 
+/// How the objects borrowed through a proposal are going to be used.
+#[derive(Clone)]
+pub(crate) enum BorrowIntent {
+  /// A user-provided JS closure.
+  Js(WasmBorrowFn),
+  /// Send `amount` out of the borrowed coins to `recipient`.
+  SendAmount { amount: u64, recipient: Address },
+  /// Merge the balances of all borrowed coins into the largest one.
+  MergeCoins,
+}
+
+impl BorrowIntent {
+  pub(crate) fn into_intent_fn(self) -> BorrowIntentFn {
+    match self {
+      Self::Js(borrow_fn) => Box::new(borrow_fn.into_intent_fn()),
+      Self::SendAmount { amount, recipient } => Box::new(send_amount_intent(amount, recipient)),
+      Self::MergeCoins => Box::new(merge_coins_intent()),
+    }
+  }
+}
+
+impl From<WasmBorrowFn> for BorrowIntent {
+  fn from(value: WasmBorrowFn) -> Self {
+    Self::Js(value)
+  }
+}
+
 #[derive(Clone)]
 #[wasm_bindgen(js_name = Borrow, inspectable, getter_with_clone)]
 pub struct WasmBorrow {
@@ -117,11 +151,12 @@ impl From<WasmBorrow> for BorrowAction {
 #[wasm_bindgen(js_class = Borrow)]
 impl WasmBorrow {
   #[wasm_bindgen(constructor)]
-  pub fn new(objects: Vec<String>, borrow_fn: Option<WasmBorrowFn>) -> StdResult<Self, JsError> {
+  pub fn new(objects: Vec<String>, borrow_fn: Option<WasmBorrowFn>) -> Result<Self> {
     let objects = objects
       .iter()
       .map(|s| s.parse::<ObjectId>())
-      .collect::<StdResult<Vec<_>, _>>()?;
+      .collect::<StdResult<Vec<_>, _>>()
+      .wasm_result()?;
 
     Ok(Self { borrow_fn, objects })
   }
@@ -134,7 +169,7 @@ impl WasmBorrow {
 
 struct Internal {
   proposal: Proposal<BorrowAction>,
-  borrow_fn: Option<WasmBorrowFn>,
+  borrow_fn: Option<BorrowIntent>,
 }
 
 impl Deref for Internal {
@@ -156,7 +191,7 @@ pub struct WasmProposalBorrow(Rc<RwLock<Internal>>);
 
 #[wasm_bindgen(js_class = BorrowProposal)]
 impl WasmProposalBorrow {
-  fn new(proposal: Proposal<BorrowAction>) -> Self {
+  pub(crate) fn new(proposal: Proposal<BorrowAction>) -> Self {
     Self(Rc::new(RwLock::new(Internal {
       proposal,
       borrow_fn: None,
@@ -216,7 +251,24 @@ impl WasmProposalBorrow {
 
   #[wasm_bindgen(js_name = borrowFn, setter)]
   pub fn set_intent_fn(&self, borrow_fn: WasmBorrowFn) -> Result<()> {
-    self.0.try_write().wasm_result()?.borrow_fn = Some(borrow_fn);
+    self.0.try_write().wasm_result()?.borrow_fn = Some(borrow_fn.into());
+    Ok(())
+  }
+
+  /// Sets this proposal's intent to sending `amount` out of the borrowed coins to `recipient`.
+  /// To be used when executing a proposal created through `OnChainIdentity.sendAmount`.
+  #[wasm_bindgen(js_name = setSendAmountIntent)]
+  pub fn set_send_amount_intent(&self, amount: u64, recipient: WasmIotaAddress) -> Result<()> {
+    let recipient = recipient.parse::<Address>().wasm_result()?;
+    self.0.try_write().wasm_result()?.borrow_fn = Some(BorrowIntent::SendAmount { amount, recipient });
+    Ok(())
+  }
+
+  /// Sets this proposal's intent to merging the borrowed coins.
+  /// To be used when executing a proposal created through `OnChainIdentity.mergeCoins`.
+  #[wasm_bindgen(js_name = setMergeCoinsIntent)]
+  pub fn set_merge_coins_intent(&self) -> Result<()> {
+    self.0.try_write().wasm_result()?.borrow_fn = Some(BorrowIntent::MergeCoins);
     Ok(())
   }
 
@@ -267,7 +319,7 @@ impl WasmApproveBorrowProposal {
 
   #[wasm_bindgen(js_name = buildProgrammableTransaction)]
   pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let mut proposal = self.proposal.0.write().await;
     let identity = self.identity.0.read().await;
     let tx = proposal
@@ -283,7 +335,7 @@ impl WasmApproveBorrowProposal {
     wasm_effects: &WasmIotaTransactionBlockEffects,
     client: &WasmCoreClientReadOnly,
   ) -> Result<()> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let mut proposal = self.proposal.0.write().await;
     let identity = self.identity.0.read().await;
     let tx = proposal
@@ -322,7 +374,7 @@ impl WasmExecuteBorrowProposal {
 
   #[wasm_bindgen(js_name = buildProgrammableTransaction)]
   pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let proposal_id = self.proposal.0.read().await.id();
     let mut proposal = managed_client.get_object_by_id(proposal_id).await.wasm_result()?;
     let borrow_fn = {
@@ -331,14 +383,20 @@ impl WasmExecuteBorrowProposal {
       guard.borrow_fn.clone()
     };
     let Some(borrow_fn) = borrow_fn else {
-      return Err(JsError::new("cannot execute this borrow proposal without a `borrowFn`").into());
+      return Err(
+        WasmError::new(
+          Cow::Borrowed("MissingBorrowFn"),
+          Cow::Borrowed("cannot execute this borrow proposal without a `borrowFn`"),
+        )
+        .into(),
+      );
     };
     let mut identity = self.identity.0.write().await;
     let tx = proposal
       .into_tx(&mut identity, &self.controller_token.0, &managed_client)
       .await
       .wasm_result()?
-      .with(Box::new(borrow_fn.into_intent_fn()))
+      .with(borrow_fn.into_intent_fn())
       .into_inner()
       .build_programmable_transaction(&managed_client)
       .await
@@ -351,7 +409,7 @@ impl WasmExecuteBorrowProposal {
     wasm_effects: &WasmIotaTransactionBlockEffects,
     client: &WasmCoreClientReadOnly,
   ) -> Result<()> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let proposal_id = self.proposal.0.read().await.id();
     let proposal = managed_client
       .get_object_by_id::<Proposal<BorrowAction>>(proposal_id)
@@ -379,7 +437,7 @@ pub struct WasmCreateBorrowProposal {
   identity: WasmOnChainIdentity,
   controller_token: WasmControllerToken,
   expiration_epoch: Option<u64>,
-  borrow_fn: Option<WasmBorrowFn>,
+  borrow_fn: Option<BorrowIntent>,
   objects: Vec<ObjectId>,
 }
 
@@ -389,7 +447,7 @@ impl WasmCreateBorrowProposal {
     identity: &WasmOnChainIdentity,
     controller_token: &WasmControllerToken,
     objects: Vec<ObjectId>,
-    borrow_fn: Option<WasmBorrowFn>,
+    borrow_fn: Option<BorrowIntent>,
     expiration_epoch: Option<u64>,
   ) -> Self {
     Self {
@@ -403,14 +461,14 @@ impl WasmCreateBorrowProposal {
 
   #[wasm_bindgen(js_name = buildProgrammableTransaction)]
   pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let mut identity_lock = self.identity.0.write().await;
     let mut builder = identity_lock
       .borrow_assets(&self.controller_token.0)
       .borrow_objects(self.objects.iter().copied());
 
     if let Some(borrow_fn) = self.borrow_fn.clone() {
-      builder = builder.with_intent(Box::new(borrow_fn.into_intent_fn()))
+      builder = builder.with_intent(borrow_fn.into_intent_fn())
     }
 
     if let Some(expiration) = self.expiration_epoch {
@@ -427,14 +485,14 @@ impl WasmCreateBorrowProposal {
     wasm_effects: &WasmIotaTransactionBlockEffects,
     client: &WasmCoreClientReadOnly,
   ) -> Result<Option<WasmProposalBorrow>> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let mut identity_lock = self.identity.0.write().await;
     let mut builder = identity_lock
       .borrow_assets(&self.controller_token.0)
       .borrow_objects(self.objects.iter().copied());
 
     if let Some(borrow_fn) = self.borrow_fn.clone() {
-      builder = builder.with_intent(Box::new(borrow_fn.into_intent_fn()))
+      builder = builder.with_intent(borrow_fn.into_intent_fn())
     }
 
     if let Some(expiration) = self.expiration_epoch {
@@ -12,7 +12,6 @@ use identity_iota::iota::rebased::proposals::ProposalT;
 use iota_interaction_ts::bindings::WasmIotaTransactionBlockEffects;
 use iota_interaction_ts::core_client::WasmCoreClientReadOnly;
 use js_sys::Object;
-use product_common::bindings::core_client::WasmManagedCoreClientReadOnly;
 use product_common::transaction::transaction_builder::Transaction;
 use tokio::sync::RwLock;
 use wasm_bindgen::prelude::wasm_bindgen;
@@ -23,6 +22,7 @@ use super::MapStringNumber;
 use super::StringSet;
 use crate::error::Result;
 use crate::error::WasmResult;
+use crate::rebased::client_compat::managed_core_client;
 use crate::rebased::WasmControllerToken;
 use crate::rebased::WasmOnChainIdentity;
 use product_common::bindings::transaction::WasmTransactionBuilder;
@@ -175,7 +175,7 @@ impl WasmApproveConfigChangeProposal {
 
   #[wasm_bindgen(js_name = buildProgrammableTransaction)]
   pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let mut proposal = self.proposal.0.write().await;
     let identity = self.identity.0.read().await;
 
@@ -193,7 +193,7 @@ impl WasmApproveConfigChangeProposal {
     wasm_effects: &WasmIotaTransactionBlockEffects,
     client: &WasmCoreClientReadOnly,
   ) -> Result<()> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let mut proposal = self.proposal.0.write().await;
     let identity = self.identity.0.read().await;
     let tx = proposal
@@ -232,7 +232,7 @@ impl WasmExecuteConfigChangeProposal {
 
   #[wasm_bindgen(js_name = buildProgrammableTransaction)]
   pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let proposal = self.proposal.0.read().await.clone();
     let mut identity = self.identity.0.write().await;
 
@@ -251,7 +251,7 @@ impl WasmExecuteConfigChangeProposal {
     wasm_effects: &WasmIotaTransactionBlockEffects,
     client: &WasmCoreClientReadOnly,
   ) -> Result<()> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let proposal = self.proposal.0.read().await.clone();
     let mut identity = self.identity.0.write().await;
     let tx = proposal
@@ -306,7 +306,7 @@ impl WasmCreateConfigChangeProposal {
 
   #[wasm_bindgen(js_name = buildProgrammableTransaction)]
   pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let mut identity_ref = self.identity.0.write().await;
     let controllers_to_add = self
       .controllers_to_add
@@ -355,7 +355,7 @@ impl WasmCreateConfigChangeProposal {
     wasm_effects: &WasmIotaTransactionBlockEffects,
     client: &WasmCoreClientReadOnly,
   ) -> Result<Option<WasmConfigChangeProposal>> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let mut identity_ref = self.identity.0.write().await;
     let controllers_to_add = self
       .controllers_to_add
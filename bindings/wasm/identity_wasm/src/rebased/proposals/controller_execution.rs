@@ -1,10 +1,10 @@
 // Copyright 2020-2025 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::borrow::Cow;
 use std::ops::Deref;
 use std::ops::DerefMut;
 use std::rc::Rc;
-use std::result::Result as StdResult;
 
 use identity_iota::iota::rebased::migration::Proposal;
 use identity_iota::iota::rebased::proposals::ControllerExecution;
@@ -19,7 +19,6 @@ use iota_sdk_types::Address;
 use iota_sdk_types::Argument;
 use iota_sdk_types::ObjectId;
 use iota_sdk_types::ProgrammableTransaction;
-use product_common::bindings::core_client::WasmManagedCoreClientReadOnly;
 use product_common::bindings::transaction::WasmTransactionBuilder;
 use product_common::core_client::CoreClientReadOnly;
 use product_common::transaction::transaction_builder::Transaction as _;
@@ -28,7 +27,9 @@ use tokio::sync::RwLock;
 use wasm_bindgen::prelude::*;
 
 use crate::error::Result;
+use crate::error::WasmError;
 use crate::error::WasmResult as _;
+use crate::rebased::client_compat::managed_core_client;
 use crate::rebased::WasmControllerToken;
 use crate::rebased::WasmOnChainIdentity;
 
@@ -120,9 +121,9 @@ impl WasmControllerExecution {
     controller_cap: String,
     identity: &WasmOnChainIdentity,
     exec_fn: Option<WasmControllerExecutionFn>,
-  ) -> StdResult<Self, JsError> {
-    let controller_cap = controller_cap.parse()?;
-    let identity = identity.0.try_read()?.id().into();
+  ) -> Result<Self> {
+    let controller_cap = controller_cap.parse::<ObjectId>().wasm_result()?;
+    let identity = identity.0.try_read().wasm_result()?.id().into();
 
     Ok(Self {
       controller_cap,
@@ -286,7 +287,7 @@ impl WasmApproveControllerExecutionProposal {
 
   #[wasm_bindgen(js_name = buildProgrammableTransaction)]
   pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let mut proposal = self.proposal.0.write().await;
     let identity = self.identity.0.read().await;
     let tx = proposal
@@ -302,7 +303,7 @@ impl WasmApproveControllerExecutionProposal {
     wasm_effects: &WasmIotaTransactionBlockEffects,
     client: &WasmCoreClientReadOnly,
   ) -> Result<()> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let mut proposal = self.proposal.0.write().await;
     let identity = self.identity.0.read().await;
     let tx = proposal
@@ -341,7 +342,7 @@ impl WasmExecuteControllerExecutionProposal {
 
   #[wasm_bindgen(js_name = buildProgrammableTransaction)]
   pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let proposal_id = self.proposal.0.read().await.id();
     let mut proposal = managed_client.get_object_by_id(proposal_id).await.wasm_result()?;
     let exec_fn = {
@@ -350,7 +351,13 @@ impl WasmExecuteControllerExecutionProposal {
       guard.exec_fn.clone()
     };
     let Some(exec_fn) = exec_fn else {
-      return Err(JsError::new("cannot execute this controller execution proposal without an `execFn`").into());
+      return Err(
+        WasmError::new(
+          Cow::Borrowed("MissingExecFn"),
+          Cow::Borrowed("cannot execute this controller execution proposal without an `execFn`"),
+        )
+        .into(),
+      );
     };
     let mut identity = self.identity.0.write().await;
     let tx = proposal
@@ -370,7 +377,7 @@ impl WasmExecuteControllerExecutionProposal {
     wasm_effects: &WasmIotaTransactionBlockEffects,
     client: &WasmCoreClientReadOnly,
   ) -> Result<()> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let proposal_id = self.proposal.0.read().await.id();
     let proposal = managed_client
       .get_object_by_id::<Proposal<ControllerExecution>>(proposal_id)
@@ -422,7 +429,7 @@ impl WasmCreateControllerExecutionProposal {
 
   #[wasm_bindgen(js_name = buildProgrammableTransaction)]
   pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let mut identity_lock = self.identity.0.write().await;
     let mut builder = identity_lock.controller_execution(self.controller_cap, &self.controller_token.0);
 
@@ -444,7 +451,7 @@ impl WasmCreateControllerExecutionProposal {
     wasm_effects: &WasmIotaTransactionBlockEffects,
     client: &WasmCoreClientReadOnly,
   ) -> Result<Option<WasmProposalControllerExecution>> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let mut identity_lock = self.identity.0.write().await;
     let mut builder = identity_lock.controller_execution(self.controller_cap, &self.controller_token.0);
 
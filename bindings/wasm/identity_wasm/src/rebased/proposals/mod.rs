@@ -7,6 +7,7 @@ mod controller_execution;
 mod send;
 mod sub_access;
 mod update_did;
+mod upgrade;
 
 pub use borrow::*;
 pub use config_change::*;
@@ -14,6 +15,7 @@ pub use controller_execution::*;
 pub use send::*;
 pub use sub_access::*;
 pub use update_did::*;
+pub use upgrade::*;
 
 use std::collections::HashMap;
 use std::collections::HashSet;
@@ -10,7 +10,6 @@ use identity_iota::iota::rebased::proposals::SendAction;
 use iota_interaction_ts::bindings::WasmIotaTransactionBlockEffects;
 use iota_interaction_ts::core_client::WasmCoreClientReadOnly;
 use js_sys::Object;
-use product_common::bindings::core_client::WasmManagedCoreClientReadOnly;
 use product_common::transaction::transaction_builder::Transaction;
 use tokio::sync::RwLock;
 use wasm_bindgen::prelude::wasm_bindgen;
@@ -21,6 +20,7 @@ use super::StringCouple;
 use super::StringSet;
 use crate::error::Result;
 use crate::error::WasmResult;
+use crate::rebased::client_compat::managed_core_client;
 use crate::rebased::WasmControllerToken;
 use crate::rebased::WasmOnChainIdentity;
 use product_common::bindings::transaction::WasmTransactionBuilder;
@@ -143,7 +143,7 @@ impl WasmApproveSendProposal {
 
   #[wasm_bindgen(js_name = buildProgrammableTransaction)]
   pub async fn build_programmable_transaction(self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let identity_ref = self.identity.0.read().await;
     let mut proposal_ref = self.proposal.0.write().await;
     let tx = proposal_ref
@@ -161,7 +161,7 @@ impl WasmApproveSendProposal {
     wasm_effects: &WasmIotaTransactionBlockEffects,
     client: &WasmCoreClientReadOnly,
   ) -> Result<()> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let identity_ref = self.identity.0.read().await;
     let mut proposal_ref = self.proposal.0.write().await;
     let tx = proposal_ref
@@ -197,7 +197,7 @@ impl WasmExecuteSendProposal {
 
   #[wasm_bindgen(js_name = buildProgrammableTransaction)]
   pub async fn build_programmable_transaction(self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let mut identity_ref = self.identity.0.write().await;
     let proposal = self.proposal.0.read().await.clone();
 
@@ -219,7 +219,7 @@ impl WasmExecuteSendProposal {
     wasm_effects: &WasmIotaTransactionBlockEffects,
     client: &WasmCoreClientReadOnly,
   ) -> Result<()> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let mut identity_ref = self.identity.0.write().await;
     let proposal = self.proposal.0.read().await.clone();
 
@@ -284,7 +284,7 @@ impl WasmCreateSendProposal {
 
   #[wasm_bindgen(js_name = buildProgrammableTransaction)]
   pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let mut identity_ref = self.identity.0.write().await;
     let tx = Proposal::<SendAction>::create(
       self.action.clone(),
@@ -307,7 +307,7 @@ impl WasmCreateSendProposal {
     wasm_effects: &WasmIotaTransactionBlockEffects,
     client: &WasmCoreClientReadOnly,
   ) -> Result<Option<WasmProposalSend>> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let mut identity_ref = self.identity.0.write().await;
     let tx = Proposal::<SendAction>::create(
       self.action.clone(),
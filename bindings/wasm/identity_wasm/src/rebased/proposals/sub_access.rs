@@ -15,17 +15,16 @@ use iota_interaction_ts::core_client::WasmCoreClientReadOnly;
 use js_sys::Object;
 use js_sys::Promise;
 use js_sys::Reflect;
-use product_common::bindings::core_client::WasmManagedCoreClientReadOnly;
 use product_common::bindings::transaction::WasmTransaction;
 use product_common::bindings::transaction::WasmTransactionBuilder;
 use product_common::transaction::Transaction;
 use tokio::sync::RwLock;
 use wasm_bindgen::prelude::wasm_bindgen;
 use wasm_bindgen::JsCast as _;
-use wasm_bindgen::JsError;
 use wasm_bindgen::JsValue;
 
 use crate::error::WasmResult as _;
+use crate::rebased::client_compat::managed_core_client;
 use crate::rebased::WasmControllerToken;
 use crate::rebased::WasmOnChainIdentity;
 
@@ -188,7 +187,7 @@ impl WasmApproveAccessSubIdentityProposal {
 
   #[wasm_bindgen(js_name = buildProgrammableTransaction)]
   pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let identity_ref = self.identity.0.read().await;
     let mut proposal_ref = self.proposal.0.write().await;
     let tx = proposal_ref
@@ -206,7 +205,7 @@ impl WasmApproveAccessSubIdentityProposal {
     wasm_effects: &WasmIotaTransactionBlockEffects,
     client: &WasmCoreClientReadOnly,
   ) -> Result<()> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let identity_ref = self.identity.0.read().await;
     let mut proposal_ref = self.proposal.0.write().await;
     let tx = proposal_ref
@@ -282,9 +281,8 @@ impl WasmAccessSubIdentityTx {
   }
 
   #[wasm_bindgen(js_name = buildProgrammableTransaction)]
-  pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>, JsError> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)
-      .map_err(|_| JsError::new("failed to create a managed client from CoreClientReadOnly"))?;
+  pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
+    let managed_client = managed_core_client(client)?;
     let mut identity_mut = self.identity.0.write().await;
     let mut sub_identity_mut = self.sub_identity.0.write().await;
     let identity_token = &self.identity_token.0;
@@ -299,10 +297,12 @@ impl WasmAccessSubIdentityTx {
           builder
         }
         .finish(&managed_client)
-        .await?
+        .await
+        .wasm_result()?
         .into_inner()
         .build_programmable_transaction(&managed_client)
-        .await?
+        .await
+        .wasm_result()?
       }
       TxKind::Execute { proposal, sub_fn } => {
         let sub_access_fn = sub_fn.to_sub_access_fn();
@@ -319,20 +319,23 @@ impl WasmAccessSubIdentityTx {
               sub_access_fn,
               &managed_client,
             )
-            .await?
+            .await
+            .wasm_result()?
         } else {
           AccessSubIdentityBuilder::<'_, '_, ()>::new(&mut identity_mut, &mut sub_identity_mut, identity_token)
             .to_perform(sub_access_fn)
             .finish(&managed_client)
-            .await?
+            .await
+            .wasm_result()?
         }
         .into_inner()
         .build_programmable_transaction(&managed_client)
-        .await?
+        .await
+        .wasm_result()?
       }
     };
 
-    Ok(bcs::to_bytes(&pt)?)
+    bcs::to_bytes(&pt).wasm_result()
   }
 
   #[wasm_bindgen]
@@ -340,9 +343,8 @@ impl WasmAccessSubIdentityTx {
     self,
     wasm_effects: &WasmIotaTransactionBlockEffects,
     client: &WasmCoreClientReadOnly,
-  ) -> Result<JsValue, JsError> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)
-      .map_err(|_| JsError::new("failed to create a managed client from CoreClientReadOnly"))?;
+  ) -> Result<JsValue> {
+    let managed_client = managed_core_client(client)?;
     let mut identity_mut = self.identity.0.write().await;
     let mut sub_identity_mut = self.sub_identity.0.write().await;
     let identity_token = &self.identity_token.0;
@@ -359,7 +361,8 @@ impl WasmAccessSubIdentityTx {
             builder
           }
           .finish(&managed_client)
-          .await?
+          .await
+          .wasm_result()?
           .into_inner()
         };
 
@@ -389,16 +392,19 @@ impl WasmAccessSubIdentityTx {
               sub_access_fn,
               &managed_client,
             )
-            .await?
+            .await
+            .wasm_result()?
         } else {
           AccessSubIdentityBuilder::<'_, '_, ()>::new(&mut identity_mut, &mut sub_identity_mut, identity_token)
             .to_perform(sub_access_fn)
             .finish(&managed_client)
-            .await?
+            .await
+            .wasm_result()?
         }
         .into_inner()
         .apply(&mut effects, &managed_client)
-        .await?
+        .await
+        .wasm_result()?
         else {
           unreachable!("TxKind::Execute always return its sub_tx application result")
         };
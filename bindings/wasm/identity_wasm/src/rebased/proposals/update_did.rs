@@ -11,7 +11,6 @@ use identity_iota::iota::StateMetadataDocument;
 use iota_interaction_ts::bindings::WasmIotaTransactionBlockEffects;
 use iota_interaction_ts::core_client::WasmCoreClientReadOnly;
 use js_sys::Object;
-use product_common::bindings::core_client::WasmManagedCoreClientReadOnly;
 use product_common::transaction::transaction_builder::Transaction;
 use tokio::sync::RwLock;
 use wasm_bindgen::prelude::wasm_bindgen;
@@ -22,6 +21,7 @@ use super::StringSet;
 use crate::error::Result;
 use crate::error::WasmResult;
 use crate::iota::WasmIotaDocument;
+use crate::rebased::client_compat::managed_core_client;
 use crate::rebased::WasmControllerToken;
 use crate::rebased::WasmOnChainIdentity;
 use product_common::bindings::transaction::WasmTransactionBuilder;
@@ -33,7 +33,7 @@ pub struct WasmUpdateDid(pub(crate) UpdateDidDocument);
 impl WasmUpdateDid {
   #[wasm_bindgen(js_name = isDeactivation)]
   pub fn is_deactivation(&self) -> bool {
-    matches!(self.0.did_document_bytes(), Some(&[]))
+    self.0.is_deactivation()
   }
 
   #[wasm_bindgen(getter, js_name = didDocument)]
@@ -169,7 +169,7 @@ impl WasmApproveUpdateDidDocumentProposal {
 
   #[wasm_bindgen(js_name = buildProgrammableTransaction)]
   pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let mut proposal = self.proposal.0.write().await;
     let identity = self.identity.0.read().await;
     let tx = proposal
@@ -185,7 +185,7 @@ impl WasmApproveUpdateDidDocumentProposal {
     wasm_effects: &WasmIotaTransactionBlockEffects,
     client: &WasmCoreClientReadOnly,
   ) -> Result<()> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let mut proposal = self.proposal.0.write().await;
     let identity = self.identity.0.read().await;
     let tx = proposal
@@ -224,7 +224,7 @@ impl WasmExecuteUpdateDidDocumentProposal {
 
   #[wasm_bindgen(js_name = buildProgrammableTransaction)]
   pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let proposal = self.proposal.0.read().await.clone();
     let mut identity = self.identity.0.write().await;
     let tx = proposal
@@ -240,7 +240,7 @@ impl WasmExecuteUpdateDidDocumentProposal {
     wasm_effects: &WasmIotaTransactionBlockEffects,
     client: &WasmCoreClientReadOnly,
   ) -> Result<()> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let proposal = self.proposal.0.read().await.clone();
     let mut identity = self.identity.0.write().await;
     let tx = proposal
@@ -263,6 +263,8 @@ pub struct WasmCreateUpdateDidProposal {
   updated_did_doc: Option<WasmIotaDocument>,
   controller_token: WasmControllerToken,
   delete: bool,
+  reactivate: bool,
+  allow_pending_proposals: bool,
   expiration_epoch: Option<u64>,
 }
 
@@ -278,6 +280,8 @@ impl WasmCreateUpdateDidProposal {
       identity: identity.clone(),
       updated_did_doc: Some(updated_did_doc),
       delete: false,
+      reactivate: false,
+      allow_pending_proposals: false,
       expiration_epoch,
       controller_token,
     }
@@ -287,12 +291,33 @@ impl WasmCreateUpdateDidProposal {
     identity: &WasmOnChainIdentity,
     controller_token: WasmControllerToken,
     expiration_epoch: Option<u64>,
+    allow_pending_proposals: bool,
   ) -> Self {
     Self {
       identity: identity.clone(),
       expiration_epoch,
       updated_did_doc: None,
       delete: false,
+      reactivate: false,
+      allow_pending_proposals,
+      controller_token,
+    }
+  }
+
+  pub(crate) fn reactivate(
+    identity: &WasmOnChainIdentity,
+    did_doc: WasmIotaDocument,
+    controller_token: WasmControllerToken,
+    expiration_epoch: Option<u64>,
+    allow_pending_proposals: bool,
+  ) -> Self {
+    Self {
+      identity: identity.clone(),
+      expiration_epoch,
+      updated_did_doc: Some(did_doc),
+      delete: false,
+      reactivate: true,
+      allow_pending_proposals,
       controller_token,
     }
   }
@@ -307,21 +332,34 @@ impl WasmCreateUpdateDidProposal {
       expiration_epoch,
       updated_did_doc: None,
       delete: true,
+      reactivate: false,
+      allow_pending_proposals: false,
       controller_token: controller_token.clone(),
     }
   }
 
-  #[wasm_bindgen(js_name = buildProgrammableTransaction)]
-  pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
-    let action = if let Some(did_doc) = self.updated_did_doc.as_ref() {
+  async fn action(&self) -> UpdateDidDocument {
+    let mut action = if let Some(did_doc) = self.updated_did_doc.as_ref() {
       let did_doc = did_doc.0.read().await.clone();
-      UpdateDidDocument::new(did_doc)
+      if self.reactivate {
+        UpdateDidDocument::reactivate(did_doc)
+      } else {
+        UpdateDidDocument::new(did_doc)
+      }
     } else if self.delete {
       UpdateDidDocument::delete()
     } else {
       UpdateDidDocument::deactivate()
     };
+    action.set_allow_pending_proposals(self.allow_pending_proposals);
+
+    action
+  }
+
+  #[wasm_bindgen(js_name = buildProgrammableTransaction)]
+  pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
+    let managed_client = managed_core_client(client)?;
+    let action = self.action().await;
 
     let mut identity_lock = self.identity.0.write().await;
     let tx = Proposal::<UpdateDidDocument>::create(
@@ -344,15 +382,8 @@ impl WasmCreateUpdateDidProposal {
     wasm_effects: &WasmIotaTransactionBlockEffects,
     client: &WasmCoreClientReadOnly,
   ) -> Result<Option<WasmProposalUpdateDid>> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
-    let action = if let Some(did_doc) = self.updated_did_doc.as_ref() {
-      let did_doc = did_doc.0.read().await.clone();
-      UpdateDidDocument::new(did_doc)
-    } else if self.delete {
-      UpdateDidDocument::deactivate()
-    } else {
-      UpdateDidDocument::delete()
-    };
+    let managed_client = managed_core_client(client)?;
+    let action = self.action().await;
 
     let mut identity_lock = self.identity.0.write().await;
     let tx = Proposal::<UpdateDidDocument>::create(
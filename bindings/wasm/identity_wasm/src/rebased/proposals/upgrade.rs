@@ -0,0 +1,78 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_iota::iota::rebased::proposals::MigrateIdentity;
+use iota_interaction_ts::bindings::WasmIotaTransactionBlockEffects;
+use iota_interaction_ts::core_client::WasmCoreClientReadOnly;
+use js_sys::Object;
+use product_common::transaction::transaction_builder::Transaction as _;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::error::Result;
+use crate::error::WasmResult;
+use crate::rebased::client_compat::managed_core_client;
+use crate::rebased::WasmControllerToken;
+use crate::rebased::WasmOnChainIdentity;
+
+/// A transaction that migrates one or more identities to the latest version of the `IotaIdentity` package,
+/// e.g. after an on-chain package upgrade.
+///
+/// Each identity is migrated right away, hence the controller token used for an identity must grant enough
+/// voting power to reach the identity's threshold.
+#[wasm_bindgen(js_name = MigrateIdentity)]
+pub struct WasmMigrateIdentity(pub(crate) MigrateIdentity);
+
+#[wasm_bindgen(js_class = MigrateIdentity)]
+impl WasmMigrateIdentity {
+  /// Creates a new transaction to migrate `identity` using `controllerToken`.
+  #[wasm_bindgen(constructor)]
+  pub fn new(identity: &WasmOnChainIdentity, controller_token: &WasmControllerToken) -> Result<Self> {
+    let identity = identity.0.try_read().wasm_result()?;
+    MigrateIdentity::new(&identity, &controller_token.0)
+      .map(Self)
+      .wasm_result()
+  }
+
+  /// Adds `identity` to the identities to migrate, using `controllerToken`.
+  #[wasm_bindgen(js_name = withIdentity)]
+  pub fn with_identity(self, identity: &WasmOnChainIdentity, controller_token: &WasmControllerToken) -> Result<Self> {
+    let identity = identity.0.try_read().wasm_result()?;
+    self
+      .0
+      .with_identity(&identity, &controller_token.0)
+      .map(Self)
+      .wasm_result()
+  }
+
+  /// Returns the IDs of the identities to migrate.
+  #[wasm_bindgen]
+  pub fn identities(&self) -> Vec<String> {
+    self.0.identities().map(|id| id.to_string()).collect()
+  }
+
+  #[wasm_bindgen(js_name = buildProgrammableTransaction)]
+  pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
+    let managed_client = managed_core_client(client)?;
+    let pt = self
+      .0
+      .build_programmable_transaction(&managed_client)
+      .await
+      .wasm_result()?;
+    bcs::to_bytes(&pt).wasm_result()
+  }
+
+  #[wasm_bindgen]
+  pub async fn apply(
+    self,
+    wasm_effects: &WasmIotaTransactionBlockEffects,
+    client: &WasmCoreClientReadOnly,
+  ) -> Result<()> {
+    let managed_client = managed_core_client(client)?;
+    let mut effects = wasm_effects.clone().into();
+    let apply_result = self.0.apply(&mut effects, &managed_client).await;
+    let rem_wasm_effects = WasmIotaTransactionBlockEffects::from(&effects);
+    Object::assign(wasm_effects, &rem_wasm_effects);
+
+    apply_result.wasm_result()
+  }
+}
@@ -0,0 +1,144 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::borrow::Cow;
+
+use fastcrypto::encoding::Base64;
+use fastcrypto::encoding::Encoding as _;
+use iota_interaction_ts::bindings::WasmTransactionSigner;
+use iota_interaction_ts::WasmPublicKey;
+use js_sys::Promise;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast as _;
+use wasm_bindgen_futures::JsFuture;
+
+use crate::error::Result;
+use crate::error::WasmError;
+
+#[wasm_bindgen(typescript_custom_section)]
+const I_WALLET_ADAPTER: &str = r#"
+import { PublicKey } from "@iota/iota-sdk/cryptography";
+
+/**
+ * The result of a wallet signing a transaction, as returned by the `signTransaction` method of dApp Kit.
+ */
+interface SignedTransaction {
+  /** The base64 encoded BCS bytes of the signed transaction data. */
+  bytes: string;
+  /** The base64 encoded serialized signature of the transaction. */
+  signature: string;
+}
+
+/**
+ * A browser wallet, e.g. an account of a wallet extension connected through dApp Kit, able to sign transactions.
+ */
+interface WalletAdapter {
+  /** The public key of the wallet account that signs the transactions. */
+  publicKey: PublicKey;
+  /**
+   * Asks the wallet to sign the transaction whose BCS encoded transaction data is `txBytes`.
+   *
+   * With dApp Kit this is usually `(txBytes) => signTransaction({ transaction: toBase64(txBytes) })`.
+   */
+  signTransaction: (txBytes: Uint8Array) => Promise<SignedTransaction>;
+}
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+  #[derive(Clone)]
+  #[wasm_bindgen(typescript_type = WalletAdapter)]
+  pub type WasmWalletAdapter;
+
+  #[wasm_bindgen(method, getter, js_name = publicKey)]
+  fn public_key(this: &WasmWalletAdapter) -> WasmPublicKey;
+
+  #[wasm_bindgen(method, catch, js_name = signTransaction)]
+  fn sign_transaction(this: &WasmWalletAdapter, tx_bytes: &[u8]) -> std::result::Result<Promise, JsValue>;
+
+  #[wasm_bindgen(typescript_type = SignedTransaction)]
+  type WasmSignedTransaction;
+
+  #[wasm_bindgen(method, getter)]
+  fn bytes(this: &WasmSignedTransaction) -> String;
+
+  #[wasm_bindgen(method, getter)]
+  fn signature(this: &WasmSignedTransaction) -> String;
+
+  /// The methods of `PublicKey` used by [WasmWalletSigner].
+  type PublicKeyExt;
+
+  #[wasm_bindgen(method, js_name = toIotaBytes)]
+  fn to_iota_bytes(this: &PublicKeyExt) -> Vec<u8>;
+
+  #[wasm_bindgen(method, js_name = toIotaAddress)]
+  fn to_iota_address(this: &PublicKeyExt) -> String;
+}
+
+/// A {@link TransactionSigner} that delegates signing to a browser wallet, letting users approve
+/// identity transactions with their existing wallet extension.
+#[wasm_bindgen(js_name = WalletSigner)]
+#[derive(Clone)]
+pub struct WasmWalletSigner {
+  wallet: WasmWalletAdapter,
+}
+
+impl WasmWalletSigner {
+  fn public_key_ext(&self) -> PublicKeyExt {
+    self.wallet.public_key().unchecked_into()
+  }
+
+  /// Returns this signer as a {@link TransactionSigner}.
+  pub(crate) fn as_transaction_signer(&self) -> WasmTransactionSigner {
+    JsValue::from(self.clone()).unchecked_into()
+  }
+}
+
+#[wasm_bindgen(js_class = WalletSigner)]
+impl WasmWalletSigner {
+  #[wasm_bindgen(constructor)]
+  pub fn new(wallet: WasmWalletAdapter) -> Self {
+    Self { wallet }
+  }
+
+  /// Returns the address of the wallet account.
+  #[wasm_bindgen(js_name = keyId)]
+  pub fn key_id(&self) -> String {
+    self.public_key_ext().to_iota_address()
+  }
+
+  /// Asks the wallet to sign the BCS encoded transaction data `data`, returning the base64 encoded signature.
+  ///
+  /// Throws a `WalletSigningError` if the wallet signed a transaction other than the given one.
+  #[wasm_bindgen(js_name = sign)]
+  pub async fn sign(&self, data: &[u8]) -> Result<String> {
+    let promise = self.wallet.sign_transaction(data)?;
+    let signed: WasmSignedTransaction = JsFuture::from(promise).await?.unchecked_into();
+
+    // Wallets may rebuild the transaction they are given, e.g. to pick their own gas coins.
+    // A signature over different transaction data would be rejected once the transaction is executed.
+    if signed.bytes() != Base64::encode(data) {
+      return Err(
+        WasmError::new(
+          Cow::Borrowed("WalletSigningError"),
+          Cow::Borrowed("the wallet signed a transaction different from the one it was asked to sign"),
+        )
+        .into(),
+      );
+    }
+
+    Ok(signed.signature())
+  }
+
+  /// Returns the public key of the wallet account.
+  #[wasm_bindgen(js_name = publicKey)]
+  pub async fn public_key(&self) -> WasmPublicKey {
+    self.wallet.public_key()
+  }
+
+  /// Returns the flag-prefixed bytes of the wallet account's public key.
+  #[wasm_bindgen(js_name = iotaPublicKeyBytes)]
+  pub async fn iota_public_key_bytes(&self) -> Vec<u8> {
+    self.public_key_ext().to_iota_bytes()
+  }
+}
@@ -1,6 +1,7 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::borrow::Cow;
 use std::ops::Deref;
 use std::rc::Rc;
 
@@ -17,11 +18,9 @@ use iota_interaction_ts::bindings::WasmIotaClient;
 use iota_interaction_ts::bindings::WasmIotaTransactionBlockEffects;
 use iota_interaction_ts::bindings::WasmOwnedObjectRef;
 use iota_interaction_ts::WasmPublicKey;
-use product_common::bindings::core_client::WasmManagedCoreClientReadOnly;
 use product_common::bindings::transaction::WasmTransactionBuilder;
 use product_common::transaction::transaction_builder::Transaction;
 
-use identity_iota::iota::rebased::Error;
 use iota_interaction_ts::bindings::WasmTransactionSigner;
 use iota_interaction_ts::core_client::WasmCoreClientReadOnly;
 use iota_interaction_ts::NativeTransactionBlockResponse;
@@ -29,13 +28,18 @@ use iota_sdk_types::ObjectId;
 
 use js_sys::Object;
 
+use super::client_compat::managed_core_client;
 use super::identity::WasmIdentityBuilder;
+use super::ArrayControllerLink;
 use super::IdentityContainer;
 use super::WasmIdentityClientReadOnly;
 use super::WasmIotaAddress;
 use super::WasmObjectID;
+use super::WasmWalletAdapter;
+use super::WasmWalletSigner;
 
 use crate::error::Result;
+use crate::error::WasmError;
 use crate::error::WasmResult;
 use crate::iota::IotaDocumentLock;
 use crate::iota::WasmIotaDID;
@@ -73,7 +77,15 @@ impl WasmIdentityClient {
   /// @deprecated Use `IdentityClient.create` instead.
   #[wasm_bindgen(constructor)]
   pub fn _new() -> Result<WasmIdentityClient> {
-    Err(JsError::new("cannot build an instance of `IdentityClient` through its default sync constructor. Use `IdentityClient.create` instead.").into())
+    Err(
+      WasmError::new(
+        Cow::Borrowed("InvalidConstructor"),
+        Cow::Borrowed(
+          "cannot build an instance of `IdentityClient` through its default sync constructor. Use `IdentityClient.create` instead.",
+        ),
+      )
+      .into(),
+    )
   }
 
   #[wasm_bindgen(js_name = create)]
@@ -85,6 +97,19 @@ impl WasmIdentityClient {
     Ok(WasmIdentityClient(inner_client))
   }
 
+  /// Creates a new {@link IdentityClient} whose transactions are signed by a browser wallet,
+  /// e.g. a wallet extension connected through dApp Kit.
+  ///
+  /// Every transaction sent through the returned client must be approved by the user in their wallet.
+  #[wasm_bindgen(js_name = fromWallet)]
+  pub async fn from_wallet(
+    client: &WasmIdentityClientReadOnly,
+    wallet: WasmWalletAdapter,
+  ) -> Result<WasmIdentityClient> {
+    let signer = WasmWalletSigner::new(wallet).as_transaction_signer();
+    Self::new(client, &signer).await
+  }
+
   #[wasm_bindgen(js_name = senderPublicKey)]
   pub fn sender_public_key(&self) -> Result<WasmPublicKey> {
     self.0.sender_public_key().try_into()
@@ -102,10 +127,7 @@ impl WasmIdentityClient {
 
   #[wasm_bindgen(js_name = createIdentity)]
   pub fn create_identity(&self, iota_document: &WasmIotaDocument) -> Result<WasmIdentityBuilder> {
-    Ok(
-      WasmIdentityBuilder::new(iota_document)
-        .map_err(|err| JsError::new(&format!("failed to initialize new identity builder; {err:?}")))?,
-    )
+    WasmIdentityBuilder::new(iota_document)
   }
 
   #[wasm_bindgen(js_name = getIdentity)]
@@ -145,10 +167,17 @@ impl WasmIdentityClient {
 
   #[wasm_bindgen(js_name = resolveDid)]
   pub async fn resolve_did(&self, did: &WasmIotaDID) -> Result<WasmIotaDocument> {
-    let document = self.0.resolve_did(&did.0).await.map_err(JsError::from)?;
+    let document = self.0.resolve_did(&did.0).await.wasm_result()?;
     Ok(WasmIotaDocument(Rc::new(IotaDocumentLock::new(document))))
   }
 
+  /// Returns the controllers of the identity with DID `did` and, recursively, the controllers of the identities
+  /// among them. Identities controlling each other in a cycle are traversed once.
+  #[wasm_bindgen(js_name = controllerChain)]
+  pub async fn controller_chain(&self, did: &WasmIotaDID) -> Result<ArrayControllerLink> {
+    self.read_only().controller_chain(did).await
+  }
+
   /// Returns the list of DIDs the given address can access as a controller.
   /// # Errors
   /// @throws {QueryControlledDidsError} when the underlying RPC calls fail;
@@ -185,16 +214,12 @@ impl WasmIdentityClient {
     document: &WasmIotaDocument,
     gas_budget: u64,
   ) -> Result<WasmIotaDocument> {
-    let doc: IotaDocument = document
-      .0
-      .try_read()
-      .map_err(|err| JsError::new(&format!("failed to read DID document; {err:?}")))?
-      .clone();
+    let doc: IotaDocument = document.0.try_read().wasm_result()?.clone();
     let document = self
       .0
       .publish_did_document_update(doc, gas_budget)
       .await
-      .map_err(<Error as std::convert::Into<JsError>>::into)?;
+      .wasm_result()?;
 
     Ok(WasmIotaDocument(Rc::new(IotaDocumentLock::new(document))))
   }
@@ -209,32 +234,29 @@ impl WasmIdentityClient {
     js_name = publishDidUpdate,
     unchecked_return_type = "TransactionBuilder<Transaction<IotaDocument>>",
   )]
-  pub async fn publish_did_update(
-    &self,
-    document: &WasmIotaDocument,
-  ) -> std::result::Result<WasmTransactionBuilder, JsError> {
-    let doc: IotaDocument = document
-      .0
-      .try_read()
-      .map_err(|err| JsError::new(&format!("failed to read DID document; {err:?}")))?
-      .clone();
+  pub async fn publish_did_update(&self, document: &WasmIotaDocument) -> Result<WasmTransactionBuilder> {
+    let doc: IotaDocument = document.0.try_read().wasm_result()?.clone();
 
-    let tx = self.0.publish_did_update(doc).await?.into_inner();
+    let tx = self.0.publish_did_update(doc).await.wasm_result()?.into_inner();
     let wasm_tx = WasmShorthandDidUpdate(tx);
     Ok(WasmTransactionBuilder::new(JsValue::from(wasm_tx).unchecked_into()))
   }
 
   #[wasm_bindgen(js_name = deactivateDidOutput)]
   pub async fn deactivate_did_output(&self, did: &WasmIotaDID, gas_budget: u64) -> Result<()> {
-    self
-      .0
-      .deactivate_did_output(&did.0, gas_budget)
-      .await
-      .map_err(<Error as std::convert::Into<JsError>>::into)?;
+    self.0.deactivate_did_output(&did.0, gas_budget).await.wasm_result()?;
 
     Ok(())
   }
 
+  #[wasm_bindgen(js_name = reactivateDidOutput)]
+  pub async fn reactivate_did_output(&self, document: &WasmIotaDocument, gas_budget: u64) -> Result<WasmIotaDocument> {
+    let doc: IotaDocument = document.0.try_read().wasm_result()?.clone();
+    let reactivated = self.0.reactivate_did_output(doc, gas_budget).await.wasm_result()?;
+
+    Ok(WasmIotaDocument::from(reactivated))
+  }
+
   #[wasm_bindgen(js_name = iotaClient)]
   pub fn iota_client(&self) -> WasmIotaClient {
     (**self.0).clone().into_inner()
@@ -287,7 +309,7 @@ impl WasmPublishDidDocument {
 
   #[wasm_bindgen(js_name = buildProgrammableTransaction)]
   pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let pt = self
       .0
       .build_programmable_transaction(&managed_client)
@@ -302,7 +324,7 @@ impl WasmPublishDidDocument {
     wasm_effects: &WasmIotaTransactionBlockEffects,
     client: &WasmCoreClientReadOnly,
   ) -> Result<WasmIotaDocument> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let mut effects = wasm_effects.clone().into();
     let apply_result = self.0.apply(&mut effects, &managed_client).await;
     let wasm_remaining_effects = WasmIotaTransactionBlockEffects::from(&effects);
@@ -319,7 +341,7 @@ pub struct WasmShorthandDidUpdate(ShorthandDidUpdate);
 impl WasmShorthandDidUpdate {
   #[wasm_bindgen(js_name = buildProgrammableTransaction)]
   pub async fn build_programmable_transaction(&self, client: &WasmCoreClientReadOnly) -> Result<Vec<u8>> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let pt = self
       .0
       .build_programmable_transaction(&managed_client)
@@ -333,7 +355,7 @@ impl WasmShorthandDidUpdate {
     wasm_effects: &WasmIotaTransactionBlockEffects,
     client: &WasmCoreClientReadOnly,
   ) -> Result<WasmIotaDocument> {
-    let managed_client = WasmManagedCoreClientReadOnly::from_wasm(client)?;
+    let managed_client = managed_core_client(client)?;
     let mut effects = wasm_effects.clone().into();
     let apply_result = self.0.apply(&mut effects, &managed_client).await;
     let wasm_remaining_effects = WasmIotaTransactionBlockEffects::from(&effects);
@@ -1,6 +1,7 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::borrow::Cow;
 use std::error::Error;
 use std::rc::Rc;
 use std::str::FromStr;
@@ -13,12 +14,40 @@ use iota_sdk_types::ObjectId;
 use product_common::core_client::CoreClientReadOnly as _;
 use wasm_bindgen::prelude::*;
 
+use super::client_compat::check_iota_client;
 use super::WasmObjectID;
 use super::WasmOnChainIdentity;
+use crate::error::Result;
+use crate::error::WasmError;
+use crate::error::WasmResult;
 use crate::iota::IotaDocumentLock;
 use crate::iota::WasmIotaDID;
 use crate::iota::WasmIotaDocument;
 
+#[wasm_bindgen(typescript_custom_section)]
+const I_CONTROLLER_LINK: &str = r#"
+/** A controller of an identity, as found by {@link IdentityClientReadOnly.controllerChain}. */
+interface ControllerLink {
+  /** The DID of the controlled identity. */
+  controlled: string;
+  /** The ID of the controller's `ControllerCap`. */
+  controllerCap: string;
+  /** The voting power of the controller. */
+  votingPower: bigint;
+  /** The address holding the controller's `ControllerCap`, if the capability is owned by an address. */
+  holder?: string;
+  /** The DID of the identity holding the controller's `ControllerCap`, if the controller is an identity. */
+  holderIdentity?: string;
+  /** Whether `holderIdentity` is also controlled by `controlled`, i.e. whether this link closes a cycle. */
+  closesCycle: boolean;
+}"#;
+
+#[wasm_bindgen]
+extern "C" {
+  #[wasm_bindgen(typescript_type = "ControllerLink[]")]
+  pub type ArrayControllerLink;
+}
+
 #[wasm_bindgen(js_name = Identity)]
 pub struct IdentityContainer(pub(crate) Identity);
 #[wasm_bindgen(js_class = Identity)]
@@ -55,8 +84,14 @@ pub struct WasmIdentityClientReadOnly(pub(crate) IdentityClientReadOnly);
 impl WasmIdentityClientReadOnly {
   /// @deprecated Use `IdentityClientReadOnly.create` instead.
   #[wasm_bindgen(constructor)]
-  pub fn _new() -> Result<WasmIdentityClientReadOnly, JsError> {
-    Err(JsError::new("cannot build an instance of `IdentityClientReadOnly` through its default sync constructor. Use `IdentityClientReadOnly.create` instead."))
+  pub fn _new() -> Result<WasmIdentityClientReadOnly> {
+    Err(
+      WasmError::new(
+        Cow::Borrowed("InvalidConstructor"),
+        Cow::Borrowed("cannot build an instance of `IdentityClientReadOnly` through its default sync constructor. Use `IdentityClientReadOnly.create` instead."),
+      )
+      .into(),
+    )
   }
 
   /// Creates a new {@link IdentityClientReadOnly} instance.
@@ -70,11 +105,12 @@ impl WasmIdentityClientReadOnly {
   pub async fn new(
     iota_client: WasmIotaClient,
     custom_package_id: Option<String>,
-  ) -> Result<WasmIdentityClientReadOnly, JsError> {
+  ) -> Result<WasmIdentityClientReadOnly> {
+    check_iota_client(iota_client.as_ref())?;
     if let Some(custom_package_id) = custom_package_id {
       Self::new_new_with_pkg_id(iota_client, custom_package_id).await
     } else {
-      let inner_client = IdentityClientReadOnly::new(iota_client).await?;
+      let inner_client = IdentityClientReadOnly::new(iota_client).await.wasm_result()?;
       Ok(WasmIdentityClientReadOnly(inner_client))
     }
   }
@@ -84,9 +120,12 @@ impl WasmIdentityClientReadOnly {
   pub async fn new_new_with_pkg_id(
     iota_client: WasmIotaClient,
     iota_identity_pkg_id: String,
-  ) -> Result<WasmIdentityClientReadOnly, JsError> {
-    let inner_client =
-      IdentityClientReadOnly::new_with_pkg_id(iota_client, ObjectId::from_str(&iota_identity_pkg_id)?).await?;
+  ) -> Result<WasmIdentityClientReadOnly> {
+    check_iota_client(iota_client.as_ref())?;
+    let package_id = ObjectId::from_str(&iota_identity_pkg_id).wasm_result()?;
+    let inner_client = IdentityClientReadOnly::new_with_pkg_id(iota_client, package_id)
+      .await
+      .wasm_result()?;
     Ok(WasmIdentityClientReadOnly(inner_client))
   }
 
@@ -121,18 +160,26 @@ impl WasmIdentityClientReadOnly {
   }
 
   #[wasm_bindgen(js_name = resolveDid)]
-  pub async fn resolve_did(&self, did: &WasmIotaDID) -> Result<WasmIotaDocument, JsError> {
-    let document = self.0.resolve_did(&did.0).await.map_err(JsError::from)?;
+  pub async fn resolve_did(&self, did: &WasmIotaDID) -> Result<WasmIotaDocument> {
+    let document = self.0.resolve_did(&did.0).await.wasm_result()?;
     Ok(WasmIotaDocument(Rc::new(IotaDocumentLock::new(document))))
   }
 
+  /// Returns the controllers of the identity with DID `did` and, recursively, the controllers of the identities
+  /// among them. Identities controlling each other in a cycle are traversed once.
+  #[wasm_bindgen(js_name = controllerChain)]
+  pub async fn controller_chain(&self, did: &WasmIotaDID) -> Result<ArrayControllerLink> {
+    let chain = self.0.controller_chain(&did.0).await.wasm_result()?;
+    let serializer = serde_wasm_bindgen::Serializer::new().serialize_large_number_types_as_bigints(true);
+    serde::Serialize::serialize(chain.links(), &serializer)
+      .map(JsCast::unchecked_into)
+      .map_err(JsValue::from)
+  }
+
   #[wasm_bindgen(js_name = getIdentity)]
-  pub async fn get_identity(&self, object_id: WasmObjectID) -> Result<IdentityContainer, JsError> {
-    let inner_value = self
-      .0
-      .get_identity(object_id.parse()?)
-      .await
-      .map_err(|err| JsError::new(&format!("failed to resolve identity by object id; {err:?}")))?;
+  pub async fn get_identity(&self, object_id: WasmObjectID) -> Result<IdentityContainer> {
+    let object_id = object_id.parse::<ObjectId>().wasm_result()?;
+    let inner_value = self.0.get_identity(object_id).await.wasm_result()?;
     Ok(IdentityContainer(inner_value))
   }
 
@@ -141,8 +188,8 @@ impl WasmIdentityClientReadOnly {
   /// @throws {QueryControlledDidsError} when the underlying RPC calls fail;
   /// @throws {Error} when the passed `address` string is not a valid IOTA address.
   #[wasm_bindgen(js_name = didsControlledBy)]
-  pub async fn dids_controlled_by(&self, address: &str) -> Result<Vec<WasmIotaDID>, js_sys::Error> {
-    let address = Address::from_str(address).map_err(|e| js_sys::Error::new(&format!("{e:#}")))?;
+  pub async fn dids_controlled_by(&self, address: &str) -> std::result::Result<Vec<WasmIotaDID>, js_sys::Error> {
+    let address = Address::from_str(address).map_err(|e| js_sys::Error::from(WasmError::from(e)))?;
     let dids = self
       .0
       .dids_controlled_by(address)
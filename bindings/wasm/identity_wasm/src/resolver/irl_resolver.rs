@@ -4,8 +4,13 @@
 use iota_caip::iota::resolver::Resolver;
 use iota_caip::iota::IotaNetwork;
 use js_sys::Object;
+use std::borrow::Cow;
 use wasm_bindgen::prelude::*;
 
+use crate::error::Result;
+use crate::error::WasmError;
+use crate::error::WasmResult;
+
 /// A resolver for IOTA Resource Locators (IRLs).
 #[wasm_bindgen(js_name = IrlResolver)]
 pub struct WasmIrlResolver(Resolver);
@@ -14,28 +19,28 @@ pub struct WasmIrlResolver(Resolver);
 impl WasmIrlResolver {
   // Creates a new {@link IrlResolver} instance.
   #[wasm_bindgen(constructor)]
-  pub fn new(params: Option<IIrlResolverParams>) -> Result<Self, JsError> {
-    let params: IrlResolverParams = serde_wasm_bindgen::from_value(params.unwrap_or_default().into())?;
+  pub fn new(params: Option<IIrlResolverParams>) -> Result<Self> {
+    let params: IrlResolverParams = serde_wasm_bindgen::from_value(params.unwrap_or_default().into()).wasm_result()?;
     let custom_networks = params
       .custom_networks
       .into_iter()
       .map(|CustomNetworkParams { chain_id, endpoint }| {
-        let network = IotaNetwork::from_genesis_digest(&chain_id)
-          .ok_or_else(|| JsError::new(&format!("Invalid chain ID: {chain_id}")))?;
+        let network = IotaNetwork::from_genesis_digest(&chain_id).ok_or_else(|| {
+          WasmError::new(
+            Cow::Borrowed("InvalidChainId"),
+            Cow::Owned(format!("Invalid chain ID: {chain_id}")),
+          )
+        })?;
         Ok((network, endpoint))
       })
-      .collect::<Result<Vec<(IotaNetwork, String)>, JsError>>()?;
+      .collect::<std::result::Result<Vec<(IotaNetwork, String)>, WasmError<'_>>>()?;
 
     Ok(Self(Resolver::new_with_custom_networks(custom_networks)))
   }
 
   /// Resolves an IOTA Resource Locator (IRL) to its corresponding resource.
-  pub async fn resolve(&self, irl: &str) -> Result<JsValue, JsError> {
-    let res = self
-      .0
-      .resolve(irl)
-      .await
-      .map_err(|e| JsError::new(&format!("{:#}", anyhow::Error::from(e))))?;
+  pub async fn resolve(&self, irl: &str) -> Result<JsValue> {
+    let res = self.0.resolve(irl).await.map_err(anyhow::Error::from).wasm_result()?;
     Ok(serde_wasm_bindgen::to_value(&res).expect("a JSON Value can be turned into a JsValue"))
   }
 }
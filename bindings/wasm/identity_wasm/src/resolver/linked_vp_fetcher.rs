@@ -0,0 +1,207 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_iota::core::Url;
+use identity_iota::credential::DecodedJwtPresentation;
+use identity_iota::did::CoreDID;
+use identity_iota::document::CoreDocument;
+use identity_iota::resolver::LinkedVp;
+use identity_iota::resolver::LinkedVpFetcher;
+use js_sys::Array;
+use js_sys::Function;
+use js_sys::Promise;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+
+use crate::common::ImportedDocumentLock;
+use crate::credential::UnknownCredential;
+use crate::credential::WasmDecodedJwtPresentation;
+use crate::credential::WasmJwtPresentationValidationOptions;
+use crate::did::IToCoreDocument;
+use crate::did::WasmDIDUrl;
+use crate::error::JsValueResult;
+use crate::error::Result;
+use crate::error::WasmError;
+use crate::error::WasmResult;
+use crate::verification::IJwsVerifier;
+use crate::verification::WasmJwsVerifier;
+
+use super::wasm_resolver::WasmResolver;
+
+#[wasm_bindgen(typescript_custom_section)]
+const T_LINKED_VP_FETCH: &str = r#"
+/** Fetches the resource at `url`, returning its content. */
+type LinkedVpFetch = (url: string) => Promise<string>;
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+  #[wasm_bindgen(typescript_type = "LinkedVpFetch")]
+  pub type LinkedVpFetch;
+
+  #[wasm_bindgen(typescript_type = "Promise<Array<LinkedVp>>")]
+  pub type PromiseArrayLinkedVp;
+}
+
+/// A presentation obtained from a [Linked Verifiable Presentation](https://identity.foundation/linked-vp/) endpoint.
+#[wasm_bindgen(js_name = LinkedVp, inspectable)]
+pub struct WasmLinkedVp {
+  service_id: WasmDIDUrl,
+  url: String,
+  presentation: Option<DecodedJwtPresentation<UnknownCredential>>,
+  error: JsValue,
+}
+
+#[wasm_bindgen(js_class = LinkedVp)]
+impl WasmLinkedVp {
+  /// Returns the identifier of the service the presentation was discovered through.
+  #[wasm_bindgen(js_name = serviceId)]
+  pub fn service_id(&self) -> WasmDIDUrl {
+    self.service_id.clone()
+  }
+
+  /// Returns the endpoint the presentation was fetched from.
+  #[wasm_bindgen]
+  pub fn url(&self) -> String {
+    self.url.clone()
+  }
+
+  /// Returns the validated presentation, or `undefined` if it could not be obtained.
+  #[wasm_bindgen]
+  pub fn presentation(&self) -> Option<WasmDecodedJwtPresentation> {
+    self.presentation.clone().map(WasmDecodedJwtPresentation)
+  }
+
+  /// Returns the reason why the presentation could not be obtained, or `undefined` if it was.
+  #[wasm_bindgen]
+  pub fn error(&self) -> JsValue {
+    self.error.clone()
+  }
+}
+
+impl From<LinkedVp<UnknownCredential>> for WasmLinkedVp {
+  fn from(linked_vp: LinkedVp<UnknownCredential>) -> Self {
+    let (presentation, error) = match linked_vp.presentation {
+      Ok(presentation) => (Some(presentation), JsValue::undefined()),
+      Err(error) => (None, JsValue::from(WasmError::from(error))),
+    };
+
+    Self {
+      service_id: WasmDIDUrl::from(linked_vp.service_id),
+      url: linked_vp.url.into_string(),
+      presentation,
+      error,
+    }
+  }
+}
+
+/// Discovers, fetches and validates the [Linked Verifiable Presentations](https://identity.foundation/linked-vp/)
+/// of a DID.
+///
+/// Only presentations encoded as JWTs are supported; each is validated against the DID document linking to it,
+/// whose DID must be the presentation's holder. Note that the credentials contained in the presentations are not
+/// validated.
+#[wasm_bindgen(js_name = LinkedVpFetcher)]
+#[derive(Clone)]
+pub struct WasmLinkedVpFetcher {
+  fetch: Function,
+  signature_verifier: Option<JsValue>,
+}
+
+#[wasm_bindgen(js_class = LinkedVpFetcher)]
+impl WasmLinkedVpFetcher {
+  /// Creates a new {@link LinkedVpFetcher} fetching presentations with `fetch`. If a `signatureVerifier` is provided
+  /// it will be used when verifying the presentations' signatures, otherwise a default verifier capable of handling
+  /// the `EdDSA`, `ES256`, `ES256K` algorithms will be used.
+  #[wasm_bindgen(constructor)]
+  #[allow(non_snake_case)]
+  pub fn new(fetch: LinkedVpFetch, signatureVerifier: Option<IJwsVerifier>) -> Result<WasmLinkedVpFetcher> {
+    let fetch: Function = fetch
+      .dyn_into::<Function>()
+      .map_err(|_| "could not construct LinkedVpFetcher: `fetch` is not a function")?;
+
+    Ok(Self {
+      fetch,
+      signature_verifier: signatureVerifier.map(JsValue::from),
+    })
+  }
+
+  /// Resolves `did` with `resolver` and returns the presentations linked from its DID document.
+  ///
+  /// ### Errors
+  /// Fails only if `did` cannot be resolved. Failures to obtain individual presentations are reported through
+  /// {@link LinkedVp.error}.
+  #[wasm_bindgen]
+  pub fn fetch(
+    &self,
+    resolver: &WasmResolver,
+    did: &str,
+    options: Option<WasmJwtPresentationValidationOptions>,
+  ) -> Result<PromiseArrayLinkedVp> {
+    let did: CoreDID = CoreDID::parse(did).wasm_result()?;
+    let resolution: Promise = resolver.resolve(did.as_str())?.unchecked_into();
+    let fetcher: WasmLinkedVpFetcher = self.clone();
+
+    let promise: Promise = wasm_bindgen_futures::future_to_promise(async move {
+      let document: JsValue = JsFuture::from(resolution).await?;
+      let document: CoreDocument = ImportedDocumentLock::from_js_value_unchecked(&document)
+        .try_read()?
+        .as_ref()
+        .clone();
+      Ok(fetcher.fetch_linked_vps(document, options).await)
+    });
+
+    Ok(promise.unchecked_into())
+  }
+
+  /// Returns the presentations linked from the `LinkedVerifiablePresentation` services of `document`, in the order
+  /// in which they appear in the document.
+  #[wasm_bindgen(js_name = fetchFromDocument)]
+  pub fn fetch_from_document(
+    &self,
+    document: &IToCoreDocument,
+    options: Option<WasmJwtPresentationValidationOptions>,
+  ) -> Result<PromiseArrayLinkedVp> {
+    let document: CoreDocument = ImportedDocumentLock::from(document).try_read()?.as_ref().clone();
+    let fetcher: WasmLinkedVpFetcher = self.clone();
+
+    let promise: Promise =
+      wasm_bindgen_futures::future_to_promise(async move { Ok(fetcher.fetch_linked_vps(document, options).await) });
+
+    Ok(promise.unchecked_into())
+  }
+
+  async fn fetch_linked_vps(
+    &self,
+    document: CoreDocument,
+    options: Option<WasmJwtPresentationValidationOptions>,
+  ) -> JsValue {
+    let fetch = |url: Url| {
+      let fetch: Function = self.fetch.clone();
+      async move {
+        let output: Promise = Promise::resolve(
+          &JsValueResult::from(fetch.call1(&JsValue::null(), &url.as_str().into())).stringify_error()?,
+        );
+        let body: JsValue = JsValueResult::from(JsFuture::from(output).await).stringify_error()?;
+        body
+          .as_string()
+          .ok_or_else(|| "the fetched linked verifiable presentation is not a string".to_owned())
+      }
+    };
+    let verifier = WasmJwsVerifier::new(self.signature_verifier.clone().map(JsCast::unchecked_into));
+    let mut fetcher = LinkedVpFetcher::new(fetch, verifier);
+    if let Some(options) = options {
+      fetcher = fetcher.options(options.0);
+    }
+
+    fetcher
+      .fetch_from_document::<UnknownCredential>(&document)
+      .await
+      .into_iter()
+      .map(WasmLinkedVp::from)
+      .map(JsValue::from)
+      .collect::<Array>()
+      .into()
+  }
+}
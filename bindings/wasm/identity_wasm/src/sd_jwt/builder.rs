@@ -1,6 +1,8 @@
 // Copyright 2020-2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use identity_iota::credential::sd_jwt_vc::expand_pointer;
+use identity_iota::sd_jwt_payload::Error as SdJwtError;
 use identity_iota::sd_jwt_payload::SdJwtBuilder;
 use wasm_bindgen::prelude::wasm_bindgen;
 use wasm_bindgen::JsValue;
@@ -15,7 +17,11 @@ use super::WasmRequiredKeyBinding;
 
 /// A class for constructing an {@link SdJwt} in a step-by-step manner.
 #[wasm_bindgen(js_name = SdJwtBuilder)]
-pub struct WasmSdJwtBuilder(pub(crate) SdJwtBuilder<WasmHasher>);
+pub struct WasmSdJwtBuilder {
+  pub(crate) builder: SdJwtBuilder<WasmHasher>,
+  // Snapshot of the object as originally given, used to expand JSON pointer patterns.
+  object: serde_json::Value,
+}
 
 #[wasm_bindgen(js_class = SdJwtBuilder)]
 impl WasmSdJwtBuilder {
@@ -25,9 +31,8 @@ impl WasmSdJwtBuilder {
   pub fn new(object: js_sys::Object, hasher: WasmHasher, salt_size: Option<usize>) -> Result<Self> {
     let object = serde_wasm_bindgen::from_value::<serde_json::Value>(object.into()).wasm_result()?;
     let salt_size = salt_size.unwrap_or(30);
-    SdJwtBuilder::new_with_hasher_and_salt_size(object, hasher, salt_size)
-      .map(Self)
-      .wasm_result()
+    let builder = SdJwtBuilder::new_with_hasher_and_salt_size(&object, hasher, salt_size).wasm_result()?;
+    Ok(Self { builder, object })
   }
 
   /// Substitutes a value with the digest of its disclosure.
@@ -36,13 +41,27 @@ impl WasmSdJwtBuilder {
   /// - `path` indicates the pointer to the value that will be concealed using the syntax of [JSON pointer](https://datatracker.ietf.org/doc/html/rfc6901).
   #[wasm_bindgen(js_name = makeConcealable)]
   pub fn make_concealable(self, path: &str) -> Result<Self> {
-    self.0.make_concealable(path).map(Self).wasm_result()
+    self.try_map(|builder| builder.make_concealable(path))
+  }
+
+  /// Substitutes every value matched by the JSON pointer pattern `pattern` with the digest of its disclosure.
+  ///
+  /// ## Notes
+  /// - `pattern` uses the syntax of [JSON pointer](https://datatracker.ietf.org/doc/html/rfc6901), where a `*` segment
+  ///   matches every element of an array, e.g. `/nationalities/*` or `/matrix/*/*`.
+  /// - Nested values must be made concealable before their parents, e.g. `/matrix/*/*` before `/matrix/*`.
+  #[wasm_bindgen(js_name = makeAllConcealable)]
+  pub fn make_all_concealable(mut self, pattern: &str) -> Result<Self> {
+    for path in expand_pointer(&self.object, pattern).wasm_result()? {
+      self = self.try_map(|builder| builder.make_concealable(&path))?;
+    }
+    Ok(self)
   }
 
   /// Sets a single JWT header by its key and value.
   pub fn header(self, key: &str, value: JsValue) -> Result<Self> {
     let value: serde_json::Value = serde_wasm_bindgen::from_value(value)?;
-    Ok(Self(self.0.header(key, value)))
+    Ok(self.map(|builder| builder.header(key, value)))
   }
 
   /// Sets the JWT header.
@@ -52,14 +71,14 @@ impl WasmSdJwtBuilder {
   /// - `alg` is always replaced with the value passed to {@link SdJwtVcBuilder.finish}.
   pub fn headers(self, headers: js_sys::Object) -> Self {
     let headers = serde_wasm_bindgen::from_value(headers.into()).expect("JS object is a valid JSON object");
-    Self(self.0.headers(headers))
+    self.map(|builder| builder.headers(headers))
   }
 
   /// Adds a new claim to the underlying object.
   #[wasm_bindgen(js_name = insertClaim)]
   pub fn insert_claim(self, key: String, value: JsValue) -> Result<Self> {
     let value = serde_wasm_bindgen::from_value::<serde_json::Value>(value).wasm_result()?;
-    self.0.insert_claim(key, value).map(Self).wasm_result()
+    self.try_map(|builder| builder.insert_claim(key, value))
   }
 
   /// Adds a decoy digest to the specified path.
@@ -70,7 +89,7 @@ impl WasmSdJwtBuilder {
   /// Use `path` = "" to add decoys to the top level.
   #[wasm_bindgen(js_name = addDecoys)]
   pub fn add_decoys(self, path: &str, number_of_decoys: usize) -> Result<Self> {
-    self.0.add_decoys(path, number_of_decoys).map(Self).wasm_result()
+    self.try_map(|builder| builder.add_decoys(path, number_of_decoys))
   }
 
   /// Require a proof of possession of a given key from the holder.
@@ -80,11 +99,30 @@ impl WasmSdJwtBuilder {
   #[wasm_bindgen(js_name = requireKeyBinding)]
   pub fn require_key_binding(self, key_bind: WasmRequiredKeyBinding) -> Result<Self> {
     let key_bind = serde_wasm_bindgen::from_value(key_bind.into()).wasm_result()?;
-    Ok(Self(self.0.require_key_binding(key_bind)))
+    Ok(self.map(|builder| builder.require_key_binding(key_bind)))
   }
 
   /// Creates an {@link SdJwtVc} with the provided data.
   pub async fn finish(self, signer: &WasmJwsSigner, alg: &str) -> Result<WasmSdJwt> {
-    self.0.finish(signer, alg).await.map(WasmSdJwt).wasm_result()
+    self.builder.finish(signer, alg).await.map(WasmSdJwt).wasm_result()
+  }
+}
+
+impl WasmSdJwtBuilder {
+  fn map(self, f: impl FnOnce(SdJwtBuilder<WasmHasher>) -> SdJwtBuilder<WasmHasher>) -> Self {
+    let Self { builder, object } = self;
+    Self {
+      builder: f(builder),
+      object,
+    }
+  }
+
+  fn try_map(
+    self,
+    f: impl FnOnce(SdJwtBuilder<WasmHasher>) -> std::result::Result<SdJwtBuilder<WasmHasher>, SdJwtError>,
+  ) -> Result<Self> {
+    let Self { builder, object } = self;
+    let builder = f(builder).wasm_result()?;
+    Ok(Self { builder, object })
   }
 }
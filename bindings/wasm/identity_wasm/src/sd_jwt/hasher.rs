@@ -1,6 +1,8 @@
 // Copyright 2020-2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use identity_iota::credential::sd_jwt_vc::Sha384Hasher;
+use identity_iota::credential::sd_jwt_vc::Sha512Hasher;
 use identity_iota::sd_jwt_payload::Hasher;
 use identity_iota::sd_jwt_payload::Sha256Hasher;
 use wasm_bindgen::prelude::wasm_bindgen;
@@ -69,3 +71,59 @@ impl WasmSha256Hasher {
     self.0.encoded_digest(data)
   }
 }
+
+/// An implementation of {@link Hasher} that uses the `sha-384` hash function.
+#[wasm_bindgen(js_name = Sha384Hasher)]
+pub struct WasmSha384Hasher(pub(crate) Sha384Hasher);
+
+#[wasm_bindgen(js_class = Sha384Hasher)]
+impl WasmSha384Hasher {
+  #[allow(clippy::new_without_default)]
+  #[wasm_bindgen(constructor)]
+  pub fn new() -> Self {
+    Self(Sha384Hasher)
+  }
+
+  #[wasm_bindgen(js_name = algName)]
+  pub fn alg_name(&self) -> String {
+    self.0.alg_name().to_owned()
+  }
+
+  #[wasm_bindgen]
+  pub fn digest(&self, input: &[u8]) -> Vec<u8> {
+    self.0.digest(input)
+  }
+
+  #[wasm_bindgen(js_name = encodedDigest)]
+  pub fn encoded_digest(&self, data: &str) -> String {
+    self.0.encoded_digest(data)
+  }
+}
+
+/// An implementation of {@link Hasher} that uses the `sha-512` hash function.
+#[wasm_bindgen(js_name = Sha512Hasher)]
+pub struct WasmSha512Hasher(pub(crate) Sha512Hasher);
+
+#[wasm_bindgen(js_class = Sha512Hasher)]
+impl WasmSha512Hasher {
+  #[allow(clippy::new_without_default)]
+  #[wasm_bindgen(constructor)]
+  pub fn new() -> Self {
+    Self(Sha512Hasher)
+  }
+
+  #[wasm_bindgen(js_name = algName)]
+  pub fn alg_name(&self) -> String {
+    self.0.alg_name().to_owned()
+  }
+
+  #[wasm_bindgen]
+  pub fn digest(&self, input: &[u8]) -> Vec<u8> {
+    self.0.digest(input)
+  }
+
+  #[wasm_bindgen(js_name = encodedDigest)]
+  pub fn encoded_digest(&self, data: &str) -> String {
+    self.0.encoded_digest(data)
+  }
+}
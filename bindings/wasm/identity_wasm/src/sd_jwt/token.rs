@@ -1,6 +1,7 @@
 // Copyright 2020-2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use identity_iota::credential::sd_jwt_vc::expand_pointer;
 use identity_iota::credential::sd_jwt_vc::Error;
 use identity_iota::sd_jwt_payload::SdJwt;
 use identity_iota::sd_jwt_payload::SdJwtPresentationBuilder;
@@ -114,13 +115,26 @@ impl WasmSdJwt {
 /// A class that enables users to conceal or disclose disclosable claims
 /// within an {@link SdJwt}.
 #[wasm_bindgen(js_name = SdJwtPresentationBuilder)]
-pub struct WasmSdJwtPresentationBuilder(pub(crate) SdJwtPresentationBuilder);
+pub struct WasmSdJwtPresentationBuilder {
+  pub(crate) builder: SdJwtPresentationBuilder,
+  // The claims disclosed by the original token, used to expand JSON pointer patterns.
+  disclosed: serde_json::Value,
+}
 
 #[wasm_bindgen(js_class = SdJwtPresentationBuilder)]
 impl WasmSdJwtPresentationBuilder {
   #[wasm_bindgen(constructor)]
   pub fn new(sd_jwt: WasmSdJwt, hasher: &WasmHasher) -> Result<Self> {
-    SdJwtPresentationBuilder::new(sd_jwt.0, hasher).map(Self).wasm_result()
+    let disclosed = sd_jwt
+      .0
+      .clone()
+      .into_disclosed_object(hasher)
+      .map(serde_json::Value::Object)
+      .map_err(Error::from)
+      .wasm_result()?;
+    let builder = SdJwtPresentationBuilder::new(sd_jwt.0, hasher).wasm_result()?;
+
+    Ok(Self { builder, disclosed })
   }
 
   /// Removes the disclosure for the property at `path`, concealing it.
@@ -128,15 +142,27 @@ impl WasmSdJwtPresentationBuilder {
   /// ## Notes
   /// - When concealing a claim more than one disclosure may be removed: the disclosure for the claim itself and the
   ///   disclosures for any concealable sub-claim.
-  pub fn conceal(self, path: &str) -> Result<Self> {
-    self.0.conceal(path).map(Self).wasm_result()
+  pub fn conceal(mut self, path: &str) -> Result<Self> {
+    self.builder = self.builder.conceal(path).wasm_result()?;
+    Ok(self)
+  }
+
+  /// Conceals every property matched by the JSON pointer pattern `pattern`, where a `*` segment matches every
+  /// element of an array, e.g. `/nationalities/*`.
+  #[wasm_bindgen(js_name = concealMatching)]
+  pub fn conceal_matching(mut self, pattern: &str) -> Result<Self> {
+    for path in expand_pointer(&self.disclosed, pattern).wasm_result()? {
+      self = self.conceal(&path)?;
+    }
+    Ok(self)
   }
 
   /// Removes all disclosures from this SD-JWT, resulting in a token that,
   /// when presented, will have *all* selectively-disclosable properties
   /// omitted.
-  pub fn conceal_all(self) -> Self {
-    Self(self.0.conceal_all())
+  pub fn conceal_all(mut self) -> Self {
+    self.builder = self.builder.conceal_all();
+    self
   }
 
   /// Discloses a value that was previously concealed.
@@ -147,14 +173,34 @@ impl WasmSdJwtPresentationBuilder {
   /// - By default *all* disclosable claims are disclosed, therefore this method can only be used to *undo* any
   ///   concealment operations previously performed by either {@link SdJwtPresentationBuilder.conceal} or {@link
   ///   SdJwtPresentationBuilder.conceal_all}.
-  pub fn disclose(self, path: &str) -> Result<Self> {
-    self.0.disclose(path).map(Self).wasm_result()
+  pub fn disclose(mut self, path: &str) -> Result<Self> {
+    self.builder = self.builder.disclose(path).wasm_result()?;
+    Ok(self)
+  }
+
+  /// Discloses every previously concealed value matched by the JSON pointer pattern `pattern`, where a `*` segment
+  /// matches every element of an array, e.g. `/nationalities/*`.
+  #[wasm_bindgen(js_name = discloseMatching)]
+  pub fn disclose_matching(mut self, pattern: &str) -> Result<Self> {
+    for path in expand_pointer(&self.disclosed, pattern).wasm_result()? {
+      self = self.disclose(&path)?;
+    }
+    Ok(self)
+  }
+
+  /// Conceals every disclosable value, except the ones matched by any of the JSON pointer patterns in `patterns`,
+  /// e.g. `["/name", "/nationalities/*"]`.
+  #[wasm_bindgen(js_name = discloseOnly)]
+  pub fn disclose_only(self, patterns: Vec<String>) -> Result<Self> {
+    patterns.iter().try_fold(self.conceal_all(), |builder, pattern| {
+      builder.disclose_matching(pattern)
+    })
   }
 
   /// Returns the resulting {@link SdJwt} together with all omitted disclosures.
   #[wasm_bindgen]
   pub fn finish(self) -> SdJwtPresentationResult {
-    let (sd_jwt, disclosures) = self.0.finish();
+    let (sd_jwt, disclosures) = self.builder.finish();
     SdJwtPresentationResult {
       sd_jwt: WasmSdJwt(sd_jwt),
       disclosures: disclosures.into_iter().map(WasmDisclosure::from).collect(),
@@ -48,6 +48,17 @@ impl WasmSdJwtVcBuilder {
     self.0.make_concealable(path).map(Self).wasm_result()
   }
 
+  /// Substitutes every value matched by the JSON pointer pattern `pattern` with the digest of its disclosure.
+  ///
+  /// ## Notes
+  /// - `pattern` uses the syntax of [JSON pointer](https://datatracker.ietf.org/doc/html/rfc6901), where a `*` segment
+  ///   matches every element of an array, e.g. `/nationalities/*` or `/matrix/*/*`.
+  /// - Nested values must be made concealable before their parents, e.g. `/matrix/*/*` before `/matrix/*`.
+  #[wasm_bindgen(js_name = makeAllConcealable)]
+  pub fn make_all_concealable(self, pattern: &str) -> Result<Self> {
+    self.0.make_all_concealable(pattern).map(Self).wasm_result()
+  }
+
   /// Sets a single JWT header by its key and value.
   pub fn header(self, key: String, value: JsValue) -> Result<Self> {
     let json_value: serde_json::Value = serde_wasm_bindgen::from_value(value).wasm_result()?;
@@ -75,6 +86,16 @@ impl WasmSdJwtVcBuilder {
     self.0.add_decoys(path, number_of_decoys).map(Self).wasm_result()
   }
 
+  /// Adds `decoys` decoy digests to every object or array containing a concealable value.
+  ///
+  /// ## Notes
+  /// - Decoys are added when the first value of an object or array is made concealable, therefore this method must be
+  ///   called before {@link SdJwtVcBuilder.makeConcealable} and {@link SdJwtVcBuilder.makeAllConcealable}.
+  #[wasm_bindgen(js_name = decoysPerObject)]
+  pub fn decoys_per_object(self, decoys: usize) -> Self {
+    Self(self.0.decoys_per_object(decoys))
+  }
+
   /// Require a proof of possession of a given key from the holder.
   ///
   /// This operation adds a JWT confirmation (`cnf`) claim as specified in
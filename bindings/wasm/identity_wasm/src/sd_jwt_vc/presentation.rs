@@ -33,6 +33,31 @@ impl WasmSdJwtVcPresentationBuilder {
     self.0.disclose(path).map(Self).wasm_result()
   }
 
+  /// Conceals every property matched by the JSON pointer pattern `pattern`, where a `*` segment matches every
+  /// element of an array, e.g. `/nationalities/*`.
+  #[wasm_bindgen(js_name = concealMatching)]
+  pub fn conceal_matching(self, pattern: &str) -> Result<Self> {
+    self.0.conceal_matching(pattern).map(Self).wasm_result()
+  }
+
+  /// Discloses every previously concealed value matched by the JSON pointer pattern `pattern`, where a `*` segment
+  /// matches every element of an array, e.g. `/nationalities/*`.
+  #[wasm_bindgen(js_name = discloseMatching)]
+  pub fn disclose_matching(self, pattern: &str) -> Result<Self> {
+    self.0.disclose_matching(pattern).map(Self).wasm_result()
+  }
+
+  /// Conceals every disclosable value, except the ones matched by any of the JSON pointer patterns in `patterns`,
+  /// e.g. `["/name", "/nationalities/*"]`.
+  #[wasm_bindgen(js_name = discloseOnly)]
+  pub fn disclose_only(self, patterns: Vec<String>) -> Result<Self> {
+    self
+      .0
+      .disclose_only(patterns.iter().map(String::as_str))
+      .map(Self)
+      .wasm_result()
+  }
+
   pub fn finish(self) -> PresentationResult {
     let (token, disclosures) = self.0.finish();
 
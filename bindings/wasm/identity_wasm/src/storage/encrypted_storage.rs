@@ -0,0 +1,288 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::borrow::Cow;
+
+use argon2::Argon2;
+use js_sys::Array;
+use js_sys::Function;
+use js_sys::Object;
+use js_sys::Promise;
+use js_sys::Reflect;
+use js_sys::Uint8Array;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::future_to_promise;
+use wasm_bindgen_futures::JsFuture;
+
+use crate::common::PromiseVoid;
+use crate::error::Result;
+use crate::error::WasmError;
+
+/// The entry of the underlying store holding the salt of a passphrase-derived key.
+const SALT_KEY: &str = "__identity_encryption_salt";
+const SALT_LENGTH: usize = 16;
+/// The length of the AES-GCM nonce prepended to each encrypted entry.
+const NONCE_LENGTH: usize = 12;
+/// The version of the format of encrypted entries, stored as their first byte.
+const FORMAT_VERSION: u8 = 1;
+
+#[wasm_bindgen]
+extern "C" {
+  #[wasm_bindgen(typescript_type = "KeyValueStore")]
+  pub type WasmKeyValueStore;
+
+  #[wasm_bindgen(method, js_name = get)]
+  pub fn get_value(this: &WasmKeyValueStore, key: String) -> PromiseOptionUint8Array;
+
+  #[wasm_bindgen(method, js_name = set)]
+  pub fn set_value(this: &WasmKeyValueStore, key: String, value: Uint8Array) -> PromiseVoid;
+
+  #[wasm_bindgen(method, js_name = delete)]
+  pub fn delete_value(this: &WasmKeyValueStore, key: String) -> PromiseVoid;
+
+  #[wasm_bindgen(typescript_type = "CryptoKey")]
+  pub type WasmCryptoKey;
+
+  #[wasm_bindgen(typescript_type = "Promise<Uint8Array | undefined>")]
+  pub type PromiseOptionUint8Array;
+
+  #[wasm_bindgen(typescript_type = "Promise<EncryptedStorageWrapper>")]
+  pub type PromiseEncryptedStorageWrapper;
+}
+
+/// Encrypts the entries of a {@link KeyValueStore}, e.g. one backed by IndexedDB, so that key material and
+/// credentials held by a browser wallet are not persisted in plaintext.
+///
+/// Entries are encrypted with AES-256-GCM through WebCrypto, using a fresh random nonce per write. The name of an
+/// entry is authenticated along with its value, so encrypted values cannot be swapped between entries. The names of
+/// the entries themselves are not encrypted.
+///
+/// The key is either a WebCrypto `CryptoKey`, ideally non-extractable, or derived from a passphrase with argon2id.
+#[wasm_bindgen(js_name = EncryptedStorageWrapper)]
+pub struct WasmEncryptedStorageWrapper {
+  store: WasmKeyValueStore,
+  key: WasmCryptoKey,
+}
+
+#[wasm_bindgen(js_class = EncryptedStorageWrapper)]
+impl WasmEncryptedStorageWrapper {
+  /// Wraps `store`, encrypting its entries with the AES-GCM `key`, which must allow the `encrypt` and `decrypt`
+  /// usages.
+  #[wasm_bindgen(js_name = fromCryptoKey)]
+  pub fn from_crypto_key(store: WasmKeyValueStore, key: WasmCryptoKey) -> WasmEncryptedStorageWrapper {
+    Self { store, key }
+  }
+
+  /// Wraps `store`, encrypting its entries with a key derived from `passphrase` with argon2id.
+  ///
+  /// The random salt of the derivation is generated on first use and kept unencrypted in `store`, under a reserved
+  /// entry. Opening the store with a different passphrase succeeds, but reading its entries fails.
+  #[wasm_bindgen(js_name = fromPassphrase)]
+  pub fn from_passphrase(store: WasmKeyValueStore, passphrase: String) -> PromiseEncryptedStorageWrapper {
+    let promise: Promise = future_to_promise(async move {
+      let salt: Vec<u8> = match get_entry(&store, SALT_KEY).await? {
+        Some(salt) => salt,
+        None => {
+          let salt: Vec<u8> = random_bytes(SALT_LENGTH)?;
+          set_entry(&store, SALT_KEY, &salt).await?;
+          salt
+        }
+      };
+
+      let mut secret: [u8; 32] = [0; 32];
+      Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), &salt, &mut secret)
+        .map_err(|error| encryption_error(error.to_string()))?;
+      let key: Result<WasmCryptoKey> = import_key(&secret).await;
+      secret.fill(0);
+
+      Ok(JsValue::from(WasmEncryptedStorageWrapper { store, key: key? }))
+    });
+    promise.unchecked_into()
+  }
+
+  /// Reads and decrypts the entry `key`, returning `undefined` if it does not exist.
+  ///
+  /// ### Errors
+  ///
+  /// Errors if the entry was not encrypted with the same key, or was tampered with.
+  #[wasm_bindgen]
+  pub fn get(&self, key: String) -> PromiseOptionUint8Array {
+    let store: WasmKeyValueStore = self.store.clone();
+    let crypto_key: WasmCryptoKey = self.key.clone();
+    let promise: Promise = future_to_promise(async move {
+      check_entry_name(&key)?;
+      let Some(entry) = get_entry(&store, &key).await? else {
+        return Ok(JsValue::UNDEFINED);
+      };
+      let plaintext: Vec<u8> = decrypt(&crypto_key, &key, &entry).await?;
+      Ok(Uint8Array::from(plaintext.as_slice()).into())
+    });
+    promise.unchecked_into()
+  }
+
+  /// Encrypts `value` and writes it to the entry `key`, replacing any previous value.
+  #[wasm_bindgen]
+  pub fn set(&self, key: String, value: Vec<u8>) -> PromiseVoid {
+    let store: WasmKeyValueStore = self.store.clone();
+    let crypto_key: WasmCryptoKey = self.key.clone();
+    let promise: Promise = future_to_promise(async move {
+      check_entry_name(&key)?;
+      let entry: Vec<u8> = encrypt(&crypto_key, &key, &value).await?;
+      set_entry(&store, &key, &entry).await?;
+      Ok(JsValue::UNDEFINED)
+    });
+    promise.unchecked_into()
+  }
+
+  /// Deletes the entry `key`.
+  #[wasm_bindgen]
+  pub fn delete(&self, key: String) -> PromiseVoid {
+    let store: WasmKeyValueStore = self.store.clone();
+    let promise: Promise = future_to_promise(async move {
+      check_entry_name(&key)?;
+      JsFuture::from(Promise::resolve(&store.delete_value(key))).await?;
+      Ok(JsValue::UNDEFINED)
+    });
+    promise.unchecked_into()
+  }
+}
+
+fn encryption_error(message: impl Into<Cow<'static, str>>) -> JsValue {
+  WasmError::new(Cow::Borrowed("EncryptedStorageError"), message.into()).into()
+}
+
+fn check_entry_name(key: &str) -> Result<()> {
+  if key == SALT_KEY {
+    return Err(encryption_error(format!("`{SALT_KEY}` is a reserved entry")));
+  }
+  Ok(())
+}
+
+async fn get_entry(store: &WasmKeyValueStore, key: &str) -> Result<Option<Vec<u8>>> {
+  let value: JsValue = JsFuture::from(Promise::resolve(&store.get_value(key.to_owned()))).await?;
+  if value.is_null() || value.is_undefined() {
+    return Ok(None);
+  }
+  let bytes: Uint8Array = value
+    .dyn_into()
+    .map_err(|_| encryption_error(format!("the entry `{key}` is not a Uint8Array")))?;
+  Ok(Some(bytes.to_vec()))
+}
+
+async fn set_entry(store: &WasmKeyValueStore, key: &str, value: &[u8]) -> Result<()> {
+  JsFuture::from(Promise::resolve(
+    &store.set_value(key.to_owned(), Uint8Array::from(value)),
+  ))
+  .await?;
+  Ok(())
+}
+
+/// Returns `globalThis.crypto`, available in browsers and Node.js.
+fn web_crypto() -> Result<JsValue> {
+  let crypto: JsValue = Reflect::get(&js_sys::global(), &JsValue::from_str("crypto"))?;
+  if crypto.is_undefined() {
+    return Err(encryption_error("the WebCrypto API is not available"));
+  }
+  Ok(crypto)
+}
+
+/// Calls `crypto.subtle[method](...args)` and awaits the returned promise.
+async fn subtle(method: &str, args: Array) -> Result<JsValue> {
+  let subtle: JsValue = Reflect::get(&web_crypto()?, &JsValue::from_str("subtle"))?;
+  let function: Function = Reflect::get(&subtle, &JsValue::from_str(method))?.dyn_into()?;
+  let promise: Promise = Reflect::apply(&function, &subtle, &args)?.dyn_into()?;
+  JsFuture::from(promise).await
+}
+
+fn random_bytes(length: usize) -> Result<Vec<u8>> {
+  let crypto: JsValue = web_crypto()?;
+  let function: Function = Reflect::get(&crypto, &JsValue::from_str("getRandomValues"))?.dyn_into()?;
+  let bytes = Uint8Array::new_with_length(length as u32);
+  function.call1(&crypto, &bytes)?;
+  Ok(bytes.to_vec())
+}
+
+/// Imports `secret` as a non-extractable AES-GCM key.
+async fn import_key(secret: &[u8]) -> Result<WasmCryptoKey> {
+  let usages: Array = Array::of2(&JsValue::from_str("encrypt"), &JsValue::from_str("decrypt"));
+  let args: Array = Array::of5(
+    &JsValue::from_str("raw"),
+    &Uint8Array::from(secret),
+    &JsValue::from_str("AES-GCM"),
+    &JsValue::FALSE,
+    &usages,
+  );
+  Ok(subtle("importKey", args).await?.unchecked_into())
+}
+
+/// The AES-GCM parameters, authenticating the name of the entry as additional data.
+fn aes_gcm_params(nonce: &[u8], key: &str) -> Result<Object> {
+  let params = Object::new();
+  Reflect::set(&params, &JsValue::from_str("name"), &JsValue::from_str("AES-GCM"))?;
+  Reflect::set(&params, &JsValue::from_str("iv"), &Uint8Array::from(nonce))?;
+  Reflect::set(
+    &params,
+    &JsValue::from_str("additionalData"),
+    &Uint8Array::from(key.as_bytes()),
+  )?;
+  Ok(params)
+}
+
+/// Encrypts `plaintext` into `version || nonce || ciphertext`.
+async fn encrypt(crypto_key: &WasmCryptoKey, key: &str, plaintext: &[u8]) -> Result<Vec<u8>> {
+  let nonce: Vec<u8> = random_bytes(NONCE_LENGTH)?;
+  let args: Array = Array::of3(&aes_gcm_params(&nonce, key)?, crypto_key, &Uint8Array::from(plaintext));
+  let ciphertext: JsValue = subtle("encrypt", args).await?;
+
+  let mut entry: Vec<u8> = Vec::with_capacity(1 + NONCE_LENGTH + plaintext.len() + 16);
+  entry.push(FORMAT_VERSION);
+  entry.extend_from_slice(&nonce);
+  entry.extend_from_slice(&Uint8Array::new(&ciphertext).to_vec());
+  Ok(entry)
+}
+
+async fn decrypt(crypto_key: &WasmCryptoKey, key: &str, entry: &[u8]) -> Result<Vec<u8>> {
+  let Some((&version, rest)) = entry.split_first() else {
+    return Err(encryption_error(format!("the entry `{key}` is empty")));
+  };
+  if version != FORMAT_VERSION {
+    return Err(encryption_error(format!(
+      "the entry `{key}` has the unsupported format version {version}"
+    )));
+  }
+  if rest.len() < NONCE_LENGTH {
+    return Err(encryption_error(format!("the entry `{key}` is truncated")));
+  }
+  let (nonce, ciphertext) = rest.split_at(NONCE_LENGTH);
+
+  let args: Array = Array::of3(&aes_gcm_params(nonce, key)?, crypto_key, &Uint8Array::from(ciphertext));
+  let plaintext: JsValue = subtle("decrypt", args)
+    .await
+    .map_err(|_| encryption_error(format!("the entry `{key}` cannot be decrypted with this key")))?;
+  Ok(Uint8Array::new(&plaintext).to_vec())
+}
+
+#[wasm_bindgen(typescript_custom_section)]
+const KEY_VALUE_STORE: &'static str = r#"
+/**
+ * An asynchronous store of binary values, e.g. backed by IndexedDB, whose entries can be encrypted with
+ * {@link EncryptedStorageWrapper}.
+ */
+interface KeyValueStore {
+  /**
+   * Returns the value of the entry `key`, or `undefined` if it does not exist.
+   */
+  get: (key: string) => Promise<Uint8Array | undefined>;
+
+  /**
+   * Sets the value of the entry `key`, replacing any previous value.
+   */
+  set: (key: string, value: Uint8Array) => Promise<void>;
+
+  /**
+   * Deletes the entry `key`, if it exists.
+   */
+  delete: (key: string) => Promise<void>;
+}"#;
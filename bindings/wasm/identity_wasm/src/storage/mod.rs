@@ -1,6 +1,7 @@
 // Copyright 2020-2025 IOTA Stiftung, Fondazione LINKS
 // SPDX-License-Identifier: Apache-2.0
 
+mod encrypted_storage;
 mod jpt_timeframe_revocation_ext;
 mod jwk_gen_output;
 mod jwk_storage;
@@ -15,6 +16,7 @@ mod signature_options;
 mod wasm_storage;
 mod wasm_storage_signer;
 
+pub use encrypted_storage::*;
 pub use jpt_timeframe_revocation_ext::*;
 pub use jwk_gen_output::*;
 pub use jwk_storage::*;
@@ -79,6 +79,13 @@ impl WasmJwsSignatureOptions {
     self.0.custom_header_parameters = Some(value.into_serde().wasm_result()?);
     Ok(())
   }
+
+  /// Replace the value of the `claimsOrdering` field.
+  #[wasm_bindgen(js_name = setClaimsOrdering)]
+  pub fn set_claims_ordering(&mut self, value: JwtClaimsOrdering) -> Result<()> {
+    self.0.claims_ordering = value.into_serde().wasm_result()?;
+    Ok(())
+  }
 }
 
 impl_wasm_json!(WasmJwsSignatureOptions, JwsSignatureOptions);
@@ -89,6 +96,9 @@ impl_wasm_clone!(WasmJwsSignatureOptions, JwsSignatureOptions);
 extern "C" {
   #[wasm_bindgen(typescript_type = "IJwsSignatureOptions")]
   pub type IJwsSignatureOptions;
+
+  #[wasm_bindgen(typescript_type = "JwtClaimsOrdering")]
+  pub type JwtClaimsOrdering;
 }
 
 #[wasm_bindgen(typescript_custom_section)]
@@ -149,4 +159,20 @@ interface IJwsSignatureOptions {
      * Additional header parameters.
      */
     readonly customHeaderParameters?: Record<string, any>;
-}"#;
+
+    /**
+     * The layout of the claims set when signing a credential as a JWT.
+     *
+     * Default: "legacy", for compatibility with previously issued credentials.
+     */
+    readonly claimsOrdering?: JwtClaimsOrdering;
+}
+
+/**
+ * The layout of the claims set of a credential JWT.
+ *
+ * - `"legacy"`: claims follow the order of the credential's fields, as in previous versions of this library.
+ * - `"canonical"`: object keys are sorted at every nesting level and properties holding exactly one value
+ *   are serialized as that value rather than as a single-element array.
+ */
+type JwtClaimsOrdering = "legacy" | "canonical";"#;
@@ -46,15 +46,13 @@ impl WasmStorageSigner {
   /// ## Notes
   /// At this time, this function only supports "JsonWebKey2020"-based verification methods.
   #[wasm_bindgen(js_name = fromVmFragment)]
-  pub async fn from_vm_fragment(
-    storage: &WasmStorage,
-    document: &IToCoreDocument,
-    fragment: &str,
-  ) -> std::result::Result<Self, JsError> {
+  pub async fn from_vm_fragment(storage: &WasmStorage, document: &IToCoreDocument, fragment: &str) -> Result<Self> {
     let document_lock = ImportedDocumentLock::from(document);
     let document_lock = document_lock.read().await;
 
-    let signer = StorageSigner::new_from_vm_fragment(&storage.0, document_lock.as_ref(), fragment).await?;
+    let signer = StorageSigner::new_from_vm_fragment(&storage.0, document_lock.as_ref(), fragment)
+      .await
+      .wasm_result()?;
     let public_key = signer.public_key_jwk().clone();
     let key_id = signer.key_id().clone();
 
@@ -114,11 +112,13 @@ pub struct WasmStorageJwsSigner(WasmStorageSigner);
 
 #[wasm_bindgen(js_class = StorageJwsSigner)]
 impl WasmStorageJwsSigner {
-  pub async fn sign(&self, headers: js_sys::Object, payload: js_sys::Object) -> std::result::Result<Vec<u8>, JsError> {
+  pub async fn sign(&self, headers: js_sys::Object, payload: js_sys::Object) -> Result<Vec<u8>> {
     use identity_iota::sd_jwt_payload::JwsSigner;
 
-    let headers = serde_wasm_bindgen::from_value(headers.into())?;
-    let payload = serde_wasm_bindgen::from_value(payload.into())?;
-    Ok(JwsSigner::sign(&self.0.signer(), &headers, &payload).await?)
+    let headers = serde_wasm_bindgen::from_value(headers.into()).wasm_result()?;
+    let payload = serde_wasm_bindgen::from_value(payload.into()).wasm_result()?;
+    JwsSigner::sign(&self.0.signer(), &headers, &payload)
+      .await
+      .wasm_result()
   }
 }
@@ -33,6 +33,18 @@ impl WasmMethodType {
     WasmMethodType(MethodType::JSON_WEB_KEY_2020)
   }
 
+  /// A verification method whose multicodec-prefixed key material is in the `publicKeyMultibase` entry.
+  #[wasm_bindgen(js_name = Multikey)]
+  pub fn multikey() -> WasmMethodType {
+    WasmMethodType(MethodType::MULTIKEY)
+  }
+
+  /// A verification method whose RSA public key is in the `publicKeyJwk` entry.
+  #[wasm_bindgen(js_name = RsaVerificationKey2018)]
+  pub fn rsa_verification_key_2018() -> WasmMethodType {
+    WasmMethodType(MethodType::RSA_VERIFICATION_KEY_2018)
+  }
+
   /// A custom method.
   pub fn custom(type_: String) -> WasmMethodType {
     WasmMethodType(MethodType::custom(type_))
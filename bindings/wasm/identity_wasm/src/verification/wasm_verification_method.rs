@@ -38,6 +38,23 @@ impl WasmVerificationMethod {
       .wasm_result()
   }
 
+  /// Creates a new `Multikey` {@link VerificationMethod} from the given `did` and public {@link Jwk}, encoding the
+  /// key material as multicodec-prefixed `publicKeyMultibase`. If `fragment` is not given the `kid` value of the
+  /// given `key` will be used, if present, otherwise an error is returned.
+  #[wasm_bindgen(js_name = fromJwk)]
+  pub fn from_jwk(did: &IToCoreDID, key: &WasmJwk, fragment: Option<String>) -> Result<WasmVerificationMethod> {
+    VerificationMethod::from_jwk(CoreDID::from(did), &key.0, fragment.as_deref())
+      .map(Self)
+      .wasm_result()
+  }
+
+  /// Returns the public key material of this {@link VerificationMethod} as a {@link Jwk}, decoding
+  /// `publicKeyMultibase` and `publicKeyBase58` material if needed.
+  #[wasm_bindgen(js_name = toJwk)]
+  pub fn to_jwk(&self) -> Result<WasmJwk> {
+    self.0.to_jwk().map(WasmJwk).wasm_result()
+  }
+
   /// Create a custom {@link VerificationMethod}.
   #[wasm_bindgen(constructor)]
   pub fn new(
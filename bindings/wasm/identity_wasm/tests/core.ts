@@ -5,7 +5,10 @@ const assert = require("assert");
 import {
     CoreDID,
     CoreDocument,
+    DIDError,
+    DocumentError,
     EdCurve,
+    IdentityError,
     Jwk,
     JwkType,
     MethodRelationship,
@@ -330,3 +333,21 @@ describe("CoreDocument", function() {
         });
     });
 });
+
+describe("Errors", function() {
+    it("should carry a stable code", () => {
+        try {
+            CoreDID.parse("invalid");
+            assert.fail("parsing an invalid DID should throw");
+        } catch (error: any) {
+            assert.ok(error instanceof Error);
+            assert.ok(error instanceof IdentityError);
+            assert.ok(error instanceof DIDError);
+            assert.ok(!(error instanceof DocumentError));
+            assert.match(error.code, /^IOTA_ID_11\d\d$/);
+        }
+    });
+    it("should not match errors without a code", () => {
+        assert.ok(!(new Error("no code") instanceof IdentityError));
+    });
+});
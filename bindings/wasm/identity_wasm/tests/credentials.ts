@@ -14,6 +14,7 @@ import {
     MethodScope,
     Presentation,
     Storage,
+    Subject,
     Timestamp,
     UnknownCredential,
 } from "../node";
@@ -122,6 +123,27 @@ describe("Credential", function() {
             assert.deepStrictEqual(credential.proof(), undefined);
         });
     });
+    describe("#toCbor", function() {
+        it("should roundtrip", () => {
+            const credential = new Credential(credentialFields);
+            assert.deepStrictEqual(Credential.fromCbor(credential.toCbor()).toJSON(), credential.toJSON());
+        });
+    });
+    describe("#getTypedSubject", function() {
+        type DegreeSubject = Subject & { degree: { type: string; name: string } };
+        const isDegreeSubject = (subject: Subject): subject is DegreeSubject =>
+            typeof subject.degree === "object" && subject.degree !== null;
+
+        it("should return subjects passing the check", () => {
+            const credential = new Credential(credentialFields);
+            const subjects = credential.getTypedSubject(isDegreeSubject);
+            assert.deepStrictEqual(subjects[0].degree.name, credentialFields.credentialSubject.degree.name);
+        });
+        it("should throw on subjects failing the check", () => {
+            const credential = new Credential(credentialFields);
+            assert.throws(() => credential.getTypedSubject((_subject): _subject is DegreeSubject => false), TypeError);
+        });
+    });
 });
 
 const presentationFields = {
@@ -170,6 +192,13 @@ describe("Presentation", function() {
                 presentation.verifiableCredential()[0].tryIntoJwt()!.toString(),
                 presentationFields.verifiableCredential[0],
             );
+            assert.deepStrictEqual(presentation.credentialCount(), presentationFields.verifiableCredential.length);
+            assert.deepStrictEqual(
+                presentation.credentialAt(0)!.tryIntoJwt()!.toString(),
+                presentationFields.verifiableCredential[0],
+            );
+            assert.deepStrictEqual(presentation.credentialAt(1), undefined);
+            assert.deepStrictEqual(Presentation.fromCbor(presentation.toCbor()).toJSON(), presentation.toJSON());
             assert.deepStrictEqual(presentation.holder(), presentationFields.holder);
             assert.deepStrictEqual(presentation.refreshService(), [
                 presentationFields.refreshService,
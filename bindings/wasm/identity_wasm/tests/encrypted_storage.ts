@@ -0,0 +1,80 @@
+const assert = require("assert");
+import {
+    EncryptedJwkStore,
+    EncryptedKeyIdStore,
+    EncryptedStorageWrapper,
+    JwsAlgorithm,
+    KeyValueStore,
+    MethodDigest,
+} from "../node";
+import { createVerificationMethod } from "./key_id_storage";
+
+/** An in-memory `KeyValueStore`, exposing its raw entries. */
+class MapStore implements KeyValueStore {
+    public entries: Map<string, Uint8Array> = new Map();
+
+    public async get(key: string): Promise<Uint8Array | undefined> {
+        return this.entries.get(key);
+    }
+
+    public async set(key: string, value: Uint8Array): Promise<void> {
+        this.entries.set(key, value);
+    }
+
+    public async delete(key: string): Promise<void> {
+        this.entries.delete(key);
+    }
+}
+
+describe("EncryptedStorageWrapper", () => {
+    it("should encrypt entries with a passphrase", async () => {
+        const store = new MapStore();
+        const storage = await EncryptedStorageWrapper.fromPassphrase(store, "correct horse battery staple");
+        const value = new TextEncoder().encode("secret key material");
+
+        await storage.set("key", value);
+        assert.deepStrictEqual(await storage.get("key"), value);
+        assert.strictEqual(await storage.get("missing"), undefined);
+
+        // The value is not stored in plaintext.
+        const raw = store.entries.get("key")!;
+        assert.ok(!Buffer.from(raw).includes(Buffer.from(value)));
+
+        // The same passphrase reopens the store, another one cannot decrypt it.
+        const reopened = await EncryptedStorageWrapper.fromPassphrase(store, "correct horse battery staple");
+        assert.deepStrictEqual(await reopened.get("key"), value);
+        const wrong = await EncryptedStorageWrapper.fromPassphrase(store, "wrong passphrase");
+        await assert.rejects(wrong.get("key"));
+
+        // Values cannot be moved to another entry.
+        store.entries.set("other", raw);
+        await assert.rejects(storage.get("other"));
+
+        await storage.delete("key");
+        assert.strictEqual(await storage.get("key"), undefined);
+    });
+
+    it("should back the key and key id storages", async () => {
+        const key = await crypto.subtle.generateKey({ name: "AES-GCM", length: 256 }, false, ["encrypt", "decrypt"]);
+        const storage = EncryptedStorageWrapper.fromCryptoKey(new MapStore(), key);
+
+        const jwkStore = new EncryptedJwkStore(storage);
+        const { keyId, jwk } = await jwkStore
+            .generate(EncryptedJwkStore.ed25519KeyType(), JwsAlgorithm.EdDSA)
+            .then((output) => ({ keyId: output.keyId(), jwk: output.jwk() }));
+        assert.ok(await jwkStore.exists(keyId));
+        const signature = await jwkStore.sign(keyId, Uint8Array.from([1, 2, 3]), jwk.toPublic()!);
+        assert.deepStrictEqual(signature.length, 64);
+
+        const keyIdStore = new EncryptedKeyIdStore(storage);
+        const methodDigest = new MethodDigest(createVerificationMethod());
+        await keyIdStore.insertKeyId(methodDigest, keyId);
+        await assert.rejects(keyIdStore.insertKeyId(methodDigest, keyId));
+        assert.equal(await keyIdStore.getKeyId(methodDigest), keyId);
+        await keyIdStore.deleteKeyId(methodDigest);
+        await assert.rejects(keyIdStore.getKeyId(methodDigest));
+
+        await jwkStore.delete(keyId);
+        assert.ok(!await jwkStore.exists(keyId));
+    });
+});
@@ -27,6 +27,7 @@ import {
     Storage,
     SubjectHolderRelationship,
     Timestamp,
+    TrustedIssuerSet,
     VerificationMethod,
 } from "../node";
 import { createVerificationMethod } from "./key_id_storage";
@@ -177,6 +178,25 @@ describe("#JwkStorageDocument", function() {
             credential.toJSON(),
         );
 
+        // Check that the issuer can be looked up in a set of trusted documents given as JSON
+        const trustedIssuers = new TrustedIssuerSet([doc.toJSON()]);
+        assert.deepStrictEqual(trustedIssuers.has(doc.id().toString()), true);
+        const decodedFromSet = credentialValidator.validateWithTrustedIssuers(
+            credentialJwt,
+            trustedIssuers,
+            new JwtCredentialValidationOptions(),
+            FailFast.FirstError,
+        );
+        assert.deepStrictEqual(decodedFromSet.credential().toJSON(), credential.toJSON());
+        assert.throws(() =>
+            credentialValidator.validateWithTrustedIssuers(
+                credentialJwt,
+                new TrustedIssuerSet(),
+                new JwtCredentialValidationOptions(),
+                FailFast.FirstError,
+            )
+        );
+
         // Delete the method
         const methodId = (method as VerificationMethod).id();
         await doc.purgeMethod(storage, methodId); // Check that the method can no longer be resolved.
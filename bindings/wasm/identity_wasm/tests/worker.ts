@@ -0,0 +1,61 @@
+export {};
+
+const assert = require("assert");
+
+import {
+    CoreDocument,
+    EdDSAJwsVerifier,
+    exposeStorage,
+    fromSnapshot,
+    JwkMemStore,
+    JwsAlgorithm,
+    JwsSignatureOptions,
+    JwsVerificationOptions,
+    KeyIdMemStore,
+    MethodScope,
+    remoteStorage,
+    Storage,
+    toSnapshot,
+    transferList,
+} from "../node";
+
+describe("Worker support", function() {
+    describe("#toSnapshot", () => {
+        it("should roundtrip nested objects", () => {
+            const doc = new CoreDocument({ id: "did:example:123" });
+            const snapshot = toSnapshot({ documents: [doc], label: "issuers" });
+            assert.deepStrictEqual(transferList(snapshot).length, 1);
+
+            const structuredClone = globalThis.structuredClone(snapshot) as unknown;
+            const restored = fromSnapshot(structuredClone) as { documents: CoreDocument[]; label: string };
+            assert.deepStrictEqual(restored.label, "issuers");
+            assert.deepStrictEqual(restored.documents[0] instanceof CoreDocument, true);
+            assert.deepStrictEqual(restored.documents[0].toJSON(), doc.toJSON());
+        });
+    });
+
+    describe("#remoteStorage", () => {
+        it("should sign with a storage exposed over a message port", async () => {
+            const { port1, port2 } = new MessageChannel();
+            exposeStorage(new Storage(new JwkMemStore(), new KeyIdMemStore()), port1 as any);
+            const storage = remoteStorage(port2 as any);
+
+            try {
+                const doc = new CoreDocument({ id: "did:example:123" });
+                const fragment = await doc.generateMethod(
+                    storage,
+                    JwkMemStore.ed25519KeyType(),
+                    JwsAlgorithm.EdDSA,
+                    "#key-1",
+                    MethodScope.VerificationMethod(),
+                );
+                const jws = await doc.createJws(storage, fragment, "test", new JwsSignatureOptions());
+                const token = doc.verifyJws(jws, new JwsVerificationOptions(), new EdDSAJwsVerifier());
+                assert.deepStrictEqual(token.claims(), "test");
+            } finally {
+                port1.close();
+                port2.close();
+            }
+        });
+    });
+});
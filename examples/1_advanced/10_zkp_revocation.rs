@@ -237,7 +237,7 @@ async fn main() -> anyhow::Result<()> {
   let timeframe_result = JptCredentialValidatorUtils::check_timeframes_with_validity_timeframe_2024(
     &decoded_credential.credential,
     None,
-    StatusCheck::Strict,
+    &JptCredentialValidationOptions::default(),
   );
 
   assert!(timeframe_result.is_ok());
@@ -256,7 +256,7 @@ async fn main() -> anyhow::Result<()> {
     &decoded_credential.credential,
     &issuer_document,
     None,
-    StatusCheck::Strict,
+    &JptCredentialValidationOptions::default(),
   );
 
   assert!(revocation_result.is_ok());
@@ -312,6 +312,7 @@ async fn main() -> anyhow::Result<()> {
     &decoded_presented_credential.credential,
     None,
     StatusCheck::Strict,
+    &presentation_validation_options,
   );
 
   assert!(timeframe_result.is_ok());
@@ -332,6 +333,7 @@ async fn main() -> anyhow::Result<()> {
     &decoded_presented_credential.credential,
     None,
     StatusCheck::Strict,
+    &presentation_validation_options,
   );
 
   // We expect validation to no longer succeed because the credential was NOT updated.
@@ -477,7 +479,7 @@ async fn main() -> anyhow::Result<()> {
   let timeframe_result = JptCredentialValidatorUtils::check_timeframes_with_validity_timeframe_2024(
     &validation_result.credential,
     None,
-    StatusCheck::Strict,
+    &JptCredentialValidationOptions::default(),
   );
 
   assert!(!timeframe_result
@@ -0,0 +1,174 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Demonstrates how to express the business rules of a relying party as [Cedar](https://www.cedarpolicy.com)
+//! policies, evaluated once a credential has been validated.
+//!
+//! cargo run --release --example 14_cedar_policy
+
+use std::str::FromStr;
+
+use cedar_policy::Authorizer;
+use cedar_policy::Context;
+use cedar_policy::Decision;
+use cedar_policy::Entities;
+use cedar_policy::EntityId;
+use cedar_policy::EntityTypeName;
+use cedar_policy::EntityUid;
+use cedar_policy::PolicySet;
+use cedar_policy::Request;
+use identity_iota::core::FromJson;
+use identity_iota::core::Object;
+use identity_iota::credential::Credential;
+use identity_iota::credential::DecodedJwtCredential;
+use identity_iota::credential::PolicyDecision;
+use identity_iota::credential::PolicyEvaluator;
+use identity_iota::credential::PolicyInput;
+use identity_iota::document::DocumentMetadata;
+use serde_json::json;
+use serde_json::Value;
+
+/// The policies of a relying party accepting university degrees from a single university, and only bachelor degrees
+/// at that.
+const POLICIES: &str = r#"
+permit (
+  principal == Issuer::"did:example:university",
+  action == Action::"accept",
+  resource == CredentialType::"UniversityDegreeCredential"
+)
+when { context.valid && !context.issuerDeactivated };
+
+forbid (principal, action, resource)
+when { context.subject has degree && context.subject.degree.type != "BachelorDegree" };
+"#;
+
+/// A [`PolicyEvaluator`] backed by a Cedar policy set.
+///
+/// The issuer of the credential is the principal, accepting it the action, and its most specific type the resource.
+/// The context holds the outcome of the validation, the credential subject and the resolver metadata of the issuer.
+struct CedarPolicyEvaluator {
+  policies: PolicySet,
+  authorizer: Authorizer,
+}
+
+impl CedarPolicyEvaluator {
+  fn new(policies: &str) -> anyhow::Result<Self> {
+    Ok(Self {
+      policies: PolicySet::from_str(policies)?,
+      authorizer: Authorizer::new(),
+    })
+  }
+
+  fn request(input: &PolicyInput) -> anyhow::Result<Request> {
+    let uid = |type_name: &str, id: &str| -> anyhow::Result<EntityUid> {
+      Ok(EntityUid::from_type_name_and_id(
+        EntityTypeName::from_str(type_name)?,
+        EntityId::new(id),
+      ))
+    };
+    let issuer: &str = input
+      .issuer()
+      .ok_or_else(|| anyhow::anyhow!("the credential has no issuer"))?;
+    let credential_type: &str = input
+      .types()
+      .into_iter()
+      .last()
+      .ok_or_else(|| anyhow::anyhow!("the credential has no type"))?;
+    let subject: Value = input
+      .credential
+      .as_ref()
+      .and_then(|credential| credential.get("credentialSubject"))
+      .cloned()
+      .unwrap_or_else(|| json!({}));
+    let context = Context::from_json_value(
+      json!({
+        "valid": input.report.is_valid(),
+        "issues": input.report.issues.iter().map(|issue| issue.name.clone()).collect::<Vec<_>>(),
+        "issuerDeactivated": input
+          .issuer_metadata
+          .as_ref()
+          .and_then(|metadata| metadata.deactivated)
+          .unwrap_or(false),
+        "subject": subject,
+      }),
+      None,
+    )?;
+
+    Ok(Request::new(
+      uid("Issuer", issuer)?,
+      uid("Action", "accept")?,
+      uid("CredentialType", credential_type)?,
+      context,
+      None,
+    )?)
+  }
+}
+
+impl PolicyEvaluator for CedarPolicyEvaluator {
+  fn evaluate(&self, input: &PolicyInput) -> PolicyDecision {
+    // Deny whenever the policies cannot be evaluated, e.g. because a claim cannot be represented in Cedar.
+    let request: Request = match Self::request(input) {
+      Ok(request) => request,
+      Err(error) => return PolicyDecision::deny(format!("cannot evaluate the policies: {error}")),
+    };
+    let response = self
+      .authorizer
+      .is_authorized(&request, &self.policies, &Entities::empty());
+
+    let mut decision = PolicyDecision {
+      allowed: response.decision() == Decision::Allow,
+      reasons: Vec::new(),
+    };
+    for policy in response.diagnostics().reason() {
+      decision = decision.reason(format!("policy {policy} applies"));
+    }
+    for error in response.diagnostics().errors() {
+      decision = decision.reason(format!("policy error: {error}"));
+    }
+    decision
+  }
+}
+
+fn degree_credential(issuer: &str, degree: &str) -> anyhow::Result<DecodedJwtCredential> {
+  // For the sake of brevity, the credential is not issued as a JWT and validated here; in practice it is the output of
+  // `JwtCredentialValidator::validate`.
+  let credential: Credential = Credential::from_json_value(json!({
+    "@context": "https://www.w3.org/2018/credentials/v1",
+    "type": ["VerifiableCredential", "UniversityDegreeCredential"],
+    "issuer": issuer,
+    "issuanceDate": "2010-01-01T00:00:00Z",
+    "credentialSubject": {
+      "id": "did:example:alice",
+      "degree": { "type": degree, "name": "Bachelor of Science and Arts" }
+    }
+  }))?;
+  Ok(DecodedJwtCredential {
+    credential,
+    header: Box::default(),
+    custom_claims: None::<Object>,
+  })
+}
+
+fn main() -> anyhow::Result<()> {
+  let evaluator = CedarPolicyEvaluator::new(POLICIES)?;
+
+  // The metadata of the issuer's DID Document, as returned by `Resolver::resolve_url_with_metadata`.
+  let mut issuer_metadata = DocumentMetadata::default();
+  issuer_metadata.deactivated = Some(false);
+
+  for (issuer, degree) in [
+    ("did:example:university", "BachelorDegree"),
+    ("did:example:university", "MasterDegree"),
+    ("did:example:diploma-mill", "BachelorDegree"),
+  ] {
+    let input = PolicyInput::new(&Ok(degree_credential(issuer, degree)?), Some(issuer_metadata.clone()));
+    let decision: PolicyDecision = evaluator.evaluate(&input);
+    println!(
+      "{degree} issued by {issuer}: {} ({})",
+      if decision.is_allowed() { "accepted" } else { "rejected" },
+      decision.reasons.join(", ")
+    );
+  }
+
+  Ok(())
+}
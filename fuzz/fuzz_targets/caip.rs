@@ -0,0 +1,46 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![no_main]
+
+use identity_core::eip155::Eip155AccountId;
+use identity_core::eip155::Eip155AssetId;
+use identity_core::eip155::Eip155ChainId;
+use identity_credential::account_control::AccountId;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+  // CAIP-2 chain IDs.
+  if let Ok(chain_id) = data.parse::<Eip155ChainId>() {
+    let reparsed: Eip155ChainId = chain_id
+      .to_string()
+      .parse()
+      .expect("a parsed chain ID is a valid chain ID");
+    assert_eq!(chain_id, reparsed);
+  }
+
+  // CAIP-10 account IDs.
+  if let Ok(account_id) = data.parse::<Eip155AccountId>() {
+    let reparsed: Eip155AccountId = account_id
+      .to_string()
+      .parse()
+      .expect("a parsed account ID is a valid account ID");
+    assert_eq!(account_id, reparsed);
+  }
+  if let Ok(account_id) = data.parse::<AccountId>() {
+    let reparsed: AccountId = account_id
+      .to_string()
+      .parse()
+      .expect("a parsed account ID is a valid account ID");
+    assert_eq!(account_id, reparsed);
+  }
+
+  // CAIP-19 asset IDs.
+  if let Ok(asset_id) = data.parse::<Eip155AssetId>() {
+    let reparsed: Eip155AssetId = asset_id
+      .to_string()
+      .parse()
+      .expect("a parsed asset ID is a valid asset ID");
+    assert_eq!(asset_id, reparsed);
+  }
+});
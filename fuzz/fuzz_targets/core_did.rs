@@ -0,0 +1,22 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![no_main]
+
+use identity_did::CoreDID;
+use identity_did::DID;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+  let Ok(did) = CoreDID::parse(data) else {
+    return;
+  };
+
+  // A DID never carries a path, query or fragment: those belong to a DID URL.
+  assert!(!did.as_str().contains(['/', '?', '#']), "parsed DID URL as DID: {data}");
+
+  let reparsed = CoreDID::parse(did.as_str()).expect("a parsed DID is a valid DID");
+  assert_eq!(did, reparsed);
+  assert_eq!(did.method(), reparsed.method());
+  assert_eq!(did.method_id(), reparsed.method_id());
+});
@@ -0,0 +1,20 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![no_main]
+
+use identity_did::DIDUrl;
+use identity_did::RelativeDIDUrl;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+  if let Ok(url) = DIDUrl::parse(data) {
+    let reparsed = DIDUrl::parse(url.to_string()).expect("a parsed DID URL is a valid DID URL");
+    assert_eq!(url, reparsed);
+  }
+
+  let mut relative = RelativeDIDUrl::new();
+  let _ = relative.set_path(Some(data));
+  let _ = relative.set_query(Some(data));
+  let _ = relative.set_fragment(Some(data));
+});
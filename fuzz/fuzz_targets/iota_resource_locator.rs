@@ -0,0 +1,16 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![no_main]
+
+use identity_core::common::Url;
+use iota_caip::iota::IotaResourceLocator;
+use libfuzzer_sys::fuzz_target;
+
+// IOTA Resource Locators are built on top of the CAIP-2 chain ID and CAIP-19 asset ID grammars.
+fuzz_target!(|data: &str| {
+  if let Ok(irl) = data.parse::<IotaResourceLocator>() {
+    // Must never panic: every IRL is expected to be a valid URL.
+    let _ = Url::from(irl);
+  }
+});
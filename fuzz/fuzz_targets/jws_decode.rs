@@ -0,0 +1,18 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![no_main]
+
+use identity_jose::jws::Decoder;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+  let decoder = Decoder::new();
+
+  let _ = decoder.decode_compact_serialization(data, None);
+  let _ = decoder.decode_compact_serialization(data, Some(b"detached"));
+  let _ = decoder.decode_flattened_serialization(data, None);
+  if let Ok(items) = decoder.decode_general_serialization(data, None) {
+    items.for_each(drop);
+  }
+});
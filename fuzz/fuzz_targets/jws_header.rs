@@ -0,0 +1,15 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![no_main]
+
+use identity_jose::jws::JwsHeader;
+use identity_jose::jwt::JwtHeader;
+use identity_jose::jwu::decode_b64_json;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+  let _ = serde_json::from_slice::<JwsHeader>(data);
+  let _ = serde_json::from_slice::<JwtHeader>(data);
+  let _ = decode_b64_json::<JwsHeader>(data);
+});
@@ -0,0 +1,16 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![no_main]
+
+use identity_credential::sd_jwt_payload::Disclosure;
+use identity_credential::sd_jwt_payload::SdJwt;
+use identity_credential::sd_jwt_payload::Sha256Hasher;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+  let _ = Disclosure::parse(data);
+  if let Ok(sd_jwt) = SdJwt::parse(data) {
+    let _ = sd_jwt.into_disclosed_object(&Sha256Hasher);
+  }
+});
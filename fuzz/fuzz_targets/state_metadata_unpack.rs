@@ -0,0 +1,14 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![no_main]
+
+use identity_iota_core::StateMetadataDocument;
+use identity_iota_core::StateMetadataEncoding;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+  if let Ok(document) = StateMetadataDocument::unpack(data) {
+    let _ = document.pack(StateMetadataEncoding::Json);
+  }
+});
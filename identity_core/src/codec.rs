@@ -0,0 +1,390 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Encoding utilities for key material, as found in `publicKeyMultibase` and `publicKeyJwk` properties.
+//!
+//! This module covers:
+//! - [Multibase](https://datatracker.ietf.org/doc/html/draft-multiformats-multibase-03) encoding and decoding, see
+//!   [`encode_multibase`] and [`decode_multibase`].
+//! - [Multicodec](https://github.com/multiformats/multicodec) tagging of public keys, see [`Multicodec`].
+//! - Conversions between the multibase and base64url encodings of raw bytes, as used by `Multikey` verification
+//!   methods and JWKs respectively.
+//!
+//! ```
+//! # use identity_core::codec::Multicodec;
+//! # use identity_core::codec::decode_multikey;
+//! # use identity_core::codec::encode_multikey;
+//! let key: [u8; 32] = [7; 32];
+//! let multikey: String = encode_multikey(Multicodec::Ed25519Pub, &key).unwrap();
+//! assert!(multikey.starts_with("z6Mk"));
+//!
+//! let (codec, decoded) = decode_multikey(&multikey).unwrap();
+//! assert_eq!(codec, Multicodec::Ed25519Pub);
+//! assert_eq!(decoded, key);
+//! ```
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+
+use crate::convert::Base;
+
+/// Alias for a `Result` with the error type [`CodecError`].
+pub type CodecResult<T> = Result<T, CodecError>;
+
+/// Errors that can occur when encoding or decoding key material.
+#[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum CodecError {
+  /// The multibase string is empty and therefore lacks a base prefix.
+  #[error("multibase string is empty")]
+  EmptyMultibase,
+  /// The prefix of the multibase string does not identify a supported base.
+  #[error("unsupported multibase prefix `{0}`")]
+  UnsupportedMultibasePrefix(char),
+  /// The data is not valid in the expected base.
+  #[error("invalid {0:?} data")]
+  InvalidBaseEncoding(Base, #[source] multibase::Error),
+  /// The unsigned varint is truncated, too long or not minimally encoded.
+  #[error("invalid unsigned varint: {0}")]
+  InvalidVarint(&'static str),
+  /// The multicodec code does not identify a supported public key type.
+  #[error("unsupported multicodec 0x{0:x}")]
+  UnsupportedMulticodec(u64),
+  /// The length of the key does not match the one expected for its type.
+  #[error("invalid {codec} key length: expected one of {expected:?} bytes, found {actual}")]
+  InvalidKeyLength {
+    /// The type of the key.
+    codec: Multicodec,
+    /// The valid lengths for keys of this type.
+    expected: &'static [usize],
+    /// The actual length of the key.
+    actual: usize,
+  },
+}
+
+crate::impl_error_code!(CodecError {
+  EmptyMultibase => 2540,
+  UnsupportedMultibasePrefix => 2541,
+  InvalidBaseEncoding => 2542,
+  InvalidVarint => 2543,
+  UnsupportedMulticodec => 2544,
+  InvalidKeyLength => 2545,
+});
+
+/// The [multicodec](https://github.com/multiformats/multicodec/blob/master/table.csv) public key types supported by
+/// this library.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Multicodec {
+  /// An Ed25519 public key (`0xed`).
+  Ed25519Pub,
+  /// An X25519 public key (`0xec`).
+  X25519Pub,
+  /// A secp256k1 public key in SEC1 encoding (`0xe7`).
+  Secp256k1Pub,
+  /// A P-256 public key in SEC1 encoding (`0x1200`).
+  P256Pub,
+  /// A P-384 public key in SEC1 encoding (`0x1201`).
+  P384Pub,
+  /// A P-521 public key in SEC1 encoding (`0x1202`).
+  P521Pub,
+}
+
+impl Multicodec {
+  /// All the supported public key types.
+  pub const ALL: [Self; 6] = [
+    Self::Ed25519Pub,
+    Self::X25519Pub,
+    Self::Secp256k1Pub,
+    Self::P256Pub,
+    Self::P384Pub,
+    Self::P521Pub,
+  ];
+
+  /// Returns the multicodec code of this key type.
+  pub const fn code(self) -> u64 {
+    match self {
+      Self::Ed25519Pub => 0xed,
+      Self::X25519Pub => 0xec,
+      Self::Secp256k1Pub => 0xe7,
+      Self::P256Pub => 0x1200,
+      Self::P384Pub => 0x1201,
+      Self::P521Pub => 0x1202,
+    }
+  }
+
+  /// Returns the name of this key type in the multicodec table, e.g. `ed25519-pub`.
+  pub const fn name(self) -> &'static str {
+    match self {
+      Self::Ed25519Pub => "ed25519-pub",
+      Self::X25519Pub => "x25519-pub",
+      Self::Secp256k1Pub => "secp256k1-pub",
+      Self::P256Pub => "p256-pub",
+      Self::P384Pub => "p384-pub",
+      Self::P521Pub => "p521-pub",
+    }
+  }
+
+  /// Returns the valid lengths in bytes of keys of this type.
+  ///
+  /// Points of the elliptic curves other than the Edwards and Montgomery ones may be SEC1-encoded in either
+  /// compressed or uncompressed form.
+  pub const fn key_lengths(self) -> &'static [usize] {
+    match self {
+      Self::Ed25519Pub | Self::X25519Pub => &[32],
+      Self::Secp256k1Pub | Self::P256Pub => &[33, 65],
+      Self::P384Pub => &[49, 97],
+      Self::P521Pub => &[67, 133],
+    }
+  }
+
+  /// Returns the key type identified by the multicodec `code`.
+  pub fn from_code(code: u64) -> CodecResult<Self> {
+    Self::ALL
+      .into_iter()
+      .find(|codec| codec.code() == code)
+      .ok_or(CodecError::UnsupportedMulticodec(code))
+  }
+
+  /// Checks that `key` has a valid length for this key type.
+  pub fn check_key(self, key: &[u8]) -> CodecResult<()> {
+    if self.key_lengths().contains(&key.len()) {
+      Ok(())
+    } else {
+      Err(CodecError::InvalidKeyLength {
+        codec: self,
+        expected: self.key_lengths(),
+        actual: key.len(),
+      })
+    }
+  }
+
+  /// Prefixes `key` with the varint-encoded code of this key type, after checking its length.
+  pub fn tag(self, key: &[u8]) -> CodecResult<Vec<u8>> {
+    self.check_key(key)?;
+    let mut bytes: Vec<u8> = Vec::with_capacity(key.len() + 3);
+    encode_varint(self.code(), &mut bytes);
+    bytes.extend_from_slice(key);
+    Ok(bytes)
+  }
+
+  /// Splits multicodec-prefixed `bytes` into the key type and the key, after checking its length.
+  pub fn untag(bytes: &[u8]) -> CodecResult<(Self, &[u8])> {
+    let (code, key) = decode_varint(bytes)?;
+    let codec: Self = Self::from_code(code)?;
+    codec.check_key(key)?;
+    Ok((codec, key))
+  }
+}
+
+impl Display for Multicodec {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    f.write_str(self.name())
+  }
+}
+
+/// The maximum length of an unsigned varint, as mandated by the
+/// [multiformats specification](https://github.com/multiformats/unsigned-varint).
+const MAX_VARINT_LEN: usize = 9;
+
+/// Appends `value` to `buf` as an unsigned varint.
+pub fn encode_varint(mut value: u64, buf: &mut Vec<u8>) {
+  while value >= 0x80 {
+    buf.push((value as u8 & 0x7f) | 0x80);
+    value >>= 7;
+  }
+  buf.push(value as u8);
+}
+
+/// Decodes the unsigned varint at the start of `bytes`, returning it along with the remaining bytes.
+pub fn decode_varint(bytes: &[u8]) -> CodecResult<(u64, &[u8])> {
+  let mut value: u64 = 0;
+  for (idx, byte) in bytes.iter().enumerate().take(MAX_VARINT_LEN) {
+    value |= u64::from(byte & 0x7f) << (7 * idx);
+    if byte & 0x80 == 0 {
+      if *byte == 0 && idx > 0 {
+        return Err(CodecError::InvalidVarint("not minimally encoded"));
+      }
+      return Ok((value, &bytes[idx + 1..]));
+    }
+  }
+
+  if bytes.len() >= MAX_VARINT_LEN {
+    Err(CodecError::InvalidVarint("longer than 9 bytes"))
+  } else {
+    Err(CodecError::InvalidVarint("truncated"))
+  }
+}
+
+/// Encodes `data` as multibase in the given `base`.
+pub fn encode_multibase(data: impl AsRef<[u8]>, base: Base) -> String {
+  multibase::encode(multibase::Base::from(base), data)
+}
+
+/// Decodes the multibase-encoded `data`, returning the base it was encoded in along with the decoded bytes.
+pub fn decode_multibase(data: &str) -> CodecResult<(Base, Vec<u8>)> {
+  let prefix: char = data.chars().next().ok_or(CodecError::EmptyMultibase)?;
+  let base: Base = base_from_prefix(prefix).ok_or(CodecError::UnsupportedMultibasePrefix(prefix))?;
+  let bytes: Vec<u8> = multibase::Base::from(base)
+    .decode(&data[prefix.len_utf8()..])
+    .map_err(|err| CodecError::InvalidBaseEncoding(base, err))?;
+  Ok((base, bytes))
+}
+
+/// Encodes the public `key` of type `codec` as found in the `publicKeyMultibase` property of `Multikey` verification
+/// methods, i.e. multicodec-prefixed and multibase-encoded in [`Base::Base58Btc`].
+pub fn encode_multikey(codec: Multicodec, key: &[u8]) -> CodecResult<String> {
+  codec.tag(key).map(|bytes| encode_multibase(bytes, Base::Base58Btc))
+}
+
+/// Decodes a multicodec-prefixed and multibase-encoded public key, returning its type along with the raw key.
+pub fn decode_multikey(data: &str) -> CodecResult<(Multicodec, Vec<u8>)> {
+  let (_, bytes) = decode_multibase(data)?;
+  let (codec, key) = Multicodec::untag(&bytes)?;
+  Ok((codec, key.to_vec()))
+}
+
+/// Encodes `data` in unpadded base64url, as used for JWK parameters.
+pub fn encode_b64url(data: impl AsRef<[u8]>) -> String {
+  multibase::Base::Base64Url.encode(data)
+}
+
+/// Decodes the unpadded base64url-encoded `data`.
+pub fn decode_b64url(data: &str) -> CodecResult<Vec<u8>> {
+  multibase::Base::Base64Url
+    .decode(data)
+    .map_err(|err| CodecError::InvalidBaseEncoding(Base::Base64Url, err))
+}
+
+/// Re-encodes the multibase-encoded `data` in unpadded base64url.
+pub fn multibase_to_b64url(data: &str) -> CodecResult<String> {
+  decode_multibase(data).map(|(_, bytes)| encode_b64url(bytes))
+}
+
+/// Re-encodes the unpadded base64url-encoded `data` as multibase in the given `base`.
+pub fn b64url_to_multibase(data: &str, base: Base) -> CodecResult<String> {
+  decode_b64url(data).map(|bytes| encode_multibase(bytes, base))
+}
+
+fn base_from_prefix(prefix: char) -> Option<Base> {
+  let base: Base = match multibase::Base::from_code(prefix).ok()? {
+    multibase::Base::Identity => return None,
+    multibase::Base::Base2 => Base::Base2,
+    multibase::Base::Base8 => Base::Base8,
+    multibase::Base::Base10 => Base::Base10,
+    multibase::Base::Base16Lower => Base::Base16Lower,
+    multibase::Base::Base16Upper => Base::Base16Upper,
+    multibase::Base::Base32Lower => Base::Base32Lower,
+    multibase::Base::Base32Upper => Base::Base32Upper,
+    multibase::Base::Base32PadLower => Base::Base32PadLower,
+    multibase::Base::Base32PadUpper => Base::Base32PadUpper,
+    multibase::Base::Base32HexLower => Base::Base32HexLower,
+    multibase::Base::Base32HexUpper => Base::Base32HexUpper,
+    multibase::Base::Base32HexPadLower => Base::Base32HexPadLower,
+    multibase::Base::Base32HexPadUpper => Base::Base32HexPadUpper,
+    multibase::Base::Base32Z => Base::Base32Z,
+    multibase::Base::Base36Lower => Base::Base36Lower,
+    multibase::Base::Base36Upper => Base::Base36Upper,
+    multibase::Base::Base58Flickr => Base::Base58Flickr,
+    multibase::Base::Base58Btc => Base::Base58Btc,
+    multibase::Base::Base64 => Base::Base64,
+    multibase::Base::Base64Pad => Base::Base64Pad,
+    multibase::Base::Base64Url => Base::Base64Url,
+    multibase::Base::Base64UrlPad => Base::Base64UrlPad,
+  };
+  Some(base)
+}
+
+#[cfg(test)]
+mod tests {
+  use quickcheck_macros::quickcheck;
+
+  use super::*;
+
+  #[quickcheck]
+  fn varint_round_trips(value: u64) -> bool {
+    let value: u64 = value >> 1;
+    let mut bytes: Vec<u8> = Vec::new();
+    encode_varint(value, &mut bytes);
+    bytes.push(42);
+    decode_varint(&bytes).unwrap() == (value, &[42][..])
+  }
+
+  #[test]
+  fn invalid_varints_are_rejected() {
+    assert!(matches!(
+      decode_varint(&[0x80, 0x80]),
+      Err(CodecError::InvalidVarint("truncated"))
+    ));
+    assert!(matches!(
+      decode_varint(&[0x81, 0x00]),
+      Err(CodecError::InvalidVarint("not minimally encoded"))
+    ));
+    assert!(matches!(
+      decode_varint(&[0xff; 10]),
+      Err(CodecError::InvalidVarint("longer than 9 bytes"))
+    ));
+  }
+
+  #[test]
+  fn keys_are_tagged_with_their_multicodec() {
+    let tagged: Vec<u8> = Multicodec::P256Pub.tag(&[2; 33]).unwrap();
+    assert_eq!(&tagged[..2], &[0x80, 0x24]);
+    assert_eq!(Multicodec::untag(&tagged).unwrap(), (Multicodec::P256Pub, &[2; 33][..]));
+
+    for codec in Multicodec::ALL {
+      let key: Vec<u8> = vec![4; codec.key_lengths()[0]];
+      let (decoded_codec, decoded_key) = Multicodec::untag(&codec.tag(&key).unwrap()).unwrap();
+      assert_eq!(decoded_codec, codec);
+      assert_eq!(decoded_key, key);
+    }
+  }
+
+  #[test]
+  fn invalid_keys_are_rejected() {
+    assert!(matches!(
+      Multicodec::Ed25519Pub.tag(&[0; 31]),
+      Err(CodecError::InvalidKeyLength {
+        codec: Multicodec::Ed25519Pub,
+        actual: 31,
+        ..
+      })
+    ));
+
+    let mut bytes: Vec<u8> = Vec::new();
+    encode_varint(0x1300, &mut bytes);
+    bytes.extend([0; 32]);
+    assert!(matches!(
+      Multicodec::untag(&bytes),
+      Err(CodecError::UnsupportedMulticodec(0x1300))
+    ));
+  }
+
+  #[test]
+  fn multikeys_match_known_vectors() {
+    let x: Vec<u8> = decode_b64url("11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo").unwrap();
+    let multikey: String = encode_multikey(Multicodec::Ed25519Pub, &x).unwrap();
+    assert_eq!(multikey, "z6MktwupdmLXVVqTzCw4i46r4uGyosGXRnR3XjN4Zq7oMMsw");
+    assert_eq!(decode_multikey(&multikey).unwrap(), (Multicodec::Ed25519Pub, x));
+  }
+
+  #[test]
+  fn multibase_errors_are_precise() {
+    assert!(matches!(decode_multibase(""), Err(CodecError::EmptyMultibase)));
+    assert!(matches!(
+      decode_multibase("!abc"),
+      Err(CodecError::UnsupportedMultibasePrefix('!'))
+    ));
+    assert!(matches!(
+      decode_multibase("z0OIl"),
+      Err(CodecError::InvalidBaseEncoding(Base::Base58Btc, _))
+    ));
+  }
+
+  #[quickcheck]
+  fn b64url_and_multibase_round_trip(data: Vec<u8>) -> bool {
+    let b64url: String = encode_b64url(&data);
+    let multibase: String = b64url_to_multibase(&b64url, Base::Base58Btc).unwrap();
+    decode_multibase(&multibase).unwrap().1 == data && multibase_to_b64url(&multibase).unwrap() == b64url
+  }
+}
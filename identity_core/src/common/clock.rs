@@ -0,0 +1,87 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Debug;
+use core::fmt::Formatter;
+use std::sync::Arc;
+
+use crate::common::Timestamp;
+
+/// A source of the current time.
+///
+/// Validation options and document updates use a [`Clock`] wherever they would otherwise read the system time,
+/// allowing tests and deterministic replays to control time, and verifiers to rely on a trusted time source
+/// (e.g. Roughtime) instead of the local system clock.
+pub trait Clock: Debug + Send + Sync {
+  /// Returns the current time.
+  fn now(&self) -> Timestamp;
+}
+
+/// A [`Clock`] reading the system time through [`Timestamp::now_utc`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+  fn now(&self) -> Timestamp {
+    Timestamp::now_utc()
+  }
+}
+
+/// A [`Clock`] that always returns the same [`Timestamp`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock(Timestamp);
+
+impl FixedClock {
+  /// Creates a new [`FixedClock`] stopped at `timestamp`.
+  pub fn new(timestamp: Timestamp) -> Self {
+    Self(timestamp)
+  }
+}
+
+impl Clock for FixedClock {
+  fn now(&self) -> Timestamp {
+    self.0
+  }
+}
+
+/// A cheaply clonable handle to a [`Clock`], defaulting to the [`SystemClock`].
+#[derive(Clone)]
+pub struct SharedClock(Arc<dyn Clock>);
+
+impl SharedClock {
+  /// Wraps `clock` into a [`SharedClock`].
+  pub fn new(clock: impl Clock + 'static) -> Self {
+    Self(Arc::new(clock))
+  }
+}
+
+impl Default for SharedClock {
+  fn default() -> Self {
+    Self::new(SystemClock)
+  }
+}
+
+impl Debug for SharedClock {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    Debug::fmt(&self.0, f)
+  }
+}
+
+impl Clock for SharedClock {
+  fn now(&self) -> Timestamp {
+    self.0.now()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn shared_clock_delegates_to_the_wrapped_clock() {
+    let timestamp: Timestamp = Timestamp::parse("2010-01-01T00:00:00Z").unwrap();
+    let clock: SharedClock = SharedClock::new(FixedClock::new(timestamp));
+    assert_eq!(clock.now(), timestamp);
+    assert_eq!(clock.clone().now(), timestamp);
+  }
+}
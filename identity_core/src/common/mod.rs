@@ -3,6 +3,7 @@
 
 //! Definitions of common types (`Url`, `Timestamp`, JSON types, etc).
 
+pub use self::clock::*;
 pub use self::context::Context;
 pub use self::data_url::*;
 pub use self::key_comparable::KeyComparable;
@@ -17,6 +18,7 @@ pub use product_common::object::Object;
 pub use product_common::object::Value;
 pub use string_or_url::StringOrUrl;
 
+mod clock;
 mod context;
 mod data_url;
 mod key_comparable;
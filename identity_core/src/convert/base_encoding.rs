@@ -112,6 +112,26 @@ impl BaseEncoding {
       .map_err(|err| Error::DecodeBase(base, err))
   }
 
+  /// Decodes the given `data` encoded as [`Base::Base64Url`] into `output`, replacing its content.
+  ///
+  /// Unlike `decode(data, Base64Url)`, `data` is decoded straight from bytes without being validated as UTF-8
+  /// first, and `output` can be reused across calls to avoid allocating a new buffer per call.
+  pub fn decode_base64url_into<T>(data: &T, output: &mut Vec<u8>) -> Result<()>
+  where
+    T: AsRef<[u8]> + ?Sized,
+  {
+    let data: &[u8] = data.as_ref();
+    let invalid = |_| Error::DecodeBase(Base::Base64Url, multibase::Error::InvalidBaseString);
+    let len: usize = data_encoding::BASE64URL_NOPAD.decode_len(data.len()).map_err(invalid)?;
+    output.clear();
+    output.resize(len, 0);
+    let len: usize = data_encoding::BASE64URL_NOPAD
+      .decode_mut(data, output)
+      .map_err(|partial| invalid(partial.error))?;
+    output.truncate(len);
+    Ok(())
+  }
+
   /// Encodes the given `data` to [`Base::Base58Btc`].
   ///
   /// Equivalent to `encode(data, Base58Btc)`.
@@ -190,6 +210,24 @@ mod tests {
     );
   }
 
+  #[quickcheck]
+  fn test_base64url_into_random(data: Vec<u8>) {
+    let encoded: String = BaseEncoding::encode(&data, Base::Base64Url);
+    let mut output: Vec<u8> = vec![0xff; 3];
+    BaseEncoding::decode_base64url_into(&encoded, &mut output).unwrap();
+    assert_eq!(output, data);
+    assert_eq!(output, BaseEncoding::decode(&encoded, Base::Base64Url).unwrap());
+  }
+
+  #[test]
+  fn test_base64url_into_invalid() {
+    let mut output: Vec<u8> = Vec::new();
+    for invalid in ["a", "aGVsbG8=", "aGV+bG8", "aGVs bG8"] {
+      assert!(BaseEncoding::decode_base64url_into(invalid, &mut output).is_err());
+      assert!(BaseEncoding::decode(invalid, Base::Base64Url).is_err());
+    }
+  }
+
   /// Base58 test vectors from Internet Engineering Task Force (IETF) Draft.
   /// https://datatracker.ietf.org/doc/html/draft-msporny-base58-02#section-5
   #[test]
@@ -2,9 +2,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use core::fmt::Formatter;
+use std::collections::BTreeMap;
 
 use serde::Deserialize;
 use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
 
 use crate::error::Error;
 use crate::error::Result;
@@ -30,10 +33,37 @@ pub trait ToJson: Serialize + Sized {
   fn to_json_pretty(&self) -> Result<String> {
     serde_json::to_string_pretty(self).map_err(Error::EncodeJSON)
   }
+
+  /// Serialize `self` as a string of canonical JSON, see [`to_canonical_json_vec`](ToJson::to_canonical_json_vec).
+  fn to_canonical_json(&self) -> Result<String> {
+    serde_json::to_string(&sort_keys(self.to_json_value()?)).map_err(Error::EncodeJSON)
+  }
+
+  /// Serialize `self` as a JSON byte vector in canonical form: without insignificant whitespace and with the members
+  /// of every object sorted by key.
+  ///
+  /// The output does not depend on the order in which `self` serializes its members, e.g. after being deserialized
+  /// from JSON with reordered members, which makes it suitable as a signing input.
+  fn to_canonical_json_vec(&self) -> Result<Vec<u8>> {
+    serde_json::to_vec(&sort_keys(self.to_json_value()?)).map_err(Error::EncodeJSON)
+  }
 }
 
 impl<T> ToJson for T where T: Serialize {}
 
+/// Recursively sorts the members of all the objects in `value` by key, regardless of the order the map
+/// implementation of `serde_json` preserves.
+fn sort_keys(value: Value) -> Value {
+  match value {
+    Value::Object(object) => {
+      let sorted: BTreeMap<String, Value> = object.into_iter().map(|(key, value)| (key, sort_keys(value))).collect();
+      Value::Object(sorted.into_iter().collect::<Map<String, Value>>())
+    }
+    Value::Array(values) => Value::Array(values.into_iter().map(sort_keys).collect()),
+    value => value,
+  }
+}
+
 // =============================================================================
 // =============================================================================
 
@@ -53,6 +83,13 @@ pub trait FromJson: for<'de> Deserialize<'de> + Sized {
   fn from_json_value(json: serde_json::Value) -> Result<Self> {
     serde_json::from_value(json).map_err(Error::DecodeJSON)
   }
+
+  /// Deserialize `Self` from a borrowed [`serde_json::Value`].
+  ///
+  /// Unlike `from_json_value(json.clone())`, the value is neither cloned nor re-serialized.
+  fn from_json_value_ref(json: &serde_json::Value) -> Result<Self> {
+    Self::deserialize(json).map_err(Error::DecodeJSON)
+  }
 }
 
 impl<T> FromJson for T where T: for<'de> Deserialize<'de> + Sized {}
@@ -74,3 +111,25 @@ pub trait FmtJson: ToJson {
 }
 
 impl<T> FmtJson for T where T: ToJson {}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn canonical_json_sorts_nested_objects() {
+    let mut inner: Map<String, Value> = Map::new();
+    inner.insert("f".to_owned(), Value::from(1));
+    inner.insert("e".to_owned(), Value::from(2));
+    let mut outer: Map<String, Value> = Map::new();
+    outer.insert("b".to_owned(), Value::Array(vec![Value::Object(inner)]));
+    outer.insert("a".to_owned(), Value::Null);
+
+    let expected: &str = r#"{"a":null,"b":[{"e":2,"f":1}]}"#;
+    assert_eq!(Value::Object(outer.clone()).to_canonical_json().unwrap(), expected);
+    assert_eq!(
+      Value::Object(outer).to_canonical_json_vec().unwrap(),
+      expected.as_bytes()
+    );
+  }
+}
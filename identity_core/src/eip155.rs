@@ -0,0 +1,504 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! [CAIP](https://github.com/ChainAgnostic/CAIPs) identifiers for EVM chains in the
+//! [`eip155` namespace](https://github.com/ChainAgnostic/namespaces/tree/main/eip155).
+//!
+//! These types allow EVM accounts and tokens to be expressed as credential subjects or claim values alongside IOTA
+//! Resource Locators, e.g. `eip155:1:0xab16a96D359eC26a11e2C2b3d8f8B8942d5Bfcdb` for an Ethereum mainnet account or
+//! `eip155:1/erc721:0x06012c8cf97BEaD5deAe237070F9587f8E7A266d/771769` for a single NFT.
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::str::FromStr;
+
+use sha3::Digest;
+use sha3::Keccak256;
+
+use crate::common::Url;
+
+/// The CAIP-2 namespace of EVM chains.
+pub const EIP155_NAMESPACE: &str = "eip155";
+
+/// Alias for a `Result` with the error type [`Eip155Error`].
+pub type Eip155Result<T> = Result<T, Eip155Error>;
+
+/// Errors that can occur when parsing `eip155` identifiers.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum Eip155Error {
+  /// The identifier is not in the `eip155` namespace.
+  #[error("expected namespace `{EIP155_NAMESPACE}`, found `{0}`")]
+  InvalidNamespace(String),
+  /// The chain reference is not a decimal EIP-155 chain ID.
+  #[error("invalid EIP-155 chain ID `{0}`")]
+  InvalidChainId(String),
+  /// The address is not a `0x`-prefixed 20 bytes hexadecimal string.
+  #[error("invalid EVM address `{0}`")]
+  InvalidAddress(String),
+  /// The address mixes upper and lower case characters but does not match its EIP-55 checksum.
+  #[error("EVM address `{0}` does not match its EIP-55 checksum")]
+  InvalidChecksum(String),
+  /// The asset namespace is not supported.
+  #[error("unsupported asset namespace `{0}`")]
+  UnsupportedAssetNamespace(String),
+  /// The token ID is missing, unexpected or malformed for the asset namespace.
+  #[error("invalid token ID: {0}")]
+  InvalidTokenId(&'static str),
+  /// The identifier does not follow the CAIP grammar.
+  #[error("malformed identifier `{0}`")]
+  Malformed(String),
+}
+
+crate::impl_error_code!(Eip155Error {
+  InvalidNamespace => 1050,
+  InvalidChainId => 1051,
+  InvalidAddress => 1052,
+  InvalidChecksum => 1053,
+  UnsupportedAssetNamespace => 1054,
+  InvalidTokenId => 1055,
+  Malformed => 1056,
+});
+
+/// A [CAIP-2](https://github.com/ChainAgnostic/CAIPs/blob/main/CAIPs/caip-2.md) chain ID of an EVM chain, e.g.
+/// `eip155:1` for Ethereum mainnet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub struct Eip155ChainId(u64);
+
+impl Eip155ChainId {
+  /// Creates the chain ID of the EVM chain with the given EIP-155 `chain_id`.
+  pub const fn new(chain_id: u64) -> Self {
+    Self(chain_id)
+  }
+
+  /// Returns the EIP-155 chain ID.
+  pub const fn reference(&self) -> u64 {
+    self.0
+  }
+}
+
+impl Display for Eip155ChainId {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    write!(f, "{EIP155_NAMESPACE}:{}", self.0)
+  }
+}
+
+impl FromStr for Eip155ChainId {
+  type Err = Eip155Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let (namespace, reference) = s.split_once(':').ok_or_else(|| Eip155Error::Malformed(s.to_owned()))?;
+    if namespace != EIP155_NAMESPACE {
+      return Err(Eip155Error::InvalidNamespace(namespace.to_owned()));
+    }
+    // Reject signs and leading zeros, which `u64::from_str` would accept.
+    let is_canonical =
+      reference.bytes().all(|c| c.is_ascii_digit()) && (reference == "0" || !reference.starts_with('0'));
+    reference
+      .parse()
+      .ok()
+      .filter(|_| is_canonical)
+      .map(Self)
+      .ok_or_else(|| Eip155Error::InvalidChainId(reference.to_owned()))
+  }
+}
+
+impl TryFrom<String> for Eip155ChainId {
+  type Error = Eip155Error;
+
+  fn try_from(value: String) -> Result<Self, Self::Error> {
+    value.parse()
+  }
+}
+
+impl From<Eip155ChainId> for String {
+  fn from(value: Eip155ChainId) -> Self {
+    value.to_string()
+  }
+}
+
+/// A 20 bytes EVM address, displayed with its [EIP-55](https://eips.ethereum.org/EIPS/eip-55) checksum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EvmAddress([u8; 20]);
+
+impl EvmAddress {
+  /// Creates an address from its bytes.
+  pub const fn new(bytes: [u8; 20]) -> Self {
+    Self(bytes)
+  }
+
+  /// Returns the bytes of the address.
+  pub const fn as_bytes(&self) -> &[u8; 20] {
+    &self.0
+  }
+
+  /// Returns the EIP-55 checksummed representation of the address, including the `0x` prefix.
+  pub fn to_checksum(&self) -> String {
+    let lowercase: String = self.0.iter().map(|byte| format!("{byte:02x}")).collect();
+    let hash = Keccak256::digest(lowercase.as_bytes());
+
+    let mut checksummed = String::with_capacity(42);
+    checksummed.push_str("0x");
+    for (idx, c) in lowercase.chars().enumerate() {
+      let nibble = (hash[idx / 2] >> (if idx % 2 == 0 { 4 } else { 0 })) & 0x0f;
+      checksummed.push(if nibble >= 8 { c.to_ascii_uppercase() } else { c });
+    }
+    checksummed
+  }
+}
+
+impl Display for EvmAddress {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    f.write_str(&self.to_checksum())
+  }
+}
+
+impl FromStr for EvmAddress {
+  type Err = Eip155Error;
+
+  /// Parses a `0x`-prefixed hexadecimal address.
+  ///
+  /// All-lowercase and all-uppercase addresses carry no checksum and are accepted as-is, mixed-case addresses must
+  /// match their EIP-55 checksum.
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let hex = s
+      .strip_prefix("0x")
+      .filter(|hex| hex.len() == 40 && hex.bytes().all(|c| c.is_ascii_hexdigit()))
+      .ok_or_else(|| Eip155Error::InvalidAddress(s.to_owned()))?;
+
+    let mut bytes = [0; 20];
+    for (idx, byte) in bytes.iter_mut().enumerate() {
+      *byte =
+        u8::from_str_radix(&hex[2 * idx..2 * idx + 2], 16).map_err(|_| Eip155Error::InvalidAddress(s.to_owned()))?;
+    }
+    let address = Self(bytes);
+
+    let is_mixed_case = hex.bytes().any(|c| c.is_ascii_lowercase()) && hex.bytes().any(|c| c.is_ascii_uppercase());
+    if is_mixed_case && address.to_checksum() != s {
+      return Err(Eip155Error::InvalidChecksum(s.to_owned()));
+    }
+
+    Ok(address)
+  }
+}
+
+/// A [CAIP-10](https://github.com/ChainAgnostic/CAIPs/blob/main/CAIPs/caip-10.md) account ID of an EVM account, e.g.
+/// `eip155:1:0xab16a96D359eC26a11e2C2b3d8f8B8942d5Bfcdb`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub struct Eip155AccountId {
+  chain_id: Eip155ChainId,
+  address: EvmAddress,
+}
+
+impl Eip155AccountId {
+  /// Creates the account ID of `address` on the chain `chain_id`.
+  pub const fn new(chain_id: Eip155ChainId, address: EvmAddress) -> Self {
+    Self { chain_id, address }
+  }
+
+  /// Returns the chain ID of the account.
+  pub const fn chain_id(&self) -> Eip155ChainId {
+    self.chain_id
+  }
+
+  /// Returns the address of the account.
+  pub const fn address(&self) -> EvmAddress {
+    self.address
+  }
+}
+
+impl Display for Eip155AccountId {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    write!(f, "{}:{}", self.chain_id, self.address)
+  }
+}
+
+impl FromStr for Eip155AccountId {
+  type Err = Eip155Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let (chain_id, address) = s.rsplit_once(':').ok_or_else(|| Eip155Error::Malformed(s.to_owned()))?;
+    Ok(Self {
+      chain_id: chain_id.parse()?,
+      address: address.parse()?,
+    })
+  }
+}
+
+impl TryFrom<String> for Eip155AccountId {
+  type Error = Eip155Error;
+
+  fn try_from(value: String) -> Result<Self, Self::Error> {
+    value.parse()
+  }
+}
+
+impl From<Eip155AccountId> for String {
+  fn from(value: Eip155AccountId) -> Self {
+    value.to_string()
+  }
+}
+
+impl From<Eip155AccountId> for Url {
+  fn from(account: Eip155AccountId) -> Self {
+    Url::parse(account.to_string()).expect("a CAIP-10 account ID is always a valid URL")
+  }
+}
+
+/// The asset namespaces supported in [`Eip155AssetId`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum Eip155AssetNamespace {
+  /// A fungible [ERC-20](https://eips.ethereum.org/EIPS/eip-20) token.
+  Erc20,
+  /// A non-fungible [ERC-721](https://eips.ethereum.org/EIPS/eip-721) token.
+  Erc721,
+}
+
+impl Eip155AssetNamespace {
+  /// Returns the CAIP-19 name of the namespace.
+  pub const fn as_str(&self) -> &'static str {
+    match self {
+      Self::Erc20 => "erc20",
+      Self::Erc721 => "erc721",
+    }
+  }
+}
+
+impl Display for Eip155AssetNamespace {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+impl FromStr for Eip155AssetNamespace {
+  type Err = Eip155Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "erc20" => Ok(Self::Erc20),
+      "erc721" => Ok(Self::Erc721),
+      _ => Err(Eip155Error::UnsupportedAssetNamespace(s.to_owned())),
+    }
+  }
+}
+
+/// A [CAIP-19](https://github.com/ChainAgnostic/CAIPs/blob/main/CAIPs/caip-19.md) asset ID of a token deployed on an
+/// EVM chain, e.g. `eip155:1/erc20:0x6B175474E89094C44Da98b954EedeAC495271d0F` for an ERC-20 token or
+/// `eip155:1/erc721:0x06012c8cf97BEaD5deAe237070F9587f8E7A266d/771769` for a single ERC-721 token.
+///
+/// ERC-721 asset IDs without a token ID identify the whole collection.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub struct Eip155AssetId {
+  chain_id: Eip155ChainId,
+  namespace: Eip155AssetNamespace,
+  contract: EvmAddress,
+  token_id: Option<String>,
+}
+
+impl Eip155AssetId {
+  /// Creates the asset ID of the ERC-20 token deployed at `contract` on the chain `chain_id`.
+  pub const fn erc20(chain_id: Eip155ChainId, contract: EvmAddress) -> Self {
+    Self {
+      chain_id,
+      namespace: Eip155AssetNamespace::Erc20,
+      contract,
+      token_id: None,
+    }
+  }
+
+  /// Creates the asset ID of the ERC-721 collection deployed at `contract` on the chain `chain_id`, or of its token
+  /// `token_id` if given.
+  ///
+  /// # Errors
+  /// Fails if `token_id` is not a decimal unsigned 256-bit integer.
+  pub fn erc721(chain_id: Eip155ChainId, contract: EvmAddress, token_id: Option<String>) -> Eip155Result<Self> {
+    if let Some(token_id) = &token_id {
+      validate_erc721_token_id(token_id)?;
+    }
+    Ok(Self {
+      chain_id,
+      namespace: Eip155AssetNamespace::Erc721,
+      contract,
+      token_id,
+    })
+  }
+
+  /// Returns the chain ID of the asset.
+  pub const fn chain_id(&self) -> Eip155ChainId {
+    self.chain_id
+  }
+
+  /// Returns the namespace of the asset.
+  pub const fn namespace(&self) -> Eip155AssetNamespace {
+    self.namespace
+  }
+
+  /// Returns the address of the contract of the asset.
+  pub const fn contract(&self) -> EvmAddress {
+    self.contract
+  }
+
+  /// Returns the ID of the token, if the asset identifies a single non-fungible token.
+  pub fn token_id(&self) -> Option<&str> {
+    self.token_id.as_deref()
+  }
+}
+
+fn validate_erc721_token_id(token_id: &str) -> Eip155Result<()> {
+  // 2^256 - 1 has 78 decimal digits.
+  if token_id.is_empty() || token_id.len() > 78 || !token_id.bytes().all(|c| c.is_ascii_digit()) {
+    return Err(Eip155Error::InvalidTokenId(
+      "an ERC-721 token ID must be a decimal unsigned 256-bit integer",
+    ));
+  }
+  Ok(())
+}
+
+impl Display for Eip155AssetId {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    write!(f, "{}/{}:{}", self.chain_id, self.namespace, self.contract)?;
+    if let Some(token_id) = &self.token_id {
+      write!(f, "/{token_id}")?;
+    }
+    Ok(())
+  }
+}
+
+impl FromStr for Eip155AssetId {
+  type Err = Eip155Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let malformed = || Eip155Error::Malformed(s.to_owned());
+    let mut parts = s.split('/');
+    let chain_id: Eip155ChainId = parts.next().ok_or_else(malformed)?.parse()?;
+    let (namespace, contract) = parts
+      .next()
+      .and_then(|asset| asset.split_once(':'))
+      .ok_or_else(malformed)?;
+    let token_id = parts.next();
+    if parts.next().is_some() {
+      return Err(malformed());
+    }
+    let contract: EvmAddress = contract.parse()?;
+
+    match namespace.parse()? {
+      Eip155AssetNamespace::Erc20 if token_id.is_some() => {
+        Err(Eip155Error::InvalidTokenId("an ERC-20 asset ID cannot have a token ID"))
+      }
+      Eip155AssetNamespace::Erc20 => Ok(Self::erc20(chain_id, contract)),
+      Eip155AssetNamespace::Erc721 => Self::erc721(chain_id, contract, token_id.map(ToOwned::to_owned)),
+    }
+  }
+}
+
+impl TryFrom<String> for Eip155AssetId {
+  type Error = Eip155Error;
+
+  fn try_from(value: String) -> Result<Self, Self::Error> {
+    value.parse()
+  }
+}
+
+impl From<Eip155AssetId> for String {
+  fn from(value: Eip155AssetId) -> Self {
+    value.to_string()
+  }
+}
+
+impl From<Eip155AssetId> for Url {
+  fn from(asset: Eip155AssetId) -> Self {
+    Url::parse(asset.to_string()).expect("a CAIP-19 asset ID is always a valid URL")
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn eip55_checksums() {
+    for address in [
+      "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+      "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+      "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+      "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+    ] {
+      let parsed: EvmAddress = address.parse().unwrap();
+      assert_eq!(parsed.to_string(), address);
+      assert_eq!(address.to_lowercase().parse::<EvmAddress>().unwrap(), parsed);
+    }
+
+    assert_eq!(
+      "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD".parse::<EvmAddress>(),
+      Err(Eip155Error::InvalidChecksum(
+        "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD".to_owned()
+      ))
+    );
+    assert!(matches!(
+      "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeA".parse::<EvmAddress>(),
+      Err(Eip155Error::InvalidAddress(_))
+    ));
+  }
+
+  #[test]
+  fn account_ids() {
+    let account: Eip155AccountId = "eip155:1:0xfb6916095ca1df60bb79ce92ce3ea74c37c5d359".parse().unwrap();
+    assert_eq!(account.chain_id(), Eip155ChainId::new(1));
+    assert_eq!(
+      account.to_string(),
+      "eip155:1:0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359"
+    );
+    assert_eq!(
+      Url::from(account).as_str(),
+      "eip155:1:0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359"
+    );
+
+    let json = serde_json::to_value(account).unwrap();
+    assert_eq!(serde_json::from_value::<Eip155AccountId>(json).unwrap(), account);
+
+    assert!(matches!(
+      "bip122:000000000019d6689c085ae165831e93:0xfb6916095ca1df60bb79ce92ce3ea74c37c5d359".parse::<Eip155AccountId>(),
+      Err(Eip155Error::InvalidNamespace(_))
+    ));
+    assert!(matches!(
+      "eip155:01:0xfb6916095ca1df60bb79ce92ce3ea74c37c5d359".parse::<Eip155AccountId>(),
+      Err(Eip155Error::InvalidChainId(_))
+    ));
+  }
+
+  #[test]
+  fn asset_ids() {
+    let erc20: Eip155AssetId = "eip155:1/erc20:0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+      .parse()
+      .unwrap();
+    assert_eq!(erc20.namespace(), Eip155AssetNamespace::Erc20);
+    assert_eq!(erc20.token_id(), None);
+
+    let nft: Eip155AssetId = "eip155:137/erc721:0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed/771769"
+      .parse()
+      .unwrap();
+    assert_eq!(nft.chain_id().reference(), 137);
+    assert_eq!(nft.token_id(), Some("771769"));
+    assert_eq!(
+      nft.to_string(),
+      "eip155:137/erc721:0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed/771769"
+    );
+
+    assert!(matches!(
+      "eip155:1/erc20:0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed/1".parse::<Eip155AssetId>(),
+      Err(Eip155Error::InvalidTokenId(_))
+    ));
+    assert!(matches!(
+      "eip155:1/erc721:0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed/0x1".parse::<Eip155AssetId>(),
+      Err(Eip155Error::InvalidTokenId(_))
+    ));
+    assert!(matches!(
+      "eip155:1/erc1155:0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".parse::<Eip155AssetId>(),
+      Err(Eip155Error::UnsupportedAssetNamespace(_))
+    ));
+  }
+}
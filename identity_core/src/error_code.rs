@@ -0,0 +1,165 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Stable error codes for the public error types of the IOTA Identity crates.
+//!
+//! Every error implementing [`HasErrorCode`] maps to an [`ErrorCode`] made of a number and the name of the error
+//! variant, rendered as e.g. `IOTA_ID_1606 Signature`. Codes are stable across releases: a number is never
+//! reassigned to a different error, and the numbers of removed errors are retired rather than reused. Callers should
+//! branch on [`ErrorCode::number`] rather than on error messages, which may change at any time.
+//!
+//! Numbers are allocated in ranges per error type:
+//!
+//! - 1000–1049: `identity_core::Error`
+//! - 1050–1069: `identity_core::eip155::Eip155Error`
+//! - 1100–1199: `identity_did::Error`
+//! - 1200–1299: `identity_jose::error::Error`
+//! - 1300–1399: `identity_verification::Error`
+//! - 1400–1499: `identity_document::Error`
+//! - 1500–1539: `identity_credential::Error`
+//! - 1540–1559: `identity_credential::revocation::RevocationError`
+//! - 1560–1569: `identity_credential::revocation::StatusListError`
+//! - 1570–1599: `identity_credential::revocation::StatusList2021CredentialError`
+//! - 1600–1699: `identity_credential::validator::JwtValidationError` and compound validation errors
+//! - 1700–1749: `identity_credential::validator::KeyBindingJwtError`
+//! - 1750–1799: `identity_credential::domain_linkage::DomainLinkageValidationError`
+//! - 1800–1849: `identity_resolver::Error`
+//! - 1850–1899: `identity_resolver::LinkedVpError`
+//! - 1900–1919: `identity_storage::KeyStorageError`
+//! - 1920–1939: `identity_storage::KeyIdStorageError`
+//! - 1940–1999: `identity_storage::JwkStorageDocumentError`
+//! - 2000–2099: `identity_iota_core::Error`
+//! - 2100–2199: `identity_iota_core::rebased::Error`
+//! - 2200–2249: `identity_storage::IssuanceSessionError`
+//! - 2250–2299: `identity_credential::account_control::AccountControlError`
+//! - 2300–2349: `identity_credential::delegation::DelegationError`
+//! - 2350–2399: `identity_credential::wallet_attestation::WalletAttestationError`
+//! - 2400–2449: `identity_credential::dcql::DcqlError`
+//! - 2450–2469: `identity_credential::did_jwt::DidJwtError`
+//! - 2470–2479: `identity_credential::credential::CredentialDiffError`
+//! - 2480–2489: `identity_credential::validator::CrossCredentialError`
+//! - 2500–2509: `identity_storage::KeyUsageViolation`
+//! - 2510–2519: `identity_credential::validator::AnyCredentialError`
+//! - 2520–2529: `identity_credential::revocation::allocation::IndexAllocationError`
+//! - 2530–2539: `identity_credential::revocation::notification::RevocationNotificationError`
+//! - 2540–2549: `identity_core::codec::CodecError`
+//! - 2550–2559: `identity_resolver::VerifierContextError`
+//! - 2560–2569: `identity_resolver::OfflineBundleError`
+//! - 2570–2579: `identity_credential::validator::ContinuityError`
+//! - 2580–2589: `identity_eddsa_verifier::BatchVerificationError`
+//! - 2590–2599: `identity_core::http::HttpError`
+
+use core::fmt::Debug;
+use core::fmt::Display;
+use core::fmt::Formatter;
+
+use crate::common::SingleStructError;
+
+/// The prefix of every rendered [`ErrorCode`].
+pub const ERROR_CODE_PREFIX: &str = "IOTA_ID_";
+
+/// A stable identifier of an error, see the [module documentation](self).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ErrorCode {
+  number: u16,
+  name: &'static str,
+}
+
+impl ErrorCode {
+  /// Creates a new [`ErrorCode`] from its `number` and the `name` of the error it identifies.
+  pub const fn new(number: u16, name: &'static str) -> Self {
+    Self { number, name }
+  }
+
+  /// Returns the stable number of this code, e.g. `1606`.
+  pub const fn number(&self) -> u16 {
+    self.number
+  }
+
+  /// Returns the name of the error identified by this code, e.g. `"Signature"`.
+  pub const fn name(&self) -> &'static str {
+    self.name
+  }
+
+  /// Returns the code without the error name, e.g. `"IOTA_ID_1606"`.
+  pub fn code(&self) -> String {
+    format!("{ERROR_CODE_PREFIX}{:04}", self.number)
+  }
+}
+
+impl Display for ErrorCode {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    write!(f, "{ERROR_CODE_PREFIX}{:04} {}", self.number, self.name)
+  }
+}
+
+/// An error that can be identified by a stable [`ErrorCode`].
+pub trait HasErrorCode {
+  /// Returns the [`ErrorCode`] identifying this error.
+  fn error_code(&self) -> ErrorCode;
+}
+
+impl<T: HasErrorCode + Debug + Display> HasErrorCode for SingleStructError<T> {
+  fn error_code(&self) -> ErrorCode {
+    self.kind().error_code()
+  }
+}
+
+/// Implements [`HasErrorCode`] for an enum by assigning a number to each of its variants.
+///
+/// The name of each [`ErrorCode`] is the name of the corresponding variant. Since the generated `match` is exhaustive,
+/// adding a variant without assigning it a number is a compile error.
+///
+/// ```
+/// # use identity_core::error_code::HasErrorCode;
+/// # use identity_core::impl_error_code;
+/// enum MyError {
+///   NotFound,
+///   Invalid(String),
+/// }
+///
+/// impl_error_code!(MyError {
+///   NotFound => 9000,
+///   Invalid => 9001,
+/// });
+///
+/// assert_eq!(MyError::Invalid("oops".to_owned()).error_code().to_string(), "IOTA_ID_9001 Invalid");
+/// ```
+#[macro_export]
+macro_rules! impl_error_code {
+  ($ty:ty { $($(#[$meta:meta])* $variant:ident => $number:literal),* $(,)? }) => {
+    impl $crate::error_code::HasErrorCode for $ty {
+      fn error_code(&self) -> $crate::error_code::ErrorCode {
+        match self {
+          $($(#[$meta])* Self::$variant { .. } => $crate::error_code::ErrorCode::new($number, stringify!($variant)),)*
+        }
+      }
+    }
+  };
+}
+
+impl_error_code!(crate::Error {
+  EncodeJSON => 1000,
+  DecodeJSON => 1001,
+  DecodeBase => 1002,
+  DecodeMultibase => 1003,
+  InvalidUrl => 1004,
+  InvalidTimestamp => 1005,
+  OneOrSetEmpty => 1006,
+  OrderedSetDuplicate => 1007,
+});
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::Error;
+
+  #[test]
+  fn error_code_formatting() {
+    let code: ErrorCode = Error::OrderedSetDuplicate.error_code();
+    assert_eq!(code.number(), 1007);
+    assert_eq!(code.name(), "OrderedSetDuplicate");
+    assert_eq!(code.code(), "IOTA_ID_1007");
+    assert_eq!(code.to_string(), "IOTA_ID_1007 OrderedSetDuplicate");
+  }
+}
@@ -0,0 +1,98 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use js_sys::Uint8Array;
+use wasm_bindgen::prelude::wasm_bindgen;
+use wasm_bindgen::JsCast;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::Request;
+use web_sys::RequestInit;
+use web_sys::Response;
+
+use super::check_response_size;
+use super::HttpClient;
+use super::HttpError;
+use super::HttpMethod;
+use super::HttpRequest;
+use super::HttpResponse;
+
+#[wasm_bindgen]
+extern "C" {
+  // The global `fetch` function, available both in browsers and in Node.js.
+  #[wasm_bindgen(js_name = fetch)]
+  fn global_fetch(input: &Request) -> js_sys::Promise;
+}
+
+/// An [`HttpClient`] using the [Fetch API](https://developer.mozilla.org/docs/Web/API/Fetch_API) of the JavaScript
+/// runtime.
+#[derive(Debug, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct FetchClient;
+
+impl FetchClient {
+  /// Creates a new [`FetchClient`].
+  pub fn new() -> Self {
+    Self
+  }
+}
+
+#[async_trait(?Send)]
+impl HttpClient for FetchClient {
+  async fn send(&self, request: HttpRequest) -> Result<HttpResponse, HttpError> {
+    let init = RequestInit::new();
+    init.set_method(request.method.as_str());
+    if !request.body.is_empty() && !matches!(request.method, HttpMethod::Get | HttpMethod::Head) {
+      init.set_body(&Uint8Array::from(request.body.as_slice()).into());
+    }
+
+    let js_request = Request::new_with_str_and_init(request.url.as_str(), &init).map_err(js_error)?;
+    for (name, value) in &request.headers {
+      js_request.headers().set(name, value).map_err(js_error)?;
+    }
+
+    let response: Response = JsFuture::from(global_fetch(&js_request))
+      .await
+      .and_then(JsCast::dyn_into)
+      .map_err(js_error)?;
+
+    // The `Content-Length` header is checked before downloading the body, when available.
+    let headers = response.headers();
+    if let Some(length) = headers
+      .get("content-length")
+      .ok()
+      .flatten()
+      .and_then(|length| length.parse::<usize>().ok())
+    {
+      check_response_size(length, request.max_response_size)?;
+    }
+    let header_entries: Vec<(String, String)> = js_sys::try_iter(&headers)
+      .ok()
+      .flatten()
+      .into_iter()
+      .flatten()
+      .filter_map(|entry| {
+        let entry: js_sys::Array = entry.ok()?.dyn_into().ok()?;
+        Some((entry.get(0).as_string()?, entry.get(1).as_string()?))
+      })
+      .collect();
+
+    let buffer = JsFuture::from(response.array_buffer().map_err(js_error)?)
+      .await
+      .map_err(js_error)?;
+    let body: Vec<u8> = Uint8Array::new(&buffer).to_vec();
+    check_response_size(body.len(), request.max_response_size)?;
+
+    Ok(HttpResponse::new(response.status(), header_entries, body))
+  }
+}
+
+fn js_error(error: JsValue) -> HttpError {
+  let message: String = error
+    .dyn_ref::<js_sys::Error>()
+    .map(|error| String::from(error.message()))
+    .or_else(|| error.as_string())
+    .unwrap_or_else(|| format!("{error:?}"));
+  HttpError::request(message)
+}
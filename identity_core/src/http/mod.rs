@@ -0,0 +1,254 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! An HTTP abstraction used by the library for all its outbound requests.
+//!
+//! Components that fetch remote resources accept any [`HttpClient`], which makes it possible to configure proxies,
+//! timeouts and TLS policies in a single place. Implementations backed by
+//! [`reqwest`](https://docs.rs/reqwest) and by the
+//! [Fetch API](https://developer.mozilla.org/docs/Web/API/Fetch_API) are provided through the `reqwest-client` and
+//! `fetch-client` features respectively.
+
+use async_trait::async_trait;
+
+use crate::common::Url;
+
+#[cfg(all(feature = "fetch-client", target_arch = "wasm32", not(target_os = "wasi")))]
+pub use self::fetch_client::FetchClient;
+#[cfg(feature = "reqwest-client")]
+pub use self::reqwest_client::ReqwestClient;
+
+#[cfg(all(feature = "fetch-client", target_arch = "wasm32", not(target_os = "wasi")))]
+mod fetch_client;
+#[cfg(feature = "reqwest-client")]
+mod reqwest_client;
+
+/// The method of an [`HttpRequest`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum HttpMethod {
+  /// `GET`
+  Get,
+  /// `POST`
+  Post,
+  /// `PUT`
+  Put,
+  /// `DELETE`
+  Delete,
+  /// `HEAD`
+  Head,
+}
+
+impl HttpMethod {
+  /// Returns the method's name, as it appears in a request line.
+  pub const fn as_str(&self) -> &'static str {
+    match self {
+      Self::Get => "GET",
+      Self::Post => "POST",
+      Self::Put => "PUT",
+      Self::Delete => "DELETE",
+      Self::Head => "HEAD",
+    }
+  }
+}
+
+/// A request to be sent by an [`HttpClient`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct HttpRequest {
+  /// The request's method.
+  pub method: HttpMethod,
+  /// The requested URL.
+  pub url: Url,
+  /// The request's headers, as name-value pairs.
+  pub headers: Vec<(String, String)>,
+  /// The request's body.
+  pub body: Vec<u8>,
+  /// The maximum size, in bytes, of the response body. Clients must fail with
+  /// [`HttpError::ResponseTooLarge`] when the response body exceeds it.
+  pub max_response_size: Option<usize>,
+}
+
+impl HttpRequest {
+  /// Creates a new request with no headers and an empty body.
+  pub fn new(method: HttpMethod, url: Url) -> Self {
+    Self {
+      method,
+      url,
+      headers: Vec::new(),
+      body: Vec::new(),
+      max_response_size: None,
+    }
+  }
+
+  /// Creates a new `GET` request for `url`.
+  pub fn get(url: Url) -> Self {
+    Self::new(HttpMethod::Get, url)
+  }
+
+  /// Adds a header to this request.
+  pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+    self.headers.push((name.into(), value.into()));
+    self
+  }
+
+  /// Sets the body of this request.
+  pub fn body(mut self, body: impl Into<Vec<u8>>) -> Self {
+    self.body = body.into();
+    self
+  }
+
+  /// Sets the maximum size, in bytes, of the response body.
+  pub fn max_response_size(mut self, max_response_size: usize) -> Self {
+    self.max_response_size = Some(max_response_size);
+    self
+  }
+}
+
+/// A response received by an [`HttpClient`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct HttpResponse {
+  /// The response's status code.
+  pub status: u16,
+  /// The response's headers, as name-value pairs.
+  pub headers: Vec<(String, String)>,
+  /// The response's body.
+  pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+  /// Creates a new response.
+  pub fn new(status: u16, headers: Vec<(String, String)>, body: Vec<u8>) -> Self {
+    Self { status, headers, body }
+  }
+
+  /// Returns `true` if the status code is in the `2xx` range.
+  pub fn is_success(&self) -> bool {
+    (200..300).contains(&self.status)
+  }
+
+  /// Returns the value of the first header named `name`, compared case-insensitively.
+  pub fn header(&self, name: &str) -> Option<&str> {
+    self
+      .headers
+      .iter()
+      .find(|(header, _)| header.eq_ignore_ascii_case(name))
+      .map(|(_, value)| value.as_str())
+  }
+
+  /// Returns this response's body if its status code is in the `2xx` range.
+  ///
+  /// # Errors
+  /// Fails with [`HttpError::Status`] otherwise.
+  pub fn into_success_body(self) -> Result<Vec<u8>, HttpError> {
+    if self.is_success() {
+      Ok(self.body)
+    } else {
+      Err(HttpError::Status(self.status))
+    }
+  }
+}
+
+/// Errors that may occur when sending an [`HttpRequest`].
+#[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum HttpError {
+  /// The request could not be sent or its response could not be received.
+  #[error("failed to send the HTTP request")]
+  Request(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
+  /// The response body exceeds the request's maximum response size.
+  #[error("the HTTP response exceeds the maximum size of {0} bytes")]
+  ResponseTooLarge(usize),
+  /// The response has a non-successful status code.
+  #[error("the HTTP request failed with status {0}")]
+  Status(u16),
+}
+
+impl HttpError {
+  /// Creates a new [`HttpError::Request`] from any error.
+  pub fn request(error: impl Into<Box<dyn std::error::Error + Send + Sync + 'static>>) -> Self {
+    Self::Request(error.into())
+  }
+}
+
+crate::impl_error_code!(HttpError {
+  Request => 2590,
+  ResponseTooLarge => 2591,
+  Status => 2592,
+});
+
+/// An asynchronous HTTP client.
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait HttpClient {
+  /// Sends `request` and returns its response, regardless of its status code.
+  async fn send(&self, request: HttpRequest) -> Result<HttpResponse, HttpError>;
+
+  /// Sends a `GET` request to `url` and returns the body of its response.
+  ///
+  /// # Errors
+  /// Fails with [`HttpError::Status`] if the response's status code is not in the `2xx` range.
+  async fn get(&self, url: Url) -> Result<Vec<u8>, HttpError> {
+    self.send(HttpRequest::get(url)).await?.into_success_body()
+  }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<C> HttpClient for &C
+where
+  C: HttpClient + ?Sized + Sync,
+{
+  async fn send(&self, request: HttpRequest) -> Result<HttpResponse, HttpError> {
+    (**self).send(request).await
+  }
+}
+
+/// Fails with [`HttpError::ResponseTooLarge`] if `size` exceeds `max_response_size`.
+#[cfg(any(feature = "reqwest-client", feature = "fetch-client", test))]
+pub(crate) fn check_response_size(size: usize, max_response_size: Option<usize>) -> Result<(), HttpError> {
+  match max_response_size {
+    Some(max) if size > max => Err(HttpError::ResponseTooLarge(max)),
+    _ => Ok(()),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn response_headers_are_case_insensitive() {
+    let response = HttpResponse::new(
+      200,
+      vec![("Content-Type".to_owned(), "application/json".to_owned())],
+      vec![],
+    );
+    assert_eq!(response.header("content-type"), Some("application/json"));
+    assert_eq!(response.header("accept"), None);
+  }
+
+  #[test]
+  fn unsuccessful_responses_have_no_body() {
+    let response = HttpResponse::new(404, vec![], b"not found".to_vec());
+    assert!(matches!(response.into_success_body(), Err(HttpError::Status(404))));
+  }
+
+  #[test]
+  fn http_errors_have_codes() {
+    use crate::error_code::HasErrorCode;
+
+    assert_eq!(HttpError::Status(404).error_code().to_string(), "IOTA_ID_2592 Status");
+  }
+
+  #[test]
+  fn response_size_is_checked() {
+    assert!(check_response_size(10, None).is_ok());
+    assert!(check_response_size(10, Some(10)).is_ok());
+    assert!(matches!(
+      check_response_size(11, Some(10)),
+      Err(HttpError::ResponseTooLarge(10))
+    ));
+  }
+}
@@ -0,0 +1,77 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+
+use super::check_response_size;
+use super::HttpClient;
+use super::HttpError;
+use super::HttpRequest;
+use super::HttpResponse;
+
+/// An [`HttpClient`] backed by a [`reqwest::Client`].
+///
+/// Proxies, timeouts, TLS and redirect policies are those of the wrapped client, e.g.
+/// ```
+/// # use identity_core::http::ReqwestClient;
+/// # fn main() -> Result<(), reqwest::Error> {
+/// let client = ReqwestClient::from(
+///   reqwest::Client::builder()
+///     .timeout(std::time::Duration::from_secs(10))
+///     .build()?,
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestClient(reqwest::Client);
+
+impl ReqwestClient {
+  /// Creates a new [`ReqwestClient`] wrapping a default [`reqwest::Client`].
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Returns a reference to the wrapped [`reqwest::Client`].
+  pub fn inner(&self) -> &reqwest::Client {
+    &self.0
+  }
+}
+
+impl From<reqwest::Client> for ReqwestClient {
+  fn from(client: reqwest::Client) -> Self {
+    Self(client)
+  }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl HttpClient for ReqwestClient {
+  async fn send(&self, request: HttpRequest) -> Result<HttpResponse, HttpError> {
+    let method = reqwest::Method::from_bytes(request.method.as_str().as_bytes()).map_err(HttpError::request)?;
+    let mut builder = self.0.request(method, request.url.as_str());
+    for (name, value) in request.headers {
+      builder = builder.header(name, value);
+    }
+    if !request.body.is_empty() {
+      builder = builder.body(request.body);
+    }
+
+    let mut response = builder.send().await.map_err(HttpError::request)?;
+    let status: u16 = response.status().as_u16();
+    let headers: Vec<(String, String)> = response
+      .headers()
+      .iter()
+      .filter_map(|(name, value)| Some((name.as_str().to_owned(), value.to_str().ok()?.to_owned())))
+      .collect();
+
+    // Read the body in chunks so that oversized responses are rejected early.
+    let mut body: Vec<u8> = Vec::new();
+    while let Some(chunk) = response.chunk().await.map_err(HttpError::request)? {
+      body.extend_from_slice(&chunk);
+      check_response_size(body.len(), request.max_response_size)?;
+    }
+
+    Ok(HttpResponse::new(status, headers, body))
+  }
+}
@@ -18,12 +18,24 @@
 #[doc(inline)]
 pub use serde_json::json;
 
+#[forbid(unsafe_code)]
+pub mod codec;
 #[forbid(unsafe_code)]
 pub mod common;
 #[forbid(unsafe_code)]
 pub mod convert;
+#[cfg(feature = "eip155")]
+#[forbid(unsafe_code)]
+pub mod eip155;
 #[forbid(unsafe_code)]
 pub mod error;
+#[forbid(unsafe_code)]
+pub mod error_code;
+#[cfg(feature = "http-client")]
+#[forbid(unsafe_code)]
+pub mod http;
+#[forbid(unsafe_code)]
+pub mod metrics;
 
 #[cfg(feature = "custom_time")]
 pub mod custom_time;
@@ -0,0 +1,165 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! A minimal facade for recording operational metrics.
+//!
+//! The IOTA Identity crates report counters and histograms through the process wide [`MetricsRecorder`] installed
+//! with [`set_metrics_recorder`]. When no recorder is installed, recording a metric is a no-op.
+//!
+//! An adapter for the [`metrics`](https://docs.rs/metrics) crate is available through [`MetricsCrateRecorder`]
+//! when the `metrics` feature is enabled.
+//!
+//! The names of the metrics emitted by the library are listed in [`names`].
+
+use std::sync::OnceLock;
+
+static RECORDER: OnceLock<Box<dyn MetricsRecorder>> = OnceLock::new();
+
+/// A key-value pair further qualifying a metric, e.g. `("method", "iota")`.
+pub type Label<'a> = (&'static str, &'a str);
+
+/// The `method` label of the metrics about DIDs whose method is not known to the component recording them.
+///
+/// DIDs are untrusted input, so only known methods are used as label values to keep the number of series bounded.
+pub const OTHER_METHOD: &str = "other";
+
+/// A sink for the metrics emitted by the IOTA Identity crates.
+pub trait MetricsRecorder: Send + Sync + 'static {
+  /// Increments the counter `name` identified by `labels` by one.
+  fn increment_counter(&self, name: &'static str, labels: &[Label<'_>]);
+  /// Records `value` in the histogram `name` identified by `labels`.
+  fn record_histogram(&self, name: &'static str, labels: &[Label<'_>], value: f64);
+}
+
+/// Caused by attempting to install a [`MetricsRecorder`] after one was already installed.
+#[derive(Debug, thiserror::Error)]
+#[error("a metrics recorder has already been installed")]
+#[non_exhaustive]
+pub struct SetRecorderError;
+
+/// Installs `recorder` as the process wide [`MetricsRecorder`].
+///
+/// # Errors
+/// Fails if a recorder has already been installed.
+pub fn set_metrics_recorder(recorder: impl MetricsRecorder) -> Result<(), SetRecorderError> {
+  RECORDER.set(Box::new(recorder)).map_err(|_| SetRecorderError)
+}
+
+/// Returns whether a [`MetricsRecorder`] has been installed.
+///
+/// Useful to avoid computing costly metric values, such as durations, when they would be discarded anyway.
+pub fn recorder_installed() -> bool {
+  RECORDER.get().is_some()
+}
+
+/// Increments the counter `name` identified by `labels` on the installed [`MetricsRecorder`], if any.
+pub fn increment_counter(name: &'static str, labels: &[Label<'_>]) {
+  if let Some(recorder) = RECORDER.get() {
+    recorder.increment_counter(name, labels);
+  }
+}
+
+/// Records `value` in the histogram `name` identified by `labels` on the installed [`MetricsRecorder`], if any.
+pub fn record_histogram(name: &'static str, labels: &[Label<'_>], value: f64) {
+  if let Some(recorder) = RECORDER.get() {
+    recorder.record_histogram(name, labels, value);
+  }
+}
+
+/// Names of the metrics emitted by the IOTA Identity crates.
+pub mod names {
+  /// Counter of credentials that passed validation.
+  pub const CREDENTIALS_VALIDATED: &str = "identity_credentials_validated_total";
+  /// Counter of credentials that failed validation, labelled with the class of the `error`.
+  pub const CREDENTIALS_FAILED: &str = "identity_credentials_failed_total";
+  /// Counter of DID resolutions, labelled with the DID `method` and the `outcome` (`ok` or `error`).
+  ///
+  /// The `method` is [`OTHER_METHOD`](super::OTHER_METHOD) for the methods the resolver has no handler for.
+  pub const RESOLUTIONS: &str = "identity_resolutions_total";
+  /// Histogram of the duration in seconds of the requests performed by the identity client, labelled with the
+  /// `operation` and the `outcome` (`ok` or `error`).
+  pub const CLIENT_RPC_DURATION: &str = "identity_client_rpc_duration_seconds";
+}
+
+/// A [`MetricsRecorder`] forwarding every metric to the recorder installed for the [`metrics`] crate.
+#[cfg(feature = "metrics")]
+#[derive(Clone, Copy, Debug, Default)]
+#[non_exhaustive]
+pub struct MetricsCrateRecorder;
+
+#[cfg(feature = "metrics")]
+impl MetricsCrateRecorder {
+  fn labels(labels: &[Label<'_>]) -> Vec<::metrics::Label> {
+    labels
+      .iter()
+      .map(|(key, value)| ::metrics::Label::new(*key, value.to_string()))
+      .collect()
+  }
+}
+
+#[cfg(feature = "metrics")]
+impl MetricsRecorder for MetricsCrateRecorder {
+  fn increment_counter(&self, name: &'static str, labels: &[Label<'_>]) {
+    ::metrics::counter!(name, Self::labels(labels)).increment(1);
+  }
+
+  fn record_histogram(&self, name: &'static str, labels: &[Label<'_>], value: f64) {
+    ::metrics::histogram!(name, Self::labels(labels)).record(value);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Mutex;
+
+  use super::*;
+
+  #[derive(Default)]
+  struct TestRecorder(Mutex<Vec<(String, Vec<(String, String)>, Option<f64>)>>);
+
+  impl MetricsRecorder for &'static TestRecorder {
+    fn increment_counter(&self, name: &'static str, labels: &[Label<'_>]) {
+      self.0.lock().unwrap().push((name.to_owned(), owned(labels), None));
+    }
+
+    fn record_histogram(&self, name: &'static str, labels: &[Label<'_>], value: f64) {
+      self
+        .0
+        .lock()
+        .unwrap()
+        .push((name.to_owned(), owned(labels), Some(value)));
+    }
+  }
+
+  fn owned(labels: &[Label<'_>]) -> Vec<(String, String)> {
+    labels
+      .iter()
+      .map(|(key, value)| (key.to_string(), value.to_string()))
+      .collect()
+  }
+
+  #[test]
+  fn metrics_are_forwarded_to_the_installed_recorder() {
+    static TEST_RECORDER: OnceLock<TestRecorder> = OnceLock::new();
+    let test_recorder: &'static TestRecorder = TEST_RECORDER.get_or_init(TestRecorder::default);
+
+    set_metrics_recorder(test_recorder).unwrap();
+    assert!(recorder_installed());
+    assert!(set_metrics_recorder(test_recorder).is_err());
+
+    increment_counter(names::RESOLUTIONS, &[("method", "iota"), ("outcome", "ok")]);
+    record_histogram(names::CLIENT_RPC_DURATION, &[("operation", "resolve_did")], 0.5);
+
+    let recorded = test_recorder.0.lock().unwrap();
+    assert_eq!(recorded.len(), 2);
+    assert_eq!(recorded[0].0, names::RESOLUTIONS);
+    assert_eq!(
+      recorded[0].1,
+      [
+        ("method".to_owned(), "iota".to_owned()),
+        ("outcome".to_owned(), "ok".to_owned())
+      ]
+    );
+    assert_eq!(recorded[1].2, Some(0.5));
+  }
+}
@@ -0,0 +1,186 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::str::FromStr;
+
+#[cfg(feature = "account-control-evm")]
+use identity_core::eip155::Eip155AccountId;
+
+use super::AccountControlError;
+use super::AccountControlResult;
+
+/// The CAIP-2 namespace of IOTA networks.
+pub const IOTA_NAMESPACE: &str = "iota";
+
+/// A [CAIP-10](https://github.com/ChainAgnostic/CAIPs/blob/main/CAIPs/caip-10.md) account ID of an IOTA address,
+/// e.g. `iota:mainnet:0x4b9b1b1c8a6a4d1f3b5d9e2b8f6c2a7d1e9f3b5c7d2e8a4f6b1c3d5e7f9a2b4c`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct IotaAccountId {
+  network: String,
+  address: [u8; 32],
+}
+
+impl IotaAccountId {
+  /// Creates the account ID of `address` on the IOTA network `network`, e.g. `mainnet` or the chain identifier of
+  /// a custom network.
+  ///
+  /// # Errors
+  /// Fails if `network` is not a valid CAIP-2 chain reference.
+  pub fn new(network: impl Into<String>, address: [u8; 32]) -> AccountControlResult<Self> {
+    let network: String = network.into();
+    let is_valid = !network.is_empty()
+      && network.len() <= 32
+      && network
+        .bytes()
+        .all(|c| c.is_ascii_alphanumeric() || c == b'-' || c == b'_');
+    if !is_valid {
+      return Err(AccountControlError::InvalidAccountId(format!(
+        "{IOTA_NAMESPACE}:{network}:{}",
+        encode_hex(&address)
+      )));
+    }
+    Ok(Self { network, address })
+  }
+
+  /// Returns the network of the account.
+  pub fn network(&self) -> &str {
+    &self.network
+  }
+
+  /// Returns the address of the account.
+  pub fn address(&self) -> &[u8; 32] {
+    &self.address
+  }
+}
+
+impl Display for IotaAccountId {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    write!(f, "{IOTA_NAMESPACE}:{}:{}", self.network, encode_hex(&self.address))
+  }
+}
+
+impl FromStr for IotaAccountId {
+  type Err = AccountControlError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let invalid = || AccountControlError::InvalidAccountId(s.to_owned());
+    let mut parts = s.split(':');
+    let (Some(namespace), Some(network), Some(address), None) =
+      (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+      return Err(invalid());
+    };
+    if namespace != IOTA_NAMESPACE {
+      return Err(AccountControlError::UnsupportedNamespace(namespace.to_owned()));
+    }
+    let address: [u8; 32] = address
+      .strip_prefix("0x")
+      .and_then(decode_hex)
+      .and_then(|bytes| bytes.try_into().ok())
+      .ok_or_else(invalid)?;
+
+    Self::new(network, address).map_err(|_| invalid())
+  }
+}
+
+/// A CAIP-10 account ID of an account whose control can be proven with an
+/// [`AccountSignature`](super::AccountSignature).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(into = "String", try_from = "String")]
+#[non_exhaustive]
+pub enum AccountId {
+  /// An IOTA account.
+  Iota(IotaAccountId),
+  /// An EVM account.
+  #[cfg(feature = "account-control-evm")]
+  Eip155(Eip155AccountId),
+}
+
+impl AccountId {
+  /// Returns the CAIP-2 namespace of the account, e.g. `iota`.
+  pub fn namespace(&self) -> &'static str {
+    match self {
+      Self::Iota(_) => IOTA_NAMESPACE,
+      #[cfg(feature = "account-control-evm")]
+      Self::Eip155(_) => identity_core::eip155::EIP155_NAMESPACE,
+    }
+  }
+}
+
+impl Display for AccountId {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    match self {
+      Self::Iota(account) => account.fmt(f),
+      #[cfg(feature = "account-control-evm")]
+      Self::Eip155(account) => account.fmt(f),
+    }
+  }
+}
+
+impl FromStr for AccountId {
+  type Err = AccountControlError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let (namespace, _) = s
+      .split_once(':')
+      .ok_or_else(|| AccountControlError::InvalidAccountId(s.to_owned()))?;
+    match namespace {
+      IOTA_NAMESPACE => s.parse().map(Self::Iota),
+      #[cfg(feature = "account-control-evm")]
+      identity_core::eip155::EIP155_NAMESPACE => s
+        .parse()
+        .map(Self::Eip155)
+        .map_err(|_| AccountControlError::InvalidAccountId(s.to_owned())),
+      _ => Err(AccountControlError::UnsupportedNamespace(namespace.to_owned())),
+    }
+  }
+}
+
+impl From<IotaAccountId> for AccountId {
+  fn from(account: IotaAccountId) -> Self {
+    Self::Iota(account)
+  }
+}
+
+#[cfg(feature = "account-control-evm")]
+impl From<Eip155AccountId> for AccountId {
+  fn from(account: Eip155AccountId) -> Self {
+    Self::Eip155(account)
+  }
+}
+
+impl TryFrom<String> for AccountId {
+  type Error = AccountControlError;
+
+  fn try_from(value: String) -> Result<Self, Self::Error> {
+    value.parse()
+  }
+}
+
+impl From<AccountId> for String {
+  fn from(value: AccountId) -> Self {
+    value.to_string()
+  }
+}
+
+pub(super) fn encode_hex(bytes: &[u8]) -> String {
+  let mut hex = String::with_capacity(2 + 2 * bytes.len());
+  hex.push_str("0x");
+  for byte in bytes {
+    hex.push_str(&format!("{byte:02x}"));
+  }
+  hex
+}
+
+/// Decodes the case-insensitive hexadecimal string `hex`, without prefix.
+pub(super) fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+  if hex.len() % 2 != 0 || !hex.bytes().all(|c| c.is_ascii_hexdigit()) {
+    return None;
+  }
+  (0..hex.len())
+    .step_by(2)
+    .map(|idx| u8::from_str_radix(&hex[idx..idx + 2], 16).ok())
+    .collect()
+}
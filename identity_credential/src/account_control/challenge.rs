@@ -0,0 +1,91 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Duration;
+use identity_core::common::Timestamp;
+use identity_did::CoreDID;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::account_id::encode_hex;
+use super::AccountControlError;
+use super::AccountControlResult;
+use super::AccountId;
+
+/// A challenge the holder of a DID signs with the wallet controlling an account, to prove control of that account.
+///
+/// The challenge is meant to be kept by the verifier, e.g. in its session storage, until the signature is received.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountControlChallenge {
+  did: CoreDID,
+  account: AccountId,
+  nonce: String,
+  issued_at: Timestamp,
+  expires_at: Timestamp,
+}
+
+impl AccountControlChallenge {
+  /// Creates a challenge with a random nonce for the holder of `did` to prove control of `account`, valid for
+  /// `validity` from now.
+  pub fn new(did: CoreDID, account: AccountId, validity: Duration) -> AccountControlResult<Self> {
+    let mut nonce = [0; 16];
+    crypto::utils::rand::fill(&mut nonce).map_err(|_| AccountControlError::NonceGeneration)?;
+
+    let issued_at: Timestamp = Timestamp::now_utc();
+    Ok(Self {
+      did,
+      account,
+      nonce: encode_hex(&nonce)[2..].to_owned(),
+      issued_at,
+      // A validity overflowing the timestamp range yields an already expired challenge.
+      expires_at: issued_at.checked_add(validity).unwrap_or(issued_at),
+    })
+  }
+
+  /// Returns the DID the account is bound to.
+  pub fn did(&self) -> &CoreDID {
+    &self.did
+  }
+
+  /// Returns the account whose control must be proven.
+  pub fn account(&self) -> &AccountId {
+    &self.account
+  }
+
+  /// Returns the nonce of the challenge.
+  pub fn nonce(&self) -> &str {
+    &self.nonce
+  }
+
+  /// Returns the time the challenge was created at.
+  pub fn issued_at(&self) -> Timestamp {
+    self.issued_at
+  }
+
+  /// Returns the time after which signatures of the challenge are no longer accepted.
+  pub fn expires_at(&self) -> Timestamp {
+    self.expires_at
+  }
+
+  /// Returns the human-readable message the wallet controlling the account must sign.
+  ///
+  /// IOTA wallets must sign it as a personal message, EVM wallets with `personal_sign`.
+  pub fn message(&self) -> String {
+    format!(
+      concat!(
+        "{did} requests proof of control of the account:\n",
+        "{account}\n",
+        "\n",
+        "Nonce: {nonce}\n",
+        "Issued At: {issued_at}\n",
+        "Expiration Time: {expires_at}"
+      ),
+      did = self.did,
+      account = self.account,
+      nonce = self.nonce,
+      issued_at = self.issued_at,
+      expires_at = self.expires_at,
+    )
+  }
+}
@@ -0,0 +1,109 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::OneOrMany;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_did::CoreDID;
+use identity_did::DID;
+
+use crate::credential::Credential;
+use crate::credential::Issuer;
+use crate::credential::Subject;
+use crate::error::Result;
+use crate::Error;
+
+use super::AccountId;
+
+/// The type of credentials attesting that the subject controls an on-chain account.
+pub const ACCOUNT_CONTROL_CREDENTIAL_TYPE: &str = "AccountControlCredential";
+
+/// Builder of credentials attesting that a DID controls an on-chain account, usually obtained from
+/// [`VerifiedAccountControl::credential_builder`](super::VerifiedAccountControl::credential_builder).
+///
+/// The credential subject has the DID as `id` and the CAIP-10 account ID as `account` property.
+///
+/// The builder expects `issuer`, `subject` and `account` to be set.
+/// Setting `issuanceDate` and `expirationDate` is optional. If unset the current time will be used as issuance date.
+#[derive(Debug, Default)]
+pub struct AccountControlCredentialBuilder {
+  pub(crate) issuer: Option<Url>,
+  pub(crate) subject: Option<CoreDID>,
+  pub(crate) account: Option<AccountId>,
+  pub(crate) issuance_date: Option<Timestamp>,
+  pub(crate) expiration_date: Option<Timestamp>,
+}
+
+impl AccountControlCredentialBuilder {
+  /// Creates a new `AccountControlCredentialBuilder`.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Sets the value of the `issuer`.
+  #[must_use]
+  pub fn issuer(mut self, did: CoreDID) -> Self {
+    self.issuer = Some(did.into_url().into());
+    self
+  }
+
+  /// Sets the DID controlling the account, used as `credentialSubject.id`.
+  #[must_use]
+  pub fn subject(mut self, did: CoreDID) -> Self {
+    self.subject = Some(did);
+    self
+  }
+
+  /// Sets the account controlled by the subject.
+  #[must_use]
+  pub fn account(mut self, account: AccountId) -> Self {
+    self.account = Some(account);
+    self
+  }
+
+  /// Sets the value of the `Credential` `issuanceDate`.
+  #[must_use]
+  pub fn issuance_date(mut self, value: Timestamp) -> Self {
+    self.issuance_date = Some(value);
+    self
+  }
+
+  /// Sets the value of the `Credential` `expirationDate`.
+  #[must_use]
+  pub fn expiration_date(mut self, value: Timestamp) -> Self {
+    self.expiration_date = Some(value);
+    self
+  }
+
+  /// Returns a new `Credential` based on the `AccountControlCredentialBuilder` configuration.
+  pub fn build(self) -> Result<Credential<Object>> {
+    let issuer: Url = self.issuer.ok_or(Error::MissingIssuer)?;
+    let subject: Url = self.subject.ok_or(Error::MissingSubject)?.into_url().into();
+    let account: AccountId = self.account.ok_or(Error::InvalidSubject)?;
+
+    let mut properties: Object = Object::new();
+    properties.insert("account".into(), account.to_string().into());
+
+    Ok(Credential {
+      context: OneOrMany::One(Credential::<Object>::base_context().clone()),
+      id: None,
+      types: OneOrMany::Many(vec![
+        Credential::<Object>::base_type().to_owned(),
+        ACCOUNT_CONTROL_CREDENTIAL_TYPE.to_owned(),
+      ]),
+      credential_subject: OneOrMany::One(Subject::with_id_and_properties(subject, properties)),
+      issuer: Issuer::Url(issuer),
+      issuance_date: self.issuance_date.unwrap_or_else(Timestamp::now_utc),
+      expiration_date: self.expiration_date,
+      credential_status: None,
+      credential_schema: Vec::new().into(),
+      refresh_service: Vec::new().into(),
+      terms_of_use: Vec::new().into(),
+      evidence: Vec::new().into(),
+      non_transferable: None,
+      properties: Object::new(),
+      proof: None,
+    })
+  }
+}
@@ -0,0 +1,51 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/// Alias for a `Result` with the error type [`AccountControlError`].
+pub type AccountControlResult<T> = Result<T, AccountControlError>;
+
+/// Errors that can occur when proving control of an account.
+#[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum AccountControlError {
+  /// Caused by a malformed CAIP-10 account ID.
+  #[error("invalid account ID `{0}`")]
+  InvalidAccountId(String),
+  /// Caused by an account ID in a namespace that is not supported.
+  #[error("unsupported account namespace `{0}`")]
+  UnsupportedNamespace(String),
+  /// Caused by a signature whose scheme cannot be used for the account it should prove control of.
+  #[error("a {signature} signature cannot prove control of a `{namespace}` account")]
+  SignatureSchemeMismatch {
+    /// The scheme of the signature.
+    signature: &'static str,
+    /// The namespace of the account.
+    namespace: String,
+  },
+  /// Caused by a signature that cannot be decoded.
+  #[error("malformed signature: {0}")]
+  MalformedSignature(&'static str),
+  /// Caused by a signature that is not valid for the challenge.
+  #[error("invalid signature")]
+  InvalidSignature,
+  /// Caused by a valid signature produced by a key that does not control the account.
+  #[error("the signature was not produced by the key controlling the account")]
+  AccountMismatch,
+  /// Caused by a challenge whose validity period has ended.
+  #[error("the challenge has expired")]
+  ChallengeExpired,
+  /// Caused by a failure to generate the nonce of a challenge.
+  #[error("failed to generate the challenge nonce")]
+  NonceGeneration,
+}
+
+identity_core::impl_error_code!(AccountControlError {
+  InvalidAccountId => 2250,
+  UnsupportedNamespace => 2251,
+  SignatureSchemeMismatch => 2252,
+  MalformedSignature => 2253,
+  InvalidSignature => 2254,
+  AccountMismatch => 2255,
+  ChallengeExpired => 2256,
+  NonceGeneration => 2257,
+});
@@ -0,0 +1,24 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Proof of control of on-chain accounts, binding
+//! [CAIP-10](https://github.com/ChainAgnostic/CAIPs/blob/main/CAIPs/caip-10.md) accounts to DIDs.
+//!
+//! The workflow is the following:
+//! 1. The verifier creates an [`AccountControlChallenge`] for the DID and the account the holder claims to control.
+//! 2. The holder signs the [message](AccountControlChallenge::message) of the challenge with the wallet holding the
+//!    account, producing an [`AccountSignature`].
+//! 3. The verifier checks the signature with [`AccountControlVerifier`] and, on success, issues a credential attesting
+//!    the linkage with an [`AccountControlCredentialBuilder`].
+
+mod account_id;
+mod challenge;
+mod credential_builder;
+mod error;
+mod verifier;
+
+pub use self::account_id::*;
+pub use self::challenge::*;
+pub use self::credential_builder::*;
+pub use self::error::*;
+pub use self::verifier::*;
@@ -0,0 +1,337 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::hashes::blake2b::Blake2b256;
+use crypto::hashes::Digest;
+use crypto::signatures::ed25519;
+use identity_core::common::Timestamp;
+use identity_core::convert::Base;
+use identity_core::convert::BaseEncoding;
+use identity_did::CoreDID;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::AccountControlChallenge;
+use super::AccountControlCredentialBuilder;
+use super::AccountControlError;
+use super::AccountControlResult;
+use super::AccountId;
+use super::IotaAccountId;
+
+/// The signature flag of Ed25519 keys in IOTA serialized signatures.
+const IOTA_ED25519_FLAG: u8 = 0x00;
+/// The BCS-serialized intent of IOTA personal messages: scope `PersonalMessage`, version `V0`, app ID `Iota`.
+const IOTA_PERSONAL_MESSAGE_INTENT: [u8; 3] = [3, 0, 0];
+
+/// A signature of the [message](AccountControlChallenge::message) of an [`AccountControlChallenge`] produced by the
+/// wallet controlling an account.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "scheme", content = "signature", rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum AccountSignature {
+  /// An IOTA personal message signature, in the base64-encoded `flag || signature || public key` format produced by
+  /// IOTA wallets. Only Ed25519 signatures are supported.
+  Iota(String),
+  /// An EVM `personal_sign` signature, as the `0x`-prefixed hexadecimal encoding of `r || s || v`.
+  #[cfg(feature = "account-control-evm")]
+  PersonalSign(String),
+}
+
+impl AccountSignature {
+  fn scheme(&self) -> &'static str {
+    match self {
+      Self::Iota(_) => "IOTA",
+      #[cfg(feature = "account-control-evm")]
+      Self::PersonalSign(_) => "personal_sign",
+    }
+  }
+}
+
+/// The successful outcome of [`AccountControlVerifier::verify`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifiedAccountControl {
+  did: CoreDID,
+  account: AccountId,
+  verified_at: Timestamp,
+}
+
+impl VerifiedAccountControl {
+  /// Returns the DID the account is bound to.
+  pub fn did(&self) -> &CoreDID {
+    &self.did
+  }
+
+  /// Returns the account whose control was proven.
+  pub fn account(&self) -> &AccountId {
+    &self.account
+  }
+
+  /// Returns the time the proof was verified at.
+  pub fn verified_at(&self) -> Timestamp {
+    self.verified_at
+  }
+
+  /// Returns a [`AccountControlCredentialBuilder`] for a credential attesting the linkage between the DID and the
+  /// account, with the issuance date set to the verification time.
+  pub fn credential_builder(&self) -> AccountControlCredentialBuilder {
+    AccountControlCredentialBuilder::new()
+      .subject(self.did.clone())
+      .account(self.account.clone())
+      .issuance_date(self.verified_at)
+  }
+}
+
+/// Verifies proofs of control of accounts, see the [module documentation](super).
+#[derive(Debug, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct AccountControlVerifier;
+
+impl AccountControlVerifier {
+  /// Verifies that `signature` is a signature of the message of `challenge` produced by the key controlling the
+  /// account of the challenge, and that the challenge has not expired.
+  pub fn verify(
+    challenge: &AccountControlChallenge,
+    signature: &AccountSignature,
+  ) -> AccountControlResult<VerifiedAccountControl> {
+    Self::verify_at(challenge, signature, Timestamp::now_utc())
+  }
+
+  /// Like [`AccountControlVerifier::verify`], but checks the expiration of `challenge` against `now`.
+  pub fn verify_at(
+    challenge: &AccountControlChallenge,
+    signature: &AccountSignature,
+    now: Timestamp,
+  ) -> AccountControlResult<VerifiedAccountControl> {
+    if now > challenge.expires_at() {
+      return Err(AccountControlError::ChallengeExpired);
+    }
+
+    let message: String = challenge.message();
+    match (challenge.account(), signature) {
+      (AccountId::Iota(account), AccountSignature::Iota(signature)) => {
+        verify_iota_signature(account, message.as_bytes(), signature)?
+      }
+      #[cfg(feature = "account-control-evm")]
+      (AccountId::Eip155(account), AccountSignature::PersonalSign(signature)) => {
+        evm::verify_personal_sign(account, message.as_bytes(), signature)?
+      }
+      #[allow(unreachable_patterns)]
+      (account, signature) => {
+        return Err(AccountControlError::SignatureSchemeMismatch {
+          signature: signature.scheme(),
+          namespace: account.namespace().to_owned(),
+        })
+      }
+    }
+
+    Ok(VerifiedAccountControl {
+      did: challenge.did().clone(),
+      account: challenge.account().clone(),
+      verified_at: now,
+    })
+  }
+}
+
+fn verify_iota_signature(account: &IotaAccountId, message: &[u8], signature: &str) -> AccountControlResult<()> {
+  let bytes: Vec<u8> = BaseEncoding::decode(signature, Base::Base64Pad)
+    .map_err(|_| AccountControlError::MalformedSignature("expected base64"))?;
+  if bytes.len() != 1 + ed25519::Signature::LENGTH + ed25519::PublicKey::LENGTH {
+    return Err(AccountControlError::MalformedSignature("unexpected length"));
+  }
+  if bytes[0] != IOTA_ED25519_FLAG {
+    return Err(AccountControlError::MalformedSignature(
+      "only Ed25519 signatures are supported",
+    ));
+  }
+  let (signature, public_key) = bytes[1..].split_at(ed25519::Signature::LENGTH);
+
+  let signature = ed25519::Signature::from_bytes(signature.try_into().expect("length was checked"));
+  let public_key = ed25519::PublicKey::try_from_bytes(public_key.try_into().expect("length was checked"))
+    .map_err(|_| AccountControlError::MalformedSignature("invalid public key"))?;
+
+  // IOTA wallets sign the digest of the BCS-serialized intent message.
+  let mut intent_message: Vec<u8> = IOTA_PERSONAL_MESSAGE_INTENT.to_vec();
+  write_uleb128(message.len(), &mut intent_message);
+  intent_message.extend_from_slice(message);
+  if !public_key.verify(&signature, &Blake2b256::digest(&intent_message)) {
+    return Err(AccountControlError::InvalidSignature);
+  }
+
+  let mut flagged_key: Vec<u8> = vec![IOTA_ED25519_FLAG];
+  flagged_key.extend_from_slice(public_key.as_slice());
+  if Blake2b256::digest(&flagged_key).as_slice() != account.address() {
+    return Err(AccountControlError::AccountMismatch);
+  }
+
+  Ok(())
+}
+
+fn write_uleb128(mut value: usize, buf: &mut Vec<u8>) {
+  while value >= 0x80 {
+    buf.push((value as u8 & 0x7f) | 0x80);
+    value >>= 7;
+  }
+  buf.push(value as u8);
+}
+
+#[cfg(feature = "account-control-evm")]
+mod evm {
+  use identity_core::eip155::Eip155AccountId;
+  use k256::ecdsa::RecoveryId;
+  use k256::ecdsa::Signature;
+  use k256::ecdsa::VerifyingKey;
+  use sha3::Digest;
+  use sha3::Keccak256;
+
+  use super::super::account_id::decode_hex;
+  use super::AccountControlError;
+  use super::AccountControlResult;
+
+  /// Returns the digest EVM wallets sign for `personal_sign`, as defined in
+  /// [EIP-191](https://eips.ethereum.org/EIPS/eip-191).
+  pub(super) fn personal_sign_digest(message: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    hasher.update(format!("\x19Ethereum Signed Message:\n{}", message.len()));
+    hasher.update(message);
+    hasher.finalize().into()
+  }
+
+  pub(super) fn verify_personal_sign(
+    account: &Eip155AccountId,
+    message: &[u8],
+    signature: &str,
+  ) -> AccountControlResult<()> {
+    let bytes: Vec<u8> = signature
+      .strip_prefix("0x")
+      .and_then(decode_hex)
+      .filter(|bytes| bytes.len() == 65)
+      .ok_or(AccountControlError::MalformedSignature("expected 65 hexadecimal bytes"))?;
+    let recovery_id = match bytes[64] {
+      0 | 27 => 0,
+      1 | 28 => 1,
+      _ => return Err(AccountControlError::MalformedSignature("invalid recovery ID")),
+    };
+    let recovery_id = RecoveryId::from_byte(recovery_id).expect("0 and 1 are valid recovery IDs");
+    let signature =
+      Signature::from_slice(&bytes[..64]).map_err(|_| AccountControlError::MalformedSignature("invalid signature"))?;
+
+    let key = VerifyingKey::recover_from_prehash(&personal_sign_digest(message), &signature, recovery_id)
+      .map_err(|_| AccountControlError::InvalidSignature)?;
+    let address = Keccak256::digest(&key.to_encoded_point(false).as_bytes()[1..]);
+    if &address[12..] != account.address().as_bytes() {
+      return Err(AccountControlError::AccountMismatch);
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crypto::signatures::ed25519::SecretKey;
+  use identity_core::common::Duration;
+
+  use super::*;
+
+  fn iota_account(secret_key: &SecretKey) -> AccountId {
+    let mut flagged_key: Vec<u8> = vec![IOTA_ED25519_FLAG];
+    flagged_key.extend_from_slice(secret_key.public_key().as_slice());
+    let address: [u8; 32] = Blake2b256::digest(&flagged_key).into();
+    IotaAccountId::new("testnet", address).unwrap().into()
+  }
+
+  fn iota_sign(secret_key: &SecretKey, message: &[u8]) -> AccountSignature {
+    let mut intent_message: Vec<u8> = IOTA_PERSONAL_MESSAGE_INTENT.to_vec();
+    write_uleb128(message.len(), &mut intent_message);
+    intent_message.extend_from_slice(message);
+    let signature = secret_key.sign(&Blake2b256::digest(&intent_message));
+
+    let mut bytes: Vec<u8> = vec![IOTA_ED25519_FLAG];
+    bytes.extend_from_slice(&signature.to_bytes());
+    bytes.extend_from_slice(secret_key.public_key().as_slice());
+    AccountSignature::Iota(BaseEncoding::encode(&bytes, Base::Base64Pad))
+  }
+
+  fn did() -> CoreDID {
+    "did:example:holder".parse().unwrap()
+  }
+
+  #[test]
+  fn iota_account_control_is_verified() {
+    let secret_key = SecretKey::generate().unwrap();
+    let challenge = AccountControlChallenge::new(did(), iota_account(&secret_key), Duration::minutes(5)).unwrap();
+    let signature = iota_sign(&secret_key, challenge.message().as_bytes());
+
+    let verified = AccountControlVerifier::verify(&challenge, &signature).unwrap();
+    assert_eq!(verified.did(), &did());
+    assert_eq!(verified.account(), challenge.account());
+
+    let credential = verified
+      .credential_builder()
+      .issuer("did:example:issuer".parse().unwrap())
+      .build()
+      .unwrap();
+    let subject = credential.credential_subject.first().unwrap();
+    assert_eq!(subject.id.as_ref().unwrap().as_str(), "did:example:holder");
+    assert_eq!(
+      subject.properties.get("account").unwrap().as_str().unwrap(),
+      challenge.account().to_string()
+    );
+  }
+
+  #[test]
+  fn iota_signatures_of_other_keys_or_messages_are_rejected() {
+    let secret_key = SecretKey::generate().unwrap();
+    let challenge = AccountControlChallenge::new(did(), iota_account(&secret_key), Duration::minutes(5)).unwrap();
+
+    let other_key = SecretKey::generate().unwrap();
+    let signature = iota_sign(&other_key, challenge.message().as_bytes());
+    assert!(matches!(
+      AccountControlVerifier::verify(&challenge, &signature),
+      Err(AccountControlError::AccountMismatch)
+    ));
+
+    let signature = iota_sign(&secret_key, b"another message");
+    assert!(matches!(
+      AccountControlVerifier::verify(&challenge, &signature),
+      Err(AccountControlError::InvalidSignature)
+    ));
+
+    let signature = iota_sign(&secret_key, challenge.message().as_bytes());
+    let after_expiration = challenge.expires_at().checked_add(Duration::seconds(1)).unwrap();
+    assert!(matches!(
+      AccountControlVerifier::verify_at(&challenge, &signature, after_expiration),
+      Err(AccountControlError::ChallengeExpired)
+    ));
+  }
+
+  #[cfg(feature = "account-control-evm")]
+  #[test]
+  fn evm_account_control_is_verified() {
+    use identity_core::eip155::Eip155AccountId;
+    use identity_core::eip155::Eip155ChainId;
+    use identity_core::eip155::EvmAddress;
+    use k256::ecdsa::SigningKey;
+    use sha3::Keccak256;
+
+    let signing_key = SigningKey::from_slice(&[0x42; 32]).unwrap();
+    let public_key = signing_key.verifying_key().to_encoded_point(false);
+    let address: [u8; 20] = <Keccak256 as sha3::Digest>::digest(&public_key.as_bytes()[1..])[12..]
+      .try_into()
+      .unwrap();
+    let account = Eip155AccountId::new(Eip155ChainId::new(1), EvmAddress::new(address));
+
+    let challenge = AccountControlChallenge::new(did(), account.into(), Duration::minutes(5)).unwrap();
+    let digest = evm::personal_sign_digest(challenge.message().as_bytes());
+    let (signature, recovery_id) = signing_key.sign_prehash_recoverable(&digest).unwrap();
+    let mut bytes = signature.to_bytes().to_vec();
+    bytes.push(27 + recovery_id.to_byte());
+    let signature = AccountSignature::PersonalSign(super::super::account_id::encode_hex(&bytes));
+
+    assert!(AccountControlVerifier::verify(&challenge, &signature).is_ok());
+    assert!(matches!(
+      AccountControlVerifier::verify(&challenge, &AccountSignature::Iota(String::new())),
+      Err(AccountControlError::SignatureSchemeMismatch { .. })
+    ));
+  }
+}
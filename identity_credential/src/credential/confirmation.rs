@@ -0,0 +1,120 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use identity_core::common::Object;
+use identity_verification::jwk::Jwk;
+
+use crate::error::Error;
+use crate::error::Result;
+
+/// The name of the JWT confirmation claim.
+pub const CNF_CLAIM: &str = "cnf";
+
+/// A JWT confirmation (`cnf`) claim as defined in [RFC 7800](https://www.rfc-editor.org/rfc/rfc7800.html#section-3),
+/// binding a credential to a key controlled by its holder.
+///
+/// When a credential carries a confirmation claim, a verifier should only accept presentations of that credential
+/// signed with the referenced key, rather than relying on the credential subject's `id`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum Confirmation {
+  /// The holder's public key.
+  Jwk(Jwk),
+  /// The identifier of the holder's key, e.g. the DID URL of a verification method of the holder.
+  Kid(String),
+}
+
+impl Confirmation {
+  /// Creates a [`Confirmation`] embedding the holder's public key `jwk`.
+  ///
+  /// # Errors
+  /// Fails if `jwk` contains private key material.
+  pub fn jwk(jwk: Jwk) -> Result<Self> {
+    if jwk.is_public() {
+      Ok(Self::Jwk(jwk))
+    } else {
+      Err(Error::JwtClaimsSetSerializationError(
+        "the confirmation key must not contain private key material".into(),
+      ))
+    }
+  }
+
+  /// Creates a [`Confirmation`] referencing the holder's key through its identifier `kid`.
+  pub fn kid(kid: impl Into<String>) -> Self {
+    Self::Kid(kid.into())
+  }
+
+  /// Extracts the [`Confirmation`] from the `cnf` property of a set of JWT claims, if any.
+  pub fn from_claims(claims: &Object) -> Result<Option<Self>> {
+    claims
+      .get(CNF_CLAIM)
+      .map(Self::deserialize)
+      .transpose()
+      .map_err(|err| Error::JwtClaimsSetDeserializationError(err.into()))
+  }
+
+  /// Inserts this [`Confirmation`] as the `cnf` property of `claims`, replacing any previous one.
+  pub fn insert_into(&self, claims: &mut Object) {
+    claims.insert(
+      CNF_CLAIM.to_owned(),
+      serde_json::to_value(self).expect("confirmation can be serialized"),
+    );
+  }
+
+  /// Returns a set of JWT claims containing only this [`Confirmation`], suitable to be passed as the custom claims
+  /// of [`Credential::serialize_jwt`](crate::credential::Credential::serialize_jwt) or of any other method issuing a
+  /// credential as a JWT.
+  pub fn to_claims(&self) -> Object {
+    let mut claims = Object::new();
+    self.insert_into(&mut claims);
+    claims
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+  use serde_json::json;
+
+  use super::*;
+
+  #[test]
+  fn confirmation_round_trips_through_claims() {
+    let kid = Confirmation::kid("did:example:holder#key-1");
+    let claims = kid.to_claims();
+    assert_eq!(
+      serde_json::to_value(&claims).unwrap(),
+      json!({ "cnf": { "kid": "did:example:holder#key-1" } })
+    );
+    assert_eq!(Confirmation::from_claims(&claims).unwrap(), Some(kid));
+    assert_eq!(Confirmation::from_claims(&Object::new()).unwrap(), None);
+
+    let jwk = Jwk::from_json_value(json!({
+      "kty": "OKP",
+      "crv": "Ed25519",
+      "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"
+    }))
+    .unwrap();
+    let confirmation = Confirmation::jwk(jwk).unwrap();
+    assert_eq!(
+      Confirmation::from_claims(&confirmation.to_claims()).unwrap(),
+      Some(confirmation)
+    );
+  }
+
+  #[test]
+  fn private_keys_are_rejected() {
+    let jwk = Jwk::from_json_value(json!({
+      "kty": "OKP",
+      "crv": "Ed25519",
+      "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo",
+      "d": "nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A"
+    }))
+    .unwrap();
+    assert!(Confirmation::jwk(jwk).is_err());
+  }
+}
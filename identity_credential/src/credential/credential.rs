@@ -4,10 +4,12 @@
 use core::fmt::Display;
 use core::fmt::Formatter;
 
+use identity_core::convert::FromJson;
 use identity_core::convert::ToJson;
 #[cfg(feature = "jpt-bbs-plus")]
 use jsonprooftoken::jpt::claims::JptClaims;
 use once_cell::sync::Lazy;
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -19,10 +21,13 @@ use identity_core::common::Url;
 use identity_core::convert::FmtJson;
 
 use crate::credential::CredentialBuilder;
+use crate::credential::CredentialDisplay;
 use crate::credential::CredentialSealed;
 use crate::credential::CredentialT;
 use crate::credential::Evidence;
 use crate::credential::Issuer;
+use crate::credential::JwtClaimsOrdering;
+use crate::credential::LocalizedDisplay;
 use crate::credential::Policy;
 use crate::credential::RefreshService;
 use crate::credential::Schema;
@@ -178,13 +183,26 @@ impl<T> Credential<T> {
   ///
   /// The resulting string can be used as the payload of a JWS when issuing the credential.  
   pub fn serialize_jwt(&self, custom_claims: Option<Object>) -> Result<String>
+  where
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+  {
+    self.serialize_jwt_with_ordering(custom_claims, JwtClaimsOrdering::Legacy)
+  }
+
+  /// Serializes the [`Credential`] as a JWT claims set laid out according to `ordering`.
+  ///
+  /// Use [`JwtClaimsOrdering::Canonical`] when the claims might be re-serialized by other implementations
+  /// before their signature is checked, e.g. when custom properties are added through an [`Object`].
+  pub fn serialize_jwt_with_ordering(
+    &self,
+    custom_claims: Option<Object>,
+    ordering: JwtClaimsOrdering,
+  ) -> Result<String>
   where
     T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
   {
     let jwt_representation: CredentialJwtClaims<'_, T> = CredentialJwtClaims::new(self, custom_claims)?;
-    jwt_representation
-      .to_json()
-      .map_err(|err| Error::JwtClaimsSetSerializationError(err.into()))
+    jwt_representation.to_json_with_ordering(ordering)
   }
 
   /// Converts the [`Credential`] into a JWT claims set in accordance with [VC Data Model v1.1](https://www.w3.org/TR/vc-data-model/#json-web-token).
@@ -214,6 +232,21 @@ impl<T> Credential<T> {
   }
 }
 
+impl Credential<Object> {
+  /// Returns the human-readable [`name` and `description`](https://www.w3.org/TR/vc-data-model-2.0/#names-and-descriptions)
+  /// of this credential in every available language.
+  pub fn display(&self) -> CredentialDisplay {
+    CredentialDisplay::from_properties(&self.properties)
+  }
+
+  /// Returns the `name` and `description` of this credential in the language best matching `locale`.
+  ///
+  /// See [`lookup_locale`](crate::credential::lookup_locale) for how the language is selected.
+  pub fn display_for(&self, locale: &str) -> LocalizedDisplay {
+    self.display().display_for(locale)
+  }
+}
+
 impl<T> Display for Credential<T>
 where
   T: Serialize,
@@ -223,6 +256,18 @@ where
   }
 }
 
+impl<T> TryFrom<&serde_json::Value> for Credential<T>
+where
+  T: DeserializeOwned,
+{
+  type Error = identity_core::Error;
+
+  /// Deserializes a [`Credential`] from a borrowed JSON value, see [`FromJson::from_json_value_ref`].
+  fn try_from(value: &serde_json::Value) -> std::result::Result<Self, Self::Error> {
+    Self::from_json_value_ref(value)
+  }
+}
+
 impl<T> CredentialSealed for Credential<T> {}
 
 impl<T> CredentialT for Credential<T>
@@ -278,9 +323,11 @@ where
 
 #[cfg(test)]
 mod tests {
+  use identity_core::common::Object;
   use identity_core::common::OneOrMany;
   use identity_core::common::Url;
   use identity_core::convert::FromJson;
+  use identity_core::convert::ToJson;
 
   use crate::credential::credential::BASE_CONTEXT;
   use crate::credential::Credential;
@@ -315,6 +362,21 @@ mod tests {
     let _credential: Credential = Credential::from_json(JSON12).unwrap();
   }
 
+  #[test]
+  fn test_try_from_json_value_ref() {
+    for json in [
+      JSON1, JSON2, JSON3, JSON4, JSON5, JSON6, JSON7, JSON8, JSON9, JSON10, JSON11, JSON12,
+    ] {
+      let credential: Credential = Credential::from_json(json).unwrap();
+      let value: serde_json::Value = credential.to_json_value().unwrap();
+      assert_eq!(Credential::try_from(&value).unwrap(), credential);
+      assert_eq!(Credential::<Object>::from_json_value_ref(&value).unwrap(), credential);
+    }
+
+    let invalid: serde_json::Value = serde_json::json!({ "type": "VerifiableCredential" });
+    assert!(Credential::<Object>::try_from(&invalid).is_err());
+  }
+
   #[test]
   fn credential_with_single_context_is_list_of_contexts_with_single_item() {
     let mut credential = Credential::builder(serde_json::Value::default())
@@ -9,6 +9,7 @@ use identity_core::common::OneOrMany;
 use identity_core::common::Timestamp;
 use identity_core::common::Url;
 use identity_core::convert::FmtJson as _;
+use identity_core::convert::FromJson as _;
 use identity_core::convert::ToJson as _;
 use once_cell::sync::Lazy;
 use serde::de::DeserializeOwned;
@@ -18,10 +19,12 @@ use serde::Deserializer;
 use serde::Serialize;
 
 use crate::credential::CredentialBuilder;
+use crate::credential::CredentialDisplay;
 use crate::credential::CredentialSealed;
 use crate::credential::CredentialT;
 use crate::credential::Evidence;
 use crate::credential::Issuer;
+use crate::credential::LocalizedDisplay;
 use crate::credential::Policy;
 use crate::credential::Proof;
 use crate::credential::RefreshService;
@@ -169,6 +172,21 @@ impl<T> Credential<T> {
   }
 }
 
+impl Credential<Object> {
+  /// Returns the human-readable [`name` and `description`](https://www.w3.org/TR/vc-data-model-2.0/#names-and-descriptions)
+  /// of this credential in every available language.
+  pub fn display(&self) -> CredentialDisplay {
+    CredentialDisplay::from_properties(&self.properties)
+  }
+
+  /// Returns the `name` and `description` of this credential in the language best matching `locale`.
+  ///
+  /// See [`lookup_locale`](crate::credential::lookup_locale) for how the language is selected.
+  pub fn display_for(&self, locale: &str) -> LocalizedDisplay {
+    self.display().display_for(locale)
+  }
+}
+
 impl<T> Display for Credential<T>
 where
   T: Serialize,
@@ -178,6 +196,19 @@ where
   }
 }
 
+impl<T> TryFrom<&serde_json::Value> for Credential<T>
+where
+  T: DeserializeOwned,
+{
+  type Error = identity_core::Error;
+
+  /// Deserializes a [`Credential`] from a borrowed JSON value, see
+  /// [`FromJson::from_json_value_ref`](identity_core::convert::FromJson::from_json_value_ref).
+  fn try_from(value: &serde_json::Value) -> std::result::Result<Self, Self::Error> {
+    Self::from_json_value_ref(value)
+  }
+}
+
 impl<T> CredentialSealed for Credential<T> {}
 
 impl<T> CredentialT for Credential<T>
@@ -279,6 +310,33 @@ mod tests {
     serde_json::from_str::<Credential>(json_credential).expect("valid VC using Data Model 2.0");
   }
 
+  #[test]
+  fn try_from_json_value_ref() {
+    use identity_core::convert::FromJson;
+    use identity_core::convert::ToJson;
+
+    let value: serde_json::Value = serde_json::json!({
+      "@context": "https://www.w3.org/ns/credentials/v2",
+      "id": "http://university.example/credentials/3732",
+      "type": ["VerifiableCredential", "ExampleDegreeCredential"],
+      "issuer": "https://university.example/issuers/565049",
+      "validFrom": "2010-01-01T00:00:00Z",
+      "credentialSubject": {
+        "id": "did:example:ebfeb1f712ebc6f1c276e12ec21",
+        "degree": { "type": "ExampleBachelorDegree" }
+      }
+    });
+    let credential: Credential = Credential::try_from(&value).unwrap();
+    assert_eq!(credential, Credential::from_json_value(value).unwrap());
+
+    let value: serde_json::Value = credential.to_json_value().unwrap();
+    assert_eq!(Credential::<Object>::from_json_value_ref(&value).unwrap(), credential);
+
+    // A Data Model 1.1 credential is rejected.
+    let v1: serde_json::Value = serde_json::from_str(include_str!("../../tests/fixtures/credential-1.json")).unwrap();
+    assert!(Credential::<Object>::try_from(&v1).is_err());
+  }
+
   #[test]
   fn invalid_from_json_str() {
     let json_credential = include_str!("../../tests/fixtures/credential-1.json");
@@ -0,0 +1,347 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use identity_core::common::Object;
+use serde::de;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+use serde_json::Value;
+
+/// Texts of the same meaning in different languages, keyed by their
+/// [BCP 47](https://www.rfc-editor.org/info/bcp47) language tag.
+///
+/// Texts without a language tag are stored under the empty tag.
+///
+/// Deserializes from any of the forms of the
+/// [`name` and `description` properties](https://www.w3.org/TR/vc-data-model-2.0/#names-and-descriptions)
+/// of credentials:
+/// - a plain string, e.g. `"University Degree"`;
+/// - a [language value object](https://www.w3.org/TR/vc-data-model-2.0/#language-and-base-direction) with its `@value`
+///   and `@language`;
+/// - an array of the above;
+/// - a JSON-LD language map, e.g. `{ "en": "University Degree", "fr": "Diplôme universitaire" }`.
+///
+/// Serializes to a plain string if it only holds an untagged text, and to an array of language value objects
+/// otherwise.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LanguageMap(BTreeMap<String, String>);
+
+impl LanguageMap {
+  /// Creates an empty [`LanguageMap`].
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Sets the text of language `language`, an empty tag denoting an untagged text.
+  pub fn insert(&mut self, language: impl Into<String>, text: impl Into<String>) -> Option<String> {
+    self.0.insert(language.into(), text.into())
+  }
+
+  /// Builder-style variant of [`LanguageMap::insert`].
+  pub fn with(mut self, language: impl Into<String>, text: impl Into<String>) -> Self {
+    self.insert(language, text);
+    self
+  }
+
+  /// Returns the text of exactly language `language`.
+  pub fn get(&self, language: &str) -> Option<&str> {
+    self
+      .0
+      .iter()
+      .find(|(tag, _)| tag.eq_ignore_ascii_case(language))
+      .map(|(_, text)| text.as_str())
+  }
+
+  /// Returns the text best matching `locale`, see [`lookup_locale`].
+  ///
+  /// Returns `None` only if the map is empty.
+  pub fn get_best(&self, locale: &str) -> Option<&str> {
+    lookup_locale(self.iter(), locale)
+  }
+
+  /// Returns an iterator over the language tags and texts of the map.
+  pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+    self.0.iter().map(|(tag, text)| (tag.as_str(), text.as_str()))
+  }
+
+  /// Returns the number of texts in the map.
+  pub fn len(&self) -> usize {
+    self.0.len()
+  }
+
+  /// Returns `true` if the map holds no text.
+  pub fn is_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+}
+
+impl<L: Into<String>, T: Into<String>> FromIterator<(L, T)> for LanguageMap {
+  fn from_iter<I: IntoIterator<Item = (L, T)>>(iter: I) -> Self {
+    Self(iter.into_iter().map(|(tag, text)| (tag.into(), text.into())).collect())
+  }
+}
+
+impl From<String> for LanguageMap {
+  fn from(text: String) -> Self {
+    Self::new().with("", text)
+  }
+}
+
+impl From<&str> for LanguageMap {
+  fn from(text: &str) -> Self {
+    Self::from(text.to_owned())
+  }
+}
+
+impl Serialize for LanguageMap {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    if let (1, Some(text)) = (self.len(), self.0.get("")) {
+      return serializer.serialize_str(text);
+    }
+    let values: Vec<LanguageValue<'_>> = self
+      .iter()
+      .map(|(language, value)| LanguageValue {
+        value,
+        language: Some(language).filter(|language| !language.is_empty()),
+      })
+      .collect();
+    values.serialize(serializer)
+  }
+}
+
+impl<'de> Deserialize<'de> for LanguageMap {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    Self::from_value(&Value::deserialize(deserializer)?).ok_or_else(|| de::Error::custom("invalid language map"))
+  }
+}
+
+impl LanguageMap {
+  fn from_value(value: &Value) -> Option<Self> {
+    let mut map = Self::new();
+    match value {
+      Value::String(text) => {
+        map.insert("", text.as_str());
+      }
+      Value::Object(object) if object.contains_key("@value") => map.insert_language_value(object)?,
+      Value::Object(object) => {
+        for (language, text) in object {
+          map.insert(language.as_str(), text.as_str()?);
+        }
+      }
+      Value::Array(values) => {
+        for value in values {
+          match value {
+            Value::String(text) => {
+              map.insert("", text.as_str());
+            }
+            Value::Object(object) => map.insert_language_value(object)?,
+            _ => return None,
+          }
+        }
+      }
+      _ => return None,
+    }
+
+    Some(map)
+  }
+
+  fn insert_language_value(&mut self, object: &serde_json::Map<String, Value>) -> Option<()> {
+    let text = object.get("@value")?.as_str()?;
+    let language = match object.get("@language") {
+      Some(language) => language.as_str()?,
+      None => "",
+    };
+    self.insert(language, text);
+    Some(())
+  }
+}
+
+#[derive(Serialize)]
+struct LanguageValue<'a> {
+  #[serde(rename = "@value")]
+  value: &'a str,
+  #[serde(rename = "@language", skip_serializing_if = "Option::is_none")]
+  language: Option<&'a str>,
+}
+
+/// The human-readable `name` and `description` of a credential or credential subject.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct CredentialDisplay {
+  /// The name in every available language.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub name: Option<LanguageMap>,
+  /// The description in every available language.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub description: Option<LanguageMap>,
+}
+
+impl CredentialDisplay {
+  /// Extracts the `name` and `description` of the given credential or subject `properties`.
+  ///
+  /// Properties that are not language maps, see [`LanguageMap`], are ignored.
+  pub fn from_properties(properties: &Object) -> Self {
+    Self {
+      name: properties.get("name").and_then(LanguageMap::from_value),
+      description: properties.get("description").and_then(LanguageMap::from_value),
+    }
+  }
+
+  /// Returns `true` if neither a name nor a description is set.
+  pub fn is_empty(&self) -> bool {
+    self.name.is_none() && self.description.is_none()
+  }
+
+  /// Selects the name and description best matching `locale`, see [`lookup_locale`].
+  pub fn display_for(&self, locale: &str) -> LocalizedDisplay {
+    let select = |map: &Option<LanguageMap>| map.as_ref().and_then(|map| map.get_best(locale)).map(ToOwned::to_owned);
+    LocalizedDisplay {
+      name: select(&self.name),
+      description: select(&self.description),
+    }
+  }
+}
+
+/// The name and description of a credential or credential subject in a single language.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct LocalizedDisplay {
+  /// The name, if any.
+  pub name: Option<String>,
+  /// The description, if any.
+  pub description: Option<String>,
+}
+
+/// Selects the value whose [BCP 47](https://www.rfc-editor.org/info/bcp47) language tag best matches `locale`.
+///
+/// Tags are compared case-insensitively, in order of preference:
+/// 1. the tag equal to `locale`, e.g. `de-CH` for `de-CH`;
+/// 2. the tags equal to `locale` stripped of its last subtags, e.g. `de` for `de-CH`, as in the [lookup scheme](https://www.rfc-editor.org/rfc/rfc4647#section-3.4)
+///    of RFC 4647;
+/// 3. the tags of the same primary language, e.g. `de-AT` for `de-CH`;
+/// 4. the untagged values, with an empty tag or `und`;
+/// 5. any other tag.
+///
+/// Among equally good tags, the first one is selected. Returns `None` only if `candidates` is empty.
+pub fn lookup_locale<'t, T>(candidates: impl IntoIterator<Item = (&'t str, T)>, locale: &str) -> Option<T> {
+  let locale = locale.to_ascii_lowercase();
+  let subtags: Vec<&str> = locale.split('-').filter(|subtag| !subtag.is_empty()).collect();
+
+  let rank = |tag: &str| -> usize {
+    let tag = tag.to_ascii_lowercase();
+    let truncations = (1..=subtags.len()).rev().map(|len| subtags[..len].join("-"));
+    if let Some(removed) = truncations
+      .enumerate()
+      .find_map(|(removed, range)| (range == tag).then_some(removed))
+    {
+      return removed;
+    }
+    if subtags
+      .first()
+      .map_or(false, |primary| tag.split('-').next() == Some(primary))
+    {
+      subtags.len()
+    } else if tag.is_empty() || tag == "und" {
+      subtags.len() + 1
+    } else {
+      subtags.len() + 2
+    }
+  };
+
+  candidates
+    .into_iter()
+    .map(|(tag, value)| (rank(tag), value))
+    .fold(None, |best: Option<(usize, T)>, (rank, value)| match best {
+      Some((best_rank, _)) if best_rank <= rank => best,
+      _ => Some((rank, value)),
+    })
+    .map(|(_, value)| value)
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+  use identity_core::convert::ToJson;
+  use serde_json::json;
+
+  use super::*;
+
+  #[test]
+  fn language_map_forms() {
+    let expected = LanguageMap::new()
+      .with("en", "University Degree")
+      .with("fr", "Diplôme universitaire");
+    let forms = [
+      json!([
+        { "@value": "University Degree", "@language": "en" },
+        { "@value": "Diplôme universitaire", "@language": "fr" },
+      ]),
+      json!({ "en": "University Degree", "fr": "Diplôme universitaire" }),
+    ];
+    for form in forms {
+      assert_eq!(LanguageMap::from_json_value(form).unwrap(), expected);
+    }
+    assert_eq!(
+      LanguageMap::from_json_value(json!({ "@value": "Degree", "@language": "en" })).unwrap(),
+      LanguageMap::new().with("en", "Degree")
+    );
+    assert_eq!(
+      LanguageMap::from_json_value(json!("Degree")).unwrap(),
+      LanguageMap::from("Degree")
+    );
+    assert!(LanguageMap::from_json_value(json!(42)).is_err());
+
+    // Serialization round-trips.
+    assert_eq!(LanguageMap::from_json(&expected.to_json().unwrap()).unwrap(), expected);
+    assert_eq!(LanguageMap::from("Degree").to_json_value().unwrap(), json!("Degree"));
+  }
+
+  #[test]
+  fn locale_fallback() {
+    let map = LanguageMap::new()
+      .with("", "untagged")
+      .with("de", "de")
+      .with("de-AT", "de-AT")
+      .with("en-US", "en-US")
+      .with("fr", "fr");
+
+    assert_eq!(map.get_best("de-AT"), Some("de-AT"));
+    assert_eq!(map.get_best("DE-at"), Some("de-AT"));
+    assert_eq!(map.get_best("de-CH-1996"), Some("de"));
+    assert_eq!(map.get_best("en"), Some("en-US"));
+    assert_eq!(map.get_best("it"), Some("untagged"));
+    assert_eq!(LanguageMap::new().with("fr", "fr").get_best("it"), Some("fr"));
+    assert_eq!(LanguageMap::new().get_best("it"), None);
+  }
+
+  #[test]
+  fn display_from_properties() {
+    let properties = Object::from_json_value(json!({
+      "name": [
+        { "@value": "Example University Degree", "@language": "en" },
+        { "@value": "Exemple de diplôme", "@language": "fr-CA" },
+      ],
+      "description": "2015 graduate",
+      "degree": "Bachelor",
+    }))
+    .unwrap();
+
+    let display = CredentialDisplay::from_properties(&properties);
+    assert_eq!(
+      display.display_for("fr"),
+      LocalizedDisplay {
+        name: Some("Exemple de diplôme".to_owned()),
+        description: Some("2015 graduate".to_owned()),
+      }
+    );
+    assert_eq!(
+      display.display_for("en-GB").name.as_deref(),
+      Some("Example University Degree")
+    );
+    assert!(CredentialDisplay::from_properties(&Object::new()).is_empty());
+  }
+}
@@ -13,6 +13,7 @@ use identity_core::common::Object;
 use identity_core::common::OneOrMany;
 use identity_core::common::Timestamp;
 use identity_core::common::Url;
+use identity_core::convert::ToJson;
 use serde::de::DeserializeOwned;
 
 use crate::credential::Credential;
@@ -27,6 +28,25 @@ use crate::credential::Subject;
 use crate::Error;
 use crate::Result;
 
+/// The way the claims of a credential JWT are laid out when the credential is serialized.
+///
+/// JWS signatures are computed over the exact bytes of the claims set, so implementations that re-serialize the
+/// claims, e.g. SDKs in other languages, must reproduce the same layout for the signature to be verifiable.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum JwtClaimsOrdering {
+  /// Claims are laid out following the order of the credential's fields, while custom properties keep the
+  /// order of their underlying map.
+  ///
+  /// This is the layout produced by previous versions of this library.
+  #[default]
+  Legacy,
+  /// Object keys are sorted lexicographically at every nesting level and properties holding one or many values
+  /// are serialized as a single value when they hold exactly one.
+  Canonical,
+}
+
 /// A JWT representing a Verifiable Credential.
 #[derive(Serialize, Deserialize)]
 #[serde(transparent)]
@@ -130,6 +150,30 @@ where
       custom,
     })
   }
+
+  /// Serializes these claims to a JSON string laid out according to `ordering`.
+  pub(crate) fn to_json_with_ordering(mut self, ordering: JwtClaimsOrdering) -> Result<String> {
+    match ordering {
+      JwtClaimsOrdering::Legacy => {
+        serde_json::to_string(&self).map_err(|err| Error::JwtClaimsSetSerializationError(err.into()))
+      }
+      JwtClaimsOrdering::Canonical => {
+        self.vc.normalize_one_or_many();
+        self
+          .to_canonical_json()
+          .map_err(|err| Error::JwtClaimsSetSerializationError(err.into()))
+      }
+    }
+  }
+}
+
+/// Replaces a [`OneOrMany::Many`] holding exactly one value with the equivalent [`OneOrMany::One`].
+fn normalize_one_or_many<T: Clone>(values: &mut Cow<'_, OneOrMany<T>>) {
+  if let OneOrMany::Many(many) = values.as_ref() {
+    if let [value] = many.as_slice() {
+      *values = Cow::Owned(OneOrMany::One(value.clone()));
+    }
+  }
 }
 
 #[cfg(feature = "validator")]
@@ -381,6 +425,21 @@ where
   proof: Option<Cow<'credential, Proof>>,
 }
 
+impl<T> InnerCredential<'_, T>
+where
+  T: ToOwned + Serialize,
+  <T as ToOwned>::Owned: DeserializeOwned,
+{
+  fn normalize_one_or_many(&mut self) {
+    normalize_one_or_many(&mut self.context);
+    normalize_one_or_many(&mut self.types);
+    normalize_one_or_many(&mut self.credential_schema);
+    normalize_one_or_many(&mut self.refresh_service);
+    normalize_one_or_many(&mut self.terms_of_use);
+    normalize_one_or_many(&mut self.evidence);
+  }
+}
+
 #[cfg(feature = "jpt-bbs-plus")]
 impl<'credential, T> From<CredentialJwtClaims<'credential, T>> for JptClaims
 where
@@ -435,6 +494,7 @@ where
 #[cfg(test)]
 mod tests {
   use identity_core::common::Object;
+  use identity_core::common::OneOrMany;
   use identity_core::convert::FromJson;
   use identity_core::convert::ToJson;
 
@@ -442,6 +502,7 @@ mod tests {
   use crate::Error;
 
   use super::CredentialJwtClaims;
+  use super::JwtClaimsOrdering;
 
   #[test]
   fn roundtrip() {
@@ -505,6 +566,53 @@ mod tests {
     assert_eq!(credential, retrieved_credential);
   }
 
+  #[test]
+  fn canonical_ordering_sorts_keys_and_normalizes_one_or_many() {
+    let credential_json: &str = r#"
+    {
+      "@context": ["https://www.w3.org/2018/credentials/v1"],
+      "type": ["VerifiableCredential"],
+      "issuer": "https://example.edu/issuers/14",
+      "issuanceDate": "2010-01-01T19:23:24Z",
+      "credentialSubject": {
+        "id": "did:example:ebfeb1f712ebc6f1c276e12ec21",
+        "name": "Alice",
+        "degree": {
+          "type": "BachelorDegree",
+          "name": "Bachelor of Science in Mechanical Engineering"
+        }
+      },
+      "zeta": 1,
+      "alpha": 2
+    }"#;
+
+    let expected_serialization: &str = concat!(
+      r#"{"iss":"https://example.edu/issuers/14","nbf":1262373804,"sub":"did:example:ebfeb1f712ebc6f1c276e12ec21","#,
+      r#""vc":{"@context":"https://www.w3.org/2018/credentials/v1","alpha":2,"credentialSubject":"#,
+      r#"{"degree":{"name":"Bachelor of Science in Mechanical Engineering","type":"BachelorDegree"},"name":"Alice"},"#,
+      r#""type":"VerifiableCredential","zeta":1}}"#
+    );
+
+    let credential: Credential = Credential::from_json(credential_json).unwrap();
+    let serialized: String = CredentialJwtClaims::new(&credential, None)
+      .unwrap()
+      .to_json_with_ordering(JwtClaimsOrdering::Canonical)
+      .unwrap();
+    assert_eq!(serialized, expected_serialization);
+
+    // Normalizing the representation doesn't change the credential.
+    let retrieved_credential: Credential = CredentialJwtClaims::<'static, Object>::from_json(&serialized)
+      .unwrap()
+      .try_into_credential()
+      .unwrap();
+    assert_eq!(
+      retrieved_credential.types,
+      OneOrMany::One("VerifiableCredential".to_owned())
+    );
+    assert_eq!(retrieved_credential.credential_subject, credential.credential_subject);
+    assert_eq!(retrieved_credential.properties, credential.properties);
+  }
+
   #[test]
   fn claims_duplication() {
     let credential_json: &str = r#"
@@ -6,8 +6,10 @@
 #![allow(clippy::module_inception)]
 
 mod builder;
+mod confirmation;
 mod credential;
 mod credential_v2;
+mod display;
 mod enveloped_credential;
 mod evidence;
 mod issuer;
@@ -22,12 +24,16 @@ mod linked_domain_service;
 mod linked_verifiable_presentation_service;
 mod policy;
 mod proof;
+#[cfg(feature = "redaction")]
+mod redaction;
 mod refresh;
+mod reissuance;
 #[cfg(feature = "revocation-bitmap")]
 mod revocation_bitmap_status;
 mod schema;
 mod status;
 mod subject;
+mod template;
 
 use identity_core::common::Context;
 use identity_core::common::Object;
@@ -35,7 +41,13 @@ use identity_core::common::OneOrMany;
 use identity_core::common::Timestamp;
 
 pub use self::builder::CredentialBuilder;
+pub use self::confirmation::Confirmation;
+pub use self::confirmation::CNF_CLAIM;
 pub use self::credential::Credential;
+pub use self::display::lookup_locale;
+pub use self::display::CredentialDisplay;
+pub use self::display::LanguageMap;
+pub use self::display::LocalizedDisplay;
 pub use self::evidence::Evidence;
 pub use self::issuer::Issuer;
 #[cfg(feature = "jpt-bbs-plus")]
@@ -44,12 +56,25 @@ pub use self::jpt::Jpt;
 pub use self::jwp_credential_options::JwpCredentialOptions;
 pub use self::jws::Jws;
 pub use self::jwt::*;
+pub use self::jwt_serialization::JwtClaimsOrdering;
 pub use self::jwt_serialization::JwtCredential;
 pub use self::linked_domain_service::LinkedDomainService;
 pub use self::linked_verifiable_presentation_service::LinkedVerifiablePresentationService;
 pub use self::policy::Policy;
 pub use self::proof::Proof;
+#[cfg(feature = "redaction")]
+pub use self::redaction::Redacted;
+#[cfg(feature = "redaction")]
+pub use self::redaction::RedactionMode;
+#[cfg(feature = "redaction")]
+pub use self::redaction::RedactionPolicy;
 pub use self::refresh::RefreshService;
+pub use self::reissuance::ChangeKind;
+pub use self::reissuance::ClaimChange;
+pub use self::reissuance::CredentialDiff;
+pub use self::reissuance::CredentialDiffError;
+pub use self::reissuance::StatusChange;
+pub use self::reissuance::ValidityPeriod;
 #[cfg(feature = "revocation-bitmap")]
 pub use self::revocation_bitmap_status::try_index_to_u32;
 #[cfg(feature = "revocation-bitmap")]
@@ -57,6 +82,8 @@ pub use self::revocation_bitmap_status::RevocationBitmapStatus;
 pub use self::schema::Schema;
 pub use self::status::Status;
 pub use self::subject::Subject;
+pub use self::template::CredentialTemplate;
+pub use self::template::StatusTemplate;
 pub use credential_v2::Credential as CredentialV2;
 pub use enveloped_credential::*;
 
@@ -0,0 +1,359 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crypto::hashes::sha::SHA256;
+use crypto::hashes::sha::SHA256_LEN;
+use identity_verification::jwu::encode_b64;
+use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::credential::Credential;
+use crate::credential::CredentialV2;
+
+/// The text replacing redacted values in [`RedactionMode::Mask`].
+const MASK: &str = "<redacted>";
+/// The prefix of the digests replacing redacted values in [`RedactionMode::Hash`].
+const HASH_PREFIX: &str = "sha-256:";
+
+/// How a [`RedactionPolicy`] redacts the values it does not allow.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum RedactionMode {
+  /// Removes the values, along with their keys.
+  Remove,
+  /// Replaces each value with the base64url encoded SHA-256 digest of its JSON serialization, prefixed by
+  /// `sha-256:`.
+  ///
+  /// Equal values have equal digests, which allows to correlate log entries. Note that values with few possible
+  /// states, e.g. a birth date, can be recovered from their digest by trying all of them.
+  #[default]
+  Hash,
+  /// Replaces each value with the text `<redacted>`.
+  Mask,
+}
+
+/// Selects the parts of a credential or presentation that are safe to log.
+///
+/// Parts are allowed through [JSON Pointers](https://www.rfc-editor.org/rfc/rfc6901), in which a `*` segment matches
+/// any key or array index. Allowing a pointer allows the whole value it points to. Every other value is redacted
+/// according to the [`RedactionMode`]; the keys of objects are kept unless values are removed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RedactionPolicy {
+  allowed: Vec<Vec<String>>,
+  mode: RedactionMode,
+}
+
+impl RedactionPolicy {
+  /// The pointers allowed by [`RedactionPolicy::new`]: the properties describing a credential or presentation,
+  /// rather than its subject, at the top level and within embedded credentials.
+  pub const DEFAULT_ALLOWED: &'static [&'static str] = &[
+    "/@context",
+    "/id",
+    "/type",
+    "/issuer",
+    "/issuanceDate",
+    "/expirationDate",
+    "/validFrom",
+    "/validUntil",
+    "/credentialStatus",
+    "/credentialSchema",
+    "/proof/type",
+    "/proof/verificationMethod",
+    "/verifiableCredential/*/@context",
+    "/verifiableCredential/*/id",
+    "/verifiableCredential/*/type",
+    "/verifiableCredential/*/issuer",
+    "/verifiableCredential/*/issuanceDate",
+    "/verifiableCredential/*/expirationDate",
+    "/verifiableCredential/*/validFrom",
+    "/verifiableCredential/*/validUntil",
+  ];
+
+  /// Creates a policy allowing [`RedactionPolicy::DEFAULT_ALLOWED`], that hashes all other values.
+  pub fn new() -> Self {
+    Self::DEFAULT_ALLOWED
+      .iter()
+      .fold(Self::deny_all(), |policy, pointer| policy.allow(pointer))
+  }
+
+  /// Creates a policy allowing nothing, that hashes all values.
+  pub fn deny_all() -> Self {
+    Self {
+      allowed: Vec::new(),
+      mode: RedactionMode::default(),
+    }
+  }
+
+  /// Additionally allows the value at JSON Pointer `pointer`, e.g. `/credentialSubject/degree/type`.
+  ///
+  /// The empty pointer allows everything.
+  pub fn allow(mut self, pointer: &str) -> Self {
+    let segments = pointer
+      .split('/')
+      .skip(1)
+      .map(|segment| segment.replace("~1", "/").replace("~0", "~"))
+      .collect();
+    self.allowed.push(segments);
+    self
+  }
+
+  /// Sets how values that are not allowed are redacted.
+  pub fn mode(mut self, mode: RedactionMode) -> Self {
+    self.mode = mode;
+    self
+  }
+
+  /// Redacts `value` according to this policy.
+  pub fn redact_value(&self, value: &Value) -> Value {
+    self.redact_at(value, &mut Vec::new()).unwrap_or(Value::Null)
+  }
+
+  /// Serializes `value` to JSON and redacts it according to this policy.
+  pub fn redact<T: Serialize + ?Sized>(&self, value: &T) -> Redacted {
+    let value = serde_json::to_value(value).unwrap_or_else(|_| Value::String(MASK.to_owned()));
+    Redacted(self.redact_value(&value))
+  }
+
+  fn redact_at(&self, value: &Value, path: &mut Vec<String>) -> Option<Value> {
+    let matches = |pointer: &[String], path: &[String]| {
+      pointer
+        .iter()
+        .zip(path)
+        .all(|(segment, key)| segment == "*" || segment == key)
+    };
+    let allowed = self
+      .allowed
+      .iter()
+      .any(|pointer| pointer.len() <= path.len() && matches(pointer, path));
+    if allowed {
+      return Some(value.clone());
+    }
+    let ancestor = self
+      .allowed
+      .iter()
+      .any(|pointer| pointer.len() > path.len() && matches(pointer, path));
+
+    let mut redact_child = |key: String, child: &Value| {
+      path.push(key);
+      let redacted = self.redact_at(child, path);
+      path.pop();
+      redacted
+    };
+    match value {
+      Value::Object(object) if ancestor || self.mode != RedactionMode::Remove => Some(Value::Object(
+        object
+          .iter()
+          .filter_map(|(key, child)| Some((key.clone(), redact_child(key.clone(), child)?)))
+          .collect::<Map<String, Value>>(),
+      )),
+      Value::Array(values) if ancestor || self.mode != RedactionMode::Remove => Some(Value::Array(
+        values
+          .iter()
+          .enumerate()
+          .filter_map(|(index, child)| redact_child(index.to_string(), child))
+          .collect(),
+      )),
+      _ => self.redact_leaf(value),
+    }
+  }
+
+  fn redact_leaf(&self, value: &Value) -> Option<Value> {
+    match self.mode {
+      RedactionMode::Remove => None,
+      RedactionMode::Mask => Some(Value::String(MASK.to_owned())),
+      RedactionMode::Hash => {
+        let mut digest = [0; SHA256_LEN];
+        SHA256(value.to_string().as_bytes(), &mut digest);
+        Some(Value::String(format!("{HASH_PREFIX}{}", encode_b64(digest))))
+      }
+    }
+  }
+}
+
+impl Default for RedactionPolicy {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+/// A redacted credential or presentation, see [`RedactionPolicy`].
+///
+/// Both its [`Display`] and [`Debug`] implementations print the redacted JSON.
+#[derive(Clone, PartialEq, Eq, Serialize)]
+#[serde(transparent)]
+pub struct Redacted(Value);
+
+impl Redacted {
+  /// Returns a reference to the redacted JSON value.
+  pub fn as_value(&self) -> &Value {
+    &self.0
+  }
+
+  /// Consumes the [`Redacted`] and returns the redacted JSON value.
+  pub fn into_value(self) -> Value {
+    self.0
+  }
+}
+
+impl Display for Redacted {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    Display::fmt(&self.0, f)
+  }
+}
+
+impl Debug for Redacted {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    Display::fmt(&self.0, f)
+  }
+}
+
+impl<T: Serialize> Credential<T> {
+  /// Returns a representation of this credential that is safe to log, see [`RedactionPolicy`].
+  pub fn redact(&self, policy: &RedactionPolicy) -> Redacted {
+    policy.redact(self)
+  }
+}
+
+impl<T: Serialize> CredentialV2<T> {
+  /// Returns a representation of this credential that is safe to log, see [`RedactionPolicy`].
+  pub fn redact(&self, policy: &RedactionPolicy) -> Redacted {
+    policy.redact(self)
+  }
+}
+
+#[cfg(feature = "presentation")]
+impl<CRED: Serialize, T: Serialize> crate::presentation::Presentation<CRED, T> {
+  /// Returns a representation of this presentation that is safe to log, see [`RedactionPolicy`].
+  ///
+  /// Embedded credentials are redacted as part of the presentation, at `/verifiableCredential/<index>`. Credentials
+  /// embedded as JWTs are redacted as a whole, as their claims are not visible to the policy.
+  pub fn redact(&self, policy: &RedactionPolicy) -> Redacted {
+    policy.redact(self)
+  }
+}
+
+#[cfg(feature = "validator")]
+impl<T: Serialize> crate::validator::DecodedJwtCredential<T> {
+  /// Returns a representation of this decoded credential that is safe to log, see [`RedactionPolicy`].
+  ///
+  /// The result is an object with the JWS `header`, which is kept as is, the `credential` and the `customClaims`,
+  /// which are both redacted by `policy`.
+  pub fn redact(&self, policy: &RedactionPolicy) -> Redacted {
+    let mut object = Map::new();
+    object.insert(
+      "header".to_owned(),
+      serde_json::to_value(&self.header).unwrap_or(Value::Null),
+    );
+    object.insert("credential".to_owned(), self.credential.redact(policy).into_value());
+    if let Some(custom_claims) = &self.custom_claims {
+      object.insert("customClaims".to_owned(), policy.redact(custom_claims).into_value());
+    }
+    Redacted(Value::Object(object))
+  }
+}
+
+#[cfg(feature = "validator")]
+impl<T: Serialize> crate::validator::DecodedJwtCredentialV2<T> {
+  /// Returns a representation of this decoded credential that is safe to log, see [`RedactionPolicy`].
+  ///
+  /// The result is an object with the JWS `header`, which is kept as is, and the `credential`, which is redacted by
+  /// `policy`.
+  pub fn redact(&self, policy: &RedactionPolicy) -> Redacted {
+    let mut object = Map::new();
+    object.insert(
+      "header".to_owned(),
+      serde_json::to_value(&self.header).unwrap_or(Value::Null),
+    );
+    object.insert("credential".to_owned(), self.credential.redact(policy).into_value());
+    Redacted(Value::Object(object))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+  use serde_json::json;
+
+  use super::*;
+
+  fn credential() -> Credential {
+    Credential::from_json_value(json!({
+      "@context": "https://www.w3.org/2018/credentials/v1",
+      "id": "https://example.edu/credentials/3732",
+      "type": ["VerifiableCredential", "UniversityDegreeCredential"],
+      "issuer": "did:example:issuer",
+      "issuanceDate": "2010-01-01T00:00:00Z",
+      "credentialSubject": {
+        "id": "did:example:holder",
+        "name": "Alice",
+        "degree": { "type": "BachelorDegree", "name": "Bachelor of Science" },
+        "grades": [1, 2]
+      }
+    }))
+    .unwrap()
+  }
+
+  #[test]
+  fn default_policy_hashes_subject() {
+    let redacted = credential().redact(&RedactionPolicy::new()).into_value();
+    assert_eq!(redacted["issuer"], "did:example:issuer");
+    assert_eq!(redacted["type"][1], "UniversityDegreeCredential");
+
+    let subject = &redacted["credentialSubject"];
+    let name = subject["name"].as_str().unwrap();
+    assert!(name.starts_with(HASH_PREFIX));
+    assert_ne!(subject["id"], "did:example:holder");
+    assert!(subject["degree"]["type"].as_str().unwrap().starts_with(HASH_PREFIX));
+    assert_eq!(subject["grades"].as_array().unwrap().len(), 2);
+    assert!(!redacted.to_string().contains("Alice"));
+
+    // Digests are deterministic.
+    assert_eq!(
+      credential().redact(&RedactionPolicy::new()).into_value()["credentialSubject"]["name"],
+      name
+    );
+  }
+
+  #[test]
+  fn allowed_pointers() {
+    let policy = RedactionPolicy::deny_all()
+      .allow("/type")
+      .allow("/credentialSubject/degree/type")
+      .allow("/credentialSubject/grades/*")
+      .mode(RedactionMode::Remove);
+    assert_eq!(
+      credential().redact(&policy).into_value(),
+      json!({
+        "type": ["VerifiableCredential", "UniversityDegreeCredential"],
+        "credentialSubject": { "degree": { "type": "BachelorDegree" }, "grades": [1, 2] }
+      })
+    );
+
+    let masked = credential()
+      .redact(&RedactionPolicy::deny_all().mode(RedactionMode::Mask))
+      .into_value();
+    assert_eq!(masked["credentialSubject"]["degree"]["name"], MASK);
+    assert_eq!(masked["issuer"], MASK);
+
+    let everything = RedactionPolicy::deny_all().allow("");
+    assert_eq!(
+      credential().redact(&everything).into_value(),
+      serde_json::to_value(credential()).unwrap()
+    );
+  }
+
+  #[test]
+  fn pointer_escapes() {
+    let policy = RedactionPolicy::deny_all()
+      .allow("/a~1b/c~0d")
+      .mode(RedactionMode::Remove);
+    assert_eq!(
+      policy.redact_value(&json!({ "a/b": { "c~d": 1, "e": 2 }, "f": 3 })),
+      json!({ "a/b": { "c~d": 1 } })
+    );
+  }
+}
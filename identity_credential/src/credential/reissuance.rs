@@ -0,0 +1,420 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Timestamp;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
+
+/// Properties of W3C credentials that are compared separately from the claims.
+const CREDENTIAL_PROPERTIES: [&str; 6] = [
+  "issuanceDate",
+  "expirationDate",
+  "validFrom",
+  "validUntil",
+  "credentialStatus",
+  "proof",
+];
+/// Claims of SD-JWT VCs that are compared separately from the other claims.
+const SD_JWT_VC_CLAIMS: [&str; 5] = ["iat", "nbf", "exp", "status", "_sd_alg"];
+
+/// An error preventing the comparison of two credentials.
+#[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum CredentialDiffError {
+  /// A credential cannot be represented as JSON, or a claim has an unexpected type.
+  #[error("invalid credential: {0}")]
+  InvalidCredential(String),
+  /// The credentials were issued by different issuers.
+  #[error("the credentials were issued by different issuers")]
+  IssuerMismatch,
+  /// The credentials are about different subjects.
+  #[error("the credentials are about different subjects")]
+  SubjectMismatch,
+  /// The disclosures of an SD-JWT VC cannot be resolved.
+  #[cfg(feature = "sd-jwt-vc")]
+  #[error("invalid SD-JWT VC")]
+  SdJwtVc(#[source] crate::sd_jwt_vc::Error),
+}
+
+identity_core::impl_error_code!(CredentialDiffError {
+  InvalidCredential => 2470,
+  IssuerMismatch => 2471,
+  SubjectMismatch => 2472,
+  #[cfg(feature = "sd-jwt-vc")]
+  SdJwtVc => 2473,
+});
+
+/// The kind of a [`ClaimChange`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ChangeKind {
+  /// The claim is only in the new credential.
+  Added,
+  /// The claim is only in the previous credential.
+  Removed,
+  /// The claim has a different value in the new credential.
+  Modified,
+}
+
+/// A claim whose value differs between two credentials.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClaimChange {
+  /// The JSON pointer to the claim, e.g. `/credentialSubject/degree/name`.
+  pub path: String,
+  /// How the claim changed.
+  pub kind: ChangeKind,
+  /// The value of the claim in the previous credential.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub previous: Option<Value>,
+  /// The value of the claim in the new credential.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub current: Option<Value>,
+}
+
+/// The period in which a credential is valid.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidityPeriod {
+  /// The date from which the credential is valid, i.e. its issuance date or `validFrom` property.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub valid_from: Option<Timestamp>,
+  /// The date until which the credential is valid, i.e. its expiration date or `validUntil` property.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub valid_until: Option<Timestamp>,
+}
+
+/// A change of the status entry of a credential.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusChange {
+  /// The status entry of the previous credential.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub previous: Option<Value>,
+  /// The status entry of the new credential.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub current: Option<Value>,
+}
+
+/// The differences between a newly issued credential and the credential it replaces, for a holder to review before
+/// accepting the new credential, e.g. in automated refresh flows.
+///
+/// Both credentials must be issued by the same issuer to the same subject.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CredentialDiff {
+  /// The claims that were added, removed or modified, ordered by path.
+  pub claims: Vec<ClaimChange>,
+  /// The validity period of the previous credential.
+  pub previous_validity: ValidityPeriod,
+  /// The validity period of the new credential.
+  pub current_validity: ValidityPeriod,
+  /// The change of the status entry, if it changed.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub status: Option<StatusChange>,
+}
+
+impl CredentialDiff {
+  /// Compares the `current` credential with the `previous` one it replaces.
+  ///
+  /// Works with any credential serialized according to the VC Data Model v1.1 or v2.0, e.g. a
+  /// [`Credential`](crate::credential::Credential) or a [`CredentialV2`](crate::credential::CredentialV2). The
+  /// `proof` property is ignored.
+  pub fn between<C: Serialize + ?Sized>(previous: &C, current: &C) -> Result<Self, CredentialDiffError> {
+    let previous: Map<String, Value> = to_object(previous)?;
+    let current: Map<String, Value> = to_object(current)?;
+
+    if issuer_id(&previous) != issuer_id(&current) {
+      return Err(CredentialDiffError::IssuerMismatch);
+    }
+    if subject_ids(&previous) != subject_ids(&current) {
+      return Err(CredentialDiffError::SubjectMismatch);
+    }
+
+    let validity = |credential: &Map<String, Value>| -> Result<ValidityPeriod, CredentialDiffError> {
+      Ok(ValidityPeriod {
+        valid_from: date(credential, "validFrom")?.or(date(credential, "issuanceDate")?),
+        valid_until: date(credential, "validUntil")?.or(date(credential, "expirationDate")?),
+      })
+    };
+    Ok(Self::new(
+      &previous,
+      &current,
+      validity(&previous)?,
+      validity(&current)?,
+      "credentialStatus",
+      &CREDENTIAL_PROPERTIES,
+    ))
+  }
+
+  /// Compares the `current` SD-JWT VC with the `previous` one it replaces, with all their disclosures resolved using
+  /// `hasher`.
+  ///
+  /// The `iss` and `sub` claims must be the same; the key binding JWTs, if any, are ignored.
+  #[cfg(feature = "sd-jwt-vc")]
+  pub fn between_sd_jwt_vc(
+    previous: &crate::sd_jwt_vc::SdJwtVc,
+    current: &crate::sd_jwt_vc::SdJwtVc,
+    hasher: &dyn sd_jwt::Hasher,
+  ) -> Result<Self, CredentialDiffError> {
+    if previous.claims().iss != current.claims().iss {
+      return Err(CredentialDiffError::IssuerMismatch);
+    }
+    if previous.claims().sub != current.claims().sub {
+      return Err(CredentialDiffError::SubjectMismatch);
+    }
+    let disclose = |sd_jwt_vc: &crate::sd_jwt_vc::SdJwtVc| {
+      sd_jwt_vc
+        .clone()
+        .into_disclosed_object(hasher)
+        .map_err(CredentialDiffError::SdJwtVc)
+    };
+    let previous: Map<String, Value> = disclose(previous)?;
+    let current: Map<String, Value> = disclose(current)?;
+
+    let validity = |claims: &Map<String, Value>| -> Result<ValidityPeriod, CredentialDiffError> {
+      Ok(ValidityPeriod {
+        valid_from: unix_date(claims, "nbf")?.or(unix_date(claims, "iat")?),
+        valid_until: unix_date(claims, "exp")?,
+      })
+    };
+    Ok(Self::new(
+      &previous,
+      &current,
+      validity(&previous)?,
+      validity(&current)?,
+      "status",
+      &SD_JWT_VC_CLAIMS,
+    ))
+  }
+
+  fn new(
+    previous: &Map<String, Value>,
+    current: &Map<String, Value>,
+    previous_validity: ValidityPeriod,
+    current_validity: ValidityPeriod,
+    status_property: &str,
+    ignored: &[&str],
+  ) -> Self {
+    let mut claims: Vec<ClaimChange> = Vec::new();
+    diff_objects("", previous, current, ignored, &mut claims);
+    claims.sort_by(|a, b| a.path.cmp(&b.path));
+
+    let previous_status: Option<&Value> = previous.get(status_property);
+    let current_status: Option<&Value> = current.get(status_property);
+    let status: Option<StatusChange> = (previous_status != current_status).then(|| StatusChange {
+      previous: previous_status.cloned(),
+      current: current_status.cloned(),
+    });
+
+    Self {
+      claims,
+      previous_validity,
+      current_validity,
+      status,
+    }
+  }
+
+  /// Returns `true` if the credentials have the same claims, validity period and status entry.
+  pub fn is_empty(&self) -> bool {
+    self.claims.is_empty() && !self.validity_changed() && self.status.is_none()
+  }
+
+  /// Returns `true` if the validity period changed.
+  pub fn validity_changed(&self) -> bool {
+    self.previous_validity != self.current_validity
+  }
+
+  /// Returns `true` if the new credential expires earlier than the previous one, or expires while the previous one
+  /// did not.
+  pub fn validity_shortened(&self) -> bool {
+    match (self.previous_validity.valid_until, self.current_validity.valid_until) {
+      (None, Some(_)) => true,
+      (Some(previous), Some(current)) => current < previous,
+      _ => false,
+    }
+  }
+}
+
+fn to_object<C: Serialize + ?Sized>(credential: &C) -> Result<Map<String, Value>, CredentialDiffError> {
+  match serde_json::to_value(credential) {
+    Ok(Value::Object(object)) => Ok(object),
+    Ok(_) => Err(CredentialDiffError::InvalidCredential(
+      "expected a JSON object".to_owned(),
+    )),
+    Err(err) => Err(CredentialDiffError::InvalidCredential(err.to_string())),
+  }
+}
+
+fn issuer_id(credential: &Map<String, Value>) -> Option<&Value> {
+  credential
+    .get("issuer")
+    .map(|issuer| issuer.get("id").unwrap_or(issuer))
+}
+
+fn subject_ids(credential: &Map<String, Value>) -> Vec<Option<&Value>> {
+  match credential.get("credentialSubject") {
+    Some(Value::Array(subjects)) => subjects.iter().map(|subject| subject.get("id")).collect(),
+    Some(subject) => vec![subject.get("id")],
+    None => Vec::new(),
+  }
+}
+
+fn date(credential: &Map<String, Value>, property: &str) -> Result<Option<Timestamp>, CredentialDiffError> {
+  credential
+    .get(property)
+    .map(|value| {
+      value
+        .as_str()
+        .and_then(|value| Timestamp::parse(value).ok())
+        .ok_or_else(|| CredentialDiffError::InvalidCredential(format!("invalid `{property}`")))
+    })
+    .transpose()
+}
+
+#[cfg(feature = "sd-jwt-vc")]
+fn unix_date(claims: &Map<String, Value>, claim: &str) -> Result<Option<Timestamp>, CredentialDiffError> {
+  claims
+    .get(claim)
+    .map(|value| {
+      value
+        .as_i64()
+        .and_then(|value| Timestamp::from_unix(value).ok())
+        .ok_or_else(|| CredentialDiffError::InvalidCredential(format!("invalid `{claim}`")))
+    })
+    .transpose()
+}
+
+/// Appends the changes between the `previous` and `current` objects found at `path`, recursing into nested objects.
+fn diff_objects(
+  path: &str,
+  previous: &Map<String, Value>,
+  current: &Map<String, Value>,
+  ignored: &[&str],
+  changes: &mut Vec<ClaimChange>,
+) {
+  let keys = previous
+    .keys()
+    .chain(current.keys().filter(|key| !previous.contains_key(*key)));
+  for key in keys {
+    if path.is_empty() && ignored.contains(&key.as_str()) {
+      continue;
+    }
+    let claim_path: String = format!("{path}/{}", key.replace('~', "~0").replace('/', "~1"));
+    match (previous.get(key), current.get(key)) {
+      (Some(Value::Object(previous)), Some(Value::Object(current))) => {
+        diff_objects(&claim_path, previous, current, &[], changes)
+      }
+      (Some(previous), Some(current)) if previous == current => (),
+      (previous, current) => changes.push(ClaimChange {
+        path: claim_path,
+        kind: match (previous, current) {
+          (None, _) => ChangeKind::Added,
+          (_, None) => ChangeKind::Removed,
+          _ => ChangeKind::Modified,
+        },
+        previous: previous.cloned(),
+        current: current.cloned(),
+      }),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+  use serde_json::json;
+
+  use super::*;
+  use crate::credential::Credential;
+
+  fn credential(degree: &str, expiration_date: &str, index: u32) -> Credential {
+    Credential::from_json_value(json!({
+      "@context": "https://www.w3.org/2018/credentials/v1",
+      "type": ["VerifiableCredential", "UniversityDegreeCredential"],
+      "issuer": { "id": "did:example:university", "name": "Example University" },
+      "issuanceDate": "2020-01-01T00:00:00Z",
+      "expirationDate": expiration_date,
+      "credentialStatus": {
+        "id": format!("did:example:university#revocation-{index}"),
+        "type": "RevocationBitmap2022",
+        "revocationBitmapIndex": index.to_string(),
+      },
+      "credentialSubject": {
+        "id": "did:example:alice",
+        "degree": { "type": "BachelorDegree", "name": degree },
+        "gpa": "4.0",
+      },
+    }))
+    .unwrap()
+  }
+
+  #[test]
+  fn reissued_credentials_are_compared() {
+    let previous = credential("Bachelor of Science", "2025-01-01T00:00:00Z", 5);
+    assert!(CredentialDiff::between(&previous, &previous).unwrap().is_empty());
+
+    let mut current = credential("Bachelor of Science and Arts", "2024-01-01T00:00:00Z", 6);
+    let subject = current.credential_subject.get_mut(0).unwrap();
+    subject.properties.remove("gpa");
+    subject.properties.insert("honors".to_owned(), json!(true));
+
+    let diff = CredentialDiff::between(&previous, &current).unwrap();
+    assert_eq!(
+      diff.claims,
+      [
+        ClaimChange {
+          path: "/credentialSubject/degree/name".to_owned(),
+          kind: ChangeKind::Modified,
+          previous: Some(json!("Bachelor of Science")),
+          current: Some(json!("Bachelor of Science and Arts")),
+        },
+        ClaimChange {
+          path: "/credentialSubject/gpa".to_owned(),
+          kind: ChangeKind::Removed,
+          previous: Some(json!("4.0")),
+          current: None,
+        },
+        ClaimChange {
+          path: "/credentialSubject/honors".to_owned(),
+          kind: ChangeKind::Added,
+          previous: None,
+          current: Some(json!(true)),
+        },
+      ]
+    );
+    assert!(diff.validity_changed());
+    assert!(diff.validity_shortened());
+    assert_eq!(
+      diff.current_validity.valid_until,
+      Some(Timestamp::parse("2024-01-01T00:00:00Z").unwrap())
+    );
+    let status = diff.status.as_ref().unwrap();
+    assert_eq!(status.current.as_ref().unwrap()["revocationBitmapIndex"], "6");
+
+    let json = serde_json::to_value(&diff).unwrap();
+    assert_eq!(json["claims"][2]["kind"], "added");
+    assert_eq!(json["currentValidity"]["validUntil"], "2024-01-01T00:00:00Z");
+  }
+
+  #[test]
+  fn credentials_of_other_subjects_are_rejected() {
+    let previous = credential("Bachelor of Science", "2025-01-01T00:00:00Z", 5);
+    let mut current = previous.clone();
+    current.credential_subject.get_mut(0).unwrap().id = Some("did:example:bob".parse().unwrap());
+    assert!(matches!(
+      CredentialDiff::between(&previous, &current),
+      Err(CredentialDiffError::SubjectMismatch)
+    ));
+
+    let mut current = previous.clone();
+    current.issuer = crate::credential::Issuer::Url("did:example:mill".parse().unwrap());
+    assert!(matches!(
+      CredentialDiff::between(&previous, &current),
+      Err(CredentialDiffError::IssuerMismatch)
+    ));
+  }
+}
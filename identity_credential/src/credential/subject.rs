@@ -7,6 +7,9 @@ use serde::Serialize;
 use identity_core::common::Object;
 use identity_core::common::Url;
 
+use crate::credential::CredentialDisplay;
+use crate::credential::LocalizedDisplay;
+
 /// An entity who is the target of a set of claims.
 ///
 /// [More Info](https://www.w3.org/TR/vc-data-model/#credential-subject)
@@ -43,6 +46,18 @@ impl Subject {
       properties,
     }
   }
+
+  /// Returns the human-readable `name` and `description` of this subject in every available language.
+  pub fn display(&self) -> CredentialDisplay {
+    CredentialDisplay::from_properties(&self.properties)
+  }
+
+  /// Returns the `name` and `description` of this subject in the language best matching `locale`.
+  ///
+  /// See [`lookup_locale`](crate::credential::lookup_locale) for how the language is selected.
+  pub fn display_for(&self, locale: &str) -> LocalizedDisplay {
+    self.display().display_for(locale)
+  }
 }
 
 #[cfg(test)]
@@ -0,0 +1,355 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use identity_core::common::Context;
+use identity_core::common::Object;
+use identity_core::common::Value;
+#[cfg(feature = "revocation-bitmap")]
+use identity_did::DIDUrl;
+
+use crate::credential::CredentialBuilder;
+use crate::credential::Evidence;
+use crate::credential::Issuer;
+#[cfg(feature = "revocation-bitmap")]
+use crate::credential::RevocationBitmapStatus;
+use crate::credential::Schema;
+use crate::credential::Status;
+use crate::credential::Subject;
+use crate::error::Error;
+use crate::error::Result;
+
+/// How the `credentialStatus` of the credentials instantiated from a [`CredentialTemplate`] is determined.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum StatusTemplate {
+  /// Every credential has the same status.
+  Fixed(Status),
+  /// Every credential is tracked at its own index of the `RevocationBitmap2022` service identified by the given
+  /// [`DIDUrl`]. See [`RevocationBitmapStatus`].
+  #[cfg(feature = "revocation-bitmap")]
+  RevocationBitmap(DIDUrl),
+}
+
+impl StatusTemplate {
+  fn to_status(&self, index: Option<u32>) -> Result<Status> {
+    match (self, index) {
+      (Self::Fixed(status), _) => Ok(status.clone()),
+      #[cfg(feature = "revocation-bitmap")]
+      (Self::RevocationBitmap(service), Some(index)) => Ok(RevocationBitmapStatus::new(service.clone(), index).into()),
+      #[cfg(feature = "revocation-bitmap")]
+      (Self::RevocationBitmap(_), None) => Err(Error::InvalidStatus(
+        "the template requires a revocation index for every credential".to_owned(),
+      )),
+    }
+  }
+}
+
+/// A reusable description of a type of credential, from which credentials are instantiated by supplying the claims
+/// about their subject.
+///
+/// A template defines the parts common to all credentials of the same type, i.e. their contexts, types, issuer,
+/// schemas, evidence and status. Subject claims are renamed or nested according to the template's claim mapping and
+/// checked against its required claims.
+///
+/// Templates are serializable, so that issuers can maintain them as configuration rather than code.
+///
+/// ## Example
+/// ```
+/// # use identity_core::common::Object;
+/// # use identity_core::common::Url;
+/// # use identity_credential::credential::CredentialTemplate;
+/// # use serde_json::json;
+/// let template = CredentialTemplate::new("UniversityDegreeCredential")
+///   .issuer(Url::parse("did:example:issuer").unwrap())
+///   .map_claim("degree_name", "/degree/name")
+///   .require_claim("/degree/name");
+///
+/// let claims: Object = serde_json::from_value(json!({
+///   "id": "did:example:holder",
+///   "degree_name": "Bachelor of Science and Arts",
+/// }))
+/// .unwrap();
+/// let credential = template.instantiate(claims).unwrap().build().unwrap();
+///
+/// assert_eq!(
+///   credential.credential_subject.first().unwrap().properties["degree"],
+///   json!({ "name": "Bachelor of Science and Arts" })
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CredentialTemplate {
+  /// The contexts of the credentials, in addition to the base context.
+  #[serde(default, rename = "@context", skip_serializing_if = "Vec::is_empty")]
+  pub context: Vec<Context>,
+  /// The types of the credentials, in addition to the base type.
+  #[serde(default, rename = "type", skip_serializing_if = "Vec::is_empty")]
+  pub types: Vec<String>,
+  /// The issuer of the credentials.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub issuer: Option<Issuer>,
+  /// The schemas the credentials conform to.
+  #[serde(default, rename = "credentialSchema", skip_serializing_if = "Vec::is_empty")]
+  pub schema: Vec<Schema>,
+  /// The evidence supporting the claims of every credential.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub evidence: Vec<Evidence>,
+  /// How the status of the credentials is determined.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub status: Option<StatusTemplate>,
+  /// Maps the name of a supplied claim to the [JSON pointer](https://datatracker.ietf.org/doc/html/rfc6901) of the
+  /// subject property it is stored into. Claims without a mapping are stored as is.
+  #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+  pub claim_mapping: BTreeMap<String, String>,
+  /// JSON pointers to the subject properties every credential must have, after mapping.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub required_claims: Vec<String>,
+}
+
+impl CredentialTemplate {
+  /// Creates a new [`CredentialTemplate`] for credentials of type `type_`.
+  pub fn new(type_: impl Into<String>) -> Self {
+    Self {
+      types: vec![type_.into()],
+      ..Default::default()
+    }
+  }
+
+  /// Adds a value to the template's context set.
+  #[must_use]
+  pub fn context(mut self, value: impl Into<Context>) -> Self {
+    self.context.push(value.into());
+    self
+  }
+
+  /// Adds a value to the template's type set.
+  #[must_use]
+  pub fn type_(mut self, value: impl Into<String>) -> Self {
+    self.types.push(value.into());
+    self
+  }
+
+  /// Sets the issuer of the credentials.
+  #[must_use]
+  pub fn issuer(mut self, value: impl Into<Issuer>) -> Self {
+    self.issuer = Some(value.into());
+    self
+  }
+
+  /// Adds a value to the template's `credentialSchema` set.
+  #[must_use]
+  pub fn schema(mut self, value: Schema) -> Self {
+    self.schema.push(value);
+    self
+  }
+
+  /// Adds a value to the template's `evidence` set.
+  #[must_use]
+  pub fn evidence(mut self, value: Evidence) -> Self {
+    self.evidence.push(value);
+    self
+  }
+
+  /// Sets how the status of the credentials is determined.
+  #[must_use]
+  pub fn status(mut self, value: StatusTemplate) -> Self {
+    self.status = Some(value);
+    self
+  }
+
+  /// Stores the supplied claim `claim` into the subject property at the JSON pointer `pointer`.
+  #[must_use]
+  pub fn map_claim(mut self, claim: impl Into<String>, pointer: impl Into<String>) -> Self {
+    self.claim_mapping.insert(claim.into(), pointer.into());
+    self
+  }
+
+  /// Requires every credential to have the subject property at the JSON pointer `pointer`.
+  #[must_use]
+  pub fn require_claim(mut self, pointer: impl Into<String>) -> Self {
+    self.required_claims.push(pointer.into());
+    self
+  }
+
+  /// Returns a [`CredentialBuilder`] pre-filled with the template and a subject made of the given `claims`.
+  ///
+  /// The returned builder can be used to set the remaining properties of the credential, e.g. its `id` or issuance
+  /// date.
+  ///
+  /// # Errors
+  /// - [`Error::MissingTemplateClaim`] if a required claim is missing.
+  /// - [`Error::InvalidStatus`] if the template's status requires a revocation index, see
+  ///   [`Self::instantiate_with_index`].
+  pub fn instantiate(&self, claims: Object) -> Result<CredentialBuilder> {
+    self.instantiate_impl(claims, None)
+  }
+
+  /// Same as [`Self::instantiate`], tracking the status of the credential at index `index` of the template's
+  /// revocation bitmap.
+  pub fn instantiate_with_index(&self, claims: Object, index: u32) -> Result<CredentialBuilder> {
+    self.instantiate_impl(claims, Some(index))
+  }
+
+  fn instantiate_impl(&self, claims: Object, index: Option<u32>) -> Result<CredentialBuilder> {
+    let subject = self.map_claims(claims)?;
+    for pointer in &self.required_claims {
+      if subject.pointer(pointer).is_none() {
+        return Err(Error::MissingTemplateClaim(pointer.clone()));
+      }
+    }
+    let subject: Subject = serde_json::from_value(subject).map_err(|_| Error::InvalidSubject)?;
+
+    let mut builder = CredentialBuilder::default().subject(subject);
+    for context in &self.context {
+      builder = builder.context(context.clone());
+    }
+    for type_ in &self.types {
+      builder = builder.type_(type_.clone());
+    }
+    if let Some(issuer) = &self.issuer {
+      builder = builder.issuer(issuer.clone());
+    }
+    for schema in &self.schema {
+      builder = builder.schema(schema.clone());
+    }
+    for evidence in &self.evidence {
+      builder = builder.evidence(evidence.clone());
+    }
+    if let Some(status) = &self.status {
+      builder = builder.status(status.to_status(index)?);
+    }
+
+    Ok(builder)
+  }
+
+  fn map_claims(&self, claims: Object) -> Result<Value> {
+    let mut subject = Value::Object(Object::new());
+    for (claim, value) in claims {
+      match self.claim_mapping.get(&claim) {
+        Some(pointer) => insert_at(&mut subject, pointer, value)?,
+        None => {
+          subject
+            .as_object_mut()
+            .expect("subject is an object")
+            .insert(claim, value);
+        }
+      }
+    }
+
+    Ok(subject)
+  }
+}
+
+/// Inserts `value` at the JSON pointer `pointer` of `target`, creating the missing intermediate objects.
+fn insert_at(target: &mut Value, pointer: &str, value: Value) -> Result<()> {
+  let Some(path) = pointer.strip_prefix('/') else {
+    return Err(Error::InvalidSubject);
+  };
+
+  let mut current = target;
+  let mut segments = path
+    .split('/')
+    .map(|segment| segment.replace("~1", "/").replace("~0", "~"));
+  let mut segment = segments.next().expect("split yields at least one segment");
+  for next in segments {
+    current = current
+      .as_object_mut()
+      .ok_or(Error::InvalidSubject)?
+      .entry(segment)
+      .or_insert_with(|| Value::Object(Object::new()));
+    segment = next;
+  }
+  current
+    .as_object_mut()
+    .ok_or(Error::InvalidSubject)?
+    .insert(segment, value);
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Url;
+  use serde_json::json;
+
+  use super::*;
+
+  fn claims(value: Value) -> Object {
+    serde_json::from_value(value).unwrap()
+  }
+
+  fn template() -> CredentialTemplate {
+    CredentialTemplate::new("UniversityDegreeCredential")
+      .context(Url::parse("https://www.w3.org/2018/credentials/examples/v1").unwrap())
+      .issuer(Url::parse("did:example:issuer").unwrap())
+      .evidence(Evidence::new("DocumentVerification"))
+      .map_claim("given_name", "/name/given")
+      .map_claim("family_name", "/name/family")
+      .require_claim("/name/family")
+  }
+
+  #[test]
+  fn instantiate_maps_claims() {
+    let credential = template()
+      .instantiate(claims(json!({
+        "id": "did:example:holder",
+        "given_name": "Alice",
+        "family_name": "Smith",
+        "GPA": "4.0",
+      })))
+      .unwrap()
+      .build()
+      .unwrap();
+
+    assert_eq!(
+      credential.types.as_slice(),
+      ["VerifiableCredential", "UniversityDegreeCredential"]
+    );
+    assert_eq!(credential.evidence.len(), 1);
+    let subject = credential.credential_subject.first().unwrap();
+    assert_eq!(subject.id.as_ref().unwrap().as_str(), "did:example:holder");
+    assert_eq!(
+      Value::Object(subject.properties.clone()),
+      json!({ "name": { "given": "Alice", "family": "Smith" }, "GPA": "4.0" })
+    );
+  }
+
+  #[test]
+  fn instantiate_requires_claims() {
+    let err = template()
+      .instantiate(claims(json!({ "given_name": "Alice" })))
+      .unwrap_err();
+    assert!(matches!(err, Error::MissingTemplateClaim(pointer) if pointer == "/name/family"));
+  }
+
+  #[cfg(feature = "revocation-bitmap")]
+  #[test]
+  fn instantiate_with_revocation_bitmap_status() {
+    let template = template().status(StatusTemplate::RevocationBitmap(
+      DIDUrl::parse("did:example:issuer#revocation").unwrap(),
+    ));
+    let input = claims(json!({ "family_name": "Smith" }));
+
+    assert!(matches!(
+      template.instantiate(input.clone()),
+      Err(Error::InvalidStatus(_))
+    ));
+    let credential = template.instantiate_with_index(input, 5).unwrap().build().unwrap();
+    let status = RevocationBitmapStatus::try_from(credential.credential_status.unwrap()).unwrap();
+    assert_eq!(status.index().unwrap(), 5);
+  }
+
+  #[test]
+  fn template_round_trips_through_json() {
+    let template = template();
+    let json = serde_json::to_value(&template).unwrap();
+    assert_eq!(json["claimMapping"]["given_name"], "/name/given");
+    assert_eq!(serde_json::from_value::<CredentialTemplate>(json).unwrap(), template);
+  }
+}
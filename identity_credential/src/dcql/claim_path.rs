@@ -0,0 +1,187 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use serde::de::Error as _;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+use serde_json::Value;
+
+/// An element of a [`ClaimPath`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClaimPathElement {
+  /// Selects the property with the given name of an object, serialized as a string.
+  Key(String),
+  /// Selects the element at the given index of an array, serialized as a non-negative integer.
+  Index(usize),
+  /// Selects all the elements of an array, serialized as `null`.
+  All,
+}
+
+impl Serialize for ClaimPathElement {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    match self {
+      Self::Key(key) => serializer.serialize_str(key),
+      Self::Index(index) => serializer.serialize_u64(*index as u64),
+      Self::All => serializer.serialize_none(),
+    }
+  }
+}
+
+impl<'de> Deserialize<'de> for ClaimPathElement {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    match Value::deserialize(deserializer)? {
+      Value::String(key) => Ok(Self::Key(key)),
+      Value::Null => Ok(Self::All),
+      Value::Number(number) => number
+        .as_u64()
+        .and_then(|index| usize::try_from(index).ok())
+        .map(Self::Index)
+        .ok_or_else(|| D::Error::custom("claim path indices must be non-negative integers")),
+      _ => Err(D::Error::custom(
+        "claim path elements must be strings, non-negative integers or null",
+      )),
+    }
+  }
+}
+
+/// A claims path pointer, selecting one or more claims of a credential.
+///
+/// Paths are evaluated against the JSON representation of a credential: its disclosed claims for SD-JWT VCs, the
+/// credential itself for W3C credentials, and the namespaces of its data elements for mdocs, e.g.
+/// `["org.iso.18013.5.1", "family_name"]`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ClaimPath(Vec<ClaimPathElement>);
+
+impl ClaimPath {
+  /// Creates a new [`ClaimPath`] from its elements.
+  pub fn new(elements: impl IntoIterator<Item = ClaimPathElement>) -> Self {
+    Self(elements.into_iter().collect())
+  }
+
+  /// Returns the elements of this path.
+  pub fn elements(&self) -> &[ClaimPathElement] {
+    &self.0
+  }
+
+  /// Returns whether this path selects exactly one claim, i.e. it does not select all the elements of an array.
+  pub fn is_concrete(&self) -> bool {
+    !self.0.contains(&ClaimPathElement::All)
+  }
+
+  /// Returns the claims of `claims` selected by this path, each with the concrete path leading to it.
+  ///
+  /// No claim is returned if this path is empty or does not select any claim.
+  pub fn resolve<'a>(&self, claims: &'a Value) -> Vec<(ClaimPath, &'a Value)> {
+    if self.0.is_empty() {
+      return Vec::new();
+    }
+
+    let mut selected: Vec<(Vec<ClaimPathElement>, &'a Value)> = vec![(Vec::new(), claims)];
+    for element in &self.0 {
+      selected = selected
+        .into_iter()
+        .flat_map(|(path, value)| {
+          select(element, value).into_iter().map(move |(next, value)| {
+            let mut path = path.clone();
+            path.push(next);
+            (path, value)
+          })
+        })
+        .collect();
+    }
+
+    selected.into_iter().map(|(path, value)| (Self(path), value)).collect()
+  }
+
+  /// Returns this path as a JSON pointer, e.g. `/address/street_address`, or [None] if it is not concrete.
+  pub fn to_json_pointer(&self) -> Option<String> {
+    self.0.iter().try_fold(String::new(), |mut pointer, element| {
+      pointer.push('/');
+      match element {
+        ClaimPathElement::Key(key) => pointer.push_str(&key.replace('~', "~0").replace('/', "~1")),
+        ClaimPathElement::Index(index) => pointer.push_str(&index.to_string()),
+        ClaimPathElement::All => return None,
+      }
+      Some(pointer)
+    })
+  }
+}
+
+impl Display for ClaimPath {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    let json = serde_json::to_string(self).map_err(|_| std::fmt::Error)?;
+    f.write_str(&json)
+  }
+}
+
+impl FromIterator<ClaimPathElement> for ClaimPath {
+  fn from_iter<I: IntoIterator<Item = ClaimPathElement>>(iter: I) -> Self {
+    Self::new(iter)
+  }
+}
+
+/// Returns the values of `value` selected by `element`, each with the concrete element selecting it.
+fn select<'a>(element: &ClaimPathElement, value: &'a Value) -> Vec<(ClaimPathElement, &'a Value)> {
+  match (element, value) {
+    (ClaimPathElement::Key(key), Value::Object(object)) => object
+      .get(key)
+      .map(|value| vec![(element.clone(), value)])
+      .unwrap_or_default(),
+    (ClaimPathElement::Index(index), Value::Array(values)) => values
+      .get(*index)
+      .map(|value| vec![(element.clone(), value)])
+      .unwrap_or_default(),
+    (ClaimPathElement::All, Value::Array(values)) => values
+      .iter()
+      .enumerate()
+      .map(|(index, value)| (ClaimPathElement::Index(index), value))
+      .collect(),
+    _ => Vec::new(),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+
+  #[test]
+  fn resolves_wildcards_to_concrete_paths() {
+    let claims = json!({
+      "nationalities": ["DE", "IT"],
+      "address": { "street/address": "Main St" }
+    });
+
+    let path: ClaimPath = serde_json::from_value(json!(["nationalities", null])).unwrap();
+    assert!(!path.is_concrete());
+    let resolved = path.resolve(&claims);
+    assert_eq!(resolved.len(), 2);
+    assert_eq!(resolved[1].0.to_json_pointer().as_deref(), Some("/nationalities/1"));
+    assert_eq!(resolved[1].1, &json!("IT"));
+
+    let path: ClaimPath = serde_json::from_value(json!(["address", "street/address"])).unwrap();
+    assert_eq!(path.to_json_pointer().as_deref(), Some("/address/street~1address"));
+    assert_eq!(path.resolve(&claims)[0].1, &json!("Main St"));
+  }
+
+  #[test]
+  fn invalid_paths_select_nothing() {
+    let claims = json!({ "name": "Alice" });
+    assert!(ClaimPath::new([]).resolve(&claims).is_empty());
+    assert!(ClaimPath::new([ClaimPathElement::Index(0)]).resolve(&claims).is_empty());
+    assert!(serde_json::from_value::<ClaimPath>(json!(["name", -1])).is_err());
+  }
+}
@@ -0,0 +1,36 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/// Alias for a `Result` with the error type [`DcqlError`].
+pub type DcqlResult<T> = Result<T, DcqlError>;
+
+/// Errors that can occur when parsing a DCQL query or matching it against stored credentials.
+#[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum DcqlError {
+  /// Caused by a query that cannot be deserialized.
+  #[error("failed to deserialize the DCQL query")]
+  Deserialization(#[source] serde_json::Error),
+  /// Caused by a query that is well-formed but violates the constraints of DCQL.
+  #[error("invalid DCQL query: {0}")]
+  InvalidQuery(String),
+  /// Caused by a credential set or claim set referencing a query that is not defined.
+  #[error("undefined query id `{0}`")]
+  UndefinedQueryId(String),
+  /// Caused by a stored credential whose claims cannot be extracted.
+  #[error("invalid stored credential `{id}`")]
+  #[non_exhaustive]
+  InvalidCredential {
+    /// The identifier of the stored credential.
+    id: String,
+    /// The source of the error.
+    source: Box<dyn std::error::Error + Send + Sync + 'static>,
+  },
+}
+
+identity_core::impl_error_code!(DcqlError {
+  Deserialization => 2400,
+  InvalidQuery => 2401,
+  UndefinedQueryId => 2402,
+  InvalidCredential => 2403,
+});
@@ -0,0 +1,353 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use super::ClaimPath;
+use super::ClaimsQuery;
+use super::CredentialQuery;
+use super::CredentialStore;
+use super::DcqlQuery;
+use super::DcqlResult;
+use super::StoredCredential;
+
+/// The outcome of matching a [`DcqlQuery`] against the contents of a [`CredentialStore`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct DcqlMatch {
+  /// The matches of each credential query, in the order of [`DcqlQuery::credentials`].
+  pub credentials: Vec<CredentialQueryMatch>,
+  /// The satisfiable options of each credential set, in the order of [`DcqlQuery::credential_sets`].
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub credential_sets: Option<Vec<CredentialSetMatch>>,
+}
+
+impl DcqlMatch {
+  /// Returns the candidates of the credential query identified by `query_id`.
+  pub fn candidates(&self, query_id: &str) -> &[Candidate] {
+    self
+      .credentials
+      .iter()
+      .find(|query_match| query_match.query_id == query_id)
+      .map(|query_match| query_match.candidates.as_slice())
+      .unwrap_or_default()
+  }
+
+  /// Returns whether the wallet holds credentials satisfying the query, i.e. every credential query has a candidate
+  /// or, if credential sets are defined, every required credential set has a satisfiable option.
+  pub fn is_satisfiable(&self) -> bool {
+    match &self.credential_sets {
+      Some(credential_sets) => credential_sets
+        .iter()
+        .all(|credential_set| !credential_set.required || !credential_set.options.is_empty()),
+      None => self
+        .credentials
+        .iter()
+        .all(|query_match| !query_match.candidates.is_empty()),
+    }
+  }
+}
+
+/// The stored credentials satisfying a [`CredentialQuery`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct CredentialQueryMatch {
+  /// The identifier of the credential query.
+  pub query_id: String,
+  /// Whether multiple candidates may be presented for this query.
+  pub multiple: bool,
+  /// The stored credentials satisfying the query.
+  pub candidates: Vec<Candidate>,
+}
+
+/// A stored credential satisfying a [`CredentialQuery`], with the claims it must disclose to do so.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Candidate {
+  /// The identifier of the stored credential.
+  pub credential_id: String,
+  /// The claim set of the query satisfied by the credential, if the query defines claim sets.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub claim_set: Option<Vec<String>>,
+  /// The concrete paths of the claims to disclose.
+  ///
+  /// For SD-JWT VCs, their [JSON pointers](ClaimPath::to_json_pointer) can be passed to
+  /// `SdJwtVcPresentationBuilder::disclose_only` to conceal everything else.
+  pub disclosures: Vec<ClaimPath>,
+}
+
+/// The satisfiable options of a [`CredentialSetQuery`](super::CredentialSetQuery).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct CredentialSetMatch {
+  /// Whether the credential set must be satisfied.
+  pub required: bool,
+  /// The purpose of the credential set.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub purpose: Option<Value>,
+  /// The options whose credential queries all have a candidate, in order of preference.
+  pub options: Vec<Vec<String>>,
+}
+
+impl DcqlQuery {
+  /// Matches this query against the credentials of `store`, computing the candidates of each credential query and
+  /// the satisfiable options of each credential set.
+  ///
+  /// # Errors
+  /// Fails if this query violates the constraints of DCQL.
+  pub fn match_credentials<S>(&self, store: &S) -> DcqlResult<DcqlMatch>
+  where
+    S: CredentialStore + ?Sized,
+  {
+    self.check_structure()?;
+
+    let credentials: Vec<CredentialQueryMatch> = self
+      .credentials
+      .iter()
+      .map(|query| CredentialQueryMatch {
+        query_id: query.id.clone(),
+        multiple: query.multiple,
+        candidates: store
+          .credentials()
+          .filter_map(|credential| match_credential(query, credential))
+          .collect(),
+      })
+      .collect();
+
+    let has_candidates = |id: &String| {
+      credentials
+        .iter()
+        .any(|query_match| &query_match.query_id == id && !query_match.candidates.is_empty())
+    };
+    let credential_sets = self.credential_sets.as_ref().map(|credential_sets| {
+      credential_sets
+        .iter()
+        .map(|credential_set| CredentialSetMatch {
+          required: credential_set.required,
+          purpose: credential_set.purpose.clone(),
+          options: credential_set
+            .options
+            .iter()
+            .filter(|option| option.iter().all(has_candidates))
+            .cloned()
+            .collect(),
+        })
+        .collect()
+    });
+
+    Ok(DcqlMatch {
+      credentials,
+      credential_sets,
+    })
+  }
+}
+
+fn match_credential(query: &CredentialQuery, credential: &StoredCredential) -> Option<Candidate> {
+  if !matches_format(query, credential)
+    || !matches_meta(query, credential)
+    || (query.require_cryptographic_holder_binding && !credential.holder_binding)
+  {
+    return None;
+  }
+
+  let Some(claims) = query.claims.as_deref() else {
+    return Some(Candidate {
+      credential_id: credential.id.clone(),
+      claim_set: None,
+      disclosures: Vec::new(),
+    });
+  };
+
+  let (claim_set, disclosures) = match &query.claim_sets {
+    None => (None, match_claims(claims.iter(), &credential.claims)?),
+    Some(claim_sets) => claim_sets.iter().find_map(|claim_set| {
+      let selected = claims
+        .iter()
+        .filter(|claim| claim.id.as_ref().map_or(false, |id| claim_set.contains(id)));
+      match_claims(selected, &credential.claims).map(|disclosures| (Some(claim_set.clone()), disclosures))
+    })?,
+  };
+
+  Some(Candidate {
+    credential_id: credential.id.clone(),
+    claim_set,
+    disclosures,
+  })
+}
+
+fn matches_format(query: &CredentialQuery, credential: &StoredCredential) -> bool {
+  let is_sd_jwt_vc =
+    |format: &str| format == CredentialQuery::FORMAT_SD_JWT_VC || format == CredentialQuery::FORMAT_SD_JWT_VC_LEGACY;
+
+  query.format == credential.format || (is_sd_jwt_vc(&query.format) && is_sd_jwt_vc(&credential.format))
+}
+
+fn matches_meta(query: &CredentialQuery, credential: &StoredCredential) -> bool {
+  let Some(meta) = query.meta.as_ref() else {
+    return true;
+  };
+  let has_type = |value: &Value| {
+    value
+      .as_str()
+      .map_or(false, |ty| credential.types.iter().any(|t| t == ty))
+  };
+
+  if let Some(Value::Array(vct_values)) = meta.get("vct_values") {
+    if !vct_values.iter().any(has_type) {
+      return false;
+    }
+  }
+  if let Some(Value::Array(type_values)) = meta.get("type_values") {
+    let matches_any_set = type_values.iter().any(|types| match types {
+      Value::Array(types) => types.iter().all(has_type),
+      _ => false,
+    });
+    if !matches_any_set {
+      return false;
+    }
+  }
+  if let Some(doctype) = meta.get("doctype_value") {
+    if !has_type(doctype) {
+      return false;
+    }
+  }
+
+  true
+}
+
+/// Returns the concrete paths of the claims matching `queries`, or [None] if any query has no matching claim.
+fn match_claims<'a>(queries: impl Iterator<Item = &'a ClaimsQuery>, claims: &Value) -> Option<Vec<ClaimPath>> {
+  let mut disclosures: Vec<ClaimPath> = Vec::new();
+  for query in queries {
+    let matching: Vec<ClaimPath> = query
+      .path
+      .resolve(claims)
+      .into_iter()
+      .filter(|(_, value)| query.values.as_ref().map_or(true, |values| values.contains(value)))
+      .map(|(path, _)| path)
+      .collect();
+    if matching.is_empty() {
+      return None;
+    }
+    for path in matching {
+      if !disclosures.contains(&path) {
+        disclosures.push(path);
+      }
+    }
+  }
+
+  Some(disclosures)
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+
+  fn store() -> Vec<StoredCredential> {
+    vec![
+      StoredCredential::new(
+        "pid",
+        CredentialQuery::FORMAT_SD_JWT_VC,
+        vec!["https://example.com/pid".to_owned()],
+        json!({
+          "given_name": "Alice",
+          "address": { "country": "DE", "locality": "Berlin" },
+          "nationalities": ["DE", "IT"]
+        }),
+        true,
+      ),
+      StoredCredential::mdoc(
+        "mdl",
+        "org.iso.18013.5.1.mDL",
+        json!({ "org.iso.18013.5.1": { "family_name": "Doe", "age_over_18": true } }),
+      ),
+    ]
+  }
+
+  #[test]
+  fn matches_credentials_across_formats() {
+    let query = DcqlQuery::parse(
+      &json!({
+        "credentials": [
+          {
+            "id": "pid",
+            "format": "vc+sd-jwt",
+            "meta": { "vct_values": ["https://example.com/pid"] },
+            "claims": [
+              { "path": ["address", "country"], "values": ["DE", "AT"] },
+              { "path": ["nationalities", null] }
+            ]
+          },
+          {
+            "id": "mdl",
+            "format": "mso_mdoc",
+            "meta": { "doctype_value": "org.iso.18013.5.1.mDL" },
+            "claims": [{ "path": ["org.iso.18013.5.1", "age_over_18"], "values": [true] }]
+          }
+        ]
+      })
+      .to_string(),
+    )
+    .unwrap();
+
+    let result = query.match_credentials(&store()).unwrap();
+    assert!(result.is_satisfiable());
+
+    let pid = &result.candidates("pid")[0];
+    let pointers: Vec<String> = pid.disclosures.iter().filter_map(ClaimPath::to_json_pointer).collect();
+    assert_eq!(pointers, ["/address/country", "/nationalities/0", "/nationalities/1"]);
+    assert_eq!(result.candidates("mdl")[0].credential_id, "mdl");
+  }
+
+  #[test]
+  fn picks_the_first_satisfiable_claim_set() {
+    let query = DcqlQuery::parse(
+      &json!({
+        "credentials": [{
+          "id": "pid",
+          "format": "dc+sd-jwt",
+          "claims": [
+            { "id": "birthdate", "path": ["birthdate"] },
+            { "id": "name", "path": ["given_name"] }
+          ],
+          "claim_sets": [["birthdate", "name"], ["name"]]
+        }]
+      })
+      .to_string(),
+    )
+    .unwrap();
+
+    let result = query.match_credentials(&store()).unwrap();
+    let candidate = &result.candidates("pid")[0];
+    assert_eq!(candidate.claim_set.as_deref(), Some(&["name".to_owned()][..]));
+    assert_eq!(candidate.disclosures.len(), 1);
+  }
+
+  #[test]
+  fn reports_unsatisfiable_credential_sets() {
+    let query = DcqlQuery::parse(
+      &json!({
+        "credentials": [
+          { "id": "pid", "format": "dc+sd-jwt", "claims": [{ "path": ["address", "country"], "values": ["FR"] }] },
+          { "id": "mdl", "format": "mso_mdoc" }
+        ],
+        "credential_sets": [
+          { "options": [["pid"], ["mdl"]] },
+          { "options": [["pid"]] }
+        ]
+      })
+      .to_string(),
+    )
+    .unwrap();
+
+    let result = query.match_credentials(&store()).unwrap();
+    assert!(result.candidates("pid").is_empty());
+    let credential_sets = result.credential_sets.as_ref().unwrap();
+    assert_eq!(credential_sets[0].options, [["mdl".to_owned()]]);
+    assert!(!result.is_satisfiable());
+  }
+}
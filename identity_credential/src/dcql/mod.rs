@@ -0,0 +1,28 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Wallet-side support for the
+//! [Digital Credentials Query Language (DCQL)](https://openid.net/specs/openid-4-verifiable-presentations-1_0.html#name-digital-credentials-query-l)
+//! used by OpenID for Verifiable Presentations.
+//!
+//! A verifier describes the credentials and claims it requests with a [`DcqlQuery`]. The wallet matches the query
+//! against the credentials it holds, exposed through a [`CredentialStore`], with [`DcqlQuery::match_credentials`]:
+//! 1. Each credential query is matched against the stored credentials of the requested format and type, yielding the
+//!    [`Candidate`]s that can satisfy it, together with the claims each of them must disclose.
+//! 2. The credential sets of the query are evaluated, telling which combinations of credential queries can be satisfied
+//!    and whether the query as a whole can be.
+//!
+//! The user then picks among the candidates, and the wallet builds the presentation disclosing the claims listed by
+//! the chosen [`Candidate`]s.
+
+mod claim_path;
+mod error;
+mod matcher;
+mod query;
+mod store;
+
+pub use self::claim_path::*;
+pub use self::error::*;
+pub use self::matcher::*;
+pub use self::query::*;
+pub use self::store::*;
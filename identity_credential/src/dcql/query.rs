@@ -0,0 +1,346 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use identity_core::common::Object;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use super::ClaimPath;
+use super::DcqlError;
+use super::DcqlResult;
+
+/// A Digital Credentials Query Language query, describing the credentials a verifier requests.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct DcqlQuery {
+  /// The requested credentials.
+  pub credentials: Vec<CredentialQuery>,
+  /// The combinations of requested credentials that satisfy the query.
+  ///
+  /// All the requested credentials are required if not set.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub credential_sets: Option<Vec<CredentialSetQuery>>,
+}
+
+impl DcqlQuery {
+  /// Creates a new [`DcqlQuery`] requesting all of `credentials`.
+  pub fn new(credentials: Vec<CredentialQuery>) -> Self {
+    Self {
+      credentials,
+      credential_sets: None,
+    }
+  }
+
+  /// Parses and [validates](DcqlQuery::check_structure) a [`DcqlQuery`] from its JSON representation.
+  ///
+  /// # Errors
+  /// Fails if `json` is not a valid DCQL query.
+  pub fn parse(json: &str) -> DcqlResult<Self> {
+    let query: Self = serde_json::from_str(json).map_err(DcqlError::Deserialization)?;
+    query.check_structure()?;
+
+    Ok(query)
+  }
+
+  /// Returns the credential query identified by `id`.
+  pub fn credential(&self, id: &str) -> Option<&CredentialQuery> {
+    self.credentials.iter().find(|query| query.id == id)
+  }
+
+  /// Checks the constraints DCQL puts on a query besides its structure, e.g. that identifiers are unique and that
+  /// credential and claim sets only reference defined queries.
+  ///
+  /// # Errors
+  /// Fails on the first violated constraint.
+  pub fn check_structure(&self) -> DcqlResult<()> {
+    if self.credentials.is_empty() {
+      return Err(DcqlError::InvalidQuery("`credentials` must not be empty".to_owned()));
+    }
+
+    let mut ids: HashSet<&str> = HashSet::new();
+    for credential in &self.credentials {
+      check_id(&credential.id)?;
+      if !ids.insert(&credential.id) {
+        return Err(DcqlError::InvalidQuery(format!(
+          "duplicate credential query id `{}`",
+          credential.id
+        )));
+      }
+      credential.check_structure()?;
+    }
+
+    for credential_set in self.credential_sets.iter().flatten() {
+      if credential_set.options.is_empty() || credential_set.options.iter().any(Vec::is_empty) {
+        return Err(DcqlError::InvalidQuery(
+          "credential set options must not be empty".to_owned(),
+        ));
+      }
+      if let Some(id) = credential_set
+        .options
+        .iter()
+        .flatten()
+        .find(|id| !ids.contains(id.as_str()))
+      {
+        return Err(DcqlError::UndefinedQueryId(id.clone()));
+      }
+    }
+
+    Ok(())
+  }
+}
+
+/// A request for a single credential within a [`DcqlQuery`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct CredentialQuery {
+  /// The identifier of this query within the [`DcqlQuery`].
+  pub id: String,
+  /// The format of the requested credential, e.g. [`CredentialQuery::FORMAT_SD_JWT_VC`].
+  pub format: String,
+  /// Whether multiple credentials can be returned for this query.
+  #[serde(default)]
+  pub multiple: bool,
+  /// Format specific constraints on the requested credential, e.g. `vct_values` for SD-JWT VCs, `type_values` for W3C
+  /// credentials and `doctype_value` for mdocs.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub meta: Option<Object>,
+  /// The requested claims.
+  ///
+  /// No claim is requested if not set.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub claims: Option<Vec<ClaimsQuery>>,
+  /// The combinations of requested claims that satisfy this query, in order of preference.
+  ///
+  /// All the requested claims are required if not set.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub claim_sets: Option<Vec<Vec<String>>>,
+  /// Whether the holder must prove possession of a key bound to the credential.
+  #[serde(default = "default_true")]
+  pub require_cryptographic_holder_binding: bool,
+}
+
+impl CredentialQuery {
+  /// The format identifier of SD-JWT VCs.
+  pub const FORMAT_SD_JWT_VC: &'static str = "dc+sd-jwt";
+  /// The format identifier of SD-JWT VCs used by earlier drafts of OpenID4VP.
+  pub const FORMAT_SD_JWT_VC_LEGACY: &'static str = "vc+sd-jwt";
+  /// The format identifier of W3C Verifiable Credentials secured as JWTs.
+  pub const FORMAT_JWT_VC_JSON: &'static str = "jwt_vc_json";
+  /// The format identifier of ISO mdocs.
+  pub const FORMAT_MSO_MDOC: &'static str = "mso_mdoc";
+
+  /// Creates a new [`CredentialQuery`] identified by `id` requesting a credential of format `format`.
+  pub fn new(id: impl Into<String>, format: impl Into<String>) -> Self {
+    Self {
+      id: id.into(),
+      format: format.into(),
+      multiple: false,
+      meta: None,
+      claims: None,
+      claim_sets: None,
+      require_cryptographic_holder_binding: true,
+    }
+  }
+
+  /// Adds `claim` to the requested claims.
+  pub fn claim(mut self, claim: ClaimsQuery) -> Self {
+    self.claims.get_or_insert_with(Vec::new).push(claim);
+    self
+  }
+
+  /// Sets the format specific property `name` of [`CredentialQuery::meta`].
+  pub fn meta(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
+    self
+      .meta
+      .get_or_insert_with(Object::new)
+      .insert(name.into(), value.into());
+    self
+  }
+
+  /// Returns the identifiers of the requested claims that may be used in [`CredentialQuery::claim_sets`].
+  fn claim_ids(&self) -> impl Iterator<Item = &str> {
+    self.claims.iter().flatten().filter_map(|claim| claim.id.as_deref())
+  }
+
+  fn check_structure(&self) -> DcqlResult<()> {
+    let invalid = |reason: &str| DcqlError::InvalidQuery(format!("credential query `{}`: {reason}", self.id));
+
+    if self.format.is_empty() {
+      return Err(invalid("`format` must not be empty"));
+    }
+    if self.claims.as_ref().map_or(false, Vec::is_empty) {
+      return Err(invalid("`claims` must not be empty"));
+    }
+
+    let mut claim_ids: HashSet<&str> = HashSet::new();
+    for claim in self.claims.iter().flatten() {
+      if claim.path.elements().is_empty() {
+        return Err(invalid("claim paths must not be empty"));
+      }
+      if let Some(id) = claim.id.as_deref() {
+        check_id(id)?;
+        if !claim_ids.insert(id) {
+          return Err(invalid(&format!("duplicate claim query id `{id}`")));
+        }
+      }
+    }
+
+    if let Some(claim_sets) = &self.claim_sets {
+      if self.claims.is_none() || self.claims.iter().flatten().any(|claim| claim.id.is_none()) {
+        return Err(invalid("`claim_sets` requires all claim queries to have an id"));
+      }
+      if claim_sets.is_empty() || claim_sets.iter().any(Vec::is_empty) {
+        return Err(invalid("`claim_sets` and its options must not be empty"));
+      }
+      let defined: HashSet<&str> = self.claim_ids().collect();
+      if let Some(id) = claim_sets.iter().flatten().find(|id| !defined.contains(id.as_str())) {
+        return Err(DcqlError::UndefinedQueryId(id.clone()));
+      }
+    }
+
+    Ok(())
+  }
+}
+
+/// A request for a claim of a credential within a [`CredentialQuery`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ClaimsQuery {
+  /// The identifier of this query within its [`CredentialQuery`], required when it uses
+  /// [`CredentialQuery::claim_sets`].
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub id: Option<String>,
+  /// The path of the requested claim.
+  pub path: ClaimPath,
+  /// The values the claim must have one of.
+  ///
+  /// Any value is accepted if not set.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub values: Option<Vec<Value>>,
+  /// Whether the verifier intends to retain the claim, for mdocs.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub intent_to_retain: Option<bool>,
+}
+
+impl ClaimsQuery {
+  /// Creates a new [`ClaimsQuery`] requesting the claim at `path`.
+  pub fn new(path: ClaimPath) -> Self {
+    Self {
+      id: None,
+      path,
+      values: None,
+      intent_to_retain: None,
+    }
+  }
+
+  /// Sets the identifier of this query.
+  pub fn id(mut self, id: impl Into<String>) -> Self {
+    self.id = Some(id.into());
+    self
+  }
+
+  /// Requires the claim to have one of `values`.
+  pub fn values(mut self, values: impl IntoIterator<Item = Value>) -> Self {
+    self.values = Some(values.into_iter().collect());
+    self
+  }
+}
+
+/// A combination of credential queries within a [`DcqlQuery`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct CredentialSetQuery {
+  /// The sets of credential query identifiers satisfying this combination, in order of preference.
+  pub options: Vec<Vec<String>>,
+  /// Whether this combination must be satisfied.
+  #[serde(default = "default_true")]
+  pub required: bool,
+  /// The purpose of the request, to be shown to the user.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub purpose: Option<Value>,
+}
+
+impl CredentialSetQuery {
+  /// Creates a new required [`CredentialSetQuery`] satisfied by any of `options`.
+  pub fn new(options: Vec<Vec<String>>) -> Self {
+    Self {
+      options,
+      required: true,
+      purpose: None,
+    }
+  }
+}
+
+fn default_true() -> bool {
+  true
+}
+
+/// Identifiers must be non-empty and made of alphanumeric, underscore or hyphen characters.
+fn check_id(id: &str) -> DcqlResult<()> {
+  if id.is_empty() || !id.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+    return Err(DcqlError::InvalidQuery(format!("invalid id `{id}`")));
+  }
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+
+  #[test]
+  fn parses_queries() {
+    let query = DcqlQuery::parse(
+      &json!({
+        "credentials": [{
+          "id": "pid",
+          "format": "dc+sd-jwt",
+          "meta": { "vct_values": ["https://example.com/pid"] },
+          "claims": [
+            { "id": "a", "path": ["given_name"] },
+            { "id": "b", "path": ["address", "country"], "values": ["DE"] }
+          ],
+          "claim_sets": [["a", "b"], ["a"]]
+        }],
+        "credential_sets": [{ "options": [["pid"]], "purpose": "Identification" }]
+      })
+      .to_string(),
+    )
+    .unwrap();
+
+    let pid = query.credential("pid").unwrap();
+    assert!(pid.require_cryptographic_holder_binding);
+    assert!(query.credential_sets.as_ref().unwrap()[0].required);
+  }
+
+  #[test]
+  fn rejects_undefined_ids() {
+    let query = DcqlQuery {
+      credentials: vec![CredentialQuery::new("pid", CredentialQuery::FORMAT_SD_JWT_VC)],
+      credential_sets: Some(vec![CredentialSetQuery::new(vec![vec!["mdl".to_owned()]])]),
+    };
+    assert!(matches!(query.check_structure(), Err(DcqlError::UndefinedQueryId(id)) if id == "mdl"));
+
+    let mut credential = CredentialQuery::new("pid", CredentialQuery::FORMAT_SD_JWT_VC)
+      .claim(ClaimsQuery::new(serde_json::from_value(json!(["given_name"])).unwrap()).id("a"));
+    credential.claim_sets = Some(vec![vec!["b".to_owned()]]);
+    assert!(matches!(
+      DcqlQuery::new(vec![credential]).check_structure(),
+      Err(DcqlError::UndefinedQueryId(id)) if id == "b"
+    ));
+  }
+
+  #[test]
+  fn rejects_duplicate_ids() {
+    let query = DcqlQuery::new(vec![
+      CredentialQuery::new("pid", CredentialQuery::FORMAT_SD_JWT_VC),
+      CredentialQuery::new("pid", CredentialQuery::FORMAT_MSO_MDOC),
+    ]);
+    assert!(matches!(query.check_structure(), Err(DcqlError::InvalidQuery(_))));
+  }
+}
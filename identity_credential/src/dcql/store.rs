@@ -0,0 +1,133 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::credential::Credential;
+#[cfg(feature = "sd-jwt-vc")]
+use crate::sd_jwt_vc::SdJwtVc;
+
+use super::CredentialQuery;
+use super::DcqlError;
+use super::DcqlResult;
+
+/// A credential held by a wallet, in the format-agnostic shape DCQL queries are matched against.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct StoredCredential {
+  /// The identifier of the credential within its [`CredentialStore`].
+  pub id: String,
+  /// The format of the credential, e.g. [`CredentialQuery::FORMAT_SD_JWT_VC`].
+  pub format: String,
+  /// The types of the credential: its `vct` for SD-JWT VCs, its `type`s for W3C credentials and its doctype for
+  /// mdocs.
+  pub types: Vec<String>,
+  /// The claims of the credential that [`ClaimPath`](super::ClaimPath)s are resolved against.
+  pub claims: Value,
+  /// Whether the credential is bound to a key of its holder.
+  pub holder_binding: bool,
+}
+
+impl StoredCredential {
+  /// Creates a new [`StoredCredential`].
+  pub fn new(
+    id: impl Into<String>,
+    format: impl Into<String>,
+    types: Vec<String>,
+    claims: Value,
+    holder_binding: bool,
+  ) -> Self {
+    Self {
+      id: id.into(),
+      format: format.into(),
+      types,
+      claims,
+      holder_binding,
+    }
+  }
+
+  /// Creates a new [`StoredCredential`] from a W3C credential held as a JWT.
+  ///
+  /// The credential is considered bound to its holder if any of its subjects has an `id`.
+  ///
+  /// # Errors
+  /// Fails if `credential` cannot be serialized.
+  pub fn from_credential<T>(id: impl Into<String>, credential: &Credential<T>) -> DcqlResult<Self>
+  where
+    T: Serialize,
+  {
+    let id = id.into();
+    let claims = serde_json::to_value(credential).map_err(|err| DcqlError::InvalidCredential {
+      id: id.clone(),
+      source: err.into(),
+    })?;
+    let holder_binding = credential.credential_subject.iter().any(|subject| subject.id.is_some());
+
+    Ok(Self::new(
+      id,
+      CredentialQuery::FORMAT_JWT_VC_JSON,
+      credential.types.iter().cloned().collect(),
+      claims,
+      holder_binding,
+    ))
+  }
+
+  /// Creates a new [`StoredCredential`] from an SD-JWT VC, whose claims are all its disclosable claims.
+  ///
+  /// # Errors
+  /// Fails if the disclosures of `sd_jwt_vc` cannot be resolved using `hasher`.
+  #[cfg(feature = "sd-jwt-vc")]
+  pub fn from_sd_jwt_vc(id: impl Into<String>, sd_jwt_vc: &SdJwtVc, hasher: &dyn sd_jwt::Hasher) -> DcqlResult<Self> {
+    let id = id.into();
+    let vct = sd_jwt_vc.claims().vct.clone();
+    let holder_binding = sd_jwt_vc.required_key_bind().is_some();
+    let claims = sd_jwt_vc
+      .clone()
+      .into_disclosed_object(hasher)
+      .map_err(|err| DcqlError::InvalidCredential {
+        id: id.clone(),
+        source: err.into(),
+      })?;
+
+    Ok(Self::new(
+      id,
+      CredentialQuery::FORMAT_SD_JWT_VC,
+      vec![vct],
+      Value::Object(claims),
+      holder_binding,
+    ))
+  }
+
+  /// Creates a new [`StoredCredential`] for an mdoc of type `doctype`, whose data elements are given as an object
+  /// mapping each namespace to its elements, e.g. `{ "org.iso.18013.5.1": { "family_name": "Doe" } }`.
+  ///
+  /// mdocs are always bound to a device key of their holder.
+  pub fn mdoc(id: impl Into<String>, doctype: impl Into<String>, namespaces: Value) -> Self {
+    Self::new(
+      id,
+      CredentialQuery::FORMAT_MSO_MDOC,
+      vec![doctype.into()],
+      namespaces,
+      true,
+    )
+  }
+}
+
+/// A source of the credentials a wallet can present.
+pub trait CredentialStore {
+  /// Returns an iterator over the stored credentials.
+  fn credentials(&self) -> Box<dyn Iterator<Item = &StoredCredential> + '_>;
+}
+
+impl CredentialStore for [StoredCredential] {
+  fn credentials(&self) -> Box<dyn Iterator<Item = &StoredCredential> + '_> {
+    Box::new(self.iter())
+  }
+}
+
+impl CredentialStore for Vec<StoredCredential> {
+  fn credentials(&self) -> Box<dyn Iterator<Item = &StoredCredential> + '_> {
+    Box::new(self.iter())
+  }
+}
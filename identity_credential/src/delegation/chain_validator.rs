@@ -0,0 +1,329 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use identity_core::common::Object;
+use identity_core::common::Value;
+use identity_did::CoreDID;
+use identity_did::DIDUrl;
+use identity_document::document::CoreDocument;
+use identity_verification::jwk::Jwk;
+use identity_verification::jws::JwsVerifier;
+
+use super::DelegationCredentialBuilder;
+use super::DelegationError;
+use super::DelegationEvidence;
+use super::DelegationResult;
+use crate::credential::Credential;
+use crate::credential::Jwt;
+use crate::validator::CompoundCredentialValidationError;
+use crate::validator::DecodedJwtCredential;
+use crate::validator::FailFast;
+use crate::validator::JwtCredentialValidationOptions;
+use crate::validator::JwtCredentialValidator;
+use crate::validator::JwtCredentialValidatorUtils;
+use crate::validator::JwtValidationError;
+
+/// The default maximum number of delegations in a chain.
+const DEFAULT_MAX_CHAIN_LENGTH: usize = 5;
+/// The maximum number of delegation credentials kept in the cache of a [`ChainValidator`].
+const MAX_CACHE_SIZE: usize = 256;
+
+/// A credential whose issuer's authority has been established through a chain of delegations.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct ValidatedDelegationChain {
+  /// The validated end credential.
+  pub credential: DecodedJwtCredential,
+  /// The validated delegation credentials, ordered from the one issued by the root.
+  pub delegations: Vec<DecodedJwtCredential>,
+  /// The trusted root of the chain.
+  pub root: CoreDID,
+}
+
+/// A delegation credential whose signature has already been verified.
+struct CachedDelegation {
+  decoded: DecodedJwtCredential,
+  method_id: DIDUrl,
+  public_key: Jwk,
+}
+
+/// A validator for credentials issued under delegated authority.
+///
+/// The chain is read from the [`DelegationEvidence`] of the end credential. Starting from a trusted root, every
+/// delegation credential must have been issued to the issuer of the next credential in the chain, and must allow the
+/// types of the end credential. The delegation credentials are validated with the same options as the end credential.
+///
+/// As the same delegations are typically shared by many end credentials, their decoded form is cached. A cached
+/// delegation is only reused if the key that signed it is still part of its issuer's DID Document; its dates and
+/// status are checked every time.
+pub struct ChainValidator<V: JwsVerifier> {
+  validator: JwtCredentialValidator<V>,
+  max_chain_length: usize,
+  cache: Mutex<HashMap<String, CachedDelegation>>,
+}
+
+impl<V: JwsVerifier> ChainValidator<V> {
+  /// Creates a new [`ChainValidator`] that delegates cryptographic signature verification to the given
+  /// `signature_verifier`.
+  pub fn with_signature_verifier(signature_verifier: V) -> Self {
+    Self {
+      validator: JwtCredentialValidator::with_signature_verifier(signature_verifier),
+      max_chain_length: DEFAULT_MAX_CHAIN_LENGTH,
+      cache: Mutex::new(HashMap::new()),
+    }
+  }
+
+  /// Sets the maximum number of delegations a chain may contain. Defaults to 5.
+  #[must_use]
+  pub fn max_chain_length(mut self, value: usize) -> Self {
+    self.max_chain_length = value;
+    self
+  }
+
+  /// Removes all delegation credentials from the cache.
+  pub fn clear_cache(&self) {
+    self.cache.lock().expect("cache lock poisoned").clear();
+  }
+
+  /// Decodes and validates `credential_jwt` and the chain of delegations it references.
+  ///
+  /// `trusted_roots` are the DIDs that may delegate their authority, while `issuers` must contain the up-to-date DID
+  /// Documents of every issuer in the chain. A credential without delegation evidence is only accepted if it was
+  /// issued by a trusted root.
+  ///
+  /// # Errors
+  /// An error is returned if any of the credentials fails validation according to `options`, or if the chain does not
+  /// establish the authority of the end credential's issuer.
+  pub fn validate<DOC>(
+    &self,
+    credential_jwt: &Jwt,
+    trusted_roots: &[CoreDID],
+    issuers: &[DOC],
+    options: &JwtCredentialValidationOptions,
+    fail_fast: FailFast,
+  ) -> DelegationResult<ValidatedDelegationChain>
+  where
+    DOC: AsRef<CoreDocument>,
+  {
+    let issuer_did: CoreDID = JwtCredentialValidatorUtils::extract_issuer_from_jwt(credential_jwt).map_err(|err| {
+      DelegationError::InvalidCredential(CompoundCredentialValidationError {
+        validation_errors: vec![err],
+      })
+    })?;
+    let credential: DecodedJwtCredential = self
+      .validator
+      .validate(credential_jwt, find_issuer(issuers, &issuer_did)?, options, fail_fast)
+      .map_err(DelegationError::InvalidCredential)?;
+
+    let evidence: DelegationEvidence = DelegationEvidence::from_credential(&credential.credential)?.unwrap_or_default();
+    let length: usize = evidence.delegations().len();
+    if length > self.max_chain_length {
+      return Err(DelegationError::ChainTooLong {
+        length,
+        max: self.max_chain_length,
+      });
+    }
+
+    let mut delegations: Vec<DecodedJwtCredential> = Vec::with_capacity(length);
+    let mut expected_issuer: Option<CoreDID> = None;
+    for (index, delegation_jwt) in evidence.delegations().iter().enumerate() {
+      let delegation: DecodedJwtCredential =
+        self.validate_delegation(index, delegation_jwt, issuers, options, fail_fast)?;
+      let delegator: CoreDID = extract_issuer(&delegation.credential, index)?;
+
+      match &expected_issuer {
+        None if !trusted_roots.contains(&delegator) => return Err(DelegationError::UntrustedRoot(delegator)),
+        Some(expected) if expected != &delegator => return Err(DelegationError::BrokenChain(index)),
+        _ => (),
+      }
+      check_scope(&delegation.credential, &credential.credential, index)?;
+
+      expected_issuer = Some(delegate(&delegation.credential, index)?);
+      delegations.push(delegation);
+    }
+
+    let root: CoreDID = match expected_issuer {
+      None if trusted_roots.contains(&issuer_did) => issuer_did,
+      None => return Err(DelegationError::UntrustedRoot(issuer_did)),
+      Some(expected) if expected != issuer_did => return Err(DelegationError::BrokenChain(length)),
+      Some(_) => extract_issuer(&delegations[0].credential, 0)?,
+    };
+
+    Ok(ValidatedDelegationChain {
+      credential,
+      delegations,
+      root,
+    })
+  }
+
+  fn validate_delegation<DOC>(
+    &self,
+    index: usize,
+    delegation_jwt: &Jwt,
+    issuers: &[DOC],
+    options: &JwtCredentialValidationOptions,
+    fail_fast: FailFast,
+  ) -> DelegationResult<DecodedJwtCredential>
+  where
+    DOC: AsRef<CoreDocument>,
+  {
+    let invalid = |err: JwtValidationError| DelegationError::InvalidDelegation {
+      index,
+      source: CompoundCredentialValidationError {
+        validation_errors: vec![err],
+      },
+    };
+
+    let issuer_did: CoreDID = JwtCredentialValidatorUtils::extract_issuer_from_jwt(delegation_jwt).map_err(invalid)?;
+    let issuer: &CoreDocument = find_issuer(issuers, &issuer_did)?.as_ref();
+
+    let cached: Option<DecodedJwtCredential> = {
+      let cache = self.cache.lock().expect("cache lock poisoned");
+      cache
+        .get(delegation_jwt.as_str())
+        .filter(|cached| {
+          issuer
+            .resolve_method(&cached.method_id, options.verification_options.method_scope)
+            .and_then(|method| method.data().public_key_jwk())
+            .is_some_and(|public_key| public_key == &cached.public_key)
+        })
+        .map(|cached| cached.decoded.clone())
+    };
+
+    let decoded: DecodedJwtCredential = match cached {
+      Some(decoded) => decoded,
+      None => {
+        options.limits.check_jwt(delegation_jwt.as_str()).map_err(invalid)?;
+        let decoded: DecodedJwtCredential = self
+          .validator
+          .verify_signature(
+            delegation_jwt,
+            std::slice::from_ref(issuer),
+            &options.verification_options,
+          )
+          .map_err(invalid)?;
+        self.cache_delegation(delegation_jwt, issuer, &decoded, options);
+        decoded
+      }
+    };
+
+    JwtCredentialValidator::<V>::validate_decoded_credential::<CoreDocument, Object>(
+      &decoded.credential,
+      std::slice::from_ref(issuer),
+      options,
+      fail_fast,
+    )
+    .map_err(|source| DelegationError::InvalidDelegation { index, source })?;
+
+    if !decoded
+      .credential
+      .types
+      .iter()
+      .any(|type_| type_ == DelegationCredentialBuilder::CREDENTIAL_TYPE)
+    {
+      return Err(DelegationError::NotADelegationCredential(index));
+    }
+
+    Ok(decoded)
+  }
+
+  fn cache_delegation(
+    &self,
+    delegation_jwt: &Jwt,
+    issuer: &CoreDocument,
+    decoded: &DecodedJwtCredential,
+    options: &JwtCredentialValidationOptions,
+  ) {
+    let method_id: Option<DIDUrl> = options
+      .verification_options
+      .method_id
+      .clone()
+      .or_else(|| decoded.header.kid().and_then(|kid| DIDUrl::parse(kid).ok()));
+    let public_key: Option<Jwk> = method_id.as_ref().and_then(|method_id| {
+      issuer
+        .resolve_method(method_id, options.verification_options.method_scope)
+        .and_then(|method| method.data().public_key_jwk())
+        .cloned()
+    });
+
+    if let (Some(method_id), Some(public_key)) = (method_id, public_key) {
+      let mut cache = self.cache.lock().expect("cache lock poisoned");
+      if cache.len() >= MAX_CACHE_SIZE {
+        cache.clear();
+      }
+      cache.insert(
+        delegation_jwt.as_str().to_owned(),
+        CachedDelegation {
+          decoded: decoded.clone(),
+          method_id,
+          public_key,
+        },
+      );
+    }
+  }
+}
+
+fn find_issuer<'i, DOC>(issuers: &'i [DOC], did: &CoreDID) -> DelegationResult<&'i DOC>
+where
+  DOC: AsRef<CoreDocument>,
+{
+  issuers
+    .iter()
+    .find(|issuer| issuer.as_ref().id() == did)
+    .ok_or_else(|| DelegationError::MissingIssuerDocument(did.clone()))
+}
+
+fn extract_issuer(delegation: &Credential<Object>, index: usize) -> DelegationResult<CoreDID> {
+  JwtCredentialValidatorUtils::extract_issuer::<CoreDID, Object>(delegation).map_err(|err| {
+    DelegationError::InvalidDelegation {
+      index,
+      source: CompoundCredentialValidationError {
+        validation_errors: vec![err],
+      },
+    }
+  })
+}
+
+/// Returns the DID to which `delegation` delegates authority.
+fn delegate(delegation: &Credential<Object>, index: usize) -> DelegationResult<CoreDID> {
+  match delegation.credential_subject.as_slice() {
+    [subject] => subject
+      .id
+      .as_ref()
+      .and_then(|id| CoreDID::parse(id.as_str()).ok())
+      .ok_or(DelegationError::NotADelegationCredential(index)),
+    _ => Err(DelegationError::NotADelegationCredential(index)),
+  }
+}
+
+/// Checks that `delegation` allows issuing credentials of the types of `credential`.
+fn check_scope(delegation: &Credential<Object>, credential: &Credential<Object>, index: usize) -> DelegationResult<()> {
+  let Some(allowed_types) = delegation.credential_subject.first().and_then(|subject| {
+    subject
+      .properties
+      .get(DelegationCredentialBuilder::CREDENTIAL_TYPES_PROPERTY)
+  }) else {
+    return Ok(());
+  };
+  let allowed_types: &[Value] = allowed_types
+    .as_array()
+    .ok_or(DelegationError::NotADelegationCredential(index))?;
+
+  let in_scope: bool = credential
+    .types
+    .iter()
+    .filter(|type_| type_.as_str() != Credential::<Object>::base_type())
+    .all(|type_| {
+      allowed_types
+        .iter()
+        .any(|allowed| allowed.as_str() == Some(type_.as_str()))
+    });
+  if in_scope {
+    Ok(())
+  } else {
+    Err(DelegationError::OutOfScope(index))
+  }
+}
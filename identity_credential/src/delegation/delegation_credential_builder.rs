@@ -0,0 +1,155 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::OneOrMany;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_did::CoreDID;
+use identity_did::DID;
+
+use crate::credential::Credential;
+use crate::credential::Issuer;
+use crate::credential::Subject;
+use crate::error::Result;
+use crate::Error;
+
+/// Convenient builder to create a delegation credential, through which `issuer` allows `delegate` to issue
+/// credentials on its behalf.
+///
+/// The builder expects `issuer` and `delegate` to be set.
+/// Setting `issuanceDate` is optional. If unset the current time will be used.
+/// If no credential types are set, the delegate is allowed to issue credentials of any type.
+#[derive(Debug, Default)]
+pub struct DelegationCredentialBuilder {
+  pub(crate) issuer: Option<Url>,
+  pub(crate) delegate: Option<Url>,
+  pub(crate) issuance_date: Option<Timestamp>,
+  pub(crate) expiration_date: Option<Timestamp>,
+  pub(crate) credential_types: Vec<String>,
+}
+
+impl DelegationCredentialBuilder {
+  /// The type of delegation credentials.
+  pub const CREDENTIAL_TYPE: &'static str = "DelegationCredential";
+  /// The `credentialSubject` property listing the types of credentials the delegate may issue.
+  pub const CREDENTIAL_TYPES_PROPERTY: &'static str = "credentialTypes";
+
+  /// Creates a new `DelegationCredentialBuilder`.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Sets the value of the `issuer`, i.e. the delegating party.
+  #[must_use]
+  pub fn issuer(mut self, did: CoreDID) -> Self {
+    self.issuer = Some(did.into_url().into());
+    self
+  }
+
+  /// Sets the delegate, which will be set as `credentialSubject.id`.
+  #[must_use]
+  pub fn delegate(mut self, did: CoreDID) -> Self {
+    self.delegate = Some(did.into_url().into());
+    self
+  }
+
+  /// Sets the value of the `Credential` `issuanceDate`.
+  #[must_use]
+  pub fn issuance_date(mut self, value: Timestamp) -> Self {
+    self.issuance_date = Some(value);
+    self
+  }
+
+  /// Sets the value of the `Credential` `expirationDate`.
+  #[must_use]
+  pub fn expiration_date(mut self, value: Timestamp) -> Self {
+    self.expiration_date = Some(value);
+    self
+  }
+
+  /// Allows the delegate to issue credentials of type `value`.
+  #[must_use]
+  pub fn credential_type(mut self, value: impl Into<String>) -> Self {
+    self.credential_types.push(value.into());
+    self
+  }
+
+  /// Returns a new `Credential` based on the `DelegationCredentialBuilder` configuration.
+  pub fn build(self) -> Result<Credential<Object>> {
+    let issuer: Url = self.issuer.ok_or(Error::MissingIssuer)?;
+    let delegate: Url = self.delegate.ok_or(Error::MissingSubject)?;
+
+    let mut properties: Object = Object::new();
+    if !self.credential_types.is_empty() {
+      properties.insert(Self::CREDENTIAL_TYPES_PROPERTY.into(), self.credential_types.into());
+    }
+
+    Ok(Credential {
+      context: OneOrMany::One(Credential::<Object>::base_context().clone()),
+      id: None,
+      types: OneOrMany::Many(vec![
+        Credential::<Object>::base_type().to_owned(),
+        Self::CREDENTIAL_TYPE.to_owned(),
+      ]),
+      credential_subject: OneOrMany::One(Subject::with_id_and_properties(delegate, properties)),
+      issuer: Issuer::Url(issuer),
+      issuance_date: self.issuance_date.unwrap_or_else(Timestamp::now_utc),
+      expiration_date: self.expiration_date,
+      credential_status: None,
+      credential_schema: Vec::new().into(),
+      refresh_service: Vec::new().into(),
+      terms_of_use: Vec::new().into(),
+      evidence: Vec::new().into(),
+      non_transferable: None,
+      properties: Object::new(),
+      proof: None,
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Value;
+  use identity_did::CoreDID;
+
+  use super::DelegationCredentialBuilder;
+  use crate::Error;
+
+  #[test]
+  fn test_builder_sets_delegate_and_types() {
+    let root: CoreDID = "did:example:root".parse().unwrap();
+    let delegate: CoreDID = "did:example:intermediate".parse().unwrap();
+    let credential = DelegationCredentialBuilder::new()
+      .issuer(root.clone())
+      .delegate(delegate.clone())
+      .credential_type("MandateCredential")
+      .build()
+      .unwrap();
+
+    assert_eq!(credential.issuer.url().as_str(), root.as_str());
+    assert!(credential
+      .types
+      .iter()
+      .any(|type_| type_ == DelegationCredentialBuilder::CREDENTIAL_TYPE));
+    let subject = credential.credential_subject.first().unwrap();
+    assert_eq!(subject.id.as_ref().unwrap().as_str(), delegate.as_str());
+    assert_eq!(
+      subject.properties[DelegationCredentialBuilder::CREDENTIAL_TYPES_PROPERTY],
+      Value::from(vec!["MandateCredential"])
+    );
+  }
+
+  #[test]
+  fn test_builder_requires_issuer_and_delegate() {
+    let did: CoreDID = "did:example:root".parse().unwrap();
+    assert!(matches!(
+      DelegationCredentialBuilder::new().delegate(did.clone()).build(),
+      Err(Error::MissingIssuer)
+    ));
+    assert!(matches!(
+      DelegationCredentialBuilder::new().issuer(did).build(),
+      Err(Error::MissingSubject)
+    ));
+  }
+}
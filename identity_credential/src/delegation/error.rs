@@ -0,0 +1,65 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_did::CoreDID;
+
+use crate::validator::CompoundCredentialValidationError;
+
+/// Alias for a `Result` with the error type [`DelegationError`].
+pub type DelegationResult<T> = Result<T, DelegationError>;
+
+/// Errors that can occur when validating a credential chain.
+#[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum DelegationError {
+  /// Caused by an end credential that failed validation.
+  #[error("invalid credential")]
+  InvalidCredential(#[source] CompoundCredentialValidationError),
+  /// Caused by a delegation credential that failed validation.
+  #[error("invalid delegation credential at position {index}")]
+  InvalidDelegation {
+    /// The position of the delegation credential in the chain, starting from the root.
+    index: usize,
+    /// The validation failure.
+    #[source]
+    source: CompoundCredentialValidationError,
+  },
+  /// Caused by a credential in the chain whose issuer's DID Document was not provided.
+  #[error("missing DID Document of issuer `{0}`")]
+  MissingIssuerDocument(CoreDID),
+  /// Caused by a malformed delegation evidence.
+  #[error("invalid delegation evidence: {0}")]
+  InvalidEvidence(String),
+  /// Caused by a credential of the chain that is not a delegation credential.
+  #[error("the credential at position {0} is not a delegation credential")]
+  NotADelegationCredential(usize),
+  /// Caused by a chain whose root is not trusted.
+  #[error("the root issuer `{0}` is not trusted")]
+  UntrustedRoot(CoreDID),
+  /// Caused by a credential of the chain that was not issued by the subject of the previous delegation.
+  #[error("the credential at position {0} was not issued by the delegate of the previous credential")]
+  BrokenChain(usize),
+  /// Caused by a delegation that does not allow issuing credentials of the end credential's types.
+  #[error("the delegation at position {0} does not cover the types of the credential")]
+  OutOfScope(usize),
+  /// Caused by a chain that exceeds the maximum allowed length.
+  #[error("the chain has {length} delegations, but at most {max} are allowed")]
+  ChainTooLong {
+    /// The number of delegations in the chain.
+    length: usize,
+    /// The maximum number of delegations allowed.
+    max: usize,
+  },
+}
+
+identity_core::impl_error_code!(DelegationError {
+  InvalidCredential => 2300,
+  InvalidDelegation => 2301,
+  MissingIssuerDocument => 2302,
+  InvalidEvidence => 2303,
+  NotADelegationCredential => 2304,
+  UntrustedRoot => 2305,
+  BrokenChain => 2306,
+  OutOfScope => 2307,
+  ChainTooLong => 2308,
+});
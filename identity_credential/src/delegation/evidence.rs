@@ -0,0 +1,120 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::Value;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::DelegationError;
+use crate::credential::Credential;
+use crate::credential::Jwt;
+
+/// The delegation credentials backing the authority of a credential's issuer, ordered from the one issued by the root
+/// to the one issued to the credential's issuer.
+///
+/// The evidence is carried in the credential's [`DelegationEvidence::PROPERTY`] property.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct DelegationEvidence(Vec<Jwt>);
+
+impl DelegationEvidence {
+  /// The credential property carrying the delegation evidence.
+  pub const PROPERTY: &'static str = "delegationEvidence";
+
+  /// Creates a new [`DelegationEvidence`] from the given delegation credentials, ordered from the root.
+  pub fn new(delegations: Vec<Jwt>) -> Self {
+    Self(delegations)
+  }
+
+  /// Returns the delegation credentials, ordered from the root.
+  pub fn delegations(&self) -> &[Jwt] {
+    &self.0
+  }
+
+  /// Appends a delegation credential at the end of the chain.
+  pub fn push(&mut self, delegation: Jwt) {
+    self.0.push(delegation);
+  }
+
+  /// Consumes this evidence, returning the delegation credentials.
+  pub fn into_inner(self) -> Vec<Jwt> {
+    self.0
+  }
+
+  /// Reads the delegation evidence of `credential`, if any.
+  pub fn from_credential(credential: &Credential<Object>) -> Result<Option<Self>, DelegationError> {
+    credential
+      .properties
+      .get(Self::PROPERTY)
+      .map(|value| Self::deserialize(value).map_err(|err| DelegationError::InvalidEvidence(err.to_string())))
+      .transpose()
+  }
+
+  /// Sets this evidence as the delegation evidence of `credential`, replacing any existing one.
+  pub fn attach(self, credential: &mut Credential<Object>) {
+    let value: Value = Value::Array(
+      self
+        .0
+        .into_iter()
+        .map(|jwt| Value::String(jwt.as_str().to_owned()))
+        .collect(),
+    );
+    credential.properties.insert(Self::PROPERTY.to_owned(), value);
+  }
+}
+
+impl From<Vec<Jwt>> for DelegationEvidence {
+  fn from(delegations: Vec<Jwt>) -> Self {
+    Self::new(delegations)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Object;
+  use identity_core::convert::FromJson;
+  use serde_json::json;
+
+  use super::*;
+
+  fn credential() -> Credential<Object> {
+    Credential::from_json_value(json!({
+      "@context": "https://www.w3.org/2018/credentials/v1",
+      "type": "VerifiableCredential",
+      "issuer": "did:example:issuer",
+      "issuanceDate": "2026-01-01T00:00:00Z",
+      "credentialSubject": { "id": "did:example:subject" }
+    }))
+    .unwrap()
+  }
+
+  #[test]
+  fn evidence_round_trips_through_credential() {
+    let mut credential = credential();
+    assert_eq!(DelegationEvidence::from_credential(&credential).unwrap(), None);
+
+    let evidence = DelegationEvidence::new(vec![Jwt::from("a.b.c".to_owned()), Jwt::from("d.e.f".to_owned())]);
+    evidence.clone().attach(&mut credential);
+    assert_eq!(
+      credential.properties[DelegationEvidence::PROPERTY],
+      json!(["a.b.c", "d.e.f"])
+    );
+    assert_eq!(
+      DelegationEvidence::from_credential(&credential).unwrap(),
+      Some(evidence)
+    );
+  }
+
+  #[test]
+  fn malformed_evidence_is_rejected() {
+    let mut credential = credential();
+    credential
+      .properties
+      .insert(DelegationEvidence::PROPERTY.to_owned(), json!({ "not": "a list" }));
+    assert!(matches!(
+      DelegationEvidence::from_credential(&credential),
+      Err(DelegationError::InvalidEvidence(_))
+    ));
+  }
+}
@@ -0,0 +1,22 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Credential chains, in which a root authority delegates the right to issue credentials to intermediate issuers.
+//!
+//! The workflow is the following:
+//! 1. A trusted root issues a delegation credential to an intermediate with a [`DelegationCredentialBuilder`],
+//!    optionally restricting the types of credentials the intermediate may issue. Intermediates may in turn delegate
+//!    to further issuers, as long as they attach the delegations they received.
+//! 2. The last issuer of the chain issues the end credential, referencing the delegations from the root down to
+//!    itself in its [`DelegationEvidence`].
+//! 3. The verifier walks and validates the chain with a [`ChainValidator`].
+
+mod chain_validator;
+mod delegation_credential_builder;
+mod error;
+mod evidence;
+
+pub use self::chain_validator::*;
+pub use self::delegation_credential_builder::*;
+pub use self::error::*;
+pub use self::evidence::*;
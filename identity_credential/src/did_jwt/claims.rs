@@ -0,0 +1,159 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::OneOrMany;
+use identity_core::common::Timestamp;
+use identity_verification::jws::JwsHeader;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+/// The claims of a JWT signed with the verification method of a DID Document.
+///
+/// All registered claims are optional; [`DidJwtClaims::custom`] holds any other claim.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct DidJwtClaims {
+  /// The DID of the issuer of the token.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub iss: Option<String>,
+  /// The subject of the token.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub sub: Option<String>,
+  /// The recipients the token is intended for.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub aud: Option<OneOrMany<String>>,
+  /// The time at which the token expires, as a UNIX timestamp.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub exp: Option<i64>,
+  /// The time before which the token must not be accepted, as a UNIX timestamp.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub nbf: Option<i64>,
+  /// The time at which the token was issued, as a UNIX timestamp.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub iat: Option<i64>,
+  /// The unique identifier of the token.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub jti: Option<String>,
+  /// Additional claims.
+  #[serde(flatten)]
+  pub custom: Object,
+}
+
+impl DidJwtClaims {
+  /// Creates empty claims.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Sets the DID of the issuer.
+  #[must_use]
+  pub fn issuer(mut self, value: impl Into<String>) -> Self {
+    self.iss = Some(value.into());
+    self
+  }
+
+  /// Sets the subject.
+  #[must_use]
+  pub fn subject(mut self, value: impl Into<String>) -> Self {
+    self.sub = Some(value.into());
+    self
+  }
+
+  /// Adds a recipient the token is intended for.
+  #[must_use]
+  pub fn audience(mut self, value: impl Into<String>) -> Self {
+    match self.aud.as_mut() {
+      Some(aud) => aud.push(value.into()),
+      None => self.aud = Some(OneOrMany::One(value.into())),
+    }
+    self
+  }
+
+  /// Sets the expiration date.
+  #[must_use]
+  pub fn expiration_date(mut self, value: Timestamp) -> Self {
+    self.exp = Some(value.to_unix());
+    self
+  }
+
+  /// Sets the date before which the token must not be accepted.
+  #[must_use]
+  pub fn not_before(mut self, value: Timestamp) -> Self {
+    self.nbf = Some(value.to_unix());
+    self
+  }
+
+  /// Sets the issuance date.
+  #[must_use]
+  pub fn issuance_date(mut self, value: Timestamp) -> Self {
+    self.iat = Some(value.to_unix());
+    self
+  }
+
+  /// Sets the unique identifier of the token.
+  #[must_use]
+  pub fn jwt_id(mut self, value: impl Into<String>) -> Self {
+    self.jti = Some(value.into());
+    self
+  }
+
+  /// Sets the custom claim `name`.
+  #[must_use]
+  pub fn claim(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
+    self.custom.insert(name.into(), value.into());
+    self
+  }
+
+  /// Returns `true` if the token is intended for `audience`.
+  pub fn is_intended_for(&self, audience: &str) -> bool {
+    self
+      .aud
+      .as_ref()
+      .map_or(false, |aud| aud.iter().any(|aud| aud == audience))
+  }
+}
+
+/// A JWT decoded and validated by a [`DidJwtValidator`](super::DidJwtValidator).
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct DecodedDidJwt {
+  /// The protected header of the token.
+  pub header: Box<JwsHeader>,
+  /// The claims of the token.
+  pub claims: DidJwtClaims,
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+
+  #[test]
+  fn claims_round_trip_through_json() {
+    let claims = DidJwtClaims::new()
+      .issuer("did:example:issuer")
+      .subject("session-1")
+      .audience("did:example:service")
+      .audience("did:example:other")
+      .expiration_date(Timestamp::from_unix(1_700_086_400).unwrap())
+      .claim("scope", "read write");
+
+    let json = serde_json::to_value(&claims).unwrap();
+    assert_eq!(
+      json,
+      json!({
+        "iss": "did:example:issuer",
+        "sub": "session-1",
+        "aud": ["did:example:service", "did:example:other"],
+        "exp": 1_700_086_400,
+        "scope": "read write",
+      })
+    );
+    assert!(claims.is_intended_for("did:example:other"));
+    assert!(!claims.is_intended_for("did:example:unknown"));
+    assert_eq!(serde_json::from_value::<DidJwtClaims>(json).unwrap(), claims);
+  }
+}
@@ -0,0 +1,60 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::validator::JwtValidationError;
+
+/// Alias for a `Result` with the error type [`DidJwtError`].
+pub type DidJwtResult<T> = Result<T, DidJwtError>;
+
+/// Errors that can occur when validating a JWT signed with the verification method of a DID Document.
+#[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum DidJwtError {
+  /// Caused by a token that exceeds the validation limits.
+  #[error("the token exceeds the validation limits")]
+  LimitExceeded(#[source] JwtValidationError),
+  /// Caused by a token whose `typ` header is not the expected one.
+  #[error("invalid token type `{0}`")]
+  InvalidType(String),
+  /// Caused by a token whose signature cannot be verified with the issuer's DID Document.
+  #[error("invalid token signature")]
+  InvalidSignature(#[source] identity_document::error::Error),
+  /// Caused by token claims that cannot be decoded.
+  #[error("invalid token claims: {0}")]
+  InvalidClaims(String),
+  /// Caused by a token whose `iss` claim is not the DID of the document it was validated against.
+  #[error("the token was not issued by the DID it was validated against")]
+  IssuerMismatch,
+  /// Caused by a token about a different subject than the expected one.
+  #[error("the token is about `{0}`, which is not the expected subject")]
+  UnexpectedSubject(String),
+  /// Caused by a token not intended for the expected audience.
+  #[error("the token is not intended for the expected audience")]
+  AudienceMismatch,
+  /// Caused by a token without an expiration date, when one is required.
+  #[error("the token has no expiration date")]
+  MissingExpirationDate,
+  /// Caused by a token that has expired.
+  #[error("the token has expired")]
+  Expired,
+  /// Caused by a token that is not valid yet.
+  #[error("the token is not valid yet")]
+  NotYetValid,
+  /// Caused by a token issued after the latest accepted issuance date.
+  #[error("the token was issued in the future")]
+  IssuedInFuture,
+}
+
+identity_core::impl_error_code!(DidJwtError {
+  LimitExceeded => 2450,
+  InvalidType => 2451,
+  InvalidSignature => 2452,
+  InvalidClaims => 2453,
+  IssuerMismatch => 2454,
+  UnexpectedSubject => 2455,
+  AudienceMismatch => 2456,
+  MissingExpirationDate => 2457,
+  Expired => 2458,
+  NotYetValid => 2459,
+  IssuedInFuture => 2460,
+});
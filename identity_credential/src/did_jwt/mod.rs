@@ -0,0 +1,19 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! General-purpose JWTs signed with the verification methods of a DID Document.
+//!
+//! Not every token exchanged between DID-controlled parties is a Verifiable Credential: authorization grants, session
+//! tokens or service-to-service requests only need a set of claims whose issuer is identified by a DID. Such tokens
+//! carry [`DidJwtClaims`], made of the registered claims of [RFC 7519](https://www.rfc-editor.org/rfc/rfc7519) and any
+//! number of custom ones, and are validated against the issuer's DID Document with a [`DidJwtValidator`].
+
+mod claims;
+mod error;
+mod options;
+mod validator;
+
+pub use self::claims::*;
+pub use self::error::*;
+pub use self::options::*;
+pub use self::validator::*;
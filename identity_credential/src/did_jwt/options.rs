@@ -0,0 +1,120 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Clock;
+use identity_core::common::SharedClock;
+use identity_core::common::Timestamp;
+use identity_document::verifiable::JwsVerificationOptions;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::validator::ValidationLimits;
+
+/// Criteria for validating a JWT signed with the verification method of a DID Document.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(rename_all = "camelCase")]
+pub struct DidJwtValidationOptions {
+  /// Options which affect the verification of the issuer's signature.
+  #[serde(default)]
+  pub verification_options: JwsVerificationOptions,
+
+  /// The `typ` header the token must have, if any.
+  #[serde(default)]
+  pub typ: Option<String>,
+
+  /// The audience the token must be intended for, if any.
+  #[serde(default)]
+  pub audience: Option<String>,
+
+  /// The subject the token must be about, if any.
+  #[serde(default)]
+  pub subject: Option<String>,
+
+  /// Whether tokens without an expiration date are rejected.
+  #[serde(default)]
+  pub require_expiration_date: bool,
+
+  /// Declares that the token is **not** considered valid if it expires before this [`Timestamp`].
+  /// Uses the current datetime during validation if not set.
+  #[serde(default)]
+  pub earliest_expiry_date: Option<Timestamp>,
+
+  /// Declares that the token is **not** considered valid if it was issued, or only becomes valid, later than this
+  /// [`Timestamp`]. Uses the current datetime during validation if not set.
+  #[serde(default)]
+  pub latest_issuance_date: Option<Timestamp>,
+
+  /// Limits on the size and complexity of the token, enforced before it is decoded.
+  #[serde(default)]
+  pub limits: ValidationLimits,
+
+  /// The clock providing the current datetime used when the dates above are not set.
+  ///
+  /// Default: [`SystemClock`](identity_core::common::SystemClock).
+  #[serde(skip)]
+  pub clock: SharedClock,
+}
+
+impl DidJwtValidationOptions {
+  /// Constructor that sets all options to their defaults.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Set options which affect the verification of the issuer's signature.
+  pub fn verification_options(mut self, options: JwsVerificationOptions) -> Self {
+    self.verification_options = options;
+    self
+  }
+
+  /// Require the token to have the `typ` header `typ`.
+  pub fn typ(mut self, typ: impl Into<String>) -> Self {
+    self.typ = Some(typ.into());
+    self
+  }
+
+  /// Require the token to be intended for `audience`.
+  pub fn audience(mut self, audience: impl Into<String>) -> Self {
+    self.audience = Some(audience.into());
+    self
+  }
+
+  /// Require the token to be about `subject`.
+  pub fn subject(mut self, subject: impl Into<String>) -> Self {
+    self.subject = Some(subject.into());
+    self
+  }
+
+  /// Set whether tokens without an expiration date are rejected.
+  pub fn require_expiration_date(mut self, value: bool) -> Self {
+    self.require_expiration_date = value;
+    self
+  }
+
+  /// Declare that the token is **not** considered valid if it expires before this [`Timestamp`].
+  /// Uses the current datetime during validation if not set.
+  pub fn earliest_expiry_date(mut self, timestamp: Timestamp) -> Self {
+    self.earliest_expiry_date = Some(timestamp);
+    self
+  }
+
+  /// Declare that the token is **not** considered valid if it was issued, or only becomes valid, later than this
+  /// [`Timestamp`]. Uses the current datetime during validation if not set.
+  pub fn latest_issuance_date(mut self, timestamp: Timestamp) -> Self {
+    self.latest_issuance_date = Some(timestamp);
+    self
+  }
+
+  /// Set the limits on the size and complexity of the token.
+  pub fn limits(mut self, limits: ValidationLimits) -> Self {
+    self.limits = limits;
+    self
+  }
+
+  /// Set the clock providing the current datetime used when the dates above are not set.
+  pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+    self.clock = SharedClock::new(clock);
+    self
+  }
+}
@@ -0,0 +1,110 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Timestamp;
+use identity_document::document::CoreDocument;
+use identity_verification::jws::DecodedJws;
+use identity_verification::jws::JwsVerifier;
+
+use super::DecodedDidJwt;
+use super::DidJwtClaims;
+use super::DidJwtError;
+use super::DidJwtResult;
+use super::DidJwtValidationOptions;
+use crate::credential::Jwt;
+
+/// A type for decoding and validating JWTs signed with the verification method of a DID Document.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct DidJwtValidator<V: JwsVerifier>(V);
+
+impl<V: JwsVerifier> DidJwtValidator<V> {
+  /// Creates a new [`DidJwtValidator`] that delegates cryptographic signature verification to the given
+  /// `signature_verifier`.
+  pub fn with_signature_verifier(signature_verifier: V) -> Self {
+    Self(signature_verifier)
+  }
+
+  /// Decodes and validates a JWT issued by `issuer`.
+  ///
+  /// The following properties are validated according to `options`:
+  /// - the `typ` header,
+  /// - the issuer's signature,
+  /// - the `iss` claim, which must be absent or the DID of `issuer`,
+  /// - the subject and audience,
+  /// - the expiration, not-before and issuance date.
+  ///
+  /// # Warning
+  /// The caller must ensure that `issuer` is the up-to-date DID Document of the party it expects the token from.
+  pub fn validate<DOC>(&self, jwt: &Jwt, issuer: &DOC, options: &DidJwtValidationOptions) -> DidJwtResult<DecodedDidJwt>
+  where
+    DOC: AsRef<CoreDocument> + ?Sized,
+  {
+    options
+      .limits
+      .check_jwt(jwt.as_str())
+      .map_err(DidJwtError::LimitExceeded)?;
+
+    let decoded: DecodedJws<'_> = issuer
+      .as_ref()
+      .verify_jws(jwt.as_str(), None, &self.0, &options.verification_options)
+      .map_err(DidJwtError::InvalidSignature)?;
+
+    if let Some(typ) = &options.typ {
+      if decoded.protected.typ() != Some(typ.as_str()) {
+        return Err(DidJwtError::InvalidType(
+          decoded.protected.typ().unwrap_or_default().to_owned(),
+        ));
+      }
+    }
+
+    let claims: DidJwtClaims =
+      serde_json::from_slice(&decoded.claims).map_err(|err| DidJwtError::InvalidClaims(err.to_string()))?;
+
+    if let Some(iss) = &claims.iss {
+      if iss != issuer.as_ref().id().as_str() {
+        return Err(DidJwtError::IssuerMismatch);
+      }
+    }
+    if let Some(subject) = &options.subject {
+      if claims.sub.as_ref() != Some(subject) {
+        return Err(DidJwtError::UnexpectedSubject(claims.sub.unwrap_or_default()));
+      }
+    }
+    if let Some(audience) = &options.audience {
+      if !claims.is_intended_for(audience) {
+        return Err(DidJwtError::AudienceMismatch);
+      }
+    }
+
+    match claims.exp.map(timestamp).transpose()? {
+      Some(expiration_date) => {
+        if expiration_date < options.earliest_expiry_date.unwrap_or_else(|| options.clock.now()) {
+          return Err(DidJwtError::Expired);
+        }
+      }
+      None if options.require_expiration_date => return Err(DidJwtError::MissingExpirationDate),
+      None => (),
+    }
+    let latest_issuance_date: Timestamp = options.latest_issuance_date.unwrap_or_else(|| options.clock.now());
+    if let Some(not_before) = claims.nbf.map(timestamp).transpose()? {
+      if not_before > latest_issuance_date {
+        return Err(DidJwtError::NotYetValid);
+      }
+    }
+    if let Some(issuance_date) = claims.iat.map(timestamp).transpose()? {
+      if issuance_date > latest_issuance_date {
+        return Err(DidJwtError::IssuedInFuture);
+      }
+    }
+
+    Ok(DecodedDidJwt {
+      header: Box::new(decoded.protected),
+      claims,
+    })
+  }
+}
+
+fn timestamp(seconds: i64) -> DidJwtResult<Timestamp> {
+  Timestamp::from_unix(seconds).map_err(|err| DidJwtError::InvalidClaims(err.to_string()))
+}
@@ -113,19 +113,46 @@ mod __fetch_configuration {
   use crate::error::Result;
   use crate::utils::url_only_includes_origin;
   use crate::Error::DomainLinkageError;
-  use futures::StreamExt;
   use identity_core::common::Url;
   use identity_core::convert::FromJson;
+  use identity_core::http::HttpClient;
+  use identity_core::http::HttpRequest;
+  use identity_core::http::ReqwestClient;
   use reqwest::redirect::Policy;
-  use reqwest::Client;
+
+  /// The maximum size of a domain linkage configuration fetched by this library.
+  const MAX_CONFIGURATION_SIZE: usize = 1_048_576;
 
   impl DomainLinkageConfiguration {
     /// Fetches the DID Configuration resource via a GET request at the
     /// well-known location: "`domain`/.well-known/did-configuration.json".
     ///
+    /// The request is sent with an HTTPS-only client that doesn't follow redirects; to use a
+    /// different client, see [`Self::fetch_configuration_with_client`].
+    ///
     /// The maximum size of the domain linkage configuration that can be retrieved with this method is 1 MiB.
     /// To download larger ones, use your own HTTP client.
-    pub async fn fetch_configuration(mut domain: Url) -> Result<DomainLinkageConfiguration> {
+    pub async fn fetch_configuration(domain: Url) -> Result<DomainLinkageConfiguration> {
+      let client: ReqwestClient = reqwest::ClientBuilder::new()
+        .https_only(true)
+        .redirect(Policy::none())
+        .build()
+        .map_err(|err| DomainLinkageError(Box::new(err)))?
+        .into();
+
+      Self::fetch_configuration_with_client(domain, &client).await
+    }
+
+    /// Fetches the DID Configuration resource via a GET request at the
+    /// well-known location: "`domain`/.well-known/did-configuration.json", sent with `client`.
+    ///
+    /// Unlike [`Self::fetch_configuration`], redirects are followed according to the policy of `client`.
+    ///
+    /// The maximum size of the domain linkage configuration that can be retrieved with this method is 1 MiB.
+    pub async fn fetch_configuration_with_client<C>(mut domain: Url, client: &C) -> Result<DomainLinkageConfiguration>
+    where
+      C: HttpClient + ?Sized,
+    {
       if domain.scheme() != "https" {
         return Err(DomainLinkageError("domain` does not use `https` protocol".into()));
       }
@@ -136,34 +163,11 @@ mod __fetch_configuration {
       }
       domain.set_path(".well-known/did-configuration.json");
 
-      let client: Client = reqwest::ClientBuilder::new()
-        .https_only(true)
-        .redirect(Policy::none())
-        .build()
-        .map_err(|err| DomainLinkageError(Box::new(err)))?;
-
-      // We use a stream so we can limit the size of the response to 1 MiB.
-      let mut stream = client
-        .get(domain.to_string())
-        .send()
+      let json: Vec<u8> = client
+        .send(HttpRequest::get(domain).max_response_size(MAX_CONFIGURATION_SIZE))
         .await
-        .map_err(|err| DomainLinkageError(Box::new(err)))?
-        .bytes_stream();
-
-      let mut json: Vec<u8> = Vec::new();
-      while let Some(item) = stream.next().await {
-        match item {
-          Ok(bytes) => {
-            json.extend(bytes);
-            if json.len() > 1_048_576 {
-              return Err(DomainLinkageError(
-                "domain linkage configuration can not exceed 1 MiB".into(),
-              ));
-            }
-          }
-          Err(err) => return Err(DomainLinkageError(Box::new(err))),
-        }
-      }
+        .and_then(|response| response.into_success_body())
+        .map_err(|err| DomainLinkageError(Box::new(err)))?;
       let domain_linkage_configuration: DomainLinkageConfiguration =
         DomainLinkageConfiguration::from_json_slice(&json).map_err(|err| DomainLinkageError(Box::new(err)))?;
       Ok(domain_linkage_configuration)
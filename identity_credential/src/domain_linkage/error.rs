@@ -3,6 +3,9 @@
 
 use std::error::Error;
 
+use identity_core::error_code::ErrorCode;
+use identity_core::error_code::HasErrorCode;
+
 pub(crate) type DomainLinkageValidationResult = Result<(), DomainLinkageValidationError>;
 
 /// An error caused by a failure to verify a Domain Linkage configuration or credential.
@@ -95,3 +98,26 @@ pub enum DomainLinkageValidationErrorCause {
   #[error("one or more validations failed")]
   List,
 }
+
+impl HasErrorCode for DomainLinkageValidationError {
+  fn error_code(&self) -> ErrorCode {
+    self.cause.error_code()
+  }
+}
+
+identity_core::impl_error_code!(DomainLinkageValidationErrorCause {
+  CredentialValidationError => 1750,
+  InvalidJwt => 1751,
+  MissingExpirationDate => 1752,
+  ImpermissibleIdProperty => 1753,
+  IssuerSubjectMismatch => 1754,
+  InvalidSubjectId => 1755,
+  MultipleCredentialSubjects => 1756,
+  InvalidIssuer => 1757,
+  MissingSubjectId => 1758,
+  InvalidTypeProperty => 1759,
+  OriginMismatch => 1760,
+  InvalidSubjectOrigin => 1761,
+  InvalidStructure => 1762,
+  List => 1763,
+});
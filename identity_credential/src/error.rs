@@ -80,8 +80,43 @@ pub enum Error {
   #[error("Attribute Not found")]
   SelectiveDisclosureError,
 
+  /// Caused when instantiating a [`CredentialTemplate`](crate::credential::CredentialTemplate) without one of its
+  /// required claims.
+  #[error("missing required claim \"{0}\"")]
+  MissingTemplateClaim(String),
+
+  /// Caused when concealing a claim a predicate of a JPT presentation refers to.
+  #[error("claim \"{0}\" is required by a predicate and cannot be concealed")]
+  ConcealedPredicateClaim(String),
+
   /// Failure of an SD-JWT VC operation.
   #[cfg(feature = "sd-jwt-vc")]
   #[error(transparent)]
   SdJwtVc(#[from] crate::sd_jwt_vc::Error),
 }
+
+identity_core::impl_error_code!(Error {
+  MissingBaseContext => 1500,
+  MissingBaseType => 1501,
+  MissingIssuer => 1502,
+  MissingSubject => 1503,
+  MissingExpirationDate => 1504,
+  MissingOrigin => 1505,
+  InvalidSubject => 1506,
+  InvalidStatus => 1507,
+  DomainLinkageError => 1508,
+  LinkedVerifiablePresentationError => 1509,
+  MoreThanOneSubjectInJwt => 1510,
+  InconsistentCredentialJwtClaims => 1511,
+  EmptyVerifiableCredentialArray => 1512,
+  InconsistentPresentationJwtClaims => 1513,
+  TimestampConversionError => 1514,
+  JwtClaimsSetSerializationError => 1515,
+  JwtClaimsSetDeserializationError => 1516,
+  JptClaimsSetDeserializationError => 1517,
+  SelectiveDisclosureError => 1518,
+  MissingTemplateClaim => 1520,
+  ConcealedPredicateClaim => 1521,
+  #[cfg(feature = "sd-jwt-vc")]
+  SdJwtVc => 1519,
+});
@@ -14,8 +14,16 @@
   clippy::missing_safety_doc
 )]
 
+#[cfg(feature = "account-control")]
+pub mod account_control;
 #[cfg(feature = "credential")]
 pub mod credential;
+#[cfg(feature = "dcql")]
+pub mod dcql;
+#[cfg(feature = "delegation")]
+pub mod delegation;
+#[cfg(feature = "did-jwt")]
+pub mod did_jwt;
 #[cfg(feature = "domain-linkage")]
 pub mod domain_linkage;
 pub mod error;
@@ -26,6 +34,8 @@ pub mod revocation;
 mod utils;
 #[cfg(feature = "validator")]
 pub mod validator;
+#[cfg(feature = "wallet-attestation")]
+pub mod wallet_attestation;
 
 /// Implementation of the SD-JWT VC token specification.
 #[cfg(feature = "sd-jwt-vc")]
@@ -0,0 +1,241 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+/// The comparison a [`JptPredicate`] applies to a claim.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum PredicateOperator {
+  /// The claim must be strictly lower than the predicate's value.
+  LessThan,
+  /// The claim must be lower than or equal to the predicate's value.
+  LessThanOrEqual,
+  /// The claim must be strictly greater than the predicate's value.
+  GreaterThan,
+  /// The claim must be greater than or equal to the predicate's value.
+  GreaterThanOrEqual,
+}
+
+impl PredicateOperator {
+  fn accepts(self, ordering: Ordering) -> bool {
+    match self {
+      Self::LessThan => ordering.is_lt(),
+      Self::LessThanOrEqual => ordering.is_le(),
+      Self::GreaterThan => ordering.is_gt(),
+      Self::GreaterThanOrEqual => ordering.is_ge(),
+    }
+  }
+}
+
+impl Display for PredicateOperator {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    let symbol = match self {
+      Self::LessThan => "<",
+      Self::LessThanOrEqual => "<=",
+      Self::GreaterThan => ">",
+      Self::GreaterThanOrEqual => ">=",
+    };
+    f.write_str(symbol)
+  }
+}
+
+/// A predicate over a `credentialSubject` claim of a JPT, e.g. "birthDate <= 2006-10-16" to express that the
+/// subject is over 18.
+///
+/// Claims are compared as numbers when both the claim and the predicate's value are numbers, and as dates when both
+/// are strings holding either an RFC 3339 timestamp or a `YYYY-MM-DD` date.
+///
+/// ## Notes
+/// The BBS+ proof suite used for JPTs does not support range proofs. The claim a predicate refers to must
+/// therefore be disclosed in the presentation, and the predicate is checked by the verifier on the disclosed value.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JptPredicate {
+  /// The path of the claim within `credentialSubject`, using the same syntax as
+  /// `SelectiveDisclosurePresentation::conceal_in_subject`, e.g. `birthDate` or `degree.level`.
+  pub path: String,
+  /// The comparison applied to the claim.
+  pub operator: PredicateOperator,
+  /// The value the claim is compared with.
+  pub value: Value,
+}
+
+impl JptPredicate {
+  /// Creates a new [`JptPredicate`] requiring the claim at `path` to compare to `value` according to `operator`.
+  pub fn new(path: impl Into<String>, operator: PredicateOperator, value: impl Into<Value>) -> Self {
+    Self {
+      path: path.into(),
+      operator,
+      value: value.into(),
+    }
+  }
+
+  /// Creates a [`JptPredicate`] requiring the birthdate found at `path` to be at least `years` years before `at`,
+  /// i.e. the subject to be at least `years` years old at `at`.
+  ///
+  /// This is not a zero-knowledge predicate: the birthdate must be disclosed in the presentation and is compared with
+  /// the cutoff date by the verifier, see the notes on [`JptPredicate`].
+  ///
+  /// Someone born on February 29th is `years` years old on March 1st in non-leap years, since the cutoff date of
+  /// February 28th precedes their birthdate.
+  pub fn disclosed_age_at_least(path: impl Into<String>, years: u16, at: Timestamp) -> Self {
+    let at = at.to_rfc3339();
+    // RFC 3339 timestamps in the range supported by `Timestamp` always start with a four digit year.
+    let (year, rest) = at.split_at(4);
+    let year = year
+      .parse::<i32>()
+      .expect("valid year")
+      .saturating_sub(years.into())
+      .max(0);
+    // February 29th does not exist `years` years earlier if that is not a leap year, in which case the cutoff is the
+    // last day of February: whoever was born up to February 28th had their birthday by then.
+    let rest = if rest.starts_with("-02-29") && !is_leap_year(year) {
+      rest.replacen("-02-29", "-02-28", 1)
+    } else {
+      rest.to_owned()
+    };
+
+    Self::new(path, PredicateOperator::LessThanOrEqual, format!("{year:04}{rest}"))
+  }
+
+  /// Returns whether the claim this predicate refers to, looked up in the `credentialSubject` `subject`, satisfies
+  /// this predicate.
+  ///
+  /// [None] is returned if the claim is not present, e.g. because it was concealed, or if it cannot be compared with
+  /// this predicate's value.
+  pub fn is_satisfied_by(&self, subject: &Object) -> Option<bool> {
+    let claim = lookup(subject, &self.path)?;
+    let ordering = compare(claim, &self.value)?;
+
+    Some(self.operator.accepts(ordering))
+  }
+}
+
+impl Display for JptPredicate {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{} {} {}", self.path, self.operator, self.value)
+  }
+}
+
+/// Finds the value at `path`, e.g. `degree.name` or `mainCourses[1]`, in `object`.
+fn lookup<'a>(object: &'a Object, path: &str) -> Option<&'a Value> {
+  let mut segments = path
+    .split(['.', '['])
+    .map(|segment| segment.strip_suffix(']').unwrap_or(segment));
+  let mut current: &Value = object.get(segments.next()?)?;
+  for segment in segments {
+    current = match current {
+      Value::Object(map) => map.get(segment)?,
+      Value::Array(values) => values.get(segment.parse::<usize>().ok()?)?,
+      _ => return None,
+    };
+  }
+
+  Some(current)
+}
+
+fn compare(claim: &Value, value: &Value) -> Option<Ordering> {
+  match (claim, value) {
+    (Value::Number(claim), Value::Number(value)) => claim.as_f64()?.partial_cmp(&value.as_f64()?),
+    (Value::String(claim), Value::String(value)) => Some(parse_date(claim)?.cmp(&parse_date(value)?)),
+    _ => None,
+  }
+}
+
+fn parse_date(input: &str) -> Option<Timestamp> {
+  Timestamp::parse(input)
+    .or_else(|_| Timestamp::parse(&format!("{input}T00:00:00Z")))
+    .ok()
+}
+
+fn is_leap_year(year: i32) -> bool {
+  (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+
+  use super::*;
+
+  fn subject() -> Object {
+    Object::from_json(
+      r#"{
+        "birthDate": "2006-10-16",
+        "degree": { "gpa": 3.7 },
+        "mainCourses": ["Mathematics", "Physics"]
+      }"#,
+    )
+    .unwrap()
+  }
+
+  #[test]
+  fn disclosed_age_at_least_compares_dates() {
+    let today = Timestamp::parse("2024-10-16T12:00:00Z").unwrap();
+    assert_eq!(
+      JptPredicate::disclosed_age_at_least("birthDate", 18, today).is_satisfied_by(&subject()),
+      Some(true)
+    );
+    assert_eq!(
+      JptPredicate::disclosed_age_at_least("birthDate", 19, today).is_satisfied_by(&subject()),
+      Some(false)
+    );
+
+    let leap_day = Timestamp::parse("2024-02-29T00:00:00Z").unwrap();
+    assert_eq!(
+      JptPredicate::disclosed_age_at_least("birthDate", 18, leap_day).value,
+      Value::from("2006-02-28T00:00:00Z")
+    );
+  }
+
+  #[test]
+  fn leap_day_birthdays_come_of_age_on_march_first() {
+    let born_on_leap_day = Object::from_json(r#"{ "birthDate": "2004-02-29" }"#).unwrap();
+    let feb_28 = Timestamp::parse("2025-02-28T00:00:00Z").unwrap();
+    let mar_1 = Timestamp::parse("2025-03-01T00:00:00Z").unwrap();
+    assert_eq!(
+      JptPredicate::disclosed_age_at_least("birthDate", 21, feb_28).is_satisfied_by(&born_on_leap_day),
+      Some(false)
+    );
+    assert_eq!(
+      JptPredicate::disclosed_age_at_least("birthDate", 21, mar_1).is_satisfied_by(&born_on_leap_day),
+      Some(true)
+    );
+
+    // On a leap day, whoever was born on February 28th of a non-leap year is of age, but not whoever was born on
+    // March 1st.
+    let leap_day = Timestamp::parse("2024-02-29T00:00:00Z").unwrap();
+    let predicate = JptPredicate::disclosed_age_at_least("birthDate", 18, leap_day);
+    let born_on = |date: &str| Object::from_json(&format!(r#"{{ "birthDate": "{date}" }}"#)).unwrap();
+    assert_eq!(predicate.is_satisfied_by(&born_on("2006-02-28")), Some(true));
+    assert_eq!(predicate.is_satisfied_by(&born_on("2006-03-01")), Some(false));
+  }
+
+  #[test]
+  fn numeric_and_nested_claims_can_be_compared() {
+    let gpa_over_3 = JptPredicate::new("degree.gpa", PredicateOperator::GreaterThan, 3);
+    assert_eq!(gpa_over_3.is_satisfied_by(&subject()), Some(true));
+
+    let gpa_below_3 = JptPredicate::new("degree.gpa", PredicateOperator::LessThan, 3);
+    assert_eq!(gpa_below_3.is_satisfied_by(&subject()), Some(false));
+  }
+
+  #[test]
+  fn missing_or_incomparable_claims_cannot_be_evaluated() {
+    let missing = JptPredicate::new("degree.name", PredicateOperator::GreaterThan, 3);
+    assert_eq!(missing.is_satisfied_by(&subject()), None);
+
+    let not_a_date = JptPredicate::new("mainCourses[1]", PredicateOperator::GreaterThan, "2020-01-01");
+    assert_eq!(not_a_date.is_satisfied_by(&subject()), None);
+  }
+}
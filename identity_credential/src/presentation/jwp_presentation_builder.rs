@@ -3,6 +3,7 @@
 
 use crate::error::Error;
 use crate::error::Result;
+use crate::presentation::JptPredicate;
 use jsonprooftoken::jwp::header::PresentationProtectedHeader;
 use jsonprooftoken::jwp::issued::JwpIssued;
 use jsonprooftoken::jwp::presented::JwpPresentedBuilder;
@@ -22,6 +23,8 @@ use jsonprooftoken::jwp::presented::JwpPresentedBuilder;
 // - evidence (Users have to choose which attribute must be blinded)
 pub struct SelectiveDisclosurePresentation {
   jwp_builder: JwpPresentedBuilder,
+  concealed_in_subject: Vec<String>,
+  predicates: Vec<JptPredicate>,
 }
 
 impl SelectiveDisclosurePresentation {
@@ -57,7 +60,11 @@ impl SelectiveDisclosurePresentation {
     jwp_builder.set_undisclosed("vc.credentialSubject.id").ok();
     jwp_builder.set_undisclosed("sub").ok();
 
-    Self { jwp_builder }
+    Self {
+      jwp_builder,
+      concealed_in_subject: vec![],
+      predicates: vec![],
+    }
   }
 
   /// Selectively conceal "credentialSubject" attributes.
@@ -80,13 +87,40 @@ impl SelectiveDisclosurePresentation {
   /// presentation_builder.conceal_in_subject("degree.name");
   /// ```
   pub fn conceal_in_subject(&mut self, path: &str) -> Result<(), Error> {
+    if let Some(predicate) = self.predicates.iter().find(|predicate| overlaps(path, &predicate.path)) {
+      return Err(Error::ConcealedPredicateClaim(predicate.path.clone()));
+    }
     let _ = self
       .jwp_builder
       .set_undisclosed(&("vc.credentialSubject.".to_owned() + path))
       .map_err(|_| Error::SelectiveDisclosureError);
+    self.concealed_in_subject.push(path.to_owned());
     Ok(())
   }
 
+  /// Declares that this presentation is meant to satisfy `predicate`, e.g. that the subject is over 18.
+  ///
+  /// Since the BBS+ proof suite does not support range proofs, the claim the predicate refers to stays disclosed
+  /// and the verifier checks the predicate against it, see
+  /// [`JptPresentationValidationOptions::predicate`](crate::validator::JptPresentationValidationOptions::predicate).
+  /// Fails if the claim, or one of its parents, has been concealed.
+  pub fn add_predicate(&mut self, predicate: JptPredicate) -> Result<(), Error> {
+    if self
+      .concealed_in_subject
+      .iter()
+      .any(|concealed| overlaps(concealed, &predicate.path))
+    {
+      return Err(Error::ConcealedPredicateClaim(predicate.path));
+    }
+    self.predicates.push(predicate);
+    Ok(())
+  }
+
+  /// Returns the predicates this presentation is meant to satisfy.
+  pub fn predicates(&self) -> &[JptPredicate] {
+    &self.predicates
+  }
+
   /// Undisclose "evidence" attributes.
   /// # Example
   /// ```ignore
@@ -122,3 +156,13 @@ impl SelectiveDisclosurePresentation {
     &self.jwp_builder
   }
 }
+
+/// Whether concealing the claim at `concealed` also conceals the claim at `path`, or vice versa.
+fn overlaps(concealed: &str, path: &str) -> bool {
+  let is_parent = |parent: &str, child: &str| {
+    child
+      .strip_prefix(parent)
+      .is_some_and(|rest| rest.is_empty() || rest.starts_with(['.', '[']))
+  };
+  is_parent(concealed, path) || is_parent(path, concealed)
+}
@@ -5,6 +5,8 @@
 
 #![allow(clippy::module_inception)]
 
+#[cfg(feature = "jpt-bbs-plus")]
+mod jpt_predicate;
 #[cfg(feature = "jpt-bbs-plus")]
 mod jwp_presentation_builder;
 #[cfg(feature = "jpt-bbs-plus")]
@@ -14,6 +16,10 @@ mod jwt_serialization;
 mod presentation;
 mod presentation_builder;
 
+#[cfg(feature = "jpt-bbs-plus")]
+pub use self::jpt_predicate::JptPredicate;
+#[cfg(feature = "jpt-bbs-plus")]
+pub use self::jpt_predicate::PredicateOperator;
 #[cfg(feature = "jpt-bbs-plus")]
 pub use self::jwp_presentation_builder::SelectiveDisclosurePresentation;
 pub use self::jwt_presentation_options::JwtPresentationOptions;
@@ -13,6 +13,7 @@ use identity_core::common::Object;
 use identity_core::common::OneOrMany;
 use identity_core::common::Url;
 use identity_core::convert::FmtJson;
+use identity_core::convert::FromJson;
 use identity_core::convert::ToJson;
 use serde::de::DeserializeOwned;
 
@@ -180,6 +181,19 @@ where
   }
 }
 
+impl<CRED, T> TryFrom<&serde_json::Value> for Presentation<CRED, T>
+where
+  CRED: DeserializeOwned,
+  T: DeserializeOwned,
+{
+  type Error = identity_core::Error;
+
+  /// Deserializes a [`Presentation`] from a borrowed JSON value, see [`FromJson::from_json_value_ref`].
+  fn try_from(value: &serde_json::Value) -> std::result::Result<Self, Self::Error> {
+    Self::from_json_value_ref(value)
+  }
+}
+
 impl<T> Display for Presentation<T>
 where
   T: Serialize,
@@ -278,4 +292,27 @@ mod tests {
       crate::error::Error::EmptyVerifiableCredentialArray.to_string()
     );
   }
+
+  #[test]
+  fn test_presentation_try_from_json_value_ref() {
+    use identity_core::convert::ToJson;
+
+    let value: serde_json::Value = json!({
+      "@context": "https://www.w3.org/2018/credentials/v1",
+      "holder": "did:test:abc1",
+      "type": "VerifiablePresentation",
+      "verifiableCredential": ["eyJhbGciOiJFZERTQSJ9.eyJpc3MiOiJkaWQ6dGVzdDp4eXoifQ.c2ln"]
+    });
+    let presentation: Presentation<String> = Presentation::try_from(&value).unwrap();
+    assert_eq!(presentation, Presentation::from_json_value(value).unwrap());
+
+    let value: serde_json::Value = presentation.to_json_value().unwrap();
+    assert_eq!(
+      Presentation::<String, Object>::from_json_value_ref(&value).unwrap(),
+      presentation
+    );
+
+    let invalid: serde_json::Value = json!({ "type": "VerifiablePresentation" });
+    assert!(Presentation::<String>::try_from(&invalid).is_err());
+  }
 }
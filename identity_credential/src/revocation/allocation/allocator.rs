@@ -0,0 +1,299 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Url;
+
+use super::AllocationState;
+use super::AllocationStore;
+use super::IndexAllocationError;
+use super::IndexAllocationResult;
+use super::StatusListShard;
+
+/// The status list and index allocated to a credential.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StatusAllocation {
+  /// The id of the status list.
+  pub list: Url,
+  /// The index of the credential in the status list.
+  pub index: u32,
+}
+
+impl StatusAllocation {
+  /// Returns the `RevocationBitmap2022` status referencing the allocated index, if the status list is identified by a
+  /// DID URL.
+  #[cfg(feature = "credential")]
+  pub fn revocation_bitmap_status(&self) -> IndexAllocationResult<crate::credential::RevocationBitmapStatus> {
+    let service: identity_did::DIDUrl = identity_did::DIDUrl::parse(self.list.as_str())
+      .map_err(|_| IndexAllocationError::InvalidListId("expected the DID URL of a service"))?;
+    Ok(crate::credential::RevocationBitmapStatus::new(service, self.index))
+  }
+
+  /// Returns the `StatusList2021` entry referencing the allocated index with the given `purpose`.
+  #[cfg(feature = "status-list-2021")]
+  pub fn status_list_2021_entry(
+    &self,
+    purpose: crate::revocation::status_list_2021::StatusPurpose,
+  ) -> crate::revocation::status_list_2021::StatusList2021Entry {
+    crate::revocation::status_list_2021::StatusList2021Entry::new(self.list.clone(), purpose, self.index as usize, None)
+  }
+}
+
+/// Allocates the status list indices of newly issued credentials across several status lists, see the
+/// [module documentation](super).
+///
+/// Status lists are filled in the order they were registered with [`IndexAllocator::add_list`]. Indices are allocated
+/// sequentially, are never handed out twice, and status lists can neither be removed nor registered again.
+#[derive(Debug)]
+pub struct IndexAllocator<S> {
+  store: S,
+  max_attempts: usize,
+}
+
+impl<S: AllocationStore> IndexAllocator<S> {
+  /// The default number of attempts to update the state when it is modified concurrently.
+  pub const DEFAULT_MAX_ATTEMPTS: usize = 16;
+
+  /// Creates a new [`IndexAllocator`] persisting its state in `store`.
+  pub fn new(store: S) -> Self {
+    Self {
+      store,
+      max_attempts: Self::DEFAULT_MAX_ATTEMPTS,
+    }
+  }
+
+  /// Sets the number of attempts to update the state when it is modified concurrently, e.g. by another allocator
+  /// sharing the store.
+  #[must_use]
+  pub fn max_attempts(mut self, max_attempts: usize) -> Self {
+    self.max_attempts = max_attempts;
+    self
+  }
+
+  /// Returns a reference to the store.
+  pub fn store(&self) -> &S {
+    &self.store
+  }
+
+  /// Returns the current allocation state.
+  pub async fn state(&self) -> IndexAllocationResult<AllocationState> {
+    self.store.load().await
+  }
+
+  /// Registers the status list `id` holding `capacity` indices, from which indices are allocated once the lists
+  /// registered before it are full.
+  ///
+  /// # Errors
+  ///
+  /// - [`IndexAllocationError::DuplicateList`] if the status list was already registered.
+  /// - [`IndexAllocationError::InvalidCapacity`] if `capacity` is zero.
+  pub async fn add_list(&self, id: Url, capacity: u32) -> IndexAllocationResult<()> {
+    if capacity == 0 {
+      return Err(IndexAllocationError::InvalidCapacity);
+    }
+    self
+      .update(|state| {
+        if state.lists.iter().any(|list| list.id == id) {
+          return Err(IndexAllocationError::DuplicateList(id.clone()));
+        }
+        state.lists.push(StatusListShard {
+          id: id.clone(),
+          capacity,
+          next_index: 0,
+        });
+        Ok(())
+      })
+      .await
+  }
+
+  /// Allocates the next free index.
+  ///
+  /// # Errors
+  ///
+  /// - [`IndexAllocationError::Exhausted`] if all status lists are full.
+  pub async fn allocate(&self) -> IndexAllocationResult<StatusAllocation> {
+    let mut allocations: Vec<StatusAllocation> = self.allocate_many(1).await?;
+    Ok(allocations.remove(0))
+  }
+
+  /// Allocates the next `count` free indices at once, possibly spanning several status lists.
+  ///
+  /// # Errors
+  ///
+  /// - [`IndexAllocationError::Exhausted`] if the status lists do not have `count` free indices left, in which case
+  ///   none is allocated.
+  pub async fn allocate_many(&self, count: usize) -> IndexAllocationResult<Vec<StatusAllocation>> {
+    self
+      .update(|state| {
+        let mut allocations: Vec<StatusAllocation> = Vec::with_capacity(count);
+        for list in state.lists.iter_mut() {
+          while allocations.len() < count && !list.is_full() {
+            allocations.push(StatusAllocation {
+              list: list.id.clone(),
+              index: list.next_index,
+            });
+            list.next_index += 1;
+          }
+        }
+        if allocations.len() < count {
+          return Err(IndexAllocationError::Exhausted);
+        }
+        Ok(allocations)
+      })
+      .await
+  }
+
+  /// Applies `update` to the current state and saves the result, retrying if the state is concurrently modified.
+  async fn update<T>(
+    &self,
+    update: impl Fn(&mut AllocationState) -> IndexAllocationResult<T>,
+  ) -> IndexAllocationResult<T> {
+    for _ in 0..self.max_attempts {
+      let mut state: AllocationState = self.store.load().await?;
+      let expected_version: u64 = state.version;
+      let output: T = update(&mut state)?;
+      state.version = expected_version + 1;
+      if self.store.save(expected_version, &state).await? {
+        return Ok(output);
+      }
+    }
+    Err(IndexAllocationError::Conflict(self.max_attempts))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::atomic::AtomicBool;
+  use std::sync::atomic::Ordering;
+
+  use async_trait::async_trait;
+
+  use super::*;
+  use crate::revocation::allocation::MemoryAllocationStore;
+
+  fn url(id: &str) -> Url {
+    Url::parse(format!("did:example:issuer#{id}")).unwrap()
+  }
+
+  #[tokio::test]
+  async fn allocates_across_lists() {
+    let allocator = IndexAllocator::new(MemoryAllocationStore::new());
+    assert!(matches!(
+      allocator.allocate().await,
+      Err(IndexAllocationError::Exhausted)
+    ));
+
+    allocator.add_list(url("list-1"), 2).await.unwrap();
+    allocator.add_list(url("list-2"), 3).await.unwrap();
+    assert!(matches!(
+      allocator.add_list(url("list-1"), 4).await,
+      Err(IndexAllocationError::DuplicateList(_))
+    ));
+    assert!(matches!(
+      allocator.add_list(url("list-3"), 0).await,
+      Err(IndexAllocationError::InvalidCapacity)
+    ));
+
+    let first = allocator.allocate().await.unwrap();
+    assert_eq!(
+      first,
+      StatusAllocation {
+        list: url("list-1"),
+        index: 0
+      }
+    );
+    let batch = allocator.allocate_many(3).await.unwrap();
+    assert_eq!(
+      batch,
+      [
+        StatusAllocation {
+          list: url("list-1"),
+          index: 1
+        },
+        StatusAllocation {
+          list: url("list-2"),
+          index: 0
+        },
+        StatusAllocation {
+          list: url("list-2"),
+          index: 1
+        },
+      ]
+    );
+
+    // A batch that does not fit is not allocated at all.
+    assert!(matches!(
+      allocator.allocate_many(2).await,
+      Err(IndexAllocationError::Exhausted)
+    ));
+    assert_eq!(
+      allocator.allocate().await.unwrap(),
+      StatusAllocation {
+        list: url("list-2"),
+        index: 2
+      }
+    );
+
+    let state = allocator.state().await.unwrap();
+    assert!(state.lists.iter().all(StatusListShard::is_full));
+    assert_eq!(state.version, 5);
+
+    let status = first.revocation_bitmap_status().unwrap();
+    assert_eq!(status.index().unwrap(), 0);
+  }
+
+  /// A store whose state is modified by another allocator right before the first save.
+  #[derive(Debug, Default)]
+  struct RacingStore {
+    inner: MemoryAllocationStore,
+    raced: AtomicBool,
+  }
+
+  #[async_trait]
+  impl AllocationStore for RacingStore {
+    async fn load(&self) -> IndexAllocationResult<AllocationState> {
+      self.inner.load().await
+    }
+
+    async fn save(&self, expected_version: u64, state: &AllocationState) -> IndexAllocationResult<bool> {
+      if !self.raced.swap(true, Ordering::SeqCst) {
+        IndexAllocator::new(&self.inner).allocate().await?;
+      }
+      self.inner.save(expected_version, state).await
+    }
+  }
+
+  #[async_trait]
+  impl AllocationStore for &MemoryAllocationStore {
+    async fn load(&self) -> IndexAllocationResult<AllocationState> {
+      (**self).load().await
+    }
+
+    async fn save(&self, expected_version: u64, state: &AllocationState) -> IndexAllocationResult<bool> {
+      (**self).save(expected_version, state).await
+    }
+  }
+
+  #[tokio::test]
+  async fn concurrent_modifications_do_not_reuse_indices() {
+    let store = RacingStore::default();
+    IndexAllocator::new(&store.inner)
+      .add_list(url("list-1"), 10)
+      .await
+      .unwrap();
+
+    let allocator = IndexAllocator::new(store);
+    // The racing allocator got index 0 in the meantime.
+    assert_eq!(allocator.allocate().await.unwrap().index, 1);
+    assert_eq!(allocator.allocate().await.unwrap().index, 2);
+
+    let allocator = IndexAllocator::new(RacingStore::default()).max_attempts(1);
+    IndexAllocator::new(&allocator.store().inner)
+      .add_list(url("list-1"), 10)
+      .await
+      .unwrap();
+    assert!(matches!(
+      allocator.allocate().await,
+      Err(IndexAllocationError::Conflict(1))
+    ));
+  }
+}
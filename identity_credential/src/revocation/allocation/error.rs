@@ -0,0 +1,40 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Url;
+
+/// Alias for a `Result` with the error type [`IndexAllocationError`].
+pub type IndexAllocationResult<T> = Result<T, IndexAllocationError>;
+
+/// Errors that can occur when allocating status list indices.
+#[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum IndexAllocationError {
+  /// All registered status lists are full. A new status list must be registered before allocating further indices.
+  #[error("all status lists are full")]
+  Exhausted,
+  /// A status list with the same id was already registered.
+  #[error("status list `{0}` is already registered")]
+  DuplicateList(Url),
+  /// A status list cannot hold a single index.
+  #[error("the capacity of a status list must be positive")]
+  InvalidCapacity,
+  /// The allocation state was modified concurrently during every attempt to update it.
+  #[error("the allocation state was concurrently modified {0} times in a row")]
+  Conflict(usize),
+  /// The id of a status list does not fit the requested credential status.
+  #[error("invalid status list id: {0}")]
+  InvalidListId(&'static str),
+  /// Caused by a failure of the [`AllocationStore`](super::AllocationStore).
+  #[error("the allocation store failed")]
+  Store(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
+}
+
+identity_core::impl_error_code!(IndexAllocationError {
+  Exhausted => 2520,
+  DuplicateList => 2521,
+  InvalidCapacity => 2522,
+  Conflict => 2523,
+  InvalidListId => 2524,
+  Store => 2525,
+});
@@ -0,0 +1,16 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Allocation of the status list indices of newly issued credentials, across several status lists of limited
+//! capacity.
+//!
+//! An [`IndexAllocator`] hands out (status list, index) pairs, filling the registered status lists in turn, and
+//! persists its state through an [`AllocationStore`] so that no index is ever handed out twice.
+
+mod allocator;
+mod error;
+mod store;
+
+pub use self::allocator::*;
+pub use self::error::*;
+pub use self::store::*;
@@ -0,0 +1,90 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use identity_core::common::Url;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::IndexAllocationResult;
+
+/// A status list from which indices are allocated.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusListShard {
+  /// The id of the status list, e.g. the id of a `RevocationBitmap2022` service or the URL of a
+  /// `StatusList2021Credential`.
+  pub id: Url,
+  /// The number of indices the status list can hold, e.g. the length of a `StatusList2021`.
+  pub capacity: u32,
+  /// The next index to allocate; all lower indices have been allocated.
+  pub next_index: u32,
+}
+
+impl StatusListShard {
+  /// Returns the number of indices left to allocate.
+  pub fn remaining(&self) -> u32 {
+    self.capacity.saturating_sub(self.next_index)
+  }
+
+  /// Returns `true` if all indices have been allocated.
+  pub fn is_full(&self) -> bool {
+    self.remaining() == 0
+  }
+}
+
+/// The persisted state of an [`IndexAllocator`](super::IndexAllocator).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AllocationState {
+  /// Incremented on every update of the state, to detect concurrent modifications.
+  pub version: u64,
+  /// The registered status lists, filled in order.
+  pub lists: Vec<StatusListShard>,
+}
+
+/// A persistent storage for the [`AllocationState`] of an [`IndexAllocator`](super::IndexAllocator).
+///
+/// Several allocators may share a store: updates are only saved if the state was not modified since it was loaded,
+/// which guarantees that no index is allocated twice.
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait AllocationStore {
+  /// Loads the current state, or the default state if none was saved yet.
+  async fn load(&self) -> IndexAllocationResult<AllocationState>;
+
+  /// Saves `state` if the current state still has version `expected_version`, atomically.
+  ///
+  /// Returns `false` without saving if the current state has another version.
+  async fn save(&self, expected_version: u64, state: &AllocationState) -> IndexAllocationResult<bool>;
+}
+
+/// An in-memory [`AllocationStore`], e.g. for tests.
+#[derive(Debug, Default)]
+pub struct MemoryAllocationStore(Mutex<AllocationState>);
+
+impl MemoryAllocationStore {
+  /// Creates a new, empty [`MemoryAllocationStore`].
+  pub fn new() -> Self {
+    Self::default()
+  }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl AllocationStore for MemoryAllocationStore {
+  async fn load(&self) -> IndexAllocationResult<AllocationState> {
+    Ok(self.0.lock().expect("mutex should not be poisoned").clone())
+  }
+
+  async fn save(&self, expected_version: u64, state: &AllocationState) -> IndexAllocationResult<bool> {
+    let mut current = self.0.lock().expect("mutex should not be poisoned");
+    if current.version != expected_version {
+      return Ok(false);
+    }
+    *current = state.clone();
+    Ok(true)
+  }
+}
@@ -26,3 +26,11 @@ pub enum RevocationError {
   /// Indicates a failure to construct a URL when attempting to construct a `ServiceEndpoint`.
   UrlConstructionError(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
 }
+
+identity_core::impl_error_code!(RevocationError {
+  BitmapDecodingError => 1540,
+  BitmapEncodingError => 1541,
+  InvalidService => 1542,
+  Base64DecodingError => 1543,
+  UrlConstructionError => 1544,
+});
@@ -4,7 +4,10 @@
 //! Contains the implementations for all the credential revocation methods that can be used with IOTA's Identity
 //! framework.
 
+pub mod allocation;
 mod error;
+#[cfg(feature = "revocation-notification")]
+pub mod notification;
 mod revocation_bitmap_2022;
 #[cfg(feature = "status-list-2021")]
 pub mod status_list_2021;
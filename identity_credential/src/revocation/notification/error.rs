@@ -0,0 +1,30 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::http::HttpError;
+
+use crate::revocation::RevocationError;
+
+/// Alias for a `Result` with the error type [`RevocationNotificationError`].
+pub type RevocationNotificationResult<T> = Result<T, RevocationNotificationError>;
+
+/// Errors that can occur when updating the status of credentials and notifying about it.
+#[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum RevocationNotificationError {
+  /// Caused by a failure to update the revocation bitmap. The DID Document was not modified.
+  #[error("failed to update the revocation bitmap")]
+  Revocation(#[source] RevocationError),
+  /// Caused by a failure to deliver a notification through an HTTP webhook.
+  #[error("failed to deliver the revocation notification")]
+  Webhook(#[source] HttpError),
+  /// Caused by a failure to deliver a notification through a custom sink.
+  #[error("failed to deliver the revocation notification")]
+  Sink(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
+}
+
+identity_core::impl_error_code!(RevocationNotificationError {
+  Revocation => 2530,
+  Webhook => 2531,
+  Sink => 2532,
+});
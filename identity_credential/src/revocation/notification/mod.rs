@@ -0,0 +1,15 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Notifications emitted when an issuer updates the status of its credentials, so that downstream systems can react
+//! without polling the issuer's DID Document.
+
+mod error;
+mod notification;
+mod notifier;
+mod sink;
+
+pub use self::error::*;
+pub use self::notification::*;
+pub use self::notifier::*;
+pub use self::sink::*;
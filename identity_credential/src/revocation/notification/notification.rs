@@ -0,0 +1,64 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Timestamp;
+use identity_did::CoreDID;
+use identity_did::DIDUrl;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The status purpose of the credentials tracked by a
+/// [`RevocationBitmap`](crate::revocation::RevocationBitmap).
+pub const REVOCATION_STATUS_PURPOSE: &str = "revocation";
+
+/// The status update a [`RevocationNotification`] is about.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum RevocationAction {
+  /// The credentials were revoked.
+  Revoked,
+  /// The credentials were unrevoked.
+  Unrevoked,
+}
+
+/// A notification that the status of some credentials was updated by their issuer.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct RevocationNotification {
+  /// The issuer of the credentials.
+  pub issuer: CoreDID,
+  /// The id of the service holding the status of the credentials.
+  pub service_id: DIDUrl,
+  /// The purpose of the status, e.g. [`REVOCATION_STATUS_PURPOSE`].
+  pub status_purpose: String,
+  /// The status update.
+  pub action: RevocationAction,
+  /// The indices of the credentials whose status was updated.
+  pub indices: Vec<u32>,
+  /// The time at which the status was updated.
+  pub timestamp: Timestamp,
+}
+
+impl RevocationNotification {
+  /// Creates a new [`RevocationNotification`] for the credentials with the given `indices` in the revocation bitmap
+  /// identified by `service_id`, timestamped with the current time.
+  pub fn new(service_id: DIDUrl, action: RevocationAction, indices: Vec<u32>) -> Self {
+    Self {
+      issuer: service_id.did().clone(),
+      service_id,
+      status_purpose: REVOCATION_STATUS_PURPOSE.to_owned(),
+      action,
+      indices,
+      timestamp: Timestamp::now_utc(),
+    }
+  }
+
+  /// Sets the purpose of the status.
+  #[must_use]
+  pub fn status_purpose(mut self, value: impl Into<String>) -> Self {
+    self.status_purpose = value.into();
+    self
+  }
+}
@@ -0,0 +1,243 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_did::DIDUrl;
+use identity_document::document::CoreDocument;
+use identity_document::service::Service;
+use identity_document::utils::DIDUrlQuery;
+
+use super::RevocationAction;
+use super::RevocationNotification;
+use super::RevocationNotificationError;
+use super::RevocationNotificationResult;
+use super::RevocationNotificationSink;
+use crate::revocation::RevocationBitmap;
+use crate::revocation::RevocationDocumentExt;
+use crate::revocation::RevocationError;
+
+/// Updates the status of credentials in an issuer's DID Document and emits a [`RevocationNotification`] about every
+/// update through a [`RevocationNotificationSink`].
+///
+/// Notifications are only emitted once the revocation bitmap has been updated, but before the DID Document is
+/// published. Issuers publishing their DID Document on a ledger should therefore only consider a failed delivery a
+/// reason to retry notifying, not to discard the update.
+#[derive(Debug, Clone)]
+pub struct RevocationNotifier<S> {
+  sink: S,
+}
+
+impl<S> RevocationNotifier<S>
+where
+  S: RevocationNotificationSink,
+{
+  /// Creates a new [`RevocationNotifier`] emitting notifications through `sink`.
+  pub fn new(sink: S) -> Self {
+    Self { sink }
+  }
+
+  /// Returns the sink notifications are emitted through.
+  pub fn sink(&self) -> &S {
+    &self.sink
+  }
+
+  /// Revokes the credentials with the given `indices` in the revocation bitmap identified by `service_query`, then
+  /// notifies about it.
+  ///
+  /// # Errors
+  /// Fails with [`RevocationNotificationError::Revocation`] if the revocation bitmap cannot be updated, in which case
+  /// `document` is left unchanged. Any other error means that `document` was updated, but the notification could not
+  /// be delivered.
+  pub async fn revoke_credentials<'query, Q>(
+    &self,
+    document: &mut CoreDocument,
+    service_query: Q,
+    indices: &[u32],
+  ) -> RevocationNotificationResult<RevocationNotification>
+  where
+    Q: Into<DIDUrlQuery<'query>>,
+  {
+    let query: DIDUrlQuery<'query> = service_query.into();
+    document
+      .revoke_credentials(query.clone(), indices)
+      .map_err(RevocationNotificationError::Revocation)?;
+    self.notify_revoked(&*document, query, indices).await
+  }
+
+  /// Unrevokes the credentials with the given `indices` in the revocation bitmap identified by `service_query`, then
+  /// notifies about it.
+  ///
+  /// # Errors
+  /// See [`Self::revoke_credentials`].
+  pub async fn unrevoke_credentials<'query, Q>(
+    &self,
+    document: &mut CoreDocument,
+    service_query: Q,
+    indices: &[u32],
+  ) -> RevocationNotificationResult<RevocationNotification>
+  where
+    Q: Into<DIDUrlQuery<'query>>,
+  {
+    let query: DIDUrlQuery<'query> = service_query.into();
+    document
+      .unrevoke_credentials(query.clone(), indices)
+      .map_err(RevocationNotificationError::Revocation)?;
+    self.notify_unrevoked(&*document, query, indices).await
+  }
+
+  /// Notifies that the credentials with the given `indices` were revoked in the revocation bitmap of `document`
+  /// identified by `service_query`.
+  ///
+  /// This is useful for DID Documents whose revocation bitmap is updated through their own methods, e.g.
+  /// `IotaDocument::revoke_credentials`.
+  pub async fn notify_revoked<'query, DOC, Q>(
+    &self,
+    document: &DOC,
+    service_query: Q,
+    indices: &[u32],
+  ) -> RevocationNotificationResult<RevocationNotification>
+  where
+    DOC: AsRef<CoreDocument> + ?Sized,
+    Q: Into<DIDUrlQuery<'query>>,
+  {
+    self
+      .notify(
+        document.as_ref(),
+        service_query.into(),
+        RevocationAction::Revoked,
+        indices,
+      )
+      .await
+  }
+
+  /// Notifies that the credentials with the given `indices` were unrevoked in the revocation bitmap of `document`
+  /// identified by `service_query`.
+  pub async fn notify_unrevoked<'query, DOC, Q>(
+    &self,
+    document: &DOC,
+    service_query: Q,
+    indices: &[u32],
+  ) -> RevocationNotificationResult<RevocationNotification>
+  where
+    DOC: AsRef<CoreDocument> + ?Sized,
+    Q: Into<DIDUrlQuery<'query>>,
+  {
+    self
+      .notify(
+        document.as_ref(),
+        service_query.into(),
+        RevocationAction::Unrevoked,
+        indices,
+      )
+      .await
+  }
+
+  async fn notify(
+    &self,
+    document: &CoreDocument,
+    query: DIDUrlQuery<'_>,
+    action: RevocationAction,
+    indices: &[u32],
+  ) -> RevocationNotificationResult<RevocationNotification> {
+    let service_id: DIDUrl = document
+      .resolve_service(query)
+      .filter(|service: &&Service| service.type_().contains(RevocationBitmap::TYPE))
+      .map(|service| service.id().clone())
+      .ok_or(RevocationNotificationError::Revocation(
+        RevocationError::InvalidService("revocation bitmap service not found"),
+      ))?;
+
+    let notification = RevocationNotification::new(service_id, action, indices.to_vec());
+    self.sink.notify(&notification).await?;
+
+    Ok(notification)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Mutex;
+
+  use async_trait::async_trait;
+  use identity_core::common::Url;
+  use identity_core::convert::FromJson;
+  use identity_core::http::HttpClient;
+  use identity_core::http::HttpError;
+  use identity_core::http::HttpRequest;
+  use identity_core::http::HttpResponse;
+  use identity_did::DID;
+  use serde_json::json;
+
+  use super::*;
+  use crate::revocation::notification::WebhookSink;
+
+  #[derive(Default)]
+  struct RecordingClient {
+    requests: Mutex<Vec<HttpRequest>>,
+  }
+
+  #[async_trait]
+  impl HttpClient for RecordingClient {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, HttpError> {
+      self.requests.lock().unwrap().push(request);
+      Ok(HttpResponse::new(204, vec![], vec![]))
+    }
+  }
+
+  fn document() -> CoreDocument {
+    let mut document = CoreDocument::from_json(r#"{ "id": "did:example:issuer" }"#).unwrap();
+    let service_id: DIDUrl = document.id().to_url().join("#revocation").unwrap();
+    document
+      .insert_service(RevocationBitmap::new().to_service(service_id).unwrap())
+      .unwrap();
+    document
+  }
+
+  #[tokio::test]
+  async fn revocations_are_posted_to_the_webhook() {
+    let client = RecordingClient::default();
+    let url = Url::parse("https://issuer.example/hooks/revocation").unwrap();
+    let notifier = RevocationNotifier::new(WebhookSink::new(&client, url.clone()).header("Authorization", "Bearer t"));
+    let mut document = document();
+
+    let notification = notifier
+      .revoke_credentials(&mut document, "#revocation", &[3, 5])
+      .await
+      .unwrap();
+    assert!(document
+      .resolve_revocation_bitmap("#revocation".into())
+      .unwrap()
+      .is_revoked(5));
+    assert_eq!(notification.action, RevocationAction::Revoked);
+
+    let requests = client.requests.lock().unwrap();
+    let [request] = requests.as_slice() else {
+      panic!("expected a single request");
+    };
+    assert_eq!(request.url, url);
+    assert!(request
+      .headers
+      .contains(&("Authorization".to_owned(), "Bearer t".to_owned())));
+    let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+    assert_eq!(body["issuer"], json!("did:example:issuer"));
+    assert_eq!(body["serviceId"], json!("did:example:issuer#revocation"));
+    assert_eq!(body["statusPurpose"], json!("revocation"));
+    assert_eq!(body["action"], json!("revoked"));
+    assert_eq!(body["indices"], json!([3, 5]));
+  }
+
+  #[tokio::test]
+  async fn unknown_services_are_not_notified() {
+    let client = RecordingClient::default();
+    let notifier = RevocationNotifier::new(WebhookSink::new(
+      &client,
+      Url::parse("https://issuer.example/hooks/revocation").unwrap(),
+    ));
+    let mut document = document();
+
+    assert!(matches!(
+      notifier.unrevoke_credentials(&mut document, "#unknown", &[1]).await,
+      Err(RevocationNotificationError::Revocation(_))
+    ));
+    assert!(client.requests.lock().unwrap().is_empty());
+  }
+}
@@ -0,0 +1,85 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use identity_core::common::Url;
+use identity_core::convert::ToJson;
+use identity_core::http::HttpClient;
+use identity_core::http::HttpMethod;
+use identity_core::http::HttpRequest;
+
+use super::RevocationNotification;
+use super::RevocationNotificationError;
+use super::RevocationNotificationResult;
+
+/// A destination for [`RevocationNotification`]s.
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait RevocationNotificationSink {
+  /// Delivers `notification`.
+  async fn notify(&self, notification: &RevocationNotification) -> RevocationNotificationResult<()>;
+}
+
+/// A [`RevocationNotificationSink`] that `POST`s every notification as JSON to a webhook URL.
+#[derive(Debug, Clone)]
+pub struct WebhookSink<C> {
+  client: C,
+  url: Url,
+  headers: Vec<(String, String)>,
+}
+
+impl<C> WebhookSink<C>
+where
+  C: HttpClient,
+{
+  /// Creates a new [`WebhookSink`] delivering notifications to `url` through `client`.
+  pub fn new(client: C, url: Url) -> Self {
+    Self {
+      client,
+      url,
+      headers: Vec::new(),
+    }
+  }
+
+  /// Adds a header to every request sent to the webhook, e.g. to authenticate with it.
+  #[must_use]
+  pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+    self.headers.push((name.into(), value.into()));
+    self
+  }
+
+  /// Returns the URL of the webhook.
+  pub fn url(&self) -> &Url {
+    &self.url
+  }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<C> RevocationNotificationSink for WebhookSink<C>
+where
+  C: HttpClient + Sync,
+{
+  async fn notify(&self, notification: &RevocationNotification) -> RevocationNotificationResult<()> {
+    let body: Vec<u8> = notification
+      .to_json_vec()
+      .map_err(|err| RevocationNotificationError::Sink(err.into()))?;
+    let request: HttpRequest = self
+      .headers
+      .iter()
+      .fold(
+        HttpRequest::new(HttpMethod::Post, self.url.clone()),
+        |request, (name, value)| request.header(name.clone(), value.clone()),
+      )
+      .header("Content-Type", "application/json")
+      .body(body);
+
+    self
+      .client
+      .send(request)
+      .await
+      .and_then(|response| response.into_success_body())
+      .map(|_| ())
+      .map_err(RevocationNotificationError::Webhook)
+  }
+}
@@ -3,6 +3,8 @@
 
 use std::borrow::Cow;
 use std::io::Write;
+use std::ops::Bound;
+use std::ops::RangeBounds;
 
 use flate2::write::ZlibDecoder;
 use flate2::write::ZlibEncoder;
@@ -62,6 +64,57 @@ impl RevocationBitmap {
     self.0.is_empty()
   }
 
+  /// Returns the highest revoked index, if any.
+  pub fn highest_index(&self) -> Option<u32> {
+    self.0.max()
+  }
+
+  /// Returns the share of revoked indices among the indices up to and including the
+  /// [highest revoked index](Self::highest_index), from `0.0` for an empty bitmap to `1.0` if all of them are
+  /// revoked.
+  ///
+  /// Assuming indices are allocated sequentially, a low density reveals that only few issued credentials are revoked.
+  pub fn density(&self) -> f64 {
+    match self.highest_index() {
+      Some(highest) => self.len() as f64 / (u64::from(highest) + 1) as f64,
+      None => 0.0,
+    }
+  }
+
+  /// Returns an iterator over the revoked indices within `range`, in ascending order.
+  pub fn revoked_in(&self, range: impl RangeBounds<u32>) -> impl Iterator<Item = u32> + '_ {
+    let start: u64 = match range.start_bound() {
+      Bound::Included(&start) => u64::from(start),
+      Bound::Excluded(&start) => u64::from(start) + 1,
+      Bound::Unbounded => 0,
+    };
+    let end: u64 = match range.end_bound() {
+      Bound::Included(&end) => u64::from(end) + 1,
+      Bound::Excluded(&end) => u64::from(end),
+      Bound::Unbounded => u64::from(u32::MAX) + 1,
+    };
+    self
+      .0
+      .iter()
+      .skip_while(move |&index| u64::from(index) < start)
+      .take_while(move |&index| u64::from(index) < end)
+  }
+
+  /// Returns statistics about the bitmap and its size once embedded in a service, see [`RevocationBitmapStats`].
+  pub fn stats(&self) -> Result<RevocationBitmapStats, RevocationError> {
+    let serialized: Vec<u8> = self.serialize_vec()?;
+    let serialized_size: usize = serialized.len();
+    let compressed: Vec<u8> = Self::compress_zlib(serialized)?;
+    Ok(RevocationBitmapStats {
+      revoked: self.len(),
+      highest_index: self.highest_index(),
+      density: self.density(),
+      serialized_size,
+      compressed_size: compressed.len(),
+      encoded_size: BaseEncoding::encode(&compressed, Base::Base64Url).len(),
+    })
+  }
+
   /// Return a [`Service`] with:
   /// - the service's id set to `service_id`,
   /// - of type `RevocationBitmap2022`,
@@ -171,6 +224,25 @@ impl RevocationBitmap {
   }
 }
 
+/// Statistics about a [`RevocationBitmap`], letting issuers monitor its growth and decide when to spread credentials
+/// over several bitmaps.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct RevocationBitmapStats {
+  /// The number of revoked indices.
+  pub revoked: u64,
+  /// The highest revoked index, if any.
+  pub highest_index: Option<u32>,
+  /// The share of revoked indices, see [`RevocationBitmap::density`].
+  pub density: f64,
+  /// The size, in bytes, of the serialized bitmap.
+  pub serialized_size: usize,
+  /// The size, in bytes, of the serialized bitmap once compressed.
+  pub compressed_size: usize,
+  /// The length of the base64url encoded compressed bitmap, as embedded in the data url of a service endpoint.
+  pub encoded_size: usize,
+}
+
 impl TryFrom<&Service> for RevocationBitmap {
   type Error = RevocationError;
 
@@ -219,6 +291,36 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_analytics() {
+    let mut bitmap = RevocationBitmap::new();
+    assert_eq!(bitmap.highest_index(), None);
+    assert_eq!(bitmap.density(), 0.0);
+
+    for index in [0, 5, 6, 8, 42000] {
+      bitmap.revoke(index);
+    }
+    assert_eq!(bitmap.highest_index(), Some(42000));
+    assert_eq!(bitmap.density(), 5.0 / 42001.0);
+    assert_eq!(bitmap.revoked_in(5..8).collect::<Vec<_>>(), [5, 6]);
+    assert_eq!(bitmap.revoked_in(5..=8).collect::<Vec<_>>(), [5, 6, 8]);
+    assert_eq!(bitmap.revoked_in(7..).collect::<Vec<_>>(), [8, 42000]);
+    assert_eq!(bitmap.revoked_in(..).count(), 5);
+    assert_eq!(bitmap.revoked_in(9..42000).count(), 0);
+
+    let stats = bitmap.stats().unwrap();
+    assert_eq!(stats.revoked, 5);
+    assert_eq!(stats.highest_index, Some(42000));
+    assert_eq!(stats.encoded_size, bitmap.serialize_compressed_base64().unwrap().len());
+    assert!(stats.compressed_size <= stats.encoded_size);
+
+    let mut dense = RevocationBitmap::new();
+    for index in 0..=9 {
+      dense.revoke(index);
+    }
+    assert_eq!(dense.density(), 1.0);
+  }
+
   #[test]
   fn test_revocation_bitmap_test_vector_1() {
     const URL: &str = "data:application/octet-stream;base64,eJyzMmAAAwADKABr";
@@ -412,6 +412,15 @@ impl StatusList2021CredentialBuilder {
   }
 }
 
+identity_core::impl_error_code!(StatusList2021CredentialError {
+  MultipleCredentialSubject => 1570,
+  InvalidProperty => 1571,
+  MissingProperty => 1572,
+  StatusListError => 1573,
+  Unreferenceable => 1574,
+  UnreversibleRevocation => 1575,
+});
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -134,6 +134,12 @@ impl StatusList2021 {
   }
 }
 
+identity_core::impl_error_code!(StatusListError {
+  IndexOutOfBounds => 1560,
+  InvalidEncoding => 1561,
+  InvalidListSize => 1562,
+});
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 #![allow(clippy::vec_init_then_push)]
+use std::collections::BTreeSet;
 use std::sync::LazyLock;
 
 use identity_core::common::StringOrUrl;
@@ -18,9 +19,11 @@ use serde::Serialize;
 use serde_json::json;
 use serde_json::Value;
 
+use crate::credential::Confirmation;
 use crate::credential::Credential;
 use crate::credential::CredentialJwtClaims;
 
+use super::expand_pointer;
 use super::Error;
 use super::Result;
 use super::SdJwtVc;
@@ -49,6 +52,11 @@ macro_rules! claim_to_key_value_pair {
 #[derive(Debug)]
 pub struct SdJwtVcBuilder<H = Sha256Hasher> {
   inner_builder: SdJwtBuilder<H>,
+  // Snapshot of the object as originally given, used to expand JSON pointer patterns.
+  object: Value,
+  decoys_per_object: usize,
+  // Objects and arrays that already received their decoys.
+  decoyed: BTreeSet<String>,
   header: JsonObject,
   iss: Option<Url>,
   nbf: Option<i64>,
@@ -63,6 +71,9 @@ impl Default for SdJwtVcBuilder {
   fn default() -> Self {
     Self {
       inner_builder: SdJwtBuilder::<Sha256Hasher>::new(json!({})).unwrap(),
+      object: json!({}),
+      decoys_per_object: 0,
+      decoyed: BTreeSet::new(),
       header: DEFAULT_HEADER.clone(),
       iss: None,
       nbf: None,
@@ -79,12 +90,7 @@ impl SdJwtVcBuilder {
   /// Creates a new [`SdJwtVcBuilder`] using `object` JSON representation and default
   /// `sha-256` hasher.
   pub fn new<T: Serialize>(object: T) -> Result<Self> {
-    let inner_builder = SdJwtBuilder::<Sha256Hasher>::new(object)?;
-    Ok(Self {
-      header: DEFAULT_HEADER.clone(),
-      inner_builder,
-      ..Default::default()
-    })
+    Self::new_with_hasher(object, Sha256Hasher)
   }
 }
 
@@ -92,9 +98,13 @@ impl<H: Hasher> SdJwtVcBuilder<H> {
   /// Creates a new [`SdJwtVcBuilder`] using `object` JSON representation and a given
   /// hasher `hasher`.
   pub fn new_with_hasher<T: Serialize>(object: T, hasher: H) -> Result<Self> {
-    let inner_builder = SdJwtBuilder::new_with_hasher(object, hasher)?;
+    let inner_builder = SdJwtBuilder::new_with_hasher(&object, hasher)?;
+    let object = serde_json::to_value(object).expect("object has already been serialized by the inner builder");
     Ok(Self {
       inner_builder,
+      object,
+      decoys_per_object: 0,
+      decoyed: BTreeSet::new(),
       header: DEFAULT_HEADER.clone(),
       iss: None,
       nbf: None,
@@ -150,6 +160,41 @@ impl<H: Hasher> SdJwtVcBuilder<H> {
   /// ```
   pub fn make_concealable(mut self, path: &str) -> Result<Self> {
     self.inner_builder = self.inner_builder.make_concealable(path)?;
+    if self.decoys_per_object > 0 {
+      let parent = &path[..path.rfind('/').unwrap_or_default()];
+      if self.decoyed.insert(parent.to_owned()) {
+        self.inner_builder = self.inner_builder.add_decoys(parent, self.decoys_per_object)?;
+      }
+    }
+    Ok(self)
+  }
+
+  /// Substitutes every value matched by the JSON pointer pattern `pattern` with the digest of its disclosure.
+  ///
+  /// ## Notes
+  /// - `pattern` uses the syntax of [JSON pointer](https://datatracker.ietf.org/doc/html/rfc6901), where a
+  ///   [`*`](super::ARRAY_WILDCARD) segment matches every element of an array. See [`expand_pointer`].
+  /// - Nested values must be made concealable before their parents, e.g. `/matrix/*/*` before `/matrix/*`.
+  ///
+  /// ## Example
+  /// ```rust
+  /// use serde_json::json;
+  /// use identity_credential::sd_jwt_vc::SdJwtVcBuilder;
+  ///
+  /// let obj = json!({
+  ///   "nationalities": ["DE", "FR"],
+  ///   "matrix": [[1, 2], [3]]
+  /// });
+  /// let builder = SdJwtVcBuilder::new(obj)
+  ///   .unwrap()
+  ///   .make_all_concealable("/nationalities/*").unwrap() // conceals "DE" and "FR"
+  ///   .make_all_concealable("/matrix/*/*").unwrap() // conceals 1, 2 and 3
+  ///   .make_all_concealable("/matrix/*").unwrap(); // conceals [1, 2] and [3]
+  /// ```
+  pub fn make_all_concealable(mut self, pattern: &str) -> Result<Self> {
+    for path in expand_pointer(&self.object, pattern)? {
+      self = self.make_concealable(&path)?;
+    }
     Ok(self)
   }
 
@@ -186,6 +231,19 @@ impl<H: Hasher> SdJwtVcBuilder<H> {
     Ok(self)
   }
 
+  /// Adds `decoys` decoy digests to every object or array containing a concealable value.
+  ///
+  /// Decoys hide the actual number of concealable claims, making tokens less linkable to each other.
+  ///
+  /// ## Notes
+  /// - Decoys are added when the first value of an object or array is made concealable, therefore this method must be
+  ///   called before [`Self::make_concealable`] and [`Self::make_all_concealable`].
+  /// - Use [`Self::add_decoys`] to control the number of decoys of a specific object or array.
+  pub fn decoys_per_object(mut self, decoys: usize) -> Self {
+    self.decoys_per_object = decoys;
+    self
+  }
+
   /// Require a proof of possession of a given key from the holder.
   ///
   /// This operation adds a JWT confirmation (`cnf`) claim as specified in
@@ -195,6 +253,21 @@ impl<H: Hasher> SdJwtVcBuilder<H> {
     self
   }
 
+  /// Binds the credential to the holder key referenced by `confirmation`, requiring the holder to prove its
+  /// possession through a key binding JWT. See [`Self::require_key_binding`].
+  pub fn holder_confirmation(self, confirmation: Confirmation) -> Self {
+    let key_bind = match confirmation {
+      Confirmation::Jwk(jwk) => {
+        let Ok(Value::Object(jwk)) = serde_json::to_value(jwk) else {
+          unreachable!("a JWK serializes to a JSON object");
+        };
+        RequiredKeyBinding::Jwk(jwk)
+      }
+      Confirmation::Kid(kid) => RequiredKeyBinding::Kid(kid),
+    };
+    self.require_key_binding(key_bind)
+  }
+
   /// Inserts an `iss` claim. See [`super::SdJwtVcClaims::iss`].
   pub fn iss(mut self, issuer: Url) -> Self {
     self.iss = Some(issuer);
@@ -246,6 +319,9 @@ impl<H: Hasher> SdJwtVcBuilder<H> {
     let Self {
       inner_builder,
       mut header,
+      object: _,
+      decoys_per_object: _,
+      decoyed: _,
       iss,
       nbf,
       exp,
@@ -284,6 +360,8 @@ mod tests {
   use crate::credential::CredentialBuilder;
   use crate::credential::Subject;
   use crate::sd_jwt_vc::tests::TestSigner;
+  use crate::sd_jwt_vc::Sha384Hasher;
+  use crate::sd_jwt_vc::SHA384_ALG_NAME;
 
   #[tokio::test]
   async fn building_valid_vc_works() -> anyhow::Result<()> {
@@ -392,4 +470,43 @@ mod tests {
 
     Ok(())
   }
+
+  #[tokio::test]
+  async fn decoys_and_sd_alg_are_configurable() -> anyhow::Result<()> {
+    let credential = json!({
+      "name": "John Doe",
+      "birthdate": "1970-01-01",
+      "nationalities": ["DE", "FR"]
+    });
+
+    let sd_jwt_vc = SdJwtVcBuilder::new_with_hasher(credential, Sha384Hasher)?
+      .vct(Url::parse("https://example.com/types/0")?)
+      .iss("https://example.com/".parse()?)
+      .decoys_per_object(3)
+      .make_concealable("/birthdate")?
+      .make_all_concealable("/nationalities/*")?
+      .finish(&TestSigner, "HS256")
+      .await?;
+
+    assert_eq!(sd_jwt_vc.claims()._sd_alg.as_deref(), Some(SHA384_ALG_NAME));
+    // One disclosure and three decoys at the top level, two disclosures and three decoys in the array.
+    assert_eq!(sd_jwt_vc.claims()._sd.len(), 4);
+    assert_eq!(
+      sd_jwt_vc
+        .claims()
+        .get("nationalities")
+        .unwrap()
+        .as_array()
+        .unwrap()
+        .len(),
+      5
+    );
+
+    let disclosed = sd_jwt_vc.clone().into_disclosed_object(&Sha384Hasher)?;
+    assert_eq!(disclosed["birthdate"], json!("1970-01-01"));
+    assert_eq!(disclosed["nationalities"], json!(["DE", "FR"]));
+    assert!(sd_jwt_vc.into_presentation(&Sha256Hasher).is_err());
+
+    Ok(())
+  }
 }
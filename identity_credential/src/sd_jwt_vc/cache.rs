@@ -0,0 +1,516 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Verifier-side caching of the remote resources needed to validate SD-JWT VCs, such as type metadata, JSON schemas
+//! and JSON-LD contexts.
+//!
+//! A [`CachingResolver`] wraps the [`Resolver`] used for validation and stores every fetched resource in a
+//! [`ResourceCache`], so that validation keeps working offline. Resources can be pinned to an [`IntegrityMetadata`]
+//! string, in which case any resolved or cached content not matching it is rejected: a compromised host cannot swap
+//! a schema or context for another one.
+
+use std::collections::HashMap;
+#[cfg(all(feature = "resource-cache-file", not(target_arch = "wasm32")))]
+use std::path::PathBuf;
+#[cfg(all(feature = "resource-cache-sqlite", not(target_arch = "wasm32")))]
+use std::sync::Arc;
+#[cfg(all(feature = "resource-cache-sqlite", not(target_arch = "wasm32")))]
+use std::sync::Mutex;
+use std::sync::PoisonError;
+use std::sync::RwLock;
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use identity_core::common::Url;
+use serde_json::Value;
+
+use super::metadata::IntegrityMetadata;
+use super::metadata::TypeMetadata;
+use super::metadata::TypeSchema;
+use super::resolver;
+use super::resolver::Error as ResolverErr;
+use super::Resolver;
+
+/// A persistent store for resolved resources, keyed by the URL or identifier they were resolved from.
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait ResourceCache {
+  /// Returns the content stored for `key`, if any.
+  async fn get(&self, key: &str) -> resolver::Result<Option<Vec<u8>>>;
+  /// Stores `content` for `key`, replacing any previous content.
+  async fn put(&self, key: &str, content: &[u8]) -> resolver::Result<()>;
+  /// Removes the content stored for `key`, if any.
+  async fn remove(&self, key: &str) -> resolver::Result<()>;
+}
+
+/// An in-memory [`ResourceCache`].
+#[derive(Debug, Default)]
+pub struct MemoryResourceCache(RwLock<HashMap<String, Vec<u8>>>);
+
+impl MemoryResourceCache {
+  /// Creates an empty [`MemoryResourceCache`].
+  pub fn new() -> Self {
+    Self::default()
+  }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl ResourceCache for MemoryResourceCache {
+  async fn get(&self, key: &str) -> resolver::Result<Option<Vec<u8>>> {
+    Ok(self.0.read().unwrap_or_else(PoisonError::into_inner).get(key).cloned())
+  }
+
+  async fn put(&self, key: &str, content: &[u8]) -> resolver::Result<()> {
+    self
+      .0
+      .write()
+      .unwrap_or_else(PoisonError::into_inner)
+      .insert(key.to_owned(), content.to_vec());
+    Ok(())
+  }
+
+  async fn remove(&self, key: &str) -> resolver::Result<()> {
+    self.0.write().unwrap_or_else(PoisonError::into_inner).remove(key);
+    Ok(())
+  }
+}
+
+/// A [`ResourceCache`] storing each resource in its own file of a directory.
+///
+/// Files are named after the SHA-256 digest of their key and written atomically through a uniquely named temporary
+/// file, so the cache can be shared by several processes. File system operations run on Tokio's blocking thread pool.
+#[cfg(all(feature = "resource-cache-file", not(target_arch = "wasm32")))]
+#[derive(Debug, Clone)]
+pub struct FileResourceCache {
+  dir: PathBuf,
+}
+
+#[cfg(all(feature = "resource-cache-file", not(target_arch = "wasm32")))]
+impl FileResourceCache {
+  /// Creates a [`FileResourceCache`] storing its files in `dir`, which is created if it does not exist.
+  pub fn new(dir: impl Into<PathBuf>) -> resolver::Result<Self> {
+    let dir: PathBuf = dir.into();
+    std::fs::create_dir_all(&dir).map_err(|err| ResolverErr::Generic(err.into()))?;
+    Ok(Self { dir })
+  }
+
+  /// Returns the directory holding the cached resources.
+  pub fn dir(&self) -> &std::path::Path {
+    &self.dir
+  }
+
+  fn path(&self, key: &str) -> PathBuf {
+    let digest: IntegrityMetadata = IntegrityMetadata::sha256(key.as_bytes());
+    let name: String = digest
+      .digest()
+      .trim_end_matches('=')
+      .replace('+', "-")
+      .replace('/', "_");
+    self.dir.join(name)
+  }
+}
+
+#[cfg(all(feature = "resource-cache-file", not(target_arch = "wasm32")))]
+#[async_trait]
+impl ResourceCache for FileResourceCache {
+  async fn get(&self, key: &str) -> resolver::Result<Option<Vec<u8>>> {
+    let path: PathBuf = self.path(key);
+    blocking(move || match std::fs::read(path) {
+      Ok(content) => Ok(Some(content)),
+      Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+      Err(err) => Err(err),
+    })
+    .await
+  }
+
+  async fn put(&self, key: &str, content: &[u8]) -> resolver::Result<()> {
+    use std::io::Write;
+
+    let path: PathBuf = self.path(key);
+    let dir: PathBuf = self.dir.clone();
+    let content: Vec<u8> = content.to_vec();
+    blocking(move || -> std::io::Result<()> {
+      let mut file = tempfile::NamedTempFile::new_in(dir)?;
+      file.write_all(&content)?;
+      file.persist(path)?;
+      Ok(())
+    })
+    .await
+  }
+
+  async fn remove(&self, key: &str) -> resolver::Result<()> {
+    let path: PathBuf = self.path(key);
+    blocking(move || match std::fs::remove_file(path) {
+      Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(err),
+      _ => Ok(()),
+    })
+    .await
+  }
+}
+
+/// A [`ResourceCache`] storing resources in a table of a SQLite database.
+///
+/// Database operations run on Tokio's blocking thread pool.
+#[cfg(all(feature = "resource-cache-sqlite", not(target_arch = "wasm32")))]
+#[derive(Debug, Clone)]
+pub struct SqliteResourceCache {
+  connection: Arc<Mutex<rusqlite::Connection>>,
+}
+
+#[cfg(all(feature = "resource-cache-sqlite", not(target_arch = "wasm32")))]
+impl SqliteResourceCache {
+  /// Opens the SQLite database at `path`, creating it and its resource table if they do not exist.
+  pub fn open(path: impl AsRef<std::path::Path>) -> resolver::Result<Self> {
+    let connection = rusqlite::Connection::open(path).map_err(|err| ResolverErr::Generic(err.into()))?;
+    Self::from_connection(connection)
+  }
+
+  /// Creates a [`SqliteResourceCache`] backed by a new in-memory SQLite database.
+  pub fn in_memory() -> resolver::Result<Self> {
+    let connection = rusqlite::Connection::open_in_memory().map_err(|err| ResolverErr::Generic(err.into()))?;
+    Self::from_connection(connection)
+  }
+
+  /// Creates a [`SqliteResourceCache`] over an existing `connection`, creating the resource table if it does not
+  /// exist.
+  pub fn from_connection(connection: rusqlite::Connection) -> resolver::Result<Self> {
+    connection
+      .execute(
+        "CREATE TABLE IF NOT EXISTS identity_resources (key TEXT PRIMARY KEY NOT NULL, content BLOB NOT NULL)",
+        [],
+      )
+      .map_err(|err| ResolverErr::Generic(err.into()))?;
+    Ok(Self {
+      connection: Arc::new(Mutex::new(connection)),
+    })
+  }
+
+  async fn with_connection<T, F>(&self, f: F) -> resolver::Result<T>
+  where
+    T: Send + 'static,
+    F: FnOnce(&rusqlite::Connection) -> rusqlite::Result<T> + Send + 'static,
+  {
+    let connection: Arc<Mutex<rusqlite::Connection>> = Arc::clone(&self.connection);
+    blocking(move || f(&connection.lock().unwrap_or_else(PoisonError::into_inner))).await
+  }
+}
+
+#[cfg(all(feature = "resource-cache-sqlite", not(target_arch = "wasm32")))]
+#[async_trait]
+impl ResourceCache for SqliteResourceCache {
+  async fn get(&self, key: &str) -> resolver::Result<Option<Vec<u8>>> {
+    use rusqlite::OptionalExtension;
+
+    let key: String = key.to_owned();
+    self
+      .with_connection(move |connection| {
+        connection
+          .query_row("SELECT content FROM identity_resources WHERE key = ?1", [key], |row| {
+            row.get(0)
+          })
+          .optional()
+      })
+      .await
+  }
+
+  async fn put(&self, key: &str, content: &[u8]) -> resolver::Result<()> {
+    let key: String = key.to_owned();
+    let content: Vec<u8> = content.to_vec();
+    self
+      .with_connection(move |connection| {
+        connection
+          .execute(
+            "INSERT INTO identity_resources (key, content) VALUES (?1, ?2) \
+             ON CONFLICT (key) DO UPDATE SET content = excluded.content",
+            rusqlite::params![key, content],
+          )
+          .map(|_| ())
+      })
+      .await
+  }
+
+  async fn remove(&self, key: &str) -> resolver::Result<()> {
+    let key: String = key.to_owned();
+    self
+      .with_connection(move |connection| {
+        connection
+          .execute("DELETE FROM identity_resources WHERE key = ?1", [key])
+          .map(|_| ())
+      })
+      .await
+  }
+}
+
+/// Runs the blocking operation `f` on Tokio's blocking thread pool.
+#[cfg(all(
+  any(feature = "resource-cache-file", feature = "resource-cache-sqlite"),
+  not(target_arch = "wasm32")
+))]
+async fn blocking<T, E, F>(f: F) -> resolver::Result<T>
+where
+  T: Send + 'static,
+  E: Into<anyhow::Error>,
+  F: FnOnce() -> Result<T, E> + Send + 'static,
+  Result<T, E>: Send + 'static,
+{
+  tokio::task::spawn_blocking(f)
+    .await
+    .map_err(|err| ResolverErr::Generic(err.into()))?
+    .map_err(|err| ResolverErr::Generic(err.into()))
+}
+
+/// A [`Resolver`] serving resources from a [`ResourceCache`], falling back to another resolver for the resources that
+/// are not cached yet.
+///
+/// Resources resolved through the fallback resolver are cached. Resources pinned with [`CachingResolver::pin`] are
+/// checked against their integrity metadata both when resolved and when read from the cache: cached content that
+/// does not match is resolved again, and resolved content that does not match is rejected without being cached.
+#[derive(Debug)]
+pub struct CachingResolver<R, C> {
+  resolver: R,
+  cache: C,
+  pins: HashMap<String, IntegrityMetadata>,
+  offline: bool,
+}
+
+impl<R, C> CachingResolver<R, C> {
+  /// Creates a new [`CachingResolver`] caching the resources resolved through `resolver` in `cache`.
+  pub fn new(resolver: R, cache: C) -> Self {
+    Self {
+      resolver,
+      cache,
+      pins: HashMap::new(),
+      offline: false,
+    }
+  }
+
+  /// Pins the resource identified by `key` to `integrity`.
+  #[must_use]
+  pub fn pin(mut self, key: impl Into<String>, integrity: IntegrityMetadata) -> Self {
+    self.pins.insert(key.into(), integrity);
+    self
+  }
+
+  /// Pins the JSON schema and extended type referenced by `type_metadata` to their integrity metadata, if any.
+  #[must_use]
+  pub fn pin_type_metadata(mut self, type_metadata: &TypeMetadata) -> Self {
+    if let (Some(extends), Some(integrity)) = (&type_metadata.extends, &type_metadata.extends_integrity) {
+      self.pins.insert(extends.to_string(), integrity.clone());
+    }
+    if let Some(TypeSchema::Uri {
+      schema_uri,
+      schema_uri_integrity: Some(integrity),
+    }) = &type_metadata.schema
+    {
+      self.pins.insert(schema_uri.to_string(), integrity.clone());
+    }
+    self
+  }
+
+  /// Sets whether resources are only served from the cache, without ever resolving them.
+  #[must_use]
+  pub fn offline(mut self, offline: bool) -> Self {
+    self.offline = offline;
+    self
+  }
+
+  /// Returns a reference to the cache.
+  pub fn cache(&self) -> &C {
+    &self.cache
+  }
+
+  /// Returns a reference to the fallback resolver.
+  pub fn resolver(&self) -> &R {
+    &self.resolver
+  }
+}
+
+impl<R, C> CachingResolver<R, C>
+where
+  C: ResourceCache + Sync,
+{
+  async fn resolve_bytes<I>(&self, input: &I) -> resolver::Result<Vec<u8>>
+  where
+    I: ToString + Sync,
+    R: Resolver<I, Vec<u8>> + Sync,
+  {
+    let key: String = input.to_string();
+    let pin: Option<&IntegrityMetadata> = self.pins.get(&key);
+
+    if let Some(content) = self.cache.get(&key).await? {
+      if pin.map_or(true, |pin| pin.matches(&content)) {
+        return Ok(content);
+      }
+      // The cache was tampered with, or the pin changed since the resource was cached.
+      self.cache.remove(&key).await?;
+    }
+    if self.offline {
+      return Err(ResolverErr::NotFound(key));
+    }
+
+    let content: Vec<u8> = self.resolver.resolve(input).await?;
+    if let Some(pin) = pin {
+      if !pin.matches(&content) {
+        return Err(ResolverErr::Generic(anyhow!(
+          "the content of \"{key}\" does not match the pinned integrity metadata \"{pin}\""
+        )));
+      }
+    }
+    self.cache.put(&key, &content).await?;
+
+    Ok(content)
+  }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<R, C> Resolver<Url, Vec<u8>> for CachingResolver<R, C>
+where
+  R: Resolver<Url, Vec<u8>> + Sync,
+  C: ResourceCache + Sync,
+{
+  async fn resolve(&self, input: &Url) -> resolver::Result<Vec<u8>> {
+    self.resolve_bytes(input).await
+  }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<R, C> Resolver<String, Vec<u8>> for CachingResolver<R, C>
+where
+  R: Resolver<String, Vec<u8>> + Sync,
+  C: ResourceCache + Sync,
+{
+  async fn resolve(&self, input: &String) -> resolver::Result<Vec<u8>> {
+    self.resolve_bytes(input).await
+  }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl<R, C> Resolver<Url, Value> for CachingResolver<R, C>
+where
+  R: Resolver<Url, Vec<u8>> + Sync,
+  C: ResourceCache + Sync,
+{
+  async fn resolve(&self, input: &Url) -> resolver::Result<Value> {
+    let content: Vec<u8> = self.resolve_bytes(input).await?;
+    serde_json::from_slice(&content).map_err(|err| ResolverErr::ParsingFailure(err.into()))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::atomic::AtomicUsize;
+  use std::sync::atomic::Ordering;
+
+  use super::*;
+
+  const SCHEMA_URL: &str = "https://example.com/schemas/degree.json";
+
+  /// A resolver serving a single schema, counting how often it was queried.
+  #[derive(Default)]
+  struct CountingResolver {
+    content: RwLock<Vec<u8>>,
+    calls: AtomicUsize,
+  }
+
+  #[async_trait]
+  impl Resolver<Url, Vec<u8>> for CountingResolver {
+    async fn resolve(&self, input: &Url) -> resolver::Result<Vec<u8>> {
+      self.calls.fetch_add(1, Ordering::SeqCst);
+      if input.as_str() == SCHEMA_URL {
+        Ok(self.content.read().unwrap().clone())
+      } else {
+        Err(ResolverErr::NotFound(input.to_string()))
+      }
+    }
+  }
+
+  fn resolver(content: &[u8]) -> CountingResolver {
+    CountingResolver {
+      content: RwLock::new(content.to_vec()),
+      calls: AtomicUsize::new(0),
+    }
+  }
+
+  #[tokio::test]
+  async fn resources_are_cached() {
+    let url = Url::parse(SCHEMA_URL).unwrap();
+    let resolver = CachingResolver::new(resolver(br#"{"type":"object"}"#), MemoryResourceCache::new());
+
+    let schema: Value = resolver.resolve(&url).await.unwrap();
+    assert_eq!(schema, serde_json::json!({ "type": "object" }));
+    let _: Vec<u8> = resolver.resolve(&url).await.unwrap();
+    assert_eq!(resolver.resolver().calls.load(Ordering::SeqCst), 1);
+
+    // Offline, only cached resources are served.
+    let resolver = resolver.offline(true);
+    let _: Vec<u8> = resolver.resolve(&url).await.unwrap();
+    let missing: resolver::Result<Vec<u8>> = resolver
+      .resolve(&Url::parse("https://example.com/schemas/other.json").unwrap())
+      .await;
+    assert!(matches!(missing, Err(ResolverErr::NotFound(_))));
+    assert_eq!(resolver.resolver().calls.load(Ordering::SeqCst), 1);
+  }
+
+  #[tokio::test]
+  async fn pinned_resources_are_checked() {
+    let url = Url::parse(SCHEMA_URL).unwrap();
+    let genuine: &[u8] = br#"{"type":"object"}"#;
+    let resolver = CachingResolver::new(resolver(b"{}"), MemoryResourceCache::new())
+      .pin(SCHEMA_URL, IntegrityMetadata::sha384(genuine));
+
+    // A hijacked host serving different content is rejected, and its content is not cached.
+    let hijacked: resolver::Result<Vec<u8>> = resolver.resolve(&url).await;
+    assert!(matches!(hijacked, Err(ResolverErr::Generic(_))));
+    assert_eq!(resolver.cache().get(SCHEMA_URL).await.unwrap(), None);
+
+    // Tampered cache entries are resolved again.
+    *resolver.resolver().content.write().unwrap() = genuine.to_vec();
+    resolver.cache().put(SCHEMA_URL, b"{}").await.unwrap();
+    let content: Vec<u8> = resolver.resolve(&url).await.unwrap();
+    assert_eq!(content, genuine);
+    assert_eq!(
+      resolver.cache().get(SCHEMA_URL).await.unwrap().as_deref(),
+      Some(genuine)
+    );
+  }
+
+  #[cfg(all(feature = "resource-cache-file", not(target_arch = "wasm32")))]
+  #[tokio::test]
+  async fn file_cache() {
+    let dir = std::env::temp_dir().join(format!("identity-resource-cache-{}", std::process::id()));
+    let cache = FileResourceCache::new(&dir).unwrap();
+
+    assert_eq!(cache.get(SCHEMA_URL).await.unwrap(), None);
+    cache.put(SCHEMA_URL, b"{}").await.unwrap();
+    assert_eq!(cache.get(SCHEMA_URL).await.unwrap().as_deref(), Some(b"{}".as_slice()));
+    // Another cache over the same directory sees the same resources.
+    let other = FileResourceCache::new(&dir).unwrap();
+    assert_eq!(other.get(SCHEMA_URL).await.unwrap().as_deref(), Some(b"{}".as_slice()));
+    cache.remove(SCHEMA_URL).await.unwrap();
+    cache.remove(SCHEMA_URL).await.unwrap();
+    assert_eq!(other.get(SCHEMA_URL).await.unwrap(), None);
+
+    std::fs::remove_dir_all(dir).unwrap();
+  }
+
+  #[cfg(all(feature = "resource-cache-sqlite", not(target_arch = "wasm32")))]
+  #[tokio::test]
+  async fn sqlite_cache() {
+    let cache = SqliteResourceCache::in_memory().unwrap();
+
+    assert_eq!(cache.get(SCHEMA_URL).await.unwrap(), None);
+    cache.put(SCHEMA_URL, b"{}").await.unwrap();
+    cache.put(SCHEMA_URL, br#"{"type":"object"}"#).await.unwrap();
+    assert_eq!(
+      cache.get(SCHEMA_URL).await.unwrap().as_deref(),
+      Some(br#"{"type":"object"}"#.as_slice())
+    );
+    cache.remove(SCHEMA_URL).await.unwrap();
+    cache.remove(SCHEMA_URL).await.unwrap();
+    assert_eq!(cache.get(SCHEMA_URL).await.unwrap(), None);
+  }
+}
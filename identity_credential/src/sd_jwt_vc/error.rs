@@ -51,6 +51,14 @@ pub enum Error {
   /// SD-JWT VC signature verification failed.
   #[error("verification failed: {0}")]
   Verification(#[source] anyhow::Error),
+  /// A JSON pointer, or JSON pointer pattern, doesn't reference any value.
+  #[error("invalid JSON pointer \"{pointer}\": {reason}")]
+  InvalidPointer {
+    /// The invalid pointer.
+    pointer: String,
+    /// Why the pointer is invalid.
+    reason: String,
+  },
 }
 
 /// Either a value of type `T` or an [`Error`].
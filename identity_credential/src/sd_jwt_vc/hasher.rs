@@ -0,0 +1,79 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::hashes::sha::SHA384;
+use crypto::hashes::sha::SHA384_LEN;
+use crypto::hashes::sha::SHA512;
+use crypto::hashes::sha::SHA512_LEN;
+use sd_jwt::Hasher;
+use sd_jwt::Sha256Hasher;
+use sd_jwt::SHA_ALG_NAME;
+
+/// Name of the SHA-384 `_sd_alg`, as registered in the IANA "Named Information Hash Algorithm" registry.
+pub const SHA384_ALG_NAME: &str = "sha-384";
+/// Name of the SHA-512 `_sd_alg`, as registered in the IANA "Named Information Hash Algorithm" registry.
+pub const SHA512_ALG_NAME: &str = "sha-512";
+
+/// An implementation of [`Hasher`] that uses the `sha-384` hash function.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Sha384Hasher;
+
+impl Hasher for Sha384Hasher {
+  fn digest(&self, input: &[u8]) -> Vec<u8> {
+    let mut digest = [0; SHA384_LEN];
+    SHA384(input, &mut digest);
+    digest.to_vec()
+  }
+
+  fn alg_name(&self) -> &'static str {
+    SHA384_ALG_NAME
+  }
+}
+
+/// An implementation of [`Hasher`] that uses the `sha-512` hash function.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Sha512Hasher;
+
+impl Hasher for Sha512Hasher {
+  fn digest(&self, input: &[u8]) -> Vec<u8> {
+    let mut digest = [0; SHA512_LEN];
+    SHA512(input, &mut digest);
+    digest.to_vec()
+  }
+
+  fn alg_name(&self) -> &'static str {
+    SHA512_ALG_NAME
+  }
+}
+
+/// Returns a [`Hasher`] for the `_sd_alg` named `alg`, if supported.
+///
+/// Supported algorithms are `sha-256`, `sha-384` and `sha-512`.
+pub fn hasher_for_alg(alg: &str) -> Option<Box<dyn Hasher>> {
+  match alg {
+    SHA_ALG_NAME => Some(Box::new(Sha256Hasher)),
+    SHA384_ALG_NAME => Some(Box::new(Sha384Hasher)),
+    SHA512_ALG_NAME => Some(Box::new(Sha512Hasher)),
+    _ => None,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn hashers_produce_digests_of_the_expected_length() {
+    for (alg, len) in [(SHA_ALG_NAME, 32), (SHA384_ALG_NAME, 48), (SHA512_ALG_NAME, 64)] {
+      let hasher = hasher_for_alg(alg).unwrap();
+      assert_eq!(hasher.alg_name(), alg);
+      assert_eq!(
+        hasher
+          .digest(b"WyI2cU1RdlJMNWhhaiIsICJmYW1pbHlfbmFtZSIsICJNw7ZiaXVzIl0")
+          .len(),
+        len
+      );
+    }
+    assert!(hasher_for_alg("md5").is_none());
+  }
+}
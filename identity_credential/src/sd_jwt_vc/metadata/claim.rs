@@ -12,6 +12,7 @@ use serde::Serialize;
 use serde::Serializer;
 use serde_json::Value;
 
+use crate::credential::lookup_locale;
 use crate::sd_jwt_vc::Error;
 
 /// Information about a particular claim for displaying and validation purposes.
@@ -35,6 +36,16 @@ pub struct ClaimMetadata {
 }
 
 impl ClaimMetadata {
+  /// Returns the [`ClaimDisplay`] whose locale best matches `locale`, if any.
+  ///
+  /// See [`lookup_locale`](crate::credential::lookup_locale) for how the locale is selected.
+  pub fn display_for(&self, locale: &str) -> Option<&ClaimDisplay> {
+    lookup_locale(
+      self.display.iter().map(|display| (display.locale.as_str(), display)),
+      locale,
+    )
+  }
+
   /// Checks whether `value` is compliant with the disclosability policy imposed by this [`ClaimMetadata`].
   pub fn check_value_disclosability(&self, value: &Value) -> Result<(), Error> {
     if self.sd.unwrap_or_default() == ClaimDisclosability::Allowed {
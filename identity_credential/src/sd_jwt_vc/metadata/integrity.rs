@@ -5,6 +5,12 @@ use std::fmt::Display;
 use std::str::FromStr;
 
 use anyhow::anyhow;
+use crypto::hashes::sha::SHA256;
+use crypto::hashes::sha::SHA256_LEN;
+use crypto::hashes::sha::SHA384;
+use crypto::hashes::sha::SHA384_LEN;
+use crypto::hashes::sha::SHA512;
+use crypto::hashes::sha::SHA512_LEN;
 use identity_core::convert::Base;
 use identity_core::convert::BaseEncoding;
 use serde::Deserialize;
@@ -65,7 +71,54 @@ impl IntegrityMetadata {
 
   /// Returns the digest's bytes.
   pub fn digest_bytes(&self) -> Vec<u8> {
-    BaseEncoding::decode(self.digest(), Base::Base64).unwrap()
+    BaseEncoding::decode(self.digest().trim_end_matches('='), Base::Base64).unwrap()
+  }
+
+  /// Computes the `sha256` integrity metadata of `bytes`.
+  pub fn sha256(bytes: &[u8]) -> Self {
+    let mut digest = [0; SHA256_LEN];
+    SHA256(bytes, &mut digest);
+    Self::from_digest("sha256", &digest)
+  }
+
+  /// Computes the `sha384` integrity metadata of `bytes`.
+  pub fn sha384(bytes: &[u8]) -> Self {
+    let mut digest = [0; SHA384_LEN];
+    SHA384(bytes, &mut digest);
+    Self::from_digest("sha384", &digest)
+  }
+
+  /// Computes the `sha512` integrity metadata of `bytes`.
+  pub fn sha512(bytes: &[u8]) -> Self {
+    let mut digest = [0; SHA512_LEN];
+    SHA512(bytes, &mut digest);
+    Self::from_digest("sha512", &digest)
+  }
+
+  /// Returns `true` if `bytes` match this integrity metadata.
+  ///
+  /// Only the `sha256`, `sha384` and `sha512` algorithms are supported; metadata using any other algorithm never
+  /// matches.
+  /// ## Example
+  /// ```rust
+  /// use identity_credential::sd_jwt_vc::metadata::IntegrityMetadata;
+  ///
+  /// let integrity_data = IntegrityMetadata::sha384(b"{}");
+  /// assert!(integrity_data.matches(b"{}"));
+  /// assert!(!integrity_data.matches(b"{ }"));
+  /// ```
+  pub fn matches(&self, bytes: &[u8]) -> bool {
+    let computed = match self.alg() {
+      "sha256" => Self::sha256(bytes),
+      "sha384" => Self::sha384(bytes),
+      "sha512" => Self::sha512(bytes),
+      _ => return false,
+    };
+    computed.digest_bytes() == self.digest_bytes()
+  }
+
+  fn from_digest(alg: &str, digest: &[u8]) -> Self {
+    Self(format!("{alg}-{}", BaseEncoding::encode(digest, Base::Base64Pad)))
   }
 
   /// Returns the option part.
@@ -112,7 +165,7 @@ impl TryFrom<String> for IntegrityMetadata {
       .ok_or_else(|| anyhow!("invalid integrity metadata"))?;
     let _digest = metadata_parts
       .next()
-      .and_then(|digest| BaseEncoding::decode(digest, Base::Base64).ok())
+      .and_then(|digest| BaseEncoding::decode(digest.trim_end_matches('='), Base::Base64).ok())
       .ok_or_else(|| anyhow!("invalid integrity metadata"))?;
     let _options = metadata_parts.next();
 
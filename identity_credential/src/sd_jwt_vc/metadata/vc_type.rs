@@ -9,6 +9,7 @@ use serde::Deserialize;
 use serde::Serialize;
 use serde_json::Value;
 
+use crate::credential::lookup_locale;
 use crate::sd_jwt_vc::Error;
 use crate::sd_jwt_vc::Resolver;
 use crate::sd_jwt_vc::Result;
@@ -70,6 +71,15 @@ impl TypeMetadata {
   pub fn display_metadata(&self) -> &[DisplayMetadata] {
     &self.display
   }
+  /// Returns the [`DisplayMetadata`] whose locale best matches `locale`, if any.
+  ///
+  /// See [`lookup_locale`](crate::credential::lookup_locale) for how the locale is selected.
+  pub fn display_for(&self, locale: &str) -> Option<&DisplayMetadata> {
+    lookup_locale(
+      self.display.iter().map(|display| (display.locale.as_str(), display)),
+      locale,
+    )
+  }
   /// Uses this [`TypeMetadata`] to validate JSON object `credential`. This method fails
   /// if the schema is referenced instead of embedded.
   /// Use [`TypeMetadata::validate_credential_with_resolver`] for such cases.
@@ -267,4 +277,25 @@ mod tests {
       .await
       .unwrap();
   }
+
+  #[test]
+  fn display_for_falls_back_to_the_closest_locale() {
+    let type_metadata: TypeMetadata = serde_json::from_value(json!({
+      "display": [
+        { "locale": "en-US", "name": "Betelgeuse Education Credential" },
+        { "locale": "de-DE", "name": "Betelgeuse-Bildungsnachweis" },
+      ]
+    }))
+    .unwrap();
+
+    assert_eq!(
+      type_metadata.display_for("de-AT").unwrap().name,
+      "Betelgeuse-Bildungsnachweis"
+    );
+    assert_eq!(
+      type_metadata.display_for("en").unwrap().name,
+      "Betelgeuse Education Credential"
+    );
+    assert!(IMMEDIATE_TYPE_METADATA.display_for("en").is_none());
+  }
 }
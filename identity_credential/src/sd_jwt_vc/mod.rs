@@ -2,11 +2,14 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod builder;
+pub mod cache;
 mod claims;
 mod error;
+mod hasher;
 /// Additional metadata defined by the SD-JWT VC specification
 /// such as issuer's metadata and credential type metadata.
 pub mod metadata;
+mod pointer;
 mod presentation;
 /// Resolver trait.
 pub mod resolver;
@@ -19,6 +22,8 @@ pub use builder::*;
 pub use claims::*;
 pub use error::Error;
 pub use error::Result;
+pub use hasher::*;
+pub use pointer::*;
 pub use presentation::*;
 pub use resolver::Resolver;
 pub use status::*;
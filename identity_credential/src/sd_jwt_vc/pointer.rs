@@ -0,0 +1,124 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde_json::Value;
+
+use super::Error;
+use super::Result;
+
+/// Segment of a JSON pointer pattern matching every element of an array.
+pub const ARRAY_WILDCARD: &str = "*";
+
+/// Expands the [JSON pointer](https://datatracker.ietf.org/doc/html/rfc6901) pattern `pattern` against `value`,
+/// returning the pointers to all the values it matches.
+///
+/// A [`ARRAY_WILDCARD`] segment matches every element of the array it is applied to, e.g. `/nationalities/*`
+/// matches `/nationalities/0`, `/nationalities/1`, and so on. Wildcards can be nested to address the elements of
+/// nested arrays, e.g. `/matrix/*/*`. When applied to an object, `*` is interpreted literally as a property name.
+///
+/// Pointers are returned in document order.
+///
+/// ## Example
+/// ```rust
+/// use identity_credential::sd_jwt_vc::expand_pointer;
+/// use serde_json::json;
+///
+/// let object = json!({ "nationalities": ["DE", "FR"] });
+/// assert_eq!(
+///   expand_pointer(&object, "/nationalities/*").unwrap(),
+///   vec!["/nationalities/0", "/nationalities/1"]
+/// );
+/// ```
+pub fn expand_pointer(value: &Value, pattern: &str) -> Result<Vec<String>> {
+  if !pattern.is_empty() && !pattern.starts_with('/') {
+    return Err(Error::InvalidPointer {
+      pointer: pattern.to_owned(),
+      reason: "a non-empty JSON pointer must start with '/'".to_owned(),
+    });
+  }
+
+  let segments: Vec<&str> = pattern.split('/').skip(1).collect();
+  let mut matches = Vec::new();
+  expand(value, &segments, String::new(), pattern, &mut matches)?;
+
+  Ok(matches)
+}
+
+fn expand(value: &Value, segments: &[&str], prefix: String, pattern: &str, matches: &mut Vec<String>) -> Result<()> {
+  let Some((segment, rest)) = segments.split_first() else {
+    matches.push(prefix);
+    return Ok(());
+  };
+
+  match value {
+    Value::Array(elements) if *segment == ARRAY_WILDCARD => {
+      for (idx, element) in elements.iter().enumerate() {
+        expand(element, rest, format!("{prefix}/{idx}"), pattern, matches)?;
+      }
+      Ok(())
+    }
+    Value::Array(elements) => {
+      let element = segment
+        .parse::<usize>()
+        .ok()
+        .and_then(|idx| elements.get(idx))
+        .ok_or_else(|| not_found(pattern, &prefix, segment))?;
+      expand(element, rest, format!("{prefix}/{segment}"), pattern, matches)
+    }
+    Value::Object(object) => {
+      let key = segment.replace("~1", "/").replace("~0", "~");
+      let property = object.get(&key).ok_or_else(|| not_found(pattern, &prefix, segment))?;
+      expand(property, rest, format!("{prefix}/{segment}"), pattern, matches)
+    }
+    _ => Err(not_found(pattern, &prefix, segment)),
+  }
+}
+
+fn not_found(pattern: &str, prefix: &str, segment: &str) -> Error {
+  Error::InvalidPointer {
+    pointer: pattern.to_owned(),
+    reason: format!("no value at \"{prefix}/{segment}\""),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+
+  #[test]
+  fn wildcard_expands_nested_arrays() {
+    let object = json!({
+      "name": "John Doe",
+      "matrix": [[1, 2], [3]],
+      "a/b": { "*": true }
+    });
+
+    assert_eq!(expand_pointer(&object, "/name").unwrap(), vec!["/name"]);
+    assert_eq!(
+      expand_pointer(&object, "/matrix/*/*").unwrap(),
+      vec!["/matrix/0/0", "/matrix/0/1", "/matrix/1/0"]
+    );
+    assert_eq!(expand_pointer(&object, "/a~1b/*").unwrap(), vec!["/a~1b/*"]);
+    assert!(expand_pointer(&json!({ "empty": [] }), "/empty/*").unwrap().is_empty());
+  }
+
+  #[test]
+  fn invalid_pointers_are_rejected() {
+    let object = json!({ "list": ["a"] });
+
+    assert!(matches!(
+      expand_pointer(&object, "list"),
+      Err(Error::InvalidPointer { .. })
+    ));
+    assert!(matches!(
+      expand_pointer(&object, "/list/1"),
+      Err(Error::InvalidPointer { .. })
+    ));
+    assert!(matches!(
+      expand_pointer(&object, "/list/0/*"),
+      Err(Error::InvalidPointer { .. })
+    ));
+  }
+}
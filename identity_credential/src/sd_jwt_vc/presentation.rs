@@ -1,6 +1,7 @@
 // Copyright 2020-2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use super::expand_pointer;
 use super::Error;
 use super::Result;
 use super::SdJwtVc;
@@ -9,6 +10,7 @@ use super::SdJwtVcClaims;
 use sd_jwt::Disclosure;
 use sd_jwt::Hasher;
 use sd_jwt::SdJwtPresentationBuilder;
+use serde_json::Value;
 
 /// Builder structure to create an SD-JWT VC presentation.
 /// It allows users to conceal claims and attach a key binding JWT.
@@ -16,6 +18,8 @@ use sd_jwt::SdJwtPresentationBuilder;
 pub struct SdJwtVcPresentationBuilder {
   vc_claims: SdJwtVcClaims,
   builder: SdJwtPresentationBuilder,
+  // The claims disclosed by the original token, used to expand JSON pointer patterns.
+  disclosed: Value,
 }
 
 impl SdJwtVcPresentationBuilder {
@@ -29,9 +33,18 @@ impl SdJwtVcPresentationBuilder {
     // The reason we do this is to make sure that the underlying SdJwtPresetationBuilder
     // that operates on the wrapped SdJwt token can handle the claims.
     std::mem::swap(sd_jwt.claims_mut(), &mut vc_claims.sd_jwt_claims);
+    let disclosed = sd_jwt
+      .clone()
+      .into_disclosed_object(hasher)
+      .map(Value::Object)
+      .map_err(Error::SdJwt)?;
     let builder = sd_jwt.into_presentation(hasher).map_err(Error::SdJwt)?;
 
-    Ok(Self { vc_claims, builder })
+    Ok(Self {
+      vc_claims,
+      builder,
+      disclosed,
+    })
   }
   /// Removes the disclosure for the property at `path`, conceiling it.
   ///
@@ -43,6 +56,18 @@ impl SdJwtVcPresentationBuilder {
     Ok(self)
   }
 
+  /// Removes the disclosures for every property matched by the JSON pointer pattern `pattern`, concealing them.
+  ///
+  /// ## Notes
+  /// - A [`*`](super::ARRAY_WILDCARD) segment in `pattern` matches every element of an array, see [`expand_pointer`].
+  /// - `pattern` is resolved against the claims disclosed by the token this presentation was created from.
+  pub fn conceal_matching(mut self, pattern: &str) -> Result<Self> {
+    for path in expand_pointer(&self.disclosed, pattern)? {
+      self.builder = self.builder.conceal(&path).map_err(Error::SdJwt)?;
+    }
+    Ok(self)
+  }
+
   /// Removes all disclosures from this SD-JWT, resulting in a token that,
   /// when presented, will have *all* selectively-disclosable properties
   /// omitted.
@@ -63,6 +88,25 @@ impl SdJwtVcPresentationBuilder {
     Ok(self)
   }
 
+  /// Discloses every previously concealed value matched by the JSON pointer pattern `pattern`.
+  /// See [`Self::disclose`] and [`Self::conceal_matching`].
+  pub fn disclose_matching(mut self, pattern: &str) -> Result<Self> {
+    for path in expand_pointer(&self.disclosed, pattern)? {
+      self.builder = self.builder.disclose(&path).map_err(Error::SdJwt)?;
+    }
+    Ok(self)
+  }
+
+  /// Conceals every disclosable value, except the ones matched by any of the JSON pointer patterns in `patterns`.
+  ///
+  /// This allows a holder to re-present a token revealing only a chosen subset of the claims it has been given,
+  /// e.g. `["/name", "/nationalities/*"]`. See [`Self::disclose_matching`].
+  pub fn disclose_only<'a>(self, patterns: impl IntoIterator<Item = &'a str>) -> Result<Self> {
+    patterns.into_iter().try_fold(self.conceal_all(), |builder, pattern| {
+      builder.disclose_matching(pattern)
+    })
+  }
+
   /// Returns the resulting [`SdJwtVc`] together with all removed disclosures.
   pub fn finish(mut self) -> (SdJwtVc, Vec<Disclosure>) {
     let (mut sd_jwt, disclosures) = self.builder.finish();
@@ -46,3 +46,35 @@ async fn test_sd_jwt_presentation_builder() -> anyhow::Result<()> {
 
   Ok(())
 }
+
+#[tokio::test]
+async fn array_elements_can_be_selectively_disclosed() -> anyhow::Result<()> {
+  let credential = SdJwtVcBuilder::new(json!({
+    "name": "John Doe",
+    "nationalities": ["DE", "FR", "IT"],
+    "matrix": [[1, 2], [3]]
+  }))?
+  .vct("https://example.com/education_credential".parse::<Url>()?)
+  .iat(Timestamp::now_utc())
+  .iss("https://example.com".parse()?)
+  .make_concealable("/name")?
+  .make_all_concealable("/nationalities/*")?
+  .make_all_concealable("/matrix/*/*")?
+  .finish(&TestSigner, "HS256")
+  .await?;
+  assert_eq!(credential.disclosures().len(), 6);
+
+  let (presentation, concealed_disclosures) = credential
+    .into_presentation(&Sha256Hasher)?
+    .disclose_only(["/nationalities/1", "/matrix/0/*"])?
+    .finish();
+  assert_eq!(presentation.disclosures().len(), 3);
+  assert_eq!(concealed_disclosures.len(), 3);
+
+  let disclosed = presentation.into_disclosed_object(&Sha256Hasher)?;
+  assert!(!disclosed.contains_key("name"));
+  assert_eq!(disclosed["nationalities"], json!(["FR"]));
+  assert_eq!(disclosed["matrix"], json!([[1, 2], []]));
+
+  Ok(())
+}
@@ -19,9 +19,10 @@ use super::SdJwtVcPresentationBuilder;
 use crate::validator::JwtCredentialValidator as JwsUtils;
 use crate::validator::KeyBindingJwtValidationOptions;
 use anyhow::anyhow;
+use identity_core::common::Clock;
 use identity_core::common::Timestamp;
 use identity_core::common::Url;
-use identity_core::convert::ToJson as _;
+use identity_core::convert::FromJson as _;
 use identity_verification::jwk::Jwk;
 use identity_verification::jwk::JwkSet;
 use identity_verification::jws::JwsVerifier;
@@ -198,7 +199,7 @@ impl SdJwtVc {
     };
     jwks
       .iter()
-      .find(|jwk| jwk.kid() == Some(kid))
+      .find(|jwk| jwk.kid_eq(kid))
       .cloned()
       .ok_or_else(|| Error::Verification(anyhow!("missing key \"{kid}\" in issuer JWK set")))
   }
@@ -237,6 +238,7 @@ impl SdJwtVc {
   ///
   /// This method checks:
   /// - JWS signature
+  /// - `hasher` implements the algorithm declared by `_sd_alg`
   /// - credential's type
   /// - claims' disclosability
   pub async fn validate<R, V>(&self, resolver: &R, jws_verifier: &V, hasher: &dyn Hasher) -> Result<()>
@@ -251,6 +253,11 @@ impl SdJwtVc {
     let jwk = self.issuer_jwk(resolver).await?;
     self.verify_signature(jws_verifier, &jwk)?;
 
+    // Disclosures' digest algorithm.
+    if self.claims()._sd_alg.as_deref().unwrap_or(SHA_ALG_NAME) != hasher.alg_name() {
+      return Err(Error::SdJwt(sd_jwt::Error::InvalidHasher(hasher.alg_name().to_owned())));
+    }
+
     // Credential type.
     // Fetch type metadata. Skip integrity check.
     let fully_disclosed_token = self.clone().into_disclosed_object(hasher).map(Value::Object)?;
@@ -298,20 +305,19 @@ impl SdJwtVc {
         )));
       }
       match requirement {
-        RequiredKeyBinding::Jwk(json_jwk) if jwk.to_json_value().unwrap().as_object().unwrap() != json_jwk => {
+        RequiredKeyBinding::Jwk(json_jwk)
+          if !Jwk::from_json_value(Value::Object(json_jwk.clone()))
+            .is_ok_and(|required| required.thumbprint_eq(jwk)) =>
+        {
           return Err(Error::Validation(anyhow!(
             "key used for signing KB-JWT does not match the key required in this SD-JWT"
           )));
         }
-        RequiredKeyBinding::Kid(kid) | RequiredKeyBinding::Jwu { kid, .. } => jwk
-          .kid()
-          .filter(|id| id == kid)
-          .ok_or_else(|| {
-            Error::Validation(anyhow::anyhow!(
-              "the provided JWK doesn't have required `kid` \"{kid}\""
-            ))
-          })
-          .map(|_| ())?,
+        RequiredKeyBinding::Kid(kid) | RequiredKeyBinding::Jwu { kid, .. } if !jwk.kid_eq(kid) => {
+          return Err(Error::Validation(anyhow::anyhow!(
+            "the provided JWK doesn't have required `kid` \"{kid}\""
+          )));
+        }
         _ => (),
       }
     }
@@ -324,6 +330,7 @@ impl SdJwtVc {
       aud,
       earliest_issuance_date,
       latest_issuance_date,
+      clock,
       ..
     } = options;
 
@@ -344,7 +351,7 @@ impl SdJwtVc {
           "this KB-JWT has been created later than `latest_issuance_date`"
         )));
       }
-    } else if issuance_date > Timestamp::now_utc() {
+    } else if issuance_date > clock.now() {
       return Err(Error::Validation(anyhow!("this KB-JWT has been created in the future")));
     }
 
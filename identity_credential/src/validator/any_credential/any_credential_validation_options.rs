@@ -0,0 +1,43 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::Deserialize;
+use serde::Serialize;
+
+#[cfg(feature = "jpt-bbs-plus")]
+use crate::validator::JptCredentialValidationOptions;
+use crate::validator::JwtCredentialValidationOptions;
+
+/// Options to declare validation criteria for [`AnyCredential`](super::AnyCredential)s.
+#[non_exhaustive]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnyCredentialValidationOptions {
+  /// The options used to validate JWT and SD-JWT credentials, including enveloped ones.
+  #[serde(default)]
+  pub jwt: JwtCredentialValidationOptions,
+  /// The options used to validate JPT credentials.
+  #[cfg(feature = "jpt-bbs-plus")]
+  #[serde(default)]
+  pub jpt: JptCredentialValidationOptions,
+}
+
+impl AnyCredentialValidationOptions {
+  /// Constructor that sets all options to their defaults.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Sets the options used to validate JWT and SD-JWT credentials.
+  pub fn jwt(mut self, options: JwtCredentialValidationOptions) -> Self {
+    self.jwt = options;
+    self
+  }
+
+  /// Sets the options used to validate JPT credentials.
+  #[cfg(feature = "jpt-bbs-plus")]
+  pub fn jpt(mut self, options: JptCredentialValidationOptions) -> Self {
+    self.jpt = options;
+    self
+  }
+}
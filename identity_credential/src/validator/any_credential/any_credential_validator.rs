@@ -0,0 +1,156 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_document::document::CoreDocument;
+use identity_verification::jwk::Jwk;
+use identity_verification::jws::JwsVerifier;
+use identity_verification::jws::JwsVerifierFn;
+use identity_verification::jws::SignatureVerificationError;
+use identity_verification::jws::VerificationInput;
+#[cfg(feature = "sd-jwt")]
+use sd_jwt::SdJwt;
+#[cfg(feature = "sd-jwt")]
+use sd_jwt::Sha256Hasher;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::credential::Jwt;
+use crate::credential::JwtVcV2;
+use crate::validator::FailFast;
+#[cfg(feature = "jpt-bbs-plus")]
+use crate::validator::JptCredentialValidator;
+use crate::validator::JwtCredentialValidator;
+#[cfg(feature = "sd-jwt")]
+use crate::validator::SdJwtCredentialValidator;
+
+use super::any_credential::is_vc_v2;
+use super::AnyCredential;
+use super::AnyCredentialError;
+use super::AnyCredentialValidationOptions;
+use super::DecodedCredential;
+
+/// A type for validating [`AnyCredential`]s, dispatching each credential to the validator of its format.
+#[non_exhaustive]
+pub struct AnyCredentialValidator<V: JwsVerifier>(V);
+
+impl<V: JwsVerifier> AnyCredentialValidator<V> {
+  /// Create a new [`AnyCredentialValidator`] that delegates cryptographic signature verification of JWT and SD-JWT
+  /// credentials to the given `signature_verifier`.
+  pub fn with_signature_verifier(signature_verifier: V) -> Self {
+    Self(signature_verifier)
+  }
+
+  /// Decodes and validates `credential` issued by `issuer`, according to its format:
+  /// - JWTs are validated as [`JwtCredentialValidator::validate`] does, or as [`JwtCredentialValidator::validate_v2`]
+  ///   does for VC Data Model 2.0 credentials,
+  /// - SD-JWTs are validated as [`SdJwtCredentialValidator::validate_credential`] does, using SHA-256 as the hash
+  ///   function of their disclosures,
+  /// - JPTs are validated as [`JptCredentialValidator::validate`] does, using [`AnyCredentialValidationOptions::jpt`],
+  /// - enveloped credentials are validated as the VC Data Model 2.0 credential they wrap.
+  ///
+  /// # Warning
+  /// The caller must ensure that `issuer` represents an up-to-date DID Document. Key binding JWTs of SD-JWTs are
+  /// **not** validated.
+  ///
+  /// # Errors
+  /// Fails if the credential is malformed, uses a format that is not enabled, or is invalid.
+  pub fn validate<DOC, T>(
+    &self,
+    credential: &AnyCredential,
+    issuer: &DOC,
+    options: &AnyCredentialValidationOptions,
+    fail_fast: FailFast,
+  ) -> Result<DecodedCredential<T>, AnyCredentialError>
+  where
+    T: ToOwned<Owned = T> + Clone + Serialize + DeserializeOwned,
+    DOC: AsRef<CoreDocument>,
+  {
+    match credential {
+      AnyCredential::Jwt(jwt) if is_vc_v2(jwt.as_str()) => self
+        .validate_jwt_v2(jwt, issuer, options, fail_fast)
+        .map(DecodedCredential::JwtV2),
+      AnyCredential::Jwt(jwt) => self
+        .jwt_validator()
+        .validate(jwt, issuer, &options.jwt, fail_fast)
+        .map(DecodedCredential::Jwt)
+        .map_err(AnyCredentialError::InvalidCredential),
+      #[cfg(feature = "sd-jwt")]
+      AnyCredential::SdJwt(sd_jwt) => self.validate_sd_jwt(sd_jwt, issuer, options),
+      #[cfg(feature = "jpt-bbs-plus")]
+      AnyCredential::Jpt(jpt) => JptCredentialValidator::validate(jpt, issuer, &options.jpt, fail_fast)
+        .map(DecodedCredential::Jpt)
+        .map_err(AnyCredentialError::InvalidCredential),
+      AnyCredential::Enveloped(_) => {
+        let decoded = match credential.unwrap_envelope()?.expect("enveloped credential") {
+          AnyCredential::Jwt(jwt) => self
+            .validate_jwt_v2(&jwt, issuer, options, fail_fast)
+            .map(DecodedCredential::JwtV2)?,
+          #[cfg(feature = "sd-jwt")]
+          AnyCredential::SdJwt(sd_jwt) => self.validate_sd_jwt(&sd_jwt, issuer, options)?,
+          other => return Err(AnyCredentialError::UnsupportedFormat(other.format().to_string())),
+        };
+        Ok(DecodedCredential::Enveloped(Box::new(decoded)))
+      }
+    }
+  }
+
+  /// Borrows this validator's signature verifier in a [`JwsVerifier`] that can be handed to the validators of the
+  /// individual formats.
+  fn verifier(&self) -> impl JwsVerifier + '_ {
+    JwsVerifierFn::from(
+      |input: VerificationInput, public_key: &Jwk| -> Result<(), SignatureVerificationError> {
+        self.0.verify(input, public_key)
+      },
+    )
+  }
+
+  fn jwt_validator(&self) -> JwtCredentialValidator<impl JwsVerifier + '_> {
+    JwtCredentialValidator::with_signature_verifier(self.verifier())
+  }
+
+  fn validate_jwt_v2<DOC, T>(
+    &self,
+    jwt: &Jwt,
+    issuer: &DOC,
+    options: &AnyCredentialValidationOptions,
+    fail_fast: FailFast,
+  ) -> Result<crate::validator::DecodedJwtCredentialV2<T>, AnyCredentialError>
+  where
+    T: Clone + Serialize + DeserializeOwned,
+    DOC: AsRef<CoreDocument>,
+  {
+    let jwt = JwtVcV2::parse(jwt.as_str()).map_err(|err| AnyCredentialError::MalformedCredential(err.into()))?;
+    self
+      .jwt_validator()
+      .validate_v2(&jwt, issuer, &options.jwt, fail_fast)
+      .map_err(AnyCredentialError::InvalidCredential)
+  }
+
+  #[cfg(feature = "sd-jwt")]
+  fn validate_sd_jwt<DOC, T>(
+    &self,
+    sd_jwt: &SdJwt,
+    issuer: &DOC,
+    options: &AnyCredentialValidationOptions,
+  ) -> Result<DecodedCredential<T>, AnyCredentialError>
+  where
+    T: ToOwned<Owned = T> + Clone + Serialize + DeserializeOwned,
+    DOC: AsRef<CoreDocument>,
+  {
+    let validator = SdJwtCredentialValidator::new(self.verifier(), Sha256Hasher);
+    let issuers = std::slice::from_ref(issuer);
+    let presentation = sd_jwt.presentation();
+    let jwt = presentation.split('~').next().unwrap_or_default();
+    let decoded = if is_vc_v2(jwt) {
+      validator
+        .validate_credential_v2(sd_jwt, issuers, &options.jwt)
+        .map(DecodedCredential::SdJwtV2)
+    } else {
+      validator
+        .validate_credential(sd_jwt, issuers, &options.jwt)
+        .map(DecodedCredential::SdJwt)
+    };
+
+    decoded.map_err(AnyCredentialError::InvalidSdJwt)
+  }
+}
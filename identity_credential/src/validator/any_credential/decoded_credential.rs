@@ -0,0 +1,83 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+#[cfg(feature = "sd-jwt")]
+use crate::credential::Credential;
+use crate::credential::CredentialT;
+#[cfg(feature = "sd-jwt")]
+use crate::credential::CredentialV2;
+#[cfg(feature = "jpt-bbs-plus")]
+use crate::validator::DecodedJptCredential;
+use crate::validator::DecodedJwtCredential;
+use crate::validator::DecodedJwtCredentialV2;
+
+use super::CredentialFormat;
+
+/// A credential decoded and validated by an [`AnyCredentialValidator`](super::AnyCredentialValidator), holding the
+/// output of the validator specific to its format.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum DecodedCredential<T = Object> {
+  /// A VC Data Model 1.1 credential encoded as a JWT.
+  Jwt(DecodedJwtCredential<T>),
+  /// A VC Data Model 2.0 credential encoded as a JWT.
+  JwtV2(DecodedJwtCredentialV2<T>),
+  /// A VC Data Model 1.1 credential encoded as an SD-JWT, built from its disclosed claims.
+  #[cfg(feature = "sd-jwt")]
+  SdJwt(Credential<T>),
+  /// A VC Data Model 2.0 credential encoded as an SD-JWT, built from its disclosed claims.
+  #[cfg(feature = "sd-jwt")]
+  SdJwtV2(CredentialV2<T>),
+  /// A credential encoded as a JSON Proof Token.
+  #[cfg(feature = "jpt-bbs-plus")]
+  Jpt(DecodedJptCredential<T>),
+  /// The credential wrapped by an enveloped verifiable credential.
+  Enveloped(Box<DecodedCredential<T>>),
+}
+
+impl<T> DecodedCredential<T>
+where
+  T: ToOwned<Owned = T> + Clone + Serialize + DeserializeOwned,
+{
+  /// Returns the format of the credential this was decoded from.
+  pub fn format(&self) -> CredentialFormat {
+    match self {
+      Self::Jwt(_) | Self::JwtV2(_) => CredentialFormat::Jwt,
+      #[cfg(feature = "sd-jwt")]
+      Self::SdJwt(_) | Self::SdJwtV2(_) => CredentialFormat::SdJwt,
+      #[cfg(feature = "jpt-bbs-plus")]
+      Self::Jpt(_) => CredentialFormat::Jpt,
+      Self::Enveloped(_) => CredentialFormat::Enveloped,
+    }
+  }
+
+  /// Returns the decoded credential, regardless of its format and data model version.
+  pub fn credential(&self) -> &dyn CredentialT<Properties = T> {
+    match self {
+      Self::Jwt(decoded) => &decoded.credential,
+      Self::JwtV2(decoded) => &decoded.credential,
+      #[cfg(feature = "sd-jwt")]
+      Self::SdJwt(credential) => credential,
+      #[cfg(feature = "sd-jwt")]
+      Self::SdJwtV2(credential) => credential,
+      #[cfg(feature = "jpt-bbs-plus")]
+      Self::Jpt(decoded) => &decoded.credential,
+      Self::Enveloped(decoded) => decoded.credential(),
+    }
+  }
+
+  /// Returns the custom claims of the credential, if its format supports them and any were set.
+  pub fn custom_claims(&self) -> Option<&Object> {
+    match self {
+      Self::Jwt(decoded) => decoded.custom_claims.as_ref(),
+      #[cfg(feature = "jpt-bbs-plus")]
+      Self::Jpt(decoded) => decoded.custom_claims.as_ref(),
+      Self::Enveloped(decoded) => decoded.custom_claims(),
+      _ => None,
+    }
+  }
+}
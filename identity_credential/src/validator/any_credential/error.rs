@@ -0,0 +1,37 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::validator::CompoundCredentialValidationError;
+#[cfg(feature = "sd-jwt")]
+use crate::validator::SdJwtCredentialValidatorError;
+
+/// Errors that can occur when decoding or validating an [`AnyCredential`](super::AnyCredential).
+#[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum AnyCredentialError {
+  /// The format of the credential could not be detected.
+  #[error("unknown credential format: expected a JWT, SD-JWT, JPT or enveloped verifiable credential")]
+  UnknownFormat,
+  /// The credential's format was detected, but is not supported, e.g. because the feature enabling it is disabled.
+  #[error("unsupported credential format `{0}`")]
+  UnsupportedFormat(String),
+  /// The credential could not be decoded.
+  #[error("malformed credential")]
+  MalformedCredential(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
+  /// The JWT or JPT credential failed validation.
+  #[error("the credential is invalid")]
+  InvalidCredential(#[source] CompoundCredentialValidationError),
+  /// The SD-JWT credential failed validation.
+  #[cfg(feature = "sd-jwt")]
+  #[error("the SD-JWT credential is invalid")]
+  InvalidSdJwt(#[source] SdJwtCredentialValidatorError),
+}
+
+identity_core::impl_error_code!(AnyCredentialError {
+  UnknownFormat => 2510,
+  UnsupportedFormat => 2511,
+  MalformedCredential => 2512,
+  InvalidCredential => 2513,
+  #[cfg(feature = "sd-jwt")]
+  InvalidSdJwt => 2514,
+});
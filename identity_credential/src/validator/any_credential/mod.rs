@@ -0,0 +1,14 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod any_credential;
+mod any_credential_validation_options;
+mod any_credential_validator;
+mod decoded_credential;
+mod error;
+
+pub use any_credential::*;
+pub use any_credential_validation_options::*;
+pub use any_credential_validator::*;
+pub use decoded_credential::*;
+pub use error::*;
@@ -0,0 +1,347 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use identity_core::common::Url;
+use identity_did::CoreDID;
+use identity_did::DIDUrl;
+use identity_document::document::CoreDocument;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::validator::DecodedJwtCredential;
+
+/// The subject properties of an equivalence credential listing the previous DIDs of the subject.
+const EQUIVALENCE_PROPERTIES: [&str; 2] = ["alsoKnownAs", "equivalentId"];
+
+/// An error preventing the construction of a [`DidRotation`] from the given evidence.
+#[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum ContinuityError {
+  /// The previous and the current DID are the same.
+  #[error("`{0}` cannot be rotated to itself")]
+  SameDid(CoreDID),
+  /// A DID Document does not list the other DID in its `alsoKnownAs` property.
+  #[error("the DID Document of `{from}` does not list `{to}` in `alsoKnownAs`")]
+  MissingAlsoKnownAs {
+    /// The DID whose document lacks the link.
+    from: CoreDID,
+    /// The DID missing from the document.
+    to: CoreDID,
+  },
+  /// A credential does not attest the equivalence of a previous DID of its subject.
+  #[error("invalid equivalence credential: {0}")]
+  InvalidEquivalenceCredential(&'static str),
+}
+
+identity_core::impl_error_code!(ContinuityError {
+  SameDid => 2570,
+  MissingAlsoKnownAs => 2571,
+  InvalidEquivalenceCredential => 2572,
+});
+
+/// Evidence that the subject formerly identified by [`previous`](Self::previous) is now identified by
+/// [`current`](Self::current).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "DidRotationData")]
+pub struct DidRotation {
+  previous: CoreDID,
+  current: CoreDID,
+}
+
+/// The unchecked fields of a [`DidRotation`], deserialized before being checked by [`DidRotation::new`].
+#[derive(Deserialize)]
+struct DidRotationData {
+  previous: CoreDID,
+  current: CoreDID,
+}
+
+impl TryFrom<DidRotationData> for DidRotation {
+  type Error = ContinuityError;
+
+  fn try_from(data: DidRotationData) -> Result<Self, Self::Error> {
+    Self::new(data.previous, data.current)
+  }
+}
+
+impl DidRotation {
+  /// Links the DIDs of two DID Documents that list each other in their `alsoKnownAs` property.
+  ///
+  /// Requiring the link to be two-way prevents a DID from claiming the credentials of another one. The documents are
+  /// expected to be freshly resolved.
+  pub fn from_documents(previous: &CoreDocument, current: &CoreDocument) -> Result<Self, ContinuityError> {
+    let lists = |document: &CoreDocument, did: &CoreDID| {
+      document
+        .also_known_as()
+        .iter()
+        .any(|url| CoreDID::parse(url.as_str()).map_or(false, |listed| &listed == did))
+    };
+    for (from, to) in [(previous, current), (current, previous)] {
+      if !lists(from, to.id()) {
+        return Err(ContinuityError::MissingAlsoKnownAs {
+          from: from.id().clone(),
+          to: to.id().clone(),
+        });
+      }
+    }
+    Self::new(previous.id().clone(), current.id().clone())
+  }
+
+  /// Links the previous DID of a subject to its current DID through an equivalence credential.
+  ///
+  /// The credential must be issued by the previous DID to the current DID, and list the previous DID in the
+  /// `alsoKnownAs` or `equivalentId` property of its subject, e.g.:
+  /// ```json
+  /// {
+  ///   "issuer": "did:example:previous",
+  ///   "credentialSubject": { "id": "did:example:current", "alsoKnownAs": ["did:example:previous"] }
+  /// }
+  /// ```
+  /// Taking a [`DecodedJwtCredential`] ensures the credential was signed; it should also have been validated against
+  /// the DID Document of the previous DID with [`JwtCredentialValidator`](crate::validator::JwtCredentialValidator).
+  pub fn from_equivalence_credential<T>(credential: &DecodedJwtCredential<T>) -> Result<Self, ContinuityError> {
+    let credential = &credential.credential;
+    let previous: CoreDID = CoreDID::parse(credential.issuer.url().as_str())
+      .map_err(|_| ContinuityError::InvalidEquivalenceCredential("the issuer is not a DID"))?;
+    let [subject] = credential.credential_subject.as_slice() else {
+      return Err(ContinuityError::InvalidEquivalenceCredential(
+        "the credential must have exactly one subject",
+      ));
+    };
+    let current: CoreDID = subject
+      .id
+      .as_ref()
+      .and_then(|id| CoreDID::parse(id.as_str()).ok())
+      .ok_or(ContinuityError::InvalidEquivalenceCredential(
+        "the subject is not identified by a DID",
+      ))?;
+
+    let lists_previous = |value: &Value| match value {
+      Value::String(did) => did == previous.as_str(),
+      Value::Array(dids) => dids.iter().any(|did| did.as_str() == Some(previous.as_str())),
+      _ => false,
+    };
+    if !EQUIVALENCE_PROPERTIES
+      .iter()
+      .filter_map(|property| subject.properties.get(*property))
+      .any(lists_previous)
+    {
+      return Err(ContinuityError::InvalidEquivalenceCredential(
+        "the subject does not list the issuer in `alsoKnownAs` or `equivalentId`",
+      ));
+    }
+
+    Self::new(previous, current)
+  }
+
+  fn new(previous: CoreDID, current: CoreDID) -> Result<Self, ContinuityError> {
+    if previous == current {
+      return Err(ContinuityError::SameDid(previous));
+    }
+    Ok(Self { previous, current })
+  }
+
+  /// Returns the DID the subject was formerly identified by.
+  pub fn previous(&self) -> &CoreDID {
+    &self.previous
+  }
+
+  /// Returns the DID the subject is now identified by.
+  pub fn current(&self) -> &CoreDID {
+    &self.current
+  }
+}
+
+/// Declares which DID rotations of credential subjects are accepted when checking the subject-holder relationship,
+/// see [`JwtCredentialValidationOptions::continuity_policy`](crate::validator::JwtCredentialValidationOptions::continuity_policy).
+///
+/// A holder matches a credential subject if the holder's DID was reached from the subject's DID through a chain of
+/// accepted [`DidRotation`]s. The default policy accepts no rotation.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContinuityPolicy {
+  #[serde(default)]
+  rotations: Vec<DidRotation>,
+}
+
+impl ContinuityPolicy {
+  /// Creates a policy that accepts no rotation.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Accepts `rotation`.
+  pub fn rotation(mut self, rotation: DidRotation) -> Self {
+    self.rotations.push(rotation);
+    self
+  }
+
+  /// Returns the accepted rotations.
+  pub fn rotations(&self) -> &[DidRotation] {
+    &self.rotations
+  }
+
+  /// Returns `true` if `current` was reached from `previous` through a chain of accepted rotations.
+  pub fn is_continuation(&self, previous: &CoreDID, current: &CoreDID) -> bool {
+    let mut visited: HashSet<&CoreDID> = HashSet::new();
+    let mut pending: Vec<&CoreDID> = vec![previous];
+    while let Some(did) = pending.pop() {
+      if !visited.insert(did) {
+        continue;
+      }
+      for rotation in self.rotations.iter().filter(|rotation| &rotation.previous == did) {
+        if &rotation.current == current {
+          return true;
+        }
+        pending.push(&rotation.current);
+      }
+    }
+    false
+  }
+
+  /// Returns `true` if `holder` identifies the same entity as `subject`, either directly or through accepted
+  /// rotations of the DID of `subject`.
+  pub(crate) fn matches(&self, subject: &Url, holder: &Url) -> bool {
+    if subject == holder {
+      return true;
+    }
+    let did = |url: &Url| DIDUrl::parse(url.as_str()).ok().map(|url| url.did().clone());
+    match (did(subject), did(holder)) {
+      (Some(subject), Some(holder)) => self.is_continuation(&subject, &holder),
+      _ => false,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+  use serde_json::json;
+
+  use super::*;
+  use crate::credential::Credential;
+  use crate::validator::JwtCredentialValidatorUtils;
+  use crate::validator::SubjectHolderRelationship;
+
+  fn document(did: &str, also_known_as: &[&str]) -> CoreDocument {
+    CoreDocument::from_json_value(json!({ "id": did, "alsoKnownAs": also_known_as })).unwrap()
+  }
+
+  fn decoded(credential: Value) -> DecodedJwtCredential {
+    DecodedJwtCredential {
+      credential: Credential::from_json_value(credential).unwrap(),
+      header: Box::default(),
+      custom_claims: None,
+    }
+  }
+
+  fn equivalence_credential(issuer: &str, subject: Value) -> Value {
+    json!({
+      "@context": "https://www.w3.org/2018/credentials/v1",
+      "type": "VerifiableCredential",
+      "issuer": issuer,
+      "issuanceDate": "2010-01-01T00:00:00Z",
+      "credentialSubject": subject,
+    })
+  }
+
+  #[test]
+  fn rotation_from_documents() {
+    let previous = document("did:example:old", &["did:example:new"]);
+    let current = document("did:example:new", &["did:example:old"]);
+    let rotation = DidRotation::from_documents(&previous, &current).unwrap();
+    assert_eq!(rotation.previous().as_str(), "did:example:old");
+    assert_eq!(rotation.current().as_str(), "did:example:new");
+
+    let one_way = document("did:example:new", &[]);
+    assert!(matches!(
+      DidRotation::from_documents(&previous, &one_way),
+      Err(ContinuityError::MissingAlsoKnownAs { .. })
+    ));
+    assert!(matches!(
+      DidRotation::from_documents(&previous, &previous),
+      Err(ContinuityError::MissingAlsoKnownAs { .. })
+    ));
+  }
+
+  #[test]
+  fn rotation_from_equivalence_credential() {
+    let credential = equivalence_credential(
+      "did:example:old",
+      json!({ "id": "did:example:new", "alsoKnownAs": ["did:example:old"] }),
+    );
+    let rotation = DidRotation::from_equivalence_credential(&decoded(credential)).unwrap();
+    assert_eq!(rotation.current().as_str(), "did:example:new");
+
+    let credential = equivalence_credential(
+      "did:example:old",
+      json!({ "id": "did:example:new", "equivalentId": "did:example:old" }),
+    );
+    assert!(DidRotation::from_equivalence_credential(&decoded(credential)).is_ok());
+
+    // Issued by a third party.
+    let credential = equivalence_credential(
+      "did:example:other",
+      json!({ "id": "did:example:new", "alsoKnownAs": ["did:example:old"] }),
+    );
+    assert!(DidRotation::from_equivalence_credential(&decoded(credential)).is_err());
+
+    let credential = equivalence_credential("did:example:old", json!({ "id": "did:example:new" }));
+    assert!(DidRotation::from_equivalence_credential(&decoded(credential)).is_err());
+  }
+
+  #[test]
+  fn subject_holder_relationship_with_continuity() {
+    let rotation = |previous: &str, current: &str| {
+      DidRotation::new(CoreDID::parse(previous).unwrap(), CoreDID::parse(current).unwrap()).unwrap()
+    };
+    let policy = ContinuityPolicy::new()
+      .rotation(rotation("did:example:a", "did:example:b"))
+      .rotation(rotation("did:example:b", "did:example:c"))
+      .rotation(rotation("did:example:c", "did:example:a"));
+    let credential = Credential::<identity_core::common::Object>::from_json_value(equivalence_credential(
+      "did:example:issuer",
+      json!({ "id": "did:example:a" }),
+    ))
+    .unwrap();
+
+    let check = |holder: &str, policy: &ContinuityPolicy| {
+      JwtCredentialValidatorUtils::check_subject_holder_relationship_with_continuity(
+        &credential,
+        &Url::parse(holder).unwrap(),
+        SubjectHolderRelationship::AlwaysSubject,
+        policy,
+      )
+      .is_ok()
+    };
+    assert!(check("did:example:a", &ContinuityPolicy::new()));
+    assert!(!check("did:example:c", &ContinuityPolicy::new()));
+    assert!(check("did:example:b", &policy));
+    assert!(check("did:example:c", &policy));
+    assert!(!check("did:example:d", &policy));
+
+    // Rotations are one-way.
+    assert!(!ContinuityPolicy::new()
+      .rotation(rotation("did:example:a", "did:example:b"))
+      .is_continuation(
+        &CoreDID::parse("did:example:b").unwrap(),
+        &CoreDID::parse("did:example:a").unwrap()
+      ));
+  }
+
+  #[test]
+  fn deserialized_rotations_are_checked() {
+    let rotation: DidRotation =
+      serde_json::from_value(json!({ "previous": "did:example:a", "current": "did:example:b" })).unwrap();
+    assert_eq!(rotation.previous().as_str(), "did:example:a");
+    assert_eq!(
+      serde_json::from_value::<DidRotation>(serde_json::to_value(&rotation).unwrap()).unwrap(),
+      rotation
+    );
+
+    let same_did = json!({ "rotations": [{ "previous": "did:example:a", "current": "did:example:a" }] });
+    assert!(serde_json::from_value::<ContinuityPolicy>(same_did).is_err());
+  }
+}
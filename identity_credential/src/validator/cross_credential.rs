@@ -0,0 +1,461 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use identity_core::error_code::ErrorCode;
+use identity_core::error_code::HasErrorCode;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::validator::DecodedJwtCredential;
+
+/// A failed [`CrossCredentialConstraint`].
+#[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum CrossCredentialError {
+  /// No credential of the presentation matches a [`CredentialSelector`].
+  #[error("no credential matches `{0}`")]
+  UnknownCredential(CredentialSelector),
+  /// A referenced claim is missing.
+  #[error("missing claim `{0}`")]
+  MissingClaim(ClaimReference),
+  /// Two claims required to be equal are not.
+  #[error("`{left}` does not match `{right}`")]
+  ClaimMismatch {
+    /// The first claim of the constraint.
+    left: ClaimReference,
+    /// The second claim of the constraint.
+    right: ClaimReference,
+  },
+  /// The credentials are not all about the same subject.
+  #[error("the credentials are not all about the same subject")]
+  SubjectMismatch,
+  /// The credentials were not all issued by the same issuer.
+  #[error("the credentials were not all issued by the same issuer")]
+  IssuerMismatch,
+}
+
+identity_core::impl_error_code!(CrossCredentialError {
+  UnknownCredential => 2480,
+  MissingClaim => 2481,
+  ClaimMismatch => 2482,
+  SubjectMismatch => 2483,
+  IssuerMismatch => 2484,
+});
+
+/// The failed constraints of a [`CrossCredentialConstraints::check`].
+#[derive(Debug)]
+pub struct CompoundCrossCredentialError {
+  /// The failures, in the order of the constraints.
+  pub errors: Vec<CrossCredentialError>,
+}
+
+impl Display for CompoundCrossCredentialError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    let detailed_information: String =
+      itertools::intersperse(self.errors.iter().map(|err| err.to_string()), "; ".to_string()).collect();
+    write!(f, "[{detailed_information}]")
+  }
+}
+
+impl std::error::Error for CompoundCrossCredentialError {}
+
+impl HasErrorCode for CompoundCrossCredentialError {
+  /// Returns the code of the compound error itself, the codes of the individual failures are available through
+  /// [`Self::errors`].
+  fn error_code(&self) -> ErrorCode {
+    ErrorCode::new(2489, "CompoundCrossCredentialError")
+  }
+}
+
+/// Selects a credential of a presentation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CredentialSelector {
+  /// The credential at the given position in the presentation.
+  Index(usize),
+  /// The first credential of the given type, e.g. `UniversityDegreeCredential`.
+  Type(String),
+}
+
+impl CredentialSelector {
+  fn select<'c>(&self, credentials: &'c [Value]) -> Option<&'c Value> {
+    match self {
+      Self::Index(index) => credentials.get(*index),
+      Self::Type(type_) => credentials.iter().find(|credential| match credential.get("type") {
+        Some(Value::String(value)) => value == type_,
+        Some(Value::Array(values)) => values.iter().any(|value| value.as_str() == Some(type_.as_str())),
+        _ => false,
+      }),
+    }
+  }
+}
+
+impl Display for CredentialSelector {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Index(index) => write!(f, "#{index}"),
+      Self::Type(type_) => f.write_str(type_),
+    }
+  }
+}
+
+/// A claim of a credential of a presentation, referenced by a JSON pointer, e.g. `/credentialSubject/name`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClaimReference {
+  /// The credential holding the claim.
+  pub credential: CredentialSelector,
+  /// The JSON pointer to the claim within the credential.
+  pub pointer: String,
+}
+
+impl ClaimReference {
+  /// References the claim at `pointer` of the credential selected by `credential`.
+  pub fn new(credential: CredentialSelector, pointer: impl Into<String>) -> Self {
+    Self {
+      credential,
+      pointer: pointer.into(),
+    }
+  }
+
+  /// References the claim at `pointer` of the first credential of type `credential_type`.
+  pub fn of_type(credential_type: impl Into<String>, pointer: impl Into<String>) -> Self {
+    Self::new(CredentialSelector::Type(credential_type.into()), pointer)
+  }
+
+  fn resolve<'c>(&self, credentials: &'c [Value]) -> Result<&'c Value, CrossCredentialError> {
+    self
+      .credential
+      .select(credentials)
+      .ok_or_else(|| CrossCredentialError::UnknownCredential(self.credential.clone()))?
+      .pointer(&self.pointer)
+      .ok_or_else(|| CrossCredentialError::MissingClaim(self.clone()))
+  }
+}
+
+impl Display for ClaimReference {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}{}", self.credential, self.pointer)
+  }
+}
+
+/// How string claims are normalized before being compared. Other claims are compared as they are.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Normalization {
+  /// Removes leading and trailing whitespace.
+  #[serde(default)]
+  pub trim: bool,
+  /// Replaces sequences of whitespace with a single space, and removes leading and trailing whitespace.
+  #[serde(default)]
+  pub collapse_whitespace: bool,
+  /// Compares strings regardless of their case.
+  #[serde(default)]
+  pub case_insensitive: bool,
+}
+
+impl Normalization {
+  /// Compares strings exactly.
+  pub fn none() -> Self {
+    Self::default()
+  }
+
+  /// Sets whether leading and trailing whitespace is removed.
+  #[must_use]
+  pub fn trim(mut self, value: bool) -> Self {
+    self.trim = value;
+    self
+  }
+
+  /// Sets whether sequences of whitespace are replaced with a single space.
+  #[must_use]
+  pub fn collapse_whitespace(mut self, value: bool) -> Self {
+    self.collapse_whitespace = value;
+    self
+  }
+
+  /// Sets whether strings are compared regardless of their case.
+  #[must_use]
+  pub fn case_insensitive(mut self, value: bool) -> Self {
+    self.case_insensitive = value;
+    self
+  }
+
+  fn normalize(&self, value: &str) -> String {
+    let value: String = if self.collapse_whitespace {
+      value.split_whitespace().collect::<Vec<_>>().join(" ")
+    } else if self.trim {
+      value.trim().to_owned()
+    } else {
+      value.to_owned()
+    };
+    if self.case_insensitive {
+      value.to_lowercase()
+    } else {
+      value
+    }
+  }
+
+  fn equal(&self, left: &Value, right: &Value) -> bool {
+    match (left, right) {
+      (Value::String(left), Value::String(right)) => self.normalize(left) == self.normalize(right),
+      _ => left == right,
+    }
+  }
+}
+
+/// A constraint across the credentials of a presentation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "kind")]
+#[non_exhaustive]
+pub enum CrossCredentialConstraint {
+  /// Two claims are equal after normalization, e.g. the name in a passport and in a diploma.
+  Equal {
+    /// The first claim.
+    left: ClaimReference,
+    /// The second claim.
+    right: ClaimReference,
+    /// How string claims are normalized before being compared.
+    #[serde(default)]
+    normalization: Normalization,
+  },
+  /// All credentials have a single subject, and all subjects have the same `id`.
+  SameSubject,
+  /// All credentials were issued by the same issuer.
+  SameIssuer,
+}
+
+impl CrossCredentialConstraint {
+  fn check(&self, credentials: &[Value]) -> Result<(), CrossCredentialError> {
+    match self {
+      Self::Equal {
+        left,
+        right,
+        normalization,
+      } => {
+        if normalization.equal(left.resolve(credentials)?, right.resolve(credentials)?) {
+          Ok(())
+        } else {
+          Err(CrossCredentialError::ClaimMismatch {
+            left: left.clone(),
+            right: right.clone(),
+          })
+        }
+      }
+      Self::SameSubject => {
+        let subject_id = |credential: &Value| match credential.get("credentialSubject") {
+          Some(Value::Array(_)) | None => None,
+          Some(subject) => subject.get("id").and_then(Value::as_str).map(ToOwned::to_owned),
+        };
+        all_same(credentials, subject_id).ok_or(CrossCredentialError::SubjectMismatch)
+      }
+      Self::SameIssuer => {
+        let issuer_id = |credential: &Value| {
+          let issuer: &Value = credential.get("issuer")?;
+          issuer
+            .as_str()
+            .or_else(|| issuer.get("id")?.as_str())
+            .map(ToOwned::to_owned)
+        };
+        all_same(credentials, issuer_id).ok_or(CrossCredentialError::IssuerMismatch)
+      }
+    }
+  }
+}
+
+/// Returns `Some` if `f` returns the same `Some` value for all `credentials`.
+fn all_same(credentials: &[Value], f: impl Fn(&Value) -> Option<String>) -> Option<()> {
+  let mut values = credentials.iter().map(f);
+  let first: String = values.next()??;
+  values.all(|value| value.as_ref() == Some(&first)).then_some(())
+}
+
+/// Constraints across the credentials of a presentation, checked after each credential was validated on its own.
+///
+/// Constraints are expressed on the JSON representation of the credentials, so that credentials of any format can be
+/// checked once decoded, e.g. SD-JWT VCs with their disclosures resolved.
+///
+/// # Example
+/// ```
+/// use identity_credential::validator::ClaimReference;
+/// use identity_credential::validator::CrossCredentialConstraint;
+/// use identity_credential::validator::CrossCredentialConstraints;
+/// use identity_credential::validator::Normalization;
+///
+/// let constraints = CrossCredentialConstraints::new()
+///   .with(CrossCredentialConstraint::SameSubject)
+///   .equal(
+///     ClaimReference::of_type("PassportCredential", "/credentialSubject/name"),
+///     ClaimReference::of_type("UniversityDegreeCredential", "/credentialSubject/name"),
+///     Normalization::none()
+///       .collapse_whitespace(true)
+///       .case_insensitive(true),
+///   );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct CrossCredentialConstraints(Vec<CrossCredentialConstraint>);
+
+impl CrossCredentialConstraints {
+  /// Creates an empty set of constraints.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Adds `constraint`.
+  #[must_use]
+  pub fn with(mut self, constraint: CrossCredentialConstraint) -> Self {
+    self.0.push(constraint);
+    self
+  }
+
+  /// Adds a [`CrossCredentialConstraint::Equal`] constraint.
+  #[must_use]
+  pub fn equal(self, left: ClaimReference, right: ClaimReference, normalization: Normalization) -> Self {
+    self.with(CrossCredentialConstraint::Equal {
+      left,
+      right,
+      normalization,
+    })
+  }
+
+  /// Returns the constraints.
+  pub fn constraints(&self) -> &[CrossCredentialConstraint] {
+    &self.0
+  }
+
+  /// Checks all constraints against the validated `credentials` of a presentation, in the order they were presented.
+  pub fn check<T: Serialize>(
+    &self,
+    credentials: &[DecodedJwtCredential<T>],
+  ) -> Result<(), CompoundCrossCredentialError> {
+    let credentials: Vec<Value> = credentials
+      .iter()
+      .map(|decoded| serde_json::to_value(&decoded.credential).unwrap_or(Value::Null))
+      .collect();
+    self.check_json(&credentials)
+  }
+
+  /// Checks all constraints against the JSON representation of the validated `credentials` of a presentation, in the
+  /// order they were presented.
+  pub fn check_json(&self, credentials: &[Value]) -> Result<(), CompoundCrossCredentialError> {
+    let errors: Vec<CrossCredentialError> = self
+      .0
+      .iter()
+      .filter_map(|constraint| constraint.check(credentials).err())
+      .collect();
+    if errors.is_empty() {
+      Ok(())
+    } else {
+      Err(CompoundCrossCredentialError { errors })
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json::json;
+
+  use super::*;
+
+  fn credentials() -> Vec<Value> {
+    vec![
+      json!({
+        "type": ["VerifiableCredential", "PassportCredential"],
+        "issuer": "did:example:state",
+        "credentialSubject": { "id": "did:example:alice", "name": "ALICE  Smith", "birthYear": 1990 },
+      }),
+      json!({
+        "type": ["VerifiableCredential", "UniversityDegreeCredential"],
+        "issuer": { "id": "did:example:university" },
+        "credentialSubject": { "id": "did:example:alice", "name": " Alice Smith", "birthYear": 1990 },
+      }),
+    ]
+  }
+
+  fn name_constraint(normalization: Normalization) -> CrossCredentialConstraints {
+    CrossCredentialConstraints::new().equal(
+      ClaimReference::of_type("PassportCredential", "/credentialSubject/name"),
+      ClaimReference::new(CredentialSelector::Index(1), "/credentialSubject/name"),
+      normalization,
+    )
+  }
+
+  #[test]
+  fn claims_are_compared_after_normalization() {
+    let credentials = credentials();
+    let error = name_constraint(Normalization::none().trim(true))
+      .check_json(&credentials)
+      .unwrap_err();
+    assert!(matches!(error.errors[..], [CrossCredentialError::ClaimMismatch { .. }]));
+    assert_eq!(
+      error.to_string(),
+      "[`PassportCredential/credentialSubject/name` does not match `#1/credentialSubject/name`]"
+    );
+
+    let normalization = Normalization::none().collapse_whitespace(true).case_insensitive(true);
+    assert!(name_constraint(normalization).check_json(&credentials).is_ok());
+
+    let birth_year = CrossCredentialConstraints::new().equal(
+      ClaimReference::new(CredentialSelector::Index(0), "/credentialSubject/birthYear"),
+      ClaimReference::new(CredentialSelector::Index(1), "/credentialSubject/birthYear"),
+      normalization,
+    );
+    assert!(birth_year.check_json(&credentials).is_ok());
+  }
+
+  #[test]
+  fn all_failures_are_reported() {
+    let constraints = CrossCredentialConstraints::new()
+      .with(CrossCredentialConstraint::SameSubject)
+      .with(CrossCredentialConstraint::SameIssuer)
+      .equal(
+        ClaimReference::of_type("DriverLicenseCredential", "/credentialSubject/name"),
+        ClaimReference::new(CredentialSelector::Index(1), "/credentialSubject/name"),
+        Normalization::none(),
+      )
+      .equal(
+        ClaimReference::new(CredentialSelector::Index(0), "/credentialSubject/nationality"),
+        ClaimReference::new(CredentialSelector::Index(1), "/credentialSubject/name"),
+        Normalization::none(),
+      );
+
+    let error = constraints.check_json(&credentials()).unwrap_err();
+    assert!(matches!(
+      error.errors[..],
+      [
+        CrossCredentialError::IssuerMismatch,
+        CrossCredentialError::UnknownCredential(_),
+        CrossCredentialError::MissingClaim(_)
+      ]
+    ));
+
+    let mut credentials = credentials();
+    credentials[1]["credentialSubject"]["id"] = json!("did:example:bob");
+    assert!(matches!(
+      CrossCredentialConstraints::new()
+        .with(CrossCredentialConstraint::SameSubject)
+        .check_json(&credentials)
+        .unwrap_err()
+        .errors[..],
+      [CrossCredentialError::SubjectMismatch]
+    ));
+  }
+
+  #[test]
+  fn constraints_round_trip_through_json() {
+    let constraints = name_constraint(Normalization::none().trim(true)).with(CrossCredentialConstraint::SameSubject);
+    let json = serde_json::to_value(&constraints).unwrap();
+    assert_eq!(json[0]["kind"], "equal");
+    assert_eq!(json[0]["left"]["credential"]["type"], "PassportCredential");
+    assert_eq!(json[1], json!({ "kind": "sameSubject" }));
+    assert_eq!(
+      serde_json::from_value::<CrossCredentialConstraints>(json).unwrap(),
+      constraints
+    );
+  }
+}
@@ -2,6 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::validator::SubjectHolderRelationship;
+use crate::validator::ValidationLimits;
+use identity_core::common::Clock;
+use identity_core::common::SharedClock;
 use identity_core::common::Timestamp;
 use identity_core::common::Url;
 use identity_document::verifiable::JwpVerificationOptions;
@@ -39,6 +42,16 @@ pub struct JptCredentialValidationOptions {
   /// Options which affect the verification of the proof on the credential.
   #[serde(default)]
   pub verification_options: JwpVerificationOptions,
+
+  /// Limits on the size and complexity of the credential, enforced before it is decoded.
+  #[serde(default)]
+  pub limits: ValidationLimits,
+
+  /// The clock providing the current datetime used when the dates above are not set.
+  ///
+  /// Default: [`SystemClock`](identity_core::common::SystemClock).
+  #[serde(skip)]
+  pub clock: SharedClock,
 }
 
 impl JptCredentialValidationOptions {
@@ -84,4 +97,16 @@ impl JptCredentialValidationOptions {
     self.verification_options = options;
     self
   }
+
+  /// Set the limits on the size and complexity of the credential.
+  pub fn limits(mut self, limits: ValidationLimits) -> Self {
+    self.limits = limits;
+    self
+  }
+
+  /// Set the clock providing the current datetime used when the dates above are not set.
+  pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+    self.clock = SharedClock::new(clock);
+    self
+  }
 }
@@ -1,6 +1,7 @@
 // Copyright 2020-2024 IOTA Stiftung, Fondazione Links
 // SPDX-License-Identifier: Apache-2.0
 
+use identity_core::common::Clock;
 use identity_core::convert::FromJson;
 use identity_core::convert::ToJson;
 use identity_did::CoreDID;
@@ -46,6 +47,13 @@ impl JptCredentialValidator {
     T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
     DOC: AsRef<CoreDocument>,
   {
+    options
+      .limits
+      .check_jpt(credential_jpt.as_str())
+      .map_err(|err| CompoundCredentialValidationError {
+        validation_errors: [err].into(),
+      })?;
+
     // First verify the JWP proof and decode the result into a credential token, then apply all other validations.
     let credential_token =
       Self::verify_proof(credential_jpt, issuer, &options.verification_options).map_err(|err| {
@@ -73,14 +81,14 @@ impl JptCredentialValidator {
     let expiry_date_validation = std::iter::once_with(|| {
       JwtCredentialValidatorUtils::check_expires_on_or_after(
         credential,
-        options.earliest_expiry_date.unwrap_or_default(),
+        options.earliest_expiry_date.unwrap_or_else(|| options.clock.now()),
       )
     });
 
     let issuance_date_validation = std::iter::once_with(|| {
       JwtCredentialValidatorUtils::check_issued_on_or_before(
         credential,
-        options.latest_issuance_date.unwrap_or_default(),
+        options.latest_issuance_date.unwrap_or_else(|| options.clock.now()),
       )
     });
 
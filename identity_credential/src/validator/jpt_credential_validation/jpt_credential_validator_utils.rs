@@ -6,6 +6,8 @@ use crate::revocation::RevocationDocumentExt;
 use crate::revocation::RevocationTimeframeStatus;
 use std::str::FromStr;
 
+use identity_core::common::Clock;
+use identity_core::common::Duration;
 use identity_core::common::Object;
 use identity_core::common::Timestamp;
 use identity_core::convert::FromJson;
@@ -17,6 +19,7 @@ use jsonprooftoken::jwp::issued::JwpIssuedDecoder;
 
 use crate::credential::CredentialJwtClaims;
 use crate::credential::Jpt;
+use crate::validator::JptCredentialValidationOptions;
 use crate::validator::JwtValidationError;
 use crate::validator::SignerContext;
 
@@ -82,11 +85,16 @@ impl JptCredentialValidatorUtils {
   }
 
   /// Check timeframe interval in credentialStatus with `RevocationTimeframeStatus`.
+  ///
+  /// The timeframe is checked at `validity_timeframe`, or at the current time of the
+  /// [clock](JptCredentialValidationOptions::clock) in `options` if not set. The
+  /// [status check](JptCredentialValidationOptions::status) in `options` decides how statuses are handled.
   pub fn check_timeframes_with_validity_timeframe_2024<T>(
     credential: &Credential<T>,
     validity_timeframe: Option<Timestamp>,
-    status_check: crate::validator::StatusCheck,
+    options: &JptCredentialValidationOptions,
   ) -> ValidationUnitResult {
+    let status_check: crate::validator::StatusCheck = options.status;
     if status_check == crate::validator::StatusCheck::SkipAll {
       return Ok(());
     }
@@ -98,7 +106,7 @@ impl JptCredentialValidatorUtils {
           let status: RevocationTimeframeStatus =
             RevocationTimeframeStatus::try_from(status).map_err(JwtValidationError::InvalidStatus)?;
 
-          Self::check_validity_timeframe(status, validity_timeframe)
+          Self::check_validity_timeframe(status, validity_timeframe, &options.clock)
         } else {
           if status_check == crate::validator::StatusCheck::SkipUnsupported {
             return Ok(());
@@ -112,11 +120,47 @@ impl JptCredentialValidatorUtils {
     }
   }
 
+  /// Returns the end of the validity timeframe of a credential using `RevocationTimeframe2024`.
+  ///
+  /// [None] is returned if the credential has no status, or a status of a different type.
+  pub fn validity_timeframe_end<T>(credential: &Credential<T>) -> ValidationUnitResult<Option<Timestamp>> {
+    match &credential.credential_status {
+      Some(status) if status.type_ == RevocationTimeframeStatus::TYPE => RevocationTimeframeStatus::try_from(status)
+        .map(|status| Some(status.end_validity_timeframe()))
+        .map_err(JwtValidationError::InvalidStatus),
+      _ => Ok(None),
+    }
+  }
+
+  /// Returns whether the validity timeframe of a credential using `RevocationTimeframe2024` ends within `window` from
+  /// `at`, which defaults to the current time of the [clock](JptCredentialValidationOptions::clock) in `options`.
+  /// When that is the case, the holder should ask the issuer to renew the credential.
+  ///
+  /// Credentials without a `RevocationTimeframe2024` status never expire this way, hence `false` is returned.
+  pub fn is_validity_timeframe_expiring<T>(
+    credential: &Credential<T>,
+    window: Duration,
+    at: Option<Timestamp>,
+    options: &JptCredentialValidationOptions,
+  ) -> ValidationUnitResult<bool> {
+    let Some(end_validity_timeframe) = Self::validity_timeframe_end(credential)? else {
+      return Ok(false);
+    };
+    let at = at.unwrap_or_else(|| options.clock.now());
+
+    // A window reaching beyond the range of valid timestamps certainly covers the end of the timeframe.
+    Ok(
+      at.checked_add(window)
+        .map_or(true, |window_end| window_end >= end_validity_timeframe),
+    )
+  }
+
   pub(crate) fn check_validity_timeframe(
     status: RevocationTimeframeStatus,
     validity_timeframe: Option<Timestamp>,
+    clock: &dyn Clock,
   ) -> ValidationUnitResult {
-    let timeframe = validity_timeframe.unwrap_or(Timestamp::now_utc());
+    let timeframe = validity_timeframe.unwrap_or_else(|| clock.now());
 
     let check = timeframe >= status.start_validity_timeframe() && timeframe <= status.end_validity_timeframe();
 
@@ -190,7 +234,8 @@ impl JptCredentialValidatorUtils {
 
   /// Checks whether the credential status has been revoked or the timeframe interval is INVALID
   ///
-  /// Only supports `RevocationTimeframe2024`.
+  /// Only supports `RevocationTimeframe2024`. `validity_timeframe` and `options` are used as in
+  /// [`Self::check_timeframes_with_validity_timeframe_2024`].
   pub fn check_timeframes_and_revocation_with_validity_timeframe_2024<
     DOC: AsRef<identity_document::document::CoreDocument> + ?Sized,
     T,
@@ -198,8 +243,9 @@ impl JptCredentialValidatorUtils {
     credential: &Credential<T>,
     issuer: &DOC,
     validity_timeframe: Option<Timestamp>,
-    status_check: crate::validator::StatusCheck,
+    options: &JptCredentialValidationOptions,
   ) -> ValidationUnitResult {
+    let status_check: crate::validator::StatusCheck = options.status;
     if status_check == crate::validator::StatusCheck::SkipAll {
       return Ok(());
     }
@@ -213,7 +259,8 @@ impl JptCredentialValidatorUtils {
 
           let revocation = std::iter::once_with(|| Self::check_revocation_bitmap(issuer, status.clone()));
 
-          let timeframes = std::iter::once_with(|| Self::check_validity_timeframe(status.clone(), validity_timeframe));
+          let timeframes =
+            std::iter::once_with(|| Self::check_validity_timeframe(status.clone(), validity_timeframe, &options.clock));
 
           let checks_iter = revocation.chain(timeframes);
 
@@ -238,3 +285,93 @@ impl JptCredentialValidatorUtils {
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::FixedClock;
+  use identity_core::common::Url;
+
+  use crate::credential::CredentialBuilder;
+  use crate::credential::Subject;
+  use crate::validator::StatusCheck;
+
+  use super::*;
+
+  fn start() -> Timestamp {
+    Timestamp::parse("2024-03-19T13:57:50Z").unwrap()
+  }
+
+  fn credential() -> Credential {
+    let status = RevocationTimeframeStatus::new(
+      Some(start()),
+      Duration::minutes(1),
+      Url::parse("did:example:issuer#my-revocation-service").unwrap(),
+      5,
+    )
+    .unwrap();
+
+    CredentialBuilder::default()
+      .issuer(Url::parse("did:example:issuer").unwrap())
+      .subject(Subject::with_id(Url::parse("did:example:holder").unwrap()))
+      .status(status)
+      .build()
+      .unwrap()
+  }
+
+  #[test]
+  fn validity_timeframe_is_checked_against_the_clock() {
+    let credential = credential();
+    let options = |now: Timestamp| {
+      JptCredentialValidationOptions::new()
+        .status_check(StatusCheck::Strict)
+        .clock(FixedClock::new(now))
+    };
+
+    let within = start().checked_add(Duration::seconds(30)).unwrap();
+    assert!(
+      JptCredentialValidatorUtils::check_timeframes_with_validity_timeframe_2024(&credential, None, &options(within))
+        .is_ok()
+    );
+
+    let after = start().checked_add(Duration::minutes(2)).unwrap();
+    assert!(matches!(
+      JptCredentialValidatorUtils::check_timeframes_with_validity_timeframe_2024(&credential, None, &options(after)),
+      Err(JwtValidationError::OutsideTimeframe)
+    ));
+    assert!(
+      JptCredentialValidatorUtils::check_timeframes_with_validity_timeframe_2024(
+        &credential,
+        Some(within),
+        &options(after)
+      )
+      .is_ok()
+    );
+  }
+
+  #[test]
+  fn expiring_validity_timeframe_is_checked_against_the_clock() {
+    let credential = credential();
+    let options = |now: Timestamp| JptCredentialValidationOptions::new().clock(FixedClock::new(now));
+    let window = Duration::seconds(15);
+
+    assert!(
+      !JptCredentialValidatorUtils::is_validity_timeframe_expiring(&credential, window, None, &options(start()))
+        .unwrap()
+    );
+    let almost_over = start().checked_add(Duration::seconds(50)).unwrap();
+    assert!(JptCredentialValidatorUtils::is_validity_timeframe_expiring(
+      &credential,
+      window,
+      None,
+      &options(almost_over)
+    )
+    .unwrap());
+    assert!(!JptCredentialValidatorUtils::is_validity_timeframe_expiring(
+      &credential,
+      window,
+      Some(start()),
+      &options(almost_over)
+    )
+    .unwrap());
+  }
+}
@@ -1,10 +1,15 @@
 // Copyright 2020-2024 IOTA Stiftung, Fondazione Links
 // SPDX-License-Identifier: Apache-2.0
 
+use identity_core::common::Clock;
+use identity_core::common::SharedClock;
 use identity_document::verifiable::JwpVerificationOptions;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::presentation::JptPredicate;
+use crate::validator::ValidationLimits;
+
 /// Criteria for validating a [`Presentation`](crate::presentation::Presentation).
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 #[non_exhaustive]
@@ -17,6 +22,21 @@ pub struct JptPresentationValidationOptions {
   /// Options which affect the verification of the proof on the credential.
   #[serde(default)]
   pub verification_options: JwpVerificationOptions,
+
+  /// Limits on the size and complexity of the presented credential, enforced before it is decoded.
+  #[serde(default)]
+  pub limits: ValidationLimits,
+
+  /// Predicates the presented credential's subject must satisfy.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub predicates: Vec<JptPredicate>,
+
+  /// The clock providing the current datetime used when checking the validity timeframe of the presented
+  /// credential.
+  ///
+  /// Default: [`SystemClock`](identity_core::common::SystemClock).
+  #[serde(skip)]
+  pub clock: SharedClock,
 }
 
 impl JptPresentationValidationOptions {
@@ -37,4 +57,23 @@ impl JptPresentationValidationOptions {
     self.verification_options = options;
     self
   }
+
+  /// Set the limits on the size and complexity of the presented credential.
+  pub fn limits(mut self, limits: ValidationLimits) -> Self {
+    self.limits = limits;
+    self
+  }
+
+  /// Require the presented credential's subject to satisfy `predicate`.
+  pub fn predicate(mut self, predicate: JptPredicate) -> Self {
+    self.predicates.push(predicate);
+    self
+  }
+
+  /// Set the clock providing the current datetime used when checking the validity timeframe of the presented
+  /// credential.
+  pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+    self.clock = SharedClock::new(clock);
+    self
+  }
 }
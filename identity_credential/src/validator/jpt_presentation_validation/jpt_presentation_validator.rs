@@ -17,6 +17,7 @@ use jsonprooftoken::jwp::presented::JwpPresentedDecoder;
 use crate::credential::Credential;
 use crate::credential::CredentialJwtClaims;
 use crate::credential::Jpt;
+use crate::presentation::JptPredicate;
 use crate::validator::CompoundCredentialValidationError;
 use crate::validator::FailFast;
 use crate::validator::JwtCredentialValidatorUtils;
@@ -38,7 +39,8 @@ impl JptPresentationValidator {
   /// - the holder's proof on the JWP,
   /// - the expiration date,
   /// - the issuance date,
-  /// - the semantic structure.
+  /// - the semantic structure,
+  /// - the predicates the presented claims must satisfy.
   pub fn validate<DOC, T>(
     presentation_jpt: &Jpt,
     issuer: &DOC,
@@ -49,6 +51,13 @@ impl JptPresentationValidator {
     T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
     DOC: AsRef<CoreDocument>,
   {
+    options
+      .limits
+      .check_jpt(presentation_jpt.as_str())
+      .map_err(|err| CompoundCredentialValidationError {
+        validation_errors: [err].into(),
+      })?;
+
     // First verify the JWP proof and decode the result into a presented credential token, then apply all other
     // validations.
     let presented_credential_token =
@@ -59,6 +68,7 @@ impl JptPresentationValidator {
     let credential: &Credential<T> = &presented_credential_token.credential;
 
     Self::validate_presented_credential::<T>(credential, fail_fast)?;
+    Self::check_predicates(credential, &options.predicates, fail_fast)?;
 
     Ok(presented_credential_token)
   }
@@ -87,6 +97,33 @@ impl JptPresentationValidator {
     }
   }
 
+  /// Checks that the subject of `credential` satisfies all `predicates`.
+  pub(crate) fn check_predicates<T>(
+    credential: &Credential<T>,
+    predicates: &[JptPredicate],
+    fail_fast: FailFast,
+  ) -> Result<(), CompoundCredentialValidationError> {
+    let subject = credential.credential_subject.get(0).map(|subject| &subject.properties);
+    let unsatisfied_predicates = predicates
+      .iter()
+      .filter(|predicate| {
+        !subject
+          .and_then(|subject| predicate.is_satisfied_by(subject))
+          .unwrap_or(false)
+      })
+      .map(|predicate| JwtValidationError::PredicateNotSatisfied(predicate.clone()));
+    let validation_errors: Vec<JwtValidationError> = match fail_fast {
+      FailFast::FirstError => unsatisfied_predicates.take(1).collect(),
+      FailFast::AllErrors => unsatisfied_predicates.collect(),
+    };
+
+    if validation_errors.is_empty() {
+      Ok(())
+    } else {
+      Err(CompoundCredentialValidationError { validation_errors })
+    }
+  }
+
   /// Proof verification function
   fn verify_proof<DOC, T>(
     presentation_jpt: &Jpt,
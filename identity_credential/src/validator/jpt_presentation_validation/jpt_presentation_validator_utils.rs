@@ -18,6 +18,7 @@ use crate::credential::Jpt;
 use crate::revocation::RevocationTimeframeStatus;
 use crate::revocation::VerifierRevocationTimeframeStatus;
 use crate::validator::JptCredentialValidatorUtils;
+use crate::validator::JptPresentationValidationOptions;
 use crate::validator::JwtValidationError;
 use crate::validator::SignerContext;
 
@@ -67,10 +68,14 @@ impl JptPresentationValidatorUtils {
   }
 
   /// Check timeframe interval in credentialStatus with `RevocationTimeframeStatus`.
+  ///
+  /// The timeframe is checked at `validity_timeframe`, or at the current time of the
+  /// [clock](JptPresentationValidationOptions::clock) in `options` if not set.
   pub fn check_timeframes_with_validity_timeframe_2024<T>(
     credential: &Credential<T>,
     validity_timeframe: Option<Timestamp>,
     status_check: crate::validator::StatusCheck,
+    options: &JptPresentationValidationOptions,
   ) -> ValidationUnitResult {
     if status_check == crate::validator::StatusCheck::SkipAll {
       return Ok(());
@@ -83,7 +88,7 @@ impl JptPresentationValidatorUtils {
           let status: VerifierRevocationTimeframeStatus =
             VerifierRevocationTimeframeStatus::try_from(status.clone()).map_err(JwtValidationError::InvalidStatus)?;
 
-          JptCredentialValidatorUtils::check_validity_timeframe(status.0, validity_timeframe)
+          JptCredentialValidatorUtils::check_validity_timeframe(status.0, validity_timeframe, &options.clock)
         } else {
           if status_check == crate::validator::StatusCheck::SkipUnsupported {
             return Ok(());
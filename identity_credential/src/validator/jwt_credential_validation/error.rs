@@ -4,6 +4,9 @@
 // This module is basically an adaptation of the old credential::validator::error module.
 use std::fmt::Display;
 
+use identity_core::error_code::ErrorCode;
+use identity_core::error_code::HasErrorCode;
+
 use itertools;
 
 /// An error associated with validating credentials and presentations.
@@ -58,6 +61,19 @@ pub enum JwtValidationError {
     signer_ctx: SignerContext,
   },
 
+  /// Indicates that the algorithm of the credential's (resp. presentation's) signature or the issuer's (resp.
+  /// holder's) key does not comply with the configured
+  /// [`AlgorithmPolicy`](identity_document::verifiable::AlgorithmPolicy).
+  #[error("the {signer_ctx}'s signature does not comply with the algorithm policy")]
+  #[non_exhaustive]
+  AlgorithmPolicy {
+    /// The policy violation.
+    source: identity_document::error::Error,
+    /// Specifies whether the error was from the DID Document of a credential issuer
+    /// or the presentation holder.
+    signer_ctx: SignerContext,
+  },
+
   /// Indicates that the credential's (resp. presentation's) issuer's (resp. holder's) URL could
   /// not be parsed as a valid DID.
   #[error("{signer_ctx} URL is not a valid DID")]
@@ -104,6 +120,20 @@ pub enum JwtValidationError {
   /// Indicates that the credential has been suspended.
   #[error("credential has been suspended")]
   Suspended,
+  /// Indicates that a credential is not bound to a key of its holder through a `cnf` claim.
+  #[error("the credential has no confirmation (`cnf`) claim")]
+  MissingConfirmation,
+  /// Indicates that a presentation was not signed with the holder key referenced by the `cnf` claim of a credential.
+  #[error("the presentation was not signed with the key referenced by the credential's confirmation (`cnf`) claim")]
+  ConfirmationMismatch,
+  /// Indicates that the key that signed the credential is no longer part of the issuer's current DID Document, see
+  /// [`KeyRevocationPolicy::Strict`](crate::validator::KeyRevocationPolicy::Strict).
+  #[error("the issuer's signing key is no longer part of its DID Document")]
+  SigningKeyRevoked,
+  /// Indicates that a credential or presentation exceeds one of the configured
+  /// [`ValidationLimits`](crate::validator::ValidationLimits).
+  #[error("validation limit exceeded")]
+  LimitExceeded(#[source] crate::validator::LimitExceeded),
   /// Indicates that the credential's timeframe interval is not valid
   #[cfg(feature = "jpt-bbs-plus")]
   #[error("timeframe interval not valid")]
@@ -116,6 +146,11 @@ pub enum JwtValidationError {
   #[cfg(feature = "jpt-bbs-plus")]
   #[error("could not verify jwp")]
   JwpProofVerificationError(#[source] jsonprooftoken::errors::CustomError),
+  /// Indicates that a presented JPT does not satisfy a required predicate, or that the claim the predicate refers to
+  /// is missing from the presentation.
+  #[cfg(feature = "jpt-bbs-plus")]
+  #[error("predicate \"{0}\" is not satisfied")]
+  PredicateNotSatisfied(crate::presentation::JptPredicate),
 }
 
 /// Specifies whether an error is related to a credential issuer or the presentation holder.
@@ -158,3 +193,44 @@ impl Display for CompoundCredentialValidationError {
 }
 
 impl std::error::Error for CompoundCredentialValidationError {}
+
+impl HasErrorCode for CompoundCredentialValidationError {
+  /// Returns the code of the compound error itself, the codes of the individual failures are available through
+  /// [`Self::validation_errors`].
+  fn error_code(&self) -> ErrorCode {
+    ErrorCode::new(1690, "CompoundCredentialValidationError")
+  }
+}
+
+identity_core::impl_error_code!(JwtValidationError {
+  JwsDecodingError => 1600,
+  PresentationJwsError => 1601,
+  MethodDataLookupError => 1602,
+  IdentifierMismatch => 1603,
+  ExpirationDate => 1604,
+  IssuanceDate => 1605,
+  Signature => 1606,
+  SignerUrl => 1607,
+  DocumentMismatch => 1608,
+  CredentialStructure => 1609,
+  PresentationStructure => 1610,
+  SubjectHolderRelationship => 1611,
+  MissingPresentationHolder => 1612,
+  InvalidStatus => 1613,
+  ServiceLookupError => 1614,
+  Revoked => 1615,
+  Suspended => 1616,
+  MissingConfirmation => 1620,
+  ConfirmationMismatch => 1621,
+  AlgorithmPolicy => 1622,
+  LimitExceeded => 1623,
+  SigningKeyRevoked => 1624,
+  #[cfg(feature = "jpt-bbs-plus")]
+  OutsideTimeframe => 1617,
+  #[cfg(feature = "jpt-bbs-plus")]
+  JwpDecodingError => 1618,
+  #[cfg(feature = "jpt-bbs-plus")]
+  JwpProofVerificationError => 1619,
+  #[cfg(feature = "jpt-bbs-plus")]
+  PredicateNotSatisfied => 1625,
+});
@@ -1,13 +1,19 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use identity_core::common::Clock;
+use identity_core::common::Duration;
+use identity_core::common::SharedClock;
 use identity_core::common::Timestamp;
 use identity_core::common::Url;
 use identity_document::verifiable::JwsVerificationOptions;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::validator::ContinuityPolicy;
+use crate::validator::KeyRevocationPolicy;
 use crate::validator::SubjectHolderRelationship;
+use crate::validator::ValidationLimits;
 
 /// Options to declare validation criteria for [`Credential`](crate::credential::Credential)s.
 #[non_exhaustive]
@@ -37,12 +43,64 @@ pub struct JwtCredentialValidationOptions {
   /// <https://www.w3.org/TR/vc-data-model/#subject-holder-relationships>
   pub subject_holder_relationship: Option<(Url, SubjectHolderRelationship)>,
 
+  /// The DID rotations of the credential subject accepted when checking the
+  /// [`subject_holder_relationship`](Self::subject_holder_relationship).
+  ///
+  /// Default: no rotation is accepted.
+  #[serde(default)]
+  pub continuity_policy: ContinuityPolicy,
+
   /// Options which affect the verification of the signature on the credential.
   #[serde(default)]
   pub verification_options: JwsVerificationOptions,
+
+  /// Limits on the size and complexity of the credential, enforced before it is decoded.
+  #[serde(default)]
+  pub limits: ValidationLimits,
+
+  /// Validates the credential as of this [`Timestamp`] rather than as of the current datetime.
+  ///
+  /// The dates above default to it when not set, and the issuer's signature is expected to be verified against the
+  /// issuer's DID Document as it was at this time, see
+  /// [`JwtCredentialValidator::validate_with_history`](crate::validator::JwtCredentialValidator::validate_with_history).
+  #[serde(default)]
+  pub validate_at: Option<Timestamp>,
+
+  /// How the removal of the issuer's signing key after [`validate_at`](Self::validate_at) is treated.
+  ///
+  /// Default: [`KeyRevocationPolicy::Strict`].
+  #[serde(default)]
+  pub key_revocation_policy: KeyRevocationPolicy,
+
+  /// How long after its removal a signing key is still accepted under [`KeyRevocationPolicy::Grace`].
+  ///
+  /// The grace period starts at [`key_removed_at`](Self::key_removed_at).
+  ///
+  /// Default: [`Self::DEFAULT_KEY_REVOCATION_GRACE_PERIOD`].
+  #[serde(default)]
+  pub key_revocation_grace_period: Option<Duration>,
+
+  /// The time at which the signing key was removed from the issuer's DID Document, as found in the issuer's
+  /// document history, e.g. the `next_update` in the metadata of the IOTA DID Document resolved as of
+  /// [`validate_at`](Self::validate_at).
+  ///
+  /// If not set, the grace period of [`KeyRevocationPolicy::Grace`] starts at the validation time instead, so that
+  /// only credentials validated as of less than [`key_revocation_grace_period`](Self::key_revocation_grace_period)
+  /// ago are accepted.
+  #[serde(default)]
+  pub key_removed_at: Option<Timestamp>,
+
+  /// The clock providing the current datetime used when the dates above are not set.
+  ///
+  /// Default: [`SystemClock`](identity_core::common::SystemClock).
+  #[serde(skip)]
+  pub clock: SharedClock,
 }
 
 impl JwtCredentialValidationOptions {
+  /// The default [`key_revocation_grace_period`](Self::key_revocation_grace_period).
+  pub const DEFAULT_KEY_REVOCATION_GRACE_PERIOD: Duration = Duration::days(30);
+
   /// Constructor that sets all options to their defaults.
   pub fn new() -> Self {
     Self::default()
@@ -80,9 +138,57 @@ impl JwtCredentialValidationOptions {
     self
   }
 
+  /// Sets the DID rotations of the credential subject accepted when checking the subject-holder relationship.
+  pub fn continuity_policy(mut self, policy: ContinuityPolicy) -> Self {
+    self.continuity_policy = policy;
+    self
+  }
+
   /// Set options which affect the verification of the JWS signature.
   pub fn verification_options(mut self, options: JwsVerificationOptions) -> Self {
     self.verification_options = options;
     self
   }
+  /// Set the limits on the size and complexity of the credential.
+  pub fn limits(mut self, limits: ValidationLimits) -> Self {
+    self.limits = limits;
+    self
+  }
+
+  /// Validate the credential as of `timestamp` rather than as of the current datetime.
+  pub fn validate_at(mut self, timestamp: Timestamp) -> Self {
+    self.validate_at = Some(timestamp);
+    self
+  }
+
+  /// Set how the removal of the issuer's signing key after [`validate_at`](Self::validate_at) is treated.
+  pub fn key_revocation_policy(mut self, policy: KeyRevocationPolicy) -> Self {
+    self.key_revocation_policy = policy;
+    self
+  }
+
+  /// Set how long after its removal a signing key is still accepted under [`KeyRevocationPolicy::Grace`].
+  pub fn key_revocation_grace_period(mut self, grace_period: Duration) -> Self {
+    self.key_revocation_grace_period = Some(grace_period);
+    self
+  }
+
+  /// Set the time at which the signing key was removed from the issuer's DID Document, which the grace period of
+  /// [`KeyRevocationPolicy::Grace`] starts at.
+  pub fn key_removed_at(mut self, timestamp: Timestamp) -> Self {
+    self.key_removed_at = Some(timestamp);
+    self
+  }
+
+  /// Set the clock providing the current datetime used when the dates above are not set.
+  pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+    self.clock = SharedClock::new(clock);
+    self
+  }
+
+  /// Returns the datetime the credential is validated as of: [`validate_at`](Self::validate_at) if set, the current
+  /// datetime otherwise.
+  pub(crate) fn validation_time(&self) -> Timestamp {
+    self.validate_at.unwrap_or_else(|| self.clock.now())
+  }
 }
@@ -3,6 +3,8 @@
 
 use std::str::FromStr as _;
 
+use identity_core::common::Clock;
+use identity_core::common::Duration;
 use identity_core::convert::FromJson;
 use identity_did::CoreDID;
 use identity_did::DIDUrl;
@@ -11,6 +13,7 @@ use identity_document::verifiable::JwsVerificationOptions;
 use identity_verification::jwk::Jwk;
 use identity_verification::jws::DecodedJws;
 use identity_verification::jws::Decoder;
+use identity_verification::jws::JwsHeader;
 use identity_verification::jws::JwsValidationItem;
 use identity_verification::jws::JwsVerifier;
 
@@ -25,8 +28,11 @@ use crate::credential::CredentialJwtClaims;
 use crate::credential::CredentialT;
 use crate::credential::Jwt;
 use crate::credential::JwtVcV2;
+use crate::validator::record_credential_validation;
+use crate::validator::validation_step;
 use crate::validator::DecodedJwtCredentialV2;
 use crate::validator::FailFast;
+use crate::validator::KeyRevocationPolicy;
 
 /// A type for decoding and validating [`Credential`]s.
 #[non_exhaustive]
@@ -62,6 +68,15 @@ impl<V: JwsVerifier> JwtCredentialValidator<V> {
   ///
   /// # Errors
   /// An error is returned whenever a validated condition is not satisfied.
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+      name = "validate_credential",
+      skip_all,
+      fields(issuer = %identity_did::DID::redacted(issuer.as_ref().id()), fail_fast = ?fail_fast),
+      err(Display)
+    )
+  )]
   pub fn validate<DOC, T>(
     &self,
     credential_jwt: &Jwt,
@@ -73,24 +88,116 @@ impl<V: JwsVerifier> JwtCredentialValidator<V> {
     T: Clone + serde::Serialize + serde::de::DeserializeOwned,
     DOC: AsRef<CoreDocument>,
   {
-    let credential_token = self
-      .verify_signature(
-        credential_jwt,
-        std::slice::from_ref(issuer.as_ref()),
-        &options.verification_options,
-      )
+    let result = validation_step("limits", || options.limits.check_jwt(credential_jwt.as_str()))
+      .and_then(|()| {
+        validation_step("signature", || {
+          self.verify_signature(
+            credential_jwt,
+            std::slice::from_ref(issuer.as_ref()),
+            &options.verification_options,
+          )
+        })
+      })
       .map_err(|err| CompoundCredentialValidationError {
         validation_errors: [err].into(),
-      })?;
+      })
+      .and_then(|credential_token: DecodedJwtCredential<T>| {
+        Self::validate_decoded_credential::<CoreDocument, T>(
+          &credential_token.credential,
+          std::slice::from_ref(issuer.as_ref()),
+          options,
+          fail_fast,
+        )?;
+        Ok(credential_token)
+      });
 
-    Self::validate_decoded_credential::<CoreDocument, T>(
-      &credential_token.credential,
-      std::slice::from_ref(issuer.as_ref()),
-      options,
-      fail_fast,
-    )?;
+    record_credential_validation(&result);
+    result
+  }
 
-    Ok(credential_token)
+  /// Decodes and validates a [`Credential`] issued as a JWT as of
+  /// [`validate_at`](JwtCredentialValidationOptions::validate_at), e.g. the time it was issued.
+  ///
+  /// `issuer_at` must be the issuer's DID Document as it was at that time, as obtained through historical resolution,
+  /// while `current_issuer` must be its up-to-date DID Document. The issuer's signature is verified against
+  /// `issuer_at`, so that rotating a key does not by itself invalidate the credentials it signed before. Whether the
+  /// signing key must still be part of `current_issuer` is determined by
+  /// [`key_revocation_policy`](JwtCredentialValidationOptions::key_revocation_policy). The credential's status is
+  /// checked against `current_issuer`.
+  ///
+  /// Otherwise, the same properties as in [`Self::validate`] are validated.
+  ///
+  /// # Errors
+  /// An error is returned whenever a validated condition is not satisfied, if `issuer_at` and `current_issuer`
+  /// are not DID Documents of the same DID, or if [`validate_at`](JwtCredentialValidationOptions::validate_at) is
+  /// earlier than the credential's issuance date.
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+      name = "validate_credential_with_history",
+      skip_all,
+      fields(issuer = %identity_did::DID::redacted(current_issuer.as_ref().id()), fail_fast = ?fail_fast),
+      err(Display)
+    )
+  )]
+  pub fn validate_with_history<DOC, T>(
+    &self,
+    credential_jwt: &Jwt,
+    issuer_at: &DOC,
+    current_issuer: &DOC,
+    options: &JwtCredentialValidationOptions,
+    fail_fast: FailFast,
+  ) -> Result<DecodedJwtCredential<T>, CompoundCredentialValidationError>
+  where
+    T: Clone + serde::Serialize + serde::de::DeserializeOwned,
+    DOC: AsRef<CoreDocument>,
+  {
+    let result = validation_step("limits", || options.limits.check_jwt(credential_jwt.as_str()))
+      .and_then(|()| {
+        if issuer_at.as_ref().id() == current_issuer.as_ref().id() {
+          Ok(())
+        } else {
+          Err(JwtValidationError::DocumentMismatch(SignerContext::Issuer))
+        }
+      })
+      .and_then(|()| {
+        validation_step("signature", || {
+          self.verify_signature(
+            credential_jwt,
+            std::slice::from_ref(issuer_at.as_ref()),
+            &options.verification_options,
+          )
+        })
+      })
+      .and_then(|credential_token: DecodedJwtCredential<T>| {
+        validation_step("validation_time", || {
+          Self::check_validated_after_issuance(&credential_token.credential, options)
+        })?;
+        validation_step("key_revocation", || {
+          Self::check_signing_key_not_revoked(
+            &credential_token.header,
+            issuer_at.as_ref(),
+            current_issuer.as_ref(),
+            options,
+          )
+        })?;
+        Ok(credential_token)
+      })
+      .map_err(|err| CompoundCredentialValidationError {
+        validation_errors: [err].into(),
+      })
+      .and_then(|credential_token: DecodedJwtCredential<T>| {
+        Self::validate_decoded_credential::<CoreDocument, T>(
+          &credential_token.credential,
+          std::slice::from_ref(current_issuer.as_ref()),
+          options,
+          fail_fast,
+        )?;
+        Ok(credential_token)
+      });
+
+    record_credential_validation(&result);
+    result
   }
 
   /// Decodes and validates a [CredentialV2](crate::credential::CredentialV2) issued as a JWT.
@@ -117,6 +224,15 @@ impl<V: JwsVerifier> JwtCredentialValidator<V> {
   ///
   /// # Errors
   /// An error is returned whenever a validated condition is not satisfied.
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+      name = "validate_credential",
+      skip_all,
+      fields(issuer = %identity_did::DID::redacted(issuer.as_ref().id()), fail_fast = ?fail_fast),
+      err(Display)
+    )
+  )]
   pub fn validate_v2<DOC, T>(
     &self,
     credential_jwt: &JwtVcV2,
@@ -128,24 +244,32 @@ impl<V: JwsVerifier> JwtCredentialValidator<V> {
     T: Clone + serde::Serialize + serde::de::DeserializeOwned,
     DOC: AsRef<CoreDocument>,
   {
-    let credential_token = Self::verify_signature_with_verifier_v2(
-      &self.0,
-      credential_jwt,
-      std::slice::from_ref(issuer.as_ref()),
-      &options.verification_options,
-    )
-    .map_err(|err| CompoundCredentialValidationError {
-      validation_errors: [err].into(),
-    })?;
-
-    Self::validate_decoded_credential(
-      &credential_token.credential,
-      std::slice::from_ref(issuer),
-      options,
-      fail_fast,
-    )?;
+    let result = validation_step("limits", || options.limits.check_jwt(credential_jwt.as_str()))
+      .and_then(|()| {
+        validation_step("signature", || {
+          Self::verify_signature_with_verifier_v2(
+            &self.0,
+            credential_jwt,
+            std::slice::from_ref(issuer.as_ref()),
+            &options.verification_options,
+          )
+        })
+      })
+      .map_err(|err| CompoundCredentialValidationError {
+        validation_errors: [err].into(),
+      })
+      .and_then(|credential_token: DecodedJwtCredentialV2<T>| {
+        Self::validate_decoded_credential(
+          &credential_token.credential,
+          std::slice::from_ref(issuer),
+          options,
+          fail_fast,
+        )?;
+        Ok(credential_token)
+      });
 
-    Ok(credential_token)
+    record_credential_validation(&result);
+    result
   }
 
   /// Decode and verify the JWS signature of a [`Credential`] issued as a JWT using the DID Document of a trusted
@@ -222,29 +346,46 @@ impl<V: JwsVerifier> JwtCredentialValidator<V> {
     // Run all single concern Credential validations in turn and fail immediately if `fail_fast` is true.
 
     let expiry_date_validation = std::iter::once_with(|| {
-      JwtCredentialValidatorUtils::check_expires_on_or_after(
-        credential,
-        options.earliest_expiry_date.unwrap_or_default(),
-      )
+      validation_step("expiration_date", || {
+        JwtCredentialValidatorUtils::check_expires_on_or_after(
+          credential,
+          options
+            .earliest_expiry_date
+            .unwrap_or_else(|| options.validation_time()),
+        )
+      })
     });
 
     let issuance_date_validation = std::iter::once_with(|| {
-      JwtCredentialValidatorUtils::check_issued_on_or_before(
-        credential,
-        options.latest_issuance_date.unwrap_or_default(),
-      )
+      validation_step("issuance_date", || {
+        JwtCredentialValidatorUtils::check_issued_on_or_before(
+          credential,
+          options
+            .latest_issuance_date
+            .unwrap_or_else(|| options.validation_time()),
+        )
+      })
     });
 
-    let structure_validation = std::iter::once_with(|| JwtCredentialValidatorUtils::check_structure(credential));
+    let structure_validation = std::iter::once_with(|| {
+      validation_step("structure", || JwtCredentialValidatorUtils::check_structure(credential))
+    });
 
     let subject_holder_validation = std::iter::once_with(|| {
-      options
-        .subject_holder_relationship
-        .as_ref()
-        .map(|(holder, relationship)| {
-          JwtCredentialValidatorUtils::check_subject_holder_relationship(credential, holder, *relationship)
-        })
-        .unwrap_or(Ok(()))
+      validation_step("subject_holder_relationship", || {
+        options
+          .subject_holder_relationship
+          .as_ref()
+          .map(|(holder, relationship)| {
+            JwtCredentialValidatorUtils::check_subject_holder_relationship_with_continuity(
+              credential,
+              holder,
+              *relationship,
+              &options.continuity_policy,
+            )
+          })
+          .unwrap_or(Ok(()))
+      })
     });
 
     let validation_units_iter = issuance_date_validation
@@ -254,8 +395,11 @@ impl<V: JwsVerifier> JwtCredentialValidator<V> {
 
     #[cfg(feature = "revocation-bitmap")]
     let validation_units_iter = {
-      let revocation_validation =
-        std::iter::once_with(|| JwtCredentialValidatorUtils::check_status(credential, issuers, options.status));
+      let revocation_validation = std::iter::once_with(|| {
+        validation_step("status", || {
+          JwtCredentialValidatorUtils::check_status(credential, issuers, options.status)
+        })
+      });
       validation_units_iter.chain(revocation_validation)
     };
 
@@ -272,6 +416,74 @@ impl<V: JwsVerifier> JwtCredentialValidator<V> {
     }
   }
 
+  /// Checks that the validation time in `options` is not earlier than the issuance date of `credential`, as the
+  /// issuer's DID Document at that time cannot vouch for a credential that was not issued yet.
+  fn check_validated_after_issuance<T>(
+    credential: &Credential<T>,
+    options: &JwtCredentialValidationOptions,
+  ) -> Result<(), JwtValidationError> {
+    match options.validate_at {
+      Some(validate_at) if validate_at < credential.issuance_date => Err(JwtValidationError::IssuanceDate),
+      _ => Ok(()),
+    }
+  }
+
+  /// Checks that the key that signed a credential, as found in `issuer_at`, is still part of `current_issuer` if
+  /// required by the [`KeyRevocationPolicy`] in `options`.
+  ///
+  /// Under [`KeyRevocationPolicy::Grace`] a removed key is only accepted until the grace period following its
+  /// removal, or following the validation time if the removal time is unknown, has elapsed. A grace period whose end
+  /// cannot be represented is treated as elapsed.
+  fn check_signing_key_not_revoked(
+    header: &JwsHeader,
+    issuer_at: &CoreDocument,
+    current_issuer: &CoreDocument,
+    options: &JwtCredentialValidationOptions,
+  ) -> Result<(), JwtValidationError> {
+    let within_grace_period = || {
+      let grace_period: Duration = options
+        .key_revocation_grace_period
+        .unwrap_or(JwtCredentialValidationOptions::DEFAULT_KEY_REVOCATION_GRACE_PERIOD);
+      options
+        .key_removed_at
+        .unwrap_or_else(|| options.validation_time())
+        .checked_add(grace_period)
+        .is_some_and(|grace_period_end| options.clock.now() <= grace_period_end)
+    };
+
+    let verification_options: &JwsVerificationOptions = &options.verification_options;
+    let method_id: DIDUrl = match &verification_options.method_id {
+      Some(method_id) => method_id.clone(),
+      None => header
+        .kid()
+        .ok_or(JwtValidationError::MethodDataLookupError {
+          source: None,
+          message: "could not extract kid from protected header",
+          signer_ctx: SignerContext::Issuer,
+        })
+        .and_then(|kid| {
+          DIDUrl::parse(kid).map_err(|err| JwtValidationError::MethodDataLookupError {
+            source: Some(err.into()),
+            message: "could not parse kid as a DID Url",
+            signer_ctx: SignerContext::Issuer,
+          })
+        })?,
+    };
+
+    let signing_key: Option<&Jwk> = issuer_at
+      .resolve_method(&method_id, verification_options.method_scope)
+      .and_then(|method| method.data().public_key_jwk());
+    let current_key: Option<&Jwk> = current_issuer
+      .resolve_method(&method_id, verification_options.method_scope)
+      .and_then(|method| method.data().public_key_jwk());
+
+    match (signing_key, current_key) {
+      (Some(signing_key), Some(current_key)) if signing_key == current_key => Ok(()),
+      _ if options.key_revocation_policy == KeyRevocationPolicy::Grace && within_grace_period() => Ok(()),
+      _ => Err(JwtValidationError::SigningKeyRevoked),
+    }
+  }
+
   pub(crate) fn parse_jwk<'a, 'i, DOC>(
     jws: &JwsValidationItem<'a>,
     trusted_issuers: &'i [DOC],
@@ -328,6 +540,7 @@ impl<V: JwsVerifier> JwtCredentialValidator<V> {
         message: "could not extract JWK from a method identified by kid",
         signer_ctx: SignerContext::Issuer,
       })
+      .and_then(|jwk| check_algorithm_policy(jws, [jwk], options, SignerContext::Issuer).map(|_| jwk))
       .map(move |jwk| (jwk, method_id))
   }
 
@@ -475,6 +688,26 @@ impl<V: JwsVerifier> JwtCredentialValidator<V> {
   }
 }
 
+/// Checks that the algorithm of `jws` and the `public_keys` used to verify it comply with the
+/// [`AlgorithmPolicy`](identity_document::verifiable::AlgorithmPolicy) of `options`, if any.
+pub(crate) fn check_algorithm_policy<'k>(
+  jws: &JwsValidationItem<'_>,
+  public_keys: impl IntoIterator<Item = &'k Jwk>,
+  options: &JwsVerificationOptions,
+  signer_ctx: SignerContext,
+) -> Result<(), JwtValidationError> {
+  let Some(policy) = &options.algorithm_policy else {
+    return Ok(());
+  };
+  let alg = jws.alg().ok_or(JwtValidationError::JwsDecodingError(
+    identity_verification::jose::error::Error::ProtectedHeaderWithoutAlg,
+  ))?;
+
+  policy
+    .check(&alg, public_keys)
+    .map_err(|source| JwtValidationError::AlgorithmPolicy { source, signer_ctx })
+}
+
 #[cfg(test)]
 mod tests {
   use crate::credential::Subject;
@@ -536,6 +769,24 @@ mod tests {
     .is_ok());
   }
 
+  #[test]
+  fn dates_are_checked_against_the_clock() {
+    let validate = |options: &JwtCredentialValidationOptions| {
+      JwtCredentialValidator::<Box<dyn JwsVerifier>>::validate_decoded_credential::<CoreDocument, Object>(
+        &*SIMPLE_CREDENTIAL,
+        &[],
+        &options.clone().status_check(crate::validator::StatusCheck::SkipAll),
+        FailFast::AllErrors,
+      )
+    };
+
+    // The credential expired in 2020.
+    assert!(validate(&JwtCredentialValidationOptions::new()).is_err());
+
+    let clock = identity_core::common::FixedClock::new(Timestamp::parse("2015-01-01T00:00:00Z").unwrap());
+    assert!(validate(&JwtCredentialValidationOptions::new().clock(clock)).is_ok());
+  }
+
   #[test]
   fn check_subject_holder_relationship() {
     let mut credential: Credential = SIMPLE_CREDENTIAL.clone();
@@ -7,12 +7,14 @@ use identity_did::DIDUrl;
 use identity_document::document::CoreDocument;
 use identity_document::verifiable::JwsVerificationOptions;
 use identity_verification::jwk::CompositeJwk;
+use identity_verification::jwk::Jwk;
 use identity_verification::jwk::PostQuantumJwk;
 use identity_verification::jwk::TraditionalJwk;
 use identity_verification::jws::DecodedJws;
 use identity_verification::jws::JwsValidationItem;
 use identity_verification::jws::JwsVerifier;
 
+use super::jwt_credential_validator::check_algorithm_policy;
 use super::CompoundCredentialValidationError;
 use super::DecodedJwtCredential;
 use super::JwtCredentialValidationOptions;
@@ -69,6 +71,13 @@ impl<TRV: JwsVerifier, PQV: JwsVerifier> JwtCredentialValidatorHybrid<TRV, PQV>
     T: Clone + serde::Serialize + serde::de::DeserializeOwned,
     DOC: AsRef<CoreDocument>,
   {
+    options
+      .limits
+      .check_jwt(credential_jwt.as_str())
+      .map_err(|err| CompoundCredentialValidationError {
+        validation_errors: [err].into(),
+      })?;
+
     let credential_token = self
       .verify_signature(
         credential_jwt,
@@ -174,6 +183,10 @@ impl<TRV: JwsVerifier, PQV: JwsVerifier> JwtCredentialValidatorHybrid<TRV, PQV>
         message: "could not extract CompositePublicKey from a method identified by kid",
         signer_ctx: SignerContext::Issuer,
       })
+      .and_then(|c: &CompositeJwk| {
+        let public_keys: [&Jwk; 2] = [c.traditional_public_key(), c.pq_public_key()];
+        check_algorithm_policy(jws, public_keys, options, SignerContext::Issuer).map(|_| c)
+      })
       .map(move |c: &CompositeJwk| (c, method_id))
   }
 
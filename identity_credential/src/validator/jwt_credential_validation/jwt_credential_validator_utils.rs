@@ -17,6 +17,7 @@ use crate::credential::CredentialT;
 use crate::credential::CredentialV2;
 #[cfg(feature = "status-list-2021")]
 use crate::revocation::status_list_2021::StatusList2021Credential;
+use crate::validator::ContinuityPolicy;
 use crate::validator::SubjectHolderRelationship;
 
 /// Utility functions for verifying JWT credentials.
@@ -95,10 +96,29 @@ impl JwtCredentialValidatorUtils {
     credential: &dyn CredentialT<Properties = T>,
     holder: &Url,
     relationship: SubjectHolderRelationship,
+  ) -> ValidationUnitResult {
+    Self::check_subject_holder_relationship_with_continuity(
+      credential,
+      holder,
+      relationship,
+      &ContinuityPolicy::default(),
+    )
+  }
+
+  /// Validate that the relationship between the `holder` and the credential subjects is in accordance with
+  /// `relationship`, where the holder also matches a subject whose DID it was rotated from according to `continuity`.
+  pub fn check_subject_holder_relationship_with_continuity<T>(
+    credential: &dyn CredentialT<Properties = T>,
+    holder: &Url,
+    relationship: SubjectHolderRelationship,
+    continuity: &ContinuityPolicy,
   ) -> ValidationUnitResult {
     let url_matches = || {
       if let [subject] = credential.subject().as_slice() {
-        subject.id.as_ref() == Some(holder)
+        subject
+          .id
+          .as_ref()
+          .map_or(false, |subject| continuity.matches(subject, holder))
       } else {
         false
       }
@@ -4,6 +4,9 @@
 use std::error::Error;
 use std::fmt::Display;
 
+use identity_core::error_code::ErrorCode;
+use identity_core::error_code::HasErrorCode;
+
 use crate::validator::jwt_credential_validation::JwtValidationError;
 
 /// Errors caused by a failure to validate a [`Presentation`](crate::presentation::Presentation).
@@ -34,3 +37,11 @@ impl Display for CompoundJwtPresentationValidationError {
 }
 
 impl Error for CompoundJwtPresentationValidationError {}
+
+impl HasErrorCode for CompoundJwtPresentationValidationError {
+  /// Returns the code of the compound error itself, the codes of the individual failures are available through
+  /// [`Self::presentation_validation_errors`].
+  fn error_code(&self) -> ErrorCode {
+    ErrorCode::new(1691, "CompoundJwtPresentationValidationError")
+  }
+}
@@ -4,9 +4,15 @@
 use serde::Deserialize;
 use serde::Serialize;
 
+use identity_core::common::Clock;
+use identity_core::common::SharedClock;
 use identity_core::common::Timestamp;
 use identity_document::verifiable::JwsVerificationOptions;
 
+use crate::validator::ValidationLimits;
+#[cfg(feature = "wallet-attestation")]
+use crate::wallet_attestation::DecodedWalletAttestation;
+
 /// Criteria for validating a [`Presentation`](crate::presentation::Presentation).
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 #[non_exhaustive]
@@ -27,6 +33,24 @@ pub struct JwtPresentationValidationOptions {
   /// Uses the current datetime during validation if not set.
   #[serde(default)]
   pub latest_issuance_date: Option<Timestamp>,
+
+  /// Limits on the size and complexity of the presentation, enforced before it is decoded.
+  #[serde(default)]
+  pub limits: ValidationLimits,
+
+  /// The clock providing the current datetime used when the dates above are not set.
+  ///
+  /// Default: [`SystemClock`](identity_core::common::SystemClock).
+  #[serde(skip)]
+  pub clock: SharedClock,
+
+  /// A validated Wallet Instance Attestation the presentation must be bound to.
+  ///
+  /// If set, the presentation is **not** considered valid unless it was signed with the wallet key attested in the
+  /// `cnf` claim of the attestation.
+  #[cfg(feature = "wallet-attestation")]
+  #[serde(skip)]
+  pub wallet_attestation: Option<DecodedWalletAttestation>,
 }
 
 impl JwtPresentationValidationOptions {
@@ -54,4 +78,26 @@ impl JwtPresentationValidationOptions {
     self.latest_issuance_date = Some(timestamp);
     self
   }
+
+  /// Set the limits on the size and complexity of the presentation.
+  pub fn limits(mut self, limits: ValidationLimits) -> Self {
+    self.limits = limits;
+    self
+  }
+
+  /// Set the clock providing the current datetime used when the dates above are not set.
+  pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+    self.clock = SharedClock::new(clock);
+    self
+  }
+
+  /// Require the presentation to be signed with the wallet key attested by `attestation`.
+  ///
+  /// The attestation must have been validated with a
+  /// [`WalletAttestationValidator`](crate::wallet_attestation::WalletAttestationValidator) beforehand.
+  #[cfg(feature = "wallet-attestation")]
+  pub fn wallet_attestation(mut self, attestation: DecodedWalletAttestation) -> Self {
+    self.wallet_attestation = Some(attestation);
+    self
+  }
 }
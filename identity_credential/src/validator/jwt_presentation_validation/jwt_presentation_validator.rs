@@ -1,6 +1,7 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use identity_core::common::Clock;
 use identity_core::common::Timestamp;
 use identity_core::convert::FromJson;
 use identity_did::CoreDID;
@@ -12,6 +13,8 @@ use std::str::FromStr;
 use crate::credential::Jwt;
 use crate::presentation::JwtPresentationV2Claims;
 use crate::presentation::PresentationJwtClaims;
+#[cfg(feature = "wallet-attestation")]
+use crate::validator::check_key_binding;
 use crate::validator::jwt_credential_validation::JwtValidationError;
 use crate::validator::jwt_credential_validation::SignerContext;
 
@@ -39,6 +42,7 @@ where
   /// - the JWT can be decoded into a semantically valid presentation.
   /// - the expiration and issuance date contained in the JWT claims.
   /// - the holder's signature.
+  /// - the binding of the holder's signing key to the wallet attestation, if one is required.
   ///
   /// Validation is done with respect to the properties set in `options`.
   ///
@@ -58,6 +62,15 @@ where
   /// # Errors
   ///
   /// An error is returned whenever a validated condition is not satisfied or when decoding fails.
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+      name = "validate_presentation",
+      skip_all,
+      fields(holder = %identity_did::DID::redacted(holder.as_ref().id())),
+      err(Display)
+    )
+  )]
   pub fn validate<HDOC, CRED, T>(
     &self,
     presentation: &Jwt,
@@ -69,6 +82,11 @@ where
     T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
     CRED: ToOwned<Owned = CRED> + serde::Serialize + serde::de::DeserializeOwned + Clone,
   {
+    options
+      .limits
+      .check_jwt(presentation.as_str())
+      .map_err(CompoundJwtPresentationValidationError::one_presentation_error)?;
+
     // Verify JWS.
     let decoded_jws: DecodedJws<'_> = holder
       .as_ref()
@@ -82,6 +100,13 @@ where
         CompoundJwtPresentationValidationError::one_presentation_error(JwtValidationError::PresentationJwsError(err))
       })?;
 
+    // Check the presentation comes from the attested wallet.
+    #[cfg(feature = "wallet-attestation")]
+    if let Some(attestation) = &options.wallet_attestation {
+      check_key_binding(attestation.confirmation(), &decoded_jws.protected, holder.as_ref())
+        .map_err(CompoundJwtPresentationValidationError::one_presentation_error)?;
+    }
+
     // Try V2 first.
     if let Ok(JwtPresentationV2Claims {
       vp,
@@ -92,12 +117,19 @@ where
     }) = serde_json::from_slice(&decoded_jws.claims)
     {
       check_holder(vp.holder.as_str(), holder.as_ref())?;
+      options
+        .limits
+        .check_credentials(vp.verifiable_credential.len())
+        .map_err(CompoundJwtPresentationValidationError::one_presentation_error)?;
 
       return Ok(DecodedJwtPresentation {
         presentation: vp,
         header: Box::new(decoded_jws.protected),
-        expiration_date: convert_and_check_exp(exp, options.earliest_expiry_date)?,
-        issuance_date: convert_and_check_iat(iat, options.latest_issuance_date)?,
+        expiration_date: convert_and_check_exp(
+          exp,
+          options.earliest_expiry_date.unwrap_or_else(|| options.clock.now()),
+        )?,
+        issuance_date: convert_and_check_iat(iat, options.latest_issuance_date.unwrap_or_else(|| options.clock.now()))?,
         aud,
         custom_claims,
       });
@@ -112,13 +144,20 @@ where
       })?;
 
     check_holder(claims.iss.as_str(), holder.as_ref())?;
-    let expiration_date = convert_and_check_exp(claims.exp, options.earliest_expiry_date)?;
+    options
+      .limits
+      .check_credentials(claims.vp.verifiable_credential.len())
+      .map_err(CompoundJwtPresentationValidationError::one_presentation_error)?;
+    let expiration_date = convert_and_check_exp(
+      claims.exp,
+      options.earliest_expiry_date.unwrap_or_else(|| options.clock.now()),
+    )?;
     let issuance_date = {
       let iat = claims
         .issuance_date
         .and_then(|id| id.to_issuance_date().ok())
         .map(|ts| ts.to_unix());
-      convert_and_check_iat(iat, options.latest_issuance_date)?
+      convert_and_check_iat(iat, options.latest_issuance_date.unwrap_or_else(|| options.clock.now()))?
     };
 
     let aud = claims.aud.take();
@@ -160,7 +199,7 @@ fn check_holder(holder: &str, holder_doc: &CoreDocument) -> Result<(), CompoundJ
 
 fn convert_and_check_exp(
   exp: Option<i64>,
-  earliest_expiry_date: Option<Timestamp>,
+  earliest_expiry_date: Timestamp,
 ) -> Result<Option<Timestamp>, CompoundJwtPresentationValidationError> {
   let Some(exp) = exp else {
     return Ok(None);
@@ -171,7 +210,7 @@ fn convert_and_check_exp(
     ))
   })?;
 
-  if exp >= earliest_expiry_date.unwrap_or_default() {
+  if exp >= earliest_expiry_date {
     Ok(Some(exp))
   } else {
     Err(CompoundJwtPresentationValidationError::one_presentation_error(
@@ -182,7 +221,7 @@ fn convert_and_check_exp(
 
 fn convert_and_check_iat(
   iat: Option<i64>,
-  latest_issuance_date: Option<Timestamp>,
+  latest_issuance_date: Timestamp,
 ) -> Result<Option<Timestamp>, CompoundJwtPresentationValidationError> {
   let Some(iat) = iat else {
     return Ok(None);
@@ -193,7 +232,7 @@ fn convert_and_check_iat(
     ))
   })?;
 
-  if iat <= latest_issuance_date.unwrap_or_default() {
+  if iat <= latest_issuance_date {
     Ok(Some(iat))
   } else {
     Err(CompoundJwtPresentationValidationError::one_presentation_error(
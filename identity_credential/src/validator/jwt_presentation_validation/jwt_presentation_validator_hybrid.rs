@@ -1,6 +1,7 @@
 // Copyright 2020-2025 IOTA Stiftung, Fondazione Links
 // SPDX-License-Identifier: Apache-2.0
 
+use identity_core::common::Clock;
 use identity_core::common::Object;
 use identity_core::common::StringOrUrl;
 use identity_core::common::Timestamp;
@@ -73,6 +74,11 @@ where
     T: Clone + serde::Serialize + serde::de::DeserializeOwned,
     CRED: Clone + serde::Serialize + serde::de::DeserializeOwned + Clone,
   {
+    options
+      .limits
+      .check_jwt(presentation.as_str())
+      .map_err(CompoundJwtPresentationValidationError::one_presentation_error)?;
+
     // Verify JWS.
     let decoded_jws: DecodedJws<'_> = holder
       .as_ref()
@@ -108,6 +114,11 @@ where
       ));
     }
 
+    options
+      .limits
+      .check_credentials(claims.vp.verifiable_credential.len())
+      .map_err(CompoundJwtPresentationValidationError::one_presentation_error)?;
+
     // Check the expiration date.
     let expiration_date: Option<Timestamp> = claims
       .exp
@@ -120,7 +131,7 @@ where
       })
       .transpose()?;
 
-    if expiration_date.is_some_and(|exp| exp < options.earliest_expiry_date.unwrap_or_default()) {
+    if expiration_date.is_some_and(|exp| exp < options.earliest_expiry_date.unwrap_or_else(|| options.clock.now())) {
       return Err(CompoundJwtPresentationValidationError::one_presentation_error(
         JwtValidationError::ExpirationDate,
       ));
@@ -141,7 +152,7 @@ where
       None => None,
     };
 
-    if issuance_date.is_some_and(|iss| iss > options.latest_issuance_date.unwrap_or_default()) {
+    if issuance_date.is_some_and(|iss| iss > options.latest_issuance_date.unwrap_or_else(|| options.clock.now())) {
       return Err(CompoundJwtPresentationValidationError::one_presentation_error(
         JwtValidationError::IssuanceDate,
       ));
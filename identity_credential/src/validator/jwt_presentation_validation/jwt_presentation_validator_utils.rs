@@ -3,17 +3,23 @@
 
 use identity_core::common::Object;
 use identity_core::convert::FromJson;
+use identity_did::DIDUrl;
 use identity_did::DID;
+use identity_document::document::CoreDocument;
 use identity_verification::jws::Decoder;
+use identity_verification::jws::JwsHeader;
 use serde_json::Value;
 use std::str::FromStr;
 
+use crate::credential::Confirmation;
 use crate::credential::Jwt;
 use crate::presentation::JwtPresentationV2Claims;
 use crate::presentation::Presentation;
 use crate::presentation::PresentationJwtClaims;
 use crate::validator::jwt_credential_validation::JwtValidationError;
 use crate::validator::jwt_credential_validation::SignerContext;
+use crate::validator::DecodedJwtCredential;
+use crate::validator::DecodedJwtPresentation;
 
 /// Utility functions for verifying JWT presentations.
 #[non_exhaustive]
@@ -53,6 +59,39 @@ impl JwtPresentationValidatorUtils {
     })
   }
 
+  /// Checks that `presentation` was signed with the holder key referenced by the confirmation (`cnf`) claim of
+  /// `credential`.
+  ///
+  /// A `kid` confirmation must reference the very verification method that signed the presentation, while a `jwk`
+  /// confirmation must have the same [thumbprint](identity_verification::jwk::Jwk::thumbprint_eq) as the key of
+  /// that method.
+  ///
+  /// # Warning
+  /// This method assumes both `credential` and `presentation` were validated, the latter using `holder`.
+  ///
+  /// # Errors
+  /// * [`JwtValidationError::MissingConfirmation`] if `credential` has no `cnf` claim.
+  /// * [`JwtValidationError::ConfirmationMismatch`] if `presentation` was signed with a different key.
+  pub fn check_confirmation<CRED, T, U, DOC>(
+    credential: &DecodedJwtCredential<U>,
+    presentation: &DecodedJwtPresentation<CRED, T>,
+    holder: &DOC,
+  ) -> Result<(), JwtValidationError>
+  where
+    DOC: AsRef<CoreDocument> + ?Sized,
+  {
+    let confirmation = credential
+      .custom_claims
+      .as_ref()
+      .map(Confirmation::from_claims)
+      .transpose()
+      .map_err(JwtValidationError::CredentialStructure)?
+      .flatten()
+      .ok_or(JwtValidationError::MissingConfirmation)?;
+
+    check_key_binding(&confirmation, &presentation.header, holder.as_ref())
+  }
+
   /// Validates the semantic structure of the `Presentation`.
   pub fn check_structure<U>(presentation: &Presentation<U>) -> Result<(), JwtValidationError> {
     presentation
@@ -60,3 +99,50 @@ impl JwtPresentationValidatorUtils {
       .map_err(JwtValidationError::PresentationStructure)
   }
 }
+
+/// Checks that the JWS with protected header `header` was signed by `holder` with the key referenced by
+/// `confirmation`.
+pub(crate) fn check_key_binding(
+  confirmation: &Confirmation,
+  header: &JwsHeader,
+  holder: &CoreDocument,
+) -> Result<(), JwtValidationError> {
+  let method_id = header
+    .kid()
+    .ok_or(JwtValidationError::MethodDataLookupError {
+      source: None,
+      message: "missing kid in the presentation's JWS header",
+      signer_ctx: SignerContext::Holder,
+    })
+    .and_then(|kid| {
+      DIDUrl::parse(kid).map_err(|err| JwtValidationError::MethodDataLookupError {
+        source: Some(err.into()),
+        message: "could not parse kid as a DID Url",
+        signer_ctx: SignerContext::Holder,
+      })
+    })?;
+  if holder.id() != method_id.did() {
+    return Err(JwtValidationError::DocumentMismatch(SignerContext::Holder));
+  }
+
+  let is_bound = match confirmation {
+    Confirmation::Kid(kid) => DIDUrl::parse(kid).is_ok_and(|cnf_method_id| cnf_method_id == method_id),
+    Confirmation::Jwk(jwk) => {
+      let holder_jwk = holder
+        .resolve_method(&method_id, None)
+        .and_then(|method| method.data().public_key_jwk())
+        .ok_or(JwtValidationError::MethodDataLookupError {
+          source: None,
+          message: "could not extract JWK from a method identified by kid",
+          signer_ctx: SignerContext::Holder,
+        })?;
+      jwk.thumbprint_eq(holder_jwk)
+    }
+  };
+
+  if is_bound {
+    Ok(())
+  } else {
+    Err(JwtValidationError::ConfirmationMismatch)
+  }
+}
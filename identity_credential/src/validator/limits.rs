@@ -0,0 +1,377 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::validator::JwtValidationError;
+
+/// Hard limits on the size and complexity of the credentials and presentations accepted by the validators.
+///
+/// Limits are enforced before any expensive processing takes place, protecting verifiers against pathological inputs
+/// crafted to exhaust their resources. Inputs exceeding a limit are rejected with
+/// [`JwtValidationError::LimitExceeded`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(rename_all = "camelCase", default)]
+pub struct ValidationLimits {
+  /// The maximum size, in bytes, of a JWT, SD-JWT or JPT.
+  ///
+  /// Default: 1 MiB.
+  pub max_jwt_size: usize,
+  /// The maximum nesting depth of objects and arrays in the claims of a JWT, the disclosures of an SD-JWT or the
+  /// payloads of a JPT.
+  ///
+  /// Default: 32.
+  pub max_claim_depth: usize,
+  /// The maximum number of credentials in a presentation.
+  ///
+  /// Default: 1000.
+  pub max_credentials_per_presentation: usize,
+  /// The maximum number of disclosures in an SD-JWT, or of payloads in a JPT.
+  ///
+  /// Default: 1000.
+  pub max_disclosures: usize,
+}
+
+impl Default for ValidationLimits {
+  fn default() -> Self {
+    Self {
+      max_jwt_size: 1_048_576,
+      max_claim_depth: 32,
+      max_credentials_per_presentation: 1000,
+      max_disclosures: 1000,
+    }
+  }
+}
+
+impl ValidationLimits {
+  /// Limits that are never exceeded.
+  pub const fn unlimited() -> Self {
+    Self {
+      max_jwt_size: usize::MAX,
+      max_claim_depth: usize::MAX,
+      max_credentials_per_presentation: usize::MAX,
+      max_disclosures: usize::MAX,
+    }
+  }
+
+  /// Sets the maximum size, in bytes, of a JWT, SD-JWT or JPT.
+  pub fn max_jwt_size(mut self, max_jwt_size: usize) -> Self {
+    self.max_jwt_size = max_jwt_size;
+    self
+  }
+
+  /// Sets the maximum nesting depth of objects and arrays in the claims of a JWT, the disclosures of an SD-JWT or
+  /// the payloads of a JPT.
+  pub fn max_claim_depth(mut self, max_claim_depth: usize) -> Self {
+    self.max_claim_depth = max_claim_depth;
+    self
+  }
+
+  /// Sets the maximum number of credentials in a presentation.
+  pub fn max_credentials_per_presentation(mut self, max_credentials_per_presentation: usize) -> Self {
+    self.max_credentials_per_presentation = max_credentials_per_presentation;
+    self
+  }
+
+  /// Sets the maximum number of disclosures in an SD-JWT, or of payloads in a JPT.
+  pub fn max_disclosures(mut self, max_disclosures: usize) -> Self {
+    self.max_disclosures = max_disclosures;
+    self
+  }
+
+  /// Checks the size of `jwt` and the nesting depth of its claims. For SD-JWTs, only the claims of the issuer-signed
+  /// JWT are checked, see [`ValidationLimits::check_sd_jwt`] for a complete check.
+  ///
+  /// Malformed JWTs are not rejected by this method, they are left to the subsequent decoding step.
+  pub(crate) fn check_jwt(&self, jwt: &str) -> Result<(), JwtValidationError> {
+    if jwt.len() > self.max_jwt_size {
+      return Err(JwtValidationError::LimitExceeded(LimitExceeded::JwtSize(
+        self.max_jwt_size,
+      )));
+    }
+
+    let compact_jws: &str = jwt.split('~').next().unwrap_or_default();
+    let Some(payload) = compact_jws.split('.').nth(1) else {
+      return Ok(());
+    };
+    let Ok(claims) = identity_verification::jwu::decode_b64(payload) else {
+      return Ok(());
+    };
+    if json_depth(&claims) > self.max_claim_depth {
+      return Err(JwtValidationError::LimitExceeded(LimitExceeded::ClaimDepth(
+        self.max_claim_depth,
+      )));
+    }
+
+    Ok(())
+  }
+
+  /// Checks the size of the serialized `sd_jwt`, the number of its disclosures and the nesting depth of the claims of
+  /// its issuer-signed JWT and of its disclosures.
+  ///
+  /// Untrusted SD-JWTs should be checked with this method before being parsed with
+  /// [`SdJwt::parse`](sd_jwt::SdJwt::parse). Malformed SD-JWTs are not rejected by this method, they are left to
+  /// parsing.
+  #[cfg(feature = "sd-jwt")]
+  pub fn check_sd_jwt(&self, sd_jwt: &str) -> Result<(), JwtValidationError> {
+    self.check_jwt(sd_jwt)?;
+
+    // Disclosures sit between the issuer-signed JWT and the, possibly empty, key binding JWT.
+    let disclosures: &str = sd_jwt
+      .split_once('~')
+      .and_then(|(_, rest)| rest.rsplit_once('~'))
+      .map_or("", |(disclosures, _)| disclosures);
+    let disclosures = disclosures.split('~').filter(|disclosure| !disclosure.is_empty());
+    self.check_disclosures(disclosures.clone().count())?;
+
+    for disclosure in disclosures {
+      let Ok(disclosure) = identity_verification::jwu::decode_b64(disclosure) else {
+        continue;
+      };
+      // Disclosures are arrays wrapping the disclosed value.
+      if json_depth(&disclosure).saturating_sub(1) > self.max_claim_depth {
+        return Err(JwtValidationError::LimitExceeded(LimitExceeded::ClaimDepth(
+          self.max_claim_depth,
+        )));
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Checks the size of the serialized `jpt`, the number of its payloads and the nesting depth of its headers and
+  /// payloads, in either the issued or the presented form.
+  ///
+  /// Malformed JPTs are not rejected by this method, they are left to the subsequent decoding step.
+  #[cfg(feature = "jpt-bbs-plus")]
+  pub(crate) fn check_jpt(&self, jpt: &str) -> Result<(), JwtValidationError> {
+    if jpt.len() > self.max_jwt_size {
+      return Err(JwtValidationError::LimitExceeded(LimitExceeded::JwtSize(
+        self.max_jwt_size,
+      )));
+    }
+
+    // Both forms end with the payloads and the proof, preceded by one or two headers.
+    let mut segments = jpt.rsplit('.').skip(1);
+    let Some(payloads) = segments.next() else {
+      return Ok(());
+    };
+    self.check_disclosures(payloads.split('~').count())?;
+
+    let exceeds_depth = |segment: &str| {
+      identity_verification::jwu::decode_b64(segment).is_ok_and(|json| json_depth(&json) > self.max_claim_depth)
+    };
+    if payloads.split('~').chain(segments).any(exceeds_depth) {
+      return Err(JwtValidationError::LimitExceeded(LimitExceeded::ClaimDepth(
+        self.max_claim_depth,
+      )));
+    }
+
+    Ok(())
+  }
+
+  /// Checks the nesting depth of `claims`, such as the claims of an SD-JWT after its disclosures were substituted.
+  #[cfg(feature = "sd-jwt")]
+  pub(crate) fn check_claims(&self, claims: &serde_json::Value) -> Result<(), JwtValidationError> {
+    if value_depth(claims) > self.max_claim_depth {
+      Err(JwtValidationError::LimitExceeded(LimitExceeded::ClaimDepth(
+        self.max_claim_depth,
+      )))
+    } else {
+      Ok(())
+    }
+  }
+
+  /// Checks the number of credentials in a presentation.
+  pub(crate) fn check_credentials(&self, count: usize) -> Result<(), JwtValidationError> {
+    if count > self.max_credentials_per_presentation {
+      Err(JwtValidationError::LimitExceeded(LimitExceeded::Credentials(
+        self.max_credentials_per_presentation,
+      )))
+    } else {
+      Ok(())
+    }
+  }
+
+  /// Checks the number of disclosures in an SD-JWT, or of payloads in a JPT.
+  #[cfg(any(feature = "sd-jwt", feature = "jpt-bbs-plus"))]
+  pub(crate) fn check_disclosures(&self, count: usize) -> Result<(), JwtValidationError> {
+    if count > self.max_disclosures {
+      Err(JwtValidationError::LimitExceeded(LimitExceeded::Disclosures(
+        self.max_disclosures,
+      )))
+    } else {
+      Ok(())
+    }
+  }
+}
+
+/// The [`ValidationLimits`] that was exceeded, together with its value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum LimitExceeded {
+  /// See [`ValidationLimits::max_jwt_size`].
+  #[error("the JWT exceeds the maximum size of {0} bytes")]
+  JwtSize(usize),
+  /// See [`ValidationLimits::max_claim_depth`].
+  #[error("the JWT claims exceed the maximum nesting depth of {0}")]
+  ClaimDepth(usize),
+  /// See [`ValidationLimits::max_credentials_per_presentation`].
+  #[error("the presentation exceeds the maximum number of {0} credentials")]
+  Credentials(usize),
+  /// See [`ValidationLimits::max_disclosures`].
+  #[error("the SD-JWT or JPT exceeds the maximum number of {0} disclosures or payloads")]
+  Disclosures(usize),
+}
+
+/// Returns the maximum nesting depth of objects and arrays in `json`, without parsing it.
+fn json_depth(json: &[u8]) -> usize {
+  let mut depth: usize = 0;
+  let mut max_depth: usize = 0;
+  let mut in_string: bool = false;
+  let mut escaped: bool = false;
+  for byte in json {
+    if in_string {
+      match byte {
+        _ if escaped => escaped = false,
+        b'\\' => escaped = true,
+        b'"' => in_string = false,
+        _ => {}
+      }
+      continue;
+    }
+
+    match byte {
+      b'"' => in_string = true,
+      b'{' | b'[' => {
+        depth += 1;
+        max_depth = max_depth.max(depth);
+      }
+      b'}' | b']' => depth = depth.saturating_sub(1),
+      _ => {}
+    }
+  }
+
+  max_depth
+}
+
+/// Returns the maximum nesting depth of objects and arrays in `value`.
+#[cfg(feature = "sd-jwt")]
+fn value_depth(value: &serde_json::Value) -> usize {
+  use serde_json::Value;
+
+  // Iterative, as substituted disclosures can nest deeper than the recursion limit of the JSON parser.
+  let mut max_depth: usize = 0;
+  let mut stack: Vec<(&Value, usize)> = vec![(value, 0)];
+  while let Some((value, depth)) = stack.pop() {
+    match value {
+      Value::Object(object) => stack.extend(object.values().map(|child| (child, depth + 1))),
+      Value::Array(array) => stack.extend(array.iter().map(|child| (child, depth + 1))),
+      _ => continue,
+    }
+    max_depth = max_depth.max(depth + 1);
+  }
+
+  max_depth
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_verification::jwu::encode_b64;
+
+  use super::*;
+
+  #[test]
+  fn json_depth_ignores_brackets_in_strings() {
+    assert_eq!(json_depth(br#"{}"#), 1);
+    assert_eq!(json_depth(br#"{"a":[{"b":1}],"c":{}}"#), 3);
+    assert_eq!(json_depth(br#"{"a":"[[[{{{\"]]]"}"#), 1);
+  }
+
+  #[test]
+  fn oversized_jwts_are_rejected() {
+    let jwt = format!("eyJhbGciOiJFZERTQSJ9.{}.c2ln", encode_b64(r#"{"iss":"did:example:1"}"#));
+    let limits = ValidationLimits::default();
+    assert!(limits.check_jwt(&jwt).is_ok());
+    assert!(matches!(
+      limits.max_jwt_size(jwt.len() - 1).check_jwt(&jwt),
+      Err(JwtValidationError::LimitExceeded(LimitExceeded::JwtSize(_)))
+    ));
+  }
+
+  #[test]
+  fn deeply_nested_claims_are_rejected() {
+    let claims = format!("{}{}", "[".repeat(33), "]".repeat(33));
+    let jwt = format!("eyJhbGciOiJFZERTQSJ9.{}.c2ln", encode_b64(claims));
+    assert!(matches!(
+      ValidationLimits::default().check_jwt(&jwt),
+      Err(JwtValidationError::LimitExceeded(LimitExceeded::ClaimDepth(32)))
+    ));
+    assert!(ValidationLimits::unlimited().check_jwt(&jwt).is_ok());
+  }
+
+  #[cfg(feature = "sd-jwt")]
+  #[test]
+  fn sd_jwt_limits_are_checked_before_parsing() {
+    let jwt = format!("eyJhbGciOiJFZERTQSJ9.{}.c2ln", encode_b64(r#"{"iss":"did:example:1"}"#));
+    let disclosure = encode_b64(r#"["c2FsdA","name","value"]"#);
+    let sd_jwt = format!("{jwt}~{disclosure}~{disclosure}~");
+    let limits = ValidationLimits::default();
+    assert!(limits.check_sd_jwt(&sd_jwt).is_ok());
+    assert!(limits
+      .max_disclosures(2)
+      .check_sd_jwt(&format!("{sd_jwt}kb.jwt.sig"))
+      .is_ok());
+    assert!(matches!(
+      limits.max_disclosures(1).check_sd_jwt(&sd_jwt),
+      Err(JwtValidationError::LimitExceeded(LimitExceeded::Disclosures(1)))
+    ));
+    assert!(matches!(
+      limits.max_jwt_size(sd_jwt.len() - 1).check_sd_jwt(&sd_jwt),
+      Err(JwtValidationError::LimitExceeded(LimitExceeded::JwtSize(_)))
+    ));
+
+    let nested = encode_b64(format!(r#"["c2FsdA","name",{}1{}]"#, "[".repeat(33), "]".repeat(33)));
+    assert!(matches!(
+      limits.check_sd_jwt(&format!("{jwt}~{nested}~")),
+      Err(JwtValidationError::LimitExceeded(LimitExceeded::ClaimDepth(32)))
+    ));
+    assert!(limits
+      .max_claim_depth(33)
+      .check_sd_jwt(&format!("{jwt}~{nested}~"))
+      .is_ok());
+  }
+
+  #[cfg(feature = "sd-jwt")]
+  #[test]
+  fn value_depth_counts_objects_and_arrays() {
+    assert_eq!(value_depth(&serde_json::json!(1)), 0);
+    assert_eq!(value_depth(&serde_json::json!({ "a": [{ "b": 1 }], "c": {} })), 3);
+  }
+
+  #[cfg(feature = "jpt-bbs-plus")]
+  #[test]
+  fn jpt_limits_are_checked() {
+    let header = encode_b64(r#"{"alg":"BBS","claims":["a","b"]}"#);
+    let payload = encode_b64("1");
+    let jpt = format!("{header}.{payload}~{payload}.cHJvb2Y");
+    let limits = ValidationLimits::default();
+    assert!(limits.check_jpt(&jpt).is_ok());
+    assert!(matches!(
+      limits.max_disclosures(1).check_jpt(&jpt),
+      Err(JwtValidationError::LimitExceeded(LimitExceeded::Disclosures(1)))
+    ));
+    assert!(matches!(
+      limits.max_jwt_size(jpt.len() - 1).check_jpt(&jpt),
+      Err(JwtValidationError::LimitExceeded(LimitExceeded::JwtSize(_)))
+    ));
+
+    let nested = encode_b64(format!("{}{}", "[".repeat(33), "]".repeat(33)));
+    assert!(matches!(
+      limits.check_jpt(&format!("{header}.{header}.{payload}~~{nested}.cHJvb2Y")),
+      Err(JwtValidationError::LimitExceeded(LimitExceeded::ClaimDepth(32)))
+    ));
+  }
+}
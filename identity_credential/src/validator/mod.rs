@@ -3,26 +3,75 @@
 
 //! Verifiable Credential and Presentation validators.
 
+pub use self::any_credential::*;
+pub use self::continuity::ContinuityError;
+pub use self::continuity::ContinuityPolicy;
+pub use self::continuity::DidRotation;
+pub use self::cross_credential::*;
 #[cfg(feature = "jpt-bbs-plus")]
 pub use self::jpt_credential_validation::*;
 #[cfg(feature = "jpt-bbs-plus")]
 pub use self::jpt_presentation_validation::*;
 pub use self::jwt_credential_validation::*;
 pub use self::jwt_presentation_validation::*;
+pub use self::limits::LimitExceeded;
+pub use self::limits::ValidationLimits;
 pub use self::options::FailFast;
+pub use self::options::KeyRevocationPolicy;
 pub use self::options::StatusCheck;
 pub use self::options::SubjectHolderRelationship;
+pub use self::policy_evaluator::*;
 #[cfg(feature = "sd-jwt")]
 pub use self::sd_jwt::*;
 
+mod any_credential;
+mod continuity;
+mod cross_credential;
 #[cfg(feature = "jpt-bbs-plus")]
 mod jpt_credential_validation;
 #[cfg(feature = "jpt-bbs-plus")]
 mod jpt_presentation_validation;
 mod jwt_credential_validation;
 mod jwt_presentation_validation;
+mod limits;
 mod options;
+mod policy_evaluator;
 #[cfg(feature = "sd-jwt")]
 mod sd_jwt;
 #[cfg(test)]
 pub(crate) mod test_utils;
+
+/// Runs a single validation `step`, recorded as a `validation_step` span when the `tracing` feature is enabled.
+pub(crate) fn validation_step<T, E: std::fmt::Display>(
+  name: &'static str,
+  step: impl FnOnce() -> Result<T, E>,
+) -> Result<T, E> {
+  #[cfg(feature = "tracing")]
+  let _span = tracing::debug_span!("validation_step", step = name).entered();
+  #[cfg(not(feature = "tracing"))]
+  let _ = name;
+
+  let result: Result<T, E> = step();
+  #[cfg(feature = "tracing")]
+  if let Err(error) = &result {
+    tracing::debug!(%error, "validation step failed");
+  }
+  result
+}
+
+/// Reports the outcome of a credential validation to the installed
+/// [`MetricsRecorder`](identity_core::metrics::MetricsRecorder), incrementing the failure counter once for each
+/// error.
+pub(crate) fn record_credential_validation<T>(result: &Result<T, CompoundCredentialValidationError>) {
+  use identity_core::metrics;
+
+  match result {
+    Ok(_) => metrics::increment_counter(metrics::names::CREDENTIALS_VALIDATED, &[]),
+    Err(error) => {
+      for validation_error in &error.validation_errors {
+        let class: &'static str = validation_error.into();
+        metrics::increment_counter(metrics::names::CREDENTIALS_FAILED, &[("error", class)]);
+      }
+    }
+  }
+}
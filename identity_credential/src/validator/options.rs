@@ -24,6 +24,29 @@ pub enum StatusCheck {
   SkipAll = 2,
 }
 
+/// Controls how the removal of an issuer's signing key after the validation time is treated when validating a
+/// credential at a point in time, see
+/// [`JwtCredentialValidationOptions::validate_at`](crate::validator::JwtCredentialValidationOptions::validate_at).
+// Need to use serde_repr to make this work with duck typed interfaces in the Wasm bindings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde_repr::Serialize_repr, serde_repr::Deserialize_repr, Default)]
+#[repr(u8)]
+pub enum KeyRevocationPolicy {
+  /// The signing key must still be part of the issuer's current DID Document: removing or rotating it invalidates
+  /// all the credentials it signed.
+  ///
+  /// This is the default.
+  #[default]
+  Strict = 0,
+  /// The signing key only needs to have been part of the issuer's DID Document at the validation time: removing or
+  /// rotating it later does not invalidate the credentials it signed before, until the
+  /// [grace period](crate::validator::JwtCredentialValidationOptions::key_revocation_grace_period) following its
+  /// [removal](crate::validator::JwtCredentialValidationOptions::key_removed_at) has elapsed.
+  ///
+  /// When the removal time is not known, the grace period starts at the validation time, so that only credentials
+  /// validated as of a recent time are accepted.
+  Grace = 1,
+}
+
 /// Declares how credential subjects must relate to the presentation holder during validation.
 ///
 /// See also the [Subject-Holder Relationship](https://www.w3.org/TR/vc-data-model/#subject-holder-relationships) section of the specification.
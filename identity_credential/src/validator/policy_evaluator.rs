@@ -0,0 +1,346 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use identity_core::error_code::HasErrorCode;
+use identity_document::document::DocumentMetadata;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::validator::CompoundCredentialValidationError;
+use crate::validator::DecodedJwtCredential;
+
+/// A failed check of a credential validation, see [`ValidationReport`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidationIssue {
+  /// The stable error code of the failure, e.g. `IOTA_ID_1604`.
+  pub code: String,
+  /// The name of the failure, e.g. `ExpirationDate`.
+  pub name: String,
+  /// A human-readable description of the failure.
+  pub message: String,
+}
+
+/// The outcome of the validation of a credential, in a structured form suitable for policy evaluation.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidationReport {
+  /// The failed checks; the credential is valid if there are none.
+  pub issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+  /// Creates the report of the validation that returned `result`.
+  pub fn from_result<T>(result: &Result<T, CompoundCredentialValidationError>) -> Self {
+    let issues = match result {
+      Ok(_) => Vec::new(),
+      Err(error) => error
+        .validation_errors
+        .iter()
+        .map(|error| {
+          let code = error.error_code();
+          ValidationIssue {
+            code: code.code(),
+            name: code.name().to_owned(),
+            message: error.to_string(),
+          }
+        })
+        .collect(),
+    };
+    Self { issues }
+  }
+
+  /// Returns `true` if the validation succeeded.
+  pub fn is_valid(&self) -> bool {
+    self.issues.is_empty()
+  }
+}
+
+/// Everything a [`PolicyEvaluator`] decides upon.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct PolicyInput {
+  /// The report of the validation of the credential.
+  pub report: ValidationReport,
+  /// The JSON representation of the decoded credential, or `None` if it could not be decoded.
+  pub credential: Option<Value>,
+  /// The custom claims of the JWT the credential was decoded from.
+  pub custom_claims: Option<Value>,
+  /// The metadata returned by the resolution of the issuer's DID Document, if available.
+  pub issuer_metadata: Option<DocumentMetadata>,
+}
+
+impl PolicyInput {
+  /// Creates the input for the evaluation of the credential validated with `result`, whose issuer's DID Document was
+  /// resolved with `issuer_metadata`.
+  pub fn new<T: Serialize>(
+    result: &Result<DecodedJwtCredential<T>, CompoundCredentialValidationError>,
+    issuer_metadata: Option<DocumentMetadata>,
+  ) -> Self {
+    let decoded = result.as_ref().ok();
+    Self {
+      report: ValidationReport::from_result(result),
+      credential: decoded.and_then(|decoded| serde_json::to_value(&decoded.credential).ok()),
+      custom_claims: decoded
+        .and_then(|decoded| decoded.custom_claims.as_ref())
+        .and_then(|claims| serde_json::to_value(claims).ok()),
+      issuer_metadata,
+    }
+  }
+
+  /// Returns the issuer of the credential, if it was decoded.
+  pub fn issuer(&self) -> Option<&str> {
+    let issuer = self.credential.as_ref()?.get("issuer")?;
+    issuer.as_str().or_else(|| issuer.get("id")?.as_str())
+  }
+
+  /// Returns the types of the credential, if it was decoded.
+  pub fn types(&self) -> Vec<&str> {
+    match self.credential.as_ref().and_then(|credential| credential.get("type")) {
+      Some(Value::String(type_)) => vec![type_.as_str()],
+      Some(Value::Array(types)) => types.iter().filter_map(Value::as_str).collect(),
+      _ => Vec::new(),
+    }
+  }
+}
+
+/// Whether a [`PolicyEvaluator`] allows a credential, with the reasons of its decision.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PolicyDecision {
+  /// Whether the credential is accepted.
+  pub allowed: bool,
+  /// The reasons of the decision, e.g. the policies that matched.
+  pub reasons: Vec<String>,
+}
+
+impl PolicyDecision {
+  /// Allows the credential.
+  pub fn allow() -> Self {
+    Self {
+      allowed: true,
+      reasons: Vec::new(),
+    }
+  }
+
+  /// Denies the credential for `reason`.
+  pub fn deny(reason: impl Into<String>) -> Self {
+    Self {
+      allowed: false,
+      reasons: vec![reason.into()],
+    }
+  }
+
+  /// Adds a reason to the decision.
+  #[must_use]
+  pub fn reason(mut self, reason: impl Into<String>) -> Self {
+    self.reasons.push(reason.into());
+    self
+  }
+
+  /// Returns `true` if the credential is allowed.
+  pub fn is_allowed(&self) -> bool {
+    self.allowed
+  }
+}
+
+/// Applies the business rules of a relying party to a credential after its validation.
+///
+/// Evaluators run once the cryptographic and structural checks are done, see [`PolicyInput::new`], and can e.g.
+/// restrict the accepted issuers per credential type or constrain claim values. They receive the validation report
+/// even if validation failed, so a policy may tolerate some failures; evaluators that do not inspect the report should
+/// deny invalid credentials. Evaluators backed by a policy language, e.g. Cedar or Rego, should deny rather than allow
+/// when a policy cannot be evaluated.
+///
+/// Closures taking a [`PolicyInput`] and returning a [`PolicyDecision`] are evaluators, and evaluators are combined
+/// with [`AllOf`].
+pub trait PolicyEvaluator {
+  /// Decides whether the credential described by `input` is accepted.
+  fn evaluate(&self, input: &PolicyInput) -> PolicyDecision;
+}
+
+impl<F> PolicyEvaluator for F
+where
+  F: Fn(&PolicyInput) -> PolicyDecision,
+{
+  fn evaluate(&self, input: &PolicyInput) -> PolicyDecision {
+    self(input)
+  }
+}
+
+/// A [`PolicyEvaluator`] allowing a credential if all of its evaluators do, with the reasons of all of them.
+#[derive(Default)]
+pub struct AllOf(Vec<Box<dyn PolicyEvaluator + Send + Sync>>);
+
+impl AllOf {
+  /// Creates an [`AllOf`] without evaluators, which allows every credential.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Adds `evaluator`.
+  #[must_use]
+  pub fn with(mut self, evaluator: impl PolicyEvaluator + Send + Sync + 'static) -> Self {
+    self.0.push(Box::new(evaluator));
+    self
+  }
+}
+
+impl std::fmt::Debug for AllOf {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("AllOf").field("evaluators", &self.0.len()).finish()
+  }
+}
+
+impl PolicyEvaluator for AllOf {
+  fn evaluate(&self, input: &PolicyInput) -> PolicyDecision {
+    let decisions: Vec<PolicyDecision> = self.0.iter().map(|evaluator| evaluator.evaluate(input)).collect();
+    PolicyDecision {
+      allowed: decisions.iter().all(PolicyDecision::is_allowed),
+      reasons: decisions.into_iter().flat_map(|decision| decision.reasons).collect(),
+    }
+  }
+}
+
+/// A [`PolicyEvaluator`] accepting valid credentials of given types only from given issuers.
+///
+/// Credentials of types without allow-list are accepted from any issuer, unless
+/// [`IssuerAllowList::deny_unlisted_types`] is set.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssuerAllowList {
+  issuers: BTreeMap<String, BTreeSet<String>>,
+  deny_unlisted_types: bool,
+}
+
+impl IssuerAllowList {
+  /// Creates an empty allow-list.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Accepts credentials of type `credential_type` from `issuer`.
+  #[must_use]
+  pub fn allow(mut self, credential_type: impl Into<String>, issuer: impl Into<String>) -> Self {
+    self
+      .issuers
+      .entry(credential_type.into())
+      .or_default()
+      .insert(issuer.into());
+    self
+  }
+
+  /// Sets whether credentials whose types have no allow-list are denied.
+  #[must_use]
+  pub fn deny_unlisted_types(mut self, deny: bool) -> Self {
+    self.deny_unlisted_types = deny;
+    self
+  }
+}
+
+impl PolicyEvaluator for IssuerAllowList {
+  fn evaluate(&self, input: &PolicyInput) -> PolicyDecision {
+    if !input.report.is_valid() {
+      return PolicyDecision::deny("the credential is not valid");
+    }
+    let Some(issuer) = input.issuer() else {
+      return PolicyDecision::deny("the credential has no issuer");
+    };
+
+    let mut listed = false;
+    for type_ in input.types() {
+      if let Some(issuers) = self.issuers.get(type_) {
+        listed = true;
+        if !issuers.contains(issuer) {
+          return PolicyDecision::deny(format!("`{issuer}` is not allowed to issue `{type_}` credentials"));
+        }
+      }
+    }
+
+    if !listed && self.deny_unlisted_types {
+      PolicyDecision::deny("no allow-list applies to the credential's types")
+    } else {
+      PolicyDecision::allow()
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+  use serde_json::json;
+
+  use super::*;
+  use crate::credential::Credential;
+  use crate::validator::JwtValidationError;
+
+  fn decoded(issuer: &str, type_: &str) -> DecodedJwtCredential {
+    DecodedJwtCredential {
+      credential: Credential::from_json_value(json!({
+        "@context": "https://www.w3.org/2018/credentials/v1",
+        "type": ["VerifiableCredential", type_],
+        "issuer": issuer,
+        "issuanceDate": "2010-01-01T00:00:00Z",
+        "credentialSubject": { "degree": "Bachelor" },
+      }))
+      .unwrap(),
+      header: Box::default(),
+      custom_claims: None,
+    }
+  }
+
+  #[test]
+  fn issuer_allow_list() {
+    let policy = IssuerAllowList::new().allow("UniversityDegreeCredential", "did:example:university");
+    let input = |issuer: &str, type_: &str| PolicyInput::new(&Ok(decoded(issuer, type_)), None);
+
+    assert!(policy
+      .evaluate(&input("did:example:university", "UniversityDegreeCredential"))
+      .is_allowed());
+    let decision = policy.evaluate(&input("did:example:mill", "UniversityDegreeCredential"));
+    assert!(!decision.is_allowed());
+    assert_eq!(
+      decision.reasons,
+      ["`did:example:mill` is not allowed to issue `UniversityDegreeCredential` credentials"]
+    );
+
+    assert!(policy.evaluate(&input("did:example:mill", "LoyaltyCard")).is_allowed());
+    assert!(!policy
+      .deny_unlisted_types(true)
+      .evaluate(&input("did:example:mill", "LoyaltyCard"))
+      .is_allowed());
+  }
+
+  #[test]
+  fn invalid_credentials_are_reported() {
+    let result: Result<DecodedJwtCredential, _> = Err(CompoundCredentialValidationError {
+      validation_errors: vec![JwtValidationError::ExpirationDate],
+    });
+    let input = PolicyInput::new(&result, None);
+    assert_eq!(input.report.issues.len(), 1);
+    assert_eq!(input.report.issues[0].code, "IOTA_ID_1604");
+    assert_eq!(input.credential, None);
+
+    let policy = IssuerAllowList::new();
+    assert!(!policy.evaluate(&input).is_allowed());
+
+    // A policy tolerating expired credentials.
+    let tolerant = |input: &PolicyInput| {
+      if input.report.issues.iter().all(|issue| issue.name == "ExpirationDate") {
+        PolicyDecision::allow().reason("expired credentials are accepted")
+      } else {
+        PolicyDecision::deny("invalid credential")
+      }
+    };
+    assert!(tolerant.evaluate(&input).is_allowed());
+
+    let decision = AllOf::new().with(tolerant).with(policy).evaluate(&input);
+    assert!(!decision.is_allowed());
+    assert_eq!(decision.reasons.len(), 2);
+  }
+}
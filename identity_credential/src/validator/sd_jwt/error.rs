@@ -77,3 +77,16 @@ pub enum KeyBindingJwtError {
   #[error("invalid KB-JWT header 'typ' value")]
   InvalidHeaderTypValue(#[source] UnexpectedValue),
 }
+
+identity_core::impl_error_code!(KeyBindingJwtError {
+  JwtValidationError => 1700,
+  DeserializationError => 1701,
+  SdJwtError => 1702,
+  UnsupportedCnfMethod => 1703,
+  InvalidDigest => 1704,
+  InvalidNonce => 1705,
+  AudienceMismatch => 1706,
+  IssuanceDate => 1707,
+  MissingKeyBindingJwt => 1708,
+  InvalidHeaderTypValue => 1709,
+});
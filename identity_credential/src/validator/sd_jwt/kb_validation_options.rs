@@ -1,6 +1,8 @@
 // Copyright 2020-2026 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use identity_core::common::Clock;
+use identity_core::common::SharedClock;
 use identity_core::common::Timestamp;
 use serde::Deserialize;
 use serde::Serialize;
@@ -25,6 +27,12 @@ pub struct KeyBindingJwtValidationOptions {
   /// Uses the current timestamp during validation if not set.
   #[serde(skip_serializing_if = "Option::is_none")]
   pub latest_issuance_date: Option<Timestamp>,
+
+  /// The clock providing the current timestamp used when `latest_issuance_date` is not set.
+  ///
+  /// Default: [`SystemClock`](identity_core::common::SystemClock).
+  #[serde(skip)]
+  pub clock: SharedClock,
 }
 
 impl KeyBindingJwtValidationOptions {
@@ -59,4 +67,10 @@ impl KeyBindingJwtValidationOptions {
     self.latest_issuance_date = Some(latest_issuance_date);
     self
   }
+
+  /// Sets the clock providing the current timestamp used when `latest_issuance_date` is not set.
+  pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+    self.clock = SharedClock::new(clock);
+    self
+  }
 }
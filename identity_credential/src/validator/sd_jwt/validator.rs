@@ -12,6 +12,7 @@ use crate::validator::JwtValidationError;
 use crate::validator::SignerContext;
 use crate::validator::UnexpectedValue;
 use anyhow::Context as _;
+use identity_core::common::Clock;
 use identity_core::common::Timestamp;
 use identity_core::convert::FromJson;
 use identity_did::CoreDID;
@@ -76,6 +77,11 @@ impl<V: JwsVerifier> SdJwtCredentialValidator<V> {
   /// ## The state of the issuer's DID Document
   /// The caller must ensure that `issuer` represents an up-to-date DID Document.
   ///
+  /// ## Limits
+  /// The limits of `options` are enforced on `sd_jwt` and on the claims it discloses, but only after it was parsed.
+  /// Untrusted input should be checked with
+  /// [`ValidationLimits::check_sd_jwt`](crate::validator::ValidationLimits::check_sd_jwt) before being parsed.
+  ///
   /// ## Properties that are not validated
   ///  There are many properties defined in [The Verifiable Credentials Data Model](https://www.w3.org/TR/vc-data-model/) that are **not** validated, such as:
   /// `proof`, `credentialStatus`, `type`, `credentialSchema`, `refreshService` **and more**.
@@ -93,13 +99,19 @@ impl<V: JwsVerifier> SdJwtCredentialValidator<V> {
     T: Clone + serde::Serialize + serde::de::DeserializeOwned,
     DOC: AsRef<CoreDocument>,
   {
+    options.limits.check_disclosures(sd_jwt.disclosures().len())?;
+    let sd_jwt_presentation = sd_jwt.presentation();
+    options.limits.check_jwt(&sd_jwt_presentation)?;
+
     // Verify the JWS signature.
-    let vm_id = self.verify_signature_impl(&sd_jwt.presentation(), trusted_issuers, &options.verification_options)?;
+    let vm_id = self.verify_signature_impl(&sd_jwt_presentation, trusted_issuers, &options.verification_options)?;
     let hasher = self.1.as_ref();
 
     // Try to construct a credential from the disclosed claims.
-    let disclosed_claims = sd_jwt.clone().into_disclosed_object(hasher)?;
-    let credential_jwt_claims: CredentialJwtClaims<'_, T> = serde_json::from_value(Value::Object(disclosed_claims))
+    check_sd_alg(sd_jwt, hasher)?;
+    let disclosed_claims = Value::Object(sd_jwt.clone().into_disclosed_object(hasher)?);
+    options.limits.check_claims(&disclosed_claims)?;
+    let credential_jwt_claims: CredentialJwtClaims<'_, T> = serde_json::from_value(disclosed_claims)
       .map_err(|e| SdJwtCredentialValidatorError::CredentialStructure(e.into()))?;
     let credential = credential_jwt_claims
       .try_into_credential()
@@ -145,6 +157,11 @@ impl<V: JwsVerifier> SdJwtCredentialValidator<V> {
   /// ## The state of the issuer's DID Document
   /// The caller must ensure that `issuer` represents an up-to-date DID Document.
   ///
+  /// ## Limits
+  /// The limits of `options` are enforced on `sd_jwt` and on the claims it discloses, but only after it was parsed.
+  /// Untrusted input should be checked with
+  /// [`ValidationLimits::check_sd_jwt`](crate::validator::ValidationLimits::check_sd_jwt) before being parsed.
+  ///
   /// ## Properties that are not validated
   /// There are many properties defined in [The Verifiable Credentials Data Model v2](https://www.w3.org/TR/vc-data-model-2.0/)
   /// that are **not** validated, such as:
@@ -163,13 +180,19 @@ impl<V: JwsVerifier> SdJwtCredentialValidator<V> {
     T: Clone + serde::Serialize + serde::de::DeserializeOwned,
     DOC: AsRef<CoreDocument>,
   {
+    options.limits.check_disclosures(sd_jwt.disclosures().len())?;
+    let sd_jwt_presentation = sd_jwt.presentation();
+    options.limits.check_jwt(&sd_jwt_presentation)?;
+
     // Verify the JWS signature.
-    let vm_id = self.verify_signature_impl(&sd_jwt.presentation(), trusted_issuers, &options.verification_options)?;
+    let vm_id = self.verify_signature_impl(&sd_jwt_presentation, trusted_issuers, &options.verification_options)?;
     let hasher = self.1.as_ref();
 
     // Try to construct a credential from the disclosed claims.
-    let disclosed_claims = sd_jwt.clone().into_disclosed_object(hasher)?;
-    let credential = CredentialV2::<T>::from_json_value(Value::Object(disclosed_claims))
+    check_sd_alg(sd_jwt, hasher)?;
+    let disclosed_claims = Value::Object(sd_jwt.clone().into_disclosed_object(hasher)?);
+    options.limits.check_claims(&disclosed_claims)?;
+    let credential = CredentialV2::<T>::from_json_value(disclosed_claims)
       .map_err(|e| SdJwtCredentialValidatorError::CredentialStructure(e.into()))?;
     JwtCredentialValidator::<V>::validate_decoded_credential(
       &credential,
@@ -307,9 +330,7 @@ impl<V: JwsVerifier> SdJwtCredentialValidator<V> {
     })?;
 
     // Make sure the passed Hasher matches the one used in the SD-JWT.
-    if sd_jwt.claims()._sd_alg.as_deref().unwrap_or(sd_jwt::SHA_ALG_NAME) != hasher.alg_name() {
-      return Err(sd_jwt::Error::InvalidHasher(hasher.alg_name().to_owned()).into());
-    }
+    check_sd_alg(sd_jwt, hasher)?;
 
     let digest = {
       let sd_jwt_str = sd_jwt.to_string();
@@ -361,10 +382,19 @@ impl<V: JwsVerifier> SdJwtCredentialValidator<V> {
           "value is later than `latest_issuance_date`".to_string(),
         ));
       }
-    } else if issuance_date > Timestamp::now_utc() {
+    } else if issuance_date > options.clock.now() {
       return Err(KeyBindingJwtError::IssuanceDate("value is in the future".to_string()));
     }
 
     Ok(())
   }
 }
+
+/// Makes sure the algorithm declared by `sd_jwt`'s `_sd_alg` claim, or `sha-256` if missing, is the one implemented by
+/// `hasher`. Decoding disclosures with a different algorithm would silently leave them undisclosed.
+fn check_sd_alg(sd_jwt: &SdJwt, hasher: &dyn Hasher) -> Result<(), sd_jwt::Error> {
+  if sd_jwt.claims()._sd_alg.as_deref().unwrap_or(sd_jwt::SHA_ALG_NAME) != hasher.alg_name() {
+    return Err(sd_jwt::Error::InvalidHasher(hasher.alg_name().to_owned()));
+  }
+  Ok(())
+}
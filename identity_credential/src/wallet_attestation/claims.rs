@@ -0,0 +1,136 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_did::CoreDID;
+use identity_did::DID;
+use identity_verification::jws::JwsHeader;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::credential::Confirmation;
+
+/// The `typ` header of Wallet Instance Attestations.
+pub const WALLET_ATTESTATION_TYP: &str = "oauth-client-attestation+jwt";
+
+/// The claims of a Wallet Instance Attestation.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct WalletAttestationClaims {
+  /// The Wallet Provider that issued the attestation.
+  pub iss: String,
+  /// The identifier of the attested wallet instance.
+  pub sub: String,
+  /// The time at which the attestation was issued, as a UNIX timestamp.
+  pub iat: i64,
+  /// The time at which the attestation expires, as a UNIX timestamp.
+  pub exp: i64,
+  /// The key of the wallet instance.
+  pub cnf: Confirmation,
+  /// The human-readable name of the wallet solution.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub wallet_name: Option<String>,
+  /// A URL with further information about the wallet solution.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub wallet_link: Option<Url>,
+  /// Additional claims, e.g. the authentication level or status of the wallet instance.
+  #[serde(flatten)]
+  pub custom: Object,
+}
+
+impl WalletAttestationClaims {
+  /// Creates the claims of an attestation, issued by `wallet_provider`, that binds `cnf` to the wallet instance
+  /// `wallet_id` from `issuance_date` to `expiration_date`.
+  pub fn new(
+    wallet_provider: &CoreDID,
+    wallet_id: impl Into<String>,
+    cnf: Confirmation,
+    issuance_date: Timestamp,
+    expiration_date: Timestamp,
+  ) -> Self {
+    Self {
+      iss: wallet_provider.as_str().to_owned(),
+      sub: wallet_id.into(),
+      iat: issuance_date.to_unix(),
+      exp: expiration_date.to_unix(),
+      cnf,
+      wallet_name: None,
+      wallet_link: None,
+      custom: Object::new(),
+    }
+  }
+
+  /// Sets the human-readable name of the wallet solution.
+  #[must_use]
+  pub fn wallet_name(mut self, value: impl Into<String>) -> Self {
+    self.wallet_name = Some(value.into());
+    self
+  }
+
+  /// Sets the URL with further information about the wallet solution.
+  #[must_use]
+  pub fn wallet_link(mut self, value: Url) -> Self {
+    self.wallet_link = Some(value);
+    self
+  }
+}
+
+/// A Wallet Instance Attestation decoded and validated by a
+/// [`WalletAttestationValidator`](super::WalletAttestationValidator).
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct DecodedWalletAttestation {
+  /// The protected header of the attestation.
+  pub header: Box<JwsHeader>,
+  /// The claims of the attestation.
+  pub claims: WalletAttestationClaims,
+}
+
+impl DecodedWalletAttestation {
+  /// Returns the identifier of the attested wallet instance.
+  pub fn wallet_id(&self) -> &str {
+    &self.claims.sub
+  }
+
+  /// Returns the key of the wallet instance.
+  pub fn confirmation(&self) -> &Confirmation {
+    &self.claims.cnf
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+  use identity_verification::jwk::Jwk;
+  use serde_json::json;
+
+  use super::*;
+
+  #[test]
+  fn claims_round_trip_through_json() {
+    let jwk = Jwk::from_json_value(json!({
+      "kty": "OKP",
+      "crv": "Ed25519",
+      "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"
+    }))
+    .unwrap();
+    let provider: CoreDID = "did:example:provider".parse().unwrap();
+    let claims = WalletAttestationClaims::new(
+      &provider,
+      "wallet-1",
+      Confirmation::jwk(jwk).unwrap(),
+      Timestamp::from_unix(1_700_000_000).unwrap(),
+      Timestamp::from_unix(1_700_086_400).unwrap(),
+    )
+    .wallet_name("Example Wallet");
+
+    let json = serde_json::to_value(&claims).unwrap();
+    assert_eq!(json["iss"], "did:example:provider");
+    assert_eq!(json["sub"], "wallet-1");
+    assert_eq!(json["wallet_name"], "Example Wallet");
+    assert_eq!(json["cnf"]["jwk"]["crv"], "Ed25519");
+    assert_eq!(serde_json::from_value::<WalletAttestationClaims>(json).unwrap(), claims);
+  }
+}
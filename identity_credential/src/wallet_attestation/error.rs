@@ -0,0 +1,56 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::validator::JwtValidationError;
+
+/// Alias for a `Result` with the error type [`WalletAttestationError`].
+pub type WalletAttestationResult<T> = Result<T, WalletAttestationError>;
+
+/// Errors that can occur when validating a Wallet Instance Attestation.
+#[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum WalletAttestationError {
+  /// Caused by an attestation that exceeds the validation limits.
+  #[error("the attestation exceeds the validation limits")]
+  LimitExceeded(#[source] JwtValidationError),
+  /// Caused by an attestation whose `typ` header is not the one of Wallet Instance Attestations.
+  #[error("invalid attestation type `{0}`")]
+  InvalidType(String),
+  /// Caused by an attestation whose signature cannot be verified with the Wallet Provider's DID Document.
+  #[error("invalid attestation signature")]
+  InvalidSignature(#[source] identity_document::error::Error),
+  /// Caused by attestation claims that cannot be decoded.
+  #[error("invalid attestation claims: {0}")]
+  InvalidClaims(String),
+  /// Caused by an attestation that was not issued by the Wallet Provider it was validated against.
+  #[error("the attestation was not issued by the wallet provider")]
+  ProviderMismatch,
+  /// Caused by an attestation about a different wallet than the expected one.
+  #[error("the attestation is about wallet `{0}`, which is not the expected one")]
+  UnexpectedWallet(String),
+  /// Caused by an attestation that has expired.
+  #[error("the attestation has expired")]
+  Expired,
+  /// Caused by an attestation issued after the latest accepted issuance date.
+  #[error("the attestation was issued in the future")]
+  IssuedInFuture,
+  /// Caused by an attestation that does not bind a public key of the wallet.
+  #[error("the attestation does not bind a public key of the wallet")]
+  InvalidConfirmation,
+  /// Caused by a presentation that was not signed with the key attested for the wallet.
+  #[error("the presentation was not signed with the attested wallet key")]
+  HolderBindingMismatch(#[source] JwtValidationError),
+}
+
+identity_core::impl_error_code!(WalletAttestationError {
+  LimitExceeded => 2350,
+  InvalidType => 2351,
+  InvalidSignature => 2352,
+  InvalidClaims => 2353,
+  ProviderMismatch => 2354,
+  UnexpectedWallet => 2355,
+  Expired => 2356,
+  IssuedInFuture => 2357,
+  InvalidConfirmation => 2358,
+  HolderBindingMismatch => 2359,
+});
@@ -0,0 +1,25 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Wallet Instance Attestations, as defined by the
+//! [EUDI Architecture and Reference Framework](https://eu-digital-identity-wallet.github.io/eudi-doc-architecture-and-reference-framework/).
+//!
+//! A Wallet Instance Attestation is a JWT issued by a Wallet Provider about one of its wallet instances, binding the
+//! instance's key through the confirmation (`cnf`) claim. Relying parties can require it to only accept presentations
+//! made by wallets they trust:
+//! 1. The holder sends the attestation along with its presentation.
+//! 2. The verifier validates the attestation against the Wallet Provider's DID Document with a
+//!    [`WalletAttestationValidator`].
+//! 3. The verifier validates the presentation with
+//!    [`JwtPresentationValidationOptions::wallet_attestation`](crate::validator::JwtPresentationValidationOptions::wallet_attestation)
+//!    set, which checks that it was signed with the attested key.
+
+mod claims;
+mod error;
+mod options;
+mod validator;
+
+pub use self::claims::*;
+pub use self::error::*;
+pub use self::options::*;
+pub use self::validator::*;
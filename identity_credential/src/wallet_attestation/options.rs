@@ -0,0 +1,90 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Clock;
+use identity_core::common::SharedClock;
+use identity_core::common::Timestamp;
+use identity_document::verifiable::JwsVerificationOptions;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::validator::ValidationLimits;
+
+/// Criteria for validating a Wallet Instance Attestation.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(rename_all = "camelCase")]
+pub struct WalletAttestationValidationOptions {
+  /// Options which affect the verification of the Wallet Provider's signature.
+  #[serde(default)]
+  pub verification_options: JwsVerificationOptions,
+
+  /// The identifier of the wallet instance the attestation must be about, if any.
+  #[serde(default)]
+  pub wallet_id: Option<String>,
+
+  /// Declares that the attestation is **not** considered valid if it expires before this [`Timestamp`].
+  /// Uses the current datetime during validation if not set.
+  #[serde(default)]
+  pub earliest_expiry_date: Option<Timestamp>,
+
+  /// Declares that the attestation is **not** considered valid if it was issued later than this [`Timestamp`].
+  /// Uses the current datetime during validation if not set.
+  #[serde(default)]
+  pub latest_issuance_date: Option<Timestamp>,
+
+  /// Limits on the size and complexity of the attestation, enforced before it is decoded.
+  #[serde(default)]
+  pub limits: ValidationLimits,
+
+  /// The clock providing the current datetime used when the dates above are not set.
+  ///
+  /// Default: [`SystemClock`](identity_core::common::SystemClock).
+  #[serde(skip)]
+  pub clock: SharedClock,
+}
+
+impl WalletAttestationValidationOptions {
+  /// Constructor that sets all options to their defaults.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Set options which affect the verification of the Wallet Provider's signature.
+  pub fn verification_options(mut self, options: JwsVerificationOptions) -> Self {
+    self.verification_options = options;
+    self
+  }
+
+  /// Require the attestation to be about the wallet instance `wallet_id`.
+  pub fn wallet_id(mut self, wallet_id: impl Into<String>) -> Self {
+    self.wallet_id = Some(wallet_id.into());
+    self
+  }
+
+  /// Declare that the attestation is **not** considered valid if it expires before this [`Timestamp`].
+  /// Uses the current datetime during validation if not set.
+  pub fn earliest_expiry_date(mut self, timestamp: Timestamp) -> Self {
+    self.earliest_expiry_date = Some(timestamp);
+    self
+  }
+
+  /// Declare that the attestation is **not** considered valid if it was issued later than this [`Timestamp`].
+  /// Uses the current datetime during validation if not set.
+  pub fn latest_issuance_date(mut self, timestamp: Timestamp) -> Self {
+    self.latest_issuance_date = Some(timestamp);
+    self
+  }
+
+  /// Set the limits on the size and complexity of the attestation.
+  pub fn limits(mut self, limits: ValidationLimits) -> Self {
+    self.limits = limits;
+    self
+  }
+
+  /// Set the clock providing the current datetime used when the dates above are not set.
+  pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+    self.clock = SharedClock::new(clock);
+    self
+  }
+}
@@ -0,0 +1,123 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Timestamp;
+use identity_document::document::CoreDocument;
+use identity_verification::jws::DecodedJws;
+use identity_verification::jws::JwsVerifier;
+
+use super::DecodedWalletAttestation;
+use super::WalletAttestationClaims;
+use super::WalletAttestationError;
+use super::WalletAttestationResult;
+use super::WalletAttestationValidationOptions;
+use super::WALLET_ATTESTATION_TYP;
+use crate::credential::Confirmation;
+use crate::credential::Jwt;
+use crate::validator::check_key_binding;
+use crate::validator::DecodedJwtPresentation;
+
+/// A type for decoding and validating Wallet Instance Attestations.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct WalletAttestationValidator<V: JwsVerifier>(V);
+
+impl<V: JwsVerifier> WalletAttestationValidator<V> {
+  /// Creates a new [`WalletAttestationValidator`] that delegates cryptographic signature verification to the given
+  /// `signature_verifier`.
+  pub fn with_signature_verifier(signature_verifier: V) -> Self {
+    Self(signature_verifier)
+  }
+
+  /// Decodes and validates a Wallet Instance Attestation issued by `wallet_provider`.
+  ///
+  /// The following properties are validated according to `options`:
+  /// - the `typ` header,
+  /// - the Wallet Provider's signature,
+  /// - the issuer and the attested wallet instance,
+  /// - the expiration and issuance date,
+  /// - the presence of a public key in the `cnf` claim.
+  ///
+  /// # Warning
+  /// The caller must ensure that `wallet_provider` is the up-to-date DID Document of a Wallet Provider it trusts.
+  pub fn validate<DOC>(
+    &self,
+    attestation: &Jwt,
+    wallet_provider: &DOC,
+    options: &WalletAttestationValidationOptions,
+  ) -> WalletAttestationResult<DecodedWalletAttestation>
+  where
+    DOC: AsRef<CoreDocument> + ?Sized,
+  {
+    options
+      .limits
+      .check_jwt(attestation.as_str())
+      .map_err(WalletAttestationError::LimitExceeded)?;
+
+    let decoded: DecodedJws<'_> = wallet_provider
+      .as_ref()
+      .verify_jws(attestation.as_str(), None, &self.0, &options.verification_options)
+      .map_err(WalletAttestationError::InvalidSignature)?;
+
+    match decoded.protected.typ() {
+      Some(WALLET_ATTESTATION_TYP) => (),
+      typ => return Err(WalletAttestationError::InvalidType(typ.unwrap_or_default().to_owned())),
+    }
+
+    let claims: WalletAttestationClaims =
+      serde_json::from_slice(&decoded.claims).map_err(|err| WalletAttestationError::InvalidClaims(err.to_string()))?;
+
+    if claims.iss != wallet_provider.as_ref().id().as_str() {
+      return Err(WalletAttestationError::ProviderMismatch);
+    }
+    if let Some(wallet_id) = &options.wallet_id {
+      if &claims.sub != wallet_id {
+        return Err(WalletAttestationError::UnexpectedWallet(claims.sub));
+      }
+    }
+
+    let expiration_date: Timestamp = timestamp(claims.exp)?;
+    if expiration_date < options.earliest_expiry_date.unwrap_or_else(|| options.clock.now()) {
+      return Err(WalletAttestationError::Expired);
+    }
+    let issuance_date: Timestamp = timestamp(claims.iat)?;
+    if issuance_date > options.latest_issuance_date.unwrap_or_else(|| options.clock.now()) {
+      return Err(WalletAttestationError::IssuedInFuture);
+    }
+
+    match &claims.cnf {
+      Confirmation::Jwk(jwk) if jwk.is_public() => (),
+      _ => return Err(WalletAttestationError::InvalidConfirmation),
+    }
+
+    Ok(DecodedWalletAttestation {
+      header: Box::new(decoded.protected),
+      claims,
+    })
+  }
+
+  /// Checks that `presentation` was signed by `holder` with the wallet key bound by `attestation`, so that it can be
+  /// trusted to come from the attested wallet instance.
+  ///
+  /// Prefer requiring the attestation through
+  /// [`JwtPresentationValidationOptions::wallet_attestation`](crate::validator::JwtPresentationValidationOptions::wallet_attestation),
+  /// which performs this check while validating the presentation.
+  ///
+  /// # Warning
+  /// This method assumes both `attestation` and `presentation` were validated, the latter using `holder`.
+  pub fn check_holder_binding<CRED, T, DOC>(
+    attestation: &DecodedWalletAttestation,
+    presentation: &DecodedJwtPresentation<CRED, T>,
+    holder: &DOC,
+  ) -> WalletAttestationResult<()>
+  where
+    DOC: AsRef<CoreDocument> + ?Sized,
+  {
+    check_key_binding(attestation.confirmation(), &presentation.header, holder.as_ref())
+      .map_err(WalletAttestationError::HolderBindingMismatch)
+  }
+}
+
+fn timestamp(seconds: i64) -> WalletAttestationResult<Timestamp> {
+  Timestamp::from_unix(seconds).map_err(|err| WalletAttestationError::InvalidClaims(err.to_string()))
+}
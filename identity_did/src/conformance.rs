@@ -0,0 +1,204 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Test vectors for the [DID Syntax](https://www.w3.org/TR/did-core/#did-syntax) and the
+//! [DID URL Syntax](https://www.w3.org/TR/did-core/#did-url-syntax) of DID Core.
+//!
+//! DID method implementations can check their own parsers against these vectors. Valid vectors are accepted by the
+//! strict parsers of this crate, e.g. [`CoreDID::parse_strict`](crate::CoreDID::parse_strict), while invalid ones
+//! are rejected by them.
+
+/// An input of a DID Core syntax test, along with the reason it is valid or invalid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TestVector {
+  /// The input to parse.
+  pub input: &'static str,
+  /// The ABNF rule exercised by the input.
+  pub description: &'static str,
+  /// Whether only the strict parsers must reject the input, while the lenient parsers, e.g.
+  /// [`CoreDID::parse`](crate::CoreDID::parse), may accept it for backwards compatibility.
+  pub strict_only: bool,
+}
+
+const fn vector(input: &'static str, description: &'static str) -> TestVector {
+  TestVector {
+    input,
+    description,
+    strict_only: false,
+  }
+}
+
+const fn strict_only(input: &'static str, description: &'static str) -> TestVector {
+  TestVector {
+    input,
+    description,
+    strict_only: true,
+  }
+}
+
+/// DIDs conforming to `did = "did:" method-name ":" method-specific-id`.
+pub const VALID_DIDS: &[TestVector] = &[
+  vector("did:example:123456789abcdefghi", "method-specific-id of idchars"),
+  vector(
+    "did:example:123:456",
+    "method-specific-id with colon separated segments",
+  ),
+  vector("did:example:ABCdef", "method-specific-id with upper case letters"),
+  vector("did:example:a.b-c_d", "method-specific-id with '.', '-' and '_'"),
+  vector("did:web:example.com%3A3000", "method-specific-id with pct-encoded char"),
+  vector(
+    "did:web:example.com%3a3000",
+    "method-specific-id with lower case pct-encoded char",
+  ),
+  vector("did:1example2:abc", "method-name with digits"),
+  vector(
+    "did:iota:0xf29dd16310c2100fd1bf568b345fb1cc14d71caa3bd9b5ad735d2bd6d455ca3b",
+    "IOTA DID",
+  ),
+];
+
+/// Inputs that are not DIDs.
+pub const INVALID_DIDS: &[TestVector] = &[
+  vector("", "empty input"),
+  vector("did:", "missing method-name"),
+  vector("did:example", "missing method-specific-id"),
+  vector("did:example:", "empty method-specific-id"),
+  vector("did::123", "empty method-name"),
+  vector("DID:example:123", "upper case scheme"),
+  vector("did:Example:123", "upper case method-name"),
+  vector("did:ex-ample:123", "'-' in method-name"),
+  vector("did:example:12 34", "space in method-specific-id"),
+  vector("did:example:123ä", "non-ASCII char in method-specific-id"),
+  vector("did:example:123%", "truncated pct-encoded char"),
+  vector("did:example:123%4", "truncated pct-encoded char"),
+  vector("did:example:123%zz", "pct-encoded char with non-HEXDIGs"),
+  vector("did:example:123%+1", "pct-encoded char with a sign"),
+  vector("did:example:123/path", "DID URL with path"),
+  vector("did:example:123?query", "DID URL with query"),
+  vector("did:example:123#fragment", "DID URL with fragment"),
+  vector("did:example:123/", "DID URL with empty path"),
+  vector("did:example:123?", "DID URL with empty query"),
+  vector("did:example:123#", "DID URL with empty fragment"),
+  strict_only("did:example:123:", "method-specific-id ending with ':'"),
+];
+
+/// DID URLs conforming to `did-url = did path-abempty [ "?" query ] [ "#" fragment ]`.
+pub const VALID_DID_URLS: &[TestVector] = &[
+  vector("did:example:123", "DID without path, query or fragment"),
+  vector("did:example:123/path/to/resource", "path"),
+  vector(
+    "did:example:123?service=files&relativeRef=%2Fresume.pdf",
+    "query with pct-encoded chars",
+  ),
+  vector("did:example:123#key-1", "fragment"),
+  vector("did:example:123/path?query#fragment", "path, query and fragment"),
+  vector("did:example:123?versionTime=2021-05-10T17:00:00Z", "query with ':'"),
+  vector("did:example:123/path;param=a,b", "path with sub-delims"),
+];
+
+/// Inputs that are not DID URLs.
+pub const INVALID_DID_URLS: &[TestVector] = &[
+  vector("did:example", "missing method-specific-id"),
+  vector("did:example:123/invalid{path}", "path with '{'"),
+  vector("did:example:123?invalid{query}", "query with '{'"),
+  vector("did:example:123#invalid{fragment}", "fragment with '{'"),
+  vector("did:example:123#frag#ment", "fragment with '#'"),
+  vector("did:example:123/%zz", "path with invalid pct-encoded char"),
+  vector("did:example:123#key 1", "fragment with space"),
+  strict_only("did:example:123?", "empty query"),
+  strict_only("did:example:123#", "empty fragment"),
+  strict_only("did:example:123:#key-1", "method-specific-id ending with ':'"),
+];
+
+/// Relative DID URLs, made of a path, a query and/or a fragment.
+pub const VALID_RELATIVE_DID_URLS: &[TestVector] = &[
+  vector("#key-1", "fragment"),
+  vector("/path", "path"),
+  vector("?service=agent", "query"),
+  vector("/path?query#fragment", "path, query and fragment"),
+  vector("?query#fragment", "query and fragment"),
+];
+
+/// Inputs that are not relative DID URLs.
+pub const INVALID_RELATIVE_DID_URLS: &[TestVector] = &[
+  vector("", "empty input"),
+  vector("#", "empty fragment"),
+  vector("?", "empty query"),
+  vector("path", "path without leading '/'"),
+  vector("did:example:123#key-1", "absolute DID URL"),
+  vector("#key{1}", "fragment with '{'"),
+  vector("?query?#", "empty fragment after query"),
+];
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::CoreDID;
+  use crate::DIDUrl;
+  use crate::RelativeDIDUrl;
+  use crate::DID;
+
+  #[test]
+  fn did_vectors() {
+    for vector in VALID_DIDS {
+      let did = CoreDID::parse_strict(vector.input).unwrap_or_else(|err| panic!("{vector:?}: {err}"));
+      assert_eq!(did.as_str(), vector.input);
+      assert_eq!(CoreDID::parse(vector.input).unwrap(), did);
+      assert_eq!(DIDUrl::parse_strict(vector.input).unwrap().did(), &did);
+    }
+    for vector in INVALID_DIDS {
+      assert!(CoreDID::parse_strict(vector.input).is_err(), "{vector:?}");
+      assert!(
+        vector.strict_only || CoreDID::parse(vector.input).is_err(),
+        "{vector:?}"
+      );
+    }
+  }
+
+  #[test]
+  fn did_url_vectors() {
+    for vector in VALID_DID_URLS {
+      let did_url = DIDUrl::parse_strict(vector.input).unwrap_or_else(|err| panic!("{vector:?}: {err}"));
+      assert_eq!(did_url.to_string(), vector.input);
+      assert_eq!(DIDUrl::parse(vector.input).unwrap(), did_url);
+    }
+    for vector in INVALID_DID_URLS {
+      assert!(DIDUrl::parse_strict(vector.input).is_err(), "{vector:?}");
+      assert!(vector.strict_only || DIDUrl::parse(vector.input).is_err(), "{vector:?}");
+    }
+  }
+
+  #[test]
+  fn relative_did_url_vectors() {
+    for vector in VALID_RELATIVE_DID_URLS {
+      let url = RelativeDIDUrl::parse(vector.input).unwrap_or_else(|err| panic!("{vector:?}: {err}"));
+      assert_eq!(url.to_string(), vector.input);
+      let did_url = DIDUrl::parse_strict(format!("did:example:123{}", vector.input)).unwrap();
+      assert_eq!(did_url.url(), &url);
+    }
+    for vector in INVALID_RELATIVE_DID_URLS {
+      assert!(RelativeDIDUrl::parse(vector.input).is_err(), "{vector:?}");
+    }
+  }
+
+  proptest::proptest! {
+    #[test]
+    fn did_rejects_did_url_components(
+      did in r"did:[a-z0-9]{1,10}:[a-zA-Z0-9\.\-_]{1,30}",
+      suffix in r"(/[a-z0-9]{1,10})?(\?[a-z0-9=&]{1,10})?(#[a-z0-9\-]{1,10})?",
+    ) {
+      proptest::prop_assume!(!suffix.is_empty());
+      let input = format!("{did}{suffix}");
+      proptest::prop_assert!(CoreDID::parse(&input).is_err());
+      proptest::prop_assert!(CoreDID::parse_strict(&input).is_err());
+      proptest::prop_assert_eq!(DIDUrl::parse(&input).unwrap().did().as_str(), did.as_str());
+    }
+
+    #[test]
+    fn strict_parsing_is_stricter(input in r"did:[a-zA-Z0-9\-]{0,6}:[a-zA-Z0-9\.\-_:%]{0,12}") {
+      if let Ok(did) = CoreDID::parse_strict(&input) {
+        proptest::prop_assert_eq!(CoreDID::parse(&input).unwrap(), did);
+      }
+    }
+  }
+}
@@ -12,8 +12,10 @@ use did_url_parser::DID as BaseDIDUrl;
 
 use identity_core::common::KeyComparable;
 
+use crate::did_url::is_valid_url_segment;
 use crate::DIDUrl;
 use crate::Error;
+use crate::RedactedDID;
 
 pub trait DID:
   Clone
@@ -96,6 +98,12 @@ pub trait DID:
   fn into_url(self) -> DIDUrl {
     DIDUrl::from(self)
   }
+
+  /// Returns a view of this [`DID`] suitable for diagnostic output, formatted according to the configured
+  /// [`DIDRedaction`](crate::DIDRedaction).
+  fn redacted(&self) -> RedactedDID<'_> {
+    RedactedDID::new(self)
+  }
 }
 
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Deserialize, serde::Serialize)]
@@ -111,10 +119,45 @@ impl CoreDID {
   ///
   /// Returns `Err` if the input is not a valid [`DID`].
   pub fn parse(input: impl AsRef<str>) -> Result<Self, Error> {
+    let input = input.as_ref();
+    // A DID URL is not a DID, even if its path, query or fragment are empty.
+    if input.contains(['/', '?', '#']) {
+      return Err(Error::InvalidMethodId);
+    }
     let base_did_url = BaseDIDUrl::parse(input)?;
     base_did_url.try_into()
   }
 
+  /// Parses a [`CoreDID`] from the given `input`, strictly following the
+  /// [DID Syntax](https://www.w3.org/TR/did-core/#did-syntax).
+  ///
+  /// Unlike [`CoreDID::parse`], this rejects method-ids ending with `:` and inputs that are not serialized back
+  /// unchanged.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Err` if the input is not a valid [`DID`].
+  pub fn parse_strict(input: impl AsRef<str>) -> Result<Self, Error> {
+    let input = input.as_ref();
+    let did = Self::parse(input)?;
+    did.check_strict()?;
+    if did.as_str() != input {
+      return Err(Error::Other("DID is not in its canonical form"));
+    }
+
+    Ok(did)
+  }
+
+  /// Checks the constraints of the DID Syntax that [`CoreDID::parse`] does not enforce.
+  pub(crate) fn check_strict(&self) -> Result<(), Error> {
+    // method-specific-id = *( *idchar ":" ) 1*idchar
+    if self.method_id().ends_with(':') {
+      return Err(Error::InvalidMethodId);
+    }
+
+    Ok(())
+  }
+
   /// Set the method name of the [`DID`].
   pub fn set_method_name(&mut self, value: impl AsRef<str>) -> Result<(), Error> {
     Self::valid_method_name(value.as_ref())?;
@@ -124,7 +167,7 @@ impl CoreDID {
 
   /// Validates whether a string is a valid [`DID`] method name.
   pub fn valid_method_name(value: &str) -> Result<(), Error> {
-    if !value.chars().all(is_char_method_name) {
+    if value.is_empty() || !value.chars().all(is_char_method_name) {
       return Err(Error::InvalidMethodName);
     }
     Ok(())
@@ -139,21 +182,8 @@ impl CoreDID {
 
   /// Validates whether a string is a valid [`DID`] method-id.
   pub fn valid_method_id(value: &str) -> Result<(), Error> {
-    // if !value.chars().all(is_char_method_id) {
-    //   return Err(Error::InvalidMethodId);
-    // }
-    let mut chars = value.chars();
-    while let Some(c) = chars.next() {
-      match c {
-        '%' => {
-          let digits = chars.clone().take(2).collect::<String>();
-          u8::from_str_radix(&digits, 16).map_err(|_| Error::InvalidMethodId)?;
-          chars.next();
-          chars.next();
-        }
-        c if is_char_method_id(c) => (),
-        _ => return Err(Error::InvalidMethodId),
-      }
+    if value.is_empty() || !is_valid_url_segment(value, is_char_method_id) {
+      return Err(Error::InvalidMethodId);
     }
 
     Ok(())
@@ -6,6 +6,7 @@ use core::fmt::Debug;
 use core::fmt::Display;
 use core::fmt::Formatter;
 use core::str::FromStr;
+use std::borrow::Cow;
 use std::cmp::Ordering;
 use std::hash::Hash;
 use std::hash::Hasher;
@@ -13,11 +14,13 @@ use std::hash::Hasher;
 use did_url_parser::DID as BaseDIDUrl;
 
 use identity_core::common::KeyComparable;
+use identity_core::common::Timestamp;
 use identity_core::common::Url;
 
 use crate::did::is_char_method_id;
 use crate::did::CoreDID;
 use crate::did::DID;
+use crate::did_url_builder::did_parameters;
 use crate::Error;
 
 /// A [DID Url]: a [CoreDID] with [RelativeDIDUrl] components.
@@ -65,6 +68,33 @@ impl RelativeDIDUrl {
     }
   }
 
+  /// Parse a [`RelativeDIDUrl`] from a non-empty [relative DID URL](https://www.w3.org/TR/did-core/#relative-did-urls)
+  /// made of a path, a query and/or a fragment.
+  ///
+  /// E.g. `"/path?query#fragment"` or `"#key-1"`.
+  pub fn parse(input: impl AsRef<str>) -> Result<Self, Error> {
+    let input = input.as_ref();
+    let (rest, fragment) = match input.split_once('#') {
+      Some((rest, fragment)) => (rest, Some(fragment)),
+      None => (input, None),
+    };
+    let (path, query) = match rest.split_once('?') {
+      Some((path, query)) => (path, Some(query)),
+      None => (rest, None),
+    };
+
+    let mut url = Self::new();
+    url.set_path(Some(path))?;
+    url.set_query(query)?;
+    url.set_fragment(fragment)?;
+    // Reject empty components, which the setters ignore.
+    if url.is_empty() || url.to_string() != input {
+      return Err(Error::Other("invalid relative DID URL"));
+    }
+
+    Ok(url)
+  }
+
   /// Returns whether all URL segments are empty.
   pub fn is_empty(&self) -> bool {
     self.path.as_deref().unwrap_or_default().is_empty()
@@ -283,6 +313,22 @@ impl DIDUrl {
     Self::from_base_did_url(did_url)
   }
 
+  /// Parse a [`DIDUrl`] from a string, strictly following the
+  /// [DID URL Syntax](https://www.w3.org/TR/did-core/#did-url-syntax).
+  ///
+  /// Unlike [`DIDUrl::parse`], the DID is checked as by [`CoreDID::parse_strict`] and inputs that are not serialized
+  /// back unchanged, e.g. with an empty query or fragment, are rejected.
+  pub fn parse_strict(input: impl AsRef<str>) -> Result<Self, Error> {
+    let input = input.as_ref();
+    let did_url = Self::parse(input)?;
+    did_url.did.check_strict()?;
+    if did_url.to_string() != input {
+      return Err(Error::Other("DID URL is not in its canonical form"));
+    }
+
+    Ok(did_url)
+  }
+
   fn from_base_did_url(did_url: BaseDIDUrl) -> Result<Self, Error> {
     // Extract relative DID URL
     let url: RelativeDIDUrl = {
@@ -369,6 +415,44 @@ impl DIDUrl {
     self.url.query_pairs()
   }
 
+  /// Returns the percent-decoded value of the first query parameter named `name`.
+  pub fn query_param(&self, name: &str) -> Option<Cow<'_, str>> {
+    self
+      .query_pairs()
+      .find(|(param, _)| param == name)
+      .map(|(_, value)| value)
+  }
+
+  /// Returns the value of the [`service`](https://www.w3.org/TR/did-core/#did-parameters) parameter.
+  pub fn service(&self) -> Option<Cow<'_, str>> {
+    self.query_param(did_parameters::SERVICE)
+  }
+
+  /// Returns the value of the [`relativeRef`](https://www.w3.org/TR/did-core/#did-parameters) parameter.
+  pub fn relative_ref(&self) -> Option<Cow<'_, str>> {
+    self.query_param(did_parameters::RELATIVE_REF)
+  }
+
+  /// Returns the value of the [`versionId`](https://www.w3.org/TR/did-core/#did-parameters) parameter.
+  pub fn version_id(&self) -> Option<Cow<'_, str>> {
+    self.query_param(did_parameters::VERSION_ID)
+  }
+
+  /// Returns the value of the [`versionTime`](https://www.w3.org/TR/did-core/#did-parameters) parameter.
+  ///
+  /// Fails with [`Error::InvalidQuery`] if the parameter is not a valid timestamp.
+  pub fn version_time(&self) -> Result<Option<Timestamp>, Error> {
+    self
+      .query_param(did_parameters::VERSION_TIME)
+      .map(|version_time| Timestamp::parse(&version_time).map_err(|_| Error::InvalidQuery))
+      .transpose()
+  }
+
+  /// Returns the value of the [`hl`](https://www.w3.org/TR/did-core/#did-parameters) parameter.
+  pub fn hl(&self) -> Option<Cow<'_, str>> {
+    self.query_param(did_parameters::HL)
+  }
+
   /// Append a string representing a `path`, `query`, and/or `fragment`, returning a new [`DIDUrl`].
   ///
   /// Must begin with a valid delimiter character: '/', '?', '#'. Overwrites the existing URL
@@ -0,0 +1,370 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Write;
+
+use identity_core::common::Timestamp;
+
+use crate::did::is_char_method_id;
+use crate::did::CoreDID;
+use crate::did_url::is_char_fragment;
+use crate::did_url::is_char_path;
+use crate::did_url::RelativeDIDUrl;
+use crate::DIDUrl;
+use crate::Error;
+
+/// The names of the query parameters standardized by the [DID specification](https://www.w3.org/TR/did-core/#did-parameters).
+pub mod did_parameters {
+  /// Identifies a service of the DID document by its ID.
+  pub const SERVICE: &str = "service";
+  /// A relative URI reference resolved against the endpoint of the service identified by [`SERVICE`].
+  pub const RELATIVE_REF: &str = "relativeRef";
+  /// Identifies a specific version of the DID document.
+  pub const VERSION_ID: &str = "versionId";
+  /// Identifies the version of the DID document that was valid at a given time.
+  pub const VERSION_TIME: &str = "versionTime";
+  /// A hashlink of the resource, to add integrity protection.
+  pub const HL: &str = "hl";
+
+  /// All the standard DID parameters.
+  pub const ALL: [&str; 5] = [SERVICE, RELATIVE_REF, VERSION_ID, VERSION_TIME, HL];
+}
+
+/// A builder for [`DIDUrl`]s that takes care of percent-encoding their components.
+///
+/// Unlike [`DIDUrl::set_query`], which expects an already encoded query, values passed to this builder are raw and
+/// get percent-encoded as needed. Setting a parameter that is already set replaces its value.
+///
+/// # Example
+///
+/// ```
+/// # use identity_did::CoreDID;
+/// # use identity_did::DIDUrlBuilder;
+/// let did: CoreDID = "did:example:123".parse().unwrap();
+/// let did_url = DIDUrlBuilder::new(did)
+///   .service("files")
+///   .relative_ref("/docs/my resume.pdf")
+///   .build()
+///   .unwrap();
+///
+/// assert_eq!(
+///   did_url.to_string(),
+///   "did:example:123?service=files&relativeRef=/docs/my%20resume.pdf"
+/// );
+/// assert_eq!(did_url.relative_ref().as_deref(), Some("/docs/my resume.pdf"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct DIDUrlBuilder {
+  did: CoreDID,
+  path: Vec<String>,
+  params: Vec<(String, String)>,
+  fragment: Option<String>,
+}
+
+impl DIDUrlBuilder {
+  /// Creates a new [`DIDUrlBuilder`] for a URL of `did`.
+  pub fn new(did: CoreDID) -> Self {
+    Self {
+      did,
+      path: Vec::new(),
+      params: Vec::new(),
+      fragment: None,
+    }
+  }
+
+  /// Appends `segment` to the path, percent-encoding it. Any `/` in `segment` is encoded as well.
+  #[must_use]
+  pub fn path_segment(mut self, segment: impl Into<String>) -> Self {
+    self.path.push(segment.into());
+    self
+  }
+
+  /// Sets the `service` parameter to `id`, the fragment of the targeted service.
+  #[must_use]
+  pub fn service(self, id: impl Into<String>) -> Self {
+    self.query_param(did_parameters::SERVICE, id)
+  }
+
+  /// Sets the `relativeRef` parameter, which requires the `service` parameter to be set.
+  #[must_use]
+  pub fn relative_ref(self, relative_ref: impl Into<String>) -> Self {
+    self.query_param(did_parameters::RELATIVE_REF, relative_ref)
+  }
+
+  /// Sets the `versionId` parameter.
+  #[must_use]
+  pub fn version_id(self, version_id: impl Into<String>) -> Self {
+    self.query_param(did_parameters::VERSION_ID, version_id)
+  }
+
+  /// Sets the `versionTime` parameter.
+  #[must_use]
+  pub fn version_time(self, version_time: Timestamp) -> Self {
+    self.query_param(did_parameters::VERSION_TIME, version_time.to_rfc3339())
+  }
+
+  /// Sets the `hl` parameter.
+  #[must_use]
+  pub fn hl(self, hashlink: impl Into<String>) -> Self {
+    self.query_param(did_parameters::HL, hashlink)
+  }
+
+  /// Sets the query parameter `name` to `value`.
+  #[must_use]
+  pub fn query_param(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+    let name: String = name.into();
+    let value: String = value.into();
+    match self.params.iter_mut().find(|(param, _)| *param == name) {
+      Some((_, current)) => *current = value,
+      None => self.params.push((name, value)),
+    }
+    self
+  }
+
+  /// Sets the fragment, percent-encoding it.
+  #[must_use]
+  pub fn fragment(mut self, fragment: impl Into<String>) -> Self {
+    self.fragment = Some(fragment.into());
+    self
+  }
+
+  /// Builds the [`DIDUrl`].
+  ///
+  /// # Errors
+  /// Fails with [`Error::InvalidPath`] if a path segment is empty, with [`Error::InvalidQuery`] if a parameter name
+  /// or the value of a standard parameter is empty, if `relativeRef` is set without `service` or if `versionTime` is not a valid timestamp, and
+  /// with [`Error::InvalidFragment`] if the fragment is empty.
+  pub fn build(self) -> Result<DIDUrl, Error> {
+    let mut url: RelativeDIDUrl = RelativeDIDUrl::new();
+
+    if !self.path.is_empty() {
+      if self.path.iter().any(String::is_empty) {
+        return Err(Error::InvalidPath);
+      }
+      let path: String = self
+        .path
+        .iter()
+        .map(|segment| format!("/{}", percent_encode(segment, |ch| ch != '/' && is_char_path(ch))))
+        .collect();
+      url.set_path(Some(&path))?;
+    }
+
+    if !self.params.is_empty() {
+      self.check_params()?;
+      let query: String = self
+        .params
+        .iter()
+        .map(|(name, value)| {
+          format!(
+            "{}={}",
+            percent_encode(name, is_char_query_param),
+            percent_encode(value, is_char_query_param)
+          )
+        })
+        .collect::<Vec<String>>()
+        .join("&");
+      url.set_query(Some(&query))?;
+    }
+
+    if let Some(fragment) = self.fragment.as_deref() {
+      if fragment.is_empty() {
+        return Err(Error::InvalidFragment);
+      }
+      url.set_fragment(Some(&percent_encode(fragment, is_char_fragment)))?;
+    }
+
+    Ok(DIDUrl::new(self.did, Some(url)))
+  }
+
+  fn check_params(&self) -> Result<(), Error> {
+    let has_param = |name: &str| self.params.iter().any(|(param, _)| param == name);
+
+    if self
+      .params
+      .iter()
+      .any(|(name, value)| name.is_empty() || (value.is_empty() && did_parameters::ALL.contains(&name.as_str())))
+    {
+      return Err(Error::InvalidQuery);
+    }
+    if has_param(did_parameters::RELATIVE_REF) && !has_param(did_parameters::SERVICE) {
+      return Err(Error::InvalidQuery);
+    }
+    if let Some((_, version_time)) = self
+      .params
+      .iter()
+      .find(|(name, _)| name == did_parameters::VERSION_TIME)
+    {
+      Timestamp::parse(version_time).map_err(|_| Error::InvalidQuery)?;
+    }
+
+    Ok(())
+  }
+}
+
+impl From<DIDUrl> for DIDUrlBuilder {
+  /// Creates a [`DIDUrlBuilder`] starting from the components of an existing [`DIDUrl`].
+  fn from(did_url: DIDUrl) -> Self {
+    let path: Vec<String> = did_url
+      .path()
+      .map(|path| path.split('/').skip(1).map(percent_decode).collect())
+      .unwrap_or_default();
+    let params: Vec<(String, String)> = did_url
+      .query_pairs()
+      .map(|(name, value)| (name.into_owned(), value.into_owned()))
+      .collect();
+    let fragment: Option<String> = did_url.fragment().map(percent_decode);
+
+    Self {
+      did: did_url.did().clone(),
+      path,
+      params,
+      fragment,
+    }
+  }
+}
+
+/// Checks whether a character may appear unencoded in the name or value of a query parameter.
+///
+/// The delimiters `&` and `=` are excluded, as is `+` since it is decoded as a space in
+/// `application/x-www-form-urlencoded` queries.
+fn is_char_query_param(ch: char) -> bool {
+  is_char_method_id(ch)
+    || matches!(
+      ch,
+      '~' | '!' | '$' | '\'' | '(' | ')' | '*' | ',' | ';' | '@' | '/' | '?'
+    )
+}
+
+/// Percent-encodes the UTF-8 bytes of `input` that do not satisfy `is_allowed`.
+fn percent_encode(input: &str, is_allowed: impl Fn(char) -> bool) -> String {
+  let mut output: String = String::with_capacity(input.len());
+  for ch in input.chars() {
+    // `%` is part of the allowed characters of the predicates, but must always be encoded in raw input.
+    if ch != '%' && is_allowed(ch) {
+      output.push(ch);
+    } else {
+      let mut buf: [u8; 4] = [0; 4];
+      for byte in ch.encode_utf8(&mut buf).bytes() {
+        write!(output, "%{byte:02X}").expect("writing to a string cannot fail");
+      }
+    }
+  }
+  output
+}
+
+/// Decodes the percent-encoded octets of `input`, replacing invalid UTF-8 sequences.
+fn percent_decode(input: &str) -> String {
+  let bytes: &[u8] = input.as_bytes();
+  let mut output: Vec<u8> = Vec::with_capacity(bytes.len());
+  let mut idx: usize = 0;
+  while idx < bytes.len() {
+    let decoded: Option<u8> = (bytes[idx] == b'%')
+      .then(|| input.get(idx + 1..idx + 3))
+      .flatten()
+      .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+    match decoded {
+      Some(byte) => {
+        output.push(byte);
+        idx += 3;
+      }
+      None => {
+        output.push(bytes[idx]);
+        idx += 1;
+      }
+    }
+  }
+  String::from_utf8_lossy(&output).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn did() -> CoreDID {
+    "did:example:123".parse().unwrap()
+  }
+
+  #[test]
+  fn standard_parameters_are_encoded() {
+    let version_time: Timestamp = Timestamp::parse("2024-01-01T00:00:00Z").unwrap();
+    let did_url: DIDUrl = DIDUrlBuilder::new(did())
+      .service("linked domain")
+      .relative_ref("/path?a=b&c=d")
+      .version_id("1")
+      .version_time(version_time)
+      .hl("zQmWvQxTqbG2Z9HPJgG57jjwR154cKhbtJenbyYTWkjgF3e")
+      .query_param("custom+param", "100%")
+      .fragment("key 1")
+      .build()
+      .unwrap();
+
+    assert_eq!(
+      did_url.to_string(),
+      "did:example:123?service=linked%20domain&relativeRef=/path?a%3Db%26c%3Dd&versionId=1\
+       &versionTime=2024-01-01T00:00:00Z&hl=zQmWvQxTqbG2Z9HPJgG57jjwR154cKhbtJenbyYTWkjgF3e\
+       &custom%2Bparam=100%25#key%201"
+    );
+    assert_eq!(did_url.service().as_deref(), Some("linked domain"));
+    assert_eq!(did_url.relative_ref().as_deref(), Some("/path?a=b&c=d"));
+    assert_eq!(did_url.version_id().as_deref(), Some("1"));
+    assert_eq!(did_url.version_time().unwrap(), Some(version_time));
+    assert_eq!(
+      did_url.hl().as_deref(),
+      Some("zQmWvQxTqbG2Z9HPJgG57jjwR154cKhbtJenbyYTWkjgF3e")
+    );
+    assert_eq!(did_url.query_param("custom+param").as_deref(), Some("100%"));
+
+    let parsed: DIDUrl = DIDUrl::parse(did_url.to_string()).unwrap();
+    assert_eq!(parsed, did_url);
+  }
+
+  #[test]
+  fn path_segments_are_encoded() {
+    let did_url: DIDUrl = DIDUrlBuilder::new(did())
+      .path_segment("files")
+      .path_segment("a/b c")
+      .build()
+      .unwrap();
+    assert_eq!(did_url.to_string(), "did:example:123/files/a%2Fb%20c");
+
+    let rebuilt: DIDUrl = DIDUrlBuilder::from(did_url.clone()).build().unwrap();
+    assert_eq!(rebuilt, did_url);
+  }
+
+  #[test]
+  fn existing_urls_can_be_extended() {
+    let did_url: DIDUrl = DIDUrl::parse("did:example:123?service=files#key-1").unwrap();
+    let extended: DIDUrl = DIDUrlBuilder::from(did_url)
+      .service("agent")
+      .version_id("2")
+      .build()
+      .unwrap();
+    assert_eq!(extended.to_string(), "did:example:123?service=agent&versionId=2#key-1");
+  }
+
+  #[test]
+  fn invalid_components_are_rejected() {
+    assert!(matches!(
+      DIDUrlBuilder::new(did()).relative_ref("/resume.pdf").build(),
+      Err(Error::InvalidQuery)
+    ));
+    assert!(matches!(
+      DIDUrlBuilder::new(did())
+        .query_param("versionTime", "yesterday")
+        .build(),
+      Err(Error::InvalidQuery)
+    ));
+    assert!(matches!(
+      DIDUrlBuilder::new(did()).version_id("").build(),
+      Err(Error::InvalidQuery)
+    ));
+    assert!(matches!(
+      DIDUrlBuilder::new(did()).path_segment("").build(),
+      Err(Error::InvalidPath)
+    ));
+    assert!(matches!(
+      DIDUrlBuilder::new(did()).fragment("").build(),
+      Err(Error::InvalidFragment)
+    ));
+  }
+}
@@ -36,3 +36,13 @@ impl From<did_url_parser::Error> for Error {
     }
   }
 }
+
+identity_core::impl_error_code!(Error {
+  InvalidFragment => 1100,
+  InvalidMethodId => 1101,
+  InvalidMethodName => 1102,
+  InvalidPath => 1103,
+  InvalidQuery => 1104,
+  InvalidScheme => 1105,
+  Other => 1106,
+});
@@ -16,18 +16,24 @@
   // clippy::missing_errors_doc
 )]
 
+pub mod conformance;
 #[allow(clippy::module_inception)]
 mod did;
 mod did_compositejwk;
 mod did_jwk;
 mod did_url;
+mod did_url_builder;
 mod error;
+mod redaction;
 
 pub use crate::did_url::DIDUrl;
 pub use crate::did_url::RelativeDIDUrl;
+pub use crate::did_url_builder::did_parameters;
+pub use crate::did_url_builder::DIDUrlBuilder;
 pub use ::did_url_parser::DID as BaseDIDUrl;
 pub use did::CoreDID;
 pub use did::DID;
 pub use did_compositejwk::*;
 pub use did_jwk::*;
 pub use error::Error;
+pub use redaction::*;
@@ -0,0 +1,150 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::sync::atomic::AtomicU8;
+use core::sync::atomic::Ordering;
+
+use crate::DID;
+
+/// Number of leading characters of a method-specific ID kept by [`DIDRedaction::Truncated`].
+const TRUNCATED_PREFIX_LEN: usize = 6;
+/// Number of trailing characters of a method-specific ID kept by [`DIDRedaction::Truncated`].
+const TRUNCATED_SUFFIX_LEN: usize = 4;
+
+static DID_REDACTION: AtomicU8 = AtomicU8::new(DIDRedaction::None as u8);
+
+/// Controls how DIDs are rendered in diagnostic output, e.g. log records and tracing spans.
+///
+/// The process wide setting is changed through [`set_did_redaction`] and applies to every [`RedactedDID`]
+/// formatted afterwards.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(u8)]
+#[non_exhaustive]
+pub enum DIDRedaction {
+  /// DIDs are rendered in full.
+  #[default]
+  None = 0,
+  /// Only the first and last few characters of the method-specific ID are kept,
+  /// e.g. `did:iota:0x1234…cdef`.
+  Truncated = 1,
+  /// The method-specific ID is hidden entirely, e.g. `did:iota:<redacted>`.
+  MethodOnly = 2,
+}
+
+impl DIDRedaction {
+  fn from_u8(value: u8) -> Self {
+    match value {
+      1 => Self::Truncated,
+      2 => Self::MethodOnly,
+      _ => Self::None,
+    }
+  }
+}
+
+/// Sets how DIDs are rendered in diagnostic output for the whole process.
+pub fn set_did_redaction(redaction: DIDRedaction) {
+  DID_REDACTION.store(redaction as u8, Ordering::Relaxed);
+}
+
+/// Returns the [`DIDRedaction`] currently in use.
+pub fn did_redaction() -> DIDRedaction {
+  DIDRedaction::from_u8(DID_REDACTION.load(Ordering::Relaxed))
+}
+
+/// A [`Display`]able view of a [`DID`] that honours the configured [`DIDRedaction`].
+///
+/// Obtained through [`DID::redacted`].
+#[derive(Clone, Copy, Debug)]
+pub struct RedactedDID<'a> {
+  method: &'a str,
+  method_id: &'a str,
+  redaction: DIDRedaction,
+}
+
+impl<'a> RedactedDID<'a> {
+  /// Creates a view of `did` using the process wide [`DIDRedaction`].
+  pub fn new<D: DID>(did: &'a D) -> Self {
+    Self::with_redaction(did, did_redaction())
+  }
+
+  /// Creates a view of `did` using the given `redaction`, regardless of the process wide setting.
+  pub fn with_redaction<D: DID>(did: &'a D, redaction: DIDRedaction) -> Self {
+    Self {
+      method: did.method(),
+      method_id: did.method_id(),
+      redaction,
+    }
+  }
+}
+
+impl Display for RedactedDID<'_> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    let Self {
+      method,
+      method_id,
+      redaction,
+    } = *self;
+    match redaction {
+      DIDRedaction::None => write!(f, "did:{method}:{method_id}"),
+      DIDRedaction::MethodOnly => write!(f, "did:{method}:<redacted>"),
+      DIDRedaction::Truncated => {
+        let len: usize = method_id.chars().count();
+        if len <= TRUNCATED_PREFIX_LEN + TRUNCATED_SUFFIX_LEN {
+          return write!(f, "did:{method}:{method_id}");
+        }
+        let prefix_end: usize = char_offset(method_id, TRUNCATED_PREFIX_LEN);
+        let suffix_start: usize = char_offset(method_id, len - TRUNCATED_SUFFIX_LEN);
+        write!(
+          f,
+          "did:{method}:{}…{}",
+          &method_id[..prefix_end],
+          &method_id[suffix_start..]
+        )
+      }
+    }
+  }
+}
+
+/// Returns the byte offset of the `n`-th character of `s`.
+fn char_offset(s: &str, n: usize) -> usize {
+  s.char_indices().nth(n).map(|(offset, _)| offset).unwrap_or(s.len())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::CoreDID;
+
+  #[test]
+  fn redacted_did_formatting() {
+    let did: CoreDID = CoreDID::parse("did:iota:0x0123456789abcdef").unwrap();
+    let short: CoreDID = CoreDID::parse("did:example:1234").unwrap();
+
+    assert_eq!(
+      RedactedDID::with_redaction(&did, DIDRedaction::None).to_string(),
+      "did:iota:0x0123456789abcdef"
+    );
+    assert_eq!(
+      RedactedDID::with_redaction(&did, DIDRedaction::Truncated).to_string(),
+      "did:iota:0x0123…cdef"
+    );
+    assert_eq!(
+      RedactedDID::with_redaction(&did, DIDRedaction::MethodOnly).to_string(),
+      "did:iota:<redacted>"
+    );
+    assert_eq!(
+      RedactedDID::with_redaction(&short, DIDRedaction::Truncated).to_string(),
+      "did:example:1234"
+    );
+  }
+
+  #[test]
+  fn redaction_setting_roundtrip() {
+    for redaction in [DIDRedaction::Truncated, DIDRedaction::MethodOnly, DIDRedaction::None] {
+      set_did_redaction(redaction);
+      assert_eq!(did_redaction(), redaction);
+    }
+  }
+}
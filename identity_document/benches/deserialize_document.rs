@@ -235,5 +235,24 @@ fn deserialize_json_document(c: &mut Criterion) {
   group.finish();
 }
 
-criterion_group!(benches, deserialize_json_document);
+fn deserialize_json_value_document(c: &mut Criterion) {
+  let mut group = c.benchmark_group("deserialize_json_value_document");
+  for (json, name) in [
+    (JSON_DOC_SHORT, "short document"),
+    (JSON_DOC_DID_KEY, "did:key document"),
+    (JSON_DOCUMENT_LARGE, "large document"),
+  ] {
+    let value: serde_json::Value = serde_json::from_str(json).unwrap();
+    group.throughput(Throughput::Bytes(json.len() as u64));
+    group.bench_with_input(BenchmarkId::new("cloned value", name), &value, |b, value| {
+      b.iter(|| CoreDocument::from_json_value(value.clone()).unwrap())
+    });
+    group.bench_with_input(BenchmarkId::new("borrowed value", name), &value, |b, value| {
+      b.iter(|| CoreDocument::from_json_value_ref(value).unwrap())
+    });
+  }
+  group.finish();
+}
+
+criterion_group!(benches, deserialize_json_document, deserialize_json_value_document);
 criterion_main!(benches);
@@ -0,0 +1,268 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+
+use identity_did::DIDUrl;
+use identity_verification::jose::jwk::Jwk;
+use identity_verification::jose::jwk::JwkParams;
+use identity_verification::jose::jws::JwsAlgorithm;
+use identity_verification::MethodScope;
+use identity_verification::VerificationMethod;
+
+use crate::document::CoreDocument;
+
+/// A capability a DID document must provide to be usable with a given protocol, e.g. "an `assertionMethod` able to
+/// produce `EdDSA` signatures" or "a `keyAgreement` method on the `X25519` curve".
+///
+/// A requirement is fulfilled by any verification method in the required scope whose key material matches the
+/// required algorithm and curve, if any.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CapabilityRequirement {
+  scope: MethodScope,
+  algorithm: Option<JwsAlgorithm>,
+  curve: Option<String>,
+}
+
+impl CapabilityRequirement {
+  /// Requires a verification method in the given `scope`.
+  pub fn new(scope: MethodScope) -> Self {
+    Self {
+      scope,
+      algorithm: None,
+      curve: None,
+    }
+  }
+
+  /// Additionally requires the method to be usable to produce JWS signatures with `algorithm`.
+  pub fn algorithm(mut self, algorithm: JwsAlgorithm) -> Self {
+    self.algorithm = Some(algorithm);
+    self
+  }
+
+  /// Additionally requires the key material of the method to be on the given `curve`, e.g. `"X25519"`.
+  pub fn curve(mut self, curve: impl Into<String>) -> Self {
+    self.curve = Some(curve.into());
+    self
+  }
+
+  /// Returns the required scope.
+  pub fn scope(&self) -> MethodScope {
+    self.scope
+  }
+
+  /// Returns the required JWS algorithm, if any.
+  pub fn required_algorithm(&self) -> Option<&JwsAlgorithm> {
+    self.algorithm.as_ref()
+  }
+
+  /// Returns the required curve, if any.
+  pub fn required_curve(&self) -> Option<&str> {
+    self.curve.as_deref()
+  }
+
+  /// Returns whether `method` fulfills this requirement, regardless of its scope.
+  ///
+  /// The key material of `method` is interpreted through [`VerificationMethod::to_jwk`], so methods encoding their
+  /// key as `publicKeyMultibase` or `publicKeyBase58` can fulfill requirements as well.
+  pub fn is_fulfilled_by(&self, method: &VerificationMethod) -> bool {
+    if self.algorithm.is_none() && self.curve.is_none() {
+      return true;
+    }
+    let Ok(jwk) = method.to_jwk() else {
+      return false;
+    };
+
+    let curve_matches = self
+      .curve
+      .as_deref()
+      .map_or(true, |curve| jwk_curve(&jwk) == Some(curve));
+    let algorithm_matches = self
+      .algorithm
+      .as_ref()
+      .map_or(true, |algorithm| jwk_supports(&jwk, algorithm));
+    curve_matches && algorithm_matches
+  }
+}
+
+impl Display for CapabilityRequirement {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    f.write_str(self.scope.as_str())?;
+    if let Some(algorithm) = &self.algorithm {
+      write!(f, " with {algorithm}")?;
+    }
+    if let Some(curve) = &self.curve {
+      write!(f, " on curve {curve}")?;
+    }
+    Ok(())
+  }
+}
+
+/// The outcome of [`CoreDocument::check_capabilities`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CapabilityReport {
+  fulfilled: Vec<(CapabilityRequirement, DIDUrl)>,
+  missing: Vec<CapabilityRequirement>,
+}
+
+impl CapabilityReport {
+  /// Returns whether all requirements are fulfilled.
+  pub fn is_satisfied(&self) -> bool {
+    self.missing.is_empty()
+  }
+
+  /// Returns the fulfilled requirements, each with the identifier of the first method fulfilling it.
+  pub fn fulfilled(&self) -> &[(CapabilityRequirement, DIDUrl)] {
+    &self.fulfilled
+  }
+
+  /// Returns the requirements no method of the document fulfills.
+  pub fn missing(&self) -> &[CapabilityRequirement] {
+    &self.missing
+  }
+}
+
+impl CoreDocument {
+  /// Checks which of the given `requirements` this document fulfills, e.g. to tell a user why their DID cannot be used
+  /// with a given protocol.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use identity_document::document::CapabilityRequirement;
+  /// # use identity_document::document::CoreDocument;
+  /// # use identity_verification::jose::jws::JwsAlgorithm;
+  /// # use identity_verification::MethodScope;
+  /// # let document = CoreDocument::builder(Default::default())
+  /// #   .id("did:example:1234".parse().unwrap())
+  /// #   .build()
+  /// #   .unwrap();
+  /// let report = document.check_capabilities(&[
+  ///   CapabilityRequirement::new(MethodScope::assertion_method()).algorithm(JwsAlgorithm::EdDSA),
+  ///   CapabilityRequirement::new(MethodScope::key_agreement()).curve("X25519"),
+  /// ]);
+  /// for requirement in report.missing() {
+  ///   println!("missing: {requirement}");
+  /// }
+  /// # assert_eq!(report.missing().len(), 2);
+  /// ```
+  pub fn check_capabilities<'r>(
+    &self,
+    requirements: impl IntoIterator<Item = &'r CapabilityRequirement>,
+  ) -> CapabilityReport {
+    let mut report = CapabilityReport::default();
+    for requirement in requirements {
+      match self
+        .methods(Some(requirement.scope))
+        .into_iter()
+        .find(|method| requirement.is_fulfilled_by(method))
+      {
+        Some(method) => report.fulfilled.push((requirement.clone(), method.id().clone())),
+        None => report.missing.push(requirement.clone()),
+      }
+    }
+
+    report
+  }
+}
+
+fn jwk_curve(jwk: &Jwk) -> Option<&str> {
+  match jwk.params() {
+    JwkParams::Okp(params) => Some(params.crv.as_str()),
+    JwkParams::Ec(params) => Some(params.crv.as_str()),
+    _ => None,
+  }
+}
+
+/// Returns the JWS algorithm producing signatures with keys on `curve`, if any.
+fn curve_algorithm(curve: &str) -> Option<JwsAlgorithm> {
+  match curve {
+    "Ed25519" => Some(JwsAlgorithm::EdDSA),
+    "P-256" => Some(JwsAlgorithm::ES256),
+    "P-384" => Some(JwsAlgorithm::ES384),
+    "P-521" => Some(JwsAlgorithm::ES512),
+    "secp256k1" => Some(JwsAlgorithm::ES256K),
+    _ => None,
+  }
+}
+
+fn jwk_supports(jwk: &Jwk, algorithm: &JwsAlgorithm) -> bool {
+  // An explicit `alg` restricts the key to that algorithm.
+  if let Some(alg) = jwk.alg() {
+    return alg.parse::<JwsAlgorithm>().ok().as_ref() == Some(algorithm);
+  }
+
+  match jwk.params() {
+    JwkParams::Okp(_) | JwkParams::Ec(_) => jwk_curve(jwk).and_then(curve_algorithm).as_ref() == Some(algorithm),
+    JwkParams::Rsa(_) => matches!(
+      algorithm,
+      JwsAlgorithm::RS256
+        | JwsAlgorithm::RS384
+        | JwsAlgorithm::RS512
+        | JwsAlgorithm::PS256
+        | JwsAlgorithm::PS384
+        | JwsAlgorithm::PS512
+    ),
+    _ => false,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+  use identity_did::CoreDID;
+  use identity_did::DID;
+  use serde_json::json;
+
+  use super::*;
+
+  fn document() -> CoreDocument {
+    let did: CoreDID = "did:example:1234".parse().unwrap();
+    let signing_key: Jwk = Jwk::from_json_value(json!({
+      "kty": "OKP",
+      "crv": "Ed25519",
+      "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"
+    }))
+    .unwrap();
+    let method = VerificationMethod::new_from_jwk(did.clone(), signing_key, Some("key-1")).unwrap();
+
+    let mut document = CoreDocument::builder(Default::default()).id(did).build().unwrap();
+    document.insert_method(method, MethodScope::assertion_method()).unwrap();
+    document
+  }
+
+  #[test]
+  fn missing_capabilities_are_reported() {
+    let document = document();
+    let assertion = CapabilityRequirement::new(MethodScope::assertion_method()).algorithm(JwsAlgorithm::EdDSA);
+    let key_agreement = CapabilityRequirement::new(MethodScope::key_agreement()).curve("X25519");
+    let es256 = CapabilityRequirement::new(MethodScope::assertion_method()).algorithm(JwsAlgorithm::ES256);
+
+    let report = document.check_capabilities([&assertion, &key_agreement, &es256]);
+    assert!(!report.is_satisfied());
+    assert_eq!(report.fulfilled().len(), 1);
+    assert_eq!(report.fulfilled()[0].0, assertion);
+    assert_eq!(report.fulfilled()[0].1, document.id().to_url().join("#key-1").unwrap());
+    assert_eq!(report.missing(), &[key_agreement.clone(), es256]);
+    assert_eq!(key_agreement.to_string(), "KeyAgreement on curve X25519");
+
+    assert!(document.check_capabilities([&assertion]).is_satisfied());
+  }
+
+  #[test]
+  fn multibase_methods_fulfill_requirements() {
+    let mut document = document();
+    let agreement_key: Jwk = Jwk::from_json_value(json!({
+      "kty": "OKP",
+      "crv": "X25519",
+      "x": "hSDwCYkwp1R0i33ctD73Wg2_Og0mOBr066SpjqqbTmo"
+    }))
+    .unwrap();
+    let method = VerificationMethod::from_jwk(document.id().clone(), &agreement_key, Some("key-2")).unwrap();
+    document.insert_method(method, MethodScope::key_agreement()).unwrap();
+
+    let key_agreement = CapabilityRequirement::new(MethodScope::key_agreement()).curve("X25519");
+    assert!(document.check_capabilities([&key_agreement]).is_satisfied());
+  }
+}
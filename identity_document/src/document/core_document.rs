@@ -20,8 +20,11 @@ use identity_core::common::OneOrSet;
 use identity_core::common::OrderedSet;
 use identity_core::common::Url;
 use identity_core::convert::FmtJson;
+use identity_core::convert::FromJson;
 use serde::Serializer;
 
+use crate::document::method_index::MethodIndex;
+use crate::document::method_index::MethodLocation;
 use crate::document::DocumentBuilder;
 use crate::error::Error;
 use crate::error::Result;
@@ -64,6 +67,17 @@ pub(crate) struct CoreDocumentData
 }
 
 impl CoreDocumentData {
+  /// Returns the set of method references for the given verification relationship.
+  pub(crate) fn relationship(&self, relationship: MethodRelationship) -> &OrderedSet<MethodRef> {
+    match relationship {
+      MethodRelationship::Authentication => &self.authentication,
+      MethodRelationship::AssertionMethod => &self.assertion_method,
+      MethodRelationship::KeyAgreement => &self.key_agreement,
+      MethodRelationship::CapabilityDelegation => &self.capability_delegation,
+      MethodRelationship::CapabilityInvocation => &self.capability_invocation,
+    }
+  }
+
   /// Checks the following:
   /// - There are no scoped method references to an embedded method in the document
   /// - The ids of verification methods (scoped/embedded or general purpose) and services are unique across the
@@ -228,12 +242,20 @@ impl CoreDocumentData {
 /// A DID Document.
 ///
 /// [Specification](https://www.w3.org/TR/did-core/#did-document-properties)
-#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[derive(Clone, PartialEq, Eq, Deserialize)]
 #[rustfmt::skip]
 #[serde(try_from = "CoreDocumentData")]
 pub struct CoreDocument
 {
-  pub(crate) data: CoreDocumentData, 
+  pub(crate) data: CoreDocumentData,
+  // Lookup structure speeding up method resolution, never serialized nor compared.
+  method_index: MethodIndex,
+}
+
+impl core::fmt::Debug for CoreDocument {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    f.debug_struct("CoreDocument").field("data", &self.data).finish()
+  }
 }
 
 //Forward serialization to inner
@@ -248,12 +270,13 @@ impl Serialize for CoreDocument {
 
 // Workaround for lifetime issues with a mutable reference to self preventing closures from being used.
 macro_rules! method_ref_mut_helper {
-  ($doc:ident, $method: ident, $query: ident) => {
-    match $doc.data.$method.query_mut($query.into())? {
+  ($doc:ident, $method: ident, $query: ident) => {{
+    let data: &mut CoreDocumentData = $doc.data_mut();
+    match data.$method.query_mut($query.into())? {
       MethodRef::Embed(method) => Some(method),
-      MethodRef::Refer(ref did) => $doc.data.verification_method.query_mut(did),
+      MethodRef::Refer(ref did) => data.verification_method.query_mut(did),
     }
-  };
+  }};
 }
 
 impl CoreDocument {
@@ -417,24 +440,31 @@ impl CoreDocument {
     if self.resolve_method(method.id(), None).is_some() || self.service().query(method.id()).is_some() {
       return Err(Error::MethodInsertionError);
     }
-    match scope {
-      MethodScope::VerificationMethod => self.data.verification_method.append(method),
-      MethodScope::VerificationRelationship(MethodRelationship::Authentication) => {
-        self.data.authentication.append(MethodRef::Embed(method))
-      }
-      MethodScope::VerificationRelationship(MethodRelationship::AssertionMethod) => {
-        self.data.assertion_method.append(MethodRef::Embed(method))
-      }
-      MethodScope::VerificationRelationship(MethodRelationship::KeyAgreement) => {
-        self.data.key_agreement.append(MethodRef::Embed(method))
-      }
-      MethodScope::VerificationRelationship(MethodRelationship::CapabilityDelegation) => {
-        self.data.capability_delegation.append(MethodRef::Embed(method))
+    let fragment: Option<String> = method.id().fragment().map(ToOwned::to_owned);
+    let (appended, location): (bool, MethodLocation) = match scope {
+      MethodScope::VerificationMethod => {
+        let appended: bool = self.data.verification_method.append(method);
+        (
+          appended,
+          MethodLocation::VerificationMethod(self.data.verification_method.len() - 1),
+        )
       }
-      MethodScope::VerificationRelationship(MethodRelationship::CapabilityInvocation) => {
-        self.data.capability_invocation.append(MethodRef::Embed(method))
+      MethodScope::VerificationRelationship(relationship) => {
+        let set: &mut OrderedSet<MethodRef> = match relationship {
+          MethodRelationship::Authentication => &mut self.data.authentication,
+          MethodRelationship::AssertionMethod => &mut self.data.assertion_method,
+          MethodRelationship::KeyAgreement => &mut self.data.key_agreement,
+          MethodRelationship::CapabilityDelegation => &mut self.data.capability_delegation,
+          MethodRelationship::CapabilityInvocation => &mut self.data.capability_invocation,
+        };
+        let appended: bool = set.append(MethodRef::Embed(method));
+        (appended, MethodLocation::Relationship(relationship, set.len() - 1))
       }
     };
+    // Appending never shifts existing entries, so the index can be updated in place rather than rebuilt.
+    if appended {
+      self.method_index.insert(fragment.as_deref(), location);
+    }
 
     Ok(())
   }
@@ -457,32 +487,33 @@ impl CoreDocument {
   /// All _references to the method_ found in the document will be removed.
   /// This includes cases where the reference is to a method contained in another DID document.
   pub fn remove_method_and_scope(&mut self, did_url: &DIDUrl) -> Option<(VerificationMethod, MethodScope)> {
+    let data: &mut CoreDocumentData = self.data_mut();
     for (method_ref, scope) in [
-      self.data.authentication.remove(did_url).map(|method_ref| {
+      data.authentication.remove(did_url).map(|method_ref| {
         (
           method_ref,
           MethodScope::VerificationRelationship(MethodRelationship::Authentication),
         )
       }),
-      self.data.assertion_method.remove(did_url).map(|method_ref| {
+      data.assertion_method.remove(did_url).map(|method_ref| {
         (
           method_ref,
           MethodScope::VerificationRelationship(MethodRelationship::AssertionMethod),
         )
       }),
-      self.data.key_agreement.remove(did_url).map(|method_ref| {
+      data.key_agreement.remove(did_url).map(|method_ref| {
         (
           method_ref,
           MethodScope::VerificationRelationship(MethodRelationship::KeyAgreement),
         )
       }),
-      self.data.capability_delegation.remove(did_url).map(|method_ref| {
+      data.capability_delegation.remove(did_url).map(|method_ref| {
         (
           method_ref,
           MethodScope::VerificationRelationship(MethodRelationship::CapabilityDelegation),
         )
       }),
-      self.data.capability_invocation.remove(did_url).map(|method_ref| {
+      data.capability_invocation.remove(did_url).map(|method_ref| {
         (
           method_ref,
           MethodScope::VerificationRelationship(MethodRelationship::CapabilityInvocation),
@@ -499,8 +530,7 @@ impl CoreDocument {
       }
     }
 
-    self
-      .data
+    data
       .verification_method
       .remove(did_url)
       .map(|method| (method, MethodScope::VerificationMethod))
@@ -555,11 +585,11 @@ impl CoreDocument {
         let method_ref = MethodRef::Refer(method.id().clone());
 
         let was_attached = match relationship {
-          MethodRelationship::Authentication => self.data.authentication.append(method_ref),
-          MethodRelationship::AssertionMethod => self.data.assertion_method.append(method_ref),
-          MethodRelationship::KeyAgreement => self.data.key_agreement.append(method_ref),
-          MethodRelationship::CapabilityDelegation => self.data.capability_delegation.append(method_ref),
-          MethodRelationship::CapabilityInvocation => self.data.capability_invocation.append(method_ref),
+          MethodRelationship::Authentication => self.data_mut().authentication.append(method_ref),
+          MethodRelationship::AssertionMethod => self.data_mut().assertion_method.append(method_ref),
+          MethodRelationship::KeyAgreement => self.data_mut().key_agreement.append(method_ref),
+          MethodRelationship::CapabilityDelegation => self.data_mut().capability_delegation.append(method_ref),
+          MethodRelationship::CapabilityInvocation => self.data_mut().capability_invocation.append(method_ref),
         };
 
         Ok(was_attached)
@@ -597,11 +627,11 @@ impl CoreDocument {
         let did_url: DIDUrl = method.id().clone();
 
         let was_detached = match relationship {
-          MethodRelationship::Authentication => self.data.authentication.remove(&did_url),
-          MethodRelationship::AssertionMethod => self.data.assertion_method.remove(&did_url),
-          MethodRelationship::KeyAgreement => self.data.key_agreement.remove(&did_url),
-          MethodRelationship::CapabilityDelegation => self.data.capability_delegation.remove(&did_url),
-          MethodRelationship::CapabilityInvocation => self.data.capability_invocation.remove(&did_url),
+          MethodRelationship::Authentication => self.data_mut().authentication.remove(&did_url),
+          MethodRelationship::AssertionMethod => self.data_mut().assertion_method.remove(&did_url),
+          MethodRelationship::KeyAgreement => self.data_mut().key_agreement.remove(&did_url),
+          MethodRelationship::CapabilityDelegation => self.data_mut().capability_delegation.remove(&did_url),
+          MethodRelationship::CapabilityInvocation => self.data_mut().capability_invocation.remove(&did_url),
         };
 
         Ok(was_detached.is_some())
@@ -695,41 +725,7 @@ impl CoreDocument {
   where
     Q: Into<DIDUrlQuery<'query>>,
   {
-    match scope {
-      Some(scope) => {
-        let resolve_ref_helper = |method_ref: &'me MethodRef| self.resolve_method_ref(method_ref);
-
-        match scope {
-          MethodScope::VerificationMethod => self.data.verification_method.query(method_query.into()),
-          MethodScope::VerificationRelationship(MethodRelationship::Authentication) => self
-            .data
-            .authentication
-            .query(method_query.into())
-            .and_then(resolve_ref_helper),
-          MethodScope::VerificationRelationship(MethodRelationship::AssertionMethod) => self
-            .data
-            .assertion_method
-            .query(method_query.into())
-            .and_then(resolve_ref_helper),
-          MethodScope::VerificationRelationship(MethodRelationship::KeyAgreement) => self
-            .data
-            .key_agreement
-            .query(method_query.into())
-            .and_then(resolve_ref_helper),
-          MethodScope::VerificationRelationship(MethodRelationship::CapabilityDelegation) => self
-            .data
-            .capability_delegation
-            .query(method_query.into())
-            .and_then(resolve_ref_helper),
-          MethodScope::VerificationRelationship(MethodRelationship::CapabilityInvocation) => self
-            .data
-            .capability_invocation
-            .query(method_query.into())
-            .and_then(resolve_ref_helper),
-        }
-      }
-      None => self.resolve_method_inner(method_query.into()),
-    }
+    self.resolve_method_inner(method_query.into(), scope)
   }
 
   /// Returns a mutable reference to the first [`VerificationMethod`] with an `id` property
@@ -750,7 +746,7 @@ impl CoreDocument {
   {
     match scope {
       Some(scope) => match scope {
-        MethodScope::VerificationMethod => self.data.verification_method.query_mut(method_query.into()),
+        MethodScope::VerificationMethod => self.data_mut().verification_method.query_mut(method_query.into()),
         MethodScope::VerificationRelationship(MethodRelationship::Authentication) => {
           method_ref_mut_helper!(self, authentication, method_query)
         }
@@ -785,67 +781,52 @@ impl CoreDocument {
   pub fn resolve_method_ref<'a>(&'a self, method_ref: &'a MethodRef) -> Option<&'a VerificationMethod> {
     match method_ref {
       MethodRef::Embed(method) => Some(method),
-      MethodRef::Refer(did) => self.data.verification_method.query(did),
+      MethodRef::Refer(did) => self.resolve_method_inner(did.into(), Some(MethodScope::VerificationMethod)),
     }
   }
 
-  fn resolve_method_inner(&self, query: DIDUrlQuery<'_>) -> Option<&VerificationMethod> {
-    let mut method: Option<&MethodRef> = None;
-
-    if method.is_none() {
-      method = self.data.authentication.query(query.clone());
-    }
-
-    if method.is_none() {
-      method = self.data.assertion_method.query(query.clone());
-    }
-
-    if method.is_none() {
-      method = self.data.key_agreement.query(query.clone());
-    }
-
-    if method.is_none() {
-      method = self.data.capability_delegation.query(query.clone());
-    }
-
-    if method.is_none() {
-      method = self.data.capability_invocation.query(query.clone());
-    }
-
-    match method {
-      Some(MethodRef::Embed(method)) => Some(method),
-      Some(MethodRef::Refer(did)) => self.data.verification_method.query(&did.to_string()),
-      None => self.data.verification_method.query(query),
+  fn resolve_method_inner(&self, query: DIDUrlQuery<'_>, scope: Option<MethodScope>) -> Option<&VerificationMethod> {
+    match self.method_index.find(&self.data, &query, scope)? {
+      MethodLocation::VerificationMethod(index) => self.data.verification_method.get(index),
+      MethodLocation::Relationship(relationship, index) => {
+        match self.data.relationship(relationship).get(index)? {
+          MethodRef::Embed(method) => Some(method),
+          // A referenced method is only looked up among the general purpose verification methods.
+          MethodRef::Refer(did) => self.resolve_method_inner(did.into(), Some(MethodScope::VerificationMethod)),
+        }
+      }
     }
   }
 
   fn resolve_method_mut_inner(&mut self, query: DIDUrlQuery<'_>) -> Option<&mut VerificationMethod> {
+    // The returned method may be modified in a way that affects its resolution, hence the index is invalidated.
+    let data: &mut CoreDocumentData = self.data_mut();
     let mut method: Option<&mut MethodRef> = None;
 
     if method.is_none() {
-      method = self.data.authentication.query_mut(query.clone());
+      method = data.authentication.query_mut(query.clone());
     }
 
     if method.is_none() {
-      method = self.data.assertion_method.query_mut(query.clone());
+      method = data.assertion_method.query_mut(query.clone());
     }
 
     if method.is_none() {
-      method = self.data.key_agreement.query_mut(query.clone());
+      method = data.key_agreement.query_mut(query.clone());
     }
 
     if method.is_none() {
-      method = self.data.capability_delegation.query_mut(query.clone());
+      method = data.capability_delegation.query_mut(query.clone());
     }
 
     if method.is_none() {
-      method = self.data.capability_invocation.query_mut(query.clone());
+      method = data.capability_invocation.query_mut(query.clone());
     }
 
     match method {
       Some(MethodRef::Embed(method)) => Some(method),
-      Some(MethodRef::Refer(did)) => self.data.verification_method.query_mut(&did.to_string()),
-      None => self.data.verification_method.query_mut(query),
+      Some(MethodRef::Refer(did)) => data.verification_method.query_mut(&did.to_string()),
+      None => data.verification_method.query_mut(query),
     }
   }
 
@@ -904,7 +885,16 @@ impl CoreDocument {
       .data
       .try_map(id_map, controller_map, method_map, services_map)
       .expect("unwrapping infallible should be fine");
-    CoreDocument { data }
+    CoreDocument {
+      data,
+      method_index: MethodIndex::default(),
+    }
+  }
+
+  /// Returns a mutable reference to the document's data, discarding any lookup structure derived from it.
+  fn data_mut(&mut self) -> &mut CoreDocumentData {
+    self.method_index.invalidate();
+    &mut self.data
   }
 }
 
@@ -918,12 +908,24 @@ impl TryFrom<CoreDocumentData> for CoreDocument {
   type Error = crate::error::Error;
   fn try_from(value: CoreDocumentData) -> Result<Self, Self::Error> {
     match value.check_id_constraints() {
-      Ok(_) => Ok(Self { data: value }),
+      Ok(_) => Ok(Self {
+        data: value,
+        method_index: MethodIndex::default(),
+      }),
       Err(err) => Err(err),
     }
   }
 }
 
+impl TryFrom<&serde_json::Value> for CoreDocument {
+  type Error = identity_core::Error;
+
+  /// Deserializes a [`CoreDocument`] from a borrowed JSON value, see [`FromJson::from_json_value_ref`].
+  fn try_from(value: &serde_json::Value) -> std::result::Result<Self, Self::Error> {
+    Self::from_json_value_ref(value)
+  }
+}
+
 impl Display for CoreDocument {
   fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
     self.fmt_json(f)
@@ -980,6 +982,13 @@ impl CoreDocument {
       .try_public_key_jwk()
       .map_err(Error::InvalidKeyMaterial)?;
 
+    if let Some(policy) = &options.algorithm_policy {
+      let alg = validation_item.alg().ok_or(Error::JwsVerificationError(
+        identity_verification::jose::error::Error::ProtectedHeaderWithoutAlg,
+      ))?;
+      policy.check(&alg, [public_key])?;
+    }
+
     validation_item
       .verify(signature_verifier, public_key)
       .map_err(Error::JwsVerificationError)
@@ -1032,6 +1041,17 @@ impl CoreDocument {
       .try_composite_public_key()
       .map_err(Error::InvalidKeyMaterial)?;
 
+    if let Some(policy) = &options.algorithm_policy {
+      let alg = validation_item.alg().ok_or(Error::JwsVerificationError(
+        identity_verification::jose::error::Error::ProtectedHeaderWithoutAlg,
+      ))?;
+      let public_keys: [&Jwk; 2] = [
+        composite_public_key.traditional_public_key(),
+        composite_public_key.pq_public_key(),
+      ];
+      policy.check(&alg, public_keys)?;
+    }
+
     validation_item
       .verify_hybrid(
         traditional_verifier,
@@ -1241,6 +1261,113 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_resolve_method_many_methods() {
+    let controller: CoreDID = controller();
+    let mut document: CoreDocument = document();
+    let scopes: [MethodScope; 6] = [
+      MethodScope::VerificationMethod,
+      MethodScope::authentication(),
+      MethodScope::assertion_method(),
+      MethodScope::key_agreement(),
+      MethodScope::capability_delegation(),
+      MethodScope::capability_invocation(),
+    ];
+    for index in 0..300 {
+      let scope: MethodScope = scopes[index % scopes.len()];
+      document
+        .insert_method(method(&controller, &format!("#many-{index}")), scope)
+        .unwrap();
+    }
+
+    for index in 0..300 {
+      let fragment: String = format!("many-{index}");
+      let expected: String = format!("did:example:1234#{fragment}");
+      let scope: MethodScope = scopes[index % scopes.len()];
+      let other_scope: MethodScope = scopes[(index + 1) % scopes.len()];
+      assert_eq!(
+        document.resolve_method(&fragment, None).unwrap().id().to_string(),
+        expected
+      );
+      assert_eq!(
+        document
+          .resolve_method(&expected, Some(scope))
+          .unwrap()
+          .id()
+          .to_string(),
+        expected
+      );
+      assert!(document.resolve_method(&fragment, Some(other_scope)).is_none());
+    }
+
+    // The DID of the query must still match.
+    assert!(document.resolve_method("did:example:5678#many-0", None).is_none());
+    assert!(document.resolve_method("#many-300", None).is_none());
+  }
+
+  #[test]
+  fn test_resolve_method_after_mutation() {
+    let controller: CoreDID = controller();
+    let mut document: CoreDocument = document();
+    assert!(document.resolve_method("#key-1", None).is_some());
+
+    // Insertion.
+    document
+      .insert_method(method(&controller, "#key-5"), MethodScope::assertion_method())
+      .unwrap();
+    assert!(document
+      .resolve_method("#key-5", Some(MethodScope::assertion_method()))
+      .is_some());
+
+    // Removal shifts the remaining methods.
+    let key_1: DIDUrl = controller.to_url().join("#key-1").unwrap();
+    assert!(document.remove_method(&key_1).is_some());
+    assert!(document.resolve_method("#key-1", None).is_none());
+    assert_eq!(
+      document.resolve_method("#key-2", None).unwrap().id().to_string(),
+      "did:example:1234#key-2"
+    );
+
+    // Relationships.
+    assert!(document
+      .attach_method_relationship("#key-2", MethodRelationship::CapabilityInvocation)
+      .unwrap());
+    assert!(document
+      .resolve_method("#key-2", Some(MethodScope::capability_invocation()))
+      .is_some());
+    assert!(document
+      .detach_method_relationship("#key-2", MethodRelationship::CapabilityInvocation)
+      .unwrap());
+    assert!(document
+      .resolve_method("#key-2", Some(MethodScope::capability_invocation()))
+      .is_none());
+
+    // Changing the id of a method through a mutable reference.
+    let renamed: DIDUrl = controller.to_url().join("#renamed").unwrap();
+    document
+      .resolve_method_mut("#key-2", None)
+      .unwrap()
+      .set_id(renamed)
+      .unwrap();
+    assert!(document.resolve_method("#key-2", None).is_none());
+    assert_eq!(
+      document.resolve_method("#renamed", None).unwrap().id().to_string(),
+      "did:example:1234#renamed"
+    );
+  }
+
+  #[test]
+  fn test_resolve_method_does_not_affect_equality_or_serialization() {
+    let fresh: CoreDocument = document();
+    let resolved: CoreDocument = document();
+    assert!(resolved.resolve_method("#key-1", None).is_some());
+
+    assert_eq!(fresh, resolved);
+    assert_eq!(fresh.to_json().unwrap(), resolved.to_json().unwrap());
+    assert_eq!(format!("{fresh:?}"), format!("{resolved:?}"));
+    assert_eq!(CoreDocument::from_json(&resolved.to_json().unwrap()).unwrap(), fresh);
+  }
+
   #[rustfmt::skip]
   #[test]
   fn test_methods_index() {
@@ -1805,4 +1932,15 @@ mod tests {
 
     assert_eq!(CoreDocument::expand_did_jwk(did_jwk).unwrap(), target_doc);
   }
+
+  #[test]
+  fn try_from_json_value_ref() {
+    let document: CoreDocument = document();
+    let value: serde_json::Value = document.to_json_value().unwrap();
+    assert_eq!(CoreDocument::try_from(&value).unwrap(), document);
+    assert_eq!(CoreDocument::from_json_value_ref(&value).unwrap(), document);
+
+    let invalid: serde_json::Value = serde_json::json!({ "id": "not a DID" });
+    assert!(CoreDocument::try_from(&invalid).is_err());
+  }
 }
@@ -0,0 +1,95 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Timestamp;
+use identity_did::CoreDID;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::document::CoreDocument;
+
+/// The [DID document metadata](https://www.w3.org/TR/did-core/#did-document-metadata) returned alongside a DID
+/// document by DID resolution.
+///
+/// Unlike method-specific metadata, this type only holds the properties standardized by the DID specification, so
+/// that resolution results of different DID methods can be handled uniformly.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct DocumentMetadata {
+  /// The time the DID was created.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub created: Option<Timestamp>,
+  /// The time of the last update of the resolved version of the DID document.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub updated: Option<Timestamp>,
+  /// Whether the DID has been deactivated.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub deactivated: Option<bool>,
+  /// The time of the update that superseded the resolved version of the DID document, if any.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub next_update: Option<Timestamp>,
+  /// The version of the resolved DID document.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub version_id: Option<String>,
+  /// The version of the DID document that superseded the resolved one, if any.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub next_version_id: Option<String>,
+  /// DIDs that are logically equivalent to the resolved DID, as asserted by the DID method.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub equivalent_id: Vec<CoreDID>,
+  /// The canonical DID of the resolved DID, as asserted by the DID method.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub canonical_id: Option<CoreDID>,
+}
+
+impl DocumentMetadata {
+  /// Creates an empty [`DocumentMetadata`].
+  pub fn new() -> Self {
+    Self::default()
+  }
+}
+
+/// A DID document carrying the [`DocumentMetadata`] obtained when resolving it.
+pub trait ProvidesDocumentMetadata {
+  /// Returns the standard DID document metadata of this document.
+  fn document_metadata(&self) -> DocumentMetadata;
+}
+
+impl ProvidesDocumentMetadata for CoreDocument {
+  /// A [`CoreDocument`] carries no metadata, hence an empty [`DocumentMetadata`] is returned.
+  fn document_metadata(&self) -> DocumentMetadata {
+    DocumentMetadata::new()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+  use identity_core::convert::ToJson;
+  use serde_json::json;
+
+  use super::*;
+
+  #[test]
+  fn serializes_standard_property_names() {
+    let mut metadata = DocumentMetadata::new();
+    metadata.version_id = Some("2".to_owned());
+    metadata.next_version_id = Some("3".to_owned());
+    metadata.canonical_id = Some("did:example:canonical".parse().unwrap());
+    metadata.equivalent_id = vec!["did:example:legacy".parse().unwrap()];
+
+    let json = metadata.to_json_value().unwrap();
+    assert_eq!(
+      json,
+      json!({
+        "versionId": "2",
+        "nextVersionId": "3",
+        "equivalentId": ["did:example:legacy"],
+        "canonicalId": "did:example:canonical"
+      })
+    );
+    assert_eq!(DocumentMetadata::from_json_value(json).unwrap(), metadata);
+    assert_eq!(DocumentMetadata::from_json("{}").unwrap(), DocumentMetadata::new());
+  }
+}
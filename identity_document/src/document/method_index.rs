@@ -0,0 +1,140 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Debug;
+use core::fmt::Formatter;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use identity_did::DIDUrl;
+use identity_verification::MethodRelationship;
+use identity_verification::MethodScope;
+
+use super::core_document::CoreDocumentData;
+use crate::utils::DIDUrlQuery;
+
+/// The verification relationships of a document in the order in which they are searched during method resolution.
+const RELATIONSHIPS: [MethodRelationship; 5] = [
+  MethodRelationship::Authentication,
+  MethodRelationship::AssertionMethod,
+  MethodRelationship::KeyAgreement,
+  MethodRelationship::CapabilityDelegation,
+  MethodRelationship::CapabilityInvocation,
+];
+
+/// The position of a verification method or method reference within a document.
+///
+/// The derived ordering matches the order in which a linear scan of the document would encounter each entry:
+/// verification relationships first (in the order of [`RELATIONSHIPS`]), then general purpose verification methods.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum MethodLocation {
+  /// Index into the set of the given verification relationship.
+  Relationship(MethodRelationship, usize),
+  /// Index into the `verificationMethod` set.
+  VerificationMethod(usize),
+}
+
+impl MethodLocation {
+  fn id<'a>(&self, data: &'a CoreDocumentData) -> Option<&'a DIDUrl> {
+    match *self {
+      Self::Relationship(relationship, index) => data.relationship(relationship).get(index).map(|method| method.id()),
+      Self::VerificationMethod(index) => data.verification_method.get(index).map(|method| method.id()),
+    }
+  }
+
+  fn in_scope(&self, scope: Option<MethodScope>) -> bool {
+    match (scope, self) {
+      (None, _) => true,
+      (Some(MethodScope::VerificationMethod), Self::VerificationMethod(_)) => true,
+      (Some(MethodScope::VerificationRelationship(scope)), Self::Relationship(relationship, _)) => {
+        scope == *relationship
+      }
+      _ => false,
+    }
+  }
+}
+
+/// A lazily built fragment → location index over the verification methods of a [`CoreDocument`](super::CoreDocument).
+///
+/// The index is not part of a document's state: it is ignored by equality and serialization, and is (re)built on the
+/// first lookup following a mutation that invalidated it.
+#[derive(Clone, Default)]
+pub(crate) struct MethodIndex(OnceLock<HashMap<String, Vec<MethodLocation>>>);
+
+impl MethodIndex {
+  /// Returns the location of the first entry matching `query` in `scope`, in the same order as a linear scan.
+  pub(crate) fn find(
+    &self,
+    data: &CoreDocumentData,
+    query: &DIDUrlQuery<'_>,
+    scope: Option<MethodScope>,
+  ) -> Option<MethodLocation> {
+    let fragment: &str = query.fragment()?;
+    self
+      .get_or_build(data)
+      .get(fragment)?
+      .iter()
+      .copied()
+      .filter(|location| location.in_scope(scope))
+      .find(|location| location.id(data).is_some_and(|id| query.matches(id)))
+  }
+
+  /// Records a newly appended entry, if the index has already been built.
+  pub(crate) fn insert(&mut self, fragment: Option<&str>, location: MethodLocation) {
+    let (Some(index), Some(fragment)) = (self.0.get_mut(), fragment) else {
+      return;
+    };
+    let locations: &mut Vec<MethodLocation> = index.entry(fragment.to_owned()).or_default();
+    let position: usize = locations.partition_point(|existing| *existing < location);
+    locations.insert(position, location);
+  }
+
+  /// Discards the index, so that it gets rebuilt on the next lookup.
+  pub(crate) fn invalidate(&mut self) {
+    self.0.take();
+  }
+
+  fn get_or_build(&self, data: &CoreDocumentData) -> &HashMap<String, Vec<MethodLocation>> {
+    self.0.get_or_init(|| Self::build(data))
+  }
+
+  fn build(data: &CoreDocumentData) -> HashMap<String, Vec<MethodLocation>> {
+    let relationship_entries = RELATIONSHIPS.into_iter().flat_map(|relationship| {
+      data
+        .relationship(relationship)
+        .iter()
+        .enumerate()
+        .map(move |(index, method_ref)| (method_ref.id(), MethodLocation::Relationship(relationship, index)))
+    });
+    let method_entries = data
+      .verification_method
+      .iter()
+      .enumerate()
+      .map(|(index, method)| (method.id(), MethodLocation::VerificationMethod(index)));
+
+    let mut index: HashMap<String, Vec<MethodLocation>> = HashMap::new();
+    for (id, location) in relationship_entries.chain(method_entries) {
+      if let Some(fragment) = id.fragment() {
+        index.entry(fragment.to_owned()).or_default().push(location);
+      }
+    }
+    index
+  }
+}
+
+impl PartialEq for MethodIndex {
+  fn eq(&self, _other: &Self) -> bool {
+    // The index is derived from the document's data and thus never distinguishes two documents.
+    true
+  }
+}
+
+impl Eq for MethodIndex {}
+
+impl Debug for MethodIndex {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    f.debug_struct("MethodIndex")
+      .field("built", &self.0.get().is_some())
+      .finish()
+  }
+}
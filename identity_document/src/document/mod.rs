@@ -6,7 +6,17 @@
 #![allow(clippy::module_inception)]
 
 pub use self::builder::DocumentBuilder;
+pub use self::capabilities::CapabilityReport;
+pub use self::capabilities::CapabilityRequirement;
 pub use self::core_document::CoreDocument;
+pub use self::document_metadata::DocumentMetadata;
+pub use self::document_metadata::ProvidesDocumentMetadata;
+pub use self::update_quorum::UpdateQuorum;
+pub use self::update_quorum::UpdateQuorumReport;
 
 mod builder;
+mod capabilities;
 mod core_document;
+mod document_metadata;
+mod method_index;
+mod update_quorum;
@@ -0,0 +1,298 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::convert::ToJson;
+use identity_did::DIDUrl;
+use identity_verification::jose::jws::DecodedJws;
+use identity_verification::jose::jws::JwsVerifier;
+use identity_verification::jwu;
+use identity_verification::MethodScope;
+
+use crate::document::CoreDocument;
+use crate::error::Error;
+use crate::error::Result;
+use crate::verifiable::AlgorithmPolicy;
+use crate::verifiable::JwsVerificationOptions;
+
+/// A policy requiring an update of a DID document to be signed by a quorum of the `capabilityInvocation` methods of
+/// the current version of the document, before the update is accepted.
+///
+/// DID methods whose documents are updated off-chain, e.g. `did:web` or peer DIDs, have no ledger enforcing who may
+/// update a document. Applications can use an [`UpdateQuorum`] to require that `threshold` distinct
+/// `capabilityInvocation` methods of the document they currently trust signed the
+/// [canonical serialization](UpdateQuorum::signing_input) of the updated document with a detached JWS, which gives
+/// them guarantees comparable to the ones of a document controlled by multiple controllers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UpdateQuorum {
+  threshold: usize,
+  algorithm_policy: Option<AlgorithmPolicy>,
+}
+
+impl UpdateQuorum {
+  /// Requires updates to be signed by at least `threshold` distinct `capabilityInvocation` methods.
+  pub fn new(threshold: usize) -> Self {
+    Self {
+      threshold,
+      algorithm_policy: None,
+    }
+  }
+
+  /// Additionally requires every signature counted towards the quorum to comply with `policy`.
+  pub fn algorithm_policy(mut self, policy: AlgorithmPolicy) -> Self {
+    self.algorithm_policy = Some(policy);
+    self
+  }
+
+  /// Returns the number of distinct methods that must sign an update.
+  pub fn threshold(&self) -> usize {
+    self.threshold
+  }
+
+  /// Returns the payload the signers of an update to `updated` must sign with a detached JWS.
+  ///
+  /// The document is serialized as JSON without insignificant whitespace and with the members of every object sorted
+  /// by key, making the output independent of how the document was built or deserialized.
+  pub fn signing_input(updated: &CoreDocument) -> Result<Vec<u8>> {
+    updated
+      .to_canonical_json_vec()
+      .map_err(|err| Error::InvalidDocument("failed to serialize the document", Some(err)))
+  }
+
+  /// Verifies that `updated` may replace `current`, i.e. that both share the same DID and that at least
+  /// [`threshold`](Self::threshold) distinct `capabilityInvocation` methods of `current` signed the
+  /// [signing input](Self::signing_input) of `updated` with one of the detached JWS in `signatures`.
+  ///
+  /// Signatures that cannot be verified with a `capabilityInvocation` method of `current` are not counted towards
+  /// the quorum, nor are additional signatures of a method that already signed.
+  ///
+  /// # Errors
+  /// Fails if the documents have different DIDs, if the threshold is zero or exceeds the number of
+  /// `capabilityInvocation` methods of `current`, or if the quorum is not reached.
+  pub fn verify<'jws, V>(
+    &self,
+    current: &CoreDocument,
+    updated: &CoreDocument,
+    signatures: impl IntoIterator<Item = &'jws str>,
+    signature_verifier: &V,
+  ) -> Result<UpdateQuorumReport>
+  where
+    V: JwsVerifier,
+  {
+    if current.id() != updated.id() {
+      return Err(Error::InvalidUpdateQuorum(
+        "the current and the updated document have different DIDs",
+      ));
+    }
+    let eligible: usize = current.methods(Some(MethodScope::capability_invocation())).len();
+    if self.threshold == 0 || self.threshold > eligible {
+      return Err(Error::InvalidUpdateQuorum(
+        "the threshold must be between one and the number of capabilityInvocation methods",
+      ));
+    }
+
+    let payload: String = jwu::encode_b64(Self::signing_input(updated)?);
+    let mut options: JwsVerificationOptions =
+      JwsVerificationOptions::new().method_scope(MethodScope::capability_invocation());
+    options.algorithm_policy = self.algorithm_policy.clone();
+
+    let mut report = UpdateQuorumReport::default();
+    for jws in signatures {
+      let Ok(decoded) = current.verify_jws(jws, Some(payload.as_bytes()), signature_verifier, &options) else {
+        report.rejected += 1;
+        continue;
+      };
+      let signer: Option<&DIDUrl> = signer_id(current, &decoded);
+      match signer {
+        Some(signer) if !report.signers.contains(signer) => report.signers.push(signer.clone()),
+        _ => report.rejected += 1,
+      }
+    }
+
+    if report.signers.len() < self.threshold {
+      return Err(Error::UpdateQuorumNotReached {
+        required: self.threshold,
+        signers: report.signers.len(),
+      });
+    }
+
+    Ok(report)
+  }
+}
+
+/// The outcome of a successful [`UpdateQuorum::verify`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct UpdateQuorumReport {
+  signers: Vec<DIDUrl>,
+  rejected: usize,
+}
+
+impl UpdateQuorumReport {
+  /// Returns the identifiers of the distinct methods that signed the update, in the order of their signatures.
+  pub fn signers(&self) -> &[DIDUrl] {
+    &self.signers
+  }
+
+  /// Returns the number of signatures that were not counted towards the quorum.
+  pub fn rejected(&self) -> usize {
+    self.rejected
+  }
+}
+
+/// Returns the identifier of the `capabilityInvocation` method of `document` that produced `decoded`.
+fn signer_id<'doc>(document: &'doc CoreDocument, decoded: &DecodedJws<'_>) -> Option<&'doc DIDUrl> {
+  let kid: &str = decoded.protected.kid()?;
+  document
+    .resolve_method(kid, Some(MethodScope::capability_invocation()))
+    .map(|method| method.id())
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+  use identity_did::CoreDID;
+  use identity_verification::jose::jwk::Jwk;
+  use identity_verification::jose::jws::CompactJwsEncoder;
+  use identity_verification::jose::jws::CompactJwsEncodingOptions;
+  use identity_verification::jose::jws::JwsAlgorithm;
+  use identity_verification::jose::jws::JwsHeader;
+  use identity_verification::jose::jws::JwsVerifierFn;
+  use identity_verification::jose::jws::SignatureVerificationError;
+  use identity_verification::jose::jws::SignatureVerificationErrorKind;
+  use identity_verification::jose::jws::VerificationInput;
+  use identity_verification::VerificationMethod;
+  use serde_json::json;
+
+  use super::*;
+
+  const KEYS: [&str; 3] = [
+    "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo",
+    "3p7bfXt9wbTTW2HC7OQ1Nz-DQ8hbeGdNrfx-FG-IK08",
+    "NzbLsXh8uDCcd-6MNwXF4W_7noWXFZAfHkxZsRGC9Xs",
+  ];
+
+  fn jwk(x: &str) -> Jwk {
+    Jwk::from_json_value(json!({ "kty": "OKP", "crv": "Ed25519", "x": x })).unwrap()
+  }
+
+  /// A document with three `capabilityInvocation` methods `#key-0` to `#key-2` and one `assertionMethod` `#other`.
+  fn document() -> CoreDocument {
+    let did: CoreDID = "did:example:1234".parse().unwrap();
+    let mut document = CoreDocument::builder(Default::default())
+      .id(did.clone())
+      .build()
+      .unwrap();
+    for (index, x) in KEYS.iter().enumerate() {
+      let method =
+        VerificationMethod::new_from_jwk(did.clone(), jwk(x), Some(format!("key-{index}").as_str())).unwrap();
+      document
+        .insert_method(method, MethodScope::capability_invocation())
+        .unwrap();
+    }
+    let other = VerificationMethod::new_from_jwk(did, jwk(KEYS[0]), Some("other")).unwrap();
+    document.insert_method(other, MethodScope::assertion_method()).unwrap();
+    document
+  }
+
+  /// A verifier accepting signatures that equal the thumbprint of the public key, standing in for real signatures.
+  fn verifier() -> JwsVerifierFn<impl Fn(VerificationInput, &Jwk) -> Result<(), SignatureVerificationError>> {
+    JwsVerifierFn::from(|input: VerificationInput, public_key: &Jwk| {
+      if input.decoded_signature.as_ref() == public_key.thumbprint_sha256_b64().as_bytes() {
+        Ok(())
+      } else {
+        Err(SignatureVerificationErrorKind::InvalidSignature.into())
+      }
+    })
+  }
+
+  fn sign(updated: &CoreDocument, fragment: &str, x: &str) -> String {
+    let mut header = JwsHeader::new();
+    header.set_alg(JwsAlgorithm::EdDSA);
+    header.set_kid(format!("{}#{fragment}", updated.id()));
+    let payload: Vec<u8> = UpdateQuorum::signing_input(updated).unwrap();
+    let encoder = CompactJwsEncoder::new_with_options(&payload, &header, CompactJwsEncodingOptions::Detached).unwrap();
+    encoder.into_jws(jwk(x).thumbprint_sha256_b64().as_bytes())
+  }
+
+  fn updated(current: &CoreDocument) -> CoreDocument {
+    let mut updated = current.clone();
+    updated
+      .also_known_as_mut()
+      .append("https://example.com/".parse().unwrap());
+    updated
+  }
+
+  #[test]
+  fn quorum_of_distinct_capability_invocation_methods_is_required() {
+    let current = document();
+    let updated = updated(&current);
+    let signatures: Vec<String> = vec![
+      sign(&updated, "key-0", KEYS[0]),
+      // Signatures of the same method are counted once.
+      sign(&updated, "key-0", KEYS[0]),
+      // Methods outside of the capabilityInvocation relationship are not counted.
+      sign(&updated, "other", KEYS[0]),
+      // Invalid signatures are not counted.
+      sign(&updated, "key-1", KEYS[2]),
+      sign(&updated, "key-2", KEYS[2]),
+    ];
+
+    let report = UpdateQuorum::new(2)
+      .verify(&current, &updated, signatures.iter().map(String::as_str), &verifier())
+      .unwrap();
+    assert_eq!(report.signers().len(), 2);
+    assert_eq!(report.signers()[0].fragment(), Some("key-0"));
+    assert_eq!(report.signers()[1].fragment(), Some("key-2"));
+    assert_eq!(report.rejected(), 3);
+
+    let err = UpdateQuorum::new(3)
+      .verify(&current, &updated, signatures.iter().map(String::as_str), &verifier())
+      .unwrap_err();
+    assert!(matches!(
+      err,
+      Error::UpdateQuorumNotReached {
+        required: 3,
+        signers: 2
+      }
+    ));
+  }
+
+  #[test]
+  fn signatures_over_another_document_are_rejected() {
+    let current = document();
+    let updated = updated(&current);
+    let signatures: Vec<String> = vec![sign(&current, "key-0", KEYS[0]), sign(&current, "key-1", KEYS[1])];
+
+    let err = UpdateQuorum::new(1)
+      .verify(&current, &updated, signatures.iter().map(String::as_str), &verifier())
+      .unwrap_err();
+    assert!(matches!(
+      err,
+      Error::UpdateQuorumNotReached {
+        required: 1,
+        signers: 0
+      }
+    ));
+  }
+
+  #[test]
+  fn invalid_thresholds_and_dids_are_rejected() {
+    let current = document();
+    let updated = updated(&current);
+
+    for threshold in [0, 4] {
+      let err = UpdateQuorum::new(threshold)
+        .verify(&current, &updated, [], &verifier())
+        .unwrap_err();
+      assert!(matches!(err, Error::InvalidUpdateQuorum(_)));
+    }
+
+    let other: CoreDocument = CoreDocument::builder(Default::default())
+      .id("did:example:5678".parse().unwrap())
+      .build()
+      .unwrap();
+    let err = UpdateQuorum::new(1)
+      .verify(&current, &other, [], &verifier())
+      .unwrap_err();
+    assert!(matches!(err, Error::InvalidUpdateQuorum(_)));
+  }
+}
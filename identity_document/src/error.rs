@@ -39,4 +39,49 @@ pub enum Error {
   /// Caused by a failure to verify a JSON Web Signature.
   #[error("jws verification failed")]
   JwsVerificationError(#[source] identity_verification::jose::error::Error),
+  /// Caused by a JWS whose algorithm or key does not comply with an
+  /// [`AlgorithmPolicy`](crate::verifiable::AlgorithmPolicy).
+  #[error("algorithm policy violation: {0}")]
+  AlgorithmPolicyViolation(String),
+  /// Caused by a `jku` header or a key from the referenced JWK Set that does not comply with a
+  /// [`JkuPolicy`](crate::verifiable::JkuPolicy).
+  #[error("jku policy violation: {0}")]
+  JkuPolicyViolation(String),
+  /// Caused by a failure to fetch or parse the JWK Set referenced by a `jku` header.
+  #[error("failed to fetch the JWK Set")]
+  JwkSetFetchError(#[source] Box<dyn std::error::Error + Send + Sync + 'static>),
+  /// Caused by an [`UpdateQuorum`](crate::document::UpdateQuorum) that cannot be applied to the given documents.
+  #[error("invalid update quorum: {0}")]
+  InvalidUpdateQuorum(&'static str),
+  /// Caused by a document update signed by fewer methods than an
+  /// [`UpdateQuorum`](crate::document::UpdateQuorum) requires.
+  #[error("update quorum not reached: {signers} of {required} required methods signed the update")]
+  UpdateQuorumNotReached {
+    /// The number of methods that must sign the update.
+    required: usize,
+    /// The number of distinct methods that signed the update.
+    signers: usize,
+  },
+  /// Caused by a proof of possession that does not answer the expected
+  /// [`PopChallenge`](crate::verifiable::PopChallenge).
+  #[error("invalid proof of possession: {0}")]
+  InvalidProofOfPossession(&'static str),
 }
+
+identity_core::impl_error_code!(Error {
+  MethodNotFound => 1400,
+  InvalidDocument => 1401,
+  InvalidService => 1402,
+  MissingIdFragment => 1403,
+  MethodInsertionError => 1404,
+  InvalidMethodEmbedded => 1405,
+  InvalidServiceInsertion => 1406,
+  InvalidKeyMaterial => 1407,
+  JwsVerificationError => 1408,
+  AlgorithmPolicyViolation => 1409,
+  JkuPolicyViolation => 1410,
+  JwkSetFetchError => 1411,
+  InvalidUpdateQuorum => 1412,
+  UpdateQuorumNotReached => 1413,
+  InvalidProofOfPossession => 1414,
+});
@@ -47,7 +47,7 @@ impl DIDUrlQuery<'_> {
   }
 
   /// Extract the query fragment if it exists.
-  fn fragment(&self) -> Option<&str> {
+  pub(crate) fn fragment(&self) -> Option<&str> {
     let query: &str = self.0.as_ref();
     let fragment_maybe: Option<&str> = if query.starts_with(CoreDID::SCHEME) {
       // Extract the fragment from a full DID-Url-like string.
@@ -0,0 +1,277 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use identity_verification::jose::jwk::Jwk;
+use identity_verification::jose::jwk::JwkParams;
+use identity_verification::jose::jwk::JwkType;
+use identity_verification::jose::jws::JwsAlgorithm;
+use identity_verification::jwu;
+
+use crate::error::Error;
+use crate::error::Result;
+
+/// Whether an [`AlgorithmPolicy`] requires post-quantum signatures.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PostQuantumRequirement {
+  /// Any algorithm may be used.
+  #[default]
+  None,
+  /// Only post-quantum or hybrid (post-quantum and traditional) algorithms may be used.
+  PostQuantum,
+  /// Only hybrid algorithms may be used.
+  Hybrid,
+}
+
+/// A policy restricting the algorithms and keys accepted when verifying a JWS.
+///
+/// Setting an [`AlgorithmPolicy`] in [`JwsVerificationOptions`](crate::verifiable::JwsVerificationOptions) enforces
+/// it on every verification performed with those options, including the ones performed by the credential and
+/// presentation validators.
+///
+/// By default, every algorithm except `none` is accepted.
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlgorithmPolicy {
+  /// If set, only these algorithms are accepted.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub allowed: Option<Vec<JwsAlgorithm>>,
+  /// Algorithms that are never accepted, even when listed in `allowed`.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub denied: Vec<JwsAlgorithm>,
+  /// The minimum size in bits of keys of the given type. For elliptic curve and octet key pair keys, the size is
+  /// the one of the curve.
+  #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+  pub min_key_sizes: BTreeMap<JwkType, usize>,
+  /// Whether the `none` algorithm is accepted.
+  #[serde(default)]
+  pub allow_none: bool,
+  /// Whether post-quantum signatures are required.
+  #[serde(default)]
+  pub post_quantum: PostQuantumRequirement,
+}
+
+impl AlgorithmPolicy {
+  /// Creates a new [`AlgorithmPolicy`] accepting every algorithm except `none`.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Only accept the algorithms in `algorithms`.
+  pub fn allow(mut self, algorithms: impl IntoIterator<Item = JwsAlgorithm>) -> Self {
+    self.allowed.get_or_insert_with(Vec::new).extend(algorithms);
+    self
+  }
+
+  /// Never accept the algorithms in `algorithms`.
+  pub fn deny(mut self, algorithms: impl IntoIterator<Item = JwsAlgorithm>) -> Self {
+    self.denied.extend(algorithms);
+    self
+  }
+
+  /// Require keys of type `kty` to be at least `bits` long.
+  pub fn min_key_size(mut self, kty: JwkType, bits: usize) -> Self {
+    self.min_key_sizes.insert(kty, bits);
+    self
+  }
+
+  /// Sets whether the `none` algorithm is accepted.
+  pub fn allow_none(mut self, value: bool) -> Self {
+    self.allow_none = value;
+    self
+  }
+
+  /// Sets whether post-quantum signatures are required.
+  pub fn post_quantum(mut self, value: PostQuantumRequirement) -> Self {
+    self.post_quantum = value;
+    self
+  }
+
+  /// Checks that a JWS signed with `alg` and verified with the keys in `public_keys` complies with this policy.
+  ///
+  /// `public_keys` contains one key for traditional and post-quantum signatures and two keys for hybrid ones.
+  pub fn check<'k>(&self, alg: &JwsAlgorithm, public_keys: impl IntoIterator<Item = &'k Jwk>) -> Result<()> {
+    if *alg == JwsAlgorithm::NONE && !self.allow_none {
+      return Err(violation(format!("algorithm `{alg}` is not allowed")));
+    }
+    if self.denied.contains(alg) {
+      return Err(violation(format!("algorithm `{alg}` is denied")));
+    }
+    if let Some(allowed) = &self.allowed {
+      if !allowed.contains(alg) {
+        return Err(violation(format!(
+          "algorithm `{alg}` is not in the list of allowed algorithms"
+        )));
+      }
+    }
+
+    match self.post_quantum {
+      PostQuantumRequirement::PostQuantum if !is_post_quantum(alg) && !is_hybrid(alg) => {
+        return Err(violation(format!("algorithm `{alg}` is not post-quantum")));
+      }
+      PostQuantumRequirement::Hybrid if !is_hybrid(alg) => {
+        return Err(violation(format!("algorithm `{alg}` is not hybrid")));
+      }
+      _ => (),
+    }
+
+    for public_key in public_keys {
+      let kty = public_key.kty();
+      let (Some(min_size), Some(size)) = (self.min_key_sizes.get(&kty), key_size(public_key)) else {
+        continue;
+      };
+      if size < *min_size {
+        return Err(violation(format!(
+          "{} key of {size} bits is smaller than the required {min_size} bits",
+          kty.name()
+        )));
+      }
+    }
+
+    Ok(())
+  }
+}
+
+fn violation(message: String) -> Error {
+  Error::AlgorithmPolicyViolation(message)
+}
+
+fn is_post_quantum(alg: &JwsAlgorithm) -> bool {
+  matches!(
+    alg,
+    JwsAlgorithm::ML_DSA_44
+      | JwsAlgorithm::ML_DSA_65
+      | JwsAlgorithm::ML_DSA_87
+      | JwsAlgorithm::SLH_DSA_SHA2_128s
+      | JwsAlgorithm::SLH_DSA_SHAKE_128s
+      | JwsAlgorithm::SLH_DSA_SHA2_128f
+      | JwsAlgorithm::SLH_DSA_SHAKE_128f
+      | JwsAlgorithm::SLH_DSA_SHA2_192s
+      | JwsAlgorithm::SLH_DSA_SHAKE_192s
+      | JwsAlgorithm::SLH_DSA_SHA2_192f
+      | JwsAlgorithm::SLH_DSA_SHAKE_192f
+      | JwsAlgorithm::SLH_DSA_SHA2_256s
+      | JwsAlgorithm::SLH_DSA_SHAKE_256s
+      | JwsAlgorithm::SLH_DSA_SHA2_256f
+      | JwsAlgorithm::SLH_DSA_SHAKE_256f
+      | JwsAlgorithm::FALCON512
+      | JwsAlgorithm::FALCON1024
+  )
+}
+
+fn is_hybrid(alg: &JwsAlgorithm) -> bool {
+  matches!(alg, JwsAlgorithm::IdMldsa44Ed25519 | JwsAlgorithm::IdMldsa65Ed25519)
+}
+
+/// Returns the size in bits of `jwk`, if it can be determined.
+fn key_size(jwk: &Jwk) -> Option<usize> {
+  let decoded_bits = |value: &str| {
+    jwu::decode_b64(value).ok().map(|bytes| {
+      let leading_zeros = bytes.iter().take_while(|byte| **byte == 0).count();
+      (bytes.len() - leading_zeros) * 8
+    })
+  };
+
+  match jwk.params() {
+    JwkParams::Rsa(params) => decoded_bits(&params.n),
+    JwkParams::Oct(params) => decoded_bits(&params.k),
+    JwkParams::Ec(params) => match params.crv.as_str() {
+      "P-256" | "secp256k1" => Some(256),
+      "P-384" => Some(384),
+      "P-521" => Some(521),
+      _ => None,
+    },
+    JwkParams::Okp(params) => match params.crv.as_str() {
+      "Ed25519" | "X25519" => Some(256),
+      "Ed448" | "X448" => Some(448),
+      _ => None,
+    },
+    JwkParams::Akp(_) => None,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+  use serde_json::json;
+
+  use super::*;
+
+  const NO_KEYS: [&Jwk; 0] = [];
+
+  fn ed25519_jwk() -> Jwk {
+    Jwk::from_json_value(json!({
+      "kty": "OKP",
+      "crv": "Ed25519",
+      "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"
+    }))
+    .unwrap()
+  }
+
+  #[test]
+  fn default_policy_only_forbids_none() {
+    let policy = AlgorithmPolicy::new();
+    assert!(policy.check(&JwsAlgorithm::EdDSA, [&ed25519_jwk()]).is_ok());
+    assert!(policy.check(&JwsAlgorithm::NONE, NO_KEYS).is_err());
+    assert!(policy
+      .clone()
+      .allow_none(true)
+      .check(&JwsAlgorithm::NONE, NO_KEYS)
+      .is_ok());
+  }
+
+  #[test]
+  fn allow_and_deny_lists_are_enforced() {
+    let policy = AlgorithmPolicy::new()
+      .allow([JwsAlgorithm::EdDSA, JwsAlgorithm::ES256])
+      .deny([JwsAlgorithm::ES256]);
+    assert!(policy.check(&JwsAlgorithm::EdDSA, NO_KEYS).is_ok());
+    assert!(policy.check(&JwsAlgorithm::ES256, NO_KEYS).is_err());
+    assert!(policy.check(&JwsAlgorithm::ES384, NO_KEYS).is_err());
+  }
+
+  #[test]
+  fn key_sizes_and_post_quantum_requirements_are_enforced() {
+    let jwk = ed25519_jwk();
+    assert!(AlgorithmPolicy::new()
+      .min_key_size(JwkType::Okp, 256)
+      .check(&JwsAlgorithm::EdDSA, [&jwk])
+      .is_ok());
+    assert!(AlgorithmPolicy::new()
+      .min_key_size(JwkType::Okp, 448)
+      .check(&JwsAlgorithm::EdDSA, [&jwk])
+      .is_err());
+
+    let post_quantum = AlgorithmPolicy::new().post_quantum(PostQuantumRequirement::PostQuantum);
+    assert!(post_quantum.check(&JwsAlgorithm::EdDSA, NO_KEYS).is_err());
+    assert!(post_quantum.check(&JwsAlgorithm::ML_DSA_44, NO_KEYS).is_ok());
+    assert!(post_quantum.check(&JwsAlgorithm::IdMldsa44Ed25519, NO_KEYS).is_ok());
+
+    let hybrid = AlgorithmPolicy::new().post_quantum(PostQuantumRequirement::Hybrid);
+    assert!(hybrid.check(&JwsAlgorithm::ML_DSA_44, NO_KEYS).is_err());
+    assert!(hybrid.check(&JwsAlgorithm::IdMldsa44Ed25519, NO_KEYS).is_ok());
+  }
+
+  #[test]
+  fn policy_round_trips_through_json() {
+    let policy = AlgorithmPolicy::new()
+      .allow([JwsAlgorithm::EdDSA])
+      .min_key_size(JwkType::Rsa, 2048)
+      .post_quantum(PostQuantumRequirement::Hybrid);
+    let json = serde_json::to_value(&policy).unwrap();
+    assert_eq!(
+      json,
+      json!({
+        "allowed": ["EdDSA"],
+        "minKeySizes": { "RSA": 2048 },
+        "allowNone": false,
+        "postQuantum": "hybrid"
+      })
+    );
+    assert_eq!(serde_json::from_value::<AlgorithmPolicy>(json).unwrap(), policy);
+  }
+}
@@ -0,0 +1,218 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Url;
+use identity_core::convert::FromJson;
+use identity_core::http::HttpClient;
+use identity_core::http::HttpRequest;
+use identity_verification::jose::jwk::Jwk;
+use identity_verification::jose::jwk::JwkSet;
+use identity_verification::jose::jws::DecodedJws;
+use identity_verification::jose::jws::Decoder;
+use identity_verification::jose::jws::JwsVerifier;
+
+use super::JkuPolicy;
+use super::JwsVerificationOptions;
+use crate::error::Error;
+use crate::error::Result;
+
+/// The maximum size of a JWK Set fetched by this library.
+const MAX_JWK_SET_SIZE: usize = 1_048_576;
+
+impl JkuPolicy {
+  /// Fetches the JWK Set published at `jku` with `client`, after checking that this policy allows it.
+  ///
+  /// The maximum size of the JWK Set that can be retrieved with this method is 1 MiB.
+  pub async fn fetch_jwk_set<C>(&self, jku: &Url, client: &C) -> Result<JwkSet>
+  where
+    C: HttpClient + ?Sized,
+  {
+    self.check_url(jku)?;
+
+    let json: Vec<u8> = client
+      .send(HttpRequest::get(jku.clone()).max_response_size(MAX_JWK_SET_SIZE))
+      .await
+      .and_then(|response| response.into_success_body())
+      .map_err(|err| Error::JwkSetFetchError(Box::new(err)))?;
+
+    JwkSet::from_json_slice(&json).map_err(|err| Error::JwkSetFetchError(Box::new(err)))
+  }
+}
+
+/// Decodes and verifies the provided JWS with the key that the JWK Set referenced by its `jku` header identifies by
+/// the `kid` header.
+///
+/// The `jku` and `kid` values must be set in the protected header. The JWK Set is fetched with `client` according to
+/// `policy`. The nonce and the
+/// [`AlgorithmPolicy`](crate::verifiable::AlgorithmPolicy) in `options` are enforced as in
+/// [`CoreDocument::verify_jws`](crate::document::CoreDocument::verify_jws()), while `method_scope` and `method_id`
+/// do not apply to keys that are not taken from a DID document.
+pub async fn verify_jws_with_jku<'jws, T, C>(
+  jws: &'jws str,
+  detached_payload: Option<&'jws [u8]>,
+  signature_verifier: &T,
+  policy: &JkuPolicy,
+  options: &JwsVerificationOptions,
+  client: &C,
+) -> Result<DecodedJws<'jws>>
+where
+  T: JwsVerifier,
+  C: HttpClient + ?Sized,
+{
+  let validation_item = Decoder::new()
+    .decode_compact_serialization(jws.as_bytes(), detached_payload)
+    .map_err(Error::JwsVerificationError)?;
+
+  if validation_item.nonce() != options.nonce.as_deref() {
+    return Err(Error::JwsVerificationError(
+      identity_verification::jose::error::Error::InvalidParam("invalid nonce value"),
+    ));
+  }
+
+  let protected_header = validation_item.protected_header();
+  let jku: &Url = protected_header
+    .and_then(|header| header.jku())
+    .ok_or(Error::JwsVerificationError(
+      identity_verification::jose::error::Error::InvalidParam("missing jku value in the protected header"),
+    ))?;
+  let kid: &str = protected_header
+    .and_then(|header| header.kid())
+    .ok_or(Error::JwsVerificationError(
+      identity_verification::jose::error::Error::InvalidParam("missing kid value in the protected header"),
+    ))?;
+
+  let jwk_set: JwkSet = policy.fetch_jwk_set(jku, client).await?;
+  let public_key: &Jwk = policy.select_key(&jwk_set, kid)?;
+
+  if let Some(algorithm_policy) = &options.algorithm_policy {
+    let alg = validation_item.alg().ok_or(Error::JwsVerificationError(
+      identity_verification::jose::error::Error::ProtectedHeaderWithoutAlg,
+    ))?;
+    algorithm_policy.check(&alg, [public_key])?;
+  }
+
+  validation_item
+    .verify(signature_verifier, public_key)
+    .map_err(Error::JwsVerificationError)
+}
+
+#[cfg(test)]
+mod tests {
+  use async_trait::async_trait;
+  use identity_core::http::HttpError;
+  use identity_core::http::HttpResponse;
+  use identity_verification::jose::jws::JwsVerifierFn;
+  use identity_verification::jose::jws::SignatureVerificationError;
+  use identity_verification::jose::jws::SignatureVerificationErrorKind;
+  use identity_verification::jose::jws::VerificationInput;
+  use identity_verification::jwu;
+  use serde_json::json;
+
+  use super::*;
+
+  const JKU: &str = "https://issuer.example/.well-known/jwks.json";
+  const SIGNATURE: &[u8] = b"signature";
+
+  /// Serves a JWK Set at [`JKU`] and fails every other request.
+  struct JwkSetServer;
+
+  #[async_trait]
+  impl HttpClient for JwkSetServer {
+    async fn send(&self, request: HttpRequest) -> std::result::Result<HttpResponse, HttpError> {
+      if request.url.as_str() != JKU {
+        return Ok(HttpResponse::new(404, Vec::new(), Vec::new()));
+      }
+      let jwk_set = json!({
+        "keys": [{
+          "kty": "OKP",
+          "crv": "Ed25519",
+          "kid": "signing-key",
+          "use": "sig",
+          "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"
+        }]
+      });
+      Ok(HttpResponse::new(
+        200,
+        Vec::new(),
+        serde_json::to_vec(&jwk_set).unwrap(),
+      ))
+    }
+  }
+
+  fn jws(jku: &str) -> String {
+    let header = json!({ "alg": "EdDSA", "jku": jku, "kid": "signing-key" });
+    format!(
+      "{}.{}.{}",
+      jwu::encode_b64(serde_json::to_vec(&header).unwrap()),
+      jwu::encode_b64("payload"),
+      jwu::encode_b64(SIGNATURE)
+    )
+  }
+
+  fn verify(input: VerificationInput, public_key: &Jwk) -> std::result::Result<(), SignatureVerificationError> {
+    if public_key.kid() == Some("signing-key") && input.decoded_signature.as_ref() == SIGNATURE {
+      Ok(())
+    } else {
+      Err(SignatureVerificationError::new(
+        SignatureVerificationErrorKind::InvalidSignature,
+      ))
+    }
+  }
+
+  #[tokio::test]
+  async fn jws_is_verified_with_key_from_allowed_jwk_set() {
+    let verifier = JwsVerifierFn::from(verify);
+    let policy = JkuPolicy::new().allow_url(Url::parse(JKU).unwrap());
+    let jws = jws(JKU);
+
+    let decoded: DecodedJws<'_> = verify_jws_with_jku(
+      &jws,
+      None,
+      &verifier,
+      &policy,
+      &JwsVerificationOptions::new(),
+      &JwkSetServer,
+    )
+    .await
+    .unwrap();
+    assert_eq!(decoded.claims.as_ref(), b"payload");
+  }
+
+  #[tokio::test]
+  async fn jws_with_disallowed_jku_is_rejected() {
+    let verifier = JwsVerifierFn::from(verify);
+    let policy = JkuPolicy::new().allow_url(Url::parse(JKU).unwrap());
+    let jws = jws("https://attacker.example/.well-known/jwks.json");
+
+    let result = verify_jws_with_jku(
+      &jws,
+      None,
+      &verifier,
+      &policy,
+      &JwsVerificationOptions::new(),
+      &JwkSetServer,
+    )
+    .await;
+    assert!(matches!(result, Err(Error::JkuPolicyViolation(_))));
+  }
+
+  #[tokio::test]
+  async fn jws_with_unpinned_key_is_rejected() {
+    let verifier = JwsVerifierFn::from(verify);
+    let policy = JkuPolicy::new()
+      .allow_url(Url::parse(JKU).unwrap())
+      .pin_thumbprint("NzbLsXh8uDCcd-6MNwXF4W_7noWXFZAfHkxZsRGC9Xs");
+    let jws = jws(JKU);
+
+    let result = verify_jws_with_jku(
+      &jws,
+      None,
+      &verifier,
+      &policy,
+      &JwsVerificationOptions::new(),
+      &JwkSetServer,
+    )
+    .await;
+    assert!(matches!(result, Err(Error::JkuPolicyViolation(_))));
+  }
+}
@@ -0,0 +1,205 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Url;
+use identity_verification::jose::jwk::Jwk;
+use identity_verification::jose::jwk::JwkSet;
+use identity_verification::jose::jwk::JwkUse;
+use identity_verification::jwu;
+
+use crate::error::Error;
+use crate::error::Result;
+
+/// A policy governing the use of the `jku` (JWK Set URL) header parameter when verifying a JWS.
+///
+/// A [`JkuPolicy`] allows verification keys to be taken from a JWK Set published at a URL, as is common in
+/// federations that distribute their keys via JWKS rather than DID documents. It is enforced by `verify_jws_with_jku`,
+/// available with the `jku-fetch` feature; [`CoreDocument::verify_jws`](crate::document::CoreDocument::verify_jws())
+/// only uses keys from the DID document and ignores the `jku` header.
+///
+/// The policy is strict by default: a JWK Set is only fetched from a URL that is listed in `allowed_urls`, and
+/// only if that URL uses `https`. Keys can additionally be pinned by their
+/// [RFC 7638](https://www.rfc-editor.org/rfc/rfc7638) SHA-256 thumbprint.
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JkuPolicy {
+  /// The JWK Set URLs that may be fetched. URLs are compared exactly.
+  #[serde(default)]
+  pub allowed_urls: Vec<Url>,
+  /// If non-empty, only keys whose base64url-encoded SHA-256 thumbprint is in this list are accepted.
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub pinned_thumbprints: Vec<String>,
+}
+
+impl JkuPolicy {
+  /// Creates a new [`JkuPolicy`] that does not allow any JWK Set URL.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Allow JWK Sets to be fetched from `url`.
+  pub fn allow_url(mut self, url: Url) -> Self {
+    self.allowed_urls.push(url);
+    self
+  }
+
+  /// Only accept the key whose base64url-encoded SHA-256 thumbprint is `thumbprint`, in addition to the ones
+  /// already pinned.
+  pub fn pin_thumbprint(mut self, thumbprint: impl Into<String>) -> Self {
+    self.pinned_thumbprints.push(thumbprint.into());
+    self
+  }
+
+  /// Checks that a JWK Set may be fetched from `jku`.
+  pub fn check_url(&self, jku: &Url) -> Result<()> {
+    if jku.scheme() != "https" {
+      return Err(violation(format!("JWK Set URL `{jku}` does not use `https`")));
+    }
+    if !self.allowed_urls.contains(jku) {
+      return Err(violation(format!("JWK Set URL `{jku}` is not allowed")));
+    }
+
+    Ok(())
+  }
+
+  /// Selects the key identified by `kid` in `jwk_set` and checks that it complies with this policy.
+  ///
+  /// The key must be unique within the set, must be a public signing key and, if any thumbprints are pinned, must
+  /// match one of them.
+  pub fn select_key<'set>(&self, jwk_set: &'set JwkSet, kid: &str) -> Result<&'set Jwk> {
+    let public_key: &Jwk = match jwk_set.get(kid).as_slice() {
+      [public_key] => *public_key,
+      [] => return Err(Error::MethodNotFound),
+      _ => return Err(violation(format!("JWK Set contains multiple keys with kid `{kid}`"))),
+    };
+
+    if public_key.is_private() || public_key.try_oct_params().is_ok() {
+      return Err(violation(format!("key `{kid}` is not a public key")));
+    }
+    if public_key.use_().is_some_and(|use_| use_ != JwkUse::Signature) {
+      return Err(violation(format!("key `{kid}` is not a signing key")));
+    }
+    if !self.pinned_thumbprints.is_empty() {
+      let thumbprint: String = public_key.thumbprint_sha256_b64();
+      if !self
+        .pinned_thumbprints
+        .iter()
+        .any(|pinned| jwu::ct_eq(pinned, &thumbprint))
+      {
+        return Err(violation(format!("key `{kid}` does not match any pinned thumbprint")));
+      }
+    }
+
+    Ok(public_key)
+  }
+}
+
+fn violation(message: String) -> Error {
+  Error::JkuPolicyViolation(message)
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+  use serde_json::json;
+
+  use super::*;
+
+  fn jwk_set() -> JwkSet {
+    JwkSet::from_json_value(json!({
+      "keys": [
+        {
+          "kty": "OKP",
+          "crv": "Ed25519",
+          "kid": "signing-key",
+          "use": "sig",
+          "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"
+        },
+        {
+          "kty": "OKP",
+          "crv": "X25519",
+          "kid": "encryption-key",
+          "use": "enc",
+          "x": "3p7bfXt9wbTTW2HC7OQ1Nz-DQ8hbeGdNrfx-FG-IK08"
+        },
+        {
+          "kty": "OKP",
+          "crv": "Ed25519",
+          "kid": "duplicate",
+          "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"
+        },
+        {
+          "kty": "OKP",
+          "crv": "Ed25519",
+          "kid": "duplicate",
+          "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"
+        }
+      ]
+    }))
+    .unwrap()
+  }
+
+  #[test]
+  fn only_allowed_https_urls_are_accepted() {
+    let allowed = Url::parse("https://issuer.example/.well-known/jwks.json").unwrap();
+    let policy = JkuPolicy::new().allow_url(allowed.clone());
+
+    assert!(policy.check_url(&allowed).is_ok());
+    assert!(policy
+      .check_url(&Url::parse("https://attacker.example/.well-known/jwks.json").unwrap())
+      .is_err());
+
+    let plain_http = Url::parse("http://issuer.example/.well-known/jwks.json").unwrap();
+    assert!(JkuPolicy::new()
+      .allow_url(plain_http.clone())
+      .check_url(&plain_http)
+      .is_err());
+    assert!(JkuPolicy::new().check_url(&allowed).is_err());
+  }
+
+  #[test]
+  fn keys_are_selected_by_kid_and_use() {
+    let jwk_set = jwk_set();
+    let policy = JkuPolicy::new();
+
+    assert_eq!(
+      policy.select_key(&jwk_set, "signing-key").unwrap().kid(),
+      Some("signing-key")
+    );
+    assert!(matches!(
+      policy.select_key(&jwk_set, "unknown"),
+      Err(Error::MethodNotFound)
+    ));
+    assert!(policy.select_key(&jwk_set, "encryption-key").is_err());
+    assert!(policy.select_key(&jwk_set, "duplicate").is_err());
+  }
+
+  #[test]
+  fn pinned_thumbprints_are_enforced() {
+    let jwk_set = jwk_set();
+    let thumbprint: String = jwk_set.get("signing-key")[0].thumbprint_sha256_b64();
+
+    let pinned = JkuPolicy::new().pin_thumbprint(thumbprint);
+    assert!(pinned.select_key(&jwk_set, "signing-key").is_ok());
+
+    let pinned_elsewhere = JkuPolicy::new().pin_thumbprint("NzbLsXh8uDCcd-6MNwXF4W_7noWXFZAfHkxZsRGC9Xs");
+    assert!(pinned_elsewhere.select_key(&jwk_set, "signing-key").is_err());
+  }
+
+  #[test]
+  fn policy_round_trips_through_json() {
+    let policy = JkuPolicy::new()
+      .allow_url(Url::parse("https://issuer.example/jwks.json").unwrap())
+      .pin_thumbprint("NzbLsXh8uDCcd-6MNwXF4W_7noWXFZAfHkxZsRGC9Xs");
+    let json = serde_json::to_value(&policy).unwrap();
+    assert_eq!(
+      json,
+      json!({
+        "allowedUrls": ["https://issuer.example/jwks.json"],
+        "pinnedThumbprints": ["NzbLsXh8uDCcd-6MNwXF4W_7noWXFZAfHkxZsRGC9Xs"]
+      })
+    );
+    assert_eq!(serde_json::from_value::<JkuPolicy>(json).unwrap(), policy);
+  }
+}
@@ -4,6 +4,8 @@
 use identity_did::DIDUrl;
 use identity_verification::MethodScope;
 
+use super::AlgorithmPolicy;
+
 /// Holds additional options for verifying a JWS with
 /// [`CoreDocument::verify_jws`](crate::document::CoreDocument::verify_jws()).
 #[non_exhaustive]
@@ -19,6 +21,9 @@ pub struct JwsVerificationOptions {
   /// The DID URl of the method, whose JWK should be used to verify the JWS.
   /// If unset, the `kid` of the JWS is used as the DID Url.
   pub method_id: Option<DIDUrl>,
+  /// The policy the algorithm of the JWS and the verification key must comply with.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub algorithm_policy: Option<AlgorithmPolicy>,
 }
 
 impl JwsVerificationOptions {
@@ -44,4 +49,10 @@ impl JwsVerificationOptions {
     self.method_id = Some(value);
     self
   }
+
+  /// Set the policy the algorithm of the JWS and the verification key must comply with.
+  pub fn algorithm_policy(mut self, value: AlgorithmPolicy) -> Self {
+    self.algorithm_policy = Some(value);
+    self
+  }
 }
@@ -3,8 +3,21 @@
 
 //! Additional functionality for DID assisted digital signatures.
 
+pub use self::algorithm_policy::AlgorithmPolicy;
+pub use self::algorithm_policy::PostQuantumRequirement;
+#[cfg(feature = "jku-fetch")]
+pub use self::jku::verify_jws_with_jku;
+pub use self::jku_policy::JkuPolicy;
 pub use self::jwp_verification_options::JwpVerificationOptions;
 pub use self::jws_verification_options::JwsVerificationOptions;
+pub use self::proof_of_possession::PopChallenge;
+pub use self::proof_of_possession::PopClaims;
+pub use self::proof_of_possession::PopVerificationOptions;
 
+mod algorithm_policy;
+#[cfg(feature = "jku-fetch")]
+mod jku;
+mod jku_policy;
 mod jwp_verification_options;
 mod jws_verification_options;
+mod proof_of_possession;
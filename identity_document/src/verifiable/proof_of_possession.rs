@@ -0,0 +1,154 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Clock;
+use identity_core::common::SharedClock;
+use identity_core::common::Timestamp;
+use identity_core::convert::FromJson;
+use identity_did::CoreDID;
+use identity_verification::jose::jws::DecodedJws;
+use identity_verification::jose::jws::JwsVerifier;
+use identity_verification::jwu;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::JwsVerificationOptions;
+use crate::document::CoreDocument;
+use crate::error::Error;
+use crate::error::Result;
+
+/// A challenge a verifier sends to the controller of a DID to prove possession of the key of one of its verification
+/// methods, e.g. when onboarding a device or registering a DID.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct PopChallenge {
+  /// The verifier the proof is intended for.
+  pub aud: String,
+  /// A unique value chosen by the verifier, preventing the proof from being replayed.
+  pub nonce: String,
+  /// The time after which the proof is no longer accepted.
+  pub exp: Timestamp,
+}
+
+impl PopChallenge {
+  /// The `typ` header of a proof of possession.
+  pub const TYP: &'static str = "did-pop+jwt";
+
+  /// Creates a new [`PopChallenge`].
+  pub fn new(aud: impl Into<String>, nonce: impl Into<String>, exp: Timestamp) -> Self {
+    Self {
+      aud: aud.into(),
+      nonce: nonce.into(),
+      exp,
+    }
+  }
+}
+
+/// The claims of a proof of possession, answering a [`PopChallenge`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct PopClaims {
+  /// The DID whose verification method signed the proof.
+  pub iss: CoreDID,
+  /// The verifier the proof is intended for.
+  pub aud: String,
+  /// The nonce of the challenge.
+  pub nonce: String,
+  /// The time after which the proof is no longer accepted, as a UNIX timestamp.
+  pub exp: i64,
+  /// The time at which the proof was created, as a UNIX timestamp.
+  pub iat: i64,
+}
+
+impl PopClaims {
+  /// Creates the claims answering `challenge` on behalf of `did`, created at `issuance_date`.
+  pub fn new(did: CoreDID, challenge: &PopChallenge, issuance_date: Timestamp) -> Self {
+    Self {
+      iss: did,
+      aud: challenge.aud.clone(),
+      nonce: challenge.nonce.clone(),
+      exp: challenge.exp.to_unix(),
+      iat: issuance_date.to_unix(),
+    }
+  }
+}
+
+/// Options for [`CoreDocument::verify_pop`].
+#[non_exhaustive]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PopVerificationOptions {
+  /// Options which affect the verification of the signature on the proof.
+  #[serde(default)]
+  pub verification_options: JwsVerificationOptions,
+
+  /// The clock providing the current datetime against which the expiration of the challenge is checked.
+  ///
+  /// Default: [`SystemClock`](identity_core::common::SystemClock).
+  #[serde(skip)]
+  pub clock: SharedClock,
+}
+
+impl PopVerificationOptions {
+  /// Constructor that sets all options to their defaults.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Set options which affect the verification of the signature on the proof.
+  pub fn verification_options(mut self, options: JwsVerificationOptions) -> Self {
+    self.verification_options = options;
+    self
+  }
+
+  /// Set the clock providing the current datetime against which the expiration of the challenge is checked.
+  pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+    self.clock = SharedClock::new(clock);
+    self
+  }
+}
+
+impl CoreDocument {
+  /// Decodes and verifies a proof of possession answering `challenge`, created with `create_pop` from the
+  /// `PopDocumentExt` trait of `identity_storage`.
+  ///
+  /// On top of the conditions of [`CoreDocument::verify_jws`], the proof must:
+  /// - have [`PopChallenge::TYP`] as its `typ` header,
+  /// - be issued by the DID of this document,
+  /// - carry the audience and nonce of `challenge`,
+  /// - not be expired at the current datetime of the clock of `options`.
+  pub fn verify_pop<T: JwsVerifier>(
+    &self,
+    pop: &str,
+    challenge: &PopChallenge,
+    signature_verifier: &T,
+    options: &PopVerificationOptions,
+  ) -> Result<PopClaims> {
+    let decoded: DecodedJws<'_> = self.verify_jws(pop, None, signature_verifier, &options.verification_options)?;
+    if decoded.protected.typ() != Some(PopChallenge::TYP) {
+      return Err(Error::InvalidProofOfPossession("invalid typ header"));
+    }
+
+    let claims: PopClaims =
+      PopClaims::from_json_slice(&decoded.claims).map_err(|_| Error::InvalidProofOfPossession("invalid claims"))?;
+    if &claims.iss != self.id() {
+      return Err(Error::InvalidProofOfPossession("issuer does not match the document"));
+    }
+    if claims.aud != challenge.aud {
+      return Err(Error::InvalidProofOfPossession("audience does not match the challenge"));
+    }
+    if !jwu::ct_eq(&claims.nonce, &challenge.nonce) {
+      return Err(Error::InvalidProofOfPossession("nonce does not match the challenge"));
+    }
+    if claims.exp != challenge.exp.to_unix() {
+      return Err(Error::InvalidProofOfPossession(
+        "expiration does not match the challenge",
+      ));
+    }
+    if challenge.exp < options.clock.now() {
+      return Err(Error::InvalidProofOfPossession("the challenge has expired"));
+    }
+
+    Ok(claims)
+  }
+}
@@ -31,4 +31,18 @@ impl JwsVerifier for EcDSAJwsVerifier {
       _ => Err(SignatureVerificationErrorKind::UnsupportedAlg.into()),
     }
   }
+
+  fn verify_borrowed(
+    &self,
+    input: identity_verification::jws::BorrowedVerificationInput<'_>,
+    public_key: &identity_verification::jwk::Jwk,
+  ) -> Result<(), identity_verification::jws::SignatureVerificationError> {
+    match input.alg {
+      #[cfg(feature = "es256")]
+      JwsAlgorithm::ES256 => crate::Secp256R1Verifier::verify_borrowed(input, public_key),
+      #[cfg(feature = "es256k")]
+      JwsAlgorithm::ES256K => crate::Secp256K1Verifier::verify_borrowed(input, public_key),
+      _ => Err(SignatureVerificationErrorKind::UnsupportedAlg.into()),
+    }
+  }
 }
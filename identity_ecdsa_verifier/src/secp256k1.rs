@@ -1,8 +1,6 @@
 // Copyright 2020-2024 IOTA Stiftung, Filancore GmbH
 // SPDX-License-Identifier: Apache-2.0
 
-use std::ops::Deref;
-
 use identity_verification::jwk::JwkParamsEc;
 use identity_verification::jws::SignatureVerificationError;
 use identity_verification::jws::SignatureVerificationErrorKind;
@@ -41,6 +39,22 @@ impl Secp256K1Verifier {
   pub fn verify(
     input: &identity_verification::jws::VerificationInput,
     public_key: &identity_verification::jwk::Jwk,
+  ) -> Result<(), SignatureVerificationError> {
+    Self::verify_borrowed(input.as_borrowed(), public_key)
+  }
+
+  /// Verify a JWS signature secured with the
+  /// [`JwsAlgorithm::ES256K`](identity_verification::jws::JwsAlgorithm::ES256K)
+  /// algorithm, without copying the signing input.
+  ///
+  /// # Warning
+  ///
+  /// This function does not check whether `alg = ES256K` in the protected
+  /// header. Callers are expected to assert this prior to calling the
+  /// function.
+  pub fn verify_borrowed(
+    input: identity_verification::jws::BorrowedVerificationInput<'_>,
+    public_key: &identity_verification::jwk::Jwk,
   ) -> Result<(), SignatureVerificationError> {
     // Obtain a K256 public key.
     let params: &JwkParamsEc = public_key
@@ -75,7 +89,7 @@ impl Secp256K1Verifier {
 
     let verifying_key: VerifyingKey = VerifyingKey::from(public_key);
 
-    let mut signature: Signature = Signature::try_from(input.decoded_signature.deref()).map_err(|err| {
+    let mut signature: Signature = Signature::try_from(input.decoded_signature).map_err(|err| {
       SignatureVerificationError::new(SignatureVerificationErrorKind::InvalidSignature).with_source(err)
     })?;
 
@@ -83,7 +97,7 @@ impl Secp256K1Verifier {
       signature = normalized;
     }
 
-    match signature::Verifier::verify(&verifying_key, &input.signing_input, &signature) {
+    match signature::Verifier::verify(&verifying_key, input.signing_input, &signature) {
       Ok(()) => Ok(()),
       Err(err) => {
         Err(SignatureVerificationError::new(SignatureVerificationErrorKind::InvalidSignature).with_source(err))
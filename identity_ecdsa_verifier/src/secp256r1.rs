@@ -1,8 +1,6 @@
 // Copyright 2020-2024 IOTA Stiftung, Filancore GmbH
 // SPDX-License-Identifier: Apache-2.0
 
-use std::ops::Deref;
-
 use identity_verification::jwk::JwkParamsEc;
 use identity_verification::jws::SignatureVerificationError;
 use identity_verification::jws::SignatureVerificationErrorKind;
@@ -41,6 +39,22 @@ impl Secp256R1Verifier {
   pub fn verify(
     input: &identity_verification::jws::VerificationInput,
     public_key: &identity_verification::jwk::Jwk,
+  ) -> Result<(), SignatureVerificationError> {
+    Self::verify_borrowed(input.as_borrowed(), public_key)
+  }
+
+  /// Verify a JWS signature secured with the
+  /// [`JwsAlgorithm::ES256`](identity_verification::jws::JwsAlgorithm::ES256)
+  /// algorithm, without copying the signing input.
+  ///
+  /// # Warning
+  ///
+  /// This function does not check whether `alg = ES256` in the protected
+  /// header. Callers are expected to assert this prior to calling the
+  /// function.
+  pub fn verify_borrowed(
+    input: identity_verification::jws::BorrowedVerificationInput<'_>,
+    public_key: &identity_verification::jwk::Jwk,
   ) -> Result<(), SignatureVerificationError> {
     // Obtain a P256 public key.
     let params: &JwkParamsEc = public_key
@@ -75,11 +89,11 @@ impl Secp256R1Verifier {
 
     let verifying_key: VerifyingKey = VerifyingKey::from(public_key);
 
-    let signature: Signature = Signature::try_from(input.decoded_signature.deref()).map_err(|err| {
+    let signature: Signature = Signature::try_from(input.decoded_signature).map_err(|err| {
       SignatureVerificationError::new(SignatureVerificationErrorKind::InvalidSignature).with_source(err)
     })?;
 
-    match signature::Verifier::verify(&verifying_key, &input.signing_input, &signature) {
+    match signature::Verifier::verify(&verifying_key, input.signing_input, &signature) {
       Ok(()) => Ok(()),
       Err(err) => {
         Err(SignatureVerificationError::new(SignatureVerificationErrorKind::InvalidSignature).with_source(err))
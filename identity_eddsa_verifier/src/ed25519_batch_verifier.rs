@@ -0,0 +1,242 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+
+use ed25519_zebra::batch;
+use ed25519_zebra::Signature;
+use ed25519_zebra::VerificationKey;
+use ed25519_zebra::VerificationKeyBytes;
+use identity_jose::jwk::Jwk;
+use identity_jose::jws::JwsAlgorithm;
+use identity_jose::jws::JwsVerifier;
+use identity_jose::jws::SignatureVerificationError;
+use identity_jose::jws::SignatureVerificationErrorKind;
+use identity_jose::jws::VerificationInput;
+
+use crate::ed25519_verifier::decode_public_key;
+use crate::ed25519_verifier::decode_signature;
+
+/// A signature queued for batch verification.
+#[derive(Debug)]
+struct QueuedSignature {
+  public_key: [u8; 32],
+  signature: [u8; 64],
+  message: Vec<u8>,
+}
+
+impl QueuedSignature {
+  fn verify(&self) -> bool {
+    VerificationKey::try_from(self.public_key)
+      .and_then(|key| key.verify(&Signature::from_bytes(&self.signature), &self.message))
+      .is_ok()
+  }
+}
+
+/// Caused by a failed [`Ed25519BatchVerifier::verify_queued`].
+#[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum BatchVerificationError {
+  /// Some of the queued signatures are invalid.
+  #[error("{} of the queued signatures are invalid", .failed.len())]
+  InvalidSignatures {
+    /// The positions, in queueing order, of the invalid signatures.
+    failed: Vec<usize>,
+  },
+}
+
+impl BatchVerificationError {
+  /// Returns the positions, in queueing order, of the invalid signatures.
+  pub fn failed(&self) -> &[usize] {
+    match self {
+      Self::InvalidSignatures { failed } => failed,
+    }
+  }
+}
+
+identity_core::impl_error_code!(BatchVerificationError {
+  InvalidSignatures => 2580,
+});
+
+/// A [`JwsVerifier`] that defers the verification of
+/// [`JwsAlgorithm::EdDSA`](identity_jose::jws::JwsAlgorithm::EdDSA) signatures with curve
+/// [`EdCurve::Ed25519`](identity_jose::jwk::EdCurve::Ed25519), to verify all of them at once with Ed25519 batch
+/// verification.
+///
+/// Batch verification is significantly faster than verifying signatures one by one, which makes this verifier
+/// suited for verifiers processing large amounts of credentials and presentations.
+///
+/// Each [`Ed25519BatchVerifier`] is a single batch with a single owner: validators borrow it, as
+/// `&Ed25519BatchVerifier` is a [`JwsVerifier`] too, possibly from different threads, and
+/// [`Ed25519BatchVerifier::verify_queued`] consumes it once they are done. The borrow checker thereby guarantees that
+/// no signature is queued after the batch was verified, and that the batch only contains signatures queued on behalf of
+/// its owner. Independent batches use independent verifiers.
+///
+/// # Warning
+///
+/// [`JwsVerifier::verify`] only checks the format of the key and signature and queues them: a successful JWS,
+/// credential or presentation validation performed with this verifier must not be trusted until
+/// [`Ed25519BatchVerifier::verify_queued`] succeeds.
+///
+/// # Example
+///
+/// ```
+/// # use identity_eddsa_verifier::Ed25519BatchVerifier;
+/// let verifier = Ed25519BatchVerifier::new();
+/// // Validate credentials and presentations with references to `verifier`, e.g. with
+/// // `JwtCredentialValidator::with_signature_verifier(&verifier)`, then:
+/// verifier.verify_queued().expect("all signatures are valid");
+/// ```
+#[derive(Debug, Default)]
+pub struct Ed25519BatchVerifier {
+  queue: Mutex<Vec<QueuedSignature>>,
+}
+
+impl Ed25519BatchVerifier {
+  /// Creates a new [`Ed25519BatchVerifier`] with an empty queue.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Queues the signature in `input` to be verified with `public_key`, returning its position in the queue.
+  ///
+  /// # Warning
+  ///
+  /// This function does not check whether `alg = EdDSA` in the protected header. Callers are expected to assert this
+  /// prior to calling the function.
+  pub fn queue(&self, input: VerificationInput, public_key: &Jwk) -> Result<usize, SignatureVerificationError> {
+    let queued = QueuedSignature {
+      public_key: decode_public_key(public_key)?,
+      signature: decode_signature(&input.decoded_signature)?,
+      message: input.signing_input.into_vec(),
+    };
+
+    let mut queue = self.lock();
+    queue.push(queued);
+    Ok(queue.len() - 1)
+  }
+
+  /// Returns the number of signatures waiting to be verified.
+  pub fn len(&self) -> usize {
+    self.lock().len()
+  }
+
+  /// Returns whether no signature is waiting to be verified.
+  pub fn is_empty(&self) -> bool {
+    self.lock().is_empty()
+  }
+
+  /// Verifies all the queued signatures at once, consuming the batch.
+  ///
+  /// # Errors
+  /// Fails if any of the queued signatures is invalid. The invalid signatures are identified by verifying the
+  /// queued signatures one by one, so that the error lists all of them.
+  pub fn verify_queued(self) -> Result<(), BatchVerificationError> {
+    let queued = self.queue.into_inner().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    let mut verifier = batch::Verifier::new();
+    for signature in &queued {
+      let item = (
+        VerificationKeyBytes::from(signature.public_key),
+        Signature::from_bytes(&signature.signature),
+        &signature.message,
+      );
+      verifier.queue(item);
+    }
+    if verifier.verify(rand::thread_rng()).is_ok() {
+      return Ok(());
+    }
+
+    let failed = queued
+      .iter()
+      .enumerate()
+      .filter(|(_, signature)| !signature.verify())
+      .map(|(idx, _)| idx)
+      .collect();
+    Err(BatchVerificationError::InvalidSignatures { failed })
+  }
+
+  fn lock(&self) -> MutexGuard<'_, Vec<QueuedSignature>> {
+    // A panic while holding the lock cannot leave the queue in an inconsistent state.
+    self.queue.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+  }
+}
+
+impl JwsVerifier for Ed25519BatchVerifier {
+  /// Queues [`JwsAlgorithm::EdDSA`] signatures for verification with [`Ed25519BatchVerifier::verify_queued`].
+  fn verify(&self, input: VerificationInput, public_key: &Jwk) -> Result<(), SignatureVerificationError> {
+    match input.alg {
+      JwsAlgorithm::EdDSA => self.queue(input, public_key).map(|_| ()),
+      _ => Err(SignatureVerificationErrorKind::UnsupportedAlg.into()),
+    }
+  }
+}
+
+impl JwsVerifier for &Ed25519BatchVerifier {
+  /// Queues [`JwsAlgorithm::EdDSA`] signatures for verification with [`Ed25519BatchVerifier::verify_queued`].
+  fn verify(&self, input: VerificationInput, public_key: &Jwk) -> Result<(), SignatureVerificationError> {
+    (**self).verify(input, public_key)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crypto::signatures::ed25519::SecretKey;
+  use identity_jose::jwk::EdCurve;
+  use identity_jose::jwk::JwkParamsOkp;
+  use identity_jose::jwu;
+
+  use super::*;
+
+  fn queue_signed(verifier: &impl JwsVerifier, secret_key: &SecretKey, message: &[u8], signed: &[u8]) {
+    let mut params = JwkParamsOkp::new();
+    params.crv = EdCurve::Ed25519.name().to_owned();
+    params.x = jwu::encode_b64(secret_key.public_key().to_bytes());
+    let input = VerificationInput {
+      alg: JwsAlgorithm::EdDSA,
+      signing_input: message.into(),
+      decoded_signature: secret_key.sign(signed).to_bytes().to_vec().into(),
+    };
+    verifier.verify(input, &Jwk::from_params(params)).unwrap();
+  }
+
+  #[test]
+  fn batch_verification_reports_invalid_signatures() {
+    let verifier = Ed25519BatchVerifier::new();
+    let secret_key = SecretKey::generate().unwrap();
+
+    queue_signed(&verifier, &secret_key, b"first", b"first");
+    queue_signed(&verifier, &secret_key, b"second", b"second");
+    assert_eq!(verifier.len(), 2);
+    verifier.verify_queued().unwrap();
+
+    let verifier = Ed25519BatchVerifier::new();
+    queue_signed(&verifier, &secret_key, b"first", b"first");
+    queue_signed(&verifier, &secret_key, b"second", b"tampered");
+    queue_signed(&verifier, &secret_key, b"third", b"third");
+    assert_eq!(verifier.verify_queued().unwrap_err().failed(), [1]);
+  }
+
+  #[test]
+  fn batches_are_independent() {
+    let secret_key = SecretKey::generate().unwrap();
+    let first = Ed25519BatchVerifier::new();
+    let second = Ed25519BatchVerifier::new();
+
+    std::thread::scope(|scope| {
+      scope.spawn(|| queue_signed(&first, &secret_key, b"first", b"tampered"));
+      scope.spawn(|| {
+        // Borrowed batches are verifiers too, e.g. to be handed to validators.
+        let borrowed: &Ed25519BatchVerifier = &second;
+        queue_signed(&borrowed, &secret_key, b"second", b"second");
+        queue_signed(&borrowed, &secret_key, b"third", b"third");
+      });
+    });
+
+    // Verifying a batch leaves the signatures queued in other batches untouched.
+    assert_eq!(first.verify_queued().unwrap_err().failed(), [0]);
+    assert_eq!(second.len(), 2);
+    second.verify_queued().unwrap();
+  }
+}
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use identity_jose::jwk::Jwk;
+use identity_jose::jws::BorrowedVerificationInput;
 use identity_jose::jws::JwsVerifier;
 use identity_jose::jws::SignatureVerificationError;
 use identity_jose::jws::SignatureVerificationErrorKind;
@@ -32,4 +33,18 @@ impl JwsVerifier for EdDSAJwsVerifier {
       _ => Err(SignatureVerificationErrorKind::UnsupportedAlg.into()),
     }
   }
+
+  // Allow unused variables in case of no-default-features.
+  #[allow(unused_variables)]
+  fn verify_borrowed(
+    &self,
+    input: BorrowedVerificationInput<'_>,
+    public_key: &Jwk,
+  ) -> std::result::Result<(), SignatureVerificationError> {
+    match input.alg {
+      #[cfg(feature = "ed25519")]
+      identity_jose::jws::JwsAlgorithm::EdDSA => crate::Ed25519Verifier::verify_borrowed(input, public_key),
+      _ => Err(SignatureVerificationErrorKind::UnsupportedAlg.into()),
+    }
+  }
 }
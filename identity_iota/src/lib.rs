@@ -17,6 +17,8 @@
   clippy::missing_errors_doc
 )]
 
+#[cfg(feature = "iota-client")]
+#[cfg_attr(docsrs, doc(cfg(feature = "iota-client")))]
 pub use iota_interaction;
 
 pub mod core {
@@ -24,7 +26,11 @@ pub mod core {
 
   pub use identity_core::common::*;
   pub use identity_core::convert::*;
+  #[cfg(feature = "eip155")]
+  pub use identity_core::eip155;
   pub use identity_core::error::*;
+  #[cfg(feature = "http-client")]
+  pub use identity_core::http;
 
   #[doc(inline)]
   pub use identity_core::json;
@@ -35,7 +41,15 @@ pub mod credential {
   //!
   //! [Specification](https://www.w3.org/TR/vc-data-model/)
 
+  #[cfg(feature = "account-control")]
+  pub use identity_credential::account_control;
   pub use identity_credential::credential::*;
+  #[cfg(feature = "dcql")]
+  pub use identity_credential::dcql;
+  #[cfg(feature = "delegation")]
+  pub use identity_credential::delegation;
+  #[cfg(feature = "did-jwt")]
+  pub use identity_credential::did_jwt;
   #[cfg(feature = "domain-linkage")]
   pub use identity_credential::domain_linkage::*;
   pub use identity_credential::error::*;
@@ -45,6 +59,8 @@ pub mod credential {
   #[cfg(feature = "sd-jwt-vc")]
   pub use identity_credential::sd_jwt_vc;
   pub use identity_credential::validator::*;
+  #[cfg(feature = "wallet-attestation")]
+  pub use identity_credential::wallet_attestation;
 }
 
 pub mod did {
@@ -79,8 +95,11 @@ pub mod prelude {
   pub use identity_iota_core::IotaDID;
   pub use identity_iota_core::IotaDocument;
 
-  #[cfg(all(feature = "resolver", not(target_arch = "wasm32")))]
-  #[cfg_attr(docsrs, doc(cfg(all(feature = "resolver", not(target_arch = "wasm32")))))]
+  #[cfg(all(feature = "resolver", feature = "iota-client", not(target_arch = "wasm32")))]
+  #[cfg_attr(
+    docsrs,
+    doc(cfg(all(feature = "resolver", feature = "iota-client", not(target_arch = "wasm32"))))
+  )]
   pub use identity_iota_core::DidResolutionHandler;
 
   #[cfg(feature = "resolver")]
@@ -102,6 +121,10 @@ pub mod verification {
 
 pub mod storage {
   //! Storage traits.
+  /// Issuance session types and functionalities.
+  pub mod issuance {
+    pub use identity_storage::issuance::*;
+  }
   /// KeyIdStorage types and functionalities.
   pub mod key_id_storage {
     pub use identity_storage::key_id_storage::*;
@@ -115,6 +138,7 @@ pub mod storage {
   pub mod storage {
     pub use identity_storage::storage::*;
   }
+  pub use identity_storage::issuance::*;
   pub use identity_storage::key_id_storage::*;
   pub use identity_storage::key_storage::*;
   pub use identity_storage::storage::*;
@@ -32,6 +32,12 @@ module iota_identity::asset {
         concluded: bool,
     }
 
+    /// Event emitted when a new `AuthenticatedAsset<T>` is created.
+    public struct AssetCreated<phantom T> has copy, drop {
+        asset: ID,
+        origin: address,
+    }
+
     /// Structures that couples some data `T` with well known
     /// ownership and origin, along configurable abilities e.g.
     /// transferability, mutability and deletability.
@@ -116,6 +122,10 @@ module iota_identity::asset {
             transferable,
             deletable,
         };
+        iota::event::emit(AssetCreated<T> {
+            asset: object::id(&asset),
+            origin: addr,
+        });
         transfer::transfer(asset, addr);
     }
 
@@ -51,6 +51,12 @@ module iota_identity::identity {
         proposal: ID,
     }
 
+    /// Event emitted when a new `Identity` is created.
+    public struct IdentityCreated has copy, drop {
+        /// ID of the created `Identity`.
+        identity: ID,
+    }
+
     /// On-chain Identity.
     public struct Identity has key {
         id: UID,
@@ -108,6 +114,7 @@ module iota_identity::identity {
             deleted_did: false,
         };
         let id = object::id(&identity);
+        iota::event::emit(IdentityCreated { identity: id });
         transfer::share_object(identity);
 
         id
@@ -143,6 +150,7 @@ module iota_identity::identity {
             deleted_did: false,
         };
         let id = object::id(&identity);
+        iota::event::emit(IdentityCreated { identity: id });
         transfer::share_object(identity);
 
         id
@@ -186,6 +194,7 @@ module iota_identity::identity {
             deleted_did: false,
         };
         let id = object::id(&identity);
+        iota::event::emit(IdentityCreated { identity: id });
 
         transfer::share_object(identity);
         id
@@ -427,6 +436,22 @@ module iota_identity::identity {
         expiration: Option<u64>,
         clock: &Clock,
         ctx: &mut TxContext,
+    ): Option<ID> {
+        self.propose_update_with_not_before(cap, updated_doc, expiration, option::none(), clock, ctx)
+    }
+
+    /// Proposes an update to the DID Document contained in this `Identity`
+    /// that cannot be executed before epoch `not_before`, if set.
+    /// This function can update the DID Document right away if `cap` has
+    /// enough voting power and `not_before` is not set.
+    public fun propose_update_with_not_before(
+        self: &mut Identity,
+        cap: &DelegationToken,
+        updated_doc: Option<vector<u8>>,
+        expiration: Option<u64>,
+        not_before: Option<u64>,
+        clock: &Clock,
+        ctx: &mut TxContext,
     ): Option<ID> {
         assert!(!self.deleted && !self.deleted_did, EDeletedIdentity);
         if (updated_doc.is_some()) {
@@ -440,12 +465,19 @@ module iota_identity::identity {
             expiration,
             ctx,
         );
+        if (not_before.is_some()) {
+            self
+                .did_doc
+                .set_not_before<_, UpdateValue<Option<vector<u8>>>>(
+                    proposal_id,
+                    *not_before.borrow(),
+                );
+        };
 
-        let is_approved = self
+        // Time-locked proposals are never executed right away.
+        let is_approved = not_before.is_none() && self
             .did_doc
-            .is_proposal_approved<_, update_value_proposal::UpdateValue<Option<vector<u8>>>>(
-                proposal_id,
-            );
+            .is_proposal_approved<_, UpdateValue<Option<vector<u8>>>>(proposal_id);
         if (is_approved) {
             self.execute_update(cap, proposal_id, clock, ctx);
             option::none()
@@ -491,6 +523,33 @@ module iota_identity::identity {
         controllers_to_remove: vector<ID>,
         controllers_to_update: VecMap<ID, u64>,
         ctx: &mut TxContext,
+    ): Option<ID> {
+        self.propose_config_change_with_not_before(
+            cap,
+            expiration,
+            option::none(),
+            threshold,
+            controllers_to_add,
+            controllers_to_remove,
+            controllers_to_update,
+            ctx,
+        )
+    }
+
+    /// Proposes to update this `Identity`'s AC with a proposal that cannot
+    /// be executed before epoch `not_before`, if set.
+    /// This operation might be carried out right away if `cap`
+    /// has enough voting power and `not_before` is not set.
+    public fun propose_config_change_with_not_before(
+        self: &mut Identity,
+        cap: &DelegationToken,
+        expiration: Option<u64>,
+        not_before: Option<u64>,
+        threshold: Option<u64>,
+        controllers_to_add: VecMap<address, u64>,
+        controllers_to_remove: vector<ID>,
+        controllers_to_update: VecMap<ID, u64>,
+        ctx: &mut TxContext,
     ): Option<ID> {
         assert!(!self.deleted, EDeletedIdentity);
         let proposal_id = config_proposal::propose_modify(
@@ -503,8 +562,12 @@ module iota_identity::identity {
             controllers_to_update,
             ctx,
         );
+        if (not_before.is_some()) {
+            self.did_doc.set_not_before<_, config_proposal::Modify>(proposal_id, *not_before.borrow());
+        };
 
-        let is_approved = self
+        // Time-locked proposals are never executed right away.
+        let is_approved = not_before.is_none() && self
             .did_doc
             .is_proposal_approved<_, config_proposal::Modify>(proposal_id);
         if (is_approved) {
@@ -738,7 +801,7 @@ module iota_identity::identity_tests {
         new_with_controllers,
         EDeletedIdentity
     };
-    use iota_identity::multicontroller::{EExpiredProposal, EThresholdNotReached};
+    use iota_identity::multicontroller::{EExpiredProposal, EThresholdNotReached, ETimeLockedProposal};
 
     #[test]
     fun adding_a_controller_works() {
@@ -1155,6 +1218,44 @@ module iota_identity::identity_tests {
         clock::destroy_for_testing(clock);
     }
 
+    #[test, expected_failure(abort_code = ETimeLockedProposal)]
+    fun time_locked_proposals_cannot_be_executed_early() {
+        let controller = @0x1;
+        let mut scenario = test_scenario::begin(controller);
+        let clock = clock::create_for_testing(scenario.ctx());
+        let not_before = scenario.ctx().epoch() + 10;
+
+        let _ = new(option::some(b"DID"), &clock, scenario.ctx());
+
+        scenario.next_tx(controller);
+
+        let mut identity = scenario.take_shared<Identity>();
+        let mut cap = scenario.take_from_address<ControllerCap>(controller);
+        let (token, borrow) = cap.borrow();
+        // Controller has enough voting power, yet the proposal is not executed right away.
+        let proposal_id = identity
+            .propose_update_with_not_before(
+                &token,
+                option::none(),
+                option::none(),
+                option::some(not_before),
+                &clock,
+                scenario.ctx(),
+            )
+            .destroy_some();
+
+        scenario.later_epoch(5, controller);
+        // this should fail!
+        identity.execute_update(&token, proposal_id, &clock, scenario.ctx());
+        cap.put_back(token, borrow);
+
+        test_scenario::return_to_address(controller, cap);
+        test_scenario::return_shared(identity);
+
+        scenario.end();
+        clock::destroy_for_testing(clock);
+    }
+
     #[test]
     fun identity_can_be_deleted() {
         let controller = @0x1;
@@ -3,6 +3,7 @@
 
 module iota_identity::multicontroller;
 
+use iota::dynamic_field as field;
 use iota::object_bag::{Self, ObjectBag};
 use iota::vec_map::{Self, VecMap};
 use iota::vec_set::{Self, VecSet};
@@ -17,6 +18,7 @@ const EExpiredProposal: u64 = 4;
 const ENotVotedYet: u64 = 5;
 const EProposalNotFound: u64 = 6;
 const ECannotDelete: u64 = 7;
+const ETimeLockedProposal: u64 = 8;
 
 /// Shares control of a value `V` with multiple entities called controllers.
 public struct Multicontroller<V> has store {
@@ -123,6 +125,25 @@ public fun is_expired<T: store>(self: &Proposal<T>, ctx: &mut TxContext): bool {
     }
 }
 
+/// Key of the dynamic field holding the epoch before which a `Proposal`
+/// cannot be executed.
+public struct NotBeforeKey has copy, drop, store {}
+
+/// Returns the epoch before which `Proposal` `self` cannot be executed, if any.
+public fun not_before_epoch<T: store>(self: &Proposal<T>): Option<u64> {
+    if (field::exists_(&self.id, NotBeforeKey {})) {
+        option::some(*field::borrow(&self.id, NotBeforeKey {}))
+    } else {
+        option::none()
+    }
+}
+
+/// Returns `true` if `Proposal` `self` cannot be executed yet.
+public fun is_time_locked<T: store>(self: &Proposal<T>, ctx: &mut TxContext): bool {
+    let not_before = self.not_before_epoch();
+    not_before.is_some() && ctx.epoch() < *not_before.borrow()
+}
+
 /// Structure that encapsulate the kind of change that will be performed
 /// when a proposal is carried out.
 public struct Action<T: store> {
@@ -213,15 +234,17 @@ public fun execute_proposal<V, T: store>(
     let proposal = multi.proposals.remove<ID, Proposal<T>>(proposal_id);
     assert!(proposal.votes >= multi.threshold, EThresholdNotReached);
     assert!(!proposal.is_expired(ctx), EExpiredProposal);
+    assert!(!proposal.is_time_locked(ctx), ETimeLockedProposal);
 
     let Proposal {
-        id,
+        mut id,
         votes: _,
         voters: _,
         expiration_epoch: _,
         action: inner,
     } = proposal;
 
+    remove_not_before(&mut id);
     id.delete();
 
     let (present, i) = multi.active_proposals.index_of(&proposal_id);
@@ -264,13 +287,14 @@ public fun delete_proposal<V, T: store + drop>(
     assert!(proposal.votes == 0 || proposal.is_expired(ctx), ECannotDelete);
 
     let Proposal {
-        id,
+        mut id,
         votes: _,
         voters: _,
         expiration_epoch: _,
         action: _,
     } = proposal;
 
+    remove_not_before(&mut id);
     id.delete();
 
     let (present, i) = multi.active_proposals.index_of(&proposal_id);
@@ -390,6 +414,22 @@ public(package) fun is_proposal_approved<V, A: store>(
     proposal.votes >= multi.threshold
 }
 
+/// Prevents `Proposal` `proposal_id` from being executed before epoch `not_before`.
+public(package) fun set_not_before<V, A: store>(
+    multi: &mut Multicontroller<V>,
+    proposal_id: ID,
+    not_before: u64,
+) {
+    let proposal = multi.proposals.borrow_mut<ID, Proposal<A>>(proposal_id);
+    field::add(&mut proposal.id, NotBeforeKey {}, not_before);
+}
+
+fun remove_not_before(id: &mut UID) {
+    if (field::exists_(id, NotBeforeKey {})) {
+        let _: u64 = field::remove(id, NotBeforeKey {});
+    }
+}
+
 public(package) fun add_members<V>(
     multi: &mut Multicontroller<V>,
     to_add: VecMap<address, u64>,
@@ -446,9 +486,10 @@ public(package) fun force_delete_proposal<V, T: drop + store>(
     let proposal = self.proposals.remove<ID, Proposal<T>>(proposal_id);
 
     let Proposal<T> {
-        id,
+        mut id,
         ..,
     } = proposal;
 
+    remove_not_before(&mut id);
     id.delete();
 }
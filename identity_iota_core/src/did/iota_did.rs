@@ -49,6 +49,10 @@ impl IotaDID {
   /// The length of an identity's object id, which is a BLAKE2b-256 hash (32-bytes).
   pub(crate) const TAG_BYTES_LEN: usize = 32;
 
+  /// The length of a chain identifier, i.e. the hex encoding of the first four bytes of the digest of a network's
+  /// genesis checkpoint.
+  pub const CHAIN_IDENTIFIER_LEN: usize = 8;
+
   /// Convert a `CoreDID` reference to an `IotaDID` reference without checking the referenced value.
   ///  
   /// # Warning
@@ -159,6 +163,28 @@ impl IotaDID {
     Self::denormalized_components(self.method_id()).1
   }
 
+  /// Returns `true` if `network` has the form of a chain identifier, e.g. `6364aad5`, rather than of a network alias,
+  /// e.g. `testnet`.
+  ///
+  /// A network may be referenced by both forms, see [`IotaDID::with_network`].
+  pub fn is_chain_identifier(network: &str) -> bool {
+    network.len() == Self::CHAIN_IDENTIFIER_LEN && network.bytes().all(|byte| matches!(byte, b'0'..=b'9' | b'a'..=b'f'))
+  }
+
+  /// Returns the chain identifier of the network of the `DID`, if the `DID` references its network by chain
+  /// identifier.
+  pub fn chain_identifier(&self) -> Option<&str> {
+    Some(self.network_str()).filter(|network| Self::is_chain_identifier(network))
+  }
+
+  /// Returns the `DID` of the same identity, referencing its network by `network_name`.
+  ///
+  /// This converts between the alias and chain identifier forms of the DIDs of a network, which identify the same
+  /// DID Document, e.g. `did:iota:testnet:0x...` and `did:iota:2304aa97:0x...`.
+  pub fn with_network(&self, network_name: &NetworkName) -> Self {
+    Self::parse(format!("did:iota:{network_name}:{}", self.tag_str())).expect("valid IOTA DID")
+  }
+
   // ===========================================================================
   // Validation
   // ===========================================================================
@@ -535,6 +561,26 @@ mod tests {
     }
   }
 
+  #[test]
+  fn network_forms() {
+    let alias: IotaDID = IotaDID::parse(format!("did:iota:testnet:{VALID_OBJECT_ID_STR}")).unwrap();
+    assert_eq!(alias.chain_identifier(), None);
+
+    let digest: IotaDID = alias.with_network(&NetworkName::try_from("2304aa97").unwrap());
+    assert_eq!(digest.as_str(), format!("did:iota:2304aa97:{VALID_OBJECT_ID_STR}"));
+    assert_eq!(digest.chain_identifier(), Some("2304aa97"));
+    assert_eq!(digest.tag_str(), alias.tag_str());
+    assert_eq!(digest.with_network(&NetworkName::try_from("testnet").unwrap()), alias);
+
+    // The default network is omitted.
+    let default: IotaDID = digest.with_network(&NetworkName::try_from(IotaDID::DEFAULT_NETWORK).unwrap());
+    assert_eq!(default.as_str(), format!("did:iota:{VALID_OBJECT_ID_STR}"));
+
+    assert!(!IotaDID::is_chain_identifier("2304AA97"));
+    assert!(!IotaDID::is_chain_identifier("2304aa9"));
+    assert!(!IotaDID::is_chain_identifier("foobar12"));
+  }
+
   #[test]
   fn normalization_in_constructors() {
     let did_with_default_network_string: String = format!(
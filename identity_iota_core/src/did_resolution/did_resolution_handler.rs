@@ -1,7 +1,11 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use identity_core::common::Timestamp;
+use identity_did::DID as _;
+
 use crate::rebased::client::IdentityClientReadOnly;
+use crate::rebased::client::MultiNetworkIdentityClient;
 use crate::Error;
 use crate::IotaDID;
 use crate::IotaDocument;
@@ -22,6 +26,70 @@ pub trait DidResolutionHandler {
   ///
   /// - [`DID resolution failed`](Error::DIDResolutionError) if the DID could not be resolved.
   async fn resolve_did(&self, did: &IotaDID) -> Result<IotaDocument>;
+
+  /// Resolve a [`IotaDocument`], following the link from a legacy DID to the DID it was migrated to, if any.
+  ///
+  /// A DID is followed when it is listed in the `alsoKnownAs` property of the document of `did` and its own document
+  /// lists `did` in `alsoKnownAs` in turn, as set up by
+  /// [`IdentityClient::migrate_legacy_document`](crate::rebased::client::IdentityClient::migrate_legacy_document).
+  /// Requiring the link to be two-way prevents a document from impersonating another one by claiming to be its
+  /// successor. Only a single link is followed.
+  ///
+  /// # Errors
+  ///
+  /// - [`DID resolution failed`](Error::DIDResolutionError) if `did` could not be resolved.
+  async fn resolve_did_following_migration(&self, did: &IotaDID) -> Result<IotaDocument> {
+    let document: IotaDocument = self.resolve_did(did).await?;
+    let successors: Vec<IotaDID> = document
+      .also_known_as()
+      .iter()
+      .filter_map(|url| IotaDID::parse(url.as_str()).ok())
+      .filter(|successor| successor != did)
+      .collect();
+
+    for successor in successors {
+      let Ok(successor_document) = self.resolve_did(&successor).await else {
+        continue;
+      };
+      if successor_document
+        .also_known_as()
+        .iter()
+        .any(|url| url.as_str() == did.as_str())
+      {
+        return Ok(successor_document);
+      }
+    }
+
+    Ok(document)
+  }
+
+  /// Resolve a [`IotaDocument`] as it was at `timestamp`.
+  ///
+  /// The default implementation does not support historical resolution and always fails.
+  ///
+  /// # Errors
+  ///
+  /// - [`DID resolution failed`](Error::DIDResolutionError) if the DID could not be resolved at `timestamp`.
+  async fn resolve_did_at(&self, did: &IotaDID, timestamp: Timestamp) -> Result<IotaDocument> {
+    Err(Error::DIDResolutionError(format!(
+      "cannot resolve `{did}` at {timestamp}: historical resolution is not supported"
+    )))
+  }
+
+  /// Resolve a [`IotaDocument`] as it was in the version identified by `version_id`, the value of the `versionId`
+  /// DID parameter.
+  ///
+  /// The default implementation does not support versioned resolution and always fails.
+  ///
+  /// # Errors
+  ///
+  /// - [`DID resolution failed`](Error::DIDResolutionError) if the DID could not be resolved in version
+  ///   `version_id`.
+  async fn resolve_did_version(&self, did: &IotaDID, version_id: &str) -> Result<IotaDocument> {
+    Err(Error::DIDResolutionError(format!(
+      "cannot resolve `{did}` in version {version_id}: versioned resolution is not supported"
+    )))
+  }
 }
 
 #[cfg_attr(feature = "send-sync-client-ext", async_trait::async_trait)]
@@ -33,4 +101,50 @@ impl DidResolutionHandler for IdentityClientReadOnly {
       .await
       .map_err(|err| Error::DIDResolutionError(err.to_string()))
   }
+
+  async fn resolve_did_at(&self, did: &IotaDID, timestamp: Timestamp) -> Result<IotaDocument> {
+    self
+      .resolve_did_at(did, timestamp)
+      .await
+      .map_err(|err| Error::DIDResolutionError(err.to_string()))
+  }
+
+  async fn resolve_did_version(&self, did: &IotaDID, version_id: &str) -> Result<IotaDocument> {
+    self
+      .resolve_did_version(did, parse_version_id(version_id)?)
+      .await
+      .map_err(|err| Error::DIDResolutionError(err.to_string()))
+  }
+}
+
+#[cfg_attr(feature = "send-sync-client-ext", async_trait::async_trait)]
+#[cfg_attr(not(feature = "send-sync-client-ext"), async_trait::async_trait(?Send))]
+impl DidResolutionHandler for MultiNetworkIdentityClient {
+  async fn resolve_did(&self, did: &IotaDID) -> Result<IotaDocument> {
+    self
+      .resolve_did(did)
+      .await
+      .map_err(|err| Error::DIDResolutionError(err.to_string()))
+  }
+
+  async fn resolve_did_at(&self, did: &IotaDID, timestamp: Timestamp) -> Result<IotaDocument> {
+    self
+      .resolve_did_at(did, timestamp)
+      .await
+      .map_err(|err| Error::DIDResolutionError(err.to_string()))
+  }
+
+  async fn resolve_did_version(&self, did: &IotaDID, version_id: &str) -> Result<IotaDocument> {
+    self
+      .resolve_did_version(did, parse_version_id(version_id)?)
+      .await
+      .map_err(|err| Error::DIDResolutionError(err.to_string()))
+  }
+}
+
+/// Parses a `versionId` DID parameter of an IOTA DID, i.e. the version of its Identity object.
+fn parse_version_id(version_id: &str) -> Result<u64> {
+  version_id
+    .parse()
+    .map_err(|_| Error::DIDResolutionError(format!("invalid versionId `{version_id}`")))
 }
@@ -0,0 +1,242 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+
+use futures::TryStreamExt as _;
+use identity_jose::jwk::Jwk;
+
+use crate::rebased::client::IdentityClientReadOnly;
+use crate::Error;
+use crate::IotaDID;
+use crate::IotaDocument;
+use crate::Result;
+
+/// A directory answering the question "which DIDs include this public key as a verification method?".
+///
+/// Keys are identified by their base64url-encoded JWK SHA-256 thumbprint, as defined in
+/// [RFC 7638](https://www.rfc-editor.org/rfc/rfc7638), which can be computed with [`Jwk::thumbprint_sha256_b64`].
+#[cfg_attr(feature = "send-sync-client-ext", async_trait::async_trait)]
+#[cfg_attr(not(feature = "send-sync-client-ext"), async_trait::async_trait(?Send))]
+pub trait KeyDirectory {
+  /// Returns the DIDs whose DID Document contains a verification method with a JWK public key matching
+  /// `thumbprint`, in ascending order.
+  async fn dids_by_thumbprint(&self, thumbprint: &str) -> Result<Vec<IotaDID>>;
+
+  /// Returns the DIDs whose DID Document contains a verification method with public key `jwk`,
+  /// in ascending order.
+  async fn dids_by_jwk(&self, jwk: &Jwk) -> Result<Vec<IotaDID>> {
+    self.dids_by_thumbprint(&jwk.thumbprint_sha256_b64()).await
+  }
+}
+
+/// A [`KeyDirectory`] over a set of cached [`IotaDocument`]s.
+#[derive(Debug, Clone, Default)]
+pub struct OfflineKeyDirectory {
+  dids_by_thumbprint: HashMap<String, BTreeSet<IotaDID>>,
+}
+
+impl OfflineKeyDirectory {
+  /// Creates an empty [`OfflineKeyDirectory`].
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Creates an [`OfflineKeyDirectory`] indexing the keys of all the given `documents`.
+  pub fn from_documents<'a>(documents: impl IntoIterator<Item = &'a IotaDocument>) -> Self {
+    let mut directory = Self::new();
+    for document in documents {
+      directory.insert(document);
+    }
+
+    directory
+  }
+
+  /// Indexes the keys of `document`, replacing any previously indexed version of it.
+  pub fn insert(&mut self, document: &IotaDocument) {
+    let did = document.id();
+    self.remove(did);
+    for thumbprint in jwk_thumbprints(document) {
+      self
+        .dids_by_thumbprint
+        .entry(thumbprint)
+        .or_default()
+        .insert(did.clone());
+    }
+  }
+
+  /// Removes the document with DID `did` from this directory.
+  pub fn remove(&mut self, did: &IotaDID) {
+    self.dids_by_thumbprint.retain(|_, dids| {
+      dids.remove(did);
+      !dids.is_empty()
+    });
+  }
+
+  /// Returns the number of distinct keys indexed by this directory.
+  pub fn len(&self) -> usize {
+    self.dids_by_thumbprint.len()
+  }
+
+  /// Returns `true` if this directory doesn't index any key.
+  pub fn is_empty(&self) -> bool {
+    self.dids_by_thumbprint.is_empty()
+  }
+
+  fn lookup(&self, thumbprint: &str) -> Vec<IotaDID> {
+    self
+      .dids_by_thumbprint
+      .get(thumbprint)
+      .map(|dids| dids.iter().cloned().collect())
+      .unwrap_or_default()
+  }
+}
+
+#[cfg_attr(feature = "send-sync-client-ext", async_trait::async_trait)]
+#[cfg_attr(not(feature = "send-sync-client-ext"), async_trait::async_trait(?Send))]
+impl KeyDirectory for OfflineKeyDirectory {
+  async fn dids_by_thumbprint(&self, thumbprint: &str) -> Result<Vec<IotaDID>> {
+    Ok(self.lookup(thumbprint))
+  }
+}
+
+/// A [`KeyDirectory`] backed by the network an [`IdentityClientReadOnly`] is connected to.
+///
+/// Identities are discovered by querying the events indexer for `IdentityCreated` events, each identity's
+/// DID Document is then resolved and its keys are matched against the requested thumbprint.
+///
+/// # Notes
+/// - Identities created before the IotaIdentity package started emitting `IdentityCreated` events are not discovered.
+/// - Identities whose DID Document cannot be resolved, e.g. because it has been deleted, are skipped.
+/// - Every lookup resolves all the discovered identities. When many keys have to be checked, e.g. during incident
+///   response, take a [snapshot](Self::snapshot) and query it instead.
+#[derive(Clone)]
+pub struct OnChainKeyDirectory {
+  client: IdentityClientReadOnly,
+}
+
+impl OnChainKeyDirectory {
+  /// Creates a new [`OnChainKeyDirectory`] using `client`.
+  pub fn new(client: IdentityClientReadOnly) -> Self {
+    Self { client }
+  }
+
+  /// Returns a reference to the [`IdentityClientReadOnly`] used by this directory.
+  pub fn client(&self) -> &IdentityClientReadOnly {
+    &self.client
+  }
+
+  /// Resolves all the discovered identities once and indexes their keys in an [`OfflineKeyDirectory`].
+  pub async fn snapshot(&self) -> Result<OfflineKeyDirectory> {
+    let mut directory = OfflineKeyDirectory::new();
+    self.for_each_document(|document| directory.insert(&document)).await?;
+
+    Ok(directory)
+  }
+
+  async fn for_each_document(&self, mut f: impl FnMut(IotaDocument)) -> Result<()> {
+    let mut dids = std::pin::pin!(self.client.streamed_created_dids());
+    while let Some(did) = dids
+      .try_next()
+      .await
+      .map_err(|e| Error::DIDResolutionError(e.to_string()))?
+    {
+      if let Ok(document) = self.client.resolve_did(&did).await {
+        f(document);
+      }
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg_attr(feature = "send-sync-client-ext", async_trait::async_trait)]
+#[cfg_attr(not(feature = "send-sync-client-ext"), async_trait::async_trait(?Send))]
+impl KeyDirectory for OnChainKeyDirectory {
+  async fn dids_by_thumbprint(&self, thumbprint: &str) -> Result<Vec<IotaDID>> {
+    let mut dids = BTreeSet::new();
+    self
+      .for_each_document(|document| {
+        if jwk_thumbprints(&document).any(|key| key == thumbprint) {
+          dids.insert(document.id().clone());
+        }
+      })
+      .await?;
+
+    Ok(dids.into_iter().collect())
+  }
+}
+
+/// Returns the thumbprints of all the JWK public keys contained in `document`'s verification methods,
+/// including the ones embedded in verification relationships.
+fn jwk_thumbprints(document: &IotaDocument) -> impl Iterator<Item = String> + '_ {
+  document
+    .methods(None)
+    .into_iter()
+    .filter_map(|method| method.data().public_key_jwk())
+    .map(Jwk::thumbprint_sha256_b64)
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_jose::jwk::JwkParamsOkp;
+  use identity_verification::MethodScope;
+  use identity_verification::VerificationMethod;
+  use product_common::network_name::NetworkName;
+
+  use super::*;
+
+  fn ed25519_jwk(x: &str) -> Jwk {
+    let mut params = JwkParamsOkp::new();
+    params.crv = "Ed25519".to_owned();
+    params.x = x.to_owned();
+    Jwk::from_params(params)
+  }
+
+  fn document_with_keys(tag: u8, keys: &[&Jwk]) -> IotaDocument {
+    let did = IotaDID::new(&[tag; 32], &NetworkName::try_from("iota").unwrap());
+    let mut document = IotaDocument::new_with_id(did.clone());
+    for (i, jwk) in keys.iter().enumerate() {
+      let method = VerificationMethod::new_from_jwk(did.clone(), (*jwk).clone(), Some(&format!("key-{i}"))).unwrap();
+      document.insert_method(method, MethodScope::VerificationMethod).unwrap();
+    }
+
+    document
+  }
+
+  #[test]
+  fn offline_directory_finds_all_dids_sharing_a_key() {
+    let leaked = ed25519_jwk("11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo");
+    let other = ed25519_jwk("VCpo2LMLhn6iWku8MKvSLg2ZAoC-nlOyPVQaO3FxVeQ");
+    let doc1 = document_with_keys(1, &[&leaked]);
+    let doc2 = document_with_keys(2, &[&other, &leaked]);
+    let doc3 = document_with_keys(3, &[&other]);
+
+    let directory = OfflineKeyDirectory::from_documents([&doc1, &doc2, &doc3]);
+    assert_eq!(directory.len(), 2);
+    assert_eq!(
+      directory.lookup(&leaked.thumbprint_sha256_b64()),
+      vec![doc1.id().clone(), doc2.id().clone()]
+    );
+    assert!(directory.lookup("unknown").is_empty());
+  }
+
+  #[test]
+  fn reinserting_a_document_replaces_its_keys() {
+    let old_key = ed25519_jwk("11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo");
+    let new_key = ed25519_jwk("VCpo2LMLhn6iWku8MKvSLg2ZAoC-nlOyPVQaO3FxVeQ");
+    let mut directory = OfflineKeyDirectory::from_documents([&document_with_keys(1, &[&old_key])]);
+
+    let rotated = document_with_keys(1, &[&new_key]);
+    directory.insert(&rotated);
+    assert!(directory.lookup(&old_key.thumbprint_sha256_b64()).is_empty());
+    assert_eq!(
+      directory.lookup(&new_key.thumbprint_sha256_b64()),
+      vec![rotated.id().clone()]
+    );
+
+    directory.remove(rotated.id());
+    assert!(directory.is_empty());
+  }
+}
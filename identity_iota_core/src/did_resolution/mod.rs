@@ -2,5 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub use did_resolution_handler::DidResolutionHandler;
+pub use key_directory::KeyDirectory;
+pub use key_directory::OfflineKeyDirectory;
+pub use key_directory::OnChainKeyDirectory;
 
 mod did_resolution_handler;
+mod key_directory;
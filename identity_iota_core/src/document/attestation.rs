@@ -0,0 +1,226 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Clock;
+use identity_core::common::SharedClock;
+use identity_core::common::Timestamp;
+use identity_core::convert::ToJson;
+use identity_credential::credential::Jws;
+use identity_did::DID;
+use identity_document::document::CoreDocument;
+use identity_document::verifiable::JwsVerificationOptions;
+use identity_verification::jose::jws::DecodedJws;
+use identity_verification::jose::jws::JwsVerifier;
+use identity_verification::jwu::encode_b64;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::error::Result;
+use crate::Error;
+use crate::IotaDocument;
+
+/// A snapshot of an [`IotaDocument`] together with a detached JWS over its
+/// [canonical serialization](IotaDocument::canonical_snapshot) and validity period, produced by one of the
+/// document's controllers.
+///
+/// Attestations allow relying parties that cannot reach the ledger, e.g. offline border control, to trust a cached
+/// version of a DID document: given a document they already trust for that DID, typically obtained the last time
+/// they were online, they can [verify](Self::verify) that a newer snapshot was signed with one of its keys and has
+/// not expired yet.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentAttestation {
+  document: IotaDocument,
+  issued_at: Timestamp,
+  expires_at: Timestamp,
+  jws: Jws,
+}
+
+/// The payload signed by a [`DocumentAttestation`].
+#[derive(Serialize)]
+struct AttestationPayload<'a> {
+  iat: i64,
+  exp: i64,
+  document: &'a IotaDocument,
+}
+
+impl DocumentAttestation {
+  /// Creates a new [`DocumentAttestation`] from `document`, its validity period and a detached `jws` over the
+  /// corresponding [signing input](Self::signing_input).
+  ///
+  /// The signature is not checked, see [`DocumentAttestation::verify`].
+  pub fn new(document: IotaDocument, issued_at: Timestamp, expires_at: Timestamp, jws: Jws) -> Self {
+    Self {
+      document,
+      issued_at,
+      expires_at,
+      jws,
+    }
+  }
+
+  /// Returns the payload to be signed to attest `document` from `issued_at` until `expires_at`.
+  ///
+  /// The payload is the canonical serialization of a JSON object holding the document's
+  /// [canonical snapshot](IotaDocument::canonical_snapshot) under `document` and the validity period as Unix
+  /// timestamps under `iat` and `exp`.
+  pub fn signing_input(document: &IotaDocument, issued_at: Timestamp, expires_at: Timestamp) -> Result<Vec<u8>> {
+    AttestationPayload {
+      iat: issued_at.to_unix(),
+      exp: expires_at.to_unix(),
+      document,
+    }
+    .to_canonical_json_vec()
+    .map_err(|err| Error::SerializationError("failed to serialize the attestation payload", Some(err)))
+  }
+
+  /// Returns a reference to the attested document.
+  pub fn document(&self) -> &IotaDocument {
+    &self.document
+  }
+
+  /// Returns the time at which the document was attested.
+  pub fn issued_at(&self) -> Timestamp {
+    self.issued_at
+  }
+
+  /// Returns the time after which the attestation is no longer accepted.
+  pub fn expires_at(&self) -> Timestamp {
+    self.expires_at
+  }
+
+  /// Returns a reference to the detached JWS over the attested document.
+  pub fn jws(&self) -> &Jws {
+    &self.jws
+  }
+
+  /// Consumes this attestation, returning the attested document.
+  pub fn into_document(self) -> IotaDocument {
+    self.document
+  }
+
+  /// Verifies that the attested document was signed with a verification method of `trusted_document`, which must
+  /// be a DID document of the same DID that the relying party already trusts.
+  ///
+  /// Verification happens entirely offline. Use the [`JwsVerificationOptions`] of `options` to restrict the methods
+  /// allowed to sign attestations, e.g. to the ones in the `capabilityInvocation` relationship.
+  ///
+  /// # Warning
+  /// The attested document is not checked against the ledger, it may have been updated or deactivated after it was
+  /// attested. Relying parties bound the staleness they accept through the validity period chosen by the attester.
+  ///
+  /// # Errors
+  /// Fails if the attested document and `trusted_document` don't share the same DID, if the attestation is not valid
+  /// at the current datetime of the clock of `options`, or if the JWS cannot be verified with a method of
+  /// `trusted_document`.
+  pub fn verify<DOC, V>(
+    &self,
+    trusted_document: &DOC,
+    signature_verifier: &V,
+    options: &AttestationVerificationOptions,
+  ) -> Result<DecodedJws<'static>>
+  where
+    DOC: AsRef<CoreDocument> + ?Sized,
+    V: JwsVerifier,
+  {
+    if self.document.id().as_str() != trusted_document.as_ref().id().as_str() {
+      return Err(Error::InvalidAttestation(
+        "the attested document and the trusted document have different DIDs",
+      ));
+    }
+
+    let now: Timestamp = options.clock.now();
+    if self.issued_at > now {
+      return Err(Error::InvalidAttestation("the attestation was issued in the future"));
+    }
+    if self.expires_at <= now {
+      return Err(Error::InvalidAttestation("the attestation expired"));
+    }
+
+    let payload: String = encode_b64(Self::signing_input(&self.document, self.issued_at, self.expires_at)?);
+    let decoded: DecodedJws<'_> = trusted_document
+      .as_ref()
+      .verify_jws(
+        self.jws.as_str(),
+        Some(payload.as_bytes()),
+        signature_verifier,
+        &options.verification_options,
+      )
+      .map_err(Error::JwsVerificationError)?;
+
+    Ok(decoded.into_owned())
+  }
+}
+
+/// Options for [`DocumentAttestation::verify`].
+#[non_exhaustive]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttestationVerificationOptions {
+  /// Options which affect the verification of the signature on the attestation.
+  #[serde(default)]
+  pub verification_options: JwsVerificationOptions,
+
+  /// The clock providing the current datetime against which the validity period of the attestation is checked.
+  ///
+  /// Default: [`SystemClock`](identity_core::common::SystemClock).
+  #[serde(skip)]
+  pub clock: SharedClock,
+}
+
+impl AttestationVerificationOptions {
+  /// Constructor that sets all options to their defaults.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Set options which affect the verification of the signature on the attestation.
+  pub fn verification_options(mut self, options: JwsVerificationOptions) -> Self {
+    self.verification_options = options;
+    self
+  }
+
+  /// Set the clock providing the current datetime against which the validity period of the attestation is checked.
+  pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+    self.clock = SharedClock::new(clock);
+    self
+  }
+}
+
+impl IotaDocument {
+  /// Returns the canonical serialization of this document, including its metadata, as signed by a
+  /// [`DocumentAttestation`].
+  ///
+  /// The document is serialized as JSON without insignificant whitespace and with the members of every object sorted
+  /// by key, making the output independent of how the document was built or deserialized.
+  pub fn canonical_snapshot(&self) -> Result<Vec<u8>> {
+    self
+      .to_canonical_json_vec()
+      .map_err(|err| Error::SerializationError("failed to serialize the document", Some(err)))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+  use identity_verification::MethodScope;
+  use product_common::network_name::NetworkName;
+  use serde_json::Map;
+  use serde_json::Value;
+
+  use super::*;
+  use crate::document::test_utils::generate_method;
+
+  #[test]
+  fn canonical_snapshot_is_independent_of_key_order() {
+    let mut document: IotaDocument = IotaDocument::new(&NetworkName::try_from("iota").unwrap());
+    let method = generate_method(document.id(), "key-1");
+    document.insert_method(method, MethodScope::VerificationMethod).unwrap();
+    let json: Value = document.to_json_value().unwrap();
+    let Value::Object(object) = json else { unreachable!() };
+    let reversed: Map<String, Value> = object.into_iter().rev().collect();
+    let roundtrip: IotaDocument = IotaDocument::from_json_value(Value::Object(reversed)).unwrap();
+
+    let snapshot: Vec<u8> = document.canonical_snapshot().unwrap();
+    assert_eq!(snapshot, roundtrip.canonical_snapshot().unwrap());
+  }
+}
@@ -0,0 +1,319 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt::Display;
+use core::fmt::Formatter;
+use std::collections::HashSet;
+
+use identity_core::common::Url;
+use identity_did::CoreDID;
+use identity_did::DIDUrl;
+use identity_did::DID;
+use identity_document::service::ServiceEndpoint;
+use identity_verification::MethodData;
+use identity_verification::MethodRef;
+use identity_verification::MethodRelationship;
+use identity_verification::MethodScope;
+use identity_verification::MethodType;
+use identity_verification::VerificationMethod;
+
+use crate::IotaDID;
+use crate::IotaDocument;
+
+/// URL schemes that require an authority component.
+const HOST_SCHEMES: [&str; 4] = ["http", "https", "ws", "wss"];
+
+/// An inconsistency found in an [`IotaDocument`] by [`IotaDocument::verify_integrity`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IntegrityFinding {
+  /// A verification relationship references a verification method that is not part of the document.
+  DanglingMethodReference {
+    /// The verification relationship containing the reference.
+    relationship: MethodRelationship,
+    /// The referenced method.
+    method: DIDUrl,
+  },
+  /// Multiple verification methods or services share the same identifier.
+  DuplicateId(DIDUrl),
+  /// The identifier of a verification method or service has no fragment.
+  MissingFragment(DIDUrl),
+  /// A controller of the document is not a valid controller for an IOTA DID document.
+  InvalidController {
+    /// The invalid controller.
+    controller: CoreDID,
+    /// Why the controller is invalid.
+    reason: String,
+  },
+  /// The key material of a verification method cannot be parsed according to the method's type.
+  InvalidKeyMaterial {
+    /// The identifier of the verification method.
+    method: DIDUrl,
+    /// Why the key material is invalid.
+    reason: String,
+  },
+  /// A URL in `alsoKnownAs` or in a service endpoint is malformed.
+  InvalidUrl {
+    /// The malformed URL.
+    url: Url,
+    /// Why the URL is malformed.
+    reason: String,
+  },
+}
+
+impl Display for IntegrityFinding {
+  fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+    match self {
+      Self::DanglingMethodReference { relationship, method } => write!(
+        f,
+        "{} references method {method}, which is not part of the document",
+        MethodScope::VerificationRelationship(*relationship).as_str()
+      ),
+      Self::DuplicateId(id) => write!(f, "identifier {id} is used more than once"),
+      Self::MissingFragment(id) => write!(f, "identifier {id} has no fragment"),
+      Self::InvalidController { controller, reason } => write!(f, "invalid controller {controller}: {reason}"),
+      Self::InvalidKeyMaterial { method, reason } => write!(f, "invalid key material in method {method}: {reason}"),
+      Self::InvalidUrl { url, reason } => write!(f, "invalid URL {url}: {reason}"),
+    }
+  }
+}
+
+impl IotaDocument {
+  /// Performs cross-field consistency checks on this document, returning every problem found.
+  ///
+  /// The following is checked:
+  /// - every verification method reference resolves to a method of the document,
+  /// - the identifiers of verification methods and services have a fragment and are unique,
+  /// - controllers are IOTA DIDs on the same network as this document,
+  /// - the key material of every method can be parsed according to the method's type and contains no private key,
+  /// - DID URLs in `alsoKnownAs` and in service endpoints are valid, and web URLs have a host.
+  ///
+  /// An empty list means no problem was found. Running this check before publishing an update prevents
+  /// broken documents from ending up on-chain.
+  pub fn verify_integrity(&self) -> Vec<IntegrityFinding> {
+    let mut findings = Vec::new();
+    self.check_method_references(&mut findings);
+    self.check_ids(&mut findings);
+    self.check_controllers(&mut findings);
+    for method in self.methods(None) {
+      check_key_material(method, &mut findings);
+    }
+    self.check_urls(&mut findings);
+
+    findings
+  }
+
+  fn check_method_references(&self, findings: &mut Vec<IntegrityFinding>) {
+    let document = self.core_document();
+    let relationships = [
+      (MethodRelationship::Authentication, document.authentication()),
+      (MethodRelationship::AssertionMethod, document.assertion_method()),
+      (MethodRelationship::KeyAgreement, document.key_agreement()),
+      (
+        MethodRelationship::CapabilityDelegation,
+        document.capability_delegation(),
+      ),
+      (
+        MethodRelationship::CapabilityInvocation,
+        document.capability_invocation(),
+      ),
+    ];
+
+    for (relationship, method_refs) in relationships {
+      for method_ref in method_refs.iter() {
+        if let MethodRef::Refer(method) = method_ref {
+          if document.resolve_method_ref(method_ref).is_none() {
+            findings.push(IntegrityFinding::DanglingMethodReference {
+              relationship,
+              method: method.clone(),
+            });
+          }
+        }
+      }
+    }
+  }
+
+  fn check_ids(&self, findings: &mut Vec<IntegrityFinding>) {
+    let method_ids = self.methods(None).into_iter().map(VerificationMethod::id);
+    let service_ids = self.service().iter().map(|service| service.id());
+
+    let mut seen: HashSet<&DIDUrl> = HashSet::new();
+    let mut duplicates: HashSet<&DIDUrl> = HashSet::new();
+    for id in method_ids.chain(service_ids) {
+      if id.fragment().is_none() {
+        findings.push(IntegrityFinding::MissingFragment(id.clone()));
+      }
+      if !seen.insert(id) && duplicates.insert(id) {
+        findings.push(IntegrityFinding::DuplicateId(id.clone()));
+      }
+    }
+  }
+
+  fn check_controllers(&self, findings: &mut Vec<IntegrityFinding>) {
+    let Some(controllers) = self.core_document().controller() else {
+      return;
+    };
+
+    for controller in controllers.iter() {
+      let reason = match IotaDID::check_validity(controller) {
+        Err(err) => err.to_string(),
+        Ok(()) => {
+          let network = IotaDID::from_inner_ref_unchecked(controller).network_str();
+          if network == self.id().network_str() {
+            continue;
+          }
+          format!(
+            "controller is on network \"{network}\" but the document is on network \"{}\"",
+            self.id().network_str()
+          )
+        }
+      };
+      findings.push(IntegrityFinding::InvalidController {
+        controller: controller.clone(),
+        reason,
+      });
+    }
+  }
+
+  fn check_urls(&self, findings: &mut Vec<IntegrityFinding>) {
+    for url in self.also_known_as().iter() {
+      check_url(url, findings);
+    }
+
+    for service in self.service().iter() {
+      match service.service_endpoint() {
+        ServiceEndpoint::One(url) => check_url(url, findings),
+        ServiceEndpoint::Set(urls) => urls.iter().for_each(|url| check_url(url, findings)),
+        ServiceEndpoint::Map(map) => map.values().flatten().for_each(|url| check_url(url, findings)),
+      }
+    }
+  }
+}
+
+fn check_key_material(method: &VerificationMethod, findings: &mut Vec<IntegrityFinding>) {
+  let type_ = method.type_();
+  let reason = match method.data() {
+    MethodData::PublicKeyJwk(jwk) if !jwk.is_public() => Some("the JWK contains private key material".to_owned()),
+    MethodData::PublicKeyJwk(_) if *type_ == MethodType::MULTIKEY => {
+      Some("Multikey methods require publicKeyMultibase key material".to_owned())
+    }
+    _ if *type_ == MethodType::JSON_WEB_KEY || *type_ == MethodType::JSON_WEB_KEY_2020 => {
+      (!matches!(method.data(), MethodData::PublicKeyJwk(_)))
+        .then(|| format!("{type_} methods require publicKeyJwk key material"))
+    }
+    _ if *type_ == MethodType::MULTIKEY
+      || *type_ == MethodType::ED25519_VERIFICATION_KEY_2018
+      || *type_ == MethodType::X25519_KEY_AGREEMENT_KEY_2019 =>
+    {
+      method.to_jwk().err().map(|err| err.to_string())
+    }
+    _ => None,
+  };
+
+  if let Some(reason) = reason {
+    findings.push(IntegrityFinding::InvalidKeyMaterial {
+      method: method.id().clone(),
+      reason,
+    });
+  }
+}
+
+fn check_url(url: &Url, findings: &mut Vec<IntegrityFinding>) {
+  let reason = if url.scheme() == "did" {
+    DIDUrl::parse(url.as_str()).err().map(|err| err.to_string())
+  } else if HOST_SCHEMES.contains(&url.scheme()) && url.host_str().map_or(true, str::is_empty) {
+    Some(format!("{} URLs require a host", url.scheme()))
+  } else {
+    None
+  };
+
+  if let Some(reason) = reason {
+    findings.push(IntegrityFinding::InvalidUrl {
+      url: url.clone(),
+      reason,
+    });
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::common::Object;
+  use identity_core::convert::FromJson;
+  use identity_document::service::Service;
+  use identity_verification::jwk::Jwk;
+  use serde_json::json;
+
+  use super::*;
+  use crate::test_utils::generate_method;
+
+  fn document() -> IotaDocument {
+    let did = IotaDID::parse("did:iota:0x0101010101010101010101010101010101010101010101010101010101010101").unwrap();
+    let mut document = IotaDocument::new_with_id(did.clone());
+    document
+      .insert_method(generate_method(&did, "key-1"), MethodScope::VerificationMethod)
+      .unwrap();
+    document
+      .insert_service(
+        Service::builder(Object::new())
+          .id(did.to_url().join("#linked-domains").unwrap())
+          .type_("LinkedDomains")
+          .service_endpoint(Url::parse("https://iota.org").unwrap())
+          .build()
+          .unwrap(),
+      )
+      .unwrap();
+    document
+  }
+
+  #[test]
+  fn consistent_document_has_no_findings() {
+    assert_eq!(document().verify_integrity(), vec![]);
+  }
+
+  #[test]
+  fn inconsistencies_are_reported() {
+    let did = "did:iota:0x0101010101010101010101010101010101010101010101010101010101010101";
+    let mut document = IotaDocument::from_json_value(json!({
+      "doc": {
+        "id": did,
+        "authentication": [format!("{did}#missing")],
+        "alsoKnownAs": ["did:invalid"]
+      },
+      "meta": {}
+    }))
+    .unwrap();
+
+    let other_network =
+      IotaDID::parse("did:iota:smr:0x0202020202020202020202020202020202020202020202020202020202020202").unwrap();
+    document.set_controller([other_network.clone()]);
+
+    let jwk = Jwk::from_json_value(json!({
+      "kty": "OKP",
+      "crv": "Ed25519",
+      "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo",
+      "d": "nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A"
+    }))
+    .unwrap();
+    let mut method = generate_method(document.id(), "private");
+    *method.data_mut() = MethodData::PublicKeyJwk(jwk);
+    document.insert_method(method, MethodScope::VerificationMethod).unwrap();
+
+    let findings = document.verify_integrity();
+    assert_eq!(findings.len(), 4);
+    assert!(findings.contains(&IntegrityFinding::DanglingMethodReference {
+      relationship: MethodRelationship::Authentication,
+      method: DIDUrl::parse(format!("{did}#missing")).unwrap(),
+    }));
+    assert!(findings.iter().any(|finding| matches!(
+      finding,
+      IntegrityFinding::InvalidController { controller, .. } if controller.as_str() == other_network.as_str()
+    )));
+    assert!(findings.iter().any(|finding| matches!(
+      finding,
+      IntegrityFinding::InvalidKeyMaterial { method, .. } if method.fragment() == Some("private")
+    )));
+    assert!(findings
+      .iter()
+      .any(|finding| matches!(finding, IntegrityFinding::InvalidUrl { .. })));
+  }
+}
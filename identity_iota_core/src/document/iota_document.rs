@@ -8,6 +8,9 @@ use identity_credential::credential::Jws;
 use identity_did::CoreDID;
 use identity_did::DIDUrl;
 use identity_document::verifiable::JwsVerificationOptions;
+use identity_document::verifiable::PopChallenge;
+use identity_document::verifiable::PopClaims;
+use identity_document::verifiable::PopVerificationOptions;
 use identity_verification::jose::jws::DecodedJws;
 use identity_verification::jose::jws::JwsVerifier;
 use product_common::network_name::NetworkName;
@@ -20,6 +23,8 @@ use identity_core::common::OrderedSet;
 use identity_core::common::Url;
 use identity_core::convert::FmtJson;
 use identity_document::document::CoreDocument;
+use identity_document::document::DocumentMetadata;
+use identity_document::document::ProvidesDocumentMetadata;
 use identity_document::service::Service;
 use identity_document::utils::DIDUrlQuery;
 use identity_verification::MethodRelationship;
@@ -378,6 +383,22 @@ impl IotaDocument {
       .map_err(Error::JwsVerificationError)
   }
 
+  /// Decodes and verifies a proof of possession answering `challenge`.
+  ///
+  /// See [`CoreDocument::verify_pop`].
+  pub fn verify_pop<T: JwsVerifier>(
+    &self,
+    pop: &Jws,
+    challenge: &PopChallenge,
+    signature_verifier: &T,
+    options: &PopVerificationOptions,
+  ) -> Result<PopClaims> {
+    self
+      .core_document()
+      .verify_pop(pop.as_str(), challenge, signature_verifier, options)
+      .map_err(Error::JwsVerificationError)
+  }
+
   // ===========================================================================
   // Packing
   // ===========================================================================
@@ -477,9 +498,13 @@ mod client_document {
         StateMetadataDocument::unpack(data).and_then(|state_metadata_doc| state_metadata_doc.into_iota_document(did))?
       };
 
-      // Set the `alsoKnownAs` property if a legacy DID is present.
+      // Set the `alsoKnownAs` property if a legacy DID is present, which is equivalent to `did`.
       if let Some(alternative_did) = alternative_did {
-        did_doc.also_known_as_mut().prepend(alternative_did.into_url().into());
+        did_doc
+          .also_known_as_mut()
+          .prepend(alternative_did.clone().into_url().into());
+        did_doc.metadata.equivalent_id = vec![alternative_did];
+        did_doc.metadata.canonical_id = Some(did.clone());
       }
 
       // Overwrite `created` and `updated` with given timestamps
@@ -491,6 +516,12 @@ mod client_document {
   }
 }
 
+impl ProvidesDocumentMetadata for IotaDocument {
+  fn document_metadata(&self) -> DocumentMetadata {
+    self.metadata.to_document_metadata()
+  }
+}
+
 impl AsRef<CoreDocument> for IotaDocument {
   fn as_ref(&self) -> &CoreDocument {
     &self.document
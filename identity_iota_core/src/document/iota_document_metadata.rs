@@ -5,12 +5,19 @@ use core::fmt::Debug;
 use core::fmt::Display;
 use core::fmt::Formatter;
 
+use identity_core::common::Clock;
 use identity_core::common::Object;
+use identity_core::common::SystemClock;
 use identity_core::common::Timestamp;
 use identity_core::convert::FmtJson;
+use identity_did::CoreDID;
+use identity_document::document::DocumentMetadata;
+use iota_sdk_types::ObjectId;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::IotaDID;
+
 /// Additional attributes related to a [`IotaDocument`][crate::IotaDocument].
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct IotaDocumentMetadata {
@@ -23,6 +30,33 @@ pub struct IotaDocumentMetadata {
   /// Signals whether the document is deactivated.
   #[serde(skip_serializing_if = "Option::is_none")]
   pub deactivated: Option<bool>,
+  /// The version of the document, i.e. the version of the Identity object it was read from.
+  ///
+  /// Only set on documents resolved at a specific version or point in time, see
+  /// [`IdentityClientReadOnly::resolve_did_version`](crate::rebased::client::IdentityClientReadOnly::resolve_did_version).
+  #[serde(rename = "versionId", skip_serializing_if = "Option::is_none")]
+  pub version_id: Option<String>,
+  /// The timestamp of the update that superseded this version of the document, if any.
+  ///
+  /// Only set on documents resolved at a specific version or point in time.
+  #[serde(rename = "nextUpdate", skip_serializing_if = "Option::is_none")]
+  pub next_update: Option<Timestamp>,
+  /// The version of the document that superseded this one, if any.
+  ///
+  /// Only set on documents resolved at a specific version or point in time.
+  #[serde(rename = "nextVersionId", skip_serializing_if = "Option::is_none")]
+  pub next_version_id: Option<String>,
+  /// The DIDs that are equivalent to the DID of the document, i.e. the legacy DID an Identity was migrated from or,
+  /// when resolving a legacy DID, the DID of the Identity it was migrated to.
+  #[serde(rename = "equivalentId", default, skip_serializing_if = "Vec::is_empty")]
+  pub equivalent_id: Vec<IotaDID>,
+  /// The canonical DID of the document, i.e. the DID of the Identity holding it, if the document also has
+  /// equivalent DIDs.
+  #[serde(rename = "canonicalId", skip_serializing_if = "Option::is_none")]
+  pub canonical_id: Option<IotaDID>,
+  /// The log of the updates to the document, if provenance recording is enabled.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  provenance: Option<Vec<ProvenanceEntry>>,
   #[serde(flatten)]
   properties: Object,
 }
@@ -31,11 +65,23 @@ impl IotaDocumentMetadata {
   /// Creates a new `IotaDocumentMetadata` with the current system datetime used for `created`
   /// and `updated` timestamps.
   pub fn new() -> Self {
-    let now: Timestamp = Timestamp::now_utc();
+    Self::new_with_clock(&SystemClock)
+  }
+
+  /// Creates a new `IotaDocumentMetadata` with the current datetime of `clock` used for `created`
+  /// and `updated` timestamps.
+  pub fn new_with_clock(clock: &dyn Clock) -> Self {
+    let now: Timestamp = clock.now();
     Self {
       created: Some(now),
       updated: Some(now),
       deactivated: None,
+      version_id: None,
+      next_update: None,
+      next_version_id: None,
+      equivalent_id: Vec::new(),
+      canonical_id: None,
+      provenance: None,
       properties: Object::default(),
     }
   }
@@ -49,6 +95,60 @@ impl IotaDocumentMetadata {
   pub fn properties_mut(&mut self) -> &mut Object {
     &mut self.properties
   }
+
+  /// Returns the standard [`DocumentMetadata`] described by this metadata.
+  pub fn to_document_metadata(&self) -> DocumentMetadata {
+    let mut metadata: DocumentMetadata = DocumentMetadata::new();
+    metadata.created = self.created;
+    metadata.updated = self.updated;
+    metadata.deactivated = self.deactivated;
+    metadata.next_update = self.next_update;
+    metadata.version_id = self.version_id.clone();
+    metadata.next_version_id = self.next_version_id.clone();
+    metadata.equivalent_id = self.equivalent_id.iter().cloned().map(CoreDID::from).collect();
+    metadata.canonical_id = self.canonical_id.clone().map(CoreDID::from);
+    metadata
+  }
+
+  /// Clears the properties that describe the document as resolved from the ledger, which must not be published
+  /// with it.
+  pub(crate) fn clear_resolution_metadata(&mut self) {
+    self.version_id = None;
+    self.next_update = None;
+    self.next_version_id = None;
+    self.equivalent_id.clear();
+    self.canonical_id = None;
+  }
+
+  /// Enables provenance recording: once the document is published, every update of the document performed through
+  /// an `UpdateDidDocument` proposal appends a [`ProvenanceEntry`] to [`IotaDocumentMetadata::history`].
+  ///
+  /// Enabling provenance recording on a document that already records it has no effect.
+  pub fn enable_provenance(&mut self) {
+    self.provenance.get_or_insert_with(Vec::new);
+  }
+
+  /// Returns whether provenance recording is enabled.
+  pub fn is_provenance_enabled(&self) -> bool {
+    self.provenance.is_some()
+  }
+
+  /// Returns the log of the updates to the document, oldest first.
+  ///
+  /// The log is empty if provenance recording is not enabled.
+  pub fn history(&self) -> &[ProvenanceEntry] {
+    self.provenance.as_deref().unwrap_or_default()
+  }
+
+  /// Replaces the provenance log.
+  pub(crate) fn set_history(&mut self, history: Vec<ProvenanceEntry>) {
+    self.provenance = Some(history);
+  }
+
+  /// Returns a mutable reference to the latest provenance entry.
+  pub(crate) fn latest_provenance_entry_mut(&mut self) -> Option<&mut ProvenanceEntry> {
+    self.provenance.as_mut().and_then(|history| history.last_mut())
+  }
 }
 
 impl Default for IotaDocumentMetadata {
@@ -62,3 +162,69 @@ impl Display for IotaDocumentMetadata {
     self.fmt_json(f)
   }
 }
+
+/// An entry of the provenance log of an IOTA DID Document, describing one of its updates.
+///
+/// The entry is written together with the updated document, so that the proposal and transaction that carried out
+/// the update are not known yet at that time. They are filled in by the client that applies the update and stored
+/// on-chain with the next update of the document.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ProvenanceEntry {
+  /// The ID of the controller capability used to propose the update.
+  pub controller: ObjectId,
+  /// The time at which the update was proposed.
+  pub timestamp: Timestamp,
+  /// The ID of the proposal that carried out the update, if known.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub proposal: Option<ObjectId>,
+  /// The digest of the transaction that carried out the update, if known.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub tx_digest: Option<String>,
+}
+
+impl ProvenanceEntry {
+  /// Creates a new [`ProvenanceEntry`] for an update proposed by `controller` at `timestamp`.
+  pub fn new(controller: ObjectId, timestamp: Timestamp) -> Self {
+    Self {
+      controller,
+      timestamp,
+      proposal: None,
+      tx_digest: None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+  use identity_core::convert::ToJson;
+
+  use super::*;
+
+  #[test]
+  fn provenance_is_opt_in() {
+    let mut metadata = IotaDocumentMetadata::new();
+    assert!(!metadata.is_provenance_enabled());
+    assert!(!metadata.to_json().unwrap().contains("provenance"));
+
+    metadata.enable_provenance();
+    let entry = ProvenanceEntry::new(ObjectId::ZERO, Timestamp::now_utc());
+    metadata.set_history(vec![entry.clone()]);
+    metadata.enable_provenance();
+    assert_eq!(metadata.history(), [entry]);
+
+    let deserialized = IotaDocumentMetadata::from_json(&metadata.to_json().unwrap()).unwrap();
+    assert_eq!(deserialized, metadata);
+    assert!(deserialized.properties().is_empty());
+  }
+
+  #[test]
+  fn timestamps_are_taken_from_the_clock() {
+    let timestamp = Timestamp::parse("2010-01-01T00:00:00Z").unwrap();
+    let metadata = IotaDocumentMetadata::new_with_clock(&identity_core::common::FixedClock::new(timestamp));
+    assert_eq!(metadata.created, Some(timestamp));
+    assert_eq!(metadata.updated, Some(timestamp));
+  }
+}
@@ -1,9 +1,15 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+pub use attestation::AttestationVerificationOptions;
+pub use attestation::DocumentAttestation;
+pub use integrity::IntegrityFinding;
 pub use iota_document::IotaDocument;
 pub use iota_document_metadata::IotaDocumentMetadata;
+pub use iota_document_metadata::ProvenanceEntry;
 
+mod attestation;
+mod integrity;
 mod iota_document;
 mod iota_document_metadata;
 
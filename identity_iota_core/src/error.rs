@@ -48,4 +48,24 @@ pub enum Error {
   /// Caused by an error during JSON Web Signature verification.
   #[error("jws signature verification failed")]
   JwsVerificationError(#[source] identity_document::Error),
+  /// Caused by an invalid document attestation.
+  #[error("invalid document attestation: {0}")]
+  InvalidAttestation(&'static str),
 }
+
+identity_core::impl_error_code!(Error {
+  SerializationError => 2000,
+  DIDSyntaxError => 2001,
+  InvalidDoc => 2002,
+  DIDResolutionError => 2003,
+  InvalidNetworkName => 2004,
+  NetworkMismatch => 2005,
+  InvalidStateMetadata => 2006,
+  #[cfg(feature = "revocation-bitmap")]
+  RevocationError => 2007,
+  OutputIdConversionError => 2008,
+  #[cfg(all(target_arch = "wasm32", not(target_os = "wasi")))]
+  JsError => 2009,
+  JwsVerificationError => 2010,
+  InvalidAttestation => 2011,
+});
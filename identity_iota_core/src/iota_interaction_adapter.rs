@@ -5,11 +5,13 @@
 // ...Adapter types from iota_interaction_rust or iota_interaction_ts
 // like IotaClientAdapter, AssetMoveCallsAdapter, IdentityMoveCallsAdapter,
 // TransactionBuilderAdapter, MigrationMoveCallsAdapter, ... and so on
+//
+// WASI targets have neither a JavaScript host nor a native IOTA client, hence no adapters are provided there.
 
 cfg_if::cfg_if! {
-    if #[cfg(target_arch = "wasm32")] {
+    if #[cfg(all(target_arch = "wasm32", not(target_os = "wasi")))] {
         pub(crate) use iota_interaction_ts::*;
-    } else {
+    } else if #[cfg(not(target_arch = "wasm32"))] {
         pub(crate) use iota_interaction_rust::*;
     }
 }
@@ -14,9 +14,20 @@
 )]
 #![allow(clippy::upper_case_acronyms)]
 
+#[cfg(all(feature = "iota-client", target_os = "wasi"))]
+compile_error!(
+  "feature `iota-client` is not supported on WASI targets, disable the default features of `identity_iota_core`"
+);
+
 pub use did::IotaDID;
 #[cfg(feature = "iota-client")]
 pub use did_resolution::DidResolutionHandler;
+#[cfg(feature = "iota-client")]
+pub use did_resolution::KeyDirectory;
+#[cfg(feature = "iota-client")]
+pub use did_resolution::OfflineKeyDirectory;
+#[cfg(feature = "iota-client")]
+pub use did_resolution::OnChainKeyDirectory;
 pub use document::*;
 pub use state_metadata::*;
 
@@ -30,6 +41,7 @@ mod state_metadata;
 
 #[cfg(feature = "iota-client")]
 mod did_resolution;
+#[cfg(feature = "iota-client")]
 mod iota_interaction_adapter;
 
 #[cfg(feature = "iota-client")]
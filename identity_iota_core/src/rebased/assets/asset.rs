@@ -5,16 +5,23 @@ use std::str::FromStr as _;
 
 use crate::rebased::client::IdentityClientReadOnly;
 use crate::rebased::iota::move_calls;
+use crate::rebased::iota::package::identity_package_history;
 
 use crate::rebased::Error;
 use anyhow::anyhow;
 use anyhow::Context;
 use async_trait::async_trait;
+use futures::Stream;
+use futures::TryStreamExt as _;
 
 use iota_interaction::ident_str;
+use iota_interaction::rpc_types::EventFilter;
 use iota_interaction::rpc_types::IotaData as _;
 use iota_interaction::rpc_types::IotaExecutionStatus;
+use iota_interaction::rpc_types::IotaObjectData;
+use iota_interaction::rpc_types::IotaObjectDataFilter;
 use iota_interaction::rpc_types::IotaObjectDataOptions;
+use iota_interaction::rpc_types::IotaObjectResponseQuery;
 use iota_interaction::rpc_types::IotaTransactionBlockEffects;
 use iota_interaction::rpc_types::IotaTransactionBlockEffectsAPI as _;
 use iota_interaction::types::base_types::ObjectRef;
@@ -84,6 +91,21 @@ where
     let Some(data) = res.data else {
       return Err(Error::ObjectLookup(res.error.map_or(String::new(), |e| e.to_string())));
     };
+    Self::from_object_data(data)
+  }
+
+  /// Same as [`AuthenticatedAsset::get_by_id`] but returns `None` if the asset doesn't exist (anymore).
+  async fn maybe_get_by_id(id: ObjectId, client: &impl CoreClientReadOnly) -> Result<Option<Self>, Error> {
+    let res = client
+      .client_adapter()
+      .read_api()
+      .get_object_with_options(id, IotaObjectDataOptions::new().with_content())
+      .await?;
+    res.data.map(Self::from_object_data).transpose()
+  }
+
+  fn from_object_data(data: IotaObjectData) -> Result<Self, Error> {
+    let id = data.object_id;
     data
       .content
       .ok_or_else(|| anyhow!("No content for object with ID {id}"))
@@ -95,17 +117,197 @@ where
   }
 }
 
-impl<T: MoveType + Send + Sync> AuthenticatedAsset<T> {
-  async fn object_ref(&self, client: &impl CoreClientReadOnly) -> Result<ObjectRef, Error> {
-    client
-      .client_adapter()
-      .read_api()
-      .get_object_with_options(self.id(), IotaObjectDataOptions::default())
-      .await?
-      .object_ref_if_exists()
-      .ok_or_else(|| Error::ObjectLookup("missing object reference in response".to_owned()))
+impl<T> AuthenticatedAsset<T>
+where
+  T: MoveType + DeserializeOwned,
+{
+  /// Returns all the [`AuthenticatedAsset`]s with a content of type `T` owned by `owner`.
+  /// # Notes
+  /// For a streaming version of this API see [find_by_owner_streamed](Self::find_by_owner_streamed).
+  pub async fn find_by_owner<C>(owner: Address, client: &C) -> Result<Vec<Self>, Error>
+  where
+    C: CoreClientReadOnly + OptionalSync,
+  {
+    Self::find_by_owner_streamed(owner, client).try_collect().await
+  }
+
+  /// Streams the [`AuthenticatedAsset`]s with a content of type `T` owned by `owner`,
+  /// lazily fetching new pages of results when needed.
+  pub fn find_by_owner_streamed<'c, C>(owner: Address, client: &'c C) -> impl Stream<Item = Result<Self, Error>> + 'c
+  where
+    C: CoreClientReadOnly + OptionalSync,
+    T: 'c,
+  {
+    Self::owned_by(owner, T::move_type, client)
+  }
+
+  /// Returns all the existing [`AuthenticatedAsset`]s with a content of type `T` created by `origin`,
+  /// regardless of their current owner.
+  /// # Notes
+  /// Assets are discovered through the `AssetCreated` events emitted by the IotaIdentity package, therefore
+  /// assets created with a version of the package that didn't emit such events are not returned.
+  /// For a streaming version of this API see [find_by_origin_streamed](Self::find_by_origin_streamed).
+  pub async fn find_by_origin<C>(origin: Address, client: &C) -> Result<Vec<Self>, Error>
+  where
+    C: CoreClientReadOnly + OptionalSync,
+  {
+    Self::find_by_origin_streamed(origin, client).try_collect().await
+  }
+
+  /// Streams the existing [`AuthenticatedAsset`]s with a content of type `T` created by `origin`,
+  /// lazily fetching new pages of results when needed.
+  /// See [find_by_origin](Self::find_by_origin) for more details.
+  pub fn find_by_origin_streamed<'c, C>(origin: Address, client: &'c C) -> impl Stream<Item = Result<Self, Error>> + 'c
+  where
+    C: CoreClientReadOnly + OptionalSync,
+    T: 'c,
+  {
+    Self::created_by(origin, T::move_type, client)
+  }
+}
+
+impl<T> AuthenticatedAsset<T>
+where
+  T: DeserializeOwned,
+{
+  /// Returns all the [`AuthenticatedAsset`]s owned by `owner` whose content has Move type `content_type`.
+  /// Useful when `T` is a dynamic representation of the content, e.g. [`serde_json::Value`].
+  pub async fn find_by_owner_with_content_type<C>(
+    owner: Address,
+    content_type: TypeTag,
+    client: &C,
+  ) -> Result<Vec<Self>, Error>
+  where
+    C: CoreClientReadOnly + OptionalSync,
+  {
+    Self::owned_by(owner, move |_| content_type.clone(), client)
+      .try_collect()
+      .await
+  }
+
+  /// Returns all the existing [`AuthenticatedAsset`]s created by `origin` whose content has Move type `content_type`.
+  /// See [find_by_origin](Self::find_by_origin) for more details.
+  pub async fn find_by_origin_with_content_type<C>(
+    origin: Address,
+    content_type: TypeTag,
+    client: &C,
+  ) -> Result<Vec<Self>, Error>
+  where
+    C: CoreClientReadOnly + OptionalSync,
+  {
+    Self::created_by(origin, move |_| content_type.clone(), client)
+      .try_collect()
+      .await
+  }
+
+  /// Streams the assets owned by `owner`, where `content_type` returns the type of the assets' content
+  /// for a given package version.
+  fn owned_by<'c, C, F>(owner: Address, content_type: F, client: &'c C) -> impl Stream<Item = Result<Self, Error>> + 'c
+  where
+    C: CoreClientReadOnly + OptionalSync,
+    F: Fn(ObjectId) -> TypeTag + 'c,
+    T: 'c,
+  {
+    async_stream::try_stream! {
+      // Match assets created with any version of the package.
+      let type_filters = identity_package_history(client)
+        .await?
+        .into_iter()
+        .map(|package| IotaObjectDataFilter::StructType(asset_struct_tag(package, content_type(package))))
+        .collect();
+      let query = IotaObjectResponseQuery::new(
+        Some(IotaObjectDataFilter::MatchAny(type_filters)),
+        Some(IotaObjectDataOptions::default().with_content()),
+      );
+
+      let mut cursor = None;
+      loop {
+        let page = client
+          .client_adapter()
+          .read_api()
+          .get_owned_objects(owner, Some(query.clone()), cursor, None)
+          .await
+          .map_err(|e| Error::RpcError(e.to_string()))?;
+        for obj_data in page.data.into_iter().filter_map(|res| res.data) {
+          yield Self::from_object_data(obj_data)?;
+        }
+
+        if !page.has_next_page || page.next_cursor.is_none() {
+          break;
+        }
+        cursor = page.next_cursor;
+      }
+    }
   }
 
+  /// Streams the existing assets created by `origin`, where `content_type` returns the type of the assets' content
+  /// for a given package version.
+  fn created_by<'c, C, F>(
+    origin: Address,
+    content_type: F,
+    client: &'c C,
+  ) -> impl Stream<Item = Result<Self, Error>> + 'c
+  where
+    C: CoreClientReadOnly + OptionalSync,
+    F: Fn(ObjectId) -> TypeTag + 'c,
+    T: 'c,
+  {
+    #[derive(Deserialize)]
+    struct AssetCreatedEvent {
+      asset: ObjectId,
+      origin: Address,
+    }
+
+    async_stream::try_stream! {
+      for package in identity_package_history(client).await? {
+        let event_type = StructTag::new(
+          package,
+          ident_str!("asset").as_str(),
+          ident_str!("AssetCreated").as_str(),
+          vec![content_type(package)],
+        );
+
+        let mut cursor = None;
+        loop {
+          let page = client
+            .client_adapter()
+            .event_api()
+            .query_events(EventFilter::MoveEventType(event_type.clone()), cursor, None, false)
+            .await
+            .map_err(|e| Error::RpcError(e.to_string()))?;
+          for event in page.data {
+            let Ok(event) = serde_json::from_value::<AssetCreatedEvent>(event.parsed_json) else {
+              continue;
+            };
+            if event.origin != origin {
+              continue;
+            }
+            // Deleted assets are skipped.
+            if let Some(asset) = Self::maybe_get_by_id(event.asset, client).await? {
+              yield asset;
+            }
+          }
+
+          if !page.has_next_page || page.next_cursor.is_none() {
+            break;
+          }
+          cursor = page.next_cursor;
+        }
+      }
+    }
+  }
+}
+
+fn asset_struct_tag(package: ObjectId, content_type: TypeTag) -> StructTag {
+  StructTag::new(
+    package,
+    ident_str!("asset").as_str(),
+    ident_str!("AuthenticatedAsset").as_str(),
+    vec![content_type],
+  )
+}
+
+impl<T> AuthenticatedAsset<T> {
   /// Returns this [`AuthenticatedAsset`]'s ID.
   pub fn id(&self) -> ObjectId {
     *self.id.object_id()
@@ -116,6 +318,28 @@ impl<T: MoveType + Send + Sync> AuthenticatedAsset<T> {
     &self.inner
   }
 
+  /// Returns the address that currently owns this [`AuthenticatedAsset`].
+  pub fn owner(&self) -> Address {
+    self.owner
+  }
+
+  /// Returns the address that created this [`AuthenticatedAsset`].
+  pub fn origin(&self) -> Address {
+    self.origin
+  }
+}
+
+impl<T: MoveType + Send + Sync> AuthenticatedAsset<T> {
+  async fn object_ref(&self, client: &impl CoreClientReadOnly) -> Result<ObjectRef, Error> {
+    client
+      .client_adapter()
+      .read_api()
+      .get_object_with_options(self.id(), IotaObjectDataOptions::default())
+      .await?
+      .object_ref_if_exists()
+      .ok_or_else(|| Error::ObjectLookup("missing object reference in response".to_owned()))
+  }
+
   /// Transfers ownership of this [`AuthenticatedAsset`] to `recipient`.
   /// # Notes
   /// This function doesn't perform the transfer right away, but instead creates a [`Transaction`] that
@@ -186,12 +410,7 @@ pub struct AuthenticatedAssetBuilder<T> {
 
 impl<T: MoveType> MoveType for AuthenticatedAsset<T> {
   fn move_type(package: ObjectId) -> TypeTag {
-    TypeTag::Struct(Box::new(StructTag::new(
-      package,
-      ident_str!("asset").as_str(),
-      ident_str!("AuthenticatedAsset").as_str(),
-      vec![T::move_type(package)],
-    )))
+    TypeTag::Struct(Box::new(asset_struct_tag(package, T::move_type(package))))
   }
 }
 
@@ -0,0 +1,160 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use iota_interaction::rpc_types::IotaObjectDataOptions;
+use iota_interaction::IotaClientTrait;
+use iota_sdk_types::Address;
+use iota_sdk_types::ObjectId;
+use iota_sdk_types::Owner;
+use product_common::core_client::CoreClientReadOnly;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::rebased::migration::get_identity_impl;
+use crate::rebased::migration::IdentityResolutionError;
+use crate::rebased::migration::IdentityResolutionErrorKind;
+use crate::rebased::migration::OnChainIdentity;
+use crate::rebased::Error;
+use crate::IotaDID;
+
+use super::get_object_id_from_did;
+use super::timed_request;
+use super::IdentityClientReadOnly;
+
+/// A controller of an identity, as found by [`IdentityClientReadOnly::controller_chain`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ControllerLink {
+  /// The DID of the controlled identity.
+  pub controlled: IotaDID,
+  /// The ID of the controller's `ControllerCap`.
+  pub controller_cap: ObjectId,
+  /// The voting power of the controller.
+  pub voting_power: u64,
+  /// The address holding the controller's `ControllerCap`, if the capability is owned by an address.
+  pub holder: Option<Address>,
+  /// The DID of the identity holding the controller's `ControllerCap`, if the controller is an identity.
+  pub holder_identity: Option<IotaDID>,
+  /// Whether [`ControllerLink::holder_identity`] is also controlled - directly or through other identities - by
+  /// [`ControllerLink::controlled`], i.e. whether this link closes a cycle.
+  pub closes_cycle: bool,
+}
+
+/// The controllers of an identity and, recursively, the controllers of the identities among them.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ControllerChain {
+  links: Vec<ControllerLink>,
+}
+
+impl ControllerChain {
+  /// Returns all the links of this chain.
+  pub fn links(&self) -> &[ControllerLink] {
+    &self.links
+  }
+
+  /// Returns the controllers of the identity with DID `did`.
+  pub fn controllers_of<'s>(&'s self, did: &'s IotaDID) -> impl Iterator<Item = &'s ControllerLink> + 's {
+    self.links.iter().filter(move |link| &link.controlled == did)
+  }
+
+  /// Returns the DIDs of all the identities acting as controllers in this chain.
+  pub fn controller_identities(&self) -> HashSet<&IotaDID> {
+    self
+      .links
+      .iter()
+      .filter_map(|link| link.holder_identity.as_ref())
+      .collect()
+  }
+
+  /// Returns whether the identities in this chain control each other in a cycle.
+  pub fn has_cycle(&self) -> bool {
+    self.links.iter().any(|link| link.closes_cycle)
+  }
+}
+
+impl IdentityClientReadOnly {
+  /// Returns the controllers of the identity with DID `did` and, recursively, the controllers of the identities
+  /// among them, e.g. to render an organization's structure.
+  ///
+  /// Every identity is traversed once: identities controlling each other in a cycle are reported through
+  /// [`ControllerLink::closes_cycle`] and are not traversed again.
+  pub async fn controller_chain(&self, did: &IotaDID) -> Result<ControllerChain, Error> {
+    timed_request("controller_chain", async {
+      let root = get_object_id_from_did(did)?;
+      let mut links = Vec::new();
+      let mut traversed = HashSet::new();
+      // Identities to traverse, each with the path of identities that led to it.
+      let mut to_traverse = vec![(root, vec![root])];
+
+      while let Some((identity_id, path)) = to_traverse.pop() {
+        if !traversed.insert(identity_id) {
+          continue;
+        }
+        let identity = get_identity_impl(self, identity_id)
+          .await
+          .map_err(|e| Error::DIDResolutionError(format!("{:#}", anyhow::Error::new(e))))?;
+        let controlled = IotaDID::from_object_id(identity_id, self.network());
+
+        for (&controller_cap, &voting_power) in identity.controllers() {
+          let holder = self.controller_cap_holder(controller_cap).await?;
+          let holder_identity = match holder {
+            Some(address) => self.identity_at(address).await?,
+            None => None,
+          };
+          let closes_cycle = holder_identity
+            .as_ref()
+            .is_some_and(|holder_identity| path.contains(&holder_identity.id()));
+
+          if let Some(holder_identity) = holder_identity.as_ref().filter(|_| !closes_cycle) {
+            let mut holder_path = path.clone();
+            holder_path.push(holder_identity.id());
+            to_traverse.push((holder_identity.id(), holder_path));
+          }
+
+          links.push(ControllerLink {
+            controlled: controlled.clone(),
+            controller_cap,
+            voting_power,
+            holder,
+            holder_identity: holder_identity.map(|identity| IotaDID::from_object_id(identity.id(), self.network())),
+            closes_cycle,
+          });
+        }
+      }
+
+      Ok(ControllerChain { links })
+    })
+    .await
+  }
+
+  /// Returns the address owning the `ControllerCap` with ID `controller_cap`, if it's owned by an address.
+  async fn controller_cap_holder(&self, controller_cap: ObjectId) -> Result<Option<Address>, Error> {
+    let owner = self
+      .client_adapter()
+      .read_api()
+      .get_object_with_options(controller_cap, IotaObjectDataOptions::default().with_owner())
+      .await
+      .map_err(|e| Error::RpcError(e.to_string()))?
+      .owner();
+
+    Ok(match owner {
+      Some(Owner::Address(address)) => Some(address),
+      _ => None,
+    })
+  }
+
+  /// Returns the identity whose ID is `address`, if any.
+  async fn identity_at(&self, address: Address) -> Result<Option<OnChainIdentity>, Error> {
+    match get_identity_impl(self, ObjectId::new(address.into_inner())).await {
+      Ok(identity) => Ok(Some(identity)),
+      Err(IdentityResolutionError {
+        kind: IdentityResolutionErrorKind::NotFound | IdentityResolutionErrorKind::InvalidType(_),
+        ..
+      }) => Ok(None),
+      Err(e) => Err(Error::ObjectLookup(format!("{:#}", anyhow::Error::new(e)))),
+    }
+  }
+}
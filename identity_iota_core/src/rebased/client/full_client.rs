@@ -12,6 +12,7 @@ use crate::rebased::migration::ControllerToken;
 use crate::rebased::migration::CreateIdentity;
 use crate::rebased::migration::IdentityResolutionError;
 use crate::rebased::migration::InsufficientControllerVotingPower;
+use crate::rebased::migration::MigrateLegacyDocument;
 use crate::rebased::migration::NotAController;
 use crate::rebased::migration::OnChainIdentity;
 use crate::IotaDID;
@@ -255,6 +256,19 @@ where
     TransactionBuilder::new(PublishDidDocument::new(document, self.sender_address()))
   }
 
+  /// Migrates the legacy DID document `legacy_document` into a new [`OnChainIdentity`] controlled by this client's
+  /// address.
+  ///
+  /// The new DID document keeps the verification methods, services and properties of `legacy_document`, with
+  /// self-references to the legacy DID replaced with the new one, and lists the legacy DID in `alsoKnownAs`.
+  /// The outcome of the transaction is a [`LegacyMigrationReport`](crate::rebased::migration::LegacyMigrationReport).
+  ///
+  /// Unlike [`MigrateLegacyIdentity`](crate::rebased::migration::MigrateLegacyIdentity), which turns a legacy Alias
+  /// into an identity keeping the same DID, this creates an identity with a new DID and leaves the legacy one as-is.
+  pub fn migrate_legacy_document(&self, legacy_document: &IotaDocument) -> TransactionBuilder<MigrateLegacyDocument> {
+    TransactionBuilder::new(MigrateLegacyDocument::new(legacy_document, self.sender_address()))
+  }
+
   // TODO: define what happens for (legacy|migrated|new) documents
   /// Updates a DID Document.
   pub async fn publish_did_document_update(
@@ -317,6 +331,35 @@ where
     Ok(())
   }
 
+  /// Reactivates a deactivated DID document, replacing it with `document`.
+  pub async fn reactivate_did_output(&self, document: IotaDocument, gas_budget: u64) -> Result<IotaDocument, Error> {
+    let mut oci =
+      if let Identity::FullFledged(value) = self.get_identity(get_object_id_from_did(document.id())?).await? {
+        value
+      } else {
+        return Err(Error::Identity("only new identities can be reactivated".to_string()));
+      };
+
+    let controller_token = oci.get_controller_token(self).await?.ok_or_else(|| {
+      Error::Identity(format!(
+        "address {} has no control over Identity {}",
+        self.sender_address(),
+        oci.id()
+      ))
+    })?;
+
+    oci
+      .reactivate_did(document, &controller_token)
+      .finish(self)
+      .await?
+      .with_gas_budget(gas_budget)
+      .build_and_execute(self)
+      .await
+      .map_err(|e| Error::TransactionUnexpectedResponse(e.to_string()))?;
+
+    Ok(oci.did_document().clone())
+  }
+
   /// A shorthand for
   /// [OnChainIdentity::update_did_document](crate::rebased::migration::OnChainIdentity::update_did_document)'s DID
   /// Document.
@@ -503,6 +546,15 @@ impl Transaction for PublishDidDocument {
   type Output = IotaDocument;
   type Error = Error;
 
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+      name = "build_transaction",
+      skip_all,
+      fields(transaction = "PublishDidDocument"),
+      err(Display)
+    )
+  )]
   async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
   where
     C: CoreClientReadOnly + OptionalSync,
@@ -510,6 +562,15 @@ impl Transaction for PublishDidDocument {
     self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
   }
 
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+      name = "apply_transaction_effects",
+      skip_all,
+      fields(transaction = "PublishDidDocument"),
+      err(Display)
+    )
+  )]
   async fn apply<C>(self, effects: &mut IotaTransactionBlockEffects, client: &C) -> Result<Self::Output, Self::Error>
   where
     C: CoreClientReadOnly + OptionalSync,
@@ -539,6 +600,15 @@ impl Transaction for ShorthandDidUpdate {
   type Error = Error;
   type Output = IotaDocument;
 
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+      name = "build_transaction",
+      skip_all,
+      fields(transaction = "ShorthandDidUpdate", did = %identity_did::DID::redacted(self.did_document.id())),
+      err(Display)
+    )
+  )]
   async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
   where
     C: CoreClientReadOnly + OptionalSync,
@@ -554,6 +624,15 @@ impl Transaction for ShorthandDidUpdate {
     Ok(ptb)
   }
 
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+      name = "apply_transaction_effects",
+      skip_all,
+      fields(transaction = "ShorthandDidUpdate", did = %identity_did::DID::redacted(self.did_document.id())),
+      err(Display)
+    )
+  )]
   async fn apply<C>(self, effects: &mut IotaTransactionBlockEffects, client: &C) -> Result<Self::Output, Self::Error>
   where
     C: CoreClientReadOnly + OptionalSync,
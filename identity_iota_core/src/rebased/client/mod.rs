@@ -1,11 +1,48 @@
 // Copyright 2020-2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+mod controller_chain;
 mod full_client;
+mod multi_network;
 mod read_only;
 
+pub use controller_chain::*;
 pub use full_client::*;
+pub use multi_network::*;
 
 pub use read_only::*;
 
 pub use iota_interaction::IotaKeySignature;
+
+use std::future::Future;
+
+use identity_core::metrics;
+
+/// Awaits `request`, reporting its duration to the installed metrics recorder as
+/// [`CLIENT_RPC_DURATION`](metrics::names::CLIENT_RPC_DURATION).
+pub(crate) async fn timed_request<T, E>(
+  operation: &'static str,
+  request: impl Future<Output = Result<T, E>>,
+) -> Result<T, E> {
+  cfg_if::cfg_if! {
+    if #[cfg(all(target_arch = "wasm32", not(target_os = "wasi")))] {
+      // `std::time::Instant` is not available in browsers.
+      let _ = operation;
+      request.await
+    } else {
+      if !metrics::recorder_installed() {
+        return request.await;
+      }
+
+      let start = std::time::Instant::now();
+      let result: Result<T, E> = request.await;
+      let outcome: &str = if result.is_ok() { "ok" } else { "error" };
+      metrics::record_histogram(
+        metrics::names::CLIENT_RPC_DURATION,
+        &[("operation", operation), ("outcome", outcome)],
+        start.elapsed().as_secs_f64(),
+      );
+      result
+    }
+  }
+}
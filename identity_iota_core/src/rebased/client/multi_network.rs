@@ -0,0 +1,222 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use identity_core::common::Timestamp;
+#[cfg(not(target_arch = "wasm32"))]
+use iota_sdk_types::ObjectId;
+use tokio::sync::OnceCell;
+
+use crate::rebased::iota::package::identity_package_registry;
+use crate::rebased::Error;
+use crate::IotaDID;
+use crate::IotaDocument;
+
+use super::IdentityClientReadOnly;
+
+/// How the client of a network is obtained.
+enum ClientSource {
+  /// The client was provided upfront.
+  Ready,
+  /// The client is connected to the node at `url` on first use.
+  #[cfg(not(target_arch = "wasm32"))]
+  Endpoint { url: String, package_id: Option<ObjectId> },
+}
+
+struct NetworkEntry {
+  source: ClientSource,
+  client: OnceCell<IdentityClientReadOnly>,
+}
+
+impl NetworkEntry {
+  async fn client(&self) -> Result<&IdentityClientReadOnly, Error> {
+    self
+      .client
+      .get_or_try_init(|| async {
+        match &self.source {
+          ClientSource::Ready => unreachable!("ready clients are initialized on registration"),
+          #[cfg(not(target_arch = "wasm32"))]
+          ClientSource::Endpoint { url, package_id } => {
+            let iota_client = crate::rebased::utils::get_client(url).await?;
+            match package_id {
+              Some(package_id) => IdentityClientReadOnly::new_with_pkg_id(iota_client, *package_id).await,
+              None => IdentityClientReadOnly::new(iota_client).await,
+            }
+          }
+        }
+      })
+      .await
+  }
+}
+
+/// A read-only identity client spanning multiple IOTA networks, routing each request to the client of the network
+/// encoded in the DID it concerns.
+///
+/// Unlike [`IdentityClientReadOnly`], which fails to resolve DIDs of networks other than the one it is connected
+/// to, this client can resolve DIDs of any of the networks it has been configured with, e.g. mainnet, testnet and
+/// custom networks at once. Clients configured through an endpoint are only connected on first use.
+///
+/// As it implements [`DidResolutionHandler`](crate::DidResolutionHandler), it can be attached to a resolver as
+/// the single handler of the `iota` DID method.
+///
+/// # Example
+/// ```ignore
+/// let client = MultiNetworkIdentityClient::new()
+///   .with_client(mainnet_client)
+///   .with_endpoint("testnet", "https://api.testnet.iota.cafe", None)
+///   .with_endpoint("4a1b2c3d", "http://127.0.0.1:9000", Some(local_package_id));
+///
+/// let document = client.resolve_did(&did).await?;
+/// ```
+#[derive(Clone, Default)]
+pub struct MultiNetworkIdentityClient {
+  networks: HashMap<String, Arc<NetworkEntry>>,
+}
+
+impl MultiNetworkIdentityClient {
+  /// Creates a client without any network.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Registers `client` for the network it is connected to, under both its network name and its chain identifier.
+  ///
+  /// Replaces any client previously registered for that network.
+  pub fn with_client(mut self, client: IdentityClientReadOnly) -> Self {
+    let names: Vec<String> = vec![client.network().as_ref().to_owned(), client.chain_id().to_owned()];
+    let entry = Arc::new(NetworkEntry {
+      source: ClientSource::Ready,
+      client: OnceCell::new_with(Some(client)),
+    });
+    for name in names {
+      self.networks.insert(name, entry.clone());
+    }
+    self
+  }
+
+  /// Registers the node at `url` for the DIDs of `network`, i.e. the network name or chain identifier DIDs encode.
+  /// The client is only connected when a DID of `network` is first resolved.
+  ///
+  /// When `package_id` is given it is used as the latest version of the `iota_identity` package on that network,
+  /// which is required for local and custom networks.
+  ///
+  /// Replaces any client previously registered for `network`.
+  #[cfg(not(target_arch = "wasm32"))]
+  pub fn with_endpoint(
+    mut self,
+    network: impl Into<String>,
+    url: impl Into<String>,
+    package_id: Option<ObjectId>,
+  ) -> Self {
+    let entry = Arc::new(NetworkEntry {
+      source: ClientSource::Endpoint {
+        url: url.into(),
+        package_id,
+      },
+      client: OnceCell::new(),
+    });
+    self.networks.insert(network.into(), entry);
+    self
+  }
+
+  /// Returns the names of the networks this client is configured with.
+  pub fn networks(&self) -> impl Iterator<Item = &str> + '_ {
+    self.networks.keys().map(String::as_str)
+  }
+
+  /// Returns the client of `network`, connecting it if needed.
+  ///
+  /// # Errors
+  /// Fails if no client is configured for `network`, or if connecting to it fails.
+  ///
+  /// A client registered under the alias of a network, e.g. `testnet`, is also used for its chain identifier, and vice
+  /// versa, as long as the `iota_identity` package registry knows both names.
+  pub async fn client(&self, network: &str) -> Result<&IdentityClientReadOnly, Error> {
+    let entry = match self.networks.get(network) {
+      Some(entry) => entry,
+      None => self
+        .equivalent_entry(network)
+        .await?
+        .ok_or_else(|| Error::InvalidConfig(format!("no client is configured for network `{network}`")))?,
+    };
+    entry.client().await
+  }
+
+  /// Returns the entry registered under the other name of `network`, i.e. its alias if `network` is a chain
+  /// identifier and its chain identifier if it is an alias.
+  async fn equivalent_entry(&self, network: &str) -> Result<Option<&Arc<NetworkEntry>>, Error> {
+    let registry = identity_package_registry().await?;
+    Ok(
+      registry
+        .equivalent_network(network)
+        .and_then(|equivalent| self.networks.get(equivalent)),
+    )
+  }
+
+  /// Queries an [`IotaDocument`] DID Document through its `did`, using the client of the network `did` belongs to.
+  pub async fn resolve_did(&self, did: &IotaDID) -> Result<IotaDocument, Error> {
+    self.client_for_did(did).await?.resolve_did(did).await
+  }
+
+  /// Queries the [`IotaDocument`] DID Document referenced by `did` as it was at `timestamp`, using the client of the
+  /// network `did` belongs to.
+  ///
+  /// See [`IdentityClientReadOnly::resolve_did_at`].
+  pub async fn resolve_did_at(&self, did: &IotaDID, timestamp: Timestamp) -> Result<IotaDocument, Error> {
+    self.client_for_did(did).await?.resolve_did_at(did, timestamp).await
+  }
+
+  /// Queries the [`IotaDocument`] DID Document referenced by `did` as it was in version `version` of its Identity
+  /// object, using the client of the network `did` belongs to.
+  ///
+  /// See [`IdentityClientReadOnly::resolve_did_version`].
+  pub async fn resolve_did_version(&self, did: &IotaDID, version: u64) -> Result<IotaDocument, Error> {
+    self.client_for_did(did).await?.resolve_did_version(did, version).await
+  }
+
+  async fn client_for_did(&self, did: &IotaDID) -> Result<&IdentityClientReadOnly, Error> {
+    let network: &str = did.network_str();
+    self.client(network).await.map_err(|err| match err {
+      Error::InvalidConfig(_) => Error::DIDResolutionError(format!(
+        "cannot resolve `{did}`: no client is configured for network `{network}`"
+      )),
+      err => err,
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[tokio::test]
+  async fn network_aliases_and_chain_identifiers_are_equivalent() {
+    let chain_id: String = identity_package_registry()
+      .await
+      .unwrap()
+      .chain_id("testnet")
+      .expect("testnet is a known network")
+      .to_owned();
+    let client = MultiNetworkIdentityClient::new().with_endpoint(chain_id, "http://127.0.0.1:1", None);
+
+    // The client registered for the chain identifier of testnet is used for `testnet` DIDs, so connecting to it is
+    // attempted.
+    assert!(matches!(client.client("testnet").await, Err(Error::Network(..))));
+    assert!(matches!(client.client("dev").await, Err(Error::InvalidConfig(_))));
+  }
+
+  #[tokio::test]
+  async fn dids_of_unknown_networks_are_rejected() {
+    let client = MultiNetworkIdentityClient::new().with_endpoint("testnet", "http://127.0.0.1:1", None);
+    assert_eq!(client.networks().collect::<Vec<_>>(), vec!["testnet"]);
+
+    let did =
+      IotaDID::parse("did:iota:dev:0xf4d6f08f5a1b80dd578da7dc1b49c886d580acd4cf7d48119dfeb82b538ad88a").unwrap();
+    assert!(matches!(
+      client.resolve_did(&did).await,
+      Err(Error::DIDResolutionError(_))
+    ));
+  }
+}
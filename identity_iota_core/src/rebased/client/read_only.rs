@@ -13,8 +13,11 @@ use futures::stream::FuturesUnordered;
 use futures::Stream;
 use futures::StreamExt as _;
 use futures::TryStreamExt as _;
+use identity_core::common::Timestamp;
 use identity_core::common::Url;
 use identity_did::DID;
+use iota_interaction::ident_str;
+use iota_interaction::rpc_types::EventFilter;
 use iota_interaction::rpc_types::IotaObjectDataFilter;
 use iota_interaction::rpc_types::IotaObjectDataOptions;
 use iota_interaction::rpc_types::IotaObjectResponseQuery;
@@ -36,7 +39,11 @@ use crate::rebased::migration::ControllerCap;
 use crate::rebased::migration::ControllerToken;
 use crate::rebased::migration::DelegationToken;
 use crate::rebased::migration::Identity;
+use crate::rebased::migration::IdentityState;
+use crate::rebased::migration::IdentityStateReplayer;
 use crate::rebased::Error;
+
+use super::timed_request;
 use crate::IotaDID;
 use crate::IotaDocument;
 
@@ -95,7 +102,8 @@ impl IdentityClientReadOnly {
   ///
   /// # Notes
   /// When trying to connect to a local or unofficial network, prefer using
-  /// [`IdentityClientReadOnly::new_with_pkg_id`].
+  /// [`IdentityClientReadOnly::new_with_pkg_id`], or register the network's package through
+  /// [`configure_identity_packages`](crate::rebased::configure_identity_packages).
   pub async fn new(
     #[cfg(target_arch = "wasm32")] iota_client: WasmIotaClient,
     #[cfg(not(target_arch = "wasm32"))] iota_client: IotaClient,
@@ -108,7 +116,7 @@ impl IdentityClientReadOnly {
   async fn new_internal(iota_client: IotaClientAdapter, network: NetworkName) -> Result<Self, Error> {
     let chain_id = network.as_ref().to_string();
     let (network, package_history) = {
-      let package_registry = iota::package::identity_package_registry().await;
+      let package_registry = iota::package::identity_package_registry().await?;
       let package_history = package_registry
         .history(&network)
         .ok_or_else(|| {
@@ -145,7 +153,7 @@ impl IdentityClientReadOnly {
 
     // Use the passed pkg_id to force it at the end of the list or create a new env.
     {
-      let mut registry = iota::package::identity_package_registry_mut().await;
+      let mut registry = iota::package::identity_package_registry_mut().await?;
       registry.insert_new_package_version(&network, package_id);
     }
 
@@ -160,9 +168,117 @@ impl IdentityClientReadOnly {
   }
 
   /// Queries an [`IotaDocument`] DID Document through its `did`.
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(name = "resolve_did", skip_all, fields(did = %did.redacted()), err(Display))
+  )]
   pub async fn resolve_did(&self, did: &IotaDID) -> Result<IotaDocument, Error> {
-    // Make sure `did` references a DID Document on the network
-    // this client is connected to.
+    timed_request("resolve_did", async {
+      self.check_did_network(did)?;
+      let identity = self.get_identity(get_object_id_from_did(did)?).await?;
+      let did_doc = identity.did_document(self.network())?;
+
+      match identity {
+        Identity::FullFledged(identity) if identity.has_deleted_did() => {
+          Err(Error::DIDResolutionError(format!("could not find DID Document {did}")))
+        }
+        _ => Ok(did_doc),
+      }
+    })
+    .await
+  }
+
+  /// Queries the [`IotaDocument`] DID Document referenced by `did` as it was at `timestamp`.
+  ///
+  /// The Identity's history is replayed through an [`IdentityStateReplayer`], see
+  /// [`IdentityStateReplayer::state_at`] for how the state at `timestamp` is selected. The `versionId`,
+  /// `nextVersionId` and `nextUpdate` properties of the returned document's metadata are set.
+  /// ## Errors
+  /// Fails if the DID Document did not exist yet at `timestamp`, had been deleted by then, or is a legacy
+  /// Stardust DID Document, whose history is not available.
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(name = "resolve_did_at", skip_all, fields(did = %did.redacted()), err(Display))
+  )]
+  pub async fn resolve_did_at(&self, did: &IotaDID, timestamp: Timestamp) -> Result<IotaDocument, Error> {
+    timed_request("resolve_did_at", async {
+      self
+        .resolve_historical_did(did, &timestamp.to_rfc3339(), |states| {
+          states
+            .iter()
+            .rposition(|state| state.updated() <= timestamp)
+            .ok_or_else(|| Error::DIDResolutionError(format!("DID Document {did} did not exist at {timestamp}")))
+        })
+        .await
+    })
+    .await
+  }
+
+  /// Queries the [`IotaDocument`] DID Document referenced by `did` as it was in version `version` of its Identity
+  /// object, as referenced by the `versionId` DID parameter.
+  ///
+  /// The `versionId`, `nextVersionId` and `nextUpdate` properties of the returned document's metadata are set.
+  /// ## Errors
+  /// Fails if the Identity object has no version `version`, if the DID Document had been deleted in that version,
+  /// or if it is a legacy Stardust DID Document, whose history is not available.
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(name = "resolve_did_version", skip_all, fields(did = %did.redacted()), err(Display))
+  )]
+  pub async fn resolve_did_version(&self, did: &IotaDID, version: u64) -> Result<IotaDocument, Error> {
+    timed_request("resolve_did_version", async {
+      self
+        .resolve_historical_did(did, &format!("version {version}"), |states| {
+          states
+            .iter()
+            .position(|state| state.object_version() == version)
+            .ok_or_else(|| Error::DIDResolutionError(format!("DID Document {did} has no version {version}")))
+        })
+        .await
+    })
+    .await
+  }
+
+  /// Resolves the DID Document of the state of `did` picked by `select` among all of its states, oldest first.
+  async fn resolve_historical_did(
+    &self,
+    did: &IotaDID,
+    at: &str,
+    select: impl FnOnce(&[IdentityState]) -> Result<usize, Error>,
+  ) -> Result<IotaDocument, Error> {
+    self.check_did_network(did)?;
+    let Identity::FullFledged(identity) = self.get_identity(get_object_id_from_did(did)?).await? else {
+      return Err(Error::DIDResolutionError(format!(
+        "the history of legacy DID Document {did} is not available"
+      )));
+    };
+
+    let mut states: Vec<IdentityState> = IdentityStateReplayer::new(self, identity.id()).replay().await?;
+    let idx: usize = select(&states)?;
+    // Later versions of the Identity object may leave the DID Document untouched, e.g. when changing controllers.
+    let next_update: Option<Timestamp> = states[idx + 1..]
+      .iter()
+      .map(IdentityState::updated)
+      .find(|updated| *updated != states[idx].updated());
+    let next_version_id: Option<String> = states.get(idx + 1).map(|next| next.object_version().to_string());
+    let state: IdentityState = states.swap_remove(idx);
+    if state.identity().has_deleted_did() {
+      return Err(Error::DIDResolutionError(format!(
+        "could not find DID Document {did} at {at}"
+      )));
+    }
+
+    let version_id: String = state.object_version().to_string();
+    let mut document: IotaDocument = state.into_identity().into();
+    document.metadata.version_id = Some(version_id);
+    document.metadata.next_update = next_update;
+    document.metadata.next_version_id = next_version_id;
+
+    Ok(document)
+  }
+
+  /// Makes sure `did` references a DID Document on the network this client is connected to.
+  fn check_did_network(&self, did: &IotaDID) -> Result<(), Error> {
     let did_network = did.network_str();
     let client_network = self.network.as_ref();
     if did_network != client_network && did_network != self.chain_id() {
@@ -172,38 +288,38 @@ impl IdentityClientReadOnly {
         but this client is connected to network `{client_network}`"
       )));
     }
-    let identity = self.get_identity(get_object_id_from_did(did)?).await?;
-    let did_doc = identity.did_document(self.network())?;
 
-    match identity {
-      Identity::FullFledged(identity) if identity.has_deleted_did() => {
-        Err(Error::DIDResolutionError(format!("could not find DID Document {did}")))
-      }
-      _ => Ok(did_doc),
-    }
+    Ok(())
   }
 
   /// Resolves an [`Identity`] from its ID `object_id`.
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(name = "get_identity", skip_all, err(Display))
+  )]
   pub async fn get_identity(&self, object_id: ObjectId) -> Result<Identity, Error> {
-    // spawn all checks
-    cfg_if::cfg_if! {
-      // Unfortunately the compiler runs into lifetime problems if we try to use a 'type ='
-      // instead of the below ugly platform specific code
-      if #[cfg(feature = "send-sync")] {
-        let all_futures = FuturesUnordered::<Pin<Box<dyn Future<Output = Result<Option<Identity>, Error>> + Send>>>::new();
-      } else {
-        let all_futures = FuturesUnordered::<Pin<Box<dyn Future<Output = Result<Option<Identity>, Error>>>>>::new();
+    timed_request("get_identity", async {
+      // spawn all checks
+      cfg_if::cfg_if! {
+        // Unfortunately the compiler runs into lifetime problems if we try to use a 'type ='
+        // instead of the below ugly platform specific code
+        if #[cfg(feature = "send-sync")] {
+          let all_futures = FuturesUnordered::<Pin<Box<dyn Future<Output = Result<Option<Identity>, Error>> + Send>>>::new();
+        } else {
+          let all_futures = FuturesUnordered::<Pin<Box<dyn Future<Output = Result<Option<Identity>, Error>>>>>::new();
+        }
       }
-    }
-    all_futures.push(Box::pin(resolve_new(self, object_id)));
-    all_futures.push(Box::pin(resolve_migrated(self, object_id)));
-    all_futures.push(Box::pin(resolve_unmigrated(self, object_id)));
-
-    all_futures
-      .filter_map(|res| Box::pin(async move { res.ok().flatten() }))
-      .next()
-      .await
-      .ok_or_else(|| Error::DIDResolutionError(format!("could not find DID document for {object_id}")))
+      all_futures.push(Box::pin(resolve_new(self, object_id)));
+      all_futures.push(Box::pin(resolve_migrated(self, object_id)));
+      all_futures.push(Box::pin(resolve_unmigrated(self, object_id)));
+
+      all_futures
+        .filter_map(|res| Box::pin(async move { res.ok().flatten() }))
+        .next()
+        .await
+        .ok_or_else(|| Error::DIDResolutionError(format!("could not find DID document for {object_id}")))
+    })
+    .await
   }
 
   /// Returns a stream yielding the unique DIDs the given address can access as a controller.
@@ -291,6 +407,49 @@ impl IdentityClientReadOnly {
     }
   }
 
+  /// Returns a stream yielding the DIDs of all the identities created with any version of the IotaIdentity package
+  /// that emits `IdentityCreated` events.
+  /// # Notes
+  /// Identities created before `IdentityCreated` events were introduced are not returned.
+  pub(crate) fn streamed_created_dids(&self) -> impl Stream<Item = Result<IotaDID, Error>> + use<'_> {
+    #[derive(serde::Deserialize)]
+    struct IdentityCreatedEvent {
+      identity: ObjectId,
+    }
+
+    async_stream::try_stream! {
+      for package in self.package_history.iter().copied() {
+        let event_type = StructTag::new(
+          package,
+          ident_str!("identity").as_str(),
+          ident_str!("IdentityCreated").as_str(),
+          vec![],
+        );
+
+        let mut cursor = None;
+        loop {
+          let page = self
+            .client_adapter()
+            .event_api()
+            .query_events(EventFilter::MoveEventType(event_type.clone()), cursor, None, false)
+            .await
+            .map_err(|e| Error::RpcError(e.to_string()))?;
+          for event in page.data {
+            let Ok(event) = serde_json::from_value::<IdentityCreatedEvent>(event.parsed_json) else {
+              continue;
+            };
+            yield IotaDID::from_object_id(event.identity, &self.network);
+          }
+
+          if !page.has_next_page || page.next_cursor.is_none() {
+            break;
+          }
+          cursor = page.next_cursor;
+        }
+      }
+    }
+  }
+
   /// Returns the list of **all** unique DIDs the given address has access to as a controller.
   /// # Notes
   /// For a streaming version of this API see [dids_controlled_by_streamed](Self::dids_controlled_by_streamed).
@@ -321,7 +480,11 @@ impl IdentityClientReadOnly {
   /// # }
   /// ```
   pub async fn dids_controlled_by(&self, address: Address) -> Result<Vec<IotaDID>, QueryControlledDidsError> {
-    self.streamed_dids_controlled_by(address).try_collect().await
+    timed_request(
+      "dids_controlled_by",
+      self.streamed_dids_controlled_by(address).try_collect(),
+    )
+    .await
   }
 }
 
@@ -385,7 +548,10 @@ async fn resolve_migrated(client: &IdentityClientReadOnly, object_id: ObjectId)
   // which gets replaced by `identity_did`.
   doc
     .also_known_as_mut()
-    .replace::<Url>(&queried_did.into_url().into(), identity_did.into_url().into());
+    .replace::<Url>(&queried_did.into_url().into(), identity_did.clone().into_url().into());
+  // The DID of the identity is the canonical one, the queried legacy DID being equivalent to it.
+  doc.metadata.equivalent_id = vec![identity_did.clone()];
+  doc.metadata.canonical_id = Some(identity_did);
 
   Ok(Some(Identity::FullFledged(onchain_identity)))
 }
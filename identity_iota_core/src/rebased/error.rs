@@ -30,6 +30,68 @@ pub enum Error {
   /// Caused by issues with paying for transaction.
   #[error("issue with gas for transaction: {0}")]
   GasIssue(String),
+  /// The gas budget of a transaction doesn't cover its estimated cost.
+  #[error("insufficient gas budget: the transaction requires {required}, but the budget is {budget}")]
+  InsufficientGas {
+    /// The gas budget of the transaction.
+    budget: u64,
+    /// The gas the transaction is estimated to require, without safety margin.
+    required: u64,
+    /// The suggested gas budget, including the safety margin.
+    estimate: u64,
+  },
+  /// An operation was submitted again through the same
+  /// [`SubmissionJournal`](crate::rebased::submission::SubmissionJournal).
+  #[error("operation {operation} was already submitted")]
+  DuplicateSubmission {
+    /// The digest of the operation.
+    operation: String,
+    /// The status of the previous submission.
+    status: crate::rebased::submission::SubmissionStatus,
+  },
+  /// An operation cannot be resubmitted, as its previous submission may have been executed.
+  #[error("operation {operation} cannot be safely resubmitted; status of the previous submission: {status:?}")]
+  UnsafeResubmission {
+    /// The digest of the operation.
+    operation: String,
+    /// The status of the previous submission.
+    status: crate::rebased::submission::SubmissionStatus,
+  },
+  /// The outcome of a pending submission cannot be determined, as the operation takes no owned objects whose
+  /// consumption would reveal it, e.g. because its only inputs are shared or immutable objects.
+  #[error("the outcome of operation {operation} cannot be determined, as it takes no mutable owned objects as input")]
+  UnverifiableSubmission {
+    /// The digest of the operation.
+    operation: String,
+  },
+  /// A step of a [`Workflow`](crate::rebased::workflow::Workflow) failed.
+  #[error("workflow `{workflow}` failed at step `{step}`")]
+  WorkflowFailed {
+    /// The identifier of the workflow.
+    workflow: String,
+    /// The name of the failed step.
+    step: String,
+    /// The error of the failed step.
+    #[source]
+    source: Box<Self>,
+    /// The state of the workflow after the failure, to persist or resume from.
+    state: Box<crate::rebased::workflow::WorkflowState>,
+  },
+  /// An object expected to exist on-chain wasn't found, e.g. because it was deleted or wrapped in the meantime.
+  #[error("object `{0}` was not found on-chain")]
+  ObjectNotFound(iota_sdk_types::ObjectId),
+  /// The on-chain state of an object doesn't match the state an operation expected, e.g. because the object was
+  /// modified concurrently.
+  #[error("unexpected on-chain state of object `{id}`; {reason}")]
+  StaleObjectRef {
+    /// The ID of the object.
+    id: iota_sdk_types::ObjectId,
+    /// What doesn't match the expected state.
+    reason: String,
+  },
+  /// A controller token doesn't grant access to the identity it was used with.
+  #[error(transparent)]
+  InvalidToken(#[from] crate::rebased::migration::InvalidControllerTokenForIdentity),
   /// Could not parse module, package, etc.
   #[error("failed to parse {0}")]
   ParsingFailed(String),
@@ -106,3 +168,39 @@ where
 {
   error.into()
 }
+
+identity_core::impl_error_code!(Error {
+  Network => 2100,
+  ObjectLookup => 2101,
+  MigrationRegistryNotFound => 2102,
+  DIDResolutionError => 2103,
+  InvalidArgument => 2104,
+  InvalidKey => 2105,
+  GasIssue => 2106,
+  ParsingFailed => 2107,
+  TransactionBuildingFailed => 2108,
+  TransactionSigningFailed => 2109,
+  TransactionExecutionFailed => 2110,
+  TransactionUnexpectedResponse => 2111,
+  TransactionOffChainApplicationFailure => 2112,
+  InvalidConfig => 2113,
+  DidDocParsingFailed => 2114,
+  DidDocSerialization => 2115,
+  Identity => 2116,
+  InvalidIdentityHistory => 2117,
+  MissingPermission => 2118,
+  RpcError => 2119,
+  BcsError => 2120,
+  AnyError => 2121,
+  FfiError => 2122,
+  IotaInteractionError => 2123,
+  IotaInteractionAdapterError => 2124,
+  InsufficientGas => 2125,
+  DuplicateSubmission => 2126,
+  UnsafeResubmission => 2127,
+  WorkflowFailed => 2128,
+  ObjectNotFound => 2129,
+  StaleObjectRef => 2130,
+  InvalidToken => 2131,
+  UnverifiableSubmission => 2132,
+});
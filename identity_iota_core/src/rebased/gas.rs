@@ -0,0 +1,268 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use iota_interaction::rpc_types::IotaExecutionStatus;
+use iota_interaction::rpc_types::IotaTransactionBlockEffectsAPI as _;
+use iota_interaction::IotaClientTrait;
+use iota_interaction::IotaKeySignature;
+use iota_interaction::OptionalSend;
+use iota_interaction::OptionalSync;
+use iota_sdk_types::transaction::TransactionKind;
+use iota_sdk_types::Address;
+use iota_sdk_types::ProgrammableTransaction;
+use product_common::core_client::CoreClient;
+use product_common::core_client::CoreClientReadOnly;
+use product_common::transaction::transaction_builder::Transaction;
+use product_common::transaction::transaction_builder::TransactionBuilder;
+use secret_storage::Signer;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::rebased::Error;
+
+/// The multiplier applied by default to the gas a transaction is estimated to require.
+pub const DEFAULT_GAS_SAFETY_MULTIPLIER: f64 = 1.2;
+
+/// The result of a gas estimation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GasEstimate {
+  /// The computation cost of the dry-run transaction.
+  pub computation_cost: u64,
+  /// The storage cost of the dry-run transaction.
+  pub storage_cost: u64,
+  /// The storage rebate of the dry-run transaction.
+  pub storage_rebate: u64,
+  /// The gas budget the transaction requires, without safety margin.
+  pub required: u64,
+  /// The suggested gas budget, i.e. [`GasEstimate::required`] with the safety margin applied.
+  pub budget: u64,
+}
+
+impl GasEstimate {
+  /// Checks that `budget` covers the gas the transaction requires.
+  /// # Errors
+  /// [`Error::InsufficientGas`] is returned otherwise.
+  pub fn check_budget(&self, budget: u64) -> Result<(), Error> {
+    if budget < self.required {
+      return Err(Error::InsufficientGas {
+        budget,
+        required: self.required,
+        estimate: self.budget,
+      });
+    }
+
+    Ok(())
+  }
+}
+
+/// Estimates the gas budget of transactions by dry-running them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GasEstimation {
+  safety_multiplier: f64,
+}
+
+impl Default for GasEstimation {
+  fn default() -> Self {
+    Self {
+      safety_multiplier: DEFAULT_GAS_SAFETY_MULTIPLIER,
+    }
+  }
+}
+
+impl GasEstimation {
+  /// Creates a new [`GasEstimation`] using [`DEFAULT_GAS_SAFETY_MULTIPLIER`].
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Sets the multiplier applied to the gas a transaction is estimated to require.
+  /// # Errors
+  /// Fails if `safety_multiplier` is not a finite number greater than or equal to `1`.
+  pub fn with_safety_multiplier(mut self, safety_multiplier: f64) -> Result<Self, Error> {
+    if !safety_multiplier.is_finite() || safety_multiplier < 1.0 {
+      return Err(Error::InvalidArgument(format!(
+        "gas safety multiplier must be a finite number greater than or equal to 1, got {safety_multiplier}"
+      )));
+    }
+    self.safety_multiplier = safety_multiplier;
+    Ok(self)
+  }
+
+  /// Returns the multiplier applied to the gas a transaction is estimated to require.
+  pub fn safety_multiplier(&self) -> f64 {
+    self.safety_multiplier
+  }
+
+  /// Estimates the gas budget of `tx`, when sent by `sender`.
+  pub async fn estimate<T, C>(&self, tx: &T, sender: Address, client: &C) -> Result<GasEstimate, Error>
+  where
+    T: Transaction,
+    T::Error: std::error::Error,
+    C: CoreClientReadOnly + OptionalSync,
+  {
+    let pt = tx
+      .build_programmable_transaction(client)
+      .await
+      .map_err(|e| Error::TransactionBuildingFailed(e.to_string()))?;
+
+    self.estimate_programmable_transaction(pt, sender, client).await
+  }
+
+  /// Estimates the gas budget of the programmable transaction `pt`, when sent by `sender`.
+  pub async fn estimate_programmable_transaction<C>(
+    &self,
+    pt: ProgrammableTransaction,
+    sender: Address,
+    client: &C,
+  ) -> Result<GasEstimate, Error>
+  where
+    C: CoreClientReadOnly + OptionalSync,
+  {
+    let results = client
+      .client_adapter()
+      .read_api()
+      .dev_inspect_transaction_block(sender, TransactionKind::Programmable(pt), None, None, None)
+      .await
+      .map_err(|e| Error::GasIssue(format!("failed to dry-run transaction: {e}")))?;
+
+    if let IotaExecutionStatus::Failure { error } = results.effects.status() {
+      return Err(Error::GasIssue(format!(
+        "cannot estimate the gas of a failing transaction: {error}"
+      )));
+    }
+
+    let summary = results.effects.gas_cost_summary();
+    Ok(self.estimate_from_costs(summary.computation_cost, summary.storage_cost, summary.storage_rebate))
+  }
+
+  fn estimate_from_costs(&self, computation_cost: u64, storage_cost: u64, storage_rebate: u64) -> GasEstimate {
+    // The budget must cover the computation cost, and the storage cost before the rebate is refunded.
+    let required = computation_cost.max(
+      computation_cost
+        .saturating_add(storage_cost)
+        .saturating_sub(storage_rebate),
+    );
+    let budget = (required as f64 * self.safety_multiplier).ceil() as u64;
+
+    GasEstimate {
+      computation_cost,
+      storage_cost,
+      storage_rebate,
+      required,
+      budget: budget.max(required),
+    }
+  }
+}
+
+/// Gas estimation capabilities for [`TransactionBuilder`].
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+pub trait TransactionBuilderGasExt: Sized {
+  /// Sets this transaction's gas budget to the one estimated by `estimation` when sent by `client`'s sender.
+  ///
+  /// ## Notes
+  /// The transaction is taken out of this builder and wrapped into a new one: call this method before
+  /// configuring the builder any further.
+  async fn with_estimated_gas_budget<S, C>(self, client: &C, estimation: &GasEstimation) -> Result<Self, Error>
+  where
+    S: Signer<IotaKeySignature> + OptionalSync,
+    C: CoreClient<S> + OptionalSync;
+
+  /// Sets this transaction's gas budget to `budget`, after checking it covers the estimated cost of the
+  /// transaction.
+  /// # Errors
+  /// [`Error::InsufficientGas`] is returned when `budget` is too low.
+  ///
+  /// ## Notes
+  /// See [`TransactionBuilderGasExt::with_estimated_gas_budget`].
+  async fn with_checked_gas_budget<S, C>(
+    self,
+    budget: u64,
+    client: &C,
+    estimation: &GasEstimation,
+  ) -> Result<Self, Error>
+  where
+    S: Signer<IotaKeySignature> + OptionalSync,
+    C: CoreClient<S> + OptionalSync;
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl<T> TransactionBuilderGasExt for TransactionBuilder<T>
+where
+  T: Transaction + OptionalSend + OptionalSync,
+  T::Error: std::error::Error,
+{
+  async fn with_estimated_gas_budget<S, C>(self, client: &C, estimation: &GasEstimation) -> Result<Self, Error>
+  where
+    S: Signer<IotaKeySignature> + OptionalSync,
+    C: CoreClient<S> + OptionalSync,
+  {
+    let tx = self.into_inner();
+    let estimate = estimation.estimate(&tx, client.sender_address(), client).await?;
+
+    Ok(TransactionBuilder::new(tx).with_gas_budget(estimate.budget))
+  }
+
+  async fn with_checked_gas_budget<S, C>(
+    self,
+    budget: u64,
+    client: &C,
+    estimation: &GasEstimation,
+  ) -> Result<Self, Error>
+  where
+    S: Signer<IotaKeySignature> + OptionalSync,
+    C: CoreClient<S> + OptionalSync,
+  {
+    let tx = self.into_inner();
+    let estimate = estimation.estimate(&tx, client.sender_address(), client).await?;
+    estimate.check_budget(budget)?;
+
+    Ok(TransactionBuilder::new(tx).with_gas_budget(budget))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn estimate_applies_safety_multiplier() {
+    let estimation = GasEstimation::new().with_safety_multiplier(1.5).unwrap();
+    let estimate = estimation.estimate_from_costs(1_000, 3_000, 2_000);
+
+    assert_eq!(estimate.required, 2_000);
+    assert_eq!(estimate.budget, 3_000);
+  }
+
+  #[test]
+  fn required_gas_covers_computation_cost() {
+    let estimate = GasEstimation::new().estimate_from_costs(1_000, 1_000, 5_000);
+
+    assert_eq!(estimate.required, 1_000);
+    assert_eq!(estimate.budget, 1_200);
+  }
+
+  #[test]
+  fn insufficient_budget_is_reported() {
+    let estimate = GasEstimation::new().estimate_from_costs(1_000, 0, 0);
+
+    assert!(estimate.check_budget(1_000).is_ok());
+    assert!(matches!(
+      estimate.check_budget(999),
+      Err(Error::InsufficientGas {
+        budget: 999,
+        required: 1_000,
+        estimate: 1_200,
+      })
+    ));
+  }
+
+  #[test]
+  fn invalid_safety_multiplier_is_rejected() {
+    assert!(GasEstimation::new().with_safety_multiplier(0.9).is_err());
+    assert!(GasEstimation::new().with_safety_multiplier(f64::NAN).is_err());
+  }
+}
@@ -24,6 +24,7 @@ pub(crate) fn propose_config_change<I1, I2>(
   identity: OwnedObjectRef,
   controller_cap: ControllerTokenRef,
   expiration: Option<u64>,
+  not_before: Option<u64>,
   threshold: Option<u64>,
   controllers_to_add: I1,
   controllers_to_remove: HashSet<ObjectId>,
@@ -68,20 +69,32 @@ where
   let threshold = utils::option_to_move(threshold, &mut ptb, package).map_err(rebased_err)?;
   let controllers_to_remove = ptb.pure(controllers_to_remove).map_err(rebased_err)?;
 
+  // Only time-locked proposals go through `propose_config_change_with_not_before`.
+  let (function, mut args) = if not_before.is_some() {
+    let not_before = utils::option_to_move(not_before, &mut ptb, package).map_err(rebased_err)?;
+    (
+      ident_str!("propose_config_change_with_not_before"),
+      vec![identity, capability.arg(), expiration, not_before],
+    )
+  } else {
+    (
+      ident_str!("propose_config_change"),
+      vec![identity, capability.arg(), expiration],
+    )
+  };
+  args.extend([
+    threshold,
+    controllers_to_add,
+    controllers_to_remove,
+    controllers_to_update,
+  ]);
+
   let _proposal_id = ptb.programmable_move_call(
     package,
     ident_str!("identity").as_str().into(),
-    ident_str!("propose_config_change").as_str().into(),
+    function.as_str().into(),
     vec![],
-    vec![
-      identity,
-      capability.arg(),
-      expiration,
-      threshold,
-      controllers_to_add,
-      controllers_to_remove,
-      controllers_to_update,
-    ],
+    args,
   );
 
   capability.put_back(&mut ptb, package);
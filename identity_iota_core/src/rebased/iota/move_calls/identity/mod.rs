@@ -28,6 +28,7 @@ use iota_interaction::MoveType;
 use iota_interaction::ProgrammableTransactionBcs;
 use iota_sdk_types::Argument;
 use iota_sdk_types::ObjectId;
+use iota_sdk_types::TypeTag;
 
 use crate::rebased::rebased_err;
 use crate::rebased::Error;
@@ -107,3 +108,33 @@ pub(crate) fn approve_proposal<T: MoveType>(
 
   Ok(bcs::to_bytes(&ptb.finish())?)
 }
+
+/// Approves all `proposals` - pairs of proposal ID and proposal action type - in a single transaction.
+pub(crate) fn approve_proposals(
+  identity: OwnedObjectRef,
+  controller_cap: ControllerTokenRef,
+  proposals: impl IntoIterator<Item = (ObjectId, TypeTag)>,
+  package: ObjectId,
+) -> Result<ProgrammableTransactionBcs, Error> {
+  let mut ptb = Ptb::new();
+  let identity = utils::owned_ref_to_shared_object_arg(identity, &mut ptb, true)
+    .map_err(|e| Error::TransactionBuildingFailed(e.to_string()))?;
+  let capability = ControllerTokenArg::from_ref(controller_cap, &mut ptb, package)?;
+
+  for (proposal_id, action_type) in proposals {
+    let proposal_id = ptb
+      .pure(proposal_id)
+      .map_err(|e| Error::InvalidArgument(e.to_string()))?;
+    ptb.programmable_move_call(
+      package,
+      ident_str!("identity").as_str().into(),
+      ident_str!("approve_proposal").as_str().into(),
+      vec![action_type],
+      vec![identity, capability.arg(), proposal_id],
+    );
+  }
+
+  capability.put_back(&mut ptb, package);
+
+  Ok(bcs::to_bytes(&ptb.finish())?)
+}
@@ -19,6 +19,7 @@ pub(crate) async fn propose_update(
   capability: ControllerTokenRef,
   did_doc: Option<&[u8]>,
   expiration: Option<u64>,
+  not_before: Option<u64>,
   package_id: ObjectId,
 ) -> Result<ProgrammableTransactionBcs, Error> {
   let mut ptb = Ptb::new();
@@ -26,14 +27,28 @@ pub(crate) async fn propose_update(
   let identity_arg = utils::owned_ref_to_shared_object_arg(identity, &mut ptb, true).map_err(rebased_err)?;
   let exp_arg = utils::option_to_move(expiration, &mut ptb, package_id).map_err(rebased_err)?;
   let doc_arg = ptb.pure(did_doc).map_err(rebased_err)?;
-  let clock = utils::get_clock_ref(&mut ptb);
+
+  // Only time-locked proposals go through `propose_update_with_not_before`.
+  let (function, mut args) = if not_before.is_some() {
+    let not_before_arg = utils::option_to_move(not_before, &mut ptb, package_id).map_err(rebased_err)?;
+    (
+      ident_str!("propose_update_with_not_before"),
+      vec![identity_arg, capability.arg(), doc_arg, exp_arg, not_before_arg],
+    )
+  } else {
+    (
+      ident_str!("propose_update"),
+      vec![identity_arg, capability.arg(), doc_arg, exp_arg],
+    )
+  };
+  args.push(utils::get_clock_ref(&mut ptb));
 
   let _proposal_id = ptb.programmable_move_call(
     package_id,
     ident_str!("identity").as_str().into(),
-    ident_str!("propose_update").as_str().into(),
+    function.as_str().into(),
     vec![],
-    vec![identity_arg, capability.arg(), doc_arg, exp_arg, clock],
+    args,
   );
 
   capability.put_back(&mut ptb, package_id);
@@ -61,3 +61,29 @@ pub(crate) fn execute_upgrade(
 
   Ok(bcs::to_bytes(&ptb.finish())?)
 }
+
+/// Migrates all `identities` to `package_id`'s version in a single transaction.
+/// Every controller token must grant enough voting power to execute the upgrade right away.
+pub(crate) fn migrate_identities<I>(identities: I, package_id: ObjectId) -> Result<ProgrammableTransactionBcs, Error>
+where
+  I: IntoIterator<Item = (OwnedObjectRef, ControllerTokenRef)>,
+{
+  let mut ptb = Ptb::new();
+  for (identity, capability) in identities {
+    let capability = ControllerTokenArg::from_ref(capability, &mut ptb, package_id)?;
+    let identity_arg = utils::owned_ref_to_shared_object_arg(identity, &mut ptb, true).map_err(rebased_err)?;
+    let exp_arg = utils::option_to_move(None::<u64>, &mut ptb, package_id).map_err(rebased_err)?;
+
+    let _proposal_id = ptb.programmable_move_call(
+      package_id,
+      ident_str!("identity").as_str().into(),
+      ident_str!("propose_upgrade").as_str().into(),
+      vec![],
+      vec![identity_arg, capability.arg(), exp_arg],
+    );
+
+    capability.put_back(&mut ptb, package_id);
+  }
+
+  Ok(bcs::to_bytes(&ptb.finish())?)
+}
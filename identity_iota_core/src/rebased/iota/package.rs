@@ -10,6 +10,7 @@ use iota_sdk_types::ObjectId;
 use product_common::core_client::CoreClientReadOnly;
 use serde::Deserialize;
 use serde::Serialize;
+use tokio::sync::OnceCell;
 use tokio::sync::RwLock;
 use tokio::sync::RwLockReadGuard;
 use tokio::sync::RwLockWriteGuard;
@@ -22,46 +23,36 @@ macro_rules! object_id {
   };
 }
 
-static IOTA_IDENTITY_PACKAGE_REGISTRY: LazyLock<RwLock<PackageRegistry>> = LazyLock::new(|| {
-  RwLock::new({
-    let mut registry = PackageRegistry::default();
-    // Add well-known networks.
-    registry.insert_env(
-      Env::new_with_alias("6364aad5", "iota"),
-      vec![
-        object_id!("0x84cf5d12de2f9731a89bb519bc0c982a941b319a33abefdd5ed2054ad931de08"),
-        object_id!("0x36d0d56aea27a59f620ba32b6dd47a5e68d810714468bd270fda5ad37a478767"),
-      ],
-    );
-    registry.insert_env(
-      Env::new_with_alias("2304aa97", "testnet"),
-      vec![
-        object_id!("0x222741bbdff74b42df48a7b4733185e9b24becb8ccfbafe8eac864ab4e4cc555"),
-        object_id!("0x3403da7ec4cd2ff9bdf6f34c0b8df5a2bd62c798089feb0d2ebf1c2e953296dc"),
-        object_id!("0x29359d33a2e84f04407da0d6cff15dd8ad271c75493ef6b78f381993e4c0abb0"),
-      ],
-    );
-    registry.insert_env(
-      Env::new_with_alias("daf90477", "devnet"),
-      vec![object_id!(
-        "0x8896ab04fe24c044c54925df3f8a7c383a8d1d6f6bbb95d1c57cfa94c75e520d"
-      )],
-    );
+/// Environment variable holding the ID of the `IotaIdentity` package to use on networks that have no
+/// registered package, e.g. local or private networks.
+/// Many IDs can be given, separated by commas, listing the package's versions from the oldest to the latest.
+pub const IOTA_IDENTITY_PKG_ID_ENV: &str = "IOTA_IDENTITY_PKG_ID";
+/// Environment variable holding the path to a JSON file listing `IotaIdentity` packages by network.
+/// See [`PackageRegistry::from_json`] for the file's format.
+pub const IOTA_IDENTITY_PACKAGE_REGISTRY_ENV: &str = "IOTA_IDENTITY_PACKAGE_REGISTRY";
 
-    registry
-  })
-});
+static IOTA_IDENTITY_PACKAGE_REGISTRY: LazyLock<RwLock<PackageRegistry>> =
+  LazyLock::new(|| RwLock::new(PackageRegistry::with_defaults()));
+static ENV_OVERRIDES: OnceCell<()> = OnceCell::const_new();
+
+/// The version of the `IotaIdentity` package this crate targets.
+/// Must match `PACKAGE_VERSION` in the package's `identity` module.
+pub(crate) const IDENTITY_PACKAGE_VERSION: u64 = 0;
 
 /// Network / Chain information.
 #[derive(Debug, Clone, Deserialize, Serialize)]
-pub(crate) struct Env {
+#[serde(rename_all = "camelCase")]
+pub struct PackageEnv {
+  /// The chain identifier of the network.
   pub chain_id: String,
+  /// A human-readable alias for the network, e.g. `testnet`.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
   pub alias: Option<String>,
 }
 
-impl Env {
+impl PackageEnv {
   /// Creates a new package's environment.
-  pub(crate) fn new(chain_id: impl Into<String>) -> Self {
+  pub fn new(chain_id: impl Into<String>) -> Self {
     Self {
       chain_id: chain_id.into(),
       alias: None,
@@ -69,7 +60,7 @@ impl Env {
   }
 
   /// Creates a new package's environment with the given alias.
-  pub(crate) fn new_with_alias(chain_id: impl Into<String>, alias: impl Into<String>) -> Self {
+  pub fn new_with_alias(chain_id: impl Into<String>, alias: impl Into<String>) -> Self {
     Self {
       chain_id: chain_id.into(),
       alias: Some(alias.into()),
@@ -77,39 +68,170 @@ impl Env {
   }
 }
 
+#[derive(Deserialize)]
+struct PackageRegistryEntry {
+  #[serde(flatten)]
+  env: PackageEnv,
+  packages: Vec<ObjectId>,
+}
+
+/// The known versions of the `IotaIdentity` package, by network.
+///
+/// The registry used by this crate is initialized with the packages published on IOTA's official networks and can
+/// be extended at runtime through [`configure_identity_packages`], or through the [`IOTA_IDENTITY_PKG_ID_ENV`] and
+/// [`IOTA_IDENTITY_PACKAGE_REGISTRY_ENV`] environment variables, to support local and private networks.
 #[derive(Debug, Clone, Default)]
-pub(crate) struct PackageRegistry {
+pub struct PackageRegistry {
   aliases: HashMap<String, String>,
   envs: HashMap<String, Vec<ObjectId>>,
+  fallback: Option<Vec<ObjectId>>,
 }
 
 impl PackageRegistry {
+  /// Returns a [`PackageRegistry`] holding the packages published on IOTA's official networks.
+  pub fn with_defaults() -> Self {
+    let mut registry = Self::default();
+    // Add well-known networks.
+    registry.insert_env(
+      PackageEnv::new_with_alias("6364aad5", "iota"),
+      vec![
+        object_id!("0x84cf5d12de2f9731a89bb519bc0c982a941b319a33abefdd5ed2054ad931de08"),
+        object_id!("0x36d0d56aea27a59f620ba32b6dd47a5e68d810714468bd270fda5ad37a478767"),
+      ],
+    );
+    registry.insert_env(
+      PackageEnv::new_with_alias("2304aa97", "testnet"),
+      vec![
+        object_id!("0x222741bbdff74b42df48a7b4733185e9b24becb8ccfbafe8eac864ab4e4cc555"),
+        object_id!("0x3403da7ec4cd2ff9bdf6f34c0b8df5a2bd62c798089feb0d2ebf1c2e953296dc"),
+        object_id!("0x29359d33a2e84f04407da0d6cff15dd8ad271c75493ef6b78f381993e4c0abb0"),
+      ],
+    );
+    registry.insert_env(
+      PackageEnv::new_with_alias("daf90477", "devnet"),
+      vec![object_id!(
+        "0x8896ab04fe24c044c54925df3f8a7c383a8d1d6f6bbb95d1c57cfa94c75e520d"
+      )],
+    );
+
+    registry
+  }
+
+  /// Parses a [`PackageRegistry`] from a JSON array of networks, each listing its package's versions from the oldest
+  /// to the latest:
+  /// ```json
+  /// [{ "chainId": "a1b2c3d4", "alias": "localnet", "packages": ["0x...", "0x..."] }]
+  /// ```
+  pub fn from_json(json: &str) -> Result<Self, Error> {
+    let entries: Vec<PackageRegistryEntry> = serde_json::from_str(json)
+      .map_err(|e| Error::InvalidConfig(format!("invalid `IotaIdentity` package registry: {e}")))?;
+
+    let mut registry = Self::default();
+    for PackageRegistryEntry { env, packages } in entries {
+      if packages.is_empty() {
+        return Err(Error::InvalidConfig(format!(
+          "no `IotaIdentity` package given for network {}",
+          env.chain_id
+        )));
+      }
+      registry.insert_env(env, packages);
+    }
+
+    Ok(registry)
+  }
+
+  /// Reads a [`PackageRegistry`] from the JSON file at `path`. See [`PackageRegistry::from_json`].
+  #[cfg(not(target_arch = "wasm32"))]
+  pub fn from_file(path: impl AsRef<std::path::Path>) -> Result<Self, Error> {
+    let path = path.as_ref();
+    let json = std::fs::read_to_string(path).map_err(|e| {
+      Error::InvalidConfig(format!(
+        "cannot read `IotaIdentity` package registry {}: {e}",
+        path.display()
+      ))
+    })?;
+
+    Self::from_json(&json)
+  }
+
+  /// Returns a [`PackageRegistry`] holding the packages configured through the [`IOTA_IDENTITY_PACKAGE_REGISTRY_ENV`]
+  /// and [`IOTA_IDENTITY_PKG_ID_ENV`] environment variables.
+  #[cfg(not(target_arch = "wasm32"))]
+  pub fn from_env() -> Result<Self, Error> {
+    let mut registry = match std::env::var_os(IOTA_IDENTITY_PACKAGE_REGISTRY_ENV) {
+      Some(path) => Self::from_file(path)?,
+      None => Self::default(),
+    };
+
+    if let Ok(ids) = std::env::var(IOTA_IDENTITY_PKG_ID_ENV) {
+      let history = ids
+        .split(',')
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(|id| {
+          id.parse::<ObjectId>()
+            .map_err(|e| Error::InvalidConfig(format!("invalid {IOTA_IDENTITY_PKG_ID_ENV} value `{id}`: {e}")))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+      if !history.is_empty() {
+        registry.set_fallback(history);
+      }
+    }
+
+    Ok(registry)
+  }
+
   /// Returns the historical list of this package's versions for a given `chain`.
   /// `chain` can either be a chain identifier or its alias.
   ///
   /// ID at position `0` is the first ever published version of the package, `1` is
   /// the second, and so forth until the last, which is the currently active version.
-  pub(crate) fn history(&self, chain: &str) -> Option<&[ObjectId]> {
+  pub fn history(&self, chain: &str) -> Option<&[ObjectId]> {
     let from_alias = || self.aliases.get(chain).and_then(|chain_id| self.envs.get(chain_id));
-    self.envs.get(chain).or_else(from_alias).map(|v| v.as_slice())
+    self
+      .envs
+      .get(chain)
+      .or_else(from_alias)
+      .or(self.fallback.as_ref())
+      .map(|v| v.as_slice())
   }
 
   /// Returns this package's latest version ID for a given chain.
-  pub(crate) fn package_id(&self, chain: &str) -> Option<ObjectId> {
+  pub fn package_id(&self, chain: &str) -> Option<ObjectId> {
     self.history(chain).and_then(|versions| versions.last()).copied()
   }
 
   /// Returns the alias of a given chain-id.
-  pub(crate) fn chain_alias(&self, chain_id: &str) -> Option<&str> {
+  pub fn chain_alias(&self, chain_id: &str) -> Option<&str> {
     self
       .aliases
       .iter()
       .find_map(|(alias, chain)| (chain == chain_id).then_some(alias.as_str()))
   }
 
+  /// Returns the chain identifier of `chain`, which can either be a chain identifier or its alias.
+  ///
+  /// Returns `None` if `chain` is neither a known chain identifier nor a known alias.
+  pub fn chain_id<'a>(&'a self, chain: &'a str) -> Option<&'a str> {
+    if self.envs.contains_key(chain) {
+      return Some(chain);
+    }
+    self.aliases.get(chain).map(String::as_str)
+  }
+
+  /// Returns the other name of `chain`, i.e. its alias if `chain` is a chain identifier and its chain identifier if it
+  /// is an alias.
+  pub fn equivalent_network(&self, chain: &str) -> Option<&str> {
+    self
+      .aliases
+      .get(chain)
+      .map(String::as_str)
+      .or_else(|| self.chain_alias(chain))
+  }
+
   /// Adds or replaces this package's metadata for a given environment.
-  pub(crate) fn insert_env(&mut self, env: Env, history: Vec<ObjectId>) {
-    let Env { chain_id, alias } = env;
+  pub fn insert_env(&mut self, env: PackageEnv, history: Vec<ObjectId>) {
+    let PackageEnv { chain_id, alias } = env;
 
     if let Some(alias) = alias {
       self.aliases.insert(alias, chain_id.clone());
@@ -117,20 +239,77 @@ impl PackageRegistry {
     self.envs.insert(chain_id, history);
   }
 
-  pub(crate) fn insert_new_package_version(&mut self, chain_id: &str, package: ObjectId) {
+  /// Adds `package` as the latest version of this package for the given chain.
+  pub fn insert_new_package_version(&mut self, chain_id: &str, package: ObjectId) {
     let history = self.envs.entry(chain_id.to_string()).or_default();
     if history.last() != Some(&package) {
       history.push(package)
     }
   }
+
+  /// Sets the package's versions to use on networks that have no registered package.
+  pub fn set_fallback(&mut self, history: Vec<ObjectId>) {
+    self.fallback = Some(history);
+  }
+
+  /// Adds all the networks of `other` to this registry, replacing the ones already registered.
+  /// `other`'s fallback, if any, replaces this registry's one.
+  pub fn merge(&mut self, other: PackageRegistry) {
+    let PackageRegistry {
+      aliases,
+      envs,
+      fallback,
+    } = other;
+
+    self.aliases.extend(aliases);
+    self.envs.extend(envs);
+    if fallback.is_some() {
+      self.fallback = fallback;
+    }
+  }
+}
+
+/// Updates the [`PackageRegistry`] used by this crate to look up the `IotaIdentity` package of a network.
+///
+/// Clients that are already connected keep using the packages they were created with.
+/// # Example
+/// ```ignore
+/// configure_identity_packages(|registry| {
+///   registry.insert_env(PackageEnv::new_with_alias("a1b2c3d4", "localnet"), vec![package_id]);
+/// })
+/// .await?;
+/// ```
+pub async fn configure_identity_packages<F>(f: F) -> Result<(), Error>
+where
+  F: FnOnce(&mut PackageRegistry),
+{
+  f(&mut *identity_package_registry_mut().await?);
+  Ok(())
 }
 
-pub(crate) async fn identity_package_registry() -> RwLockReadGuard<'static, PackageRegistry> {
-  IOTA_IDENTITY_PACKAGE_REGISTRY.read().await
+/// Applies the overrides configured through environment variables, once.
+async fn apply_env_overrides() -> Result<(), Error> {
+  ENV_OVERRIDES
+    .get_or_try_init(|| async {
+      #[cfg(not(target_arch = "wasm32"))]
+      {
+        let overrides = PackageRegistry::from_env()?;
+        IOTA_IDENTITY_PACKAGE_REGISTRY.write().await.merge(overrides);
+      }
+      Ok::<_, Error>(())
+    })
+    .await
+    .map(|_| ())
 }
 
-pub(crate) async fn identity_package_registry_mut() -> RwLockWriteGuard<'static, PackageRegistry> {
-  IOTA_IDENTITY_PACKAGE_REGISTRY.write().await
+pub(crate) async fn identity_package_registry() -> Result<RwLockReadGuard<'static, PackageRegistry>, Error> {
+  apply_env_overrides().await?;
+  Ok(IOTA_IDENTITY_PACKAGE_REGISTRY.read().await)
+}
+
+pub(crate) async fn identity_package_registry_mut() -> Result<RwLockWriteGuard<'static, PackageRegistry>, Error> {
+  apply_env_overrides().await?;
+  Ok(IOTA_IDENTITY_PACKAGE_REGISTRY.write().await)
 }
 
 pub(crate) async fn identity_package_id<C>(client: &C) -> Result<ObjectId, Error>
@@ -138,19 +317,75 @@ where
   C: CoreClientReadOnly,
 {
   let network = client.network_name().as_ref();
-  IOTA_IDENTITY_PACKAGE_REGISTRY
-    .read()
-    .await
+  identity_package_registry()
+    .await?
     .package_id(network)
     .ok_or_else(|| Error::InvalidConfig(format!("cannot find `IotaIdentity` package ID for network {network}")))
 }
 
+/// Returns all the known versions of the IotaIdentity package on `client`'s network, oldest first.
+pub(crate) async fn identity_package_history<C>(client: &C) -> Result<Vec<ObjectId>, Error>
+where
+  C: CoreClientReadOnly,
+{
+  let network = client.network_name().as_ref();
+  identity_package_registry()
+    .await?
+    .history(network)
+    .map(<[ObjectId]>::to_vec)
+    .ok_or_else(|| Error::InvalidConfig(format!("cannot find `IotaIdentity` package ID for network {network}")))
+}
+
 #[cfg(test)]
 mod tests {
   use iota_interaction::IotaClientBuilder;
+  use iota_sdk_types::ObjectId;
 
   use crate::rebased::client::IdentityClientReadOnly;
 
+  use super::PackageEnv;
+  use super::PackageRegistry;
+
+  const PACKAGE_1: &str = "0x0000000000000000000000000000000000000000000000000000000000000001";
+  const PACKAGE_2: &str = "0x0000000000000000000000000000000000000000000000000000000000000002";
+
+  fn package(id: &str) -> ObjectId {
+    ObjectId::from_prefixed_hex(id).unwrap()
+  }
+
+  #[test]
+  fn registry_can_be_parsed_from_json() {
+    let json =
+      format!(r#"[{{ "chainId": "a1b2c3d4", "alias": "localnet", "packages": ["{PACKAGE_1}", "{PACKAGE_2}"] }}]"#);
+    let registry = PackageRegistry::from_json(&json).unwrap();
+    let expected = [package(PACKAGE_1), package(PACKAGE_2)];
+
+    assert_eq!(registry.history("a1b2c3d4"), Some(expected.as_slice()));
+    assert_eq!(registry.package_id("localnet"), Some(expected[1]));
+    assert_eq!(registry.chain_alias("a1b2c3d4"), Some("localnet"));
+    assert_eq!(registry.chain_id("localnet"), Some("a1b2c3d4"));
+    assert_eq!(registry.chain_id("a1b2c3d4"), Some("a1b2c3d4"));
+    assert_eq!(registry.chain_id("unknown"), None);
+    assert_eq!(registry.equivalent_network("localnet"), Some("a1b2c3d4"));
+    assert_eq!(registry.equivalent_network("a1b2c3d4"), Some("localnet"));
+    assert!(PackageRegistry::from_json(r#"[{ "chainId": "a1b2c3d4", "packages": [] }]"#).is_err());
+  }
+
+  #[test]
+  fn fallback_is_used_for_unknown_networks_only() {
+    let mut overrides = PackageRegistry::default();
+    overrides.set_fallback(vec![package(PACKAGE_1)]);
+    overrides.insert_env(PackageEnv::new("a1b2c3d4"), vec![package(PACKAGE_2)]);
+
+    let mut registry = PackageRegistry::with_defaults();
+    let testnet_package = registry.package_id("testnet");
+    registry.merge(overrides);
+
+    assert_eq!(registry.package_id("testnet"), testnet_package);
+    assert_eq!(registry.package_id("a1b2c3d4"), Some(package(PACKAGE_2)));
+    assert_eq!(registry.package_id("unknown"), Some(package(PACKAGE_1)));
+  }
+
   #[tokio::test]
   async fn can_connect_to_testnet() -> anyhow::Result<()> {
     let iota_client = IotaClientBuilder::default().build_testnet().await?;
@@ -112,10 +112,13 @@ impl MigrateLegacyIdentity {
       .await
       .map_err(|e| Error::RpcError(e.to_string()))?
       .owner()
-      .expect("owner was requested")
+      .ok_or(Error::ObjectNotFound(*alias.id.object_id()))?
       .address_or_object()
       .copied()
-      .expect("alias is a dynamic field")
+      .ok_or_else(|| Error::StaleObjectRef {
+        id: *alias.id.object_id(),
+        reason: "alias is not a dynamic field".to_owned(),
+      })?
       .into();
     let alias_output_id = client
       .client_adapter()
@@ -124,10 +127,13 @@ impl MigrateLegacyIdentity {
       .await
       .map_err(|e| Error::RpcError(e.to_string()))?
       .owner()
-      .expect("owner was requested")
+      .ok_or(Error::ObjectNotFound(dynamic_field_wrapper))?
       .address_or_object()
       .copied()
-      .expect("alias is owned by an alias_output")
+      .ok_or_else(|| Error::StaleObjectRef {
+        id: dynamic_field_wrapper,
+        reason: "alias is not owned by an alias output".to_owned(),
+      })?
       .into();
     // Get alias_output's ref.
     let alias_output_ref = client
@@ -137,7 +143,7 @@ impl MigrateLegacyIdentity {
       .await
       .map_err(|e| Error::RpcError(e.to_string()))?
       .object_ref_if_exists()
-      .expect("alias_output exists");
+      .ok_or(Error::ObjectNotFound(alias_output_id))?;
     // Get migration registry ref.
     let migration_registry_id = migration_registry_id(client)
       .await
@@ -145,7 +151,7 @@ impl MigrateLegacyIdentity {
     let migration_registry_ref = client
       .get_object_ref_by_id(migration_registry_id)
       .await?
-      .expect("migration registry exists");
+      .ok_or(Error::ObjectNotFound(migration_registry_id))?;
 
     // Extract creation metadata
     let created = did_doc
@@ -0,0 +1,375 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use iota_interaction::ident_str;
+use iota_interaction::rpc_types::IotaObjectData;
+use iota_interaction::rpc_types::IotaObjectDataFilter;
+use iota_interaction::rpc_types::IotaObjectDataOptions;
+use iota_interaction::types::base_types::ObjectType;
+use iota_interaction::types::id::UID;
+use iota_interaction::types::programmable_transaction_builder::ProgrammableTransactionBuilder as Ptb;
+use iota_interaction::types::IOTA_FRAMEWORK_PACKAGE_ID;
+use iota_interaction::OptionalSync;
+use iota_sdk_types::Address;
+use iota_sdk_types::Argument;
+use iota_sdk_types::ObjectId;
+use iota_sdk_types::StructTag;
+use iota_sdk_types::TypeTag;
+use product_common::core_client::CoreClientReadOnly;
+use serde::Deserialize;
+
+use crate::rebased::proposals::BorrowAction;
+use crate::rebased::proposals::BorrowIntentFnT;
+use crate::rebased::proposals::ProposalBuilder;
+use crate::rebased::Error;
+
+use super::ControllerToken;
+use super::OnChainIdentity;
+
+/// A single `0x2::coin::Coin` object owned by an [`OnChainIdentity`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IdentityCoin {
+  id: ObjectId,
+  balance: u64,
+}
+
+impl IdentityCoin {
+  /// Returns this coin's ID.
+  pub fn id(&self) -> ObjectId {
+    self.id
+  }
+
+  /// Returns this coin's balance.
+  pub fn balance(&self) -> u64 {
+    self.balance
+  }
+}
+
+/// The funds of a given coin type owned by an [`OnChainIdentity`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoinBalance {
+  coin_type: TypeTag,
+  total_balance: u64,
+  coins: Vec<IdentityCoin>,
+}
+
+impl CoinBalance {
+  fn new(coin_type: TypeTag) -> Self {
+    Self {
+      coin_type,
+      total_balance: 0,
+      coins: vec![],
+    }
+  }
+
+  fn push(&mut self, coin: IdentityCoin) {
+    self.total_balance = self.total_balance.saturating_add(coin.balance);
+    self.coins.push(coin);
+  }
+
+  /// Returns the type `T` of the `Coin<T>` objects making up this balance.
+  pub fn coin_type(&self) -> &TypeTag {
+    &self.coin_type
+  }
+
+  /// Returns the sum of the balances of all coins of this type.
+  pub fn total_balance(&self) -> u64 {
+    self.total_balance
+  }
+
+  /// Returns the coins making up this balance, sorted from the largest to the smallest.
+  pub fn coins(&self) -> &[IdentityCoin] {
+    &self.coins
+  }
+
+  /// Selects the coins needed to cover `amount`, largest coins first.
+  /// Returns `None` if this balance is insufficient.
+  pub fn select_coins(&self, amount: u64) -> Option<Vec<ObjectId>> {
+    if amount > self.total_balance {
+      return None;
+    }
+
+    let mut selected = vec![];
+    let mut covered = 0_u64;
+    for coin in self.coins.iter() {
+      if covered >= amount {
+        break;
+      }
+      covered += coin.balance;
+      selected.push(coin.id);
+    }
+
+    Some(selected)
+  }
+}
+
+/// On-chain layout of a `0x2::coin::Coin<T>`.
+#[derive(Deserialize)]
+struct CoinData {
+  #[allow(dead_code)]
+  id: UID,
+  balance: u64,
+}
+
+fn coin_struct_tag(type_param: Option<TypeTag>) -> StructTag {
+  StructTag::new(
+    IOTA_FRAMEWORK_PACKAGE_ID,
+    ident_str!("coin").as_str(),
+    ident_str!("Coin").as_str(),
+    type_param.into_iter().collect(),
+  )
+}
+
+/// Returns the type `T` of a `0x2::coin::Coin<T>`, or `None` if `obj_data` is not a coin.
+fn coin_type_of(obj_data: &IotaObjectData) -> Option<TypeTag> {
+  let ObjectType::Struct(obj_type) = obj_data.object_type().ok()? else {
+    return None;
+  };
+  coin_type_from_tag(StructTag::from(obj_type))
+}
+
+/// Returns `T` if `tag` is `0x2::coin::Coin<T>`.
+pub(super) fn coin_type_from_tag(mut tag: StructTag) -> Option<TypeTag> {
+  let coin_tag = coin_struct_tag(None);
+  let is_coin =
+    tag.address() == coin_tag.address() && tag.module() == coin_tag.module() && tag.name() == coin_tag.name();
+  if !is_coin || tag.type_params().len() != 1 {
+    return None;
+  }
+
+  Some(tag.type_params_mut().remove(0))
+}
+
+fn coin_balance_of(obj_data: &IotaObjectData) -> Option<u64> {
+  obj_data
+    .move_object_bcs()
+    .and_then(|bcs_content| bcs::from_bytes::<CoinData>(bcs_content).ok())
+    .map(|coin| coin.balance)
+}
+
+/// Returns the borrowed coins in `objects` together with their balance, largest first.
+fn borrowed_coins(objects: &HashMap<ObjectId, (Argument, IotaObjectData)>) -> Vec<(Argument, TypeTag, u64)> {
+  let mut coins = objects
+    .values()
+    .filter_map(|(arg, obj_data)| Some((*arg, coin_type_of(obj_data)?, coin_balance_of(obj_data)?)))
+    .collect::<Vec<_>>();
+  coins.sort_by(|(_, _, a), (_, _, b)| b.cmp(a));
+
+  coins
+}
+
+fn split_coin(ptb: &mut Ptb, coin: Argument, coin_type: TypeTag, amount: u64) -> Argument {
+  let amount = ptb.pure(amount).expect("u64 is a valid pure argument");
+  ptb.programmable_move_call(
+    IOTA_FRAMEWORK_PACKAGE_ID,
+    ident_str!("coin").as_str().into(),
+    ident_str!("split").as_str().into(),
+    vec![coin_type],
+    vec![coin, amount],
+  )
+}
+
+fn join_coin(ptb: &mut Ptb, target: Argument, coin_type: TypeTag, coin: Argument) {
+  ptb.programmable_move_call(
+    IOTA_FRAMEWORK_PACKAGE_ID,
+    ident_str!("coin").as_str().into(),
+    ident_str!("join").as_str().into(),
+    vec![coin_type],
+    vec![target, coin],
+  );
+}
+
+/// Returns a borrow intent that takes `amount` out of the borrowed coins and transfers it to `recipient`.
+///
+/// The amount is collected starting from the largest borrowed coin; all borrowed coins are returned to
+/// the identity afterwards. Use this intent to execute a pending proposal created through
+/// [`OnChainIdentity::send_amount`].
+pub fn send_amount_intent(amount: u64, recipient: Address) -> impl BorrowIntentFnT + Send + Sync {
+  move |ptb: &mut Ptb, objects: &HashMap<ObjectId, (Argument, IotaObjectData)>| {
+    let mut remaining = amount;
+    let mut payment: Option<Argument> = None;
+    let coins = borrowed_coins(objects);
+    let last_idx = coins.len().saturating_sub(1);
+
+    for (idx, (coin, coin_type, balance)) in coins.into_iter().enumerate() {
+      if remaining == 0 {
+        break;
+      }
+      // Ask the last coin for whatever is left, letting the transaction fail if funds are insufficient.
+      let to_take = if idx == last_idx {
+        remaining
+      } else {
+        remaining.min(balance)
+      };
+      if to_take == 0 {
+        continue;
+      }
+      remaining -= to_take;
+
+      let split = split_coin(ptb, coin, coin_type.clone(), to_take);
+      match payment {
+        Some(payment) => join_coin(ptb, payment, coin_type, split),
+        None => payment = Some(split),
+      }
+    }
+
+    if let Some(payment) = payment {
+      ptb.transfer_arg(recipient, payment);
+    }
+  }
+}
+
+/// Returns a borrow intent that moves the balance of all borrowed coins into the largest one.
+///
+/// # Notes
+/// Borrowed coins must be returned to the identity, hence the emptied coins are not destroyed
+/// and remain owned by the identity with a balance of zero.
+pub fn merge_coins_intent() -> impl BorrowIntentFnT + Send + Sync {
+  |ptb: &mut Ptb, objects: &HashMap<ObjectId, (Argument, IotaObjectData)>| {
+    let mut coins = borrowed_coins(objects).into_iter();
+    let Some((target, _, _)) = coins.next() else {
+      return;
+    };
+
+    for (coin, coin_type, balance) in coins.filter(|(_, _, balance)| *balance > 0) {
+      let split = split_coin(ptb, coin, coin_type.clone(), balance);
+      join_coin(ptb, target, coin_type, split);
+    }
+  }
+}
+
+impl OnChainIdentity {
+  /// Returns the balances of all the coins owned by this identity, grouped by coin type.
+  pub async fn balances<C>(&self, client: &C) -> Result<Vec<CoinBalance>, Error>
+  where
+    C: CoreClientReadOnly + OptionalSync,
+  {
+    let coins = self
+      .fetch_owned_objects(
+        Some(IotaObjectDataFilter::StructType(coin_struct_tag(None))),
+        IotaObjectDataOptions::default().with_type().with_bcs(),
+        client,
+      )
+      .await?;
+
+    let mut balances: Vec<CoinBalance> = vec![];
+    for obj_data in coins.iter() {
+      let (Some(coin_type), Some(balance)) = (coin_type_of(obj_data), coin_balance_of(obj_data)) else {
+        continue;
+      };
+      let coin = IdentityCoin {
+        id: obj_data.object_id,
+        balance,
+      };
+      match balances.iter_mut().find(|b| b.coin_type == coin_type) {
+        Some(coin_balance) => coin_balance.push(coin),
+        None => {
+          let mut coin_balance = CoinBalance::new(coin_type);
+          coin_balance.push(coin);
+          balances.push(coin_balance);
+        }
+      }
+    }
+
+    balances
+      .iter_mut()
+      .for_each(|balance| balance.coins.sort_by(|a, b| b.balance.cmp(&a.balance)));
+
+    Ok(balances)
+  }
+
+  /// Returns the balance of this identity's coins of type `coin_type`.
+  pub async fn balance<C>(&self, coin_type: &TypeTag, client: &C) -> Result<CoinBalance, Error>
+  where
+    C: CoreClientReadOnly + OptionalSync,
+  {
+    let balance = self
+      .balances(client)
+      .await?
+      .into_iter()
+      .find(|balance| balance.coin_type() == coin_type)
+      .unwrap_or_else(|| CoinBalance::new(coin_type.clone()));
+
+    Ok(balance)
+  }
+
+  /// Proposes to send `amount` of this identity's funds to `recipient`, selecting which of the
+  /// coins in `balance` should be used.
+  ///
+  /// # Notes
+  /// The coins are borrowed by the resulting proposal. If the proposal cannot be executed right away,
+  /// it can later be executed with [`send_amount_intent`].
+  pub fn send_amount<'i, 'c>(
+    &'i mut self,
+    balance: &CoinBalance,
+    amount: u64,
+    recipient: Address,
+    controller_token: &'c ControllerToken,
+  ) -> Result<ProposalBuilder<'i, 'c, BorrowAction<impl BorrowIntentFnT + Send + Sync>>, Error> {
+    if amount == 0 {
+      return Err(Error::InvalidArgument("cannot send an amount of zero".to_owned()));
+    }
+    let coins = balance.select_coins(amount).ok_or_else(|| {
+      Error::InvalidArgument(format!(
+        "insufficient funds: identity {} owns {} of coin type `{}`, but {amount} was requested",
+        self.id(),
+        balance.total_balance(),
+        balance.coin_type(),
+      ))
+    })?;
+
+    let action = BorrowAction::new_with_intent(coins, send_amount_intent(amount, recipient));
+    Ok(ProposalBuilder::new(self, controller_token, action))
+  }
+
+  /// Proposes to merge all the coins in `balance` into a single one.
+  /// See [`merge_coins_intent`] for more details.
+  pub fn merge_coins<'i, 'c>(
+    &'i mut self,
+    balance: &CoinBalance,
+    controller_token: &'c ControllerToken,
+  ) -> Result<ProposalBuilder<'i, 'c, BorrowAction<impl BorrowIntentFnT + Send + Sync>>, Error> {
+    if balance.coins().len() < 2 {
+      return Err(Error::InvalidArgument(format!(
+        "at least two coins of type `{}` are required for merging",
+        balance.coin_type()
+      )));
+    }
+
+    let coins = balance.coins().iter().map(IdentityCoin::id);
+    let action = BorrowAction::new_with_intent(coins, merge_coins_intent());
+    Ok(ProposalBuilder::new(self, controller_token, action))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn balance_of(amounts: &[u64]) -> CoinBalance {
+    let mut balance = CoinBalance::new(TypeTag::Bool);
+    for (i, amount) in amounts.iter().enumerate() {
+      balance.push(IdentityCoin {
+        id: ObjectId::new([i as u8; 32]),
+        balance: *amount,
+      });
+    }
+    balance
+  }
+
+  #[test]
+  fn select_coins_picks_largest_coins_first() {
+    let balance = balance_of(&[50, 30, 20]);
+    assert_eq!(balance.select_coins(60).unwrap().len(), 2);
+    assert_eq!(balance.select_coins(50).unwrap().len(), 1);
+    assert_eq!(balance.select_coins(100).unwrap().len(), 3);
+  }
+
+  #[test]
+  fn select_coins_fails_on_insufficient_funds() {
+    let balance = balance_of(&[50, 30, 20]);
+    assert!(balance.select_coins(101).is_none());
+  }
+}
@@ -122,7 +122,7 @@ impl ControllerToken {
     let obj_ref = client
       .get_object_ref_by_id(self.id())
       .await?
-      .expect("token exists on-chain")
+      .ok_or_else(|| Error::ObjectNotFound(self.id()))?
       .reference;
 
     Ok(match self {
@@ -427,7 +427,7 @@ impl Transaction for DelegateToken {
     let controller_cap_ref = client
       .get_object_ref_by_id(self.cap_id)
       .await?
-      .expect("ControllerCap exists on-chain")
+      .ok_or(Error::ObjectNotFound(self.cap_id))?
       .reference;
 
     let ptb_bcs =
@@ -554,11 +554,11 @@ impl Transaction for DelegationTokenRevocation {
     let identity_ref = client
       .get_object_ref_by_id(self.identity_id)
       .await?
-      .expect("identity exists on-chain");
+      .ok_or(Error::ObjectNotFound(self.identity_id))?;
     let controller_cap_ref = client
       .get_object_ref_by_id(self.controller_cap_id)
       .await?
-      .expect("controller_cap exists on-chain")
+      .ok_or(Error::ObjectNotFound(self.controller_cap_id))?
       .reference;
 
     let tx_bytes = if self.is_revocation() {
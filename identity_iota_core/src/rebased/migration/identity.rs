@@ -8,6 +8,7 @@ use std::error::Error as StdError;
 use crate::rebased::iota::move_calls;
 
 use crate::rebased::iota::package::identity_package_id;
+use crate::rebased::iota::package::IDENTITY_PACKAGE_VERSION;
 use crate::rebased::proposals::AccessSubIdentityBuilder;
 use iota_interaction::rpc_types::IotaObjectResponseError;
 use iota_interaction::IotaKeySignature;
@@ -54,6 +55,9 @@ use crate::rebased::client::IdentityClientReadOnly;
 use crate::rebased::proposals::BorrowAction;
 use crate::rebased::proposals::ConfigChange;
 use crate::rebased::proposals::ControllerExecution;
+use crate::rebased::proposals::CustomCallAction;
+use crate::rebased::proposals::CustomCallArg;
+use crate::rebased::proposals::CustomMoveCall;
 use crate::rebased::proposals::ProposalBuilder;
 use crate::rebased::proposals::SendAction;
 use crate::rebased::proposals::UpdateDidDocument;
@@ -67,6 +71,7 @@ use super::ControllerToken;
 use super::DelegationToken;
 use super::DelegationTokenRevocation;
 use super::DeleteDelegationToken;
+use super::InvalidControllerTokenForIdentity;
 use super::Multicontroller;
 use super::UnmigratedAlias;
 
@@ -148,6 +153,29 @@ impl OnChainIdentity {
     self.deleted_did
   }
 
+  /// Returns the version of the `IotaIdentity` package this [`OnChainIdentity`] has been migrated to.
+  pub fn version(&self) -> u64 {
+    self.version
+  }
+
+  /// Returns whether this [`OnChainIdentity`] must be migrated to the latest version of the `IotaIdentity` package,
+  /// e.g. after an on-chain package upgrade.
+  ///
+  /// The Identity's version is fetched from the network, as this [`OnChainIdentity`] might be outdated.
+  /// Identities needing a migration can be migrated through
+  /// [`MigrateIdentity`](crate::rebased::proposals::MigrateIdentity) or, for identities requiring
+  /// the approval of multiple controllers, through [`OnChainIdentity::upgrade_version`].
+  pub async fn needs_migration<C>(&self, client: &C) -> Result<bool, Error>
+  where
+    C: CoreClientReadOnly + OptionalSync,
+  {
+    let identity = get_identity(client, self.id())
+      .await?
+      .ok_or_else(|| Error::ObjectLookup(format!("identity {} doesn't exist", self.id())))?;
+
+    Ok(!identity.deleted && identity.version < IDENTITY_PACKAGE_VERSION)
+  }
+
   /// Returns true if this [`OnChainIdentity`] is shared between multiple controllers.
   pub fn is_shared(&self) -> bool {
     self.multi_controller.controllers().len() > 1
@@ -173,6 +201,20 @@ impl OnChainIdentity {
     self.multi_controller.controller_voting_power(controller_id)
   }
 
+  /// Returns the voting power of the controller `controller_token` grants access to, failing if the token
+  /// isn't valid for this [`OnChainIdentity`], e.g. because the controller was removed in the meantime.
+  pub(crate) fn voting_power_of(
+    &self,
+    controller_token: &ControllerToken,
+  ) -> Result<u64, InvalidControllerTokenForIdentity> {
+    self
+      .controller_voting_power(controller_token.controller_id())
+      .ok_or_else(|| InvalidControllerTokenForIdentity {
+        identity: self.id(),
+        controller_token: controller_token.clone(),
+      })
+  }
+
   /// Returns a [ControllerToken] owned by `address` that grants access to this Identity.
   /// ## Notes
   /// [None] is returned if `address` doesn't own a valid [ControllerToken].
@@ -208,6 +250,17 @@ impl OnChainIdentity {
       .await
   }
 
+  /// Returns the DIDs of the identities this identity controls, i.e. the identities for which this identity owns a
+  /// [ControllerToken].
+  /// ## Notes
+  /// See [IdentityClientReadOnly::controller_chain] to traverse an identity's controllers instead.
+  pub async fn controlled_identities(&self, client: &IdentityClientReadOnly) -> Result<Vec<IotaDID>, Error> {
+    client
+      .dids_controlled_by(self.id().into())
+      .await
+      .map_err(|e| Error::RpcError(format!("{:#}", anyhow::Error::new(e))))
+  }
+
   pub(crate) fn multicontroller(&self) -> &Multicontroller<Option<Vec<u8>>> {
     &self.multi_controller
   }
@@ -230,6 +283,10 @@ impl OnChainIdentity {
   }
 
   /// Deactivates the DID Document represented by this [`OnChainIdentity`].
+  ///
+  /// The proposal cannot be created if the DID Document is already deactivated or if this identity has pending
+  /// proposals, unless they are explicitly allowed through
+  /// [`ProposalBuilder::allow_pending_proposals`](crate::rebased::proposals::ProposalBuilder::allow_pending_proposals).
   pub fn deactivate_did<'i, 'c>(
     &'i mut self,
     controller_token: &'c ControllerToken,
@@ -237,6 +294,19 @@ impl OnChainIdentity {
     ProposalBuilder::new(self, controller_token, UpdateDidDocument::deactivate())
   }
 
+  /// Reactivates the deactivated DID Document represented by this [`OnChainIdentity`], replacing it with `document`.
+  ///
+  /// The proposal cannot be created if the DID Document is not deactivated or if this identity has pending
+  /// proposals, unless they are explicitly allowed through
+  /// [`ProposalBuilder::allow_pending_proposals`](crate::rebased::proposals::ProposalBuilder::allow_pending_proposals).
+  pub fn reactivate_did<'i, 'c>(
+    &'i mut self,
+    document: IotaDocument,
+    controller_token: &'c ControllerToken,
+  ) -> ProposalBuilder<'i, 'c, UpdateDidDocument> {
+    ProposalBuilder::new(self, controller_token, UpdateDidDocument::reactivate(document))
+  }
+
   /// Deletes the DID Document contained in this [OnChainIdentity].
   pub fn delete_did<'i, 'c>(
     &'i mut self,
@@ -282,6 +352,25 @@ impl OnChainIdentity {
     ProposalBuilder::new(self, controller_token, action)
   }
 
+  /// Calls `function` of `module` in the `iota_identity` package with arguments `args`, for functions that are
+  /// not wrapped by this SDK.
+  ///
+  /// The call goes through the usual proposal flow. Objects owned by this identity can be passed to the call
+  /// through [`CustomCallArg::Borrowed`]. See [`CustomMoveCall`] for more details.
+  pub fn execute_custom_move_call<'i, 'c, I>(
+    &'i mut self,
+    module: &str,
+    function: &str,
+    args: I,
+    controller_token: &'c ControllerToken,
+  ) -> ProposalBuilder<'i, 'c, CustomCallAction>
+  where
+    I: IntoIterator<Item = CustomCallArg>,
+  {
+    let action = CustomCallAction::new(CustomMoveCall::new(module, function, args));
+    ProposalBuilder::new(self, controller_token, action)
+  }
+
   /// Perform an action on an Identity that is controlled by this Identity.
   pub fn access_sub_identity<'i, 'sub>(
     &'i mut self,
@@ -376,7 +465,7 @@ pub fn has_previous_version(history_item: &IotaObjectData) -> Result<bool, Error
   }
 }
 
-async fn get_previous_version(
+pub(crate) async fn get_previous_version(
   client: &IdentityClientReadOnly,
   iod: IotaObjectData,
 ) -> Result<Option<IotaObjectData>, Error> {
@@ -428,44 +517,53 @@ pub(crate) async fn get_identity_impl(
   }
 
   let data = response.data.expect("already handled errors in response");
-  let network = client.network_name();
-  let did = IotaDID::from_object_id(object_id, network);
-  let IdentityData {
-    id,
-    multicontroller,
-    legacy_id,
-    created,
-    updated,
-    version,
-    deleted,
-    deleted_did,
-  } = unpack_identity_data(data)?;
-  let legacy_did = legacy_id.map(|legacy_id| IotaDID::from_object_id(legacy_id, client.network_name()));
-
-  let did_doc = multicontroller
-    .controlled_value()
-    .as_deref()
-    .map(|did_doc_bytes| IotaDocument::from_iota_document_data(did_doc_bytes, true, &did, legacy_did, created, updated))
-    .transpose()
-    .map_err(|e| IdentityResolutionError {
-      resolving: object_id,
-      kind: IdentityResolutionErrorKind::InvalidDidDocument(e.into()),
-    })?
-    .unwrap_or_else(|| {
-      let mut empty_did_doc = IotaDocument::new(network);
-      empty_did_doc.metadata.deactivated = Some(true);
-
-      empty_did_doc
-    });
+  OnChainIdentity::from_identity_data(unpack_identity_data(data)?, client.network_name())
+}
 
-  Ok(OnChainIdentity {
-    id,
-    multi_controller: multicontroller,
-    did_doc,
-    version,
-    deleted,
-    deleted_did,
-  })
+impl OnChainIdentity {
+  /// Builds an [`OnChainIdentity`] out of the data stored in an Identity object on network `network`.
+  pub(crate) fn from_identity_data(data: IdentityData, network: &NetworkName) -> Result<Self, IdentityResolutionError> {
+    let IdentityData {
+      id,
+      multicontroller,
+      legacy_id,
+      created,
+      updated,
+      version,
+      deleted,
+      deleted_did,
+    } = data;
+    let object_id = *id.object_id();
+    let did = IotaDID::from_object_id(object_id, network);
+    let legacy_did = legacy_id.map(|legacy_id| IotaDID::from_object_id(legacy_id, network));
+
+    let did_doc = multicontroller
+      .controlled_value()
+      .as_deref()
+      .map(|did_doc_bytes| {
+        IotaDocument::from_iota_document_data(did_doc_bytes, true, &did, legacy_did, created, updated)
+      })
+      .transpose()
+      .map_err(|e| IdentityResolutionError {
+        resolving: object_id,
+        kind: IdentityResolutionErrorKind::InvalidDidDocument(e.into()),
+      })?
+      .unwrap_or_else(|| {
+        let mut empty_did_doc = IotaDocument::new(network);
+        empty_did_doc.metadata.deactivated = Some(true);
+
+        empty_did_doc
+      });
+
+    Ok(OnChainIdentity {
+      id,
+      multi_controller: multicontroller,
+      did_doc,
+      version,
+      deleted,
+      deleted_did,
+    })
+  }
 }
 
 /// Type of failures that can be encountered when resolving an Identity.
@@ -560,18 +658,19 @@ pub(crate) fn unpack_identity_data(data: IotaObjectData) -> Result<IdentityData,
     }
   })?;
 
-  // Parse DID document timestamps
-  let created = {
-    let timestamp_ms: u64 = created.try_into().expect("Move string-encoded u64 are valid u64");
-    // `Timestamp` requires a timestamp expressed in seconds.
-    Timestamp::from_unix(timestamp_ms as i64 / 1000).expect("On-chain clock produces valid timestamps")
+  let malformed = |err: Box<dyn StdError + Send + Sync>| IdentityResolutionError {
+    resolving: data.object_id,
+    kind: IdentityResolutionErrorKind::Malformed(err),
   };
-  let updated = {
-    let timestamp_ms: u64 = updated.try_into().expect("Move string-encoded u64 are valid u64");
+  // Parse DID document timestamps
+  let parse_timestamp = |timestamp_ms: Number<u64>| -> Result<Timestamp, IdentityResolutionError> {
+    let timestamp_ms: u64 = timestamp_ms.try_into().map_err(|e| malformed(Box::new(e)))?;
     // `Timestamp` requires a timestamp expressed in seconds.
-    Timestamp::from_unix(timestamp_ms as i64 / 1000).expect("On-chain clock produces valid timestamps")
+    Timestamp::from_unix(timestamp_ms as i64 / 1000).map_err(|e| malformed(Box::new(e)))
   };
-  let version = version.try_into().expect("Move string-encoded u64 are valid u64");
+  let created = parse_timestamp(created)?;
+  let updated = parse_timestamp(updated)?;
+  let version = version.try_into().map_err(|e| malformed(Box::new(e)))?;
 
   Ok(IdentityData {
     id,
@@ -735,6 +834,15 @@ impl Transaction for CreateIdentity {
   type Output = OnChainIdentity;
   type Error = Error;
 
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+      name = "build_transaction",
+      skip_all,
+      fields(transaction = "CreateIdentity"),
+      err(Display)
+    )
+  )]
   async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
   where
     C: CoreClientReadOnly + OptionalSync,
@@ -742,6 +850,15 @@ impl Transaction for CreateIdentity {
     self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
   }
 
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+      name = "apply_transaction_effects",
+      skip_all,
+      fields(transaction = "CreateIdentity", digest = %effects.transaction_digest()),
+      err(Display)
+    )
+  )]
   async fn apply<C>(
     mut self,
     effects: &mut IotaTransactionBlockEffects,
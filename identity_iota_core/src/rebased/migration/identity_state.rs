@@ -0,0 +1,170 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use identity_core::common::Timestamp;
+use iota_interaction::rpc_types::IotaObjectData;
+use iota_interaction::rpc_types::IotaPastObjectResponse;
+use iota_sdk_types::ObjectId;
+use product_common::core_client::CoreClientReadOnly;
+
+use crate::rebased::client::IdentityClientReadOnly;
+use crate::rebased::rebased_err;
+use crate::rebased::Error;
+use crate::IotaDocument;
+
+use super::get_previous_version;
+use super::unpack_identity_data;
+use super::OnChainIdentity;
+
+/// The state of an [`OnChainIdentity`] as of a given version of its on-chain object.
+#[derive(Debug, Clone)]
+pub struct IdentityState {
+  object_version: u64,
+  updated: Timestamp,
+  identity: OnChainIdentity,
+}
+
+impl IdentityState {
+  /// Returns the version of the Identity object this state was read from.
+  pub fn object_version(&self) -> u64 {
+    self.object_version
+  }
+
+  /// Returns the time at which the DID Document of this state was last updated.
+  pub fn updated(&self) -> Timestamp {
+    self.updated
+  }
+
+  /// Returns the [`OnChainIdentity`] as it was in this state.
+  pub fn identity(&self) -> &OnChainIdentity {
+    &self.identity
+  }
+
+  /// Returns the DID Document as it was in this state.
+  pub fn did_document(&self) -> &IotaDocument {
+    self.identity.did_document()
+  }
+
+  /// Returns the Identity's controllers, as the map `controller_id -> controller_voting_power`, as they were in this
+  /// state.
+  pub fn controllers(&self) -> &HashMap<ObjectId, u64> {
+    self.identity.controllers()
+  }
+
+  /// Returns the Identity's threshold as it was in this state.
+  pub fn threshold(&self) -> u64 {
+    self.identity.threshold()
+  }
+
+  /// Consumes this state, returning the [`OnChainIdentity`] it wraps.
+  pub fn into_identity(self) -> OnChainIdentity {
+    self.identity
+  }
+}
+
+/// Reconstructs the states an [`OnChainIdentity`] went through by replaying the versions of its on-chain object.
+///
+/// Together with [`IdentityState::did_document`] this enables point-in-time checks, e.g. whether the key that
+/// signed a credential was part of the issuer's DID Document at the time of issuance.
+#[derive(Clone)]
+pub struct IdentityStateReplayer<'c> {
+  client: &'c IdentityClientReadOnly,
+  object_id: ObjectId,
+}
+
+impl<'c> IdentityStateReplayer<'c> {
+  /// Creates a new [`IdentityStateReplayer`] for the Identity having ID `object_id`.
+  pub fn new(client: &'c IdentityClientReadOnly, object_id: ObjectId) -> Self {
+    Self { client, object_id }
+  }
+
+  /// Returns the ID of the Identity whose states are replayed.
+  pub fn object_id(&self) -> ObjectId {
+    self.object_id
+  }
+
+  /// Returns all the states of the Identity, ordered from the oldest to the most recent one.
+  ///
+  /// ## Notes
+  /// Every version of the Identity object is fetched, which requires one request per version.
+  pub async fn replay(&self) -> Result<Vec<IdentityState>, Error> {
+    let mut states = vec![];
+    let mut current = Some(self.current_object_data().await?);
+    while let Some(object_data) = current {
+      current = get_previous_version(self.client, object_data.clone()).await?;
+      states.push(self.state_from_object_data(object_data)?);
+    }
+    states.reverse();
+
+    Ok(states)
+  }
+
+  /// Returns the state of the Identity as of object version `version`, i.e. the state produced by the latest
+  /// version that is lower than or equal to `version`.
+  ///
+  /// [None] is returned if the Identity was created after `version`.
+  pub async fn state_at_version(&self, version: u64) -> Result<Option<IdentityState>, Error> {
+    let states = self.replay().await?;
+    let position = states.iter().rposition(|state| state.object_version <= version);
+
+    Ok(position.map(|idx| states.into_iter().nth(idx).expect("valid index")))
+  }
+
+  /// Returns the state of the Identity as of `timestamp`, i.e. the most recent state whose DID Document
+  /// was last updated at or before `timestamp`.
+  ///
+  /// [None] is returned if the Identity was created after `timestamp`.
+  /// ## Notes
+  /// The on-chain Identity only tracks when its DID Document was updated. Changes to its controllers or threshold
+  /// are attributed to the DID Document update that precedes them.
+  pub async fn state_at(&self, timestamp: Timestamp) -> Result<Option<IdentityState>, Error> {
+    let states = self.replay().await?;
+    let position = states.iter().rposition(|state| state.updated <= timestamp);
+
+    Ok(position.map(|idx| states.into_iter().nth(idx).expect("valid index")))
+  }
+
+  async fn current_object_data(&self) -> Result<IotaObjectData, Error> {
+    let object_id = self.object_id;
+    let identity_ref = self
+      .client
+      .get_object_ref_by_id(object_id)
+      .await?
+      .ok_or_else(|| Error::InvalidIdentityHistory(format!("could not find Identity {object_id}")))?;
+    let version = identity_ref.version();
+    let response = self
+      .client
+      .get_past_object(object_id, version)
+      .await
+      .map_err(rebased_err)?;
+
+    if let IotaPastObjectResponse::VersionFound(object_data) = response {
+      Ok(object_data)
+    } else {
+      Err(Error::InvalidIdentityHistory(format!(
+        "could not find current version {version} of object {object_id}, response {response:?}"
+      )))
+    }
+  }
+
+  fn state_from_object_data(&self, object_data: IotaObjectData) -> Result<IdentityState, Error> {
+    let object_version = object_data.version.as_u64();
+    let identity_data = unpack_identity_data(object_data).map_err(history_error)?;
+    let updated = identity_data.updated;
+    let identity =
+      OnChainIdentity::from_identity_data(identity_data, self.client.network_name()).map_err(history_error)?;
+
+    Ok(IdentityState {
+      object_version,
+      updated,
+      identity,
+    })
+  }
+}
+
+fn history_error(error: impl std::error::Error + Send + Sync + 'static) -> Error {
+  // Use anyhow to format the error in such a way that all its causes are displayed too.
+  Error::InvalidIdentityHistory(format!("{:#}", anyhow::Error::new(error)))
+}
@@ -0,0 +1,184 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use identity_core::common::Url;
+use identity_did::DIDUrl;
+use identity_did::DID as _;
+use iota_interaction::rpc_types::IotaTransactionBlockEffects;
+use iota_interaction::OptionalSync;
+use iota_sdk_types::Address;
+use iota_sdk_types::ProgrammableTransaction;
+use product_common::core_client::CoreClientReadOnly;
+use product_common::transaction::transaction_builder::Transaction;
+
+use crate::rebased::client::PublishDidDocument;
+use crate::rebased::Error;
+use crate::IotaDID;
+use crate::IotaDocument;
+use crate::IotaDocumentMetadata;
+
+/// The outcome of the migration of a legacy DID document into a new [`OnChainIdentity`](super::OnChainIdentity),
+/// see [`IdentityClient::migrate_legacy_document`](crate::rebased::client::IdentityClient::migrate_legacy_document).
+#[derive(Debug, Clone)]
+pub struct LegacyMigrationReport {
+  legacy_did: IotaDID,
+  document: IotaDocument,
+  migrated_methods: Vec<DIDUrl>,
+  migrated_services: Vec<DIDUrl>,
+}
+
+impl LegacyMigrationReport {
+  fn new(legacy_did: IotaDID, document: IotaDocument) -> Self {
+    let migrated_methods = document
+      .methods(None)
+      .into_iter()
+      .map(|method| method.id().clone())
+      .collect();
+    let migrated_services = document.service().iter().map(|service| service.id().clone()).collect();
+    Self {
+      legacy_did,
+      document,
+      migrated_methods,
+      migrated_services,
+    }
+  }
+
+  /// Returns the DID of the legacy document.
+  pub fn legacy_did(&self) -> &IotaDID {
+    &self.legacy_did
+  }
+
+  /// Returns the DID of the new identity.
+  pub fn did(&self) -> &IotaDID {
+    self.document.id()
+  }
+
+  /// Returns the DID document published in the new identity.
+  pub fn document(&self) -> &IotaDocument {
+    &self.document
+  }
+
+  /// Returns the identifiers of the verification methods of the new document, which keep the fragments they had in
+  /// the legacy document.
+  pub fn migrated_methods(&self) -> &[DIDUrl] {
+    &self.migrated_methods
+  }
+
+  /// Returns the identifiers of the services of the new document, which keep the fragments they had in the legacy
+  /// document.
+  pub fn migrated_services(&self) -> &[DIDUrl] {
+    &self.migrated_services
+  }
+
+  /// Returns a copy of `legacy_document` linking to the new DID through `alsoKnownAs`.
+  ///
+  /// Publishing it, where the legacy identity can still be updated, completes the two-way link that
+  /// [`DidResolutionHandler::resolve_did_following_migration`](crate::DidResolutionHandler::resolve_did_following_migration)
+  /// follows.
+  pub fn legacy_document_update(&self, legacy_document: &IotaDocument) -> IotaDocument {
+    let mut update = legacy_document.clone();
+    update
+      .also_known_as_mut()
+      .append(Url::from(self.did().clone().into_url()));
+    update
+  }
+}
+
+/// Returns the document to publish in a new identity to migrate `legacy_document`.
+///
+/// The methods, services and other properties of `legacy_document` are kept, while its metadata is reset.
+/// Self-references to the legacy DID are replaced with the new one on publication.
+pub(crate) fn migrated_document(legacy_document: &IotaDocument) -> IotaDocument {
+  let mut document = legacy_document.clone();
+  document.metadata = IotaDocumentMetadata::new();
+  document
+    .also_known_as_mut()
+    .append(Url::from(legacy_document.id().clone().into_url()));
+  document
+}
+
+/// A [`Transaction`] creating a new [`OnChainIdentity`](super::OnChainIdentity) holding a copy of a legacy DID
+/// document, linked to the legacy DID through `alsoKnownAs`.
+#[derive(Debug)]
+pub struct MigrateLegacyDocument {
+  legacy_did: IotaDID,
+  publish: PublishDidDocument,
+}
+
+impl MigrateLegacyDocument {
+  /// Creates a new [`MigrateLegacyDocument`] transaction, creating an identity controlled by `controller`.
+  pub fn new(legacy_document: &IotaDocument, controller: Address) -> Self {
+    Self {
+      legacy_did: legacy_document.id().clone(),
+      publish: PublishDidDocument::new(migrated_document(legacy_document), controller),
+    }
+  }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl Transaction for MigrateLegacyDocument {
+  type Output = LegacyMigrationReport;
+  type Error = Error;
+
+  async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+  where
+    C: CoreClientReadOnly + OptionalSync,
+  {
+    self.publish.build_programmable_transaction(client).await
+  }
+
+  async fn apply<C>(self, effects: &mut IotaTransactionBlockEffects, client: &C) -> Result<Self::Output, Self::Error>
+  where
+    C: CoreClientReadOnly + OptionalSync,
+  {
+    let document = self.publish.apply(effects, client).await?;
+    Ok(LegacyMigrationReport::new(self.legacy_did, document))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_document::service::Service;
+  use identity_verification::MethodScope;
+  use product_common::network_name::NetworkName;
+
+  use super::*;
+  use crate::test_utils::generate_method;
+
+  #[test]
+  fn migrated_document_links_to_legacy_did() {
+    let legacy_did =
+      IotaDID::parse("did:iota:0xf4d6f08f5a1b80dd578da7dc1b49c886d580acd4cf7d48119dfeb82b538ad88a").unwrap();
+    let mut legacy_document = IotaDocument::new_with_id(legacy_did.clone());
+    legacy_document
+      .insert_method(generate_method(&legacy_did, "key-1"), MethodScope::assertion_method())
+      .unwrap();
+    legacy_document
+      .insert_service(
+        Service::builder(Default::default())
+          .id(legacy_did.to_url().join("#linked-domain").unwrap())
+          .type_("LinkedDomains")
+          .service_endpoint(Url::parse("https://example.com").unwrap())
+          .build()
+          .unwrap(),
+      )
+      .unwrap();
+    legacy_document.metadata.deactivated = Some(true);
+
+    let document = migrated_document(&legacy_document);
+    assert_eq!(document.metadata.deactivated, None);
+    assert_eq!(document.also_known_as().as_slice(), &[Url::from(legacy_did.to_url())]);
+
+    // Simulate the publication, which replaces the legacy DID with the one of the new identity.
+    let new_did = IotaDID::placeholder(&NetworkName::try_from("iota").unwrap());
+    let published = IotaDocument::new_with_id(new_did.clone());
+    let report = LegacyMigrationReport::new(legacy_did.clone(), published);
+    assert_eq!(report.did(), &new_did);
+    assert_eq!(report.legacy_did(), &legacy_did);
+
+    let update = report.legacy_document_update(&legacy_document);
+    assert!(update.also_known_as().contains(&Url::from(new_did.to_url())));
+  }
+}
@@ -2,13 +2,22 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod alias;
+mod coins;
 mod controller_token;
 mod identity;
+mod identity_state;
+mod legacy_migration;
 mod multicontroller;
+mod owned_objects;
 mod registry;
 
 pub use alias::*;
+pub use coins::*;
 pub use controller_token::*;
 pub use identity::*;
+pub use identity_state::*;
+pub use legacy_migration::LegacyMigrationReport;
+pub use legacy_migration::MigrateLegacyDocument;
 pub use multicontroller::*;
+pub use owned_objects::*;
 pub use registry::*;
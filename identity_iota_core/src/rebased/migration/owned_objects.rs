@@ -0,0 +1,236 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Context as _;
+use iota_interaction::ident_str;
+use iota_interaction::rpc_types::IotaData as _;
+use iota_interaction::rpc_types::IotaObjectData;
+use iota_interaction::rpc_types::IotaObjectDataFilter;
+use iota_interaction::rpc_types::IotaObjectDataOptions;
+use iota_interaction::rpc_types::IotaObjectResponseQuery;
+use iota_interaction::types::base_types::ObjectType;
+use iota_interaction::IotaClientTrait;
+use iota_interaction::MoveType;
+use iota_interaction::OptionalSync;
+use iota_sdk_types::Address;
+use iota_sdk_types::ObjectId;
+use iota_sdk_types::StructTag;
+use iota_sdk_types::TypeTag;
+use product_common::core_client::CoreClientReadOnly;
+use serde::de::DeserializeOwned;
+
+use crate::rebased::iota::package::identity_package_history;
+use crate::rebased::proposals::BorrowAction;
+use crate::rebased::proposals::ProposalBuilder;
+use crate::rebased::proposals::SendAction;
+use crate::rebased::Error;
+
+use super::coins::coin_type_from_tag;
+use super::ControllerToken;
+use super::OnChainIdentity;
+
+/// The kind of an object owned by an [`OnChainIdentity`], as decoded from its Move type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum OwnedObjectKind {
+  /// A `0x2::coin::Coin<T>`, where `T` is `coin_type`.
+  Coin {
+    /// The type of the coin.
+    coin_type: TypeTag,
+  },
+  /// An [`AuthenticatedAsset`](crate::rebased::AuthenticatedAsset) wrapping a value of type `content_type`.
+  AuthenticatedAsset {
+    /// The type of the asset's content.
+    content_type: TypeTag,
+  },
+  /// A [`ControllerCap`](super::ControllerCap) granting control over another identity.
+  ControllerCap,
+  /// A [`DelegationToken`](super::DelegationToken) granting delegated control over another identity.
+  DelegationToken,
+  /// Any other object, e.g. an NFT.
+  Other,
+}
+
+impl OwnedObjectKind {
+  /// Decodes the kind of an object of type `tag`, where `identity_packages` are all the known versions
+  /// of the IotaIdentity package.
+  fn from_type(tag: &StructTag, identity_packages: &[ObjectId]) -> Self {
+    if let Some(coin_type) = coin_type_from_tag(tag.clone()) {
+      return Self::Coin { coin_type };
+    }
+
+    let is_identity_type = |module: &str, name: &str| {
+      identity_packages.iter().any(|pkg| {
+        let expected = StructTag::new(*pkg, module, name, vec![]);
+        expected.address() == tag.address() && expected.module() == tag.module() && expected.name() == tag.name()
+      })
+    };
+
+    if is_identity_type(ident_str!("asset").as_str(), ident_str!("AuthenticatedAsset").as_str()) {
+      tag
+        .type_params()
+        .first()
+        .cloned()
+        .map(|content_type| Self::AuthenticatedAsset { content_type })
+        .unwrap_or(Self::Other)
+    } else if is_identity_type(ident_str!("controller").as_str(), ident_str!("ControllerCap").as_str()) {
+      Self::ControllerCap
+    } else if is_identity_type(
+      ident_str!("controller").as_str(),
+      ident_str!("DelegationToken").as_str(),
+    ) {
+      Self::DelegationToken
+    } else {
+      Self::Other
+    }
+  }
+}
+
+/// An object owned by an [`OnChainIdentity`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdentityOwnedObject {
+  id: ObjectId,
+  object_type: StructTag,
+  kind: OwnedObjectKind,
+}
+
+impl IdentityOwnedObject {
+  /// Returns this object's ID.
+  pub fn id(&self) -> ObjectId {
+    self.id
+  }
+
+  /// Returns this object's Move type.
+  pub fn object_type(&self) -> &StructTag {
+    &self.object_type
+  }
+
+  /// Returns the decoded kind of this object.
+  pub fn kind(&self) -> &OwnedObjectKind {
+    &self.kind
+  }
+}
+
+impl OnChainIdentity {
+  /// Fetches all objects owned by this identity that match `filter`.
+  pub(crate) async fn fetch_owned_objects<C>(
+    &self,
+    filter: Option<IotaObjectDataFilter>,
+    options: IotaObjectDataOptions,
+    client: &C,
+  ) -> Result<Vec<IotaObjectData>, Error>
+  where
+    C: CoreClientReadOnly + OptionalSync,
+  {
+    let query = IotaObjectResponseQuery::new(filter, Some(options));
+    let mut objects = vec![];
+    let mut cursor = None;
+    loop {
+      let mut page = client
+        .client_adapter()
+        .read_api()
+        .get_owned_objects(self.id().into(), Some(query.clone()), cursor, None)
+        .await
+        .map_err(|e| Error::RpcError(e.to_string()))?;
+      objects.extend(std::mem::take(&mut page.data).into_iter().filter_map(|res| res.data));
+
+      if !page.has_next_page || page.next_cursor.is_none() {
+        break;
+      }
+      cursor = page.next_cursor;
+    }
+
+    Ok(objects)
+  }
+
+  /// Returns all the objects owned by this identity, together with their decoded kind.
+  pub async fn owned_objects<C>(&self, client: &C) -> Result<Vec<IdentityOwnedObject>, Error>
+  where
+    C: CoreClientReadOnly + OptionalSync,
+  {
+    let identity_packages = identity_package_history(client).await?;
+    let objects = self
+      .fetch_owned_objects(None, IotaObjectDataOptions::default().with_type(), client)
+      .await?
+      .into_iter()
+      .filter_map(|obj_data| {
+        let ObjectType::Struct(obj_type) = obj_data.object_type().ok()? else {
+          return None;
+        };
+        let object_type = StructTag::from(obj_type);
+        let kind = OwnedObjectKind::from_type(&object_type, &identity_packages);
+
+        Some(IdentityOwnedObject {
+          id: obj_data.object_id,
+          object_type,
+          kind,
+        })
+      })
+      .collect();
+
+    Ok(objects)
+  }
+
+  /// Returns all the objects of type `T` owned by this identity, e.g. all the
+  /// [`AuthenticatedAsset<T>`](crate::rebased::AuthenticatedAsset) it holds.
+  ///
+  /// # Notes
+  /// For types defined in the IotaIdentity package, objects created by any of the package's versions are returned.
+  pub async fn owned_objects_of_type<T, C>(&self, client: &C) -> Result<Vec<T>, Error>
+  where
+    T: MoveType + DeserializeOwned,
+    C: CoreClientReadOnly + OptionalSync,
+  {
+    let mut type_tags = vec![];
+    for package in identity_package_history(client).await? {
+      let TypeTag::Struct(tag) = T::move_type(package) else {
+        return Err(Error::InvalidArgument(format!(
+          "`{}` is not a Move struct",
+          std::any::type_name::<T>()
+        )));
+      };
+      if !type_tags.contains(&*tag) {
+        type_tags.push(*tag);
+      }
+    }
+    let filter = IotaObjectDataFilter::MatchAny(type_tags.into_iter().map(IotaObjectDataFilter::StructType).collect());
+
+    self
+      .fetch_owned_objects(Some(filter), IotaObjectDataOptions::default().with_content(), client)
+      .await?
+      .into_iter()
+      .map(|obj_data| {
+        let object_id = obj_data.object_id;
+        obj_data
+          .content
+          .context("missing object content")
+          .and_then(|content| content.try_into_move().context("not a Move object"))
+          .and_then(|obj| {
+            serde_json::from_value(obj.fields.to_json_value()).context("failed to deserialize move object")
+          })
+          .map_err(|e| Error::ObjectLookup(format!("object {object_id}: {e:#}")))
+      })
+      .collect()
+  }
+
+  /// Proposes to send the object with ID `object_id` owned by this identity to `recipient`.
+  /// Shortcut for `identity.send_assets(controller_token).object(object_id, recipient)`.
+  pub fn send_asset<'i, 'c>(
+    &'i mut self,
+    object_id: ObjectId,
+    recipient: Address,
+    controller_token: &'c ControllerToken,
+  ) -> ProposalBuilder<'i, 'c, SendAction> {
+    self.send_assets(controller_token).object(object_id, recipient)
+  }
+
+  /// Proposes to borrow the object with ID `object_id` owned by this identity.
+  /// Shortcut for `identity.borrow_assets(controller_token).borrow(object_id)`.
+  pub fn borrow_asset<'i, 'c>(
+    &'i mut self,
+    object_id: ObjectId,
+    controller_token: &'c ControllerToken,
+  ) -> ProposalBuilder<'i, 'c, BorrowAction> {
+    self.borrow_assets(controller_token).borrow(object_id)
+  }
+}
@@ -6,17 +6,28 @@ pub mod assets;
 /// Module for handling client operations.
 pub mod client;
 mod error;
+/// Estimation of the gas budget of transactions.
+pub mod gas;
 mod iota;
 /// Module for handling migration operations.
 pub mod migration;
 /// Contains the operations of proposals.
 pub mod proposals;
+/// Protection against the double execution of resubmitted transactions.
+pub mod submission;
+/// Multi-step workflows with checkpoints and compensation.
+pub mod workflow;
 /// Contains utility functions.
 #[cfg(not(target_arch = "wasm32"))]
 pub mod utils;
 
 pub use assets::*;
 pub use error::*;
+pub use iota::package::configure_identity_packages;
+pub use iota::package::PackageEnv;
+pub use iota::package::PackageRegistry;
+pub use iota::package::IOTA_IDENTITY_PACKAGE_REGISTRY_ENV;
+pub use iota::package::IOTA_IDENTITY_PKG_ID_ENV;
 
 /// Integration with IOTA's Keytool.
 #[cfg(feature = "keytool")]
@@ -238,8 +238,8 @@ where
     // `true` if this operation can also be executed in the same transaction.
     let can_execute = self
       .identity
-      .controller_voting_power(self.identity_token.controller_id())
-      .expect("valid controller token")
+      .voting_power_of(&self.identity_token)
+      .map_err(AccessSubIdentityBuilderErrorKind::Unauthorized)?
       >= self.identity.threshold();
 
     // Invoke the user-passed function, if any, to compute the transaction to perform on `sub_identity`.
@@ -405,12 +405,12 @@ where
       .get_object_ref_by_id(self.identity.id())
       .await
       .map_err(|e| AccessSubIdentityErrorKind::RpcError(e.into()))?
-      .expect("exists on-chain");
+      .ok_or(AccessSubIdentityErrorKind::ObjectNotFound(self.identity.id()))?;
     let sub_identity = client
       .get_object_ref_by_id(self.sub_identity)
       .await
       .map_err(|e| AccessSubIdentityErrorKind::RpcError(e.into()))?
-      .expect("exists on-chain");
+      .ok_or(AccessSubIdentityErrorKind::ObjectNotFound(self.sub_identity))?;
     let identity_token = self
       .identity_token
       .controller_ref(client)
@@ -565,11 +565,14 @@ where
     };
 
     match self.tx_kind {
-      TxKind::Create { .. } => client
-        .get_object_by_id(maybe_proposal_id.expect("tx was successful"))
-        .await
-        .map(ProposedTxResult::Pending)
-        .map_err(|e| AccessSubIdentityErrorKind::RpcError(e.into())),
+      TxKind::Create { .. } => match maybe_proposal_id {
+        Some(proposal_id) => client
+          .get_object_by_id(proposal_id)
+          .await
+          .map(ProposedTxResult::Pending)
+          .map_err(|e| AccessSubIdentityErrorKind::RpcError(e.into())),
+        None => Err(AccessSubIdentityErrorKind::MissingProposal),
+      },
       TxKind::CreateAndExecute { sub_tx, .. } | TxKind::Execute { sub_tx, .. } => sub_tx
         .apply_with_events(effects, events, client)
         .await
@@ -606,9 +609,15 @@ enum AccessSubIdentityErrorKind {
   /// Building the whole transaction failed.
   #[error("failed to build transaction")]
   TransactionBuilding(#[source] Box<dyn std::error::Error + Send + Sync>),
+  /// An object required by the transaction wasn't found on-chain.
+  #[error("object `{0}` was not found on-chain")]
+  ObjectNotFound(ObjectId),
   /// Executing the transaction failed.
   #[error("transaction execution failed")]
   TransactionExecution(#[source] Box<dyn std::error::Error + Send + Sync>),
+  /// The transaction was successful, but didn't emit the event of the created proposal.
+  #[error("transaction didn't create a proposal")]
+  MissingProposal,
   /// Failed to apply the transaction's effects off-chain.
   #[error("transaction was successful but its effect couldn't be applied off-chain")]
   EffectsApplication(#[source] Box<dyn std::error::Error + Send + Sync>),
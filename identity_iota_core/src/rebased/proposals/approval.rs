@@ -0,0 +1,214 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+use iota_interaction::rpc_types::IotaExecutionStatus;
+use iota_interaction::rpc_types::IotaTransactionBlockEffects;
+use iota_interaction::rpc_types::IotaTransactionBlockEffectsAPI as _;
+use iota_interaction::MoveType;
+use iota_interaction::OptionalSync;
+use iota_sdk_types::ObjectId;
+use iota_sdk_types::ProgrammableTransaction;
+use iota_sdk_types::TypeTag;
+use product_common::core_client::CoreClientReadOnly;
+use product_common::transaction::transaction_builder::Transaction;
+use product_common::transaction::transaction_builder::TransactionBuilder;
+use tokio::sync::OnceCell;
+
+use crate::rebased::iota::move_calls;
+use crate::rebased::iota::package::identity_package_id;
+use crate::rebased::migration::ControllerToken;
+use crate::rebased::migration::OnChainIdentity;
+use crate::rebased::migration::Proposal;
+use crate::rebased::Error;
+
+/// The approval state of a [`Proposal`], as returned by [`Proposal::approval_status`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ApprovalStatus {
+  /// The voting power that approved the proposal.
+  pub votes: u64,
+  /// The voting power required to execute the proposal.
+  pub threshold: u64,
+  /// The IDs of the controllers that approved the proposal.
+  pub approved_by: Vec<ObjectId>,
+  /// The IDs and voting powers of the controllers that haven't approved the proposal yet, by decreasing voting power.
+  pub pending_approvers: Vec<(ObjectId, u64)>,
+}
+
+impl ApprovalStatus {
+  /// Returns whether the proposal gathered enough votes to be executed.
+  pub fn is_approved(&self) -> bool {
+    self.votes >= self.threshold
+  }
+
+  /// Returns the voting power still needed for the proposal to be executed.
+  pub fn missing_votes(&self) -> u64 {
+    self.threshold.saturating_sub(self.votes)
+  }
+
+  /// Returns whether the proposal can still gather enough votes to be executed, i.e. whether the controllers that
+  /// haven't approved it yet have enough voting power.
+  pub fn can_be_approved(&self) -> bool {
+    let pending_votes = self
+      .pending_approvers
+      .iter()
+      .fold(0u64, |votes, (_, voting_power)| votes.saturating_add(*voting_power));
+    self.votes.saturating_add(pending_votes) >= self.threshold
+  }
+}
+
+impl<A> Proposal<A> {
+  /// Returns the approval state of this proposal of `identity`.
+  pub fn approval_status(&self, identity: &OnChainIdentity) -> ApprovalStatus {
+    let mut approved_by: Vec<ObjectId> = self.voters().iter().copied().collect();
+    approved_by.sort();
+    let mut pending_approvers: Vec<(ObjectId, u64)> = identity
+      .controllers()
+      .iter()
+      .filter(|(controller, _)| !self.voters().contains(controller))
+      .map(|(controller, voting_power)| (*controller, *voting_power))
+      .collect();
+    pending_approvers.sort_by(|(a_id, a_vp), (b_id, b_vp)| b_vp.cmp(a_vp).then(a_id.cmp(b_id)));
+
+    ApprovalStatus {
+      votes: self.votes(),
+      threshold: identity.threshold(),
+      approved_by,
+      pending_approvers,
+    }
+  }
+
+  /// Returns the IDs of `identity`'s controllers that haven't approved this proposal yet.
+  pub fn pending_approvers(&self, identity: &OnChainIdentity) -> Vec<ObjectId> {
+    self
+      .approval_status(identity)
+      .pending_approvers
+      .into_iter()
+      .map(|(controller, _)| controller)
+      .collect()
+  }
+}
+
+/// A transaction to approve many [`Proposal`]s of the same identity at once.
+///
+/// ## Notes
+/// Unlike [`ApproveProposal`](super::ApproveProposal), this transaction doesn't update the votes of the approved
+/// proposals: fetch them again to get their current state.
+#[derive(Debug)]
+pub struct ApproveProposals<'i> {
+  identity: &'i OnChainIdentity,
+  controller_token: ControllerToken,
+  proposals: Vec<(ObjectId, fn(ObjectId) -> TypeTag)>,
+  cached_ptb: OnceCell<ProgrammableTransaction>,
+}
+
+impl<'i> ApproveProposals<'i> {
+  /// Creates a new [Transaction] to approve proposals of `identity` using `controller_token`.
+  pub fn new(identity: &'i OnChainIdentity, controller_token: &ControllerToken) -> Result<Self, Error> {
+    if identity.id() != controller_token.controller_of() {
+      return Err(Error::Identity(format!(
+        "token {} doesn't grant access to identity {}",
+        controller_token.id(),
+        identity.id()
+      )));
+    }
+
+    Ok(Self {
+      identity,
+      controller_token: controller_token.clone(),
+      proposals: Vec::new(),
+      cached_ptb: OnceCell::new(),
+    })
+  }
+
+  /// Adds `proposal` to the proposals to approve. Adding a proposal more than once has no effect.
+  pub fn with_proposal<A: MoveType>(mut self, proposal: &Proposal<A>) -> Self {
+    if !self.proposals.iter().any(|(id, _)| *id == proposal.id()) {
+      self.proposals.push((proposal.id(), A::move_type));
+    }
+    self
+  }
+
+  /// Returns the IDs of the proposals to approve.
+  pub fn proposals(&self) -> impl Iterator<Item = ObjectId> + '_ {
+    self.proposals.iter().map(|(id, _)| *id)
+  }
+
+  /// Wraps this transaction in a [TransactionBuilder].
+  pub fn finish(self) -> TransactionBuilder<Self> {
+    TransactionBuilder::new(self)
+  }
+
+  async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
+  where
+    C: CoreClientReadOnly + OptionalSync,
+  {
+    if self.proposals.is_empty() {
+      return Err(Error::InvalidArgument("no proposals to approve".to_owned()));
+    }
+
+    let identity_ref = client
+      .get_object_ref_by_id(self.identity.id())
+      .await?
+      .ok_or_else(|| Error::Identity(format!("identity {} doesn't exist", self.identity.id())))?;
+    let controller_cap = self.controller_token.controller_ref(client).await?;
+    let package = identity_package_id(client).await?;
+    let proposals = self.proposals.iter().map(|(id, move_type)| (*id, move_type(package)));
+
+    let tx = move_calls::identity::approve_proposals(identity_ref, controller_cap, proposals, package)?;
+
+    Ok(bcs::from_bytes(&tx)?)
+  }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl Transaction for ApproveProposals<'_> {
+  type Output = ();
+  type Error = Error;
+
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+      name = "build_transaction",
+      skip_all,
+      fields(transaction = "ApproveProposals"),
+      err(Display)
+    )
+  )]
+  async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+  where
+    C: CoreClientReadOnly + OptionalSync,
+  {
+    self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+  }
+
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+      name = "apply_transaction_effects",
+      skip_all,
+      fields(transaction = "ApproveProposals", digest = %effects.transaction_digest()),
+      err(Display)
+    )
+  )]
+  async fn apply<C>(self, effects: &mut IotaTransactionBlockEffects, _client: &C) -> Result<Self::Output, Self::Error>
+  where
+    C: CoreClientReadOnly + OptionalSync,
+  {
+    if let IotaExecutionStatus::Failure { error } = effects.status() {
+      return Err(Error::TransactionUnexpectedResponse(error.clone()));
+    }
+
+    let mutated: HashSet<ObjectId> = effects.mutated().iter().map(|obj| obj.object_id()).collect();
+    match self.proposals().find(|id| !mutated.contains(id)) {
+      None => Ok(()),
+      Some(id) => Err(Error::TransactionUnexpectedResponse(format!(
+        "proposal {id} wasn't updated in this transaction"
+      ))),
+    }
+  }
+}
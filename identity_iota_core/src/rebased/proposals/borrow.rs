@@ -7,6 +7,8 @@ use std::marker::PhantomData;
 use crate::rebased::iota::move_calls;
 use crate::rebased::iota::package::identity_package_id;
 
+use crate::rebased::migration::merge_coins_intent;
+use crate::rebased::migration::send_amount_intent;
 use crate::rebased::migration::ControllerToken;
 
 use iota_interaction::OptionalSend;
@@ -26,6 +28,7 @@ use iota_interaction::rpc_types::IotaTransactionBlockEffects;
 use iota_interaction::rpc_types::IotaTransactionBlockEffectsAPI as _;
 use iota_interaction::MoveType;
 use iota_interaction::OptionalSync;
+use iota_sdk_types::Address;
 use iota_sdk_types::Argument;
 use iota_sdk_types::ObjectId;
 use iota_sdk_types::ProgrammableTransaction;
@@ -60,6 +63,64 @@ cfg_if::cfg_if! {
     }
 }
 
+/// A read-only view over the objects borrowed from an [`OnChainIdentity`], keyed by their ID.
+///
+/// Each borrowed object is available as an [`Argument`] that can be used in the programmable
+/// transaction describing the borrow's intent. Borrowed objects are returned to the identity
+/// automatically once the intent has been applied.
+#[derive(Debug, Clone, Copy)]
+pub struct BorrowedObjects<'a>(&'a HashMap<ObjectId, (Argument, IotaObjectData)>);
+
+impl<'a> BorrowedObjects<'a> {
+  /// Wraps the map `object_id -> (argument, object_data)` passed to a [`BorrowIntentFnT`].
+  pub fn new(objects: &'a HashMap<ObjectId, (Argument, IotaObjectData)>) -> Self {
+    Self(objects)
+  }
+
+  /// Returns the transaction argument for the borrowed object with ID `object_id`, if any.
+  pub fn argument(&self, object_id: &ObjectId) -> Option<Argument> {
+    self.0.get(object_id).map(|(arg, _)| *arg)
+  }
+
+  /// Returns the data of the borrowed object with ID `object_id`, if any.
+  pub fn object_data(&self, object_id: &ObjectId) -> Option<&'a IotaObjectData> {
+    self.0.get(object_id).map(|(_, data)| data)
+  }
+
+  /// Returns `true` if the object with ID `object_id` has been borrowed.
+  pub fn contains(&self, object_id: &ObjectId) -> bool {
+    self.0.contains_key(object_id)
+  }
+
+  /// Returns an iterator over the IDs of the borrowed objects.
+  pub fn ids(&self) -> impl Iterator<Item = ObjectId> + 'a {
+    self.0.keys().copied()
+  }
+
+  /// Returns an iterator over the borrowed objects, as `(object_id, argument, object_data)`.
+  pub fn iter(&self) -> impl Iterator<Item = (ObjectId, Argument, &'a IotaObjectData)> + 'a {
+    self.0.iter().map(|(id, (arg, data))| (*id, *arg, data))
+  }
+
+  /// Returns the number of borrowed objects.
+  pub fn len(&self) -> usize {
+    self.0.len()
+  }
+
+  /// Returns `true` if no object has been borrowed.
+  pub fn is_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+}
+
+/// Turns a function operating on [`BorrowedObjects`] into a [`BorrowIntentFnT`].
+fn borrowed_objects_intent<F>(intent_fn: F) -> impl FnOnce(&mut Ptb, &HashMap<ObjectId, (Argument, IotaObjectData)>)
+where
+  F: FnOnce(&mut Ptb, BorrowedObjects<'_>),
+{
+  move |ptb, objects| intent_fn(ptb, BorrowedObjects::new(objects))
+}
+
 /// Action used to borrow in transaction [OnChainIdentity]'s assets.
 #[derive(Deserialize, Serialize)]
 pub struct BorrowAction<F = BorrowIntentFn> {
@@ -165,6 +226,9 @@ impl<'i, 'c, F> ProposalBuilder<'i, 'c, BorrowAction<F>> {
 
   /// Specifies how to use the borrowed assets. This is only useful if the sender of this
   /// transaction has enough voting power to execute this proposal right-away.
+  ///
+  /// When the proposal cannot be executed right-away, the intent must be provided once the
+  /// proposal is executed, through [`UserDrivenTx::with_intent`].
   pub fn with_intent<F1>(self, intent_fn: F1) -> ProposalBuilder<'i, 'c, BorrowAction<F1>>
   where
     F1: FnOnce(&mut Ptb, &HashMap<ObjectId, (Argument, IotaObjectData)>),
@@ -183,6 +247,28 @@ impl<'i, 'c, F> ProposalBuilder<'i, 'c, BorrowAction<F>> {
       action: BorrowAction { objects, intent_fn },
     }
   }
+
+  /// Same as [`ProposalBuilder::with_intent`], but `intent_fn` accesses the borrowed assets
+  /// through [`BorrowedObjects`].
+  /// # Example
+  /// ```ignore
+  /// identity
+  ///   .borrow_assets(&controller_token)
+  ///   .borrow(coin_id)
+  ///   .with_borrowed_intent(move |ptb, borrowed| {
+  ///     let coin = borrowed.argument(&coin_id).expect("coin is borrowed");
+  ///     // use `coin` in `ptb`.
+  ///   })
+  /// ```
+  pub fn with_borrowed_intent<F1>(
+    self,
+    intent_fn: F1,
+  ) -> ProposalBuilder<'i, 'c, BorrowAction<impl FnOnce(&mut Ptb, &HashMap<ObjectId, (Argument, IotaObjectData)>)>>
+  where
+    F1: FnOnce(&mut Ptb, BorrowedObjects<'_>),
+  {
+    self.with_intent(borrowed_objects_intent(intent_fn))
+  }
 }
 
 #[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
@@ -216,12 +302,9 @@ where
     let identity_ref = client
       .get_object_ref_by_id(identity.id())
       .await?
-      .expect("identity exists on-chain");
+      .ok_or_else(|| Error::ObjectNotFound(identity.id()))?;
     let controller_cap_ref = controller_token.controller_ref(client).await?;
-    let can_execute = identity
-      .controller_voting_power(controller_token.controller_id())
-      .expect("is a controller of identity")
-      >= identity.threshold();
+    let can_execute = identity.voting_power_of(controller_token)? >= identity.threshold();
     let maybe_intent_fn = action.intent_fn.into_inner();
     let chained_execution = can_execute && maybe_intent_fn.is_some();
     let tx = if chained_execution {
@@ -304,6 +387,31 @@ impl<'i, F> UserDrivenTx<'i, BorrowAction<F>> {
       self.proposal_id,
     )
   }
+
+  /// Same as [`UserDrivenTx::with_intent`], but `intent_fn` accesses the borrowed assets
+  /// through [`BorrowedObjects`].
+  pub fn with_borrowed_intent<F1>(self, intent_fn: F1) -> UserDrivenTx<'i, BorrowActionWithIntent<impl BorrowIntentFnT>>
+  where
+    F1: FnOnce(&mut Ptb, BorrowedObjects<'_>),
+  {
+    self.with_intent(borrowed_objects_intent(intent_fn))
+  }
+
+  /// Sets this proposal's intent to sending `amount` out of the borrowed coins to `recipient`.
+  /// To be used when executing a proposal created through [`OnChainIdentity::send_amount`].
+  pub fn with_send_amount_intent(
+    self,
+    amount: u64,
+    recipient: Address,
+  ) -> UserDrivenTx<'i, BorrowActionWithIntent<impl BorrowIntentFnT + Send + Sync>> {
+    self.with_intent(send_amount_intent(amount, recipient))
+  }
+
+  /// Sets this proposal's intent to merging the borrowed coins.
+  /// To be used when executing a proposal created through [`OnChainIdentity::merge_coins`].
+  pub fn with_merge_coins_intent(self) -> UserDrivenTx<'i, BorrowActionWithIntent<impl BorrowIntentFnT + Send + Sync>> {
+    self.with_intent(merge_coins_intent())
+  }
 }
 
 impl<'i, F> ProtoTransaction for UserDrivenTx<'i, BorrowAction<F>> {
@@ -333,7 +441,7 @@ where
     let identity_ref = client
       .get_object_ref_by_id(identity.id())
       .await?
-      .expect("identity exists on-chain");
+      .ok_or_else(|| Error::ObjectNotFound(identity.id()))?;
     let controller_token = client.get_object_by_id::<ControllerToken>(*controller_token).await?;
     let controller_token_ref = controller_token.controller_ref(client).await?;
 
@@ -48,6 +48,8 @@ pub struct ConfigChange {
   controllers_to_add: HashMap<Address, u64>,
   controllers_to_remove: HashSet<ObjectId>,
   controllers_voting_power: HashMap<ObjectId, u64>,
+  #[serde(skip)]
+  not_before: Option<u64>,
 }
 
 impl MoveType for ConfigChange {
@@ -111,6 +113,13 @@ impl ProposalBuilder<'_, '_, ConfigChange> {
 
     self
   }
+
+  /// Prevents the [`Proposal`] from being executed before epoch `epoch`.
+  /// See [`ConfigChange::set_not_before`].
+  pub fn with_not_before(mut self, epoch: u64) -> Self {
+    self.set_not_before(epoch);
+    self
+  }
 }
 
 impl ConfigChange {
@@ -129,6 +138,18 @@ impl ConfigChange {
     self.threshold
   }
 
+  /// Sets the epoch before which the [`Proposal`] carrying this action cannot be executed.
+  ///
+  /// A time-locked [`Proposal`] is never executed upon creation, regardless of its creator's voting power.
+  pub fn set_not_before(&mut self, epoch: u64) {
+    self.not_before = Some(epoch);
+  }
+
+  /// Returns the epoch before which the [`Proposal`] carrying this action cannot be executed, if any.
+  pub fn not_before(&self) -> Option<u64> {
+    self.not_before
+  }
+
   /// Returns the controllers that will be added, as the map [Address] -> [u64].
   pub fn controllers_to_add(&self) -> &HashMap<Address, u64> {
     &self.controllers_to_add
@@ -248,16 +269,15 @@ impl ProposalT for Proposal<ConfigChange> {
     let identity_ref = client
       .get_object_ref_by_id(identity.id())
       .await?
-      .expect("identity exists on-chain");
+      .ok_or_else(|| Error::ObjectNotFound(identity.id()))?;
     let controller_cap_ref = controller_token.controller_ref(client).await?;
-    let sender_vp = identity
-      .controller_voting_power(controller_token.controller_id())
-      .expect("controller exists");
-    let chained_execution = sender_vp >= identity.threshold();
+    let sender_vp = identity.voting_power_of(controller_token)?;
+    let chained_execution = sender_vp >= identity.threshold() && action.not_before.is_none();
     let tx = move_calls::identity::propose_config_change(
       identity_ref,
       controller_cap_ref,
       expiration,
+      action.not_before,
       action.threshold,
       action.controllers_to_add,
       action.controllers_to_remove,
@@ -295,7 +315,7 @@ impl ProposalT for Proposal<ConfigChange> {
     let identity_ref = client
       .get_object_ref_by_id(identity.id())
       .await?
-      .expect("identity exists on-chain");
+      .ok_or_else(|| Error::ObjectNotFound(identity.id()))?;
     let controller_cap_ref = controller_token.controller_ref(client).await?;
     let package = identity_package_id(client).await?;
     let tx = move_calls::identity::execute_config_change(identity_ref, controller_cap_ref, proposal_id, package)
@@ -304,6 +324,7 @@ impl ProposalT for Proposal<ConfigChange> {
     Ok(TransactionBuilder::new(ExecuteProposal {
       identity,
       ptb: bcs::from_bytes(&tx)?,
+      proposal_id,
       _action: PhantomData,
     }))
   }
@@ -346,6 +367,7 @@ impl TryFrom<Modify> for ConfigChange {
       controllers_to_add,
       controllers_to_remove,
       controllers_voting_power: controllers_to_update,
+      not_before: None,
     })
   }
 }
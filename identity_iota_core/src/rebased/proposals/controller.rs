@@ -58,6 +58,81 @@ cfg_if::cfg_if! {
     }
 }
 
+/// An argument of a Move call performed through a [`LentControllerCap`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LentCapArg {
+  /// The lent `ControllerCap`, passed by reference.
+  ControllerCap,
+  /// Any other argument of the call.
+  Arg(Argument),
+}
+
+impl From<Argument> for LentCapArg {
+  fn from(value: Argument) -> Self {
+    Self::Arg(value)
+  }
+}
+
+/// A `ControllerCap` lent to a transaction by an [`OnChainIdentity`] through a [`ControllerExecution`] proposal.
+///
+/// The lent capability is never exposed as a raw [`Argument`]: it can only be referenced in Move calls
+/// through [`LentCapArg::ControllerCap`]. Since a [`LentControllerCap`] cannot outlive the function it is
+/// lent to, the capability is always available to be returned to the identity once that function completes.
+///
+/// ## Notes
+/// Move functions receiving the lent capability must take it by reference (`&ControllerCap`), as a
+/// capability consumed by value cannot be returned.
+pub struct LentControllerCap<'p> {
+  ptb: &'p mut Ptb,
+  controller_cap: Argument,
+}
+
+impl<'p> LentControllerCap<'p> {
+  fn new(ptb: &'p mut Ptb, controller_cap: Argument) -> Self {
+    Self { ptb, controller_cap }
+  }
+
+  /// Returns the transaction being built, to add the inputs and commands that don't involve the lent capability.
+  pub fn ptb(&mut self) -> &mut Ptb {
+    self.ptb
+  }
+
+  /// Adds a call to `package::module::function` to the transaction, replacing every occurrence of
+  /// [`LentCapArg::ControllerCap`] in `args` with the lent capability.
+  /// Returns the result of the call.
+  pub fn move_call<I>(
+    &mut self,
+    package: ObjectId,
+    module: &str,
+    function: &str,
+    type_args: Vec<TypeTag>,
+    args: I,
+  ) -> Argument
+  where
+    I: IntoIterator<Item = LentCapArg>,
+  {
+    let args = args
+      .into_iter()
+      .map(|arg| match arg {
+        LentCapArg::ControllerCap => self.controller_cap,
+        LentCapArg::Arg(arg) => arg,
+      })
+      .collect();
+
+    self
+      .ptb
+      .programmable_move_call(package, module.into(), function.into(), type_args, args)
+  }
+}
+
+/// Turns a function operating on a [`LentControllerCap`] into a [`ControllerIntentFnT`].
+fn lent_cap_intent<F>(intent_fn: F) -> impl FnOnce(&mut Ptb, &Argument)
+where
+  F: FnOnce(&mut LentControllerCap<'_>),
+{
+  move |ptb, controller_cap| intent_fn(&mut LentControllerCap::new(ptb, *controller_cap))
+}
+
 /// Borrow an [`OnChainIdentity`]'s controller capability to exert control on
 /// a sub-owned identity.
 #[derive(Debug, Deserialize, Serialize)]
@@ -154,6 +229,34 @@ impl<'i, 'c, F> ProposalBuilder<'i, 'c, ControllerExecution<F>> {
       action: action.with_intent(intent_fn),
     }
   }
+
+  /// Same as [`ProposalBuilder::with_intent`], but the borrowed `ControllerCap` is lent to `intent_fn`
+  /// as a [`LentControllerCap`], guaranteeing it can be returned to the identity.
+  /// # Example
+  /// ```ignore
+  /// identity
+  ///   .controller_execution(controller_cap_id, &controller_token)
+  ///   .with_lent_cap(|cap| {
+  ///     let sub_identity = cap.ptb().obj(sub_identity_arg).unwrap();
+  ///     let token_id = cap.ptb().pure(token_to_revoke).unwrap();
+  ///     cap.move_call(
+  ///       package,
+  ///       "identity",
+  ///       "revoke_token",
+  ///       vec![],
+  ///       [sub_identity.into(), LentCapArg::ControllerCap, token_id.into()],
+  ///     );
+  ///   })
+  /// ```
+  pub fn with_lent_cap<F1>(
+    self,
+    intent_fn: F1,
+  ) -> ProposalBuilder<'i, 'c, ControllerExecution<impl FnOnce(&mut Ptb, &Argument)>>
+  where
+    F1: FnOnce(&mut LentControllerCap<'_>),
+  {
+    self.with_intent(lent_cap_intent(intent_fn))
+  }
 }
 
 impl MoveType for ControllerExecution {
@@ -193,14 +296,11 @@ where
     let identity_ref = client
       .get_object_ref_by_id(identity.id())
       .await?
-      .expect("identity exists on-chain");
+      .ok_or_else(|| Error::ObjectNotFound(identity.id()))?;
     let controller_cap_ref = controller_token.controller_ref(client).await?;
     let maybe_intent_fn = action.intent_fn.into_inner();
-    let chained_execution = maybe_intent_fn.is_some()
-      && identity
-        .controller_voting_power(controller_token.controller_id())
-        .expect("is an identity's controller")
-        >= identity.threshold();
+    let chained_execution =
+      maybe_intent_fn.is_some() && identity.voting_power_of(controller_token)? >= identity.threshold();
 
     let package = identity_package_id(client).await?;
     let ptb = if chained_execution {
@@ -292,6 +392,18 @@ impl<'i, F> UserDrivenTx<'i, ControllerExecution<F>> {
       proposal_id,
     )
   }
+
+  /// Same as [`UserDrivenTx::with_intent`], but the borrowed `ControllerCap` is lent to `intent_fn`
+  /// as a [`LentControllerCap`], guaranteeing it can be returned to the identity.
+  pub fn with_lent_cap<F1>(
+    self,
+    intent_fn: F1,
+  ) -> UserDrivenTx<'i, ControllerExecutionWithIntent<impl ControllerIntentFnT>>
+  where
+    F1: FnOnce(&mut LentControllerCap<'_>),
+  {
+    self.with_intent(lent_cap_intent(intent_fn))
+  }
 }
 
 impl<'i, F> ProtoTransaction for UserDrivenTx<'i, ControllerExecution<F>> {
@@ -321,7 +433,7 @@ where
     let identity_ref = client
       .get_object_ref_by_id(identity.id())
       .await?
-      .expect("identity exists on-chain");
+      .ok_or_else(|| Error::ObjectNotFound(identity.id()))?;
     let controller_token = client.get_object_by_id::<ControllerToken>(*controller_token).await?;
     let controller_cap_ref = controller_token.controller_ref(client).await?;
 
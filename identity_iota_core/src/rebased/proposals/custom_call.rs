@@ -0,0 +1,476 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+use crate::rebased::iota::move_calls;
+use crate::rebased::iota::package::identity_package_id;
+use crate::rebased::migration::ControllerToken;
+use crate::rebased::migration::Proposal;
+use crate::rebased::Error;
+use async_trait::async_trait;
+use iota_interaction::rpc_types::IotaExecutionStatus;
+use iota_interaction::rpc_types::IotaObjectData;
+use iota_interaction::rpc_types::IotaTransactionBlockEffects;
+use iota_interaction::rpc_types::IotaTransactionBlockEffectsAPI as _;
+use iota_interaction::types::programmable_transaction_builder::ProgrammableTransactionBuilder as Ptb;
+use iota_interaction::MoveType;
+use iota_interaction::OptionalSend;
+use iota_interaction::OptionalSync;
+use iota_sdk_types::Argument;
+use iota_sdk_types::ObjectId;
+use iota_sdk_types::ProgrammableTransaction;
+use iota_sdk_types::TypeTag;
+use itertools::Itertools as _;
+use product_common::core_client::CoreClientReadOnly;
+use product_common::transaction::transaction_builder::Transaction;
+use product_common::transaction::transaction_builder::TransactionBuilder;
+use product_common::transaction::ProtoTransaction;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::BorrowAction;
+use super::CreateProposal;
+use super::OnChainIdentity;
+use super::ProposalBuilder;
+use super::ProposalT;
+use super::UserDrivenTx;
+
+/// Decodes the typed result of a [`CustomMoveCall`] from the effects of the transaction that carried it out.
+pub trait CustomCallOutput: Sized {
+  /// Decodes the call's result from `effects`.
+  fn decode(effects: &IotaTransactionBlockEffects) -> Result<Self, Error>;
+}
+
+impl CustomCallOutput for () {
+  fn decode(_effects: &IotaTransactionBlockEffects) -> Result<Self, Error> {
+    Ok(())
+  }
+}
+
+/// Decodes the IDs of the objects created by the call.
+impl CustomCallOutput for Vec<ObjectId> {
+  fn decode(effects: &IotaTransactionBlockEffects) -> Result<Self, Error> {
+    Ok(effects.created().iter().map(|obj_ref| obj_ref.object_id()).collect())
+  }
+}
+
+/// An argument of a [`CustomMoveCall`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CustomCallArg {
+  /// A BCS-encoded pure value.
+  Pure(Vec<u8>),
+  /// An object owned by the identity, borrowed for the duration of the call.
+  Borrowed(ObjectId),
+}
+
+impl CustomCallArg {
+  /// Creates a pure argument out of `value`.
+  pub fn pure<T: Serialize>(value: &T) -> Result<Self, Error> {
+    Ok(Self::Pure(bcs::to_bytes(value)?))
+  }
+
+  /// Creates an argument that borrows the identity's object with ID `object_id`.
+  pub fn borrowed(object_id: ObjectId) -> Self {
+    Self::Borrowed(object_id)
+  }
+}
+
+/// A call to a Move function that is not wrapped by this SDK, whose result is decoded as `T`.
+///
+/// The call is carried out through a borrow proposal: objects passed as [`CustomCallArg::Borrowed`] are borrowed
+/// from the identity for the duration of the call and returned afterwards.
+#[derive(Debug)]
+pub struct CustomMoveCall<T = ()> {
+  package: Option<ObjectId>,
+  module: String,
+  function: String,
+  type_args: Vec<TypeTag>,
+  args: Vec<CustomCallArg>,
+  _output: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for CustomMoveCall<T> {
+  fn clone(&self) -> Self {
+    Self {
+      package: self.package,
+      module: self.module.clone(),
+      function: self.function.clone(),
+      type_args: self.type_args.clone(),
+      args: self.args.clone(),
+      _output: PhantomData,
+    }
+  }
+}
+
+impl<T> CustomMoveCall<T> {
+  /// Creates a new call to `module::function` of the `iota_identity` package, using `args` as arguments.
+  pub fn new<I>(module: impl Into<String>, function: impl Into<String>, args: I) -> Self
+  where
+    I: IntoIterator<Item = CustomCallArg>,
+  {
+    Self {
+      package: None,
+      module: module.into(),
+      function: function.into(),
+      type_args: vec![],
+      args: args.into_iter().collect(),
+      _output: PhantomData,
+    }
+  }
+
+  /// Calls the function of package `package` instead of `iota_identity`.
+  pub fn set_package(&mut self, package: ObjectId) {
+    self.package = Some(package);
+  }
+
+  /// Appends a type argument to the call.
+  pub fn add_type_arg(&mut self, type_arg: TypeTag) {
+    self.type_args.push(type_arg);
+  }
+
+  /// Returns the ID of the package that will be called, if it's not `iota_identity`.
+  pub fn package(&self) -> Option<ObjectId> {
+    self.package
+  }
+
+  /// Returns the name of the called module.
+  pub fn module(&self) -> &str {
+    &self.module
+  }
+
+  /// Returns the name of the called function.
+  pub fn function(&self) -> &str {
+    &self.function
+  }
+
+  /// Returns the call's arguments.
+  pub fn args(&self) -> &[CustomCallArg] {
+    &self.args
+  }
+
+  /// Returns the IDs of the identity's objects that are borrowed by this call.
+  pub fn borrowed_objects(&self) -> Vec<ObjectId> {
+    self
+      .args
+      .iter()
+      .filter_map(|arg| match arg {
+        CustomCallArg::Borrowed(object_id) => Some(*object_id),
+        CustomCallArg::Pure(_) => None,
+      })
+      .unique()
+      .collect()
+  }
+
+  /// Decodes the call's result as `U` instead of `T`.
+  pub fn with_output<U>(self) -> CustomMoveCall<U> {
+    CustomMoveCall {
+      package: self.package,
+      module: self.module,
+      function: self.function,
+      type_args: self.type_args,
+      args: self.args,
+      _output: PhantomData,
+    }
+  }
+
+  /// Returns a borrow intent that adds this call to a transaction, calling into `package` unless
+  /// this call specifies its own.
+  fn into_intent(self, package: ObjectId) -> impl FnOnce(&mut Ptb, &HashMap<ObjectId, (Argument, IotaObjectData)>) {
+    move |ptb: &mut Ptb, borrowed_objects: &HashMap<ObjectId, (Argument, IotaObjectData)>| {
+      let args: Vec<Argument> = self
+        .args
+        .into_iter()
+        .map(|arg| match arg {
+          CustomCallArg::Pure(bytes) => ptb.pure_bytes(bytes, false),
+          CustomCallArg::Borrowed(object_id) => {
+            borrowed_objects
+              .get(&object_id)
+              .expect("borrowed objects are derived from the call's arguments")
+              .0
+          }
+        })
+        .collect();
+
+      ptb.programmable_move_call(
+        self.package.unwrap_or(package),
+        self.module.as_str().into(),
+        self.function.as_str().into(),
+        self.type_args,
+        args,
+      );
+    }
+  }
+}
+
+/// [`Proposal`] action that carries out a [`CustomMoveCall`].
+///
+/// On-chain this action is a borrow action; only the borrowed objects are stored in the proposal. The call
+/// itself must be provided again to execute a pending proposal.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct CustomCallAction<T = ()> {
+  objects: Vec<ObjectId>,
+  #[serde(skip)]
+  call: Option<CustomMoveCall<T>>,
+}
+
+impl<T> CustomCallAction<T> {
+  /// Creates a new [`CustomCallAction`] carrying out `call`.
+  pub fn new(call: CustomMoveCall<T>) -> Self {
+    Self {
+      objects: call.borrowed_objects(),
+      call: Some(call),
+    }
+  }
+
+  /// Returns the IDs of the identity's objects that are borrowed by this action.
+  pub fn objects(&self) -> &[ObjectId] {
+    &self.objects
+  }
+
+  /// Returns the call carried out by this action, if known.
+  pub fn call(&self) -> Option<&CustomMoveCall<T>> {
+    self.call.as_ref()
+  }
+
+  /// Checks that `call` borrows exactly the objects borrowed by this action.
+  fn check_call(&self, call: &CustomMoveCall<T>) -> Result<(), Error> {
+    let mut objects = call.borrowed_objects();
+    let mut expected_objects = self.objects.clone();
+    objects.sort();
+    expected_objects.sort();
+    if objects != expected_objects {
+      return Err(Error::InvalidArgument(
+        "the call must borrow exactly the objects borrowed by the proposal".to_owned(),
+      ));
+    }
+    Ok(())
+  }
+}
+
+impl<T> MoveType for CustomCallAction<T> {
+  fn move_type(package: ObjectId) -> TypeTag {
+    BorrowAction::move_type(package)
+  }
+}
+
+impl<'i, 'c, T> ProposalBuilder<'i, 'c, CustomCallAction<T>> {
+  /// Calls the function of package `package` instead of `iota_identity`.
+  pub fn package(mut self, package: ObjectId) -> Self {
+    if let Some(call) = self.action.call.as_mut() {
+      call.set_package(package);
+    }
+    self
+  }
+
+  /// Appends a type argument to the call.
+  pub fn type_arg(mut self, type_arg: TypeTag) -> Self {
+    if let Some(call) = self.action.call.as_mut() {
+      call.add_type_arg(type_arg);
+    }
+    self
+  }
+
+  /// Decodes the call's result as `U`. See [`CustomCallOutput`].
+  pub fn with_output<U>(self) -> ProposalBuilder<'i, 'c, CustomCallAction<U>> {
+    let ProposalBuilder {
+      identity,
+      controller_token,
+      expiration,
+      action: CustomCallAction { objects, call },
+    } = self;
+    ProposalBuilder {
+      identity,
+      controller_token,
+      expiration,
+      action: CustomCallAction {
+        objects,
+        call: call.map(CustomMoveCall::with_output),
+      },
+    }
+  }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl<T> ProposalT for Proposal<CustomCallAction<T>>
+where
+  T: CustomCallOutput + OptionalSend + OptionalSync,
+{
+  type Action = CustomCallAction<T>;
+  type Output = T;
+
+  async fn create<'i, C>(
+    action: Self::Action,
+    expiration: Option<u64>,
+    identity: &'i mut OnChainIdentity,
+    controller_token: &ControllerToken,
+    client: &C,
+  ) -> Result<TransactionBuilder<CreateProposal<'i, Self::Action>>, Error>
+  where
+    C: CoreClientReadOnly + OptionalSync,
+  {
+    if identity.id() != controller_token.controller_of() {
+      return Err(Error::Identity(format!(
+        "token {} doesn't grant access to identity {}",
+        controller_token.id(),
+        identity.id()
+      )));
+    }
+
+    let package = identity_package_id(client).await?;
+    let identity_ref = client
+      .get_object_ref_by_id(identity.id())
+      .await?
+      .ok_or_else(|| Error::ObjectNotFound(identity.id()))?;
+    let controller_cap_ref = controller_token.controller_ref(client).await?;
+    let can_execute = identity.voting_power_of(controller_token)? >= identity.threshold();
+    let CustomCallAction { objects, call } = action;
+    let chained_execution = can_execute && call.is_some();
+    let tx = if chained_execution {
+      let mut object_data_list = vec![];
+      for obj_id in objects {
+        let object_data = super::obj_data_for_id(client, obj_id)
+          .await
+          .map_err(|e| Error::TransactionBuildingFailed(e.to_string()))?;
+        object_data_list.push(object_data);
+      }
+      move_calls::identity::create_and_execute_borrow(
+        identity_ref,
+        controller_cap_ref,
+        object_data_list,
+        call.unwrap().into_intent(package),
+        expiration,
+        package,
+      )
+    } else {
+      move_calls::identity::propose_borrow(identity_ref, controller_cap_ref, objects, expiration, package)
+    }
+    .map_err(|e| Error::TransactionBuildingFailed(e.to_string()))?;
+
+    Ok(TransactionBuilder::new(CreateProposal {
+      identity,
+      ptb: bcs::from_bytes(&tx)?,
+      chained_execution,
+      _action: PhantomData,
+    }))
+  }
+
+  async fn into_tx<'i, C>(
+    self,
+    identity: &'i mut OnChainIdentity,
+    controller_token: &ControllerToken,
+    _client: &C,
+  ) -> Result<UserDrivenTx<'i, Self::Action>, Error> {
+    if identity.id() != controller_token.controller_of() {
+      return Err(Error::Identity(format!(
+        "token {} doesn't grant access to identity {}",
+        controller_token.id(),
+        identity.id()
+      )));
+    }
+
+    let proposal_id = self.id();
+    let action = self.into_action();
+
+    Ok(UserDrivenTx::new(identity, controller_token.id(), action, proposal_id))
+  }
+
+  fn parse_tx_effects(effects: &IotaTransactionBlockEffects) -> Result<Self::Output, Error> {
+    if let IotaExecutionStatus::Failure { error } = effects.status() {
+      return Err(Error::TransactionUnexpectedResponse(error.clone()));
+    }
+
+    T::decode(effects)
+  }
+}
+
+impl<T> UserDrivenTx<'_, CustomCallAction<T>> {
+  /// Provides the call to carry out. `call` must borrow the same objects as the proposal being executed.
+  pub fn with_call(mut self, call: CustomMoveCall<T>) -> Self {
+    self.action.call = Some(call);
+    self
+  }
+
+  async fn make_ptb(
+    &self,
+    client: &(impl CoreClientReadOnly + OptionalSync),
+  ) -> Result<ProgrammableTransaction, Error> {
+    let Self {
+      identity,
+      action,
+      proposal_id,
+      controller_token,
+      ..
+    } = self;
+    let call = action.call.clone().ok_or_else(|| {
+      Error::TransactionBuildingFailed("the call to carry out must be provided through `with_call`".to_owned())
+    })?;
+    action.check_call(&call)?;
+    let identity_ref = client
+      .get_object_ref_by_id(identity.id())
+      .await?
+      .ok_or_else(|| Error::ObjectNotFound(identity.id()))?;
+    let controller_token = client.get_object_by_id::<ControllerToken>(*controller_token).await?;
+    let controller_token_ref = controller_token.controller_ref(client).await?;
+
+    let mut object_data_list = vec![];
+    for obj_id in action.objects.iter() {
+      let object_data = super::obj_data_for_id(client, *obj_id)
+        .await
+        .map_err(|e| Error::TransactionBuildingFailed(e.to_string()))?;
+      object_data_list.push(object_data);
+    }
+    let package = identity_package_id(client).await?;
+    let tx = move_calls::identity::execute_borrow(
+      identity_ref,
+      controller_token_ref,
+      *proposal_id,
+      object_data_list,
+      call.into_intent(package),
+      package,
+    )
+    .map_err(|e| Error::TransactionBuildingFailed(e.to_string()))?;
+
+    Ok(bcs::from_bytes(&tx)?)
+  }
+}
+
+impl<'i, T> ProtoTransaction for UserDrivenTx<'i, CustomCallAction<T>> {
+  type Input = CustomMoveCall<T>;
+  type Tx = TransactionBuilder<UserDrivenTx<'i, CustomCallAction<T>>>;
+
+  fn with(self, input: Self::Input) -> Self::Tx {
+    TransactionBuilder::new(self.with_call(input))
+  }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl<T> Transaction for UserDrivenTx<'_, CustomCallAction<T>>
+where
+  T: CustomCallOutput + OptionalSend + OptionalSync,
+{
+  type Output = T;
+  type Error = Error;
+
+  async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+  where
+    C: CoreClientReadOnly + OptionalSync,
+  {
+    self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+  }
+
+  async fn apply<C>(self, effects: &mut IotaTransactionBlockEffects, _client: &C) -> Result<Self::Output, Self::Error>
+  where
+    C: CoreClientReadOnly + OptionalSync,
+  {
+    if let IotaExecutionStatus::Failure { error } = effects.status() {
+      return Err(Error::TransactionUnexpectedResponse(error.clone()));
+    }
+
+    T::decode(effects)
+  }
+}
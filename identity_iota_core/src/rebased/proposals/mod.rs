@@ -2,9 +2,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod access_sub_identity;
+mod approval;
 mod borrow;
 mod config_change;
 mod controller;
+mod custom_call;
 mod send;
 mod update_did_doc;
 mod upgrade;
@@ -16,10 +18,12 @@ use std::ops::DerefMut;
 use crate::rebased::iota::move_calls;
 use crate::rebased::migration::get_identity;
 pub use access_sub_identity::*;
+pub use approval::*;
 use async_trait::async_trait;
 pub use borrow::*;
 pub use config_change::*;
 pub use controller::*;
+pub use custom_call::*;
 use iota_interaction::rpc_types::IotaExecutionStatus;
 use iota_interaction::rpc_types::IotaObjectData;
 use iota_interaction::rpc_types::IotaObjectDataOptions;
@@ -205,6 +209,15 @@ where
   type Output = ProposalResult<Proposal<A>>;
   type Error = Error;
 
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+      name = "build_transaction",
+      skip_all,
+      fields(transaction = "CreateProposal"),
+      err(Display)
+    )
+  )]
   async fn build_programmable_transaction<C>(&self, _client: &C) -> Result<ProgrammableTransaction, Error>
   where
     C: CoreClientReadOnly + OptionalSync,
@@ -212,6 +225,15 @@ where
     Ok(self.ptb.clone())
   }
 
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+      name = "apply_transaction_effects",
+      skip_all,
+      fields(transaction = "CreateProposal", digest = %effects.transaction_digest()),
+      err(Display)
+    )
+  )]
   async fn apply<C>(self, effects: &mut IotaTransactionBlockEffects, client: &C) -> Result<Self::Output, Error>
   where
     C: CoreClientReadOnly + OptionalSync,
@@ -228,9 +250,11 @@ where
 
     // Identity has been changed regardless of whether the proposal has been executed
     // or simply created. Refetch it, to sync it with its on-chain state.
+    let recorded_updates = identity.did_document().metadata.history().len();
     *identity = get_identity(client, identity.id())
       .await?
       .ok_or_else(|| Error::Identity(format!("identity {} cannot be found", identity.id())))?;
+    complete_provenance_entry(identity, recorded_updates, None, effects);
 
     if chained_execution {
       // The proposal has been created and executed right-away. Parse its effects.
@@ -243,7 +267,7 @@ where
         .created()
         .iter()
         .find(|obj_ref| obj_ref.owner != proposals_bag_id)
-        .expect("tx was successful")
+        .ok_or_else(|| Error::TransactionUnexpectedResponse("transaction didn't create a proposal".to_owned()))?
         .object_id();
 
       client
@@ -260,6 +284,7 @@ where
 pub struct ExecuteProposal<'i, A> {
   ptb: ProgrammableTransaction,
   identity: &'i mut OnChainIdentity,
+  proposal_id: ObjectId,
   _action: PhantomData<A>,
 }
 
@@ -280,6 +305,15 @@ where
   type Output = <Proposal<A> as ProposalT>::Output;
   type Error = Error;
 
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+      name = "build_transaction",
+      skip_all,
+      fields(transaction = "ExecuteProposal"),
+      err(Display)
+    )
+  )]
   async fn build_programmable_transaction<C>(&self, _client: &C) -> Result<ProgrammableTransaction, Error>
   where
     C: CoreClientReadOnly + OptionalSync,
@@ -287,24 +321,57 @@ where
     Ok(self.ptb.clone())
   }
 
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+      name = "apply_transaction_effects",
+      skip_all,
+      fields(transaction = "ExecuteProposal", digest = %effects.transaction_digest()),
+      err(Display)
+    )
+  )]
   async fn apply<C>(self, effects: &mut IotaTransactionBlockEffects, client: &C) -> Result<Self::Output, Error>
   where
     C: CoreClientReadOnly + OptionalSync,
   {
-    let Self { identity, .. } = self;
+    let Self {
+      identity, proposal_id, ..
+    } = self;
 
     if let IotaExecutionStatus::Failure { error } = effects.status() {
       return Err(Error::TransactionUnexpectedResponse(error.clone()));
     }
 
+    let recorded_updates = identity.did_document().metadata.history().len();
     *identity = get_identity(client, identity.id())
       .await?
       .ok_or_else(|| Error::Identity(format!("identity {} cannot be found", identity.id())))?;
+    complete_provenance_entry(identity, recorded_updates, Some(proposal_id), effects);
 
     Proposal::<A>::parse_tx_effects(effects)
   }
 }
 
+/// Fills in the proposal and transaction of the provenance entry written by the transaction with `effects`, if the
+/// transaction updated `identity`'s DID Document and provenance recording is enabled.
+///
+/// `recorded_updates` is the number of provenance entries `identity`'s DID Document had before the transaction.
+fn complete_provenance_entry(
+  identity: &mut OnChainIdentity,
+  recorded_updates: usize,
+  proposal_id: Option<ObjectId>,
+  effects: &IotaTransactionBlockEffects,
+) {
+  let metadata = &mut identity.did_document_mut().metadata;
+  if metadata.history().len() <= recorded_updates {
+    return;
+  }
+  if let Some(entry) = metadata.latest_provenance_entry_mut() {
+    entry.proposal = proposal_id;
+    entry.tx_digest = Some(effects.transaction_digest().to_string());
+  }
+}
+
 /// A transaction to approve a [`Proposal`].
 #[derive(Debug)]
 pub struct ApproveProposal<'p, 'i, A> {
@@ -370,12 +437,30 @@ where
   type Output = ();
   type Error = Error;
 
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+      name = "build_transaction",
+      skip_all,
+      fields(transaction = "ApproveProposal"),
+      err(Display)
+    )
+  )]
   async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
   where
     C: CoreClientReadOnly + OptionalSync,
   {
     self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
   }
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+      name = "apply_transaction_effects",
+      skip_all,
+      fields(transaction = "ApproveProposal", digest = %effects.transaction_digest()),
+      err(Display)
+    )
+  )]
   async fn apply<C>(self, effects: &mut IotaTransactionBlockEffects, _client: &C) -> Result<Self::Output, Self::Error>
   where
     C: CoreClientReadOnly + OptionalSync,
@@ -389,10 +474,7 @@ where
       .iter()
       .any(|obj| obj.object_id() == self.proposal.id());
     if proposal_was_updated {
-      let vp = self
-        .identity
-        .controller_voting_power(self.controller_token.controller_id())
-        .expect("is identity's controller");
+      let vp = self.identity.voting_power_of(&self.controller_token)?;
       *self.proposal.votes_mut() = self.proposal.votes() + vp;
       Ok(())
     } else {
@@ -410,7 +492,10 @@ async fn obj_data_for_id(client: &impl CoreClientReadOnly, obj_id: ObjectId) ->
   client
     .client_adapter()
     .read_api()
-    .get_object_with_options(obj_id, IotaObjectDataOptions::default().with_type().with_owner())
+    .get_object_with_options(
+      obj_id,
+      IotaObjectDataOptions::default().with_type().with_owner().with_bcs(),
+    )
     .await?
     .into_object()
     .context("no iota object in response")
@@ -422,7 +507,7 @@ async fn obj_ref_and_type_for_id(
 ) -> anyhow::Result<(ObjectRef, TypeTag)> {
   let res = obj_data_for_id(client, obj_id).await?;
   let obj_ref = res.object_ref();
-  let obj_type = match res.object_type().expect("object type is requested") {
+  let obj_type = match res.object_type()? {
     ObjectType::Package => anyhow::bail!("a move package cannot be sent"),
     ObjectType::Struct(type_) => TypeTag::Struct(Box::new(type_.into())),
   };
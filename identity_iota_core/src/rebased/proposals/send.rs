@@ -107,12 +107,9 @@ impl ProposalT for Proposal<SendAction> {
     let identity_ref = client
       .get_object_ref_by_id(identity.id())
       .await?
-      .expect("identity exists on-chain");
+      .ok_or_else(|| Error::ObjectNotFound(identity.id()))?;
     let controller_cap_ref = controller_token.controller_ref(client).await?;
-    let can_execute = identity
-      .controller_voting_power(controller_token.controller_id())
-      .expect("controller_cap is for this identity")
-      >= identity.threshold();
+    let can_execute = identity.voting_power_of(controller_token)? >= identity.threshold();
     let tx = if can_execute {
       // Construct a list of `(ObjectRef, TypeTag)` from the list of objects to send.
       let object_type_list = {
@@ -166,7 +163,7 @@ impl ProposalT for Proposal<SendAction> {
     let identity_ref = client
       .get_object_ref_by_id(identity.id())
       .await?
-      .expect("identity exists on-chain");
+      .ok_or_else(|| Error::ObjectNotFound(identity.id()))?;
     let controller_cap_ref = controller_token.controller_ref(client).await?;
 
     // Construct a list of `(ObjectRef, TypeTag)` from the list of objects to send.
@@ -190,6 +187,7 @@ impl ProposalT for Proposal<SendAction> {
     Ok(TransactionBuilder::new(ExecuteProposal {
       identity,
       ptb: bcs::from_bytes(&tx)?,
+      proposal_id,
       _action: PhantomData,
     }))
   }
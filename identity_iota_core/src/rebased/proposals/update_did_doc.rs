@@ -8,7 +8,11 @@ use std::marker::PhantomData;
 use crate::rebased::iota::move_calls;
 use crate::rebased::migration::ControllerToken;
 use crate::IotaDocument;
+use crate::ProvenanceEntry;
+use crate::StateMetadataDocument;
 use async_trait::async_trait;
+use identity_core::common::Clock;
+use identity_core::common::SharedClock;
 use iota_interaction::rpc_types::IotaTransactionBlockEffects;
 use iota_sdk_types::ObjectId;
 use iota_sdk_types::TypeTag;
@@ -24,12 +28,35 @@ use iota_interaction::MoveType;
 
 use super::CreateProposal;
 use super::ExecuteProposal;
+use super::ProposalBuilder;
 use super::ProposalT;
 
 /// Proposal's action for updating a DID Document.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(into = "UpdateValue::<Option<Vec<u8>>>", from = "UpdateValue::<Option<Vec<u8>>>")]
-pub struct UpdateDidDocument(Option<Vec<u8>>);
+pub struct UpdateDidDocument {
+  document: Option<Vec<u8>>,
+  lifecycle_change: Option<LifecycleChange>,
+  allow_pending_proposals: bool,
+  not_before: Option<u64>,
+  clock: SharedClock,
+}
+
+/// A change to the activation state of a DID Document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LifecycleChange {
+  Deactivation,
+  Reactivation,
+}
+
+impl LifecycleChange {
+  fn as_str(&self) -> &'static str {
+    match self {
+      Self::Deactivation => "deactivated",
+      Self::Reactivation => "reactivated",
+    }
+  }
+}
 
 impl MoveType for UpdateDidDocument {
   fn move_type(package: ObjectId) -> TypeTag {
@@ -45,22 +72,176 @@ impl MoveType for UpdateDidDocument {
 impl UpdateDidDocument {
   /// Creates a new [`UpdateDidDocument`] action.
   pub fn new(document: IotaDocument) -> Self {
-    Self(Some(document.pack().expect("a valid IotaDocument is packable")))
+    Self::from_bytes(Some(document.pack().expect("a valid IotaDocument is packable")))
   }
 
   /// Creates a new [`UpdateDidDocument`] action to deactivate the DID Document.
+  ///
+  /// Creating a proposal with this action fails if the DID Document is already deactivated or, unless
+  /// [`UpdateDidDocument::set_allow_pending_proposals`] is used, if the identity has pending proposals.
   pub fn deactivate() -> Self {
-    Self(Some(vec![]))
+    Self {
+      lifecycle_change: Some(LifecycleChange::Deactivation),
+      ..Self::from_bytes(Some(vec![]))
+    }
+  }
+
+  /// Creates a new [`UpdateDidDocument`] action to reactivate a deactivated DID Document, replacing it with
+  /// `document`. The `deactivated` flag of `document`'s metadata is cleared.
+  ///
+  /// Creating a proposal with this action fails if the DID Document is not deactivated or, unless
+  /// [`UpdateDidDocument::set_allow_pending_proposals`] is used, if the identity has pending proposals.
+  pub fn reactivate(mut document: IotaDocument) -> Self {
+    document.metadata.deactivated = None;
+    Self {
+      lifecycle_change: Some(LifecycleChange::Reactivation),
+      ..Self::new(document)
+    }
   }
 
   /// Creates a new [`UpdateDidDocument`] action to delete the DID Document.
   pub fn delete() -> Self {
-    Self(None)
+    Self::from_bytes(None)
+  }
+
+  fn from_bytes(document: Option<Vec<u8>>) -> Self {
+    Self {
+      document,
+      lifecycle_change: None,
+      allow_pending_proposals: false,
+      not_before: None,
+      clock: SharedClock::default(),
+    }
   }
 
   /// Returns the serialized DID document bytes.
   pub fn did_document_bytes(&self) -> Option<&[u8]> {
-    self.0.as_deref()
+    self.document.as_deref()
+  }
+
+  /// Returns whether this action deactivates the DID Document.
+  pub fn is_deactivation(&self) -> bool {
+    matches!(self.document.as_deref(), Some(&[]))
+  }
+
+  /// Returns whether this action was created through [`UpdateDidDocument::reactivate`].
+  pub fn is_reactivation(&self) -> bool {
+    self.lifecycle_change == Some(LifecycleChange::Reactivation)
+  }
+
+  /// Sets whether a deactivation or reactivation may be proposed while the identity has other pending proposals.
+  ///
+  /// Pending proposals are rejected by default, as executing them after the lifecycle change - e.g. a DID Document
+  /// update executed after a deactivation - would silently undo it.
+  pub fn set_allow_pending_proposals(&mut self, allow: bool) {
+    self.allow_pending_proposals = allow;
+  }
+
+  /// Sets the epoch before which the [`Proposal`] carrying this action cannot be executed - e.g. to schedule a key
+  /// rotation in advance.
+  ///
+  /// A time-locked [`Proposal`] is never executed upon creation, regardless of its creator's voting power.
+  pub fn set_not_before(&mut self, epoch: u64) {
+    self.not_before = Some(epoch);
+  }
+
+  /// Returns the epoch before which the [`Proposal`] carrying this action cannot be executed, if any.
+  pub fn not_before(&self) -> Option<u64> {
+    self.not_before
+  }
+
+  /// Sets the clock providing the timestamp of the [`ProvenanceEntry`] recorded for this update.
+  ///
+  /// Defaults to the [`SystemClock`](identity_core::common::SystemClock).
+  pub fn set_clock(&mut self, clock: impl Clock + 'static) {
+    self.clock = SharedClock::new(clock);
+  }
+
+  /// Checks that the lifecycle change carried by this action, if any, can be applied to `identity`.
+  fn check_lifecycle_change(&self, identity: &OnChainIdentity) -> Result<(), Error> {
+    let Some(change) = self.lifecycle_change else {
+      return Ok(());
+    };
+
+    let is_deactivated = identity.did_document().metadata.deactivated == Some(true);
+    match change {
+      LifecycleChange::Deactivation if is_deactivated => {
+        return Err(Error::Identity("the DID Document is already deactivated".into()));
+      }
+      LifecycleChange::Reactivation if !is_deactivated => {
+        return Err(Error::Identity("the DID Document is not deactivated".into()));
+      }
+      _ => (),
+    }
+
+    let pending_proposals = identity.proposals().len();
+    if pending_proposals == 0 {
+      return Ok(());
+    }
+    if !self.allow_pending_proposals {
+      return Err(Error::Identity(format!(
+        "identity {} has {pending_proposals} pending proposal(s) that could be executed after its DID Document is {}; \
+        execute or delete them first, or explicitly allow pending proposals",
+        identity.id(),
+        change.as_str(),
+      )));
+    }
+
+    #[cfg(feature = "tracing")]
+    tracing::warn!(
+      identity = %identity.id(),
+      pending_proposals,
+      "proposing a DID Document lifecycle change while other proposals are pending"
+    );
+
+    Ok(())
+  }
+
+  /// Appends an entry describing this update, proposed through `controller`, to the provenance log of the new DID
+  /// Document, if either `identity`'s current DID Document or the new one records provenance.
+  ///
+  /// The log is carried over from `identity`'s current DID Document, so that it cannot be rewritten by an update.
+  fn record_provenance(&mut self, identity: &OnChainIdentity, controller: ObjectId) -> Result<(), Error> {
+    let Some(bytes) = self.document.as_deref().filter(|bytes| !bytes.is_empty()) else {
+      return Ok(());
+    };
+    let current_metadata = &identity.did_document().metadata;
+    let mut document = StateMetadataDocument::unpack(bytes)
+      .and_then(|document| document.into_iota_document(identity.did_document().id()))
+      .map_err(|e| Error::DidDocParsingFailed(e.to_string()))?;
+    if !current_metadata.is_provenance_enabled() && !document.metadata.is_provenance_enabled() {
+      return Ok(());
+    }
+
+    let mut history = current_metadata.history().to_vec();
+    history.push(ProvenanceEntry::new(controller, self.clock.now()));
+    document.metadata.set_history(history);
+    self.document = Some(document.pack().map_err(|e| Error::DidDocSerialization(e.to_string()))?);
+
+    Ok(())
+  }
+}
+
+impl ProposalBuilder<'_, '_, UpdateDidDocument> {
+  /// Allows proposing a deactivation or reactivation while the identity has other pending proposals.
+  /// See [`UpdateDidDocument::set_allow_pending_proposals`].
+  pub fn allow_pending_proposals(mut self) -> Self {
+    self.set_allow_pending_proposals(true);
+    self
+  }
+
+  /// Prevents the [`Proposal`] from being executed before epoch `epoch`.
+  /// See [`UpdateDidDocument::set_not_before`].
+  pub fn with_not_before(mut self, epoch: u64) -> Self {
+    self.set_not_before(epoch);
+    self
+  }
+
+  /// Sets the clock providing the timestamp of the recorded [`ProvenanceEntry`].
+  /// See [`UpdateDidDocument::set_clock`].
+  pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+    self.set_clock(clock);
+    self
   }
 }
 
@@ -71,7 +252,7 @@ impl ProposalT for Proposal<UpdateDidDocument> {
   type Output = ();
 
   async fn create<'i, C>(
-    action: Self::Action,
+    mut action: Self::Action,
     expiration: Option<u64>,
     identity: &'i mut OnChainIdentity,
     controller_token: &ControllerToken,
@@ -90,22 +271,23 @@ impl ProposalT for Proposal<UpdateDidDocument> {
     if identity.has_deleted_did() {
       return Err(Error::Identity("cannot update a deleted DID Document".into()));
     }
+    action.check_lifecycle_change(identity)?;
+    action.record_provenance(identity, controller_token.controller_id())?;
 
     let package = identity_package_id(client).await?;
     let identity_ref = client
       .get_object_ref_by_id(identity.id())
       .await?
-      .expect("identity exists on-chain");
+      .ok_or_else(|| Error::ObjectNotFound(identity.id()))?;
     let controller_cap_ref = controller_token.controller_ref(client).await?;
-    let sender_vp = identity
-      .controller_voting_power(controller_token.controller_id())
-      .expect("controller exists");
-    let chained_execution = sender_vp >= identity.threshold();
+    let sender_vp = identity.voting_power_of(controller_token)?;
+    let chained_execution = sender_vp >= identity.threshold() && action.not_before.is_none();
     let tx = move_calls::identity::propose_update(
       identity_ref,
       controller_cap_ref,
-      action.0.as_deref(),
+      action.did_document_bytes(),
       expiration,
+      action.not_before,
       package,
     )
     .await
@@ -145,7 +327,7 @@ impl ProposalT for Proposal<UpdateDidDocument> {
     let identity_ref = client
       .get_object_ref_by_id(identity.id())
       .await?
-      .expect("identity exists on-chain");
+      .ok_or_else(|| Error::ObjectNotFound(identity.id()))?;
     let controller_cap_ref = controller_token.controller_ref(client).await?;
     let package = identity_package_id(client).await?;
 
@@ -158,6 +340,7 @@ impl ProposalT for Proposal<UpdateDidDocument> {
     Ok(TransactionBuilder::new(ExecuteProposal {
       identity,
       ptb,
+      proposal_id,
       _action: PhantomData,
     }))
   }
@@ -174,12 +357,18 @@ struct UpdateValue<V> {
 
 impl From<UpdateDidDocument> for UpdateValue<Option<Vec<u8>>> {
   fn from(value: UpdateDidDocument) -> Self {
-    Self { new_value: value.0 }
+    Self {
+      new_value: value.document,
+    }
   }
 }
 
 impl From<UpdateValue<Option<Vec<u8>>>> for UpdateDidDocument {
   fn from(value: UpdateValue<Option<Vec<u8>>>) -> Self {
-    UpdateDidDocument(value.new_value)
+    let mut action = UpdateDidDocument::from_bytes(value.new_value);
+    if action.is_deactivation() {
+      action.lifecycle_change = Some(LifecycleChange::Deactivation);
+    }
+    action
   }
 }
@@ -3,12 +3,18 @@
 
 use std::marker::PhantomData;
 
+use iota_interaction::rpc_types::IotaExecutionStatus;
 use iota_interaction::rpc_types::IotaTransactionBlockEffects;
+use iota_interaction::rpc_types::IotaTransactionBlockEffectsAPI as _;
+use iota_sdk_types::ProgrammableTransaction;
 use product_common::core_client::CoreClientReadOnly;
+use product_common::transaction::transaction_builder::Transaction;
 use product_common::transaction::transaction_builder::TransactionBuilder;
+use tokio::sync::OnceCell;
 
 use crate::rebased::iota::move_calls;
 use crate::rebased::iota::package::identity_package_id;
+use crate::rebased::iota::package::IDENTITY_PACKAGE_VERSION;
 use crate::rebased::migration::ControllerToken;
 use async_trait::async_trait;
 use iota_sdk_types::ObjectId;
@@ -72,11 +78,9 @@ impl ProposalT for Proposal<Upgrade> {
     let identity_ref = client
       .get_object_ref_by_id(identity.id())
       .await?
-      .expect("identity exists on-chain");
+      .ok_or_else(|| Error::ObjectNotFound(identity.id()))?;
     let controller_cap_ref = controller_token.controller_ref(client).await?;
-    let sender_vp = identity
-      .controller_voting_power(controller_token.controller_id())
-      .expect("controller exists");
+    let sender_vp = identity.voting_power_of(controller_token)?;
     let chained_execution = sender_vp >= identity.threshold();
     let package = identity_package_id(client).await?;
 
@@ -112,7 +116,7 @@ impl ProposalT for Proposal<Upgrade> {
     let identity_ref = client
       .get_object_ref_by_id(identity.id())
       .await?
-      .expect("identity exists on-chain");
+      .ok_or_else(|| Error::ObjectNotFound(identity.id()))?;
     let controller_cap_ref = controller_token.controller_ref(client).await?;
     let package = identity_package_id(client).await?;
 
@@ -122,6 +126,7 @@ impl ProposalT for Proposal<Upgrade> {
     Ok(TransactionBuilder::new(ExecuteProposal {
       identity,
       ptb: bcs::from_bytes(&tx)?,
+      proposal_id,
       _action: PhantomData,
     }))
   }
@@ -130,3 +135,139 @@ impl ProposalT for Proposal<Upgrade> {
     Ok(())
   }
 }
+
+/// A transaction that migrates one or more identities to the latest version of the `IotaIdentity` package,
+/// e.g. after an on-chain package upgrade.
+///
+/// Each identity is migrated right away, hence the controller token used for an identity must grant enough
+/// voting power to reach the identity's threshold. Identities requiring the approval of several controllers
+/// must be migrated through [`OnChainIdentity::upgrade_version`] instead.
+///
+/// ## Notes
+/// This transaction doesn't update the given [`OnChainIdentity`]s: fetch them again to get their current state.
+#[derive(Debug)]
+pub struct MigrateIdentity {
+  identities: Vec<(ObjectId, ControllerToken)>,
+  cached_ptb: OnceCell<ProgrammableTransaction>,
+}
+
+impl MigrateIdentity {
+  /// Creates a new [Transaction] to migrate `identity` using `controller_token`.
+  pub fn new(identity: &OnChainIdentity, controller_token: &ControllerToken) -> Result<Self, Error> {
+    Self {
+      identities: Vec::new(),
+      cached_ptb: OnceCell::new(),
+    }
+    .with_identity(identity, controller_token)
+  }
+
+  /// Adds `identity` to the identities to migrate, using `controller_token`.
+  /// Adding an identity more than once has no effect.
+  pub fn with_identity(
+    mut self,
+    identity: &OnChainIdentity,
+    controller_token: &ControllerToken,
+  ) -> Result<Self, Error> {
+    if identity.id() != controller_token.controller_of() {
+      return Err(Error::Identity(format!(
+        "token {} doesn't grant access to identity {}",
+        controller_token.id(),
+        identity.id()
+      )));
+    }
+    if identity.version() >= IDENTITY_PACKAGE_VERSION {
+      return Err(Error::InvalidArgument(format!(
+        "identity {} is already at the latest package version",
+        identity.id()
+      )));
+    }
+    let voting_power = identity
+      .controller_voting_power(controller_token.controller_id())
+      .unwrap_or_default();
+    if voting_power < identity.threshold() {
+      return Err(Error::InvalidArgument(format!(
+        "controller {} doesn't have enough voting power to migrate identity {} without a proposal",
+        controller_token.controller_id(),
+        identity.id()
+      )));
+    }
+
+    if !self.identities.iter().any(|(id, _)| *id == identity.id()) {
+      self.identities.push((identity.id(), controller_token.clone()));
+    }
+    Ok(self)
+  }
+
+  /// Returns the IDs of the identities to migrate.
+  pub fn identities(&self) -> impl Iterator<Item = ObjectId> + '_ {
+    self.identities.iter().map(|(id, _)| *id)
+  }
+
+  /// Wraps this transaction in a [TransactionBuilder].
+  pub fn finish(self) -> TransactionBuilder<Self> {
+    TransactionBuilder::new(self)
+  }
+
+  async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
+  where
+    C: CoreClientReadOnly + OptionalSync,
+  {
+    let mut identities = Vec::with_capacity(self.identities.len());
+    for (identity_id, controller_token) in &self.identities {
+      let identity_ref = client
+        .get_object_ref_by_id(*identity_id)
+        .await?
+        .ok_or_else(|| Error::Identity(format!("identity {identity_id} doesn't exist")))?;
+      let controller_cap = controller_token.controller_ref(client).await?;
+      identities.push((identity_ref, controller_cap));
+    }
+    let package = identity_package_id(client).await?;
+
+    let tx = move_calls::identity::migrate_identities(identities, package)?;
+
+    Ok(bcs::from_bytes(&tx)?)
+  }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl Transaction for MigrateIdentity {
+  type Output = ();
+  type Error = Error;
+
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+      name = "build_transaction",
+      skip_all,
+      fields(transaction = "MigrateIdentity"),
+      err(Display)
+    )
+  )]
+  async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+  where
+    C: CoreClientReadOnly + OptionalSync,
+  {
+    self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+  }
+
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+      name = "apply_transaction_effects",
+      skip_all,
+      fields(transaction = "MigrateIdentity", digest = %effects.transaction_digest()),
+      err(Display)
+    )
+  )]
+  async fn apply<C>(self, effects: &mut IotaTransactionBlockEffects, _client: &C) -> Result<Self::Output, Self::Error>
+  where
+    C: CoreClientReadOnly + OptionalSync,
+  {
+    if let IotaExecutionStatus::Failure { error } = effects.status() {
+      return Err(Error::TransactionUnexpectedResponse(error.clone()));
+    }
+
+    Ok(())
+  }
+}
@@ -0,0 +1,477 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use fastcrypto::hash::Blake2b256;
+use fastcrypto::hash::HashFunction as _;
+use iota_interaction::rpc_types::IotaObjectDataOptions;
+use iota_interaction::rpc_types::IotaTransactionBlockEffectsAPI as _;
+use iota_interaction::IotaClientTrait;
+use iota_interaction::IotaKeySignature;
+use iota_interaction::OptionalSend;
+use iota_interaction::OptionalSync;
+use iota_sdk_types::Address;
+use iota_sdk_types::Input;
+use iota_sdk_types::ObjectId;
+use iota_sdk_types::Owner;
+use iota_sdk_types::ProgrammableTransaction;
+use product_common::core_client::CoreClient;
+use product_common::core_client::CoreClientReadOnly;
+use product_common::transaction::transaction_builder::Transaction;
+use product_common::transaction::transaction_builder::TransactionBuilder;
+use product_common::transaction::TransactionOutput;
+use secret_storage::Signer;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::rebased::Error;
+
+/// The digest of the operation a transaction performs, i.e. of its sender and programmable transaction.
+///
+/// Unlike the digest of the transaction itself, it does not depend on the gas payment, and is therefore known before
+/// the transaction is submitted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct OperationDigest([u8; 32]);
+
+impl OperationDigest {
+  /// Computes the digest of the operation performed by `pt` when sent by `sender`.
+  pub fn new(sender: Address, pt: &ProgrammableTransaction) -> Result<Self, Error> {
+    let bytes = bcs::to_bytes(&(sender, pt))?;
+    Ok(Self(Blake2b256::digest(bytes).digest))
+  }
+
+  /// Returns the bytes of this digest.
+  pub fn as_bytes(&self) -> &[u8; 32] {
+    &self.0
+  }
+}
+
+impl Display for OperationDigest {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    f.write_str(&prefix_hex::encode(self.0))
+  }
+}
+
+impl FromStr for OperationDigest {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    prefix_hex::decode(s)
+      .map(Self)
+      .map_err(|e| Error::ParsingFailed(format!("operation digest `{s}`: {e}")))
+  }
+}
+
+impl Serialize for OperationDigest {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: serde::Serializer,
+  {
+    serializer.collect_str(self)
+  }
+}
+
+impl<'de> Deserialize<'de> for OperationDigest {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: serde::Deserializer<'de>,
+  {
+    let s = String::deserialize(deserializer)?;
+    s.parse().map_err(serde::de::Error::custom)
+  }
+}
+
+/// The outcome of a recorded submission.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum SubmissionStatus {
+  /// The operation was submitted, but its outcome is unknown, e.g. because the submission timed out.
+  Pending,
+  /// The operation was executed by the transaction with the given digest.
+  #[serde(rename_all = "camelCase")]
+  Executed {
+    /// The digest of the executed transaction.
+    transaction_digest: String,
+  },
+  /// The submitted transaction has not been executed, as none of the owned objects it takes as input were consumed.
+  NotExecuted,
+  /// Some owned objects the operation takes as input were consumed after the submission, most likely by the submitted
+  /// transaction itself. The operation must not be resubmitted.
+  InputsConsumed {
+    /// The objects that were consumed.
+    objects: Vec<ObjectId>,
+  },
+}
+
+/// A submission of an operation, as recorded in a [`SubmissionJournal`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmissionRecord {
+  /// The digest of the submitted operation.
+  pub operation: OperationDigest,
+  /// The sender of the operation.
+  pub sender: Address,
+  /// The owned objects the operation takes as input, with the version they had when it was submitted.
+  pub owned_inputs: Vec<(ObjectId, u64)>,
+  /// The last known status of the submission.
+  pub status: SubmissionStatus,
+}
+
+impl SubmissionRecord {
+  fn new(operation: OperationDigest, sender: Address, pt: &ProgrammableTransaction) -> Self {
+    let owned_inputs = pt
+      .inputs
+      .iter()
+      .filter_map(|input| match input {
+        Input::ImmutableOrOwned(object) | Input::Receiving(object) => Some((*object.object_id(), object.version())),
+        _ => None,
+      })
+      .collect();
+
+    Self {
+      operation,
+      sender,
+      owned_inputs,
+      status: SubmissionStatus::Pending,
+    }
+  }
+}
+
+/// A record of the operations submitted to the ledger, which protects them against double execution when their
+/// submission is retried.
+///
+/// The journal is kept in memory: use [`SubmissionJournal::records`] and [`SubmissionJournal::from_records`] to
+/// persist it across restarts.
+#[derive(Debug, Default)]
+pub struct SubmissionJournal {
+  records: Mutex<HashMap<OperationDigest, SubmissionRecord>>,
+}
+
+impl SubmissionJournal {
+  /// Creates a new empty [`SubmissionJournal`].
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Creates a new [`SubmissionJournal`] from previously persisted `records`.
+  pub fn from_records(records: impl IntoIterator<Item = SubmissionRecord>) -> Self {
+    let records = records.into_iter().map(|record| (record.operation, record)).collect();
+    Self {
+      records: Mutex::new(records),
+    }
+  }
+
+  /// Returns all the records of this journal.
+  pub fn records(&self) -> Vec<SubmissionRecord> {
+    self.lock().values().cloned().collect()
+  }
+
+  /// Returns the record of the operation with digest `operation`, if any.
+  pub fn record(&self, operation: &OperationDigest) -> Option<SubmissionRecord> {
+    self.lock().get(operation).cloned()
+  }
+
+  /// Removes the record of the operation with digest `operation`, e.g. once the operation has been confirmed and
+  /// doesn't need to be protected anymore.
+  pub fn forget(&self, operation: &OperationDigest) -> Option<SubmissionRecord> {
+    self.lock().remove(operation)
+  }
+
+  /// Returns the status of the submission of the operation with digest `operation`, querying the ledger if its
+  /// outcome is not known yet.
+  ///
+  /// The outcome of a pending submission is derived from the owned objects the operation takes as input, as the
+  /// digest of the submitted transaction depends on its gas payment and cannot be recorded beforehand.
+  ///
+  /// # Errors
+  /// - [`Error::InvalidArgument`] if no submission of `operation` was recorded.
+  /// - [`Error::UnverifiableSubmission`] if the submission is pending and the operation takes no mutable owned objects
+  ///   as input, e.g. because it only uses shared or immutable objects. Its outcome must then be checked by other
+  ///   means, after which the record can be [forgotten](SubmissionJournal::forget) to submit the operation again.
+  /// - An RPC error if the ledger cannot be queried.
+  pub async fn check_submission_status<C>(
+    &self,
+    operation: &OperationDigest,
+    client: &C,
+  ) -> Result<SubmissionStatus, Error>
+  where
+    C: CoreClientReadOnly + OptionalSync,
+  {
+    let record = self
+      .record(operation)
+      .ok_or_else(|| Error::InvalidArgument(format!("no submission of operation {operation} was recorded")))?;
+    if !matches!(record.status, SubmissionStatus::Pending) {
+      return Ok(record.status);
+    }
+    let unverifiable = || Error::UnverifiableSubmission {
+      operation: operation.to_string(),
+    };
+    if record.owned_inputs.is_empty() {
+      return Err(unverifiable());
+    }
+
+    let mut consumed = Vec::new();
+    let mut mutable_inputs = 0;
+    for (object_id, version) in &record.owned_inputs {
+      let response = client
+        .client_adapter()
+        .read_api()
+        .get_object_with_options(*object_id, IotaObjectDataOptions::default().with_owner())
+        .await
+        .map_err(|e| Error::RpcError(e.to_string()))?;
+      // Immutable objects are never consumed and tell nothing about the execution.
+      if matches!(response.owner(), Some(Owner::Immutable)) {
+        continue;
+      }
+      mutable_inputs += 1;
+      // Deleted or wrapped objects were consumed as well.
+      let current_version = response.object().ok().map(|object| object.version);
+      if current_version != Some(*version) {
+        consumed.push(*object_id);
+      }
+    }
+    if mutable_inputs == 0 {
+      return Err(unverifiable());
+    }
+
+    let status = if consumed.is_empty() {
+      SubmissionStatus::NotExecuted
+    } else {
+      SubmissionStatus::InputsConsumed { objects: consumed }
+    };
+    self.set_status(operation, status.clone());
+
+    Ok(status)
+  }
+
+  /// Records the submission of `pt` by `sender`, failing if the same operation is already recorded.
+  fn begin(&self, sender: Address, pt: &ProgrammableTransaction) -> Result<OperationDigest, Error> {
+    let operation = OperationDigest::new(sender, pt)?;
+    let mut records = self.lock();
+    if let Some(record) = records.get(&operation) {
+      return Err(Error::DuplicateSubmission {
+        operation: operation.to_string(),
+        status: record.status.clone(),
+      });
+    }
+    records.insert(operation, SubmissionRecord::new(operation, sender, pt));
+
+    Ok(operation)
+  }
+
+  fn set_status(&self, operation: &OperationDigest, status: SubmissionStatus) {
+    if let Some(record) = self.lock().get_mut(operation) {
+      record.status = status;
+    }
+  }
+
+  fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<OperationDigest, SubmissionRecord>> {
+    // A panic while holding the lock cannot leave the map in an inconsistent state.
+    self.records.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+  }
+}
+
+/// Idempotent submission capabilities for [`TransactionBuilder`].
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+pub trait TransactionBuilderSubmissionExt: Sized {
+  /// The output of the transaction.
+  type Output;
+
+  /// Records this transaction's operation in `journal`, then builds and executes it, optionally with `gas_budget`.
+  ///
+  /// If the execution fails without a definite outcome, e.g. because it timed out, the submission stays
+  /// [pending](SubmissionStatus::Pending): use [`SubmissionJournal::check_submission_status`] and
+  /// [`TransactionBuilderSubmissionExt::resubmit_if_unexecuted`] to safely retry it.
+  ///
+  /// # Errors
+  /// [`Error::DuplicateSubmission`] is returned if the same operation was already submitted through `journal`.
+  ///
+  /// ## Notes
+  /// The transaction is taken out of this builder and wrapped into a new one: call this method on a builder
+  /// that wasn't configured any further.
+  async fn execute_recorded<S, C>(
+    self,
+    client: &C,
+    journal: &SubmissionJournal,
+    gas_budget: Option<u64>,
+  ) -> Result<(OperationDigest, TransactionOutput<Self::Output>), Error>
+  where
+    S: Signer<IotaKeySignature> + OptionalSync,
+    C: CoreClient<S> + OptionalSync;
+
+  /// Resubmits this transaction, whose previous submission was recorded in `journal`, only if the previous
+  /// submission is known not to have been executed.
+  ///
+  /// Both submissions take the same owned objects as input, so at most one of them can be executed. Returns [None]
+  /// if the operation was already executed.
+  ///
+  /// # Errors
+  /// [`Error::UnsafeResubmission`] is returned if it cannot be ruled out that the previous submission was executed,
+  /// and [`Error::UnverifiableSubmission`] if its outcome cannot be determined at all, see
+  /// [`SubmissionJournal::check_submission_status`].
+  ///
+  /// ## Notes
+  /// See [`TransactionBuilderSubmissionExt::execute_recorded`].
+  async fn resubmit_if_unexecuted<S, C>(
+    self,
+    client: &C,
+    journal: &SubmissionJournal,
+    gas_budget: Option<u64>,
+  ) -> Result<Option<TransactionOutput<Self::Output>>, Error>
+  where
+    S: Signer<IotaKeySignature> + OptionalSync,
+    C: CoreClient<S> + OptionalSync;
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl<T> TransactionBuilderSubmissionExt for TransactionBuilder<T>
+where
+  T: Transaction + OptionalSend + OptionalSync,
+  T::Error: std::error::Error,
+  T::Output: OptionalSend,
+{
+  type Output = T::Output;
+
+  async fn execute_recorded<S, C>(
+    self,
+    client: &C,
+    journal: &SubmissionJournal,
+    gas_budget: Option<u64>,
+  ) -> Result<(OperationDigest, TransactionOutput<T::Output>), Error>
+  where
+    S: Signer<IotaKeySignature> + OptionalSync,
+    C: CoreClient<S> + OptionalSync,
+  {
+    let (tx, pt) = take_transaction(self, client).await?;
+    let operation = journal.begin(client.sender_address(), &pt)?;
+    let output = execute(tx, client, journal, operation, gas_budget).await?;
+
+    Ok((operation, output))
+  }
+
+  async fn resubmit_if_unexecuted<S, C>(
+    self,
+    client: &C,
+    journal: &SubmissionJournal,
+    gas_budget: Option<u64>,
+  ) -> Result<Option<TransactionOutput<T::Output>>, Error>
+  where
+    S: Signer<IotaKeySignature> + OptionalSync,
+    C: CoreClient<S> + OptionalSync,
+  {
+    let (tx, pt) = take_transaction(self, client).await?;
+    let operation = OperationDigest::new(client.sender_address(), &pt)?;
+
+    match journal.check_submission_status(&operation, client).await? {
+      SubmissionStatus::Executed { .. } => Ok(None),
+      SubmissionStatus::NotExecuted => {
+        journal.set_status(&operation, SubmissionStatus::Pending);
+        execute(tx, client, journal, operation, gas_budget).await.map(Some)
+      }
+      status => Err(Error::UnsafeResubmission {
+        operation: operation.to_string(),
+        status,
+      }),
+    }
+  }
+}
+
+async fn take_transaction<T, C>(
+  builder: TransactionBuilder<T>,
+  client: &C,
+) -> Result<(T, ProgrammableTransaction), Error>
+where
+  T: Transaction,
+  T::Error: std::error::Error,
+  C: CoreClientReadOnly + OptionalSync,
+{
+  let tx = builder.into_inner();
+  let pt = tx
+    .build_programmable_transaction(client)
+    .await
+    .map_err(|e| Error::TransactionBuildingFailed(e.to_string()))?;
+
+  Ok((tx, pt))
+}
+
+async fn execute<T, S, C>(
+  tx: T,
+  client: &C,
+  journal: &SubmissionJournal,
+  operation: OperationDigest,
+  gas_budget: Option<u64>,
+) -> Result<TransactionOutput<T::Output>, Error>
+where
+  T: Transaction + OptionalSend + OptionalSync,
+  T::Error: std::error::Error,
+  S: Signer<IotaKeySignature> + OptionalSync,
+  C: CoreClient<S> + OptionalSync,
+{
+  let builder = TransactionBuilder::new(tx);
+  let builder = match gas_budget {
+    Some(gas_budget) => builder.with_gas_budget(gas_budget),
+    None => builder,
+  };
+  // The submission stays pending on failure, as the transaction may have been executed nonetheless.
+  let output = builder
+    .build_and_execute(client)
+    .await
+    .map_err(|e| Error::TransactionUnexpectedResponse(e.to_string()))?;
+
+  let transaction_digest = output
+    .response
+    .effects
+    .as_ref()
+    .map(|effects| effects.transaction_digest().to_string())
+    .unwrap_or_else(|| output.response.digest.to_string());
+  journal.set_status(&operation, SubmissionStatus::Executed { transaction_digest });
+
+  Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn pt() -> ProgrammableTransaction {
+    ProgrammableTransaction {
+      inputs: vec![],
+      commands: vec![],
+    }
+  }
+
+  #[test]
+  fn operation_digest_depends_on_sender() {
+    let digest = OperationDigest::new(Address::ZERO, &pt()).unwrap();
+
+    assert_eq!(digest, OperationDigest::new(Address::ZERO, &pt()).unwrap());
+    assert_ne!(digest, OperationDigest::new(Address::new([1; 32]), &pt()).unwrap());
+    assert_eq!(digest.to_string().parse::<OperationDigest>().unwrap(), digest);
+  }
+
+  #[test]
+  fn duplicate_submissions_are_rejected() {
+    let journal = SubmissionJournal::new();
+    let operation = journal.begin(Address::ZERO, &pt()).unwrap();
+
+    assert!(matches!(
+      journal.begin(Address::ZERO, &pt()),
+      Err(Error::DuplicateSubmission {
+        status: SubmissionStatus::Pending,
+        ..
+      })
+    ));
+
+    let restored = SubmissionJournal::from_records(journal.records());
+    assert_eq!(restored.record(&operation).unwrap().status, SubmissionStatus::Pending);
+  }
+}
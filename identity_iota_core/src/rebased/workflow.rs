@@ -0,0 +1,751 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Multi-step workflows whose progress is checkpointed after every step, e.g. creating an identity, adding
+//! verification methods to its DID Document and issuing a first credential.
+//!
+//! A [`Workflow`] runs its [`WorkflowStep`]s in order, recording the output of each step in a [`WorkflowState`] that
+//! can be persisted through [`Workflow::on_checkpoint`]. When a step fails, either the steps completed so far are
+//! compensated in reverse order, or the workflow is left in a [failed](WorkflowStatus::Failed) state that can be
+//! resumed with [`Workflow::resume`] once the cause of the failure is fixed. Interrupted or failed compensations are
+//! resumed with [`Workflow::compensate`].
+
+use std::marker::PhantomData;
+
+use async_trait::async_trait;
+use iota_interaction::IotaKeySignature;
+use iota_interaction::OptionalSend;
+use iota_interaction::OptionalSync;
+use product_common::core_client::CoreClient;
+use product_common::transaction::transaction_builder::Transaction;
+use product_common::transaction::transaction_builder::TransactionBuilder;
+use secret_storage::Signer;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::rebased::Error;
+
+/// The future returned by the closures of a [`TransactionStep`].
+#[cfg(feature = "send-sync")]
+pub type StepFuture<'a, T> = futures::future::BoxFuture<'a, Result<T, Error>>;
+/// The future returned by the closures of a [`TransactionStep`].
+#[cfg(not(feature = "send-sync"))]
+pub type StepFuture<'a, T> = futures::future::LocalBoxFuture<'a, Result<T, Error>>;
+
+#[cfg(feature = "send-sync")]
+type CheckpointHook = Box<dyn Fn(&WorkflowState) -> Result<(), Error> + Send + Sync>;
+#[cfg(not(feature = "send-sync"))]
+type CheckpointHook = Box<dyn Fn(&WorkflowState) -> Result<(), Error>>;
+
+#[cfg(feature = "send-sync")]
+type CompensationFn<C> =
+  Box<dyn for<'a> Fn(&'a C, &'a Checkpoint, &'a WorkflowState) -> StepFuture<'a, ()> + Send + Sync>;
+#[cfg(not(feature = "send-sync"))]
+type CompensationFn<C> = Box<dyn for<'a> Fn(&'a C, &'a Checkpoint, &'a WorkflowState) -> StepFuture<'a, ()>>;
+
+/// The recorded outcome of a completed [`WorkflowStep`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Checkpoint {
+  /// The name of the step.
+  pub step: String,
+  /// The output of the step.
+  pub output: Value,
+}
+
+/// The status of a [`Workflow`] run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum WorkflowStatus {
+  /// The workflow has steps left to run.
+  Running,
+  /// All the steps of the workflow completed.
+  Completed,
+  /// A step failed and the workflow can be resumed from it.
+  Failed {
+    /// The name of the failed step.
+    step: String,
+    /// The description of the error.
+    error: String,
+  },
+  /// A step failed and the steps completed before it are being compensated; the checkpoints left are those of the
+  /// steps not compensated yet.
+  Compensating {
+    /// The name of the failed step.
+    step: String,
+    /// The description of the error.
+    error: String,
+  },
+  /// A step failed and the steps completed before it were compensated.
+  Compensated {
+    /// The name of the failed step.
+    step: String,
+    /// The description of the error.
+    error: String,
+  },
+  /// A step failed and compensating the steps completed before it failed as well. The compensation can be resumed
+  /// with [`Workflow::compensate`].
+  CompensationFailed {
+    /// The name of the failed step.
+    step: String,
+    /// The description of the error.
+    error: String,
+    /// The name of the step whose compensation failed.
+    compensation_step: String,
+    /// The description of the compensation error.
+    compensation_error: String,
+  },
+}
+
+/// The persistable progress of a [`Workflow`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkflowState {
+  workflow: String,
+  checkpoints: Vec<Checkpoint>,
+  status: WorkflowStatus,
+}
+
+impl WorkflowState {
+  fn new(workflow: &str) -> Self {
+    Self {
+      workflow: workflow.to_owned(),
+      checkpoints: Vec::new(),
+      status: WorkflowStatus::Running,
+    }
+  }
+
+  /// Returns the identifier of the workflow this state belongs to.
+  pub fn workflow(&self) -> &str {
+    &self.workflow
+  }
+
+  /// Returns the checkpoints of the completed steps, in the order they were completed.
+  pub fn checkpoints(&self) -> &[Checkpoint] {
+    &self.checkpoints
+  }
+
+  /// Returns the status of the workflow.
+  pub fn status(&self) -> &WorkflowStatus {
+    &self.status
+  }
+
+  /// Returns the output of the completed step named `step`, if any.
+  pub fn output(&self, step: &str) -> Option<&Value> {
+    self
+      .checkpoints
+      .iter()
+      .find(|checkpoint| checkpoint.step == step)
+      .map(|checkpoint| &checkpoint.output)
+  }
+
+  /// Returns the output of the completed step named `step`, deserialized as `T`.
+  /// # Errors
+  /// Fails if the step was not completed or its output is not a `T`.
+  pub fn output_as<T: DeserializeOwned>(&self, step: &str) -> Result<T, Error> {
+    let output = self.output(step).ok_or_else(|| {
+      Error::InvalidArgument(format!(
+        "step `{step}` of workflow `{}` is not completed",
+        self.workflow
+      ))
+    })?;
+
+    serde_json::from_value(output.clone()).map_err(|e| Error::ParsingFailed(format!("output of step `{step}`: {e}")))
+  }
+}
+
+/// A step of a [`Workflow`], run with a client of type `C`.
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+pub trait WorkflowStep<C: OptionalSync>: OptionalSend + OptionalSync {
+  /// Returns the name of this step, unique within its workflow.
+  fn name(&self) -> &str;
+
+  /// Runs this step, returning the output recorded in its [`Checkpoint`].
+  ///
+  /// `state` holds the checkpoints of the steps completed before this one.
+  async fn execute(&self, client: &C, state: &WorkflowState) -> Result<Value, Error>;
+
+  /// Undoes the effects of this step, completed with `checkpoint`, after a later step failed.
+  ///
+  /// Does nothing by default.
+  async fn compensate(&self, _client: &C, _checkpoint: &Checkpoint, _state: &WorkflowState) -> Result<(), Error> {
+    Ok(())
+  }
+}
+
+/// A sequence of [`WorkflowStep`]s, checkpointed after every step.
+pub struct Workflow<C: OptionalSync> {
+  id: String,
+  steps: Vec<Box<dyn WorkflowStep<C>>>,
+  compensate_on_failure: bool,
+  checkpoint_hook: Option<CheckpointHook>,
+}
+
+impl<C: OptionalSync> std::fmt::Debug for Workflow<C> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("Workflow")
+      .field("id", &self.id)
+      .field("steps", &self.steps.iter().map(|step| step.name()).collect::<Vec<_>>())
+      .field("compensate_on_failure", &self.compensate_on_failure)
+      .finish_non_exhaustive()
+  }
+}
+
+impl<C: OptionalSync> Workflow<C> {
+  /// Creates a new [`Workflow`] identified by `id`, without any step.
+  ///
+  /// Steps completed before a failure are compensated by default.
+  pub fn new(id: impl Into<String>) -> Self {
+    Self {
+      id: id.into(),
+      steps: Vec::new(),
+      compensate_on_failure: true,
+      checkpoint_hook: None,
+    }
+  }
+
+  /// Appends `step` to the steps of this workflow.
+  /// # Errors
+  /// Fails if this workflow already has a step with the same name.
+  pub fn step(mut self, step: impl WorkflowStep<C> + 'static) -> Result<Self, Error> {
+    if self.steps.iter().any(|existing| existing.name() == step.name()) {
+      return Err(Error::InvalidArgument(format!(
+        "workflow `{}` already has a step named `{}`",
+        self.id,
+        step.name()
+      )));
+    }
+    self.steps.push(Box::new(step));
+    Ok(self)
+  }
+
+  /// Sets whether the steps completed before a failed step are compensated.
+  ///
+  /// When they are not, the workflow can be [resumed](Workflow::resume) from the failed step.
+  pub fn compensate_on_failure(mut self, compensate: bool) -> Self {
+    self.compensate_on_failure = compensate;
+    self
+  }
+
+  /// Sets a hook called with the state of the workflow every time it changes, e.g. to persist it.
+  ///
+  /// A failing hook stops the workflow with [`Error::WorkflowFailed`], carrying the state the hook failed to persist.
+  #[cfg(feature = "send-sync")]
+  pub fn on_checkpoint(mut self, hook: impl Fn(&WorkflowState) -> Result<(), Error> + Send + Sync + 'static) -> Self {
+    self.checkpoint_hook = Some(Box::new(hook));
+    self
+  }
+
+  /// Sets a hook called with the state of the workflow every time it changes, e.g. to persist it.
+  ///
+  /// A failing hook stops the workflow with [`Error::WorkflowFailed`], carrying the state the hook failed to persist.
+  #[cfg(not(feature = "send-sync"))]
+  pub fn on_checkpoint(mut self, hook: impl Fn(&WorkflowState) -> Result<(), Error> + 'static) -> Self {
+    self.checkpoint_hook = Some(Box::new(hook));
+    self
+  }
+
+  /// Returns the identifier of this workflow.
+  pub fn id(&self) -> &str {
+    &self.id
+  }
+
+  /// Runs all the steps of this workflow.
+  /// # Errors
+  /// [`Error::WorkflowFailed`] is returned, together with the final state of the workflow, if a step fails.
+  pub async fn run(&self, client: &C) -> Result<WorkflowState, Error> {
+    self.resume(client, WorkflowState::new(&self.id)).await
+  }
+
+  /// Runs the steps of this workflow that were not completed in `state`, e.g. after a previous run failed or was
+  /// interrupted.
+  /// # Errors
+  /// [`Error::WorkflowFailed`] is returned, together with the final state of the workflow, if a step or the
+  /// checkpoint hook fails. In the latter case the returned state includes the step that completed, so that resuming
+  /// from it does not run that step again.
+  /// [`Error::InvalidArgument`] is returned if `state` doesn't belong to this workflow, or the workflow is being or
+  /// was compensated.
+  pub async fn resume(&self, client: &C, mut state: WorkflowState) -> Result<WorkflowState, Error> {
+    self.check_state(&state)?;
+    match state.status {
+      WorkflowStatus::Completed => return Ok(state),
+      WorkflowStatus::Running | WorkflowStatus::Failed { .. } => (),
+      _ => {
+        return Err(Error::InvalidArgument(format!(
+          "workflow `{}` was compensated and cannot be resumed",
+          self.id
+        )))
+      }
+    }
+    state.status = WorkflowStatus::Running;
+
+    for step in &self.steps[state.checkpoints.len()..] {
+      let output = match step.execute(client, &state).await {
+        Ok(output) => output,
+        Err(error) => return Err(self.fail(client, state, step.name(), error).await),
+      };
+      state.checkpoints.push(Checkpoint {
+        step: step.name().to_owned(),
+        output,
+      });
+      if let Err(error) = self.checkpoint(&state) {
+        return Err(self.workflow_failed(step.name(), error, state));
+      }
+    }
+
+    state.status = WorkflowStatus::Completed;
+    if let Err(error) = self.checkpoint(&state) {
+      let step = self.steps.last().map(|step| step.name().to_owned()).unwrap_or_default();
+      return Err(self.workflow_failed(&step, error, state));
+    }
+
+    Ok(state)
+  }
+
+  /// Compensates the steps completed in `state` in reverse order, e.g. to finish a compensation that was interrupted
+  /// or failed, or to roll back a failed workflow that was not compensated on failure.
+  ///
+  /// Compensations already completed are not run again. Returns the [compensated](WorkflowStatus::Compensated) state.
+  /// # Errors
+  /// [`Error::WorkflowFailed`] is returned, together with the state of the workflow, if a compensation or the
+  /// checkpoint hook fails.
+  /// [`Error::InvalidArgument`] is returned if `state` doesn't belong to this workflow, or the workflow didn't fail.
+  pub async fn compensate(&self, client: &C, state: WorkflowState) -> Result<WorkflowState, Error> {
+    self.check_state(&state)?;
+    let (step, error) = match &state.status {
+      WorkflowStatus::Compensated { .. } => return Ok(state),
+      WorkflowStatus::Failed { step, error }
+      | WorkflowStatus::Compensating { step, error }
+      | WorkflowStatus::CompensationFailed { step, error, .. } => (step.clone(), error.clone()),
+      _ => {
+        return Err(Error::InvalidArgument(format!(
+          "workflow `{}` did not fail and cannot be compensated",
+          self.id
+        )))
+      }
+    };
+
+    self
+      .run_compensations(client, state, step, error)
+      .await
+      .map_err(|(step, error, state)| self.workflow_failed(&step, error, state))
+  }
+
+  fn check_state(&self, state: &WorkflowState) -> Result<(), Error> {
+    if state.workflow != self.id {
+      return Err(Error::InvalidArgument(format!(
+        "state of workflow `{}` cannot be used to resume workflow `{}`",
+        state.workflow, self.id
+      )));
+    }
+    let matches_steps = state.checkpoints.len() <= self.steps.len()
+      && state
+        .checkpoints
+        .iter()
+        .zip(&self.steps)
+        .all(|(checkpoint, step)| checkpoint.step == step.name());
+    if !matches_steps {
+      return Err(Error::InvalidArgument(format!(
+        "the checkpoints of workflow `{}` don't match its steps",
+        self.id
+      )));
+    }
+
+    Ok(())
+  }
+
+  /// Records the failure of `step`, compensating the completed steps if required.
+  async fn fail(&self, client: &C, mut state: WorkflowState, step: &str, error: Error) -> Error {
+    if self.compensate_on_failure {
+      // The failure of the step takes precedence over the failure of a compensation or of the hook, both of which
+      // are recorded in the returned state.
+      let state = match self
+        .run_compensations(client, state, step.to_owned(), error.to_string())
+        .await
+      {
+        Ok(state) | Err((_, _, state)) => state,
+      };
+      return self.workflow_failed(step, error, state);
+    }
+
+    state.status = WorkflowStatus::Failed {
+      step: step.to_owned(),
+      error: error.to_string(),
+    };
+    // The failure of the step takes precedence over the failure of the hook.
+    let _ = self.checkpoint(&state);
+
+    self.workflow_failed(step, error, state)
+  }
+
+  /// Compensates the completed steps of `state` in reverse order after `step` failed with `error`, persisting the
+  /// state after every compensation.
+  ///
+  /// On failure, returns the name of the step whose compensation or checkpoint failed, the error and the state to
+  /// resume the compensation from.
+  async fn run_compensations(
+    &self,
+    client: &C,
+    mut state: WorkflowState,
+    step: String,
+    error: String,
+  ) -> Result<WorkflowState, (String, Error, WorkflowState)> {
+    state.status = WorkflowStatus::Compensating {
+      step: step.clone(),
+      error: error.clone(),
+    };
+    if let Err(hook_error) = self.checkpoint(&state) {
+      return Err((step, hook_error, state));
+    }
+
+    while let Some(checkpoint) = state.checkpoints.last().cloned() {
+      let completed_step = &self.steps[state.checkpoints.len() - 1];
+      if let Err(compensation_error) = completed_step.compensate(client, &checkpoint, &state).await {
+        state.status = WorkflowStatus::CompensationFailed {
+          step,
+          error,
+          compensation_step: completed_step.name().to_owned(),
+          compensation_error: compensation_error.to_string(),
+        };
+        // The failure of the compensation takes precedence over the failure of the hook.
+        let _ = self.checkpoint(&state);
+        return Err((completed_step.name().to_owned(), compensation_error, state));
+      }
+      state.checkpoints.pop();
+      if let Err(hook_error) = self.checkpoint(&state) {
+        return Err((completed_step.name().to_owned(), hook_error, state));
+      }
+    }
+
+    state.status = WorkflowStatus::Compensated {
+      step: step.clone(),
+      error,
+    };
+    if let Err(hook_error) = self.checkpoint(&state) {
+      return Err((step, hook_error, state));
+    }
+
+    Ok(state)
+  }
+
+  fn workflow_failed(&self, step: &str, error: Error, state: WorkflowState) -> Error {
+    Error::WorkflowFailed {
+      workflow: self.id.clone(),
+      step: step.to_owned(),
+      source: Box::new(error),
+      state: Box::new(state),
+    }
+  }
+
+  fn checkpoint(&self, state: &WorkflowState) -> Result<(), Error> {
+    self.checkpoint_hook.as_ref().map_or(Ok(()), |hook| hook(state))
+  }
+}
+
+/// A [`WorkflowStep`] building and executing a [`Transaction`].
+///
+/// Its checkpoint records the digest of the executed transaction as `transactionDigest`, and the output of the
+/// transaction as `output`.
+pub struct TransactionStep<C, S, T, F> {
+  name: String,
+  build: F,
+  compensation: Option<CompensationFn<C>>,
+  _marker: PhantomData<fn() -> (C, S, T)>,
+}
+
+impl<C, S, T, F> std::fmt::Debug for TransactionStep<C, S, T, F> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("TransactionStep")
+      .field("name", &self.name)
+      .field("compensable", &self.compensation.is_some())
+      .finish_non_exhaustive()
+  }
+}
+
+impl<C, S, T, F> TransactionStep<C, S, T, F>
+where
+  F: for<'a> Fn(&'a C, &'a WorkflowState) -> StepFuture<'a, TransactionBuilder<T>>,
+{
+  /// Creates a new [`TransactionStep`] named `name`, executing the transaction built by `build` from the state of
+  /// the workflow.
+  pub fn new(name: impl Into<String>, build: F) -> Self {
+    Self {
+      name: name.into(),
+      build,
+      compensation: None,
+      _marker: PhantomData,
+    }
+  }
+
+  /// Sets the compensation of this step, called with its checkpoint when a later step fails.
+  #[cfg(feature = "send-sync")]
+  pub fn with_compensation<G>(mut self, compensation: G) -> Self
+  where
+    G: for<'a> Fn(&'a C, &'a Checkpoint, &'a WorkflowState) -> StepFuture<'a, ()> + Send + Sync + 'static,
+  {
+    self.compensation = Some(Box::new(compensation));
+    self
+  }
+
+  /// Sets the compensation of this step, called with its checkpoint when a later step fails.
+  #[cfg(not(feature = "send-sync"))]
+  pub fn with_compensation<G>(mut self, compensation: G) -> Self
+  where
+    G: for<'a> Fn(&'a C, &'a Checkpoint, &'a WorkflowState) -> StepFuture<'a, ()> + 'static,
+  {
+    self.compensation = Some(Box::new(compensation));
+    self
+  }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl<C, S, T, F> WorkflowStep<C> for TransactionStep<C, S, T, F>
+where
+  C: CoreClient<S> + OptionalSync,
+  S: Signer<IotaKeySignature> + OptionalSync,
+  T: Transaction + OptionalSend + OptionalSync,
+  T::Error: std::error::Error,
+  T::Output: Serialize + OptionalSend,
+  F: for<'a> Fn(&'a C, &'a WorkflowState) -> StepFuture<'a, TransactionBuilder<T>> + OptionalSend + OptionalSync,
+{
+  fn name(&self) -> &str {
+    &self.name
+  }
+
+  async fn execute(&self, client: &C, state: &WorkflowState) -> Result<Value, Error> {
+    let output = (self.build)(client, state)
+      .await?
+      .build_and_execute(client)
+      .await
+      .map_err(|e| Error::TransactionUnexpectedResponse(e.to_string()))?;
+    let tx_output = serde_json::to_value(&output.output).map_err(|e| Error::AnyError(e.into()))?;
+
+    Ok(serde_json::json!({
+      "transactionDigest": output.response.digest.to_string(),
+      "output": tx_output,
+    }))
+  }
+
+  async fn compensate(&self, client: &C, checkpoint: &Checkpoint, state: &WorkflowState) -> Result<(), Error> {
+    match &self.compensation {
+      Some(compensation) => compensation(client, checkpoint, state).await,
+      None => Ok(()),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+  use std::sync::Mutex;
+
+  use super::*;
+
+  /// A step recording its executions and compensations in a shared log.
+  struct LogStep {
+    name: &'static str,
+    fail: bool,
+    fail_compensation: bool,
+    log: Arc<Mutex<Vec<String>>>,
+  }
+
+  impl LogStep {
+    fn new(name: &'static str, fail: bool, log: &Arc<Mutex<Vec<String>>>) -> Self {
+      Self {
+        name,
+        fail,
+        fail_compensation: false,
+        log: log.clone(),
+      }
+    }
+
+    fn failing_compensation(mut self) -> Self {
+      self.fail_compensation = true;
+      self
+    }
+  }
+
+  #[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+  #[cfg_attr(feature = "send-sync", async_trait)]
+  impl WorkflowStep<()> for LogStep {
+    fn name(&self) -> &str {
+      self.name
+    }
+
+    async fn execute(&self, _client: &(), state: &WorkflowState) -> Result<Value, Error> {
+      if self.fail {
+        return Err(Error::InvalidArgument(format!("{} failed", self.name)));
+      }
+      self.log.lock().unwrap().push(format!("execute {}", self.name));
+      Ok(Value::from(state.checkpoints().len()))
+    }
+
+    async fn compensate(&self, _client: &(), _checkpoint: &Checkpoint, _state: &WorkflowState) -> Result<(), Error> {
+      if self.fail_compensation {
+        return Err(Error::InvalidArgument(format!("compensating {} failed", self.name)));
+      }
+      self.log.lock().unwrap().push(format!("compensate {}", self.name));
+      Ok(())
+    }
+  }
+
+  #[tokio::test]
+  async fn completed_steps_are_compensated_in_reverse_order() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let workflow = Workflow::new("onboarding")
+      .step(LogStep::new("create", false, &log))
+      .unwrap()
+      .step(LogStep::new("update", false, &log))
+      .unwrap()
+      .step(LogStep::new("issue", true, &log))
+      .unwrap();
+
+    let Err(Error::WorkflowFailed { step, state, .. }) = workflow.run(&()).await else {
+      panic!("workflow should fail");
+    };
+    assert_eq!(step, "issue");
+    assert!(matches!(state.status(), WorkflowStatus::Compensated { .. }));
+    assert!(state.checkpoints().is_empty());
+    assert_eq!(
+      *log.lock().unwrap(),
+      [
+        "execute create",
+        "execute update",
+        "compensate update",
+        "compensate create"
+      ]
+    );
+  }
+
+  #[tokio::test]
+  async fn failed_workflows_resume_from_the_failed_step() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let failing = Workflow::new("onboarding")
+      .compensate_on_failure(false)
+      .step(LogStep::new("create", false, &log))
+      .unwrap()
+      .step(LogStep::new("issue", true, &log))
+      .unwrap();
+    let Err(Error::WorkflowFailed { state, .. }) = failing.run(&()).await else {
+      panic!("workflow should fail");
+    };
+    assert!(matches!(state.status(), WorkflowStatus::Failed { step, .. } if step == "issue"));
+
+    let fixed = Workflow::new("onboarding")
+      .step(LogStep::new("create", false, &log))
+      .unwrap()
+      .step(LogStep::new("issue", false, &log))
+      .unwrap();
+    let state = fixed.resume(&(), *state).await.unwrap();
+    assert_eq!(state.status(), &WorkflowStatus::Completed);
+    assert_eq!(state.output_as::<usize>("issue").unwrap(), 1);
+    assert_eq!(*log.lock().unwrap(), ["execute create", "execute issue"]);
+  }
+
+  #[tokio::test]
+  async fn failed_checkpoints_return_the_state() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let workflow = Workflow::new("onboarding")
+      .step(LogStep::new("create", false, &log))
+      .unwrap()
+      .step(LogStep::new("issue", false, &log))
+      .unwrap();
+
+    let Err(Error::WorkflowFailed { step, state, .. }) = workflow
+      .on_checkpoint(|_| Err(Error::InvalidArgument("storage unavailable".to_owned())))
+      .run(&())
+      .await
+    else {
+      panic!("workflow should fail");
+    };
+    assert_eq!(step, "create");
+    assert_eq!(state.status(), &WorkflowStatus::Running);
+    assert_eq!(state.checkpoints().len(), 1);
+
+    let workflow = Workflow::new("onboarding")
+      .step(LogStep::new("create", false, &log))
+      .unwrap()
+      .step(LogStep::new("issue", false, &log))
+      .unwrap();
+    let state = workflow.resume(&(), *state).await.unwrap();
+    assert_eq!(state.status(), &WorkflowStatus::Completed);
+    assert_eq!(*log.lock().unwrap(), ["execute create", "execute issue"]);
+  }
+
+  #[tokio::test]
+  async fn failed_compensations_are_resumed() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let persisted = Arc::new(Mutex::new(Vec::new()));
+    let hook_persisted = persisted.clone();
+    let failing = Workflow::new("onboarding")
+      .step(LogStep::new("create", false, &log).failing_compensation())
+      .unwrap()
+      .step(LogStep::new("update", false, &log))
+      .unwrap()
+      .step(LogStep::new("issue", true, &log))
+      .unwrap()
+      .on_checkpoint(move |state| {
+        hook_persisted.lock().unwrap().push(state.clone());
+        Ok(())
+      });
+
+    let Err(Error::WorkflowFailed { step, state, .. }) = failing.run(&()).await else {
+      panic!("workflow should fail");
+    };
+    assert_eq!(step, "issue");
+    assert!(matches!(
+      state.status(),
+      WorkflowStatus::CompensationFailed { step, compensation_step, .. }
+        if step == "issue" && compensation_step == "create"
+    ));
+    assert_eq!(state.checkpoints().len(), 1);
+    // The state was persisted after the compensation of `update`, before compensating `create`.
+    assert!(persisted.lock().unwrap().iter().any(|persisted| matches!(
+      persisted.status(),
+      WorkflowStatus::Compensating { .. }
+    ) && persisted.checkpoints().len() == 1));
+    assert!(matches!(
+      failing.resume(&(), (*state).clone()).await,
+      Err(Error::InvalidArgument(_))
+    ));
+
+    let fixed = Workflow::new("onboarding")
+      .step(LogStep::new("create", false, &log))
+      .unwrap()
+      .step(LogStep::new("update", false, &log))
+      .unwrap()
+      .step(LogStep::new("issue", true, &log))
+      .unwrap();
+    let state = fixed.compensate(&(), *state).await.unwrap();
+    assert!(matches!(state.status(), WorkflowStatus::Compensated { step, .. } if step == "issue"));
+    assert!(state.checkpoints().is_empty());
+    assert_eq!(
+      *log.lock().unwrap(),
+      [
+        "execute create",
+        "execute update",
+        "compensate update",
+        "compensate create"
+      ]
+    );
+  }
+
+  #[tokio::test]
+  async fn states_of_other_workflows_are_rejected() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let state = Workflow::new("a")
+      .step(LogStep::new("create", false, &log))
+      .unwrap()
+      .run(&())
+      .await
+      .unwrap();
+
+    let other = Workflow::new("b").step(LogStep::new("create", false, &log)).unwrap();
+    assert!(matches!(other.resume(&(), state).await, Err(Error::InvalidArgument(_))));
+  }
+}
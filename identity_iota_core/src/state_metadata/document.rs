@@ -191,7 +191,8 @@ impl From<IotaDocument> for StateMetadataDocument {
   /// occurrences of its did with a placeholder.
   fn from(document: IotaDocument) -> Self {
     let id: IotaDID = document.id().clone();
-    let IotaDocument { document, metadata } = document;
+    let IotaDocument { document, mut metadata } = document;
+    metadata.clear_resolution_metadata();
 
     // Replace self-referential identifiers with a placeholder, but not others.
     let replace_id_with_placeholder = |did: CoreDID| -> CoreDID {
@@ -47,6 +47,37 @@ async fn creating_authenticated_asset_works() -> anyhow::Result<()> {
   Ok(())
 }
 
+#[tokio::test]
+async fn assets_can_be_found_by_owner_and_origin() -> anyhow::Result<()> {
+  let test_client = get_funded_test_client().await?;
+  let alice_client = test_client.new_user_client().await?;
+
+  let mut asset_ids = vec![];
+  for content in [1_u64, 2] {
+    let asset = alice_client
+      .create_authenticated_asset::<u64>(content)
+      .finish(&alice_client)
+      .build_and_execute(&alice_client)
+      .await?
+      .output;
+    asset_ids.push(asset.id());
+  }
+
+  let owned_assets = AuthenticatedAsset::<u64>::find_by_owner(alice_client.sender_address(), &alice_client).await?;
+  assert_eq!(
+    owned_assets.iter().map(AuthenticatedAsset::id).sorted().collect_vec(),
+    asset_ids.iter().copied().sorted().collect_vec()
+  );
+
+  let created_assets = AuthenticatedAsset::<u64>::find_by_origin(alice_client.sender_address(), &alice_client).await?;
+  assert!(created_assets
+    .iter()
+    .all(|asset| asset.origin() == alice_client.sender_address()));
+  assert_eq!(created_assets.len(), 2);
+
+  Ok(())
+}
+
 #[tokio::test]
 async fn transferring_asset_works() -> anyhow::Result<()> {
   let test_client = get_funded_test_client().await?;
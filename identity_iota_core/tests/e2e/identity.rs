@@ -6,13 +6,23 @@ use crate::common::get_key_data;
 use crate::common::TestClient;
 use crate::common::TEST_COIN_TYPE;
 use crate::common::TEST_GAS_BUDGET;
+use identity_core::common::Timestamp;
 use identity_iota_core::rebased::client::get_object_id_from_did;
 use identity_iota_core::rebased::migration::get_identity;
 use identity_iota_core::rebased::migration::has_previous_version;
 use identity_iota_core::rebased::migration::ControllerToken;
 use identity_iota_core::rebased::migration::DelegationToken;
 use identity_iota_core::rebased::migration::Identity;
+use identity_iota_core::rebased::migration::IdentityStateReplayer;
+use identity_iota_core::rebased::migration::OwnedObjectKind;
+use identity_iota_core::rebased::migration::Proposal;
+use identity_iota_core::rebased::proposals::ApproveProposals;
+use identity_iota_core::rebased::proposals::CustomCallArg;
+use identity_iota_core::rebased::proposals::LentCapArg;
+use identity_iota_core::rebased::proposals::MigrateIdentity;
 use identity_iota_core::rebased::proposals::ProposalResult;
+use identity_iota_core::rebased::proposals::ProposalT as _;
+use identity_iota_core::rebased::proposals::UpdateDidDocument;
 use identity_iota_core::IotaDID;
 use identity_iota_core::IotaDocument;
 use identity_jose::jwk::ToJwk as _;
@@ -58,6 +68,23 @@ async fn identity_deactivation_works() -> anyhow::Result<()> {
 
   assert!(identity.did_document().metadata.deactivated == Some(true));
 
+  // An already deactivated DID Document cannot be deactivated again.
+  assert!(identity
+    .deactivate_did(&controller_token)
+    .finish(&identity_client)
+    .await
+    .is_err());
+
+  let document = IotaDocument::new_with_id(identity.did_document().id().clone());
+  identity
+    .reactivate_did(document, &controller_token)
+    .finish(&identity_client)
+    .await?
+    .build_and_execute(&identity_client)
+    .await?;
+
+  assert_eq!(identity.did_document().metadata.deactivated, None);
+
   Ok(())
 }
 
@@ -103,6 +130,54 @@ async fn updating_onchain_identity_did_doc_with_single_controller_works() -> any
   Ok(())
 }
 
+#[tokio::test]
+async fn did_doc_updates_are_recorded_in_provenance_log() -> anyhow::Result<()> {
+  let test_client = get_funded_test_client().await?;
+  let identity_client = test_client.new_user_client().await?;
+
+  let mut did_doc = IotaDocument::new(identity_client.network());
+  did_doc.metadata.enable_provenance();
+  let mut identity = identity_client
+    .create_identity(did_doc)
+    .finish()
+    .with_gas_budget(TEST_GAS_BUDGET)
+    .build_and_execute(&identity_client)
+    .await?
+    .output;
+  assert!(identity.did_document().metadata.history().is_empty());
+
+  let controller_token = identity
+    .get_controller_token(&identity_client)
+    .await?
+    .expect("this address is a controller");
+
+  for _ in 0..2 {
+    let did_doc = identity.did_document().clone();
+    identity
+      .update_did_document(did_doc, &controller_token)
+      .finish(&identity_client)
+      .await?
+      .build_and_execute(&identity_client)
+      .await?;
+  }
+
+  let history = identity.did_document().metadata.history();
+  assert_eq!(history.len(), 2);
+  assert!(history
+    .iter()
+    .all(|entry| entry.controller == controller_token.controller_id() && entry.tx_digest.is_some()));
+
+  // The digest of the latest update is only known once it's applied, and is stored on-chain with the next update.
+  let onchain_identity = get_identity(&identity_client, identity.id())
+    .await?
+    .expect("identity exists on-chain");
+  let onchain_history = onchain_identity.did_document().metadata.history();
+  assert_eq!(onchain_history[0], history[0]);
+  assert!(onchain_history[1].tx_digest.is_none());
+
+  Ok(())
+}
+
 #[tokio::test]
 async fn approving_proposal_works() -> anyhow::Result<()> {
   let test_client = get_funded_test_client().await?;
@@ -160,6 +235,113 @@ async fn approving_proposal_works() -> anyhow::Result<()> {
   Ok(())
 }
 
+#[tokio::test]
+async fn time_locked_proposals_cannot_be_executed_early() -> anyhow::Result<()> {
+  let test_client = get_funded_test_client().await?;
+  let alice_client = test_client.new_user_client().await?;
+
+  let mut identity = alice_client
+    .create_identity(IotaDocument::new(alice_client.network()))
+    .finish()
+    .build_and_execute(&alice_client)
+    .await?
+    .output;
+  let alice_token = identity
+    .get_controller_token(&alice_client)
+    .await?
+    .expect("alice is a controller");
+
+  // Alice has enough voting power, but the proposal is time-locked and therefore not executed right away.
+  let did_doc = identity.did_document().clone();
+  let ProposalResult::Pending(proposal) = identity
+    .update_did_document(did_doc, &alice_token)
+    .with_not_before(u64::MAX)
+    .finish(&alice_client)
+    .await?
+    .build_and_execute(&alice_client)
+    .await?
+    .output
+  else {
+    anyhow::bail!("the time-locked proposal is executed");
+  };
+  assert_eq!(proposal.votes(), 1);
+
+  let res = proposal
+    .into_tx(&mut identity, &alice_token, &alice_client)
+    .await?
+    .build_and_execute(&alice_client)
+    .await;
+  assert!(res.is_err());
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn approving_many_proposals_at_once_works() -> anyhow::Result<()> {
+  let test_client = get_funded_test_client().await?;
+  let alice_client = test_client.new_user_client().await?;
+  let bob_client = test_client.new_user_client().await?;
+
+  let mut identity = alice_client
+    .create_identity(IotaDocument::new(alice_client.network()))
+    .controller(alice_client.sender_address(), 1)
+    .controller(bob_client.sender_address(), 1)
+    .threshold(2)
+    .finish()
+    .build_and_execute(&alice_client)
+    .await?
+    .output;
+  let alice_token = identity
+    .get_controller_token(&alice_client)
+    .await?
+    .expect("alice is a controller");
+  let bob_token = identity
+    .get_controller_token(&bob_client)
+    .await?
+    .expect("bob is a controller");
+
+  let mut proposals = vec![];
+  for _ in 0..2 {
+    let did_doc = identity.did_document().clone();
+    let ProposalResult::Pending(proposal) = identity
+      .update_did_document(did_doc, &alice_token)
+      .finish(&alice_client)
+      .await?
+      .build_and_execute(&alice_client)
+      .await?
+      .output
+    else {
+      anyhow::bail!("the proposal is executed");
+    };
+    proposals.push(proposal);
+  }
+
+  let status = proposals[0].approval_status(&identity);
+  assert_eq!(status.votes, 1);
+  assert_eq!(status.missing_votes(), 1);
+  assert!(!status.is_approved() && status.can_be_approved());
+  assert_eq!(
+    proposals[0].pending_approvers(&identity),
+    vec![bob_token.controller_id()]
+  );
+
+  ApproveProposals::new(&identity, &bob_token)?
+    .with_proposal(&proposals[0])
+    .with_proposal(&proposals[1])
+    .finish()
+    .build_and_execute(&bob_client)
+    .await?;
+
+  for proposal in &proposals {
+    let proposal: Proposal<UpdateDidDocument> = bob_client.get_object_by_id(proposal.id()).await?;
+    let status = proposal.approval_status(&identity);
+    assert!(status.is_approved());
+    assert!(status.pending_approvers.is_empty());
+  }
+
+  Ok(())
+}
+
 #[tokio::test]
 async fn adding_controller_works() -> anyhow::Result<()> {
   let test_client = get_funded_test_client().await?;
@@ -294,6 +476,70 @@ async fn can_get_historical_identity_data() -> anyhow::Result<()> {
   Ok(())
 }
 
+#[tokio::test]
+async fn identity_state_can_be_replayed() -> anyhow::Result<()> {
+  let test_client = get_funded_test_client().await?;
+  let identity_client = test_client.new_user_client().await?;
+
+  let mut identity = identity_client
+    .create_identity(IotaDocument::new(identity_client.network()))
+    .finish()
+    .with_gas_budget(TEST_GAS_BUDGET)
+    .build_and_execute(&identity_client)
+    .await?
+    .output;
+
+  let did = IotaDID::parse(format!("did:iota:{}", identity.id()))?;
+  let updated_did_doc = {
+    let mut doc = IotaDocument::new_with_id(did.clone());
+    let (_, key_id, public_key_jwk, _) = get_key_data().await?;
+    doc.insert_method(
+      VerificationMethod::new_from_jwk(did.clone(), public_key_jwk, Some(key_id.as_str()))?,
+      MethodScope::VerificationMethod,
+    )?;
+    doc
+  };
+
+  let token = identity
+    .get_controller_token(&identity_client)
+    .await?
+    .expect("is a controller");
+  identity
+    .update_did_document(updated_did_doc, &token)
+    .finish(&identity_client)
+    .await?
+    .with_gas_budget(TEST_GAS_BUDGET)
+    .build_and_execute(&identity_client)
+    .await?;
+
+  let replayer = IdentityStateReplayer::new(&identity_client, identity.id());
+  let states = replayer.replay().await?;
+  assert_eq!(states.len(), 2);
+  assert!(states[0].object_version() < states[1].object_version());
+  assert!(states[0].did_document().methods(None).is_empty());
+  assert_eq!(states[1].did_document().methods(None).len(), 1);
+  assert_eq!(states[1].threshold(), 1);
+  assert_eq!(states[1].controllers().len(), 1);
+
+  let first_state = replayer
+    .state_at_version(states[0].object_version())
+    .await?
+    .expect("identity exists at its first version");
+  assert_eq!(first_state.did_document(), states[0].did_document());
+  assert!(replayer
+    .state_at_version(states[0].object_version() - 1)
+    .await?
+    .is_none());
+
+  let current_doc = identity_client.resolve_did(&did).await?;
+  assert_eq!(
+    identity_client.resolve_did_at(&did, Timestamp::now_utc()).await?,
+    current_doc
+  );
+
+  Ok(())
+}
+
 #[tokio::test]
 async fn send_proposal_works() -> anyhow::Result<()> {
   let test_client = get_funded_test_client().await?;
@@ -344,6 +590,45 @@ async fn send_proposal_works() -> anyhow::Result<()> {
   Ok(())
 }
 
+#[tokio::test]
+async fn custom_move_call_works() -> anyhow::Result<()> {
+  let test_client = get_funded_test_client().await?;
+  let identity_client = test_client.new_user_client().await?;
+
+  let mut identity = identity_client
+    .create_identity(IotaDocument::new(identity_client.network()))
+    .finish()
+    .with_gas_budget(TEST_GAS_BUDGET)
+    .build_and_execute(&identity_client)
+    .await?
+    .output;
+  let token = identity
+    .get_controller_token(&identity_client)
+    .await?
+    .expect("is a controller");
+
+  let args = [
+    CustomCallArg::pure(&vec![identity_client.sender_address()])?,
+    CustomCallArg::pure(&vec![1u64])?,
+  ];
+  let ProposalResult::Executed(created_objects) = identity
+    .execute_custom_move_call("utils", "vec_map_from_keys_values", args, &token)
+    .type_arg(TypeTag::Address)
+    .type_arg(TypeTag::U64)
+    .with_output::<Vec<ObjectId>>()
+    .finish(&identity_client)
+    .await?
+    .build_and_execute(&identity_client)
+    .await?
+    .output
+  else {
+    panic!("the controller has enough voting power and the proposal should have been executed");
+  };
+  assert!(created_objects.is_empty());
+
+  Ok(())
+}
+
 #[tokio::test]
 async fn borrow_proposal_works() -> anyhow::Result<()> {
   let test_client = get_funded_test_client().await?;
@@ -391,6 +676,161 @@ async fn borrow_proposal_works() -> anyhow::Result<()> {
   Ok(())
 }
 
+#[tokio::test]
+async fn borrow_proposal_with_borrowed_objects_view_works() -> anyhow::Result<()> {
+  let test_client = get_funded_test_client().await?;
+  let identity_client = test_client.new_user_client().await?;
+
+  let mut identity = identity_client
+    .create_identity(IotaDocument::new(identity_client.network()))
+    .finish()
+    .build_and_execute(&identity_client)
+    .await?
+    .output;
+  let identity_address = identity.id().into();
+
+  let token = identity
+    .get_controller_token(&identity_client)
+    .await?
+    .expect("is a controller");
+
+  let coin = common::get_test_coin(identity_address, &identity_client).await?;
+
+  let ProposalResult::Executed(_) = identity
+    .borrow_assets(&token)
+    .borrow(coin)
+    .with_borrowed_intent(move |ptb, borrowed| {
+      assert_eq!(borrowed.len(), 1);
+      assert!(borrowed.object_data(&coin).is_some());
+      ptb.programmable_move_call(
+        IOTA_FRAMEWORK_PACKAGE_ID,
+        ident_str!("coin").as_str().into(),
+        ident_str!("value").as_str().into(),
+        vec![TypeTag::Bool],
+        vec![borrowed.argument(&coin).expect("coin is borrowed")],
+      );
+    })
+    .finish(&identity_client)
+    .await?
+    .build_and_execute(&identity_client)
+    .await?
+    .output
+  else {
+    panic!("controller has enough voting power and proposal should have been executed");
+  };
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn identity_balances_and_coin_merging_work() -> anyhow::Result<()> {
+  let test_client = get_funded_test_client().await?;
+  let identity_client = test_client.new_user_client().await?;
+
+  let mut identity = identity_client
+    .create_identity(IotaDocument::new(identity_client.network()))
+    .finish()
+    .build_and_execute(&identity_client)
+    .await?
+    .output;
+  let identity_address = identity.id().into();
+
+  let token = identity
+    .get_controller_token(&identity_client)
+    .await?
+    .expect("is a controller");
+
+  let coin1 = common::get_test_coin(identity_address, &identity_client).await?;
+  let coin2 = common::get_test_coin(identity_address, &identity_client).await?;
+
+  let balance = identity.balance(&TypeTag::Bool, &identity_client).await?;
+  assert_eq!(balance.total_balance(), 0);
+  let mut coin_ids = balance.coins().iter().map(|coin| coin.id()).collect::<Vec<_>>();
+  coin_ids.sort();
+  let mut expected_ids = vec![coin1, coin2];
+  expected_ids.sort();
+  assert_eq!(coin_ids, expected_ids);
+
+  // Sending more than what the identity owns must fail before any transaction is built.
+  assert!(identity
+    .send_amount(&balance, 1, identity_client.sender_address(), &token)
+    .is_err());
+
+  let ProposalResult::Executed(_) = identity
+    .merge_coins(&balance, &token)?
+    .finish(&identity_client)
+    .await?
+    .build_and_execute(&identity_client)
+    .await?
+    .output
+  else {
+    panic!("controller has enough voting power and proposal should have been executed");
+  };
+
+  // Borrowed coins are returned to the identity.
+  let balances = identity.balances(&identity_client).await?;
+  assert_eq!(balances.len(), 1);
+  assert_eq!(balances[0].coins().len(), 2);
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn identity_owned_objects_can_be_listed_and_sent() -> anyhow::Result<()> {
+  let test_client = get_funded_test_client().await?;
+  let identity_client = test_client.new_user_client().await?;
+
+  let mut identity = identity_client
+    .create_identity(IotaDocument::new(identity_client.network()))
+    .finish()
+    .build_and_execute(&identity_client)
+    .await?
+    .output;
+  let identity_address = identity.id().into();
+
+  let token = identity
+    .get_controller_token(&identity_client)
+    .await?
+    .expect("is a controller");
+
+  let coin = common::get_test_coin(identity_address, &identity_client).await?;
+
+  let owned_objects = identity.owned_objects(&identity_client).await?;
+  let owned_coin = owned_objects
+    .iter()
+    .find(|obj| obj.id() == coin)
+    .expect("identity owns the test coin");
+  assert_eq!(
+    owned_coin.kind(),
+    &OwnedObjectKind::Coin {
+      coin_type: TypeTag::Bool
+    }
+  );
+
+  let ProposalResult::Executed(_) = identity
+    .send_asset(coin, identity_client.sender_address(), &token)
+    .finish(&identity_client)
+    .await?
+    .build_and_execute(&identity_client)
+    .await?
+    .output
+  else {
+    panic!("controller has enough voting power and proposal should have been executed");
+  };
+
+  identity_client
+    .find_owned_ref(TEST_COIN_TYPE.clone(), |obj| obj.object_id == coin)
+    .await?
+    .expect("coin was transferred to this address");
+  assert!(identity
+    .owned_objects(&identity_client)
+    .await?
+    .iter()
+    .all(|obj| obj.id() != coin));
+
+  Ok(())
+}
+
 #[allow(deprecated)]
 #[tokio::test]
 async fn controller_execution_works() -> anyhow::Result<()> {
@@ -467,6 +907,100 @@ async fn controller_execution_works() -> anyhow::Result<()> {
   Ok(())
 }
 
+#[allow(deprecated)]
+#[tokio::test]
+async fn controller_execution_with_lent_cap_works() -> anyhow::Result<()> {
+  let test_client = get_funded_test_client().await?;
+  let identity_client = test_client.new_user_client().await?;
+
+  let mut identity = identity_client
+    .create_identity(IotaDocument::new(identity_client.network()))
+    .finish()
+    .build_and_execute(&identity_client)
+    .await?
+    .output;
+  let identity_address = identity.id().into();
+
+  // Create a second identity owned by the first.
+  let identity2 = identity_client
+    .create_identity(IotaDocument::new(identity_client.network()))
+    .controller(identity_address, 1)
+    .threshold(1)
+    .finish()
+    .build_and_execute(&identity_client)
+    .await?
+    .output;
+
+  // Let's find identity's controller cap for identity2.
+  let controller_cap = identity_client
+    .find_owned_ref_for_address(
+      identity_address,
+      format!("{}::controller::ControllerCap", identity_client.package_id()).parse()?,
+      |_| true,
+    )
+    .await?
+    .expect("identity is a controller of identity2");
+
+  let identity2_ref = identity_client.get_object_ref_by_id(identity2.id()).await?.unwrap();
+  let Owner::Shared(initial_shared_version) = identity2_ref.owner else {
+    panic!("identity2 is shared")
+  };
+
+  let token = identity
+    .get_controller_token(&identity_client)
+    .await?
+    .expect("is a controller");
+  // Perform an action on `identity2` as a controller of `identity`.
+  let result = identity
+    .controller_execution(controller_cap.object_id, &token)
+    .with_lent_cap(|cap| {
+      let identity2 = cap
+        .ptb()
+        .obj(CallArg::Shared(SharedObjectRef {
+          object_id: identity2_ref.object_id(),
+          initial_shared_version,
+          mutable: true,
+        }))
+        .unwrap();
+
+      let token_to_revoke = cap.ptb().pure(ObjectId::ZERO).unwrap();
+
+      cap.move_call(
+        identity_client.package_id(),
+        "identity",
+        "revoke_token",
+        vec![],
+        [identity2.into(), LentCapArg::ControllerCap, token_to_revoke.into()],
+      );
+    })
+    .finish(&identity_client)
+    .await?
+    .build_and_execute(&identity_client)
+    .await?;
+
+  assert!(result.response.status_ok().unwrap());
+  assert!(matches!(result.output, ProposalResult::Executed(_)));
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn new_identity_does_not_need_migration() -> anyhow::Result<()> {
+  let client = get_funded_test_client().await?;
+  let identity = client
+    .create_identity(IotaDocument::new(client.network()))
+    .finish()
+    .build_and_execute(&client)
+    .await?
+    .output;
+  let token = identity.get_controller_token(&client).await?.expect("is a controller");
+
+  assert!(!identity.needs_migration(&client).await?);
+  assert!(MigrateIdentity::new(&identity, &token).is_err());
+
+  Ok(())
+}
+
 #[tokio::test]
 async fn identity_delete_did_works() -> anyhow::Result<()> {
   let client = get_funded_test_client().await?;
@@ -658,3 +1192,40 @@ async fn access_sub_identity_works() -> anyhow::Result<()> {
 
   Ok(())
 }
+
+#[tokio::test]
+async fn controller_hierarchy_can_be_traversed() -> anyhow::Result<()> {
+  let client = TestClient::new().await?;
+  let network = client.network();
+  let org = client
+    .create_identity(IotaDocument::new(network))
+    .finish()
+    .build_and_execute(&client)
+    .await?
+    .output;
+  let department = client
+    .create_identity(IotaDocument::new(network))
+    .controller(org.id().into(), 1)
+    .finish()
+    .build_and_execute(&client)
+    .await?
+    .output;
+
+  assert_eq!(
+    org.controlled_identities(&client).await?,
+    vec![department.did_document().id().clone()]
+  );
+
+  let chain = client.controller_chain(department.did_document().id()).await?;
+  assert!(!chain.has_cycle());
+  let department_controllers: Vec<_> = chain.controllers_of(department.did_document().id()).collect();
+  assert_eq!(department_controllers.len(), 1);
+  assert_eq!(
+    department_controllers[0].holder_identity.as_ref(),
+    Some(org.did_document().id())
+  );
+  // The organization's own controller is part of the chain.
+  assert_eq!(chain.controllers_of(org.did_document().id()).count(), 1);
+
+  Ok(())
+}
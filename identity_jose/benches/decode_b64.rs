@@ -0,0 +1,41 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+// This is a benchmark measuring the time it takes to decode the base64url-encoded segments of JWTs.
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::BenchmarkId;
+use criterion::Criterion;
+use criterion::Throughput;
+use identity_core::convert::Base;
+use identity_core::convert::BaseEncoding;
+use identity_jose::jwu::decode_b64;
+use identity_jose::jwu::decode_b64_into;
+use identity_jose::jwu::encode_b64;
+
+fn decode_base64url(c: &mut Criterion) {
+  let mut group = c.benchmark_group("decode_base64url");
+  // Roughly the sizes of a signature, a protected header and the payloads of a small and a large credential.
+  for size in [64, 256, 2048, 32768] {
+    let data: Vec<u8> = (0..size).map(|i| i as u8).collect();
+    let encoded: String = encode_b64(&data);
+    group.throughput(Throughput::Bytes(encoded.len() as u64));
+    group.bench_with_input(BenchmarkId::new("from string", size), &encoded, |b, encoded| {
+      b.iter(|| {
+        let string: &str = std::str::from_utf8(encoded.as_bytes()).unwrap();
+        BaseEncoding::decode(string, Base::Base64Url).unwrap()
+      })
+    });
+    group.bench_with_input(BenchmarkId::new("from bytes", size), &encoded, |b, encoded| {
+      b.iter(|| decode_b64(encoded).unwrap())
+    });
+    group.bench_with_input(BenchmarkId::new("into reused buffer", size), &encoded, |b, encoded| {
+      let mut output: Vec<u8> = Vec::new();
+      b.iter(|| decode_b64_into(encoded, &mut output).unwrap())
+    });
+  }
+  group.finish();
+}
+
+criterion_group!(benches, decode_base64url);
+criterion_main!(benches);
@@ -56,3 +56,21 @@ pub enum Error {
   #[error("key type not supported; {0}")]
   UnsupportedKeyType(String),
 }
+
+identity_core::impl_error_code!(Error {
+  InvalidJson => 1200,
+  InvalidBase64 => 1201,
+  InvalidUtf8 => 1202,
+  InvalidClaim => 1203,
+  MissingClaim => 1204,
+  InvalidParam => 1205,
+  MissingParam => 1206,
+  InvalidContent => 1207,
+  KeyError => 1208,
+  JwsAlgorithmParsingError => 1209,
+  SignatureVerificationError => 1210,
+  MissingHeader => 1211,
+  ProtectedHeaderWithoutAlg => 1212,
+  KeyConversion => 1213,
+  UnsupportedKeyType => 1214,
+});
@@ -20,6 +20,7 @@ use crate::jwk::JwkParamsOkp;
 use crate::jwk::JwkParamsRsa;
 use crate::jwk::JwkType;
 use crate::jwk::JwkUse;
+use crate::jwu::ct_eq;
 use crate::jwu::encode_b64;
 
 /// A SHA256 JSON Web Key Thumbprint.
@@ -185,6 +186,13 @@ impl Jwk {
     self.kid.as_deref()
   }
 
+  /// Returns `true` if the key ID property (kid) is set and equal to `kid`.
+  ///
+  /// The comparison is performed in constant time.
+  pub fn kid_eq(&self, kid: &str) -> bool {
+    self.kid().is_some_and(|value| ct_eq(value, kid))
+  }
+
   /// Sets a value for the key ID property (kid).
   pub fn set_kid(&mut self, value: impl Into<String>) {
     self.kid = Some(value.into());
@@ -387,6 +395,21 @@ impl Jwk {
     out
   }
 
+  /// Returns `true` if the SHA2-256 thumbprint of this key equals `thumbprint`.
+  ///
+  /// The comparison is performed in constant time.
+  pub fn matches_thumbprint(&self, thumbprint: impl AsRef<[u8]>) -> bool {
+    ct_eq(self.thumbprint_sha256(), thumbprint)
+  }
+
+  /// Returns `true` if this key and `other` have the same SHA2-256 thumbprint, i.e. if they represent the same key
+  /// material according to [RFC7638](https://tools.ietf.org/html/rfc7638).
+  ///
+  /// The comparison is performed in constant time.
+  pub fn thumbprint_eq(&self, other: &Jwk) -> bool {
+    self.matches_thumbprint(other.thumbprint_sha256())
+  }
+
   /// Creates the JSON string of the JSON Web Key according to [RFC7638](https://tools.ietf.org/html/rfc7638),
   /// which is used as the input for the JWK thumbprint hashing procedure.
   /// This can be used as input for a custom hash function.
@@ -544,4 +567,38 @@ mod tests {
 
     assert_eq!(priv_jwk.to_public(), priv_jwk.into_public());
   }
+
+  #[test]
+  fn thumbprint_and_kid_comparisons() {
+    let mut jwk = Jwk::from_json_slice(
+      r#"
+      {
+        "kty": "OKP",
+        "crv": "Ed25519",
+        "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"
+      }
+    "#,
+    )
+    .unwrap();
+    let other = Jwk::from_json_slice(
+      r#"
+      {
+        "kty": "OKP",
+        "crv": "Ed25519",
+        "x": "VCpo2LMLhn6iWku8MKvSLg2ZAoC-nlOyPVQaO3FxVeQ"
+      }
+    "#,
+    )
+    .unwrap();
+
+    assert!(!jwk.kid_eq(""));
+    jwk.set_kid("key-1");
+    assert!(jwk.kid_eq("key-1"));
+    assert!(!jwk.kid_eq("key-2"));
+
+    assert!(jwk.thumbprint_eq(&jwk.clone()));
+    assert!(!jwk.thumbprint_eq(&other));
+    assert!(jwk.matches_thumbprint(jwk.thumbprint_sha256()));
+    assert!(!jwk.matches_thumbprint(&jwk.thumbprint_sha256()[..16]));
+  }
 }
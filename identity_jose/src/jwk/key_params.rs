@@ -377,6 +377,72 @@ impl JwkParamsRsa {
     self.strip_private();
     self
   }
+
+  /// The minimum size in bits of the modulus `n` accepted by [`JwkParamsRsa::validate`].
+  ///
+  /// [More Info](https://tools.ietf.org/html/rfc7518#section-3.3)
+  pub const MIN_MODULUS_BITS: usize = 2048;
+
+  /// Returns the size in bits of the modulus `n`.
+  pub fn modulus_bits(&self) -> Result<usize> {
+    let n: Vec<u8> = decode_uint(&self.n, "n")?;
+    Ok(n.len() * 8 - n[0].leading_zeros() as usize)
+  }
+
+  /// Checks that the parameters describe a well-formed RSA key:
+  /// - `n` and `e` are base64urlUInt-encoded values,
+  /// - the modulus is at least [`JwkParamsRsa::MIN_MODULUS_BITS`] long,
+  /// - the public exponent is an odd number greater than 1,
+  /// - the private key components are either all unset, limited to `d`, or all of `d`, `p`, `q`, `dp`, `dq` and `qi`
+  ///   are set, and are base64urlUInt-encoded values.
+  pub fn validate(&self) -> Result<()> {
+    if self.modulus_bits()? < Self::MIN_MODULUS_BITS {
+      return Err(Error::InvalidParam("RSA modulus `n` is shorter than 2048 bits"));
+    }
+
+    let e: Vec<u8> = decode_uint(&self.e, "e")?;
+    if e.last().is_some_and(|byte| byte % 2 == 0) || e == [1] {
+      return Err(Error::InvalidParam(
+        "RSA public exponent `e` must be an odd number greater than 1",
+      ));
+    }
+
+    let crt_params = [&self.p, &self.q, &self.dp, &self.dq, &self.qi];
+    let only_d: bool = crt_params.iter().all(|param| param.is_none()) && self.oth.is_none();
+    if !self.is_public() && !self.is_private() && !(self.d.is_some() && only_d) {
+      return Err(Error::InvalidParam(
+        "RSA private key components must either all be set or be limited to `d`",
+      ));
+    }
+
+    let private_params = [
+      (&self.d, "d"),
+      (&self.p, "p"),
+      (&self.q, "q"),
+      (&self.dp, "dp"),
+      (&self.dq, "dq"),
+      (&self.qi, "qi"),
+    ];
+    for (param, name) in private_params {
+      if let Some(value) = param {
+        decode_uint(value, name)?;
+      }
+    }
+
+    Ok(())
+  }
+}
+
+/// Decodes the base64urlUInt-encoded parameter `name`, which must be non-empty and use the minimum number of octets.
+///
+/// [More Info](https://tools.ietf.org/html/rfc7518#section-2)
+fn decode_uint(value: &str, name: &'static str) -> Result<Vec<u8>> {
+  let bytes: Vec<u8> = crate::jwu::decode_b64(value)?;
+  match bytes.first() {
+    None => Err(Error::MissingParam(name)),
+    Some(0) if bytes.len() > 1 => Err(Error::InvalidParam("base64urlUInt value with leading zero octets")),
+    Some(_) => Ok(bytes),
+  }
 }
 
 impl From<JwkParamsRsa> for JwkParams {
@@ -48,12 +48,10 @@ impl JwkSet {
   }
 
   /// Returns a list of keys matching the given `kid`.
+  ///
+  /// Every key in the set is compared against `kid` in constant time.
   pub fn get(&self, kid: &str) -> Vec<&Jwk> {
-    self
-      .keys
-      .iter()
-      .filter(|key| matches!(key.kid(), Some(value) if value == kid))
-      .collect()
+    self.keys.iter().filter(|key| key.kid_eq(kid)).collect()
   }
 
   /// Adds a new `key` to the set.
@@ -18,6 +18,45 @@ pub struct VerificationInput {
   pub decoded_signature: Box<[u8]>,
 }
 
+impl VerificationInput {
+  /// Returns a [`BorrowedVerificationInput`] referencing the data of this input.
+  #[allow(clippy::clone_on_copy)]
+  pub fn as_borrowed(&self) -> BorrowedVerificationInput<'_> {
+    BorrowedVerificationInput {
+      alg: self.alg.clone(),
+      signing_input: &self.signing_input,
+      decoded_signature: &self.decoded_signature,
+    }
+  }
+}
+
+/// Input a [`JwsVerifier`] verifies through [`JwsVerifier::verify_borrowed`], borrowing the signing input and
+/// signature from the decoded JWS.
+#[derive(Debug, Clone)]
+pub struct BorrowedVerificationInput<'a> {
+  /// The `alg` parsed from the protected header.
+  pub alg: JwsAlgorithm,
+  /// The signing input.
+  ///
+  /// See [RFC 7515: section 5.2 part 8.](https://www.rfc-editor.org/rfc/rfc7515#section-5.2) and
+  /// [RFC 7797 section 3](https://www.rfc-editor.org/rfc/rfc7797#section-3).
+  pub signing_input: &'a [u8],
+  /// The decoded signature to validate the `signing_input` against in the manner defined by the `alg` field.
+  pub decoded_signature: &'a [u8],
+}
+
+impl BorrowedVerificationInput<'_> {
+  /// Copies the referenced data into an owned [`VerificationInput`].
+  #[allow(clippy::clone_on_copy)]
+  pub fn to_owned_input(&self) -> VerificationInput {
+    VerificationInput {
+      alg: self.alg.clone(),
+      signing_input: self.signing_input.into(),
+      decoded_signature: self.decoded_signature.into(),
+    }
+  }
+}
+
 /// Trait for cryptographically verifying a JWS signature.
 ///
 /// Any type implementing this trait can be passed to
@@ -30,7 +69,8 @@ pub struct VerificationInput {
 ///
 /// Implementers are expected to provide a procedure for step 8 of
 /// [RFC 7515 section 5.2](https://www.rfc-editor.org/rfc/rfc7515#section-5.2) for
-/// the JWS signature algorithms they want to support.
+/// the JWS signature algorithms they want to support. Implementations of MAC-based algorithms such as `HS256` should
+/// compare MACs with [`ct_eq`](crate::jwu::ct_eq) to avoid leaking timing information.
 ///
 /// Custom implementations can be constructed inline by converting a suitable closure into a [`JwsVerifierFn`]
 /// using the [`From`] trait.
@@ -48,12 +88,34 @@ pub trait JwsVerifier {
   /// [`SignatureVerificationErrorKind::UnsupportedAlg`](crate::jws::SignatureVerificationErrorKind::UnsupportedAlg) if
   /// they are not interested in supporting a given algorithm.
   fn verify(&self, input: VerificationInput, public_key: &Jwk) -> Result<(), SignatureVerificationError>;
+
+  /// Validate the `decoded_signature` against the `signing_input` like [`JwsVerifier::verify`], without taking
+  /// ownership of them.
+  ///
+  /// This is the method called by [`JwsValidationItem::verify`](crate::jws::JwsValidationItem::verify). The default
+  /// implementation copies `input` into a [`VerificationInput`] and calls [`JwsVerifier::verify`]: implementors
+  /// should override it to verify signatures without allocating.
+  fn verify_borrowed(
+    &self,
+    input: BorrowedVerificationInput<'_>,
+    public_key: &Jwk,
+  ) -> Result<(), SignatureVerificationError> {
+    self.verify(input.to_owned_input(), public_key)
+  }
 }
 
 impl JwsVerifier for Box<dyn JwsVerifier> {
   fn verify(&self, input: VerificationInput, public_key: &Jwk) -> Result<(), SignatureVerificationError> {
     <dyn JwsVerifier>::verify(self, input, public_key)
   }
+
+  fn verify_borrowed(
+    &self,
+    input: BorrowedVerificationInput<'_>,
+    public_key: &Jwk,
+  ) -> Result<(), SignatureVerificationError> {
+    <dyn JwsVerifier>::verify_borrowed(self, input, public_key)
+  }
 }
 
 // =================================================================================================================
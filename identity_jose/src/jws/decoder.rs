@@ -19,6 +19,7 @@ use crate::jwu::filter_non_empty_bytes;
 use crate::jwu::parse_utf8;
 use crate::jwu::validate_jws_headers;
 
+use super::BorrowedVerificationInput;
 use super::JwsVerifier;
 use super::VerificationInput;
 
@@ -33,9 +34,23 @@ pub struct DecodedJws<'a> {
   /// The decoded unprotected header.
   pub unprotected: Option<Box<JwsHeader>>,
   /// The decoded raw claims.
+  ///
+  /// Borrowed from the input when the payload is not base64url-encoded, see
+  /// [RFC 7797](https://www.rfc-editor.org/rfc/rfc7797).
   pub claims: Cow<'a, [u8]>,
 }
 
+impl DecodedJws<'_> {
+  /// Converts this [`DecodedJws`] into one that does not borrow from the decoded JWS.
+  pub fn into_owned(self) -> DecodedJws<'static> {
+    DecodedJws {
+      protected: self.protected,
+      unprotected: self.unprotected,
+      claims: Cow::Owned(self.claims.into_owned()),
+    }
+  }
+}
+
 enum DecodedHeaders {
   Protected(JwsHeader),
   Unprotected(JwsHeader),
@@ -81,7 +96,7 @@ impl DecodedHeaders {
 /// can be cryptographically verified using a [`JwsVerifier`]. See [`Self::verify`](Self::verify).
 pub struct JwsValidationItem<'a> {
   headers: DecodedHeaders,
-  signing_input: Box<[u8]>,
+  signing_input: Cow<'a, [u8]>,
   decoded_signature: Box<[u8]>,
   claims: Cow<'a, [u8]>,
 }
@@ -129,8 +144,11 @@ impl<'a> JwsValidationItem<'a> {
     &self.decoded_signature
   }
 
-  /// Constructs [`VerificationInput`] from this data and passes it to the given `verifier` along with the
-  /// provided `public_key`.
+  /// Constructs a [`BorrowedVerificationInput`] from this data and passes it to
+  /// [`JwsVerifier::verify_borrowed`] of the given `verifier` along with the provided `public_key`.
+  ///
+  /// The signing input and signature are not copied, so verifiers overriding
+  /// [`JwsVerifier::verify_borrowed`] verify the JWS without allocating.
   ///
   /// # Errors
   /// Apart from the fallible call to [`JwsVerifier::verify`] this method can also error if there is no
@@ -162,14 +180,14 @@ impl<'a> JwsValidationItem<'a> {
     public_key.check_alg(alg.name())?;
 
     // Construct verification input
-    let input = VerificationInput {
+    let input = BorrowedVerificationInput {
       alg,
-      signing_input,
-      decoded_signature,
+      signing_input: &signing_input,
+      decoded_signature: &decoded_signature,
     };
     // Call verifier
     verifier
-      .verify(input, public_key)
+      .verify_borrowed(input, public_key)
       .map_err(Error::SignatureVerificationError)?;
 
     Ok(DecodedJws {
@@ -225,7 +243,7 @@ impl<'a> JwsValidationItem<'a> {
     input.push(0x00);
 
     //M
-    input.extend(signing_input);
+    input.extend_from_slice(&signing_input);
 
     traditional_pk.check_alg(JwsAlgorithm::EdDSA.name())?;
 
@@ -294,6 +312,9 @@ struct Flatten<'a> {
 // =============================================================================
 
 /// The [`Decoder`] is responsible for decoding a JWS into one or more [`JwsValidationItems`](JwsValidationItem).
+///
+/// Decoding borrows from the input wherever possible: the signing input of a compact JWS with an attached payload
+/// is never re-assembled and the claims of a JWS with an unencoded payload are never copied.
 #[derive(Debug, Clone)]
 pub struct Decoder;
 
@@ -329,9 +350,13 @@ impl Decoder {
       signature: parse_utf8(signature)?,
     };
 
+    // With an attached payload the signing input is a prefix of the token and can be borrowed as is.
+    let signing_input: Option<&'b [u8]> = detached_payload
+      .is_none()
+      .then(|| &jws_bytes[..protected.len() + 1 + payload.len()]);
     let payload = Self::expand_payload(detached_payload, Some(payload))?;
 
-    self.decode_signature(payload, signature)
+    self.decode_signature(payload, signing_input, signature)
   }
 
   /// Decode a JWS encoded with the [flattened JWS JSON serialization format](https://www.rfc-editor.org/rfc/rfc7515#section-7.2.2).
@@ -348,12 +373,13 @@ impl Decoder {
     let data: Flatten<'_> = serde_json::from_slice(jws_bytes).map_err(Error::InvalidJson)?;
     let payload = Self::expand_payload(detached_payload, data.payload)?;
     let signature = data.signature;
-    self.decode_signature(payload, signature)
+    self.decode_signature(payload, None, signature)
   }
 
   fn decode_signature<'a, 'b>(
     &self,
     payload: &'b [u8],
+    signing_input: Option<&'b [u8]>,
     jws_signature: JwsSignature<'a>,
   ) -> Result<JwsValidationItem<'b>> {
     let JwsSignature {
@@ -365,8 +391,13 @@ impl Decoder {
     let protected_header: Option<JwsHeader> = protected.map(decode_b64_json).transpose()?;
     validate_jws_headers(protected_header.as_ref(), unprotected_header.as_ref())?;
 
-    let protected_bytes: &[u8] = protected.map(str::as_bytes).unwrap_or_default();
-    let signing_input: Box<[u8]> = create_message(protected_bytes, payload).into();
+    let signing_input: Cow<'b, [u8]> = match signing_input {
+      Some(signing_input) => Cow::Borrowed(signing_input),
+      None => Cow::Owned(create_message(
+        protected.map(str::as_bytes).unwrap_or_default(),
+        payload,
+      )),
+    };
     let decoded_signature: Box<[u8]> = decode_b64(signature)?.into();
 
     let claims: Cow<'b, [u8]> = if protected_header.as_ref().and_then(|value| value.b64()).unwrap_or(true) {
@@ -415,7 +446,7 @@ impl<'payload> Iterator for JwsValidationIter<'_, 'payload, '_> {
     self
       .signatures
       .next()
-      .map(|signature| self.decoder.decode_signature(self.payload, signature))
+      .map(|signature| self.decoder.decode_signature(self.payload, None, signature))
   }
 }
 
@@ -452,6 +483,9 @@ impl Default for Decoder {
 
 #[cfg(test)]
 mod tests {
+  use crate::jwk::JwkType;
+  use crate::jws::JwsVerifierFn;
+  use crate::jws::SignatureVerificationError;
   use crate::jwt::JwtClaims;
 
   use super::*;
@@ -542,6 +576,89 @@ mod tests {
     );
   }
 
+  #[test]
+  fn compact_decoding_borrows_from_input() {
+    // https://www.rfc-editor.org/rfc/rfc7515#appendix-A.3
+    let jws: &str = concat!(
+      "eyJhbGciOiJFUzI1NiJ9",
+      ".eyJpc3MiOiJqb2UiLA0KICJleHAiOjEzMDA4MTkzODAsDQogImh0dHA6Ly9leGFtcGxlLmNvbS9pc19yb290Ijp0cnVlfQ",
+      ".DtEhU3ljbEg8L38VWAfUAqOyKAM6-Xx-F4GawxaepmXFCgfTjDxw5djxLa8ISlSApmWQxfKTUJqPP3-Kg6NU1Q"
+    );
+    let decoded = Decoder::new()
+      .decode_compact_serialization(jws.as_bytes(), None)
+      .unwrap();
+    assert!(matches!(decoded.signing_input, Cow::Borrowed(_)));
+    assert_eq!(decoded.signing_input(), SIGNING_INPUT_ES256_RFC_7515_APPENDIX_EXAMPLE);
+  }
+
+  #[test]
+  fn verification_does_not_copy_the_signing_input() {
+    struct BorrowingVerifier<'a>(&'a [u8]);
+
+    impl JwsVerifier for BorrowingVerifier<'_> {
+      fn verify(
+        &self,
+        _input: VerificationInput,
+        _public_key: &Jwk,
+      ) -> std::result::Result<(), SignatureVerificationError> {
+        panic!("the verification input was copied");
+      }
+
+      fn verify_borrowed(
+        &self,
+        input: BorrowedVerificationInput<'_>,
+        _public_key: &Jwk,
+      ) -> std::result::Result<(), SignatureVerificationError> {
+        // The signing input points into the token itself.
+        assert_eq!(input.signing_input.as_ptr(), self.0.as_ptr());
+        assert_eq!(input.signing_input, SIGNING_INPUT_ES256_RFC_7515_APPENDIX_EXAMPLE);
+        Ok(())
+      }
+    }
+
+    // https://www.rfc-editor.org/rfc/rfc7515#appendix-A.3
+    let jws: &str = concat!(
+      "eyJhbGciOiJFUzI1NiJ9",
+      ".eyJpc3MiOiJqb2UiLA0KICJleHAiOjEzMDA4MTkzODAsDQogImh0dHA6Ly9leGFtcGxlLmNvbS9pc19yb290Ijp0cnVlfQ",
+      ".DtEhU3ljbEg8L38VWAfUAqOyKAM6-Xx-F4GawxaepmXFCgfTjDxw5djxLa8ISlSApmWQxfKTUJqPP3-Kg6NU1Q"
+    );
+    let public_key = Jwk::new(JwkType::Ec);
+
+    let decoded = Decoder::new()
+      .decode_compact_serialization(jws.as_bytes(), None)
+      .unwrap();
+    decoded.verify(&BorrowingVerifier(jws.as_bytes()), &public_key).unwrap();
+
+    // Verifiers that only implement `verify` receive a copy of the input.
+    let owning_verifier = JwsVerifierFn::from(|input: VerificationInput, _: &Jwk| {
+      assert_eq!(&*input.signing_input, SIGNING_INPUT_ES256_RFC_7515_APPENDIX_EXAMPLE);
+      Ok(())
+    });
+    Decoder::new()
+      .decode_compact_serialization(jws.as_bytes(), None)
+      .unwrap()
+      .verify(&owning_verifier, &public_key)
+      .unwrap();
+  }
+
+  #[test]
+  fn unencoded_detached_payload_is_borrowed() {
+    // https://www.rfc-editor.org/rfc/rfc7797#section-4.2
+    let jws: &str =
+      "eyJhbGciOiJIUzI1NiIsImI2NCI6ZmFsc2UsImNyaXQiOlsiYjY0Il19..A5dxf2s96_n5FLueVuW1Z_vh161FwXZC4YLPff6dmDY";
+    let payload: &[u8] = b"$.02";
+
+    let decoded = Decoder::new()
+      .decode_compact_serialization(jws.as_bytes(), Some(payload))
+      .unwrap();
+    assert!(matches!(decoded.claims, Cow::Borrowed(_)));
+    assert_eq!(decoded.claims(), payload);
+    assert_eq!(
+      decoded.signing_input(),
+      b"eyJhbGciOiJIUzI1NiIsImI2NCI6ZmFsc2UsImNyaXQiOlsiYjY0Il19.$.02"
+    );
+  }
+
   // Test https://www.rfc-editor.org/rfc/rfc7515#appendix-A.7
   #[test]
   fn rfc7515_appendix_a_7() {
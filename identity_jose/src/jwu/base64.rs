@@ -16,9 +16,21 @@ pub fn encode_b64(data: impl AsRef<[u8]>) -> String {
 
 /// Decode the given url-safe, unpadded base64-encoded slice into its raw bytes.
 pub fn decode_b64(data: impl AsRef<[u8]>) -> Result<Vec<u8>> {
-  std::str::from_utf8(data.as_ref())
-    .map_err(Error::InvalidUtf8)
-    .and_then(|string| BaseEncoding::decode(string, Base::Base64Url).map_err(Error::InvalidBase64))
+  let mut output: Vec<u8> = Vec::new();
+  decode_b64_into(data, &mut output)?;
+  Ok(output)
+}
+
+/// Decode the given url-safe, unpadded base64-encoded slice into `output`, replacing its content.
+///
+/// Reusing `output` across calls avoids allocating a new buffer for every decoded slice.
+pub fn decode_b64_into(data: impl AsRef<[u8]>, output: &mut Vec<u8>) -> Result<()> {
+  let data: &[u8] = data.as_ref();
+  // UTF-8 is only checked on failure, to report the same errors as decoding from a string.
+  BaseEncoding::decode_base64url_into(data, output).map_err(|err| match std::str::from_utf8(data) {
+    Err(utf8) => Error::InvalidUtf8(utf8),
+    Ok(_) => Error::InvalidBase64(err),
+  })
 }
 
 /// Serialize the given data into JSON and encode the result in url-safe base64.
@@ -45,4 +57,20 @@ mod tests {
   fn smoke() {
     assert!(decode_b64(encode_b64(b"libjose")).is_ok());
   }
+
+  #[test]
+  fn decode_into_reuses_buffer() {
+    let mut output: Vec<u8> = b"previous content".to_vec();
+    decode_b64_into(encode_b64(b"libjose"), &mut output).unwrap();
+    assert_eq!(output, b"libjose");
+    decode_b64_into("", &mut output).unwrap();
+    assert!(output.is_empty());
+  }
+
+  #[test]
+  fn decode_errors() {
+    assert!(matches!(decode_b64(b"bGli\xffam9zZQ"), Err(Error::InvalidUtf8(_))));
+    assert!(matches!(decode_b64("bGlia+9zZQ"), Err(Error::InvalidBase64(_))));
+    assert!(matches!(decode_b64("bGlham9zZQ=="), Err(Error::InvalidBase64(_))));
+  }
 }
@@ -0,0 +1,45 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Constant-time comparison helpers.
+//!
+//! Comparisons involving secret or attacker-influenced values on verification paths are routed through
+//! [`ct_eq`], which is backed by [`subtle`]. The running time of [`ct_eq`] depends only on the *lengths* of its
+//! inputs, never on their contents or on the position of the first differing byte.
+//!
+//! The following comparisons performed by this crate (and by the IOTA Identity crates built on top of it) are
+//! timing-safe:
+//! - JWK thumbprint comparisons through [`Jwk::thumbprint_eq`](crate::jwk::Jwk::thumbprint_eq) and
+//!   [`Jwk::matches_thumbprint`](crate::jwk::Jwk::matches_thumbprint).
+//! - `kid` matching through [`Jwk::kid_eq`](crate::jwk::Jwk::kid_eq) and [`JwkSet::get`](crate::jwk::JwkSet::get).
+//!
+//! This crate does not verify `HS256`/`HS384`/`HS512` signatures itself: MACs are compared by the
+//! [`JwsVerifier`](crate::jws::JwsVerifier) supplied by the caller. Implementations of it supporting these algorithms
+//! must compare the computed MAC with the decoded signature through [`ct_eq`] to be timing-safe.
+//!
+//! Lengths are not hidden: MACs, digests and thumbprints have a fixed, public length for a given algorithm, and key
+//! identifiers are public values whose length carries no secret information.
+
+use subtle::ConstantTimeEq;
+
+/// Compares `a` and `b` for equality in constant time.
+///
+/// The time taken depends only on the lengths of `a` and `b`, never on their contents. Inputs of differing lengths
+/// are never equal.
+pub fn ct_eq(a: impl AsRef<[u8]>, b: impl AsRef<[u8]>) -> bool {
+  a.as_ref().ct_eq(b.as_ref()).into()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn ct_eq_works() {
+    assert!(ct_eq(b"", b""));
+    assert!(ct_eq(b"thumbprint", b"thumbprint"));
+    assert!(!ct_eq(b"thumbprint", b"thumbprinT"));
+    assert!(!ct_eq(b"thumbprint", b"thumb"));
+    assert!(!ct_eq(b"", b"x"));
+  }
+}
@@ -4,7 +4,9 @@
 //! JSON Web Utilities.
 
 mod base64;
+mod constant_time;
 mod serde;
 
 pub use self::base64::*;
+pub use self::constant_time::*;
 pub(crate) use self::serde::*;
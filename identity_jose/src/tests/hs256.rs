@@ -9,6 +9,7 @@ use crate::jws::SignatureVerificationError;
 use crate::jws::SignatureVerificationErrorKind;
 use crate::jws::VerificationInput;
 use crate::jwu;
+use crate::jwu::ct_eq;
 use crypto::hashes::sha::SHA256_LEN;
 
 pub(crate) fn expand_hmac_jwk(jwk: &Jwk, key_len: usize) -> Vec<u8> {
@@ -35,7 +36,7 @@ pub(crate) fn verify(verification_input: VerificationInput, jwk: &Jwk) -> Result
   let mut mac: [u8; SHA256_LEN] = Default::default();
   crypto::macs::hmac::HMAC_SHA256(&verification_input.signing_input, &shared_secret, &mut mac);
 
-  if verification_input.decoded_signature.deref() == mac {
+  if ct_eq(verification_input.decoded_signature.deref(), mac) {
     Ok(())
   } else {
     Err(SignatureVerificationErrorKind::InvalidSignature.into())
@@ -2,7 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::jwk::Jwk;
+use crate::jwk::JwkParamsRsa;
 use crate::jwk::JwkSet;
+use crate::jwu;
 use serde_json::Value;
 
 #[test]
@@ -35,3 +37,43 @@ fn test_rfc7517() {
     }
   }
 }
+
+#[test]
+fn test_rfc7517_rsa_params() {
+  enum TestVector {
+    KeySet { json: &'static str },
+    Key { json: &'static str },
+  }
+
+  static TVS: &[TestVector] = &include!("fixtures/rfc7517.rs");
+
+  let keys: Vec<Jwk> = TVS
+    .iter()
+    .flat_map(|tv| match tv {
+      TestVector::KeySet { json } => serde_json::from_str::<JwkSet>(json).unwrap().iter().cloned().collect(),
+      TestVector::Key { json } => vec![serde_json::from_str::<Jwk>(json).unwrap()],
+    })
+    .collect();
+
+  let rsa_keys: Vec<&JwkParamsRsa> = keys.iter().filter_map(|jwk| jwk.try_rsa_params().ok()).collect();
+  assert!(!rsa_keys.is_empty());
+
+  for params in rsa_keys {
+    assert_eq!(params.modulus_bits().unwrap(), 2048);
+    params.validate().unwrap();
+
+    let mut even_exponent: JwkParamsRsa = params.to_public();
+    even_exponent.e = "AQAC".to_owned();
+    assert!(even_exponent.validate().is_err());
+
+    let mut short_modulus: JwkParamsRsa = params.to_public();
+    short_modulus.n = jwu::encode_b64([0xff; 128]);
+    assert!(short_modulus.validate().is_err());
+
+    if params.is_private() {
+      let mut partial: JwkParamsRsa = params.clone();
+      partial.dq = None;
+      assert!(partial.validate().is_err());
+    }
+  }
+}
@@ -1,6 +1,14 @@
 // Copyright 2020-2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use identity_core::common::Timestamp;
+use identity_core::error_code::ErrorCode;
+use identity_core::error_code::HasErrorCode;
+use identity_credential::validator::AnyCredentialError;
+use identity_credential::validator::CompoundCredentialValidationError;
+use identity_credential::validator::CompoundJwtPresentationValidationError;
+use identity_credential::validator::JwtValidationError;
+
 /// Alias for a `Result` with the error type [`Error`].
 pub type Result<T, E = Error> = core::result::Result<T, E>;
 
@@ -40,6 +48,12 @@ impl std::error::Error for Error {
   }
 }
 
+impl HasErrorCode for Error {
+  fn error_code(&self) -> ErrorCode {
+    self.error_cause.error_code()
+  }
+}
+
 /// Error failure modes associated with the methods on the [Resolver's](crate::Resolver).
 ///
 /// NOTE: This is a "read only error" in the sense that it can only be constructed by the methods in this crate.
@@ -71,4 +85,132 @@ pub enum ErrorCause {
   /// No client attached to the specific network.
   #[error("none of the attached clients support the network {0}")]
   UnsupportedNetwork(String),
+  /// Caused by attempting to resolve a DID at a point in time when no handler supporting historical resolution
+  /// is attached to the [`Resolver`](crate::resolution::Resolver) for its method.
+  #[error("did resolution failed: historical resolution of DID method \"{method}\" is not supported by the resolver")]
+  UnsupportedHistoricalResolution {
+    /// The method whose historical resolution is unsupported.
+    method: String,
+  },
+  /// Caused by attempting to resolve a DID in a given version when no handler supporting versioned resolution is
+  /// attached to the [`Resolver`](crate::resolution::Resolver) for its method.
+  #[error("did resolution failed: versioned resolution of DID method \"{method}\" is not supported by the resolver")]
+  UnsupportedVersionedResolution {
+    /// The method whose versioned resolution is unsupported.
+    method: String,
+  },
+  /// Caused by invalid DID parameters in the DID URL to resolve.
+  #[error("did resolution failed: {0}")]
+  InvalidDIDParameters(&'static str),
+}
+
+identity_core::impl_error_code!(ErrorCause {
+  DIDParsingError => 1800,
+  HandlerError => 1801,
+  UnsupportedMethodError => 1802,
+  UnsupportedNetwork => 1803,
+  UnsupportedHistoricalResolution => 1804,
+  UnsupportedVersionedResolution => 1805,
+  InvalidDIDParameters => 1806,
+});
+
+/// Errors caused by a failure to obtain a valid presentation from a
+/// [Linked Verifiable Presentation](https://identity.foundation/linked-vp/) endpoint.
+#[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum LinkedVpError {
+  /// The presentation could not be fetched from its endpoint.
+  #[error("failed to fetch the linked verifiable presentation")]
+  #[non_exhaustive]
+  FetchError {
+    /// The source of the fetching error.
+    source: Box<dyn std::error::Error + Send + Sync + 'static>,
+  },
+  /// The fetched resource is not a presentation encoded as a JWT.
+  #[error("the linked verifiable presentation is malformed")]
+  MalformedPresentation(#[source] JwtValidationError),
+  /// The holder of the presentation is not the DID linking to it.
+  #[error("the holder of the linked verifiable presentation does not match the linking DID")]
+  HolderMismatch,
+  /// The presentation failed validation against the DID document linking to it.
+  #[error("the linked verifiable presentation is invalid")]
+  InvalidPresentation(#[source] CompoundJwtPresentationValidationError),
 }
+
+identity_core::impl_error_code!(LinkedVpError {
+  FetchError => 1850,
+  MalformedPresentation => 1851,
+  HolderMismatch => 1852,
+  InvalidPresentation => 1853,
+});
+
+/// Errors that may occur when validating a credential or a presentation through a
+/// [`VerifierContext`](crate::VerifierContext).
+#[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum VerifierContextError {
+  /// The DID document of the credential's issuer could not be resolved.
+  #[error("failed to resolve the DID document of the credential's issuer")]
+  ResolutionError(#[source] Error),
+  /// The credential failed validation.
+  #[error("the credential is invalid")]
+  InvalidCredential(#[source] CompoundCredentialValidationError),
+  /// The presentation could not be decoded.
+  #[error("the presentation is malformed")]
+  MalformedPresentation(#[source] JwtValidationError),
+  /// The presentation failed validation.
+  #[error("the presentation is invalid")]
+  InvalidPresentation(#[source] CompoundJwtPresentationValidationError),
+  /// A credential of any format could not be decoded or failed validation.
+  #[error("the credential is invalid")]
+  InvalidAnyCredential(#[source] AnyCredentialError),
+}
+
+identity_core::impl_error_code!(VerifierContextError {
+  ResolutionError => 2550,
+  InvalidCredential => 2551,
+  MalformedPresentation => 2552,
+  InvalidPresentation => 2553,
+  InvalidAnyCredential => 2554,
+});
+
+/// Errors that may occur when exporting, verifying or resolving from a
+/// [`ResolutionBundle`](crate::ResolutionBundle).
+#[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum OfflineBundleError {
+  /// The DID documents to be bundled could not be resolved.
+  #[error("failed to resolve the DID documents to be bundled")]
+  ResolutionError(#[source] Error),
+  /// The bundle could not be signed.
+  #[error("failed to sign the offline bundle")]
+  #[non_exhaustive]
+  SigningError {
+    /// The source of the signing error.
+    source: Box<dyn std::error::Error + Send + Sync + 'static>,
+  },
+  /// The bundle could not be serialized for signing or verification.
+  #[error("failed to serialize the offline bundle")]
+  SerializationError(#[source] identity_core::Error),
+  /// The proof of the bundle could not be verified.
+  #[error("the proof of the offline bundle is invalid")]
+  InvalidProof(#[source] identity_document::Error),
+  /// The bundle is stale and must no longer be used.
+  #[error("the offline bundle expired at {expires}")]
+  Expired {
+    /// The time the bundle expired at.
+    expires: Timestamp,
+  },
+  /// The requested DID is not part of the bundle.
+  #[error("the DID {0} is not part of the offline bundle")]
+  NotInBundle(String),
+}
+
+identity_core::impl_error_code!(OfflineBundleError {
+  ResolutionError => 2560,
+  SigningError => 2561,
+  SerializationError => 2562,
+  InvalidProof => 2563,
+  Expired => 2564,
+  NotInBundle => 2565,
+});
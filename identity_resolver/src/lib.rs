@@ -19,5 +19,8 @@ mod resolution;
 
 pub use self::error::Error;
 pub use self::error::ErrorCause;
+pub use self::error::LinkedVpError;
+pub use self::error::OfflineBundleError;
 pub use self::error::Result;
+pub use self::error::VerifierContextError;
 pub use resolution::*;
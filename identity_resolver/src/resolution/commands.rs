@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use core::future::Future;
+use identity_core::common::Timestamp;
 use identity_did::DID;
 
 use crate::Error;
@@ -140,3 +141,65 @@ impl<DOC: 'static> SingleThreadedCommand<DOC> {
     Self { fun }
   }
 }
+
+// ===========================================================================
+// Historical commands
+// ===========================================================================
+
+/// Internal representation of a thread safe handler resolving DIDs at a point in time, or in a given version.
+type HistoricalCallback<DOC, P> =
+  Box<dyn for<'r> Fn(&'r str, P) -> Pin<Box<dyn Future<Output = Result<DOC>> + 'r + Send>> + Send + Sync>;
+
+/// Wrapper around a thread safe callback resolving DIDs at a point in time, or in the version identified by a
+/// parameter of type `P`.
+pub struct HistoricalCommand<DOC, P = Timestamp> {
+  fun: HistoricalCallback<DOC, P>,
+}
+
+impl<DOC, P> std::fmt::Debug for HistoricalCommand<DOC, P> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str("<historical_resolution_handler>")
+  }
+}
+
+impl<DOC: 'static, P: Send + 'static> HistoricalCommand<DOC, P> {
+  /// Equivalent to [`SendSyncCommand::new`](SendSyncCommand::new()), for handlers that additionally take the
+  /// point in time or version at which the DID is to be resolved.
+  pub(super) fn new<D, F, Fut, DOCUMENT, E, DIDERR>(handler: F) -> Self
+  where
+    D: DID + Send + for<'r> TryFrom<&'r str, Error = DIDERR> + 'static,
+    DOCUMENT: 'static + Into<DOC>,
+    F: Fn(D, P) -> Fut + 'static + Clone + Send + Sync,
+    Fut: Future<Output = std::result::Result<DOCUMENT, E>> + Send,
+    E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+    DIDERR: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+  {
+    let fun: HistoricalCallback<DOC, P> = Box::new(move |input: &str, parameter: P| {
+      let handler_clone: F = handler.clone();
+      let did_parse_attempt = D::try_from(input)
+        .map_err(|error| ErrorCause::DIDParsingError { source: error.into() })
+        .map_err(Error::new);
+
+      Box::pin(async move {
+        let did: D = did_parse_attempt?;
+        handler_clone(did, parameter)
+          .await
+          .map(Into::into)
+          .map_err(|error| ErrorCause::HandlerError { source: error.into() })
+          .map_err(Error::new)
+      })
+    });
+
+    Self { fun }
+  }
+}
+
+impl<DOC, P> HistoricalCommand<DOC, P> {
+  pub(super) fn apply<'a>(
+    &self,
+    input: &'a str,
+    parameter: P,
+  ) -> Pin<Box<dyn Future<Output = Result<DOC>> + 'a + Send>> {
+    (self.fun)(input, parameter)
+  }
+}
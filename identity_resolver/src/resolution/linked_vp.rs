@@ -0,0 +1,184 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::future::Future;
+
+use identity_core::common::Object;
+use identity_core::common::Url;
+use identity_core::convert::FromJson;
+use identity_credential::credential::Jwt;
+use identity_credential::credential::LinkedVerifiablePresentationService;
+use identity_credential::validator::DecodedJwtPresentation;
+use identity_credential::validator::JwtPresentationValidationOptions;
+use identity_credential::validator::JwtPresentationValidator;
+use identity_credential::validator::JwtPresentationValidatorUtils;
+use identity_did::CoreDID;
+use identity_did::DIDUrl;
+use identity_did::DID;
+use identity_document::document::CoreDocument;
+use identity_verification::jws::JwsVerifier;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::LinkedVpError;
+use crate::Result;
+
+use super::commands::Command;
+use super::Resolver;
+
+/// A presentation obtained from a [Linked Verifiable Presentation](https://identity.foundation/linked-vp/) endpoint.
+///
+/// The type of the credentials contained in the presentation, `CRED`, defaults to [`Jwt`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct LinkedVp<CRED = Jwt> {
+  /// The identifier of the service the presentation was discovered through.
+  pub service_id: DIDUrl,
+  /// The endpoint the presentation was fetched from.
+  pub url: Url,
+  /// The validated presentation, or the reason why it could not be obtained.
+  pub presentation: std::result::Result<DecodedJwtPresentation<CRED, Object>, LinkedVpError>,
+}
+
+/// Discovers, fetches and validates the [Linked Verifiable Presentations](https://identity.foundation/linked-vp/)
+/// of a DID.
+///
+/// The presentations are fetched with a user supplied function, which makes it possible to use any HTTP client or
+/// to support non-HTTP endpoints. Only presentations encoded as JWTs are supported; each is validated against the
+/// DID document linking to it, whose DID must be the presentation's holder. Note that the credentials contained in
+/// the presentations are not validated.
+///
+/// # Example
+///
+/// ```
+/// # use identity_core::common::Url;
+/// # use identity_did::CoreDID;
+/// # use identity_resolver::LinkedVp;
+/// # use identity_resolver::LinkedVpFetcher;
+/// # use identity_resolver::Resolver;
+/// # use identity_verification::jws::JwsVerifier;
+/// async fn linked_presentations(
+///   resolver: &Resolver,
+///   did: &CoreDID,
+///   verifier: impl JwsVerifier,
+/// ) -> Result<(), identity_resolver::Error> {
+///   let fetcher = LinkedVpFetcher::new(fetch, verifier);
+///   let linked_vps: Vec<LinkedVp> = fetcher.fetch(resolver, did).await?;
+///   for linked_vp in linked_vps {
+///     println!("{}: {}", linked_vp.url, linked_vp.presentation.is_ok());
+///   }
+///   Ok(())
+/// }
+///
+/// async fn fetch(url: Url) -> Result<String, std::io::Error> {
+///   todo!("perform an HTTP GET request to `url`")
+/// }
+/// ```
+#[derive(Debug)]
+pub struct LinkedVpFetcher<F, V: JwsVerifier> {
+  fetch: F,
+  validator: JwtPresentationValidator<V>,
+  options: JwtPresentationValidationOptions,
+}
+
+impl<F, Fut, E, V> LinkedVpFetcher<F, V>
+where
+  F: Fn(Url) -> Fut,
+  Fut: Future<Output = std::result::Result<String, E>>,
+  E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+  V: JwsVerifier,
+{
+  /// Creates a new [`LinkedVpFetcher`] fetching presentations with `fetch` and verifying their signatures with
+  /// `verifier`.
+  pub fn new(fetch: F, verifier: V) -> Self {
+    Self {
+      fetch,
+      validator: JwtPresentationValidator::with_signature_verifier(verifier),
+      options: JwtPresentationValidationOptions::default(),
+    }
+  }
+
+  /// Sets the options used to validate the fetched presentations.
+  pub fn options(mut self, options: JwtPresentationValidationOptions) -> Self {
+    self.options = options;
+    self
+  }
+
+  /// Resolves `did` with `resolver` and returns the presentations linked from its DID document, see
+  /// [`Self::fetch_from_document`].
+  ///
+  /// # Errors
+  /// Fails only if `did` cannot be resolved. Failures to obtain individual presentations are reported through
+  /// [`LinkedVp::presentation`].
+  pub async fn fetch<CRED, DOC, CMD, D>(&self, resolver: &Resolver<DOC, CMD>, did: &D) -> Result<Vec<LinkedVp<CRED>>>
+  where
+    CRED: ToOwned<Owned = CRED> + Serialize + DeserializeOwned + Clone,
+    CMD: for<'r> Command<'r, Result<DOC>>,
+    DOC: AsRef<CoreDocument>,
+    D: DID,
+  {
+    let document: DOC = resolver.resolve(did).await?;
+    Ok(self.fetch_from_document(document.as_ref()).await)
+  }
+
+  /// Returns the presentations linked from the `LinkedVerifiablePresentation` services of `document`, fetched
+  /// concurrently, in the order in which they appear in the document.
+  pub async fn fetch_from_document<CRED>(&self, document: &CoreDocument) -> Vec<LinkedVp<CRED>>
+  where
+    CRED: ToOwned<Owned = CRED> + Serialize + DeserializeOwned + Clone,
+  {
+    let endpoints: Vec<(DIDUrl, Url)> = document
+      .service()
+      .iter()
+      .cloned()
+      .filter_map(|service| LinkedVerifiablePresentationService::try_from(service).ok())
+      .flat_map(|service| {
+        let service_id: DIDUrl = service.id().clone();
+        service
+          .verifiable_presentation_urls()
+          .iter()
+          .map(move |url| (service_id.clone(), url.clone()))
+          .collect::<Vec<_>>()
+      })
+      .collect();
+
+    futures::future::join_all(endpoints.into_iter().map(|(service_id, url)| async move {
+      let presentation = self.fetch_presentation(document, url.clone()).await;
+      LinkedVp {
+        service_id,
+        url,
+        presentation,
+      }
+    }))
+    .await
+  }
+
+  async fn fetch_presentation<CRED>(
+    &self,
+    document: &CoreDocument,
+    url: Url,
+  ) -> std::result::Result<DecodedJwtPresentation<CRED, Object>, LinkedVpError>
+  where
+    CRED: ToOwned<Owned = CRED> + Serialize + DeserializeOwned + Clone,
+  {
+    let body: String = (self.fetch)(url)
+      .await
+      .map_err(|err| LinkedVpError::FetchError { source: err.into() })?;
+    // Endpoints may serve the JWT as is or as a JSON string.
+    let jwt: Jwt = match String::from_json(&body) {
+      Ok(jwt) => Jwt::new(jwt),
+      Err(_) => Jwt::new(body.trim().to_owned()),
+    };
+
+    let holder: CoreDID =
+      JwtPresentationValidatorUtils::extract_holder(&jwt).map_err(LinkedVpError::MalformedPresentation)?;
+    if &holder != document.id() {
+      return Err(LinkedVpError::HolderMismatch);
+    }
+
+    self
+      .validator
+      .validate(&jwt, document, &self.options)
+      .map_err(LinkedVpError::InvalidPresentation)
+  }
+}
@@ -2,13 +2,23 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod commands;
+mod linked_vp;
+mod offline_bundle;
+mod presentation_verifier;
 mod resolver;
 #[cfg(test)]
 mod tests;
+mod verifier_context;
 
 use self::commands::SingleThreadedCommand;
 use identity_document::document::CoreDocument;
 
+pub use linked_vp::LinkedVp;
+pub use linked_vp::LinkedVpFetcher;
+pub use offline_bundle::ResolutionBundle;
+pub use presentation_verifier::CredentialOutcome;
+pub use presentation_verifier::IncrementalPresentationVerifier;
 pub use resolver::Resolver;
+pub use verifier_context::VerifierContext;
 /// Alias for a [`Resolver`] that is not [`Send`] + [`Sync`].
 pub type SingleThreadedResolver<DOC = CoreDocument> = Resolver<DOC, SingleThreadedCommand<DOC>>;
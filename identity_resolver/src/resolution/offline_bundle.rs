@@ -0,0 +1,286 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::future::Future;
+use core::future::Ready;
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use identity_core::common::Clock;
+use identity_core::common::SharedClock;
+use identity_core::common::Timestamp;
+use identity_core::convert::ToJson;
+use identity_did::CoreDID;
+use identity_did::DID;
+use identity_document::document::CoreDocument;
+use identity_document::document::DocumentMetadata;
+use identity_document::document::ProvidesDocumentMetadata;
+use identity_document::verifiable::JwsVerificationOptions;
+use identity_verification::jws::JwsVerifier;
+use identity_verification::jwu::encode_b64;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::OfflineBundleError;
+use crate::Result;
+
+use super::commands::Command;
+use super::commands::SendSyncCommand;
+use super::commands::SingleThreadedCommand;
+use super::Resolver;
+
+/// A bundle of resolved DID documents and their metadata, signed by the party that exported it, to be used for DID
+/// resolution in environments without network access.
+///
+/// Bundles are exported with [`Resolver::export_bundle`] while online, transferred to the air-gapped verifier and
+/// served by a resolver created with [`Resolver::with_offline_bundle`], which checks their proof first.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolutionBundle<DOC = CoreDocument> {
+  documents: BTreeMap<String, DOC>,
+  metadata: BTreeMap<String, DocumentMetadata>,
+  created: Timestamp,
+  expires: Timestamp,
+  proof: String,
+}
+
+/// The part of a [`ResolutionBundle`] covered by its proof, signed in its canonical form, see [`signing_input`].
+#[derive(Serialize)]
+struct SigningInput<'a, DOC> {
+  documents: &'a BTreeMap<String, DOC>,
+  metadata: &'a BTreeMap<String, DocumentMetadata>,
+  created: Timestamp,
+  expires: Timestamp,
+}
+
+impl<DOC> ResolutionBundle<DOC> {
+  /// Returns the DID documents contained in this bundle, indexed by DID.
+  pub fn documents(&self) -> &BTreeMap<String, DOC> {
+    &self.documents
+  }
+
+  /// Returns the [DID document metadata](https://www.w3.org/TR/did-core/#did-document-metadata) obtained when
+  /// resolving the documents in this bundle, indexed by DID.
+  pub fn metadata(&self) -> &BTreeMap<String, DocumentMetadata> {
+    &self.metadata
+  }
+
+  /// Returns the time this bundle was exported at.
+  pub fn created(&self) -> Timestamp {
+    self.created
+  }
+
+  /// Returns the time after which the documents in this bundle must no longer be used.
+  pub fn expires(&self) -> Timestamp {
+    self.expires
+  }
+
+  /// Returns the detached JWS over this bundle.
+  pub fn proof(&self) -> &str {
+    &self.proof
+  }
+
+  /// Returns `true` if this bundle must no longer be used at `timestamp`.
+  pub fn is_expired_at(&self, timestamp: Timestamp) -> bool {
+    timestamp >= self.expires
+  }
+
+  /// Returns the document of `did`, unless this bundle is expired at `now`.
+  pub(crate) fn lookup(&self, did: &str, now: Timestamp) -> std::result::Result<DOC, OfflineBundleError>
+  where
+    DOC: Clone,
+  {
+    if self.is_expired_at(now) {
+      return Err(OfflineBundleError::Expired { expires: self.expires });
+    }
+
+    self
+      .documents
+      .get(did)
+      .cloned()
+      .ok_or_else(|| OfflineBundleError::NotInBundle(did.to_owned()))
+  }
+
+  /// Returns the DID methods of the documents in this bundle.
+  fn methods(&self) -> BTreeSet<String> {
+    self
+      .documents
+      .keys()
+      .filter_map(|did| CoreDID::parse(did).ok())
+      .map(|did| did.method().to_owned())
+      .collect()
+  }
+}
+
+impl<DOC: Serialize> ResolutionBundle<DOC> {
+  /// Verifies the proof of this bundle against a verification method of `signer`, the DID document of the party the
+  /// bundle is expected to be exported by.
+  ///
+  /// Verification happens entirely offline, and is performed by [`Resolver::with_offline_bundle`] before the bundle
+  /// is installed.
+  ///
+  /// # Errors
+  /// Fails if the proof cannot be verified with a method of `signer`.
+  pub fn verify<V, S>(
+    &self,
+    signer: &S,
+    signature_verifier: &V,
+    options: &JwsVerificationOptions,
+  ) -> std::result::Result<(), OfflineBundleError>
+  where
+    V: JwsVerifier,
+    S: AsRef<CoreDocument> + ?Sized,
+  {
+    let payload: String = encode_b64(signing_input(
+      &self.documents,
+      &self.metadata,
+      self.created,
+      self.expires,
+    )?);
+    signer
+      .as_ref()
+      .verify_jws(&self.proof, Some(payload.as_bytes()), signature_verifier, options)
+      .map_err(OfflineBundleError::InvalidProof)?;
+
+    Ok(())
+  }
+}
+
+/// Returns the canonical JSON serialization of the part of a bundle covered by its proof.
+///
+/// The members of all the objects are sorted by key, so that the signing input does not depend on the order in which
+/// the documents serialize their properties, e.g. after being deserialized from a bundle with reordered members.
+fn signing_input<DOC: Serialize>(
+  documents: &BTreeMap<String, DOC>,
+  metadata: &BTreeMap<String, DocumentMetadata>,
+  created: Timestamp,
+  expires: Timestamp,
+) -> std::result::Result<Vec<u8>, OfflineBundleError> {
+  SigningInput {
+    documents,
+    metadata,
+    created,
+    expires,
+  }
+  .to_canonical_json_vec()
+  .map_err(OfflineBundleError::SerializationError)
+}
+
+impl<DOC, CMD> Resolver<DOC, CMD>
+where
+  CMD: for<'r> Command<'r, Result<DOC>>,
+  DOC: Serialize + ProvidesDocumentMetadata,
+{
+  /// Resolves the DID documents of `dids` and packs them, along with their metadata, in a [`ResolutionBundle`]
+  /// valid until `expires`. The bundle is marked as created at the current time of `clock`.
+  ///
+  /// The bundle is signed by `sign`, which receives the bytes to be signed and must return a detached JWS over them,
+  /// e.g. as produced by `JwkDocumentExt::create_jws` from the `identity_storage` crate with the
+  /// `detached_payload` option set.
+  ///
+  /// # Errors
+  /// Fails if any of the DIDs cannot be resolved or the bundle cannot be signed.
+  pub async fn export_bundle<D, F, Fut, E>(
+    &self,
+    dids: &[D],
+    expires: Timestamp,
+    clock: SharedClock,
+    sign: F,
+  ) -> std::result::Result<ResolutionBundle<DOC>, OfflineBundleError>
+  where
+    D: DID,
+    F: FnOnce(Vec<u8>) -> Fut,
+    Fut: Future<Output = std::result::Result<String, E>>,
+    E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+  {
+    let documents: BTreeMap<String, DOC> = self
+      .resolve_multiple(dids)
+      .await
+      .map_err(OfflineBundleError::ResolutionError)?
+      .into_iter()
+      .map(|(did, document)| (did.into_string(), document))
+      .collect();
+    let metadata: BTreeMap<String, DocumentMetadata> = documents
+      .iter()
+      .map(|(did, document)| (did.clone(), document.document_metadata()))
+      .collect();
+    let created: Timestamp = clock.now();
+
+    let proof: String = sign(signing_input(&documents, &metadata, created, expires)?)
+      .await
+      .map_err(|err| OfflineBundleError::SigningError { source: err.into() })?;
+
+    Ok(ResolutionBundle {
+      documents,
+      metadata,
+      created,
+      expires,
+      proof,
+    })
+  }
+}
+
+impl<DOC, CMD> Resolver<DOC, CMD>
+where
+  CMD: for<'r> Command<'r, Result<DOC>> + BundleCommand<DOC>,
+  DOC: Serialize,
+{
+  /// Creates a [`Resolver`] that resolves DIDs exclusively from `bundle`, after verifying its proof against `signer`
+  /// as [`ResolutionBundle::verify`] does.
+  ///
+  /// Resolving a DID that is not part of the bundle, or any DID once the bundle has expired according to `clock`,
+  /// fails with a [`HandlerError`](crate::ErrorCause::HandlerError) whose source is an [`OfflineBundleError`].
+  ///
+  /// # Errors
+  /// Fails if the proof of the bundle cannot be verified with a method of `signer`.
+  pub fn with_offline_bundle<V, S>(
+    bundle: ResolutionBundle<DOC>,
+    signer: &S,
+    signature_verifier: &V,
+    options: &JwsVerificationOptions,
+    clock: SharedClock,
+  ) -> std::result::Result<Self, OfflineBundleError>
+  where
+    V: JwsVerifier,
+    S: AsRef<CoreDocument> + ?Sized,
+  {
+    bundle.verify(signer, signature_verifier, options)?;
+
+    let methods: BTreeSet<String> = bundle.methods();
+    let bundle: Arc<ResolutionBundle<DOC>> = Arc::new(bundle);
+    let mut resolver: Self = Self::new();
+    for method in methods {
+      resolver.attach_command(method, CMD::from_bundle(bundle.clone(), clock.clone()));
+    }
+
+    Ok(resolver)
+  }
+}
+
+/// A command serving DIDs from a [`ResolutionBundle`], implemented for the commands of both [`Resolver`] and
+/// [`SingleThreadedResolver`](crate::SingleThreadedResolver).
+pub trait BundleCommand<DOC>: Sized {
+  /// Creates a command resolving DIDs from `bundle` as of the current time of `clock`.
+  fn from_bundle(bundle: Arc<ResolutionBundle<DOC>>, clock: SharedClock) -> Self;
+}
+
+impl<DOC: Clone + Send + Sync + 'static> BundleCommand<DOC> for SendSyncCommand<DOC> {
+  fn from_bundle(bundle: Arc<ResolutionBundle<DOC>>, clock: SharedClock) -> Self {
+    Self::new(bundle_handler(bundle, clock))
+  }
+}
+
+impl<DOC: Clone + 'static> BundleCommand<DOC> for SingleThreadedCommand<DOC> {
+  fn from_bundle(bundle: Arc<ResolutionBundle<DOC>>, clock: SharedClock) -> Self {
+    Self::new(bundle_handler(bundle, clock))
+  }
+}
+
+/// Returns a handler looking DIDs up in `bundle`, which is [`Send`] and [`Sync`] whenever `DOC` is.
+fn bundle_handler<DOC: Clone>(
+  bundle: Arc<ResolutionBundle<DOC>>,
+  clock: SharedClock,
+) -> impl Fn(CoreDID) -> Ready<std::result::Result<DOC, OfflineBundleError>> + Clone {
+  move |did: CoreDID| core::future::ready(bundle.lookup(did.as_str(), clock.now()))
+}
@@ -0,0 +1,223 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use futures::Stream;
+use futures::StreamExt as _;
+use identity_core::common::Url;
+use identity_credential::credential::Jwt;
+use identity_credential::validator::DecodedJwtCredential;
+use identity_credential::validator::DecodedJwtPresentation;
+use identity_credential::validator::FailFast;
+use identity_credential::validator::JwtCredentialValidationOptions;
+use identity_credential::validator::JwtCredentialValidator;
+use identity_credential::validator::JwtPresentationValidationOptions;
+use identity_credential::validator::JwtPresentationValidator;
+use identity_credential::validator::JwtPresentationValidatorUtils;
+use identity_credential::validator::SubjectHolderRelationship;
+use identity_did::CoreDID;
+use identity_did::DID;
+use identity_document::document::CoreDocument;
+use identity_verification::jws::JwsVerifier;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::Result;
+use crate::VerifierContextError;
+
+use super::commands::Command;
+use super::VerifierContext;
+
+/// The outcome of the validation of a single credential of a presentation.
+pub type CredentialOutcome<T> = std::result::Result<DecodedJwtCredential<T>, VerifierContextError>;
+
+/// Validates the credentials of a presentation one at a time, without requiring all of them to be decoded upfront.
+///
+/// The state shared by all the credentials - the presentation's holder and how it must relate to the credentials'
+/// subjects, and the issuers' DID documents cached by the [`VerifierContext`] - is established once, either from a
+/// validated presentation with [`Self::from_presentation`] or directly with [`Self::new`]. Credentials can then be
+/// validated individually with [`Self::validate`], or concurrently with [`Self::validate_iter`] and
+/// [`Self::validate_stream`], which only take the next credential once a validation slot is free, so that dropping
+/// the returned stream stops the validation of the remaining credentials.
+///
+/// # Example
+///
+/// ```
+/// # use futures::StreamExt;
+/// # use identity_core::common::Object;
+/// # use identity_credential::credential::Jwt;
+/// # use identity_credential::validator::FailFast;
+/// # use identity_credential::validator::JwtCredentialValidationOptions;
+/// # use identity_credential::validator::JwtPresentationValidationOptions;
+/// # use identity_document::verifiable::JwsVerificationOptions;
+/// # use identity_resolver::IncrementalPresentationVerifier;
+/// # use identity_resolver::VerifierContext;
+/// # use identity_resolver::VerifierContextError;
+/// # use identity_verification::jws::JwsVerifier;
+/// async fn verify<V: JwsVerifier + Clone>(
+///   context: &VerifierContext,
+///   verifier: V,
+///   presentation: &Jwt,
+///   nonce: &str,
+/// ) -> Result<(), VerifierContextError> {
+///   let presentation_options = JwtPresentationValidationOptions::default()
+///     .presentation_verifier_options(JwsVerificationOptions::default().nonce(nonce));
+///   let (verifier, decoded) = IncrementalPresentationVerifier::from_presentation::<Object>(
+///     context,
+///     verifier,
+///     presentation,
+///     &presentation_options,
+///     JwtCredentialValidationOptions::default(),
+///     FailFast::FirstError,
+///   )
+///   .await?;
+///
+///   let credentials = decoded.presentation.verifiable_credential;
+///   let mut outcomes = std::pin::pin!(verifier.validate_iter::<_, Object>(credentials, 16));
+///   while let Some((index, outcome)) = outcomes.next().await {
+///     // Stop at the first invalid credential: the ones not taken yet are never validated.
+///     if let Err(err) = outcome {
+///       println!("credential {index} is invalid: {err}");
+///       return Err(err);
+///     }
+///   }
+///   Ok(())
+/// }
+/// ```
+pub struct IncrementalPresentationVerifier<'c, V, DOC, CMD>
+where
+  V: JwsVerifier,
+  CMD: for<'r> Command<'r, Result<DOC>>,
+{
+  context: &'c VerifierContext<DOC, CMD>,
+  validator: JwtCredentialValidator<V>,
+  holder: CoreDID,
+  options: JwtCredentialValidationOptions,
+  fail_fast: FailFast,
+}
+
+impl<'c, V, DOC, CMD> IncrementalPresentationVerifier<'c, V, DOC, CMD>
+where
+  V: JwsVerifier,
+  CMD: for<'r> Command<'r, Result<DOC>>,
+  DOC: AsRef<CoreDocument> + Clone,
+{
+  /// Creates a new [`IncrementalPresentationVerifier`] for the credentials presented by `holder`.
+  ///
+  /// Unless `options` already sets one, the credentials' subjects must always be `holder`, see
+  /// [`SubjectHolderRelationship::AlwaysSubject`].
+  pub fn new(
+    context: &'c VerifierContext<DOC, CMD>,
+    signature_verifier: V,
+    holder: CoreDID,
+    mut options: JwtCredentialValidationOptions,
+    fail_fast: FailFast,
+  ) -> Self {
+    if options.subject_holder_relationship.is_none() {
+      let holder_url: Url = holder.to_url().into();
+      options.subject_holder_relationship = Some((holder_url, SubjectHolderRelationship::AlwaysSubject));
+    }
+
+    Self {
+      context,
+      validator: JwtCredentialValidator::with_signature_verifier(signature_verifier),
+      holder,
+      options,
+      fail_fast,
+    }
+  }
+
+  /// Validates `presentation`, resolving its holder through `context`, and returns an
+  /// [`IncrementalPresentationVerifier`] for its credentials together with the decoded presentation.
+  ///
+  /// The credentials contained in the presentation are only decoded as [`Jwt`]s and are not validated: they should be
+  /// fed to the returned verifier, e.g. with [`Self::validate_iter`], which validates them lazily.
+  ///
+  /// # Errors
+  /// Fails if the holder's DID document cannot be resolved or the presentation is invalid, e.g. because its `nonce`
+  /// does not match the one required by `presentation_options`.
+  pub async fn from_presentation<T>(
+    context: &'c VerifierContext<DOC, CMD>,
+    signature_verifier: V,
+    presentation: &Jwt,
+    presentation_options: &JwtPresentationValidationOptions,
+    credential_options: JwtCredentialValidationOptions,
+    fail_fast: FailFast,
+  ) -> std::result::Result<(Self, DecodedJwtPresentation<Jwt, T>), VerifierContextError>
+  where
+    V: Clone,
+    T: ToOwned<Owned = T> + Serialize + DeserializeOwned,
+  {
+    let holder: CoreDID = JwtPresentationValidatorUtils::extract_holder(presentation)
+      .map_err(VerifierContextError::MalformedPresentation)?;
+    let holder_document: DOC = context
+      .resolve(&holder)
+      .await
+      .map_err(VerifierContextError::ResolutionError)?;
+
+    let mut presentation_options: JwtPresentationValidationOptions = presentation_options.clone();
+    let now = context.now();
+    presentation_options.earliest_expiry_date.get_or_insert(now);
+    presentation_options.latest_issuance_date.get_or_insert(now);
+    let decoded: DecodedJwtPresentation<Jwt, T> =
+      JwtPresentationValidator::with_signature_verifier(signature_verifier.clone())
+        .validate(presentation, &holder_document, &presentation_options)
+        .map_err(VerifierContextError::InvalidPresentation)?;
+
+    let verifier = Self::new(context, signature_verifier, holder, credential_options, fail_fast);
+    Ok((verifier, decoded))
+  }
+
+  /// Returns the DID of the holder of the verified credentials.
+  pub fn holder(&self) -> &CoreDID {
+    &self.holder
+  }
+
+  /// Validates a single credential of the presentation.
+  pub async fn validate<T>(&self, credential: &Jwt) -> CredentialOutcome<T>
+  where
+    T: Clone + Serialize + DeserializeOwned,
+  {
+    self
+      .context
+      .validate_credential(&self.validator, credential, &self.options, self.fail_fast)
+      .await
+  }
+
+  /// Validates the credentials yielded by `credentials`, e.g. the credentials of a decoded presentation, running up to
+  /// `concurrency` validations at once.
+  ///
+  /// Credentials are taken from `credentials` as the returned stream is polled, see [`Self::validate_stream`].
+  pub fn validate_iter<'s, I, T>(
+    &'s self,
+    credentials: I,
+    concurrency: usize,
+  ) -> impl Stream<Item = (usize, CredentialOutcome<T>)> + 's
+  where
+    I: IntoIterator<Item = Jwt>,
+    I::IntoIter: 's,
+    T: Clone + Serialize + DeserializeOwned + 's,
+  {
+    self.validate_stream(futures::stream::iter(credentials), concurrency)
+  }
+
+  /// Validates the credentials yielded by `credentials`, running up to `concurrency` validations at once.
+  ///
+  /// The returned stream yields the outcome of each validation as soon as it completes, together with the position of
+  /// the credential in `credentials`; outcomes are therefore not necessarily yielded in order. A credential is only
+  /// taken from `credentials` once fewer than `concurrency` validations are running, so dropping the returned stream
+  /// stops the validation of the credentials that were not taken yet.
+  pub fn validate_stream<'s, S, T>(
+    &'s self,
+    credentials: S,
+    concurrency: usize,
+  ) -> impl Stream<Item = (usize, CredentialOutcome<T>)> + 's
+  where
+    S: Stream<Item = Jwt> + 's,
+    T: Clone + Serialize + DeserializeOwned + 's,
+  {
+    credentials
+      .enumerate()
+      .map(move |(index, credential)| async move { (index, self.validate(&credential).await) })
+      .buffer_unordered(concurrency.max(1))
+  }
+}
@@ -4,12 +4,17 @@
 use core::future::Future;
 use futures::stream::FuturesUnordered;
 use futures::TryStreamExt;
+use identity_core::common::Timestamp;
+use identity_core::metrics;
 use identity_did::DIDCompositeJwk;
 use identity_did::DIDJwk;
+use identity_did::DIDUrl;
 use identity_did::DID;
 use std::collections::HashSet;
 
 use identity_document::document::CoreDocument;
+use identity_document::document::DocumentMetadata;
+use identity_document::document::ProvidesDocumentMetadata;
 use std::collections::HashMap;
 use std::marker::PhantomData;
 
@@ -18,6 +23,7 @@ use crate::ErrorCause;
 use crate::Result;
 
 use super::commands::Command;
+use super::commands::HistoricalCommand;
 use super::commands::SendSyncCommand;
 use super::commands::SingleThreadedCommand;
 
@@ -32,6 +38,8 @@ where
   CMD: for<'r> Command<'r, Result<DOC>>,
 {
   command_map: HashMap<String, CMD>,
+  historical_command_map: HashMap<String, HistoricalCommand<DOC>>,
+  versioned_command_map: HashMap<String, HistoricalCommand<DOC, String>>,
   _required: PhantomData<DOC>,
 }
 
@@ -55,6 +63,8 @@ where
   pub fn new() -> Self {
     Self {
       command_map: HashMap::new(),
+      historical_command_map: HashMap::new(),
+      versioned_command_map: HashMap::new(),
       _required: PhantomData::<DOC>,
     }
   }
@@ -91,17 +101,122 @@ where
   ///   todo!()
   /// }
   /// ```
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(name = "resolve", skip_all, fields(did = %did.redacted()), err(Display))
+  )]
   pub async fn resolve<D: DID>(&self, did: &D) -> Result<DOC> {
     let method: &str = did.method();
-    let delegate: &M = self
-      .command_map
-      .get(method)
-      .ok_or_else(|| ErrorCause::UnsupportedMethodError {
+    // Only methods with an attached handler are used as label, the DID being untrusted input.
+    let (method_label, result): (&str, Result<DOC>) = match self.command_map.get(method) {
+      Some(delegate) => (method, delegate.apply(did.as_str()).await),
+      None => (
+        metrics::OTHER_METHOD,
+        Err(Error::new(ErrorCause::UnsupportedMethodError {
+          method: method.to_owned(),
+        })),
+      ),
+    };
+
+    let outcome: &str = if result.is_ok() { "ok" } else { "error" };
+    metrics::increment_counter(
+      metrics::names::RESOLUTIONS,
+      &[("method", method_label), ("outcome", outcome)],
+    );
+
+    result
+  }
+
+  /// Fetches the DID Document of the given DID as it was at `timestamp`.
+  ///
+  /// Only DID methods with a handler attached through
+  /// [`attach_historical_handler`](Resolver::attach_historical_handler) can be resolved at a point in time.
+  ///
+  /// # Errors
+  ///
+  /// Errors if the resolver has no historical handler for the method corresponding to the given DID or the
+  /// resolution process itself fails.
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(name = "resolve_at", skip_all, fields(did = %did.redacted()), err(Display))
+  )]
+  pub async fn resolve_at<D: DID>(&self, did: &D, timestamp: Timestamp) -> Result<DOC> {
+    let method: &str = did.method();
+    match self.historical_command_map.get(method) {
+      Some(delegate) => delegate.apply(did.as_str(), timestamp).await,
+      None => Err(Error::new(ErrorCause::UnsupportedHistoricalResolution {
         method: method.to_owned(),
-      })
-      .map_err(Error::new)?;
+      })),
+    }
+  }
+
+  /// Fetches the DID Document of the given DID in the version identified by `version_id`, the value of the
+  /// [`versionId`](https://www.w3.org/TR/did-core/#did-parameters) DID parameter.
+  ///
+  /// Only DID methods with a handler attached through
+  /// [`attach_versioned_handler`](Resolver::attach_versioned_handler) can be resolved in a given version.
+  ///
+  /// # Errors
+  ///
+  /// Errors if the resolver has no versioned handler for the method corresponding to the given DID or the
+  /// resolution process itself fails.
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(name = "resolve_version", skip_all, fields(did = %did.redacted()), err(Display))
+  )]
+  pub async fn resolve_version<D: DID>(&self, did: &D, version_id: &str) -> Result<DOC> {
+    let method: &str = did.method();
+    match self.versioned_command_map.get(method) {
+      Some(delegate) => delegate.apply(did.as_str(), version_id.to_owned()).await,
+      None => Err(Error::new(ErrorCause::UnsupportedVersionedResolution {
+        method: method.to_owned(),
+      })),
+    }
+  }
 
-    delegate.apply(did.as_str()).await
+  /// Fetches the DID Document referenced by `did_url`, honoring its
+  /// [`versionId`](https://www.w3.org/TR/did-core/#did-parameters) and
+  /// [`versionTime`](https://www.w3.org/TR/did-core/#did-parameters) DID parameters.
+  ///
+  /// Without any of these parameters, the latest DID Document is resolved as with [`resolve`](Resolver::resolve).
+  /// Otherwise the DID Document is resolved as with [`resolve_version`](Resolver::resolve_version) or
+  /// [`resolve_at`](Resolver::resolve_at) respectively.
+  ///
+  /// # Errors
+  ///
+  /// Errors if both parameters are set, if `versionTime` is not a valid timestamp, or if the resolution fails.
+  pub async fn resolve_url(&self, did_url: &DIDUrl) -> Result<DOC> {
+    let version_time: Option<Timestamp> = did_url
+      .version_time()
+      .map_err(|_| Error::new(ErrorCause::InvalidDIDParameters("invalid versionTime")))?;
+
+    match (did_url.version_id(), version_time) {
+      (Some(_), Some(_)) => Err(Error::new(ErrorCause::InvalidDIDParameters(
+        "versionId and versionTime cannot be combined",
+      ))),
+      (Some(version_id), None) => self.resolve_version(did_url.did(), &version_id).await,
+      (None, Some(timestamp)) => self.resolve_at(did_url.did(), timestamp).await,
+      (None, None) => self.resolve(did_url.did()).await,
+    }
+  }
+
+  /// Like [`resolve_url`](Resolver::resolve_url), but additionally returns the standard
+  /// [DID document metadata](https://www.w3.org/TR/did-core/#did-document-metadata) of the resolved DID Document,
+  /// e.g. its `versionId` or `equivalentId`.
+  ///
+  /// The metadata is read from the resolved document, so that it is only available if `DOC` preserves the metadata
+  /// provided by the handler, e.g. `IotaDocument` does while [`CoreDocument`] does not.
+  ///
+  /// # Errors
+  ///
+  /// See [`resolve_url`](Resolver::resolve_url).
+  pub async fn resolve_url_with_metadata(&self, did_url: &DIDUrl) -> Result<(DOC, DocumentMetadata)>
+  where
+    DOC: ProvidesDocumentMetadata,
+  {
+    let document: DOC = self.resolve_url(did_url).await?;
+    let metadata: DocumentMetadata = document.document_metadata();
+    Ok((document, metadata))
   }
 
   /// Concurrently fetches the DID Documents of the multiple given DIDs.
@@ -112,6 +227,10 @@ where
   ///
   /// ## Note
   /// * If `dids` contains duplicates, these will be resolved only once.
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(name = "resolve_multiple", skip_all, fields(count = dids.len()), err(Display))
+  )]
   pub async fn resolve_multiple<D: DID>(&self, dids: &[D]) -> Result<HashMap<D, DOC>> {
     let futures = FuturesUnordered::new();
 
@@ -128,6 +247,11 @@ where
 
     Ok(documents)
   }
+
+  /// Attaches `command` as the handler of the given DID method, replacing any existing one.
+  pub(super) fn attach_command(&mut self, method: String, command: M) {
+    self.command_map.insert(method, command);
+  }
 }
 
 impl<DOC: 'static> Resolver<DOC, SendSyncCommand<DOC>> {
@@ -188,6 +312,46 @@ impl<DOC: 'static> Resolver<DOC, SendSyncCommand<DOC>> {
     let command = SendSyncCommand::new(handler);
     self.command_map.insert(method, command);
   }
+
+  /// Attach a new handler responsible for resolving DIDs of the given DID method at a point in time, see
+  /// [`resolve_at`](Resolver::resolve_at).
+  ///
+  /// The `handler` is subject to the same requirements as the ones of [`attach_handler`](Self::attach_handler), but
+  /// additionally takes the [`Timestamp`] at which the DID is to be resolved.
+  ///
+  /// NOTE: If there already exists a historical handler for this method then it will be replaced with the new handler.
+  pub fn attach_historical_handler<D, F, Fut, DOCUMENT, E, DIDERR>(&mut self, method: String, handler: F)
+  where
+    D: DID + Send + for<'r> TryFrom<&'r str, Error = DIDERR> + 'static,
+    DOCUMENT: 'static + Into<DOC>,
+    F: Fn(D, Timestamp) -> Fut + 'static + Clone + Send + Sync,
+    Fut: Future<Output = std::result::Result<DOCUMENT, E>> + Send,
+    E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+    DIDERR: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+  {
+    let command = HistoricalCommand::new(handler);
+    self.historical_command_map.insert(method, command);
+  }
+
+  /// Attach a new handler responsible for resolving DIDs of the given DID method in a given version, see
+  /// [`resolve_version`](Resolver::resolve_version).
+  ///
+  /// The `handler` is subject to the same requirements as the ones of [`attach_handler`](Self::attach_handler), but
+  /// additionally takes the value of the `versionId` DID parameter identifying the version to resolve.
+  ///
+  /// NOTE: If there already exists a versioned handler for this method then it will be replaced with the new handler.
+  pub fn attach_versioned_handler<D, F, Fut, DOCUMENT, E, DIDERR>(&mut self, method: String, handler: F)
+  where
+    D: DID + Send + for<'r> TryFrom<&'r str, Error = DIDERR> + 'static,
+    DOCUMENT: 'static + Into<DOC>,
+    F: Fn(D, String) -> Fut + 'static + Clone + Send + Sync,
+    Fut: Future<Output = std::result::Result<DOCUMENT, E>> + Send,
+    E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+    DIDERR: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+  {
+    let command = HistoricalCommand::new(handler);
+    self.versioned_command_map.insert(method, command);
+  }
 }
 
 impl<DOC: 'static> Resolver<DOC, SingleThreadedCommand<DOC>> {
@@ -288,6 +452,7 @@ mod iota_handler {
   use crate::ErrorCause;
 
   use super::Resolver;
+  use identity_core::common::Timestamp;
   use identity_document::document::CoreDocument;
   use identity_iota_core::IotaDID;
   use identity_iota_core::IotaDocument;
@@ -312,12 +477,62 @@ mod iota_handler {
       {
         let arc_client: Arc<CLI> = Arc::new(client);
 
-        let handler = move |did: IotaDID| {
+        let handler = {
+          let arc_client = arc_client.clone();
+          move |did: IotaDID| {
+            let future_client = arc_client.clone();
+            async move { future_client.resolve_did(&did).await }
+          }
+        };
+        let historical_handler = {
+          let arc_client = arc_client.clone();
+          move |did: IotaDID, timestamp: Timestamp| {
+            let future_client = arc_client.clone();
+            async move { future_client.resolve_did_at(&did, timestamp).await }
+          }
+        };
+        let versioned_handler = move |did: IotaDID, version_id: String| {
           let future_client = arc_client.clone();
-          async move { future_client.resolve_did(&did).await }
+          async move { future_client.resolve_did_version(&did, &version_id).await }
         };
 
         self.attach_handler(IotaDID::METHOD.to_owned(), handler);
+        self.attach_historical_handler(IotaDID::METHOD.to_owned(), historical_handler);
+        self.attach_versioned_handler(IotaDID::METHOD.to_owned(), versioned_handler);
+      }
+
+      /// Like [`attach_iota_handler`](Self::attach_iota_handler), but resolves legacy IOTA DIDs migrated to a new
+      /// identity to the document of the new identity.
+      ///
+      /// See [`DidResolutionHandler::resolve_did_following_migration`] for how migration links are followed.
+      pub fn attach_iota_handler_following_migrations<CLI>(&mut self, client: CLI)
+      where
+        CLI: DidResolutionHandler + Send + Sync + 'static,
+      {
+        let arc_client: Arc<CLI> = Arc::new(client);
+
+        let handler = {
+          let arc_client = arc_client.clone();
+          move |did: IotaDID| {
+            let future_client = arc_client.clone();
+            async move { future_client.resolve_did_following_migration(&did).await }
+          }
+        };
+        let historical_handler = {
+          let arc_client = arc_client.clone();
+          move |did: IotaDID, timestamp: Timestamp| {
+            let future_client = arc_client.clone();
+            async move { future_client.resolve_did_at(&did, timestamp).await }
+          }
+        };
+        let versioned_handler = move |did: IotaDID, version_id: String| {
+          let future_client = arc_client.clone();
+          async move { future_client.resolve_did_version(&did, &version_id).await }
+        };
+
+        self.attach_handler(IotaDID::METHOD.to_owned(), handler);
+        self.attach_historical_handler(IotaDID::METHOD.to_owned(), historical_handler);
+        self.attach_versioned_handler(IotaDID::METHOD.to_owned(), versioned_handler);
       }
 
       /// Convenience method for attaching multiple handlers responsible for resolving IOTA DIDs
@@ -352,26 +567,57 @@ mod iota_handler {
       {
         let arc_clients = Arc::new(clients.into_iter().collect::<HashMap<&'static str, CLI>>());
 
-        let handler = move |did: IotaDID| {
+        let handler = {
+          let arc_clients = arc_clients.clone();
+          move |did: IotaDID| {
+            let future_client = arc_clients.clone();
+            async move {
+              let client: &CLI = client_for_network(&future_client, &did)?;
+              client
+                .resolve_did(&did)
+                .await
+                .map_err(|err| crate::Error::new(ErrorCause::HandlerError { source: Box::new(err) }))
+            }
+          }
+        };
+        let historical_handler = {
+          let arc_clients = arc_clients.clone();
+          move |did: IotaDID, timestamp: Timestamp| {
+            let future_client = arc_clients.clone();
+            async move {
+              let client: &CLI = client_for_network(&future_client, &did)?;
+              client
+                .resolve_did_at(&did, timestamp)
+                .await
+                .map_err(|err| crate::Error::new(ErrorCause::HandlerError { source: Box::new(err) }))
+            }
+          }
+        };
+        let versioned_handler = move |did: IotaDID, version_id: String| {
           let future_client = arc_clients.clone();
           async move {
-            let did_network = did.network_str();
-            let client: &CLI =
-              future_client
-                .get(did_network)
-                .ok_or(crate::Error::new(ErrorCause::UnsupportedNetwork(
-                  did_network.to_string(),
-                )))?;
+            let client: &CLI = client_for_network(&future_client, &did)?;
             client
-              .resolve_did(&did)
+              .resolve_did_version(&did, &version_id)
               .await
               .map_err(|err| crate::Error::new(ErrorCause::HandlerError { source: Box::new(err) }))
           }
         };
 
         self.attach_handler(IotaDID::METHOD.to_owned(), handler);
+        self.attach_historical_handler(IotaDID::METHOD.to_owned(), historical_handler);
+        self.attach_versioned_handler(IotaDID::METHOD.to_owned(), versioned_handler);
       }
     }
+
+    fn client_for_network<'c, CLI>(clients: &'c HashMap<&'static str, CLI>, did: &IotaDID) -> crate::Result<&'c CLI> {
+      let did_network = did.network_str();
+      clients
+        .get(did_network)
+        .ok_or(crate::Error::new(ErrorCause::UnsupportedNetwork(
+          did_network.to_string(),
+        )))
+    }
   }
 }
 
@@ -393,6 +639,8 @@ where
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     f.debug_struct("Resolver")
       .field("command_map", &self.command_map)
+      .field("historical_command_map", &self.historical_command_map)
+      .field("versioned_command_map", &self.versioned_command_map)
       .finish()
   }
 }
@@ -443,6 +691,189 @@ mod tests {
     assert_eq!(doc.id(), &did2);
   }
 
+  struct DummyNetwork(Vec<IotaDocument>);
+
+  #[async_trait::async_trait]
+  impl DidResolutionHandler for DummyNetwork {
+    async fn resolve_did(&self, did: &IotaDID) -> identity_iota_core::Result<IotaDocument> {
+      self
+        .0
+        .iter()
+        .find(|document| document.id() == did)
+        .cloned()
+        .ok_or_else(|| identity_iota_core::Error::DIDResolutionError("DID not found".to_string()))
+    }
+  }
+
+  #[cfg(feature = "iota")]
+  #[tokio::test]
+  async fn test_following_migrations() {
+    use identity_core::common::Url;
+
+    let legacy_did =
+      IotaDID::parse("did:iota:0x0101010101010101010101010101010101010101010101010101010101010101").unwrap();
+    let new_did =
+      IotaDID::parse("did:iota:0x0202020202020202020202020202020202020202020202020202020202020202").unwrap();
+    let impostor_did =
+      IotaDID::parse("did:iota:0x0303030303030303030303030303030303030303030303030303030303030303").unwrap();
+
+    let mut legacy_document = IotaDocument::new_with_id(legacy_did.clone());
+    let mut new_document = IotaDocument::new_with_id(new_did.clone());
+    let mut impostor_document = IotaDocument::new_with_id(impostor_did.clone());
+    legacy_document.also_known_as_mut().append(Url::from(new_did.to_url()));
+    new_document.also_known_as_mut().append(Url::from(legacy_did.to_url()));
+    // Only claims to be the successor of the legacy DID.
+    impostor_document
+      .also_known_as_mut()
+      .append(Url::from(legacy_did.to_url()));
+
+    let mut resolver = Resolver::<IotaDocument>::new();
+    resolver.attach_iota_handler_following_migrations(DummyNetwork(vec![
+      legacy_document.clone(),
+      new_document,
+      impostor_document,
+    ]));
+    assert_eq!(resolver.resolve(&legacy_did).await.unwrap().id(), &new_did);
+    assert_eq!(resolver.resolve(&impostor_did).await.unwrap().id(), &impostor_did);
+
+    let mut resolver = Resolver::<IotaDocument>::new();
+    resolver.attach_iota_handler(DummyNetwork(vec![legacy_document]));
+    assert_eq!(resolver.resolve(&legacy_did).await.unwrap().id(), &legacy_did);
+  }
+
+  struct DummyHistory(Vec<IotaDocument>);
+
+  #[async_trait::async_trait]
+  impl DidResolutionHandler for DummyHistory {
+    async fn resolve_did(&self, did: &IotaDID) -> identity_iota_core::Result<IotaDocument> {
+      self
+        .0
+        .last()
+        .filter(|document| document.id() == did)
+        .cloned()
+        .ok_or_else(|| identity_iota_core::Error::DIDResolutionError("DID not found".to_string()))
+    }
+
+    async fn resolve_did_at(&self, did: &IotaDID, timestamp: Timestamp) -> identity_iota_core::Result<IotaDocument> {
+      self
+        .0
+        .iter()
+        .rev()
+        .find(|document| document.id() == did && document.metadata.updated.is_some_and(|updated| updated <= timestamp))
+        .cloned()
+        .ok_or_else(|| identity_iota_core::Error::DIDResolutionError("DID not found".to_string()))
+    }
+
+    async fn resolve_did_version(&self, did: &IotaDID, version_id: &str) -> identity_iota_core::Result<IotaDocument> {
+      self
+        .0
+        .iter()
+        .find(|document| document.id() == did && document.metadata.version_id.as_deref() == Some(version_id))
+        .cloned()
+        .ok_or_else(|| identity_iota_core::Error::DIDResolutionError("DID not found".to_string()))
+    }
+  }
+
+  #[cfg(feature = "iota")]
+  #[tokio::test]
+  async fn test_historical_resolution() {
+    let did = IotaDID::parse("did:iota:0x0101010101010101010101010101010101010101010101010101010101010101").unwrap();
+    let history: Vec<IotaDocument> = [1_000, 2_000]
+      .into_iter()
+      .map(|seconds| {
+        let mut document = IotaDocument::new_with_id(did.clone());
+        document.metadata.updated = Some(Timestamp::from_unix(seconds).unwrap());
+        document
+      })
+      .collect();
+
+    let mut resolver = Resolver::<IotaDocument>::new();
+    assert!(matches!(
+      resolver
+        .resolve_at(&did, Timestamp::from_unix(1_500).unwrap())
+        .await
+        .unwrap_err()
+        .into_error_cause(),
+      ErrorCause::UnsupportedHistoricalResolution { .. }
+    ));
+
+    resolver.attach_iota_handler(DummyHistory(history.clone()));
+    let doc = resolver
+      .resolve_at(&did, Timestamp::from_unix(1_500).unwrap())
+      .await
+      .unwrap();
+    assert_eq!(doc, history[0]);
+    let doc = resolver
+      .resolve_at(&did, Timestamp::from_unix(2_500).unwrap())
+      .await
+      .unwrap();
+    assert_eq!(doc, history[1]);
+    assert!(resolver
+      .resolve_at(&did, Timestamp::from_unix(500).unwrap())
+      .await
+      .is_err());
+
+    // Handlers relying on the default implementation do not support historical resolution.
+    let mut resolver = Resolver::<IotaDocument>::new();
+    resolver.attach_iota_handler(DummyClient(history[1].clone()));
+    assert!(resolver
+      .resolve_at(&did, Timestamp::from_unix(2_500).unwrap())
+      .await
+      .is_err());
+  }
+
+  #[cfg(feature = "iota")]
+  #[tokio::test]
+  async fn test_did_url_resolution() {
+    let did = IotaDID::parse("did:iota:0x0101010101010101010101010101010101010101010101010101010101010101").unwrap();
+    let history: Vec<IotaDocument> = [(1_000, 3), (2_000, 7)]
+      .into_iter()
+      .map(|(seconds, version)| {
+        let mut document = IotaDocument::new_with_id(did.clone());
+        document.metadata.updated = Some(Timestamp::from_unix(seconds).unwrap());
+        document.metadata.version_id = Some(version.to_string());
+        document
+      })
+      .collect();
+
+    let mut resolver = Resolver::<IotaDocument>::new();
+    resolver.attach_iota_handler(DummyHistory(history.clone()));
+
+    let resolve = |did_url: String| {
+      let resolver = &resolver;
+      async move { resolver.resolve_url(&DIDUrl::parse(did_url).unwrap()).await }
+    };
+    assert_eq!(resolve(format!("{did}")).await.unwrap(), history[1]);
+    assert_eq!(resolve(format!("{did}?versionId=3")).await.unwrap(), history[0]);
+    assert_eq!(
+      resolve(format!("{did}?versionTime=1970-01-01T00:25:00Z"))
+        .await
+        .unwrap(),
+      history[0]
+    );
+    assert!(resolve(format!("{did}?versionId=5")).await.is_err());
+    assert!(matches!(
+      resolve(format!("{did}?versionId=3&versionTime=1970-01-01T00:25:00Z"))
+        .await
+        .unwrap_err()
+        .into_error_cause(),
+      ErrorCause::InvalidDIDParameters(_)
+    ));
+
+    let (document, metadata) = resolver
+      .resolve_url_with_metadata(&DIDUrl::parse(format!("{did}?versionId=3")).unwrap())
+      .await
+      .unwrap();
+    assert_eq!(document, history[0]);
+    assert_eq!(metadata.version_id.as_deref(), Some("3"));
+    assert_eq!(metadata.updated, history[0].metadata.updated);
+
+    // Handlers relying on the default implementation do not support versioned resolution.
+    let mut resolver = Resolver::<IotaDocument>::new();
+    resolver.attach_iota_handler(DummyClient(history[1].clone()));
+    assert!(resolver.resolve_version(&did, "7").await.is_err());
+  }
+
   #[tokio::test]
   async fn test_did_jwk_resolution() {
     let mut resolver = Resolver::<CoreDocument>::new();
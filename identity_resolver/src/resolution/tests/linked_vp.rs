@@ -0,0 +1,124 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::Url;
+use identity_core::convert::Base;
+use identity_core::convert::BaseEncoding;
+use identity_credential::credential::Jwt;
+use identity_credential::credential::LinkedVerifiablePresentationService;
+use identity_credential::presentation::JwtPresentationOptions;
+use identity_credential::presentation::Presentation;
+use identity_credential::presentation::PresentationBuilder;
+use identity_did::CoreDID;
+use identity_did::DID;
+use identity_document::document::CoreDocument;
+use identity_document::document::DocumentBuilder;
+use identity_document::service::Service;
+use identity_verification::jwk::Jwk;
+use identity_verification::jws::JwsVerifier;
+use identity_verification::jws::SignatureVerificationError;
+use identity_verification::jws::VerificationInput;
+
+use crate::LinkedVp;
+use crate::LinkedVpError;
+use crate::LinkedVpFetcher;
+use crate::Resolver;
+
+struct AcceptAllVerifier;
+
+impl JwsVerifier for AcceptAllVerifier {
+  fn verify(&self, _input: VerificationInput, _public_key: &Jwk) -> Result<(), SignatureVerificationError> {
+    Ok(())
+  }
+}
+
+fn did() -> CoreDID {
+  CoreDID::parse("did:foo:linked").unwrap()
+}
+
+fn document(did: CoreDID) -> CoreDocument {
+  let linked_vp_service = LinkedVerifiablePresentationService::new(
+    did.clone().join("#linked-vp").unwrap(),
+    [
+      Url::parse("https://example.com/unreachable").unwrap(),
+      Url::parse("https://example.com/not-a-jwt").unwrap(),
+      Url::parse("https://example.com/other-holder").unwrap(),
+    ],
+    Object::new(),
+  )
+  .unwrap();
+  let other_service = Service::builder(Object::new())
+    .id(did.clone().join("#other").unwrap())
+    .type_("Other")
+    .service_endpoint(Url::parse("https://example.com/other").unwrap())
+    .build()
+    .unwrap();
+
+  DocumentBuilder::default()
+    .id(did)
+    .service(linked_vp_service.into())
+    .service(other_service)
+    .build()
+    .unwrap()
+}
+
+fn unsigned_presentation_jwt(holder: &str) -> Jwt {
+  let presentation: Presentation<Jwt> = PresentationBuilder::new(Url::parse(holder).unwrap(), Object::new())
+    .build()
+    .unwrap();
+  let claims: String = presentation.serialize_jwt(&JwtPresentationOptions::default()).unwrap();
+  let header: &str = r#"{"alg":"EdDSA","kid":"did:foo:other#key-1"}"#;
+
+  Jwt::new(format!(
+    "{}.{}.c2ln",
+    BaseEncoding::encode(header, Base::Base64Url),
+    BaseEncoding::encode(&claims, Base::Base64Url)
+  ))
+}
+
+async fn resolve_foo(did: CoreDID) -> Result<CoreDocument, std::io::Error> {
+  Ok(document(did))
+}
+
+async fn fetch(url: Url) -> Result<String, std::io::Error> {
+  match url.path() {
+    "/not-a-jwt" => Ok("<html></html>".to_owned()),
+    "/other-holder" => Ok(format!("\"{}\"", unsigned_presentation_jwt("did:foo:other").as_str())),
+    _ => Err(std::io::Error::new(std::io::ErrorKind::NotFound, "not found")),
+  }
+}
+
+#[tokio::test]
+async fn linked_vps_are_discovered_and_checked() {
+  let mut resolver: Resolver<CoreDocument> = Resolver::new();
+  resolver.attach_handler("foo".to_owned(), resolve_foo);
+
+  let linked_vps: Vec<LinkedVp> = LinkedVpFetcher::new(fetch, AcceptAllVerifier)
+    .fetch(&resolver, &did())
+    .await
+    .unwrap();
+
+  assert_eq!(linked_vps.len(), 3);
+  assert!(linked_vps
+    .iter()
+    .all(|linked_vp| linked_vp.service_id.fragment() == Some("linked-vp")));
+  assert!(matches!(
+    linked_vps[0].presentation,
+    Err(LinkedVpError::FetchError { .. })
+  ));
+  assert!(matches!(
+    linked_vps[1].presentation,
+    Err(LinkedVpError::MalformedPresentation(_))
+  ));
+  assert!(matches!(linked_vps[2].presentation, Err(LinkedVpError::HolderMismatch)));
+}
+
+#[tokio::test]
+async fn unresolvable_dids_fail() {
+  let resolver: Resolver<CoreDocument> = Resolver::new();
+  assert!(LinkedVpFetcher::new(fetch, AcceptAllVerifier)
+    .fetch::<Jwt, _, _, _>(&resolver, &did())
+    .await
+    .is_err());
+}
@@ -2,5 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::resolver::*;
+mod linked_vp;
+mod offline_bundle;
+mod presentation_verifier;
 mod resolution;
 mod send_sync;
+mod verifier_context;
@@ -0,0 +1,170 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Duration;
+use identity_core::common::FixedClock;
+use identity_core::common::Object;
+use identity_core::common::SharedClock;
+use identity_core::common::Timestamp;
+use identity_core::convert::FromJson;
+use identity_core::convert::ToJson;
+use identity_did::CoreDID;
+use identity_document::document::CoreDocument;
+use identity_document::document::DocumentBuilder;
+use identity_document::verifiable::JwsVerificationOptions;
+use identity_verification::jwk::Jwk;
+use identity_verification::jws::JwsVerifier;
+use identity_verification::jws::SignatureVerificationError;
+use identity_verification::jws::SignatureVerificationErrorKind;
+use identity_verification::jws::VerificationInput;
+use identity_verification::jwu::encode_b64;
+use identity_verification::VerificationMethod;
+use serde_json::json;
+use serde_json::Value;
+
+use crate::ErrorCause;
+use crate::OfflineBundleError;
+use crate::ResolutionBundle;
+use crate::Resolver;
+
+const SIGNER_HEADER: &str = r#"{"alg":"EdDSA","kid":"did:foo:signer#key-1"}"#;
+
+/// Accepts a signature iff it equals the signing input, mimicking the signing closure of [`export`].
+struct EchoVerifier;
+
+impl JwsVerifier for EchoVerifier {
+  fn verify(&self, input: VerificationInput, _public_key: &Jwk) -> Result<(), SignatureVerificationError> {
+    if input.signing_input == input.decoded_signature {
+      Ok(())
+    } else {
+      Err(SignatureVerificationErrorKind::InvalidSignature.into())
+    }
+  }
+}
+
+fn signer() -> CoreDocument {
+  let did: CoreDID = CoreDID::parse("did:foo:signer").unwrap();
+  let jwk: Jwk = Jwk::from_json_value(json!({
+    "kty": "OKP",
+    "crv": "Ed25519",
+    "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"
+  }))
+  .unwrap();
+  let method: VerificationMethod = VerificationMethod::new_from_jwk(did.clone(), jwk, Some("key-1")).unwrap();
+
+  DocumentBuilder::default().id(did).method(method).build().unwrap()
+}
+
+fn install(bundle: ResolutionBundle, now: Timestamp) -> Result<Resolver, OfflineBundleError> {
+  Resolver::with_offline_bundle(
+    bundle,
+    &signer(),
+    &EchoVerifier,
+    &JwsVerificationOptions::default(),
+    SharedClock::new(FixedClock::new(now)),
+  )
+}
+
+fn created() -> Timestamp {
+  Timestamp::from_unix(1_700_000_000).unwrap()
+}
+
+fn expires() -> Timestamp {
+  created().checked_add(Duration::hours(1)).unwrap()
+}
+
+async fn export() -> ResolutionBundle {
+  let mut resolver: Resolver = Resolver::new();
+  resolver.attach_handler("foo".to_owned(), |did: CoreDID| async move {
+    Ok::<_, std::io::Error>(DocumentBuilder::default().id(did).build().unwrap())
+  });
+
+  let dids: [CoreDID; 2] = [
+    CoreDID::parse("did:foo:1234").unwrap(),
+    CoreDID::parse("did:foo:5678").unwrap(),
+  ];
+  resolver
+    .export_bundle(
+      &dids,
+      expires(),
+      SharedClock::new(FixedClock::new(created())),
+      |input: Vec<u8>| async move {
+        let header: String = encode_b64(SIGNER_HEADER);
+        let signing_input: String = format!("{header}.{}", encode_b64(input));
+        Ok::<_, std::io::Error>(format!("{header}..{}", encode_b64(signing_input)))
+      },
+    )
+    .await
+    .unwrap()
+}
+
+fn bundle_error(cause: ErrorCause) -> OfflineBundleError {
+  match cause {
+    ErrorCause::HandlerError { source } => *source.downcast::<OfflineBundleError>().unwrap(),
+    cause => panic!("unexpected error cause: {cause}"),
+  }
+}
+
+#[tokio::test]
+async fn offline_resolver_serves_bundled_documents() {
+  let bundle: ResolutionBundle = export().await;
+  assert_eq!(bundle.documents().len(), 2);
+  assert_eq!(bundle.metadata().len(), 2);
+  assert_eq!(bundle.created(), created());
+  assert!(bundle.proof().contains(".."));
+
+  let resolver: Resolver = install(bundle, created()).unwrap();
+  let did: CoreDID = CoreDID::parse("did:foo:1234").unwrap();
+  let document: CoreDocument = resolver.resolve(&did).await.unwrap();
+  assert_eq!(document.id(), &did);
+
+  let missing: CoreDID = CoreDID::parse("did:foo:0000").unwrap();
+  let err = resolver.resolve(&missing).await.unwrap_err().into_error_cause();
+  assert!(matches!(bundle_error(err), OfflineBundleError::NotInBundle(_)));
+
+  let unsupported: CoreDID = CoreDID::parse("did:bar:1234").unwrap();
+  let err = resolver.resolve(&unsupported).await.unwrap_err().into_error_cause();
+  assert!(matches!(err, ErrorCause::UnsupportedMethodError { .. }));
+}
+
+#[tokio::test]
+async fn expired_bundles_are_rejected() {
+  let bundle: ResolutionBundle = export().await;
+  let resolver: Resolver = install(bundle, expires()).unwrap();
+
+  let did: CoreDID = CoreDID::parse("did:foo:1234").unwrap();
+  let err = resolver.resolve(&did).await.unwrap_err().into_error_cause();
+  assert!(matches!(bundle_error(err), OfflineBundleError::Expired { .. }));
+}
+
+#[tokio::test]
+async fn tampered_bundles_are_not_installed() {
+  let bundle: ResolutionBundle = export().await;
+
+  let mut json: Value = bundle.to_json_value().unwrap();
+  json["expires"] = Value::String(created().checked_add(Duration::days(365)).unwrap().to_rfc3339());
+  let tampered: ResolutionBundle = ResolutionBundle::from_json_value(json.clone()).unwrap();
+  assert!(matches!(
+    install(tampered, created()),
+    Err(OfflineBundleError::InvalidProof(_))
+  ));
+
+  json["expires"] = Value::String(expires().to_rfc3339());
+  json["metadata"]["did:foo:1234"]["deactivated"] = Value::Bool(true);
+  let tampered: ResolutionBundle = ResolutionBundle::from_json_value(json).unwrap();
+  assert!(matches!(
+    install(tampered, created()),
+    Err(OfflineBundleError::InvalidProof(_))
+  ));
+}
+
+#[tokio::test]
+async fn bundles_verify_regardless_of_the_document_representation() {
+  let bundle: ResolutionBundle = export().await;
+
+  // Generic objects do not serialize their members in the order `CoreDocument` does.
+  let untyped: ResolutionBundle<Object> = ResolutionBundle::from_json(&bundle.to_json().unwrap()).unwrap();
+  untyped
+    .verify(&signer(), &EchoVerifier, &JwsVerificationOptions::default())
+    .unwrap();
+}
@@ -0,0 +1,203 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use futures::StreamExt;
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_core::convert::Base;
+use identity_core::convert::BaseEncoding;
+use identity_core::convert::FromJson;
+use identity_credential::credential::Credential;
+use identity_credential::credential::CredentialBuilder;
+use identity_credential::credential::Jwt;
+use identity_credential::credential::Subject;
+use identity_credential::presentation::JwtPresentationOptions;
+use identity_credential::presentation::Presentation;
+use identity_credential::presentation::PresentationBuilder;
+use identity_credential::validator::DecodedJwtPresentation;
+use identity_credential::validator::FailFast;
+use identity_credential::validator::JwtCredentialValidationOptions;
+use identity_credential::validator::JwtPresentationValidationOptions;
+use identity_did::CoreDID;
+use identity_document::document::CoreDocument;
+use identity_document::document::DocumentBuilder;
+use identity_verification::jwk::Jwk;
+use identity_verification::jws::JwsVerifier;
+use identity_verification::jws::SignatureVerificationError;
+use identity_verification::jws::VerificationInput;
+use identity_verification::VerificationMethod;
+use serde_json::json;
+
+use super::super::commands::SendSyncCommand;
+use crate::CredentialOutcome;
+use crate::IncrementalPresentationVerifier;
+use crate::Resolver;
+use crate::VerifierContext;
+use crate::VerifierContextError;
+
+const HOLDER: &str = "did:foo:holder";
+
+struct AcceptAllVerifier;
+
+impl JwsVerifier for AcceptAllVerifier {
+  fn verify(&self, _input: VerificationInput, _public_key: &Jwk) -> Result<(), SignatureVerificationError> {
+    Ok(())
+  }
+}
+
+/// Returns a [`VerifierContext`] resolving every `did:foo` DID to a document with a `#key-1` method, counting the
+/// resolutions in `resolutions`.
+fn context(resolutions: Arc<AtomicUsize>) -> VerifierContext {
+  let mut resolver: Resolver = Resolver::new();
+  resolver.attach_handler("foo".to_owned(), move |did: CoreDID| {
+    let resolutions = resolutions.clone();
+    async move {
+      resolutions.fetch_add(1, Ordering::SeqCst);
+      let jwk: Jwk = Jwk::from_json_value(json!({
+        "kty": "OKP",
+        "crv": "Ed25519",
+        "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"
+      }))
+      .unwrap();
+      let method: VerificationMethod = VerificationMethod::new_from_jwk(did.clone(), jwk, Some("key-1")).unwrap();
+      Ok::<_, std::io::Error>(DocumentBuilder::default().id(did).method(method).build().unwrap())
+    }
+  });
+
+  VerifierContext::new(resolver)
+}
+
+/// Returns an unsigned compact JWS over `claims` by the `#key-1` method of `signer`.
+fn unsigned_jwt(signer: &str, claims: &str) -> Jwt {
+  let header: String = format!(r#"{{"alg":"EdDSA","kid":"{signer}#key-1"}}"#);
+
+  Jwt::new(format!(
+    "{}.{}.c2ln",
+    BaseEncoding::encode(&header, Base::Base64Url),
+    BaseEncoding::encode(claims, Base::Base64Url)
+  ))
+}
+
+fn credential(issuer: &str, expiration_date: Option<Timestamp>) -> Jwt {
+  let mut builder = CredentialBuilder::default()
+    .issuer(Url::parse(issuer).unwrap())
+    .issuance_date(Timestamp::parse("2020-01-01T00:00:00Z").unwrap())
+    .subject(Subject::with_id(Url::parse(HOLDER).unwrap()));
+  if let Some(expiration_date) = expiration_date {
+    builder = builder.expiration_date(expiration_date);
+  }
+  let credential: Credential = builder.build().unwrap();
+
+  unsigned_jwt(issuer, &credential.serialize_jwt(None).unwrap())
+}
+
+fn presentation(credentials: impl IntoIterator<Item = Jwt>) -> Jwt {
+  let presentation: Presentation<Jwt> = credentials
+    .into_iter()
+    .fold(
+      PresentationBuilder::new(Url::parse(HOLDER).unwrap(), Object::new()),
+      PresentationBuilder::credential,
+    )
+    .build()
+    .unwrap();
+
+  unsigned_jwt(
+    HOLDER,
+    &presentation.serialize_jwt(&JwtPresentationOptions::default()).unwrap(),
+  )
+}
+
+async fn verifier<'c>(
+  context: &'c VerifierContext,
+  presentation: &Jwt,
+) -> (
+  IncrementalPresentationVerifier<'c, AcceptAllVerifier, CoreDocument, SendSyncCommand<CoreDocument>>,
+  DecodedJwtPresentation<Jwt, Object>,
+) {
+  IncrementalPresentationVerifier::from_presentation::<Object>(
+    context,
+    AcceptAllVerifier,
+    presentation,
+    &JwtPresentationValidationOptions::default(),
+    JwtCredentialValidationOptions::default(),
+    FailFast::FirstError,
+  )
+  .await
+  .unwrap()
+}
+
+#[tokio::test]
+async fn valid_presentations_validate_every_credential() {
+  let context = context(Arc::new(AtomicUsize::new(0)));
+  let presentation = presentation((0..3).map(|idx| credential(&format!("did:foo:issuer-{idx}"), None)));
+  let (verifier, decoded) = verifier(&context, &presentation).await;
+  assert_eq!(verifier.holder().as_str(), HOLDER);
+
+  let mut outcomes: Vec<(usize, CredentialOutcome<Object>)> = verifier
+    .validate_iter(decoded.presentation.verifiable_credential, 2)
+    .collect()
+    .await;
+  outcomes.sort_by_key(|(index, _)| *index);
+  assert_eq!(outcomes.len(), 3);
+  for (expected_index, (index, outcome)) in outcomes.into_iter().enumerate() {
+    assert_eq!(index, expected_index);
+    let decoded_credential = outcome.unwrap();
+    assert_eq!(
+      decoded_credential.credential.issuer.url().as_str(),
+      format!("did:foo:issuer-{index}")
+    );
+  }
+}
+
+#[tokio::test]
+async fn failing_credentials_are_reported_individually() {
+  let context = context(Arc::new(AtomicUsize::new(0)));
+  let expired: Timestamp = Timestamp::parse("2021-01-01T00:00:00Z").unwrap();
+  let presentation = presentation([
+    credential("did:foo:issuer-0", None),
+    credential("did:foo:issuer-1", Some(expired)),
+    credential("did:foo:issuer-2", None),
+  ]);
+  let (verifier, decoded) = verifier(&context, &presentation).await;
+
+  let mut outcomes: Vec<(usize, CredentialOutcome<Object>)> = verifier
+    .validate_iter(decoded.presentation.verifiable_credential, 3)
+    .collect()
+    .await;
+  outcomes.sort_by_key(|(index, _)| *index);
+  assert!(outcomes[0].1.is_ok());
+  assert!(matches!(outcomes[1].1, Err(VerifierContextError::InvalidCredential(_))));
+  assert!(outcomes[2].1.is_ok());
+}
+
+#[tokio::test]
+async fn dropping_the_stream_stops_the_validation() {
+  const CREDENTIALS: usize = 10;
+  let resolutions = Arc::new(AtomicUsize::new(0));
+  let context = context(resolutions.clone());
+  let presentation = presentation((0..CREDENTIALS).map(|idx| credential(&format!("did:foo:issuer-{idx}"), None)));
+  let (verifier, decoded) = verifier(&context, &presentation).await;
+  // The holder's DID document was resolved to validate the presentation.
+  assert_eq!(resolutions.load(Ordering::SeqCst), 1);
+
+  let taken = AtomicUsize::new(0);
+  let credentials = decoded.presentation.verifiable_credential.into_iter().inspect(|_| {
+    taken.fetch_add(1, Ordering::SeqCst);
+  });
+  {
+    let mut outcomes = std::pin::pin!(verifier.validate_iter::<_, Object>(credentials, 1));
+    let (index, outcome) = outcomes.next().await.unwrap();
+    assert_eq!(index, 0);
+    assert!(outcome.is_ok());
+  }
+
+  // The credentials that were not taken before the stream was dropped were never validated.
+  let taken: usize = taken.load(Ordering::SeqCst);
+  assert!(taken < CREDENTIALS);
+  assert!(resolutions.load(Ordering::SeqCst) <= 1 + taken);
+}
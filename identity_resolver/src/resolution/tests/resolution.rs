@@ -5,7 +5,12 @@ use std::collections::HashMap;
 use std::error::Error;
 use std::fmt::Debug;
 use std::str::FromStr;
+use std::sync::Mutex;
+use std::sync::OnceLock;
 
+use identity_core::metrics;
+use identity_core::metrics::Label;
+use identity_core::metrics::MetricsRecorder;
 use identity_did::BaseDIDUrl;
 use identity_did::CoreDID;
 use identity_did::Error as DIDError;
@@ -280,3 +285,39 @@ async fn resolve_multiple() {
   assert_eq!(resolved_dids.len(), 1);
   assert_eq!(resolved_dids.get(&did_1).unwrap().id(), &did_1);
 }
+
+#[derive(Debug, Default)]
+struct MethodLabels(Mutex<Vec<String>>);
+
+impl MetricsRecorder for &'static MethodLabels {
+  fn increment_counter(&self, _name: &'static str, labels: &[Label<'_>]) {
+    if let Some((_, method)) = labels.iter().find(|(key, _)| *key == "method") {
+      self.0.lock().unwrap().push(method.to_string());
+    }
+  }
+
+  fn record_histogram(&self, _name: &'static str, _labels: &[Label<'_>], _value: f64) {}
+}
+
+#[tokio::test]
+async fn resolution_metrics_only_label_known_methods() {
+  static LABELS: OnceLock<MethodLabels> = OnceLock::new();
+  let labels: &'static MethodLabels = LABELS.get_or_init(MethodLabels::default);
+  metrics::set_metrics_recorder(labels).unwrap();
+
+  let mut resolver: Resolver<CoreDocument> = Resolver::new();
+  resolver.attach_handler("known".to_owned(), mock_handler);
+  resolver
+    .resolve(&CoreDID::parse("did:known:1234").unwrap())
+    .await
+    .unwrap();
+  resolver
+    .resolve(&CoreDID::parse("did:unknown:1234").unwrap())
+    .await
+    .unwrap_err();
+
+  let labels = labels.0.lock().unwrap();
+  assert!(labels.iter().any(|method| method == "known"));
+  assert!(labels.iter().any(|method| method == metrics::OTHER_METHOD));
+  assert!(labels.iter().all(|method| method != "unknown"));
+}
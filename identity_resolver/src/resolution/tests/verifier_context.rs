@@ -0,0 +1,101 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::atomic::AtomicI64;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use identity_core::common::Clock;
+use identity_core::common::Duration;
+use identity_core::common::Timestamp;
+use identity_did::CoreDID;
+use identity_document::document::CoreDocument;
+use identity_document::document::DocumentBuilder;
+
+use crate::Resolver;
+use crate::VerifierContext;
+
+const START: i64 = 1_700_000_000;
+
+/// A [`Clock`] returning the unix timestamp stored in an [`AtomicI64`].
+#[derive(Debug)]
+struct TestClock(Arc<AtomicI64>);
+
+impl Clock for TestClock {
+  fn now(&self) -> Timestamp {
+    Timestamp::from_unix(self.0.load(Ordering::SeqCst)).unwrap()
+  }
+}
+
+fn counting_context(resolutions: Arc<AtomicUsize>, now: Arc<AtomicI64>) -> VerifierContext {
+  let mut resolver: Resolver = Resolver::new();
+  resolver.attach_handler("foo".to_owned(), move |did: CoreDID| {
+    let resolutions = resolutions.clone();
+    async move {
+      resolutions.fetch_add(1, Ordering::SeqCst);
+      Ok::<_, std::io::Error>(DocumentBuilder::default().id(did).build().unwrap())
+    }
+  });
+
+  VerifierContext::new(resolver)
+    .document_ttl(Duration::minutes(5))
+    .clock(TestClock(now))
+}
+
+#[tokio::test]
+async fn cached_documents_are_reused_until_they_expire() {
+  let resolutions = Arc::new(AtomicUsize::new(0));
+  let now = Arc::new(AtomicI64::new(START));
+  let context = counting_context(resolutions.clone(), now.clone());
+  let did: CoreDID = CoreDID::parse("did:foo:1234").unwrap();
+
+  let first: CoreDocument = context.resolve(&did).await.unwrap();
+  let second: CoreDocument = context.resolve(&did).await.unwrap();
+  assert_eq!(first, second);
+  assert_eq!(resolutions.load(Ordering::SeqCst), 1);
+
+  now.store(START + 5 * 60, Ordering::SeqCst);
+  context.resolve(&did).await.unwrap();
+  assert_eq!(resolutions.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn invalidated_documents_are_resolved_again() {
+  let resolutions = Arc::new(AtomicUsize::new(0));
+  let context = counting_context(resolutions.clone(), Arc::new(AtomicI64::new(START)));
+  let did: CoreDID = CoreDID::parse("did:foo:1234").unwrap();
+
+  context.resolve(&did).await.unwrap();
+  context.invalidate(&did);
+  context.resolve(&did).await.unwrap();
+  assert_eq!(resolutions.load(Ordering::SeqCst), 2);
+
+  context.clear();
+  context.resolve(&did).await.unwrap();
+  assert_eq!(resolutions.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn cache_evicts_the_oldest_documents_when_full() {
+  let resolutions = Arc::new(AtomicUsize::new(0));
+  let now = Arc::new(AtomicI64::new(START));
+  let context = counting_context(resolutions.clone(), now.clone()).max_documents(2);
+  let dids: Vec<CoreDID> = ["did:foo:1", "did:foo:2", "did:foo:3"]
+    .into_iter()
+    .map(|did| CoreDID::parse(did).unwrap())
+    .collect();
+
+  for did in &dids {
+    context.resolve(did).await.unwrap();
+    now.fetch_add(1, Ordering::SeqCst);
+  }
+  assert_eq!(resolutions.load(Ordering::SeqCst), 3);
+
+  // The first document was evicted to make room for the third one.
+  context.resolve(&dids[2]).await.unwrap();
+  context.resolve(&dids[1]).await.unwrap();
+  assert_eq!(resolutions.load(Ordering::SeqCst), 3);
+  context.resolve(&dids[0]).await.unwrap();
+  assert_eq!(resolutions.load(Ordering::SeqCst), 4);
+}
@@ -0,0 +1,352 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::sync::PoisonError;
+
+use identity_core::common::Clock;
+use identity_core::common::Duration;
+use identity_core::common::SharedClock;
+use identity_core::common::Timestamp;
+use identity_credential::credential::Jwt;
+use identity_credential::validator::AnyCredential;
+use identity_credential::validator::AnyCredentialValidationOptions;
+use identity_credential::validator::AnyCredentialValidator;
+use identity_credential::validator::CompoundCredentialValidationError;
+use identity_credential::validator::DecodedCredential;
+use identity_credential::validator::DecodedJwtCredential;
+use identity_credential::validator::FailFast;
+use identity_credential::validator::JwtCredentialValidationOptions;
+use identity_credential::validator::JwtCredentialValidator;
+use identity_credential::validator::JwtCredentialValidatorUtils;
+use identity_credential::validator::StatusCheck;
+use identity_did::CoreDID;
+use identity_did::DID;
+use identity_document::document::CoreDocument;
+use identity_verification::jws::JwsVerifier;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::Result;
+use crate::VerifierContextError;
+
+use super::commands::Command;
+use super::commands::SendSyncCommand;
+use super::Resolver;
+
+struct CachedDocument<DOC> {
+  document: DOC,
+  fetched_at: Timestamp,
+}
+
+/// Bundles a [`Resolver`] with a cache of resolved DID documents and a [`Clock`], to be used by verifiers that
+/// validate many credentials from a limited set of issuers.
+///
+/// Issuers' DID documents are cached for [`document_ttl`](Self::document_ttl), and at most
+/// [`max_documents`](Self::max_documents) of them are cached at once. Revocation status is checked against
+/// the `RevocationBitmap2022` service of the issuer's DID document, which is re-resolved when it was fetched longer
+/// than [`status_ttl`](Self::status_ttl) ago. The clock is used both to expire cache entries and as the validation
+/// time, unless the validation options explicitly set one.
+///
+/// # Example
+///
+/// ```
+/// # use identity_credential::credential::Jwt;
+/// # use identity_credential::validator::FailFast;
+/// # use identity_credential::validator::JwtCredentialValidationOptions;
+/// # use identity_credential::validator::JwtCredentialValidator;
+/// # use identity_core::common::Duration;
+/// # use identity_core::common::Object;
+/// # use identity_resolver::Resolver;
+/// # use identity_resolver::VerifierContext;
+/// # use identity_resolver::VerifierContextError;
+/// # use identity_verification::jws::JwsVerifier;
+/// async fn validate_all(
+///   resolver: Resolver,
+///   verifier: impl JwsVerifier,
+///   credentials: &[Jwt],
+/// ) -> Result<(), VerifierContextError> {
+///   let context = VerifierContext::new(resolver).status_ttl(Duration::seconds(30));
+///   let validator = JwtCredentialValidator::with_signature_verifier(verifier);
+///   let options = JwtCredentialValidationOptions::default();
+///   for credential in credentials {
+///     context
+///       .validate_credential::<_, Object>(&validator, credential, &options, FailFast::FirstError)
+///       .await?;
+///   }
+///   Ok(())
+/// }
+/// ```
+pub struct VerifierContext<DOC = CoreDocument, CMD = SendSyncCommand<DOC>>
+where
+  CMD: for<'r> Command<'r, Result<DOC>>,
+{
+  resolver: Resolver<DOC, CMD>,
+  documents: Mutex<HashMap<String, CachedDocument<DOC>>>,
+  document_ttl: Duration,
+  max_documents: usize,
+  #[cfg_attr(not(feature = "revocation-bitmap"), allow(dead_code))]
+  status_ttl: Duration,
+  clock: SharedClock,
+}
+
+impl<DOC, CMD> VerifierContext<DOC, CMD>
+where
+  CMD: for<'r> Command<'r, Result<DOC>>,
+  DOC: Clone,
+{
+  /// The default time-to-live of cached DID documents.
+  pub const DEFAULT_DOCUMENT_TTL: Duration = Duration::minutes(5);
+  /// The default time-to-live of the revocation status of cached DID documents.
+  pub const DEFAULT_STATUS_TTL: Duration = Duration::minutes(1);
+  /// The default maximum number of cached DID documents.
+  pub const DEFAULT_MAX_DOCUMENTS: usize = 1024;
+
+  /// Creates a new [`VerifierContext`] resolving DID documents with `resolver`.
+  pub fn new(resolver: Resolver<DOC, CMD>) -> Self {
+    Self {
+      resolver,
+      documents: Mutex::new(HashMap::new()),
+      document_ttl: Self::DEFAULT_DOCUMENT_TTL,
+      max_documents: Self::DEFAULT_MAX_DOCUMENTS,
+      status_ttl: Self::DEFAULT_STATUS_TTL,
+      clock: SharedClock::default(),
+    }
+  }
+
+  /// Sets for how long resolved DID documents are cached.
+  pub fn document_ttl(mut self, ttl: Duration) -> Self {
+    self.document_ttl = ttl;
+    self
+  }
+
+  /// Sets the maximum number of cached DID documents.
+  ///
+  /// When the cache is full, expired documents are evicted first, then the ones that were fetched the longest ago.
+  pub fn max_documents(mut self, max_documents: usize) -> Self {
+    self.max_documents = max_documents;
+    self
+  }
+
+  /// Sets for how long the revocation status contained in a cached DID document is considered up-to-date.
+  pub fn status_ttl(mut self, ttl: Duration) -> Self {
+    self.status_ttl = ttl;
+    self
+  }
+
+  /// Sets the [`Clock`] used to expire cache entries and as the default validation time.
+  ///
+  /// Defaults to the [`SystemClock`](identity_core::common::SystemClock).
+  pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+    self.clock = SharedClock::new(clock);
+    self
+  }
+
+  /// Returns a reference to the underlying [`Resolver`].
+  pub fn resolver(&self) -> &Resolver<DOC, CMD> {
+    &self.resolver
+  }
+
+  /// Returns the current time according to this context's [`Clock`].
+  pub fn now(&self) -> Timestamp {
+    self.clock.now()
+  }
+
+  /// Returns the DID document of `did`, resolving it only if it isn't cached or its cache entry has expired.
+  ///
+  /// # Errors
+  /// Fails if `did` must be resolved and its resolution fails.
+  pub async fn resolve<D: DID>(&self, did: &D) -> Result<DOC> {
+    self.resolve_with_max_age(did, self.document_ttl).await
+  }
+
+  /// Removes the DID document of `did` from the cache.
+  pub fn invalidate<D: DID>(&self, did: &D) {
+    self.lock_documents().remove(did.as_str());
+  }
+
+  /// Removes all the DID documents from the cache.
+  pub fn clear(&self) {
+    self.lock_documents().clear();
+  }
+
+  async fn resolve_with_max_age<D: DID>(&self, did: &D, max_age: Duration) -> Result<DOC> {
+    let now: Timestamp = self.now();
+    let cached: Option<DOC> = self
+      .lock_documents()
+      .get(did.as_str())
+      .filter(|cached| {
+        cached
+          .fetched_at
+          .checked_add(max_age)
+          .is_some_and(|expiry| now < expiry)
+      })
+      .map(|cached| cached.document.clone());
+    if let Some(document) = cached {
+      return Ok(document);
+    }
+
+    let document: DOC = self.resolver.resolve(did).await?;
+    if self.max_documents > 0 {
+      let mut documents = self.lock_documents();
+      if !documents.contains_key(did.as_str()) && documents.len() >= self.max_documents {
+        self.evict(&mut documents, now);
+      }
+      documents.insert(
+        did.as_str().to_owned(),
+        CachedDocument {
+          document: document.clone(),
+          fetched_at: now,
+        },
+      );
+    }
+
+    Ok(document)
+  }
+
+  /// Makes room for one more document in `documents`, evicting the expired documents or, if there are none, the one
+  /// fetched the longest ago.
+  fn evict(&self, documents: &mut HashMap<String, CachedDocument<DOC>>, now: Timestamp) {
+    let document_ttl = self.document_ttl;
+    documents.retain(|_, cached| {
+      cached
+        .fetched_at
+        .checked_add(document_ttl)
+        .is_some_and(|expiry| now < expiry)
+    });
+    while documents.len() >= self.max_documents {
+      let Some(oldest) = documents
+        .iter()
+        .min_by_key(|(_, cached)| cached.fetched_at)
+        .map(|(did, _)| did.clone())
+      else {
+        break;
+      };
+      documents.remove(&oldest);
+    }
+  }
+
+  fn lock_documents(&self) -> MutexGuard<'_, HashMap<String, CachedDocument<DOC>>> {
+    // Every operation on the cache leaves it in a consistent state, so it can still be used if a thread panicked
+    // while holding the lock.
+    self.documents.lock().unwrap_or_else(PoisonError::into_inner)
+  }
+}
+
+impl<DOC, CMD> VerifierContext<DOC, CMD>
+where
+  CMD: for<'r> Command<'r, Result<DOC>>,
+  DOC: AsRef<CoreDocument> + Clone,
+{
+  /// Validates `credential` with `validator`, as [`JwtCredentialValidator::validate`] does, using the DID
+  /// document of its issuer as obtained through this context.
+  ///
+  /// Unset [`earliest_expiry_date`](JwtCredentialValidationOptions::earliest_expiry_date) and
+  /// [`latest_issuance_date`](JwtCredentialValidationOptions::latest_issuance_date) default to this context's
+  /// current time. The credential's status is only checked once all the other validations succeeded.
+  ///
+  /// If [`validate_at`](JwtCredentialValidationOptions::validate_at) is set, the dates default to it instead and
+  /// the credential is validated as [`JwtCredentialValidator::validate_with_history`] does, against the issuer's DID
+  /// document as it was at that time, obtained through [`Resolver::resolve_at`]. Historical DID documents are not
+  /// cached.
+  ///
+  /// # Errors
+  /// Fails if the issuer's DID document cannot be resolved or the credential is invalid.
+  pub async fn validate_credential<V, T>(
+    &self,
+    validator: &JwtCredentialValidator<V>,
+    credential: &Jwt,
+    options: &JwtCredentialValidationOptions,
+    fail_fast: FailFast,
+  ) -> std::result::Result<DecodedJwtCredential<T>, VerifierContextError>
+  where
+    V: JwsVerifier,
+    T: Clone + Serialize + DeserializeOwned,
+  {
+    let issuer: CoreDID = JwtCredentialValidatorUtils::extract_issuer_from_jwt(credential).map_err(|err| {
+      VerifierContextError::InvalidCredential(CompoundCredentialValidationError {
+        validation_errors: vec![err],
+      })
+    })?;
+    let document: DOC = self
+      .resolve(&issuer)
+      .await
+      .map_err(VerifierContextError::ResolutionError)?;
+
+    let validation_time: Timestamp = options.validate_at.unwrap_or_else(|| self.now());
+    let mut context_options: JwtCredentialValidationOptions = options.clone();
+    context_options.earliest_expiry_date.get_or_insert(validation_time);
+    context_options.latest_issuance_date.get_or_insert(validation_time);
+    context_options.status = StatusCheck::SkipAll;
+    let decoded: DecodedJwtCredential<T> = match options.validate_at {
+      Some(validate_at) => {
+        let historical_document: DOC = self
+          .resolver
+          .resolve_at(&issuer, validate_at)
+          .await
+          .map_err(VerifierContextError::ResolutionError)?;
+        validator.validate_with_history(credential, &historical_document, &document, &context_options, fail_fast)
+      }
+      None => validator.validate(credential, &document, &context_options, fail_fast),
+    }
+    .map_err(VerifierContextError::InvalidCredential)?;
+
+    #[cfg(feature = "revocation-bitmap")]
+    if options.status != StatusCheck::SkipAll && decoded.credential.credential_status.is_some() {
+      let document: DOC = self
+        .resolve_with_max_age(&issuer, self.status_ttl)
+        .await
+        .map_err(VerifierContextError::ResolutionError)?;
+      JwtCredentialValidatorUtils::check_status(&decoded.credential, std::slice::from_ref(&document), options.status)
+        .map_err(|err| {
+        VerifierContextError::InvalidCredential(CompoundCredentialValidationError {
+          validation_errors: vec![err],
+        })
+      })?;
+    }
+
+    Ok(decoded)
+  }
+  /// Validates `credential`, whatever its format, with `validator`, as [`AnyCredentialValidator::validate`] does,
+  /// using the DID document of its issuer as obtained through this context.
+  ///
+  /// Unset expiry and issuance dates in `options` default to this context's current time.
+  ///
+  /// # Errors
+  /// Fails if the issuer's DID document cannot be resolved or the credential is malformed or invalid.
+  pub async fn validate_any_credential<V, T>(
+    &self,
+    validator: &AnyCredentialValidator<V>,
+    credential: &AnyCredential,
+    options: &AnyCredentialValidationOptions,
+    fail_fast: FailFast,
+  ) -> std::result::Result<DecodedCredential<T>, VerifierContextError>
+  where
+    V: JwsVerifier,
+    T: ToOwned<Owned = T> + Clone + Serialize + DeserializeOwned,
+  {
+    let issuer: CoreDID = credential
+      .extract_issuer()
+      .map_err(VerifierContextError::InvalidAnyCredential)?;
+    let document: DOC = self
+      .resolve(&issuer)
+      .await
+      .map_err(VerifierContextError::ResolutionError)?;
+
+    let now: Timestamp = self.now();
+    let mut context_options: AnyCredentialValidationOptions = options.clone();
+    context_options.jwt.earliest_expiry_date.get_or_insert(now);
+    context_options.jwt.latest_issuance_date.get_or_insert(now);
+    #[cfg(feature = "jpt-bbs-plus")]
+    {
+      context_options.jpt.earliest_expiry_date.get_or_insert(now);
+      context_options.jpt.latest_issuance_date.get_or_insert(now);
+    }
+
+    validator
+      .validate(credential, &document, &context_options, fail_fast)
+      .map_err(VerifierContextError::InvalidAnyCredential)
+  }
+}
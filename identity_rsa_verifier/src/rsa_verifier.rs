@@ -0,0 +1,109 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::ops::Deref;
+
+use identity_verification::jwk::Jwk;
+use identity_verification::jwk::JwkParamsRsa;
+use identity_verification::jws::JwsAlgorithm;
+use identity_verification::jws::JwsVerifier;
+use identity_verification::jws::SignatureVerificationError;
+use identity_verification::jws::SignatureVerificationErrorKind;
+use identity_verification::jws::VerificationInput;
+use identity_verification::jwu;
+use rsa::BigUint;
+use rsa::RsaPublicKey;
+
+/// An implementor of [`JwsVerifier`](identity_verification::jws::JwsVerifier)
+/// that can handle a selection of RSA algorithms.
+///
+/// The following algorithms are supported, if the respective feature on the
+/// crate is activated:
+///
+/// - [`JwsAlgorithm::RS256`](identity_verification::jws::JwsAlgorithm::RS256).
+/// - [`JwsAlgorithm::PS256`](identity_verification::jws::JwsAlgorithm::PS256).
+///
+/// Public keys are checked with [`JwkParamsRsa::validate`] before use, keys with a modulus shorter than
+/// [`JwkParamsRsa::MIN_MODULUS_BITS`] are rejected.
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct RsaVerifier {}
+
+impl RsaVerifier {
+  /// Verify a JWS signature secured with the
+  /// [`JwsAlgorithm::RS256`](identity_verification::jws::JwsAlgorithm::RS256)
+  /// algorithm, i.e. RSASSA-PKCS1-v1_5 using SHA-256.
+  ///
+  /// # Warning
+  ///
+  /// This function does not check whether `alg = RS256` in the protected
+  /// header. Callers are expected to assert this prior to calling the
+  /// function.
+  #[cfg(feature = "rs256")]
+  pub fn verify_rs256(input: &VerificationInput, public_key: &Jwk) -> Result<(), SignatureVerificationError> {
+    use rsa::pkcs1v15::Signature;
+    use rsa::pkcs1v15::VerifyingKey;
+    use rsa::sha2::Sha256;
+
+    let verifying_key: VerifyingKey<Sha256> = VerifyingKey::new(rsa_public_key(public_key)?);
+    let signature: Signature = Signature::try_from(input.decoded_signature.deref()).map_err(|err| {
+      SignatureVerificationError::new(SignatureVerificationErrorKind::InvalidSignature).with_source(err)
+    })?;
+
+    rsa::signature::Verifier::verify(&verifying_key, &input.signing_input, &signature)
+      .map_err(|err| SignatureVerificationError::new(SignatureVerificationErrorKind::InvalidSignature).with_source(err))
+  }
+
+  /// Verify a JWS signature secured with the
+  /// [`JwsAlgorithm::PS256`](identity_verification::jws::JwsAlgorithm::PS256)
+  /// algorithm, i.e. RSASSA-PSS using SHA-256 and MGF1 with SHA-256.
+  ///
+  /// # Warning
+  ///
+  /// This function does not check whether `alg = PS256` in the protected
+  /// header. Callers are expected to assert this prior to calling the
+  /// function.
+  #[cfg(feature = "ps256")]
+  pub fn verify_ps256(input: &VerificationInput, public_key: &Jwk) -> Result<(), SignatureVerificationError> {
+    use rsa::pss::Signature;
+    use rsa::pss::VerifyingKey;
+    use rsa::sha2::Sha256;
+
+    let verifying_key: VerifyingKey<Sha256> = VerifyingKey::new(rsa_public_key(public_key)?);
+    let signature: Signature = Signature::try_from(input.decoded_signature.deref()).map_err(|err| {
+      SignatureVerificationError::new(SignatureVerificationErrorKind::InvalidSignature).with_source(err)
+    })?;
+
+    rsa::signature::Verifier::verify(&verifying_key, &input.signing_input, &signature)
+      .map_err(|err| SignatureVerificationError::new(SignatureVerificationErrorKind::InvalidSignature).with_source(err))
+  }
+}
+
+impl JwsVerifier for RsaVerifier {
+  fn verify(&self, input: VerificationInput, public_key: &Jwk) -> Result<(), SignatureVerificationError> {
+    match input.alg {
+      #[cfg(feature = "rs256")]
+      JwsAlgorithm::RS256 => Self::verify_rs256(&input, public_key),
+      #[cfg(feature = "ps256")]
+      JwsAlgorithm::PS256 => Self::verify_ps256(&input, public_key),
+      _ => Err(SignatureVerificationErrorKind::UnsupportedAlg.into()),
+    }
+  }
+}
+
+/// Obtains the RSA public key of `jwk`, after checking its parameters.
+#[cfg_attr(not(any(feature = "rs256", feature = "ps256")), allow(dead_code))]
+fn rsa_public_key(jwk: &Jwk) -> Result<RsaPublicKey, SignatureVerificationError> {
+  let params: &JwkParamsRsa = jwk
+    .try_rsa_params()
+    .map_err(|_| SignatureVerificationErrorKind::UnsupportedKeyType)?;
+  let key_decoding_failure =
+    |err| SignatureVerificationError::new(SignatureVerificationErrorKind::KeyDecodingFailure).with_source(err);
+
+  params.validate().map_err(key_decoding_failure)?;
+  let n: Vec<u8> = jwu::decode_b64(&params.n).map_err(key_decoding_failure)?;
+  let e: Vec<u8> = jwu::decode_b64(&params.e).map_err(key_decoding_failure)?;
+
+  RsaPublicKey::new(BigUint::from_bytes_be(&n), BigUint::from_bytes_be(&e))
+    .map_err(|err| SignatureVerificationError::new(SignatureVerificationErrorKind::KeyDecodingFailure).with_source(err))
+}
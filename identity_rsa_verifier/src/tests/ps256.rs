@@ -0,0 +1,63 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_verification::jose::error::Error;
+use identity_verification::jwk::Jwk;
+use identity_verification::jws;
+use identity_verification::jws::JwsHeader;
+use identity_verification::jws::SignatureVerificationErrorKind;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rsa::pss::BlindedSigningKey;
+use rsa::sha2::Sha256;
+use rsa::signature::SignatureEncoding;
+
+use super::expand_rsa_jwk;
+use super::RFC7515_PRIVATE_KEY;
+use crate::RsaVerifier;
+
+fn sign(message: &[u8], private_key: &Jwk) -> Vec<u8> {
+  let signing_key: BlindedSigningKey<Sha256> = BlindedSigningKey::new(expand_rsa_jwk(private_key));
+  let mut rng: StdRng = StdRng::seed_from_u64(0);
+  rsa::signature::RandomizedSigner::sign_with_rng(&signing_key, &mut rng, message).to_vec()
+}
+
+#[test]
+fn test_ps256_roundtrip() {
+  let header: JwsHeader = serde_json::from_str(r#"{"alg":"PS256"}"#).unwrap();
+  let claims: &[u8] = br#"{"iss":"joe"}"#;
+  let jwk: Jwk = serde_json::from_str(RFC7515_PRIVATE_KEY).unwrap();
+  let encoder: jws::CompactJwsEncoder<'_> = jws::CompactJwsEncoder::new(claims, &header).unwrap();
+  let signature: Vec<u8> = sign(encoder.signing_input(), &jwk);
+  let encoded: String = encoder.into_jws(&signature);
+
+  let token = jws::Decoder::new()
+    .decode_compact_serialization(encoded.as_bytes(), None)
+    .and_then(|decoded| decoded.verify(&RsaVerifier::default(), &jwk.to_public().unwrap()))
+    .unwrap();
+
+  assert_eq!(token.protected, header);
+  assert_eq!(token.claims, claims);
+}
+
+#[test]
+fn test_ps256_rejects_pkcs1v15_signature() {
+  use rsa::pkcs1v15::SigningKey;
+
+  // A signature produced with RS256 must not verify under PS256.
+  let header: JwsHeader = serde_json::from_str(r#"{"alg":"PS256"}"#).unwrap();
+  let jwk: Jwk = serde_json::from_str(RFC7515_PRIVATE_KEY).unwrap();
+  let encoder: jws::CompactJwsEncoder<'_> = jws::CompactJwsEncoder::new(b"payload", &header).unwrap();
+  let signing_key: SigningKey<Sha256> = SigningKey::new(expand_rsa_jwk(&jwk));
+  let signature: Vec<u8> = rsa::signature::Signer::sign(&signing_key, encoder.signing_input()).to_vec();
+  let encoded: String = encoder.into_jws(&signature);
+
+  let err = jws::Decoder::new()
+    .decode_compact_serialization(encoded.as_bytes(), None)
+    .and_then(|decoded| decoded.verify(&RsaVerifier::default(), &jwk.to_public().unwrap()))
+    .unwrap_err();
+  assert!(matches!(
+    err,
+    Error::SignatureVerificationError(ref err) if matches!(err.kind(), SignatureVerificationErrorKind::InvalidSignature)
+  ));
+}
@@ -0,0 +1,117 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::Display;
+
+use identity_core::common::SingleStructError;
+
+/// Error type for issuance session operations.
+pub type IssuanceSessionError = SingleStructError<IssuanceSessionErrorKind>;
+
+/// The cause of the failed issuance session operation.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum IssuanceSessionErrorKind {
+  /// Indicates that the store is not able to find the requested session.
+  SessionNotFound,
+
+  /// Indicates that a session with the same identifier already exists in the store.
+  SessionAlreadyExists,
+
+  /// Indicates that the stored session was modified concurrently, i.e. its revision differs from the expected one.
+  Conflict,
+
+  /// Indicates that the requested operation is not allowed in the current state of the session.
+  InvalidTransition,
+
+  /// Indicates that the credential offer expired.
+  OfferExpired,
+
+  /// Indicates that the nonce of the holder's proof of possession does not match the one of the offer.
+  NonceMismatch,
+
+  /// Indicates that the transaction code provided by the holder does not match the one of the offer.
+  TxCodeMismatch,
+
+  /// Indicates that the holder key of a proof of possession does not match the one already bound to the session.
+  HolderMismatch,
+
+  /// Indicates that the deferred transaction identifier does not match the one of the session.
+  TransactionIdMismatch,
+
+  /// Indicates that issuing the credential failed.
+  IssuanceFailed,
+
+  /// Indicates that the credential of the session is being issued by another caller.
+  IssuanceInProgress,
+
+  /// Indicates that the session was locked after too many wrong transaction codes were presented.
+  SessionLocked,
+
+  /// Indicates that the store is unavailable for an unpredictable amount of time.
+  Unavailable,
+
+  /// Indicates a failure to serialize or deserialize.
+  SerializationError,
+
+  /// Indicates that something went wrong, but it is unclear whether the reason matches any of the other variants.
+  ///
+  /// When using this variant one may want to attach additional context to the corresponding
+  /// [`IssuanceSessionError`]. See
+  /// [`IssuanceSessionError::with_custom_message`](IssuanceSessionError::with_custom_message()) and
+  /// [`IssuanceSessionError::with_source`](IssuanceSessionError::with_source()).
+  Unspecified,
+}
+
+impl IssuanceSessionErrorKind {
+  /// Returns the string representation of the error.
+  pub const fn as_str(&self) -> &str {
+    match self {
+      Self::SessionNotFound => "issuance session not found in storage",
+      Self::SessionAlreadyExists => "issuance session already exists in storage",
+      Self::Conflict => "issuance session was modified concurrently",
+      Self::InvalidTransition => "operation not allowed in the current state of the issuance session",
+      Self::OfferExpired => "credential offer expired",
+      Self::NonceMismatch => "proof of possession nonce does not match the offer",
+      Self::TxCodeMismatch => "transaction code does not match the offer",
+      Self::HolderMismatch => "holder key does not match the one bound to the issuance session",
+      Self::TransactionIdMismatch => "deferred transaction id does not match the issuance session",
+      Self::IssuanceFailed => "credential issuance failed",
+      Self::IssuanceInProgress => "credential issuance is already in progress",
+      Self::SessionLocked => "issuance session locked after too many failed transaction code attempts",
+      Self::Unavailable => "issuance session storage unavailable",
+      Self::SerializationError => "(de)serialization error",
+      Self::Unspecified => "issuance session operation failed",
+    }
+  }
+}
+
+impl AsRef<str> for IssuanceSessionErrorKind {
+  fn as_ref(&self) -> &str {
+    self.as_str()
+  }
+}
+
+impl Display for IssuanceSessionErrorKind {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.as_str())
+  }
+}
+
+identity_core::impl_error_code!(IssuanceSessionErrorKind {
+  SessionNotFound => 2200,
+  SessionAlreadyExists => 2201,
+  Conflict => 2202,
+  InvalidTransition => 2203,
+  OfferExpired => 2204,
+  NonceMismatch => 2205,
+  TxCodeMismatch => 2206,
+  HolderMismatch => 2207,
+  TransactionIdMismatch => 2208,
+  IssuanceFailed => 2209,
+  Unavailable => 2210,
+  SerializationError => 2211,
+  Unspecified => 2212,
+  IssuanceInProgress => 2213,
+  SessionLocked => 2214,
+});
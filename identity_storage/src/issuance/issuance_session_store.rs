@@ -0,0 +1,50 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+
+use super::IssuanceSession;
+use super::IssuanceSessionError;
+
+/// Result of issuance session store operations.
+pub type IssuanceSessionResult<T> = Result<T, IssuanceSessionError>;
+
+/// Persistent storage for [`IssuanceSession`]s, indexed by their identifier.
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+pub trait IssuanceSessionStore: storage_sub_trait::StorageSendSyncMaybe {
+  /// Insert a new [`IssuanceSession`] into the store.
+  ///
+  /// If a session with the same identifier already exists in the store an error of kind
+  /// [`SessionAlreadyExists`](super::IssuanceSessionErrorKind::SessionAlreadyExists) must be returned immediately
+  /// without altering the state of the store.
+  async fn insert_session(&self, session: IssuanceSession) -> IssuanceSessionResult<()>;
+
+  /// Obtain the [`IssuanceSession`] with the given identifier.
+  async fn get_session(&self, id: &str) -> IssuanceSessionResult<IssuanceSession>;
+
+  /// Replace the stored session with the same identifier as `session`, provided that the revision of the stored
+  /// session is `expected_revision`.
+  ///
+  /// If the stored session has a different revision, an error of kind
+  /// [`Conflict`](super::IssuanceSessionErrorKind::Conflict) must be returned without altering the state of the
+  /// store. The check and the replacement must happen atomically.
+  async fn update_session(&self, session: IssuanceSession, expected_revision: u64) -> IssuanceSessionResult<()>;
+
+  /// Delete the [`IssuanceSession`] with the given identifier from the store.
+  ///
+  /// If no such session is found in the store, an error must be returned.
+  async fn delete_session(&self, id: &str) -> IssuanceSessionResult<()>;
+}
+
+#[cfg(not(feature = "send-sync-storage"))]
+mod storage_sub_trait {
+  pub trait StorageSendSyncMaybe {}
+  impl<S: super::IssuanceSessionStore> StorageSendSyncMaybe for S {}
+}
+
+#[cfg(feature = "send-sync-storage")]
+mod storage_sub_trait {
+  pub trait StorageSendSyncMaybe: Send + Sync {}
+  impl<S: Send + Sync + super::IssuanceSessionStore> StorageSendSyncMaybe for S {}
+}
@@ -0,0 +1,379 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::future::Future;
+
+use identity_core::common::Clock;
+use identity_core::common::SharedClock;
+
+use super::HolderProof;
+use super::IssuanceOutcome;
+use super::IssuanceSession;
+use super::IssuanceSessionError;
+use super::IssuanceSessionErrorKind;
+use super::IssuanceSessionResult;
+use super::IssuanceSessionStore;
+
+/// Drives [`IssuanceSession`]s persisted in an [`IssuanceSessionStore`] through their lifecycle.
+///
+/// Every step loads the session, applies the transition and writes it back only if the session was not modified in
+/// the meantime. Steps are idempotent: retrying a step that already succeeded returns the same result without
+/// modifying the session, and in particular never issues a credential twice.
+///
+/// The issuance step reserves the session, see [`IssuanceSession::reserve`], before the credential is issued, so that
+/// concurrent callers fail with [`IssuanceSessionErrorKind::IssuanceInProgress`] instead of issuing it again.
+#[derive(Debug)]
+pub struct IssuanceSessions<S> {
+  store: S,
+  clock: SharedClock,
+}
+
+impl<S: IssuanceSessionStore> IssuanceSessions<S> {
+  /// Creates a new [`IssuanceSessions`] persisting sessions in `store`.
+  pub fn new(store: S) -> Self {
+    Self {
+      store,
+      clock: SharedClock::default(),
+    }
+  }
+
+  /// Sets the clock providing the time at which offers are checked for expiry when accepting holder proofs.
+  ///
+  /// Defaults to the [`SystemClock`](identity_core::common::SystemClock).
+  pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+    self.clock = SharedClock::new(clock);
+    self
+  }
+
+  /// Returns a reference to the underlying store.
+  pub fn store(&self) -> &S {
+    &self.store
+  }
+
+  /// Opens `session`, which must be in its initial state.
+  pub async fn open(&self, session: IssuanceSession) -> IssuanceSessionResult<()> {
+    if session.revision() != 0 {
+      return Err(IssuanceSessionError::new(IssuanceSessionErrorKind::InvalidTransition));
+    }
+    self.store.insert_session(session).await
+  }
+
+  /// Returns the session with identifier `id`.
+  pub async fn get(&self, id: &str) -> IssuanceSessionResult<IssuanceSession> {
+    self.store.get_session(id).await
+  }
+
+  /// Accepts the holder's proof of possession for session `id`, see [`IssuanceSession::accept_proof`].
+  ///
+  /// Wrong transaction codes are persisted even though this fails, so that the session is locked after
+  /// [`IssuanceSession::MAX_TX_CODE_ATTEMPTS`] attempts.
+  pub async fn accept_proof(&self, id: &str, proof: &HolderProof) -> IssuanceSessionResult<IssuanceSession> {
+    let now = self.clock.now();
+    self.transition(id, |session| session.accept_proof(proof, now)).await
+  }
+
+  /// Performs the issuance step of session `id`, calling `issue` to issue or defer the credential.
+  ///
+  /// If the issuance step already took place, its recorded outcome is returned and `issue` is not called. Otherwise
+  /// the session is reserved and persisted before `issue` is called, so that `issue` is called by at most one caller.
+  /// If `issue` fails, the reservation is released and the issuance step can be retried.
+  ///
+  /// # Errors
+  /// Fails if the holder's proof of possession was not accepted yet, if another caller is currently issuing the
+  /// credential ([`IssuanceSessionErrorKind::IssuanceInProgress`]), or if `issue` fails.
+  pub async fn issue<F, Fut, E>(&self, id: &str, issue: F) -> IssuanceSessionResult<IssuanceOutcome>
+  where
+    F: FnOnce(IssuanceSession) -> Fut,
+    Fut: Future<Output = Result<IssuanceOutcome, E>>,
+    E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+  {
+    // A conflict means another caller either reserved the session, which makes the next `reserve` fail, or recorded
+    // the outcome, which is returned on the next iteration.
+    let reserved = loop {
+      let mut session = self.store.get_session(id).await?;
+      if let Some(outcome) = session.outcome() {
+        return Ok(outcome);
+      }
+      let expected_revision = session.revision();
+      session.reserve()?;
+
+      match self.store.update_session(session.clone(), expected_revision).await {
+        Ok(()) => break session,
+        Err(err) if matches!(err.kind(), IssuanceSessionErrorKind::Conflict) => continue,
+        Err(err) => return Err(err),
+      }
+    };
+
+    let outcome = match issue(reserved.clone()).await {
+      Ok(outcome) => outcome,
+      Err(err) => {
+        let mut released = reserved.clone();
+        released.release()?;
+        self.store.update_session(released, reserved.revision()).await?;
+        return Err(IssuanceSessionError::new(IssuanceSessionErrorKind::IssuanceFailed).with_source(err));
+      }
+    };
+
+    let mut completed = reserved.clone();
+    completed.complete(outcome.clone())?;
+    self.store.update_session(completed, reserved.revision()).await?;
+    Ok(outcome)
+  }
+
+  /// Releases the reservation of session `id` left behind by a caller that stopped during the issuance step, e.g.
+  /// because the process crashed, see [`IssuanceSession::release`].
+  ///
+  /// Only call this once it is certain that the reserving caller no longer issues the credential; otherwise it may be
+  /// issued twice.
+  pub async fn release(&self, id: &str) -> IssuanceSessionResult<IssuanceSession> {
+    self.transition(id, |session| session.release()).await
+  }
+
+  /// Records the `credential` whose issuance was deferred for session `id`, see
+  /// [`IssuanceSession::complete_deferred`].
+  pub async fn complete_deferred(&self, id: &str, credential: &str) -> IssuanceSessionResult<IssuanceSession> {
+    self
+      .transition(id, |session| session.complete_deferred(credential))
+      .await
+  }
+
+  /// Retrieves the deferred credential of session `id` through its `transaction_id`.
+  ///
+  /// Returns `None` if the credential was not issued yet.
+  ///
+  /// # Errors
+  /// Fails if issuance was not deferred under `transaction_id`.
+  pub async fn retrieve_deferred(&self, id: &str, transaction_id: &str) -> IssuanceSessionResult<Option<String>> {
+    let session = self.store.get_session(id).await?;
+    match session.transaction_id() {
+      Some(deferred_id) if deferred_id == transaction_id => (),
+      Some(_) => {
+        return Err(IssuanceSessionError::new(
+          IssuanceSessionErrorKind::TransactionIdMismatch,
+        ))
+      }
+      None => return Err(IssuanceSessionError::new(IssuanceSessionErrorKind::InvalidTransition)),
+    }
+
+    match session.outcome() {
+      Some(IssuanceOutcome::Issued(credential)) => Ok(Some(credential)),
+      _ => Ok(None),
+    }
+  }
+
+  /// Deletes session `id`, e.g. once the holder retrieved its credential.
+  pub async fn close(&self, id: &str) -> IssuanceSessionResult<()> {
+    self.store.delete_session(id).await
+  }
+
+  async fn transition<F>(&self, id: &str, f: F) -> IssuanceSessionResult<IssuanceSession>
+  where
+    F: Fn(&mut IssuanceSession) -> IssuanceSessionResult<()>,
+  {
+    // Each conflict means the session went through a transition, of which there is a finite number, so this
+    // eventually either succeeds, becomes a no-op or fails.
+    loop {
+      let mut session = self.store.get_session(id).await?;
+      let expected_revision = session.revision();
+      // Failed transitions may still modify the session, e.g. to record a wrong transaction code, in which case the
+      // modification is persisted before the error is returned.
+      let result = f(&mut session);
+      if session.revision() == expected_revision {
+        return result.map(|()| session);
+      }
+
+      match self.store.update_session(session.clone(), expected_revision).await {
+        Ok(()) => return result.map(|()| session),
+        Err(err) if matches!(err.kind(), IssuanceSessionErrorKind::Conflict) => continue,
+        Err(err) => return Err(err),
+      }
+    }
+  }
+}
+
+#[cfg(all(test, feature = "memstore"))]
+mod tests {
+  use std::sync::atomic::AtomicUsize;
+  use std::sync::atomic::Ordering;
+
+  use identity_core::common::Duration;
+  use identity_core::common::FixedClock;
+  use identity_core::common::Timestamp;
+  use identity_credential::credential::Confirmation;
+
+  use super::*;
+  use crate::issuance::CredentialOffer;
+  use crate::issuance::IssuanceSessionMemstore;
+
+  async fn sessions() -> IssuanceSessions<IssuanceSessionMemstore> {
+    let sessions = IssuanceSessions::new(IssuanceSessionMemstore::new());
+    sessions
+      .open(IssuanceSession::new(
+        "code",
+        CredentialOffer::new("UniversityDegreeCredential", "nonce"),
+      ))
+      .await
+      .unwrap();
+    sessions
+  }
+
+  fn proof() -> HolderProof {
+    HolderProof::new("nonce", Confirmation::kid("did:example:holder#key-1"))
+  }
+
+  #[tokio::test]
+  async fn retried_issuance_issues_once() {
+    let sessions = sessions().await;
+    let issued = AtomicUsize::new(0);
+    let issue = |session: IssuanceSession| {
+      let issued = &issued;
+      async move {
+        assert!(session.holder().is_some());
+        issued.fetch_add(1, Ordering::SeqCst);
+        Ok::<_, IssuanceSessionError>(IssuanceOutcome::Issued("jwt".to_owned()))
+      }
+    };
+
+    assert!(matches!(
+      sessions.issue("code", issue).await.unwrap_err().kind(),
+      IssuanceSessionErrorKind::InvalidTransition
+    ));
+
+    sessions.accept_proof("code", &proof()).await.unwrap();
+    sessions.accept_proof("code", &proof()).await.unwrap();
+    assert_eq!(
+      sessions.issue("code", issue).await.unwrap(),
+      IssuanceOutcome::Issued("jwt".to_owned())
+    );
+    assert_eq!(
+      sessions.issue("code", issue).await.unwrap(),
+      IssuanceOutcome::Issued("jwt".to_owned())
+    );
+    assert_eq!(issued.load(Ordering::SeqCst), 1);
+    assert_eq!(sessions.get("code").await.unwrap().revision(), 3);
+  }
+
+  #[tokio::test]
+  async fn concurrent_issuance_is_rejected_while_reserved() {
+    let sessions = sessions().await;
+    sessions.accept_proof("code", &proof()).await.unwrap();
+
+    let outcome = sessions
+      .issue("code", |_| async {
+        let err = sessions
+          .issue("code", |_| async {
+            Ok::<_, IssuanceSessionError>(IssuanceOutcome::Issued("other".to_owned()))
+          })
+          .await
+          .unwrap_err();
+        assert!(matches!(err.kind(), IssuanceSessionErrorKind::IssuanceInProgress));
+        Ok::<_, IssuanceSessionError>(IssuanceOutcome::Issued("jwt".to_owned()))
+      })
+      .await
+      .unwrap();
+    assert_eq!(outcome, IssuanceOutcome::Issued("jwt".to_owned()));
+    assert_eq!(
+      sessions.get("code").await.unwrap().outcome(),
+      Some(IssuanceOutcome::Issued("jwt".to_owned()))
+    );
+  }
+
+  #[tokio::test]
+  async fn failed_issuance_releases_the_reservation() {
+    let sessions = sessions().await;
+    sessions.accept_proof("code", &proof()).await.unwrap();
+
+    let err = sessions
+      .issue("code", |_| async {
+        Err::<IssuanceOutcome, _>(IssuanceSessionError::new(IssuanceSessionErrorKind::Unavailable))
+      })
+      .await
+      .unwrap_err();
+    assert!(matches!(err.kind(), IssuanceSessionErrorKind::IssuanceFailed));
+    assert!(sessions.get("code").await.unwrap().outcome().is_none());
+
+    sessions
+      .issue("code", |_| async {
+        Ok::<_, IssuanceSessionError>(IssuanceOutcome::Issued("jwt".to_owned()))
+      })
+      .await
+      .unwrap();
+  }
+
+  #[tokio::test]
+  async fn wrong_tx_codes_are_persisted() {
+    let sessions = IssuanceSessions::new(IssuanceSessionMemstore::new());
+    sessions
+      .open(IssuanceSession::new(
+        "code",
+        CredentialOffer::new("UniversityDegreeCredential", "nonce").tx_code("1234"),
+      ))
+      .await
+      .unwrap();
+
+    for _ in 0..IssuanceSession::MAX_TX_CODE_ATTEMPTS {
+      assert!(matches!(
+        sessions
+          .accept_proof("code", &proof().tx_code("0000"))
+          .await
+          .unwrap_err()
+          .kind(),
+        IssuanceSessionErrorKind::TxCodeMismatch
+      ));
+    }
+    assert!(sessions.get("code").await.unwrap().is_locked());
+    assert!(matches!(
+      sessions
+        .accept_proof("code", &proof().tx_code("1234"))
+        .await
+        .unwrap_err()
+        .kind(),
+      IssuanceSessionErrorKind::SessionLocked
+    ));
+  }
+
+  #[tokio::test]
+  async fn deferred_credentials_are_retrieved_by_transaction_id() {
+    let sessions = sessions().await;
+    sessions.accept_proof("code", &proof()).await.unwrap();
+    sessions
+      .issue("code", |_| async {
+        Ok::<_, IssuanceSessionError>(IssuanceOutcome::Deferred("transaction".to_owned()))
+      })
+      .await
+      .unwrap();
+
+    assert_eq!(sessions.retrieve_deferred("code", "transaction").await.unwrap(), None);
+    assert!(matches!(
+      sessions.retrieve_deferred("code", "other").await.unwrap_err().kind(),
+      IssuanceSessionErrorKind::TransactionIdMismatch
+    ));
+
+    sessions.complete_deferred("code", "jwt").await.unwrap();
+    assert_eq!(
+      sessions.retrieve_deferred("code", "transaction").await.unwrap(),
+      Some("jwt".to_owned())
+    );
+
+    sessions.close("code").await.unwrap();
+    assert_eq!(sessions.store().count().await, 0);
+  }
+
+  #[tokio::test]
+  async fn offer_expiry_is_checked_at_the_clock_time() {
+    let expires_at = Timestamp::parse("2024-01-01T00:00:00Z").unwrap();
+    let offer = || CredentialOffer::new("UniversityDegreeCredential", "nonce").expires_at(expires_at);
+
+    let expired = IssuanceSessions::new(IssuanceSessionMemstore::new())
+      .clock(FixedClock::new(expires_at.checked_add(Duration::seconds(1)).unwrap()));
+    expired.open(IssuanceSession::new("code", offer())).await.unwrap();
+    assert!(matches!(
+      expired.accept_proof("code", &proof()).await.unwrap_err().kind(),
+      IssuanceSessionErrorKind::OfferExpired
+    ));
+
+    let valid = IssuanceSessions::new(IssuanceSessionMemstore::new()).clock(FixedClock::new(expires_at));
+    valid.open(IssuanceSession::new("code", offer())).await.unwrap();
+    valid.accept_proof("code", &proof()).await.unwrap();
+  }
+}
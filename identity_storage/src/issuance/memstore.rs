@@ -0,0 +1,120 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use tokio::sync::RwLockReadGuard;
+use tokio::sync::RwLockWriteGuard;
+
+use crate::key_storage::shared::Shared;
+
+use super::IssuanceSession;
+use super::IssuanceSessionError;
+use super::IssuanceSessionErrorKind;
+use super::IssuanceSessionResult;
+use super::IssuanceSessionStore;
+
+type SessionStore = HashMap<String, IssuanceSession>;
+
+/// An insecure, in-memory [`IssuanceSessionStore`] implementation that serves as an example and may be used in tests.
+#[derive(Debug)]
+pub struct IssuanceSessionMemstore {
+  sessions: Shared<SessionStore>,
+}
+
+impl IssuanceSessionMemstore {
+  /// Creates a new, empty `IssuanceSessionMemstore` instance.
+  pub fn new() -> Self {
+    Self {
+      sessions: Shared::new(HashMap::new()),
+    }
+  }
+
+  /// Returns the number of sessions contained in the [`IssuanceSessionMemstore`].
+  pub async fn count(&self) -> usize {
+    self.sessions.read().await.len()
+  }
+}
+
+impl Default for IssuanceSessionMemstore {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl IssuanceSessionStore for IssuanceSessionMemstore {
+  async fn insert_session(&self, session: IssuanceSession) -> IssuanceSessionResult<()> {
+    let mut sessions: RwLockWriteGuard<'_, SessionStore> = self.sessions.write().await;
+    if sessions.contains_key(session.id()) {
+      return Err(IssuanceSessionError::new(
+        IssuanceSessionErrorKind::SessionAlreadyExists,
+      ));
+    }
+    sessions.insert(session.id().to_owned(), session);
+    Ok(())
+  }
+
+  async fn get_session(&self, id: &str) -> IssuanceSessionResult<IssuanceSession> {
+    let sessions: RwLockReadGuard<'_, SessionStore> = self.sessions.read().await;
+    sessions
+      .get(id)
+      .cloned()
+      .ok_or_else(|| IssuanceSessionError::new(IssuanceSessionErrorKind::SessionNotFound))
+  }
+
+  async fn update_session(&self, session: IssuanceSession, expected_revision: u64) -> IssuanceSessionResult<()> {
+    let mut sessions: RwLockWriteGuard<'_, SessionStore> = self.sessions.write().await;
+    let stored = sessions
+      .get_mut(session.id())
+      .ok_or_else(|| IssuanceSessionError::new(IssuanceSessionErrorKind::SessionNotFound))?;
+    if stored.revision() != expected_revision {
+      return Err(IssuanceSessionError::new(IssuanceSessionErrorKind::Conflict));
+    }
+    *stored = session;
+    Ok(())
+  }
+
+  async fn delete_session(&self, id: &str) -> IssuanceSessionResult<()> {
+    let mut sessions: RwLockWriteGuard<'_, SessionStore> = self.sessions.write().await;
+    sessions
+      .remove(id)
+      .ok_or_else(|| IssuanceSessionError::new(IssuanceSessionErrorKind::SessionNotFound))?;
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::issuance::CredentialOffer;
+
+  #[tokio::test]
+  async fn memstore_operations() {
+    let memstore = IssuanceSessionMemstore::new();
+    let session = IssuanceSession::new("code", CredentialOffer::new("UniversityDegreeCredential", "nonce"));
+
+    memstore.insert_session(session.clone()).await.unwrap();
+    assert!(matches!(
+      memstore.insert_session(session.clone()).await.unwrap_err().kind(),
+      IssuanceSessionErrorKind::SessionAlreadyExists
+    ));
+    assert_eq!(memstore.get_session("code").await.unwrap(), session);
+
+    // Updates are rejected unless the stored revision matches.
+    assert!(matches!(
+      memstore.update_session(session.clone(), 1).await.unwrap_err().kind(),
+      IssuanceSessionErrorKind::Conflict
+    ));
+    memstore.update_session(session.clone(), 0).await.unwrap();
+
+    memstore.delete_session("code").await.unwrap();
+    assert!(matches!(
+      memstore.get_session("code").await.unwrap_err().kind(),
+      IssuanceSessionErrorKind::SessionNotFound
+    ));
+    assert_eq!(memstore.count().await, 0);
+  }
+}
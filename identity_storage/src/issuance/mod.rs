@@ -0,0 +1,26 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Transport-agnostic credential issuance sessions.
+//!
+//! An [`IssuanceSession`] tracks a single credential issuance through its lifecycle: the credential is offered to a
+//! holder, the holder proves possession of the key the credential will be bound to, and the credential is then either
+//! issued right away or deferred and retrieved later on. This module does not depend on any issuance protocol, so
+//! that it can back e.g. OpenID for Verifiable Credential Issuance pre-authorized flows as well as custom frontends.
+//!
+//! Sessions are persisted in an [`IssuanceSessionStore`], and [`IssuanceSessions`] drives them through the lifecycle
+//! with optimistic concurrency control, making every step safe to retry.
+
+mod issuance_session_error;
+mod issuance_session_store;
+mod manager;
+#[cfg(feature = "memstore")]
+mod memstore;
+mod session;
+
+pub use issuance_session_error::*;
+pub use issuance_session_store::*;
+pub use manager::*;
+#[cfg(feature = "memstore")]
+pub use memstore::*;
+pub use session::*;
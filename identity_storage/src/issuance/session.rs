@@ -0,0 +1,476 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Timestamp;
+use identity_credential::credential::Confirmation;
+use identity_verification::jwu::ct_eq;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::IssuanceSessionError;
+use super::IssuanceSessionErrorKind;
+use super::IssuanceSessionResult;
+
+/// The offer of one or more credentials made to a holder, opening an [`IssuanceSession`].
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CredentialOffer {
+  /// The types of the offered credentials.
+  pub credential_types: Vec<String>,
+  /// The nonce the holder must include in its proof of possession.
+  pub nonce: String,
+  /// The transaction code the holder must present, e.g. a PIN sent through a separate channel in pre-authorized
+  /// flows.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub tx_code: Option<String>,
+  /// The time after which the offer can no longer be accepted.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub expires_at: Option<Timestamp>,
+}
+
+impl CredentialOffer {
+  /// Creates a new [`CredentialOffer`] for the given credential type, whose proof of possession must include `nonce`.
+  pub fn new(credential_type: impl Into<String>, nonce: impl Into<String>) -> Self {
+    Self {
+      credential_types: vec![credential_type.into()],
+      nonce: nonce.into(),
+      tx_code: None,
+      expires_at: None,
+    }
+  }
+
+  /// Adds a credential type to the offer.
+  pub fn credential_type(mut self, credential_type: impl Into<String>) -> Self {
+    self.credential_types.push(credential_type.into());
+    self
+  }
+
+  /// Requires the holder to present the given transaction code.
+  pub fn tx_code(mut self, tx_code: impl Into<String>) -> Self {
+    self.tx_code = Some(tx_code.into());
+    self
+  }
+
+  /// Sets the time after which the offer can no longer be accepted.
+  pub fn expires_at(mut self, expires_at: Timestamp) -> Self {
+    self.expires_at = Some(expires_at);
+    self
+  }
+}
+
+/// A holder's proof of possession of the key the issued credential will be bound to.
+///
+/// Verifying the cryptographic proof, e.g. a key-bound JWT, is the responsibility of the issuance frontend since its
+/// format depends on the transport; this only carries its outcome.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct HolderProof {
+  /// The nonce contained in the proof.
+  pub nonce: String,
+  /// The key the holder proved possession of.
+  pub holder: Confirmation,
+  /// The transaction code presented by the holder, if any.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub tx_code: Option<String>,
+}
+
+impl HolderProof {
+  /// Creates a new [`HolderProof`] for the verified proof of possession of `holder` containing `nonce`.
+  pub fn new(nonce: impl Into<String>, holder: Confirmation) -> Self {
+    Self {
+      nonce: nonce.into(),
+      holder,
+      tx_code: None,
+    }
+  }
+
+  /// Sets the transaction code presented by the holder.
+  pub fn tx_code(mut self, tx_code: impl Into<String>) -> Self {
+    self.tx_code = Some(tx_code.into());
+    self
+  }
+}
+
+/// The outcome of the issuance step of an [`IssuanceSession`].
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum IssuanceOutcome {
+  /// The credential, e.g. a JWT or an SD-JWT, was issued.
+  Issued(String),
+  /// Issuance was deferred; the credential can be retrieved later on through the given transaction identifier.
+  Deferred(String),
+}
+
+/// The state of an [`IssuanceSession`].
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(tag = "state", rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum IssuanceState {
+  /// The credential was offered and the session awaits the holder's proof of possession.
+  Offered,
+  /// The holder proved possession of its key and the credential can be issued.
+  ProofAccepted,
+  /// The credential is being issued; the session is reserved by the caller performing the issuance step.
+  Issuing,
+  /// Issuance was deferred, see [`IssuanceSession::transaction_id`].
+  Deferred,
+  /// The credential was issued.
+  Issued {
+    /// The issued credential.
+    credential: String,
+  },
+}
+
+/// A single credential issuance, moving from [`IssuanceState::Offered`] through [`IssuanceState::ProofAccepted`] and
+/// [`IssuanceState::Issuing`] to either [`IssuanceState::Issued`] or [`IssuanceState::Deferred`], and from the latter
+/// to [`IssuanceState::Issued`].
+///
+/// Every transition increments the session's revision, which [`IssuanceSessionStore`](super::IssuanceSessionStore)s
+/// use to detect concurrent modifications. Repeating a transition with the same input is a no-op, so that retried
+/// requests do not fail nor change the session.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssuanceSession {
+  id: String,
+  revision: u64,
+  offer: CredentialOffer,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  holder: Option<Confirmation>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  transaction_id: Option<String>,
+  #[serde(default)]
+  failed_tx_code_attempts: u32,
+  #[serde(flatten)]
+  state: IssuanceState,
+}
+
+impl IssuanceSession {
+  /// The number of wrong transaction codes after which a session is locked.
+  pub const MAX_TX_CODE_ATTEMPTS: u32 = 5;
+
+  /// Opens a new session with identifier `id`, e.g. the pre-authorized code handed to the holder, for `offer`.
+  pub fn new(id: impl Into<String>, offer: CredentialOffer) -> Self {
+    Self {
+      id: id.into(),
+      revision: 0,
+      offer,
+      holder: None,
+      transaction_id: None,
+      failed_tx_code_attempts: 0,
+      state: IssuanceState::Offered,
+    }
+  }
+
+  /// Returns the identifier of the session.
+  pub fn id(&self) -> &str {
+    &self.id
+  }
+
+  /// Returns the revision of the session, incremented by every transition.
+  pub fn revision(&self) -> u64 {
+    self.revision
+  }
+
+  /// Returns the credential offer that opened the session.
+  pub fn offer(&self) -> &CredentialOffer {
+    &self.offer
+  }
+
+  /// Returns the holder key the credential must be bound to, once the holder's proof of possession was accepted.
+  pub fn holder(&self) -> Option<&Confirmation> {
+    self.holder.as_ref()
+  }
+
+  /// Returns the identifier through which the holder retrieves the credential, if its issuance was deferred.
+  pub fn transaction_id(&self) -> Option<&str> {
+    self.transaction_id.as_deref()
+  }
+
+  /// Returns the number of wrong transaction codes presented so far.
+  pub fn failed_tx_code_attempts(&self) -> u32 {
+    self.failed_tx_code_attempts
+  }
+
+  /// Returns whether the session was locked after [`Self::MAX_TX_CODE_ATTEMPTS`] wrong transaction codes.
+  pub fn is_locked(&self) -> bool {
+    self.failed_tx_code_attempts >= Self::MAX_TX_CODE_ATTEMPTS
+  }
+
+  /// Returns the current state of the session.
+  pub fn state(&self) -> &IssuanceState {
+    &self.state
+  }
+
+  /// Returns the outcome of the issuance step, if it already took place.
+  pub fn outcome(&self) -> Option<IssuanceOutcome> {
+    match &self.state {
+      IssuanceState::Issued { credential } => Some(IssuanceOutcome::Issued(credential.clone())),
+      IssuanceState::Deferred => self.transaction_id.clone().map(IssuanceOutcome::Deferred),
+      IssuanceState::Offered | IssuanceState::ProofAccepted | IssuanceState::Issuing => None,
+    }
+  }
+
+  /// Accepts the holder's proof of possession, binding the session to the holder's key.
+  ///
+  /// # Errors
+  /// Fails if the offer expired at `now`, if the nonce or transaction code of `proof` do not match the offer, or if
+  /// the session was already bound to a different key.
+  ///
+  /// Transaction codes are compared in constant time. Every wrong transaction code is recorded in the session, which
+  /// must therefore be persisted even if this fails; after [`Self::MAX_TX_CODE_ATTEMPTS`] wrong codes the session is
+  /// locked and every further proof is rejected.
+  pub fn accept_proof(&mut self, proof: &HolderProof, now: Timestamp) -> IssuanceSessionResult<()> {
+    if let Some(holder) = &self.holder {
+      return if holder == &proof.holder {
+        Ok(())
+      } else {
+        Err(IssuanceSessionError::new(IssuanceSessionErrorKind::HolderMismatch))
+      };
+    }
+    if !matches!(self.state, IssuanceState::Offered) {
+      return Err(IssuanceSessionError::new(IssuanceSessionErrorKind::InvalidTransition));
+    }
+    if self.is_locked() {
+      return Err(IssuanceSessionError::new(IssuanceSessionErrorKind::SessionLocked));
+    }
+
+    if self.offer.expires_at.is_some_and(|expires_at| now > expires_at) {
+      return Err(IssuanceSessionError::new(IssuanceSessionErrorKind::OfferExpired));
+    }
+    if proof.nonce != self.offer.nonce {
+      return Err(IssuanceSessionError::new(IssuanceSessionErrorKind::NonceMismatch));
+    }
+    if let Some(expected) = &self.offer.tx_code {
+      let matches = proof.tx_code.as_ref().map_or(false, |tx_code| ct_eq(tx_code, expected));
+      if !matches {
+        self.failed_tx_code_attempts += 1;
+        self.revision += 1;
+        return Err(IssuanceSessionError::new(IssuanceSessionErrorKind::TxCodeMismatch));
+      }
+    }
+
+    self.holder = Some(proof.holder.clone());
+    self.transition(IssuanceState::ProofAccepted);
+    Ok(())
+  }
+
+  /// Reserves the session for the issuance step, so that concurrent callers do not issue the credential twice.
+  ///
+  /// # Errors
+  /// Fails with [`IssuanceSessionErrorKind::IssuanceInProgress`] if the session is already reserved, or if the
+  /// holder's proof of possession was not accepted yet or issuance already took place.
+  pub fn reserve(&mut self) -> IssuanceSessionResult<()> {
+    match self.state {
+      IssuanceState::ProofAccepted => {
+        self.transition(IssuanceState::Issuing);
+        Ok(())
+      }
+      IssuanceState::Issuing => Err(IssuanceSessionError::new(IssuanceSessionErrorKind::IssuanceInProgress)),
+      _ => Err(IssuanceSessionError::new(IssuanceSessionErrorKind::InvalidTransition)),
+    }
+  }
+
+  /// Releases the reservation made by [`Self::reserve`] without recording an outcome, e.g. because issuance failed.
+  ///
+  /// # Errors
+  /// Fails if the session is neither reserved nor awaiting issuance.
+  pub fn release(&mut self) -> IssuanceSessionResult<()> {
+    match self.state {
+      IssuanceState::Issuing => {
+        self.transition(IssuanceState::ProofAccepted);
+        Ok(())
+      }
+      IssuanceState::ProofAccepted => Ok(()),
+      _ => Err(IssuanceSessionError::new(IssuanceSessionErrorKind::InvalidTransition)),
+    }
+  }
+
+  /// Records the outcome of the issuance step.
+  ///
+  /// # Errors
+  /// Fails if the holder's proof of possession was not accepted yet, or if a different outcome was already recorded.
+  pub fn complete(&mut self, outcome: IssuanceOutcome) -> IssuanceSessionResult<()> {
+    let state = match (&self.state, outcome) {
+      (IssuanceState::ProofAccepted | IssuanceState::Issuing, IssuanceOutcome::Issued(credential)) => {
+        IssuanceState::Issued { credential }
+      }
+      (IssuanceState::ProofAccepted | IssuanceState::Issuing, IssuanceOutcome::Deferred(transaction_id)) => {
+        self.transaction_id = Some(transaction_id);
+        IssuanceState::Deferred
+      }
+      (IssuanceState::Issued { credential }, IssuanceOutcome::Issued(retried)) if credential == &retried => {
+        return Ok(())
+      }
+      (IssuanceState::Deferred, IssuanceOutcome::Deferred(retried))
+        if self.transaction_id.as_ref() == Some(&retried) =>
+      {
+        return Ok(())
+      }
+      _ => return Err(IssuanceSessionError::new(IssuanceSessionErrorKind::InvalidTransition)),
+    };
+
+    self.transition(state);
+    Ok(())
+  }
+
+  /// Records the credential whose issuance was deferred.
+  ///
+  /// # Errors
+  /// Fails if issuance was not deferred, or if a different credential was already recorded.
+  pub fn complete_deferred(&mut self, credential: impl Into<String>) -> IssuanceSessionResult<()> {
+    let credential = credential.into();
+    match &self.state {
+      IssuanceState::Deferred => (),
+      IssuanceState::Issued { credential: issued } if issued == &credential => return Ok(()),
+      _ => return Err(IssuanceSessionError::new(IssuanceSessionErrorKind::InvalidTransition)),
+    }
+
+    self.transition(IssuanceState::Issued { credential });
+    Ok(())
+  }
+
+  fn transition(&mut self, state: IssuanceState) {
+    self.state = state;
+    self.revision += 1;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn session() -> IssuanceSession {
+    IssuanceSession::new(
+      "pre-authorized-code",
+      CredentialOffer::new("UniversityDegreeCredential", "nonce").tx_code("1234"),
+    )
+  }
+
+  fn proof() -> HolderProof {
+    HolderProof::new("nonce", Confirmation::kid("did:example:holder#key-1")).tx_code("1234")
+  }
+
+  #[test]
+  fn session_goes_through_deferred_issuance() {
+    let mut session = session();
+    assert!(session.complete(IssuanceOutcome::Issued("jwt".to_owned())).is_err());
+
+    session.accept_proof(&proof(), Timestamp::now_utc()).unwrap();
+    assert_eq!(session.state(), &IssuanceState::ProofAccepted);
+    assert_eq!(session.revision(), 1);
+
+    session
+      .complete(IssuanceOutcome::Deferred("transaction".to_owned()))
+      .unwrap();
+    assert_eq!(
+      session.outcome(),
+      Some(IssuanceOutcome::Deferred("transaction".to_owned()))
+    );
+    assert!(session.complete(IssuanceOutcome::Issued("jwt".to_owned())).is_err());
+
+    session.complete_deferred("jwt").unwrap();
+    assert_eq!(session.outcome(), Some(IssuanceOutcome::Issued("jwt".to_owned())));
+    assert_eq!(session.revision(), 3);
+  }
+
+  #[test]
+  fn retried_transitions_are_no_ops() {
+    let mut session = session();
+    session.accept_proof(&proof(), Timestamp::now_utc()).unwrap();
+    session.accept_proof(&proof(), Timestamp::now_utc()).unwrap();
+    session.complete(IssuanceOutcome::Issued("jwt".to_owned())).unwrap();
+    session.complete(IssuanceOutcome::Issued("jwt".to_owned())).unwrap();
+    assert_eq!(session.revision(), 2);
+
+    let other_holder = HolderProof::new("nonce", Confirmation::kid("did:example:other#key-1")).tx_code("1234");
+    assert!(matches!(
+      session
+        .accept_proof(&other_holder, Timestamp::now_utc())
+        .unwrap_err()
+        .kind(),
+      IssuanceSessionErrorKind::HolderMismatch
+    ));
+    assert!(session.complete(IssuanceOutcome::Issued("other".to_owned())).is_err());
+  }
+
+  #[test]
+  fn invalid_proofs_are_rejected() {
+    let now = Timestamp::now_utc();
+
+    let mut session = session();
+    let mut wrong_nonce = proof();
+    wrong_nonce.nonce = "other".to_owned();
+    assert!(matches!(
+      session.accept_proof(&wrong_nonce, now).unwrap_err().kind(),
+      IssuanceSessionErrorKind::NonceMismatch
+    ));
+
+    let mut missing_tx_code = proof();
+    missing_tx_code.tx_code = None;
+    assert!(matches!(
+      session.accept_proof(&missing_tx_code, now).unwrap_err().kind(),
+      IssuanceSessionErrorKind::TxCodeMismatch
+    ));
+
+    let mut expired = IssuanceSession::new(
+      "expired",
+      CredentialOffer::new("UniversityDegreeCredential", "nonce").expires_at(Timestamp::from_unix(0).unwrap()),
+    );
+    assert!(matches!(
+      expired
+        .accept_proof(&HolderProof::new("nonce", proof().holder), now)
+        .unwrap_err()
+        .kind(),
+      IssuanceSessionErrorKind::OfferExpired
+    ));
+    assert_eq!(session.state(), &IssuanceState::Offered);
+  }
+
+  #[test]
+  fn session_is_locked_after_too_many_wrong_tx_codes() {
+    let now = Timestamp::now_utc();
+    let mut session = session();
+    let wrong_tx_code = proof().tx_code("0000");
+
+    for attempt in 1..=IssuanceSession::MAX_TX_CODE_ATTEMPTS {
+      assert!(matches!(
+        session.accept_proof(&wrong_tx_code, now).unwrap_err().kind(),
+        IssuanceSessionErrorKind::TxCodeMismatch
+      ));
+      assert_eq!(session.failed_tx_code_attempts(), attempt);
+    }
+    assert!(session.is_locked());
+    assert!(matches!(
+      session.accept_proof(&proof(), now).unwrap_err().kind(),
+      IssuanceSessionErrorKind::SessionLocked
+    ));
+    assert_eq!(session.state(), &IssuanceState::Offered);
+  }
+
+  #[test]
+  fn reserved_sessions_cannot_be_reserved_again() {
+    let mut session = session();
+    assert!(matches!(
+      session.reserve().unwrap_err().kind(),
+      IssuanceSessionErrorKind::InvalidTransition
+    ));
+
+    session.accept_proof(&proof(), Timestamp::now_utc()).unwrap();
+    session.reserve().unwrap();
+    assert!(matches!(
+      session.reserve().unwrap_err().kind(),
+      IssuanceSessionErrorKind::IssuanceInProgress
+    ));
+
+    session.release().unwrap();
+    assert_eq!(session.state(), &IssuanceState::ProofAccepted);
+    session.reserve().unwrap();
+    session.complete(IssuanceOutcome::Issued("jwt".to_owned())).unwrap();
+    assert!(session.reserve().is_err());
+    assert!(session.release().is_err());
+    assert_eq!(session.revision(), 5);
+  }
+}
@@ -70,3 +70,13 @@ impl Display for KeyIdStorageErrorKind {
     write!(f, "{}", self.as_str())
   }
 }
+
+identity_core::impl_error_code!(KeyIdStorageErrorKind {
+  KeyIdNotFound => 1920,
+  KeyIdAlreadyExists => 1921,
+  Unavailable => 1922,
+  Unauthenticated => 1923,
+  RetryableIOFailure => 1924,
+  SerializationError => 1925,
+  Unspecified => 1926,
+});
@@ -0,0 +1,375 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use crypto::hashes::sha::SHA256;
+use crypto::hashes::sha::SHA256_LEN;
+use identity_core::common::Timestamp;
+use identity_verification::jose::jwk::Jwk;
+use identity_verification::jose::jws::JwsAlgorithm;
+use identity_verification::jwu::encode_b64;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::JwkGenOutput;
+use super::JwkStorage;
+use super::KeyId;
+use super::KeyStorageError;
+use super::KeyStorageErrorKind;
+use super::KeyStorageResult;
+use super::KeyType;
+
+/// A key storage operation recorded by an [`AuditSink`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum AuditOperation {
+  /// A key was generated.
+  Generate,
+  /// An existing key was inserted.
+  Insert,
+  /// Data was signed.
+  Sign,
+  /// A key was deleted.
+  Delete,
+}
+
+/// The outcome of an audited key storage operation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "outcome", rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum AuditOutcome {
+  /// The operation succeeded.
+  Success,
+  /// The operation failed.
+  Failure {
+    /// The description of the error.
+    error: String,
+  },
+}
+
+/// A record of a key storage operation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct AuditEvent {
+  /// The time the operation completed.
+  pub timestamp: Timestamp,
+  /// The operation.
+  pub operation: AuditOperation,
+  /// The identifier of the key the operation used or produced, if known.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub key_id: Option<String>,
+  /// The algorithm of the key.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub alg: Option<String>,
+  /// The base64url-encoded SHA-256 digest of the signed data.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub payload_digest: Option<String>,
+  /// The purpose of the operation, as supplied by the caller.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub purpose: Option<String>,
+  /// The outcome of the operation.
+  #[serde(flatten)]
+  pub outcome: AuditOutcome,
+}
+
+impl AuditEvent {
+  /// Creates a new [`AuditEvent`] for `operation`, timestamped now.
+  pub fn new(operation: AuditOperation, outcome: AuditOutcome) -> Self {
+    Self {
+      timestamp: Timestamp::now_utc(),
+      operation,
+      key_id: None,
+      alg: None,
+      payload_digest: None,
+      purpose: None,
+      outcome,
+    }
+  }
+}
+
+/// A destination for the [`AuditEvent`]s of an [`AuditedJwkStorage`].
+pub trait AuditSink: Send + Sync {
+  /// Records `event`.
+  ///
+  /// An error aborts the audited operation: e.g. the produced signature is not returned.
+  fn record(&self, event: &AuditEvent) -> KeyStorageResult<()>;
+}
+
+impl<T: AuditSink + ?Sized> AuditSink for Arc<T> {
+  fn record(&self, event: &AuditEvent) -> KeyStorageResult<()> {
+    T::record(self, event)
+  }
+}
+
+/// An [`AuditSink`] discarding all events.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopAuditSink;
+
+impl AuditSink for NoopAuditSink {
+  fn record(&self, _event: &AuditEvent) -> KeyStorageResult<()> {
+    Ok(())
+  }
+}
+
+/// A line written by a [`JsonLinesAuditSink`].
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AuditLogEntry {
+  #[serde(flatten)]
+  event: AuditEvent,
+  /// The hash of the previous entry, or an empty string for the first entry.
+  prev_hash: String,
+  /// The hash of this entry, computed over `prev_hash` and the JSON encoding of `event`.
+  hash: String,
+}
+
+impl AuditLogEntry {
+  fn new(event: AuditEvent, prev_hash: String) -> KeyStorageResult<Self> {
+    let hash = Self::hash(&event, &prev_hash)?;
+    Ok(Self { event, prev_hash, hash })
+  }
+
+  fn hash(event: &AuditEvent, prev_hash: &str) -> KeyStorageResult<String> {
+    let mut input = prev_hash.as_bytes().to_vec();
+    input.extend(serde_json::to_vec(event).map_err(serialization_error)?);
+    let mut digest = [0; SHA256_LEN];
+    SHA256(&input, &mut digest);
+
+    Ok(encode_b64(digest))
+  }
+}
+
+/// An [`AuditSink`] appending events to a file as JSON lines.
+///
+/// Every line carries the hash of the previous one, chaining the entries together so that any modification,
+/// insertion or removal of an entry other than the last can be detected with [`JsonLinesAuditSink::verify`].
+#[derive(Debug)]
+pub struct JsonLinesAuditSink {
+  path: PathBuf,
+  state: Mutex<(File, String)>,
+}
+
+impl JsonLinesAuditSink {
+  /// Opens the log at `path` for appending, creating it if it doesn't exist.
+  ///
+  /// # Errors
+  /// Fails if the file cannot be opened or its last entry cannot be read.
+  pub fn open(path: impl AsRef<Path>) -> KeyStorageResult<Self> {
+    let path = path.as_ref().to_owned();
+    let last_hash = if path.exists() {
+      read_entries(&path)?
+        .last()
+        .map(|entry| entry.hash.clone())
+        .unwrap_or_default()
+    } else {
+      String::new()
+    };
+    let file = OpenOptions::new()
+      .create(true)
+      .append(true)
+      .open(&path)
+      .map_err(io_error)?;
+
+    Ok(Self {
+      path,
+      state: Mutex::new((file, last_hash)),
+    })
+  }
+
+  /// Returns the path of the log.
+  pub fn path(&self) -> &Path {
+    &self.path
+  }
+
+  /// Checks the hash chain of the log at `path`, returning the number of its entries.
+  ///
+  /// # Errors
+  /// Fails if the log cannot be read or an entry was tampered with.
+  pub fn verify(path: impl AsRef<Path>) -> KeyStorageResult<usize> {
+    let entries = read_entries(path.as_ref())?;
+    let mut prev_hash = String::new();
+    for (line, entry) in entries.iter().enumerate() {
+      if entry.prev_hash != prev_hash || AuditLogEntry::hash(&entry.event, &entry.prev_hash)? != entry.hash {
+        return Err(
+          KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+            .with_custom_message(format!("audit log entry {} was tampered with", line + 1)),
+        );
+      }
+      prev_hash.clone_from(&entry.hash);
+    }
+
+    Ok(entries.len())
+  }
+}
+
+impl AuditSink for JsonLinesAuditSink {
+  fn record(&self, event: &AuditEvent) -> KeyStorageResult<()> {
+    let mut state = self.state.lock().map_err(|_| {
+      KeyStorageError::new(KeyStorageErrorKind::Unavailable).with_custom_message("audit log lock is poisoned")
+    })?;
+    let (file, last_hash) = &mut *state;
+
+    let entry = AuditLogEntry::new(event.clone(), last_hash.clone())?;
+    let mut line = serde_json::to_vec(&entry).map_err(serialization_error)?;
+    line.push(b'\n');
+    file.write_all(&line).and_then(|_| file.sync_data()).map_err(io_error)?;
+    *last_hash = entry.hash;
+
+    Ok(())
+  }
+}
+
+fn read_entries(path: &Path) -> KeyStorageResult<Vec<AuditLogEntry>> {
+  let file = File::open(path).map_err(io_error)?;
+  BufReader::new(file)
+    .lines()
+    .filter(|line| line.as_ref().map_or(true, |line| !line.trim().is_empty()))
+    .map(|line| {
+      let line = line.map_err(io_error)?;
+      serde_json::from_str(&line).map_err(serialization_error)
+    })
+    .collect()
+}
+
+fn io_error(err: std::io::Error) -> KeyStorageError {
+  KeyStorageError::new(KeyStorageErrorKind::RetryableIOFailure)
+    .with_custom_message("failed to access the audit log")
+    .with_source(err)
+}
+
+fn serialization_error(err: serde_json::Error) -> KeyStorageError {
+  KeyStorageError::new(KeyStorageErrorKind::SerializationError)
+    .with_custom_message("failed to (de)serialize an audit log entry")
+    .with_source(err)
+}
+
+/// A [`JwkStorage`] recording every key generation, insertion, signing and deletion of the wrapped storage in an
+/// [`AuditSink`].
+///
+/// Clones share the wrapped storage and sink.
+#[derive(Debug)]
+pub struct AuditedJwkStorage<K, A = NoopAuditSink> {
+  storage: Arc<K>,
+  sink: Arc<A>,
+  purpose: Option<String>,
+}
+
+impl<K, A> Clone for AuditedJwkStorage<K, A> {
+  fn clone(&self) -> Self {
+    Self {
+      storage: self.storage.clone(),
+      sink: self.sink.clone(),
+      purpose: self.purpose.clone(),
+    }
+  }
+}
+
+impl<K, A> AuditedJwkStorage<K, A> {
+  /// Creates a new [`AuditedJwkStorage`] recording the operations of `storage` in `sink`.
+  pub fn new(storage: K, sink: A) -> Self {
+    Self {
+      storage: Arc::new(storage),
+      sink: Arc::new(sink),
+      purpose: None,
+    }
+  }
+
+  /// Returns a view of this storage recording `purpose` with every operation.
+  pub fn with_purpose(&self, purpose: impl Into<String>) -> Self {
+    Self {
+      purpose: Some(purpose.into()),
+      ..self.clone()
+    }
+  }
+
+  /// Returns the wrapped storage.
+  pub fn storage(&self) -> &K {
+    &self.storage
+  }
+
+  /// Returns the sink events are recorded in.
+  pub fn sink(&self) -> &A {
+    &self.sink
+  }
+}
+
+impl<K, A: AuditSink> AuditedJwkStorage<K, A> {
+  /// Records `event`, returning `result` unless the event cannot be recorded.
+  fn record<T>(&self, mut event: AuditEvent, result: KeyStorageResult<T>) -> KeyStorageResult<T> {
+    event.purpose.clone_from(&self.purpose);
+    self.sink.record(&event)?;
+
+    result
+  }
+}
+
+fn outcome<T>(result: &KeyStorageResult<T>) -> AuditOutcome {
+  match result {
+    Ok(_) => AuditOutcome::Success,
+    Err(err) => AuditOutcome::Failure { error: err.to_string() },
+  }
+}
+
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl<K, A> JwkStorage for AuditedJwkStorage<K, A>
+where
+  K: JwkStorage,
+  A: AuditSink,
+{
+  async fn generate(&self, key_type: KeyType, alg: JwsAlgorithm) -> KeyStorageResult<JwkGenOutput> {
+    let result = self.storage.generate(key_type, alg).await;
+    let mut event = AuditEvent::new(AuditOperation::Generate, outcome(&result));
+    event.key_id = result.as_ref().ok().map(|output| output.key_id.to_string());
+    event.alg = Some(alg.name().to_owned());
+
+    self.record(event, result)
+  }
+
+  async fn insert(&self, jwk: Jwk) -> KeyStorageResult<KeyId> {
+    let alg = jwk.alg().map(ToOwned::to_owned);
+    let result = self.storage.insert(jwk).await;
+    let mut event = AuditEvent::new(AuditOperation::Insert, outcome(&result));
+    event.key_id = result.as_ref().ok().map(ToString::to_string);
+    event.alg = alg;
+
+    self.record(event, result)
+  }
+
+  async fn sign(&self, key_id: &KeyId, data: &[u8], public_key: &Jwk) -> KeyStorageResult<Vec<u8>> {
+    let mut digest = [0; SHA256_LEN];
+    SHA256(data, &mut digest);
+
+    let result = self.storage.sign(key_id, data, public_key).await;
+    let mut event = AuditEvent::new(AuditOperation::Sign, outcome(&result));
+    event.key_id = Some(key_id.to_string());
+    event.alg = public_key.alg().map(ToOwned::to_owned);
+    event.payload_digest = Some(encode_b64(digest));
+
+    self.record(event, result)
+  }
+
+  async fn delete(&self, key_id: &KeyId) -> KeyStorageResult<()> {
+    let result = self.storage.delete(key_id).await;
+    let mut event = AuditEvent::new(AuditOperation::Delete, outcome(&result));
+    event.key_id = Some(key_id.to_string());
+
+    self.record(event, result)
+  }
+
+  async fn exists(&self, key_id: &KeyId) -> KeyStorageResult<bool> {
+    self.storage.exists(key_id).await
+  }
+}
@@ -28,6 +28,15 @@ where
   KeyPair::<BBSplus<S>>::random().map(KeyPair::into_parts)
 }
 
+fn bbs_keypair_from_key_material<S>(
+  key_material: &[u8],
+) -> Result<(BBSplusSecretKey, BBSplusPublicKey), zkryptium::errors::Error>
+where
+  S: BbsCiphersuite,
+{
+  KeyPair::<BBSplus<S>>::generate(key_material, None, None).map(KeyPair::into_parts)
+}
+
 /// Generates a new BBS+ keypair using either `BLS12381-SHA256` or `BLS12381-SHAKE256`.
 pub fn generate_bbs_keypair(alg: ProofAlgorithm) -> KeyStorageResult<(BBSplusSecretKey, BBSplusPublicKey)> {
   match alg {
@@ -38,6 +47,20 @@ pub fn generate_bbs_keypair(alg: ProofAlgorithm) -> KeyStorageResult<(BBSplusSec
   .map_err(|err| KeyStorageError::new(KeyStorageErrorKind::Unspecified).with_source(err))
 }
 
+/// Deterministically derives a BBS+ keypair using either `BLS12381-SHA256` or `BLS12381-SHAKE256` from
+/// `key_material`, which must be at least 32 bytes long.
+pub fn generate_bbs_keypair_from_key_material(
+  alg: ProofAlgorithm,
+  key_material: &[u8],
+) -> KeyStorageResult<(BBSplusSecretKey, BBSplusPublicKey)> {
+  match alg {
+    ProofAlgorithm::BBS => bbs_keypair_from_key_material::<Bls12381Sha256>(key_material),
+    ProofAlgorithm::BBS_SHAKE256 => bbs_keypair_from_key_material::<Bls12381Shake256>(key_material),
+    _ => return Err(KeyStorageErrorKind::UnsupportedProofAlgorithm.into()),
+  }
+  .map_err(|err| KeyStorageError::new(KeyStorageErrorKind::Unspecified).with_source(err))
+}
+
 /// Encodes a private BBS+ key into JWK.
 pub fn encode_bls_jwk(
   private_key: &BBSplusSecretKey,
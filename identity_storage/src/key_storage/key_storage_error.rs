@@ -47,6 +47,12 @@ pub enum KeyStorageErrorKind {
   /// Indicates a failure to serialize or deserialize.
   SerializationError,
 
+  /// Indicates that the key exists, but its usage constraints forbid the requested operation.
+  ///
+  /// The [`KeyUsageViolation`](crate::key_storage::KeyUsageViolation) describing the forbidden operation is attached
+  /// as the source of the corresponding [`KeyStorageError`].
+  KeyUsageNotAllowed,
+
   /// Indicates that something went wrong, but it is unclear whether the reason matches any of the other variants.
   ///
   /// When using this variant one may want to attach additional context to the corresponding [`KeyStorageError`]. See
@@ -69,6 +75,7 @@ impl KeyStorageErrorKind {
       Self::Unspecified => "key storage operation failed",
       Self::RetryableIOFailure => "key storage was unsuccessful because of an I/O failure",
       Self::SerializationError => "(de)serialization error",
+      Self::KeyUsageNotAllowed => "the usage constraints of the key forbid the operation",
     }
   }
 }
@@ -84,3 +91,17 @@ impl Display for KeyStorageErrorKind {
     write!(f, "{}", self.as_str())
   }
 }
+
+identity_core::impl_error_code!(KeyStorageErrorKind {
+  UnsupportedKeyType => 1900,
+  KeyAlgorithmMismatch => 1901,
+  UnsupportedSignatureAlgorithm => 1902,
+  UnsupportedProofAlgorithm => 1903,
+  KeyNotFound => 1904,
+  Unavailable => 1905,
+  Unauthenticated => 1906,
+  RetryableIOFailure => 1907,
+  SerializationError => 1908,
+  Unspecified => 1909,
+  KeyUsageNotAllowed => 1910,
+});
@@ -0,0 +1,291 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+
+use async_trait::async_trait;
+use identity_core::common::Clock;
+use identity_core::common::SharedClock;
+use identity_core::common::Timestamp;
+use identity_verification::jose::jwk::Jwk;
+use identity_verification::jose::jws::JwsAlgorithm;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::JwkGenOutput;
+use super::JwkStorage;
+use super::KeyId;
+use super::KeyStorageError;
+use super::KeyStorageErrorKind;
+use super::KeyStorageResult;
+use super::KeyType;
+
+/// Constraints on the use of a key, enforced by a [`ConstrainedJwkStorage`] every time the key signs.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct KeyUsagePolicy {
+  /// The purposes the key may sign for, e.g. [`KeyUsagePolicy::CREDENTIAL_ISSUANCE`].
+  ///
+  /// The key may sign for any purpose if not set.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub allowed_purposes: Option<Vec<String>>,
+  /// The maximum number of signatures the key may produce.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub max_uses: Option<u64>,
+  /// The time after which the key may no longer sign.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub expires_at: Option<Timestamp>,
+}
+
+impl KeyUsagePolicy {
+  /// The purpose of keys signing credentials.
+  pub const CREDENTIAL_ISSUANCE: &'static str = "credential-issuance";
+  /// The purpose of keys signing presentations.
+  pub const PRESENTATION: &'static str = "presentation";
+  /// The purpose of keys authenticating their controller, e.g. in DID authentication challenges.
+  pub const AUTHENTICATION: &'static str = "authentication";
+
+  /// Creates a new unrestricted [`KeyUsagePolicy`].
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Restricts the key to signing for `purposes` only.
+  pub fn allowed_purposes<P: Into<String>>(mut self, purposes: impl IntoIterator<Item = P>) -> Self {
+    self.allowed_purposes = Some(purposes.into_iter().map(Into::into).collect());
+    self
+  }
+
+  /// Restricts the key to producing at most `max_uses` signatures.
+  pub fn max_uses(mut self, max_uses: u64) -> Self {
+    self.max_uses = Some(max_uses);
+    self
+  }
+
+  /// Restricts the key to signing until `expires_at`.
+  pub fn expires_at(mut self, expires_at: Timestamp) -> Self {
+    self.expires_at = Some(expires_at);
+    self
+  }
+
+  /// Checks that a key, which already produced `uses` signatures, may sign for `purpose` at time `now`.
+  fn check(&self, purpose: Option<&str>, uses: u64, now: Timestamp) -> Result<(), KeyUsageViolation> {
+    if let Some(allowed) = &self.allowed_purposes {
+      if !purpose.map_or(false, |purpose| allowed.iter().any(|allowed| allowed == purpose)) {
+        return Err(KeyUsageViolation::PurposeNotAllowed {
+          purpose: purpose.map(ToOwned::to_owned),
+          allowed: allowed.clone(),
+        });
+      }
+    }
+    if let Some(max_uses) = self.max_uses {
+      if uses >= max_uses {
+        return Err(KeyUsageViolation::UsageLimitReached { max_uses });
+      }
+    }
+    if let Some(expires_at) = self.expires_at {
+      if now > expires_at {
+        return Err(KeyUsageViolation::Expired { expires_at });
+      }
+    }
+
+    Ok(())
+  }
+}
+
+/// The reason a [`ConstrainedJwkStorage`] refused to sign, attached as the source of a [`KeyStorageError`] of kind
+/// [`KeyStorageErrorKind::KeyUsageNotAllowed`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum KeyUsageViolation {
+  /// The key may not sign for the requested purpose.
+  #[error("the key may not sign for purpose {purpose:?}, allowed purposes are {allowed:?}")]
+  PurposeNotAllowed {
+    /// The requested purpose, if any.
+    purpose: Option<String>,
+    /// The purposes the key may sign for.
+    allowed: Vec<String>,
+  },
+  /// The key produced the maximum number of signatures it may produce.
+  #[error("the key reached its limit of {max_uses} signatures")]
+  UsageLimitReached {
+    /// The maximum number of signatures of the key.
+    max_uses: u64,
+  },
+  /// The key expired.
+  #[error("the key expired at {expires_at}")]
+  Expired {
+    /// The expiration time of the key.
+    expires_at: Timestamp,
+  },
+}
+
+impl KeyUsageViolation {
+  /// Returns the [`KeyUsageViolation`] that caused `error`, if any.
+  pub fn from_error(error: &KeyStorageError) -> Option<&Self> {
+    std::error::Error::source(error).and_then(|source| source.downcast_ref())
+  }
+}
+
+impl From<KeyUsageViolation> for KeyStorageError {
+  fn from(violation: KeyUsageViolation) -> Self {
+    KeyStorageError::new(KeyStorageErrorKind::KeyUsageNotAllowed).with_source(violation)
+  }
+}
+
+identity_core::impl_error_code!(KeyUsageViolation {
+  PurposeNotAllowed => 2500,
+  UsageLimitReached => 2501,
+  Expired => 2502,
+});
+
+#[derive(Debug, Clone)]
+struct KeyUsage {
+  policy: KeyUsagePolicy,
+  uses: u64,
+}
+
+/// A [`JwkStorage`] enforcing [`KeyUsagePolicy`]s on the keys of the wrapped storage.
+///
+/// Keys without a policy are unrestricted. The purpose of the signatures is set with
+/// [`ConstrainedJwkStorage::with_purpose`], which returns a view sharing the wrapped storage and the policies.
+#[derive(Debug)]
+pub struct ConstrainedJwkStorage<K> {
+  storage: Arc<K>,
+  usages: Arc<Mutex<HashMap<KeyId, KeyUsage>>>,
+  purpose: Option<String>,
+  clock: SharedClock,
+}
+
+impl<K> Clone for ConstrainedJwkStorage<K> {
+  fn clone(&self) -> Self {
+    Self {
+      storage: self.storage.clone(),
+      usages: self.usages.clone(),
+      purpose: self.purpose.clone(),
+      clock: self.clock.clone(),
+    }
+  }
+}
+
+impl<K> ConstrainedJwkStorage<K> {
+  /// Creates a new [`ConstrainedJwkStorage`] wrapping `storage`, without any policy.
+  pub fn new(storage: K) -> Self {
+    Self {
+      storage: Arc::new(storage),
+      usages: Arc::default(),
+      purpose: None,
+      clock: SharedClock::default(),
+    }
+  }
+
+  /// Sets the clock providing the time at which key expiries are checked.
+  ///
+  /// Defaults to the [`SystemClock`](identity_core::common::SystemClock).
+  pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+    self.clock = SharedClock::new(clock);
+    self
+  }
+
+  /// Returns a view of this storage signing for `purpose`.
+  pub fn with_purpose(&self, purpose: impl Into<String>) -> Self {
+    Self {
+      purpose: Some(purpose.into()),
+      ..self.clone()
+    }
+  }
+
+  /// Returns the wrapped storage.
+  pub fn storage(&self) -> &K {
+    &self.storage
+  }
+
+  /// Sets the policy of the key identified by `key_id`, resetting its count of signatures.
+  pub fn set_policy(&self, key_id: KeyId, policy: KeyUsagePolicy) {
+    self.lock().insert(key_id, KeyUsage { policy, uses: 0 });
+  }
+
+  /// Returns the policy of the key identified by `key_id` and the number of signatures it produced since the policy
+  /// was set.
+  pub fn policy(&self, key_id: &KeyId) -> Option<(KeyUsagePolicy, u64)> {
+    self.lock().get(key_id).map(|usage| (usage.policy.clone(), usage.uses))
+  }
+
+  /// Removes the policy of the key identified by `key_id`, making it unrestricted.
+  pub fn remove_policy(&self, key_id: &KeyId) -> Option<KeyUsagePolicy> {
+    self.lock().remove(key_id).map(|usage| usage.policy)
+  }
+
+  fn lock(&self) -> MutexGuard<'_, HashMap<KeyId, KeyUsage>> {
+    // Counters are updated atomically, a panic while holding the lock cannot corrupt them.
+    self.usages.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+  }
+}
+
+impl<K: JwkStorage> ConstrainedJwkStorage<K> {
+  /// Generates a new key restricted by `policy`.
+  pub async fn generate_with_policy(
+    &self,
+    key_type: KeyType,
+    alg: JwsAlgorithm,
+    policy: KeyUsagePolicy,
+  ) -> KeyStorageResult<JwkGenOutput> {
+    let output = self.storage.generate(key_type, alg).await?;
+    self.set_policy(output.key_id.clone(), policy);
+
+    Ok(output)
+  }
+}
+
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl<K: JwkStorage> JwkStorage for ConstrainedJwkStorage<K> {
+  async fn generate(&self, key_type: KeyType, alg: JwsAlgorithm) -> KeyStorageResult<JwkGenOutput> {
+    self.storage.generate(key_type, alg).await
+  }
+
+  async fn insert(&self, jwk: Jwk) -> KeyStorageResult<KeyId> {
+    self.storage.insert(jwk).await
+  }
+
+  async fn sign(&self, key_id: &KeyId, data: &[u8], public_key: &Jwk) -> KeyStorageResult<Vec<u8>> {
+    // Reserve a use before signing, so that concurrent signatures cannot exceed the limit.
+    let reserved = {
+      let mut usages = self.lock();
+      match usages.get_mut(key_id) {
+        Some(usage) => {
+          usage
+            .policy
+            .check(self.purpose.as_deref(), usage.uses, self.clock.now())?;
+          usage.uses += 1;
+          true
+        }
+        None => false,
+      }
+    };
+
+    let result = self.storage.sign(key_id, data, public_key).await;
+    if result.is_err() && reserved {
+      if let Some(usage) = self.lock().get_mut(key_id) {
+        usage.uses = usage.uses.saturating_sub(1);
+      }
+    }
+
+    result
+  }
+
+  async fn delete(&self, key_id: &KeyId) -> KeyStorageResult<()> {
+    self.storage.delete(key_id).await?;
+    self.lock().remove(key_id);
+
+    Ok(())
+  }
+
+  async fn exists(&self, key_id: &KeyId) -> KeyStorageResult<bool> {
+    self.storage.exists(key_id).await
+  }
+}
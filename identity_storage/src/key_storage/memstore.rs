@@ -5,6 +5,7 @@ use core::fmt::Debug;
 use std::collections::HashMap;
 use std::fmt::Display;
 use std::str::FromStr;
+use std::sync::Mutex;
 
 use async_trait::async_trait;
 use fastcrypto::ed25519::Ed25519KeyPair;
@@ -19,6 +20,8 @@ use identity_verification::jwk::BlsCurve;
 use identity_verification::jwk::FromJwk as _;
 use identity_verification::jwk::ToJwk as _;
 use rand::distributions::DistString;
+use rand::CryptoRng;
+use rand::RngCore;
 use shared::Shared;
 use tokio::sync::RwLockReadGuard;
 use tokio::sync::RwLockWriteGuard;
@@ -34,10 +37,15 @@ use crate::key_storage::JwkStorage;
 /// The map from key ids to JWKs.
 type JwkKeyStore = HashMap<KeyId, Jwk>;
 
+/// A cryptographically secure random number generator.
+trait SecureRng: RngCore + CryptoRng {}
+
+impl<T: RngCore + CryptoRng> SecureRng for T {}
+
 /// An insecure, in-memory [`JwkStorage`] implementation that serves as an example and may be used in tests.
-#[derive(Debug)]
 pub struct JwkMemStore {
   jwk_store: Shared<JwkKeyStore>,
+  rng: Option<Mutex<Box<dyn SecureRng + Send>>>,
 }
 
 impl JwkMemStore {
@@ -45,6 +53,24 @@ impl JwkMemStore {
   pub fn new() -> Self {
     Self {
       jwk_store: Shared::new(HashMap::new()),
+      rng: None,
+    }
+  }
+
+  /// Creates a new, empty `JwkMemStore` instance that draws Ed25519, RSA and BBS+ keys, RSA signatures and key ids
+  /// from `rng`, instead of the thread-local random number generator.
+  ///
+  /// Using a seeded `rng` makes key generation reproducible, which is useful in tests.
+  ///
+  /// Post-quantum keys are not drawn from `rng`: liboqs only supports replacing its random number generator
+  /// process-wide, so `JwkStoragePQ` keys of this store are never reproducible.
+  pub fn new_with_rng<R>(rng: R) -> Self
+  where
+    R: RngCore + CryptoRng + Send + 'static,
+  {
+    Self {
+      jwk_store: Shared::new(HashMap::new()),
+      rng: Some(Mutex::new(Box::new(rng))),
     }
   }
 
@@ -75,8 +101,17 @@ impl JwkStorage for JwkMemStore {
 
     check_key_alg_compatibility(key_type, &alg)?;
 
-    let keypair = match key_type {
-      MemStoreKeyType::Ed25519 => Ed25519KeyPair::generate(&mut rand::thread_rng()),
+    let mut jwk: Jwk = match key_type {
+      MemStoreKeyType::Ed25519 => {
+        let keypair = self.with_rng(|mut rng| Ed25519KeyPair::generate(&mut rng));
+        keypair.to_jwk().map_err(|err| {
+          KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+            .with_custom_message("could not convert `Ed25519KeyPair` to `Jwk`")
+            .with_source(err)
+        })?
+      }
+      #[cfg(feature = "memstore-rsa")]
+      MemStoreKeyType::Rsa => self.with_rng(rsa_keys::generate)?,
       other => {
         return Err(
           KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType)
@@ -85,13 +120,8 @@ impl JwkStorage for JwkMemStore {
       }
     };
 
-    let kid: KeyId = random_key_id();
+    let kid: KeyId = self.random_key_id();
 
-    let mut jwk: Jwk = keypair.to_jwk().map_err(|err| {
-      KeyStorageError::new(KeyStorageErrorKind::Unspecified)
-        .with_custom_message("could not convert `Ed25519KeyPair` to `Jwk`")
-        .with_source(err)
-    })?;
     jwk.set_alg(alg.name());
     jwk.set_kid(jwk.thumbprint_sha256_b64());
     let public_jwk: Jwk = jwk.to_public().expect("should only panic if kty == oct");
@@ -133,7 +163,7 @@ impl JwkStorage for JwkMemStore {
       );
     }
 
-    let key_id: KeyId = random_key_id();
+    let key_id: KeyId = self.random_key_id();
 
     let mut jwk_store: RwLockWriteGuard<'_, JwkKeyStore> = self.jwk_store.write().await;
 
@@ -153,7 +183,7 @@ impl JwkStorage for JwkMemStore {
         JwsAlgorithm::from_str(alg_str).map_err(|_| KeyStorageErrorKind::UnsupportedSignatureAlgorithm)
       })?;
 
-    // Check that `kty` is `Okp` and `crv = Ed25519`.
+    // Check that `kty` is `Okp` and `crv = Ed25519`, or `kty` is `RSA` for the RSA algorithms.
     match alg {
       JwsAlgorithm::EdDSA => {
         let okp_params = public_key.try_okp_params().map_err(|err| {
@@ -170,6 +200,14 @@ impl JwkStorage for JwkMemStore {
           );
         }
       }
+      #[cfg(feature = "memstore-rsa")]
+      JwsAlgorithm::RS256 | JwsAlgorithm::PS256 => {
+        public_key.try_rsa_params().map_err(|err| {
+          KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+            .with_custom_message(format!("expected a Jwk with RSA params in order to sign with {alg}"))
+            .with_source(err)
+        })?;
+      }
       other => {
         return Err(
           KeyStorageError::new(KeyStorageErrorKind::UnsupportedSignatureAlgorithm)
@@ -182,6 +220,10 @@ impl JwkStorage for JwkMemStore {
     let jwk: &Jwk = jwk_store
       .get(key_id)
       .ok_or_else(|| KeyStorageError::new(KeyStorageErrorKind::KeyNotFound))?;
+    #[cfg(feature = "memstore-rsa")]
+    if matches!(alg, JwsAlgorithm::RS256 | JwsAlgorithm::PS256) {
+      return self.with_rng(|rng| rsa_keys::sign(jwk, &alg, data, rng));
+    }
     let secret_key = Ed25519KeyPair::from_jwk(jwk).map_err(|err| {
       KeyStorageError::new(KeyStorageErrorKind::Unspecified)
         .with_custom_message("could not convert `Jwk` to `Ed25519KeyPair`")
@@ -209,6 +251,7 @@ impl JwkStorage for JwkMemStore {
 enum MemStoreKeyType {
   Ed25519,
   BLS12381G2,
+  Rsa,
 }
 
 impl JwkMemStore {
@@ -220,6 +263,12 @@ impl JwkMemStore {
   /// The BLS12381G2 key type
   pub const BLS12381G2_KEY_TYPE: KeyType = KeyType::from_static_str(Self::BLS12381G2_KEY_TYPE_STR);
 
+  const RSA_KEY_TYPE_STR: &'static str = "RSA";
+  /// The RSA key type, whose keys can be used with `RS256` and `PS256`.
+  ///
+  /// Generating keys of this type requires the `memstore-rsa` feature.
+  pub const RSA_KEY_TYPE: KeyType = KeyType::from_static_str(Self::RSA_KEY_TYPE_STR);
+
   const PQ_KEY_TYPE_STR: &'static str = "AKP";
   /// ML-DSA algorithms key types;
   pub const PQ_KEY_TYPE: KeyType = KeyType::from_static_str(Self::PQ_KEY_TYPE_STR);
@@ -230,6 +279,7 @@ impl MemStoreKeyType {
     match self {
       MemStoreKeyType::Ed25519 => JwkMemStore::ED25519_KEY_TYPE_STR,
       MemStoreKeyType::BLS12381G2 => JwkMemStore::BLS12381G2_KEY_TYPE_STR,
+      MemStoreKeyType::Rsa => JwkMemStore::RSA_KEY_TYPE_STR,
     }
   }
 }
@@ -247,6 +297,7 @@ impl TryFrom<&KeyType> for MemStoreKeyType {
     match value.as_str() {
       JwkMemStore::ED25519_KEY_TYPE_STR => Ok(MemStoreKeyType::Ed25519),
       JwkMemStore::BLS12381G2_KEY_TYPE_STR => Ok(MemStoreKeyType::BLS12381G2),
+      JwkMemStore::RSA_KEY_TYPE_STR => Ok(MemStoreKeyType::Rsa),
       _ => Err(KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType)),
     }
   }
@@ -293,6 +344,7 @@ impl TryFrom<&Jwk> for MemStoreKeyType {
           ),
         }
       }
+      JwkType::Rsa => Ok(MemStoreKeyType::Rsa),
       other => Err(
         KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType)
           .with_custom_message(format!("Jwk `kty` {other} not supported")),
@@ -307,15 +359,35 @@ impl Default for JwkMemStore {
   }
 }
 
-/// Generate a random alphanumeric string of len 32.
-fn random_key_id() -> KeyId {
-  KeyId::new(rand::distributions::Alphanumeric.sample_string(&mut rand::thread_rng(), 32))
+impl Debug for JwkMemStore {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("JwkMemStore")
+      .field("jwk_store", &self.jwk_store)
+      .finish_non_exhaustive()
+  }
+}
+
+impl JwkMemStore {
+  /// Calls `f` with the random number generator of this store.
+  fn with_rng<T>(&self, f: impl FnOnce(&mut dyn SecureRng) -> T) -> T {
+    match &self.rng {
+      // A panic while holding the lock cannot leave the generator in an unusable state.
+      Some(rng) => f(&mut **rng.lock().unwrap_or_else(|poisoned| poisoned.into_inner())),
+      None => f(&mut rand::thread_rng()),
+    }
+  }
+
+  /// Generate a random alphanumeric string of len 32.
+  fn random_key_id(&self) -> KeyId {
+    self.with_rng(|mut rng| KeyId::new(rand::distributions::Alphanumeric.sample_string(&mut rng, 32)))
+  }
 }
 
 /// Check that the key type can be used with the algorithm.
 fn check_key_alg_compatibility(key_type: MemStoreKeyType, alg: &JwsAlgorithm) -> KeyStorageResult<()> {
   match (key_type, alg) {
     (MemStoreKeyType::Ed25519, JwsAlgorithm::EdDSA) => Ok(()),
+    (MemStoreKeyType::Rsa, JwsAlgorithm::RS256 | JwsAlgorithm::PS256) => Ok(()),
     (key_type, alg) => Err(
       KeyStorageError::new(crate::key_storage::KeyStorageErrorKind::KeyAlgorithmMismatch)
         .with_custom_message(format!("`cannot use key type `{key_type}` with algorithm `{alg}`")),
@@ -323,6 +395,103 @@ fn check_key_alg_compatibility(key_type: MemStoreKeyType, alg: &JwsAlgorithm) ->
   }
 }
 
+#[cfg(feature = "memstore-rsa")]
+mod rsa_keys {
+  use identity_verification::jose::jwk::Jwk;
+  use identity_verification::jose::jwk::JwkParamsRsa;
+  use identity_verification::jose::jws::JwsAlgorithm;
+  use identity_verification::jwu;
+  use rsa::sha2::Sha256;
+  use rsa::signature::RandomizedSigner;
+  use rsa::signature::SignatureEncoding;
+  use rsa::signature::Signer;
+  use rsa::traits::PrivateKeyParts;
+  use rsa::traits::PublicKeyParts;
+  use rsa::BigUint;
+  use rsa::RsaPrivateKey;
+
+  use super::KeyStorageError;
+  use super::KeyStorageErrorKind;
+  use super::KeyStorageResult;
+  use super::SecureRng;
+
+  /// The size in bits of the modulus of generated keys.
+  const MODULUS_BITS: usize = JwkParamsRsa::MIN_MODULUS_BITS;
+
+  /// Generates a new RSA private key and returns it as a [`Jwk`] with all private key components set.
+  pub(super) fn generate(mut rng: &mut dyn SecureRng) -> KeyStorageResult<Jwk> {
+    let private_key: RsaPrivateKey = RsaPrivateKey::new(&mut rng, MODULUS_BITS).map_err(|err| {
+      KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+        .with_custom_message("RSA key generation failed")
+        .with_source(err)
+    })?;
+    let missing_component =
+      || KeyStorageError::new(KeyStorageErrorKind::Unspecified).with_custom_message("missing RSA key component");
+
+    let encode = |value: &BigUint| jwu::encode_b64(value.to_bytes_be());
+    let mut params: JwkParamsRsa = JwkParamsRsa::new();
+    params.n = encode(private_key.n());
+    params.e = encode(private_key.e());
+    params.d = Some(encode(private_key.d()));
+    params.p = Some(encode(&private_key.primes()[0]));
+    params.q = Some(encode(&private_key.primes()[1]));
+    params.dp = Some(encode(private_key.dp().ok_or_else(missing_component)?));
+    params.dq = Some(encode(private_key.dq().ok_or_else(missing_component)?));
+    params.qi = Some(encode(&private_key.crt_coefficient().ok_or_else(missing_component)?));
+
+    Ok(Jwk::from_params(params))
+  }
+
+  /// Signs `data` with the RSA private key `jwk` according to `alg`, which must be `RS256` or `PS256`.
+  pub(super) fn sign(
+    jwk: &Jwk,
+    alg: &JwsAlgorithm,
+    data: &[u8],
+    mut rng: &mut dyn SecureRng,
+  ) -> KeyStorageResult<Vec<u8>> {
+    let private_key: RsaPrivateKey = expand_private_key(jwk)?;
+
+    match alg {
+      JwsAlgorithm::RS256 => {
+        let signing_key = rsa::pkcs1v15::SigningKey::<Sha256>::new(private_key);
+        Ok(signing_key.sign(data).to_vec())
+      }
+      JwsAlgorithm::PS256 => {
+        let signing_key = rsa::pss::BlindedSigningKey::<Sha256>::new(private_key);
+        Ok(signing_key.sign_with_rng(&mut rng, data).to_vec())
+      }
+      other => Err(
+        KeyStorageError::new(KeyStorageErrorKind::UnsupportedSignatureAlgorithm)
+          .with_custom_message(format!("{other} is not supported")),
+      ),
+    }
+  }
+
+  fn expand_private_key(jwk: &Jwk) -> KeyStorageResult<RsaPrivateKey> {
+    let conversion_error = || {
+      KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+        .with_custom_message("could not convert `Jwk` to `RsaPrivateKey`")
+    };
+    let params: &JwkParamsRsa = jwk
+      .try_rsa_params()
+      .map_err(|err| conversion_error().with_source(err))?;
+    let decode = |value: Option<&str>| -> KeyStorageResult<BigUint> {
+      let value: &str = value.ok_or_else(conversion_error)?;
+      jwu::decode_b64(value)
+        .map(|bytes| BigUint::from_bytes_be(&bytes))
+        .map_err(|err| conversion_error().with_source(err))
+    };
+
+    RsaPrivateKey::from_components(
+      decode(Some(params.n.as_str()))?,
+      decode(Some(params.e.as_str()))?,
+      decode(params.d.as_deref())?,
+      vec![decode(params.p.as_deref())?, decode(params.q.as_deref())?],
+    )
+    .map_err(|err| conversion_error().with_source(err))
+  }
+}
+
 #[cfg(feature = "pqc-liboqs")]
 mod pqc_liboqs {
   use async_trait::async_trait;
@@ -338,7 +507,6 @@ mod pqc_liboqs {
   use tokio::sync::RwLockReadGuard;
   use tokio::sync::RwLockWriteGuard;
 
-  use super::random_key_id;
   use super::JwkKeyStore;
   use super::JwkMemStore;
   use super::KeyId;
@@ -403,7 +571,7 @@ mod pqc_liboqs {
           .with_source(err)
       })?;
 
-      let kid: KeyId = random_key_id();
+      let kid: KeyId = self.random_key_id();
 
       let public = jwu::encode_b64(pk.into_vec());
       let private = jwu::encode_b64(sk.into_vec());
@@ -573,7 +741,7 @@ mod bbs_plus_impl {
 
   use crate::key_storage::bls::encode_bls_jwk;
   use crate::key_storage::bls::expand_bls_jwk;
-  use crate::key_storage::bls::generate_bbs_keypair;
+  use crate::key_storage::bls::generate_bbs_keypair_from_key_material;
   use crate::key_storage::bls::sign_bbs;
   use crate::key_storage::bls::update_bbs_signature;
   use crate::JwkGenOutput;
@@ -589,8 +757,7 @@ mod bbs_plus_impl {
   use identity_verification::jwk::BlsCurve;
   use identity_verification::jwk::Jwk;
   use jsonprooftoken::jpa::algs::ProofAlgorithm;
-
-  use super::random_key_id;
+  use rand::RngCore as _;
 
   /// JwkStorageBbsPlusExt implementation for JwkMemStore
   #[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
@@ -604,10 +771,13 @@ mod bbs_plus_impl {
         );
       }
 
-      let (private_key, public_key) = generate_bbs_keypair(alg)?;
+      // Derive the key from the store's generator, to keep key generation reproducible with a seeded one.
+      let mut key_material = [0; 32];
+      self.with_rng(|rng| rng.fill_bytes(&mut key_material));
+      let (private_key, public_key) = generate_bbs_keypair_from_key_material(alg, &key_material)?;
       let (jwk, public_jwk) = encode_bls_jwk(&private_key, &public_key, alg);
 
-      let kid: KeyId = random_key_id();
+      let kid: KeyId = self.random_key_id();
       let mut jwk_store = self.jwk_store.write().await;
       jwk_store.insert(kid.clone(), jwk);
 
@@ -6,6 +6,8 @@
 //! This module provides the [`JwkStorage`] trait that
 //! abstracts over storages that store JSON Web Keys.
 
+#[cfg(feature = "audit-log")]
+mod audit;
 #[cfg(feature = "jpt-bbs-plus")]
 /// BLS12381 utils.
 pub mod bls;
@@ -18,6 +20,7 @@ mod jwk_storage_pqc;
 mod key_id;
 mod key_storage_error;
 mod key_type;
+mod key_usage;
 #[cfg(feature = "keytool")]
 mod keytool;
 #[cfg(feature = "memstore")]
@@ -28,6 +31,8 @@ pub(crate) mod tests;
 
 /// All modules that should be made available to end-users.
 pub mod public_modules {
+  #[cfg(feature = "audit-log")]
+  pub use super::audit::*;
   pub use super::jwk_gen_output::*;
   pub use super::jwk_storage::*;
   #[cfg(feature = "jpt-bbs-plus")]
@@ -37,6 +42,7 @@ pub mod public_modules {
   pub use super::key_id::*;
   pub use super::key_storage_error::*;
   pub use super::key_type::*;
+  pub use super::key_usage::*;
   #[cfg(feature = "memstore")]
   pub use super::memstore::*;
 }
@@ -0,0 +1,82 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::path::PathBuf;
+
+use identity_verification::jws::JwsAlgorithm;
+
+use super::utils::test_generate_and_sign;
+use crate::key_storage::AuditedJwkStorage;
+use crate::key_storage::JsonLinesAuditSink;
+use crate::key_storage::JwkMemStore;
+use crate::key_storage::JwkStorage;
+use crate::key_storage::KeyId;
+use crate::key_storage::NoopAuditSink;
+
+fn log_path(name: &str) -> PathBuf {
+  let path = std::env::temp_dir().join(format!("identity-audit-{name}-{}.jsonl", std::process::id()));
+  let _ = std::fs::remove_file(&path);
+  path
+}
+
+#[tokio::test]
+async fn generate_and_sign() {
+  let store = AuditedJwkStorage::new(JwkMemStore::new(), NoopAuditSink);
+  test_generate_and_sign(store).await;
+}
+
+#[tokio::test]
+async fn operations_are_logged_in_a_hash_chain() {
+  let path = log_path("chain");
+  let store = AuditedJwkStorage::new(JwkMemStore::new(), JsonLinesAuditSink::open(&path).unwrap());
+  let issuance = store.with_purpose("credential-issuance");
+
+  let output = store
+    .generate(JwkMemStore::ED25519_KEY_TYPE, JwsAlgorithm::EdDSA)
+    .await
+    .unwrap();
+  issuance.sign(&output.key_id, b"payload", &output.jwk).await.unwrap();
+  assert!(store.delete(&KeyId::new("missing")).await.is_err());
+
+  assert_eq!(JsonLinesAuditSink::verify(&path).unwrap(), 3);
+  let log = std::fs::read_to_string(&path).unwrap();
+  let sign_entry: serde_json::Value = serde_json::from_str(log.lines().nth(1).unwrap()).unwrap();
+  assert_eq!(sign_entry["operation"], "sign");
+  assert_eq!(sign_entry["purpose"], "credential-issuance");
+  assert_eq!(sign_entry["keyId"], output.key_id.as_str());
+  assert!(sign_entry["payloadDigest"].is_string());
+  assert!(log.lines().nth(2).unwrap().contains("\"outcome\":\"failure\""));
+
+  // Reopening the log continues the chain.
+  drop(store);
+  drop(issuance);
+  let store = AuditedJwkStorage::new(JwkMemStore::new(), JsonLinesAuditSink::open(&path).unwrap());
+  store
+    .generate(JwkMemStore::ED25519_KEY_TYPE, JwsAlgorithm::EdDSA)
+    .await
+    .unwrap();
+  assert_eq!(JsonLinesAuditSink::verify(&path).unwrap(), 4);
+
+  std::fs::remove_file(path).unwrap();
+}
+
+#[tokio::test]
+async fn tampering_is_detected() {
+  let path = log_path("tampering");
+  let store = AuditedJwkStorage::new(JwkMemStore::new(), JsonLinesAuditSink::open(&path).unwrap());
+  let output = store
+    .generate(JwkMemStore::ED25519_KEY_TYPE, JwsAlgorithm::EdDSA)
+    .await
+    .unwrap();
+  store.sign(&output.key_id, b"payload", &output.jwk).await.unwrap();
+
+  let log = std::fs::read_to_string(&path).unwrap();
+  std::fs::write(
+    &path,
+    log.replacen("\"operation\":\"sign\"", "\"operation\":\"delete\"", 1),
+  )
+  .unwrap();
+  assert!(JsonLinesAuditSink::verify(&path).is_err());
+
+  std::fs::remove_file(path).unwrap();
+}
@@ -0,0 +1,98 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Duration;
+use identity_core::common::FixedClock;
+use identity_core::common::Timestamp;
+use identity_verification::jws::JwsAlgorithm;
+
+use super::utils::test_generate_and_sign;
+use crate::key_storage::ConstrainedJwkStorage;
+use crate::key_storage::JwkMemStore;
+use crate::key_storage::JwkStorage;
+use crate::key_storage::KeyStorageErrorKind;
+use crate::key_storage::KeyUsagePolicy;
+use crate::key_storage::KeyUsageViolation;
+
+#[tokio::test]
+async fn generate_and_sign() {
+  let store = ConstrainedJwkStorage::new(JwkMemStore::new());
+  test_generate_and_sign(store).await;
+}
+
+#[tokio::test]
+async fn purpose_is_enforced() {
+  let store = ConstrainedJwkStorage::new(JwkMemStore::new());
+  let output = store
+    .generate_with_policy(
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      KeyUsagePolicy::new().allowed_purposes([KeyUsagePolicy::AUTHENTICATION]),
+    )
+    .await
+    .unwrap();
+
+  store
+    .with_purpose(KeyUsagePolicy::AUTHENTICATION)
+    .sign(&output.key_id, b"challenge", &output.jwk)
+    .await
+    .unwrap();
+
+  for view in [store.clone(), store.with_purpose(KeyUsagePolicy::CREDENTIAL_ISSUANCE)] {
+    let error = view.sign(&output.key_id, b"credential", &output.jwk).await.unwrap_err();
+    assert!(matches!(error.kind(), KeyStorageErrorKind::KeyUsageNotAllowed));
+    assert!(matches!(
+      KeyUsageViolation::from_error(&error),
+      Some(KeyUsageViolation::PurposeNotAllowed { .. })
+    ));
+  }
+}
+
+#[tokio::test]
+async fn max_uses_are_enforced() {
+  let store = ConstrainedJwkStorage::new(JwkMemStore::new());
+  let output = store
+    .generate(JwkMemStore::ED25519_KEY_TYPE, JwsAlgorithm::EdDSA)
+    .await
+    .unwrap();
+  store.set_policy(output.key_id.clone(), KeyUsagePolicy::new().max_uses(2));
+
+  for _ in 0..2 {
+    store.sign(&output.key_id, b"payload", &output.jwk).await.unwrap();
+  }
+  let error = store.sign(&output.key_id, b"payload", &output.jwk).await.unwrap_err();
+  assert_eq!(
+    KeyUsageViolation::from_error(&error),
+    Some(&KeyUsageViolation::UsageLimitReached { max_uses: 2 })
+  );
+  assert_eq!(store.policy(&output.key_id).unwrap().1, 2);
+
+  // Deleting the key removes its policy.
+  store.delete(&output.key_id).await.unwrap();
+  assert!(store.policy(&output.key_id).is_none());
+}
+
+#[tokio::test]
+async fn expiry_is_enforced_at_the_clock_time() {
+  let expires_at = Timestamp::parse("2024-01-01T00:00:00Z").unwrap();
+  let policy = KeyUsagePolicy::new().expires_at(expires_at);
+
+  let store = ConstrainedJwkStorage::new(JwkMemStore::new()).clock(FixedClock::new(expires_at));
+  let output = store
+    .generate_with_policy(JwkMemStore::ED25519_KEY_TYPE, JwsAlgorithm::EdDSA, policy.clone())
+    .await
+    .unwrap();
+  store.sign(&output.key_id, b"payload", &output.jwk).await.unwrap();
+
+  let store = ConstrainedJwkStorage::new(JwkMemStore::new())
+    .clock(FixedClock::new(expires_at.checked_add(Duration::seconds(1)).unwrap()));
+  let output = store
+    .generate_with_policy(JwkMemStore::ED25519_KEY_TYPE, JwsAlgorithm::EdDSA, policy)
+    .await
+    .unwrap();
+  let error = store.sign(&output.key_id, b"payload", &output.jwk).await.unwrap_err();
+  assert_eq!(
+    KeyUsageViolation::from_error(&error),
+    Some(&KeyUsageViolation::Expired { expires_at })
+  );
+}
@@ -7,6 +7,10 @@ use super::utils::test_incompatible_key_type;
 use super::utils::test_insertion;
 use super::utils::test_key_exists;
 use crate::key_storage::JwkMemStore;
+use crate::key_storage::JwkStorage;
+use identity_verification::jws::JwsAlgorithm;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 
 #[tokio::test]
 async fn insert() {
@@ -37,3 +41,75 @@ async fn key_exists() {
   let store: JwkMemStore = JwkMemStore::new();
   test_key_exists(store).await;
 }
+
+#[tokio::test]
+async fn seeded_rng_is_reproducible() {
+  let first: JwkMemStore = JwkMemStore::new_with_rng(StdRng::seed_from_u64(42));
+  let second: JwkMemStore = JwkMemStore::new_with_rng(StdRng::seed_from_u64(42));
+
+  let first_output = first
+    .generate(JwkMemStore::ED25519_KEY_TYPE, JwsAlgorithm::EdDSA)
+    .await
+    .unwrap();
+  let second_output = second
+    .generate(JwkMemStore::ED25519_KEY_TYPE, JwsAlgorithm::EdDSA)
+    .await
+    .unwrap();
+
+  assert_eq!(first_output.key_id, second_output.key_id);
+  assert_eq!(first_output.jwk, second_output.jwk);
+}
+
+#[cfg(feature = "memstore-rsa")]
+#[tokio::test]
+async fn generate_and_sign_rsa() {
+  use identity_rsa_verifier::RsaVerifier;
+  use identity_verification::jws::JwsVerifier;
+  use identity_verification::jws::VerificationInput;
+
+  let store: JwkMemStore = JwkMemStore::new_with_rng(StdRng::seed_from_u64(42));
+  let test_msg: &[u8] = b"test";
+
+  for alg in [JwsAlgorithm::RS256, JwsAlgorithm::PS256] {
+    let output = store.generate(JwkMemStore::RSA_KEY_TYPE, alg).await.unwrap();
+    assert!(output.jwk.is_public());
+
+    let signature: Vec<u8> = store.sign(&output.key_id, test_msg, &output.jwk).await.unwrap();
+    let input = VerificationInput {
+      alg: output.jwk.alg().unwrap().parse().unwrap(),
+      signing_input: test_msg.into(),
+      decoded_signature: signature.into(),
+    };
+    RsaVerifier::default().verify(input, &output.jwk).unwrap();
+  }
+
+  // INVALID: RSA keys cannot be used with EdDSA.
+  let err = store
+    .generate(JwkMemStore::RSA_KEY_TYPE, JwsAlgorithm::EdDSA)
+    .await
+    .unwrap_err();
+  assert!(matches!(
+    err.kind(),
+    crate::key_storage::KeyStorageErrorKind::KeyAlgorithmMismatch
+  ));
+}
+
+#[cfg(feature = "jpt-bbs-plus")]
+#[tokio::test]
+async fn seeded_rng_is_reproducible_for_bbs_plus() {
+  use crate::key_storage::JwkStorageBbsPlusExt;
+  use jsonprooftoken::jpa::algs::ProofAlgorithm;
+
+  let generate = |seed: u64| async move {
+    JwkMemStore::new_with_rng(StdRng::seed_from_u64(seed))
+      .generate_bbs(JwkMemStore::BLS12381G2_KEY_TYPE, ProofAlgorithm::BBS)
+      .await
+      .unwrap()
+  };
+
+  let first = generate(42).await;
+  let second = generate(42).await;
+  assert_eq!(first.key_id, second.key_id);
+  assert_eq!(first.jwk, second.jwk);
+  assert_ne!(first.jwk, generate(43).await.jwk);
+}
@@ -1,6 +1,9 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "audit-log")]
+mod audit;
+mod key_usage;
 mod memstore;
 
 #[cfg(test)]
@@ -14,10 +14,12 @@
   clippy::missing_safety_doc
 )]
 
+pub mod issuance;
 pub mod key_id_storage;
 pub mod key_storage;
 pub mod storage;
 
+pub use issuance::*;
 pub use key_id_storage::*;
 pub use key_storage::public_modules::*;
 pub use storage::*;
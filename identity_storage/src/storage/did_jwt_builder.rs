@@ -0,0 +1,147 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Timestamp;
+use identity_credential::credential::Jws;
+use identity_credential::credential::Jwt;
+use identity_credential::did_jwt::DidJwtClaims;
+use identity_did::DID;
+use identity_document::document::CoreDocument;
+use serde_json::Value;
+
+use super::JwkDocumentExt;
+use super::JwkStorageDocumentError as Error;
+use super::JwsSignatureOptions;
+use super::Storage;
+use super::StorageResult;
+
+use crate::key_id_storage::KeyIdStorage;
+use crate::key_storage::JwkStorage;
+
+/// Builds JWTs with arbitrary claims, signed with the verification method of a DID Document.
+///
+/// Unlike [`JwkDocumentExt::create_credential_jwt`], the claims are not those of a Verifiable Credential: this is meant
+/// for tokens such as authorization grants or session tokens. They are validated with a
+/// [`DidJwtValidator`](identity_credential::did_jwt::DidJwtValidator).
+#[derive(Debug, Clone, Default)]
+pub struct DidJwtBuilder {
+  claims: DidJwtClaims,
+  options: JwsSignatureOptions,
+}
+
+impl DidJwtBuilder {
+  /// The `typ` header of the tokens, unless set otherwise.
+  pub const DEFAULT_TYP: &'static str = "JWT";
+
+  /// Creates a new [`DidJwtBuilder`] without claims.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Creates a new [`DidJwtBuilder`] signing `claims`.
+  pub fn from_claims(claims: DidJwtClaims) -> Self {
+    Self {
+      claims,
+      options: JwsSignatureOptions::default(),
+    }
+  }
+
+  /// Sets the `iss` claim, which defaults to the DID of the signing document.
+  #[must_use]
+  pub fn issuer(mut self, value: impl Into<String>) -> Self {
+    self.claims = self.claims.issuer(value);
+    self
+  }
+
+  /// Sets the `sub` claim.
+  #[must_use]
+  pub fn subject(mut self, value: impl Into<String>) -> Self {
+    self.claims = self.claims.subject(value);
+    self
+  }
+
+  /// Adds a recipient to the `aud` claim.
+  #[must_use]
+  pub fn audience(mut self, value: impl Into<String>) -> Self {
+    self.claims = self.claims.audience(value);
+    self
+  }
+
+  /// Sets the `exp` claim.
+  #[must_use]
+  pub fn expiration_date(mut self, value: Timestamp) -> Self {
+    self.claims = self.claims.expiration_date(value);
+    self
+  }
+
+  /// Sets the `nbf` claim.
+  #[must_use]
+  pub fn not_before(mut self, value: Timestamp) -> Self {
+    self.claims = self.claims.not_before(value);
+    self
+  }
+
+  /// Sets the `iat` claim, which defaults to the time of signing.
+  #[must_use]
+  pub fn issuance_date(mut self, value: Timestamp) -> Self {
+    self.claims = self.claims.issuance_date(value);
+    self
+  }
+
+  /// Sets the `jti` claim.
+  #[must_use]
+  pub fn jwt_id(mut self, value: impl Into<String>) -> Self {
+    self.claims = self.claims.jwt_id(value);
+    self
+  }
+
+  /// Sets the custom claim `name`.
+  #[must_use]
+  pub fn claim(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
+    self.claims = self.claims.claim(name, value);
+    self
+  }
+
+  /// Sets the `typ` header, which defaults to [`DidJwtBuilder::DEFAULT_TYP`].
+  #[must_use]
+  pub fn typ(mut self, value: impl Into<String>) -> Self {
+    self.options = self.options.typ(value);
+    self
+  }
+
+  /// Sets the options of the signature, e.g. additional header parameters.
+  #[must_use]
+  pub fn signature_options(mut self, options: JwsSignatureOptions) -> Self {
+    self.options = options;
+    self
+  }
+
+  /// Returns the claims built so far.
+  pub fn claims(&self) -> &DidJwtClaims {
+    &self.claims
+  }
+
+  /// Signs the claims with the storage backed private key corresponding to the verification method of `document`
+  /// identified by `fragment`.
+  ///
+  /// The `iss` claim is set to the DID of `document` and the `iat` claim to the current time, unless set otherwise.
+  pub async fn sign<D, K, I>(&self, document: &D, storage: &Storage<K, I>, fragment: &str) -> StorageResult<Jwt>
+  where
+    D: JwkDocumentExt + AsRef<CoreDocument>,
+    K: JwkStorage,
+    I: KeyIdStorage,
+  {
+    let mut claims: DidJwtClaims = self.claims.clone();
+    claims
+      .iss
+      .get_or_insert_with(|| document.as_ref().id().as_str().to_owned());
+    claims.iat.get_or_insert_with(|| Timestamp::now_utc().to_unix());
+    let payload: Vec<u8> = serde_json::to_vec(&claims).map_err(|err| Error::EncodingError(Box::new(err)))?;
+
+    let mut options: JwsSignatureOptions = self.options.clone();
+    options.typ.get_or_insert_with(|| Self::DEFAULT_TYP.to_owned());
+
+    let jws: Jws = document.create_jws(storage, fragment, &payload, &options).await?;
+    Ok(Jwt::new(jws.into()))
+  }
+}
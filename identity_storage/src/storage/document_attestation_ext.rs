@@ -0,0 +1,77 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use identity_core::common::Duration;
+use identity_core::common::Timestamp;
+use identity_credential::credential::Jws;
+use identity_iota_core::DocumentAttestation;
+use identity_iota_core::IotaDocument;
+
+use super::JwkDocumentExt;
+use super::JwkStorageDocumentError as Error;
+use super::JwsSignatureOptions;
+use super::Storage;
+use super::StorageResult;
+
+use crate::key_id_storage::KeyIdStorage;
+use crate::key_storage::JwkStorage;
+
+/// Extension trait for producing [`DocumentAttestation`]s of DID documents.
+///
+/// This trait is deliberately sealed and cannot be implemented by external crates.
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+pub trait DocumentAttestationExt: private::Sealed {
+  /// Produces a [`DocumentAttestation`] of this document valid for `validity` from now, signing its
+  /// [signing input](DocumentAttestation::signing_input) with a detached JWS using the storage backed private key
+  /// corresponding to the verification method identified by `fragment`.
+  ///
+  /// Relying parties can verify the attestation offline with [`DocumentAttestation::verify`] until it expires.
+  async fn attest<K, I>(
+    &self,
+    storage: &Storage<K, I>,
+    fragment: &str,
+    validity: Duration,
+  ) -> StorageResult<DocumentAttestation>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage;
+}
+
+mod private {
+  pub trait Sealed {}
+  impl Sealed for identity_iota_core::IotaDocument {}
+}
+
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl DocumentAttestationExt for IotaDocument {
+  async fn attest<K, I>(
+    &self,
+    storage: &Storage<K, I>,
+    fragment: &str,
+    validity: Duration,
+  ) -> StorageResult<DocumentAttestation>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage,
+  {
+    let issued_at: Timestamp = Timestamp::now_utc();
+    let expires_at: Timestamp = issued_at
+      .checked_add(validity)
+      .ok_or_else(|| Error::EncodingError("the attestation expiry overflows".into()))?;
+    let payload: Vec<u8> = DocumentAttestation::signing_input(self, issued_at, expires_at)
+      .map_err(|err| Error::EncodingError(err.into()))?;
+    let jws: Jws = self
+      .create_jws(
+        storage,
+        fragment,
+        &payload,
+        &JwsSignatureOptions::new().detached_payload(true),
+      )
+      .await?;
+
+    Ok(DocumentAttestation::new(self.clone(), issued_at, expires_at, jws))
+  }
+}
@@ -63,6 +63,24 @@ pub enum JwkStorageDocumentError {
   },
 }
 
+identity_core::impl_error_code!(JwkStorageDocumentError {
+  KeyStorageError => 1940,
+  KeyIdStorageError => 1941,
+  FragmentAlreadyExists => 1942,
+  MethodNotFound => 1943,
+  NotPublicKeyJwk => 1944,
+  NotCompositePublicKey => 1945,
+  InvalidJwsAlgorithm => 1946,
+  InvalidJwpAlgorithm => 1947,
+  JwpBuildingError => 1948,
+  ProofUpdateError => 1949,
+  VerificationMethodConstructionError => 1950,
+  EncodingError => 1951,
+  MethodDigestConstructionError => 1952,
+  ClaimsSerializationError => 1953,
+  UndoOperationFailed => 1954,
+});
+
 #[cfg(test)]
 mod tests {
   use super::JwkStorageDocumentError;
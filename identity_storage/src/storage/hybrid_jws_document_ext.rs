@@ -382,7 +382,7 @@ impl JwkDocumentExtHybrid for CoreDocument {
     }
 
     let payload = credential
-      .serialize_jwt(custom_claims)
+      .serialize_jwt_with_ordering(custom_claims, options.claims_ordering)
       .map_err(Error::ClaimsSerializationError)?;
     self
       .create_jws(storage, fragment, payload.as_bytes(), options)
@@ -5,6 +5,7 @@ use std::borrow::Cow;
 
 use super::JwkStorageDocumentError as Error;
 use super::JwsSignatureOptions;
+use super::KidStrategy;
 use super::Storage;
 
 use crate::key_id_storage::KeyIdStorage;
@@ -31,6 +32,7 @@ use identity_verification::jose::jws::CompactJwsEncoder;
 use identity_verification::jose::jws::CompactJwsEncodingOptions;
 use identity_verification::jose::jws::JwsAlgorithm;
 use identity_verification::jose::jws::JwsHeader;
+use identity_verification::jwk::Jwk;
 use identity_verification::jws::CharSet;
 use identity_verification::MethodData;
 use identity_verification::MethodScope;
@@ -59,6 +61,8 @@ pub trait JwkDocumentExt: private::Sealed {
   /// - The `key_type` must be compatible with the given `storage`. [`Storage`]s are expected to export key type
   ///   constants for that use case.
   ///
+  /// - The `kid` of the generated JWK is set according to the [`KidStrategy`] of the `storage`.
+  ///
   /// The fragment of the generated method is returned.
   async fn generate_method<K, I>(
     &mut self,
@@ -121,6 +125,31 @@ pub trait JwkDocumentExt: private::Sealed {
     I: KeyIdStorage,
     T: ToOwned<Owned = T> + Serialize + DeserializeOwned + Sync;
 
+  /// Produces a JWT for each of the given `credentials`, in the same order, as if
+  /// [`JwkDocumentExt::create_credential_jwt`] was called on each of them with the same arguments.
+  ///
+  /// The method identified by `fragment`, its key identifier and the protected header are resolved only once and
+  /// reused for every credential, and the signatures are requested from the `storage` concurrently. This makes this
+  /// method considerably cheaper than repeated calls to [`JwkDocumentExt::create_credential_jwt`] when issuing
+  /// credentials in bulk.
+  ///
+  /// The `custom_claims`, if any, are set on every resulting JWT.
+  ///
+  /// # Errors
+  /// Fails without returning any JWT if any of the `credentials` cannot be serialized or signed.
+  async fn create_credential_jwt_batch<K, I, T>(
+    &self,
+    credentials: &[Credential<T>],
+    storage: &Storage<K, I>,
+    fragment: &str,
+    options: &JwsSignatureOptions,
+    custom_claims: Option<Object>,
+  ) -> StorageResult<Vec<Jwt>>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage,
+    T: ToOwned<Owned = T> + Serialize + DeserializeOwned + Sync;
+
   /// Returns a JWT containing the given VC Data Model 2.0 `credential` in accordance with the mediatype
   /// `application/vc+jwt` defined in [Securing Verifiable Credentials using JOSE and COSE](https://www.w3.org/TR/vc-jose-cose/#securing-with-jose).
   ///
@@ -160,6 +189,13 @@ pub trait JwkDocumentExt: private::Sealed {
     I: KeyIdStorage,
     T: Clone + Serialize + DeserializeOwned + Sync,
     CRED: ToOwned<Owned = CRED> + Serialize + DeserializeOwned + Clone + Sync;
+
+  /// Sets the `kid` of the JWKs of all embedded methods of the document according to `strategy`, e.g. to migrate
+  /// existing keys to thumbprint `kid`s.
+  ///
+  /// The fragments of the methods are left unchanged, so their keys remain retrievable from the storage. The ids of
+  /// the updated methods are returned; the document must be published again for the changes to take effect.
+  fn apply_kid_strategy(&mut self, strategy: &KidStrategy) -> Vec<DIDUrl>;
 }
 
 mod private {
@@ -191,9 +227,10 @@ macro_rules! generate_method_for_document_type {
       K: $k,
       I: KeyIdStorage,
     {
-      let JwkGenOutput { key_id, jwk } = $f(storage.key_storage(), key_type, alg)
+      let JwkGenOutput { key_id, mut jwk } = $f(storage.key_storage(), key_type, alg)
         .await
         .map_err(Error::KeyStorageError)?;
+      storage.kid_strategy().apply(&mut jwk, fragment);
 
       // Produce a new verification method containing the generated JWK. If this operation fails we handle the error
       // by attempting to revert key generation before returning an error.
@@ -375,91 +412,8 @@ impl JwkDocumentExt for CoreDocument {
     K: JwkStorage,
     I: KeyIdStorage,
   {
-    // Obtain the method corresponding to the given fragment.
-    let method: &VerificationMethod = self.resolve_method(fragment, None).ok_or(Error::MethodNotFound)?;
-    let MethodData::PublicKeyJwk(ref jwk) = method.data() else {
-      return Err(Error::NotPublicKeyJwk);
-    };
-
-    // Extract JwsAlgorithm.
-    let alg: JwsAlgorithm = jwk
-      .alg()
-      .unwrap_or("")
-      .parse()
-      .map_err(|_| Error::InvalidJwsAlgorithm)?;
-
-    // Create JWS header in accordance with options.
-    let header: JwsHeader = {
-      let mut header = JwsHeader::new();
-
-      header.set_alg(alg);
-      if let Some(custom) = &options.custom_header_parameters {
-        header.set_custom(custom.clone())
-      }
-
-      if let Some(ref kid) = options.kid {
-        header.set_kid(kid.clone());
-      } else {
-        header.set_kid(method.id().to_string());
-      }
-
-      if options.attach_jwk {
-        header.set_jwk(jwk.clone())
-      };
-
-      if let Some(b64) = options.b64 {
-        // Follow recommendation in https://datatracker.ietf.org/doc/html/rfc7797#section-7.
-        if !b64 {
-          header.set_b64(b64);
-          header.set_crit(["b64"]);
-        }
-      };
-
-      if let Some(typ) = &options.typ {
-        header.set_typ(typ.clone())
-      } else {
-        // https://www.w3.org/TR/vc-data-model/#jwt-encoding
-        header.set_typ("JWT")
-      }
-
-      if let Some(cty) = &options.cty {
-        header.set_cty(cty.clone())
-      };
-
-      if let Some(url) = &options.url {
-        header.set_url(url.clone())
-      };
-
-      if let Some(nonce) = &options.nonce {
-        header.set_nonce(nonce.clone())
-      };
-
-      header
-    };
-
-    // Get the key identifier corresponding to the given method from the KeyId storage.
-    let method_digest: MethodDigest = MethodDigest::new(method).map_err(Error::MethodDigestConstructionError)?;
-    let key_id = <I as KeyIdStorage>::get_key_id(storage.key_id_storage(), &method_digest)
-      .await
-      .map_err(Error::KeyIdStorageError)?;
-
-    // Extract Compact JWS encoding options.
-    let encoding_options: CompactJwsEncodingOptions = if !options.detached_payload {
-      // We use this as a default and don't provide the extra UrlSafe check for now.
-      // Applications that require such checks can easily do so after JWS creation.
-      CompactJwsEncodingOptions::NonDetached {
-        charset_requirements: CharSet::Default,
-      }
-    } else {
-      CompactJwsEncodingOptions::Detached
-    };
-
-    let jws_encoder: CompactJwsEncoder<'_> = CompactJwsEncoder::new_with_options(payload, &header, encoding_options)
-      .map_err(|err| Error::EncodingError(err.into()))?;
-    let signature = <K as JwkStorage>::sign(storage.key_storage(), &key_id, jws_encoder.signing_input(), jwk)
-      .await
-      .map_err(Error::KeyStorageError)?;
-    Ok(Jws::new(jws_encoder.into_jws(&signature)))
+    let signing_key = SigningKey::resolve(self, storage, fragment, options).await?;
+    signing_key.sign(storage, payload).await
   }
 
   async fn create_credential_jwt<K, I, T>(
@@ -489,7 +443,7 @@ impl JwkDocumentExt for CoreDocument {
     }
 
     let payload = credential
-      .serialize_jwt(custom_claims)
+      .serialize_jwt_with_ordering(custom_claims, options.claims_ordering)
       .map_err(Error::ClaimsSerializationError)?;
     self
       .create_jws(storage, fragment, payload.as_bytes(), options)
@@ -497,6 +451,46 @@ impl JwkDocumentExt for CoreDocument {
       .map(|jws| Jwt::new(jws.into()))
   }
 
+  async fn create_credential_jwt_batch<K, I, T>(
+    &self,
+    credentials: &[Credential<T>],
+    storage: &Storage<K, I>,
+    fragment: &str,
+    options: &JwsSignatureOptions,
+    custom_claims: Option<Object>,
+  ) -> StorageResult<Vec<Jwt>>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage,
+    T: ToOwned<Owned = T> + Serialize + DeserializeOwned + Sync,
+  {
+    if options.detached_payload {
+      return Err(Error::EncodingError(
+        "cannot use detached payload for credential signing".into(),
+      ));
+    }
+
+    if !options.b64.unwrap_or(true) {
+      // JWTs should not have `b64` set per https://datatracker.ietf.org/doc/html/rfc7797#section-7.
+      return Err(Error::EncodingError("cannot use `b64 = false` with JWTs".into()));
+    }
+
+    let payloads: Vec<String> = credentials
+      .iter()
+      .map(|credential| credential.serialize_jwt_with_ordering(custom_claims.clone(), options.claims_ordering))
+      .collect::<Result<_, _>>()
+      .map_err(Error::ClaimsSerializationError)?;
+
+    let signing_key = SigningKey::resolve(self, storage, fragment, options).await?;
+    let jws_futures = payloads
+      .iter()
+      .map(|payload| signing_key.sign(storage, payload.as_bytes()));
+
+    futures::future::try_join_all(jws_futures)
+      .await
+      .map(|jwss| jwss.into_iter().map(|jws| Jwt::new(jws.into())).collect())
+  }
+
   async fn create_credential_v2_jwt<K, I, T>(
     &self,
     credential: &CredentialV2<T>,
@@ -576,6 +570,22 @@ impl JwkDocumentExt for CoreDocument {
       .await
       .map(|jws| Jwt::new(jws.into()))
   }
+
+  fn apply_kid_strategy(&mut self, strategy: &KidStrategy) -> Vec<DIDUrl> {
+    let ids: Vec<DIDUrl> = self
+      .methods(None)
+      .into_iter()
+      .map(|method| method.id().clone())
+      .collect();
+    ids
+      .into_iter()
+      .filter(|id| {
+        self
+          .resolve_method_mut(id, None)
+          .map_or(false, |method| strategy.apply_to_method(method))
+      })
+      .collect()
+  }
 }
 
 /// Attempt to revert key generation. If this succeeds the original `source_error` is returned,
@@ -598,6 +608,135 @@ where
   }
 }
 
+/// The signing material of a verification method, resolved once so that it can be used to sign several payloads.
+struct SigningKey<'a> {
+  jwk: &'a Jwk,
+  header: JwsHeader,
+  key_id: KeyId,
+  encoding_options: CompactJwsEncodingOptions,
+}
+
+impl<'a> SigningKey<'a> {
+  /// Resolves the method identified by `fragment` in `document`, its [`KeyId`] in `storage`, and the protected
+  /// header to use according to `options`.
+  async fn resolve<K, I>(
+    document: &'a CoreDocument,
+    storage: &Storage<K, I>,
+    fragment: &str,
+    options: &JwsSignatureOptions,
+  ) -> StorageResult<SigningKey<'a>>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage,
+  {
+    // Obtain the method corresponding to the given fragment.
+    let method: &VerificationMethod = document.resolve_method(fragment, None).ok_or(Error::MethodNotFound)?;
+    let MethodData::PublicKeyJwk(ref jwk) = method.data() else {
+      return Err(Error::NotPublicKeyJwk);
+    };
+
+    // Extract JwsAlgorithm.
+    let alg: JwsAlgorithm = jwk
+      .alg()
+      .unwrap_or("")
+      .parse()
+      .map_err(|_| Error::InvalidJwsAlgorithm)?;
+
+    // Create JWS header in accordance with options.
+    let header: JwsHeader = {
+      let mut header = JwsHeader::new();
+
+      header.set_alg(alg);
+      if let Some(custom) = &options.custom_header_parameters {
+        header.set_custom(custom.clone())
+      }
+
+      if let Some(ref kid) = options.kid {
+        header.set_kid(kid.clone());
+      } else {
+        header.set_kid(method.id().to_string());
+      }
+
+      if options.attach_jwk {
+        header.set_jwk(jwk.clone())
+      };
+
+      if let Some(b64) = options.b64 {
+        // Follow recommendation in https://datatracker.ietf.org/doc/html/rfc7797#section-7.
+        if !b64 {
+          header.set_b64(b64);
+          header.set_crit(["b64"]);
+        }
+      };
+
+      if let Some(typ) = &options.typ {
+        header.set_typ(typ.clone())
+      } else {
+        // https://www.w3.org/TR/vc-data-model/#jwt-encoding
+        header.set_typ("JWT")
+      }
+
+      if let Some(cty) = &options.cty {
+        header.set_cty(cty.clone())
+      };
+
+      if let Some(url) = &options.url {
+        header.set_url(url.clone())
+      };
+
+      if let Some(nonce) = &options.nonce {
+        header.set_nonce(nonce.clone())
+      };
+
+      header
+    };
+
+    // Get the key identifier corresponding to the given method from the KeyId storage.
+    let method_digest: MethodDigest = MethodDigest::new(method).map_err(Error::MethodDigestConstructionError)?;
+    let key_id = <I as KeyIdStorage>::get_key_id(storage.key_id_storage(), &method_digest)
+      .await
+      .map_err(Error::KeyIdStorageError)?;
+
+    // Extract Compact JWS encoding options.
+    let encoding_options: CompactJwsEncodingOptions = if !options.detached_payload {
+      // We use this as a default and don't provide the extra UrlSafe check for now.
+      // Applications that require such checks can easily do so after JWS creation.
+      CompactJwsEncodingOptions::NonDetached {
+        charset_requirements: CharSet::Default,
+      }
+    } else {
+      CompactJwsEncodingOptions::Detached
+    };
+
+    Ok(SigningKey {
+      jwk,
+      header,
+      key_id,
+      encoding_options,
+    })
+  }
+
+  /// Signs `payload` with the resolved key, producing a JWS.
+  async fn sign<K, I>(&self, storage: &Storage<K, I>, payload: &[u8]) -> StorageResult<Jws>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage,
+  {
+    let jws_encoder: CompactJwsEncoder<'_> =
+      CompactJwsEncoder::new_with_options(payload, &self.header, self.encoding_options)
+        .map_err(|err| Error::EncodingError(err.into()))?;
+    let signature = <K as JwkStorage>::sign(
+      storage.key_storage(),
+      &self.key_id,
+      jws_encoder.signing_input(),
+      self.jwk,
+    )
+    .await
+    .map_err(Error::KeyStorageError)?;
+    Ok(Jws::new(jws_encoder.into_jws(&signature)))
+  }
+}
+
 // ====================================================================================================================
 // IotaDocument
 // ====================================================================================================================
@@ -678,6 +817,26 @@ mod iota_document {
         .create_credential_jwt(credential, storage, fragment, options, custom_claims)
         .await
     }
+
+    async fn create_credential_jwt_batch<K, I, T>(
+      &self,
+      credentials: &[Credential<T>],
+      storage: &Storage<K, I>,
+      fragment: &str,
+      options: &JwsSignatureOptions,
+      custom_claims: Option<Object>,
+    ) -> StorageResult<Vec<Jwt>>
+    where
+      K: JwkStorage,
+      I: KeyIdStorage,
+      T: ToOwned<Owned = T> + Serialize + DeserializeOwned + Sync,
+    {
+      self
+        .core_document()
+        .create_credential_jwt_batch(credentials, storage, fragment, options, custom_claims)
+        .await
+    }
+
     async fn create_presentation_jwt<K, I, CRED, T>(
       &self,
       presentation: &Presentation<CRED, T>,
@@ -715,5 +874,21 @@ mod iota_document {
         .create_credential_v2_jwt(credential, storage, fragment, options)
         .await
     }
+
+    fn apply_kid_strategy(&mut self, strategy: &KidStrategy) -> Vec<DIDUrl> {
+      let ids: Vec<DIDUrl> = self
+        .methods(None)
+        .into_iter()
+        .map(|method| method.id().clone())
+        .collect();
+      ids
+        .into_iter()
+        .filter(|id| {
+          self
+            .resolve_method_mut(id, None)
+            .map_or(false, |method| strategy.apply_to_method(method))
+        })
+        .collect()
+    }
   }
 }
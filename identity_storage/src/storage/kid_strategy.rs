@@ -0,0 +1,134 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use identity_verification::jwk::Jwk;
+use identity_verification::MethodData;
+use identity_verification::VerificationMethod;
+
+/// A function computing the `kid` of a JWK, given the fragment of the method the JWK is embedded in, if known.
+pub type KidFn = dyn Fn(&Jwk, Option<&str>) -> String + Send + Sync;
+
+/// Determines the `kid` of the public JWKs embedded in verification methods, see [`Storage::with_kid_strategy`].
+///
+/// The strategy is applied by the `generate_method` functions of the document extensions, before the method is
+/// created. If no fragment is passed to them, the fragment of the method is the resulting `kid`. Existing methods are
+/// migrated with [`JwkDocumentExt::apply_kid_strategy`](crate::JwkDocumentExt::apply_kid_strategy).
+///
+/// [`Storage::with_kid_strategy`]: crate::Storage::with_kid_strategy
+#[derive(Clone, Default)]
+#[non_exhaustive]
+pub enum KidStrategy {
+  /// Keeps the `kid` set by the [`JwkStorage`](crate::JwkStorage), if any.
+  #[default]
+  Storage,
+  /// Sets the `kid` to the base64url-encoded SHA-256 JWK thumbprint of the key, as defined in
+  /// [RFC 7638](https://www.rfc-editor.org/rfc/rfc7638).
+  Thumbprint,
+  /// Sets the `kid` to the fragment of the method, e.g. `key-1` for `did:example:123#key-1`.
+  ///
+  /// If the fragment is not known yet, the `kid` set by the storage is kept, or the thumbprint is used if there is
+  /// none.
+  Fragment,
+  /// Sets the `kid` with a custom function.
+  Custom(Arc<KidFn>),
+}
+
+impl KidStrategy {
+  /// Creates a [`KidStrategy::Custom`] from `f`.
+  pub fn custom(f: impl Fn(&Jwk, Option<&str>) -> String + Send + Sync + 'static) -> Self {
+    Self::Custom(Arc::new(f))
+  }
+
+  /// Returns the `kid` of `jwk` according to this strategy, or `None` if it is to be left unchanged.
+  ///
+  /// `fragment` is the fragment of the method `jwk` is embedded in, with or without a leading `#`.
+  pub fn kid(&self, jwk: &Jwk, fragment: Option<&str>) -> Option<String> {
+    let fragment: Option<&str> = fragment.map(|fragment| fragment.trim_start_matches('#'));
+    match self {
+      Self::Storage => None,
+      Self::Thumbprint => Some(jwk.thumbprint_sha256_b64()),
+      Self::Fragment => Some(
+        fragment
+          .or_else(|| jwk.kid())
+          .map(ToOwned::to_owned)
+          .unwrap_or_else(|| jwk.thumbprint_sha256_b64()),
+      ),
+      Self::Custom(f) => Some(f(jwk, fragment)),
+    }
+  }
+
+  /// Sets the `kid` of `jwk` according to this strategy.
+  pub fn apply(&self, jwk: &mut Jwk, fragment: Option<&str>) {
+    if let Some(kid) = self.kid(jwk, fragment) {
+      jwk.set_kid(kid);
+    }
+  }
+
+  /// Sets the `kid` of the JWK embedded in `method` according to this strategy, returning `true` if it changed.
+  ///
+  /// Methods not expressed as a JWK are left unchanged. The fragment of the method is never changed, so its key
+  /// remains retrievable from the [`KeyIdStorage`](crate::KeyIdStorage).
+  pub fn apply_to_method(&self, method: &mut VerificationMethod) -> bool {
+    let fragment: Option<String> = method.id().fragment().map(ToOwned::to_owned);
+    let MethodData::PublicKeyJwk(jwk) = method.data_mut() else {
+      return false;
+    };
+    match self.kid(jwk, fragment.as_deref()) {
+      Some(kid) if jwk.kid() != Some(kid.as_str()) => {
+        jwk.set_kid(kid);
+        true
+      }
+      _ => false,
+    }
+  }
+}
+
+impl Debug for KidStrategy {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Storage => f.write_str("Storage"),
+      Self::Thumbprint => f.write_str("Thumbprint"),
+      Self::Fragment => f.write_str("Fragment"),
+      Self::Custom(_) => f.write_str("Custom"),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+  use serde_json::json;
+
+  use super::*;
+
+  fn jwk() -> Jwk {
+    Jwk::from_json_value(json!({
+      "kty": "OKP",
+      "crv": "Ed25519",
+      "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo",
+      "kid": "storage-kid",
+    }))
+    .unwrap()
+  }
+
+  #[test]
+  fn kid_strategies() {
+    let jwk = jwk();
+    assert_eq!(KidStrategy::Storage.kid(&jwk, Some("key-1")), None);
+    assert_eq!(
+      KidStrategy::Thumbprint.kid(&jwk, Some("key-1")),
+      Some(jwk.thumbprint_sha256_b64())
+    );
+    assert_eq!(
+      KidStrategy::Fragment.kid(&jwk, Some("#key-1")).as_deref(),
+      Some("key-1")
+    );
+    assert_eq!(KidStrategy::Fragment.kid(&jwk, None).as_deref(), Some("storage-kid"));
+    let custom = KidStrategy::custom(|jwk, fragment| format!("{}-{}", fragment.unwrap_or("none"), jwk.kty()));
+    assert_eq!(custom.kid(&jwk, Some("#key-1")).as_deref(), Some("key-1-OKP"));
+  }
+}
@@ -3,6 +3,10 @@
 
 //! This module provides a type wrapping a key and key id storage.
 
+#[cfg(feature = "did-jwt")]
+mod did_jwt_builder;
+#[cfg(feature = "iota-document")]
+mod document_attestation_ext;
 mod error;
 #[macro_use]
 mod jwk_document_ext;
@@ -10,10 +14,14 @@ mod jwk_document_ext;
 mod hybrid_jws_document_ext;
 #[cfg(feature = "jpt-bbs-plus")]
 mod jwp_document_ext;
+mod kid_strategy;
+mod pop_document_ext;
 #[cfg(feature = "pqc")]
 mod pqc_jws_document_ext;
 mod signature_options;
 #[cfg(feature = "jpt-bbs-plus")]
+mod timeframe_manager;
+#[cfg(feature = "jpt-bbs-plus")]
 mod timeframe_revocation_ext;
 
 mod did_jwk_document_ext;
@@ -23,6 +31,10 @@ mod storage_signer;
 #[cfg(all(test, feature = "memstore"))]
 pub(crate) mod tests;
 
+#[cfg(feature = "did-jwt")]
+pub use did_jwt_builder::*;
+#[cfg(feature = "iota-document")]
+pub use document_attestation_ext::*;
 pub use error::*;
 
 #[cfg(feature = "hybrid")]
@@ -30,12 +42,16 @@ pub use hybrid_jws_document_ext::*;
 pub use jwk_document_ext::*;
 #[cfg(feature = "jpt-bbs-plus")]
 pub use jwp_document_ext::*;
+pub use kid_strategy::*;
+pub use pop_document_ext::*;
 #[cfg(feature = "pqc")]
 pub use pqc_jws_document_ext::*;
 pub use signature_options::*;
 #[cfg(feature = "storage-signer")]
 pub use storage_signer::*;
 #[cfg(feature = "jpt-bbs-plus")]
+pub use timeframe_manager::*;
+#[cfg(feature = "jpt-bbs-plus")]
 pub use timeframe_revocation_ext::*;
 
 pub use did_jwk_document_ext::*;
@@ -46,6 +62,7 @@ pub use did_jwk_document_ext::*;
 pub struct Storage<K, I> {
   key_storage: K,
   key_id_storage: I,
+  kid_strategy: KidStrategy,
 }
 
 impl<K, I> Storage<K, I> {
@@ -54,9 +71,22 @@ impl<K, I> Storage<K, I> {
     Self {
       key_storage,
       key_id_storage,
+      kid_strategy: KidStrategy::default(),
     }
   }
 
+  /// Sets the [`KidStrategy`] determining the `kid` of the keys of the methods generated with this storage.
+  #[must_use]
+  pub fn with_kid_strategy(mut self, kid_strategy: KidStrategy) -> Self {
+    self.kid_strategy = kid_strategy;
+    self
+  }
+
+  /// Obtain a reference to the [`KidStrategy`] of this storage.
+  pub fn kid_strategy(&self) -> &KidStrategy {
+    &self.kid_strategy
+  }
+
   /// Obtain a reference to the wrapped [`JwkStorage`](crate::key_storage::JwkStorage).
   pub fn key_storage(&self) -> &K {
     &self.key_storage
@@ -0,0 +1,88 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use identity_core::common::Timestamp;
+use identity_core::convert::ToJson;
+use identity_credential::credential::Jws;
+use identity_document::document::CoreDocument;
+use identity_document::verifiable::PopChallenge;
+use identity_document::verifiable::PopClaims;
+
+use super::JwkDocumentExt;
+use super::JwkStorageDocumentError as Error;
+use super::JwsSignatureOptions;
+use super::Storage;
+use super::StorageResult;
+
+use crate::key_id_storage::KeyIdStorage;
+use crate::key_storage::JwkStorage;
+
+/// Extension trait for proving possession of the keys of the verification methods of DID documents.
+///
+/// This trait is deliberately sealed and cannot be implemented by external crates.
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+pub trait PopDocumentExt: private::Sealed {
+  /// Creates a proof of possession of the private key corresponding to the public key material in the verification
+  /// method identified by the given `fragment`, answering the verifier's `challenge`.
+  ///
+  /// The proof is a JWS encoded according to the Compact JWS Serialization format, which can be checked with
+  /// [`CoreDocument::verify_pop`].
+  async fn create_pop<K, I>(
+    &self,
+    challenge: &PopChallenge,
+    fragment: &str,
+    storage: &Storage<K, I>,
+  ) -> StorageResult<Jws>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage;
+}
+
+mod private {
+  pub trait Sealed {}
+  impl Sealed for identity_document::document::CoreDocument {}
+  #[cfg(feature = "iota-document")]
+  impl Sealed for identity_iota_core::IotaDocument {}
+}
+
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl PopDocumentExt for CoreDocument {
+  async fn create_pop<K, I>(
+    &self,
+    challenge: &PopChallenge,
+    fragment: &str,
+    storage: &Storage<K, I>,
+  ) -> StorageResult<Jws>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage,
+  {
+    let claims = PopClaims::new(self.id().clone(), challenge, Timestamp::now_utc());
+    let payload: Vec<u8> = claims
+      .to_json_vec()
+      .map_err(|err| Error::EncodingError(Box::new(err)))?;
+    let options = JwsSignatureOptions::new().typ(PopChallenge::TYP);
+    self.create_jws(storage, fragment, &payload, &options).await
+  }
+}
+
+#[cfg(feature = "iota-document")]
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl PopDocumentExt for identity_iota_core::IotaDocument {
+  async fn create_pop<K, I>(
+    &self,
+    challenge: &PopChallenge,
+    fragment: &str,
+    storage: &Storage<K, I>,
+  ) -> StorageResult<Jws>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage,
+  {
+    self.core_document().create_pop(challenge, fragment, storage).await
+  }
+}
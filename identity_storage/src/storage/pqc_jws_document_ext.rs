@@ -268,7 +268,7 @@ impl JwsDocumentExtPQC for CoreDocument {
     }
 
     let payload = credential
-      .serialize_jwt(custom_claims)
+      .serialize_jwt_with_ordering(custom_claims, options.claims_ordering)
       .map_err(Error::ClaimsSerializationError)?;
     self
       .create_jws_pqc(storage, fragment, payload.as_bytes(), options)
@@ -3,6 +3,7 @@
 
 use identity_core::common::Object;
 use identity_core::common::Url;
+use identity_credential::credential::JwtClaimsOrdering;
 
 /// Options for creating a JSON Web Signature.
 #[non_exhaustive]
@@ -60,6 +61,11 @@ pub struct JwsSignatureOptions {
   /// Additional header parameters.
   #[serde(skip_serializing_if = "Option::is_none")]
   pub custom_header_parameters: Option<Object>,
+
+  /// The layout of the claims set when signing a credential as a JWT.
+  ///
+  /// Defaults to [`JwtClaimsOrdering::Legacy`] for compatibility with previously issued credentials.
+  pub claims_ordering: JwtClaimsOrdering,
 }
 
 impl JwsSignatureOptions {
@@ -121,4 +127,10 @@ impl JwsSignatureOptions {
     self.custom_header_parameters = Some(value);
     self
   }
+
+  /// Replace the value of the `claims_ordering` field.
+  pub fn claims_ordering(mut self, value: JwtClaimsOrdering) -> Self {
+    self.claims_ordering = value;
+    self
+  }
 }
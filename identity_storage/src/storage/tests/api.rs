@@ -1,7 +1,10 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use identity_core::common::Duration;
+use identity_core::common::FixedClock;
 use identity_core::common::Object;
+use identity_core::common::Timestamp;
 use identity_core::common::Url;
 use identity_core::convert::FromJson;
 use identity_credential::credential::Credential;
@@ -11,6 +14,9 @@ use identity_did::DIDUrl;
 use identity_did::DID;
 use identity_document::document::CoreDocument;
 use identity_document::verifiable::JwsVerificationOptions;
+use identity_document::verifiable::PopChallenge;
+use identity_document::verifiable::PopClaims;
+use identity_document::verifiable::PopVerificationOptions;
 use identity_eddsa_verifier::EdDSAJwsVerifier;
 use identity_verification::jose::jws::JwsAlgorithm;
 use identity_verification::jwk::Jwk;
@@ -24,6 +30,8 @@ use crate::key_storage::JwkMemStore;
 use crate::storage::JwsSignatureOptions;
 
 use crate::storage::JwkDocumentExt;
+use crate::storage::KidStrategy;
+use crate::storage::PopDocumentExt;
 use crate::Storage;
 
 type MemStorage = Storage<JwkMemStore, KeyIdMemstore>;
@@ -105,6 +113,70 @@ async fn generation() {
     .is_some());
 }
 
+#[tokio::test]
+async fn generation_with_kid_strategy() {
+  let (mut document, storage) = setup();
+  let public_jwk = |document: &CoreDocument, fragment: &str| -> Jwk {
+    document
+      .resolve_method(fragment, None)
+      .unwrap()
+      .data()
+      .try_public_key_jwk()
+      .unwrap()
+      .clone()
+  };
+
+  // Without a fragment, the method is identified by the thumbprint of its key.
+  let storage = storage.with_kid_strategy(KidStrategy::Thumbprint);
+  let fragment: String = document
+    .generate_method(
+      &storage,
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      None,
+      MethodScope::VerificationMethod,
+    )
+    .await
+    .unwrap();
+  let jwk: Jwk = public_jwk(&document, &fragment);
+  assert_eq!(jwk.kid(), Some(jwk.thumbprint_sha256_b64().as_str()));
+  assert_eq!(fragment, jwk.thumbprint_sha256_b64());
+
+  let storage = storage.with_kid_strategy(KidStrategy::Fragment);
+  let fragment: String = document
+    .generate_method(
+      &storage,
+      JwkMemStore::ED25519_KEY_TYPE,
+      JwsAlgorithm::EdDSA,
+      Some("#key-1"),
+      MethodScope::VerificationMethod,
+    )
+    .await
+    .unwrap();
+  assert_eq!(public_jwk(&document, &fragment).kid(), Some("key-1"));
+
+  // Migrating to thumbprint `kid`s keeps the keys usable.
+  let updated: Vec<DIDUrl> = document.apply_kid_strategy(&KidStrategy::Thumbprint);
+  assert_eq!(updated.len(), 1);
+  assert_eq!(updated[0].fragment(), Some("key-1"));
+  assert!(document.apply_kid_strategy(&KidStrategy::Thumbprint).is_empty());
+  let jwk: Jwk = public_jwk(&document, &fragment);
+  assert_eq!(jwk.kid(), Some(jwk.thumbprint_sha256_b64().as_str()));
+
+  let jws: Jws = document
+    .create_jws(&storage, &fragment, b"test", &JwsSignatureOptions::new())
+    .await
+    .unwrap();
+  assert!(document
+    .verify_jws(
+      jws.as_str(),
+      None,
+      &EdDSAJwsVerifier::default(),
+      &JwsVerificationOptions::new()
+    )
+    .is_ok());
+}
+
 #[tokio::test]
 async fn create_jws() {
   let (document, storage, fragment) = setup_with_method().await;
@@ -151,6 +223,66 @@ async fn create_jws_typ() {
   assert_eq!(decoded_jws.unwrap().protected.typ().unwrap(), "test-typ");
 }
 
+#[tokio::test]
+async fn create_pop() {
+  let (document, storage, fragment) = setup_with_method().await;
+  let verifier = EdDSAJwsVerifier::default();
+  let options = PopVerificationOptions::default();
+  let exp: Timestamp = Timestamp::now_utc().checked_add(Duration::minutes(5)).unwrap();
+  let challenge = PopChallenge::new("https://registrar.example", "nonce-1", exp);
+
+  let pop: Jws = document.create_pop(&challenge, &fragment, &storage).await.unwrap();
+  let claims: PopClaims = document
+    .verify_pop(pop.as_str(), &challenge, &verifier, &options)
+    .unwrap();
+  assert_eq!(&claims.iss, document.id());
+  assert_eq!(claims.nonce, "nonce-1");
+
+  // The proof only answers the challenge it was created for.
+  let other_nonce = PopChallenge::new("https://registrar.example", "nonce-2", exp);
+  assert!(matches!(
+    document.verify_pop(pop.as_str(), &other_nonce, &verifier, &options),
+    Err(identity_document::Error::InvalidProofOfPossession(_))
+  ));
+  let other_audience = PopChallenge::new("https://attacker.example", "nonce-1", exp);
+  assert!(document
+    .verify_pop(pop.as_str(), &other_audience, &verifier, &options)
+    .is_err());
+
+  // Expired challenges are rejected.
+  let expired = PopChallenge::new(
+    "https://registrar.example",
+    "nonce-1",
+    Timestamp::now_utc().checked_sub(Duration::minutes(5)).unwrap(),
+  );
+  let pop: Jws = document.create_pop(&expired, &fragment, &storage).await.unwrap();
+  assert!(document
+    .verify_pop(pop.as_str(), &expired, &verifier, &options)
+    .is_err());
+
+  // The expiration is checked against the clock of the options.
+  let pop: Jws = document.create_pop(&challenge, &fragment, &storage).await.unwrap();
+  let after_expiry =
+    PopVerificationOptions::new().clock(FixedClock::new(exp.checked_add(Duration::seconds(1)).unwrap()));
+  assert!(document
+    .verify_pop(pop.as_str(), &challenge, &verifier, &after_expiry)
+    .is_err());
+  let before_expiry =
+    PopVerificationOptions::new().clock(FixedClock::new(exp.checked_sub(Duration::seconds(1)).unwrap()));
+  assert!(document
+    .verify_pop(pop.as_str(), &challenge, &verifier, &before_expiry)
+    .is_ok());
+
+  // An arbitrary JWS is not a proof of possession.
+  let jws: Jws = document
+    .create_jws(&storage, &fragment, b"test", &JwsSignatureOptions::new())
+    .await
+    .unwrap();
+  assert!(document
+    .verify_pop(jws.as_str(), &challenge, &verifier, &options)
+    .is_err());
+}
+
 #[tokio::test]
 async fn create_jws_with_nonce() {
   let (document, storage, fragment) = setup_with_method().await;
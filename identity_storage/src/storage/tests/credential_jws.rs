@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use identity_core::common::Object;
+use identity_core::common::Url;
 use identity_core::convert::FromJson;
 use identity_credential::credential::Credential;
 
@@ -299,3 +300,53 @@ async fn custom_header_parameters() {
     "test-value".to_owned()
   );
 }
+
+#[tokio::test]
+async fn signing_credentials_in_batch() {
+  let (document, storage, kid, credential) = setup().await;
+
+  let credentials: Vec<Credential> = (0..5)
+    .map(|idx| {
+      let mut credential = credential.clone();
+      credential.id = Some(Url::parse(format!("http://example.edu/credentials/{idx}")).unwrap());
+      credential
+    })
+    .collect();
+
+  let jwts = document
+    .create_credential_jwt_batch(
+      &credentials,
+      &storage,
+      kid.as_ref(),
+      &JwsSignatureOptions::default(),
+      None,
+    )
+    .await
+    .unwrap();
+  assert_eq!(jwts.len(), credentials.len());
+
+  let validator =
+    identity_credential::validator::JwtCredentialValidator::with_signature_verifier(EdDSAJwsVerifier::default());
+  for (jwt, credential) in jwts.iter().zip(&credentials) {
+    let decoded = validator
+      .validate::<_, Object>(
+        jwt,
+        &document,
+        &JwtCredentialValidationOptions::default(),
+        identity_credential::validator::FailFast::FirstError,
+      )
+      .unwrap();
+    assert_eq!(&decoded.credential, credential);
+  }
+
+  assert!(document
+    .create_credential_jwt_batch(
+      &credentials,
+      &storage,
+      "missing-fragment",
+      &JwsSignatureOptions::default(),
+      None
+    )
+    .await
+    .is_err());
+}
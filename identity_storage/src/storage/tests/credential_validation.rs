@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use identity_core::common::Duration;
+use identity_core::common::FixedClock;
 use identity_core::common::Object;
 use identity_core::common::Timestamp;
 use identity_core::common::Url;
@@ -15,6 +16,7 @@ use identity_credential::validator::JwtCredentialValidationOptions;
 use identity_credential::validator::JwtCredentialValidator;
 use identity_credential::validator::JwtCredentialValidatorUtils;
 use identity_credential::validator::JwtValidationError;
+use identity_credential::validator::KeyRevocationPolicy;
 use identity_credential::validator::StatusCheck;
 use identity_did::DID;
 use identity_document::document::CoreDocument;
@@ -161,6 +163,173 @@ async fn full_validation() {
   full_validation_impl(test_utils::setup_iotadocument(None, None).await).await;
 }
 
+async fn validation_with_history_impl<T>(setup: Setup<T, T>)
+where
+  T: JwkDocumentExt + AsRef<CoreDocument>,
+{
+  let Setup {
+    issuer_doc,
+    subject_doc,
+    issuer_storage: storage,
+    issuer_method_fragment: method_fragment,
+    subject_storage: _,
+    subject_method_fragment: _,
+  } = setup;
+
+  let CredentialSetup {
+    credential,
+    issuance_date,
+    expiration_date: _,
+  } = test_utils::generate_credential(&issuer_doc, &[&subject_doc], None, None);
+
+  let jwt: Jwt = issuer_doc
+    .create_credential_jwt(
+      &credential,
+      &storage,
+      method_fragment.as_ref(),
+      &JwsSignatureOptions::default(),
+      None,
+    )
+    .await
+    .unwrap();
+
+  // The issuer rotated its key after issuing the credential.
+  let issuer_at_issuance: &CoreDocument = issuer_doc.as_ref();
+  let mut current_issuer: CoreDocument = issuer_at_issuance.clone();
+  let method_id = issuer_at_issuance
+    .resolve_method(method_fragment.as_str(), None)
+    .unwrap()
+    .id()
+    .clone();
+  current_issuer.remove_method(&method_id).unwrap();
+
+  let options = JwtCredentialValidationOptions::default()
+    .validate_at(issuance_date.checked_add(Duration::seconds(1)).unwrap())
+    .status_check(StatusCheck::SkipAll);
+
+  // Grace: the key was part of the issuer's DID Document when the credential was issued.
+  let grace_options = options
+    .clone()
+    .key_revocation_policy(KeyRevocationPolicy::Grace)
+    .key_revocation_grace_period(Duration::days(30))
+    .clock(FixedClock::new(issuance_date.checked_add(Duration::days(10)).unwrap()));
+  assert!(JWT_CREDENTIAL_VALIDATOR_ED25519
+    .validate_with_history::<_, Object>(
+      &jwt,
+      issuer_at_issuance,
+      &current_issuer,
+      &grace_options,
+      FailFast::FirstError
+    )
+    .is_ok());
+
+  // Grace: the removed key is no longer accepted once the grace period has elapsed.
+  let elapsed_grace_options = grace_options
+    .clone()
+    .clock(FixedClock::new(issuance_date.checked_add(Duration::days(31)).unwrap()));
+  let validation_errors = JWT_CREDENTIAL_VALIDATOR_ED25519
+    .validate_with_history::<_, Object>(
+      &jwt,
+      issuer_at_issuance,
+      &current_issuer,
+      &elapsed_grace_options,
+      FailFast::FirstError,
+    )
+    .unwrap_err()
+    .validation_errors;
+  assert!(matches!(
+    validation_errors.as_slice(),
+    [JwtValidationError::SigningKeyRevoked]
+  ));
+
+  // Grace: the grace period starts when the key was removed, if known.
+  let key_removed_at: Timestamp = issuance_date.checked_add(Duration::days(40)).unwrap();
+  let removal_grace_options = grace_options
+    .clone()
+    .key_removed_at(key_removed_at)
+    .clock(FixedClock::new(key_removed_at.checked_add(Duration::days(10)).unwrap()));
+  assert!(JWT_CREDENTIAL_VALIDATOR_ED25519
+    .validate_with_history::<_, Object>(
+      &jwt,
+      issuer_at_issuance,
+      &current_issuer,
+      &removal_grace_options,
+      FailFast::FirstError
+    )
+    .is_ok());
+  let elapsed_removal_grace_options = removal_grace_options
+    .clone()
+    .clock(FixedClock::new(key_removed_at.checked_add(Duration::days(31)).unwrap()));
+  assert!(JWT_CREDENTIAL_VALIDATOR_ED25519
+    .validate_with_history::<_, Object>(
+      &jwt,
+      issuer_at_issuance,
+      &current_issuer,
+      &elapsed_removal_grace_options,
+      FailFast::FirstError
+    )
+    .is_err());
+
+  // Strict: removing the key invalidates the credential.
+  let validation_errors = JWT_CREDENTIAL_VALIDATOR_ED25519
+    .validate_with_history::<_, Object>(
+      &jwt,
+      issuer_at_issuance,
+      &current_issuer,
+      &options,
+      FailFast::FirstError,
+    )
+    .unwrap_err()
+    .validation_errors;
+  assert!(matches!(
+    validation_errors.as_slice(),
+    [JwtValidationError::SigningKeyRevoked]
+  ));
+  assert!(JWT_CREDENTIAL_VALIDATOR_ED25519
+    .validate_with_history::<_, Object>(
+      &jwt,
+      issuer_at_issuance,
+      issuer_at_issuance,
+      &options,
+      FailFast::FirstError
+    )
+    .is_ok());
+
+  // The issuer's DID Document at issuance time does not help if the key was not part of it yet.
+  assert!(JWT_CREDENTIAL_VALIDATOR_ED25519
+    .validate_with_history::<_, Object>(
+      &jwt,
+      &current_issuer,
+      &current_issuer,
+      &grace_options,
+      FailFast::FirstError
+    )
+    .is_err());
+
+  // The credential cannot be validated as of a time before it was issued.
+  let before_issuance = options.validate_at(issuance_date.checked_sub(Duration::seconds(1)).unwrap());
+  let validation_errors = JWT_CREDENTIAL_VALIDATOR_ED25519
+    .validate_with_history::<_, Object>(
+      &jwt,
+      issuer_at_issuance,
+      issuer_at_issuance,
+      &before_issuance,
+      FailFast::FirstError,
+    )
+    .unwrap_err()
+    .validation_errors;
+  assert!(matches!(
+    validation_errors.as_slice(),
+    [JwtValidationError::IssuanceDate]
+  ));
+}
+
+#[tokio::test]
+async fn validation_with_history() {
+  validation_with_history_impl(test_utils::setup_coredocument(None, None).await).await;
+  validation_with_history_impl(test_utils::setup_iotadocument(None, None).await).await;
+}
+
 async fn matches_issuer_did_unrelated_issuer_impl<T>(setup: Setup<T, T>)
 where
   T: JwkDocumentExt + AsRef<CoreDocument>,
@@ -0,0 +1,157 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_credential::credential::Credential;
+use identity_credential::credential::CredentialBuilder;
+use identity_credential::credential::Jwt;
+use identity_credential::credential::Subject;
+use identity_credential::delegation::ChainValidator;
+use identity_credential::delegation::DelegationCredentialBuilder;
+use identity_credential::delegation::DelegationError;
+use identity_credential::delegation::DelegationEvidence;
+use identity_credential::validator::FailFast;
+use identity_credential::validator::JwtCredentialValidationOptions;
+use identity_did::CoreDID;
+use identity_did::DID;
+use identity_document::document::CoreDocument;
+use identity_eddsa_verifier::EdDSAJwsVerifier;
+
+use crate::storage::tests::test_utils;
+use crate::storage::tests::test_utils::Setup;
+use crate::storage::JwkDocumentExt;
+use crate::storage::JwsSignatureOptions;
+
+fn end_credential(issuer: &CoreDID, credential_type: &str, evidence: DelegationEvidence) -> Credential {
+  let mut credential: Credential = CredentialBuilder::default()
+    .issuer(Url::parse(issuer.as_str()).unwrap())
+    .type_(credential_type)
+    .subject(Subject::with_id(Url::parse("did:example:holder").unwrap()))
+    .issuance_date(Timestamp::now_utc())
+    .build()
+    .unwrap();
+  evidence.attach(&mut credential);
+  credential
+}
+
+async fn credential_chain_impl<T>(setup: Setup<T, T>)
+where
+  T: JwkDocumentExt + AsRef<CoreDocument>,
+{
+  let Setup {
+    issuer_doc: root_doc,
+    subject_doc: intermediate_doc,
+    issuer_storage: root_storage,
+    issuer_method_fragment: root_fragment,
+    subject_storage: intermediate_storage,
+    subject_method_fragment: intermediate_fragment,
+  } = setup;
+  let root: CoreDID = root_doc.as_ref().id().clone();
+  let intermediate: CoreDID = intermediate_doc.as_ref().id().clone();
+
+  let delegation: Credential<Object> = DelegationCredentialBuilder::new()
+    .issuer(root.clone())
+    .delegate(intermediate.clone())
+    .credential_type("MandateCredential")
+    .build()
+    .unwrap();
+  let delegation_jwt: Jwt = root_doc
+    .create_credential_jwt(
+      &delegation,
+      &root_storage,
+      &root_fragment,
+      &JwsSignatureOptions::default(),
+      None,
+    )
+    .await
+    .unwrap();
+
+  let (intermediate_doc, intermediate_storage, intermediate_fragment) =
+    (&intermediate_doc, &intermediate_storage, intermediate_fragment.as_str());
+  let sign = move |credential: Credential| async move {
+    intermediate_doc
+      .create_credential_jwt(
+        &credential,
+        intermediate_storage,
+        intermediate_fragment,
+        &JwsSignatureOptions::default(),
+        None,
+      )
+      .await
+      .unwrap()
+  };
+  let evidence = DelegationEvidence::new(vec![delegation_jwt.clone()]);
+  let mandate_jwt: Jwt = sign(end_credential(&intermediate, "MandateCredential", evidence.clone())).await;
+
+  let validator = ChainValidator::with_signature_verifier(EdDSAJwsVerifier::default());
+  let issuers: [&CoreDocument; 2] = [root_doc.as_ref(), intermediate_doc.as_ref()];
+  let options = JwtCredentialValidationOptions::default();
+
+  let chain = validator
+    .validate(&mandate_jwt, &[root.clone()], &issuers, &options, FailFast::FirstError)
+    .unwrap();
+  assert_eq!(chain.root, root);
+  assert_eq!(chain.delegations.len(), 1);
+
+  // The cached delegation is reused for subsequent credentials.
+  assert!(validator
+    .validate(&mandate_jwt, &[root.clone()], &issuers, &options, FailFast::FirstError)
+    .is_ok());
+
+  // The root must be trusted.
+  assert!(matches!(
+    validator.validate(&mandate_jwt, &[], &issuers, &options, FailFast::FirstError),
+    Err(DelegationError::UntrustedRoot(did)) if did == root
+  ));
+
+  // The delegation only covers mandates.
+  let other_jwt: Jwt = sign(end_credential(&intermediate, "OtherCredential", evidence)).await;
+  assert!(matches!(
+    validator.validate(&other_jwt, &[root.clone()], &issuers, &options, FailFast::FirstError),
+    Err(DelegationError::OutOfScope(0))
+  ));
+
+  // Without evidence, the intermediate has no authority.
+  let unbacked_jwt: Jwt = sign(end_credential(
+    &intermediate,
+    "MandateCredential",
+    DelegationEvidence::default(),
+  ))
+  .await;
+  assert!(matches!(
+    validator.validate(&unbacked_jwt, &[root.clone()], &issuers, &options, FailFast::FirstError),
+    Err(DelegationError::UntrustedRoot(did)) if did == intermediate
+  ));
+
+  // A regular credential cannot be used as a delegation.
+  let not_a_delegation: Jwt = sign(end_credential(
+    &intermediate,
+    "MandateCredential",
+    DelegationEvidence::default(),
+  ))
+  .await;
+  let forged_jwt: Jwt = sign(end_credential(
+    &intermediate,
+    "MandateCredential",
+    DelegationEvidence::new(vec![not_a_delegation]),
+  ))
+  .await;
+  assert!(matches!(
+    validator.validate(
+      &forged_jwt,
+      &[intermediate.clone()],
+      &issuers,
+      &options,
+      FailFast::FirstError
+    ),
+    Err(DelegationError::NotADelegationCredential(0))
+  ));
+}
+
+#[tokio::test]
+async fn credential_chain() {
+  credential_chain_impl(test_utils::setup_coredocument(None, None).await).await;
+  credential_chain_impl(test_utils::setup_iotadocument(None, None).await).await;
+}
@@ -0,0 +1,123 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Duration;
+use identity_core::common::Timestamp;
+use identity_credential::credential::Jwt;
+use identity_credential::did_jwt::DecodedDidJwt;
+use identity_credential::did_jwt::DidJwtError;
+use identity_credential::did_jwt::DidJwtValidationOptions;
+use identity_credential::did_jwt::DidJwtValidator;
+use identity_did::DID;
+use identity_document::document::CoreDocument;
+use identity_eddsa_verifier::EdDSAJwsVerifier;
+use serde_json::json;
+
+use crate::storage::tests::test_utils::setup_coredocument;
+use crate::storage::tests::test_utils::setup_iotadocument;
+use crate::storage::tests::test_utils::Setup;
+use crate::DidJwtBuilder;
+use crate::JwkDocumentExt;
+
+async fn did_jwt_impl<T>(setup: Setup<T, T>)
+where
+  T: JwkDocumentExt + AsRef<CoreDocument>,
+{
+  let Setup {
+    issuer_doc,
+    subject_doc,
+    issuer_storage,
+    issuer_method_fragment,
+    ..
+  } = setup;
+  let service: String = subject_doc.as_ref().id().to_string();
+
+  let jwt: Jwt = DidJwtBuilder::new()
+    .subject("session-1")
+    .audience(service.clone())
+    .expiration_date(Timestamp::now_utc().checked_add(Duration::hours(1)).unwrap())
+    .claim("scope", "read write")
+    .claim("limits", json!({ "requests": 100 }))
+    .sign(&issuer_doc, &issuer_storage, &issuer_method_fragment)
+    .await
+    .unwrap();
+
+  let validator = DidJwtValidator::with_signature_verifier(EdDSAJwsVerifier::default());
+  let options = DidJwtValidationOptions::new()
+    .typ(DidJwtBuilder::DEFAULT_TYP)
+    .audience(service.clone())
+    .subject("session-1")
+    .require_expiration_date(true);
+
+  let decoded: DecodedDidJwt = validator.validate(&jwt, &issuer_doc, &options).unwrap();
+  assert_eq!(decoded.claims.iss.as_deref(), Some(issuer_doc.as_ref().id().as_str()));
+  assert!(decoded.claims.iat.is_some());
+  assert_eq!(decoded.claims.custom.get("scope"), Some(&json!("read write")));
+  assert_eq!(decoded.claims.custom.get("limits"), Some(&json!({ "requests": 100 })));
+
+  // The token must be signed by the document it is validated against, for the expected audience and subject.
+  assert!(matches!(
+    validator.validate(&jwt, &subject_doc, &options),
+    Err(DidJwtError::InvalidSignature(_))
+  ));
+  assert!(matches!(
+    validator.validate(&jwt, &issuer_doc, &options.clone().audience("did:example:other")),
+    Err(DidJwtError::AudienceMismatch)
+  ));
+  assert!(matches!(
+    validator.validate(&jwt, &issuer_doc, &options.clone().subject("session-2")),
+    Err(DidJwtError::UnexpectedSubject(_))
+  ));
+  assert!(matches!(
+    validator.validate(
+      &jwt,
+      &issuer_doc,
+      &options
+        .clone()
+        .earliest_expiry_date(Timestamp::now_utc().checked_add(Duration::hours(2)).unwrap())
+    ),
+    Err(DidJwtError::Expired)
+  ));
+
+  // The issuer claim, if set, must be the DID of the signing document.
+  let forged: Jwt = DidJwtBuilder::new()
+    .issuer(service.clone())
+    .sign(&issuer_doc, &issuer_storage, &issuer_method_fragment)
+    .await
+    .unwrap();
+  assert!(matches!(
+    validator.validate(&forged, &issuer_doc, &DidJwtValidationOptions::new()),
+    Err(DidJwtError::IssuerMismatch)
+  ));
+
+  let unbounded: Jwt = DidJwtBuilder::new()
+    .typ("at+jwt")
+    .not_before(Timestamp::now_utc().checked_add(Duration::hours(1)).unwrap())
+    .sign(&issuer_doc, &issuer_storage, &issuer_method_fragment)
+    .await
+    .unwrap();
+  assert!(matches!(
+    validator.validate(&unbounded, &issuer_doc, &options),
+    Err(DidJwtError::InvalidType(_))
+  ));
+  assert!(matches!(
+    validator.validate(&unbounded, &issuer_doc, &DidJwtValidationOptions::new()),
+    Err(DidJwtError::NotYetValid)
+  ));
+  assert!(matches!(
+    validator.validate(
+      &unbounded,
+      &issuer_doc,
+      &DidJwtValidationOptions::new()
+        .latest_issuance_date(Timestamp::now_utc().checked_add(Duration::hours(2)).unwrap())
+        .require_expiration_date(true)
+    ),
+    Err(DidJwtError::MissingExpirationDate)
+  ));
+}
+
+#[tokio::test]
+async fn did_jwt() {
+  did_jwt_impl(setup_coredocument(None, None).await).await;
+  did_jwt_impl(setup_iotadocument(None, None).await).await;
+}
@@ -0,0 +1,134 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Duration;
+use identity_core::common::FixedClock;
+use identity_core::common::Timestamp;
+use identity_core::common::Value;
+use identity_eddsa_verifier::EdDSAJwsVerifier;
+use identity_iota_core::AttestationVerificationOptions;
+use identity_iota_core::DocumentAttestation;
+use identity_iota_core::Error;
+use identity_iota_core::IotaDocument;
+
+use crate::storage::tests::test_utils::setup_iotadocument;
+use crate::storage::tests::test_utils::Setup;
+use crate::storage::DocumentAttestationExt;
+
+#[tokio::test]
+async fn attestation_can_be_verified_offline() {
+  let Setup {
+    issuer_doc,
+    issuer_storage,
+    issuer_method_fragment,
+    ..
+  } = setup_iotadocument(None, None).await;
+
+  let attestation: DocumentAttestation = issuer_doc
+    .attest(&issuer_storage, &issuer_method_fragment, Duration::days(1))
+    .await
+    .unwrap();
+  assert_eq!(
+    attestation.expires_at(),
+    attestation.issued_at().checked_add(Duration::days(1)).unwrap()
+  );
+  assert!(attestation
+    .verify(
+      &issuer_doc,
+      &EdDSAJwsVerifier::default(),
+      &AttestationVerificationOptions::default()
+    )
+    .is_ok());
+}
+
+#[tokio::test]
+async fn tampered_attestation_is_rejected() {
+  let Setup {
+    issuer_doc,
+    subject_doc,
+    issuer_storage,
+    issuer_method_fragment,
+    ..
+  } = setup_iotadocument(None, None).await;
+
+  let attestation: DocumentAttestation = issuer_doc
+    .attest(&issuer_storage, &issuer_method_fragment, Duration::days(1))
+    .await
+    .unwrap();
+
+  let mut tampered: IotaDocument = attestation.document().clone();
+  tampered
+    .properties_mut_unchecked()
+    .insert("tampered".to_owned(), Value::Bool(true));
+  let tampered: DocumentAttestation = DocumentAttestation::new(
+    tampered,
+    attestation.issued_at(),
+    attestation.expires_at(),
+    attestation.jws().clone(),
+  );
+  assert!(matches!(
+    tampered.verify(
+      &issuer_doc,
+      &EdDSAJwsVerifier::default(),
+      &AttestationVerificationOptions::default()
+    ),
+    Err(Error::JwsVerificationError(_))
+  ));
+
+  // The validity period is signed as well.
+  let extended: DocumentAttestation = DocumentAttestation::new(
+    attestation.document().clone(),
+    attestation.issued_at(),
+    attestation.expires_at().checked_add(Duration::days(365)).unwrap(),
+    attestation.jws().clone(),
+  );
+  assert!(matches!(
+    extended.verify(
+      &issuer_doc,
+      &EdDSAJwsVerifier::default(),
+      &AttestationVerificationOptions::default()
+    ),
+    Err(Error::JwsVerificationError(_))
+  ));
+
+  assert!(matches!(
+    attestation.verify(
+      &subject_doc,
+      &EdDSAJwsVerifier::default(),
+      &AttestationVerificationOptions::default()
+    ),
+    Err(Error::InvalidAttestation(_))
+  ));
+}
+
+#[tokio::test]
+async fn stale_attestation_is_rejected() {
+  let Setup {
+    issuer_doc,
+    issuer_storage,
+    issuer_method_fragment,
+    ..
+  } = setup_iotadocument(None, None).await;
+
+  let attestation: DocumentAttestation = issuer_doc
+    .attest(&issuer_storage, &issuer_method_fragment, Duration::hours(1))
+    .await
+    .unwrap();
+  let verify_at = |timestamp: Timestamp| {
+    attestation.verify(
+      &issuer_doc,
+      &EdDSAJwsVerifier::default(),
+      &AttestationVerificationOptions::new().clock(FixedClock::new(timestamp)),
+    )
+  };
+
+  assert!(verify_at(attestation.issued_at().checked_add(Duration::minutes(59)).unwrap()).is_ok());
+  assert!(matches!(
+    verify_at(attestation.expires_at()),
+    Err(Error::InvalidAttestation(_))
+  ));
+  assert!(matches!(
+    verify_at(attestation.issued_at().checked_sub(Duration::minutes(1)).unwrap()),
+    Err(Error::InvalidAttestation(_))
+  ));
+}
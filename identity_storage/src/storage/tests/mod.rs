@@ -4,6 +4,14 @@
 mod api;
 mod credential_jws;
 mod credential_validation;
+mod delegation;
+#[cfg(feature = "did-jwt")]
+mod did_jwt;
+#[cfg(feature = "iota-document")]
+mod document_attestation;
 mod kb_jwt;
 mod presentation_validation;
 pub(crate) mod test_utils;
+#[cfg(feature = "jpt-bbs-plus")]
+mod timeframe_manager;
+mod wallet_attestation;
@@ -6,12 +6,17 @@ use identity_core::common::Object;
 use identity_core::common::StringOrUrl;
 use identity_core::common::Timestamp;
 use identity_core::convert::FromJson;
+use identity_credential::credential::Confirmation;
 use identity_credential::credential::Credential;
 use identity_credential::credential::Jwt;
 use identity_credential::presentation::JwtPresentationOptions;
 use identity_credential::presentation::Presentation;
 use identity_credential::presentation::PresentationBuilder;
+use identity_credential::validator::DecodedJwtCredential;
 use identity_credential::validator::DecodedJwtPresentation;
+use identity_credential::validator::FailFast;
+use identity_credential::validator::JwtCredentialValidationOptions;
+use identity_credential::validator::JwtCredentialValidator;
 use identity_credential::validator::JwtPresentationValidationOptions;
 use identity_credential::validator::JwtPresentationValidator;
 use identity_credential::validator::JwtPresentationValidatorUtils;
@@ -156,6 +161,110 @@ where
   );
 }
 
+#[tokio::test]
+async fn test_check_confirmation() {
+  test_check_confirmation_impl(setup_coredocument(None, None).await).await;
+  test_check_confirmation_impl(setup_iotadocument(None, None).await).await;
+}
+async fn test_check_confirmation_impl<T>(setup: Setup<T, T>)
+where
+  T: JwkDocumentExt + AsRef<CoreDocument>,
+{
+  let credential: CredentialSetup = generate_credential(&setup.issuer_doc, &[&setup.subject_doc], None, None);
+  let method_id = setup
+    .subject_doc
+    .as_ref()
+    .id()
+    .to_url()
+    .join(format!("#{}", setup.subject_method_fragment))
+    .unwrap();
+  let holder_jwk = setup
+    .subject_doc
+    .as_ref()
+    .resolve_method(&method_id, None)
+    .and_then(|method| method.data().public_key_jwk())
+    .unwrap()
+    .clone();
+
+  let presentation_jwt = {
+    let presentation: Presentation<Jwt> =
+      PresentationBuilder::new(setup.subject_doc.as_ref().id().to_url().into(), Object::new())
+        .build()
+        .unwrap();
+    setup
+      .subject_doc
+      .create_presentation_jwt(
+        &presentation,
+        &setup.subject_storage,
+        &setup.subject_method_fragment,
+        &JwsSignatureOptions::default(),
+        &JwtPresentationOptions::default(),
+      )
+      .await
+      .unwrap()
+  };
+  let decoded_presentation: DecodedJwtPresentation<Jwt> = JWT_PRESENTATION_VALIDATOR_ED25519
+    .validate::<_, Jwt, Object>(
+      &presentation_jwt,
+      &setup.subject_doc,
+      &JwtPresentationValidationOptions::default(),
+    )
+    .unwrap();
+
+  let issue = |confirmation: Option<Confirmation>| {
+    let credential = &credential.credential;
+    let setup = &setup;
+    async move {
+      let jws = setup
+        .issuer_doc
+        .create_credential_jwt(
+          credential,
+          &setup.issuer_storage,
+          &setup.issuer_method_fragment,
+          &JwsSignatureOptions::default(),
+          confirmation.as_ref().map(Confirmation::to_claims),
+        )
+        .await
+        .unwrap();
+      let decoded: DecodedJwtCredential = JwtCredentialValidator::with_signature_verifier(EdDSAJwsVerifier::default())
+        .validate(
+          &jws,
+          &setup.issuer_doc,
+          &JwtCredentialValidationOptions::default(),
+          FailFast::FirstError,
+        )
+        .unwrap();
+      decoded
+    }
+  };
+
+  for confirmation in [
+    Confirmation::kid(method_id.to_string()),
+    Confirmation::jwk(holder_jwk).unwrap(),
+  ] {
+    let decoded_credential = issue(Some(confirmation)).await;
+    assert!(JwtPresentationValidatorUtils::check_confirmation(
+      &decoded_credential,
+      &decoded_presentation,
+      &setup.subject_doc
+    )
+    .is_ok());
+  }
+
+  let other_method_id = method_id.join("#other-key").unwrap();
+  let decoded_credential = issue(Some(Confirmation::kid(other_method_id.to_string()))).await;
+  assert!(matches!(
+    JwtPresentationValidatorUtils::check_confirmation(&decoded_credential, &decoded_presentation, &setup.subject_doc),
+    Err(JwtValidationError::ConfirmationMismatch)
+  ));
+
+  let decoded_credential = issue(None).await;
+  assert!(matches!(
+    JwtPresentationValidatorUtils::check_confirmation(&decoded_credential, &decoded_presentation, &setup.subject_doc),
+    Err(JwtValidationError::MissingConfirmation)
+  ));
+}
+
 // > Create a VP signed by a verification method with `subject_method_fragment`.
 // > Replace the verification method but keep the same fragment.
 // > Validation fails due to invalid signature since key material changed.
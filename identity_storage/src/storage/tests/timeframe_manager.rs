@@ -0,0 +1,157 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Duration;
+use identity_core::common::FixedClock;
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_core::convert::FromJson;
+use identity_credential::credential::Credential;
+use identity_credential::credential::CredentialBuilder;
+use identity_credential::credential::JwpCredentialOptions;
+use identity_credential::credential::Status;
+use identity_credential::credential::Subject;
+use identity_credential::revocation::RevocationTimeframeStatus;
+use identity_credential::validator::FailFast;
+use identity_credential::validator::JptCredentialValidationOptions;
+use identity_credential::validator::JptCredentialValidator;
+use identity_did::DID;
+use identity_document::document::CoreDocument;
+use identity_verification::MethodScope;
+use jsonprooftoken::jpa::algs::ProofAlgorithm;
+use jsonprooftoken::jwp::issued::JwpIssued;
+use serde_json::json;
+
+use crate::key_id_storage::KeyIdMemstore;
+use crate::key_storage::JwkMemStore;
+use crate::JwpDocumentExt;
+use crate::Storage;
+use crate::TimeframeManager;
+
+type MemStorage = Storage<JwkMemStore, KeyIdMemstore>;
+
+const FRAGMENT: &str = "bbs-1";
+
+async fn issuer() -> (CoreDocument, MemStorage) {
+  let mut document =
+    CoreDocument::from_json(r#"{ "id": "did:bar:Hyx62wPQGyvXCoihZq1BrbUjBRh2LuNxWiiqMkfAuSZr" }"#).unwrap();
+  let storage = Storage::new(JwkMemStore::new(), KeyIdMemstore::new());
+  document
+    .generate_method_jwp(
+      &storage,
+      JwkMemStore::BLS12381G2_KEY_TYPE,
+      ProofAlgorithm::BBS,
+      Some(FRAGMENT),
+      MethodScope::VerificationMethod,
+    )
+    .await
+    .unwrap();
+
+  (document, storage)
+}
+
+async fn issue(issuer: &CoreDocument, storage: &MemStorage, status: Status) -> JwpIssued {
+  let credential: Credential = CredentialBuilder::default()
+    .issuer(Url::parse(issuer.id().as_str()).unwrap())
+    .issuance_date(Timestamp::parse("2020-01-01T00:00:00Z").unwrap())
+    .subject(Subject::from_json_value(json!({ "id": "did:foo:0xabcdef", "name": "Alice" })).unwrap())
+    .status(status)
+    .build()
+    .unwrap();
+  let jpt = issuer
+    .create_credential_jpt(&credential, storage, FRAGMENT, &JwpCredentialOptions::default(), None)
+    .await
+    .unwrap();
+
+  JptCredentialValidator::validate::<_, Object>(
+    &jpt,
+    issuer,
+    &JptCredentialValidationOptions::default(),
+    FailFast::FirstError,
+  )
+  .unwrap()
+  .decoded_jwp
+}
+
+fn timeframe_status(issuer: &CoreDocument, start: Timestamp) -> Status {
+  RevocationTimeframeStatus::new(
+    Some(start),
+    Duration::hours(1),
+    issuer.id().to_url().join("#revocation").unwrap().into(),
+    0,
+  )
+  .unwrap()
+  .into()
+}
+
+#[tokio::test]
+async fn renew_due_renews_due_credentials_at_the_clock_time() {
+  let (issuer, storage) = issuer().await;
+  let start = Timestamp::parse("2024-01-01T00:00:00Z").unwrap();
+  let now = start.checked_add(Duration::hours(2)).unwrap();
+
+  let mut manager = TimeframeManager::new(Duration::hours(1)).clock(FixedClock::new(now));
+  manager
+    .track(
+      "expired",
+      issue(&issuer, &storage, timeframe_status(&issuer, start)).await,
+    )
+    .unwrap();
+  manager
+    .track(
+      "current",
+      issue(&issuer, &storage, timeframe_status(&issuer, now)).await,
+    )
+    .unwrap();
+  assert_eq!(manager.due_for_renewal(now), ["expired"]);
+
+  let renewals = manager.renew_due(&issuer, &storage, FRAGMENT, None).await;
+  assert!(renewals.is_complete());
+  assert_eq!(renewals.renewed.len(), 1);
+  assert_eq!(renewals.renewed[0].0, "expired");
+  assert_eq!(
+    manager.end_validity_timeframe("expired"),
+    now.checked_add(Duration::hours(1))
+  );
+  assert!(manager.due_for_renewal(now).is_empty());
+}
+
+#[tokio::test]
+async fn renew_due_reports_failures_alongside_renewals() {
+  let (issuer, storage) = issuer().await;
+  let start = Timestamp::parse("2024-01-01T00:00:00Z").unwrap();
+  let now = start.checked_add(Duration::hours(2)).unwrap();
+
+  // A status lacking `startValidityTimeframe` is tracked, but cannot be renewed.
+  let end = start.checked_add(Duration::hours(1)).unwrap();
+  let incomplete_status = Status::new_with_properties(
+    issuer.id().to_url().join("#revocation").unwrap().into(),
+    RevocationTimeframeStatus::TYPE.to_owned(),
+    Object::from_json(&format!(
+      r#"{{ "{}": "{}" }}"#,
+      RevocationTimeframeStatus::END_TIMEFRAME_PROPERTY,
+      end.to_rfc3339()
+    ))
+    .unwrap(),
+  );
+
+  let mut manager = TimeframeManager::new(Duration::hours(1));
+  manager
+    .track(
+      "renewable",
+      issue(&issuer, &storage, timeframe_status(&issuer, start)).await,
+    )
+    .unwrap();
+  manager
+    .track("incomplete", issue(&issuer, &storage, incomplete_status).await)
+    .unwrap();
+
+  let renewals = manager.renew_due(&issuer, &storage, FRAGMENT, Some(now)).await;
+  assert!(!renewals.is_complete());
+  assert_eq!(renewals.renewed.len(), 1);
+  assert_eq!(renewals.renewed[0].0, "renewable");
+  assert_eq!(renewals.failed.len(), 1);
+  assert_eq!(renewals.failed[0].0, "incomplete");
+  assert_eq!(manager.due_for_renewal(now), ["incomplete"]);
+}
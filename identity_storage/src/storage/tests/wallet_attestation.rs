@@ -0,0 +1,179 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Duration;
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
+use identity_credential::credential::Confirmation;
+use identity_credential::credential::Jws;
+use identity_credential::credential::Jwt;
+use identity_credential::presentation::JwtPresentationOptions;
+use identity_credential::presentation::Presentation;
+use identity_credential::presentation::PresentationBuilder;
+use identity_credential::validator::CompoundJwtPresentationValidationError;
+use identity_credential::validator::DecodedJwtPresentation;
+use identity_credential::validator::JwtPresentationValidationOptions;
+use identity_credential::validator::JwtPresentationValidator;
+use identity_credential::wallet_attestation::DecodedWalletAttestation;
+use identity_credential::wallet_attestation::WalletAttestationClaims;
+use identity_credential::wallet_attestation::WalletAttestationError;
+use identity_credential::wallet_attestation::WalletAttestationValidationOptions;
+use identity_credential::wallet_attestation::WalletAttestationValidator;
+use identity_credential::wallet_attestation::WALLET_ATTESTATION_TYP;
+use identity_did::DID;
+use identity_document::document::CoreDocument;
+use identity_eddsa_verifier::EdDSAJwsVerifier;
+use identity_verification::jwk::Jwk;
+
+use crate::key_id_storage::KeyIdMemstore;
+use crate::key_storage::JwkMemStore;
+use crate::storage::tests::test_utils::setup_coredocument;
+use crate::storage::tests::test_utils::setup_iotadocument;
+use crate::storage::tests::test_utils::Setup;
+use crate::JwkDocumentExt;
+use crate::JwsSignatureOptions;
+use crate::Storage;
+
+type MemStorage = Storage<JwkMemStore, KeyIdMemstore>;
+type Validator = WalletAttestationValidator<EdDSAJwsVerifier>;
+
+async fn sign_presentation<T>(holder_doc: &T, storage: &MemStorage, fragment: &str) -> Jwt
+where
+  T: JwkDocumentExt + AsRef<CoreDocument>,
+{
+  let presentation: Presentation<Jwt> =
+    PresentationBuilder::new(holder_doc.as_ref().id().to_url().into(), Object::new())
+      .build()
+      .unwrap();
+  holder_doc
+    .create_presentation_jwt(
+      &presentation,
+      storage,
+      fragment,
+      &JwsSignatureOptions::default(),
+      &JwtPresentationOptions::default(),
+    )
+    .await
+    .unwrap()
+}
+
+fn validate_presentation<T>(
+  presentation: &Jwt,
+  holder_doc: &T,
+  options: &JwtPresentationValidationOptions,
+) -> Result<DecodedJwtPresentation<Jwt>, CompoundJwtPresentationValidationError>
+where
+  T: AsRef<CoreDocument>,
+{
+  JwtPresentationValidator::with_signature_verifier(EdDSAJwsVerifier::default()).validate::<_, Jwt, Object>(
+    presentation,
+    holder_doc,
+    options,
+  )
+}
+
+async fn wallet_attestation_impl<T>(setup: Setup<T, T>)
+where
+  T: JwkDocumentExt + AsRef<CoreDocument>,
+{
+  let Setup {
+    issuer_doc: provider_doc,
+    subject_doc: wallet_doc,
+    issuer_storage: provider_storage,
+    issuer_method_fragment: provider_fragment,
+    subject_storage: wallet_storage,
+    subject_method_fragment: wallet_fragment,
+  } = setup;
+
+  let wallet_key: Jwk = wallet_doc
+    .as_ref()
+    .resolve_method(wallet_fragment.as_str(), None)
+    .and_then(|method| method.data().public_key_jwk())
+    .cloned()
+    .unwrap();
+  let claims = WalletAttestationClaims::new(
+    provider_doc.as_ref().id(),
+    "wallet-1",
+    Confirmation::jwk(wallet_key).unwrap(),
+    Timestamp::now_utc(),
+    Timestamp::now_utc().checked_add(Duration::hours(1)).unwrap(),
+  )
+  .wallet_name("Example Wallet");
+  let payload: Vec<u8> = serde_json::to_vec(&claims).unwrap();
+  let sign_attestation = |typ: &'static str| {
+    let (provider_doc, provider_storage, provider_fragment, payload) = (
+      &provider_doc,
+      &provider_storage,
+      provider_fragment.as_str(),
+      payload.as_slice(),
+    );
+    async move {
+      let jws: Jws = provider_doc
+        .create_jws(
+          provider_storage,
+          provider_fragment,
+          payload,
+          &JwsSignatureOptions::new().typ(typ),
+        )
+        .await
+        .unwrap();
+      Jwt::new(jws.into())
+    }
+  };
+
+  let validator: Validator = WalletAttestationValidator::with_signature_verifier(EdDSAJwsVerifier::default());
+  let attestation_jwt: Jwt = sign_attestation(WALLET_ATTESTATION_TYP).await;
+  let options = WalletAttestationValidationOptions::new().wallet_id("wallet-1");
+
+  let attestation: DecodedWalletAttestation = validator.validate(&attestation_jwt, &provider_doc, &options).unwrap();
+  assert_eq!(attestation.wallet_id(), "wallet-1");
+  assert_eq!(attestation.claims.wallet_name.as_deref(), Some("Example Wallet"));
+
+  // The attestation must be issued by the provider it is validated against, for the expected wallet and with the
+  // expected type.
+  assert!(matches!(
+    validator.validate(&attestation_jwt, &wallet_doc, &options),
+    Err(WalletAttestationError::InvalidSignature(_))
+  ));
+  assert!(matches!(
+    validator.validate(&attestation_jwt, &provider_doc, &options.clone().wallet_id("wallet-2")),
+    Err(WalletAttestationError::UnexpectedWallet(_))
+  ));
+  assert!(matches!(
+    validator.validate(
+      &attestation_jwt,
+      &provider_doc,
+      &options
+        .clone()
+        .earliest_expiry_date(Timestamp::now_utc().checked_add(Duration::hours(2)).unwrap())
+    ),
+    Err(WalletAttestationError::Expired)
+  ));
+  assert!(matches!(
+    validator.validate(&sign_attestation("JWT").await, &provider_doc, &options),
+    Err(WalletAttestationError::InvalidType(_))
+  ));
+
+  // Only presentations signed with the attested key are bound to the wallet.
+  let presentation_jwt: Jwt = sign_presentation(&wallet_doc, &wallet_storage, &wallet_fragment).await;
+  let presentation = validate_presentation(&presentation_jwt, &wallet_doc, &Default::default()).unwrap();
+  assert!(Validator::check_holder_binding(&attestation, &presentation, &wallet_doc).is_ok());
+
+  let other_presentation_jwt: Jwt = sign_presentation(&provider_doc, &provider_storage, &provider_fragment).await;
+  let other_presentation = validate_presentation(&other_presentation_jwt, &provider_doc, &Default::default()).unwrap();
+  assert!(matches!(
+    Validator::check_holder_binding(&attestation, &other_presentation, &provider_doc),
+    Err(WalletAttestationError::HolderBindingMismatch(_))
+  ));
+
+  // The presentation validator enforces the binding when the attestation is required.
+  let options = JwtPresentationValidationOptions::new().wallet_attestation(attestation);
+  assert!(validate_presentation(&presentation_jwt, &wallet_doc, &options).is_ok());
+  assert!(validate_presentation(&other_presentation_jwt, &provider_doc, &options).is_err());
+}
+
+#[tokio::test]
+async fn wallet_attestation() {
+  wallet_attestation_impl(setup_coredocument(None, None).await).await;
+  wallet_attestation_impl(setup_iotadocument(None, None).await).await;
+}
@@ -0,0 +1,250 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use identity_core::common::Clock;
+use identity_core::common::Duration;
+use identity_core::common::SharedClock;
+use identity_core::common::Timestamp;
+use identity_core::convert::ToJson;
+use identity_credential::credential::Jpt;
+use identity_credential::revocation::RevocationTimeframeStatus;
+use jsonprooftoken::jpt::claims::JptClaims;
+use jsonprooftoken::jwp::issued::JwpIssued;
+
+use super::JwkStorageDocumentError as Error;
+use super::TimeframeRevocationExtension;
+use crate::JwkStorageBbsPlusExt;
+use crate::KeyIdStorage;
+use crate::Storage;
+use crate::StorageResult;
+
+/// A credential tracked by a [`TimeframeManager`].
+struct TrackedCredential {
+  jwp: JwpIssued,
+  end_validity_timeframe: Timestamp,
+}
+
+impl TrackedCredential {
+  fn new(jwp: JwpIssued) -> StorageResult<Self> {
+    let end_validity_timeframe = end_validity_timeframe(&jwp)?;
+    Ok(Self {
+      jwp,
+      end_validity_timeframe,
+    })
+  }
+
+  fn renewal_time(&self, renewal_window: Duration) -> Timestamp {
+    self
+      .end_validity_timeframe
+      .checked_sub(renewal_window)
+      .unwrap_or(self.end_validity_timeframe)
+  }
+}
+
+/// The outcome of [`TimeframeManager::renew_due`].
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct TimeframeRenewals {
+  /// The renewed credentials, as JPTs, together with their IDs, sorted by ID.
+  pub renewed: Vec<(String, Jpt)>,
+  /// The credentials whose renewal failed, together with their IDs and the cause of the failure, sorted by ID.
+  pub failed: Vec<(String, Error)>,
+}
+
+impl TimeframeRenewals {
+  /// Returns whether every credential that was due for renewal was renewed.
+  pub fn is_complete(&self) -> bool {
+    self.failed.is_empty()
+  }
+}
+
+/// Issuer-side bookkeeping of the credentials issued with a `RevocationTimeframe2024` status.
+///
+/// The validity timeframe of such credentials must be periodically renewed by the issuer. A [`TimeframeManager`]
+/// tracks the issued credentials, computes which of them are due for renewal and renews them in bulk through
+/// [`TimeframeRevocationExtension::update`].
+pub struct TimeframeManager {
+  duration: Duration,
+  renewal_window: Duration,
+  credentials: HashMap<String, TrackedCredential>,
+  clock: SharedClock,
+}
+
+impl TimeframeManager {
+  /// Creates a new [`TimeframeManager`] renewing credentials for timeframes lasting `duration`.
+  ///
+  /// Credentials are due for renewal once their validity timeframe has ended, see
+  /// [`TimeframeManager::renewal_window`] to renew them ahead of time.
+  pub fn new(duration: Duration) -> Self {
+    Self {
+      duration,
+      renewal_window: Duration::seconds(0),
+      credentials: HashMap::new(),
+      clock: SharedClock::default(),
+    }
+  }
+
+  /// Makes credentials due for renewal `window` before their validity timeframe ends.
+  pub fn renewal_window(mut self, window: Duration) -> Self {
+    self.renewal_window = window;
+    self
+  }
+
+  /// Sets the clock providing the time at which [`TimeframeManager::renew_due`] renews credentials when no time is
+  /// given.
+  ///
+  /// Defaults to the [`SystemClock`](identity_core::common::SystemClock).
+  pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+    self.clock = SharedClock::new(clock);
+    self
+  }
+
+  /// Returns the duration of the timeframes of renewed credentials.
+  pub fn duration(&self) -> Duration {
+    self.duration
+  }
+
+  /// Starts tracking the issued credential `jwp` under `id`, returning the credential previously tracked under `id`,
+  /// if any.
+  ///
+  /// # Errors
+  /// Fails if the credential has no valid `RevocationTimeframe2024` status.
+  pub fn track(&mut self, id: impl Into<String>, jwp: JwpIssued) -> StorageResult<Option<JwpIssued>> {
+    let credential = TrackedCredential::new(jwp)?;
+    Ok(
+      self
+        .credentials
+        .insert(id.into(), credential)
+        .map(|previous| previous.jwp),
+    )
+  }
+
+  /// Stops tracking the credential tracked under `id`, returning it.
+  pub fn untrack(&mut self, id: &str) -> Option<JwpIssued> {
+    self.credentials.remove(id).map(|credential| credential.jwp)
+  }
+
+  /// Returns the credential tracked under `id`.
+  pub fn get(&self, id: &str) -> Option<&JwpIssued> {
+    self.credentials.get(id).map(|credential| &credential.jwp)
+  }
+
+  /// Returns the end of the validity timeframe of the credential tracked under `id`.
+  pub fn end_validity_timeframe(&self, id: &str) -> Option<Timestamp> {
+    self
+      .credentials
+      .get(id)
+      .map(|credential| credential.end_validity_timeframe)
+  }
+
+  /// Returns the IDs of the tracked credentials.
+  pub fn ids(&self) -> impl Iterator<Item = &str> {
+    self.credentials.keys().map(String::as_str)
+  }
+
+  /// Returns the number of tracked credentials.
+  pub fn len(&self) -> usize {
+    self.credentials.len()
+  }
+
+  /// Returns whether no credential is tracked.
+  pub fn is_empty(&self) -> bool {
+    self.credentials.is_empty()
+  }
+
+  /// Returns the IDs of the credentials that are due for renewal at `at`.
+  pub fn due_for_renewal(&self, at: Timestamp) -> Vec<&str> {
+    let mut due: Vec<&str> = self
+      .credentials
+      .iter()
+      .filter(|(_, credential)| credential.renewal_time(self.renewal_window) <= at)
+      .map(|(id, _)| id.as_str())
+      .collect();
+    due.sort_unstable();
+
+    due
+  }
+
+  /// Returns the earliest time at which one of the tracked credentials becomes due for renewal.
+  pub fn next_renewal(&self) -> Option<Timestamp> {
+    self
+      .credentials
+      .values()
+      .map(|credential| credential.renewal_time(self.renewal_window))
+      .min()
+  }
+
+  /// Renews all the credentials that are due for renewal at `at`, which defaults to the current time of the
+  /// manager's [clock](TimeframeManager::clock).
+  ///
+  /// The renewed timeframes start at `at` and last [`TimeframeManager::duration`]. The credentials must have been
+  /// issued by `issuer` with the method identified by `fragment`.
+  ///
+  /// Renewals are performed concurrently and independently of each other: the returned [`TimeframeRenewals`] holds
+  /// the renewed credentials as well as the failures. Credentials whose renewal failed remain due for renewal.
+  pub async fn renew_due<DOC, K, I>(
+    &mut self,
+    issuer: &DOC,
+    storage: &Storage<K, I>,
+    fragment: &str,
+    at: Option<Timestamp>,
+  ) -> TimeframeRenewals
+  where
+    DOC: TimeframeRevocationExtension,
+    K: JwkStorageBbsPlusExt,
+    I: KeyIdStorage,
+  {
+    let at = at.unwrap_or_else(|| self.clock.now());
+    let duration = self.duration;
+    let renewal_window = self.renewal_window;
+
+    let renewals = self
+      .credentials
+      .iter_mut()
+      .filter(|(_, credential)| credential.renewal_time(renewal_window) <= at)
+      .map(|(id, credential)| async move {
+        let result = issuer
+          .update(storage, fragment, Some(at), duration, &mut credential.jwp)
+          .await
+          .and_then(|jpt| {
+            credential.end_validity_timeframe = end_validity_timeframe(&credential.jwp)?;
+            Ok(jpt)
+          });
+
+        (id.clone(), result)
+      });
+
+    let mut outcome = TimeframeRenewals::default();
+    for (id, result) in futures::future::join_all(renewals).await {
+      match result {
+        Ok(jpt) => outcome.renewed.push((id, jpt)),
+        Err(error) => outcome.failed.push((id, error)),
+      }
+    }
+    outcome.renewed.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+    outcome.failed.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+
+    outcome
+  }
+}
+
+/// Extracts the end of the validity timeframe from the claims of the issued credential `jwp`.
+fn end_validity_timeframe(jwp: &JwpIssued) -> StorageResult<Timestamp> {
+  let claims = jwp
+    .get_claims()
+    .ok_or_else(|| Error::ProofUpdateError("claims NOT found".to_owned()))?;
+  let claims = JptClaims::from_claims_and_payloads(claims, jwp.get_payloads())
+    .to_json_value()
+    .map_err(|err| Error::EncodingError(Box::new(err)))?;
+
+  claims
+    .pointer(&format!(
+      "/vc/credentialStatus/{}",
+      RevocationTimeframeStatus::END_TIMEFRAME_PROPERTY
+    ))
+    .and_then(|value| value.as_str())
+    .and_then(|end_validity_timeframe| Timestamp::parse(end_validity_timeframe).ok())
+    .ok_or_else(|| Error::ProofUpdateError("'endValidityTimeframe' property NOT found".to_owned()))
+}
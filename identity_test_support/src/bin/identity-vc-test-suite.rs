@@ -0,0 +1,191 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Command line adapter between `identity_credential` and the W3C VC test suites.
+//!
+//! ```text
+//! identity-vc-test-suite [generate] [--jwt <config>] [--jwt-aud <aud>] [--jwt-no-jws] [--jwt-presentation]
+//!                        [--jwt-decode] <input>
+//! identity-vc-test-suite jose-secure <input>
+//! identity-vc-test-suite jose-verify [--key <jwk>] <input>
+//! identity-vc-test-suite report --suite <name> --results <mocha.json> [--baseline <report.json>]
+//!                        [--output <report.json>]
+//! ```
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use identity_core::convert::FromJson;
+use identity_test_support::conformance::ConformanceReport;
+use identity_test_support::conformance::ConformanceResult;
+use identity_test_support::conformance::Generator;
+use identity_test_support::conformance::JwtOptions;
+use identity_verification::jwk::Jwk;
+
+/// The seed of the identity signing generated documents.
+const SEED: u64 = 0;
+/// The implementation name recorded in reports.
+const IMPLEMENTATION: &str = "IOTA Identity";
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> ExitCode {
+  let mut args: Vec<String> = std::env::args().skip(1).collect();
+  let command = match args.first().map(String::as_str) {
+    Some("generate" | "jose-secure" | "jose-verify" | "report") => args.remove(0),
+    _ => "generate".to_owned(),
+  };
+
+  let result = match command.as_str() {
+    "jose-secure" => jose_secure(args).await,
+    "jose-verify" => jose_verify(args).await,
+    "report" => report(args),
+    _ => generate(args).await,
+  };
+  match result {
+    Ok(success) => {
+      if success {
+        ExitCode::SUCCESS
+      } else {
+        ExitCode::FAILURE
+      }
+    }
+    Err(error) => {
+      eprintln!("error: {error}");
+      ExitCode::FAILURE
+    }
+  }
+}
+
+/// Implements the generator interface of the VC Data Model 1.1 test suite.
+async fn generate(args: Vec<String>) -> ConformanceResult<bool> {
+  let mut args = Args::new(args);
+  let mut jwt = false;
+  let mut decode = false;
+  let mut options = JwtOptions::new();
+  while let Some(flag) = args.next_flag() {
+    match flag.as_str() {
+      // The suite passes its own signing keys, documents are signed with the key of the generator instead.
+      "--jwt" => {
+        args.value(&flag)?;
+        jwt = true;
+      }
+      "--jwt-aud" => options = options.audience(args.value(&flag)?),
+      "--jwt-no-jws" => options = options.unsigned(true),
+      "--jwt-presentation" => options = options.presentation(true),
+      "--jwt-decode" => decode = true,
+      _ => return Err(format!("unknown flag `{flag}`").into()),
+    }
+  }
+  let input = std::fs::read_to_string(args.input()?)?;
+
+  let generator = Generator::new(SEED).await?;
+  let output = if decode {
+    generator.decode_jwt(&input)?
+  } else if jwt {
+    generator.generate_jwt(&input, &options).await?
+  } else {
+    generator.generate(&input)?
+  };
+  println!("{output}");
+
+  Ok(true)
+}
+
+/// Secures a VC Data Model 2.0 credential as a `vc+jwt`.
+async fn jose_secure(args: Vec<String>) -> ConformanceResult<bool> {
+  let mut args = Args::new(args);
+  if let Some(flag) = args.next_flag() {
+    return Err(format!("unknown flag `{flag}`").into());
+  }
+  let input = std::fs::read_to_string(args.input()?)?;
+
+  println!("{}", Generator::new(SEED).await?.secure_jose(&input).await?);
+  Ok(true)
+}
+
+/// Verifies a `vc+jwt` and prints the secured credential.
+async fn jose_verify(args: Vec<String>) -> ConformanceResult<bool> {
+  let mut args = Args::new(args);
+  let mut public_key = None;
+  while let Some(flag) = args.next_flag() {
+    match flag.as_str() {
+      "--key" => public_key = Some(Jwk::from_json(&std::fs::read_to_string(args.value(&flag)?)?)?),
+      _ => return Err(format!("unknown flag `{flag}`").into()),
+    }
+  }
+  let input = std::fs::read_to_string(args.input()?)?;
+
+  println!(
+    "{}",
+    Generator::new(SEED).await?.verify_jose(&input, public_key.as_ref())?
+  );
+  Ok(true)
+}
+
+/// Turns mocha results into a report and compares it against a baseline, failing on regressions.
+fn report(args: Vec<String>) -> ConformanceResult<bool> {
+  let mut args = Args::new(args);
+  let mut suite = None;
+  let mut results = None;
+  let mut baseline = None;
+  let mut output = None;
+  while let Some(flag) = args.next_flag() {
+    match flag.as_str() {
+      "--suite" => suite = Some(args.value(&flag)?),
+      "--results" => results = Some(PathBuf::from(args.value(&flag)?)),
+      "--baseline" => baseline = Some(PathBuf::from(args.value(&flag)?)),
+      "--output" => output = Some(PathBuf::from(args.value(&flag)?)),
+      _ => return Err(format!("unknown flag `{flag}`").into()),
+    }
+  }
+  let suite = suite.ok_or("missing `--suite`")?;
+  let results = std::fs::read_to_string(results.ok_or("missing `--results`")?)?;
+
+  let report = ConformanceReport::from_mocha_json(suite, IMPLEMENTATION, &results)?;
+  println!("{report}");
+  if let Some(output) = output {
+    std::fs::write(output, serde_json::to_string_pretty(&report)?)?;
+  }
+
+  let Some(baseline) = baseline else {
+    return Ok(true);
+  };
+  let baseline: ConformanceReport = serde_json::from_str(&std::fs::read_to_string(baseline)?)?;
+  for test in report.improvements(&baseline) {
+    println!("newly passing: {test}");
+  }
+  let regressions = report.regressions(&baseline);
+  for test in &regressions {
+    println!("regression: {test}");
+  }
+
+  Ok(regressions.is_empty())
+}
+
+/// Minimal parser of `--flag [value]... <input>` arguments.
+struct Args(std::vec::IntoIter<String>, Option<String>);
+
+impl Args {
+  fn new(args: Vec<String>) -> Self {
+    Self(args.into_iter(), None)
+  }
+
+  /// Returns the next flag, storing the first positional argument as the input.
+  fn next_flag(&mut self) -> Option<String> {
+    for arg in self.0.by_ref() {
+      if arg.starts_with("--") {
+        return Some(arg);
+      }
+      self.1.get_or_insert(arg);
+    }
+    None
+  }
+
+  fn value(&mut self, flag: &str) -> ConformanceResult<String> {
+    self.0.next().ok_or_else(|| format!("missing value of `{flag}`").into())
+  }
+
+  fn input(self) -> ConformanceResult<String> {
+    self.1.ok_or_else(|| "missing input file".into())
+  }
+}
@@ -0,0 +1,270 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_core::common::StringOrUrl;
+use identity_core::convert::FromJson;
+use identity_core::convert::ToJson;
+use identity_credential::credential::Credential;
+use identity_credential::credential::CredentialV2;
+use identity_credential::credential::JwtCredential;
+use identity_credential::presentation::JwtPresentationOptions;
+use identity_credential::presentation::Presentation;
+use identity_eddsa_verifier::EdDSAJwsVerifier;
+use identity_storage::JwkDocumentExt;
+use identity_storage::JwsSignatureOptions;
+use identity_verification::jwk::Jwk;
+use identity_verification::jws::Decoder;
+use identity_verification::jwu;
+use serde_json::json;
+use serde_json::Value;
+
+use super::ConformanceResult;
+use crate::fixtures::FixtureIdentity;
+use crate::fixtures::Fixtures;
+use crate::fixtures::FIXTURE_JWS_FRAGMENT;
+
+/// The version of the VC Data Model a document conforms to, determined by its base context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DataModel {
+  /// [VC Data Model 1.1](https://www.w3.org/TR/vc-data-model/).
+  V1_1,
+  /// [VC Data Model 2.0](https://www.w3.org/TR/vc-data-model-2.0/).
+  V2_0,
+}
+
+impl DataModel {
+  /// Returns the data model of the JSON `document`, if its base context is known.
+  pub fn of(document: &Value) -> Option<Self> {
+    let base_context = match document.get("@context")? {
+      Value::Array(contexts) => contexts.first()?,
+      context => context,
+    };
+    if base_context == &Credential::<Object>::base_context().to_json_value().ok()? {
+      Some(Self::V1_1)
+    } else if base_context == &CredentialV2::<Object>::base_context().to_json_value().ok()? {
+      Some(Self::V2_0)
+    } else {
+      None
+    }
+  }
+}
+
+/// Options of [`Generator::generate_jwt`], mirroring the `--jwt-*` flags of the VC Data Model 1.1 test suite.
+#[derive(Debug, Clone, Default)]
+#[non_exhaustive]
+pub struct JwtOptions {
+  /// The `aud` claim of presentation JWTs (`--jwt-aud`).
+  pub audience: Option<String>,
+  /// Whether to emit an unsecured JWT with `alg` set to `none` (`--jwt-no-jws`).
+  pub unsigned: bool,
+  /// Whether the input is expected to be a presentation (`--jwt-presentation`).
+  pub presentation: bool,
+}
+
+impl JwtOptions {
+  /// Creates new default [`JwtOptions`].
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Sets [`JwtOptions::audience`].
+  pub fn audience(mut self, audience: impl Into<String>) -> Self {
+    self.audience = Some(audience.into());
+    self
+  }
+
+  /// Sets [`JwtOptions::unsigned`].
+  pub fn unsigned(mut self, unsigned: bool) -> Self {
+    self.unsigned = unsigned;
+    self
+  }
+
+  /// Sets [`JwtOptions::presentation`].
+  pub fn presentation(mut self, presentation: bool) -> Self {
+    self.presentation = presentation;
+    self
+  }
+}
+
+/// Generates and verifies documents on behalf of the W3C test suites.
+///
+/// Documents are signed with the Ed25519 method of an identity derived from a seed, so that the output of the
+/// generator is reproducible.
+#[derive(Debug)]
+pub struct Generator {
+  fixtures: Fixtures,
+  issuer: FixtureIdentity,
+}
+
+impl Generator {
+  /// Creates a new [`Generator`] signing with an identity derived from `seed`.
+  pub async fn new(seed: u64) -> ConformanceResult<Self> {
+    let mut fixtures = Fixtures::new(seed);
+    let issuer = fixtures.identity().await?;
+
+    Ok(Self { fixtures, issuer })
+  }
+
+  /// Returns the identity signing the generated documents.
+  pub fn issuer(&self) -> &FixtureIdentity {
+    &self.issuer
+  }
+
+  /// Parses and checks the credential or presentation in `input` and returns it re-serialized.
+  ///
+  /// Fails if `input` is not a well-formed credential or presentation.
+  pub fn generate(&self, input: &str) -> ConformanceResult<String> {
+    Document::parse(input)?.to_json()
+  }
+
+  /// Encodes the credential or presentation in `input` as a JWT, following
+  /// [the JWT encoding of the VC Data Model 1.1](https://www.w3.org/TR/vc-data-model/#json-web-token).
+  pub async fn generate_jwt(&self, input: &str, options: &JwtOptions) -> ConformanceResult<String> {
+    let claims = match Document::parse(input)? {
+      Document::Presentation(presentation) if options.presentation => {
+        let mut jwt_options = JwtPresentationOptions::default();
+        if let Some(audience) = options.audience.as_deref() {
+          jwt_options = jwt_options.audience(StringOrUrl::parse(audience)?);
+        }
+        presentation.serialize_jwt(&jwt_options)?
+      }
+      Document::Credential(credential) if !options.presentation => credential.serialize_jwt(None)?,
+      Document::CredentialV2(credential) if !options.presentation => credential.serialize_jwt(None)?,
+      _ if options.presentation => return Err("expected a presentation".into()),
+      _ => return Err("expected a credential".into()),
+    };
+
+    if options.unsigned {
+      let header = jwu::encode_b64_json(&json!({ "alg": "none", "typ": "JWT" }))?;
+      return Ok(format!("{header}.{}.", jwu::encode_b64(claims)));
+    }
+    self
+      .sign(claims.as_bytes(), JwsSignatureOptions::default().typ("JWT"))
+      .await
+  }
+
+  /// Decodes the credential or presentation JWT `jwt` without verifying its signature and returns the decoded
+  /// document.
+  pub fn decode_jwt(&self, jwt: &str) -> ConformanceResult<String> {
+    let decoded = Decoder::new().decode_compact_serialization(jwt.trim().as_bytes(), None)?;
+    let claims: Object = Object::from_json_slice(decoded.claims())?;
+
+    if claims.contains_key("vc") {
+      let credential = Credential::<Object>::try_from(JwtCredential::from_json_value(Value::Object(claims))?)?;
+      return Ok(credential.to_json()?);
+    }
+    if let Some(Value::Object(mut presentation)) = claims.get("vp").cloned() {
+      // The registered claims take precedence over the properties of the `vp` claim.
+      for (claim, property) in [("iss", "holder"), ("jti", "id")] {
+        if let Some(value) = claims.get(claim) {
+          presentation.insert(property.to_owned(), value.clone());
+        }
+      }
+      return Document::parse(&Value::Object(presentation).to_string())?.to_json();
+    }
+    Document::parse(&Value::Object(claims).to_string())?.to_json()
+  }
+
+  /// Secures the VC Data Model 2.0 credential in `input` as a `vc+jwt`, following
+  /// [VC-JOSE-COSE](https://www.w3.org/TR/vc-jose-cose/#securing-with-jose).
+  pub async fn secure_jose(&self, input: &str) -> ConformanceResult<String> {
+    let Document::CredentialV2(credential) = Document::parse(input)? else {
+      return Err("expected a VC Data Model 2.0 credential".into());
+    };
+    let payload = credential.serialize_jwt(None)?;
+
+    self
+      .sign(
+        payload.as_bytes(),
+        JwsSignatureOptions::default().typ("vc+jwt").cty("vc"),
+      )
+      .await
+  }
+
+  /// Verifies the `vc+jwt` `jws` and returns the secured credential.
+  ///
+  /// The signature is verified with `public_key`, or with the key of [`Generator::issuer`] if not set.
+  pub fn verify_jose(&self, jws: &str, public_key: Option<&Jwk>) -> ConformanceResult<String> {
+    let public_key = match public_key {
+      Some(public_key) => public_key,
+      None => self
+        .issuer
+        .document()
+        .resolve_method(&self.issuer.jws_method_id(), None)
+        .ok_or("missing issuer method")?
+        .data()
+        .try_public_key_jwk()?,
+    };
+    let decoded = Decoder::new()
+      .decode_compact_serialization(jws.trim().as_bytes(), None)?
+      .verify(&EdDSAJwsVerifier::default(), public_key)?;
+    if decoded.protected.typ() != Some("vc+jwt") {
+      return Err("expected a `vc+jwt` typed JWS".into());
+    }
+
+    Document::parse(std::str::from_utf8(&decoded.claims)?)?.to_json()
+  }
+
+  async fn sign(&self, payload: &[u8], options: JwsSignatureOptions) -> ConformanceResult<String> {
+    let jws = self
+      .issuer
+      .document()
+      .create_jws(self.fixtures.storage(), FIXTURE_JWS_FRAGMENT, payload, &options)
+      .await?;
+
+    Ok(jws.as_str().to_owned())
+  }
+}
+
+/// A credential or presentation checked against the data model it declares.
+enum Document {
+  Credential(Credential),
+  CredentialV2(CredentialV2),
+  Presentation(Presentation<Value>),
+}
+
+impl Document {
+  fn parse(input: &str) -> ConformanceResult<Self> {
+    let value: Value = serde_json::from_str(input)?;
+    let is_presentation = match value.get("type") {
+      Some(Value::String(type_)) => type_ == "VerifiablePresentation",
+      Some(Value::Array(types)) => types.iter().any(|type_| type_ == "VerifiablePresentation"),
+      _ => false,
+    };
+
+    if is_presentation {
+      let presentation = Presentation::<Value>::from_json_value(value)?;
+      presentation.check_structure()?;
+      // Embedded credentials must be well-formed too, while enveloped ones, e.g. JWTs, are opaque.
+      for credential in presentation
+        .verifiable_credential
+        .iter()
+        .filter(|credential| credential.is_object())
+      {
+        Self::parse(&credential.to_string())?;
+      }
+      return Ok(Self::Presentation(presentation));
+    }
+
+    match DataModel::of(&value) {
+      Some(DataModel::V2_0) => Ok(Self::CredentialV2(CredentialV2::from_json_value(value)?)),
+      _ => {
+        let credential = Credential::from_json_value(value)?;
+        credential.check_structure()?;
+        Ok(Self::Credential(credential))
+      }
+    }
+  }
+
+  fn to_json(&self) -> ConformanceResult<String> {
+    let json = match self {
+      Self::Credential(credential) => credential.to_json()?,
+      Self::CredentialV2(credential) => credential.to_json()?,
+      Self::Presentation(presentation) => presentation.to_json()?,
+    };
+
+    Ok(json)
+  }
+}
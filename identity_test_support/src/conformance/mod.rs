@@ -0,0 +1,30 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Adapter plugging `identity_credential` into the W3C conformance test suites.
+//!
+//! The suites drive an implementation through a command line interface and record which of their tests pass:
+//! - the [VC Data Model 1.1 test suite](https://github.com/w3c/vc-test-suite) calls a generator with a credential or
+//!   presentation file and expects the generated document on the standard output, or a non-zero exit code if the input
+//!   is invalid. Its `--jwt-*` flags request JWT encoded documents instead.
+//! - the [VC-JOSE-COSE test suite](https://github.com/w3c/vc-jose-cose-test-suite) has VC Data Model 2.0
+//!   credentials secured as `vc+jwt` and secured credentials verified.
+//!
+//! [`Generator`] implements the generator interface and the securing and verification of `vc+jwt`s, and is exposed
+//! by the `identity-vc-test-suite` binary. The mocha results
+//! of a suite run are turned into a [`ConformanceReport`], which can be compared against the report of a previous run
+//! to detect regressions.
+
+mod generator;
+mod report;
+
+pub use generator::DataModel;
+pub use generator::Generator;
+pub use generator::JwtOptions;
+pub use report::ConformanceReport;
+
+/// The error returned by the conformance adapter.
+pub type ConformanceError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Alias for a `Result` with the error type [`ConformanceError`].
+pub type ConformanceResult<T> = Result<T, ConformanceError>;
@@ -0,0 +1,88 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeSet;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::ConformanceResult;
+
+/// The outcome of a run of a W3C test suite, listing its tests by full title.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConformanceReport {
+  /// The name of the test suite.
+  pub suite: String,
+  /// The name of the tested implementation.
+  pub implementation: String,
+  /// The tests that passed.
+  pub passed: BTreeSet<String>,
+  /// The tests that failed.
+  pub failed: BTreeSet<String>,
+  /// The tests that were skipped, e.g. because the suite section is not supported.
+  pub pending: BTreeSet<String>,
+}
+
+impl ConformanceReport {
+  /// Creates a [`ConformanceReport`] from the results of a suite run with mocha's `json` reporter.
+  pub fn from_mocha_json(
+    suite: impl Into<String>,
+    implementation: impl Into<String>,
+    results: &str,
+  ) -> ConformanceResult<Self> {
+    let results: MochaResults = serde_json::from_str(results)?;
+    let titles = |tests: Vec<MochaTest>| tests.into_iter().map(|test| test.full_title).collect();
+
+    Ok(Self {
+      suite: suite.into(),
+      implementation: implementation.into(),
+      passed: titles(results.passes),
+      failed: titles(results.failures),
+      pending: titles(results.pending),
+    })
+  }
+
+  /// Returns the tests that passed in `baseline` but not in this report.
+  pub fn regressions(&self, baseline: &Self) -> Vec<String> {
+    baseline.passed.difference(&self.passed).cloned().collect()
+  }
+
+  /// Returns the tests that pass in this report but did not in `baseline`.
+  pub fn improvements(&self, baseline: &Self) -> Vec<String> {
+    self.passed.difference(&baseline.passed).cloned().collect()
+  }
+}
+
+impl Display for ConformanceReport {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{} ({}): {} passed, {} failed, {} pending",
+      self.suite,
+      self.implementation,
+      self.passed.len(),
+      self.failed.len(),
+      self.pending.len()
+    )
+  }
+}
+
+/// The output of mocha's `json` reporter, of which only the test titles are used.
+#[derive(Deserialize)]
+struct MochaResults {
+  #[serde(default)]
+  passes: Vec<MochaTest>,
+  #[serde(default)]
+  failures: Vec<MochaTest>,
+  #[serde(default)]
+  pending: Vec<MochaTest>,
+}
+
+#[derive(Deserialize)]
+struct MochaTest {
+  #[serde(rename = "fullTitle")]
+  full_title: String,
+}
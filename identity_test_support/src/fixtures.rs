@@ -0,0 +1,359 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Deterministic generation of DID documents, credentials, presentations and tokens for tests.
+//!
+//! [`Fixtures`] created from the same seed produce byte-for-byte identical artifacts, as long as they are asked for
+//! the same artifacts in the same order. Keys are generated by an in-memory storage drawing from a seeded random
+//! number generator and every timestamp is set to [`Fixtures::timestamp`], so the artifacts neither depend on live
+//! keys nor on the time they are generated at.
+//!
+//! ```rust,ignore
+//! use identity_test_support::fixtures::Fixtures;
+//!
+//! let mut fixtures = Fixtures::new(42);
+//! let issuer = fixtures.identity().await?;
+//! let holder = fixtures.identity().await?;
+//! let credential = fixtures.credential(&issuer, &holder)?;
+//! let jwt = fixtures.credential_jwt(&issuer, &credential).await?;
+//! ```
+
+use identity_core::common::Object;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_core::convert::FromJson;
+use identity_credential::credential::Credential;
+use identity_credential::credential::CredentialBuilder;
+use identity_credential::credential::Jwt;
+use identity_credential::credential::Subject;
+use identity_credential::presentation::JwtPresentationOptions;
+use identity_credential::presentation::PresentationBuilder;
+use identity_did::DIDUrl;
+use identity_did::DID;
+use identity_iota_core::IotaDID;
+use identity_iota_core::IotaDocument;
+use identity_storage::JwkDocumentExt;
+use identity_storage::JwkMemStore;
+use identity_storage::JwsSignatureOptions;
+use identity_storage::KeyIdMemstore;
+use identity_storage::Storage;
+use identity_verification::jws::JwsAlgorithm;
+use identity_verification::MethodScope;
+use rand::rngs::StdRng;
+use rand::RngCore;
+use rand::SeedableRng;
+use serde_json::json;
+
+/// The error returned when generating fixtures.
+pub type FixtureError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Alias for a `Result` with the error type [`FixtureError`].
+pub type FixtureResult<T> = Result<T, FixtureError>;
+
+/// The storage holding the keys of the fixtures.
+pub type FixtureStorage = Storage<JwkMemStore, KeyIdMemstore>;
+
+/// The network of the DIDs of the fixtures.
+pub const FIXTURE_NETWORK: &str = "test";
+
+/// The fragment of the Ed25519 verification method of every [`FixtureIdentity`].
+pub const FIXTURE_JWS_FRAGMENT: &str = "key-1";
+
+/// The fragment of the BBS+ verification method of every [`FixtureIdentity`].
+#[cfg(feature = "fixtures-jpt")]
+pub const FIXTURE_JWP_FRAGMENT: &str = "bbs-1";
+
+/// The default value of [`Fixtures::timestamp`], 2025-01-01T00:00:00Z.
+const DEFAULT_TIMESTAMP: i64 = 1_735_689_600;
+
+/// A DID document whose private keys are held in the [`FixtureStorage`] of the [`Fixtures`] that generated it.
+#[derive(Debug, Clone)]
+pub struct FixtureIdentity {
+  document: IotaDocument,
+}
+
+impl FixtureIdentity {
+  /// Returns the DID document of this identity.
+  pub fn document(&self) -> &IotaDocument {
+    &self.document
+  }
+
+  /// Returns the DID of this identity.
+  pub fn did(&self) -> &IotaDID {
+    self.document.id()
+  }
+
+  /// Returns the ID of the Ed25519 verification method of this identity.
+  pub fn jws_method_id(&self) -> DIDUrl {
+    self.method_id(FIXTURE_JWS_FRAGMENT)
+  }
+
+  /// Returns the ID of the BBS+ verification method of this identity.
+  #[cfg(feature = "fixtures-jpt")]
+  pub fn jwp_method_id(&self) -> DIDUrl {
+    self.method_id(FIXTURE_JWP_FRAGMENT)
+  }
+
+  fn method_id(&self, fragment: &str) -> DIDUrl {
+    // PANIC: the fragments are valid.
+    self
+      .did()
+      .to_url()
+      .join(format!("#{fragment}"))
+      .expect("valid fragment")
+  }
+}
+
+/// Deterministic generator of identities, credentials, presentations and tokens.
+pub struct Fixtures {
+  storage: FixtureStorage,
+  rng: StdRng,
+  timestamp: Timestamp,
+}
+
+impl std::fmt::Debug for Fixtures {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("Fixtures")
+      .field("timestamp", &self.timestamp)
+      .finish_non_exhaustive()
+  }
+}
+
+impl Fixtures {
+  /// Creates a new generator whose artifacts are fully determined by `seed`.
+  pub fn new(seed: u64) -> Self {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let key_rng = StdRng::seed_from_u64(rng.next_u64());
+
+    Self {
+      storage: Storage::new(JwkMemStore::new_with_rng(key_rng), KeyIdMemstore::new()),
+      rng,
+      // PANIC: the timestamp is in range.
+      timestamp: Timestamp::from_unix(DEFAULT_TIMESTAMP).expect("valid timestamp"),
+    }
+  }
+
+  /// Sets the timestamp used for the documents' metadata and the issuance of credentials and presentations.
+  pub fn with_timestamp(mut self, timestamp: Timestamp) -> Self {
+    self.timestamp = timestamp;
+    self
+  }
+
+  /// Returns the timestamp used for the documents' metadata and the issuance of credentials and presentations.
+  pub fn timestamp(&self) -> Timestamp {
+    self.timestamp
+  }
+
+  /// Returns the storage holding the private keys of the generated identities.
+  pub fn storage(&self) -> &FixtureStorage {
+    &self.storage
+  }
+
+  /// Generates a new identity with an Ed25519 verification method [`FIXTURE_JWS_FRAGMENT`] and, if the
+  /// `fixtures-jpt` feature is enabled, a BBS+ verification method [`FIXTURE_JWP_FRAGMENT`].
+  pub async fn identity(&mut self) -> FixtureResult<FixtureIdentity> {
+    let tag: String = self
+      .random_bytes::<32>()
+      .iter()
+      .map(|byte| format!("{byte:02x}"))
+      .collect();
+    let did = IotaDID::parse(format!("did:{}:{FIXTURE_NETWORK}:0x{tag}", IotaDID::METHOD))?;
+
+    let mut document = IotaDocument::new_with_id(did);
+    document.metadata.created = Some(self.timestamp);
+    document.metadata.updated = Some(self.timestamp);
+    document
+      .generate_method(
+        &self.storage,
+        JwkMemStore::ED25519_KEY_TYPE,
+        JwsAlgorithm::EdDSA,
+        Some(FIXTURE_JWS_FRAGMENT),
+        MethodScope::VerificationMethod,
+      )
+      .await?;
+
+    #[cfg(feature = "fixtures-jpt")]
+    {
+      use identity_storage::JwpDocumentExt;
+      use jsonprooftoken::jpa::algs::ProofAlgorithm;
+
+      document
+        .generate_method_jwp(
+          &self.storage,
+          JwkMemStore::BLS12381G2_KEY_TYPE,
+          ProofAlgorithm::BBS,
+          Some(FIXTURE_JWP_FRAGMENT),
+          MethodScope::VerificationMethod,
+        )
+        .await?;
+    }
+
+    Ok(FixtureIdentity { document })
+  }
+
+  /// Builds an address credential issued by `issuer` to `holder`, at [`Fixtures::timestamp`].
+  pub fn credential(&self, issuer: &FixtureIdentity, holder: &FixtureIdentity) -> FixtureResult<Credential> {
+    let subject = Subject::from_json_value(json!({
+      "id": holder.did().as_str(),
+      "name": "Alice",
+      "address": {
+        "locality": "Maxstadt",
+        "postal_code": "12344",
+        "country": "DE",
+        "street_address": "Weidenstraße 22"
+      }
+    }))?;
+
+    Ok(
+      CredentialBuilder::default()
+        .id(Url::parse("https://example.com/credentials/3732")?)
+        .issuer(Url::parse(issuer.did().as_str())?)
+        .type_("AddressCredential")
+        .subject(subject)
+        .issuance_date(self.timestamp)
+        .build()?,
+    )
+  }
+
+  /// Signs `credential` as a JWT with the Ed25519 method of `issuer`.
+  pub async fn credential_jwt(&self, issuer: &FixtureIdentity, credential: &Credential) -> FixtureResult<Jwt> {
+    Ok(
+      issuer
+        .document
+        .create_credential_jwt(
+          credential,
+          &self.storage,
+          FIXTURE_JWS_FRAGMENT,
+          &JwsSignatureOptions::default(),
+          None,
+        )
+        .await?,
+    )
+  }
+
+  /// Signs a presentation of `credentials` as a JWT with the Ed25519 method of `holder`.
+  ///
+  /// The `nonce` is set in the protected header, e.g. to answer a verifier's challenge.
+  pub async fn presentation_jwt(
+    &self,
+    holder: &FixtureIdentity,
+    credentials: impl IntoIterator<Item = Jwt>,
+    nonce: Option<&str>,
+  ) -> FixtureResult<Jwt> {
+    let presentation = credentials
+      .into_iter()
+      .fold(
+        PresentationBuilder::new(Url::parse(holder.did().as_str())?, Object::default()),
+        PresentationBuilder::credential,
+      )
+      .build()?;
+    let signature_options = nonce.map_or_else(JwsSignatureOptions::default, |nonce| {
+      JwsSignatureOptions::default().nonce(nonce.to_owned())
+    });
+
+    Ok(
+      holder
+        .document
+        .create_presentation_jwt(
+          &presentation,
+          &self.storage,
+          FIXTURE_JWS_FRAGMENT,
+          &signature_options,
+          &JwtPresentationOptions::default().issuance_date(self.timestamp),
+        )
+        .await?,
+    )
+  }
+
+  /// Signs `credential` as an SD-JWT with the Ed25519 method of `issuer`, making the claims identified by the
+  /// JSON pointers in `concealable` selectively disclosable, e.g. `/vc/credentialSubject/address/locality`.
+  ///
+  /// The salts of the disclosures are drawn from the seeded random number generator.
+  #[cfg(feature = "fixtures-sd-jwt")]
+  pub async fn sd_jwt(
+    &mut self,
+    issuer: &FixtureIdentity,
+    credential: &Credential,
+    concealable: &[&str],
+  ) -> FixtureResult<identity_credential::sd_jwt_payload::SdJwt> {
+    use identity_credential::sd_jwt_payload::Hasher as _;
+    use identity_credential::sd_jwt_payload::SdJwt;
+    use identity_credential::sd_jwt_payload::Sha256Hasher;
+    use identity_verification::jwu;
+    use serde_json::Value;
+
+    let hasher = Sha256Hasher::new();
+    let mut claims = Value::Object(credential.to_jwt_claims(None)?);
+    let mut disclosures = Vec::with_capacity(concealable.len());
+    for pointer in concealable {
+      let (parent_pointer, key) = pointer
+        .rsplit_once('/')
+        .ok_or_else(|| format!("invalid JSON pointer `{pointer}`"))?;
+      let key = key.replace("~1", "/").replace("~0", "~");
+      let parent = claims
+        .pointer_mut(parent_pointer)
+        .and_then(Value::as_object_mut)
+        .ok_or_else(|| format!("no object at `{parent_pointer}`"))?;
+      let value = parent.remove(&key).ok_or_else(|| format!("no claim at `{pointer}`"))?;
+
+      let salt = jwu::encode_b64(self.random_bytes::<16>());
+      let disclosure = jwu::encode_b64(serde_json::to_vec(&json!([salt, key, value]))?);
+      parent
+        .entry("_sd")
+        .or_insert_with(|| Value::Array(Vec::new()))
+        .as_array_mut()
+        .ok_or_else(|| format!("`_sd` at `{parent_pointer}` is not an array"))?
+        .push(hasher.encoded_digest(&disclosure).into());
+      disclosures.push(disclosure);
+    }
+    claims["_sd_alg"] = Value::from(hasher.alg_name());
+
+    let jws = issuer
+      .document
+      .create_jws(
+        &self.storage,
+        FIXTURE_JWS_FRAGMENT,
+        &serde_json::to_vec(&claims)?,
+        &JwsSignatureOptions::default().typ("vc+sd-jwt"),
+      )
+      .await?;
+    let mut sd_jwt = jws.as_str().to_owned();
+    for disclosure in &disclosures {
+      sd_jwt.push('~');
+      sd_jwt.push_str(disclosure);
+    }
+    sd_jwt.push('~');
+
+    Ok(SdJwt::parse(&sd_jwt)?)
+  }
+
+  /// Signs `credential` as a JPT with the BBS+ method of `issuer`.
+  #[cfg(feature = "fixtures-jpt")]
+  pub async fn credential_jpt(
+    &self,
+    issuer: &FixtureIdentity,
+    credential: &Credential,
+  ) -> FixtureResult<identity_credential::credential::Jpt> {
+    use identity_credential::credential::JwpCredentialOptions;
+    use identity_storage::JwpDocumentExt;
+
+    Ok(
+      issuer
+        .document
+        .create_credential_jpt(
+          credential,
+          &self.storage,
+          FIXTURE_JWP_FRAGMENT,
+          &JwpCredentialOptions::default(),
+          None,
+        )
+        .await?,
+    )
+  }
+
+  fn random_bytes<const N: usize>(&mut self) -> [u8; N] {
+    let mut bytes = [0; N];
+    self.rng.fill_bytes(&mut bytes);
+    bytes
+  }
+}
@@ -0,0 +1,116 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Invariants every DID document is expected to uphold.
+//!
+//! Every check returns a [`TestCaseError`] on failure, so that it can be used with `?` inside of a
+//! [`proptest!`](proptest::proptest) block and benefit from input shrinking.
+
+use identity_did::DIDUrl;
+use identity_document::document::CoreDocument;
+use identity_verification::MethodRef;
+use identity_verification::VerificationMethod;
+use proptest::prop_assert;
+use proptest::prop_assert_eq;
+use proptest::test_runner::TestCaseError;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Checks that `value` survives a JSON serialization round-trip unchanged, both through a string and through a
+/// [`serde_json::Value`].
+pub fn check_json_roundtrip<T>(value: &T) -> Result<(), TestCaseError>
+where
+  T: Serialize + DeserializeOwned + PartialEq + std::fmt::Debug,
+{
+  let json: String = serde_json::to_string(value).map_err(fail)?;
+  let from_string: T = serde_json::from_str(&json).map_err(fail)?;
+  prop_assert_eq!(&from_string, value);
+
+  let json_value: serde_json::Value = serde_json::to_value(value).map_err(fail)?;
+  let from_value: T = serde_json::from_value(json_value).map_err(fail)?;
+  prop_assert_eq!(&from_value, value);
+
+  Ok(())
+}
+
+/// Checks that every verification relationship of `document` resolves to a verification method.
+pub fn check_relationships_resolve(document: &CoreDocument) -> Result<(), TestCaseError> {
+  for method_ref in document.verification_relationships() {
+    let resolved: Option<&VerificationMethod> = document.resolve_method_ref(method_ref);
+    prop_assert!(resolved.is_some(), "dangling method reference `{}`", method_ref.id());
+    if let (MethodRef::Embed(embedded), Some(resolved)) = (method_ref, resolved) {
+      prop_assert_eq!(embedded, resolved);
+    }
+  }
+  Ok(())
+}
+
+/// Checks that resolving, removing and re-inserting each embedded method of `document` is consistent:
+/// - every method resolves by its id, both with and without the scope it was found in;
+/// - removing it returns the same method and removes every reference to it;
+/// - re-inserting it in the same scope makes it resolvable again.
+pub fn check_method_insertion_removal(document: &CoreDocument) -> Result<(), TestCaseError> {
+  for method in document.methods(None) {
+    let id: &DIDUrl = method.id();
+    prop_assert_eq!(document.resolve_method(id, None), Some(method));
+
+    let mut modified: CoreDocument = document.clone();
+    let (removed, scope) = modified
+      .remove_method_and_scope(id)
+      .ok_or_else(|| TestCaseError::fail(format!("method `{id}` could not be removed")))?;
+    prop_assert_eq!(&removed, method);
+    prop_assert_eq!(document.resolve_method(id, Some(scope)), Some(method));
+    prop_assert!(modified.resolve_method(id, None).is_none());
+    prop_assert!(modified
+      .verification_relationships()
+      .all(|method_ref| method_ref.id() != id));
+
+    modified.insert_method(removed, scope).map_err(fail)?;
+    prop_assert_eq!(modified.resolve_method(id, Some(scope)), Some(method));
+  }
+  Ok(())
+}
+
+/// Runs every check applicable to a [`CoreDocument`].
+pub fn check_core_document(document: &CoreDocument) -> Result<(), TestCaseError> {
+  check_json_roundtrip(document)?;
+  check_relationships_resolve(document)?;
+  check_method_insertion_removal(document)
+}
+
+#[cfg(feature = "iota-document")]
+mod iota {
+  use identity_iota_core::IotaDocument;
+  use identity_iota_core::StateMetadataDocument;
+  use identity_iota_core::StateMetadataEncoding;
+
+  use super::*;
+
+  /// Checks that packing `document` and unpacking the result yields the same document.
+  pub fn check_pack_roundtrip(document: &IotaDocument) -> Result<(), TestCaseError> {
+    let packed: Vec<u8> = document
+      .clone()
+      .pack_with_encoding(StateMetadataEncoding::Json)
+      .map_err(fail)?;
+    let unpacked: IotaDocument = StateMetadataDocument::unpack(&packed)
+      .and_then(|state_metadata| state_metadata.into_iota_document(document.id()))
+      .map_err(fail)?;
+    prop_assert_eq!(&unpacked, document);
+
+    Ok(())
+  }
+
+  /// Runs every check applicable to an [`IotaDocument`], including those for its [`CoreDocument`].
+  pub fn check_iota_document(document: &IotaDocument) -> Result<(), TestCaseError> {
+    check_json_roundtrip(document)?;
+    check_pack_roundtrip(document)?;
+    check_core_document(document.core_document())
+  }
+}
+
+#[cfg(feature = "iota-document")]
+pub use iota::*;
+
+fn fail(error: impl std::fmt::Display) -> TestCaseError {
+  TestCaseError::fail(error.to_string())
+}
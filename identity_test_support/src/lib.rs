@@ -0,0 +1,22 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![forbid(unsafe_code)]
+#![doc = include_str!("./../README.md")]
+#![warn(
+  rust_2018_idioms,
+  unreachable_pub,
+  missing_docs,
+  rustdoc::missing_crate_level_docs,
+  rustdoc::broken_intra_doc_links,
+  rustdoc::private_intra_doc_links,
+  rustdoc::private_doc_tests,
+  clippy::missing_safety_doc
+)]
+
+#[cfg(feature = "conformance")]
+pub mod conformance;
+#[cfg(feature = "fixtures")]
+pub mod fixtures;
+pub mod invariants;
+pub mod strategies;
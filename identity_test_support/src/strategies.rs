@@ -0,0 +1,370 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! [`Strategies`](Strategy) generating random, yet valid, DID documents and their components.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use identity_core::common::Object;
+use identity_core::common::OrderedSet;
+use identity_core::common::Url;
+use identity_core::common::Value;
+use identity_did::CoreDID;
+use identity_did::DIDUrl;
+use identity_did::DID;
+use identity_document::document::CoreDocument;
+use identity_document::service::Service;
+use identity_document::service::ServiceEndpoint;
+use identity_verification::jwk::EdCurve;
+use identity_verification::jwk::Jwk;
+use identity_verification::jwk::JwkParamsOkp;
+use identity_verification::jwu;
+use identity_verification::MethodData;
+use identity_verification::MethodRelationship;
+use identity_verification::MethodType;
+use identity_verification::VerificationMethod;
+use proptest::collection::btree_map;
+use proptest::collection::btree_set;
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::sample::select;
+use proptest::sample::subsequence;
+
+/// Maximum number of verification methods and services generated for a single document.
+pub const MAX_DOCUMENT_RESOURCES: usize = 12;
+
+/// Top-level keys of a DID document that must not be generated as custom properties.
+const RESERVED_PROPERTIES: &[&str] = &[
+  "id",
+  "controller",
+  "alsoKnownAs",
+  "verificationMethod",
+  "authentication",
+  "assertionMethod",
+  "keyAgreement",
+  "capabilityDelegation",
+  "capabilityInvocation",
+  "service",
+];
+
+const RELATIONSHIPS: [MethodRelationship; 5] = [
+  MethodRelationship::Authentication,
+  MethodRelationship::AssertionMethod,
+  MethodRelationship::KeyAgreement,
+  MethodRelationship::CapabilityDelegation,
+  MethodRelationship::CapabilityInvocation,
+];
+
+/// Where a generated resource is placed within a document.
+#[derive(Clone, Debug)]
+enum Placement {
+  /// A general purpose verification method, referenced by the given relationships.
+  VerificationMethod(Vec<MethodRelationship>),
+  /// A verification method embedded in the given relationship.
+  Embedded(MethodRelationship),
+  /// A service.
+  Service,
+}
+
+fn arb_placement() -> impl Strategy<Value = Placement> {
+  prop_oneof![
+    subsequence(RELATIONSHIPS.to_vec(), 0..=RELATIONSHIPS.len()).prop_map(Placement::VerificationMethod),
+    select(RELATIONSHIPS.to_vec()).prop_map(Placement::Embedded),
+    Just(Placement::Service),
+  ]
+}
+
+/// Generates a [`CoreDID`] with an arbitrary method name and method-specific identifier.
+pub fn arb_core_did() -> impl Strategy<Value = CoreDID> {
+  "did:[a-z0-9]{1,10}:[a-zA-Z0-9._-]{1,40}"
+    .prop_map(|did| CoreDID::parse(did).expect("generated DIDs conform to the DID syntax"))
+}
+
+/// Generates a DID URL fragment, without the leading `#`.
+pub fn arb_fragment() -> impl Strategy<Value = String> {
+  "[a-zA-Z0-9_-]{1,16}"
+}
+
+/// Generates an `https` [`Url`].
+pub fn arb_url() -> impl Strategy<Value = Url> {
+  "[a-z]{1,12}\\.[a-z]{2,3}(/[a-z0-9]{1,8}){0,2}"
+    .prop_map(|url| Url::parse(format!("https://{url}")).expect("generated URLs are valid"))
+}
+
+/// Generates public key material together with a matching [`MethodType`].
+pub fn arb_method_data() -> impl Strategy<Value = (MethodType, MethodData)> {
+  prop_oneof![
+    any::<[u8; 32]>().prop_map(|key| (
+      MethodType::ED25519_VERIFICATION_KEY_2018,
+      MethodData::new_multibase(key)
+    )),
+    any::<[u8; 32]>().prop_map(|key| (MethodType::ED25519_VERIFICATION_KEY_2018, MethodData::new_base58(key))),
+    any::<[u8; 32]>().prop_map(|key| (
+      MethodType::X25519_KEY_AGREEMENT_KEY_2019,
+      MethodData::new_multibase(key)
+    )),
+    any::<[u8; 32]>().prop_map(|key| (
+      MethodType::JSON_WEB_KEY_2020,
+      MethodData::PublicKeyJwk(ed25519_jwk(&key))
+    )),
+  ]
+}
+
+/// Generates a [`VerificationMethod`] identified by `did#fragment` and controlled by `did`.
+pub fn arb_verification_method(did: CoreDID, fragment: String) -> impl Strategy<Value = VerificationMethod> {
+  arb_method_data().prop_map(move |(type_, data)| method(&did, &fragment, type_, data))
+}
+
+/// Generates a [`Service`] identified by `did#fragment`.
+pub fn arb_service(did: CoreDID, fragment: String) -> impl Strategy<Value = Service> {
+  (arb_service_types(), arb_service_endpoint())
+    .prop_map(move |(types, endpoint)| service(&did, &fragment, types, endpoint))
+}
+
+/// Generates a non-empty list of distinct service types.
+pub fn arb_service_types() -> impl Strategy<Value = Vec<String>> {
+  btree_set("[A-Z][a-zA-Z]{2,20}", 1..3).prop_map(|types| types.into_iter().collect())
+}
+
+/// Generates a [`ServiceEndpoint`] of any shape.
+pub fn arb_service_endpoint() -> impl Strategy<Value = ServiceEndpoint> {
+  prop_oneof![
+    arb_url().prop_map(ServiceEndpoint::One),
+    arb_url_set().prop_map(ServiceEndpoint::Set),
+    btree_map("[a-z]{1,8}", arb_url_set(), 1..3).prop_map(|map| ServiceEndpoint::Map(map.into_iter().collect())),
+  ]
+}
+
+fn arb_url_set() -> impl Strategy<Value = OrderedSet<Url>> {
+  btree_set(arb_url(), 1..3).prop_map(|urls| urls.into_iter().collect())
+}
+
+/// Generates a JSON value of limited depth, without floating point numbers so that values survive round-trips
+/// unchanged.
+pub fn arb_json_value() -> impl Strategy<Value = Value> {
+  let leaf = prop_oneof![
+    Just(Value::Null),
+    any::<bool>().prop_map(Value::from),
+    any::<i64>().prop_map(Value::from),
+    "[a-zA-Z0-9 ]{0,16}".prop_map(Value::from),
+  ];
+  leaf.prop_recursive(2, 16, 4, |inner| {
+    prop_oneof![
+      vec(inner.clone(), 0..4).prop_map(Value::from),
+      btree_map("[a-z]{1,8}", inner, 0..4).prop_map(|map| Value::Object(map.into_iter().collect())),
+    ]
+  })
+}
+
+/// Generates custom document properties that do not collide with any property defined by DID Core.
+pub fn arb_properties() -> impl Strategy<Value = Object> {
+  btree_map(
+    "[a-z][a-zA-Z]{0,11}".prop_filter("reserved property", |key| !RESERVED_PROPERTIES.contains(&key.as_str())),
+    arb_json_value(),
+    0..4,
+  )
+  .prop_map(|properties| properties.into_iter().collect())
+}
+
+/// Generates a [`CoreDocument`] with an arbitrary identifier.
+pub fn arb_core_document() -> impl Strategy<Value = CoreDocument> {
+  arb_core_did().prop_flat_map(|id| arb_core_document_with_id(id, vec(arb_core_did(), 0..3)))
+}
+
+/// Generates a [`CoreDocument`] identified by `id`, with controllers drawn from `controllers`.
+///
+/// The document contains up to [`MAX_DOCUMENT_RESOURCES`] verification methods and services, with general purpose
+/// methods referenced from arbitrary verification relationships, methods embedded in relationships, custom
+/// properties and `alsoKnownAs` entries. All identifiers in the document are unique.
+pub fn arb_core_document_with_id(
+  id: CoreDID,
+  controllers: impl Strategy<Value = Vec<CoreDID>>,
+) -> impl Strategy<Value = CoreDocument> {
+  (
+    controllers,
+    btree_set(arb_url(), 0..3),
+    btree_set(arb_fragment(), 0..MAX_DOCUMENT_RESOURCES),
+    arb_properties(),
+  )
+    .prop_flat_map(move |(controllers, also_known_as, fragments, properties)| {
+      let fragments: Vec<String> = fragments.into_iter().collect();
+      let len: usize = fragments.len();
+      (
+        Just(id.clone()),
+        Just(controllers),
+        Just(also_known_as),
+        Just(properties),
+        Just(fragments),
+        vec(arb_placement(), len),
+        vec(arb_method_data(), len),
+        vec((arb_service_types(), arb_service_endpoint()), len),
+      )
+    })
+    .prop_map(
+      |(id, controllers, also_known_as, properties, fragments, placements, method_data, services)| {
+        build_document(DocumentParts {
+          id,
+          controllers,
+          also_known_as,
+          properties,
+          resources: fragments
+            .into_iter()
+            .zip(placements)
+            .zip(method_data.into_iter().zip(services))
+            .map(|((fragment, placement), (method_data, service))| (fragment, placement, method_data, service))
+            .collect(),
+        })
+      },
+    )
+}
+
+#[cfg(feature = "iota-document")]
+mod iota {
+  use identity_iota_core::IotaDID;
+  use identity_iota_core::IotaDocument;
+  use identity_iota_core::IotaDocumentMetadata;
+
+  use super::*;
+
+  /// Generates an [`IotaDID`] on one of the well-known networks or on a custom one.
+  pub fn arb_iota_did() -> impl Strategy<Value = IotaDID> {
+    (
+      any::<[u8; 32]>(),
+      prop_oneof![
+        Just(None),
+        select(vec!["testnet", "devnet"]).prop_map(|network| Some(network.to_owned())),
+        "[a-z0-9]{1,6}".prop_map(Some),
+      ],
+    )
+      .prop_map(|(tag, network)| {
+        let tag: String = tag.iter().map(|byte| format!("{byte:02x}")).collect();
+        let did: String = match network {
+          Some(network) => format!("did:{}:{network}:0x{tag}", IotaDID::METHOD),
+          None => format!("did:{}:0x{tag}", IotaDID::METHOD),
+        };
+        IotaDID::parse(did).expect("generated DIDs are valid IOTA DIDs")
+      })
+  }
+
+  /// Generates an [`IotaDocument`] with an arbitrary identifier and IOTA DID controllers.
+  pub fn arb_iota_document() -> impl Strategy<Value = IotaDocument> {
+    arb_iota_did().prop_flat_map(|did| {
+      let controllers = vec(arb_iota_did().prop_map(CoreDID::from), 0..3);
+      arb_core_document_with_id(CoreDID::from(did), controllers).prop_map(|document| {
+        IotaDocument::try_from((document, IotaDocumentMetadata::new()))
+          .expect("the generated document satisfies the IOTA DID method requirements")
+      })
+    })
+  }
+}
+
+#[cfg(feature = "iota-document")]
+pub use iota::*;
+
+// ===========================================================================
+// Helpers
+// ===========================================================================
+
+type ServiceParts = (Vec<String>, ServiceEndpoint);
+
+struct DocumentParts {
+  id: CoreDID,
+  controllers: Vec<CoreDID>,
+  also_known_as: BTreeSet<Url>,
+  properties: Object,
+  resources: Vec<(String, Placement, (MethodType, MethodData), ServiceParts)>,
+}
+
+fn build_document(parts: DocumentParts) -> CoreDocument {
+  let DocumentParts {
+    id,
+    controllers,
+    also_known_as,
+    properties,
+    resources,
+  } = parts;
+
+  // Controllers must be unique.
+  let controllers: BTreeMap<String, CoreDID> = controllers
+    .into_iter()
+    .map(|controller| (controller.to_string(), controller))
+    .collect();
+
+  let mut builder = CoreDocument::builder(properties).id(id.clone());
+  for controller in controllers.into_values() {
+    builder = builder.controller(controller);
+  }
+  for url in also_known_as {
+    builder = builder.also_known_as(url);
+  }
+
+  for (fragment, placement, (type_, data), (types, endpoint)) in resources {
+    match placement {
+      Placement::VerificationMethod(relationships) => {
+        let method: VerificationMethod = method(&id, &fragment, type_, data);
+        let method_id: DIDUrl = method.id().clone();
+        builder = builder.verification_method(method);
+        for relationship in relationships {
+          builder = match relationship {
+            MethodRelationship::Authentication => builder.authentication(method_id.clone()),
+            MethodRelationship::AssertionMethod => builder.assertion_method(method_id.clone()),
+            MethodRelationship::KeyAgreement => builder.key_agreement(method_id.clone()),
+            MethodRelationship::CapabilityDelegation => builder.capability_delegation(method_id.clone()),
+            MethodRelationship::CapabilityInvocation => builder.capability_invocation(method_id.clone()),
+          };
+        }
+      }
+      Placement::Embedded(relationship) => {
+        let method: VerificationMethod = method(&id, &fragment, type_, data);
+        builder = match relationship {
+          MethodRelationship::Authentication => builder.authentication(method),
+          MethodRelationship::AssertionMethod => builder.assertion_method(method),
+          MethodRelationship::KeyAgreement => builder.key_agreement(method),
+          MethodRelationship::CapabilityDelegation => builder.capability_delegation(method),
+          MethodRelationship::CapabilityInvocation => builder.capability_invocation(method),
+        };
+      }
+      Placement::Service => {
+        builder = builder.service(service(&id, &fragment, types, endpoint));
+      }
+    }
+  }
+
+  builder
+    .build()
+    .expect("generated documents contain unique identifiers only")
+}
+
+fn method(did: &CoreDID, fragment: &str, type_: MethodType, data: MethodData) -> VerificationMethod {
+  VerificationMethod::builder(Object::new())
+    .id(did_url(did, fragment))
+    .controller(did.clone())
+    .type_(type_)
+    .data(data)
+    .build()
+    .expect("generated methods are valid")
+}
+
+fn service(did: &CoreDID, fragment: &str, types: Vec<String>, endpoint: ServiceEndpoint) -> Service {
+  Service::builder(Object::new())
+    .id(did_url(did, fragment))
+    .types(types)
+    .service_endpoint(endpoint)
+    .build()
+    .expect("generated services are valid")
+}
+
+fn did_url(did: &CoreDID, fragment: &str) -> DIDUrl {
+  did
+    .to_url()
+    .join(format!("#{fragment}"))
+    .expect("generated fragments are valid")
+}
+
+fn ed25519_jwk(public_key: &[u8]) -> Jwk {
+  let mut params: JwkParamsOkp = JwkParamsOkp::new();
+  params.x = jwu::encode_b64(public_key);
+  params.crv = EdCurve::Ed25519.name().to_owned();
+  Jwk::from_params(params)
+}
@@ -0,0 +1,99 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "conformance")]
+
+use identity_test_support::conformance::ConformanceReport;
+use identity_test_support::conformance::Generator;
+use identity_test_support::conformance::JwtOptions;
+use serde_json::json;
+use serde_json::Value;
+
+fn credential_v1() -> Value {
+  json!({
+    "@context": ["https://www.w3.org/2018/credentials/v1", "https://www.w3.org/2018/credentials/examples/v1"],
+    "id": "http://example.edu/credentials/1872",
+    "type": ["VerifiableCredential", "AlumniCredential"],
+    "issuer": "https://example.edu/issuers/565049",
+    "issuanceDate": "2010-01-01T19:23:24Z",
+    "credentialSubject": {
+      "id": "did:example:ebfeb1f712ebc6f1c276e12ec21",
+      "alumniOf": "Example University"
+    }
+  })
+}
+
+#[tokio::test]
+async fn generator_rejects_malformed_credentials() {
+  let generator = Generator::new(0).await.unwrap();
+  let credential = credential_v1();
+  let output: Value = serde_json::from_str(&generator.generate(&credential.to_string()).unwrap()).unwrap();
+  assert_eq!(output, credential);
+
+  let mut missing_subject = credential;
+  missing_subject.as_object_mut().unwrap().remove("credentialSubject");
+  assert!(generator.generate(&missing_subject.to_string()).is_err());
+}
+
+#[tokio::test]
+async fn jwt_round_trip() {
+  let generator = Generator::new(0).await.unwrap();
+  let credential = credential_v1();
+  for options in [JwtOptions::new(), JwtOptions::new().unsigned(true)] {
+    let jwt = generator.generate_jwt(&credential.to_string(), &options).await.unwrap();
+    let decoded: Value = serde_json::from_str(&generator.decode_jwt(&jwt).unwrap()).unwrap();
+    assert_eq!(decoded, credential);
+  }
+
+  let error = generator
+    .generate_jwt(&credential.to_string(), &JwtOptions::new().presentation(true))
+    .await;
+  assert!(error.is_err());
+}
+
+#[tokio::test]
+async fn jose_round_trip() {
+  let generator = Generator::new(0).await.unwrap();
+  let credential = json!({
+    "@context": ["https://www.w3.org/ns/credentials/v2", "https://www.w3.org/ns/credentials/examples/v2"],
+    "id": "http://university.example/credentials/3732",
+    "type": ["VerifiableCredential", "ExampleDegreeCredential"],
+    "issuer": "https://university.example/issuers/565049",
+    "validFrom": "2010-01-01T00:00:00Z",
+    "credentialSubject": { "id": "did:example:ebfeb1f712ebc6f1c276e12ec21" }
+  });
+
+  let jws = generator.secure_jose(&credential.to_string()).await.unwrap();
+  let verified: Value = serde_json::from_str(&generator.verify_jose(&jws, None).unwrap()).unwrap();
+  assert_eq!(verified, credential);
+
+  let mut tampered = jws.clone();
+  tampered.pop();
+  assert!(generator.verify_jose(&tampered, None).is_err());
+  assert!(generator.secure_jose(&credential_v1().to_string()).await.is_err());
+}
+
+#[test]
+fn report_detects_regressions() {
+  let results = |passes: &[&str], failures: &[&str]| {
+    let tests = |titles: &[&str]| {
+      titles
+        .iter()
+        .map(|title| json!({ "fullTitle": title }))
+        .collect::<Vec<_>>()
+    };
+    json!({ "stats": {}, "passes": tests(passes), "failures": tests(failures), "pending": [] }).to_string()
+  };
+
+  let baseline =
+    ConformanceReport::from_mocha_json("vc-data-model-1.1", "test", &results(&["a", "b"], &["c"])).unwrap();
+  let report = ConformanceReport::from_mocha_json("vc-data-model-1.1", "test", &results(&["a", "c"], &["b"])).unwrap();
+
+  assert_eq!(report.regressions(&baseline), vec!["b".to_owned()]);
+  assert_eq!(report.improvements(&baseline), vec!["c".to_owned()]);
+  assert!(baseline.regressions(&baseline).is_empty());
+  assert_eq!(
+    report.to_string(),
+    "vc-data-model-1.1 (test): 2 passed, 1 failed, 0 pending"
+  );
+}
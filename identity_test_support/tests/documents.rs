@@ -0,0 +1,41 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_test_support::invariants::check_core_document;
+use identity_test_support::invariants::check_iota_document;
+use identity_test_support::invariants::check_json_roundtrip;
+use identity_test_support::strategies::arb_core_did;
+use identity_test_support::strategies::arb_core_document;
+use identity_test_support::strategies::arb_fragment;
+use identity_test_support::strategies::arb_iota_document;
+use identity_test_support::strategies::arb_service;
+use identity_test_support::strategies::arb_verification_method;
+use proptest::prelude::*;
+
+proptest! {
+  #![proptest_config(ProptestConfig::with_cases(64))]
+
+  #[test]
+  fn core_document_invariants(document in arb_core_document()) {
+    check_core_document(&document)?;
+  }
+
+  #[test]
+  fn iota_document_invariants(document in arb_iota_document()) {
+    check_iota_document(&document)?;
+  }
+
+  #[test]
+  fn verification_method_roundtrip(
+    method in (arb_core_did(), arb_fragment()).prop_flat_map(|(did, fragment)| arb_verification_method(did, fragment))
+  ) {
+    check_json_roundtrip(&method)?;
+  }
+
+  #[test]
+  fn service_roundtrip(
+    service in (arb_core_did(), arb_fragment()).prop_flat_map(|(did, fragment)| arb_service(did, fragment))
+  ) {
+    check_json_roundtrip(&service)?;
+  }
+}
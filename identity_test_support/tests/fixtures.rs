@@ -0,0 +1,79 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![cfg(feature = "fixtures")]
+
+use identity_core::common::Object;
+use identity_core::convert::ToJson;
+use identity_credential::validator::FailFast;
+use identity_credential::validator::JwtCredentialValidationOptions;
+use identity_credential::validator::JwtCredentialValidator;
+use identity_eddsa_verifier::EdDSAJwsVerifier;
+use identity_test_support::fixtures::Fixtures;
+
+async fn artifacts(seed: u64) -> (String, String, String) {
+  let mut fixtures = Fixtures::new(seed);
+  let issuer = fixtures.identity().await.unwrap();
+  let holder = fixtures.identity().await.unwrap();
+  let credential = fixtures.credential(&issuer, &holder).unwrap();
+  let credential_jwt = fixtures.credential_jwt(&issuer, &credential).await.unwrap();
+  let presentation_jwt = fixtures
+    .presentation_jwt(&holder, [credential_jwt.clone()], Some("nonce"))
+    .await
+    .unwrap();
+
+  (
+    issuer.document().to_json().unwrap(),
+    credential_jwt.as_str().to_owned(),
+    presentation_jwt.as_str().to_owned(),
+  )
+}
+
+#[tokio::test]
+async fn fixtures_are_stable_for_a_seed() {
+  assert_eq!(artifacts(7).await, artifacts(7).await);
+  assert_ne!(artifacts(7).await, artifacts(8).await);
+}
+
+#[tokio::test]
+async fn credential_jwt_is_valid() {
+  let mut fixtures = Fixtures::new(7);
+  let issuer = fixtures.identity().await.unwrap();
+  let holder = fixtures.identity().await.unwrap();
+  let credential = fixtures.credential(&issuer, &holder).unwrap();
+  let jwt = fixtures.credential_jwt(&issuer, &credential).await.unwrap();
+
+  let decoded = JwtCredentialValidator::with_signature_verifier(EdDSAJwsVerifier::default())
+    .validate::<_, Object>(
+      &jwt,
+      issuer.document(),
+      &JwtCredentialValidationOptions::default(),
+      FailFast::FirstError,
+    )
+    .unwrap();
+  assert_eq!(decoded.credential.issuance_date, fixtures.timestamp());
+}
+
+#[cfg(feature = "fixtures-sd-jwt")]
+#[tokio::test]
+async fn sd_jwt_conceals_the_requested_claims() {
+  let mut fixtures = Fixtures::new(7);
+  let issuer = fixtures.identity().await.unwrap();
+  let holder = fixtures.identity().await.unwrap();
+  let credential = fixtures.credential(&issuer, &holder).unwrap();
+  let concealable = [
+    "/vc/credentialSubject/address/locality",
+    "/vc/credentialSubject/address/street_address",
+  ];
+  let sd_jwt = fixtures.sd_jwt(&issuer, &credential, &concealable).await.unwrap();
+
+  assert_eq!(sd_jwt.disclosures().len(), 2);
+  let other = {
+    let mut fixtures = Fixtures::new(7);
+    let issuer = fixtures.identity().await.unwrap();
+    let holder = fixtures.identity().await.unwrap();
+    let credential = fixtures.credential(&issuer, &holder).unwrap();
+    fixtures.sd_jwt(&issuer, &credential, &concealable).await.unwrap()
+  };
+  assert_eq!(sd_jwt.to_string(), other.to_string());
+}
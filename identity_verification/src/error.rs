@@ -42,4 +42,25 @@ pub enum Error {
   /// Caused by key material that is not a Composite Public Key.
   #[error("verification material format is not compositePublicKey")]
   NotCompositePublicKey,
+  /// Caused by multicodec-prefixed key material of an unsupported type.
+  #[error("unsupported multicodec key type {0:#x}")]
+  UnsupportedMulticodec(u64),
+  /// Caused by a JSON Web Key with malformed or insecure RSA parameters.
+  #[error("invalid RSA key parameters")]
+  InvalidRsaKey(#[source] identity_jose::error::Error),
 }
+
+identity_core::impl_error_code!(Error {
+  InvalidMethod => 1300,
+  DIDUrlConstructionError => 1301,
+  MissingIdFragment => 1302,
+  UnknownMethodScope => 1303,
+  InvalidKeyDataBase58 => 1304,
+  InvalidKeyDataMultibase => 1305,
+  InvalidMethodDataTransformation => 1306,
+  PrivateKeyMaterialExposed => 1307,
+  NotPublicKeyJwk => 1308,
+  NotCompositePublicKey => 1309,
+  UnsupportedMulticodec => 1310,
+  InvalidRsaKey => 1311,
+});
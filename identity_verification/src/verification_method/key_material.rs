@@ -0,0 +1,394 @@
+// Copyright 2020-2026 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::codec::decode_varint;
+use identity_core::codec::encode_varint;
+use identity_did::DID;
+use identity_jose::jwk::Jwk;
+use identity_jose::jwk::JwkParams;
+use identity_jose::jwk::JwkParamsEc;
+use identity_jose::jwk::JwkParamsOkp;
+use identity_jose::jwu;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::verification_method::MethodBuilder;
+use crate::verification_method::MethodData;
+use crate::verification_method::MethodType;
+use crate::verification_method::VerificationMethod;
+
+/// The [multicodec](https://github.com/multiformats/multicodec/blob/master/table.csv) public key types
+/// supported by [`VerificationMethod::to_jwk`] and [`VerificationMethod::from_jwk`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum KeyCodec {
+  Ed25519,
+  X25519,
+  Secp256k1,
+  P256,
+  P384,
+  P521,
+}
+
+impl KeyCodec {
+  const ALL: [Self; 6] = [
+    Self::Ed25519,
+    Self::X25519,
+    Self::Secp256k1,
+    Self::P256,
+    Self::P384,
+    Self::P521,
+  ];
+
+  /// The multicodec code of the public key type.
+  const fn code(self) -> u64 {
+    match self {
+      Self::Ed25519 => 0xed,
+      Self::X25519 => 0xec,
+      Self::Secp256k1 => 0xe7,
+      Self::P256 => 0x1200,
+      Self::P384 => 0x1201,
+      Self::P521 => 0x1202,
+    }
+  }
+
+  /// The JWK `crv` of the public key type.
+  const fn curve(self) -> &'static str {
+    match self {
+      Self::Ed25519 => "Ed25519",
+      Self::X25519 => "X25519",
+      Self::Secp256k1 => "secp256k1",
+      Self::P256 => "P-256",
+      Self::P384 => "P-384",
+      Self::P521 => "P-521",
+    }
+  }
+
+  /// The length in bytes of a coordinate of the public key.
+  const fn coordinate_len(self) -> usize {
+    match self {
+      Self::Ed25519 | Self::X25519 | Self::Secp256k1 | Self::P256 => 32,
+      Self::P384 => 48,
+      Self::P521 => 66,
+    }
+  }
+
+  fn from_code(code: u64) -> Result<Self> {
+    Self::ALL
+      .into_iter()
+      .find(|codec| codec.code() == code)
+      .ok_or(Error::UnsupportedMulticodec(code))
+  }
+
+  fn from_curve(curve: &str) -> Result<Self> {
+    Self::ALL
+      .into_iter()
+      .find(|codec| codec.curve() == curve)
+      .ok_or(Error::InvalidMethodDataTransformation("unsupported curve"))
+  }
+
+  /// Builds the JWK of the raw public key `bytes`, which must be SEC1-encoded for elliptic curves other than the
+  /// Edwards and Montgomery ones.
+  fn to_jwk(self, bytes: &[u8]) -> Result<Jwk> {
+    match self {
+      Self::Ed25519 | Self::X25519 => {
+        if bytes.len() != self.coordinate_len() {
+          return Err(Error::InvalidMethodDataTransformation("invalid public key length"));
+        }
+        let mut params = JwkParamsOkp::new();
+        params.crv = self.curve().to_owned();
+        params.x = jwu::encode_b64(bytes);
+        Ok(Jwk::from_params(params))
+      }
+      _ => {
+        let (x, y) = self.decompress(bytes)?;
+        let mut params = JwkParamsEc::new();
+        params.crv = self.curve().to_owned();
+        params.x = jwu::encode_b64(x);
+        params.y = jwu::encode_b64(y);
+        Ok(Jwk::from_params(params))
+      }
+    }
+  }
+
+  /// Returns the raw public key of `jwk`, encoding the points of elliptic curves other than the Edwards and Montgomery
+  /// ones in uncompressed SEC1 form.
+  ///
+  /// Points are not compressed since decompressing them requires arithmetic on the curve, which is only available for
+  /// some curves and behind the `jwk-conversion` feature.
+  fn from_jwk(jwk: &Jwk) -> Result<(Self, Vec<u8>)> {
+    if !jwk.is_public() {
+      return Err(Error::PrivateKeyMaterialExposed);
+    }
+
+    match jwk.params() {
+      JwkParams::Okp(params) => {
+        let codec = Self::from_curve(&params.crv)?;
+        let x = decode_coordinate(&params.x, codec)?;
+        Ok((codec, x))
+      }
+      JwkParams::Ec(params) => {
+        let codec = Self::from_curve(&params.crv)?;
+        let x = decode_coordinate(&params.x, codec)?;
+        let y = decode_coordinate(&params.y, codec)?;
+        let mut uncompressed = Vec::with_capacity(x.len() + y.len() + 1);
+        uncompressed.push(0x04);
+        uncompressed.extend(x);
+        uncompressed.extend(y);
+        Ok((codec, uncompressed))
+      }
+      _ => Err(Error::InvalidMethodDataTransformation("unsupported key type")),
+    }
+  }
+
+  /// Returns the coordinates of the SEC1-encoded point `bytes`.
+  fn decompress(self, bytes: &[u8]) -> Result<(Vec<u8>, Vec<u8>)> {
+    let len = self.coordinate_len();
+    match bytes.first() {
+      Some(0x04) if bytes.len() == 2 * len + 1 => Ok((bytes[1..=len].to_vec(), bytes[len + 1..].to_vec())),
+      #[cfg(feature = "jwk-conversion")]
+      Some(0x02 | 0x03) if bytes.len() == len + 1 => {
+        use p256::elliptic_curve::sec1::ToEncodedPoint;
+
+        let point = match self {
+          Self::P256 => p256::PublicKey::from_sec1_bytes(bytes).map(|pk| pk.to_encoded_point(false)),
+          Self::Secp256k1 => k256::PublicKey::from_sec1_bytes(bytes).map(|pk| pk.to_encoded_point(false)),
+          _ => {
+            return Err(Error::InvalidMethodDataTransformation(
+              "decompression is not supported for this curve",
+            ))
+          }
+        }
+        .map_err(|_| Error::InvalidMethodDataTransformation("invalid elliptic curve point"))?;
+        let x = point.x().expect("uncompressed point").to_vec();
+        let y = point.y().expect("uncompressed point").to_vec();
+        Ok((x, y))
+      }
+      #[cfg(not(feature = "jwk-conversion"))]
+      Some(0x02 | 0x03) if bytes.len() == len + 1 => Err(Error::InvalidMethodDataTransformation(
+        "decompressing elliptic curve points requires the `jwk-conversion` feature",
+      )),
+      _ => Err(Error::InvalidMethodDataTransformation("invalid elliptic curve point")),
+    }
+  }
+}
+
+fn decode_coordinate(coordinate: &str, codec: KeyCodec) -> Result<Vec<u8>> {
+  jwu::decode_b64(coordinate)
+    .ok()
+    .filter(|bytes| bytes.len() == codec.coordinate_len())
+    .ok_or(Error::InvalidMethodDataTransformation("invalid JWK coordinate"))
+}
+
+impl MethodData {
+  /// Creates a new [`MethodData::PublicKeyMultibase`] from the public key `jwk`, prefixing the key material with its
+  /// [multicodec](https://github.com/multiformats/multicodec) identifier as done by `Multikey` verification methods.
+  ///
+  /// Points of elliptic curves other than `Ed25519` and `X25519` are encoded in uncompressed SEC1 form, so that
+  /// [`VerificationMethod::to_jwk`] can decode them for every supported curve.
+  ///
+  /// # Errors
+  /// Fails if `jwk` contains private key material or if its curve is not supported.
+  pub fn new_multicodec(jwk: &Jwk) -> Result<Self> {
+    let (codec, key) = KeyCodec::from_jwk(jwk)?;
+    let mut bytes = Vec::with_capacity(key.len() + 3);
+    encode_varint(codec.code(), &mut bytes);
+    bytes.extend(key);
+    Ok(Self::new_multibase(bytes))
+  }
+}
+
+impl VerificationMethod {
+  /// Creates a new `Multikey` [`VerificationMethod`] from the given `did` and public key `jwk`, whose key material
+  /// is multicodec-prefixed and multibase-encoded in the `publicKeyMultibase` property.
+  ///
+  /// As with [`VerificationMethod::new_from_jwk`], the `kid` of `jwk` is used when no `fragment` is given.
+  ///
+  /// # Errors
+  /// Fails if no fragment can be determined, if `jwk` contains private key material or if its curve is not
+  /// supported.
+  pub fn from_jwk<D: DID>(did: D, jwk: &Jwk, fragment: Option<&str>) -> Result<Self> {
+    let data = MethodData::new_multicodec(jwk)?;
+    let method = Self::new_from_jwk(did, jwk.to_public().ok_or(Error::PrivateKeyMaterialExposed)?, fragment)?;
+
+    MethodBuilder::default()
+      .id(method.id)
+      .controller(method.controller)
+      .type_(MethodType::MULTIKEY)
+      .data(data)
+      .build()
+  }
+
+  /// Returns the public key material of this method as a [`Jwk`], regardless of how it is encoded.
+  ///
+  /// `publicKeyJwk` material is returned as-is. Multicodec-prefixed `publicKeyMultibase` material is decoded for the
+  /// `Ed25519`, `X25519`, `secp256k1`, `P-256`, `P-384` and `P-521` curves. Unprefixed `publicKeyMultibase` and
+  /// `publicKeyBase58` material is decoded according to the method type for `Ed25519VerificationKey2018` and
+  /// `X25519KeyAgreementKey2019` methods.
+  ///
+  /// Uncompressed elliptic curve points are decoded for every supported curve. Compressed points, as produced by
+  /// other implementations, can only be decoded for `secp256k1` and `P-256` keys, and require the `jwk-conversion`
+  /// feature.
+  ///
+  /// # Errors
+  /// Fails if the key material cannot be decoded or is not supported.
+  pub fn to_jwk(&self) -> Result<Jwk> {
+    let raw_codec = if self.type_ == MethodType::ED25519_VERIFICATION_KEY_2018 {
+      Some(KeyCodec::Ed25519)
+    } else if self.type_ == MethodType::X25519_KEY_AGREEMENT_KEY_2019 {
+      Some(KeyCodec::X25519)
+    } else {
+      None
+    };
+
+    match &self.data {
+      MethodData::PublicKeyJwk(jwk) => Ok(jwk.clone()),
+      MethodData::PublicKeyBase58(_) => {
+        let codec = raw_codec.ok_or(Error::InvalidMethodDataTransformation("unsupported method type"))?;
+        codec.to_jwk(&self.data.try_decode()?)
+      }
+      MethodData::PublicKeyMultibase(_) => {
+        let bytes: Vec<u8> = self.data.try_decode()?;
+        match raw_codec {
+          Some(codec) if bytes.len() == codec.coordinate_len() => codec.to_jwk(&bytes),
+          _ => {
+            let (code, key) = decode_varint(&bytes).map_err(|_| Error::InvalidKeyDataMultibase)?;
+            KeyCodec::from_code(code)?.to_jwk(key)
+          }
+        }
+      }
+      _ => Err(Error::InvalidMethodDataTransformation(
+        "method data does not contain a single public key",
+      )),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+  use identity_did::CoreDID;
+  use serde_json::json;
+
+  use super::*;
+
+  fn did() -> CoreDID {
+    "did:example:1234".parse().unwrap()
+  }
+
+  #[test]
+  fn ed25519_round_trips_through_multikey() {
+    let jwk = Jwk::from_json_value(json!({
+      "kty": "OKP",
+      "crv": "Ed25519",
+      "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"
+    }))
+    .unwrap();
+
+    let method = VerificationMethod::from_jwk(did(), &jwk, Some("key-1")).unwrap();
+    assert_eq!(method.type_(), &MethodType::MULTIKEY);
+    let MethodData::PublicKeyMultibase(multibase) = method.data() else {
+      panic!("expected publicKeyMultibase");
+    };
+    assert_eq!(multibase, "z6MktwupdmLXVVqTzCw4i46r4uGyosGXRnR3XjN4Zq7oMMsw");
+    assert_eq!(method.to_jwk().unwrap(), jwk);
+  }
+
+  #[test]
+  fn legacy_method_types_are_decoded() {
+    let key = [7; 32];
+    for (type_, crv) in [
+      (MethodType::ED25519_VERIFICATION_KEY_2018, "Ed25519"),
+      (MethodType::X25519_KEY_AGREEMENT_KEY_2019, "X25519"),
+    ] {
+      for data in [MethodData::new_base58(key), MethodData::new_multibase(key)] {
+        let method = MethodBuilder::default()
+          .id(did().join("#key-1").unwrap())
+          .controller(did())
+          .type_(type_.clone())
+          .data(data)
+          .build()
+          .unwrap();
+        let jwk = method.to_jwk().unwrap();
+        assert_eq!(jwk.try_okp_params().unwrap().crv, crv);
+        assert_eq!(jwu::decode_b64(&jwk.try_okp_params().unwrap().x).unwrap(), key);
+      }
+    }
+  }
+
+  #[test]
+  fn every_curve_round_trips_through_multikey() {
+    for codec in KeyCodec::ALL {
+      let len = codec.coordinate_len();
+      let params = match codec {
+        KeyCodec::Ed25519 | KeyCodec::X25519 => json!({
+          "kty": "OKP",
+          "crv": codec.curve(),
+          "x": jwu::encode_b64(vec![1; len]),
+        }),
+        _ => json!({
+          "kty": "EC",
+          "crv": codec.curve(),
+          "x": jwu::encode_b64(vec![1; len]),
+          "y": jwu::encode_b64(vec![2; len]),
+        }),
+      };
+      let jwk = Jwk::from_json_value(params).unwrap();
+
+      let method = VerificationMethod::from_jwk(did(), &jwk, Some("key-1")).unwrap();
+      let bytes: Vec<u8> = method.data().try_decode().unwrap();
+      let (code, key) = decode_varint(&bytes).unwrap();
+      assert_eq!(code, codec.code());
+      if jwk.try_ec_params().is_ok() {
+        assert_eq!(key.len(), 2 * len + 1, "{}", codec.curve());
+        assert_eq!(key[0], 0x04);
+      }
+      assert_eq!(method.to_jwk().unwrap(), jwk, "{}", codec.curve());
+    }
+  }
+
+  #[cfg(feature = "jwk-conversion")]
+  #[test]
+  fn compressed_ec_points_are_decoded() {
+    let jwk = Jwk::from_json_value(json!({
+      "kty": "EC",
+      "crv": "P-256",
+      "x": "igrFmi0whuihKnj9R3Om1SoMph72wUGeFaBbzG2vzns",
+      "y": "efsX5b10x8yjyrj4ny3pGfLcY7Xby1KzgqOdqnsrJIM"
+    }))
+    .unwrap();
+
+    let params = jwk.try_ec_params().unwrap();
+    let y = jwu::decode_b64(&params.y).unwrap();
+    let mut compressed = vec![0x80, 0x24, if y[y.len() - 1] % 2 == 1 { 0x03 } else { 0x02 }];
+    compressed.extend(jwu::decode_b64(&params.x).unwrap());
+
+    let mut method = VerificationMethod::from_jwk(did(), &jwk, Some("key-1")).unwrap();
+    *method.data_mut() = MethodData::new_multibase(compressed);
+    assert_eq!(method.to_jwk().unwrap(), jwk);
+  }
+
+  #[test]
+  fn unsupported_material_is_rejected() {
+    let private = Jwk::from_json_value(json!({
+      "kty": "OKP",
+      "crv": "Ed25519",
+      "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo",
+      "d": "nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A"
+    }))
+    .unwrap();
+    assert!(matches!(
+      VerificationMethod::from_jwk(did(), &private, Some("key-1")),
+      Err(Error::PrivateKeyMaterialExposed)
+    ));
+
+    let mut method = VerificationMethod::from_jwk(did(), &private.to_public().unwrap(), Some("key-1")).unwrap();
+    *method.data_mut() = MethodData::new_multibase([0x80, 0x80]);
+    assert!(matches!(method.to_jwk(), Err(Error::InvalidKeyDataMultibase)));
+    let mut bytes = Vec::new();
+    encode_varint(0x1300, &mut bytes);
+    assert!(matches!(
+      KeyCodec::from_code(decode_varint(&bytes).unwrap().0),
+      Err(Error::UnsupportedMulticodec(0x1300))
+    ));
+  }
+}
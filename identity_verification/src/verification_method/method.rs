@@ -79,6 +79,9 @@ impl VerificationMethod {
       if !jwk.is_public() {
         return Err(crate::error::Error::PrivateKeyMaterialExposed);
       }
+      if let Ok(params) = jwk.try_rsa_params() {
+        params.validate().map_err(Error::InvalidRsaKey)?;
+      }
     };
 
     Ok(VerificationMethod {
@@ -13,6 +13,8 @@ const ED25519_VERIFICATION_KEY_2018_STR: &str = "Ed25519VerificationKey2018";
 const X25519_KEY_AGREEMENT_KEY_2019_STR: &str = "X25519KeyAgreementKey2019";
 const JSON_WEB_KEY_METHOD_TYPE: &str = "JsonWebKey";
 const JSON_WEB_KEY_2020_STR: &str = "JsonWebKey2020";
+const MULTIKEY_STR: &str = "Multikey";
+const RSA_VERIFICATION_KEY_2018_STR: &str = "RsaVerificationKey2018";
 
 /// verification method types.
 #[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
@@ -30,6 +32,13 @@ impl MethodType {
   /// A verification method for use with JWT verification as prescribed by the [`Jwk`](::identity_jose::jwk::Jwk)
   /// in the [`publicKeyJwk`](crate::MethodData::PublicKeyJwk) entry.
   pub const JSON_WEB_KEY_2020: Self = Self(Cow::Borrowed(JSON_WEB_KEY_2020_STR));
+  /// A verification method whose multicodec-prefixed key material is in the
+  /// [`publicKeyMultibase`](crate::MethodData::PublicKeyMultibase) entry, as defined in
+  /// [Controlled Identifiers](https://www.w3.org/TR/cid-1.0/#Multikey).
+  pub const MULTIKEY: Self = Self(Cow::Borrowed(MULTIKEY_STR));
+  /// The `RsaVerificationKey2018` method type, whose RSA public key is in the
+  /// [`publicKeyJwk`](crate::MethodData::PublicKeyJwk) entry.
+  pub const RSA_VERIFICATION_KEY_2018: Self = Self(Cow::Borrowed(RSA_VERIFICATION_KEY_2018_STR));
   /// Construct a custom method type.
   pub fn custom(type_: impl AsRef<str>) -> Self {
     Self(Cow::Owned(type_.as_ref().to_owned()))
@@ -67,6 +76,8 @@ impl FromStr for MethodType {
         Self::JSON_WEB_KEY,
       ),
       JSON_WEB_KEY_2020_STR => Ok(Self::JSON_WEB_KEY_2020),
+      MULTIKEY_STR => Ok(Self::MULTIKEY),
+      RSA_VERIFICATION_KEY_2018_STR => Ok(Self::RSA_VERIFICATION_KEY_2018),
       _ => Ok(Self(Cow::Owned(string.to_owned()))),
     }
   }
@@ -84,6 +95,8 @@ mod tests {
       MethodType::ED25519_VERIFICATION_KEY_2018,
       MethodType::X25519_KEY_AGREEMENT_KEY_2019,
       MethodType::JSON_WEB_KEY_2020,
+      MethodType::MULTIKEY,
+      MethodType::RSA_VERIFICATION_KEY_2018,
     ] {
       let ser: Value = serde_json::to_value(method_type.clone()).unwrap();
       assert_eq!(ser.as_str().unwrap(), method_type.as_str());
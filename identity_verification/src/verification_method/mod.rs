@@ -7,6 +7,7 @@
 //! `identity_iota_core_legacy` crate.
 
 mod builder;
+mod key_material;
 mod material;
 mod method;
 mod method_ref;